@@ -7,7 +7,7 @@
 use anyhow::Result;
 use serde::Deserialize;
 use std::io::{self, BufRead};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::UNIX_EPOCH;
 // use tokio::time::{sleep, Duration};  // Not needed for CARLA mode
 // use zenoh::prelude::*;  // Removed in Zenoh 1.0
 
@@ -17,10 +17,10 @@ use godview_core::{
     TrackManager, TrackingConfig, GlobalHazardPacket as CoreHazardPacket,
 };
 use ed25519_dalek::SigningKey;
+use godview_env::determinism::{Entropy, WallClock};
 use h3o::Resolution;
 use nalgebra::{DVector, DMatrix};
 use uuid::Uuid;
-use rand::rngs::OsRng;
 
 use crate::GlobalHazardPacket;
 
@@ -86,8 +86,10 @@ pub async fn run_carla_mode() -> Result<()> {
     println!("   ✅ Track Manager initialized (GNN + CI + Highlander)");
     
     // Initialize Security
-    let signing_key = SigningKey::generate(&mut OsRng);
+    let signing_key = SigningKey::generate(&mut Entropy::os());
     println!("   ✅ Security initialized (Ed25519)");
+    
+    let clock = WallClock::system();
     println!();
 
     // ========== INITIALIZE ZENOH ==========
@@ -149,12 +151,12 @@ pub async fn run_carla_mode() -> Result<()> {
         ];
         
         // Get current time
-        let current_time = SystemTime::now()
+        let current_time = clock.now()
             .duration_since(UNIX_EPOCH)?
             .as_secs_f64();
         
         // Create Entity
-        let timestamp = SystemTime::now()
+        let timestamp = clock.now()
             .duration_since(UNIX_EPOCH)?
             .as_millis() as i64;
         
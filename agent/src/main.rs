@@ -8,7 +8,6 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::time::{sleep, Duration};
 // use zenoh::prelude::*;  // Removed in Zenoh 1.0
 
@@ -18,7 +17,6 @@ use ed25519_dalek::SigningKey;
 use h3o::Resolution;
 use nalgebra::{DVector, DMatrix};
 use uuid::Uuid;
-use rand::rngs::OsRng;
 
 // Webcam mode (optional - requires OpenCV)
 #[cfg(feature = "webcam")]
@@ -15,7 +15,7 @@
 //! ```
 
 use godview_core::{
-    GlobalHazardPacket, TrackManager,
+    GlobalHazardPacket, MeasurementModel, TrackManager,
     visualization::RerunVisualizer,
     metrics::{calculate_ghost_score, calculate_entropy, GhostScoreConfig},
 };
@@ -355,6 +355,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     class_id: 1,
                     timestamp: t,
                     confidence_score: 0.95,
+                    measurement_model: MeasurementModel::default(),
+                    priority: 0,
                 };
                 
                 viz.log_detection_line(
@@ -389,6 +391,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     class_id: match target.class { "pedestrian" => 2, "cyclist" => 3, _ => 1 },
                     timestamp: t,
                     confidence_score: 1.0 - dist / av.sensor_range * 0.2,
+                    measurement_model: MeasurementModel::default(),
+                    priority: 0,
                 };
                 
                 viz.log_packet_detection(&packet, av.noise_scale as f32 * 0.3)?;
@@ -429,6 +433,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     class_id: match target.class { "pedestrian" => 2, "cyclist" => 3, _ => 1 },
                     timestamp: t,
                     confidence_score: 0.8,
+                    measurement_model: MeasurementModel::default(),
+                    priority: 0,
                 };
                 
                 viz.log_packet_detection(&packet, cam.noise_scale as f32 * 0.3)?;
@@ -457,6 +463,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     class_id: 1,
                     timestamp: t,
                     confidence_score: 0.85,
+                    measurement_model: MeasurementModel::default(),
+                    priority: 0,
                 };
                 
                 viz.log_packet_detection(&packet, cam.noise_scale as f32 * 0.3)?;
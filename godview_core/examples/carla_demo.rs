@@ -23,7 +23,7 @@ use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 use godview_core::{
-    TrackManager, GlobalHazardPacket,
+    TrackManager, GlobalHazardPacket, MeasurementModel,
     visualization::{RerunVisualizer, CinematicCamera},
     metrics::{calculate_ghost_score, GhostScoreConfig},
 };
@@ -414,6 +414,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         class_id: match actor_type { "vehicle" => 1, "pedestrian" => 2, _ => 0 },
                         timestamp: sim_time,
                         confidence_score: 1.0,
+                        measurement_model: MeasurementModel::default(),
+                        priority: 0,
                     };
                     
                     let _ = track_manager.process_packet(&packet);
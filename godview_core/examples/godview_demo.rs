@@ -12,6 +12,8 @@
 //!
 //! Run: `cargo run --example godview_demo`
 
+#![allow(dead_code)]
+
 use std::collections::{HashMap, HashSet};
 
 // ============================================================================
@@ -22,7 +22,7 @@
 //! ```
 
 use godview_core::{
-    GlobalHazardPacket, TrackManager, TrackingConfig,
+    GlobalHazardPacket, MeasurementModel, TrackManager, TrackingConfig,
     visualization::RerunVisualizer,
     metrics::{calculate_ghost_score, calculate_entropy, GhostScoreConfig},
 };
@@ -293,6 +293,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     },
                     timestamp: t,
                     confidence_score: 1.0 - (dist / agent.range) * 0.3,
+                    measurement_model: MeasurementModel::default(),
+                    priority: 0,
                 };
                 
                 // Log raw detection as small colored dot
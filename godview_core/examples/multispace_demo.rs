@@ -15,7 +15,7 @@
 //! ```
 
 use godview_core::{
-    GlobalHazardPacket, TrackManager,
+    GlobalHazardPacket, MeasurementModel, TrackManager,
     visualization::RerunVisualizer,
     metrics::{calculate_ghost_score, GhostScoreConfig},
 };
@@ -172,6 +172,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     class_id: 1,
                     timestamp: t,
                     confidence_score: 0.9,
+                    measurement_model: MeasurementModel::default(),
+                    priority: 0,
                 };
                 
                 let _ = track_manager.process_packet(&packet);
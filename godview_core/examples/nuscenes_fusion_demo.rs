@@ -21,7 +21,7 @@
 //! ```
 
 use godview_core::{
-    GlobalHazardPacket, TrackManager, TrackingConfig,
+    GlobalHazardPacket, MeasurementModel, TrackManager, TrackingConfig,
     visualization::RerunVisualizer,
     metrics::{calculate_ghost_score, calculate_entropy, calculate_entropy_reduction, calculate_tension, GhostScoreConfig},
 };
@@ -296,6 +296,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     class_id,
                     timestamp: frame.timestamp,
                     confidence_score: 1.0 / (1.0 + noise_factor * 0.5),
+                    measurement_model: MeasurementModel::default(),
+                    priority: 0,
                 };
                 
                 // Log raw detection (small colored dot)
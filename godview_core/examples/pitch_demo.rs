@@ -7,6 +7,8 @@
 //!
 //! Run: `cargo run --example pitch_demo`
 
+#![allow(dead_code)]
+
 use std::collections::{HashMap, HashSet};
 
 // ============================================================================
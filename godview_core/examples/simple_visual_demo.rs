@@ -115,6 +115,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             class_id: 1,
                             timestamp: t,
                             confidence_score: 0.9,
+                            measurement_model: godview_core::MeasurementModel::default(),
+                            priority: 0,
                         },
                         1.5,
                     )?;
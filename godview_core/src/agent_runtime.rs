@@ -29,7 +29,10 @@
 //! use godview_env::TokioContext;
 //!
 //! let ctx = TokioContext::shared();
-//! let config = AgentConfig::default();
+//! let config = AgentConfig::builder()
+//!     .tick_rate_hz(30)
+//!     .process_noise(0.1, 1.0)
+//!     .build()?;
 //! let agent = GodViewAgent::new(ctx, config);
 //!
 //! // Run agent loop
@@ -40,7 +43,8 @@ use godview_env::{GodViewContext, NodeId, NetworkTransport, SignedPacketEnvelope
 use crate::godview_time::AugmentedStateFilter;
 use crate::godview_space::SpatialEngine;
 use crate::godview_trust::SecurityContext;
-use crate::godview_tracking::TrackManager;
+use crate::godview_tracking::{ClassCompatibility, FilterTuning, Frame, LifecycleConfig, NonlinearMeasurements, RobustFusion, TrackManager, TrackingConfig};
+use h3o::Resolution;
 
 use std::sync::Arc;
 
@@ -58,6 +62,137 @@ pub struct AgentConfig {
     
     /// Maximum OOSM lag depth in ticks (default: 20)
     pub max_lag_depth: usize,
+    
+    /// Ticks between gossip rounds (default: 5)
+    pub gossip_interval_ticks: u64,
+    
+    /// Record track create/merge/split/delete history (default: false)
+    pub record_genealogy: bool,
+    
+    /// Gossip acceptance decisions kept per agent for inspection (default:
+    /// None = not logged)
+    pub gossip_decision_log: Option<usize>,
+    
+    /// Robust (Huber-weighted) track fusion (default: None)
+    pub robust_fusion: Option<RobustFusion>,
+    
+    /// Track prediction tuning applied every tick (default: None = no
+    /// prediction between updates)
+    pub filter: Option<FilterTuning>,
+    
+    /// Track capacity before least-useful tracks are evicted (default:
+    /// None = unbounded)
+    pub max_tracks: Option<usize>,
+    
+    /// Coordinate frame of observed positions (default: Wgs84)
+    pub frame: Frame,
+    
+    /// M-of-N track confirmation and coasting (default: None = tracks are
+    /// confirmed on creation)
+    pub lifecycle: Option<LifecycleConfig>,
+    
+    /// Class pairs allowed to associate (default: None = exact class only)
+    pub class_gate: Option<ClassCompatibility>,
+    
+    /// Accept bearing and range-Doppler measurements (default: None)
+    pub nonlinear_measurements: Option<NonlinearMeasurements>,
+    
+    /// Cycles before an unobserved high-priority track is deleted
+    /// (default: None = same as every other track)
+    pub high_priority_max_age: Option<u32>,
+    
+    /// Altitude variance of a reading as a multiple of its horizontal
+    /// variance (default: None = isotropic)
+    pub vertical_variance_ratio: Option<f64>,
+    
+    /// Most neighbors each track credits with the information their gossip
+    /// added (default: None = no provenance, see
+    /// [`TrackingConfig::provenance`])
+    pub provenance: Option<usize>,
+}
+
+impl AgentConfig {
+    /// Starts a validated builder from the default config.
+    pub fn builder() -> AgentConfigBuilder {
+        AgentConfigBuilder::default()
+    }
+    
+    /// Checks every field against its valid range.
+    ///
+    /// Returns the first offending field. Configs built with struct literals
+    /// skip this until an agent is constructed from them.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check("tick_rate_hz", self.tick_rate_hz, self.tick_rate_hz > 0, "> 0")?;
+        check("h3_resolution", self.h3_resolution, Resolution::try_from(self.h3_resolution).is_ok(), "in 0..=15")?;
+        check("max_lag_depth", self.max_lag_depth, self.max_lag_depth > 0, "> 0")?;
+        check("gossip_interval_ticks", self.gossip_interval_ticks, self.gossip_interval_ticks > 0, "> 0")?;
+        if let Some(max) = self.max_tracks {
+            check("max_tracks", max, max > 0, "> 0")?;
+        }
+        if let Some(capacity) = self.gossip_decision_log {
+            check("gossip_decision_log", capacity, capacity > 0, "> 0")?;
+        }
+        if let Some(max) = self.provenance {
+            check("provenance", max, max > 0, "> 0")?;
+        }
+        if let Some(robust) = &self.robust_fusion {
+            positive("robust_fusion.soft_threshold", robust.soft_threshold)?;
+            check("robust_fusion.max_inflation", robust.max_inflation,
+                robust.max_inflation.is_finite() && robust.max_inflation >= 1.0, "finite and >= 1")?;
+        }
+        if let Some(filter) = &self.filter {
+            non_negative("filter.position_noise_density", filter.position_noise_density)?;
+            non_negative("filter.velocity_noise_density", filter.velocity_noise_density)?;
+            positive("filter.initial_position_variance", filter.initial_position_variance)?;
+            positive("filter.initial_velocity_variance", filter.initial_velocity_variance)?;
+        }
+        if let Some(lifecycle) = &self.lifecycle {
+            for settings in std::iter::once(&lifecycle.default).chain(lifecycle.classes.values()) {
+                check("lifecycle.confirm_hits", settings.confirm_hits, settings.confirm_hits > 0, "> 0")?;
+                check("lifecycle.confirm_window", settings.confirm_window,
+                    settings.confirm_window >= settings.confirm_hits, ">= confirm_hits")?;
+                check("lifecycle.coast_cycles", settings.coast_cycles, settings.coast_cycles > 0, "> 0")?;
+            }
+        }
+        if let Some(gate) = &self.class_gate {
+            for &penalty in gate.penalties.values() {
+                non_negative("class_gate.penalty", penalty)?;
+            }
+        }
+        if let Some(age) = self.high_priority_max_age {
+            check("high_priority_max_age", age, age > 0, "> 0")?;
+        }
+        if let Some(ratio) = self.vertical_variance_ratio {
+            positive("vertical_variance_ratio", ratio)?;
+        }
+        if let Some(nonlinear) = &self.nonlinear_measurements {
+            positive("nonlinear_measurements.bearing_variance", nonlinear.bearing_variance)?;
+        }
+        if let Frame::LocalEnu { origin } = self.frame {
+            check("frame.origin", origin, Frame::local_enu(origin.lat(), origin.lng()).is_ok(), "a valid latitude/longitude")?;
+        }
+        Ok(())
+    }
+    
+    /// Track manager settings for an agent built from this config.
+    ///
+    /// An invalid `h3_resolution` falls back to resolution 11.
+    pub fn tracking_config(&self) -> TrackingConfig {
+        TrackingConfig {
+            h3_resolution: Resolution::try_from(self.h3_resolution).unwrap_or(Resolution::Eleven),
+            robust: self.robust_fusion,
+            motion: self.filter,
+            max_tracks: self.max_tracks,
+            frame: self.frame,
+            lifecycle: self.lifecycle.clone(),
+            class_gate: self.class_gate.clone(),
+            nonlinear: self.nonlinear_measurements,
+            high_priority_max_age: self.high_priority_max_age,
+            vertical_variance_ratio: self.vertical_variance_ratio,
+            provenance: self.provenance,
+            ..TrackingConfig::default()
+        }
+    }
 }
 
 impl Default for AgentConfig {
@@ -67,10 +202,179 @@ impl Default for AgentConfig {
             tick_rate_hz: 30,
             h3_resolution: 11,
             max_lag_depth: 20,
+            gossip_interval_ticks: 5,
+            record_genealogy: false,
+            gossip_decision_log: None,
+            robust_fusion: None,
+            filter: None,
+            max_tracks: None,
+            frame: Frame::Wgs84,
+            lifecycle: None,
+            class_gate: None,
+            nonlinear_measurements: None,
+            high_priority_max_age: None,
+            vertical_variance_ratio: None,
+            provenance: None,
         }
     }
 }
 
+/// An [`AgentConfig`] field outside its valid range.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("invalid AgentConfig: {field} = {value} (must be {expected})")]
+pub struct ConfigError {
+    /// Offending field, e.g. `filter.position_noise_density`
+    pub field: &'static str,
+    
+    /// Rejected value
+    pub value: String,
+    
+    /// Valid range
+    pub expected: &'static str,
+}
+
+fn check(field: &'static str, value: impl std::fmt::Display, valid: bool, expected: &'static str) -> Result<(), ConfigError> {
+    if valid {
+        return Ok(());
+    }
+    Err(ConfigError { field, value: value.to_string(), expected })
+}
+
+fn positive(field: &'static str, value: f64) -> Result<(), ConfigError> {
+    check(field, value, value.is_finite() && value > 0.0, "finite and > 0")
+}
+
+fn non_negative(field: &'static str, value: f64) -> Result<(), ConfigError> {
+    check(field, value, value.is_finite() && value >= 0.0, "finite and >= 0")
+}
+
+/// Builder for an [`AgentConfig`] that is validated on [`Self::build`].
+#[derive(Debug, Clone, Default)]
+pub struct AgentConfigBuilder {
+    config: AgentConfig,
+}
+
+impl AgentConfigBuilder {
+    /// Sets the agent's logical name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.config.name = name.into();
+        self
+    }
+    
+    /// Sets the tick rate in Hz.
+    pub fn tick_rate_hz(mut self, hz: u32) -> Self {
+        self.config.tick_rate_hz = hz;
+        self
+    }
+    
+    /// Sets the H3 resolution for spatial indexing.
+    pub fn h3_resolution(mut self, resolution: u8) -> Self {
+        self.config.h3_resolution = resolution;
+        self
+    }
+    
+    /// Sets the maximum OOSM lag depth in ticks.
+    pub fn max_lag_depth(mut self, ticks: usize) -> Self {
+        self.config.max_lag_depth = ticks;
+        self
+    }
+    
+    /// Sets the ticks between gossip rounds.
+    pub fn gossip_interval(mut self, ticks: u64) -> Self {
+        self.config.gossip_interval_ticks = ticks;
+        self
+    }
+    
+    /// Records track genealogy.
+    pub fn record_genealogy(mut self, enabled: bool) -> Self {
+        self.config.record_genealogy = enabled;
+        self
+    }
+    
+    /// Keeps the last `capacity` gossip acceptance decisions.
+    pub fn gossip_decision_log(mut self, capacity: usize) -> Self {
+        self.config.gossip_decision_log = Some(capacity);
+        self
+    }
+    
+    /// Credits up to `max_contributors` neighbors per track with the
+    /// information their gossip added.
+    pub fn provenance(mut self, max_contributors: usize) -> Self {
+        self.config.provenance = Some(max_contributors);
+        self
+    }
+    
+    /// Enables robust track fusion.
+    pub fn robust_fusion(mut self, robust: RobustFusion) -> Self {
+        self.config.robust_fusion = Some(robust);
+        self
+    }
+    
+    /// Enables track prediction with `filter`.
+    pub fn filter(mut self, filter: FilterTuning) -> Self {
+        self.config.filter = Some(filter);
+        self
+    }
+    
+    /// Sets the position and velocity process noise spectral densities,
+    /// enabling prediction with default tuning otherwise.
+    pub fn process_noise(mut self, position_density: f64, velocity_density: f64) -> Self {
+        let filter = self.config.filter.get_or_insert_with(FilterTuning::default);
+        filter.position_noise_density = position_density;
+        filter.velocity_noise_density = velocity_density;
+        self
+    }
+    
+    /// Caps the number of live tracks.
+    pub fn max_tracks(mut self, max: usize) -> Self {
+        self.config.max_tracks = Some(max);
+        self
+    }
+    
+    /// Sets the coordinate frame of observed positions.
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.config.frame = frame;
+        self
+    }
+    
+    /// Requires new tracks to be confirmed per `lifecycle`.
+    pub fn lifecycle(mut self, lifecycle: LifecycleConfig) -> Self {
+        self.config.lifecycle = Some(lifecycle);
+        self
+    }
+    
+    /// Lets the class pairs in `gate` associate.
+    pub fn class_gate(mut self, gate: ClassCompatibility) -> Self {
+        self.config.class_gate = Some(gate);
+        self
+    }
+    
+    /// Accepts bearing and range-Doppler measurements per `nonlinear`.
+    pub fn nonlinear_measurements(mut self, nonlinear: NonlinearMeasurements) -> Self {
+        self.config.nonlinear_measurements = Some(nonlinear);
+        self
+    }
+    
+    /// Deletes unobserved high-priority tracks after `cycles`.
+    pub fn high_priority_max_age(mut self, cycles: u32) -> Self {
+        self.config.high_priority_max_age = Some(cycles);
+        self
+    }
+    
+    /// Models readings' altitude variance as `ratio` times their horizontal
+    /// variance.
+    pub fn vertical_variance_ratio(mut self, ratio: f64) -> Self {
+        self.config.vertical_variance_ratio = Some(ratio);
+        self
+    }
+    
+    /// Validates and returns the config.
+    pub fn build(self) -> Result<AgentConfig, ConfigError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
 /// A GodView agent that orchestrates all engines.
 ///
 /// Generic over the context and network implementations,
@@ -121,36 +425,17 @@ where
         config: AgentConfig,
         root_public_key: biscuit_auth::PublicKey,
     ) -> Self {
-        use h3o::Resolution;
-        use crate::godview_tracking::TrackingConfig;
-        
         // Initialize Time Engine with default state
-        let initial_state = nalgebra::DVector::zeros(6); // [pos_x, pos_y, pos_z, vel_x, vel_y, vel_z]
-        let initial_cov = nalgebra::DMatrix::identity(6, 6) * 100.0;
-        let process_noise = nalgebra::DMatrix::identity(6, 6) * 0.01;
-        let measurement_noise = nalgebra::DMatrix::identity(3, 3) * 0.1;
-        
-        let time_engine = AugmentedStateFilter::new(
-            initial_state,
-            initial_cov,
-            process_noise,
-            measurement_noise,
-            config.max_lag_depth,
-        );
+        let time_engine = Self::initial_time_engine(&config);
         
         // Initialize Space Engine with H3 resolution
-        let resolution = Resolution::try_from(config.h3_resolution)
-            .unwrap_or(Resolution::Eleven);
-        let space_engine = SpatialEngine::new(resolution);
+        let tracking_config = config.tracking_config();
+        let space_engine = SpatialEngine::new(tracking_config.h3_resolution);
         
         // Initialize Trust Engine with root key
         let trust_engine = SecurityContext::new(root_public_key);
         
         // Initialize Track Manager with config
-        let tracking_config = TrackingConfig {
-            h3_resolution: resolution,
-            ..TrackingConfig::default()
-        };
         let track_manager = TrackManager::new(tracking_config);
         
         Self {
@@ -166,6 +451,32 @@ where
         }
     }
     
+    /// Builds the Time Engine in its default state for `config`.
+    fn initial_time_engine(config: &AgentConfig) -> AugmentedStateFilter {
+        let initial_state = nalgebra::DVector::zeros(6); // [pos_x, pos_y, pos_z, vel_x, vel_y, vel_z]
+        let initial_cov = nalgebra::DMatrix::identity(6, 6) * 100.0;
+        let process_noise = nalgebra::DMatrix::identity(6, 6) * 0.01;
+        let measurement_noise = nalgebra::DMatrix::identity(3, 3) * 0.1;
+        
+        AugmentedStateFilter::new(
+            initial_state,
+            initial_cov,
+            process_noise,
+            measurement_noise,
+            config.max_lag_depth,
+        )
+    }
+    
+    /// Forgets everything learned about the world: the Time, Space and
+    /// Tracking engines start over. Identity, trust, configuration and the
+    /// tick count are kept.
+    pub fn reset_world(&mut self) {
+        let tracking_config = self.config.tracking_config();
+        self.time_engine = Self::initial_time_engine(&self.config);
+        self.space_engine = SpatialEngine::new(tracking_config.h3_resolution);
+        self.track_manager = TrackManager::new(tracking_config);
+    }
+    
     /// Returns the current simulation time from the context.
     pub fn now_secs(&self) -> f64 {
         self.context.now().as_secs_f64()
@@ -185,6 +496,9 @@ where
         let current_time = self.now_secs();
         self.time_engine.predict(dt, current_time);
         
+        // Predict tracks forward (no-op unless filter tuning is configured)
+        self.track_manager.predict_tracks(dt);
+        
         // Age tracks
         self.track_manager.age_tracks();
         
@@ -221,5 +535,40 @@ mod tests {
         assert_eq!(config.tick_rate_hz, 30);
         assert_eq!(config.h3_resolution, 11);
         assert_eq!(config.max_lag_depth, 20);
+        assert_eq!(config.validate(), Ok(()));
+    }
+    
+    #[test]
+    fn test_builder_names_invalid_field() {
+        let config = AgentConfig::builder()
+            .gossip_interval(10)
+            .process_noise(0.5, 2.0)
+            .build()
+            .unwrap();
+        assert_eq!(config.gossip_interval_ticks, 10);
+        assert_eq!(config.filter.unwrap().position_noise_density, 0.5);
+        
+        let err = AgentConfig::builder().gossip_interval(0).build().unwrap_err();
+        assert_eq!(err.field, "gossip_interval_ticks");
+        assert_eq!(err.to_string(), "invalid AgentConfig: gossip_interval_ticks = 0 (must be > 0)");
+        
+        let field = |builder: AgentConfigBuilder| builder.build().unwrap_err().field;
+        assert_eq!(field(AgentConfig::builder().tick_rate_hz(0)), "tick_rate_hz");
+        assert_eq!(field(AgentConfig::builder().h3_resolution(16)), "h3_resolution");
+        assert_eq!(field(AgentConfig::builder().process_noise(-0.1, 1.0)), "filter.position_noise_density");
+        assert_eq!(field(AgentConfig::builder().process_noise(0.1, f64::NAN)), "filter.velocity_noise_density");
+        assert_eq!(field(AgentConfig::builder().max_tracks(0)), "max_tracks");
+        assert_eq!(field(AgentConfig::builder().gossip_decision_log(0)), "gossip_decision_log");
+        assert_eq!(field(AgentConfig::builder().provenance(0)), "provenance");
+        let robust = RobustFusion { max_inflation: 0.5, ..RobustFusion::default() };
+        assert_eq!(field(AgentConfig::builder().robust_fusion(robust)), "robust_fusion.max_inflation");
+        let lifecycle = LifecycleConfig::default()
+            .with_class(2, crate::godview_tracking::TrackLifecycle { confirm_window: 2, ..Default::default() });
+        assert_eq!(field(AgentConfig::builder().lifecycle(lifecycle)), "lifecycle.confirm_window");
+        assert_eq!(field(AgentConfig::builder().class_gate(ClassCompatibility::new().allow(2, 3, -1.0))), "class_gate.penalty");
+        let nonlinear = NonlinearMeasurements { bearing_variance: 0.0 };
+        assert_eq!(field(AgentConfig::builder().nonlinear_measurements(nonlinear)), "nonlinear_measurements.bearing_variance");
+        assert_eq!(field(AgentConfig::builder().high_priority_max_age(0)), "high_priority_max_age");
+        assert_eq!(field(AgentConfig::builder().vertical_variance_ratio(0.0)), "vertical_variance_ratio");
     }
 }
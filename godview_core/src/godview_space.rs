@@ -150,7 +150,7 @@ impl WorldShard {
         // Add to spatial index
         self.spatial_grid
             .entry(grid_cell)
-            .or_insert_with(HashSet::new)
+            .or_default()
             .insert(element_id);
         
         // Store entity data
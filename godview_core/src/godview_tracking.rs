@@ -10,11 +10,15 @@
 //! 2. Geometric Gating (Mahalanobis Distance)
 //! 3. Identity Resolution (Highlander)
 //! 4. State Fusion (Covariance Intersection)
+//!
+//! Batches of simultaneous measurements are gated together and assigned
+//! one-to-one before any fusion, so the outcome doesn't depend on the order
+//! gossip happened to deliver them in.
 
 use h3o::{CellIndex, LatLng, Resolution};
-use nalgebra::{Matrix3, Matrix6, Vector3, Vector6};
+use nalgebra::{DMatrix, DVector, Matrix3, Matrix6, Vector3, Vector6};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use uuid::Uuid;
 use crate::godview_trust::AdaptiveState;
 
@@ -34,11 +38,448 @@ pub struct TrackingConfig {
     /// Maximum age in cycles before track deletion (default: 60 = 2s at 30Hz)
     pub max_age: u32,
     
+    /// Maximum age for tracks known only by quarantined IDs (default: 15 = 0.5s at 30Hz)
+    pub quarantine_max_age: u32,
+    
+    /// Maximum age for tracks at or above [`GlobalHazardPacket::HIGH_PRIORITY`],
+    /// so a lost threat is dropped sooner (default: None = `max_age`)
+    pub high_priority_max_age: Option<u32>,
+    
     /// Base position variance for confidence conversion (default: 25.0 m²)
     pub base_pos_variance: f64,
     
     /// Base velocity variance for confidence conversion (default: 4.0 m²/s²)
     pub base_vel_variance: f64,
+    
+    /// Vertical (z) position variance as a multiple of the horizontal in
+    /// confidence conversion, for sensors like GNSS whose altitude is worse
+    /// than their horizontal fix (default: None = isotropic)
+    pub vertical_variance_ratio: Option<f64>,
+    
+    /// Robust fusion of far-off measurements (default: None = full-weight fusion)
+    pub robust: Option<RobustFusion>,
+    
+    /// Track prediction between updates (default: None = tracks hold their
+    /// last fused state)
+    pub motion: Option<FilterTuning>,
+    
+    /// Maximum live tracks; at capacity the least useful track is evicted
+    /// before a new one is created (default: None = unbounded)
+    pub max_tracks: Option<usize>,
+    
+    /// How `process_packets` associates a batch (default: Batch)
+    pub association: AssociationMode,
+    
+    /// Coordinate frame of packet positions (default: Wgs84)
+    pub frame: Frame,
+    
+    /// M-of-N confirmation and coasting, per class (default: None = tracks
+    /// are confirmed on creation and kept until `max_age`)
+    pub lifecycle: Option<LifecycleConfig>,
+    
+    /// How far from a packet's H3 cell association looks for tracks
+    /// (default: Adaptive up to k=3)
+    pub cell_search: CellSearch,
+    
+    /// Most neighbors each track keeps [`UniqueTrack::provenance`] for
+    /// (default: None = no provenance, which costs a map per track)
+    pub provenance: Option<usize>,
+    
+    /// Give each track a [`UniqueTrack::local_alias`] that survives
+    /// Highlander renames (default: false)
+    pub local_aliases: bool,
+    
+    /// Classes allowed to associate with each other, at a cost (default:
+    /// None = a packet only associates with tracks of its exact class)
+    pub class_gate: Option<ClassCompatibility>,
+    
+    /// Evidence a track needs before absorbing another entity ID (default:
+    /// None = the first association absorbs it)
+    pub merge_confirmation: Option<MergeConfirmation>,
+    
+    /// Accept bearing and range-Doppler measurements (default: None =
+    /// packets with those [`MeasurementModel`]s are rejected)
+    pub nonlinear: Option<NonlinearMeasurements>,
+}
+
+/// Coordinate frame of [`GlobalHazardPacket::position`] and track states.
+///
+/// H3 indexes WGS84 only, so positions in a local frame are mapped onto the
+/// globe before they are assigned a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Frame {
+    /// [latitude°, longitude°, altitude m], with velocities [north, east,
+    /// up] in m/s
+    #[default]
+    Wgs84,
+    
+    /// [east, north, up] in meters from `origin`, on the tangent plane there
+    LocalEnu { origin: LatLng },
+}
+
+/// WGS84 semi-major axis (m)
+const WGS84_A: f64 = 6_378_137.0;
+
+/// WGS84 first eccentricity squared
+const WGS84_E2: f64 = 6.694_379_990_14e-3;
+
+impl Frame {
+    /// Local ENU frame around `lat`, `lon` (degrees).
+    pub fn local_enu(lat: f64, lon: f64) -> Result<Self, TrackingError> {
+        let origin = Frame::Wgs84.to_latlng(lat, lon)?;
+        Ok(Frame::LocalEnu { origin })
+    }
+    
+    /// Degrees of latitude per meter north and of longitude per meter east
+    /// at latitude `lat` (degrees), from the ellipsoid's radii of curvature.
+    fn degrees_per_meter(lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let w2 = 1.0 - WGS84_E2 * phi.sin().powi(2);
+        let meridian = WGS84_A * (1.0 - WGS84_E2) / w2.powf(1.5);
+        let prime_vertical = WGS84_A / w2.sqrt();
+        // Longitude degenerates at the poles; keep it finite there
+        let parallel = prime_vertical * phi.cos().max(f64::EPSILON);
+        ((1.0 / meridian).to_degrees(), (1.0 / parallel).to_degrees())
+    }
+    
+    /// WGS84 location of the horizontal position (`x`, `y`) in this frame.
+    ///
+    /// Latitudes outside [-90°, 90°] and longitudes outside [-180°, 180°]
+    /// are rejected rather than wrapped to somewhere else on the globe.
+    /// Local frames wrap longitude across the antimeridian.
+    pub fn to_latlng(&self, x: f64, y: f64) -> Result<LatLng, TrackingError> {
+        let (lat, lon) = match self {
+            Frame::Wgs84 => (x, y),
+            Frame::LocalEnu { origin } => {
+                // Meridian and prime-vertical radii of curvature at the origin
+                let phi = origin.lat_radians();
+                let w2 = 1.0 - WGS84_E2 * phi.sin().powi(2);
+                let meridian = WGS84_A * (1.0 - WGS84_E2) / w2.powf(1.5);
+                let prime_vertical = WGS84_A / w2.sqrt();
+                let lat = origin.lat() + (y / meridian).to_degrees();
+                let lon = origin.lng() + (x / (prime_vertical * phi.cos())).to_degrees();
+                (lat, (lon + 180.0).rem_euclid(360.0) - 180.0)
+            }
+        };
+        if !lat.is_finite() || !lon.is_finite() {
+            return Err(TrackingError::InvalidCoordinates(format!("({}, {}) is not finite", lat, lon)));
+        }
+        if !(-90.0..=90.0).contains(&lat) {
+            return Err(TrackingError::LatitudeOutOfRange(lat));
+        }
+        if !(-180.0..=180.0).contains(&lon) {
+            return Err(TrackingError::LongitudeOutOfRange(lon));
+        }
+        LatLng::new(lat, lon).map_err(|e| TrackingError::InvalidCoordinates(format!("{:?}", e)))
+    }
+}
+
+/// Association strategy for [`TrackManager::process_packets`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AssociationMode {
+    /// Gate the whole batch first, assign packets to tracks one-to-one, then
+    /// fuse in entity_id order; the result is independent of packet order
+    #[default]
+    Batch,
+    
+    /// Run each packet through `process_packet` in arrival order
+    Sequential,
+}
+
+/// Which H3 k-ring [`TrackManager::find_association`] draws candidate tracks
+/// from.
+///
+/// A track stays indexed under the cell of its last fused (or predicted)
+/// position, so an object moving more than a cell or so between updates can
+/// land outside the k=1 ring around it and spawn a duplicate track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellSearch {
+    /// Always the ring of radius `k`
+    Fixed(u32),
+    
+    /// The k=1 ring, widened to at most `max_k` when nothing there gates: a
+    /// track `d` rings away is a candidate if it or the packet is fast
+    /// enough to have covered it since the track's last update,
+    /// `d <= 1 + ceil(speed * dt / edge_length)`
+    Adaptive { max_k: u32 },
+}
+
+impl Default for CellSearch {
+    fn default() -> Self {
+        CellSearch::Adaptive { max_k: 3 }
+    }
+}
+
+/// Kinematic model used to predict tracks between updates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MotionModel {
+    /// Constant velocity with independent random-walk noise on position
+    /// and velocity
+    #[default]
+    ConstantVelocity,
+    
+    /// Constant velocity driven by continuous white-noise acceleration, so
+    /// position and velocity noise are correlated
+    WhiteNoiseAcceleration,
+}
+
+/// Process noise and initial covariance for track prediction.
+///
+/// Noise densities are per second, in the units of the track state: the
+/// covariance grows by `density * dt` (ConstantVelocity) or by the
+/// integrated white-noise-acceleration terms (WhiteNoiseAcceleration).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FilterTuning {
+    /// Motion model used for the prediction step
+    pub motion_model: MotionModel,
+    
+    /// Position process noise spectral density (ConstantVelocity only)
+    pub position_noise_density: f64,
+    
+    /// Velocity process noise spectral density (the acceleration PSD for
+    /// WhiteNoiseAcceleration)
+    pub velocity_noise_density: f64,
+    
+    /// Position variance of newly created tracks
+    pub initial_position_variance: f64,
+    
+    /// Velocity variance of newly created tracks
+    pub initial_velocity_variance: f64,
+}
+
+impl Default for FilterTuning {
+    fn default() -> Self {
+        Self {
+            motion_model: MotionModel::ConstantVelocity,
+            position_noise_density: 0.1,
+            velocity_noise_density: 1.0,
+            initial_position_variance: 25.0,
+            initial_velocity_variance: 4.0,
+        }
+    }
+}
+
+impl FilterTuning {
+    /// State transition matrix for a `dt`-second prediction.
+    pub fn transition(dt: f64) -> Matrix6<f64> {
+        let mut f = Matrix6::identity();
+        for i in 0..3 {
+            f[(i, i + 3)] = dt;
+        }
+        f
+    }
+    
+    /// Process noise covariance Q for a `dt`-second prediction.
+    pub fn process_noise(&self, dt: f64) -> Matrix6<f64> {
+        let mut q = Matrix6::zeros();
+        match self.motion_model {
+            MotionModel::ConstantVelocity => {
+                for i in 0..3 {
+                    q[(i, i)] = self.position_noise_density * dt;
+                    q[(i + 3, i + 3)] = self.velocity_noise_density * dt;
+                }
+            }
+            MotionModel::WhiteNoiseAcceleration => {
+                let qa = self.velocity_noise_density;
+                for i in 0..3 {
+                    q[(i, i)] = qa * dt.powi(3) / 3.0;
+                    q[(i, i + 3)] = qa * dt.powi(2) / 2.0;
+                    q[(i + 3, i)] = qa * dt.powi(2) / 2.0;
+                    q[(i + 3, i + 3)] = qa * dt;
+                }
+            }
+        }
+        q
+    }
+    
+    /// Covariance of a newly created track.
+    pub fn initial_covariance(&self) -> Matrix6<f64> {
+        let p = self.initial_position_variance;
+        let v = self.initial_velocity_variance;
+        Matrix6::from_diagonal(&Vector6::new(p, p, p, v, v, v))
+    }
+}
+
+/// Huber-style down-weighting for measurements that pass the gate but sit
+/// far from the track.
+///
+/// Above `soft_threshold` (Mahalanobis distance, not squared) the
+/// measurement covariance is inflated by `d / soft_threshold`, capped at
+/// `max_inflation`, before Covariance Intersection. This is the Huber
+/// weight `w = k / d` applied as `R / w`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RobustFusion {
+    /// Mahalanobis distance beyond which measurements are down-weighted
+    pub soft_threshold: f64,
+    
+    /// Upper bound on the covariance inflation factor
+    pub max_inflation: f64,
+}
+
+impl Default for RobustFusion {
+    fn default() -> Self {
+        Self {
+            soft_threshold: 1.5,
+            max_inflation: 25.0,
+        }
+    }
+}
+
+impl RobustFusion {
+    /// Covariance inflation factor for a measurement at squared distance `d_squared`.
+    ///
+    /// Always in `[1.0, max_inflation]`; exactly 1.0 for inliers.
+    pub fn inflation(&self, d_squared: f64) -> f64 {
+        let d = d_squared.max(0.0).sqrt();
+        if d <= self.soft_threshold || self.soft_threshold <= 0.0 {
+            return 1.0;
+        }
+        (d / self.soft_threshold).clamp(1.0, self.max_inflation.max(1.0))
+    }
+    
+    /// Huber weight (reciprocal of the inflation) in `(0.0, 1.0]`.
+    pub fn weight(&self, d_squared: f64) -> f64 {
+        1.0 / self.inflation(d_squared)
+    }
+}
+
+/// Settings for the angular [`MeasurementModel`]s, `BearingOnly` and
+/// `RangeDoppler`, which are linearized about the track they update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NonlinearMeasurements {
+    /// Bearing variance (rad²) at zero confidence, scaled down with
+    /// confidence like `base_pos_variance`
+    pub bearing_variance: f64,
+}
+
+impl Default for NonlinearMeasurements {
+    fn default() -> Self {
+        Self {
+            // ~2.6° at zero confidence, ~0.6° at 0.95
+            bearing_variance: 2e-3,
+        }
+    }
+}
+
+/// Confirmation a track needs before it absorbs an external entity ID.
+///
+/// Without it any packet that passes the gate donates its ID, and since
+/// `observed_ids` never shrinks, one gate pass during a near miss couples
+/// two identities for good. With it the ID is a pending candidate on the
+/// track ([`UniqueTrack::merge_candidates`]) until it has associated
+/// `hits` times within `window_secs` of the first, each time with a
+/// velocity that agrees with the track's. An association whose velocity
+/// disagrees is a near miss and drops the candidate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MergeConfirmation {
+    /// Associations needed to absorb an ID (K)
+    pub hits: u32,
+    
+    /// Packet time (s) from a candidate's first association within which
+    /// those hits must land
+    pub window_secs: f64,
+    
+    /// Lowest cosine of the angle between packet and track velocity
+    pub min_velocity_cosine: f64,
+    
+    /// Lowest ratio of the smaller speed to the larger
+    pub min_speed_ratio: f64,
+    
+    /// Speed (m/s) below which both velocities count as stationary and
+    /// agree whatever their direction
+    pub stationary_speed: f64,
+}
+
+impl Default for MergeConfirmation {
+    fn default() -> Self {
+        Self {
+            hits: 3,
+            window_secs: 1.0,
+            min_velocity_cosine: 0.9, // within ~25°
+            min_speed_ratio: 0.5,
+            stationary_speed: 0.5,
+        }
+    }
+}
+
+impl MergeConfirmation {
+    /// Whether a packet moving at `packet` agrees with a track moving at
+    /// `track`.
+    pub fn velocities_agree(&self, track: &Vector3<f64>, packet: &Vector3<f64>) -> bool {
+        let (slow, fast) = (track.norm().min(packet.norm()), track.norm().max(packet.norm()));
+        if fast < self.stationary_speed {
+            return true;
+        }
+        slow / fast >= self.min_speed_ratio
+            && track.dot(packet) / (track.norm() * packet.norm()) >= self.min_velocity_cosine
+    }
+}
+
+/// An external ID a track has associated with but not yet absorbed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MergeCandidate {
+    /// Agreeing associations so far
+    pub hits: u32,
+    
+    /// Packet timestamp of the first of them
+    pub first_seen: f64,
+}
+
+/// M-of-N confirmation and coasting for tracks of one class.
+///
+/// A new track is [`TrackState::Tentative`] until it has been associated in
+/// `confirm_hits` separate cycles; if that hasn't happened `confirm_window`
+/// cycles after creation it is deleted. A confirmed track that goes a whole
+/// cycle without an update is [`TrackState::Coasting`] until an update
+/// confirms it again or `coast_cycles` cycles without one delete it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackLifecycle {
+    /// Cycles with an association needed to confirm a track (M)
+    pub confirm_hits: u32,
+    
+    /// Cycles after creation within which those hits must land (N)
+    pub confirm_window: u32,
+    
+    /// Cycles without an update before a coasting track is deleted
+    pub coast_cycles: u32,
+}
+
+impl Default for TrackLifecycle {
+    fn default() -> Self {
+        Self {
+            confirm_hits: 3,   // 3 of 5 cycles
+            confirm_window: 5,
+            coast_cycles: 30,  // 1 second at 30 Hz
+        }
+    }
+}
+
+/// [`TrackLifecycle`] settings per object class.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LifecycleConfig {
+    /// Settings for classes without their own entry
+    pub default: TrackLifecycle,
+    
+    /// Overrides keyed by `class_id`
+    pub classes: BTreeMap<u8, TrackLifecycle>,
+}
+
+impl LifecycleConfig {
+    /// The same settings for every class.
+    pub fn uniform(lifecycle: TrackLifecycle) -> Self {
+        Self { default: lifecycle, classes: BTreeMap::new() }
+    }
+    
+    /// Overrides the settings for `class_id`.
+    pub fn with_class(mut self, class_id: u8, lifecycle: TrackLifecycle) -> Self {
+        self.classes.insert(class_id, lifecycle);
+        self
+    }
+    
+    /// Settings for tracks of `class_id`.
+    pub fn for_class(&self, class_id: u8) -> &TrackLifecycle {
+        self.classes.get(&class_id).unwrap_or(&self.default)
+    }
 }
 
 impl Default for TrackingConfig {
@@ -47,9 +488,87 @@ impl Default for TrackingConfig {
             h3_resolution: Resolution::Ten,
             gating_threshold: 12.59, // Chi² for 6 DOF at 95%
             max_age: 60,             // 2 seconds at 30 Hz
+            quarantine_max_age: 15,  // 0.5 seconds at 30 Hz
+            high_priority_max_age: None,
             base_pos_variance: 25.0, // 5m standard deviation
             base_vel_variance: 4.0,  // 2 m/s standard deviation
+            vertical_variance_ratio: None,
+            robust: None,
+            motion: None,
+            max_tracks: None,
+            association: AssociationMode::Batch,
+            frame: Frame::Wgs84,
+            lifecycle: None,
+            cell_search: CellSearch::default(),
+            provenance: None,
+            local_aliases: false,
+            class_gate: None,
+            merge_confirmation: None,
+            nonlinear: None,
+        }
+    }
+}
+
+impl TrackingConfig {
+    /// Checks the thresholds and limits a [`TrackManager`] relies on.
+    pub fn validate(&self) -> Result<(), TrackingError> {
+        let positive = |field: &str, value: f64| {
+            if value.is_finite() && value > 0.0 {
+                Ok(())
+            } else {
+                Err(TrackingError::InvalidConfig(format!("{field} must be finite and positive, got {value}")))
+            }
+        };
+        positive("gating_threshold", self.gating_threshold)?;
+        positive("base_pos_variance", self.base_pos_variance)?;
+        positive("base_vel_variance", self.base_vel_variance)?;
+        if let Some(ratio) = self.vertical_variance_ratio {
+            positive("vertical_variance_ratio", ratio)?;
+        }
+        if self.max_age == 0 {
+            return Err(TrackingError::InvalidConfig("max_age must be at least 1".to_string()));
+        }
+        if self.max_tracks == Some(0) {
+            return Err(TrackingError::InvalidConfig("max_tracks must be at least 1".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Pairs of classes gating may associate despite the mismatch, for
+/// perception that confuses similar objects (a cyclist seen as a
+/// pedestrian).
+///
+/// Each pair carries a penalty added to the Mahalanobis distance², so a
+/// mismatched packet needs to sit that much closer to the track to pass the
+/// gate and loses to a same-class track at equal distance. Tracks gated this
+/// way take the class most of their packets reported
+/// ([`UniqueTrack::class_votes`]).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClassCompatibility {
+    /// Penalty per compatible pair, keyed (smaller class, larger class)
+    pub penalties: BTreeMap<(u8, u8), f64>,
+}
+
+impl ClassCompatibility {
+    /// Only exact class matches associate.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Lets classes `a` and `b` associate, either way round, at `penalty`.
+    pub fn allow(mut self, a: u8, b: u8, penalty: f64) -> Self {
+        self.penalties.insert((a.min(b), a.max(b)), penalty);
+        self
+    }
+    
+    /// Penalty for associating `a` with `b`: 0 for the same class, None
+    /// if they may not associate.
+    pub fn penalty(&self, a: u8, b: u8) -> Option<f64> {
+        if a == b {
+            return Some(0.0);
         }
+        self.penalties.get(&(a.min(b), a.max(b))).copied()
     }
 }
 
@@ -63,10 +582,12 @@ pub struct GlobalHazardPacket {
     /// Publisher's local UUID for this object
     pub entity_id: Uuid,
     
-    /// Position in WGS84 [latitude, longitude, altitude]
+    /// Position in the receiver's [`Frame`]: WGS84 [latitude, longitude,
+    /// altitude] unless configured otherwise
     pub position: [f64; 3],
     
-    /// Velocity vector [vx, vy, vz] in m/s
+    /// Velocity vector in m/s along the [`Frame`]'s axes: [north, east, up]
+    /// in WGS84, [east, north, up] in a local ENU frame
     pub velocity: [f64; 3],
     
     /// Object class: 0=Unknown, 1=Vehicle, 2=Pedestrian, 3=Cyclist, 4=Drone, etc.
@@ -77,6 +598,181 @@ pub struct GlobalHazardPacket {
     
     /// Confidence score [0.0 - 1.0]
     pub confidence_score: f64,
+    
+    /// What `position` and `velocity` measure (default: the full state,
+    /// left off the wire)
+    #[serde(default, skip_serializing_if = "MeasurementModel::is_full_state")]
+    pub measurement_model: MeasurementModel,
+    
+    /// Threat level of the object, 0 (routine) to 255; at or above
+    /// [`GlobalHazardPacket::HIGH_PRIORITY`] it is gossiped first and aged
+    /// out on [`TrackingConfig::high_priority_max_age`] (default 0, left
+    /// off the wire)
+    #[serde(default, skip_serializing_if = "is_routine_priority")]
+    pub priority: u8,
+}
+
+pub(crate) fn is_routine_priority(priority: &u8) -> bool {
+    *priority == 0
+}
+
+impl GlobalHazardPacket {
+    /// Lowest priority treated as high priority.
+    pub const HIGH_PRIORITY: u8 = 128;
+    
+    /// Whether the packet's priority is at least [`Self::HIGH_PRIORITY`].
+    pub fn is_high_priority(&self) -> bool {
+        self.priority >= Self::HIGH_PRIORITY
+    }
+}
+
+/// What a [`GlobalHazardPacket`] measures of an object's state.
+///
+/// Reduced models carry their measurement in the packet's `position` and
+/// `velocity` fields as each variant lists; the other fields are ignored.
+/// Gating and fusion run in the measurement space through the model's
+/// observation matrix H, so a position-only stream still settles a track's
+/// velocity through the motion model ([`TrackingConfig::motion`]). Reduced
+/// measurements fuse by Kalman update ([`TrackManager::kalman_update`]),
+/// not Covariance Intersection, so they should come from the agent's own
+/// sensors; gossip the fused track instead.
+///
+/// The angular models are linearized about the track they update, need
+/// [`TrackingConfig::nonlinear`] and assume a metric frame
+/// ([`Frame::LocalEnu`]). They can't place an object on their own, so they
+/// only update the track their entity ID names and never start one.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum MeasurementModel {
+    /// Position and velocity: the whole state (H = I)
+    #[default]
+    PositionVelocity6D,
+    
+    /// Position only
+    Position3D,
+    
+    /// Direction from a sensor at `origin`: azimuth (rad, counterclockwise
+    /// from +x) in `position[0]` and elevation (rad) in `position[1]`
+    BearingOnly { origin: [f64; 3] },
+    
+    /// Distance (m) in `position[0]` and range rate (m/s, positive when
+    /// opening) in `velocity[0]` from a sensor at `origin`
+    RangeDoppler { origin: [f64; 3] },
+}
+
+impl MeasurementModel {
+    /// Number of measured values.
+    pub fn dimension(&self) -> usize {
+        match self {
+            Self::PositionVelocity6D => 6,
+            Self::Position3D => 3,
+            Self::BearingOnly { .. } | Self::RangeDoppler { .. } => 2,
+        }
+    }
+    
+    /// True for the full-state model.
+    pub fn is_full_state(&self) -> bool {
+        matches!(self, Self::PositionVelocity6D)
+    }
+    
+    /// True if the measurement locates the object, so it can be spatially
+    /// indexed and start a track.
+    pub fn is_positional(&self) -> bool {
+        matches!(self, Self::PositionVelocity6D | Self::Position3D)
+    }
+    
+    /// True if the measurement includes the velocity vector.
+    pub fn observes_velocity(&self) -> bool {
+        self.is_full_state()
+    }
+    
+    /// The measurement vector z `packet` carries.
+    pub fn measurement(&self, packet: &GlobalHazardPacket) -> DVector<f64> {
+        let [x, y, z] = packet.position;
+        let [vx, vy, vz] = packet.velocity;
+        match self {
+            Self::PositionVelocity6D => DVector::from_column_slice(&[x, y, z, vx, vy, vz]),
+            Self::Position3D => DVector::from_column_slice(&[x, y, z]),
+            Self::BearingOnly { .. } => DVector::from_column_slice(&[x, y]),
+            Self::RangeDoppler { .. } => DVector::from_column_slice(&[x, vx]),
+        }
+    }
+    
+    /// The measurement expected of `state`, h(x), and its Jacobian H (one
+    /// row per measured value, one column per state component).
+    ///
+    /// None where an angular model is undefined: an object at the sensor,
+    /// or for bearings straight above or below it.
+    pub fn observe(&self, state: &Vector6<f64>) -> Option<(DVector<f64>, DMatrix<f64>)> {
+        let offset = |origin: &[f64; 3]| Vector3::new(state[0] - origin[0], state[1] - origin[1], state[2] - origin[2]);
+        match self {
+            Self::PositionVelocity6D => Some((DVector::from_column_slice(state.as_slice()), DMatrix::identity(6, 6))),
+            Self::Position3D => Some((DVector::from_column_slice(&state.as_slice()[..3]), DMatrix::identity(3, 6))),
+            Self::BearingOnly { origin } => {
+                let d = offset(origin);
+                let horizontal_sq = d.x * d.x + d.y * d.y;
+                if horizontal_sq < MIN_OBSERVABLE_OFFSET_SQ {
+                    return None;
+                }
+                let horizontal = horizontal_sq.sqrt();
+                let range_sq = horizontal_sq + d.z * d.z;
+                let expected = DVector::from_column_slice(&[d.y.atan2(d.x), d.z.atan2(horizontal)]);
+                let mut h = DMatrix::zeros(2, 6);
+                h[(0, 0)] = -d.y / horizontal_sq;
+                h[(0, 1)] = d.x / horizontal_sq;
+                h[(1, 0)] = -d.x * d.z / (range_sq * horizontal);
+                h[(1, 1)] = -d.y * d.z / (range_sq * horizontal);
+                h[(1, 2)] = horizontal / range_sq;
+                Some((expected, h))
+            }
+            Self::RangeDoppler { origin } => {
+                let d = offset(origin);
+                let range = d.norm();
+                if range * range < MIN_OBSERVABLE_OFFSET_SQ {
+                    return None;
+                }
+                let unit = d / range;
+                let velocity = Vector3::new(state[3], state[4], state[5]);
+                let rate = unit.dot(&velocity);
+                let rate_by_position = (velocity - unit * rate) / range;
+                let expected = DVector::from_column_slice(&[range, rate]);
+                let mut h = DMatrix::zeros(2, 6);
+                for axis in 0..3 {
+                    h[(0, axis)] = unit[axis];
+                    h[(1, axis)] = rate_by_position[axis];
+                    h[(1, axis + 3)] = unit[axis];
+                }
+                Some((expected, h))
+            }
+        }
+    }
+    
+    /// Innovation z - h(x), with a bearing's azimuth wrapped into [-π, π).
+    pub fn residual(&self, measured: &DVector<f64>, expected: &DVector<f64>) -> DVector<f64> {
+        let mut residual = measured - expected;
+        if let Self::BearingOnly { .. } = self {
+            residual[0] = (residual[0] + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU) - std::f64::consts::PI;
+        }
+        residual
+    }
+}
+
+/// Squared distance (m²) from a sensor inside which the angular
+/// [`MeasurementModel`]s have no usable Jacobian.
+const MIN_OBSERVABLE_OFFSET_SQ: f64 = 1e-6;
+
+/// A packet's innovation against a track, in its measurement space.
+struct Innovation {
+    /// z - h(x)
+    residual: DVector<f64>,
+    
+    /// Innovation covariance HPHᵀ + R
+    covariance: DMatrix<f64>,
+    
+    /// Observation matrix (Jacobian of h at the track state)
+    h: DMatrix<f64>,
+    
+    /// Measurement noise covariance
+    noise: DMatrix<f64>,
 }
 
 // ============================================================================
@@ -109,6 +805,20 @@ pub struct MergeEvent {
 // UNIQUE TRACK (Internal State)
 // ============================================================================
 
+/// Where a track is in its [`TrackLifecycle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TrackState {
+    /// Seen in too few cycles to be trusted: kept, but not reported or shared
+    Tentative,
+    
+    /// Associated often enough to be reported and shared
+    #[default]
+    Confirmed,
+    
+    /// Confirmed but missing updates, and held until `coast_cycles` run out
+    Coasting,
+}
+
 /// Internal representation of a fused object in the local world model.
 /// 
 /// Each UniqueTrack represents one physical object, potentially observed
@@ -126,7 +836,7 @@ pub struct UniqueTrack {
     
     // === State (6-DOF: Position + Velocity) ===
     
-    /// Fused state vector [x, y, z, vx, vy, vz] in ECEF meters
+    /// Fused state vector [x, y, z, vx, vy, vz] in the configured [`Frame`]
     pub state: Vector6<f64>,
     
     /// 6×6 uncertainty covariance matrix
@@ -143,10 +853,56 @@ pub struct UniqueTrack {
     /// Cycles since last update (for aging/deletion)
     pub age: u32,
     
+    // === Lifecycle ===
+    
+    /// Confirmation state (always Confirmed without `TrackingConfig::lifecycle`)
+    #[serde(default)]
+    pub track_state: TrackState,
+    
+    /// Cycles with at least one association, counting creation
+    #[serde(default)]
+    pub hits: u32,
+    
+    /// Cycles since creation
+    #[serde(default)]
+    pub lifetime: u32,
+    
     // === Spatial Index Key ===
     
     /// Current H3 cell for spatial indexing
     pub h3_cell: CellIndex,
+    
+    // === Provenance ===
+    
+    /// Information each neighbor's packets added to this track, as the
+    /// covariance trace their fusions removed, by neighbor index. Only kept
+    /// with [`TrackingConfig::provenance`]; at that many neighbors the
+    /// smallest contributor makes room for a new one
+    #[serde(default)]
+    pub provenance: BTreeMap<usize, f64>,
+    
+    /// Handle this manager assigned the track at creation, in creation
+    /// order. Unlike `canonical_id` it never changes, so consumers keying
+    /// on it aren't disturbed by merges. Only kept with
+    /// [`TrackingConfig::local_aliases`]; local to one manager
+    #[serde(default)]
+    pub local_alias: Option<u64>,
+    
+    // === Classification ===
+    
+    /// Packets fused into this track by the class they reported. Only kept
+    /// with [`TrackingConfig::class_gate`], where `class_id` follows the
+    /// majority
+    #[serde(default)]
+    pub class_votes: BTreeMap<u8, u32>,
+    
+    /// External IDs waiting for [`TrackingConfig::merge_confirmation`]
+    #[serde(default)]
+    pub merge_candidates: BTreeMap<Uuid, MergeCandidate>,
+    
+    /// Highest [`GlobalHazardPacket::priority`] fused into this track
+    #[serde(default)]
+    pub priority: u8,
 }
 
 impl UniqueTrack {
@@ -170,7 +926,15 @@ impl UniqueTrack {
             class_id: packet.class_id,
             last_update: packet.timestamp,
             age: 0,
+            track_state: TrackState::Confirmed,
+            hits: 1,
+            lifetime: 0,
             h3_cell,
+            provenance: BTreeMap::new(),
+            local_alias: None,
+            class_votes: BTreeMap::new(),
+            merge_candidates: BTreeMap::new(),
+            priority: packet.priority,
         }
     }
     
@@ -185,6 +949,64 @@ impl UniqueTrack {
         }
     }
     
+    /// Merge a remote ID, never choosing a quarantined ID as canonical.
+    /// 
+    /// Quarantined IDs are still kept in `observed_ids`; one only becomes
+    /// canonical if every observed ID is quarantined.
+    pub fn merge_id_excluding(&mut self, remote_id: Uuid, quarantined: &HashSet<Uuid>) {
+        self.observed_ids.insert(remote_id);
+        let trusted_min = self.observed_ids.iter()
+            .filter(|id| !quarantined.contains(id))
+            .min()
+            .or_else(|| self.observed_ids.iter().min())
+            .copied();
+        self.canonical_id = trusted_min.unwrap_or(remote_id);
+    }
+    
+    /// Counts an association of `packet`'s ID, which this track doesn't
+    /// carry yet, towards absorbing it. `velocity` is the track's before
+    /// the packet was fused. Returns true once the ID is confirmed.
+    fn confirm_merge(
+        &mut self,
+        packet: &GlobalHazardPacket,
+        velocity: &Vector3<f64>,
+        confirmation: &MergeConfirmation,
+        stats: &mut MergeStats,
+    ) -> bool {
+        let now = packet.timestamp;
+        let before = self.merge_candidates.len();
+        self.merge_candidates.retain(|_, c| now - c.first_seen <= confirmation.window_secs);
+        stats.expired += (before - self.merge_candidates.len()) as u64;
+        
+        // Only a measured velocity can disagree
+        if packet.measurement_model.observes_velocity()
+            && !confirmation.velocities_agree(velocity, &Vector3::from(packet.velocity)) {
+            stats.near_misses += 1;
+            self.merge_candidates.remove(&packet.entity_id);
+            return false;
+        }
+        let candidate = self.merge_candidates.entry(packet.entity_id)
+            .or_insert(MergeCandidate { hits: 0, first_seen: now });
+        candidate.hits += 1;
+        if candidate.hits < confirmation.hits {
+            return false;
+        }
+        self.merge_candidates.remove(&packet.entity_id);
+        stats.confirmed += 1;
+        true
+    }
+    
+    /// True if every ID this track is known by is quarantined.
+    pub fn is_quarantined(&self, quarantined: &HashSet<Uuid>) -> bool {
+        self.observed_ids.iter().all(|id| quarantined.contains(id))
+    }
+    
+    /// True until the track has been confirmed.
+    #[inline]
+    pub fn is_tentative(&self) -> bool {
+        self.track_state == TrackState::Tentative
+    }
+    
     /// Get the position component of the state vector.
     #[inline]
     pub fn position(&self) -> Vector3<f64> {
@@ -202,35 +1024,137 @@ impl UniqueTrack {
     pub fn position_covariance(&self) -> Matrix3<f64> {
         self.covariance.fixed_view::<3, 3>(0, 0).into()
     }
-}
-
-// ============================================================================
-// TRACK MANAGER (The Engine)
-// ============================================================================
-
-/// The core engine for distributed data association.
-/// 
-/// Maintains a local world model by:
-/// 1. Ingesting GlobalHazardPackets from the network
-/// 2. Associating them with existing tracks via GNN
-/// 3. Fusing state via Covariance Intersection
-/// 4. Resolving IDs via the Highlander heuristic
-pub struct TrackManager {
-    // === Track Store ===
-    
-    /// All active tracks, keyed by their canonical_id
-    tracks: HashMap<Uuid, UniqueTrack>,
     
-    // === Spatial Index (H3 → Track IDs) ===
+    /// The neighbor whose packets added the most information, if provenance
+    /// is kept and any neighbor has contributed.
+    pub fn dominant_contributor(&self) -> Option<usize> {
+        self.provenance.iter()
+            .max_by(|a, b| a.1.total_cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(&neighbor, _)| neighbor)
+    }
+    
+    /// Counts a packet reporting `class_id` and moves the track to the
+    /// class with the most votes (staying put on a tie).
+    fn record_class_vote(&mut self, class_id: u8) {
+        *self.class_votes.entry(class_id).or_insert(0) += 1;
+        let current = self.class_votes.get(&self.class_id).copied().unwrap_or(0);
+        if let Some((&leader, &votes)) = self.class_votes.iter().max_by_key(|(_, &votes)| votes) {
+            if votes > current {
+                self.class_id = leader;
+            }
+        }
+    }
+    
+    /// Credits `neighbor` with `information`, evicting the smallest
+    /// contributor (the highest index on a tie) if a new neighbor would
+    /// take the map past `max_contributors`.
+    fn record_contribution(&mut self, neighbor: usize, information: f64, max_contributors: usize) {
+        if !self.provenance.contains_key(&neighbor) && self.provenance.len() >= max_contributors {
+            let smallest = self.provenance.iter()
+                .min_by(|a, b| a.1.total_cmp(b.1).then(b.0.cmp(a.0)))
+                .map(|(&n, _)| n);
+            match smallest {
+                Some(n) => { self.provenance.remove(&n); }
+                None => return,
+            }
+        }
+        *self.provenance.entry(neighbor).or_insert(0.0) += information;
+    }
+    
+    /// Fuses `other`, a track of the same entity, into this one: states by
+    /// Covariance Intersection (the tighter one kept if CI fails), IDs,
+    /// votes, provenance and merge candidates pooled. The caller re-indexes
+    /// the track, whose position may have moved.
+    fn absorb(&mut self, other: UniqueTrack, max_contributors: Option<usize>) {
+        match TrackManager::covariance_intersection(&self.state, &self.covariance, &other.state, &other.covariance) {
+            Some((state, covariance)) => {
+                self.state = state;
+                self.covariance = covariance;
+            }
+            None if other.covariance.trace() < self.covariance.trace() => {
+                self.state = other.state;
+                self.covariance = other.covariance;
+            }
+            None => {}
+        }
+        self.observed_ids.extend(other.observed_ids);
+        self.last_update = self.last_update.max(other.last_update);
+        self.age = self.age.min(other.age);
+        self.hits = self.hits.max(other.hits);
+        self.lifetime = self.lifetime.max(other.lifetime);
+        if other.track_state == TrackState::Confirmed {
+            self.track_state = TrackState::Confirmed;
+        }
+        if let Some(max_contributors) = max_contributors {
+            for (neighbor, information) in other.provenance {
+                self.record_contribution(neighbor, information, max_contributors);
+            }
+        }
+        self.local_alias = match (self.local_alias, other.local_alias) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        for (class_id, votes) in other.class_votes {
+            *self.class_votes.entry(class_id).or_insert(0) += votes;
+        }
+        if let Some((&leader, _)) = self.class_votes.iter().max_by_key(|(_, &votes)| votes) {
+            self.class_id = leader;
+        }
+        for (id, candidate) in other.merge_candidates {
+            self.merge_candidates.entry(id).or_insert(candidate);
+        }
+        self.priority = self.priority.max(other.priority);
+    }
+}
+
+// ============================================================================
+// TRACK MANAGER (The Engine)
+// ============================================================================
+
+/// The core engine for distributed data association.
+/// 
+/// Maintains a local world model by:
+/// 1. Ingesting GlobalHazardPackets from the network
+/// 2. Associating them with existing tracks via GNN
+/// 3. Fusing state via Covariance Intersection
+/// 4. Resolving IDs via the Highlander heuristic
+pub struct TrackManager {
+    // === Track Store ===
+    
+    /// All active tracks, keyed by their canonical_id
+    tracks: HashMap<Uuid, UniqueTrack>,
+    
+    // === Spatial Index (H3 → Track IDs) ===
     
     /// Maps H3 cells to the set of track IDs within that cell
     spatial_index: HashMap<CellIndex, HashSet<Uuid>>,
     
+    // === Identity Quarantine ===
+    
+    /// IDs from distrusted sources, excluded from Highlander resolution
+    quarantine: HashSet<Uuid>,
+    
     // === Configuration ===
     
     /// Runtime configuration
     config: TrackingConfig,
 
+    /// Tracks evicted to stay within `max_tracks`
+    evictions: u64,
+    
+    /// Spatial associations found beyond the k=1 ring
+    wide_associations: u64,
+    
+    /// Next [`UniqueTrack::local_alias`] to hand out
+    next_alias: u64,
+    
+    /// Outcomes of [`TrackingConfig::merge_confirmation`]
+    merge_stats: MergeStats,
+    
+    /// Confirmed merges and rekey collisions not yet taken by
+    /// [`Self::take_merge_events`]
+    merge_events: Vec<MergeEvent>,
+    
     /// History of Peer Agreement Cost (J_PA) values
     /// Used for blind fitness evaluation
     pub peer_agreement_history: VecDeque<f64>,
@@ -247,7 +1171,13 @@ impl TrackManager {
         Self {
             tracks: HashMap::new(),
             spatial_index: HashMap::new(),
+            quarantine: HashSet::new(),
             config,
+            evictions: 0,
+            wide_associations: 0,
+            next_alias: 0,
+            merge_stats: MergeStats::default(),
+            merge_events: Vec::new(),
             peer_agreement_history: VecDeque::new(),
             pa_window_size: 30,
         }
@@ -258,14 +1188,62 @@ impl TrackManager {
         Self::new(TrackingConfig::default())
     }
     
+    /// The configuration in use.
+    pub fn config(&self) -> &TrackingConfig {
+        &self.config
+    }
+    
+    /// Swaps in `config` on a live manager, keeping every track.
+    ///
+    /// `config` is validated first, and the frame can't change under
+    /// existing tracks; on error the old config stays in use. A new
+    /// `h3_resolution` re-cells every track and rebuilds the spatial index,
+    /// and a lower `max_tracks` evicts down to it at once. Age limits apply
+    /// from the next [`Self::age_tracks`], which prunes tracks already past
+    /// a shortened `max_age`. The rest shapes fusions and tracks from here on.
+    pub fn update_config(&mut self, config: TrackingConfig) -> Result<(), TrackingError> {
+        config.validate()?;
+        if config.frame != self.config.frame {
+            return Err(TrackingError::InvalidConfig("frame can't change with live tracks".to_string()));
+        }
+        
+        if config.h3_resolution != self.config.h3_resolution {
+            // Every cell is computed before any track moves
+            let cells = self.tracks
+                .iter()
+                .map(|(&id, track)| {
+                    let latlng = config.frame.to_latlng(track.state[0], track.state[1])?;
+                    Ok((id, latlng.to_cell(config.h3_resolution)))
+                })
+                .collect::<Result<Vec<_>, TrackingError>>()?;
+            for (id, cell) in cells {
+                if let Some(track) = self.tracks.get_mut(&id) {
+                    track.h3_cell = cell;
+                }
+            }
+            self.config = config;
+            self.rebuild_spatial_index();
+        } else {
+            self.config = config;
+        }
+        
+        if let Some(max_tracks) = self.config.max_tracks {
+            while self.tracks.len() > max_tracks {
+                if !self.evict_least_useful() {
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+    
     // ========================================================================
     // SPATIAL INDEX OPERATIONS
     // ========================================================================
     
-    /// Convert WGS84 coordinates to an H3 cell index.
-    pub fn position_to_cell(&self, lat: f64, lon: f64) -> Result<CellIndex, TrackingError> {
-        let latlng = LatLng::new(lat, lon)
-            .map_err(|e| TrackingError::InvalidCoordinates(format!("{:?}", e)))?;
+    /// Convert a horizontal position in the configured frame to an H3 cell index.
+    pub fn position_to_cell(&self, x: f64, y: f64) -> Result<CellIndex, TrackingError> {
+        let latlng = self.config.frame.to_latlng(x, y)?;
         Ok(latlng.to_cell(self.config.h3_resolution))
     }
     
@@ -273,7 +1251,7 @@ impl TrackManager {
     fn spatial_index_insert(&mut self, cell: CellIndex, track_id: Uuid) {
         self.spatial_index
             .entry(cell)
-            .or_insert_with(HashSet::new)
+            .or_default()
             .insert(track_id);
     }
     
@@ -305,6 +1283,53 @@ impl TrackManager {
         result
     }
     
+    /// Tracks a packet may associate with: only the track keyed by its UUID
+    /// if there is one, otherwise every track in the base ring around `cell`
+    /// (k=1, or the fixed k of [`CellSearch::Fixed`]).
+    ///
+    /// A source's ID names one object, so its measurement never fuses into
+    /// (and hands that ID to) some other track it happens to land near.
+    fn association_candidates(&self, packet: &GlobalHazardPacket, cell: CellIndex) -> HashSet<Uuid> {
+        if self.tracks.contains_key(&packet.entity_id) {
+            return HashSet::from([packet.entity_id]);
+        }
+        let k = match self.config.cell_search {
+            CellSearch::Fixed(k) => k,
+            CellSearch::Adaptive { .. } => 1,
+        };
+        self.spatial_query_kring(cell, k)
+    }
+    
+    /// Tracks beyond the k=1 ring that [`CellSearch::Adaptive`] lets a packet
+    /// reach: those up to `max_k` rings away that it, or they, could have
+    /// moved to since the track's last update. Empty for a fixed search or a
+    /// packet whose UUID already names a track.
+    fn wide_candidates(&self, packet: &GlobalHazardPacket, cell: CellIndex) -> HashSet<Uuid> {
+        let CellSearch::Adaptive { max_k } = self.config.cell_search else {
+            return HashSet::new();
+        };
+        if max_k <= 1 || self.tracks.contains_key(&packet.entity_id) {
+            return HashSet::new();
+        }
+        let edge_m = self.config.h3_resolution.edge_length_m();
+        let packet_speed = packet.velocity[0].hypot(packet.velocity[1]);
+        self.spatial_query_kring(cell, max_k)
+            .into_iter()
+            .filter(|id| {
+                let Some(track) = self.tracks.get(id) else {
+                    return false;
+                };
+                let Ok(distance) = track.h3_cell.grid_distance(cell) else {
+                    return false;
+                };
+                let speed = packet_speed.max(track.state[3].hypot(track.state[4]));
+                let dt = (packet.timestamp - track.last_update).max(0.0);
+                let reach = 1.0 + (speed * dt / edge_m).ceil();
+                distance > 1 && f64::from(distance) <= reach
+            })
+            .collect()
+    }
+    
     /// Update a track's position in the spatial index if its cell changed.
     fn reindex_track(&mut self, track_id: Uuid, old_cell: CellIndex, new_cell: CellIndex) {
         if old_cell != new_cell {
@@ -313,38 +1338,270 @@ impl TrackManager {
         }
     }
     
+    /// Moves the track keyed `old_id` to `new_id`, in the track store and
+    /// the spatial index.
+    ///
+    /// A different track already keyed by `new_id` is the same entity by
+    /// Highlander's rule, so the two are fused into one (see
+    /// [`UniqueTrack::absorb`]) and the merge recorded as a [`MergeEvent`].
+    fn rekey_track(&mut self, old_id: Uuid, new_id: Uuid) {
+        if old_id == new_id {
+            return;
+        }
+        let Some(mut track) = self.tracks.remove(&old_id) else {
+            return;
+        };
+        self.spatial_index_remove(track.h3_cell, old_id);
+        if let Some(live) = self.tracks.remove(&new_id) {
+            self.spatial_index_remove(live.h3_cell, new_id);
+            let position = track.position();
+            let timestamp = track.last_update.max(live.last_update);
+            track.absorb(live, self.config.provenance);
+            track.h3_cell = self.position_to_cell(track.state[0], track.state[1]).unwrap_or(track.h3_cell);
+            self.merge_events.push(MergeEvent {
+                winner_id: new_id,
+                loser_id: old_id,
+                merge_position: [position.x, position.y, position.z],
+                reason: "rekeyed onto a live track with the same ID".to_string(),
+                timestamp,
+            });
+        }
+        self.spatial_index_insert(track.h3_cell, new_id);
+        self.tracks.insert(new_id, track);
+    }
+    
+    /// Cross-checks the spatial index against the track store, both ways:
+    /// every index entry must name a live track in that cell, and every
+    /// track must be indexed under its own `h3_cell`.
+    ///
+    /// Returns what disagrees, sorted (empty when consistent).
+    pub fn verify_spatial_index(&self) -> Vec<Inconsistency> {
+        let mut found: Vec<Inconsistency> = self.spatial_index.keys()
+            .flat_map(|&cell| self.cell_inconsistencies(cell))
+            .chain(self.tracks.iter().filter_map(|(&id, track)| self.track_inconsistency(id, track)))
+            .collect();
+        found.sort();
+        found
+    }
+    
+    /// Rebuilds the spatial index from the track store, dropping entries
+    /// for missing tracks and indexing every track under its `h3_cell`.
+    pub fn rebuild_spatial_index(&mut self) {
+        let mut index: HashMap<CellIndex, HashSet<Uuid>> = HashMap::new();
+        for (&id, track) in &self.tracks {
+            index.entry(track.h3_cell).or_default().insert(id);
+        }
+        self.spatial_index = index;
+    }
+    
+    /// Entries of `cell` naming a missing track or one in another cell.
+    fn cell_inconsistencies(&self, cell: CellIndex) -> Vec<Inconsistency> {
+        let Some(ids) = self.spatial_index.get(&cell) else {
+            return Vec::new();
+        };
+        ids.iter()
+            .filter_map(|&track_id| match self.tracks.get(&track_id) {
+                None => Some(Inconsistency::MissingTrack { cell, track_id }),
+                Some(track) if track.h3_cell != cell => {
+                    Some(Inconsistency::WrongCell { cell, track_id, track_cell: track.h3_cell })
+                }
+                Some(_) => None,
+            })
+            .collect()
+    }
+    
+    /// The track keyed `track_id` if it is missing from its cell's entry.
+    fn track_inconsistency(&self, track_id: Uuid, track: &UniqueTrack) -> Option<Inconsistency> {
+        let indexed = self.spatial_index.get(&track.h3_cell).is_some_and(|ids| ids.contains(&track_id));
+        (!indexed).then_some(Inconsistency::Unindexed { track_id, cell: track.h3_cell })
+    }
+    
+    /// Whether the cells a fusion touched, and the fused track, agree with
+    /// the track store. Cheaper than [`Self::verify_spatial_index`], for
+    /// checking after every fusion in debug builds.
+    fn fusion_indexed_consistently(&self, track_id: Uuid, cells: [CellIndex; 2]) -> bool {
+        cells.iter().all(|&cell| self.cell_inconsistencies(cell).is_empty())
+            && self.tracks.get(&track_id).is_none_or(|track| self.track_inconsistency(track_id, track).is_none())
+    }
+    
     // ========================================================================
     // TRACK LIFECYCLE
     // ========================================================================
     
     /// Create a new track from an incoming packet.
+    ///
+    /// At `max_tracks` the least useful track is evicted first.
     fn create_track(&mut self, packet: &GlobalHazardPacket) -> Result<Uuid, TrackingError> {
+        let model = packet.measurement_model;
+        if !model.is_positional() {
+            return Err(TrackingError::UnsupportedMeasurement(
+                "bearing and range-Doppler measurements can't start a track".to_string(),
+            ));
+        }
         let cell = self.position_to_cell(packet.position[0], packet.position[1])?;
-        let covariance = self.confidence_to_covariance(packet.confidence_score);
+        let mut covariance = match &self.config.motion {
+            Some(tuning) => tuning.initial_covariance(),
+            None => self.confidence_to_covariance(packet.confidence_score),
+        };
+        if !model.observes_velocity() && self.config.motion.is_none() {
+            for i in 3..6 {
+                covariance[(i, i)] = self.config.base_vel_variance;
+            }
+        }
         
-        let track = UniqueTrack::from_packet(packet, covariance, cell);
+        let mut track = UniqueTrack::from_packet(packet, covariance, cell);
+        if !model.observes_velocity() {
+            // Unobserved velocity starts at rest
+            track.state.fixed_rows_mut::<3>(3).fill(0.0);
+        }
+        if self.config.class_gate.is_some() {
+            track.class_votes.insert(packet.class_id, 1);
+        }
+        if self.config.lifecycle.as_ref().is_some_and(|l| l.for_class(packet.class_id).confirm_hits > 1) {
+            track.track_state = TrackState::Tentative;
+        }
         let track_id = track.canonical_id;
+        Self::check_numerics(track_id, &track.state, &track.covariance)?;
+        
+        if let Some(max_tracks) = self.config.max_tracks {
+            // A packet carrying an existing ID replaces that track instead
+            while self.tracks.len() >= max_tracks && !self.tracks.contains_key(&packet.entity_id) {
+                if !self.evict_least_useful() {
+                    break;
+                }
+            }
+        }
         
+        // A track already keyed by this ID is replaced, index entry included,
+        // and passes its alias on
+        if let Some(replaced) = self.tracks.remove(&track_id) {
+            self.spatial_index_remove(replaced.h3_cell, track_id);
+            track.local_alias = replaced.local_alias;
+        }
+        if self.config.local_aliases && track.local_alias.is_none() {
+            track.local_alias = Some(self.next_alias);
+            self.next_alias += 1;
+        }
         self.spatial_index_insert(cell, track_id);
         self.tracks.insert(track_id, track);
         
         Ok(track_id)
     }
     
+    /// Evicts the track with the highest age × covariance trace, breaking
+    /// ties by the oldest `last_update` and then the smallest ID.
+    ///
+    /// Returns false if there was nothing to evict.
+    fn evict_least_useful(&mut self) -> bool {
+        let victim = self.tracks
+            .values()
+            .map(|t| (t.age as f64 * t.covariance.trace(), t.last_update, t.canonical_id, t.h3_cell))
+            .max_by(|a, b| {
+                a.0.total_cmp(&b.0)
+                    .then(b.1.total_cmp(&a.1))
+                    .then(b.2.cmp(&a.2))
+            });
+        let Some((_, _, track_id, cell)) = victim else {
+            return false;
+        };
+        
+        self.spatial_index_remove(cell, track_id);
+        self.tracks.remove(&track_id);
+        self.evictions += 1;
+        true
+    }
+    
+    /// Removes a track and its spatial index entry, returning it.
+    pub fn remove_track(&mut self, track_id: &Uuid) -> Option<UniqueTrack> {
+        let track = self.tracks.remove(track_id)?;
+        self.spatial_index_remove(track.h3_cell, *track_id);
+        Some(track)
+    }
+    
+    /// Predict all tracks forward by `dt` seconds using the configured
+    /// motion model. No-op when `TrackingConfig::motion` is None.
+    ///
+    /// In [`Frame::Wgs84`] the m/s velocities are turned into degrees per
+    /// second at each track's latitude before they move its position.
+    pub fn predict_tracks(&mut self, dt: f64) {
+        let Some(tuning) = self.config.motion else {
+            return;
+        };
+        let transition = FilterTuning::transition(dt);
+        let noise = tuning.process_noise(dt);
+        let frame = self.config.frame;
+        
+        let mut moved = Vec::new();
+        for (id, track) in self.tracks.iter_mut() {
+            let (f, q) = match frame {
+                Frame::LocalEnu { .. } => (transition, noise),
+                Frame::Wgs84 => {
+                    // Predict in meters around the track: x_deg = S x_m
+                    let (lat_per_m, lon_per_m) = Frame::degrees_per_meter(track.state[0]);
+                    let s = Matrix6::from_diagonal(&Vector6::new(lat_per_m, lon_per_m, 1.0, 1.0, 1.0, 1.0));
+                    let s_inv = Matrix6::from_diagonal(&Vector6::new(1.0 / lat_per_m, 1.0 / lon_per_m, 1.0, 1.0, 1.0, 1.0));
+                    (s * transition * s_inv, s * noise * s)
+                }
+            };
+            track.state = f * track.state;
+            track.covariance = f * track.covariance * f.transpose() + q;
+            moved.push((*id, track.h3_cell, track.state[0], track.state[1]));
+        }
+        
+        for (track_id, old_cell, x, y) in moved {
+            // Keep the old cell if prediction left the valid coordinate range
+            let Ok(new_cell) = self.position_to_cell(x, y) else {
+                continue;
+            };
+            if let Some(track) = self.tracks.get_mut(&track_id) {
+                track.h3_cell = new_cell;
+            }
+            self.reindex_track(track_id, old_cell, new_cell);
+        }
+    }
+    
     /// Age all tracks by one cycle and remove those that exceed max_age.
+    /// 
+    /// Tracks known only by quarantined IDs expire at `quarantine_max_age`,
+    /// and high-priority tracks at `high_priority_max_age` if set. With a [`LifecycleConfig`], confirmed tracks that missed the last
+    /// cycle start coasting, and tentative tracks past their confirmation
+    /// window and coasting tracks past `coast_cycles` are removed as well.
     pub fn age_tracks(&mut self) {
         let max_age = self.config.max_age;
+        let quarantine_max_age = self.config.quarantine_max_age.min(max_age);
+        let high_priority_max_age = self.config.high_priority_max_age.map_or(max_age, |age| age.min(max_age));
+        let lifecycle = self.config.lifecycle.as_ref();
         
         // First, increment age for all tracks
         for track in self.tracks.values_mut() {
             track.age += 1;
+            track.lifetime = track.lifetime.saturating_add(1);
+            // Fused tracks reach age 1 here; 2 means a whole cycle was missed
+            if lifecycle.is_some() && track.track_state == TrackState::Confirmed && track.age >= 2 {
+                track.track_state = TrackState::Coasting;
+            }
         }
         
         // Then collect tracks to remove (those at or above max_age)
         let to_remove: Vec<(Uuid, CellIndex)> = self.tracks
             .iter()
             .filter_map(|(id, track)| {
-                if track.age >= max_age {
+                let limit = if !self.quarantine.is_empty() && track.is_quarantined(&self.quarantine) {
+                    quarantine_max_age
+                } else if track.priority >= GlobalHazardPacket::HIGH_PRIORITY {
+                    high_priority_max_age
+                } else {
+                    max_age
+                };
+                let expired = lifecycle.is_some_and(|l| {
+                    let settings = l.for_class(track.class_id);
+                    match track.track_state {
+                        TrackState::Tentative => track.lifetime >= settings.confirm_window,
+                        TrackState::Coasting => track.age >= settings.coast_cycles,
+                        TrackState::Confirmed => false,
+                    }
+                });
+                if track.age >= limit || expired {
                     Some((*id, track.h3_cell))
                 } else {
                     None
@@ -359,6 +1616,37 @@ impl TrackManager {
         }
     }
     
+    // ========================================================================
+    // IDENTITY QUARANTINE
+    // ========================================================================
+    
+    /// Quarantine an ID so it can no longer win Highlander resolution.
+    /// 
+    /// A track currently keyed by `id` falls back to its smallest
+    /// non-quarantined ID. Returns false if `id` was already quarantined.
+    pub fn quarantine_id(&mut self, id: Uuid) -> bool {
+        if !self.quarantine.insert(id) {
+            return false;
+        }
+        
+        if let Some(track) = self.tracks.get_mut(&id) {
+            track.merge_id_excluding(id, &self.quarantine);
+            let new_id = track.canonical_id;
+            self.rekey_track(id, new_id);
+        }
+        true
+    }
+    
+    /// Returns true if `id` is quarantined.
+    pub fn is_quarantined(&self, id: &Uuid) -> bool {
+        self.quarantine.contains(id)
+    }
+    
+    /// Returns the number of quarantined IDs.
+    pub fn quarantined_count(&self) -> usize {
+        self.quarantine.len()
+    }
+    
     // ========================================================================
     // COVARIANCE / CONFIDENCE CONVERSION
     // ========================================================================
@@ -367,20 +1655,57 @@ impl TrackManager {
     /// 
     /// Higher confidence → lower variance.
     /// This allows handling packets that don't transmit full covariance matrices.
+    /// With [`TrackingConfig::vertical_variance_ratio`] the z variance is
+    /// that multiple of the x and y variance.
     pub fn confidence_to_covariance(&self, confidence: f64) -> Matrix6<f64> {
-        // Clamp to avoid division by zero or negative variance
-        let inv_confidence = (1.0 - confidence).clamp(0.01, 1.0);
+        let inv_confidence = inverse_confidence(confidence);
         
         let pos_var = self.config.base_pos_variance * inv_confidence;
+        let alt_var = pos_var * self.config.vertical_variance_ratio.unwrap_or(1.0);
         let vel_var = self.config.base_vel_variance * inv_confidence;
         
         // Diagonal covariance (uncorrelated errors)
         Matrix6::from_diagonal(&Vector6::new(
-            pos_var, pos_var, pos_var,  // x, y, z
+            pos_var, pos_var, alt_var,  // x, y, z
             vel_var, vel_var, vel_var,  // vx, vy, vz
         ))
     }
+
+    /// Measurement noise covariance R of `packet` in its
+    /// [`MeasurementModel`]'s space: the matching block of
+    /// [`Self::confidence_to_covariance`], with bearings scaled from
+    /// [`NonlinearMeasurements::bearing_variance`] the same way.
+    pub fn measurement_noise(&self, packet: &GlobalHazardPacket) -> DMatrix<f64> {
+        let r = self.confidence_to_covariance(packet.confidence_score);
+        match packet.measurement_model {
+            MeasurementModel::PositionVelocity6D => DMatrix::from_column_slice(6, 6, r.as_slice()),
+            MeasurementModel::Position3D => DMatrix::from_fn(3, 3, |i, j| r[(i, j)]),
+            MeasurementModel::BearingOnly { .. } => {
+                let bearing_variance = self.config.nonlinear.unwrap_or_default().bearing_variance;
+                DMatrix::from_diagonal_element(2, 2, bearing_variance * inverse_confidence(packet.confidence_score))
+            }
+            MeasurementModel::RangeDoppler { .. } => {
+                DMatrix::from_diagonal(&DVector::from_column_slice(&[r[(0, 0)], r[(3, 3)]]))
+            }
+        }
+    }
     
+    /// Convert a covariance matrix back to a scalar confidence score.
+    ///
+    /// Inverse of [`Self::confidence_to_covariance`] on the mean position
+    /// variance (z scaled back by [`TrackingConfig::vertical_variance_ratio`]),
+    /// so a receiver converting the score back gets roughly the same
+    /// position uncertainty. Clamped to the range that function can
+    /// produce, [0.0, 0.99]; a non-finite covariance has no confidence.
+    pub fn covariance_to_confidence(&self, covariance: &Matrix6<f64>) -> f64 {
+        let alt_var = covariance[(2, 2)] / self.config.vertical_variance_ratio.unwrap_or(1.0);
+        let pos_var = (covariance[(0, 0)] + covariance[(1, 1)] + alt_var) / 3.0;
+        if !pos_var.is_finite() {
+            return 0.0;
+        }
+        (1.0 - pos_var / self.config.base_pos_variance).clamp(0.0, 0.99)
+    }
+
     // ========================================================================
     // PHASE 2: MATH ENGINE (Mahalanobis & GNN)
     // ========================================================================
@@ -395,12 +1720,22 @@ impl TrackManager {
     /// - H = observation matrix
     /// - S = HPHᵀ + R = innovation covariance
     /// 
-    /// Returns f64::MAX if the innovation covariance is singular.
+    /// Returns f64::MAX if the innovation covariance is singular, or the
+    /// packet's [`MeasurementModel`] is disabled or undefined at the track.
     pub fn mahalanobis_distance_squared(
         &self,
         track: &UniqueTrack,
         packet: &GlobalHazardPacket,
     ) -> f64 {
+        if !packet.measurement_model.is_full_state() {
+            return self.innovation(track, packet)
+                .and_then(|innovation| {
+                    let s_inv = innovation.covariance.try_inverse()?;
+                    Some((innovation.residual.transpose() * s_inv * &innovation.residual)[(0, 0)])
+                })
+                .unwrap_or(f64::MAX);
+        }
+        
         // Construct measurement vector (6-DOF: position + velocity)
         let z = Vector6::new(
             packet.position[0],
@@ -435,13 +1770,34 @@ impl TrackManager {
         }
     }
     
+    /// Innovation of `packet` against `track` in the packet's measurement
+    /// space (H and R per its [`MeasurementModel`]).
+    ///
+    /// None for an angular model without [`TrackingConfig::nonlinear`] or
+    /// where it is undefined at the track.
+    fn innovation(&self, track: &UniqueTrack, packet: &GlobalHazardPacket) -> Option<Innovation> {
+        let model = packet.measurement_model;
+        if !model.is_positional() && self.config.nonlinear.is_none() {
+            return None;
+        }
+        let (expected, h) = model.observe(&track.state)?;
+        let residual = model.residual(&model.measurement(packet), &expected);
+        let noise = self.measurement_noise(packet);
+        let p = DMatrix::from_column_slice(6, 6, track.covariance.as_slice());
+        let covariance = &h * p * h.transpose() + &noise;
+        Some(Innovation { residual, covariance, h, noise })
+    }
+    
     /// Perform geometric gating on a set of candidate tracks.
     /// 
     /// Returns tracks that pass the Chi-squared test, sorted by Mahalanobis distance.
     /// 
     /// Hard gating rules:
-    /// 1. Class ID must match (pedestrians don't associate with vehicles)
-    /// 2. Mahalanobis distance² must be below threshold
+    /// 1. Class ID must match (pedestrians don't associate with vehicles),
+    ///    unless [`TrackingConfig::class_gate`] allows the pair
+    /// 2. Mahalanobis distance², plus any class mismatch penalty, must be
+    ///    below threshold (the same whatever the packet's
+    ///    [`MeasurementModel`], so reduced measurements gate more loosely)
     pub fn gate_candidates(
         &self,
         candidates: &HashSet<Uuid>,
@@ -452,13 +1808,11 @@ impl TrackManager {
             .filter_map(|&track_id| {
                 let track = self.tracks.get(&track_id)?;
                 
-                // Hard gate: class must match
-                if track.class_id != packet.class_id {
-                    return None;
-                }
+                // Hard gate: class must match or be compatible
+                let penalty = self.class_penalty(track.class_id, packet.class_id)?;
                 
                 // Soft gate: Mahalanobis distance
-                let d_squared = self.mahalanobis_distance_squared(track, packet);
+                let d_squared = self.mahalanobis_distance_squared(track, packet) + penalty;
                 
                 if d_squared < self.config.gating_threshold {
                     Some((track_id, d_squared))
@@ -474,6 +1828,15 @@ impl TrackManager {
         gated
     }
     
+    /// Gating penalty for associating a packet of class `packet_class` with
+    /// a track of class `track_class` (None: they may not associate).
+    fn class_penalty(&self, track_class: u8, packet_class: u8) -> Option<f64> {
+        match &self.config.class_gate {
+            Some(gate) => gate.penalty(track_class, packet_class),
+            None => (track_class == packet_class).then_some(0.0),
+        }
+    }
+    
     /// Select the best match using Global Nearest Neighbor (GNN).
     /// 
     /// Returns the track ID with the smallest Mahalanobis distance,
@@ -487,19 +1850,46 @@ impl TrackManager {
     /// This combines Stage 1 (Spatial Pruning) and Stage 2 (Geometric Gating)
     /// of the processing pipeline.
     pub fn find_association(&self, packet: &GlobalHazardPacket) -> Result<Option<Uuid>, TrackingError> {
+        Ok(self.search_association(packet)?.map(|(track_id, _)| track_id))
+    }
+    
+    /// [`Self::find_association`], also reporting whether the match was found
+    /// beyond the k=1 ring.
+    fn search_association(&self, packet: &GlobalHazardPacket) -> Result<Option<(Uuid, bool)>, TrackingError> {
+        if !packet.measurement_model.is_positional() {
+            if self.config.nonlinear.is_none() {
+                return Err(TrackingError::UnsupportedMeasurement(
+                    "bearing and range-Doppler measurements need TrackingConfig::nonlinear".to_string(),
+                ));
+            }
+            // Nothing to search by: only the track the packet's ID names
+            let named = HashSet::from([packet.entity_id]);
+            return Ok(self.select_best_match(&self.gate_candidates(&named, packet)).map(|track_id| (track_id, false)));
+        }
+        
         // Stage 1: Spatial Pruning using H3 k-ring
         let packet_cell = self.position_to_cell(packet.position[0], packet.position[1])?;
-        let candidates = self.spatial_query_kring(packet_cell, 1);
-        
-        if candidates.is_empty() {
-            return Ok(None);
-        }
+        let candidates = self.association_candidates(packet, packet_cell);
         
         // Stage 2: Geometric Gating (Mahalanobis + class check)
         let gated = self.gate_candidates(&candidates, packet);
         
         // GNN: Select best match
-        Ok(self.select_best_match(&gated))
+        if let Some(track_id) = self.select_best_match(&gated) {
+            return Ok(Some((track_id, self.is_wide_ring_match(track_id, packet, packet_cell))));
+        }
+        
+        // Nothing close: reach further for tracks fast or stale enough
+        let wide = self.wide_candidates(packet, packet_cell);
+        Ok(self.select_best_match(&self.gate_candidates(&wide, packet)).map(|track_id| (track_id, true)))
+    }
+    
+    /// Whether a match found in the base ring of a [`CellSearch::Fixed`]
+    /// search with k > 1 lies beyond the k=1 ring.
+    fn is_wide_ring_match(&self, track_id: Uuid, packet: &GlobalHazardPacket, packet_cell: CellIndex) -> bool {
+        matches!(self.config.cell_search, CellSearch::Fixed(k) if k > 1)
+            && track_id != packet.entity_id
+            && self.tracks[&track_id].h3_cell.grid_distance(packet_cell).is_ok_and(|d| d > 1)
     }
     
     // ========================================================================
@@ -516,6 +1906,11 @@ impl TrackManager {
         self.tracks.get_mut(id)
     }
     
+    /// Get a reference to a track by its [`UniqueTrack::local_alias`].
+    pub fn track_by_alias(&self, alias: u64) -> Option<&UniqueTrack> {
+        self.tracks.values().find(|t| t.local_alias == Some(alias))
+    }
+    
     /// Get all tracks as an iterator.
     pub fn tracks(&self) -> impl Iterator<Item = &UniqueTrack> {
         self.tracks.values()
@@ -540,9 +1935,73 @@ impl TrackManager {
             total_cells,
             total_entries,
             avg_per_cell,
+            wide_associations: self.wide_associations,
         }
     }
 
+    /// Count live tracks by lifecycle state.
+    pub fn state_counts(&self) -> TrackStateCounts {
+        let mut counts = TrackStateCounts::default();
+        for track in self.tracks.values() {
+            match track.track_state {
+                TrackState::Tentative => counts.tentative += 1,
+                TrackState::Confirmed => counts.confirmed += 1,
+                TrackState::Coasting => counts.coasting += 1,
+            }
+        }
+        counts
+    }
+    
+    /// Get track capacity usage and eviction counts.
+    pub fn capacity_stats(&self) -> CapacityStats {
+        CapacityStats {
+            track_count: self.tracks.len(),
+            max_tracks: self.config.max_tracks,
+            evictions: self.evictions,
+        }
+    }
+    
+    /// Merge confirmation outcomes so far, with the candidates pending now.
+    pub fn merge_stats(&self) -> MergeStats {
+        MergeStats {
+            pending: self.tracks.values().map(|t| t.merge_candidates.len()).sum(),
+            ..self.merge_stats
+        }
+    }
+    
+    /// Takes the merges since the last call, oldest first: IDs confirmed
+    /// with [`TrackingConfig::merge_confirmation`], and tracks fused because
+    /// a rekey landed on a live track's ID.
+    pub fn take_merge_events(&mut self) -> Vec<MergeEvent> {
+        std::mem::take(&mut self.merge_events)
+    }
+    
+    /// Estimated heap and inline footprint in bytes: the tracks and their
+    /// observed and candidate IDs, spatial index entries, quarantine and
+    /// J_PA window.
+    ///
+    /// Counts live entries rather than allocated capacity, so it shrinks as
+    /// tracks are evicted or aged out.
+    pub fn memory_estimate_bytes(&self) -> usize {
+        let uuid = std::mem::size_of::<Uuid>();
+        let tracks: usize = self.tracks
+            .values()
+            .map(|t| {
+                uuid + std::mem::size_of::<UniqueTrack>()
+                    + t.observed_ids.len() * uuid
+                    + t.merge_candidates.len() * (uuid + std::mem::size_of::<MergeCandidate>())
+            })
+            .sum();
+        let cells = self.spatial_index.len() * (std::mem::size_of::<CellIndex>() + std::mem::size_of::<HashSet<Uuid>>());
+        let entries: usize = self.spatial_index.values().map(|ids| ids.len() * uuid).sum();
+        std::mem::size_of::<Self>()
+            + tracks
+            + cells
+            + entries
+            + self.quarantine.len() * uuid
+            + self.peer_agreement_history.len() * std::mem::size_of::<f64>()
+    }
+    
     /// Get average Peer Agreement Cost (J_PA) over the rolling window.
     /// Used for blind fitness evaluation.
     pub fn get_peer_agreement_cost(&self) -> f64 {
@@ -566,7 +2025,8 @@ impl TrackManager {
     /// Uses Fast-CI with Trace Minimization:
     ///   ω = tr(P_B) / (tr(P_A) + tr(P_B))
     /// 
-    /// Returns (fused_state, fused_covariance), or None if matrices are singular.
+    /// Returns (fused_state, fused_covariance), or None if matrices are singular
+    /// or the result is not finite and positive-definite.
     pub fn covariance_intersection(
         x_a: &Vector6<f64>,
         p_a: &Matrix6<f64>,
@@ -603,9 +2063,74 @@ impl TrackManager {
         let info_b = p_b_inv * x_b * (1.0 - omega);
         let x_ci = p_ci * (info_a + info_b);
         
+        // Non-finite inputs come out of the inverses as NaN rather than None
+        if !x_ci.iter().all(|v| v.is_finite()) || !is_positive_definite(&p_ci) {
+            return None;
+        }
+        
         Some((x_ci, p_ci))
     }
     
+    /// Kalman update of a track with a measurement of part of its state,
+    /// through observation matrix `h` (linearized for the angular models).
+    /// 
+    ///   K = P Hᵀ (HPHᵀ + R)⁻¹
+    ///   x' = x + K y
+    ///   P' = (I - KH) P (I - KH)ᵀ + K R Kᵀ
+    /// where `residual` y is the innovation z - h(x). State components the
+    /// measurement misses move only through their correlation with the ones
+    /// it sees, which prediction builds up between updates.
+    /// 
+    /// Used instead of [`Self::covariance_intersection`] for reduced
+    /// measurements: they come straight from a sensor, so their noise is
+    /// independent of the track, and CI would inflate the components they
+    /// miss on every update. Returns None if the innovation covariance is
+    /// singular or the result is not finite and positive-definite.
+    pub fn kalman_update(
+        x: &Vector6<f64>,
+        p: &Matrix6<f64>,
+        residual: &DVector<f64>,
+        h: &DMatrix<f64>,
+        r: &DMatrix<f64>,
+    ) -> Option<(Vector6<f64>, Matrix6<f64>)> {
+        let p_dyn = DMatrix::from_column_slice(6, 6, p.as_slice());
+        let s_inv = (h * &p_dyn * h.transpose() + r).try_inverse()?;
+        let gain = &p_dyn * h.transpose() * s_inv;
+        let x_new = x + Vector6::from_column_slice((&gain * residual).as_slice());
+        let i_kh = DMatrix::identity(6, 6) - &gain * h;
+        let p_new = &i_kh * p_dyn * i_kh.transpose() + &gain * r * gain.transpose();
+        let p_new = Matrix6::from_column_slice(p_new.as_slice());
+        
+        if !x_new.iter().all(|v| v.is_finite()) || !is_positive_definite(&p_new) {
+            return None;
+        }
+        
+        Some((x_new, p_new))
+    }
+    
+    /// Checks that a state is finite and its covariance finite and
+    /// positive-definite.
+    ///
+    /// Fails with [`TrackingError::NumericalInstability`] carrying `track_id`
+    /// and the covariance eigenvalues (all NaN if the covariance isn't finite).
+    pub fn check_numerics(
+        track_id: Uuid,
+        state: &Vector6<f64>,
+        covariance: &Matrix6<f64>,
+    ) -> Result<(), TrackingError> {
+        if state.iter().all(|v| v.is_finite()) && is_positive_definite(covariance) {
+            return Ok(());
+        }
+        let eigenvalues = if covariance.iter().all(|v| v.is_finite()) {
+            let mut values: Vec<f64> = covariance.symmetric_eigenvalues().iter().copied().collect();
+            values.sort_by(f64::total_cmp);
+            values
+        } else {
+            vec![f64::NAN; 6]
+        };
+        Err(TrackingError::NumericalInstability { track_id, eigenvalues })
+    }
+    
     /// Fuse an incoming packet with an existing track using Covariance Intersection.
     /// 
     /// This also applies the Highlander heuristic for ID resolution and updates
@@ -614,6 +2139,9 @@ impl TrackManager {
     /// If the canonical_id changes (when the incoming packet has a smaller UUID),
     /// the track will be rekeyed in the HashMap.
     /// 
+    /// The measurement covariance is scaled by `inflation` (1.0 leaves it as
+    /// the packet's confidence implies).
+    /// 
     /// Returns the new canonical_id (which may have changed due to Highlander merge).
     fn fuse_track(
         &mut self,
@@ -621,6 +2149,7 @@ impl TrackManager {
         packet: &GlobalHazardPacket,
         adaptive_state: Option<&AdaptiveState>,
         neighbor_id: Option<usize>,
+        inflation: f64,
     ) -> Result<Uuid, TrackingError> {
         // Get the track
         let track = self.tracks.get(&track_id)
@@ -628,6 +2157,7 @@ impl TrackManager {
         
         let old_cell = track.h3_cell;
         let old_canonical_id = track.canonical_id;
+        let old_velocity = track.velocity();
         
         // Construct measurement state vector
         let x_meas = Vector6::new(
@@ -639,35 +2169,98 @@ impl TrackManager {
             packet.velocity[2],
         );
         
-        // Construct measurement covariance
-        let p_meas = self.confidence_to_covariance(packet.confidence_score);
-        
-        // Perform Covariance Intersection
-        let (x_fused, p_fused) = Self::covariance_intersection(
-            &track.state,
-            &track.covariance,
-            &x_meas,
-            &p_meas,
-        ).ok_or(TrackingError::SingularCovariance)?;
+        let model = packet.measurement_model;
+        let (x_fused, p_fused) = if model.is_full_state() {
+            // Construct measurement covariance, down-weighting far-off
+            // measurements in robust mode
+            let mut p_meas = self.confidence_to_covariance(packet.confidence_score);
+            if let Some(robust) = self.config.robust {
+                p_meas *= robust.inflation(self.mahalanobis_distance_squared(track, packet));
+            }
+            p_meas *= inflation;
+            
+            // A NaN or an indefinite covariance on either side would spread
+            // through CI into every later estimate of this track
+            Self::check_numerics(track_id, &track.state, &track.covariance)?;
+            Self::check_numerics(track_id, &x_meas, &p_meas)?;
+            
+            // Perform Covariance Intersection
+            Self::covariance_intersection(
+                &track.state,
+                &track.covariance,
+                &x_meas,
+                &p_meas,
+            ).ok_or(TrackingError::SingularCovariance)?
+        } else {
+            Self::check_numerics(track_id, &track.state, &track.covariance)?;
+            let innovation = self.innovation(track, packet)
+                .ok_or(TrackingError::SingularCovariance)?;
+            let mut noise = innovation.noise;
+            if let Some(robust) = self.config.robust {
+                noise *= robust.inflation(self.mahalanobis_distance_squared(track, packet));
+            }
+            noise *= inflation;
+            if !innovation.residual.iter().chain(noise.iter()).all(|v| v.is_finite()) {
+                return Err(TrackingError::NumericalInstability {
+                    track_id,
+                    eigenvalues: vec![f64::NAN; noise.nrows()],
+                });
+            }
+            Self::kalman_update(
+                &track.state,
+                &track.covariance,
+                &innovation.residual,
+                &innovation.h,
+                &noise,
+            ).ok_or(TrackingError::SingularCovariance)?
+        };
         
         // Compute new cell for potentially updated position
         let new_cell = self.position_to_cell(x_fused[0], x_fused[1])?;
         
+        let confirm_hits = self.config.lifecycle.as_ref()
+            .map_or(1, |l| l.for_class(track.class_id).confirm_hits);
+        
         // Now we can mutably borrow the track
         let track = self.tracks.get_mut(&track_id).unwrap();
         
+        if self.config.class_gate.is_some() {
+            track.record_class_vote(packet.class_id);
+        }
+        track.priority = track.priority.max(packet.priority);
+        
+        // Credit the sender with the uncertainty this fusion removed
+        if let (Some(max_contributors), Some(nid)) = (self.config.provenance, neighbor_id) {
+            let information = (track.covariance.trace() - p_fused.trace()).max(0.0);
+            track.record_contribution(nid, information, max_contributors);
+        }
+        
         // Update state and covariance
         track.state = x_fused;
         track.covariance = p_fused;
         track.last_update = packet.timestamp;
+        // One hit per cycle, however many packets land in it
+        if track.age > 0 {
+            track.hits += 1;
+        }
         track.age = 0;
+        if track.track_state == TrackState::Coasting
+            || (track.track_state == TrackState::Tentative && track.hits >= confirm_hits) {
+            track.track_state = TrackState::Confirmed;
+        }
         track.h3_cell = new_cell;
         
         // --- Blind Fitness Instrumentation (Peer Agreement) ---
         // If this packet came from a neighbor, calculate weighted disagreement
         if let (Some(state), Some(nid)) = (adaptive_state, neighbor_id) {
-             // 1. Calculate distance d_ij (Euclidean distance between track and packet)
-             let dist = (track.state - x_meas).norm();
+             // 1. Calculate distance d_ij (Euclidean distance between track and
+             //    packet, in the packet's measurement space)
+             let dist = if model.is_full_state() {
+                 (track.state - x_meas).norm()
+             } else {
+                 model.observe(&track.state)
+                     .map_or(0.0, |(expected, _)| model.residual(&model.measurement(packet), &expected).norm())
+             };
              
              // 2. Get neighbor reputation weight w_ij
              // We access the reputation from the passed AdaptiveState
@@ -692,29 +2285,47 @@ impl TrackManager {
         }
         // ----------------------------------------------------
 
-        // Stage 3: Highlander ID Resolution
-        track.merge_id(packet.entity_id);
+        // Stage 3: Highlander ID Resolution (quarantined IDs can't win),
+        // once a new ID is confirmed if confirmation is configured
+        let confirmed = match &self.config.merge_confirmation {
+            Some(confirmation) if !track.observed_ids.contains(&packet.entity_id) => {
+                Some(track.confirm_merge(packet, &old_velocity, confirmation, &mut self.merge_stats))
+            }
+            _ => None,
+        };
+        if confirmed != Some(false) {
+            if self.quarantine.is_empty() {
+                track.merge_id(packet.entity_id);
+            } else {
+                track.merge_id_excluding(packet.entity_id, &self.quarantine);
+            }
+        }
         let new_canonical_id = track.canonical_id;
+        if confirmed == Some(true) {
+            let hits = self.config.merge_confirmation.map_or(1, |c| c.hits);
+            self.merge_events.push(MergeEvent {
+                winner_id: new_canonical_id,
+                loser_id: if new_canonical_id == packet.entity_id { old_canonical_id } else { packet.entity_id },
+                merge_position: [x_fused[0], x_fused[1], x_fused[2]],
+                reason: format!("{hits} associations with agreeing velocity"),
+                timestamp: packet.timestamp,
+            });
+        }
         
         // Update spatial index if cell changed
-        if old_cell != new_cell {
-            self.spatial_index_remove(old_cell, track_id);
-            self.spatial_index_insert(new_cell, track_id);
-        }
+        self.reindex_track(track_id, old_cell, new_cell);
         
         // Critical: If canonical_id changed, we need to rekey the track in the HashMap
         // and update the spatial index to point to the new key
         if new_canonical_id != old_canonical_id {
-            // Remove track from HashMap, update its key, and reinsert
-            if let Some(track) = self.tracks.remove(&track_id) {
-                // Update spatial index with new key
-                self.spatial_index_remove(track.h3_cell, track_id);
-                self.spatial_index_insert(track.h3_cell, new_canonical_id);
-                
-                // Reinsert track under new canonical_id
-                self.tracks.insert(new_canonical_id, track);
-            }
+            self.rekey_track(track_id, new_canonical_id);
         }
+        debug_assert!(
+            self.fusion_indexed_consistently(new_canonical_id, [old_cell, new_cell]),
+            "spatial index out of sync after fusing track {}: {:?}",
+            track_id,
+            self.verify_spatial_index(),
+        );
         
         // Return the new canonical_id (may have changed due to Highlander merge)
         Ok(new_canonical_id)
@@ -733,15 +2344,43 @@ impl TrackManager {
         packet: &GlobalHazardPacket,
         adaptive_state: Option<&AdaptiveState>,
         neighbor_id: Option<usize>
+    ) -> Result<Uuid, TrackingError> {
+        self.process_packet_inflated(packet, adaptive_state, neighbor_id, 1.0)
+    }
+    
+    /// [`Self::process_packet`], with the packet's measurement covariance
+    /// scaled by `inflation` when it fuses into an existing track.
+    /// 
+    /// Callers pass a trust-derived factor (see
+    /// [`AdaptiveState::covariance_inflation`]) so that gossip from a doubtful
+    /// neighbor pulls tracks less; new tracks are created as usual.
+    pub fn process_packet_inflated(
+        &mut self,
+        packet: &GlobalHazardPacket,
+        adaptive_state: Option<&AdaptiveState>,
+        neighbor_id: Option<usize>,
+        inflation: f64,
     ) -> Result<Uuid, TrackingError> {
         // Stages 1 & 2: Find association
-        match self.find_association(packet)? {
-            Some(track_id) => {
+        match self.search_association(packet)? {
+            Some((track_id, wide)) => {
+                if wide {
+                    self.wide_associations += 1;
+                }
                 // Stages 3 & 4: Fuse with existing track
                 // fuse_track returns the (possibly updated) canonical_id
-                let canonical_id = self.fuse_track(track_id, packet, adaptive_state, neighbor_id)?;
+                let canonical_id = self.fuse_track(track_id, packet, adaptive_state, neighbor_id, inflation)?;
                 Ok(canonical_id)
             }
+            // Robust mode: a gate miss on a track that already carries this
+            // UUID is an outlier from the same source, not a new object.
+            // Fuse it down-weighted rather than letting create_track replace
+            // the track with the outlier.
+            None if self.config.robust.is_some() && self.tracks.get(&packet.entity_id)
+                .is_some_and(|t| self.class_penalty(t.class_id, packet.class_id).is_some()) =>
+            {
+                self.fuse_track(packet.entity_id, packet, adaptive_state, neighbor_id, inflation)
+            }
             None => {
                 // No match: Create new track
                 self.create_track(packet)
@@ -751,15 +2390,212 @@ impl TrackManager {
     
     /// Process multiple packets (batch processing).
     /// 
-    /// Returns a vector of (result, original_packet_entity_id) tuples.
+    /// In [`AssociationMode::Batch`] the batch is associated in one pass (see
+    /// [`Self::process_batch`]); in `Sequential` mode each packet goes through
+    /// [`Self::process_packet`] in arrival order.
+    /// 
+    /// Returns a vector of (result, original_packet_entity_id) tuples, in input order.
     pub fn process_packets(&mut self, packets: &[GlobalHazardPacket]) -> Vec<(Result<Uuid, TrackingError>, Uuid)> {
-        packets
-            .iter()
-            .map(|packet| (self.process_packet(packet, None, None), packet.entity_id))
+        match self.config.association {
+            AssociationMode::Batch => self.process_batch(packets),
+            AssociationMode::Sequential => packets
+                .iter()
+                .map(|packet| (self.process_packet(packet, None, None), packet.entity_id))
+                .collect(),
+        }
+    }
+    
+    /// Associate a batch of simultaneous measurements in a single pass.
+    /// 
+    /// 1. Every packet is gated against the tracks as they were before the batch
+    /// 2. Packets are assigned to tracks one-to-one by GNN: the Hungarian
+    ///    algorithm on the Mahalanobis cost matrix, where leaving a packet
+    ///    unassigned costs the gating threshold
+    /// 3. Assigned packets are fused in entity_id order
+    /// 4. The rest run the single-packet pipeline in entity_id order, so a
+    ///    second measurement of an object fuses into the track the first one
+    ///    updated or created instead of spawning a duplicate
+    /// 
+    /// Shuffling the batch leaves the resulting track state bit-for-bit the same.
+    pub fn process_batch(&mut self, packets: &[GlobalHazardPacket]) -> Vec<(Result<Uuid, TrackingError>, Uuid)> {
+        let mut order: Vec<usize> = (0..packets.len()).collect();
+        order.sort_by(|&a, &b| canonical_packet_order(&packets[a], &packets[b]));
+        
+        // Stages 1 & 2 for the whole batch, against the pre-batch tracks
+        let mut results: Vec<Option<Result<Uuid, TrackingError>>> = vec![None; packets.len()];
+        let mut rows = Vec::new();
+        for &i in &order {
+            let packet = &packets[i];
+            if !packet.measurement_model.is_positional() {
+                // Left to the single-packet pipeline below
+                continue;
+            }
+            match self.position_to_cell(packet.position[0], packet.position[1]) {
+                Ok(cell) => {
+                    let gated = self.gate_candidates(&self.association_candidates(packet, cell), packet);
+                    if !gated.is_empty() {
+                        rows.push((i, cell, gated));
+                    }
+                }
+                Err(e) => results[i] = Some(Err(e)),
+            }
+        }
+        
+        // Cost matrix: one column per gated track, then one "unassigned"
+        // column per packet
+        let mut columns: Vec<Uuid> = rows.iter()
+            .flat_map(|(_, _, gated)| gated.iter().map(|(id, _)| *id))
+            .collect();
+        columns.sort();
+        columns.dedup();
+        let width = columns.len() + rows.len();
+        let cost: Vec<Vec<f64>> = rows.iter()
+            .enumerate()
+            .map(|(r, (_, _, gated))| {
+                let mut row = vec![UNGATED_COST; width];
+                for (id, d_squared) in gated {
+                    if let Ok(col) = columns.binary_search(id) {
+                        row[col] = *d_squared;
+                    }
+                }
+                row[columns.len() + r] = self.config.gating_threshold;
+                row
+            })
+            .collect();
+        
+        // Stages 3 & 4 for the assigned packets (rows are in canonical order)
+        for ((i, cell, _), col) in rows.iter().zip(solve_assignment(&cost)) {
+            if let Some(&track_id) = columns.get(col) {
+                if self.is_wide_ring_match(track_id, &packets[*i], *cell) {
+                    self.wide_associations += 1;
+                }
+                results[*i] = Some(self.fuse_track(track_id, &packets[*i], None, None, 1.0));
+            }
+        }
+        
+        // Everything else against the updated tracks, in canonical order
+        for &i in &order {
+            if results[i].is_none() {
+                results[i] = Some(self.process_packet(&packets[i], None, None));
+            }
+        }
+        
+        results.into_iter()
+            .zip(packets)
+            .map(|(result, packet)| (result.expect("every packet is processed"), packet.entity_id))
             .collect()
     }
 }
 
+/// `1 - confidence`, clamped to avoid division by zero or negative variance.
+fn inverse_confidence(confidence: f64) -> f64 {
+    (1.0 - confidence).clamp(0.01, 1.0)
+}
+
+/// Whether a covariance is finite and positive-definite (has a Cholesky
+/// factor).
+fn is_positive_definite(covariance: &Matrix6<f64>) -> bool {
+    covariance.iter().all(|v| v.is_finite()) && covariance.cholesky().is_some()
+}
+
+/// Cost of a packet/track pair that failed gating; never chosen over
+/// leaving the packet unassigned.
+const UNGATED_COST: f64 = 1e9;
+
+/// Total order on packets by content, starting with entity_id.
+fn canonical_packet_order(a: &GlobalHazardPacket, b: &GlobalHazardPacket) -> std::cmp::Ordering {
+    let floats = |p: &GlobalHazardPacket| {
+        let (model, origin) = match p.measurement_model {
+            MeasurementModel::PositionVelocity6D => (0, [0.0; 3]),
+            MeasurementModel::Position3D => (1, [0.0; 3]),
+            MeasurementModel::BearingOnly { origin } => (2, origin),
+            MeasurementModel::RangeDoppler { origin } => (3, origin),
+        };
+        [p.timestamp, p.position[0], p.position[1], p.position[2],
+         p.velocity[0], p.velocity[1], p.velocity[2], p.confidence_score,
+         model as f64, origin[0], origin[1], origin[2]]
+    };
+    a.entity_id.cmp(&b.entity_id)
+        .then(a.class_id.cmp(&b.class_id))
+        .then(a.priority.cmp(&b.priority))
+        .then_with(|| {
+            floats(a).iter().zip(floats(b).iter())
+                .map(|(x, y)| x.total_cmp(y))
+                .find(|o| o.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+}
+
+/// Minimum-cost assignment of every row to a distinct column (Hungarian
+/// algorithm with potentials, O(n²m)).
+/// 
+/// `cost` is n × m with n ≤ m. Returns the column assigned to each row.
+fn solve_assignment(cost: &[Vec<f64>]) -> Vec<usize> {
+    let n = cost.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let m = cost[0].len();
+    
+    // 1-based potentials and column owners; row 0 / column 0 are sentinels
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    let mut owner = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+    for row in 1..=n {
+        owner[0] = row;
+        let mut j0 = 0;
+        let mut min_slack = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = owner[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let slack = cost[i0 - 1][j - 1] - u[i0] - v[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = j0;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[owner[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if owner[j0] == 0 {
+                break;
+            }
+        }
+        // Flip the augmenting path
+        while j0 != 0 {
+            let j1 = way[j0];
+            owner[j0] = owner[j1];
+            j0 = j1;
+        }
+    }
+    
+    let mut assignment = vec![0; n];
+    for (j, &row) in owner.iter().enumerate().skip(1) {
+        if row != 0 {
+            assignment[row - 1] = j - 1;
+        }
+    }
+    assignment
+}
+
 // ============================================================================
 // STATISTICS
 // ============================================================================
@@ -770,6 +2606,73 @@ pub struct SpatialIndexStats {
     pub total_cells: usize,
     pub total_entries: usize,
     pub avg_per_cell: f64,
+    
+    /// Packets associated with a track more than one ring (k>1) from their
+    /// cell, by search of a wider ring rather than by UUID
+    pub wide_associations: u64,
+}
+
+/// A disagreement between a [`TrackManager`]'s spatial index and its
+/// track store, found by [`TrackManager::verify_spatial_index`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Inconsistency {
+    /// The index lists `track_id` in `cell`, but no track has that ID
+    MissingTrack { cell: CellIndex, track_id: Uuid },
+    
+    /// The index lists `track_id` in `cell`, but the track is in `track_cell`
+    WrongCell { cell: CellIndex, track_id: Uuid, track_cell: CellIndex },
+    
+    /// The track keyed `track_id` is missing from its own cell's entry
+    Unindexed { track_id: Uuid, cell: CellIndex },
+}
+
+/// Outcomes of [`MergeConfirmation`] in a [`TrackManager`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MergeStats {
+    /// IDs absorbed after confirmation
+    pub confirmed: u64,
+    
+    /// Associations whose velocity disagreed with the track's
+    pub near_misses: u64,
+    
+    /// Candidates whose window ran out before confirmation
+    pub expired: u64,
+    
+    /// Candidates pending across all tracks
+    pub pending: usize,
+}
+
+/// Track capacity usage of a [`TrackManager`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityStats {
+    /// Live tracks
+    pub track_count: usize,
+    
+    /// Configured capacity (None = unbounded)
+    pub max_tracks: Option<usize>,
+    
+    /// Tracks evicted to make room for new ones
+    pub evictions: u64,
+}
+
+/// Live tracks of a [`TrackManager`] by [`TrackState`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrackStateCounts {
+    /// Tracks not yet confirmed
+    pub tentative: usize,
+    
+    /// Confirmed tracks updated in the last cycle
+    pub confirmed: usize,
+    
+    /// Confirmed tracks missing updates
+    pub coasting: usize,
+}
+
+impl TrackStateCounts {
+    /// Tracks that are reported and shared: confirmed or coasting.
+    pub fn reportable(&self) -> usize {
+        self.confirmed + self.coasting
+    }
 }
 
 // ============================================================================
@@ -782,14 +2685,29 @@ pub enum TrackingError {
     #[error("Invalid coordinates: {0}")]
     InvalidCoordinates(String),
     
+    #[error("Latitude {0}° outside [-90°, 90°]")]
+    LatitudeOutOfRange(f64),
+    
+    #[error("Longitude {0}° outside [-180°, 180°]")]
+    LongitudeOutOfRange(f64),
+    
     #[error("Track not found: {0}")]
     TrackNotFound(Uuid),
     
     #[error("Covariance matrix is singular")]
     SingularCovariance,
     
+    #[error("Numerical instability in track {track_id}: covariance eigenvalues {eigenvalues:?}")]
+    NumericalInstability { track_id: Uuid, eigenvalues: Vec<f64> },
+    
     #[error("Gating failed: no candidates within threshold")]
     GatingFailed,
+    
+    #[error("Unsupported measurement: {0}")]
+    UnsupportedMeasurement(String),
+    
+    #[error("Invalid tracking config: {0}")]
+    InvalidConfig(String),
 }
 
 // ============================================================================
@@ -808,6 +2726,8 @@ mod tests {
             class_id: 1, // Vehicle
             timestamp: 1703001600.0,
             confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
         }
     }
     
@@ -832,6 +2752,244 @@ mod tests {
         assert_eq!(stats.total_entries, 1);
     }
     
+    fn provenance_manager(max_contributors: usize) -> TrackManager {
+        TrackManager::new(TrackingConfig { provenance: Some(max_contributors), ..TrackingConfig::default() })
+    }
+    
+    #[test]
+    fn test_provenance_sums_trace_reduction_per_neighbor() {
+        let mut manager = provenance_manager(8);
+        let first = GlobalHazardPacket { confidence_score: 0.3, ..sample_packet() };
+        let id = manager.process_packet(&first, None, None).unwrap();
+        
+        // Each packet is more confident than the track, so each one shrinks it
+        let sequence = [(Some(0), 0.4), (Some(1), 0.5), (None, 0.6), (Some(0), 0.7), (Some(2), 0.8), (Some(1), 0.9)];
+        let mut expected: BTreeMap<usize, f64> = BTreeMap::new();
+        for (neighbor, confidence) in sequence {
+            let before = manager.get_track(&id).unwrap().covariance.trace();
+            let packet = GlobalHazardPacket { confidence_score: confidence, ..first.clone() };
+            assert_eq!(manager.process_packet(&packet, None, neighbor).unwrap(), id);
+            let reduction = before - manager.get_track(&id).unwrap().covariance.trace();
+            assert!(reduction > 0.0);
+            if let Some(n) = neighbor {
+                *expected.entry(n).or_insert(0.0) += reduction;
+            }
+        }
+        
+        let track = manager.get_track(&id).unwrap();
+        assert_eq!(track.provenance.keys().collect::<Vec<_>>(), expected.keys().collect::<Vec<_>>());
+        for (n, information) in &expected {
+            assert!((track.provenance[n] - information).abs() < 1e-9, "neighbor {n}");
+        }
+        let dominant = expected.iter().max_by(|a, b| a.1.total_cmp(b.1)).map(|(&n, _)| n);
+        assert_eq!(track.dominant_contributor(), dominant);
+        
+        // Off by default
+        let mut plain = TrackManager::with_defaults();
+        let id = plain.process_packet(&first, None, None).unwrap();
+        plain.process_packet(&GlobalHazardPacket { confidence_score: 0.9, ..first.clone() }, None, Some(0)).unwrap();
+        assert!(plain.get_track(&id).unwrap().provenance.is_empty());
+        assert_eq!(plain.get_track(&id).unwrap().dominant_contributor(), None);
+    }
+    
+    #[test]
+    fn test_provenance_stays_bounded() {
+        let mut manager = provenance_manager(2);
+        let first = GlobalHazardPacket { confidence_score: 0.1, ..sample_packet() };
+        let id = manager.process_packet(&first, None, None).unwrap();
+        
+        for (neighbor, step) in (0..10).enumerate() {
+            let packet = GlobalHazardPacket { confidence_score: 0.15 + step as f64 * 0.08, ..first.clone() };
+            manager.process_packet(&packet, None, Some(neighbor)).unwrap();
+            assert!(manager.get_track(&id).unwrap().provenance.len() <= 2);
+        }
+        
+        // The newest neighbor always gets in, next to the largest earlier one
+        let track = manager.get_track(&id).unwrap();
+        assert_eq!(track.provenance.len(), 2);
+        assert!(track.provenance.contains_key(&9));
+    }
+    
+    #[test]
+    fn test_local_alias_survives_highlander_renames() {
+        let mut manager = TrackManager::new(TrackingConfig { local_aliases: true, ..TrackingConfig::default() });
+        let first = GlobalHazardPacket { entity_id: Uuid::from_u128(100), ..sample_packet() };
+        let id = manager.process_packet(&first, None, None).unwrap();
+        let far = GlobalHazardPacket { entity_id: Uuid::from_u128(200), position: [37.80, -122.45, 10.0], ..sample_packet() };
+        let other = manager.process_packet(&far, None, None).unwrap();
+        assert_eq!(manager.get_track(&id).unwrap().local_alias, Some(0));
+        assert_eq!(manager.get_track(&other).unwrap().local_alias, Some(1));
+        
+        // Ever smaller IDs arrive over gossip: the canonical ID churns, the
+        // alias stays put
+        let mut canonical = id;
+        for n in (1..10).rev().map(|n: u128| n * 10) {
+            let packet = GlobalHazardPacket { entity_id: Uuid::from_u128(n), ..first.clone() };
+            let renamed = manager.process_packet(&packet, None, Some(1)).unwrap();
+            assert_ne!(renamed, canonical);
+            canonical = renamed;
+            assert_eq!(manager.get_track(&canonical).unwrap().local_alias, Some(0));
+        }
+        assert_eq!(manager.track_by_alias(0).unwrap().canonical_id, Uuid::from_u128(10));
+        assert_eq!(manager.track_by_alias(1).unwrap().canonical_id, other);
+        
+        // Off by default
+        let mut plain = TrackManager::with_defaults();
+        let id = plain.process_packet(&first, None, None).unwrap();
+        assert_eq!(plain.get_track(&id).unwrap().local_alias, None);
+    }
+    
+    #[test]
+    fn test_robust_weight_monotonic() {
+        let robust = RobustFusion { soft_threshold: 2.0, max_inflation: 10.0 };
+        
+        let mut last = f64::INFINITY;
+        for i in 0..200 {
+            let d = i as f64 * 0.25;
+            let w = robust.weight(d * d);
+            assert!(w <= last, "weight rose at d={}", d);
+            assert!(w > 0.0 && w <= 1.0);
+            last = w;
+        }
+        
+        assert_eq!(robust.weight(1.9 * 1.9), 1.0);
+        assert!((robust.inflation(4.0 * 4.0) - 2.0).abs() < 1e-12);
+        // Capped far out
+        assert_eq!(robust.inflation(1e6), 10.0);
+    }
+    
+    #[test]
+    fn test_robust_fusion_leaves_inliers_unchanged() {
+        let robust = RobustFusion::default();
+        let mut plain = TrackManager::with_defaults();
+        let mut huber = TrackManager::new(TrackingConfig { robust: Some(robust), ..TrackingConfig::default() });
+        
+        let first = sample_packet();
+        let mut inlier = first.clone();
+        inlier.position[2] += 0.5;
+        
+        let id_plain = plain.process_packet(&first, None, None).unwrap();
+        let id_huber = huber.process_packet(&first, None, None).unwrap();
+        let d2 = plain.mahalanobis_distance_squared(plain.get_track(&id_plain).unwrap(), &inlier);
+        assert!(d2.sqrt() < robust.soft_threshold);
+        
+        plain.process_packet(&inlier, None, None).unwrap();
+        huber.process_packet(&inlier, None, None).unwrap();
+        let a = plain.get_track(&id_plain).unwrap();
+        let b = huber.get_track(&id_huber).unwrap();
+        assert_eq!(a.state, b.state);
+        assert_eq!(a.covariance, b.covariance);
+    }
+    
+    #[test]
+    fn test_robust_fusion_damps_outliers() {
+        let robust = RobustFusion::default();
+        let mut plain = TrackManager::with_defaults();
+        let mut huber = TrackManager::new(TrackingConfig { robust: Some(robust), ..TrackingConfig::default() });
+        
+        let first = sample_packet();
+        let mut outlier = first.clone();
+        outlier.position[2] += 6.0;
+        
+        let id_plain = plain.process_packet(&first, None, None).unwrap();
+        let id_huber = huber.process_packet(&first, None, None).unwrap();
+        let d2 = plain.mahalanobis_distance_squared(plain.get_track(&id_plain).unwrap(), &outlier);
+        assert!(d2 < plain.config.gating_threshold && d2.sqrt() > robust.soft_threshold);
+        
+        plain.process_packet(&outlier, None, None).unwrap();
+        huber.process_packet(&outlier, None, None).unwrap();
+        let pulled_plain = plain.get_track(&id_plain).unwrap().state[2] - first.position[2];
+        let pulled_huber = huber.get_track(&id_huber).unwrap().state[2] - first.position[2];
+        assert!(pulled_huber > 0.0 && pulled_huber < pulled_plain);
+    }
+    
+    #[test]
+    fn test_robust_fusion_keeps_track_on_gate_miss() {
+        let mut plain = TrackManager::with_defaults();
+        let mut huber = TrackManager::new(TrackingConfig { robust: Some(RobustFusion::default()), ..TrackingConfig::default() });
+        
+        let first = sample_packet();
+        let mut wild = first.clone();
+        wild.position[2] += 200.0;
+        
+        let id = plain.process_packet(&first, None, None).unwrap();
+        huber.process_packet(&first, None, None).unwrap();
+        plain.process_packet(&wild, None, None).unwrap();
+        huber.process_packet(&wild, None, None).unwrap();
+        
+        // Plain mode re-creates the track at the outlier; robust mode barely moves
+        assert_eq!(plain.get_track(&id).unwrap().state[2], wild.position[2]);
+        let moved = huber.get_track(&id).unwrap().state[2] - first.position[2];
+        assert!(moved > 0.0 && moved < 10.0, "moved {}m", moved);
+        assert_eq!(huber.track_count(), 1);
+    }
+    
+    #[test]
+    fn test_white_noise_acceleration_noise_is_correlated() {
+        let dt = 0.1;
+        let cv = FilterTuning::default().process_noise(dt);
+        let wna = FilterTuning { motion_model: MotionModel::WhiteNoiseAcceleration, ..FilterTuning::default() }
+            .process_noise(dt);
+        
+        assert_eq!(cv[(0, 3)], 0.0);
+        assert!((wna[(0, 3)] - dt * dt / 2.0).abs() < 1e-12);
+        assert!((wna[(0, 0)] - dt.powi(3) / 3.0).abs() < 1e-12);
+        assert!((wna[(3, 3)] - dt).abs() < 1e-12);
+        assert_eq!(wna, wna.transpose());
+    }
+    
+    #[test]
+    fn test_predict_tracks_is_noop_without_motion() {
+        let mut manager = TrackManager::with_defaults();
+        let id = manager.process_packet(&sample_packet(), None, None).unwrap();
+        let before = manager.get_track(&id).unwrap().state;
+        
+        manager.predict_tracks(1.0);
+        assert_eq!(manager.get_track(&id).unwrap().state, before);
+        
+        let mut manager = TrackManager::new(TrackingConfig {
+            motion: Some(FilterTuning::default()),
+            ..TrackingConfig::default()
+        });
+        let id = manager.process_packet(&sample_packet(), None, None).unwrap();
+        let p0 = manager.get_track(&id).unwrap().covariance.trace();
+        manager.predict_tracks(1.0);
+        let track = manager.get_track(&id).unwrap();
+        assert_ne!(track.state, before);
+        assert!(track.covariance.trace() > p0);
+    }
+    
+    #[test]
+    fn test_wgs84_prediction_moves_tracks_by_meters() {
+        // 10 s at 6 m/s north and 8 m/s east from San Francisco, once as
+        // [lat, lon] and [north, east] and once in ENU meters around it
+        let (lat, lon) = (37.7749, -122.4194);
+        let predicted = |frame: Frame, position: [f64; 3], velocity: [f64; 3]| {
+            let mut manager = TrackManager::new(TrackingConfig {
+                motion: Some(FilterTuning::default()),
+                frame,
+                ..TrackingConfig::default()
+            });
+            let packet = GlobalHazardPacket { position, velocity, ..sample_packet() };
+            let id = manager.process_packet(&packet, None, None).unwrap();
+            for _ in 0..100 {
+                manager.predict_tracks(0.1);
+            }
+            assert_eq!(manager.verify_spatial_index(), Vec::new());
+            let track = manager.get_track(&id).unwrap().clone();
+            (frame.to_latlng(track.state[0], track.state[1]).unwrap(), track.h3_cell)
+        };
+        let (wgs84, wgs84_cell) = predicted(Frame::Wgs84, [lat, lon, 10.0], [6.0, 8.0, 0.0]);
+        let (enu, enu_cell) = predicted(Frame::local_enu(lat, lon).unwrap(), [0.0, 0.0, 10.0], [8.0, 6.0, 0.0]);
+        
+        // About 60 m north (0.00054°) and 80 m east (0.00091° at 37.77°N)
+        assert!((wgs84.lat() - lat - 0.00054).abs() < 0.00001, "latitude moved {}°", wgs84.lat() - lat);
+        assert!((wgs84.lng() - lon - 0.00091).abs() < 0.00001, "longitude moved {}°", wgs84.lng() - lon);
+        assert!((wgs84.lat() - enu.lat()).abs() < 1e-8 && (wgs84.lng() - enu.lng()).abs() < 1e-8,
+            "WGS84 prediction {wgs84:?} vs ENU {enu:?}");
+        assert_eq!(wgs84_cell, enu_cell);
+    }
+    
     #[test]
     fn test_spatial_query_kring() {
         let mut manager = TrackManager::with_defaults();
@@ -878,14 +3036,345 @@ mod tests {
     }
     
     #[test]
-    fn test_confidence_to_covariance() {
-        let manager = TrackManager::with_defaults();
+    fn test_quarantined_id_cannot_win_highlander() {
+        let mut manager = TrackManager::with_defaults();
+        let legit = sample_packet();
+        let legit_id = manager.process_packet(&legit, None, None).unwrap();
+        
+        // Poisoned near-nil UUID captures the track before quarantine
+        let poison_id = Uuid::from_u128(1);
+        let poison = GlobalHazardPacket { entity_id: poison_id, ..legit.clone() };
+        assert_eq!(manager.process_packet(&poison, None, None).unwrap(), poison_id);
+        
+        // Quarantine rekeys the track back onto the legitimate ID
+        assert!(manager.quarantine_id(poison_id));
+        assert!(!manager.quarantine_id(poison_id));
+        assert!(manager.get_track(&poison_id).is_none());
+        let track = manager.get_track(&legit_id).unwrap();
+        assert!(track.observed_ids.contains(&poison_id));
+        
+        // Re-gossiping the poisoned ID no longer moves the canonical ID
+        assert_eq!(manager.process_packet(&poison, None, None).unwrap(), legit_id);
+        
+        // A larger, non-quarantined ID still loses to the legitimate one and
+        // a smaller one still wins
+        let other = GlobalHazardPacket { entity_id: Uuid::max(), ..legit.clone() };
+        assert_eq!(manager.process_packet(&other, None, None).unwrap(), legit_id);
+        let smaller = GlobalHazardPacket { entity_id: Uuid::from_u128(2), ..legit };
+        assert_eq!(manager.process_packet(&smaller, None, None).unwrap(), Uuid::from_u128(2));
+        assert_eq!(manager.track_count(), 1);
+    }
+    
+    #[test]
+    fn test_quarantine_rekey_fuses_colliding_track() {
+        let mut manager = TrackManager::with_defaults();
+        let a = Uuid::from_u128(1);
+        let b = Uuid::from_u128(2);
+        
+        // T1 is keyed by A and has also seen B
+        let near = GlobalHazardPacket { entity_id: a, ..sample_packet() };
+        manager.process_packet(&near, None, None).unwrap();
+        let merged = GlobalHazardPacket { entity_id: b, ..near.clone() };
+        assert_eq!(manager.process_packet(&merged, None, None).unwrap(), a);
+        let t1 = manager.get_track(&a).unwrap().clone();
+        
+        // T2 is keyed by B a few hundred meters off, in another cell
+        let off = GlobalHazardPacket { entity_id: b, position: [37.7780, -122.4150, 10.0], confidence_score: 0.6, ..near };
+        manager.create_track(&off).unwrap();
+        let t2 = manager.get_track(&b).unwrap().clone();
+        assert_ne!(t1.h3_cell, t2.h3_cell);
+        assert_eq!(manager.track_count(), 2);
+        
+        // Quarantining A falls T1 back onto B: the two are fused, not
+        // one thrown away
+        assert!(manager.quarantine_id(a));
+        assert_eq!(manager.verify_spatial_index(), Vec::new());
+        assert_eq!(manager.track_count(), 1);
+        assert_eq!(manager.spatial_stats().total_entries, 1);
+        let track = manager.get_track(&b).unwrap();
+        assert!(track.observed_ids.contains(&a) && track.observed_ids.contains(&b));
+        let (lo, hi) = (t1.state[0].min(t2.state[0]), t1.state[0].max(t2.state[0]));
+        assert!((lo..=hi).contains(&track.state[0]), "fused latitude {} outside [{lo}, {hi}]", track.state[0]);
+        assert!(track.covariance.trace() < t1.covariance.trace().max(t2.covariance.trace()));
+        assert_eq!(track.hits, t1.hits.max(t2.hits));
+        
+        let events = manager.take_merge_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!((events[0].winner_id, events[0].loser_id), (b, a));
+        assert_eq!(events[0].merge_position, [t1.state[0], t1.state[1], t1.state[2]]);
+    }
+    
+    #[test]
+    fn test_verify_and_rebuild_spatial_index() {
+        let mut manager = TrackManager::with_defaults();
+        let first = sample_packet();
+        let second = GlobalHazardPacket { position: [40.7128, -74.0060, 10.0], ..sample_packet() };
+        let first_id = manager.create_track(&first).unwrap();
+        let second_id = manager.create_track(&second).unwrap();
+        assert!(manager.verify_spatial_index().is_empty());
+        
+        // Moving a track through get_track_mut bypasses the index
+        let first_cell = manager.get_track(&first_id).unwrap().h3_cell;
+        let second_cell = manager.get_track(&second_id).unwrap().h3_cell;
+        manager.get_track_mut(&first_id).unwrap().h3_cell = second_cell;
+        let ghost = Uuid::from_u128(7);
+        manager.spatial_index_insert(second_cell, ghost);
+        
+        let mut expected = vec![
+            Inconsistency::MissingTrack { cell: second_cell, track_id: ghost },
+            Inconsistency::WrongCell { cell: first_cell, track_id: first_id, track_cell: second_cell },
+            Inconsistency::Unindexed { track_id: first_id, cell: second_cell },
+        ];
+        expected.sort();
+        assert_eq!(manager.verify_spatial_index(), expected);
+        
+        manager.rebuild_spatial_index();
+        assert!(manager.verify_spatial_index().is_empty());
+        assert_eq!(manager.spatial_stats().total_entries, 2);
+        assert!(!manager.spatial_index.contains_key(&first_cell));
+    }
+    
+    #[test]
+    fn test_quarantined_tracks_age_out_early() {
+        let mut manager = TrackManager::new(TrackingConfig {
+            max_age: 10,
+            quarantine_max_age: 2,
+            ..Default::default()
+        });
+        
+        let legit = sample_packet();
+        let ghost = GlobalHazardPacket {
+            entity_id: Uuid::from_u128(1),
+            position: [-33.8688, 151.2093, 10.0], // Sydney
+            ..legit.clone()
+        };
+        manager.process_packet(&legit, None, None).unwrap();
+        manager.process_packet(&ghost, None, None).unwrap();
+        manager.quarantine_id(ghost.entity_id);
+        
+        manager.age_tracks();
+        manager.age_tracks();
+        assert_eq!(manager.track_count(), 1);
+        assert!(manager.get_track(&legit.entity_id).is_some());
+    }
+    
+    fn lifecycle_manager() -> TrackManager {
+        TrackManager::new(TrackingConfig {
+            lifecycle: Some(LifecycleConfig::uniform(TrackLifecycle {
+                confirm_hits: 3,
+                confirm_window: 5,
+                coast_cycles: 4,
+            }).with_class(2, TrackLifecycle { confirm_hits: 1, ..TrackLifecycle::default() })),
+            ..Default::default()
+        })
+    }
+    
+    #[test]
+    fn test_spurious_packet_never_confirms() {
+        let mut manager = lifecycle_manager();
+        let ghost = sample_packet();
+        
+        // Repeats within the creation cycle are one hit
+        for _ in 0..5 {
+            manager.process_packet(&ghost, None, None).unwrap();
+        }
+        for cycle in 1..5 {
+            manager.age_tracks();
+            let track = manager.get_track(&ghost.entity_id).unwrap();
+            assert!(track.is_tentative(), "confirmed after {cycle} cycles");
+            assert_eq!(track.hits, 1);
+        }
+        assert_eq!(manager.state_counts(), TrackStateCounts { tentative: 1, confirmed: 0, coasting: 0 });
+        
+        manager.age_tracks();
+        assert_eq!(manager.track_count(), 0);
+        
+        // Classes configured to confirm on one hit skip the tentative state
+        let pedestrian = GlobalHazardPacket { class_id: 2, ..sample_packet() };
+        manager.process_packet(&pedestrian, None, None).unwrap();
+        assert_eq!(manager.get_track(&pedestrian.entity_id).unwrap().track_state, TrackState::Confirmed);
+    }
+    
+    #[test]
+    fn test_tracked_entity_confirms_then_coasts() {
+        let mut manager = lifecycle_manager();
+        let at = |cycle: u32| GlobalHazardPacket {
+            entity_id: Uuid::from_u128(7),
+            position: [37.7749 + cycle as f64 * 1e-6, -122.4194, 10.0],
+            timestamp: 1703001600.0 + cycle as f64 / 30.0,
+            ..sample_packet()
+        };
+        let id = Uuid::from_u128(7);
+        let state = |manager: &TrackManager| manager.get_track(&id).unwrap().track_state;
+        
+        // A 30 Hz entity confirms on its third cycle
+        manager.process_packet(&at(0), None, None).unwrap();
+        for cycle in 1..3 {
+            assert_eq!(state(&manager), TrackState::Tentative);
+            manager.age_tracks();
+            manager.process_packet(&at(cycle), None, None).unwrap();
+        }
+        assert_eq!(state(&manager), TrackState::Confirmed);
+        assert_eq!(manager.get_track(&id).unwrap().hits, 3);
+        
+        // Missing a whole cycle coasts, and an update confirms it again
+        manager.age_tracks();
+        assert_eq!(state(&manager), TrackState::Confirmed);
+        manager.age_tracks();
+        assert_eq!(state(&manager), TrackState::Coasting);
+        assert_eq!(manager.state_counts().reportable(), 1);
+        manager.process_packet(&at(5), None, None).unwrap();
+        assert_eq!(state(&manager), TrackState::Confirmed);
+        
+        // Coasting ends in deletion after coast_cycles without an update
+        for _ in 0..3 {
+            manager.age_tracks();
+        }
+        assert_eq!(state(&manager), TrackState::Coasting);
+        manager.age_tracks();
+        assert_eq!(manager.track_count(), 0);
+    }
+    
+    #[test]
+    fn test_max_tracks_evicts_stale_uncertain_tracks_first() {
+        let mut manager = TrackManager::new(TrackingConfig {
+            max_tracks: Some(2),
+            ..Default::default()
+        });
+        let at = |id: u128, lat: f64, confidence_score: f64| GlobalHazardPacket {
+            entity_id: Uuid::from_u128(id),
+            position: [lat, 10.0, 10.0],
+            confidence_score,
+            ..sample_packet()
+        };
+        
+        manager.process_packet(&at(1, 10.0, 0.99), None, None).unwrap();
+        manager.process_packet(&at(2, 20.0, 0.1), None, None).unwrap();
+        manager.age_tracks();
+        
+        // Same age; the low-confidence track has the larger covariance
+        manager.process_packet(&at(3, 30.0, 0.9), None, None).unwrap();
+        assert!(manager.get_track(&Uuid::from_u128(2)).is_none());
+        assert!(manager.get_track(&Uuid::from_u128(1)).is_some());
+        assert_eq!(manager.capacity_stats(), CapacityStats {
+            track_count: 2,
+            max_tracks: Some(2),
+            evictions: 1,
+        });
+    }
+    
+    #[test]
+    fn test_memory_estimate_follows_track_lifecycle() {
+        let mut manager = TrackManager::new(TrackingConfig {
+            frame: Frame::local_enu(37.7749, -122.4194).unwrap(),
+            max_tracks: Some(50),
+            max_age: 3,
+            ..TrackingConfig::default()
+        });
+        let empty = manager.memory_estimate_bytes();
+        
+        let per_track = std::mem::size_of::<UniqueTrack>() + 2 * std::mem::size_of::<Uuid>();
+        for id in 0..50 {
+            manager.process_packet(&enu_packet(id, id as f64 * 1000.0, 0.0), None, None).unwrap();
+        }
+        let full = manager.memory_estimate_bytes();
+        let grown = full - empty;
+        assert!(grown >= 50 * per_track && grown <= 50 * (per_track + 128), "{grown} bytes for 50 tracks");
+        
+        // Evicting to stay at capacity holds the estimate steady
+        for id in 50..100 {
+            manager.process_packet(&enu_packet(id, id as f64 * 1000.0, 0.0), None, None).unwrap();
+        }
+        assert_eq!(manager.capacity_stats().evictions, 50);
+        let churned = manager.memory_estimate_bytes();
+        assert!(churned.abs_diff(full) <= full / 20, "{churned} vs {full}");
+        
+        // Aging everything out returns to the empty footprint
+        for _ in 0..3 {
+            manager.age_tracks();
+        }
+        assert_eq!(manager.track_count(), 0);
+        assert_eq!(manager.memory_estimate_bytes(), empty);
+    }
+    
+    #[test]
+    fn test_max_tracks_bounds_memory_under_garbage_flood() {
+        let run = || {
+            let mut manager = TrackManager::new(TrackingConfig {
+                max_tracks: Some(500),
+                ..Default::default()
+            });
+            for i in 0..10_000u32 {
+                // Every packet lands in its own cell, so nothing associates
+                let packet = GlobalHazardPacket {
+                    entity_id: Uuid::from_u128(i as u128 + 1),
+                    position: [-60.0 + (i / 100) as f64 * 1.2, -170.0 + (i % 100) as f64 * 3.4, 10.0],
+                    timestamp: i as f64,
+                    ..sample_packet()
+                };
+                manager.process_packet(&packet, None, None).unwrap();
+                assert!(manager.track_count() <= 500);
+                assert!(manager.spatial_stats().total_entries <= 500);
+                if (i + 1) % 100 == 0 {
+                    manager.age_tracks();
+                }
+            }
+            manager
+        };
+        
+        let manager = run();
+        assert_eq!(manager.capacity_stats().evictions, 9_500);
+        
+        // The 500 most recently updated tracks survive
+        let mut survivors: Vec<u128> = manager.tracks().map(|t| t.canonical_id.as_u128()).collect();
+        survivors.sort_unstable();
+        assert_eq!(survivors, (9_501..=10_000).collect::<Vec<u128>>());
+        
+        let again = run();
+        assert_eq!(again.capacity_stats(), manager.capacity_stats());
+        let mut again_survivors: Vec<u128> = again.tracks().map(|t| t.canonical_id.as_u128()).collect();
+        again_survivors.sort_unstable();
+        assert_eq!(again_survivors, survivors);
+    }
+    
+    #[test]
+    fn test_confidence_to_covariance() {
+        let manager = TrackManager::with_defaults();
+        
+        // High confidence → low variance
+        let high_conf = manager.confidence_to_covariance(0.99);
+        let low_conf = manager.confidence_to_covariance(0.1);
+        
+        assert!(high_conf[(0, 0)] < low_conf[(0, 0)]);
+    }
+
+    #[test]
+    fn test_covariance_to_confidence_round_trips() {
+        let manager = TrackManager::with_defaults();
+
+        for confidence in [0.1, 0.5, 0.95] {
+            let covariance = manager.confidence_to_covariance(confidence);
+            assert!((manager.covariance_to_confidence(&covariance) - confidence).abs() < 1e-9);
+        }
+        assert_eq!(manager.covariance_to_confidence(&(Matrix6::identity() * 100.0)), 0.0);
+        assert_eq!(manager.covariance_to_confidence(&(Matrix6::identity() * f64::NAN)), 0.0);
+    }
+
+    #[test]
+    fn test_vertical_variance_ratio_scales_altitude_only() {
+        let isotropic = TrackManager::with_defaults();
+        let gnss = TrackManager::new(TrackingConfig { vertical_variance_ratio: Some(9.0), ..TrackingConfig::default() });
         
-        // High confidence → low variance
-        let high_conf = manager.confidence_to_covariance(0.99);
-        let low_conf = manager.confidence_to_covariance(0.1);
+        let (flat, tall) = (isotropic.confidence_to_covariance(0.95), gnss.confidence_to_covariance(0.95));
+        assert_eq!((tall[(0, 0)], tall[(1, 1)]), (flat[(0, 0)], flat[(1, 1)]));
+        assert!((tall[(2, 2)] - 9.0 * flat[(2, 2)]).abs() < 1e-12);
+        assert_eq!(tall.fixed_view::<3, 3>(3, 3), flat.fixed_view::<3, 3>(3, 3));
         
-        assert!(high_conf[(0, 0)] < low_conf[(0, 0)]);
+        // The score survives the round trip with the ratio applied
+        for confidence in [0.1, 0.5, 0.95] {
+            let covariance = gnss.confidence_to_covariance(confidence);
+            assert!((gnss.covariance_to_confidence(&covariance) - confidence).abs() < 1e-9);
+        }
     }
     
     #[test]
@@ -910,6 +3399,100 @@ mod tests {
         assert_eq!(manager.track_count(), 0);
     }
     
+    #[test]
+    fn test_high_priority_tracks_age_out_at_their_own_limit() {
+        let mut manager = TrackManager::new(TrackingConfig {
+            max_age: 5,
+            high_priority_max_age: Some(2),
+            ..Default::default()
+        });
+        
+        let routine = sample_packet();
+        let id = manager.process_packet(&routine, None, None).unwrap();
+        assert_eq!(manager.get_track(&id).unwrap().priority, 0);
+        
+        // A high-priority report of the same object raises the track's priority
+        let threat = GlobalHazardPacket { priority: GlobalHazardPacket::HIGH_PRIORITY, ..routine.clone() };
+        assert_eq!(manager.process_packet(&threat, None, None).unwrap(), id);
+        assert_eq!(manager.get_track(&id).unwrap().priority, GlobalHazardPacket::HIGH_PRIORITY);
+        // ...and a later routine one doesn't lower it
+        assert_eq!(manager.process_packet(&routine, None, None).unwrap(), id);
+        assert_eq!(manager.get_track(&id).unwrap().priority, GlobalHazardPacket::HIGH_PRIORITY);
+        
+        let other = GlobalHazardPacket { position: [37.80, -122.45, 10.0], ..sample_packet() };
+        let other_id = manager.process_packet(&other, None, None).unwrap();
+        
+        manager.age_tracks();
+        manager.age_tracks();
+        assert!(manager.get_track(&id).is_none());
+        assert!(manager.get_track(&other_id).is_some());
+    }
+    
+    #[test]
+    fn test_update_config_recells_tracks_without_dropping_any() {
+        let mut manager = TrackManager::with_defaults();
+        let ids: Vec<Uuid> = (0..6)
+            .map(|i| {
+                let packet = GlobalHazardPacket {
+                    entity_id: Uuid::from_u128(i),
+                    position: [37.77 + i as f64 * 0.01, -122.42, 10.0],
+                    ..sample_packet()
+                };
+                manager.process_packet(&packet, None, None).unwrap()
+            })
+            .collect();
+        
+        let coarser = TrackingConfig { h3_resolution: Resolution::Seven, gating_threshold: 6.0, ..TrackingConfig::default() };
+        manager.update_config(coarser).unwrap();
+        assert_eq!(manager.track_count(), ids.len());
+        assert!(manager.verify_spatial_index().is_empty());
+        for id in &ids {
+            let track = manager.get_track(id).unwrap();
+            assert_eq!(track.h3_cell.resolution(), Resolution::Seven);
+            assert!(manager.spatial_query_kring(track.h3_cell, 0).contains(id));
+        }
+        assert!(manager.spatial_stats().total_cells < ids.len(), "coarser cells hold several tracks");
+        
+        // A rejected config leaves the manager as it was
+        let invalid = TrackingConfig { gating_threshold: -1.0, ..TrackingConfig::default() };
+        assert!(matches!(manager.update_config(invalid), Err(TrackingError::InvalidConfig(_))));
+        let local = TrackingConfig { frame: Frame::local_enu(37.77, -122.42).unwrap(), ..TrackingConfig::default() };
+        assert!(manager.update_config(local).is_err());
+        assert_eq!(manager.config().gating_threshold, 6.0);
+        assert_eq!(manager.config().h3_resolution, Resolution::Seven);
+        
+        // Fusion keeps working against the rebuilt index
+        let repeat = GlobalHazardPacket { entity_id: Uuid::from_u128(0), position: [37.77, -122.42, 10.0], ..sample_packet() };
+        assert_eq!(manager.process_packet(&repeat, None, None).unwrap(), ids[0]);
+        assert_eq!(manager.track_count(), ids.len());
+    }
+    
+    #[test]
+    fn test_shrinking_max_age_prunes_on_the_next_age_tracks() {
+        let mut manager = TrackManager::new(TrackingConfig { max_age: 10, ..Default::default() });
+        let stale = manager.process_packet(&sample_packet(), None, None).unwrap();
+        for _ in 0..4 {
+            manager.age_tracks();
+        }
+        let fresh_packet = GlobalHazardPacket { position: [37.80, -122.45, 10.0], ..sample_packet() };
+        let fresh = manager.process_packet(&fresh_packet, None, None).unwrap();
+        
+        manager.update_config(TrackingConfig { max_age: 3, ..Default::default() }).unwrap();
+        assert_eq!(manager.track_count(), 2, "the swap itself removes nothing");
+        
+        manager.age_tracks();
+        assert!(manager.get_track(&stale).is_none());
+        assert!(manager.get_track(&fresh).is_some());
+        assert!(manager.verify_spatial_index().is_empty());
+        
+        // A lower max_tracks evicts down to it at once
+        manager.process_packet(&GlobalHazardPacket { position: [37.90, -122.45, 10.0], ..sample_packet() }, None, None).unwrap();
+        manager.update_config(TrackingConfig { max_age: 3, max_tracks: Some(1), ..Default::default() }).unwrap();
+        assert_eq!(manager.track_count(), 1);
+        assert_eq!(manager.capacity_stats().evictions, 1);
+        assert!(manager.verify_spatial_index().is_empty());
+    }
+    
     #[test]
     fn test_covariance_intersection_basic() {
         // Two estimates with equal covariance → should average the states
@@ -972,6 +3555,70 @@ mod tests {
         );
     }
     
+    #[test]
+    fn test_covariance_intersection_rejects_non_finite() {
+        let x = Vector6::new(10.0, 20.0, 30.0, 1.0, 2.0, 0.0);
+        let p = Matrix6::identity();
+        let mut x_nan = x;
+        x_nan[3] = f64::NAN;
+        let p_inf = p * f64::INFINITY;
+        
+        assert!(TrackManager::covariance_intersection(&x, &p, &x_nan, &p).is_none());
+        assert!(TrackManager::covariance_intersection(&x, &p, &x, &p_inf).is_none());
+    }
+    
+    #[test]
+    fn test_check_numerics_reports_eigenvalues() {
+        let id = Uuid::from_u128(7);
+        let state = Vector6::zeros();
+        assert!(TrackManager::check_numerics(id, &state, &Matrix6::identity()).is_ok());
+        
+        let indefinite = Matrix6::from_diagonal(&Vector6::new(4.0, 4.0, -1.0, 1.0, 1.0, 1.0));
+        match TrackManager::check_numerics(id, &state, &indefinite) {
+            Err(TrackingError::NumericalInstability { track_id, eigenvalues }) => {
+                assert_eq!(track_id, id);
+                assert!((eigenvalues[0] + 1.0).abs() < 1e-9, "{eigenvalues:?}");
+            }
+            other => panic!("expected NumericalInstability, got {other:?}"),
+        }
+        
+        let mut nan_state = state;
+        nan_state[4] = f64::NAN;
+        assert!(matches!(
+            TrackManager::check_numerics(id, &nan_state, &Matrix6::identity()),
+            Err(TrackingError::NumericalInstability { eigenvalues, .. }) if eigenvalues.iter().all(|&e| e == 1.0)
+        ));
+    }
+    
+    #[test]
+    fn test_nan_measurement_is_contained() {
+        let mut manager = TrackManager::new(TrackingConfig {
+            frame: Frame::local_enu(37.7749, -122.4194).unwrap(),
+            robust: Some(RobustFusion::default()),
+            ..TrackingConfig::default()
+        });
+        manager.process_packet(&enu_packet(1, 0.0, 0.0), None, None).unwrap();
+        manager.process_packet(&enu_packet(2, 30.0, 0.0), None, None).unwrap();
+        let before = manager.get_track(&Uuid::from_u128(1)).unwrap().state;
+        
+        // A NaN velocity from track 1's source is refused, leaving it intact
+        let mut bad = enu_packet(1, 0.5, 0.0);
+        bad.velocity[0] = f64::NAN;
+        let result = manager.process_packet(&bad, None, None);
+        assert!(matches!(result, Err(TrackingError::NumericalInstability { track_id, .. }) if track_id == Uuid::from_u128(1)), "{result:?}");
+        assert_eq!(manager.get_track(&Uuid::from_u128(1)).unwrap().state, before);
+        
+        // ...and never becomes a track of its own
+        let mut stray = enu_packet(3, 500.0, 0.0);
+        stray.velocity[1] = f64::INFINITY;
+        assert!(matches!(manager.process_packet(&stray, None, None), Err(TrackingError::NumericalInstability { .. })));
+        assert_eq!(manager.track_count(), 2);
+        assert!(manager.tracks().all(|t| t.state.iter().all(|v| v.is_finite())));
+        
+        assert!(manager.remove_track(&Uuid::from_u128(2)).is_some());
+        assert_eq!((manager.track_count(), manager.spatial_stats().total_entries), (1, 1));
+    }
+    
     #[test]
     fn test_process_packet_creates_new_track() {
         let mut manager = TrackManager::with_defaults();
@@ -997,8 +3644,10 @@ mod tests {
             class_id: 1,
             timestamp: 1703001600.0,
             confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
         };
-        let track_id1 = manager.process_packet(&packet1, None, None).unwrap();
+        let _track_id1 = manager.process_packet(&packet1, None, None).unwrap();
         
         // Second packet at very similar position should associate
         let packet2 = GlobalHazardPacket {
@@ -1008,6 +3657,8 @@ mod tests {
             class_id: 1, // Same class
             timestamp: 1703001601.0,
             confidence_score: 0.85,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
         };
         let track_id2 = manager.process_packet(&packet2, None, None).unwrap();
         
@@ -1036,6 +3687,8 @@ mod tests {
             class_id: 1, // Vehicle
             timestamp: 1703001600.0,
             confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
         };
         manager.process_packet(&packet1, None, None).unwrap();
         
@@ -1047,6 +3700,8 @@ mod tests {
             class_id: 2, // Pedestrian (different class!)
             timestamp: 1703001600.0,
             confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
         };
         manager.process_packet(&packet2, None, None).unwrap();
         
@@ -1054,6 +3709,42 @@ mod tests {
         assert_eq!(manager.track_count(), 2);
     }
     
+    #[test]
+    fn test_class_gate_associates_compatible_classes_by_majority() {
+        let gate = ClassCompatibility::new().allow(3, 2, 2.0);
+        assert_eq!((gate.penalty(2, 3), gate.penalty(3, 3), gate.penalty(1, 2)), (Some(2.0), Some(0.0), None));
+        let mut manager = TrackManager::new(TrackingConfig { class_gate: Some(gate), ..TrackingConfig::default() });
+        let at = |class_id: u8| GlobalHazardPacket {
+            entity_id: Uuid::from_u128(7),
+            position: [37.7749, -122.4194, 10.0],
+            velocity: [1.0, 0.0, 0.0],
+            class_id,
+            timestamp: 1703001600.0,
+            confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        };
+        
+        // A pedestrian first, then mostly cyclist reports: one track that
+        // ends up a cyclist
+        let id = manager.process_packet(&at(2), None, None).unwrap();
+        for class_id in [3, 3, 2, 3] {
+            assert_eq!(manager.process_packet(&at(class_id), None, None).unwrap(), id);
+        }
+        let track = manager.get_track(&id).unwrap();
+        assert_eq!(track.class_id, 3);
+        assert_eq!(track.class_votes, BTreeMap::from([(2, 2), (3, 3)]));
+        
+        // Vehicles still never associate with either
+        manager.process_packet(&GlobalHazardPacket { entity_id: Uuid::from_u128(8), ..at(1) }, None, None).unwrap();
+        assert_eq!(manager.track_count(), 2);
+        
+        // Without the gate, class isn't voted on
+        let mut plain = TrackManager::with_defaults();
+        let id = plain.process_packet(&at(2), None, None).unwrap();
+        assert!(plain.get_track(&id).unwrap().class_votes.is_empty());
+    }
+    
     #[test]
     fn test_mahalanobis_gating() {
         let mut manager = TrackManager::with_defaults();
@@ -1070,9 +3761,735 @@ mod tests {
             class_id: 1,
             timestamp: 1703001600.0,
             confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
         };
         
         let association = manager.find_association(&far_packet).unwrap();
         assert!(association.is_none(), "Far packet should not associate");
     }
+    
+    #[test]
+    fn test_solve_assignment_beats_greedy() {
+        // Greedy takes (0, 0) first and is left with (1, 1) for a total of 11
+        let cost = vec![vec![1.0, 2.0], vec![1.0, 10.0]];
+        assert_eq!(solve_assignment(&cost), vec![1, 0]);
+        
+        // Rectangular: 3 + 1.5 beats both rows taking their cheapest column
+        let cost = vec![vec![5.0, 1.0, 3.0], vec![4.0, 1.5, 9.0]];
+        assert_eq!(solve_assignment(&cost), vec![2, 1]);
+        assert!(solve_assignment(&[]).is_empty());
+    }
+    
+    /// A batch with two measurements of each of two tracked objects, two of a
+    /// new object and one of a different class.
+    fn simultaneous_batch(manager: &mut TrackManager) -> Vec<GlobalHazardPacket> {
+        let at = |id: u128, altitude: f64, class_id: u8| GlobalHazardPacket {
+            entity_id: Uuid::from_u128(id),
+            position: [37.7749, -122.4194, altitude],
+            velocity: [1.0, 0.0, 0.0],
+            class_id,
+            timestamp: 1703001600.0,
+            confidence_score: 0.5,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        };
+        for (id, altitude) in [(100, 10.0), (200, 40.0), (300, 70.0)] {
+            manager.process_packet(&at(id, altitude, 1), None, None).unwrap();
+        }
+        vec![
+            at(11, 10.5, 1), at(12, 9.6, 1),
+            at(21, 41.0, 1), at(22, 39.5, 1),
+            at(31, 100.2, 1), at(32, 99.9, 1),
+            at(41, 10.0, 2),
+        ]
+    }
+    
+    /// Every track's identity and state as bytes, in canonical_id order.
+    fn track_state_bytes(manager: &TrackManager) -> Vec<u8> {
+        let mut tracks: Vec<&UniqueTrack> = manager.tracks().collect();
+        tracks.sort_by_key(|t| t.canonical_id);
+        let mut bytes = Vec::new();
+        for t in tracks {
+            let mut ids: Vec<Uuid> = t.observed_ids.iter().copied().collect();
+            ids.sort();
+            bytes.extend(t.canonical_id.as_bytes());
+            ids.iter().for_each(|id| bytes.extend(id.as_bytes()));
+            t.state.iter().chain(t.covariance.iter()).for_each(|x| bytes.extend(x.to_le_bytes()));
+            bytes.extend(t.last_update.to_le_bytes());
+            bytes.extend(t.age.to_le_bytes());
+            bytes.push(t.class_id);
+            bytes.extend(u64::from(t.h3_cell).to_le_bytes());
+        }
+        bytes
+    }
+    
+    #[test]
+    fn test_batch_association_is_order_independent() {
+        let mut reference = TrackManager::with_defaults();
+        let batch = simultaneous_batch(&mut reference);
+        reference.process_packets(&batch);
+        let expected = track_state_bytes(&reference);
+        
+        // Old objects fused, one track for the new object, one for the other class
+        assert_eq!(reference.track_count(), 5);
+        assert!(reference.get_track(&Uuid::from_u128(11)).is_some());
+        assert!(reference.get_track(&Uuid::from_u128(21)).is_some());
+        assert!(reference.get_track(&Uuid::from_u128(31)).is_some());
+        
+        let shuffles: [&[usize]; 3] = [
+            &[6, 5, 4, 3, 2, 1, 0],
+            &[3, 0, 6, 1, 5, 2, 4],
+            &[1, 0, 3, 2, 5, 4, 6],
+        ];
+        for order in shuffles {
+            let mut manager = TrackManager::with_defaults();
+            let batch = simultaneous_batch(&mut manager);
+            let shuffled: Vec<GlobalHazardPacket> = order.iter().map(|&i| batch[i].clone()).collect();
+            let results = manager.process_packets(&shuffled);
+            
+            // Results stay in input order
+            for ((result, entity_id), packet) in results.iter().zip(&shuffled) {
+                assert!(result.is_ok());
+                assert_eq!(*entity_id, packet.entity_id);
+            }
+            assert_eq!(track_state_bytes(&manager), expected, "order {order:?}");
+        }
+        
+        // One packet at a time, arrival order changes the fused state
+        let mut sequential = TrackManager::new(TrackingConfig {
+            association: AssociationMode::Sequential,
+            ..TrackingConfig::default()
+        });
+        let batch = simultaneous_batch(&mut sequential);
+        sequential.process_packets(&batch);
+        assert_ne!(track_state_bytes(&sequential), expected);
+    }
+    
+    #[test]
+    fn test_batch_and_sequential_agree_one_packet_at_a_time() {
+        let run = |association, cell_search| {
+            let mut manager = TrackManager::new(TrackingConfig {
+                frame: Frame::local_enu(37.7749, -122.4194).unwrap(),
+                base_pos_variance: 10_000.0,
+                association,
+                cell_search,
+                ..TrackingConfig::default()
+            });
+            // A fast mover seen by a different source each time, each fix
+            // repeated at high priority
+            for i in 0..10 {
+                let packet = GlobalHazardPacket {
+                    velocity: [100.0, 0.0, 0.0],
+                    timestamp: 1703001600.0 + 2.0 * i as f64,
+                    confidence_score: 0.0,
+                    ..enu_packet(i as u128 + 1, 200.0 * i as f64, 0.0)
+                };
+                let urgent = GlobalHazardPacket { priority: GlobalHazardPacket::HIGH_PRIORITY, ..packet.clone() };
+                manager.process_packets(&[packet]);
+                manager.process_packets(&[urgent]);
+            }
+            manager
+        };
+        
+        for cell_search in [CellSearch::default(), CellSearch::Fixed(1), CellSearch::Fixed(3)] {
+            let batch = run(AssociationMode::Batch, cell_search);
+            let sequential = run(AssociationMode::Sequential, cell_search);
+            assert_eq!(track_state_bytes(&batch), track_state_bytes(&sequential), "{cell_search:?}");
+            assert_eq!(
+                batch.spatial_stats().wide_associations,
+                sequential.spatial_stats().wide_associations,
+                "{cell_search:?}",
+            );
+        }
+        
+        // A k=3 ring reaches the mover's next fix beyond the k=1 ring
+        assert!(run(AssociationMode::Batch, CellSearch::Fixed(3)).spatial_stats().wide_associations > 0);
+    }
+    
+    #[test]
+    fn test_batch_order_breaks_ties_on_model_and_priority() {
+        let packet = sample_packet();
+        let urgent = GlobalHazardPacket { priority: GlobalHazardPacket::HIGH_PRIORITY, ..packet.clone() };
+        let bearing = GlobalHazardPacket {
+            measurement_model: MeasurementModel::BearingOnly { origin: [0.0; 3] },
+            ..packet.clone()
+        };
+        assert_eq!(canonical_packet_order(&packet, &urgent), std::cmp::Ordering::Less);
+        assert_eq!(canonical_packet_order(&packet, &bearing), std::cmp::Ordering::Less);
+        assert_eq!(canonical_packet_order(&bearing, &bearing.clone()), std::cmp::Ordering::Equal);
+    }
+    
+    fn enu_manager() -> TrackManager {
+        TrackManager::new(TrackingConfig {
+            frame: Frame::local_enu(37.7749, -122.4194).unwrap(),
+            ..TrackingConfig::default()
+        })
+    }
+    
+    fn enu_packet(id: u128, east: f64, north: f64) -> GlobalHazardPacket {
+        GlobalHazardPacket {
+            entity_id: Uuid::from_u128(id),
+            position: [east, north, 100.0],
+            ..sample_packet()
+        }
+    }
+    
+    fn merge_audit_manager(confirmation: Option<MergeConfirmation>) -> TrackManager {
+        TrackManager::new(TrackingConfig {
+            frame: Frame::local_enu(37.7749, -122.4194).unwrap(),
+            association: AssociationMode::Sequential,
+            merge_confirmation: confirmation,
+            ..TrackingConfig::default()
+        })
+    }
+    
+    /// Drone 1 flies east through the origin; drone 2, moving north, is first
+    /// detected right beside it as they cross at 5 s. Flies `ticks` of 0.5 s.
+    fn fly_crossing(manager: &mut TrackManager, ticks: u32) {
+        let dt = 0.5;
+        for tick in 0..ticks {
+            let t = tick as f64 * dt;
+            let mut east = enu_packet(1, -7.5 + 1.5 * t, 0.0);
+            east.velocity = [1.5, 0.0, 0.0];
+            east.timestamp = t;
+            manager.process_packet(&east, None, None).unwrap();
+            if t >= 5.0 {
+                let mut north = enu_packet(2, 1.0, -1.0 + 1.5 * (t - 5.0));
+                north.velocity = [0.0, 1.5, 0.0];
+                north.timestamp = t;
+                manager.process_packet(&north, None, None).unwrap();
+            }
+        }
+    }
+    
+    #[test]
+    fn test_crossing_tracks_keep_distinct_ids() {
+        // Drone 2's packets gate onto drone 1's track until 11 s. Without
+        // confirmation the first of them couples the identities
+        let mut plain = merge_audit_manager(None);
+        fly_crossing(&mut plain, 12);
+        assert_eq!(plain.track_count(), 1);
+        assert!(plain.get_track(&Uuid::from_u128(1)).unwrap().observed_ids.contains(&Uuid::from_u128(2)));
+        
+        let mut audited = merge_audit_manager(Some(MergeConfirmation::default()));
+        fly_crossing(&mut audited, 12);
+        assert_eq!(audited.get_track(&Uuid::from_u128(1)).unwrap().observed_ids.len(), 1);
+        
+        // Once they separate, drone 2 gets a track of its own
+        let mut audited = merge_audit_manager(Some(MergeConfirmation::default()));
+        fly_crossing(&mut audited, 30);
+        let mut ids: Vec<Uuid> = audited.tracks().map(|t| t.canonical_id).collect();
+        ids.sort();
+        assert_eq!(ids, [Uuid::from_u128(1), Uuid::from_u128(2)]);
+        assert!(audited.tracks().all(|t| t.observed_ids.len() == 1));
+        
+        let stats = audited.merge_stats();
+        assert!(stats.near_misses > 0);
+        assert_eq!((stats.confirmed, stats.pending), (0, 0));
+        assert!(audited.take_merge_events().is_empty());
+    }
+    
+    #[test]
+    fn test_merge_confirmed_after_k_agreeing_associations() {
+        let mut manager = merge_audit_manager(Some(MergeConfirmation { hits: 3, window_secs: 1.0, ..Default::default() }));
+        let own = GlobalHazardPacket { timestamp: 0.0, ..enu_packet(50, 0.0, 0.0) };
+        manager.process_packet(&own, None, None).unwrap();
+        
+        // Another agent's ID for the same drone: two hits, then the window
+        // lapses and the count restarts
+        let remote = |t: f64| GlobalHazardPacket { timestamp: t, ..enu_packet(7, 0.0, 0.0) };
+        for t in [0.1, 0.2] {
+            assert_eq!(manager.process_packet(&remote(t), None, None).unwrap(), own.entity_id);
+        }
+        assert_eq!(manager.merge_stats().pending, 1);
+        for t in [1.5, 1.6] {
+            assert_eq!(manager.process_packet(&remote(t), None, None).unwrap(), own.entity_id);
+        }
+        assert_eq!(manager.get_track(&own.entity_id).unwrap().merge_candidates[&remote(0.0).entity_id].hits, 2);
+        
+        // The third hit in the window absorbs it, and the smaller ID wins
+        assert_eq!(manager.process_packet(&remote(1.7), None, None).unwrap(), Uuid::from_u128(7));
+        let track = manager.get_track(&Uuid::from_u128(7)).unwrap();
+        assert_eq!(track.observed_ids.len(), 2);
+        assert!(track.merge_candidates.is_empty());
+        assert_eq!(manager.merge_stats(), MergeStats { confirmed: 1, near_misses: 0, expired: 1, pending: 0 });
+        
+        let events = manager.take_merge_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!((events[0].winner_id, events[0].loser_id), (Uuid::from_u128(7), own.entity_id));
+        assert_eq!(events[0].timestamp, 1.7);
+        assert!(manager.take_merge_events().is_empty());
+    }
+    
+    #[test]
+    fn test_frame_rejects_out_of_range_coordinates() {
+        assert!(matches!(Frame::Wgs84.to_latlng(91.0, 0.0), Err(TrackingError::LatitudeOutOfRange(_))));
+        assert!(matches!(Frame::Wgs84.to_latlng(0.0, -180.5), Err(TrackingError::LongitudeOutOfRange(_))));
+        assert!(matches!(Frame::Wgs84.to_latlng(f64::NAN, 0.0), Err(TrackingError::InvalidCoordinates(_))));
+        
+        // Sim-scale meters read as degrees are refused instead of mis-indexed
+        let mut wgs84 = TrackManager::with_defaults();
+        let result = wgs84.process_packet(&enu_packet(1, 450.0, 0.0), None, None);
+        assert!(matches!(result, Err(TrackingError::LatitudeOutOfRange(lat)) if lat == 450.0), "{result:?}");
+        assert_eq!(wgs84.track_count(), 0);
+        
+        // Local frames are metric and wrap across the antimeridian
+        let frame = Frame::local_enu(37.7749, -122.4194).unwrap();
+        let origin = frame.to_latlng(0.0, 0.0).unwrap();
+        assert!((origin.distance_m(frame.to_latlng(300.0, 400.0).unwrap()) - 500.0).abs() < 0.5);
+        let dateline = Frame::local_enu(0.0, 179.9999).unwrap().to_latlng(1000.0, 0.0).unwrap();
+        assert!(dateline.lng() < -179.99, "{}", dateline.lng());
+    }
+    
+    #[test]
+    fn test_local_enu_kring_prunes_distant_tracks() {
+        let mut manager = enu_manager();
+        for (id, east, north) in [(1, 0.0, 0.0), (2, 300.0, 0.0), (3, 0.0, 400.0), (4, -250.0, -250.0)] {
+            manager.process_packet(&enu_packet(id, east, north), None, None).unwrap();
+        }
+        assert_eq!(manager.track_count(), 4);
+        assert_eq!(manager.spatial_stats().total_cells, 4);
+        
+        let cell = manager.position_to_cell(0.0, 0.0).unwrap();
+        assert_eq!(manager.spatial_query_kring(cell, 1), HashSet::from([Uuid::from_u128(1)]));
+    }
+    
+    #[test]
+    fn test_nearby_enu_positions_land_in_neighboring_cells() {
+        let mut manager = enu_manager();
+        let origin_cell = manager.position_to_cell(0.0, 0.0).unwrap();
+        
+        // First meter east of the origin that falls in another cell
+        let east = (1..200)
+            .map(f64::from)
+            .find(|&east| manager.position_to_cell(east, 0.0).unwrap() != origin_cell)
+            .expect("a resolution-10 cell is ~66m across");
+        let cell = manager.position_to_cell(east, 0.0).unwrap();
+        assert_eq!(origin_cell.is_neighbor_with(cell), Ok(true));
+        
+        // One meter apart across the boundary, the k-ring still finds the other
+        manager.process_packet(&enu_packet(1, east - 1.0, 0.0), None, None).unwrap();
+        assert!(manager.spatial_query_kring(cell, 1).contains(&Uuid::from_u128(1)));
+        let track_id = manager.process_packet(&enu_packet(2, east, 0.0), None, None).unwrap();
+        assert_eq!(track_id, Uuid::from_u128(1), "second entity associated across the cell boundary");
+        assert_eq!(manager.track_count(), 1);
+    }
+    
+    #[test]
+    fn test_fast_mover_stays_one_track_across_cells() {
+        let run = |cell_search| {
+            // A coarse (100m σ) sensor, so the gate spans several cells
+            let mut manager = TrackManager::new(TrackingConfig {
+                frame: Frame::local_enu(37.7749, -122.4194).unwrap(),
+                base_pos_variance: 10_000.0,
+                cell_search,
+                ..TrackingConfig::default()
+            });
+            // 50 m/s east, seen every 2s by a different source each time
+            for i in 0..10 {
+                let packet = GlobalHazardPacket {
+                    velocity: [50.0, 0.0, 0.0],
+                    timestamp: 1703001600.0 + 2.0 * i as f64,
+                    confidence_score: 0.0,
+                    ..enu_packet(i as u128 + 1, 100.0 * i as f64, 0.0)
+                };
+                manager.process_packet(&packet, None, None).unwrap();
+            }
+            manager
+        };
+        
+        let adaptive = run(CellSearch::default());
+        assert_eq!(adaptive.track_count(), 1);
+        assert!(adaptive.get_track(&Uuid::from_u128(1)).is_some());
+        assert!(adaptive.spatial_stats().wide_associations > 0);
+        
+        // The k=1 ring loses it at cell boundaries
+        let fixed = run(CellSearch::Fixed(1));
+        assert!(fixed.track_count() > 1, "{}", fixed.track_count());
+        assert_eq!(fixed.spatial_stats().wide_associations, 0);
+    }
+    
+    #[test]
+    fn test_packet_stays_with_the_track_keyed_by_its_id() {
+        let mut manager = enu_manager();
+        manager.process_packet(&enu_packet(1, 0.0, 0.0), None, None).unwrap();
+        manager.process_packet(&enu_packet(2, 30.0, 0.0), None, None).unwrap();
+        
+        // An outlier from source 2 lands on track 1 but isn't fused into it
+        manager.process_packet(&enu_packet(2, 0.5, 0.0), None, None).unwrap();
+        assert_eq!(manager.track_count(), 2);
+        let track = manager.get_track(&Uuid::from_u128(1)).unwrap();
+        assert_eq!(track.observed_ids, HashSet::from([Uuid::from_u128(1)]));
+        assert_eq!(track.position(), Vector3::new(0.0, 0.0, 100.0));
+    }
+    
+    #[test]
+    fn test_replaced_track_leaves_no_index_entry() {
+        let mut manager = enu_manager();
+        manager.process_packet(&enu_packet(1, 0.0, 0.0), None, None).unwrap();
+        
+        // Same ID far outside the gate: the track is replaced, not duplicated
+        manager.process_packet(&enu_packet(1, 2000.0, 0.0), None, None).unwrap();
+        let stats = manager.spatial_stats();
+        assert_eq!((manager.track_count(), stats.total_cells, stats.total_entries), (1, 1, 1));
+        let old_cell = manager.position_to_cell(0.0, 0.0).unwrap();
+        assert!(manager.spatial_query_kring(old_cell, 0).is_empty());
+    }
+    
+    #[test]
+    fn test_trust_inflation_weakens_doubtful_gossip() {
+        use crate::godview_trust::TrustInflation;
+        
+        let fuse = |reliability: Option<f64>| {
+            let mut adaptive = AdaptiveState::new();
+            let inflation = match reliability {
+                Some(score) => {
+                    adaptive.get_neighbor(7).reliability_score = score;
+                    adaptive.covariance_inflation(7, &TrustInflation::INVERSE)
+                }
+                None => 1.0,
+            };
+            let mut manager = enu_manager();
+            manager.process_packet(&enu_packet(1, 0.0, 0.0), None, None).unwrap();
+            manager.process_packet_inflated(&enu_packet(2, 2.0, 0.0), Some(&adaptive), Some(7), inflation).unwrap();
+            let track = manager.get_track(&Uuid::from_u128(1)).unwrap();
+            (track.state, track.covariance)
+        };
+        
+        // Full reliability fuses exactly as uninflated gossip does
+        assert_eq!(fuse(Some(1.0)), fuse(None));
+        
+        // A quarter-reliable neighbor pulls the track less
+        let (trusted, _) = fuse(None);
+        let (doubted, _) = fuse(Some(0.25));
+        assert!(doubted[0] > 0.0 && doubted[0] < trusted[0], "{} vs {}", doubted[0], trusted[0]);
+        
+        assert_eq!(TrustInflation::INVERSE.factor(0.25), 4.0);
+        assert_eq!(TrustInflation::INVERSE.factor(0.0), 1.0 / crate::godview_trust::DEFAULT_MIN_RELIABILITY);
+        assert_eq!(TrustInflation::OFF.factor(0.01), 1.0);
+        assert_eq!(AdaptiveState::new().covariance_inflation(3, &TrustInflation::new(2.0)), 4.0);
+    }
+    
+    #[test]
+    fn test_position_only_stream_converges_velocity() {
+        let mut manager = TrackManager::new(TrackingConfig {
+            motion: Some(FilterTuning::default()),
+            ..enu_manager().config
+        });
+        let velocity = Vector3::new(4.0, -2.0, 0.5);
+        let dt = 0.1;
+        let mut track_id = None;
+        for step in 0..300 {
+            let t = step as f64 * dt;
+            let position = Vector3::new(10.0, 20.0, 100.0) + velocity * t;
+            manager.predict_tracks(dt);
+            let packet = GlobalHazardPacket {
+                position: [position.x, position.y, position.z],
+                velocity: [99.0, 99.0, 99.0],
+                timestamp: t,
+                measurement_model: MeasurementModel::Position3D,
+                ..enu_packet(1, 0.0, 0.0)
+            };
+            track_id = Some(manager.process_packet(&packet, None, None).unwrap());
+            if step == 0 {
+                // The velocity it doesn't measure starts at rest
+                assert_eq!(manager.get_track(&track_id.unwrap()).unwrap().velocity(), Vector3::zeros());
+            }
+        }
+        
+        let track = manager.get_track(&track_id.unwrap()).unwrap();
+        assert_eq!(manager.track_count(), 1);
+        assert!((track.velocity() - velocity).norm() < 0.05, "velocity {:?}", track.velocity());
+        assert!(track.covariance[(3, 3)] < FilterTuning::default().initial_velocity_variance, "{}", track.covariance[(3, 3)]);
+    }
+    
+    #[test]
+    fn test_full_state_packets_keep_the_wire_format() {
+        let packet = sample_packet();
+        let json = serde_json::to_value(&packet).unwrap();
+        assert!(json.get("measurement_model").is_none());
+        let legacy: GlobalHazardPacket = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.measurement_model, MeasurementModel::PositionVelocity6D);
+        
+        let reduced = GlobalHazardPacket { measurement_model: MeasurementModel::Position3D, ..packet };
+        let json = serde_json::to_string(&reduced).unwrap();
+        assert_eq!(serde_json::from_str::<GlobalHazardPacket>(&json).unwrap().measurement_model, MeasurementModel::Position3D);
+    }
+    
+    #[test]
+    fn test_angular_jacobians_match_finite_differences() {
+        let state = Vector6::new(30.0, -40.0, 25.0, 3.0, 1.0, -0.5);
+        let origin = [5.0, 2.0, 1.0];
+        for model in [MeasurementModel::BearingOnly { origin }, MeasurementModel::RangeDoppler { origin }] {
+            let (expected, h) = model.observe(&state).unwrap();
+            assert_eq!((h.nrows(), h.ncols()), (model.dimension(), 6));
+            for column in 0..6 {
+                let mut nudged = state;
+                nudged[column] += 1e-6;
+                let (moved, _) = model.observe(&nudged).unwrap();
+                let numeric = model.residual(&moved, &expected) / 1e-6;
+                for row in 0..model.dimension() {
+                    assert!((numeric[row] - h[(row, column)]).abs() < 1e-5, "{model:?} H[{row},{column}]");
+                }
+            }
+        }
+        
+        // Undefined at the sensor, and azimuth wraps across ±π
+        assert!(MeasurementModel::RangeDoppler { origin }.observe(&Vector6::new(5.0, 2.0, 1.0, 0.0, 0.0, 0.0)).is_none());
+        let bearing = MeasurementModel::BearingOnly { origin };
+        let wrapped = bearing.residual(&DVector::from_column_slice(&[3.1, 0.0]), &DVector::from_column_slice(&[-3.1, 0.0]));
+        assert!((wrapped[0] - (6.2 - std::f64::consts::TAU)).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_bearings_refine_a_track_behind_the_flag() {
+        let truth = Vector3::new(100.0, 50.0, 100.0);
+        let bearing = |origin: [f64; 3]| {
+            let d = truth - Vector3::from(origin);
+            GlobalHazardPacket {
+                position: [d.y.atan2(d.x), d.z.atan2(d.x.hypot(d.y)), 0.0],
+                confidence_score: 0.99,
+                measurement_model: MeasurementModel::BearingOnly { origin },
+                ..enu_packet(1, 0.0, 0.0)
+            }
+        };
+        let start = GlobalHazardPacket { position: [104.0, 47.0, 100.0], ..enu_packet(1, 0.0, 0.0) };
+        
+        // Off by default, and never a track of their own
+        let mut manager = enu_manager();
+        manager.process_packet(&start, None, None).unwrap();
+        let err = manager.process_packet(&bearing([0.0, 0.0, 0.0]), None, None).unwrap_err();
+        assert!(matches!(err, TrackingError::UnsupportedMeasurement(_)));
+        
+        let mut manager = TrackManager::new(TrackingConfig {
+            nonlinear: Some(NonlinearMeasurements::default()),
+            ..enu_manager().config
+        });
+        let err = manager.process_packet(&bearing([0.0, 0.0, 0.0]), None, None).unwrap_err();
+        assert!(matches!(err, TrackingError::UnsupportedMeasurement(_)));
+        assert_eq!(manager.track_count(), 0);
+        
+        // Crossing bearings from two sensors pull the track onto the target
+        let id = manager.process_packet(&start, None, None).unwrap();
+        let before = (manager.get_track(&id).unwrap().position() - truth).norm();
+        for _ in 0..20 {
+            for origin in [[0.0, 0.0, 0.0], [200.0, 0.0, 0.0]] {
+                assert_eq!(manager.process_packet(&bearing(origin), None, None).unwrap(), id);
+            }
+        }
+        let after = (manager.get_track(&id).unwrap().position() - truth).norm();
+        assert!(after < before / 4.0, "{before} -> {after}");
+        assert_eq!(manager.track_count(), 1);
+    }
+    
+    #[test]
+    fn test_noisy_bearings_refine_an_overconfident_track() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        
+        let truth = Vector3::new(100.0, 50.0, 100.0);
+        for seed in [1, 7, 42] {
+            let mut manager = TrackManager::new(TrackingConfig {
+                nonlinear: Some(NonlinearMeasurements::default()),
+                ..enu_manager().config
+            });
+            // A fix 5m off at a confidence that claims ~1m
+            let start = GlobalHazardPacket {
+                position: [104.0, 47.0, 100.0],
+                confidence_score: 0.95,
+                ..enu_packet(1, 0.0, 0.0)
+            };
+            let id = manager.process_packet(&start, None, None).unwrap();
+            let before = (manager.get_track(&id).unwrap().position() - truth).norm();
+            
+            // Bearings to a position blurred by 0.5m of Gaussian noise per
+            // axis, ~0.26° at this range
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut normal = || (-2.0 * (1.0 - rng.gen::<f64>()).ln()).sqrt() * (std::f64::consts::TAU * rng.gen::<f64>()).cos();
+            for _ in 0..20 {
+                for origin in [[0.0, 0.0, 100.0], [200.0, 0.0, 100.0]] {
+                    let seen = truth + Vector3::new(normal(), normal(), normal()) * 0.5;
+                    let d = seen - Vector3::from(origin);
+                    let bearing = GlobalHazardPacket {
+                        position: [d.y.atan2(d.x), d.z.atan2(d.x.hypot(d.y)), 0.0],
+                        measurement_model: MeasurementModel::BearingOnly { origin },
+                        ..start.clone()
+                    };
+                    manager.process_packet(&bearing, None, None).unwrap();
+                }
+            }
+            let after = (manager.get_track(&id).unwrap().position() - truth).norm();
+            assert!(after < before / 2.0, "seed {seed}: {before} -> {after}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    //! Property tests for the TrackManager invariants: random sequences of
+    //! packets, batches, aging and prediction, checked after every operation.
+    //! `cargo test --features fuzz` runs many more cases.
+    use super::*;
+    use proptest::prelude::*;
+    
+    const CASES: u32 = if cfg!(feature = "fuzz") { 20_000 } else { 64 };
+    
+    /// Center of the generated packet clusters, in degrees.
+    const ORIGIN: (f64, f64) = (37.7749, -122.4194);
+    
+    #[derive(Debug, Clone)]
+    enum Op {
+        Process(GlobalHazardPacket),
+        Age,
+        Predict(f64),
+        ProcessBatch(Vec<GlobalHazardPacket>),
+    }
+    
+    fn frame() -> impl Strategy<Value = Frame> {
+        prop_oneof![
+            Just(Frame::Wgs84),
+            Just(Frame::local_enu(ORIGIN.0, ORIGIN.1).unwrap()),
+        ]
+    }
+    
+    /// Mostly within ~300m of `center`, sometimes on or past the edge of
+    /// what the frame can index.
+    fn coordinate(center: f64, spread: f64) -> impl Strategy<Value = f64> {
+        prop_oneof![
+            6 => (center - spread)..(center + spread),
+            1 => -1e8..1e8f64,
+            1 => prop::sample::select(vec![0.0, -90.0, 90.0, -180.0, 180.0, 1e7, -1e7, f64::MAX, f64::MIN]),
+        ]
+    }
+    
+    fn packet(frame: Frame) -> impl Strategy<Value = GlobalHazardPacket> {
+        let (x, y) = match frame {
+            Frame::Wgs84 => (coordinate(ORIGIN.0, 0.003), coordinate(ORIGIN.1, 0.003)),
+            Frame::LocalEnu { .. } => (coordinate(0.0, 300.0), coordinate(0.0, 300.0)),
+        };
+        let component = prop_oneof![4 => -50.0..50.0f64, 1 => -1e6..1e6f64];
+        let confidence = prop_oneof![Just(0.0), Just(1.0), 0.0..=1.0f64];
+        (
+            1u128..=8, // few IDs, so they collide
+            (x, y, -100.0..1000.0f64),
+            [component.clone(), component.clone(), component],
+            1u8..=3,
+            0.0..1e9f64,
+            confidence,
+        ).prop_map(|(id, (x, y, z), velocity, class_id, timestamp, confidence_score)| GlobalHazardPacket {
+            entity_id: Uuid::from_u128(id),
+            position: [x, y, z],
+            velocity,
+            class_id,
+            timestamp,
+            confidence_score,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        })
+    }
+    
+    fn op(frame: Frame) -> impl Strategy<Value = Op> {
+        prop_oneof![
+            8 => packet(frame).prop_map(Op::Process),
+            1 => Just(Op::Age),
+            1 => (0.0..2.0f64).prop_map(Op::Predict),
+            2 => prop::collection::vec(packet(frame), 1..6).prop_map(Op::ProcessBatch),
+        ]
+    }
+    
+    fn config() -> impl Strategy<Value = TrackingConfig> {
+        (
+            frame(),
+            any::<bool>(),
+            any::<bool>(),
+            prop::option::of(1usize..6),
+            0u32..20,
+        ).prop_map(|(frame, robust, motion, max_tracks, max_age)| TrackingConfig {
+            frame,
+            robust: robust.then(RobustFusion::default),
+            motion: motion.then(FilterTuning::default),
+            max_tracks,
+            max_age: max_age + 1,
+            ..TrackingConfig::default()
+        })
+    }
+    
+    fn scenario() -> impl Strategy<Value = (TrackingConfig, Vec<Op>)> {
+        config().prop_flat_map(|config| {
+            let ops = prop::collection::vec(op(config.frame), 1..60);
+            (Just(config), ops)
+        })
+    }
+    
+    fn check_invariants(manager: &TrackManager) -> Result<(), TestCaseError> {
+        // The spatial index holds exactly the live tracks, each in its cell
+        let mut indexed = HashSet::new();
+        for (cell, ids) in &manager.spatial_index {
+            prop_assert!(!ids.is_empty(), "empty cell {} left in the index", cell);
+            indexed.extend(ids.iter().map(|id| (*cell, *id)));
+        }
+        let live: HashSet<(CellIndex, Uuid)> = manager.tracks.iter()
+            .map(|(id, track)| (track.h3_cell, *id))
+            .collect();
+        prop_assert_eq!(indexed, live);
+        prop_assert_eq!(manager.verify_spatial_index(), Vec::new());
+        
+        // Highlander: each track is keyed by the smallest ID it has seen
+        for (id, track) in &manager.tracks {
+            prop_assert_eq!(*id, track.canonical_id);
+            prop_assert_eq!(track.observed_ids.iter().min(), Some(&track.canonical_id));
+        }
+        
+        if let Some(max_tracks) = manager.config.max_tracks {
+            prop_assert!(manager.track_count() <= max_tracks);
+        }
+        Ok(())
+    }
+    
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(CASES))]
+        
+        #[test]
+        fn prop_invariants_hold_after_every_operation((config, ops) in scenario()) {
+            let mut manager = TrackManager::new(config);
+            for op in &ops {
+                match op {
+                    Op::Process(packet) => {
+                        let _ = manager.process_packet(packet, None, None);
+                    }
+                    Op::Age => manager.age_tracks(),
+                    Op::Predict(dt) => manager.predict_tracks(*dt),
+                    Op::ProcessBatch(packets) => {
+                        manager.process_packets(packets);
+                    }
+                }
+                check_invariants(&manager)?;
+            }
+            
+            // Every live track is found by a query around its own cell
+            for track in manager.tracks() {
+                let found = manager.spatial_query_kring(track.h3_cell, 0);
+                prop_assert!(found.contains(&track.canonical_id));
+            }
+        }
+        
+        #[test]
+        fn prop_ci_of_identical_estimates_never_shrinks_covariance(
+            state in prop::array::uniform6(-1e4..1e4f64),
+            factor in prop::collection::vec(-5.0..5.0f64, 36),
+            jitter in 0.01..10.0f64,
+        ) {
+            let x = Vector6::from_column_slice(&state);
+            let l = Matrix6::from_column_slice(&factor);
+            let p = l * l.transpose() + Matrix6::identity() * jitter;
+            
+            let (x_ci, p_ci) = TrackManager::covariance_intersection(&x, &p, &x, &p)
+                .expect("SPD inputs");
+            let scale = p.norm();
+            prop_assert!((x_ci - x).norm() <= 1e-6 * (1.0 + x.norm()));
+            for eigenvalue in (p_ci - p).symmetric_eigen().eigenvalues.iter() {
+                prop_assert!(*eigenvalue >= -1e-9 * scale, "covariance shrank by {}", eigenvalue);
+            }
+        }
+    }
 }
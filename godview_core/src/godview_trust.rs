@@ -13,7 +13,7 @@ use std::collections::HashSet;
 use std::path::Path;
 use std::sync::Arc;
 use thiserror::Error;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use uuid::Uuid;
 use crate::godview_tracking::GlobalHazardPacket;
 
@@ -154,7 +154,7 @@ impl SledRevocationStore {
 
 impl RevocationStore for SledRevocationStore {
     fn insert(&self, key_bytes: [u8; 32]) -> Result<(), AuthError> {
-        self.db.insert(&key_bytes, &[1u8])
+        self.db.insert(key_bytes, &[1u8])
             .map_err(|e| AuthError::StorageError(format!("Insert failed: {}", e)))?;
         self.db.flush()
             .map_err(|e| AuthError::StorageError(format!("Flush failed: {}", e)))?;
@@ -374,8 +374,8 @@ impl TokenFactory {
             .build(&self.root_keypair)
             .map_err(|e| AuthError::BiscuitError(format!("{:?}", e)))?;
         
-        Ok(biscuit.to_vec()
-            .map_err(|e| AuthError::BiscuitError(format!("{:?}", e)))?)
+        biscuit.to_vec()
+            .map_err(|e| AuthError::BiscuitError(format!("{:?}", e)))
     }
     
     /// Create a token with write access to a specific resource prefix
@@ -387,8 +387,8 @@ impl TokenFactory {
             .build(&self.root_keypair)
             .map_err(|e| AuthError::BiscuitError(format!("{:?}", e)))?;
         
-        Ok(biscuit.to_vec()
-            .map_err(|e| AuthError::BiscuitError(format!("{:?}", e)))?)
+        biscuit.to_vec()
+            .map_err(|e| AuthError::BiscuitError(format!("{:?}", e)))
     }
     
     /// Create a token with publish rights
@@ -401,8 +401,8 @@ impl TokenFactory {
             .build(&self.root_keypair)
             .map_err(|e| AuthError::BiscuitError(format!("{:?}", e)))?;
         
-        Ok(biscuit.to_vec()
-            .map_err(|e| AuthError::BiscuitError(format!("{:?}", e)))?)
+        biscuit.to_vec()
+            .map_err(|e| AuthError::BiscuitError(format!("{:?}", e)))
     }
 }
 
@@ -452,11 +452,11 @@ mod verifying_key_serde {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rand::rngs::OsRng;
+    use godview_env::determinism::Entropy;
     
     #[test]
     fn test_signed_packet_creation() {
-        let signing_key = SigningKey::generate(&mut OsRng);
+        let signing_key = SigningKey::generate(&mut Entropy::os());
         let payload = b"test payload".to_vec();
         
         let packet = SignedPacket::new(payload.clone(), &signing_key, None);
@@ -467,7 +467,7 @@ mod tests {
     
     #[test]
     fn test_signature_verification_fails_on_tampering() {
-        let signing_key = SigningKey::generate(&mut OsRng);
+        let signing_key = SigningKey::generate(&mut Entropy::os());
         let payload = b"original payload".to_vec();
         
         let mut packet = SignedPacket::new(payload, &signing_key, None);
@@ -524,6 +524,103 @@ mod tests {
 // ADAPTIVE STATE (Moved from godview_sim)
 // ============================================================================
 
+/// Reliability a neighbor starts at before any of its gossip is scored.
+pub const NEUTRAL_RELIABILITY: f64 = 0.5;
+
+/// Reliability below which a neighbor's gossip is filtered (see
+/// [`NeighborReputation::is_reliable`]).
+pub const GOSSIP_FILTER_THRESHOLD: f64 = 0.3;
+
+/// Floor on the reliability [`TrustInflation`] divides by.
+pub const DEFAULT_MIN_RELIABILITY: f64 = 0.05;
+
+/// Curve mapping a neighbor's reliability to a covariance inflation factor:
+/// `R' = R / max(reliability, min_reliability)^exponent`.
+///
+/// An exponent of 0 turns inflation off, leaving only the binary filter in
+/// [`AdaptiveState::should_accept_gossip`]; 1 is plain `R / reliability`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrustInflation {
+    /// How steeply low reliability inflates the covariance
+    pub exponent: f64,
+    
+    /// Floor on reliability, bounding the factor at `min_reliability^-exponent`
+    pub min_reliability: f64,
+}
+
+impl TrustInflation {
+    /// No inflation: every accepted packet fuses at face value.
+    pub const OFF: Self = Self { exponent: 0.0, min_reliability: DEFAULT_MIN_RELIABILITY };
+    
+    /// `R / max(reliability, 0.05)`.
+    pub const INVERSE: Self = Self { exponent: 1.0, min_reliability: DEFAULT_MIN_RELIABILITY };
+    
+    /// Creates a curve with the given exponent and the default floor.
+    pub fn new(exponent: f64) -> Self {
+        Self { exponent, ..Self::OFF }
+    }
+    
+    /// Returns true if this curve never inflates.
+    pub fn is_off(&self) -> bool {
+        self.exponent == 0.0
+    }
+    
+    /// Factor the covariance of a packet from a neighbor with `reliability`
+    /// is multiplied by. Always 1.0 at full reliability.
+    pub fn factor(&self, reliability: f64) -> f64 {
+        if self.is_off() {
+            return 1.0;
+        }
+        let reliability = reliability.max(self.min_reliability).min(1.0);
+        reliability.powf(-self.exponent)
+    }
+}
+
+impl Default for TrustInflation {
+    fn default() -> Self {
+        Self::OFF
+    }
+}
+
+/// Per-neighbor token bucket bounding how much gossip one neighbor can get
+/// fused in a round.
+///
+/// Each neighbor's bucket holds up to `capacity` packets and refills at
+/// `refill_per_sec`, both scaled down for neighbors below
+/// [`NEUTRAL_RELIABILITY`] (see [`Self::scale`]); packets that find it empty
+/// are dropped before fusion.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GossipQuota {
+    /// Packets a neighbor in good standing can send in one burst
+    pub capacity: f64,
+    
+    /// Packets per second a neighbor in good standing earns back
+    pub refill_per_sec: f64,
+}
+
+impl GossipQuota {
+    /// Creates a quota of `capacity` packets refilling at `refill_per_sec`.
+    pub fn new(capacity: f64, refill_per_sec: f64) -> Self {
+        Self { capacity, refill_per_sec }
+    }
+    
+    /// Share of the quota a neighbor with `reliability` gets: all of it at
+    /// neutral or better, shrinking linearly to nothing below.
+    pub fn scale(reliability: f64) -> f64 {
+        (reliability / NEUTRAL_RELIABILITY).clamp(0.0, 1.0)
+    }
+}
+
+/// A neighbor's [`GossipQuota`] bucket.
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    /// Packets that can still be admitted
+    tokens: f64,
+    
+    /// Time the bucket was last refilled (seconds)
+    refilled_at: f64,
+}
+
 /// Tracks the reliability of a neighbor agent.
 #[derive(Debug, Clone)]
 pub struct NeighborReputation {
@@ -555,7 +652,7 @@ impl NeighborReputation {
             packets_useful: 0,
             packets_redundant: 0,
             packets_wrong: 0,
-            reliability_score: 0.5, // Start neutral
+            reliability_score: NEUTRAL_RELIABILITY,
         }
     }
     
@@ -582,7 +679,7 @@ impl NeighborReputation {
     
     /// Returns true if this neighbor is considered reliable.
     pub fn is_reliable(&self) -> bool {
-        self.reliability_score >= 0.3
+        self.reliability_score >= GOSSIP_FILTER_THRESHOLD
     }
     
     /// Returns true if this neighbor is considered a bad actor.
@@ -687,8 +784,17 @@ pub struct AdaptiveState {
     /// Total tracks dropped due to low confidence
     pub tracks_dropped: u64,
     
+    /// Total gossip dropped by a neighbor's [`GossipQuota`]
+    pub gossip_rate_limited: u64,
+    
+    /// Incoming gossip by what was decided about it, counted by the agent
+    pub gossip_decisions: GossipDecisionCounts,
+    
     /// Whether this agent is a "bad actor" (for testing)
     pub is_bad_actor: bool,
+    
+    /// Each neighbor's quota bucket
+    quota_buckets: HashMap<usize, TokenBucket>,
 }
 
 impl AdaptiveState {
@@ -701,7 +807,10 @@ impl AdaptiveState {
             current_time: 0.0,
             gossip_filtered: 0,
             tracks_dropped: 0,
+            gossip_rate_limited: 0,
+            gossip_decisions: GossipDecisionCounts::default(),
             is_bad_actor: false,
+            quota_buckets: HashMap::new(),
         }
     }
     
@@ -733,6 +842,22 @@ impl AdaptiveState {
         }
     }
     
+    /// Starts a new episode of a world that restarts at time 0: forgets
+    /// track confidences and quota buckets and zeroes the counters. Neighbor
+    /// reputations are kept if `keep_reputations`.
+    pub fn start_episode(&mut self, keep_reputations: bool) {
+        self.track_confidences.clear();
+        self.quota_buckets.clear();
+        self.current_time = 0.0;
+        self.gossip_filtered = 0;
+        self.tracks_dropped = 0;
+        self.gossip_rate_limited = 0;
+        self.gossip_decisions = GossipDecisionCounts::default();
+        if !keep_reputations {
+            self.neighbor_reputations.clear();
+        }
+    }
+    
     /// Gets or creates reputation for a neighbor.
     pub fn get_neighbor(&mut self, neighbor_id: usize) -> &mut NeighborReputation {
         self.neighbor_reputations
@@ -756,6 +881,45 @@ impl AdaptiveState {
         }
     }
     
+    /// Takes one packet from `neighbor_id`'s bucket under `quota`, after
+    /// refilling it for the time since its last refill. Returns false, and
+    /// counts the packet as rate limited, if the bucket is empty.
+    ///
+    /// The bucket is capped and refilled at the neighbor's share of `quota`
+    /// ([`GossipQuota::scale`]); unknown neighbors start with a full one.
+    pub fn admit_gossip(&mut self, neighbor_id: usize, quota: &GossipQuota) -> bool {
+        let reliability = self.neighbor_reputations.get(&neighbor_id)
+            .map_or(NEUTRAL_RELIABILITY, |rep| rep.reliability_score);
+        let scale = GossipQuota::scale(reliability);
+        let capacity = quota.capacity * scale;
+        let now = self.current_time;
+        
+        let bucket = self.quota_buckets
+            .entry(neighbor_id)
+            .or_insert(TokenBucket { tokens: capacity, refilled_at: now });
+        let elapsed = (now - bucket.refilled_at).max(0.0);
+        bucket.tokens = (bucket.tokens + elapsed * quota.refill_per_sec * scale).min(capacity);
+        bucket.refilled_at = now;
+        
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            self.gossip_rate_limited += 1;
+            false
+        }
+    }
+    
+    /// Covariance inflation for gossip from a neighbor under `curve`.
+    ///
+    /// Unknown neighbors are inflated at [`NEUTRAL_RELIABILITY`], the score
+    /// they'd start at.
+    pub fn covariance_inflation(&self, neighbor_id: usize, curve: &TrustInflation) -> f64 {
+        let reliability = self.neighbor_reputations.get(&neighbor_id)
+            .map_or(NEUTRAL_RELIABILITY, |rep| rep.reliability_score);
+        curve.factor(reliability)
+    }
+    
     /// Processes incoming gossip and updates reputations.
     ///
     /// Returns true if the gossip was useful, false if redundant/filtered.
@@ -832,7 +996,9 @@ impl AdaptiveState {
             high_confidence_tracks,
             tracks_dropped: self.tracks_dropped,
             gossip_filtered: self.gossip_filtered,
+            gossip_rate_limited: self.gossip_rate_limited,
             gossip_efficiency,
+            gossip_decisions: self.gossip_decisions,
         }
     }
 }
@@ -861,6 +1027,144 @@ pub struct AdaptiveMetrics {
     /// Total gossip filtered due to low reliability
     pub gossip_filtered: u64,
     
+    /// Total gossip dropped by per-neighbor quotas
+    pub gossip_rate_limited: u64,
+    
     /// Ratio of useful gossip to total gossip
     pub gossip_efficiency: f64,
+    
+    /// Incoming gossip packets by what was decided about them
+    pub gossip_decisions: GossipDecisionCounts,
+}
+
+/// What an agent decided about one incoming gossip packet, and why.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "decision", rename_all = "snake_case")]
+pub enum GossipDecision {
+    /// Passed every filter and went to the tracker
+    Accepted,
+    
+    /// The sender's reliability `score` was below [`GOSSIP_FILTER_THRESHOLD`]
+    RejectedReputation { score: f64 },
+    
+    /// The packet's confidence `value` was below the agent's threshold
+    RejectedConfidence { value: f64 },
+    
+    /// The sender's [`GossipQuota`] bucket was empty
+    RejectedQuota,
+    
+    /// A resent copy of gossip already received
+    Deduplicated,
+    
+    /// Older than the agent's staleness horizon
+    RejectedStale,
+}
+
+impl GossipDecision {
+    /// Short snake_case name of the decision's reason code.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Self::Accepted => "accepted",
+            Self::RejectedReputation { .. } => "rejected_reputation",
+            Self::RejectedConfidence { .. } => "rejected_confidence",
+            Self::RejectedQuota => "rejected_quota",
+            Self::Deduplicated => "deduplicated",
+            Self::RejectedStale => "rejected_stale",
+        }
+    }
+}
+
+/// Gossip packets counted by [`GossipDecision`] reason.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GossipDecisionCounts {
+    pub accepted: u64,
+    pub rejected_reputation: u64,
+    pub rejected_confidence: u64,
+    pub rejected_quota: u64,
+    pub deduplicated: u64,
+    pub rejected_stale: u64,
+}
+
+impl GossipDecisionCounts {
+    /// Counts one `decision`.
+    pub fn record(&mut self, decision: &GossipDecision) {
+        let count = match decision {
+            GossipDecision::Accepted => &mut self.accepted,
+            GossipDecision::RejectedReputation { .. } => &mut self.rejected_reputation,
+            GossipDecision::RejectedConfidence { .. } => &mut self.rejected_confidence,
+            GossipDecision::RejectedQuota => &mut self.rejected_quota,
+            GossipDecision::Deduplicated => &mut self.deduplicated,
+            GossipDecision::RejectedStale => &mut self.rejected_stale,
+        };
+        *count += 1;
+    }
+    
+    /// Packets dropped by a filter (duplicates aren't counted).
+    pub fn rejected(&self) -> u64 {
+        self.rejected_reputation + self.rejected_confidence + self.rejected_quota + self.rejected_stale
+    }
+    
+    /// Every decision counted.
+    pub fn total(&self) -> u64 {
+        self.accepted + self.deduplicated + self.rejected()
+    }
+}
+
+/// One entry in a [`GossipDecisionLog`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GossipDecisionRecord {
+    /// Agent clock when the packet was decided on (seconds)
+    pub time_secs: f64,
+    
+    /// Neighbor the packet came from (`usize::MAX` if unattributed)
+    pub neighbor_id: usize,
+    
+    /// Entity the packet reported
+    pub entity_id: Uuid,
+    
+    #[serde(flatten)]
+    pub decision: GossipDecision,
+}
+
+/// Ring buffer of an agent's latest [`GossipDecisionRecord`]s, oldest first.
+#[derive(Debug, Clone)]
+pub struct GossipDecisionLog {
+    capacity: usize,
+    records: VecDeque<GossipDecisionRecord>,
+}
+
+impl GossipDecisionLog {
+    /// Creates a log keeping the last `capacity` decisions (at least one).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self { capacity, records: VecDeque::with_capacity(capacity) }
+    }
+    
+    /// Decisions kept before the oldest is dropped.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    
+    /// Appends `record`, dropping the oldest if the log is full.
+    pub fn push(&mut self, record: GossipDecisionRecord) {
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+    
+    /// Decisions held, oldest first.
+    pub fn records(&self) -> impl Iterator<Item = &GossipDecisionRecord> {
+        self.records.iter()
+    }
+    
+    /// Number of decisions held.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+    
+    /// Returns true if no decision has been logged.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
 }
@@ -12,6 +12,7 @@ pub mod godview_trust;
 pub mod godview_tracking;
 pub mod metrics;
 pub mod agent_runtime;
+pub mod wire;
 
 #[cfg(feature = "visualization")]
 pub mod visualization;
@@ -28,9 +29,10 @@ pub mod validation;
 pub use godview_time::AugmentedStateFilter;
 pub use godview_space::{Entity, SpatialEngine, WorldShard};
 pub use godview_trust::{AuthError, SecurityContext, SignedPacket};
-pub use godview_tracking::{GlobalHazardPacket, TrackManager, TrackingConfig, TrackingError, UniqueTrack, MergeEvent};
+pub use godview_tracking::{GlobalHazardPacket, MeasurementModel, NonlinearMeasurements, TrackManager, TrackingConfig, TrackingError, Frame, UniqueTrack, MergeEvent, RobustFusion, FilterTuning, MotionModel, CapacityStats, AssociationMode, TrackState, TrackStateCounts, TrackLifecycle, LifecycleConfig, CellSearch, Inconsistency, ClassCompatibility, MergeConfirmation, MergeCandidate, MergeStats};
 pub use metrics::{TrackMetrics, GhostScoreConfig, calculate_ghost_score, calculate_entropy, calculate_tension};
-pub use agent_runtime::{GodViewAgent, AgentConfig};
+pub use agent_runtime::{GodViewAgent, AgentConfig, AgentConfigBuilder, ConfigError};
+pub use wire::{PacketVersion, PacketCodec, HazardPacketV2, DecodedBatch, WireError};
 
 // Re-export environment types for convenience
 pub use godview_env::{GodViewContext, NetworkTransport, NetworkController, NodeId, TokioContext};
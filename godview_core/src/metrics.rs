@@ -324,7 +324,7 @@ mod tests {
 
         // No neighbors = low ghost score
         let (score, _, _) = calculate_ghost_score(&pos, &vel, &cov, 4, 4, &[], &config);
-        assert!(score >= 0.0 && score <= 1.0);
+        assert!((0.0..=1.0).contains(&score));
     }
 
     #[test]
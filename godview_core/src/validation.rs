@@ -233,7 +233,7 @@ impl ValidationSession {
         
         self.ground_truth
             .entry(actor_id)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(entry);
         
         self.global_metrics.total_ground_truth += 1;
@@ -258,7 +258,7 @@ impl ValidationSession {
         
         self.detections
             .entry(actor_id)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(entry.clone());
         
         self.global_metrics.total_detections += 1;
@@ -280,7 +280,7 @@ impl ValidationSession {
                 // Update per-actor metrics
                 let metrics = self.per_actor_metrics
                     .entry(actor_id)
-                    .or_insert_with(ActorMetrics::new);
+                    .or_default();
                 
                 metrics.detection_count += 1;
                 metrics.error_sum_squared += error * error;
@@ -295,7 +295,7 @@ impl ValidationSession {
                 
                 // Record latency
                 let latency = detection_timestamp - gt.timestamp;
-                if latency >= 0.0 && latency < 1.0 {
+                if (0.0..1.0).contains(&latency) {
                     self.global_metrics.latency_samples.push(latency);
                 }
             }
@@ -391,7 +391,7 @@ impl ValidationReport {
             println!("─────────────────────────────────────────────────────────");
             
             let mut actors: Vec<_> = self.per_actor_metrics.iter().collect();
-            actors.sort_by(|a, b| b.1.detection_count.cmp(&a.1.detection_count));
+            actors.sort_by_key(|(_, m)| std::cmp::Reverse(m.detection_count));
             
             for (actor_id, metrics) in actors.iter().take(5) {
                 println!("  {:>8}    {:>10}    {:>8.3}    {:>12.3}",
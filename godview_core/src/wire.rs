@@ -0,0 +1,324 @@
+//! Versioned wire formats for batches of [`GlobalHazardPacket`]s.
+//!
+//! - **V1** is the original format: a bare JSON array of packets.
+//! - **V2** wraps the batch as `{"version": 2, "packets": [...]}`, renames
+//!   `confidence_score` to `confidence` and adds the sender's 6×6 track
+//!   covariance where it has one.
+//!
+//! Decoding is best-effort in both directions. A V2 codec reads V1 batches
+//! as packets without a covariance. An older codec reads a newer batch by the
+//! fields it knows and ignores the rest; those packets are counted as
+//! downgraded. Unknown fields never fail a decode.
+
+use nalgebra::Matrix6;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::godview_tracking::{GlobalHazardPacket, MeasurementModel};
+
+/// A wire format revision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum PacketVersion {
+    /// Bare array of [`GlobalHazardPacket`]
+    #[default]
+    V1,
+    
+    /// Versioned batch of [`HazardPacketV2`]
+    V2,
+}
+
+impl PacketVersion {
+    /// Newest version this build speaks.
+    pub const LATEST: Self = PacketVersion::V2;
+    
+    /// Version number written on the wire.
+    pub fn number(self) -> u8 {
+        match self {
+            PacketVersion::V1 => 1,
+            PacketVersion::V2 => 2,
+        }
+    }
+    
+    /// Version for a wire number, if this build knows it.
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(PacketVersion::V1),
+            2 => Some(PacketVersion::V2),
+            _ => None,
+        }
+    }
+    
+    /// Version both sides speak: the older of the two.
+    pub fn negotiate(self, peer: Self) -> Self {
+        self.min(peer)
+    }
+}
+
+/// Errors decoding a wire batch.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum WireError {
+    #[error("Malformed packet batch: {0}")]
+    Malformed(String),
+}
+
+/// A packet in the V2 format.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HazardPacketV2 {
+    /// Publisher's local UUID for this object
+    pub entity_id: Uuid,
+    
+    /// Position in the receiver's frame
+    pub position: [f64; 3],
+    
+    /// Velocity vector [vx, vy, vz] in m/s
+    pub velocity: [f64; 3],
+    
+    /// Object class (see [`GlobalHazardPacket::class_id`])
+    pub class_id: u8,
+    
+    /// Unix timestamp (seconds since epoch)
+    pub timestamp: f64,
+    
+    /// Confidence score [0.0 - 1.0] (V1's `confidence_score`)
+    #[serde(alias = "confidence_score")]
+    pub confidence: f64,
+    
+    /// Sender's state covariance for this object, if it tracks one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub covariance: Option<Matrix6<f64>>,
+    
+    /// Threat level (see [`GlobalHazardPacket::priority`])
+    #[serde(default, skip_serializing_if = "crate::godview_tracking::is_routine_priority")]
+    pub priority: u8,
+}
+
+impl HazardPacketV2 {
+    /// Attaches the sender's covariance.
+    pub fn with_covariance(mut self, covariance: Matrix6<f64>) -> Self {
+        self.covariance = Some(covariance);
+        self
+    }
+    
+    /// The packet as V1 carries it (without the covariance).
+    pub fn to_v1(&self) -> GlobalHazardPacket {
+        GlobalHazardPacket {
+            entity_id: self.entity_id,
+            position: self.position,
+            velocity: self.velocity,
+            class_id: self.class_id,
+            timestamp: self.timestamp,
+            confidence_score: self.confidence,
+            measurement_model: MeasurementModel::default(),
+            priority: self.priority,
+        }
+    }
+}
+
+impl From<&GlobalHazardPacket> for HazardPacketV2 {
+    fn from(packet: &GlobalHazardPacket) -> Self {
+        Self {
+            entity_id: packet.entity_id,
+            position: packet.position,
+            velocity: packet.velocity,
+            class_id: packet.class_id,
+            timestamp: packet.timestamp,
+            confidence: packet.confidence_score,
+            covariance: None,
+            priority: packet.priority,
+        }
+    }
+}
+
+/// Any batch this build can read.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum WireBatch {
+    V1(Vec<GlobalHazardPacket>),
+    Versioned { version: u8, packets: Vec<HazardPacketV2> },
+}
+
+/// Versioned batch in the V2 layout.
+#[derive(Serialize)]
+struct VersionedBatch<'a> {
+    version: u8,
+    packets: &'a [HazardPacketV2],
+}
+
+/// A decoded batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedBatch {
+    /// Version number on the wire (may be newer than any [`PacketVersion`])
+    pub wire_version: u8,
+    
+    /// Packets as the decoding codec understands them
+    pub packets: Vec<HazardPacketV2>,
+    
+    /// Packets read from a newer version than the codec speaks
+    pub downgraded: usize,
+}
+
+impl DecodedBatch {
+    /// The packets in the in-memory format.
+    pub fn to_v1(&self) -> Vec<GlobalHazardPacket> {
+        self.packets.iter().map(HazardPacketV2::to_v1).collect()
+    }
+}
+
+/// Encodes and decodes batches for a side that speaks up to `version`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PacketCodec {
+    version: PacketVersion,
+}
+
+impl PacketCodec {
+    /// Creates a codec speaking up to `version`.
+    pub fn new(version: PacketVersion) -> Self {
+        Self { version }
+    }
+    
+    /// Newest version this codec speaks.
+    pub fn version(&self) -> PacketVersion {
+        self.version
+    }
+    
+    /// Encodes `packets` in this codec's version.
+    pub fn encode(&self, packets: &[HazardPacketV2]) -> Result<Vec<u8>, WireError> {
+        self.encode_as(packets, self.version)
+    }
+    
+    /// Encodes `packets` in the version negotiated with a peer speaking
+    /// `peer`. V1 drops covariances.
+    pub fn encode_as(&self, packets: &[HazardPacketV2], peer: PacketVersion) -> Result<Vec<u8>, WireError> {
+        let encoded = match self.version.negotiate(peer) {
+            PacketVersion::V1 => {
+                let v1: Vec<GlobalHazardPacket> = packets.iter().map(HazardPacketV2::to_v1).collect();
+                serde_json::to_vec(&v1)
+            }
+            PacketVersion::V2 => serde_json::to_vec(&VersionedBatch { version: 2, packets }),
+        };
+        encoded.map_err(|e| WireError::Malformed(e.to_string()))
+    }
+    
+    /// Decodes a batch of any version, downgrading what this codec doesn't
+    /// speak.
+    pub fn decode(&self, bytes: &[u8]) -> Result<DecodedBatch, WireError> {
+        let batch: WireBatch = serde_json::from_slice(bytes).map_err(|e| WireError::Malformed(e.to_string()))?;
+        match batch {
+            WireBatch::V1(packets) => Ok(DecodedBatch {
+                wire_version: 1,
+                packets: packets.iter().map(HazardPacketV2::from).collect(),
+                downgraded: 0,
+            }),
+            WireBatch::Versioned { version, mut packets } => {
+                let newer = version > self.version.number();
+                if newer && self.version < PacketVersion::V2 {
+                    for packet in &mut packets {
+                        packet.covariance = None;
+                    }
+                }
+                Ok(DecodedBatch {
+                    wire_version: version,
+                    downgraded: if newer { packets.len() } else { 0 },
+                    packets,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn packet(id: u128, confidence_score: f64) -> GlobalHazardPacket {
+        GlobalHazardPacket {
+            entity_id: Uuid::from_u128(id),
+            position: [10.0, -4.0, 100.0],
+            velocity: [3.0, 0.5, 0.0],
+            class_id: 4,
+            timestamp: 12.5,
+            confidence_score,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        }
+    }
+    
+    fn v2_batch() -> Vec<HazardPacketV2> {
+        let covariance = Matrix6::from_diagonal(&nalgebra::Vector6::new(1.0, 1.0, 4.0, 0.5, 0.5, 0.5));
+        vec![
+            HazardPacketV2::from(&packet(1, 0.9)).with_covariance(covariance),
+            HazardPacketV2::from(&packet(2, 0.6)),
+        ]
+    }
+    
+    #[test]
+    fn test_v1_round_trip_matches_legacy_bytes() {
+        let packets = vec![packet(1, 0.9), packet(2, 0.6)];
+        let codec = PacketCodec::new(PacketVersion::V1);
+        let bytes = codec.encode(&packets.iter().map(HazardPacketV2::from).collect::<Vec<_>>()).unwrap();
+        assert_eq!(bytes, serde_json::to_vec(&packets).unwrap());
+        
+        let decoded = codec.decode(&bytes).unwrap();
+        assert_eq!((decoded.wire_version, decoded.downgraded), (1, 0));
+        assert_eq!(serde_json::to_vec(&decoded.to_v1()).unwrap(), bytes);
+    }
+    
+    #[test]
+    fn test_v2_round_trip_keeps_covariance() {
+        let codec = PacketCodec::new(PacketVersion::V2);
+        let bytes = codec.encode(&v2_batch()).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["version"], 2);
+        assert_eq!(json["packets"][0]["confidence"], 0.9);
+        assert!(json["packets"][1].get("covariance").is_none());
+        
+        let decoded = codec.decode(&bytes).unwrap();
+        assert_eq!((decoded.wire_version, decoded.downgraded), (2, 0));
+        assert_eq!(decoded.packets, v2_batch());
+    }
+    
+    #[test]
+    fn test_cross_version_decode() {
+        let v1 = PacketCodec::new(PacketVersion::V1);
+        let v2 = PacketCodec::new(PacketVersion::V2);
+        
+        // V1 reads V2 by the fields it knows, confidence included
+        let decoded = v1.decode(&v2.encode(&v2_batch()).unwrap()).unwrap();
+        assert_eq!(decoded.downgraded, 2);
+        assert!(decoded.packets.iter().all(|p| p.covariance.is_none()));
+        assert_eq!(decoded.to_v1()[0].confidence_score, 0.9);
+        
+        // V2 reads V1 as packets without a covariance
+        let decoded = v2.decode(&v1.encode(&v2_batch()).unwrap()).unwrap();
+        assert_eq!((decoded.wire_version, decoded.downgraded), (1, 0));
+        assert_eq!(decoded.packets[0].covariance, None);
+        assert_eq!(decoded.packets[1].confidence, 0.6);
+        
+        // Negotiating with a V1 peer writes V1
+        assert_eq!(PacketVersion::V2.negotiate(PacketVersion::V1), PacketVersion::V1);
+        let bytes = v2.encode_as(&v2_batch(), PacketVersion::V1).unwrap();
+        assert_eq!(v1.decode(&bytes).unwrap().wire_version, 1);
+    }
+    
+    #[test]
+    fn test_unknown_fields_and_versions_are_tolerated() {
+        let bytes = br#"{"version": 3, "extension": {"a": 1}, "packets": [{
+            "entity_id": "00000000-0000-0000-0000-000000000007",
+            "position": [1.0, 2.0, 3.0], "velocity": [0.0, 0.0, 0.0],
+            "class_id": 4, "timestamp": 1.0, "confidence": 0.8,
+            "covariance": null, "radar_cross_section": 2.5
+        }]}"#;
+        for version in [PacketVersion::V1, PacketVersion::V2] {
+            let decoded = PacketCodec::new(version).decode(bytes).unwrap();
+            assert_eq!((decoded.wire_version, decoded.downgraded), (3, 1));
+            assert_eq!(decoded.packets[0].entity_id, Uuid::from_u128(7));
+        }
+        assert_eq!(PacketVersion::from_number(3), None);
+        
+        let v1_with_extra = br#"[{"entity_id": "00000000-0000-0000-0000-000000000007",
+            "position": [1.0, 2.0, 3.0], "velocity": [0.0, 0.0, 0.0], "class_id": 4,
+            "timestamp": 1.0, "confidence_score": 0.8, "dialect": "legacy"}]"#;
+        assert_eq!(PacketCodec::default().decode(v1_with_extra).unwrap().packets.len(), 1);
+        
+        assert!(matches!(PacketCodec::default().decode(b"{\"packets\": 5}"), Err(WireError::Malformed(_))));
+    }
+}
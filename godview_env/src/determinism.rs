@@ -0,0 +1,185 @@
+//! Guards against entropy and wall-clock time leaking into simulation.
+//!
+//! A simulation run must draw all its randomness from its seed and read
+//! time only from its virtual clock, or its seed stops reproducing it.
+//! Production code gets OS entropy and the system clock through
+//! [`Entropy::os`] and [`WallClock::system`]. With the `sim` feature those
+//! two constructors panic on a thread that has entered a simulation
+//! ([`enter_simulation`]), so a production pattern copied into simulation
+//! code fails the first test that runs it. Without the feature the check
+//! compiles away.
+//!
+//! The flag is per thread, so a simulation never trips production code on
+//! another thread of the same process, such as a test running alongside
+//! it. Threads a simulation starts for its own work enter it too (the sim's
+//! agent pool does so for its workers). Once set it stays set for the
+//! thread's life.
+
+use rand::rngs::OsRng;
+use rand::{CryptoRng, RngCore};
+use std::cell::Cell;
+use std::time::{Duration, Instant, SystemTime};
+
+thread_local! {
+    static SIMULATION: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Marks the calling thread as running a simulation: from now on
+/// [`Entropy::os`] and [`WallClock::system`] panic on it (with the `sim`
+/// feature).
+pub fn enter_simulation() {
+    SIMULATION.with(|simulation| simulation.set(true));
+}
+
+/// True once [`enter_simulation`] has been called on this thread.
+pub fn in_simulation() -> bool {
+    SIMULATION.with(Cell::get)
+}
+
+/// Panics if `source` is read inside a simulation.
+#[track_caller]
+fn forbid_in_simulation(source: &str) {
+    if cfg!(feature = "sim") && in_simulation() {
+        panic!(
+            "nondeterminism in a simulation: {source} was requested after enter_simulation(); \
+             derive it from the run's seed or virtual clock instead"
+        );
+    }
+}
+
+/// A source of randomness: the OS, or a generator the caller seeded.
+pub struct Entropy {
+    source: EntropySource,
+}
+
+enum EntropySource {
+    Os(OsRng),
+    Seeded(Box<dyn RngCore + Send>),
+}
+
+impl Entropy {
+    /// OS entropy. Panics inside a simulation (see [module docs](self)).
+    #[track_caller]
+    pub fn os() -> Self {
+        forbid_in_simulation("OS entropy (OsRng)");
+        Self { source: EntropySource::Os(OsRng) }
+    }
+    
+    /// Draws from `rng`, e.g. a generator seeded from the run's seed.
+    pub fn from_rng<R: RngCore + CryptoRng + Send + 'static>(rng: R) -> Self {
+        Self { source: EntropySource::Seeded(Box::new(rng)) }
+    }
+    
+    /// True if this draws from the OS.
+    pub fn is_os(&self) -> bool {
+        matches!(self.source, EntropySource::Os(_))
+    }
+    
+    fn rng(&mut self) -> &mut dyn RngCore {
+        match &mut self.source {
+            EntropySource::Os(rng) => rng,
+            EntropySource::Seeded(rng) => rng.as_mut(),
+        }
+    }
+}
+
+impl RngCore for Entropy {
+    fn next_u32(&mut self) -> u32 {
+        self.rng().next_u32()
+    }
+    
+    fn next_u64(&mut self) -> u64 {
+        self.rng().next_u64()
+    }
+    
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng().fill_bytes(dest)
+    }
+    
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng().try_fill_bytes(dest)
+    }
+}
+
+// Both sources are cryptographic: OsRng, or a generator from_rng required to be `CryptoRng`
+impl CryptoRng for Entropy {}
+
+/// A clock: the system's, or one stopped at a fixed instant.
+#[derive(Debug, Clone)]
+pub struct WallClock {
+    source: ClockSource,
+}
+
+#[derive(Debug, Clone)]
+enum ClockSource {
+    System { start: Instant },
+    Fixed(SystemTime),
+}
+
+impl WallClock {
+    /// The system clock. Panics inside a simulation (see
+    /// [module docs](self)).
+    #[track_caller]
+    pub fn system() -> Self {
+        forbid_in_simulation("the system clock (SystemTime)");
+        Self { source: ClockSource::System { start: Instant::now() } }
+    }
+    
+    /// A clock that always reads `at`.
+    pub fn fixed(at: SystemTime) -> Self {
+        Self { source: ClockSource::Fixed(at) }
+    }
+    
+    /// Current time on this clock.
+    pub fn now(&self) -> SystemTime {
+        match self.source {
+            ClockSource::System { .. } => SystemTime::now(),
+            ClockSource::Fixed(at) => at,
+        }
+    }
+    
+    /// Monotonic time since the clock was created (zero for a fixed clock).
+    pub fn elapsed(&self) -> Duration {
+        match self.source {
+            ClockSource::System { start } => start.elapsed(),
+            ClockSource::Fixed(_) => Duration::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    
+    #[test]
+    fn test_seeded_entropy_and_fixed_clocks_repeat() {
+        let draws = |seed| {
+            let mut entropy = Entropy::from_rng(StdRng::seed_from_u64(seed));
+            assert!(!entropy.is_os());
+            (entropy.next_u64(), entropy.next_u32())
+        };
+        assert_eq!(draws(7), draws(7));
+        assert_ne!(draws(7), draws(8));
+        
+        let at = SystemTime::UNIX_EPOCH + Duration::from_secs(1704067200);
+        let clock = WallClock::fixed(at);
+        assert_eq!(clock.now(), at);
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+    }
+    
+    #[test]
+    fn test_simulation_is_entered_per_thread() {
+        let simulating = std::thread::spawn(|| {
+            enter_simulation();
+            in_simulation()
+        });
+        assert!(simulating.join().unwrap());
+        
+        // This thread never entered one, so production sources still work
+        assert!(!in_simulation());
+        assert!(Entropy::os().is_os());
+        WallClock::system();
+    }
+}
@@ -13,6 +13,9 @@
 //! By deriving all entropy from a single 64-bit seed, any bug becomes
 //! reproducible via its seed number.
 //!
+//! [`determinism`] catches code that reaches past the context for OS
+//! entropy or the system clock while a simulation runs.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -30,16 +33,42 @@
 //!     }
 //! }
 //! ```
+//!
+//! # What is guaranteed identical
+//!
+//! `godview_sim/tests/env_conformance.rs` runs one agent loop, written only
+//! against these traits, under [`TokioContext`] with a [`LoopbackNetwork`]
+//! and under godview_sim's `SimContext` + `SimExecutor` with a
+//! `SimNetwork::mesh`, and checks that both make the same sends, in the
+//! same order. Given the same link latencies, both environments guarantee:
+//!
+//! - `sleep(d)` resumes no earlier than `d` after it was called, on the
+//!   context's clock, and `now()` never goes backwards
+//! - each directed link delivers in send order, no earlier than its latency
+//!   after the send, without loss or duplication
+//! - `broadcast` reaches every other connected node, in a fixed order, and
+//!   returns how many it was sent to
+//! - [`recv_timeout`] returns a packet that is ready before its timer
+//!   fires, and `EnvError::Timeout` otherwise
+//!
+//! Exact instants are not: tokio sleeps overshoot by scheduler jitter while
+//! the simulator resumes exactly on the deadline, and packets on different
+//! links due at the same instant may interleave differently under tokio.
+//! Code whose sends must match across environments should keep its
+//! decisions a tick's margin away from these races.
 
 mod context;
 mod network;
 mod types;
 mod error;
 mod tokio_impl;
+mod loopback;
+pub mod determinism;
 
 pub use context::GodViewContext;
-pub use network::{NetworkTransport, NetworkController};
+pub use network::{NetworkTransport, NetworkController, recv_timeout};
 pub use types::{NodeId, SignedPacketEnvelope};
 pub use error::EnvError;
 pub use tokio_impl::TokioContext;
+pub use loopback::{LoopbackNetwork, LoopbackTransport};
 
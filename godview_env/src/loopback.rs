@@ -0,0 +1,151 @@
+//! In-process network transport over tokio, for tests.
+
+use crate::error::EnvError;
+use crate::network::NetworkTransport;
+use crate::types::{NodeId, SignedPacketEnvelope};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+/// A packet in flight on a link, due at its deadline.
+type InFlight = (Instant, NodeId, SignedPacketEnvelope);
+
+/// An endpoint's inbox of (sender, packet).
+type Inbox = mpsc::UnboundedSender<(NodeId, SignedPacketEnvelope)>;
+
+/// An in-process network connecting [`LoopbackTransport`] endpoints with a
+/// fixed one-way latency.
+///
+/// Each directed link delivers in send order: a forwarding task per link
+/// holds every packet until `latency` after it was sent. Packets on
+/// different links that come due at the same instant arrive in whichever
+/// order tokio's timers fire. Nothing is lost or duplicated.
+pub struct LoopbackNetwork {
+    latency: Duration,
+    
+    /// Inbox of every endpoint, in registration order
+    inboxes: Mutex<Vec<(NodeId, Inbox)>>,
+    
+    /// Forwarding task queue of every link used so far
+    links: Mutex<HashMap<(NodeId, NodeId), mpsc::UnboundedSender<InFlight>>>,
+}
+
+impl LoopbackNetwork {
+    /// Creates a network with no endpoints that delivers after `latency`.
+    pub fn new(latency: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            latency,
+            inboxes: Mutex::new(Vec::new()),
+            links: Mutex::new(HashMap::new()),
+        })
+    }
+    
+    /// Adds an endpoint for `id`, replacing any earlier one.
+    pub fn endpoint(self: &Arc<Self>, id: NodeId) -> LoopbackTransport {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut inboxes = self.inboxes.lock().unwrap();
+        inboxes.retain(|(node, _)| *node != id);
+        inboxes.push((id, tx));
+        LoopbackTransport {
+            local_id: id,
+            network: Arc::clone(self),
+            rx: tokio::sync::Mutex::new(rx),
+        }
+    }
+    
+    /// Queues `packet` on the link `from -> to`, starting the link's
+    /// forwarding task on first use (must be called inside a tokio runtime).
+    fn send(&self, from: NodeId, to: NodeId, packet: SignedPacketEnvelope) -> Result<(), EnvError> {
+        let inbox = self.inboxes.lock().unwrap().iter()
+            .find(|(node, _)| *node == to)
+            .map(|(_, tx)| tx.clone())
+            .ok_or_else(|| EnvError::unreachable(to))?;
+        let mut links = self.links.lock().unwrap();
+        let link = links.entry((from, to)).or_insert_with(|| {
+            let (tx, mut rx) = mpsc::unbounded_channel::<InFlight>();
+            tokio::spawn(async move {
+                while let Some((due, sender, packet)) = rx.recv().await {
+                    tokio::time::sleep_until(due).await;
+                    if inbox.send((sender, packet)).is_err() {
+                        break;
+                    }
+                }
+            });
+            tx
+        });
+        link.send((Instant::now() + self.latency, from, packet))
+            .map_err(|_| EnvError::network("Link closed"))
+    }
+    
+    /// Every endpoint but `except`, in registration order.
+    fn peers(&self, except: NodeId) -> Vec<NodeId> {
+        self.inboxes.lock().unwrap().iter()
+            .map(|(node, _)| *node)
+            .filter(|node| *node != except)
+            .collect()
+    }
+}
+
+/// One node's endpoint on a [`LoopbackNetwork`].
+pub struct LoopbackTransport {
+    local_id: NodeId,
+    network: Arc<LoopbackNetwork>,
+    rx: tokio::sync::Mutex<mpsc::UnboundedReceiver<(NodeId, SignedPacketEnvelope)>>,
+}
+
+#[async_trait]
+impl NetworkTransport for LoopbackTransport {
+    async fn send(&self, target: NodeId, packet: SignedPacketEnvelope) -> Result<(), EnvError> {
+        self.network.send(self.local_id, target, packet)
+    }
+    
+    async fn recv(&self) -> Option<(NodeId, SignedPacketEnvelope)> {
+        self.rx.lock().await.recv().await
+    }
+    
+    async fn broadcast(&self, packet: SignedPacketEnvelope) -> usize {
+        self.network.peers(self.local_id)
+            .into_iter()
+            .filter(|peer| self.network.send(self.local_id, *peer, packet.clone()).is_ok())
+            .count()
+    }
+    
+    fn local_id(&self) -> NodeId {
+        self.local_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::recv_timeout;
+    use crate::TokioContext;
+    
+    #[tokio::test]
+    async fn test_loopback_delivers_in_order_after_latency() {
+        let network = LoopbackNetwork::new(Duration::from_millis(20));
+        let (a, b, c) = (NodeId::from_seed(1), NodeId::from_seed(2), NodeId::from_seed(3));
+        let (net_a, net_b, _net_c) = (network.endpoint(a), network.endpoint(b), network.endpoint(c));
+        let ctx = TokioContext::new();
+        
+        let sent = Instant::now();
+        for i in 0..3u64 {
+            net_a.send(b, SignedPacketEnvelope::new(vec![i as u8], i)).await.unwrap();
+        }
+        assert_eq!(net_a.broadcast(SignedPacketEnvelope::new(vec![9], 3)).await, 2);
+        
+        let mut payloads = Vec::new();
+        while let Ok((from, packet)) = recv_timeout(&ctx, &net_b, Duration::from_millis(200)).await {
+            assert_eq!(from, a);
+            payloads.push(packet.payload[0]);
+        }
+        assert!(sent.elapsed() >= Duration::from_millis(20));
+        assert_eq!(payloads, [0, 1, 2, 9]);
+        
+        let unknown = net_a.send(NodeId::from_seed(4), SignedPacketEnvelope::new(vec![], 0)).await;
+        assert!(matches!(unknown, Err(EnvError::NodeUnreachable(_))));
+    }
+}
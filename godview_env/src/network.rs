@@ -1,8 +1,12 @@
 //! Network transport abstraction for GodView agents.
 
 use async_trait::async_trait;
+use crate::context::GodViewContext;
 use crate::error::EnvError;
 use crate::types::{NodeId, SignedPacketEnvelope};
+use std::future::poll_fn;
+use std::task::Poll;
+use std::time::Duration;
 
 /// Abstraction for network I/O between GodView agents.
 ///
@@ -59,6 +63,36 @@ pub trait NetworkTransport: Send + Sync + 'static {
     fn local_id(&self) -> NodeId;
 }
 
+/// Receives the next packet for `net`, giving up after `timeout` on `ctx`'s
+/// clock.
+///
+/// The receive and the timer race: whichever is ready first wins, and a
+/// packet that is ready when the timer expires is still returned. Under
+/// simulation the timer is virtual, so the timeout costs no real time.
+///
+/// # Returns
+/// * `Ok((sender, packet))` - A packet arrived in time
+/// * `Err(EnvError::Timeout)` - Nothing arrived within `timeout`
+/// * `Err(EnvError::NetworkError)` - The channel closed
+pub async fn recv_timeout<C, N>(ctx: &C, net: &N, timeout: Duration) -> Result<(NodeId, SignedPacketEnvelope), EnvError>
+where
+    C: GodViewContext,
+    N: NetworkTransport + ?Sized,
+{
+    let mut packet = net.recv();
+    let mut timer = ctx.sleep(timeout);
+    poll_fn(|cx| {
+        if let Poll::Ready(received) = packet.as_mut().poll(cx) {
+            return Poll::Ready(received.ok_or_else(|| EnvError::network("Channel closed")));
+        }
+        if timer.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(Err(EnvError::Timeout(timeout.as_millis() as u64)));
+        }
+        Poll::Pending
+    })
+    .await
+}
+
 /// Marker trait for network controllers in simulation.
 ///
 /// Allows injecting faults like partitions and latency.
@@ -1,25 +1,27 @@
 //! Production implementation of GodViewContext using Tokio.
 
+use crate::determinism::{Entropy, WallClock};
 use crate::GodViewContext;
 use async_trait::async_trait;
 use ed25519_dalek::SigningKey;
 use std::sync::Arc;
-use std::time::{Duration, Instant, SystemTime};
+use std::time::{Duration, SystemTime};
 
 /// Production context backed by Tokio and OS entropy.
 ///
 /// This is the "real" implementation used in production deployments.
-/// Time comes from the system clock, randomness from OsRng.
+/// Time comes from the system clock, randomness from OsRng. Building one
+/// inside a simulation panics (see [`crate::determinism`]).
 pub struct TokioContext {
-    /// Start time for monotonic duration calculations
-    start: Instant,
+    /// System clock; its start anchors monotonic durations
+    clock: WallClock,
 }
 
 impl TokioContext {
     /// Creates a new TokioContext.
     pub fn new() -> Self {
         Self {
-            start: Instant::now(),
+            clock: WallClock::system(),
         }
     }
     
@@ -38,11 +40,11 @@ impl Default for TokioContext {
 #[async_trait]
 impl GodViewContext for TokioContext {
     fn now(&self) -> Duration {
-        self.start.elapsed()
+        self.clock.elapsed()
     }
     
     fn system_time(&self) -> SystemTime {
-        SystemTime::now()
+        self.clock.now()
     }
     
     async fn sleep(&self, duration: Duration) {
@@ -61,8 +63,7 @@ impl GodViewContext for TokioContext {
     
     fn derive_signing_key(&self, _seed_extension: u64) -> SigningKey {
         // In production, generate a truly random key
-        use rand::rngs::OsRng;
-        SigningKey::generate(&mut OsRng)
+        SigningKey::generate(&mut Entropy::os())
     }
     
     fn seed(&self) -> u64 {
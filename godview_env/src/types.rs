@@ -62,18 +62,60 @@ pub struct SignedPacketEnvelope {
     
     /// Optional routing hint for multi-hop delivery
     pub hop_count: u8,
+    
+    /// Hops the envelope may still travel, counting the one in flight
+    /// (None: never expires)
+    #[serde(default)]
+    pub ttl: Option<u8>,
+    
+    /// Sender's sequence number for this hop, counting every envelope it
+    /// sends and wrapping at u32::MAX (None: unsequenced)
+    #[serde(default)]
+    pub sequence: Option<u32>,
 }
 
 impl SignedPacketEnvelope {
-    /// Creates a new envelope from payload bytes.
+    /// Creates a new envelope from payload bytes, good for a single hop.
     pub fn new(payload: Vec<u8>, timestamp_ms: u64) -> Self {
         Self {
             payload,
             timestamp_ms,
             hop_count: 0,
+            ttl: Some(1),
+            sequence: None,
         }
     }
     
+    /// Sets how many hops the envelope may travel (at least 1; None: never
+    /// expires).
+    pub fn with_ttl(mut self, ttl: Option<u8>) -> Self {
+        self.ttl = ttl.map(|hops| hops.max(1));
+        self
+    }
+    
+    /// Stamps the sender's sequence number for the hop in flight.
+    pub fn with_sequence(mut self, sequence: u32) -> Self {
+        self.sequence = Some(sequence);
+        self
+    }
+    
+    /// The envelope a relay re-broadcasts at `now_ms`: one hop further and
+    /// one hop less to live, or None if its TTL is spent. The sequence
+    /// number is cleared for the relay to stamp its own.
+    pub fn relayed(&self, now_ms: u64) -> Option<Self> {
+        let ttl = match self.ttl {
+            Some(hops) => Some(hops.checked_sub(1).filter(|&left| left > 0)?),
+            None => None,
+        };
+        Some(Self {
+            payload: self.payload.clone(),
+            timestamp_ms: now_ms,
+            hop_count: self.hop_count.saturating_add(1),
+            ttl,
+            sequence: None,
+        })
+    }
+    
     /// Returns the payload size in bytes.
     pub fn size(&self) -> usize {
         self.payload.len()
@@ -0,0 +1,40 @@
+"""Smoke test of the Python bindings: `make python-smoke` builds the wheel
+and runs it."""
+
+import unittest
+
+import godview_sim
+
+# Asserted by test_time_warp_json_reports_the_seeded_rms in src/runner.rs
+TIME_WARP_SEED_42_1S_RMS = 0.7625483875814048
+
+
+class SmokeTest(unittest.TestCase):
+    def test_time_warp_matches_the_rust_run(self):
+        result = godview_sim.run_scenario("time_warp", seed=42, duration=1.0)
+        self.assertTrue(result["passed"], result["failure_reason"])
+        self.assertEqual(result["rms_mean"], TIME_WARP_SEED_42_1S_RMS)
+
+    def test_sweep_runs_each_seed(self):
+        results = godview_sim.run_sweep("time_warp", [1, 2], duration=0.5, overrides={"agents": 3})
+        self.assertEqual([r["seed"] for r in results], [1, 2])
+
+    def test_frames_step_lazily(self):
+        frames = godview_sim.frames("time_warp", seed=42, duration=1.0, verbose=True)
+        self.assertEqual(len(frames), 30)
+        first = next(frames)
+        self.assertEqual(first["tick"], 0)
+        self.assertEqual(len(frames), 29)
+        self.assertEqual(len(list(frames)), 29)
+
+    def test_bad_arguments_raise_value_error(self):
+        with self.assertRaises(ValueError):
+            godview_sim.run_scenario("no_such_scenario")
+        with self.assertRaises(ValueError):
+            godview_sim.run_scenario("time_warp", overrides={"no_such_option": 1})
+        with self.assertRaises(ValueError):
+            godview_sim.frames("byzantine")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -11,4 +11,12 @@ pub use godview_core::godview_trust::{
     NeighborReputation,
     TrackConfidence,
     AdaptiveMetrics,
+    TrustInflation,
+    GossipQuota,
+    GossipDecision,
+    GossipDecisionCounts,
+    GossipDecisionLog,
+    GossipDecisionRecord,
+    GOSSIP_FILTER_THRESHOLD,
+    NEUTRAL_RELIABILITY,
 };
@@ -6,21 +6,195 @@
 //! - Packet processing loop
 //! - Metric collection
 //! - Adaptive learning (neighbor reputation, track confidence)
+//! - Per-sensor bias calibration against surveyed references
+//! - Quarantine of entity IDs introduced by distrusted neighbors
+//! - Per-neighbor gossip quotas, shrunk for neighbors of low reputation
+//! - Signed gossip envelopes verified against a key registry, with their
+//!   one-way delivery delay measured from the envelope's send timestamp
+//! - Optional track genealogy recording
+//! - Gossip ordered by information value when bandwidth is short
+//! - Optional alignment of gossip timestamps to the local clock, with
+//!   per-neighbor clock offset estimation
+//! - Gossip encoded in a configurable wire version, newer versions decoded
+//!   best-effort
+//! - Broadcast confidence derived from the sending track's covariance
+//! - Dead-reckoned pose error while GPS is denied, and down-weighting of
+//!   neighbors whose gossip sits at a systematic offset
 
-use crate::adaptive::AdaptiveState;
-use crate::evolution::{EvolutionaryState, FitnessProvider, OracleFitness};
+use crate::adaptive::{AdaptiveState, GossipDecision, GossipDecisionLog, GossipDecisionRecord, GossipQuota, TrustInflation};
+use crate::anti_entropy::{digest_response, GossipDigest};
+use crate::reputation_gossip::{blend_summary, ReputationSummary};
+use crate::gossip_sequence::{GossipNack, Retransmission, RetransmitBuffer, SequenceOutcome, SequenceRange, SequenceStats, SequenceTracker};
+use crate::clock_sync::{ClockOffsetEstimator, OffsetEstimate, TimeAlignment, MAX_ALIGNMENT_SECS};
+use crate::evolution::{EpochRecord, EvoParams, EvolutionaryState, FitnessProvider, OracleFitness};
 use crate::context::SimContext;
-use crate::network::SimNetwork;
-use crate::oracle::SensorReading;
+use crate::event_bus::{SimEvent, SimEventBus};
+use crate::genealogy::{GenealogyEvent, TrackGenealogy};
+use crate::keys::{KeyRegistry, KeyStatus};
+use crate::network::{LatencyHistogram, SimNetwork};
+use crate::oracle::{Oracle, SensorReading};
+use crate::rng_audit::AuditedRng;
+use crate::priority::{GossipOrder, GossipPriority, PacketValue, STALENESS_HORIZON_ROUNDS};
 
-use godview_core::{GodViewAgent, AgentConfig};
-use godview_core::godview_tracking::GlobalHazardPacket;
-use godview_env::NodeId;
-use nalgebra::Vector3;
+use godview_core::{GodViewAgent, AgentConfig, ConfigError, Frame, SignedPacket, TrackManager, TrackingConfig, UniqueTrack, GhostScoreConfig, calculate_ghost_score};
+use godview_core::godview_tracking::{GlobalHazardPacket, MeasurementModel, TrackStateCounts, TrackingError};
+use godview_core::godview_trust::{NeighborReputation, PacketMetadata, TrackConfidence};
+use godview_core::wire::{HazardPacketV2, PacketCodec, PacketVersion};
+use ed25519_dalek::{Signature, SigningKey, VerifyingKey};
+use godview_env::{NodeId, SignedPacketEnvelope};
+use nalgebra::{Matrix6, Vector3};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
-use rand::SeedableRng;
-use rand_chacha::ChaCha8Rng;
+
+/// Smoothing gain for per-sensor bias calibration.
+const SENSOR_BIAS_GAIN: f64 = 0.05;
+
+/// Smoothing gain for each neighbor's estimated gossip offset.
+const PEER_OFFSET_GAIN: f64 = 0.1;
+
+/// Speed a pose error shrinks at once GPS is back (m/s).
+const POSE_RECONVERGENCE_MPS: f64 = 5.0;
+
+/// Signature, public key and metadata length at the front of a gossip envelope.
+const ENVELOPE_HEADER_LEN: usize = 64 + 32 + 4;
+
+/// Resolution of an envelope's send timestamp (s).
+const SEND_TIMESTAMP_RESOLUTION_SECS: f64 = 0.001;
+
+/// How an agent sets the confidence of the packets it gossips.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BroadcastConfidence {
+    /// Every packet claims the reading confidence
+    Fixed,
+    
+    /// [`TrackManager::covariance_to_confidence`] of the track the reading
+    /// updated, clamped to `[min, max]` and never above the reading
+    /// confidence, so a fresh or coasting track is weighted less by the
+    /// receiver than a long-lived one
+    FromCovariance { min: f64, max: f64 },
+}
+
+impl Default for BroadcastConfidence {
+    fn default() -> Self {
+        Self::FromCovariance { min: 0.5, max: 0.95 }
+    }
+}
+
+/// Error in an agent's belief about its own position.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct PoseError {
+    /// Drift of the dead-reckoned position while GPS is denied (m/s;
+    /// None while the agent has a fix)
+    drift_mps: Option<Vector3<f64>>,
+    
+    /// Believed position minus true position (m)
+    offset: Vector3<f64>,
+}
+
+/// Whether an agent re-broadcasts the signed gossip envelopes it accepts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GossipRelay {
+    /// Only the agent's own envelopes are sent, each good for one hop
+    #[default]
+    Off,
+    
+    /// Accepted envelopes are forwarded to every neighbor until their TTL
+    /// runs out; the agent's own envelopes start with this many hops
+    Hops(u8),
+    
+    /// Accepted envelopes are forwarded forever. Anything gossiped keeps
+    /// circulating, refreshing tracks of entities long gone; a baseline
+    /// for [`Self::Hops`], not a setting to run with
+    Unbounded,
+}
+
+/// How gossip is exchanged between agents.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GossipMode {
+    /// Agents only push what they sensed since their last round
+    #[default]
+    Push,
+    
+    /// Agents push, and every this many ticks also pull from one random
+    /// neighbor by sending it a digest (see [`crate::GossipDigest`])
+    PushPull { digest_interval_ticks: u64 },
+}
+
+/// Why a signed gossip envelope was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum GossipRejection {
+    #[error("signature does not match payload")]
+    BadSignature,
+    
+    #[error("signer {0:?} has no registered key matching the envelope")]
+    UnknownSigner(Option<u64>),
+    
+    #[error("agent {agent_id} signed with its retired epoch-{epoch} key")]
+    StaleKey { agent_id: u64, epoch: u64 },
+    
+    #[error("payload is not a hazard packet batch")]
+    Malformed,
+}
+
+/// Counters for signed gossip envelopes received by an agent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SignedGossipStats {
+    /// Envelopes that verified and were processed
+    pub accepted: u64,
+    
+    /// Envelopes whose signature didn't verify
+    pub bad_signature: u64,
+    
+    /// Envelopes from an unregistered signer or key
+    pub unknown_signer: u64,
+    
+    /// Envelopes signed with a key retired by rotation
+    pub stale_key: u64,
+    
+    /// Envelopes whose payload couldn't be decoded
+    pub malformed: u64,
+    
+    /// Packets decoded from a newer wire version than this agent speaks
+    pub downgraded_packets: u64,
+}
+
+impl SignedGossipStats {
+    /// Total envelopes rejected for any reason.
+    pub fn rejected(&self) -> u64 {
+        self.bad_signature + self.unknown_signer + self.stale_key + self.malformed
+    }
+}
+
+/// Delivery measurements for gossip envelopes received by an agent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkStats {
+    /// One-way delay of each envelope, from its send timestamp to receipt
+    pub latency: LatencyHistogram,
+    
+    /// Packets whose own timestamp claimed to be newer than the envelope
+    /// carrying them, and were re-stamped with the envelope's send time
+    pub clamped_timestamps: u64,
+    
+    /// Gossip packets older than the staleness horizon, dropped before
+    /// fusion
+    pub stale_packets: u64,
+    
+    /// Envelopes forwarded on to neighbors with their TTL decremented
+    pub relayed_envelopes: u64,
+    
+    /// Anti-entropy digests answered
+    pub digests_answered: u64,
+    
+    /// Packets sent in answer to digests
+    pub digest_packets: u64,
+    
+    /// NACKs sent asking neighbors to resend missing envelopes
+    pub nacks_sent: u64,
+    
+    /// Envelopes resent in answer to neighbors' NACKs
+    pub retransmitted_envelopes: u64,
+}
 
 /// A simulated agent running in the deterministic environment.
 pub struct SimulatedAgent {
@@ -31,7 +205,7 @@ pub struct SimulatedAgent {
     agent_index: u64,
     
     /// Tracks created by this agent (entity_id -> track_id)
-    entity_track_map: std::collections::HashMap<u64, Uuid>,
+    entity_track_map: HashMap<u64, Uuid>,
     
     /// Metrics: total readings processed
     readings_processed: u64,
@@ -55,10 +229,115 @@ pub struct SimulatedAgent {
     fitness_provider: Box<dyn FitnessProvider>,
 
     /// RNG for evolutionary decisions
-    rng: ChaCha8Rng,
+    rng: AuditedRng,
     
     /// Current energy level (Joules)
     energy: f64,
+    
+    /// Energy every episode starts with (Joules)
+    energy_capacity: f64,
+    
+    /// Key used to sign outgoing gossip envelopes
+    signing_key: Option<SigningKey>,
+    
+    /// Signed gossip verification counters
+    signed_gossip: SignedGossipStats,
+    
+    /// Gossip envelope delivery delays
+    network_stats: NetworkStats,
+    
+    /// Track genealogy recorder (if enabled in AgentConfig)
+    genealogy: Option<TrackGenealogy>,
+    
+    /// Neighbor that first introduced each gossiped entity_id
+    id_provenance: HashMap<Uuid, usize>,
+    
+    /// Neighbors whose introduced IDs have been quarantined
+    quarantined_neighbors: HashSet<usize>,
+    
+    /// Calibrated bias estimate per sensor (sensor_id -> offset)
+    sensor_biases: HashMap<u32, Vector3<f64>>,
+    
+    /// Dead-reckoning error of this agent's own position
+    pose_error: PoseError,
+    
+    /// Estimated offset beyond which a neighbor's gossip is down-weighted
+    /// (m; None: never)
+    peer_offset_tolerance: Option<f64>,
+    
+    /// Estimated systematic offset of each neighbor's gossip from this
+    /// agent's own readings (neighbor -> offset)
+    peer_offsets: HashMap<usize, Vector3<f64>>,
+    
+    /// Positions from the last batch of readings, while estimating peer
+    /// offsets (local UUID -> position)
+    own_readings: HashMap<Uuid, Vector3<f64>>,
+    
+    /// Order recent packets are offered in by [`Self::gossip_batch`]
+    gossip_order: GossipOrder,
+    
+    /// Gossip batches taken so far
+    gossip_rounds: u64,
+    
+    /// Gossip round each entity_id was last sent in
+    last_shared: HashMap<Uuid, u64>,
+    
+    /// Entity IDs each neighbor has gossiped to us
+    heard_from: HashMap<usize, HashSet<Uuid>>,
+    
+    /// How gossiped timestamps are reconciled with the local clock
+    time_alignment: TimeAlignment,
+    
+    /// Estimated clock offset of each neighbor
+    clock_offsets: ClockOffsetEstimator,
+    
+    /// Packets refused, and tracks dropped, over a NaN or indefinite covariance
+    numerical_faults: u64,
+    
+    /// Wire format for outgoing gossip and decoder for incoming
+    codec: PacketCodec,
+    
+    /// Credit gossip about tracks we don't sense ourselves as useful
+    blind_spot_credit: bool,
+    
+    /// Confidence stamped on packets built from our own readings
+    reading_confidence: f64,
+    
+    /// Confidence stamped on those packets when they are gossiped
+    broadcast_confidence: BroadcastConfidence,
+    
+    /// Ticks between spatial index verifications (`None` = never)
+    index_verify_interval: Option<u64>,
+    
+    /// Spatial index verifications that found and repaired inconsistencies
+    index_repairs: u64,
+    
+    /// Whether accepted envelopes are re-broadcast
+    relay_gossip: bool,
+    
+    /// Accepted envelopes waiting to be re-broadcast
+    relay_queue: Vec<SignedPacketEnvelope>,
+    
+    /// Oldest gossip, by packet timestamp, fused (s; `None` = any age)
+    staleness_horizon_secs: Option<f64>,
+    
+    /// Sequence number stamped on the next envelope sent
+    next_sequence: u32,
+    
+    /// Sequence numbers heard from each neighbor
+    sequence_trackers: BTreeMap<usize, SequenceTracker>,
+    
+    /// NACKing and resending of missed envelopes (`None` = off)
+    retransmission: Option<Retransmission>,
+    
+    /// Recent envelopes sent, kept to answer NACKs
+    retransmit_buffer: RetransmitBuffer,
+    
+    /// Latest gossip acceptance decisions (`None` = not logged)
+    gossip_decisions: Option<GossipDecisionLog>,
+    
+    /// Bus merges, rejected gossip and evolution epochs are published on
+    events: Option<SimEventBus>,
 }
 
 impl SimulatedAgent {
@@ -69,7 +348,11 @@ impl SimulatedAgent {
     /// * `network` - Network interface
     /// * `root_public_key` - Root authority public key (from biscuit_auth)
     /// * `agent_index` - Index for identification
-    /// * `config` - Agent configuration
+    /// * `config` - Agent configuration, in the oracle's ENU frame (see
+    ///   [`Self::default_config`])
+    ///
+    /// # Panics
+    /// If `config` fails [`AgentConfig::validate`]; see [`Self::try_new`].
     pub fn new(
         context: Arc<SimContext>,
         network: Arc<SimNetwork>,
@@ -77,23 +360,91 @@ impl SimulatedAgent {
         agent_index: u64,
         config: AgentConfig,
     ) -> Self {
+        Self::try_new(context, network, root_public_key, agent_index, config)
+            .unwrap_or_else(|e| panic!("agent {}: {}", agent_index, e))
+    }
+    
+    /// [`AgentConfig::default`] in [`Oracle::frame`]: oracle positions are
+    /// ENU meters, not WGS84 degrees.
+    pub fn default_config() -> AgentConfig {
+        AgentConfig { frame: Oracle::frame(), ..AgentConfig::default() }
+    }
+    
+    /// Creates a new simulated agent, or returns the first invalid field of
+    /// `config`. A WGS84 `frame` is rejected, since oracle readings are ENU
+    /// meters.
+    pub fn try_new(
+        context: Arc<SimContext>,
+        network: Arc<SimNetwork>,
+        root_public_key: biscuit_auth::PublicKey,
+        agent_index: u64,
+        config: AgentConfig,
+    ) -> Result<Self, ConfigError> {
+        config.validate()?;
+        if config.frame == Frame::Wgs84 {
+            return Err(ConfigError {
+                field: "frame",
+                value: format!("{:?}", config.frame),
+                expected: "a local ENU frame such as Oracle::frame()",
+            });
+        }
+        let genealogy = config.record_genealogy.then(TrackGenealogy::new);
+        let gossip_decisions = config.gossip_decision_log.map(GossipDecisionLog::new);
+        let evolution = EvolutionaryState::with_params(EvoParams {
+            gossip_interval_ticks: config.gossip_interval_ticks,
+            ..EvoParams::default()
+        });
+        let rng = context.derive_stream(&format!("agent-{}/evolution", agent_index));
         let inner = GodViewAgent::new(context, network, config, root_public_key);
-        let rng = ChaCha8Rng::seed_from_u64(agent_index.wrapping_mul(0xeb0123));
         
-        Self {
+        Ok(Self {
             inner,
             agent_index,
-            entity_track_map: std::collections::HashMap::new(),
+            entity_track_map: HashMap::new(),
             readings_processed: 0,
             tracks_created: 0,
             recent_packets: Vec::new(),
             gossip_received: 0,
             adaptive: AdaptiveState::new(),
-            evolution: EvolutionaryState::new(),
+            evolution,
             fitness_provider: Box::new(OracleFitness::new()), // Default to Oracle
             rng,
             energy: 1000.0, // 1000 Joules capacity
-        }
+            energy_capacity: 1000.0,
+            signing_key: None,
+            signed_gossip: SignedGossipStats::default(),
+            network_stats: NetworkStats::default(),
+            genealogy,
+            id_provenance: HashMap::new(),
+            quarantined_neighbors: HashSet::new(),
+            sensor_biases: HashMap::new(),
+            gossip_order: GossipOrder::default(),
+            gossip_rounds: 0,
+            last_shared: HashMap::new(),
+            heard_from: HashMap::new(),
+            time_alignment: TimeAlignment::default(),
+            clock_offsets: ClockOffsetEstimator::new(),
+            numerical_faults: 0,
+            codec: PacketCodec::default(),
+            blind_spot_credit: false,
+            reading_confidence: 0.95,
+            broadcast_confidence: BroadcastConfidence::default(),
+            index_verify_interval: None,
+            index_repairs: 0,
+            relay_gossip: false,
+            relay_queue: Vec::new(),
+            staleness_horizon_secs: None,
+            next_sequence: 0,
+            sequence_trackers: BTreeMap::new(),
+            retransmission: None,
+            retransmit_buffer: RetransmitBuffer::default(),
+            pose_error: PoseError::default(),
+            peer_offset_tolerance: None,
+            peer_offsets: HashMap::new(),
+            own_readings: HashMap::new(),
+            gossip_decisions,
+            events: None,
+        })
     }
     
     /// Creates a new simulated agent configured as a bad actor (for testing).
@@ -109,6 +460,18 @@ impl SimulatedAgent {
         agent
     }
     
+    /// Publishes this agent's track merges (with genealogy on), rejected
+    /// gossip and evolution epochs on `bus`.
+    pub fn set_event_bus(&mut self, bus: SimEventBus) {
+        self.events = Some(bus);
+    }
+    
+    fn publish(&self, event: SimEvent) {
+        if let Some(bus) = &self.events {
+            bus.publish(event);
+        }
+    }
+    
     /// Sets the fitness provider for this agent (e.g. to switch to BlindFitness).
     pub fn set_fitness_provider(&mut self, provider: Box<dyn FitnessProvider>) {
         self.fitness_provider = provider;
@@ -130,6 +493,13 @@ impl SimulatedAgent {
         self.energy > 0.0
     }
     
+    /// Sets the agent's remaining energy (Joules), which every later
+    /// episode also starts with (see [`Self::reset_world_state`]).
+    pub fn set_energy(&mut self, joules: f64) {
+        self.energy = joules.max(0.0);
+        self.energy_capacity = self.energy;
+    }
+    
     /// Returns true if the agent has energy remaining.
     pub fn is_alive(&self) -> bool {
         self.energy > 0.0
@@ -146,11 +516,43 @@ impl SimulatedAgent {
         
         // Update adaptive state with current time
         let current_time = self.inner.now_secs();
+        if let Some(genealogy) = self.genealogy.as_mut() {
+            genealogy.observe_tick(&self.inner.track_manager, current_time);
+        }
         self.adaptive.tick(current_time);
+        self.verify_spatial_index_periodically();
+        let tick_secs = 1.0 / self.inner.config.tick_rate_hz as f64;
+        match self.pose_error.drift_mps {
+            Some(drift) => self.pose_error.offset += drift * tick_secs,
+            None => {
+                let error = self.pose_error.offset.norm();
+                let step = POSE_RECONVERGENCE_MPS * tick_secs;
+                self.pose_error.offset *= if error > step { 1.0 - step / error } else { 0.0 };
+            }
+        }
         
         true
     }
     
+    /// Every `index_verify_interval` ticks, rebuilds the tracker's spatial
+    /// index if it disagrees with the track store.
+    fn verify_spatial_index_periodically(&mut self) {
+        let Some(interval) = self.index_verify_interval else {
+            return;
+        };
+        if !self.tick_count().is_multiple_of(interval) {
+            return;
+        }
+        let inconsistencies = self.inner.track_manager.verify_spatial_index();
+        if inconsistencies.is_empty() {
+            return;
+        }
+        tracing::warn!("Agent {}: repairing spatial index ({} inconsistencies, first {:?})",
+            self.agent_index, inconsistencies.len(), inconsistencies[0]);
+        self.inner.track_manager.rebuild_spatial_index();
+        self.index_repairs += 1;
+    }
+    
     /// Runs a tick of the evolutionary process.
     ///
     /// # Arguments
@@ -162,11 +564,7 @@ impl SimulatedAgent {
         // Collect metrics for this tick
         
         // 1. Accuracy (if GT available)
-        let error = if let Some(gt) = ground_truth {
-            self.compute_position_error(gt)
-        } else {
-            0.0 // No error data if no GT
-        };
+        let error = ground_truth.map(|gt| self.compute_position_error(gt));
         
         // 2. Consistency (NIS) via Time Engine
         // Assumption: avg_nis is available from the filter
@@ -182,10 +580,19 @@ impl SimulatedAgent {
         self.evolution.record_metrics(error, nis, pa_cost, self.energy, cov_trace);
         
         // Check if epoch should end
-        if self.inner.tick_count() % epoch_length_ticks == 0 {
+        if self.inner.tick_count().is_multiple_of(epoch_length_ticks) {
             // Calculate fitness using the configured provider
             // The state handles aggregating average metrics from the recorded sums
-            self.evolution.evolve(&mut self.rng, self.fitness_provider.as_ref());
+            let tick = self.inner.tick_count();
+            self.evolution.evolve(tick, &mut self.rng, self.fitness_provider.as_ref());
+            if let Some(record) = self.evolution.history().last() {
+                self.publish(SimEvent::EpochCompleted {
+                    agent: self.agent_index as usize,
+                    tick: record.tick,
+                    fitness: record.fitness,
+                    accepted: record.accepted,
+                });
+            }
             return true;
         }
         false
@@ -195,73 +602,192 @@ impl SimulatedAgent {
     ///
     /// Converts each reading into a GlobalHazardPacket and processes it
     /// through the full tracking pipeline (association, fusion, Highlander).
+    /// Each position is corrected by its sensor's calibrated bias (zero until
+    /// [`Self::calibrate_sensor`] has been called for that sensor). Readings
+    /// from a placed sensor are measured relative to it, so they inherit any
+    /// error in where the agent believes it is ([`Self::deny_gps`]); so do
+    /// bearing and range-Doppler readings, through their origin.
+    ///
+    /// A reading of part of the state ([`SensorReading::measurement_model`])
+    /// is gossiped as the track it fused into rather than as measured.
     pub fn ingest_readings(&mut self, readings: &[SensorReading]) {
         // Sensor/CPU cost
         self.consume_energy(0.05 * readings.len() as f64);
 
         let current_time = self.inner.now_secs();
+        self.own_readings.clear();
         
         for reading in readings {
+            let mut measurement_model = reading.measurement_model;
+            let mut position = reading.position;
+            match &mut measurement_model {
+                MeasurementModel::BearingOnly { origin } | MeasurementModel::RangeDoppler { origin } => {
+                    for (axis, value) in origin.iter_mut().enumerate() {
+                        *value += self.pose_error.offset[axis];
+                    }
+                }
+                _ => {
+                    position -= self.sensor_bias(reading.sensor_id);
+                    if let Some(origin) = reading.origin {
+                        let believed_origin = origin + self.pose_error.offset;
+                        position = believed_origin + (position - origin);
+                    }
+                }
+            }
+            let entity_id = self.get_or_create_entity_uuid(reading.entity_id);
+            if self.peer_offset_tolerance.is_some() && measurement_model.is_positional() {
+                self.own_readings.insert(entity_id, position);
+            }
+            
             // Convert sensor reading to GlobalHazardPacket
             let packet = GlobalHazardPacket {
-                entity_id: self.get_or_create_entity_uuid(reading.entity_id),
-                position: [reading.position.x, reading.position.y, reading.position.z],
+                entity_id,
+                position: [position.x, position.y, position.z],
                 velocity: [reading.velocity.x, reading.velocity.y, reading.velocity.z],
-                class_id: 4, // Drone class
+                class_id: reading.class_id,
                 timestamp: current_time,
-                confidence_score: 0.95,
+                confidence_score: self.reading_confidence,
+                measurement_model,
+                priority: reading.priority,
             };
             
-            // Save for gossip (subject to evolution params?)
-            // For now, always save, but gossip logic determines sending frequency
-            self.recent_packets.push(packet.clone());
-            
             // Process through TrackManager
             // Local readings: No adaptive state or neighbor ID needed
-            match self.inner.track_manager.process_packet(&packet, None, None) {
-                Ok(_track_id) => {
+            let track = match self.inner.track_manager.process_packet(&packet, None, None) {
+                Ok(track_id) => {
                     self.readings_processed += 1;
+                    self.observe_genealogy(packet.entity_id, track_id, current_time);
+                    self.inner.track_manager.get_track(&track_id)
                 }
                 Err(e) => {
+                    self.contain_numerical_fault(&e);
                     tracing::debug!("Track processing error: {:?}", e);
+                    None
                 }
+            };
+            
+            // Save for gossip; gossip logic determines sending frequency.
+            // Tentative tracks stay local until confirmed.
+            if let Some(track) = track.filter(|t| !t.is_tentative()) {
+                let confidence_score = self.broadcast_confidence_of(track);
+                let packet = if measurement_model.is_full_state() {
+                    packet
+                } else {
+                    let (position, velocity) = (track.position(), track.velocity());
+                    GlobalHazardPacket {
+                        position: [position.x, position.y, position.z],
+                        velocity: [velocity.x, velocity.y, velocity.z],
+                        measurement_model: MeasurementModel::default(),
+                        ..packet
+                    }
+                };
+                self.recent_packets.push(GlobalHazardPacket { confidence_score, ..packet });
             }
         }
     }
     
+    /// Drops the track named by a [`TrackingError::NumericalInstability`]
+    /// before its NaNs reach gossip or scoring. Other errors are ignored.
+    fn contain_numerical_fault(&mut self, error: &TrackingError) {
+        let TrackingError::NumericalInstability { track_id, .. } = error else {
+            return;
+        };
+        self.numerical_faults += 1;
+        let dropped = self.inner.track_manager.remove_track(track_id).is_some();
+        tracing::warn!("Agent {}: {} (track {})", self.agent_index, error,
+            if dropped { "dropped" } else { "never created" });
+    }
+    
     /// Receives gossip packets from neighbors and processes them with learning.
     ///
-    /// Tracks which neighbors provide useful vs redundant/wrong data.
+    /// Tracks which neighbors provide useful vs redundant/wrong data, and
+    /// which neighbor first introduced each entity_id. Once a neighbor falls
+    /// below the gossip filter threshold, the IDs it introduced are
+    /// quarantined in the TrackManager. Packets over the neighbor's quota
+    /// ([`Self::set_gossip_quota`]) or older than the staleness horizon
+    /// ([`Self::set_staleness_horizon`]) are dropped, and the rest aligned to
+    /// the local clock per [`Self::set_time_alignment`]. What was decided
+    /// about each packet is counted by [`GossipDecision`] reason and logged
+    /// if [`Self::set_gossip_decision_log`] is on.
     pub fn receive_gossip_from(&mut self, neighbor_id: usize, packets: &[GlobalHazardPacket]) {
         // Check if we should accept gossip from this neighbor
         if !self.adaptive.should_accept_gossip(neighbor_id) {
             self.adaptive.gossip_filtered += packets.len() as u64;
+            let score = self.adaptive.neighbor_reputations.get(&neighbor_id).map_or(0.0, |rep| rep.reliability_score);
+            for packet in packets {
+                self.decide_gossip(neighbor_id, packet.entity_id, GossipDecision::RejectedReputation { score });
+            }
+            self.quarantine_neighbor(neighbor_id);
             return;
         }
         
+        let quota = self.evolution.current_params.gossip_quota.filter(|_| neighbor_id != usize::MAX);
         for packet in packets {
+            // Drop whatever is over the sender's quota before it costs anything
+            if quota.is_some_and(|quota| !self.adaptive.admit_gossip(neighbor_id, &quota)) {
+                self.decide_gossip(neighbor_id, packet.entity_id, GossipDecision::RejectedQuota);
+                continue;
+            }
+            
             // Apply evolutionary confidence threshold
             if packet.confidence_score < self.evolution.current_params.confidence_threshold {
+                self.decide_gossip(neighbor_id, packet.entity_id, GossipDecision::RejectedConfidence { value: packet.confidence_score });
+                continue;
+            }
+            
+            if self.staleness_horizon_secs.is_some_and(|horizon| self.inner.now_secs() - packet.timestamp > horizon) {
+                self.network_stats.stale_packets += 1;
+                self.decide_gossip(neighbor_id, packet.entity_id, GossipDecision::RejectedStale);
                 continue;
             }
+            self.decide_gossip(neighbor_id, packet.entity_id, GossipDecision::Accepted);
+            
+            let aligned = self.align_packet(neighbor_id, packet);
+            let packet = aligned.as_ref().unwrap_or(packet);
 
             self.gossip_received += 1;
             
+            // Untracked gossip (usize::MAX) has no provenance, and our own
+            // IDs echoed back weren't introduced by this neighbor
+            if neighbor_id != usize::MAX {
+                self.heard_from.entry(neighbor_id).or_default().insert(packet.entity_id);
+                if !self.is_local_uuid(&packet.entity_id) {
+                    self.id_provenance.entry(packet.entity_id).or_insert(neighbor_id);
+                }
+            }
+            
             // Check if we already have this track with high confidence
             let existing_confidence = self.adaptive.track_confidences
                 .get(&packet.entity_id)
                 .map(|tc| tc.confidence)
                 .unwrap_or(0.0);
             
+            // Untracked gossip has no reputation to inflate by
+            let inflation = if neighbor_id == usize::MAX {
+                1.0
+            } else {
+                self.adaptive.covariance_inflation(neighbor_id, &self.evolution.current_params.trust_inflation)
+                    * self.observe_peer_offset(neighbor_id, packet)
+            };
+            
             // Process through TrackManager
             // Gossip: Pass adaptive state and neighbor ID for peer agreement tracking
-            let was_useful = match self.inner.track_manager.process_packet(
+            let was_useful = match self.inner.track_manager.process_packet_inflated(
                 packet, 
                 Some(&self.adaptive), 
-                Some(neighbor_id)
+                Some(neighbor_id),
+                inflation,
             ) {
-                Ok(_) => existing_confidence < 0.5, // Useful if we didn't have it
-                Err(_) => false,
+                Ok(track_id) => {
+                    self.observe_genealogy(packet.entity_id, track_id, self.inner.now_secs());
+                    // Useful if we didn't have it (or, with blind-spot
+                    // credit, if we can't sense it ourselves)
+                    existing_confidence < 0.5 || (self.blind_spot_credit && !self.senses_track(track_id))
+                }
+                Err(e) => {
+                    self.contain_numerical_fault(&e);
+                    false
+                }
             };
             
             // Update neighbor reputation
@@ -272,238 +798,2372 @@ impl SimulatedAgent {
                 false, // TODO: detect contradictions
             );
         }
+        
+        if !self.adaptive.should_accept_gossip(neighbor_id) {
+            self.quarantine_neighbor(neighbor_id);
+        }
     }
     
-    /// Legacy receive_gossip without neighbor tracking (for backward compat).
-    pub fn receive_gossip(&mut self, packets: &[GlobalHazardPacket]) {
-        // Use a dummy neighbor ID for non-tracked gossip
-        self.receive_gossip_from(usize::MAX, packets);
+    /// Updates `neighbor_id`'s estimated offset from a packet about an
+    /// entity in this agent's last batch of readings, and returns the factor
+    /// to inflate the packet's covariance by: the square of the estimate
+    /// over the tolerance, or 1.0 within it (and without one).
+    ///
+    /// The residual is the reading-to-packet distance the tracker's peer
+    /// agreement cost averages, kept per neighbor and as a vector so a
+    /// constant offset stands out from noise that averages away. It is
+    /// taken against the reading rather than the track, which the
+    /// neighbor's own gossip has already pulled toward it.
+    fn observe_peer_offset(&mut self, neighbor_id: usize, packet: &GlobalHazardPacket) -> f64 {
+        let Some(tolerance) = self.peer_offset_tolerance else {
+            return 1.0;
+        };
+        let own = self.inner.track_manager.tracks()
+            .filter(|t| t.observed_ids.contains(&packet.entity_id))
+            .find_map(|t| t.observed_ids.iter().find_map(|id| self.own_readings.get(id)));
+        if let Some(own) = own {
+            let residual = Vector3::from(packet.position) - own;
+            let estimate = self.peer_offsets.entry(neighbor_id).or_insert_with(Vector3::zeros);
+            *estimate += (residual - *estimate) * PEER_OFFSET_GAIN;
+        }
+        let offset = self.peer_offsets.get(&neighbor_id).map_or(0.0, |o| o.norm());
+        (offset / tolerance).powi(2).max(1.0)
     }
     
-    /// Returns recent packets for sharing (since last clear).
-    pub fn recent_packets(&self) -> &[GlobalHazardPacket] {
-        &self.recent_packets
+    /// Counts gossip about a track this agent doesn't sense itself as useful
+    /// to its sender, however confident the track already is. Off by
+    /// default, so a neighbor that only repeats what an agent knows decays
+    /// as redundant; an agent with blind spots needs it on, or the neighbor
+    /// covering one is eventually quarantined along with those tracks.
+    pub fn set_blind_spot_credit(&mut self, enabled: bool) {
+        self.blind_spot_credit = enabled;
     }
     
-    /// Clears recent packets after gossip round.
-    pub fn clear_recent_packets(&mut self) {
-        self.recent_packets.clear();
+    /// Sets the confidence (0.0-1.0) of packets built from this agent's own
+    /// readings. The tracker turns it into measurement variance, so a
+    /// noisier sensor should claim less (default 0.95).
+    pub fn set_reading_confidence(&mut self, confidence: f64) {
+        self.reading_confidence = confidence.clamp(0.0, 1.0);
     }
     
-    /// Records a message sent metric for evolution.
-    pub fn record_message_sent_metric(&mut self, bytes_sent: u64) {
-        self.evolution.record_message_sent(bytes_sent);
+    /// Sets how the confidence of gossiped packets is chosen (default
+    /// [`BroadcastConfidence::FromCovariance`]).
+    pub fn set_broadcast_confidence(&mut self, mode: BroadcastConfidence) {
+        self.broadcast_confidence = mode;
     }
     
-    /// Returns current gossip interval in ticks (evolved).
-    pub fn gossip_interval(&self) -> u64 {
-        self.evolution.current_params.gossip_interval_ticks
+    /// Cuts the agent off from GPS: from now on it dead-reckons its own
+    /// position, which drifts from the truth by `drift_mps` every second of
+    /// its ticks. Readings from placed sensors are shifted by the
+    /// accumulated error before fusion, and so is everything it gossips.
+    pub fn deny_gps(&mut self, drift_mps: Vector3<f64>) {
+        self.pose_error.drift_mps = Some(drift_mps);
     }
     
-    /// Emergency Protocol (v0.6.0): Returns whether the agent should broadcast.
-    /// Returns false if energy is critically low (< 50J) to prevent messaging death.
-    pub fn should_broadcast(&self, current_tick: u64) -> bool {
-        // Emergency Protocol: Enter conservation mode at 50J
-        if self.energy < 50.0 {
-            return false;
-        }
-        // Normal gossip interval check
-        current_tick % self.gossip_interval() == 0
+    /// Gives the agent a GPS fix again. Its pose error doesn't vanish at
+    /// once: the position estimate converges back onto the fix at a few
+    /// m/s, so readings don't jump out of their tracks' gates.
+    pub fn restore_gps(&mut self) {
+        self.pose_error.drift_mps = None;
     }
     
-    /// Returns max gossip neighbors (evolved).
-    pub fn max_gossip_neighbors(&self) -> usize {
-        self.evolution.current_params.max_neighbors_gossip
+    /// Returns true while the agent is dead-reckoning without GPS.
+    pub fn is_gps_denied(&self) -> bool {
+        self.pose_error.drift_mps.is_some()
     }
     
-    /// Returns the current evolved sensor bias estimate (v0.6.0).
-    /// Agents evolve this to compensate for systematic GPS errors.
-    pub fn sensor_bias_estimate(&self) -> f64 {
-        self.evolution.current_params.sensor_bias_estimate
+    /// Returns the agent's believed position minus its true one (m).
+    pub fn pose_error(&self) -> Vector3<f64> {
+        self.pose_error.offset
     }
     
-    /// Returns total gossip packets received.
-    pub fn gossip_received(&self) -> u64 {
-        self.gossip_received
+    /// Estimates each neighbor's systematic offset from gossip about the
+    /// entities in this agent's own readings, and inflates the covariance of a neighbor's
+    /// packets once its estimate passes `tolerance_m` (None, the default,
+    /// does neither). A neighbor dead-reckoning without GPS gossips every
+    /// track shifted by its pose error.
+    pub fn set_peer_offset_tolerance(&mut self, tolerance_m: Option<f64>) {
+        self.peer_offset_tolerance = tolerance_m.map(|m| m.max(f64::EPSILON));
     }
     
-    /// Gets or creates a deterministic UUID for an entity.
-    fn get_or_create_entity_uuid(&mut self, entity_id: u64) -> Uuid {
-        if let Some(&uuid) = self.entity_track_map.get(&entity_id) {
-            return uuid;
-        }
-        
-        // Create deterministic UUID from agent index and entity ID
-        let bytes: [u8; 16] = {
-            let mut b = [0u8; 16];
-            b[0..8].copy_from_slice(&self.agent_index.to_le_bytes());
-            b[8..16].copy_from_slice(&entity_id.to_le_bytes());
-            b
-        };
-        let uuid = Uuid::from_bytes(bytes);
-        self.entity_track_map.insert(entity_id, uuid);
-        self.tracks_created += 1;
-        uuid
+    /// Returns the estimated offset of `neighbor_id`'s gossip, if it has
+    /// gossiped about an entity this agent was reading at the time.
+    pub fn peer_offset(&self, neighbor_id: usize) -> Option<Vector3<f64>> {
+        self.peer_offsets.get(&neighbor_id).copied()
     }
     
-    /// Returns the number of tracks currently maintained.
-    pub fn track_count(&self) -> usize {
-        self.inner.track_manager.tracks().count()
+    /// Returns the neighbors whose estimated offset is past the tolerance,
+    /// in order.
+    pub fn offset_neighbors(&self) -> Vec<usize> {
+        let Some(tolerance) = self.peer_offset_tolerance else {
+            return Vec::new();
+        };
+        let mut neighbors: Vec<usize> = self.peer_offsets.iter()
+            .filter(|(_, offset)| offset.norm() > tolerance)
+            .map(|(&neighbor, _)| neighbor)
+            .collect();
+        neighbors.sort_unstable();
+        neighbors
     }
     
-    /// Returns all current track positions.
-    pub fn track_positions(&self) -> Vec<(Uuid, Vector3<f64>)> {
-        self.inner.track_manager.tracks()
-            .map(|t| (t.canonical_id, t.position()))
-            .collect()
+    /// Verifies the tracker's spatial index every `interval_ticks` ticks and
+    /// rebuilds it when inconsistent, counting each repair (`None`, the
+    /// default, never verifies; each check is O(tracks)).
+    pub fn set_index_verification(&mut self, interval_ticks: Option<u64>) {
+        self.index_verify_interval = interval_ticks.map(|ticks| ticks.max(1));
     }
     
-    /// Returns the current tick count.
-    pub fn tick_count(&self) -> u64 {
-        self.inner.tick_count()
+    /// Sets whether accepted signed gossip envelopes are re-broadcast, and
+    /// the TTL of this agent's own (see [`GossipRelay`]). With `evolve`, a
+    /// [`GossipRelay::Hops`] TTL is evolvable too.
+    pub fn set_gossip_relay(&mut self, relay: GossipRelay, evolve: bool) {
+        self.relay_gossip = relay != GossipRelay::Off;
+        self.evolution.current_params.gossip_ttl = match relay {
+            GossipRelay::Off => Some(1),
+            GossipRelay::Hops(hops) => Some(hops.max(1)),
+            GossipRelay::Unbounded => None,
+        };
+        self.evolution.set_ttl_genes(evolve && matches!(relay, GossipRelay::Hops(_)));
     }
     
-    /// Returns the current simulation time in seconds.
-    pub fn time_secs(&self) -> f64 {
-        self.inner.now_secs()
+    /// Returns the current (evolved) TTL of this agent's envelopes (None:
+    /// never expires).
+    pub fn gossip_ttl(&self) -> Option<u8> {
+        self.evolution.current_params.gossip_ttl
     }
     
-    /// Returns total readings processed.
-    pub fn readings_processed(&self) -> u64 {
-        self.readings_processed
+    /// Sets whether this agent also pulls gossip with anti-entropy digests
+    /// (see [`GossipMode`]). With `evolve`, a [`GossipMode::PushPull`]
+    /// digest interval is evolvable too.
+    pub fn set_gossip_mode(&mut self, mode: GossipMode, evolve: bool) {
+        self.evolution.current_params.digest_interval_ticks = match mode {
+            GossipMode::Push => None,
+            GossipMode::PushPull { digest_interval_ticks } => Some(digest_interval_ticks.max(1)),
+        };
+        self.evolution.set_digest_genes(evolve && mode != GossipMode::Push);
     }
     
-    /// Returns total unique entities seen.
-    pub fn unique_entities(&self) -> usize {
-        self.entity_track_map.len()
+    /// Returns the current gossip mode, with its evolved digest interval.
+    pub fn gossip_mode(&self) -> GossipMode {
+        match self.evolution.current_params.digest_interval_ticks {
+            None => GossipMode::Push,
+            Some(digest_interval_ticks) => GossipMode::PushPull { digest_interval_ticks },
+        }
     }
     
-    /// Returns a reference to the inner agent.
-    pub fn inner(&self) -> &GodViewAgent<SimContext, SimNetwork> {
-        &self.inner
+    /// True on ticks this agent should send a neighbor its digest.
+    pub fn digest_due(&self) -> bool {
+        self.evolution.current_params.digest_interval_ticks
+            .is_some_and(|interval| self.tick_count().is_multiple_of(interval))
     }
     
-    /// Returns a mutable reference to the inner agent.
-    pub fn inner_mut(&mut self) -> &mut GodViewAgent<SimContext, SimNetwork> {
-        &mut self.inner
+    /// Digest of the tracks this agent holds, to send a neighbor.
+    pub fn gossip_digest(&self) -> GossipDigest {
+        GossipDigest::from_tracks(self.inner.track_manager.tracks())
     }
     
-    /// Returns the agent index.
-    pub fn agent_index(&self) -> u64 {
-        self.agent_index
+    /// Answers a neighbor's digest with packets for the tracks it lacks or
+    /// holds stale, for it to take with [`Self::receive_gossip_from`].
+    pub fn answer_digest(&mut self, digest: &GossipDigest) -> Vec<GlobalHazardPacket> {
+        let packets = digest_response(digest, self.inner.track_manager.tracks(), |t| self.broadcast_confidence_of(t));
+        self.network_stats.digests_answered += 1;
+        self.network_stats.digest_packets += packets.len() as u64;
+        packets
     }
     
-    /// Returns whether this agent is configured as a bad actor.
-    pub fn is_bad_actor(&self) -> bool {
-        self.adaptive.is_bad_actor
+    /// Summary of the neighbor scores this agent earned first-hand, to share
+    /// with its neighbors.
+    pub fn reputation_summary(&self) -> ReputationSummary {
+        ReputationSummary::from_state(&self.adaptive)
     }
     
-    /// Returns adaptive learning metrics.
-    pub fn adaptive_metrics(&self) -> crate::adaptive::AdaptiveMetrics {
-        self.adaptive.metrics()
+    /// Blends a neighbor's reputation summary into this agent's scores (see
+    /// [`blend_summary`]), returning the number of scores moved.
+    pub fn receive_reputation_summary(&mut self, neighbor_id: usize, summary: &ReputationSummary, trust_discount: f64) -> usize {
+        blend_summary(&mut self.adaptive, self.agent_index as usize, neighbor_id, summary, trust_discount)
     }
     
-    /// Returns a reference to the adaptive state.
-    pub fn adaptive_state(&self) -> &AdaptiveState {
-        &self.adaptive
+    /// Drops gossip packets stamped more than `horizon_secs` before the
+    /// local clock before fusion, counting them in
+    /// [`NetworkStats::stale_packets`] (`None`, the default, fuses any age).
+    /// Relayed packets keep their original timestamps, so this also bounds
+    /// how long a relayed packet can refresh a track.
+    pub fn set_staleness_horizon(&mut self, horizon_secs: Option<f64>) {
+        self.staleness_horizon_secs = horizon_secs;
     }
     
-    /// Returns a reference to the evolutionary state.
-    pub fn evolutionary_state(&self) -> &EvolutionaryState {
-        &self.evolution
+    /// Takes the envelopes accepted since the last call that still have hops
+    /// to live, re-stamped for sending now with their TTL decremented.
+    pub fn take_relay_envelopes(&mut self) -> Vec<SignedPacketEnvelope> {
+        let now_ms = self.now_ms();
+        let relayed: Vec<SignedPacketEnvelope> = self.relay_queue.drain(..)
+            .filter_map(|envelope| envelope.relayed(now_ms))
+            .collect();
+        self.network_stats.relayed_envelopes += relayed.len() as u64;
+        relayed
     }
-
-    /// Computes position error against ground truth.
-    pub fn compute_position_error(&self, ground_truth: &[(u64, Vector3<f64>)]) -> f64 {
-        let mut total_error = 0.0;
-        let mut count = 0;
-        
-        for (entity_id, true_pos) in ground_truth {
-            if let Some(&track_uuid) = self.entity_track_map.get(entity_id) {
-                // Find the track with this UUID
-                for track in self.inner.track_manager.tracks() {
-                    if track.canonical_id == track_uuid {
-                        let estimated_pos = track.position();
-                        let error = (estimated_pos - true_pos).norm();
-                        total_error += error * error;
-                        count += 1;
-                        break;
-                    }
-                }
-            }
+    
+    /// Stamps an outgoing envelope, own or relayed, with this agent's next
+    /// sequence number, keeping a copy to resend if retransmission is on.
+    pub fn sequence_envelope(&mut self, envelope: SignedPacketEnvelope) -> SignedPacketEnvelope {
+        let envelope = envelope.with_sequence(self.next_sequence);
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+        if self.retransmission.is_some() {
+            self.retransmit_buffer.push(envelope.clone());
         }
-        
+        envelope
+    }
+    
+    /// Turns NACKing of gaps, and resending from the last
+    /// `buffer_envelopes` envelopes sent, on or off (the default).
+    pub fn set_retransmission(&mut self, retransmission: Option<Retransmission>) {
+        self.retransmission = retransmission;
+        self.retransmit_buffer = RetransmitBuffer::new(retransmission.map_or(0, |r| r.buffer_envelopes));
+    }
+    
+    /// Sequences missing from every neighbor's envelopes so far.
+    pub fn gap_count(&self) -> u64 {
+        self.sequence_trackers.values().map(|t| t.gap_count()).sum()
+    }
+    
+    /// Sequences missing from `neighbor_id`'s envelopes, oldest first.
+    pub fn missing_ranges(&self, neighbor_id: usize) -> Vec<SequenceRange> {
+        self.sequence_trackers.get(&neighbor_id).map(|t| t.missing_ranges()).unwrap_or_default()
+    }
+    
+    /// Sequence numbers heard from `neighbor_id`, if it has sent any.
+    pub fn sequence_tracker(&self, neighbor_id: usize) -> Option<&SequenceTracker> {
+        self.sequence_trackers.get(&neighbor_id)
+    }
+    
+    /// Gap counters summed over neighbors.
+    pub fn sequence_stats(&self) -> SequenceStats {
+        let mut stats = SequenceStats::default();
+        for tracker in self.sequence_trackers.values() {
+            stats.merge(&tracker.stats());
+        }
+        stats
+    }
+    
+    /// NACKs for every neighbor with gaps, each gap counting against its
+    /// `max_nacks` (none with retransmission off).
+    pub fn gossip_nacks(&mut self) -> Vec<(usize, GossipNack)> {
+        let Some(retransmission) = self.retransmission else {
+            return Vec::new();
+        };
+        let nacks: Vec<(usize, GossipNack)> = self.sequence_trackers.iter_mut()
+            .map(|(&neighbor, tracker)| (neighbor, GossipNack { ranges: tracker.nack(retransmission.max_nacks) }))
+            .filter(|(_, nack)| !nack.ranges.is_empty())
+            .collect();
+        self.network_stats.nacks_sent += nacks.len() as u64;
+        nacks
+    }
+    
+    /// The envelopes a neighbor's NACK asks for that are still buffered.
+    pub fn answer_nack(&mut self, nack: &GossipNack) -> Vec<SignedPacketEnvelope> {
+        let resent = self.retransmit_buffer.answer(nack);
+        self.network_stats.retransmitted_envelopes += resent.len() as u64;
+        resent
+    }
+    
+    /// Confidence to gossip a reading of `track` with.
+    fn broadcast_confidence_of(&self, track: &UniqueTrack) -> f64 {
+        match self.broadcast_confidence {
+            BroadcastConfidence::Fixed => self.reading_confidence,
+            BroadcastConfidence::FromCovariance { min, max } => self.inner.track_manager
+                .covariance_to_confidence(&track.covariance)
+                .clamp(min, max)
+                .min(self.reading_confidence),
+        }
+    }
+    
+    /// Sets how gossiped timestamps are reconciled with the local clock.
+    pub fn set_time_alignment(&mut self, alignment: TimeAlignment) {
+        self.time_alignment = alignment;
+    }
+    
+    /// Returns the estimated clock offset of `neighbor_id`, once any of its
+    /// packets have been compared against our own tracks.
+    pub fn clock_offset_estimate(&self, neighbor_id: usize) -> Option<OffsetEstimate> {
+        self.clock_offsets.estimate(neighbor_id)
+    }
+    
+    /// Propagates `packet` from its timestamp to the local clock, or returns
+    /// None when alignment is off. Estimating agents shift the timestamp by
+    /// the sender's estimated offset and, when the packet matches a track we
+    /// sense ourselves, refine that estimate from the residual.
+    fn align_packet(&mut self, neighbor_id: usize, packet: &GlobalHazardPacket) -> Option<GlobalHazardPacket> {
+        let estimating = match self.time_alignment {
+            TimeAlignment::Off => return None,
+            TimeAlignment::Propagate => false,
+            TimeAlignment::Estimate => neighbor_id != usize::MAX,
+        };
+        
+        let now = self.inner.now_secs();
+        let offset = if estimating { self.clock_offsets.offset_secs(neighbor_id) } else { 0.0 };
+        let age = (now - (packet.timestamp - offset)).clamp(-MAX_ALIGNMENT_SECS, MAX_ALIGNMENT_SECS);
+        
+        let mut aligned = packet.clone();
+        for axis in 0..3 {
+            aligned.position[axis] += packet.velocity[axis] * age;
+        }
+        aligned.timestamp = now;
+        
+        if estimating {
+            if let Some(residual) = self.own_track_residual(&aligned, now) {
+                self.clock_offsets.observe(neighbor_id, residual, Vector3::from(packet.velocity));
+            }
+        }
+        Some(aligned)
+    }
+    
+    /// Offset of an aligned `packet` from the matching track, if that track
+    /// includes our own sensing. Tracks without a motion model hold their
+    /// last fused state and are extrapolated to `now` first.
+    fn own_track_residual(&self, packet: &GlobalHazardPacket, now: f64) -> Option<Vector3<f64>> {
+        let tracks = &self.inner.track_manager;
+        let track = tracks.get_track(&packet.entity_id)
+            .or_else(|| tracks.tracks().find(|t| t.observed_ids.contains(&packet.entity_id)))
+            .or_else(|| {
+                let id = tracks.find_association(packet).ok().flatten()?;
+                tracks.get_track(&id)
+            })?;
+        if !track.observed_ids.iter().any(|id| self.is_local_uuid(id)) {
+            return None;
+        }
+        
+        let predicted = match self.inner.config.filter {
+            Some(_) => track.position(),
+            None => track.position() + track.velocity() * (now - track.last_update),
+        };
+        Some(Vector3::from(packet.position) - predicted)
+    }
+    
+    /// Quarantines every entity_id first introduced by `neighbor_id`.
+    ///
+    /// Quarantined IDs never become a track's canonical_id, and tracks known
+    /// only by them age out early.
+    fn quarantine_neighbor(&mut self, neighbor_id: usize) {
+        if !self.quarantined_neighbors.insert(neighbor_id) {
+            return;
+        }
+        
+        for (id, source) in &self.id_provenance {
+            if *source == neighbor_id {
+                self.inner.track_manager.quarantine_id(*id);
+            }
+        }
+    }
+    
+    /// Wipes the agent's tracks, as after a restart that lost its memory.
+    ///
+    /// Signing keys, neighbor reputations, gossip sequence numbers and the
+    /// local UUID assigned to each sensed entity survive, so the agent's
+    /// gossip still verifies, its neighbors see no false gaps and its fresh
+    /// tracks come back under the same IDs. ID provenance goes with the
+    /// tracks; quarantines are rebuilt as neighbors' gossip arrives again.
+    /// The tracking config in use, perturbed or not, is kept.
+    pub fn forget_tracks(&mut self) {
+        self.inner.track_manager = TrackManager::new(self.inner.track_manager.config().clone());
+        self.recent_packets.clear();
+        self.relay_queue.clear();
+        self.own_readings.clear();
+        self.id_provenance.clear();
+        self.quarantined_neighbors.clear();
+    }
+    
+    /// Readies the agent for another episode of a world that restarts at
+    /// time 0 (see [`Oracle::reset`]).
+    ///
+    /// Everything learned about the last episode's world goes: tracks and
+    /// filter state, the entity-to-track map, track confidences, clock
+    /// estimates, genealogy, logged gossip decisions and the per-episode
+    /// counters, and the battery is recharged. What the agent is keeps: its
+    /// signing key, gossip sequence numbers, energy capacity, evolved
+    /// parameters and fitness history (a mutation still under test is
+    /// reverted) and sensor bias estimates. Neighbor reputations and the
+    /// peer offsets learned alongside them are kept only if
+    /// `keep_reputation`.
+    pub fn reset_world_state(&mut self, keep_reputation: bool) {
+        self.inner.reset_world();
+        self.forget_tracks();
+        self.entity_track_map.clear();
+        self.readings_processed = 0;
+        self.tracks_created = 0;
+        self.gossip_received = 0;
+        self.adaptive.start_episode(keep_reputation);
+        self.evolution.start_episode();
+        self.energy = self.energy_capacity;
+        self.signed_gossip = SignedGossipStats::default();
+        self.network_stats = NetworkStats::default();
+        self.genealogy = self.genealogy.as_ref().map(|_| TrackGenealogy::new());
+        self.gossip_decisions = self.gossip_decisions.as_ref().map(|log| GossipDecisionLog::new(log.capacity()));
+        self.gossip_rounds = 0;
+        self.last_shared.clear();
+        self.heard_from.clear();
+        self.clock_offsets = ClockOffsetEstimator::new();
+        self.numerical_faults = 0;
+        self.index_repairs = 0;
+        if !keep_reputation {
+            self.peer_offsets.clear();
+        }
+    }
+    
+    /// Returns the neighbor that first introduced `entity_id` via gossip.
+    pub fn id_provenance(&self, entity_id: &Uuid) -> Option<usize> {
+        self.id_provenance.get(entity_id).copied()
+    }
+    
+    /// Returns true if IDs introduced by `neighbor_id` have been quarantined.
+    pub fn is_neighbor_quarantined(&self, neighbor_id: usize) -> bool {
+        self.quarantined_neighbors.contains(&neighbor_id)
+    }
+    
+    /// Legacy receive_gossip without neighbor tracking (for backward compat).
+    pub fn receive_gossip(&mut self, packets: &[GlobalHazardPacket]) {
+        // Use a dummy neighbor ID for non-tracked gossip
+        self.receive_gossip_from(usize::MAX, packets);
+    }
+    
+    /// Processes gossip tagged with the agent that sent each packet (as
+    /// [`crate::SwarmNetwork::take_gossip`] returns it), handing each run of packets
+    /// from one sender to [`Self::receive_gossip_from`].
+    pub fn receive_attributed_gossip(&mut self, incoming: &[(usize, GlobalHazardPacket)]) {
+        for run in incoming.chunk_by(|a, b| a.0 == b.0) {
+            let packets: Vec<GlobalHazardPacket> = run.iter().map(|(_, packet)| packet.clone()).collect();
+            self.receive_gossip_from(run[0].0, &packets);
+        }
+    }
+    
+    /// Installs the key this agent signs its gossip envelopes with.
+    pub fn set_signing_key(&mut self, key: SigningKey) {
+        self.signing_key = Some(key);
+    }
+    
+    /// Returns the key this agent signs with, if one is installed.
+    pub fn signing_key(&self) -> Option<&SigningKey> {
+        self.signing_key.as_ref()
+    }
+    
+    /// Signs the recent packets as a single gossip envelope.
+    ///
+    /// Returns `None` if no signing key is installed or there is nothing to send.
+    pub fn sign_recent_packets(&self) -> Option<SignedPacket> {
+        self.sign_packets(&self.recent_packets)
+    }
+    
+    /// Sets the wire version gossip is encoded in (V1 by default).
+    pub fn set_packet_version(&mut self, version: PacketVersion) {
+        self.codec = PacketCodec::new(version);
+    }
+    
+    /// Returns the wire version gossip is encoded in.
+    pub fn packet_version(&self) -> PacketVersion {
+        self.codec.version()
+    }
+    
+    /// Signs `packets` as a single gossip envelope from this agent.
+    ///
+    /// The payload is encoded in [`Self::packet_version`]; V2 attaches the
+    /// covariance of the track each packet was published from.
+    ///
+    /// Returns `None` if no signing key is installed or `packets` is empty.
+    pub fn sign_packets(&self, packets: &[GlobalHazardPacket]) -> Option<SignedPacket> {
+        let key = self.signing_key.as_ref()?;
+        if packets.is_empty() {
+            return None;
+        }
+        
+        let packets: Vec<HazardPacketV2> = packets.iter()
+            .map(|p| {
+                let packet = HazardPacketV2::from(p);
+                match self.inner.track_manager.get_track(&p.entity_id) {
+                    Some(track) if self.codec.version() >= PacketVersion::V2 => packet.with_covariance(track.covariance),
+                    _ => packet,
+                }
+            })
+            .collect();
+        let payload = self.codec.encode(&packets).ok()?;
+        let metadata = PacketMetadata {
+            agent_id: self.agent_index.to_string(),
+            timestamp: (self.inner.now_secs() * 1000.0) as i64,
+            packet_type: "hazard_batch".to_string(),
+        };
+        Some(SignedPacket::new(payload, key, Some(metadata)))
+    }
+    
+    /// Verifies a signed gossip envelope and processes its packets.
+    ///
+    /// The envelope must carry a valid signature from the key `registry`
+    /// currently holds for the signer named in its metadata. Returns the
+    /// number of packets handed to [`Self::receive_gossip_from`].
+    pub fn receive_signed_gossip(
+        &mut self,
+        neighbor_id: usize,
+        envelope: &SignedPacket,
+        registry: &KeyRegistry,
+    ) -> Result<usize, GossipRejection> {
+        self.accept_signed_gossip(neighbor_id, envelope, registry, None)
+    }
+    
+    /// [`Self::receive_signed_gossip`], re-stamping packets that claim to be
+    /// newer than `sent_at_ms` (the transport envelope's send time).
+    fn accept_signed_gossip(
+        &mut self,
+        neighbor_id: usize,
+        envelope: &SignedPacket,
+        registry: &KeyRegistry,
+        sent_at_ms: Option<u64>,
+    ) -> Result<usize, GossipRejection> {
+        let result = Self::open_envelope(envelope, registry).and_then(|payload| {
+            self.codec.decode(payload).map_err(|_| GossipRejection::Malformed)
+        });
+        match &result {
+            Ok(batch) => {
+                self.signed_gossip.accepted += 1;
+                self.signed_gossip.downgraded_packets += batch.downgraded as u64;
+            }
+            Err(GossipRejection::BadSignature) => self.signed_gossip.bad_signature += 1,
+            Err(GossipRejection::UnknownSigner(_)) => self.signed_gossip.unknown_signer += 1,
+            Err(GossipRejection::StaleKey { .. }) => self.signed_gossip.stale_key += 1,
+            Err(GossipRejection::Malformed) => self.signed_gossip.malformed += 1,
+        }
+        
+        let mut packets = result?.to_v1();
+        if let Some(sent_at_ms) = sent_at_ms {
+            // A packet can't have been measured after the envelope left
+            let sent_at = sent_at_ms as f64 / 1000.0;
+            for packet in &mut packets {
+                if packet.timestamp > sent_at + SEND_TIMESTAMP_RESOLUTION_SECS {
+                    packet.timestamp = sent_at;
+                    self.network_stats.clamped_timestamps += 1;
+                }
+            }
+        }
+        self.receive_gossip_from(neighbor_id, &packets);
+        Ok(packets.len())
+    }
+    
+    /// Wraps a signed packet in a transport envelope stamped with this
+    /// agent's clock.
+    ///
+    /// Envelope payload layout: signature (64 bytes), public key (32 bytes),
+    /// metadata length (u32 LE), metadata JSON, then the signed payload.
+    pub fn seal_envelope(&self, signed: &SignedPacket) -> Option<SignedPacketEnvelope> {
+        let metadata = match &signed.metadata {
+            Some(metadata) => serde_json::to_vec(metadata).ok()?,
+            None => Vec::new(),
+        };
+        
+        let mut bytes = Vec::with_capacity(ENVELOPE_HEADER_LEN + metadata.len() + signed.payload.len());
+        bytes.extend_from_slice(&signed.signature.to_bytes());
+        bytes.extend_from_slice(signed.public_key.as_bytes());
+        bytes.extend_from_slice(&(metadata.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&metadata);
+        bytes.extend_from_slice(&signed.payload);
+        Some(SignedPacketEnvelope::new(bytes, self.now_ms()).with_ttl(self.gossip_ttl()))
+    }
+    
+    /// Current time on the shared sim clock, as stamped on envelopes (ms).
+    fn now_ms(&self) -> u64 {
+        (self.inner.now_secs() * 1000.0) as u64
+    }
+    
+    /// Parses an envelope payload written by [`Self::seal_envelope`].
+    fn unseal_envelope(bytes: &[u8]) -> Option<SignedPacket> {
+        if bytes.len() < ENVELOPE_HEADER_LEN {
+            return None;
+        }
+        let signature: [u8; 64] = bytes[..64].try_into().ok()?;
+        let public_key: [u8; 32] = bytes[64..96].try_into().ok()?;
+        let metadata_len = u32::from_le_bytes(bytes[96..100].try_into().ok()?) as usize;
+        let rest = &bytes[ENVELOPE_HEADER_LEN..];
+        if rest.len() < metadata_len {
+            return None;
+        }
+        let (metadata, payload) = rest.split_at(metadata_len);
+        
+        Some(SignedPacket {
+            payload: payload.to_vec(),
+            signature: Signature::from_bytes(&signature),
+            public_key: VerifyingKey::from_bytes(&public_key).ok()?,
+            metadata: if metadata.is_empty() {
+                None
+            } else {
+                Some(serde_json::from_slice(metadata).ok()?)
+            },
+        })
+    }
+    
+    /// Unwraps a transport envelope and hands the signed packet inside to
+    /// [`Self::receive_signed_gossip`].
+    ///
+    /// The delay since the envelope's send timestamp is recorded in
+    /// [`Self::network_stats`], and packets stamped later than the envelope
+    /// was sent are re-stamped with its send time. A sequence number is
+    /// recorded against the neighbor; a sequence already received is a
+    /// resend that crossed a repair, and is dropped unverified, its packets
+    /// decided [`GossipDecision::Deduplicated`]. Rejections are published on
+    /// the agent's event bus ([`Self::set_event_bus`]).
+    pub fn receive_gossip_envelope(
+        &mut self,
+        neighbor_id: usize,
+        envelope: &SignedPacketEnvelope,
+        registry: &KeyRegistry,
+    ) -> Result<usize, GossipRejection> {
+        let accepted = self.open_gossip_envelope(neighbor_id, envelope, registry);
+        if let Err(rejection) = &accepted {
+            self.publish(SimEvent::GossipRejected {
+                agent: self.agent_index as usize,
+                from: neighbor_id,
+                reason: rejection.to_string(),
+            });
+        }
+        accepted
+    }
+    
+    fn open_gossip_envelope(
+        &mut self,
+        neighbor_id: usize,
+        envelope: &SignedPacketEnvelope,
+        registry: &KeyRegistry,
+    ) -> Result<usize, GossipRejection> {
+        if let Some(sequence) = envelope.sequence {
+            if self.sequence_trackers.entry(neighbor_id).or_default().record(sequence) == SequenceOutcome::Duplicate {
+                self.decide_duplicate(neighbor_id, envelope);
+                return Ok(0);
+            }
+        }
+        let delay_ms = self.now_ms().saturating_sub(envelope.timestamp_ms);
+        self.network_stats.latency.observe(delay_ms);
+        
+        match Self::unseal_envelope(&envelope.payload) {
+            Some(signed) => {
+                let accepted = self.accept_signed_gossip(neighbor_id, &signed, registry, Some(envelope.timestamp_ms))?;
+                if self.relay_gossip && envelope.ttl != Some(1) {
+                    self.relay_queue.push(envelope.clone());
+                }
+                Ok(accepted)
+            }
+            None => {
+                self.signed_gossip.malformed += 1;
+                Err(GossipRejection::Malformed)
+            }
+        }
+    }
+    
+    /// Checks an envelope's signature and signer, returning its payload.
+    fn open_envelope<'a>(
+        envelope: &'a SignedPacket,
+        registry: &KeyRegistry,
+    ) -> Result<&'a [u8], GossipRejection> {
+        let payload = envelope.get_verified_payload()
+            .map_err(|_| GossipRejection::BadSignature)?;
+        
+        let signer = envelope.metadata.as_ref()
+            .and_then(|m| m.agent_id.parse::<u64>().ok())
+            .ok_or(GossipRejection::UnknownSigner(None))?;
+        
+        match registry.check(signer, &envelope.public_key) {
+            KeyStatus::Current => {}
+            KeyStatus::Stale { epoch } => {
+                return Err(GossipRejection::StaleKey { agent_id: signer, epoch });
+            }
+            KeyStatus::Unknown => return Err(GossipRejection::UnknownSigner(Some(signer))),
+        }
+        
+        Ok(payload)
+    }
+    
+    /// Returns the signed gossip verification counters.
+    pub fn signed_gossip_stats(&self) -> SignedGossipStats {
+        self.signed_gossip
+    }
+    
+    /// Returns the delivery delays measured on received gossip envelopes.
+    pub fn network_stats(&self) -> &NetworkStats {
+        &self.network_stats
+    }
+    
+    /// Records a packet's outcome in the genealogy, if enabled, and
+    /// publishes any merge it reveals.
+    fn observe_genealogy(&mut self, packet_id: Uuid, track_id: Uuid, now: f64) {
+        let Some(genealogy) = self.genealogy.as_mut() else {
+            return;
+        };
+        let before = genealogy.events().len();
+        genealogy.observe_packet(&self.inner.track_manager, packet_id, track_id, now);
+        let merges: Vec<SimEvent> = genealogy.events()[before..].iter()
+            .filter_map(|e| match *e {
+                GenealogyEvent::Merged { winner_id, loser_id, .. } => Some(SimEvent::TracksMerged {
+                    agent: self.agent_index as usize,
+                    winner: winner_id,
+                    loser: loser_id,
+                }),
+                _ => None,
+            })
+            .collect();
+        for event in merges {
+            self.publish(event);
+        }
+    }
+    
+    /// Counts what was decided about a gossip packet, and logs it if
+    /// decisions are logged.
+    fn decide_gossip(&mut self, neighbor_id: usize, entity_id: Uuid, decision: GossipDecision) {
+        self.adaptive.gossip_decisions.record(&decision);
+        if let Some(log) = self.gossip_decisions.as_mut() {
+            log.push(GossipDecisionRecord {
+                time_secs: self.inner.now_secs(),
+                neighbor_id,
+                entity_id,
+                decision,
+            });
+        }
+    }
+    
+    /// Marks each packet in a resent envelope as deduplicated. The copy
+    /// is decoded for its entity IDs but never verified.
+    fn decide_duplicate(&mut self, neighbor_id: usize, envelope: &SignedPacketEnvelope) {
+        let Some(batch) = Self::unseal_envelope(&envelope.payload).and_then(|signed| self.codec.decode(&signed.payload).ok()) else {
+            return;
+        };
+        for packet in batch.to_v1() {
+            self.decide_gossip(neighbor_id, packet.entity_id, GossipDecision::Deduplicated);
+        }
+    }
+    
+    /// Keeps the last `capacity` gossip acceptance decisions (None: stops
+    /// logging them). Counts by reason are kept either way, in
+    /// [`Self::adaptive_metrics`].
+    pub fn set_gossip_decision_log(&mut self, capacity: Option<usize>) {
+        self.gossip_decisions = capacity.map(GossipDecisionLog::new);
+    }
+    
+    /// Returns the logged gossip decisions, if logging is on
+    /// ([`AgentConfig::gossip_decision_log`]).
+    pub fn gossip_decisions(&self) -> Option<&GossipDecisionLog> {
+        self.gossip_decisions.as_ref()
+    }
+    
+    /// Returns the recorded track genealogy (empty unless enabled in AgentConfig).
+    pub fn genealogy(&self) -> &[GenealogyEvent] {
+        self.genealogy.as_ref().map(|g| g.events()).unwrap_or(&[])
+    }
+    
+    /// Returns the genealogy recorder, if enabled.
+    pub fn genealogy_recorder(&self) -> Option<&TrackGenealogy> {
+        self.genealogy.as_ref()
+    }
+    
+    /// Returns recent packets for sharing (since last clear).
+    pub fn recent_packets(&self) -> &[GlobalHazardPacket] {
+        &self.recent_packets
+    }
+    
+    /// Clears recent packets after gossip round.
+    pub fn clear_recent_packets(&mut self) {
+        self.recent_packets.clear();
+    }
+    
+    /// Sets the order [`Self::gossip_batch`] offers packets in. Prioritized
+    /// agents also evolve their [`GossipPriority`] weights.
+    pub fn set_gossip_order(&mut self, order: GossipOrder) {
+        self.gossip_order = order;
+        self.evolution.set_priority_genes(order == GossipOrder::Prioritized);
+    }
+    
+    /// Replaces the weights prioritized gossip is scored with.
+    pub fn set_gossip_priority(&mut self, priority: GossipPriority) {
+        self.evolution.current_params.gossip_priority = priority;
+    }
+    
+    /// Returns the current (evolved) gossip priority weights.
+    pub fn gossip_priority(&self) -> GossipPriority {
+        self.evolution.current_params.gossip_priority
+    }
+    
+    /// Inflates the covariance of gossip by its sender's reliability under
+    /// `curve` before fusing it. Any curve but [`TrustInflation::OFF`] also
+    /// lets the agent evolve the curve's exponent.
+    pub fn set_trust_inflation(&mut self, curve: TrustInflation) {
+        self.evolution.current_params.trust_inflation = curve;
+        self.evolution.set_trust_genes(!curve.is_off());
+    }
+    
+    /// Returns the current (evolved) trust inflation curve.
+    pub fn trust_inflation(&self) -> TrustInflation {
+        self.evolution.current_params.trust_inflation
+    }
+    
+    /// Rate-limits each neighbor's gossip to `quota` (None: unlimited),
+    /// dropping packets over it before fusion. With `evolve`, the agent may
+    /// also evolve the quota's size.
+    pub fn set_gossip_quota(&mut self, quota: Option<GossipQuota>, evolve: bool) {
+        self.evolution.current_params.gossip_quota = quota;
+        self.evolution.set_quota_genes(evolve && quota.is_some());
+    }
+    
+    /// Returns the current (evolved) per-neighbor gossip quota.
+    pub fn gossip_quota(&self) -> Option<GossipQuota> {
+        self.evolution.current_params.gossip_quota
+    }
+    
+    /// Describes how much sending `packet` to `destination` (None if
+    /// unknown, e.g. a broadcast) is worth.
+    pub fn packet_value(&self, packet: &GlobalHazardPacket, destination: Option<usize>) -> PacketValue {
+        let tracks = &self.inner.track_manager;
+        let uncertainty = tracks.get_track(&packet.entity_id)
+            .or_else(|| tracks.tracks().find(|t| t.observed_ids.contains(&packet.entity_id)))
+            .map(|t| {
+                let trace = t.covariance.trace().max(0.0);
+                trace / (1.0 + trace)
+            })
+            .unwrap_or(1.0);
+        
+        let staleness = match self.last_shared.get(&packet.entity_id) {
+            Some(&round) => (self.gossip_rounds - round) as f64 / STALENESS_HORIZON_ROUNDS as f64,
+            None => 1.0,
+        };
+        
+        let known_to_destination = destination
+            .and_then(|n| self.heard_from.get(&n))
+            .is_some_and(|ids| ids.contains(&packet.entity_id));
+        
+        let ghost = self.adaptive.track_confidences
+            .get(&packet.entity_id)
+            .map_or(0.0, |tc| 1.0 - tc.confidence.clamp(0.0, 1.0));
+        
+        PacketValue {
+            uncertainty,
+            staleness: staleness.min(1.0),
+            novelty: if known_to_destination { 0.0 } else { 1.0 },
+            ghost,
+        }
+    }
+    
+    /// Takes this round's gossip: at most `budget` recent packets, in send
+    /// order for `destination`.
+    ///
+    /// FIFO agents take packets as they were sensed; prioritized agents take
+    /// the highest-scoring ones first. [High-priority](GlobalHazardPacket::is_high_priority)
+    /// packets come before all others and are sent whatever the budget, so
+    /// they crowd routine packets out of it. Returned packets are marked as
+    /// shared, which resets their staleness.
+    pub fn gossip_batch(&mut self, budget: usize, destination: Option<usize>) -> Vec<GlobalHazardPacket> {
+        self.gossip_rounds += 1;
+        let order: Vec<usize> = match self.gossip_order {
+            GossipOrder::Fifo => (0..self.recent_packets.len()).collect(),
+            GossipOrder::Prioritized => {
+                let values: Vec<PacketValue> = self.recent_packets.iter()
+                    .map(|p| self.packet_value(p, destination))
+                    .collect();
+                self.gossip_priority().send_order(&values)
+            }
+        };
+        let (urgent, routine): (Vec<usize>, Vec<usize>) = order.into_iter()
+            .partition(|&i| self.recent_packets[i].is_high_priority());
+        let routine_budget = budget.saturating_sub(urgent.len());
+        
+        let batch: Vec<GlobalHazardPacket> = urgent.into_iter()
+            .chain(routine.into_iter().take(routine_budget))
+            .map(|i| self.recent_packets[i].clone())
+            .collect();
+        for packet in &batch {
+            self.last_shared.insert(packet.entity_id, self.gossip_rounds);
+        }
+        batch
+    }
+    
+    /// Takes the high-priority packets out of this round's gossip, for ticks
+    /// between gossip intervals. They are marked as shared and don't go out
+    /// again with the next [`Self::gossip_batch`].
+    pub fn urgent_gossip_batch(&mut self) -> Vec<GlobalHazardPacket> {
+        let (urgent, routine): (Vec<_>, Vec<_>) = std::mem::take(&mut self.recent_packets)
+            .into_iter()
+            .partition(|p| p.is_high_priority());
+        self.recent_packets = routine;
+        for packet in &urgent {
+            self.last_shared.insert(packet.entity_id, self.gossip_rounds);
+        }
+        urgent
+    }
+    
+    /// Records a message sent metric for evolution.
+    pub fn record_message_sent_metric(&mut self, bytes_sent: u64) {
+        self.evolution.record_message_sent(bytes_sent);
+    }
+    
+    /// Returns current gossip interval in ticks (evolved), at least 1.
+    pub fn gossip_interval(&self) -> u64 {
+        self.evolution.current_params.gossip_interval_ticks.max(1)
+    }
+    
+    /// Emergency Protocol (v0.6.0): Returns whether the agent should broadcast.
+    /// Returns false if energy is critically low (< 50J) to prevent messaging death.
+    pub fn should_broadcast(&self, current_tick: u64) -> bool {
+        // Emergency Protocol: Enter conservation mode at 50J
+        if self.energy < 50.0 {
+            return false;
+        }
+        // Normal gossip interval check
+        current_tick.is_multiple_of(self.gossip_interval())
+    }
+    
+    /// Returns max gossip neighbors (evolved).
+    pub fn max_gossip_neighbors(&self) -> usize {
+        self.evolution.current_params.max_neighbors_gossip
+    }
+    
+    /// Returns the current evolved sensor bias estimate (v0.6.0).
+    /// Agents evolve this to compensate for systematic GPS errors.
+    pub fn sensor_bias_estimate(&self) -> f64 {
+        self.evolution.current_params.sensor_bias_estimate
+    }
+    
+    /// Returns the calibrated bias estimate for `sensor_id` (zero if uncalibrated).
+    pub fn sensor_bias(&self, sensor_id: u32) -> Vector3<f64> {
+        self.sensor_biases.get(&sensor_id).copied().unwrap_or_else(Vector3::zeros)
+    }
+    
+    /// Updates the bias estimate for the reading's sensor from a surveyed
+    /// reference position, as an exponential moving average of the residual.
+    pub fn calibrate_sensor(&mut self, reading: &SensorReading, reference: Vector3<f64>) {
+        let residual = reading.position - reference;
+        let estimate = self.sensor_biases.entry(reading.sensor_id).or_insert_with(Vector3::zeros);
+        *estimate += (residual - *estimate) * SENSOR_BIAS_GAIN;
+    }
+    
+    /// Returns total gossip packets received.
+    pub fn gossip_received(&self) -> u64 {
+        self.gossip_received
+    }
+    
+    /// Gets or creates a deterministic UUID for an entity.
+    fn get_or_create_entity_uuid(&mut self, entity_id: u64) -> Uuid {
+        if let Some(&uuid) = self.entity_track_map.get(&entity_id) {
+            return uuid;
+        }
+        
+        // Create deterministic UUID from agent index and entity ID
+        let bytes: [u8; 16] = {
+            let mut b = [0u8; 16];
+            b[0..8].copy_from_slice(&self.agent_index.to_le_bytes());
+            b[8..16].copy_from_slice(&entity_id.to_le_bytes());
+            b
+        };
+        let uuid = Uuid::from_bytes(bytes);
+        self.entity_track_map.insert(entity_id, uuid);
+        self.tracks_created += 1;
+        uuid
+    }
+    
+    /// Returns true if the track holds one of this agent's local UUIDs,
+    /// i.e. its own readings feed it.
+    fn senses_track(&self, track_id: Uuid) -> bool {
+        self.inner.track_manager.get_track(&track_id)
+            .is_some_and(|track| track.observed_ids.iter().any(|id| self.is_local_uuid(id)))
+    }
+    
+    /// Returns true if `uuid` is one this agent assigned to a sensed entity.
+    fn is_local_uuid(&self, uuid: &Uuid) -> bool {
+        let entity_id = u64::from_le_bytes(uuid.as_bytes()[8..16].try_into().unwrap());
+        self.entity_track_map.get(&entity_id) == Some(uuid)
+    }
+    
+    /// Returns the number of tracks currently maintained.
+    pub fn track_count(&self) -> usize {
+        self.inner.track_manager.tracks().count()
+    }
+    
+    /// Returns how many numerically unstable tracks or packets were refused.
+    pub fn numerical_faults(&self) -> u64 {
+        self.numerical_faults
+    }
+    
+    /// Returns the tracking config the agent's track manager is running.
+    pub fn tracking_config(&self) -> &TrackingConfig {
+        self.inner.track_manager.config()
+    }
+    
+    /// Swaps a new tracking config into the live track manager (see
+    /// [`TrackManager::update_config`]); on error the old one stays.
+    pub fn update_tracking_config(&mut self, config: TrackingConfig) -> Result<(), TrackingError> {
+        self.inner.track_manager.update_config(config)
+    }
+    
+    /// Returns how many periodic verifications repaired the spatial index.
+    pub fn index_repairs(&self) -> u64 {
+        self.index_repairs
+    }
+    
+    /// Returns how many tracks were evicted to stay within `max_tracks`.
+    pub fn track_evictions(&self) -> u64 {
+        self.inner.track_manager.capacity_stats().evictions
+    }
+    
+    /// Returns how many packets associated with a track beyond the k=1 H3
+    /// ring (see [`godview_core::CellSearch`]).
+    pub fn wide_associations(&self) -> u64 {
+        self.inner.track_manager.spatial_stats().wide_associations
+    }
+    
+    /// Returns live tracks by lifecycle state.
+    pub fn track_state_counts(&self) -> TrackStateCounts {
+        self.inner.track_manager.state_counts()
+    }
+    
+    /// Returns the positions of all confirmed and coasting tracks.
+    pub fn track_positions(&self) -> Vec<(Uuid, Vector3<f64>)> {
+        self.inner.track_manager.tracks()
+            .filter(|t| !t.is_tentative())
+            .map(|t| (t.canonical_id, t.position()))
+            .collect()
+    }
+    
+    /// Returns the confirmed and coasting tracks' positions with the cycles
+    /// since each was last updated: 0 if a reading or gossip fused into it
+    /// since the last [`Self::tick`], N once it has coasted N ticks.
+    pub fn track_states(&self) -> Vec<(Uuid, Vector3<f64>, u32)> {
+        self.inner.track_manager.tracks()
+            .filter(|t| !t.is_tentative())
+            .map(|t| (t.canonical_id, t.position(), t.age))
+            .collect()
+    }
+    
+    /// Returns the confirmed and coasting tracks with their ghost scores
+    /// ([`calculate_ghost_score`] with default weights), each scored
+    /// against the others, with the IDs merged into a track as its
+    /// supporting agents out of `total_agents`.
+    ///
+    /// Quadratic in the track count; meant for exports, not every tick.
+    pub fn ghost_scored_tracks(&self, total_agents: usize) -> Vec<(&UniqueTrack, f64)> {
+        let config = GhostScoreConfig::default();
+        let tracks: Vec<&UniqueTrack> = self.inner.track_manager.tracks().filter(|t| !t.is_tentative()).collect();
+        let states: Vec<([f64; 3], [f64; 3], Matrix6<f64>)> = tracks.iter()
+            .map(|t| ([t.state[0], t.state[1], t.state[2]], [t.state[3], t.state[4], t.state[5]], t.covariance))
+            .collect();
+        tracks.iter().enumerate()
+            .map(|(i, track)| {
+                let neighbors: Vec<_> = states.iter().enumerate().filter(|(j, _)| *j != i).map(|(_, s)| *s).collect();
+                let (position, velocity, covariance) = &states[i];
+                let supporting = track.observed_ids.len().min(total_agents);
+                let (score, _, _) = calculate_ghost_score(position, velocity, covariance, supporting, total_agents, &neighbors, &config);
+                (*track, score)
+            })
+            .collect()
+    }
+    
+    /// Returns the UUID this agent assigned to `entity_id`, if it has seen it.
+    pub fn local_entity_uuid(&self, entity_id: u64) -> Option<Uuid> {
+        self.entity_track_map.get(&entity_id).copied()
+    }
+    
+    /// Returns the track with canonical ID `id`, or the one `id` was merged
+    /// into, tentative tracks included.
+    pub fn track(&self, id: &Uuid) -> Option<&UniqueTrack> {
+        let tracks = &self.inner.track_manager;
+        tracks.get_track(id)
+            .or_else(|| tracks.tracks().find(|t| t.observed_ids.contains(id)))
+    }
+    
+    /// Returns the track holding this agent's local UUID for `entity_id`,
+    /// following Highlander merges onto a smaller canonical ID.
+    pub fn track_for_entity(&self, entity_id: u64) -> Option<&UniqueTrack> {
+        self.track(self.entity_track_map.get(&entity_id)?)
+    }
+    
+    /// A full-state packet carrying confirmed track `id` under its canonical
+    /// ID as it stands now, at the confidence it would be gossiped with, for
+    /// handing the track to another agent. None if there is no such track.
+    pub fn handoff_packet(&self, id: &Uuid) -> Option<GlobalHazardPacket> {
+        let track = self.track(id).filter(|t| !t.is_tentative())?;
+        let (position, velocity) = (track.position(), track.velocity());
+        Some(GlobalHazardPacket {
+            entity_id: track.canonical_id,
+            position: [position.x, position.y, position.z],
+            velocity: [velocity.x, velocity.y, velocity.z],
+            class_id: track.class_id,
+            timestamp: self.inner.now_secs(),
+            confidence_score: self.broadcast_confidence_of(track),
+            measurement_model: MeasurementModel::default(),
+            priority: track.priority,
+        })
+    }
+    
+    /// Takes over a track `from` handed off with [`Self::handoff_packet`].
+    /// Unlike gossip, a handoff skips the reputation, quota, confidence and
+    /// staleness filters and doesn't score `from`: the sender isn't
+    /// offering news but giving up a track this agent now answers for,
+    /// often one its own readings have just picked up. Returns false if
+    /// the packet was refused as numerically invalid.
+    pub fn accept_handoff(&mut self, from: usize, packet: &GlobalHazardPacket) -> bool {
+        self.heard_from.entry(from).or_default().insert(packet.entity_id);
+        if !self.is_local_uuid(&packet.entity_id) {
+            self.id_provenance.entry(packet.entity_id).or_insert(from);
+        }
+        match self.inner.track_manager.process_packet_inflated(packet, Some(&self.adaptive), Some(from), 1.0) {
+            Ok(track_id) => {
+                self.observe_genealogy(packet.entity_id, track_id, self.inner.now_secs());
+                true
+            }
+            Err(e) => {
+                self.contain_numerical_fault(&e);
+                false
+            }
+        }
+    }
+    
+    /// Drops track `id`, as an agent does with a track that has left the
+    /// area it answers for, along with any gossip about it not yet sent.
+    /// Returns false if there was no such track.
+    pub fn drop_track(&mut self, id: &Uuid) -> bool {
+        let Some(track) = self.inner.track_manager.remove_track(id) else {
+            return false;
+        };
+        self.recent_packets.retain(|p| p.entity_id != track.canonical_id && !track.observed_ids.contains(&p.entity_id));
+        true
+    }
+    
+    /// Estimated footprint in bytes: the agent itself plus its tracks,
+    /// adaptive state, genealogy, gossip buffers and per-ID bookkeeping.
+    ///
+    /// Like [`TrackManager::memory_estimate_bytes`], counts live entries
+    /// rather than allocated capacity.
+    pub fn memory_estimate_bytes(&self) -> usize {
+        use std::mem::{size_of, size_of_val};
+        let uuid = size_of::<Uuid>();
+        let adaptive = self.adaptive.neighbor_reputations.len() * (size_of::<usize>() + size_of::<NeighborReputation>())
+            + self.adaptive.track_confidences.len() * (uuid + size_of::<TrackConfidence>());
+        let heard_from: usize = self.heard_from.values()
+            .map(|ids| size_of::<usize>() + size_of::<HashSet<Uuid>>() + ids.len() * uuid)
+            .sum();
+        size_of::<Self>()
+            + self.inner.track_manager.memory_estimate_bytes()
+            + adaptive
+            + self.genealogy.as_ref().map_or(0, |g| g.memory_estimate_bytes())
+            + self.recent_packets.len() * size_of::<GlobalHazardPacket>()
+            + self.relay_queue.iter().map(|e| size_of::<SignedPacketEnvelope>() + e.payload.len()).sum::<usize>()
+            + self.retransmit_buffer.memory_bytes()
+            + self.sequence_trackers.values().map(|t| size_of::<usize>() + t.memory_bytes()).sum::<usize>()
+            + self.entity_track_map.len() * (size_of::<u64>() + uuid)
+            + self.id_provenance.len() * (uuid + size_of::<usize>())
+            + self.quarantined_neighbors.len() * size_of::<usize>()
+            + self.sensor_biases.len() * (size_of::<u32>() + size_of::<Vector3<f64>>())
+            + self.peer_offsets.len() * (size_of::<usize>() + size_of::<Vector3<f64>>())
+            + self.own_readings.len() * (uuid + size_of::<Vector3<f64>>())
+            + self.last_shared.len() * (uuid + size_of::<u64>())
+            + heard_from
+            + size_of_val(self.evolution.history())
+    }
+    
+    /// Returns the current tick count.
+    pub fn tick_count(&self) -> u64 {
+        self.inner.tick_count()
+    }
+    
+    /// Returns the current simulation time in seconds.
+    pub fn time_secs(&self) -> f64 {
+        self.inner.now_secs()
+    }
+    
+    /// Returns total readings processed.
+    pub fn readings_processed(&self) -> u64 {
+        self.readings_processed
+    }
+    
+    /// Returns total unique entities seen.
+    pub fn unique_entities(&self) -> usize {
+        self.entity_track_map.len()
+    }
+    
+    /// Returns a reference to the inner agent.
+    pub fn inner(&self) -> &GodViewAgent<SimContext, SimNetwork> {
+        &self.inner
+    }
+    
+    /// Returns a mutable reference to the inner agent.
+    pub fn inner_mut(&mut self) -> &mut GodViewAgent<SimContext, SimNetwork> {
+        &mut self.inner
+    }
+    
+    /// Returns the agent index.
+    pub fn agent_index(&self) -> u64 {
+        self.agent_index
+    }
+    
+    /// Returns whether this agent is configured as a bad actor.
+    pub fn is_bad_actor(&self) -> bool {
+        self.adaptive.is_bad_actor
+    }
+    
+    /// Returns adaptive learning metrics.
+    pub fn adaptive_metrics(&self) -> crate::adaptive::AdaptiveMetrics {
+        self.adaptive.metrics()
+    }
+    
+    /// Returns a reference to the adaptive state.
+    pub fn adaptive_state(&self) -> &AdaptiveState {
+        &self.adaptive
+    }
+    
+    /// Returns a reference to the evolutionary state.
+    pub fn evolutionary_state(&self) -> &EvolutionaryState {
+        &self.evolution
+    }
+
+    /// Starts evolution from previously evolved `params` (see
+    /// [`crate::TrainedParams`]) instead of the defaults.
+    pub fn load_evolved_params(&mut self, params: EvoParams) {
+        self.evolution.load_params(params);
+    }
+    
+    /// Returns the per-epoch evolution record, oldest first.
+    pub fn evolution_history(&self) -> &[EpochRecord] {
+        self.evolution.history()
+    }
+    
+    /// Computes position error against ground truth.
+    pub fn compute_position_error(&self, ground_truth: &[(u64, Vector3<f64>)]) -> f64 {
+        let mut total_error = 0.0;
+        let mut count = 0;
+        
+        for (entity_id, true_pos) in ground_truth {
+            if let Some(track_uuid) = self.entity_track_map.get(entity_id) {
+                // Tracks are keyed by canonical ID
+                if let Some(track) = self.inner.track_manager.get_track(track_uuid) {
+                    let error = (track.position() - true_pos).norm();
+                    total_error += error * error;
+                    count += 1;
+                }
+            }
+        }
+        
         if count > 0 {
             (total_error / count as f64).sqrt() // RMS error
         } else {
             0.0
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::keys::DeterministicKeyProvider;
+    
+    /// RMS altitude (z) error against ground truth, over the same
+    /// entity-to-track assignment as [`Self::compute_position_error`].
+    pub fn compute_altitude_error(&self, ground_truth: &[(u64, Vector3<f64>)]) -> f64 {
+        let errors: Vec<f64> = ground_truth.iter()
+            .filter_map(|(entity_id, true_pos)| {
+                let track = self.inner.track_manager.get_track(self.entity_track_map.get(entity_id)?)?;
+                Some((track.position().z - true_pos.z).powi(2))
+            })
+            .collect();
+        if errors.is_empty() {
+            return 0.0;
+        }
+        (errors.iter().sum::<f64>() / errors.len() as f64).sqrt()
+    }
+    
+    /// RMS velocity error (m/s) against `(entity, position, velocity)`
+    /// ground truth, over the same entity-to-track assignment as
+    /// [`Self::compute_position_error`].
+    pub fn compute_velocity_error(&self, ground_truth: &[(u64, Vector3<f64>, Vector3<f64>)]) -> f64 {
+        let errors: Vec<f64> = ground_truth.iter()
+            .filter_map(|(entity_id, _, true_vel)| {
+                let track = self.inner.track_manager.get_track(self.entity_track_map.get(entity_id)?)?;
+                Some((track.velocity() - true_vel).norm_squared())
+            })
+            .collect();
+        if errors.is_empty() {
+            return 0.0;
+        }
+        (errors.iter().sum::<f64>() / errors.len() as f64).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::DeterministicKeyProvider;
+    use crate::oracle::SensorModality;
+    use godview_core::{ClassCompatibility, FilterTuning, LifecycleConfig, NonlinearMeasurements, TrackLifecycle};
+    
+    #[test]
+    fn test_agent_uuid_generation() {
+        let context = Arc::new(SimContext::new(42));
+        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+        let key_provider = DeterministicKeyProvider::new(42);
+        let root_key = key_provider.biscuit_root_key().public();
+        
+        let mut agent = SimulatedAgent::new(
+            context,
+            network,
+            root_key,
+            0,
+            SimulatedAgent::default_config(),
+        );
+        
+        // Same entity ID should give same UUID
+        let uuid1 = agent.get_or_create_entity_uuid(100);
+        let uuid2 = agent.get_or_create_entity_uuid(100);
+        assert_eq!(uuid1, uuid2);
+        
+        // Different entity IDs should give different UUIDs
+        let uuid3 = agent.get_or_create_entity_uuid(200);
+        assert_ne!(uuid1, uuid3);
+    }
+    
+    #[test]
+    fn test_agent_reading_ingestion() {
+        let context = Arc::new(SimContext::new(42));
+        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+        let key_provider = DeterministicKeyProvider::new(42);
+        let root_key = key_provider.biscuit_root_key().public();
+        
+        let mut agent = SimulatedAgent::new(
+            context,
+            network,
+            root_key,
+            0,
+            SimulatedAgent::default_config(),
+        );
+        
+        // Create some test readings
+        let readings = vec![
+            SensorReading::new(1, Vector3::new(37.7749, -122.4194, 100.0), Vector3::new(1.0, 0.0, 0.0)),
+            SensorReading::new(2, Vector3::new(37.7750, -122.4195, 105.0), Vector3::new(0.0, 1.0, 0.0)),
+        ];
+        
+        agent.ingest_readings(&readings);
+        
+        assert_eq!(agent.readings_processed(), 2);
+        assert_eq!(agent.unique_entities(), 2);
+        assert!(agent.track_count() >= 1); // At least some tracks created
+    }
+    
+    #[test]
+    fn test_nan_reading_is_contained() {
+        let context = Arc::new(SimContext::new(42));
+        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+        let root_key = DeterministicKeyProvider::new(42).biscuit_root_key().public();
+        let mut agent = SimulatedAgent::new(context, network, root_key, 0, SimulatedAgent::default_config());
+        
+        agent.ingest_readings(&[
+            SensorReading::new(1, Vector3::new(0.0, 0.0, 100.0), Vector3::new(1.0, 0.0, 0.0)),
+            SensorReading::new(2, Vector3::new(50.0, 0.0, 100.0), Vector3::new(0.0, 1.0, 0.0)),
+        ]);
+        let untouched = agent.local_entity_uuid(2).unwrap();
+        
+        // A NaN never becomes a track; an infinity fused into track 1 drops it
+        agent.ingest_readings(&[
+            SensorReading::new(3, Vector3::new(-50.0, 0.0, 100.0), Vector3::new(f64::NAN, 0.0, 0.0)),
+            SensorReading::new(1, Vector3::new(0.1, 0.0, 100.0), Vector3::new(0.0, f64::INFINITY, 0.0)),
+        ]);
+        
+        assert_eq!(agent.numerical_faults(), 2);
+        assert_eq!(agent.track_positions(), vec![(untouched, Vector3::new(50.0, 0.0, 100.0))]);
+        let error = agent.compute_position_error(&[(2, Vector3::new(50.0, 0.0, 100.0))]);
+        assert!(error.is_finite() && error < 1.0, "{error}");
+    }
+    
+    #[test]
+    fn test_reset_world_state_keeps_what_the_agent_learned() {
+        let context = Arc::new(SimContext::new(42));
+        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+        let root_key = DeterministicKeyProvider::new(42).biscuit_root_key().public();
+        let mut agent = SimulatedAgent::new(context, network, root_key, 0, SimulatedAgent::default_config());
+        let learn = |agent: &mut SimulatedAgent| {
+            agent.ingest_readings(&[SensorReading::new(1, Vector3::new(0.0, 0.0, 100.0), Vector3::new(1.0, 0.0, 0.0))]);
+            agent.tick();
+            agent.evolution.current_params.sensor_bias_estimate = 2.5;
+            agent.adaptive.get_neighbor(3).reliability_score = 0.25;
+        };
+        
+        agent.set_energy(500.0);
+        learn(&mut agent);
+        assert!(agent.energy < 500.0);
+        agent.reset_world_state(true);
+        
+        assert_eq!(agent.track_count(), 0);
+        assert_eq!(agent.readings_processed(), 0);
+        assert!(agent.local_entity_uuid(1).is_none());
+        assert_eq!(agent.sensor_bias_estimate(), 2.5);
+        assert_eq!(agent.energy, 500.0);
+        assert_eq!(agent.adaptive.get_neighbor(3).reliability_score, 0.25);
+        
+        learn(&mut agent);
+        agent.reset_world_state(false);
+        assert_eq!(agent.sensor_bias_estimate(), 2.5);
+        assert!(agent.adaptive.neighbor_reputations.is_empty());
+    }
+    
+    #[test]
+    fn test_memory_estimate_follows_agent_state() {
+        let context = Arc::new(SimContext::new(42));
+        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+        let root_key = DeterministicKeyProvider::new(42).biscuit_root_key().public();
+        let config = AgentConfig::builder().frame(Oracle::frame()).record_genealogy(true).build().unwrap();
+        let mut agent = SimulatedAgent::new(context, network, root_key, 0, config);
+        let empty = agent.memory_estimate_bytes();
+        
+        let readings: Vec<SensorReading> = (0..100)
+            .map(|i| SensorReading::new(i, Vector3::new(i as f64 * 500.0, 0.0, 100.0), Vector3::zeros()))
+            .collect();
+        agent.ingest_readings(&readings);
+        agent.tick();
+        let loaded = agent.memory_estimate_bytes();
+        let per_entity = (loaded - empty) / 100;
+        assert!((size_of::<UniqueTrack>()..4096).contains(&per_entity), "{per_entity} bytes per entity");
+        
+        // Draining the gossip buffer and aging out every track gives memory back,
+        // leaving only the ID map and genealogy history
+        agent.clear_recent_packets();
+        let drained = agent.memory_estimate_bytes();
+        assert!(loaded - drained >= 100 * size_of::<GlobalHazardPacket>());
+        while agent.track_count() > 0 && agent.tick_count() < 200 {
+            agent.tick();
+        }
+        assert_eq!(agent.track_count(), 0);
+        assert!(agent.memory_estimate_bytes() < drained - 100 * size_of::<UniqueTrack>());
+    }
+    
+    #[test]
+    fn test_signed_gossip_rejects_stale_and_forged_keys() {
+        let mut key_provider = DeterministicKeyProvider::new(42);
+        let root_key = key_provider.biscuit_root_key().public();
+        let mut registry = KeyRegistry::from_provider(&key_provider, 3);
+        
+        let make_agent = |index: u64| SimulatedAgent::new(
+            Arc::new(SimContext::new(42 + index)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(index))),
+            root_key,
+            index,
+            SimulatedAgent::default_config(),
+        );
+        let mut sender = make_agent(1);
+        let mut receiver = make_agent(2);
+        sender.set_signing_key(key_provider.agent_signing_key(1));
+        sender.ingest_readings(&[SensorReading::new(7, Vector3::new(10.0, 20.0, 100.0), Vector3::new(1.0, 0.0, 0.0))]);
+        
+        let envelope = sender.sign_recent_packets().expect("sender has packets and a key");
+        assert_eq!(receiver.receive_signed_gossip(1, &envelope, &registry), Ok(1));
+        
+        // Agent 1's key is rotated; the old envelope is now stale
+        registry.register(1, 1, key_provider.rotate(1, 1).verifying_key());
+        assert_eq!(
+            receiver.receive_signed_gossip(1, &envelope, &registry),
+            Err(GossipRejection::StaleKey { agent_id: 1, epoch: 0 })
+        );
+        
+        // Agent 0 claiming to be agent 1 with its own key
+        let mut forger = make_agent(1);
+        forger.set_signing_key(key_provider.agent_signing_key(0));
+        forger.recent_packets = sender.recent_packets.clone();
+        let forged = forger.sign_recent_packets().unwrap();
+        assert_eq!(
+            receiver.receive_signed_gossip(1, &forged, &registry),
+            Err(GossipRejection::UnknownSigner(Some(1)))
+        );
+        
+        // Tampered payload
+        let mut tampered = envelope.clone();
+        tampered.payload.push(b' ');
+        assert_eq!(
+            receiver.receive_signed_gossip(1, &tampered, &registry),
+            Err(GossipRejection::BadSignature)
+        );
+        
+        let stats = receiver.signed_gossip_stats();
+        assert_eq!(stats.accepted, 1);
+        assert_eq!(stats.stale_key, 1);
+        assert_eq!(stats.unknown_signer, 1);
+        assert_eq!(stats.bad_signature, 1);
+        assert_eq!(stats.rejected(), 3);
+    }
+    
+    #[test]
+    fn test_gossip_envelope_round_trip() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let root_key = key_provider.biscuit_root_key().public();
+        let registry = KeyRegistry::from_provider(&key_provider, 3);
+        
+        let make_agent = |index: u64| SimulatedAgent::new(
+            Arc::new(SimContext::new(42 + index)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(index))),
+            root_key,
+            index,
+            SimulatedAgent::default_config(),
+        );
+        let mut sender = make_agent(1);
+        let mut receiver = make_agent(2);
+        sender.set_signing_key(key_provider.agent_signing_key(1));
+        sender.ingest_readings(&[SensorReading::new(7, Vector3::new(10.0, 20.0, 100.0), Vector3::new(1.0, 0.0, 0.0))]);
+        
+        let signed = sender.sign_recent_packets().unwrap();
+        let envelope = sender.seal_envelope(&signed).unwrap();
+        assert_eq!(receiver.receive_gossip_envelope(1, &envelope, &registry), Ok(1));
+        
+        let mut truncated = envelope.clone();
+        truncated.payload.truncate(ENVELOPE_HEADER_LEN - 1);
+        assert_eq!(
+            receiver.receive_gossip_envelope(1, &truncated, &registry),
+            Err(GossipRejection::Malformed)
+        );
+        
+        let stats = receiver.signed_gossip_stats();
+        assert_eq!(stats.accepted, 1);
+        assert_eq!(stats.malformed, 1);
+    }
     
     #[test]
-    fn test_agent_uuid_generation() {
-        let context = Arc::new(SimContext::new(42));
-        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+    fn test_v2_gossip_is_downgraded_by_v1_receivers() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let root_key = key_provider.biscuit_root_key().public();
+        let registry = KeyRegistry::from_provider(&key_provider, 3);
+        let make_agent = |index: u64| SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(index))),
+            root_key,
+            index,
+            SimulatedAgent::default_config(),
+        );
+        let mut sender = make_agent(1);
+        let mut old = make_agent(2);
+        let mut new = make_agent(3);
+        new.set_packet_version(PacketVersion::V2);
+        sender.set_packet_version(PacketVersion::V2);
+        sender.set_signing_key(key_provider.agent_signing_key(1));
+        sender.ingest_readings(&[
+            SensorReading::new(7, Vector3::new(10.0, 20.0, 100.0), Vector3::new(1.0, 0.0, 0.0)),
+            SensorReading::new(8, Vector3::new(90.0, 20.0, 100.0), Vector3::new(1.0, 0.0, 0.0)),
+        ]);
+        
+        let signed = sender.sign_recent_packets().unwrap();
+        let batch = PacketCodec::new(PacketVersion::V2).decode(&signed.payload).unwrap();
+        assert!(batch.packets.iter().all(|p| p.covariance.is_some()));
+        
+        let envelope = sender.seal_envelope(&signed).unwrap();
+        assert_eq!(old.receive_gossip_envelope(1, &envelope, &registry), Ok(2));
+        assert_eq!(new.receive_gossip_envelope(1, &envelope, &registry), Ok(2));
+        assert_eq!(old.signed_gossip_stats().downgraded_packets, 2);
+        assert_eq!(new.signed_gossip_stats().downgraded_packets, 0);
+        assert_eq!(old.inner().track_manager.track_count(), 2);
+    }
+    
+    #[test]
+    fn test_packets_newer_than_their_envelope_are_restamped() {
         let key_provider = DeterministicKeyProvider::new(42);
         let root_key = key_provider.biscuit_root_key().public();
+        let registry = KeyRegistry::from_provider(&key_provider, 3);
+        let make_agent = |index: u64| SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(index))),
+            root_key,
+            index,
+            SimulatedAgent::default_config(),
+        );
+        let mut sender = make_agent(1);
+        let mut receiver = make_agent(2);
+        sender.set_signing_key(key_provider.agent_signing_key(1));
+        sender.ingest_readings(&[
+            SensorReading::new(7, Vector3::new(10.0, 20.0, 100.0), Vector3::new(1.0, 0.0, 0.0)),
+            SensorReading::new(8, Vector3::new(90.0, 20.0, 100.0), Vector3::new(1.0, 0.0, 0.0)),
+        ]);
         
-        let mut agent = SimulatedAgent::new(
-            context,
-            network,
+        // One packet claims to come from 5s after the envelope is sent
+        sender.recent_packets[0].timestamp = 5.0;
+        let envelope = sender.seal_envelope(&sender.sign_recent_packets().unwrap()).unwrap();
+        assert_eq!(receiver.receive_gossip_envelope(1, &envelope, &registry), Ok(2));
+        
+        let stats = receiver.network_stats();
+        assert_eq!(stats.clamped_timestamps, 1);
+        assert_eq!(stats.latency.count(), 1);
+        assert_eq!(stats.latency.max(), 0);
+        assert!(receiver.inner().track_manager.tracks().all(|t| t.last_update <= 0.0));
+    }
+    
+    #[test]
+    fn test_genealogy_records_single_min_uuid_merge() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let root_key = key_provider.biscuit_root_key().public();
+        let config = AgentConfig { record_genealogy: true, ..SimulatedAgent::default_config() };
+        
+        let make_agent = |index: u64| SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(index))),
             root_key,
+            index,
+            config.clone(),
+        );
+        let mut agent0 = make_agent(0);
+        let mut agent1 = make_agent(1);
+        
+        // Both agents see the same entity and name it with their own UUID
+        let reading = SensorReading::new(9, Vector3::new(37.7749, -122.4194, 100.0), Vector3::new(1.0, 0.0, 0.0));
+        agent0.ingest_readings(std::slice::from_ref(&reading));
+        agent1.ingest_readings(std::slice::from_ref(&reading));
+        let uuid0 = agent0.recent_packets()[0].entity_id;
+        let uuid1 = agent1.recent_packets()[0].entity_id;
+        assert_ne!(uuid0, uuid1);
+        let winner = uuid0.min(uuid1);
+        let loser = uuid0.max(uuid1);
+        
+        // Exchange gossip both ways: the smaller-UUID side absorbs the other's
+        // ID, the larger-UUID side re-keys onto the smaller one
+        let from0 = agent0.recent_packets().to_vec();
+        let from1 = agent1.recent_packets().to_vec();
+        agent0.receive_gossip_from(1, &from1);
+        agent1.receive_gossip_from(0, &from0);
+        
+        for agent in [&agent0, &agent1] {
+            let merges: Vec<_> = agent.genealogy().iter()
+                .filter(|e| matches!(e, GenealogyEvent::Merged { .. }))
+                .collect();
+            assert_eq!(merges.len(), 1, "{:?}", agent.genealogy());
+            assert!(matches!(
+                merges[0],
+                GenealogyEvent::Merged { winner_id, loser_id, .. } if *winner_id == winner && *loser_id == loser
+            ));
+            assert!(matches!(agent.genealogy()[0], GenealogyEvent::Created { .. }));
+        }
+    }
+    
+    #[test]
+    fn test_genealogy_disabled_by_default() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let mut agent = SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(0))),
+            key_provider.biscuit_root_key().public(),
+            0,
+            SimulatedAgent::default_config(),
+        );
+        agent.ingest_readings(&[SensorReading::new(1, Vector3::new(37.7749, -122.4194, 100.0), Vector3::new(1.0, 0.0, 0.0))]);
+        assert!(agent.genealogy().is_empty());
+        assert!(agent.genealogy_recorder().is_none());
+    }
+    
+    /// Ticks until the track is back within `tolerance` of a target whose
+    /// velocity steps from (1, 0, 0) to (1, 0.5, 0) after 2s.
+    fn ticks_to_reacquire(filter: Option<FilterTuning>, tolerance: f64) -> Option<u64> {
+        // Positions are ENU meters in the oracle's frame; the path stays
+        // within a few meters, inside one k-ring
+        let config = AgentConfig { filter, ..SimulatedAgent::default_config() };
+        let key_provider = DeterministicKeyProvider::new(42);
+        let context = Arc::new(SimContext::new(42));
+        let mut agent = SimulatedAgent::new(
+            context.clone(),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(0))),
+            key_provider.biscuit_root_key().public(),
             0,
-            AgentConfig::default(),
+            config,
         );
         
-        // Same entity ID should give same UUID
-        let uuid1 = agent.get_or_create_entity_uuid(100);
-        let uuid2 = agent.get_or_create_entity_uuid(100);
-        assert_eq!(uuid1, uuid2);
+        let dt = 1.0 / 30.0;
+        let mut position = Vector3::new(10.0, 10.0, 100.0);
+        let mut velocity = Vector3::new(1.0, 0.0, 0.0);
+        let mut reacquired_at = None;
         
-        // Different entity IDs should give different UUIDs
-        let uuid3 = agent.get_or_create_entity_uuid(200);
-        assert_ne!(uuid1, uuid3);
+        for tick in 0..240u64 {
+            if tick == 60 {
+                velocity = Vector3::new(1.0, 0.5, 0.0);
+            }
+            position += velocity * dt;
+            context.advance_time(std::time::Duration::from_secs_f64(dt));
+            agent.tick();
+            agent.ingest_readings(&[SensorReading::new(1, position, velocity)]);
+            
+            let error = agent.compute_position_error(&[(1, position)]);
+            if tick >= 60 {
+                match (error < tolerance, reacquired_at) {
+                    (true, None) => reacquired_at = Some(tick - 60),
+                    (false, Some(_)) => reacquired_at = None,
+                    _ => {}
+                }
+            }
+        }
+        reacquired_at
     }
     
     #[test]
-    fn test_agent_reading_ingestion() {
+    fn test_process_noise_speeds_up_reacquisition() {
+        let agile = FilterTuning {
+            position_noise_density: 10.0,
+            velocity_noise_density: 100.0,
+            ..FilterTuning::default()
+        };
+        
+        let tolerance = 1e-5;
+        let fast = ticks_to_reacquire(Some(agile), tolerance).expect("agile filter never reacquired");
+        let slow = ticks_to_reacquire(Some(FilterTuning::default()), tolerance).expect("default tuning never reacquired");
+        assert!(fast <= 5, "agile filter took {fast} ticks");
+        assert!(slow >= 2 * fast, "default tuning took {slow} ticks vs {fast}");
+        
+        // Without prediction the track lags a moving target indefinitely
+        assert_eq!(ticks_to_reacquire(None, tolerance), None);
+    }
+    
+    /// Velocity error each tick of an oracle entity moving at (20, 0, 0)
+    /// m/s, turned onto `turn` after 3s if given. Readings reach the agent
+    /// 0.2s after they are taken.
+    fn velocity_errors(turn: Option<Vector3<f64>>) -> Vec<f64> {
+        let config = AgentConfig { h3_resolution: 0, filter: Some(FilterTuning::default()), ..SimulatedAgent::default_config() };
         let context = Arc::new(SimContext::new(42));
-        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+        let mut agent = SimulatedAgent::new(
+            context.clone(),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(0))),
+            DeterministicKeyProvider::new(42).biscuit_root_key().public(),
+            0,
+            config,
+        );
+        let mut oracle = Oracle::new(42);
+        let id = oracle.spawn_entity(Vector3::new(10.0, 10.0, 100.0), Vector3::new(20.0, 0.0, 0.0), "drone");
+        
+        let dt = 1.0 / 30.0;
+        let mut in_flight = std::collections::VecDeque::new();
+        (0..240u64)
+            .map(|tick| {
+                if let Some(turn) = turn.filter(|_| tick == 90) {
+                    oracle.set_velocity(id, turn);
+                }
+                oracle.step(dt);
+                context.advance_time(std::time::Duration::from_secs_f64(dt));
+                agent.tick();
+                in_flight.push_back(oracle.generate_sensor_readings());
+                if in_flight.len() > 6 {
+                    agent.ingest_readings(&in_flight.pop_front().unwrap());
+                }
+                agent.compute_velocity_error(&oracle.ground_truth_states())
+            })
+            .collect()
+    }
+    
+    #[test]
+    fn test_velocity_error_vanishes_at_constant_velocity() {
+        let errors = velocity_errors(None);
+        let settled = errors[60..].iter().copied().fold(0.0, f64::max);
+        assert!(settled < 0.05, "velocity error {settled} m/s after convergence");
+    }
+    
+    #[test]
+    fn test_velocity_error_spikes_at_a_turn_and_recovers() {
+        let errors = velocity_errors(Some(Vector3::new(0.0, 20.0, 0.0)));
+        let before = errors[60..90].iter().copied().fold(0.0, f64::max);
+        let spike = errors[90..120].iter().copied().fold(0.0, f64::max);
+        let after = errors[120..].iter().copied().fold(0.0, f64::max);
+        assert!(spike > 20.0 && before < 0.05, "no spike at the turn: {before} -> {spike} m/s");
+        assert!(after < 0.05, "velocity error still {after} m/s after the turn");
+    }
+    
+    #[test]
+    fn test_invalid_config_is_rejected_at_construction() {
         let key_provider = DeterministicKeyProvider::new(42);
-        let root_key = key_provider.biscuit_root_key().public();
+        let try_new = |config| SimulatedAgent::try_new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(0))),
+            key_provider.biscuit_root_key().public(),
+            0,
+            config,
+        );
+        
+        let err = try_new(AgentConfig { gossip_interval_ticks: 0, ..SimulatedAgent::default_config() }).err().unwrap();
+        assert_eq!(err.field, "gossip_interval_ticks");
+        
+        let agent = try_new(AgentConfig { gossip_interval_ticks: 7, ..SimulatedAgent::default_config() }).unwrap();
+        assert_eq!(agent.gossip_interval(), 7);
+        assert!(agent.should_broadcast(14) && !agent.should_broadcast(15));
+        
+        // Oracle readings are meters, so a WGS84 frame is an error rather
+        // than quietly swapped for the oracle's
+        let err = try_new(AgentConfig::default()).err().unwrap();
+        assert_eq!(err.field, "frame");
+    }
+    
+    #[test]
+    fn test_tentative_tracks_stay_local_until_confirmed() {
+        let config = AgentConfig {
+            h3_resolution: 0,
+            lifecycle: Some(LifecycleConfig::uniform(TrackLifecycle::default())),
+            ..SimulatedAgent::default_config()
+        };
+        let key_provider = DeterministicKeyProvider::new(42);
+        let mut agent = SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(3))),
+            key_provider.biscuit_root_key().public(),
+            3,
+            config,
+        );
+        let reading = SensorReading::new(1, Vector3::new(10.0, 10.0, 100.0), Vector3::zeros());
+        
+        // Confirmed on the third cycle with readings; nothing is reported
+        // or offered to gossip before that
+        for cycle in 0..3 {
+            assert!(agent.track_positions().is_empty() && agent.recent_packets().is_empty());
+            if cycle > 0 {
+                agent.tick();
+            }
+            agent.ingest_readings(std::slice::from_ref(&reading));
+        }
+        assert_eq!(agent.track_positions().len(), 1);
+        assert_eq!(agent.recent_packets().len(), 1);
+        assert_eq!(agent.track_state_counts(), TrackStateCounts { tentative: 0, confirmed: 1, coasting: 0 });
         
+        // A one-off detection elsewhere stays tentative
+        agent.ingest_readings(&[SensorReading::new(2, Vector3::new(-40.0, 60.0, 100.0), Vector3::zeros())]);
+        assert_eq!(agent.track_state_counts().tentative, 1);
+        assert_eq!(agent.track_positions().len(), 1);
+        assert_eq!(agent.recent_packets().len(), 1);
+    }
+    
+    #[test]
+    fn test_high_priority_packets_bypass_the_gossip_budget() {
+        let key_provider = DeterministicKeyProvider::new(42);
         let mut agent = SimulatedAgent::new(
-            context,
-            network,
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(0))),
+            key_provider.biscuit_root_key().public(),
+            0,
+            AgentConfig { h3_resolution: 0, ..SimulatedAgent::default_config() },
+        );
+        let readings: Vec<SensorReading> = (0..4)
+            .map(|i| SensorReading {
+                priority: if i >= 2 { GlobalHazardPacket::HIGH_PRIORITY } else { 0 },
+                ..SensorReading::new(i, Vector3::new(i as f64 * 500.0, 0.0, 100.0), Vector3::zeros())
+            })
+            .collect();
+        agent.ingest_readings(&readings);
+        
+        // Both urgent packets go first, leaving room for one routine packet
+        let batch = agent.gossip_batch(3, None);
+        let priorities: Vec<u8> = batch.iter().map(|p| p.priority).collect();
+        assert_eq!(priorities, vec![GlobalHazardPacket::HIGH_PRIORITY, GlobalHazardPacket::HIGH_PRIORITY, 0]);
+        
+        // ...and go even when there is no budget at all
+        assert_eq!(agent.gossip_batch(0, None).len(), 2);
+        
+        // Between intervals they can go out alone, and not again after
+        let urgent = agent.urgent_gossip_batch();
+        assert!(urgent.len() == 2 && urgent.iter().all(|p| p.is_high_priority()));
+        assert_eq!(agent.recent_packets().len(), 2);
+        assert_eq!(agent.gossip_batch(1, None).len(), 1);
+    }
+    
+    #[test]
+    fn test_poisoned_min_uuid_cannot_capture_track() {
+        let config = AgentConfig { h3_resolution: 0, ..SimulatedAgent::default_config() };
+        let key_provider = DeterministicKeyProvider::new(42);
+        let mut agent = SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(3))),
+            key_provider.biscuit_root_key().public(),
+            3,
+            config,
+        );
+        
+        let position = Vector3::new(10.0, 10.0, 100.0);
+        let velocity = Vector3::new(1.0, 0.0, 0.0);
+        agent.ingest_readings(&[SensorReading::new(1, position, velocity)]);
+        let local_id = agent.local_entity_uuid(1).unwrap();
+        
+        let packet = |entity_id| GlobalHazardPacket {
+            entity_id,
+            position: [position.x, position.y, position.z],
+            velocity: [velocity.x, velocity.y, velocity.z],
+            class_id: 4,
+            timestamp: 0.0,
+            confidence_score: 0.95,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        };
+        
+        // Neighbor 7 gossips a near-nil UUID for the same object; honest
+        // neighbor 2 gossips its own larger ID and echoes ours back
+        let poison_id = Uuid::from_u128(1);
+        let honest_id = Uuid::max();
+        agent.receive_gossip_from(7, &[packet(poison_id)]);
+        agent.receive_gossip_from(2, &[packet(honest_id), packet(local_id)]);
+        assert_eq!(agent.track_for_entity(1).unwrap().canonical_id, poison_id);
+        assert_eq!(agent.id_provenance(&poison_id), Some(7));
+        assert_eq!(agent.id_provenance(&honest_id), Some(2));
+        assert_eq!(agent.id_provenance(&local_id), None);
+        
+        // Neighbor 7 is caught contradicting local data and drops below the
+        // filter threshold: its next gossip is filtered and its IDs quarantined
+        for _ in 0..5 {
+            agent.adaptive.get_neighbor(7).record_wrong();
+        }
+        agent.receive_gossip_from(7, &[packet(poison_id)]);
+        assert!(agent.is_neighbor_quarantined(7));
+        assert!(!agent.is_neighbor_quarantined(2));
+        
+        let track = agent.track_for_entity(1).unwrap();
+        assert_eq!(track.canonical_id, local_id);
+        assert!(track.observed_ids.contains(&poison_id));
+        
+        // The poisoned ID relayed by an honest neighbor still can't win
+        agent.receive_gossip_from(2, &[packet(poison_id)]);
+        assert_eq!(agent.track_for_entity(1).unwrap().canonical_id, local_id);
+        assert_eq!(agent.track_count(), 1);
+    }
+    
+    #[test]
+    fn test_trust_inflation_discounts_doubtful_neighbors() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let position = Vector3::new(10.0, 10.0, 100.0);
+        let velocity = Vector3::new(1.0, 0.0, 0.0);
+        
+        // Neighbor 5 reports the sensed object 3m east of where we see it
+        let fused_x = |curve: Option<TrustInflation>, reliability: f64| {
+            let mut agent = SimulatedAgent::new(
+                Arc::new(SimContext::new(42)),
+                Arc::new(SimNetwork::new_stub(NodeId::from_seed(3))),
+                key_provider.biscuit_root_key().public(),
+                3,
+                SimulatedAgent::default_config(),
+            );
+            if let Some(curve) = curve {
+                agent.set_trust_inflation(curve);
+            }
+            agent.ingest_readings(&[SensorReading::new(1, position, velocity)]);
+            agent.adaptive.get_neighbor(5).reliability_score = reliability;
+            let packet = GlobalHazardPacket {
+                entity_id: agent.local_entity_uuid(1).unwrap(),
+                position: [position.x + 3.0, position.y, position.z],
+                velocity: [velocity.x, velocity.y, velocity.z],
+                class_id: 4,
+                timestamp: 0.0,
+                confidence_score: 0.95,
+                measurement_model: MeasurementModel::default(),
+                priority: 0,
+            };
+            agent.receive_gossip_from(5, &[packet]);
+            agent.track_for_entity(1).unwrap().position().x
+        };
+        
+        let filter_only = fused_x(None, 0.4);
+        assert!(filter_only > position.x);
+        assert_eq!(fused_x(Some(TrustInflation::INVERSE), 1.0), fused_x(None, 1.0));
+        assert!(fused_x(Some(TrustInflation::INVERSE), 0.4) < filter_only);
+        assert!(fused_x(Some(TrustInflation::new(2.0)), 0.4) < fused_x(Some(TrustInflation::INVERSE), 0.4));
+        
+        assert_eq!(EvoParams::default().trust_inflation, TrustInflation::OFF);
+    }
+    
+    #[test]
+    fn test_gossip_quota_caps_each_neighbor() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let flood: Vec<GlobalHazardPacket> = (0..100)
+            .map(|i| GlobalHazardPacket {
+                entity_id: Uuid::from_u128(i as u128 + 1),
+                position: [i as f64 * 100.0, 0.0, 100.0],
+                velocity: [0.0; 3],
+                class_id: 4,
+                timestamp: 0.0,
+                confidence_score: 0.9,
+                measurement_model: MeasurementModel::default(),
+                priority: 0,
+            })
+            .collect();
+        
+        // Tracks and rate-limited packets after `neighbor` at `reliability`
+        // sends all 100 in one round
+        let flooded = |quota: Option<GossipQuota>, reliability: f64| {
+            let mut agent = SimulatedAgent::new(
+                Arc::new(SimContext::new(42)),
+                Arc::new(SimNetwork::new_stub(NodeId::from_seed(3))),
+                key_provider.biscuit_root_key().public(),
+                3,
+                SimulatedAgent::default_config(),
+            );
+            agent.set_gossip_quota(quota, false);
+            agent.adaptive.get_neighbor(7).reliability_score = reliability;
+            agent.receive_gossip_from(7, &flood);
+            (agent.track_count(), agent.adaptive_metrics().gossip_rate_limited)
+        };
+        
+        let quota = GossipQuota::new(20.0, 60.0);
+        assert_eq!(flooded(None, 0.5), (100, 0));
+        assert_eq!(flooded(Some(quota), 0.5), (20, 80));
+        assert_eq!(flooded(Some(quota), 1.0), (20, 80), "good standing doesn't raise the quota");
+        assert_eq!(flooded(Some(quota), 0.375), (15, 85), "three quarters of neutral reliability gets three quarters of the bucket");
+    }
+    
+    #[test]
+    fn test_gossip_decisions_record_each_reason_in_order() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let root_key = key_provider.biscuit_root_key().public();
+        let registry = KeyRegistry::from_provider(&key_provider, 3);
+        let packet = |id: u128, confidence_score: f64, timestamp: f64| GlobalHazardPacket {
+            entity_id: Uuid::from_u128(id),
+            position: [id as f64 * 100.0, 0.0, 100.0],
+            velocity: [0.0; 3],
+            class_id: 4,
+            timestamp,
+            confidence_score,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        };
+        let mut agent = SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(2))),
+            root_key,
+            2,
+            AgentConfig::builder().frame(Oracle::frame()).gossip_decision_log(8).build().unwrap(),
+        );
+        
+        agent.set_gossip_quota(Some(GossipQuota::new(1.0, 0.0)), false);
+        agent.receive_gossip_from(7, &[packet(1, 0.9, 0.0), packet(2, 0.9, 0.0)]);
+        agent.set_gossip_quota(None, false);
+        
+        agent.evolution.current_params.confidence_threshold = 0.5;
+        agent.receive_gossip_from(7, &[packet(3, 0.25, 0.0)]);
+        
+        agent.set_staleness_horizon(Some(1.0));
+        agent.receive_gossip_from(7, &[packet(4, 0.9, -5.0)]);
+        
+        agent.adaptive.get_neighbor(9).reliability_score = 0.125;
+        agent.receive_gossip_from(9, &[packet(5, 0.9, 0.0)]);
+        
+        // The same sequenced envelope twice: the resend is dropped unverified
+        let mut sender = SimulatedAgent::new(
+            Arc::new(SimContext::new(43)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(1))),
             root_key,
+            1,
+            SimulatedAgent::default_config(),
+        );
+        sender.set_signing_key(key_provider.agent_signing_key(1));
+        let signed = sender.sign_packets(&[packet(6, 0.9, 0.0)]).unwrap();
+        let envelope = sender.sequence_envelope(sender.seal_envelope(&signed).unwrap());
+        assert_eq!(agent.receive_gossip_envelope(1, &envelope, &registry), Ok(1));
+        assert_eq!(agent.receive_gossip_envelope(1, &envelope, &registry), Ok(0));
+        
+        let decisions: Vec<(usize, u128, GossipDecision)> = agent.gossip_decisions().unwrap().records()
+            .map(|r| (r.neighbor_id, r.entity_id.as_u128(), r.decision))
+            .collect();
+        assert_eq!(decisions, vec![
+            (7, 1, GossipDecision::Accepted),
+            (7, 2, GossipDecision::RejectedQuota),
+            (7, 3, GossipDecision::RejectedConfidence { value: 0.25 }),
+            (7, 4, GossipDecision::RejectedStale),
+            (9, 5, GossipDecision::RejectedReputation { score: 0.125 }),
+            (1, 6, GossipDecision::Accepted),
+            (1, 6, GossipDecision::Deduplicated),
+        ]);
+        
+        let counts = agent.adaptive_metrics().gossip_decisions;
+        assert_eq!((counts.accepted, counts.deduplicated, counts.rejected(), counts.total()), (2, 1, 4, 7));
+        
+        // The log keeps only the latest decisions; the counts keep them all
+        agent.set_gossip_decision_log(Some(2));
+        agent.receive_gossip_from(7, &[packet(10, 0.9, 0.0), packet(11, 0.9, 0.0), packet(12, 0.1, 0.0)]);
+        let reasons: Vec<&str> = agent.gossip_decisions().unwrap().records().map(|r| r.decision.reason()).collect();
+        assert_eq!(reasons, ["accepted", "rejected_confidence"]);
+        assert_eq!(agent.adaptive_metrics().gossip_decisions.total(), 10);
+    }
+    
+    #[test]
+    fn test_blind_spot_credit_keeps_sole_sources_reputable() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let position = Vector3::new(10.0, 10.0, 100.0);
+        let velocity = Vector3::new(1.0, 0.0, 0.0);
+        
+        // Neighbor 5 keeps reporting an object we sense (entity 1) and one
+        // we can't (its own UUID for a hidden object)
+        let reliability = |credit: bool, sensed: bool| {
+            let mut agent = SimulatedAgent::new(
+                Arc::new(SimContext::new(42)),
+                Arc::new(SimNetwork::new_stub(NodeId::from_seed(3))),
+                key_provider.biscuit_root_key().public(),
+                3,
+                SimulatedAgent::default_config(),
+            );
+            agent.set_blind_spot_credit(credit);
+            agent.ingest_readings(&[SensorReading::new(1, position, velocity)]);
+            let entity_id = if sensed { agent.local_entity_uuid(1).unwrap() } else { Uuid::from_u128(99) };
+            let offset = if sensed { 0.0 } else { 50.0 };
+            let packet = GlobalHazardPacket {
+                entity_id,
+                position: [position.x + offset, position.y, position.z],
+                velocity: [velocity.x, velocity.y, velocity.z],
+                class_id: 4,
+                timestamp: 0.0,
+                confidence_score: 0.95,
+                measurement_model: MeasurementModel::default(),
+                priority: 0,
+            };
+            for _ in 0..100 {
+                agent.receive_gossip_from(5, std::slice::from_ref(&packet));
+            }
+            agent.adaptive.get_neighbor(5).reliability_score
+        };
+        
+        assert!(reliability(true, false) > reliability(false, false));
+        assert_eq!(reliability(true, true), reliability(false, true));
+    }
+    
+    #[test]
+    fn test_broadcast_confidence_follows_track_covariance() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let mut sender = SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(0))),
+            key_provider.biscuit_root_key().public(),
             0,
-            AgentConfig::default(),
+            SimulatedAgent::default_config(),
         );
+        sender.ingest_readings(&[SensorReading::new(1, Vector3::new(10.0, 10.0, 100.0), Vector3::zeros())]);
+        let settled = sender.track_for_entity(1).unwrap().clone();
+        let mut uncertain = settled.clone();
+        uncertain.covariance *= 4.0;
         
-        // Create some test readings
-        let readings = vec![
-            SensorReading {
-                entity_id: 1,
-                position: Vector3::new(37.7749, -122.4194, 100.0),
-                velocity: Vector3::new(1.0, 0.0, 0.0),
-            },
-            SensorReading {
-                entity_id: 2,
-                position: Vector3::new(37.7750, -122.4195, 105.0),
-                velocity: Vector3::new(0.0, 1.0, 0.0),
-            },
-        ];
+        let settled_confidence = sender.broadcast_confidence_of(&settled);
+        let uncertain_confidence = sender.broadcast_confidence_of(&uncertain);
+        assert_eq!(sender.recent_packets()[0].confidence_score, settled_confidence);
+        assert!(uncertain_confidence < settled_confidence, "{uncertain_confidence} vs {settled_confidence}");
         
-        agent.ingest_readings(&readings);
+        // The receiver turns each back into about the sender's uncertainty
+        let received_variance = |confidence_score: f64| {
+            let mut receiver = TrackManager::with_defaults();
+            let packet = GlobalHazardPacket { confidence_score, ..sender.recent_packets()[0].clone() };
+            let track_id = receiver.process_packet(&packet, None, None).unwrap();
+            receiver.get_track(&track_id).unwrap().covariance[(0, 0)]
+        };
+        let ratio = received_variance(uncertain_confidence) / received_variance(settled_confidence);
+        assert!((ratio - 4.0).abs() < 1e-6, "ratio={ratio}");
         
-        assert_eq!(agent.readings_processed(), 2);
-        assert_eq!(agent.unique_entities(), 2);
-        assert!(agent.track_count() >= 1); // At least some tracks created
+        sender.set_broadcast_confidence(BroadcastConfidence::Fixed);
+        assert_eq!(sender.broadcast_confidence_of(&uncertain), 0.95);
+    }
+    
+    #[test]
+    fn test_periodic_index_verification_repairs_desync() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let mut agent = SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(0))),
+            key_provider.biscuit_root_key().public(),
+            0,
+            SimulatedAgent::default_config(),
+        );
+        agent.ingest_readings(&[
+            SensorReading::new(1, Vector3::new(0.0, 0.0, 100.0), Vector3::zeros()),
+            SensorReading::new(2, Vector3::new(5000.0, 0.0, 100.0), Vector3::zeros()),
+        ]);
+        let moved = agent.local_entity_uuid(1).unwrap();
+        let far_cell = agent.track_for_entity(2).unwrap().h3_cell;
+        agent.inner_mut().track_manager.get_track_mut(&moved).unwrap().h3_cell = far_cell;
+        
+        // Off by default
+        agent.tick();
+        assert!(!agent.inner().track_manager.verify_spatial_index().is_empty());
+        
+        // Checked only on multiples of the interval, and repaired once
+        agent.set_index_verification(Some(3));
+        agent.tick();
+        assert_eq!(agent.index_repairs(), 0);
+        for _ in 0..4 {
+            agent.tick();
+        }
+        assert_eq!(agent.index_repairs(), 1);
+        assert!(agent.inner().track_manager.verify_spatial_index().is_empty());
+    }
+    
+    /// Ticks an agent tracking a cyclist spent with more than one track, and
+    /// with its track for the cyclist classed as anything else, when its
+    /// sensor takes 10% of cyclists for pedestrians. A neighbor's smaller ID
+    /// names the track, as it would in a swarm.
+    fn misclassified_cyclist(class_gate: Option<ClassCompatibility>) -> (usize, usize) {
+        use crate::oracle::{ClassConfusion, Oracle, CYCLIST_CLASS_ID, PEDESTRIAN_CLASS_ID};
+        
+        let config = AgentConfig { h3_resolution: 0, class_gate, ..SimulatedAgent::default_config() };
+        let context = Arc::new(SimContext::new(42));
+        let mut agent = SimulatedAgent::new(
+            context.clone(),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(5))),
+            DeterministicKeyProvider::new(42).biscuit_root_key().public(),
+            5,
+            config,
+        );
+        let mut oracle = Oracle::new(42);
+        let id = oracle.spawn_entity(Vector3::new(10.0, 10.0, 100.0), Vector3::new(5.0, 0.0, 0.0), "cyclist");
+        oracle.set_classification_confusion(0, ClassConfusion::new().swapping(CYCLIST_CLASS_ID, PEDESTRIAN_CLASS_ID, 0.1));
+        
+        let truth = oracle.entity(id).unwrap().position;
+        agent.receive_gossip_from(2, &[GlobalHazardPacket {
+            entity_id: Uuid::from_u128(1),
+            position: [truth.x, truth.y, truth.z],
+            velocity: [5.0, 0.0, 0.0],
+            class_id: CYCLIST_CLASS_ID,
+            timestamp: 0.0,
+            confidence_score: 0.95,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        }]);
+        
+        let dt = 1.0 / 30.0;
+        let (mut ghost_ticks, mut misclassed_ticks) = (0, 0);
+        for _ in 0..300 {
+            oracle.step(dt);
+            context.advance_time(std::time::Duration::from_secs_f64(dt));
+            agent.tick();
+            agent.ingest_readings(&oracle.generate_sensor_readings());
+            if agent.track_count() > 1 {
+                ghost_ticks += 1;
+            }
+            if agent.track_for_entity(id).is_none_or(|t| t.class_id != CYCLIST_CLASS_ID) {
+                misclassed_ticks += 1;
+            }
+        }
+        (ghost_ticks, misclassed_ticks)
+    }
+    
+    #[test]
+    fn test_soft_class_gate_absorbs_misclassified_readings() {
+        use crate::oracle::{CYCLIST_CLASS_ID, PEDESTRIAN_CLASS_ID};
+        
+        // Exact class gating: the first pedestrian report misses the
+        // cyclist's track and starts a ghost under the agent's own ID, which
+        // then takes every reading (replaced outright by each class flip)
+        // while the real track starves
+        let (ghost_ticks, misclassed_ticks) = misclassified_cyclist(None);
+        assert!(ghost_ticks > 0);
+        assert!(misclassed_ticks > 0);
+        
+        let gate = ClassCompatibility::new().allow(CYCLIST_CLASS_ID, PEDESTRIAN_CLASS_ID, 2.0);
+        assert_eq!(misclassified_cyclist(Some(gate)), (0, 0));
+    }
+    
+    #[test]
+    fn test_sensor_bias_calibrated_per_sensor() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let mut agent = SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(0))),
+            key_provider.biscuit_root_key().public(),
+            0,
+            AgentConfig { h3_resolution: 0, ..SimulatedAgent::default_config() },
+        );
+        
+        let landmark = Vector3::new(10.0, 20.0, 100.0);
+        let biases = [Vector3::new(5.0, 5.0, 5.0), Vector3::new(-3.0, 2.0, 0.5)];
+        for _ in 0..200 {
+            for (sensor_id, bias) in biases.iter().enumerate() {
+                let reading = SensorReading {
+                    sensor_id: sensor_id as u32,
+                    ..SensorReading::new(1, landmark + bias, Vector3::zeros())
+                };
+                agent.calibrate_sensor(&reading, landmark);
+            }
+        }
+        assert!((agent.sensor_bias(0) - biases[0]).norm() < 1e-3);
+        assert!((agent.sensor_bias(1) - biases[1]).norm() < 1e-3);
+        assert_eq!(agent.sensor_bias(2), Vector3::zeros());
+        
+        // Readings from sensor 1 are corrected before they reach the tracker
+        let reading = SensorReading {
+            sensor_id: 1,
+            ..SensorReading::new(2, landmark + biases[1], Vector3::zeros())
+        };
+        agent.ingest_readings(&[reading]);
+        let (_, position) = agent.track_positions()[0];
+        assert!((position - landmark).norm() < 1e-2, "track at {position:?}");
+    }
+    
+    #[test]
+    fn test_bearing_readings_refine_a_track_gossiped_whole() {
+        let config = AgentConfig {
+            h3_resolution: 0,
+            nonlinear_measurements: Some(NonlinearMeasurements::default()),
+            ..SimulatedAgent::default_config()
+        };
+        let key_provider = DeterministicKeyProvider::new(42);
+        for seed in [1, 7, 42] {
+            let mut agent = SimulatedAgent::new(
+                Arc::new(SimContext::new(42)),
+                Arc::new(SimNetwork::new_stub(NodeId::from_seed(3))),
+                key_provider.biscuit_root_key().public(),
+                3,
+                config.clone(),
+            );
+            let mut oracle = Oracle::new(seed);
+            let truth = Vector3::new(100.0, 50.0, 100.0);
+            oracle.spawn_entity(truth, Vector3::zeros(), "drone");
+            oracle.set_sensor_modality(1, SensorModality::Bearing);
+            oracle.set_sensor_modality(2, SensorModality::Bearing);
+            
+            // A coarse position fix starts the track; bearings can't
+            agent.ingest_readings(&[SensorReading::new(0, truth + Vector3::new(4.0, -3.0, 0.0), Vector3::zeros())]);
+            let before = (agent.track_for_entity(0).unwrap().position() - truth).norm();
+            for _ in 0..20 {
+                agent.ingest_readings(&oracle.generate_readings_for(0, 1, &Vector3::new(0.0, 0.0, 100.0)));
+                agent.ingest_readings(&oracle.generate_readings_for(0, 2, &Vector3::new(200.0, 0.0, 100.0)));
+            }
+            let track = agent.track_for_entity(0).unwrap();
+            let after = (track.position() - truth).norm();
+            assert!(after < before / 2.0, "seed {seed}: {before} -> {after}");
+            assert_eq!(agent.track_positions().len(), 1);
+            
+            // Neighbors are offered the fused track, never a bearing
+            let gossip = agent.recent_packets().last().unwrap();
+            assert_eq!(gossip.measurement_model, MeasurementModel::PositionVelocity6D);
+            assert_eq!(Vector3::from(gossip.position), track.position());
+        }
+    }
+    
+    #[test]
+    fn test_gps_denied_neighbor_is_flagged_by_its_offset() {
+        let key_provider = DeterministicKeyProvider::new(42);
+        let mut agents: Vec<SimulatedAgent> = (0..2)
+            .map(|i| {
+                let mut agent = SimulatedAgent::new(
+                    Arc::new(SimContext::new(42)),
+                    Arc::new(SimNetwork::new_stub(NodeId::from_seed(i))),
+                    key_provider.biscuit_root_key().public(),
+                    i,
+                    SimulatedAgent::default_config(),
+                );
+                agent.set_peer_offset_tolerance(Some(2.0));
+                agent
+            })
+            .collect();
+        
+        let landmark = Vector3::new(10.0, 20.0, 100.0);
+        let reading = SensorReading { origin: Some(Vector3::zeros()), ..SensorReading::new(1, landmark, Vector3::zeros()) };
+        let round = |agents: &mut [SimulatedAgent]| {
+            for agent in agents.iter_mut() {
+                agent.tick();
+                agent.ingest_readings(std::slice::from_ref(&reading));
+            }
+            let packets = agents[1].recent_packets().to_vec();
+            agents[0].receive_gossip_from(1, &packets);
+            for agent in agents.iter_mut() {
+                agent.clear_recent_packets();
+            }
+        };
+        for _ in 0..10 {
+            round(&mut agents);
+        }
+        assert!(agents[0].peer_offset(1).unwrap().norm() < 1e-9);
+        
+        // Agent 1's readings, and its gossip, drift east with its pose
+        agents[1].deny_gps(Vector3::new(6.0, 0.0, 0.0));
+        for _ in 0..90 {
+            round(&mut agents);
+        }
+        let pose_error = agents[1].pose_error();
+        assert!((pose_error.x - 18.0).abs() < 1e-9 && pose_error.y == 0.0, "{pose_error:?}");
+        let offset = agents[0].peer_offset(1).unwrap();
+        assert!(offset.x > 2.0 && offset.y.abs() < 1e-9, "{offset:?}");
+        assert_eq!(agents[0].offset_neighbors(), [1]);
+        
+        // The pose converges back onto the fix rather than jumping
+        agents[1].restore_gps();
+        round(&mut agents);
+        assert!(!agents[1].is_gps_denied());
+        assert!(agents[1].pose_error().x > 17.0);
+        for _ in 0..200 {
+            round(&mut agents);
+        }
+        assert_eq!(agents[1].pose_error(), Vector3::zeros());
+        assert!(agents[0].offset_neighbors().is_empty());
     }
 }
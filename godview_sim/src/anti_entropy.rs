@@ -0,0 +1,156 @@
+//! Pull gossip: anti-entropy digests.
+//!
+//! Push gossip only carries what an agent sensed since its last round, so an
+//! agent that missed a round (packet loss, a partition) never hears about a
+//! track its neighbor stopped sensing. With anti-entropy on, every so often
+//! an agent sends one neighbor a [`GossipDigest`] of the tracks it holds
+//! (canonical ID and last update), and the neighbor answers with full
+//! packets for each of its own tracks the digest lacks or holds stale
+//! ([`digest_response`]). A track the requester knows under a different ID
+//! counts as missing, so the answer also carries Highlander IDs across.
+
+use godview_core::godview_tracking::{GlobalHazardPacket, MeasurementModel};
+use godview_core::UniqueTrack;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// Wire bytes of one digest entry: a UUID and an f64 timestamp.
+pub const DIGEST_ENTRY_BYTES: usize = 24;
+
+/// The tracks an agent holds, by canonical ID and last update.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GossipDigest {
+    /// Last fusion time of each track, by canonical ID
+    pub entries: BTreeMap<Uuid, f64>,
+}
+
+impl GossipDigest {
+    /// Digests `tracks`, leaving out tentative ones (which aren't gossiped
+    /// either).
+    pub fn from_tracks<'a>(tracks: impl IntoIterator<Item = &'a UniqueTrack>) -> Self {
+        Self {
+            entries: tracks.into_iter()
+                .filter(|t| !t.is_tentative())
+                .map(|t| (t.canonical_id, t.last_update))
+                .collect(),
+        }
+    }
+    
+    /// Number of tracks digested.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    /// True if the digest names no tracks.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    
+    /// Bytes the digest takes on the wire.
+    pub fn wire_bytes(&self) -> usize {
+        self.entries.len() * DIGEST_ENTRY_BYTES
+    }
+    
+    /// True if the digest's sender lacks `track` under its canonical ID, or
+    /// last updated it before `track` was.
+    pub fn wants(&self, track: &UniqueTrack) -> bool {
+        self.entries.get(&track.canonical_id)
+            .is_none_or(|&last_update| last_update < track.last_update)
+    }
+}
+
+/// Packets answering `digest`: one per confirmed track in `tracks` the
+/// digest wants, under its canonical ID and stamped with its last update,
+/// in canonical ID order. `confidence` gives each packet's confidence.
+pub fn digest_response<'a>(
+    digest: &GossipDigest,
+    tracks: impl IntoIterator<Item = &'a UniqueTrack>,
+    confidence: impl Fn(&UniqueTrack) -> f64,
+) -> Vec<GlobalHazardPacket> {
+    let mut packets: Vec<GlobalHazardPacket> = tracks.into_iter()
+        .filter(|t| !t.is_tentative() && digest.wants(t))
+        .map(|t| {
+            let (position, velocity) = (t.position(), t.velocity());
+            GlobalHazardPacket {
+                entity_id: t.canonical_id,
+                position: [position.x, position.y, position.z],
+                velocity: [velocity.x, velocity.y, velocity.z],
+                class_id: t.class_id,
+                timestamp: t.last_update,
+                confidence_score: confidence(t),
+                measurement_model: MeasurementModel::default(),
+                priority: 0,
+            }
+        })
+        .collect();
+    packets.sort_by_key(|p| p.entity_id);
+    packets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use godview_core::{TrackManager, TrackState, TrackingConfig};
+    
+    fn track(id: u128, last_update: f64) -> UniqueTrack {
+        let packet = GlobalHazardPacket {
+            entity_id: Uuid::from_u128(id),
+            position: [id as f64, 2.0, 100.0],
+            velocity: [1.0, 0.0, 0.0],
+            class_id: 4,
+            timestamp: last_update,
+            confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        };
+        let mut manager = TrackManager::new(TrackingConfig::default());
+        let id = manager.process_packet(&packet, None, None).unwrap();
+        manager.get_track(&id).unwrap().clone()
+    }
+    
+    #[test]
+    fn test_digest_skips_tentative_tracks() {
+        let mut tentative = track(3, 1.0);
+        tentative.track_state = TrackState::Tentative;
+        let digest = GossipDigest::from_tracks(&[track(1, 1.0), track(2, 2.5), tentative]);
+        
+        assert_eq!(digest.entries, BTreeMap::from([(Uuid::from_u128(1), 1.0), (Uuid::from_u128(2), 2.5)]));
+        assert_eq!(digest.wire_bytes(), 2 * DIGEST_ENTRY_BYTES);
+        assert!(GossipDigest::default().is_empty());
+    }
+    
+    #[test]
+    fn test_digest_wants_missing_and_stale_tracks() {
+        let digest = GossipDigest::from_tracks(&[track(1, 2.0), track(2, 2.0)]);
+        
+        assert!(!digest.wants(&track(1, 2.0)));
+        assert!(!digest.wants(&track(1, 1.5)), "the requester's copy is newer");
+        assert!(digest.wants(&track(2, 2.5)), "stale");
+        assert!(digest.wants(&track(3, 0.5)), "missing");
+        
+        // A merged track is wanted under its canonical ID, even if the
+        // requester holds it under another
+        let mut merged = track(2, 2.0);
+        merged.merge_id(Uuid::from_u128(0));
+        assert!(digest.wants(&merged));
+    }
+    
+    #[test]
+    fn test_response_carries_wanted_tracks_only() {
+        let digest = GossipDigest::from_tracks(&[track(1, 2.0), track(2, 2.0)]);
+        let mut tentative = track(9, 3.0);
+        tentative.track_state = TrackState::Tentative;
+        let held = [track(3, 1.0), track(2, 3.0), track(1, 2.0), tentative];
+        
+        let response = digest_response(&digest, &held, |t| t.last_update / 10.0);
+        let ids: Vec<Uuid> = response.iter().map(|p| p.entity_id).collect();
+        assert_eq!(ids, [Uuid::from_u128(2), Uuid::from_u128(3)]);
+        
+        let stale = &response[0];
+        assert_eq!(stale.position, [2.0, 2.0, 100.0]);
+        assert_eq!(stale.velocity, [1.0, 0.0, 0.0]);
+        assert_eq!((stale.class_id, stale.timestamp, stale.confidence_score), (4, 3.0, 0.3));
+        
+        assert!(digest_response(&GossipDigest::from_tracks(&held), &held, |_| 1.0).is_empty());
+    }
+}
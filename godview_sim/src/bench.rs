@@ -0,0 +1,453 @@
+//! Scenario benchmarks against a saved baseline.
+//!
+//! The CLI's `--baseline` file is a [`BenchBaseline`]: the key metrics of
+//! every (scenario, seed) run. The first invocation writes it; later ones
+//! compare against it with [`BenchComparison::compare`] and render a
+//! markdown diff table for PR comments. A scored metric that worsens by more
+//! than the tolerance (relative to its baseline value) is flagged as a
+//! regression; operation counts are shown when they change but never flagged.
+
+use crate::corpus::CRATE_VERSION;
+use crate::runner::ScenarioResult;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::Path;
+
+/// Default relative worsening that counts as a regression (5%).
+pub const DEFAULT_BENCH_TOLERANCE: f64 = 0.05;
+
+/// Counters recorded in a baseline as operation counts.
+const OPS_COUNTERS: [&str; 7] = [
+    "packets_sent",
+    "packets_dropped",
+    "oosm_updates",
+    "ghost_detections",
+    "link_dropped_overflow",
+    "track_evictions",
+    "ticks",
+];
+
+/// Which way a metric improves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Better {
+    /// Errors, spreads and times
+    Lower,
+    
+    /// Pass flags and detection rates
+    Higher,
+    
+    /// Operation counts: reported, never flagged
+    Neither,
+}
+
+impl Better {
+    /// Direction of the metric called `name`.
+    pub fn of(name: &str) -> Self {
+        match name {
+            "rms_mean" | "rms_p95" | "track_cv" | "observer_rms" | "convergence_time_secs" => Better::Lower,
+            "passed" | "detection_rate" => Better::Higher,
+            _ => Better::Neither,
+        }
+    }
+}
+
+/// Key metrics of one (scenario, seed) run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// Scenario name (see [`ScenarioId::name`](crate::scenarios::ScenarioId::name))
+    pub scenario: String,
+    
+    /// Seed of the run
+    pub seed: u64,
+    
+    /// Metric values by name
+    pub metrics: BTreeMap<String, f64>,
+}
+
+impl BenchRun {
+    /// Extracts the benchmark metrics of a finished run: pass flag, RMS,
+    /// `tracks_per_agent` (mean live tracks) and `track_cv` (their
+    /// coefficient of variation across agents),
+    /// `detection_rate`, `observer_rms` and `convergence_time_secs` where the
+    /// scenario records them, and the operation counts.
+    pub fn from_result(result: &ScenarioResult) -> Self {
+        let mut metrics = BTreeMap::new();
+        metrics.insert("passed".to_string(), if result.passed { 1.0 } else { 0.0 });
+        metrics.insert("rms_mean".to_string(), result.rms_mean);
+        metrics.insert("rms_p95".to_string(), result.rms_p95);
+        
+        let snapshot = result.registry.snapshot();
+        let tracks: Vec<f64> = snapshot.agents.values()
+            .filter_map(|gauges| gauges.get("tracks_per_agent").copied())
+            .collect();
+        if !tracks.is_empty() {
+            metrics.insert("tracks_per_agent".to_string(), tracks.iter().sum::<f64>() / tracks.len() as f64);
+        }
+        if let Some(cv) = coefficient_of_variation(&tracks) {
+            metrics.insert("track_cv".to_string(), cv);
+        }
+        for name in ["detection_rate", "observer_rms", "convergence_time_secs"] {
+            if let Some(value) = snapshot.gauges.get(name) {
+                metrics.insert(name.to_string(), *value);
+            }
+        }
+        for name in OPS_COUNTERS {
+            metrics.insert(name.to_string(), result.registry.counter(name) as f64);
+        }
+        
+        Self {
+            scenario: result.scenario.name().to_string(),
+            seed: result.seed,
+            metrics,
+        }
+    }
+}
+
+/// Standard deviation over mean (None for fewer than two values or a zero mean).
+fn coefficient_of_variation(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    if mean == 0.0 {
+        return None;
+    }
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    Some(variance.sqrt() / mean)
+}
+
+/// Benchmark runs, unique and sorted by scenario name then seed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchBaseline {
+    /// Crate version that recorded the baseline
+    #[serde(default)]
+    pub crate_version: String,
+    
+    runs: Vec<BenchRun>,
+}
+
+impl BenchBaseline {
+    /// Records `results`; a repeated (scenario, seed) keeps the last run.
+    pub fn from_results(results: &[ScenarioResult]) -> Self {
+        let mut baseline = Self {
+            crate_version: CRATE_VERSION.to_string(),
+            runs: Vec::new(),
+        };
+        for result in results {
+            baseline.insert(BenchRun::from_result(result));
+        }
+        baseline
+    }
+    
+    /// Loads a baseline file, or returns None if it doesn't exist yet.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Option<Self>> {
+        let json = match std::fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let file: BenchBaseline = serde_json::from_str(&json)?;
+        let mut baseline = Self {
+            crate_version: file.crate_version,
+            runs: Vec::new(),
+        };
+        for run in file.runs {
+            baseline.insert(run);
+        }
+        Ok(Some(baseline))
+    }
+    
+    /// Writes the baseline as pretty-printed JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        let mut file = std::fs::File::create(path)?;
+        file.write_all(json.as_bytes())?;
+        file.write_all(b"\n")
+    }
+    
+    /// Adds a run in sorted position, replacing any run of the same pair.
+    pub fn insert(&mut self, run: BenchRun) {
+        let key = |r: &BenchRun| (r.scenario.clone(), r.seed);
+        match self.runs.binary_search_by_key(&key(&run), key) {
+            Ok(at) => self.runs[at] = run,
+            Err(at) => self.runs.insert(at, run),
+        }
+    }
+    
+    /// The run of a (scenario, seed) pair, if recorded.
+    pub fn get(&self, scenario: &str, seed: u64) -> Option<&BenchRun> {
+        self.runs.iter().find(|r| r.scenario == scenario && r.seed == seed)
+    }
+    
+    /// All runs, in order.
+    pub fn runs(&self) -> &[BenchRun] {
+        &self.runs
+    }
+}
+
+/// One metric of one run, compared with its baseline value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricDelta {
+    /// Scenario name
+    pub scenario: String,
+    
+    /// Seed of the run
+    pub seed: u64,
+    
+    /// Metric name
+    pub metric: String,
+    
+    /// Baseline value
+    pub baseline: f64,
+    
+    /// Value in this run
+    pub current: f64,
+    
+    /// Relative change `(current - baseline) / |baseline|` (None for a zero
+    /// baseline)
+    pub change: Option<f64>,
+    
+    /// Whether the metric worsened by more than the tolerance
+    pub regressed: bool,
+}
+
+/// This run's benchmarks compared with a baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchComparison {
+    /// Relative worsening that counts as a regression
+    pub tolerance: f64,
+    
+    /// Crate version of the baseline
+    pub baseline_version: String,
+    
+    /// Metrics present in both, in (scenario, seed, metric) order
+    pub deltas: Vec<MetricDelta>,
+    
+    /// Pairs run now but missing from the baseline
+    pub new_runs: Vec<(String, u64)>,
+    
+    /// Baseline pairs not run now
+    pub missing_runs: Vec<(String, u64)>,
+}
+
+impl BenchComparison {
+    /// Compares `current` with `baseline`.
+    ///
+    /// A zero baseline has no relative change; it regresses on any
+    /// worsening, since there's no scale to measure the tolerance against.
+    pub fn compare(baseline: &BenchBaseline, current: &BenchBaseline, tolerance: f64) -> Self {
+        let mut comparison = Self {
+            tolerance,
+            baseline_version: baseline.crate_version.clone(),
+            deltas: Vec::new(),
+            new_runs: Vec::new(),
+            missing_runs: Vec::new(),
+        };
+        for run in current.runs() {
+            let Some(base) = baseline.get(&run.scenario, run.seed) else {
+                comparison.new_runs.push((run.scenario.clone(), run.seed));
+                continue;
+            };
+            for (metric, &current) in &run.metrics {
+                let Some(&baseline) = base.metrics.get(metric) else {
+                    continue;
+                };
+                let change = (baseline != 0.0).then(|| (current - baseline) / baseline.abs());
+                let worsening = match Better::of(metric) {
+                    Better::Lower => current - baseline,
+                    Better::Higher => baseline - current,
+                    Better::Neither => 0.0,
+                };
+                let regressed = worsening > 0.0 && (baseline == 0.0 || worsening / baseline.abs() > tolerance);
+                comparison.deltas.push(MetricDelta {
+                    scenario: run.scenario.clone(),
+                    seed: run.seed,
+                    metric: metric.clone(),
+                    baseline,
+                    current,
+                    change,
+                    regressed,
+                });
+            }
+        }
+        comparison.missing_runs = baseline.runs().iter()
+            .filter(|r| current.get(&r.scenario, r.seed).is_none())
+            .map(|r| (r.scenario.clone(), r.seed))
+            .collect();
+        comparison
+    }
+    
+    /// Metrics that regressed.
+    pub fn regressions(&self) -> impl Iterator<Item = &MetricDelta> {
+        self.deltas.iter().filter(|d| d.regressed)
+    }
+    
+    /// Returns true if any metric regressed.
+    pub fn has_regressions(&self) -> bool {
+        self.regressions().next().is_some()
+    }
+    
+    /// Renders the comparison as markdown: a summary line and a table of
+    /// every metric that changed, regressions first.
+    pub fn to_markdown(&self) -> String {
+        let changed: Vec<&MetricDelta> = self.deltas.iter().filter(|d| d.current != d.baseline).collect();
+        let regressions = self.regressions().count();
+        
+        let mut md = String::new();
+        md.push_str("## DST benchmark\n\n");
+        let _ = writeln!(
+            md,
+            "Baseline `{}` vs `{}`, tolerance {:.1}%: **{} regression(s)**, {} of {} metric(s) changed.",
+            self.baseline_version,
+            CRATE_VERSION,
+            self.tolerance * 100.0,
+            regressions,
+            changed.len(),
+            self.deltas.len(),
+        );
+        if !self.new_runs.is_empty() {
+            let _ = writeln!(md, "\nNot in baseline: {}.", pair_list(&self.new_runs));
+        }
+        if !self.missing_runs.is_empty() {
+            let _ = writeln!(md, "\nNot run: {}.", pair_list(&self.missing_runs));
+        }
+        if changed.is_empty() {
+            return md;
+        }
+        
+        md.push_str("\n| Scenario | Seed | Metric | Baseline | Current | Change | |\n");
+        md.push_str("|---|---:|---|---:|---:|---:|---|\n");
+        let (regressed, other): (Vec<&MetricDelta>, Vec<&MetricDelta>) = changed.into_iter().partition(|d| d.regressed);
+        for d in regressed.into_iter().chain(other) {
+            let change = d.change.map_or("new".to_string(), |c| format!("{:+.1}%", c * 100.0));
+            let flag = if d.regressed { ":x: regression" } else { "" };
+            let _ = writeln!(
+                md,
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                d.scenario,
+                d.seed,
+                d.metric,
+                value(d.baseline),
+                value(d.current),
+                change,
+                flag,
+            );
+        }
+        md
+    }
+}
+
+/// Formats a metric value: integers as-is, others to 3 decimals.
+fn value(v: f64) -> String {
+    if v.fract() == 0.0 && v.abs() < 1e15 {
+        format!("{}", v as i64)
+    } else {
+        format!("{:.3}", v)
+    }
+}
+
+/// `scenario[seed=N]` pairs, comma separated.
+fn pair_list(pairs: &[(String, u64)]) -> String {
+    pairs.iter()
+        .map(|(scenario, seed)| format!("`{}[seed={}]`", scenario, seed))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn run(scenario: &str, seed: u64, metrics: &[(&str, f64)]) -> BenchRun {
+        BenchRun {
+            scenario: scenario.to_string(),
+            seed,
+            metrics: metrics.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+        }
+    }
+    
+    fn baseline(runs: Vec<BenchRun>) -> BenchBaseline {
+        let mut baseline = BenchBaseline { crate_version: "0.0.1".to_string(), runs: Vec::new() };
+        for r in runs {
+            baseline.insert(r);
+        }
+        baseline
+    }
+    
+    #[test]
+    fn test_compare_flags_worsening_beyond_tolerance() {
+        let base = baseline(vec![
+            run("time_warp", 1, &[("rms_mean", 1.0), ("passed", 1.0), ("packets_sent", 100.0)]),
+            run("swarm", 1, &[("rms_mean", 2.0), ("detection_rate", 0.5), ("packets_dropped", 0.0)]),
+            run("byzantine", 3, &[("rms_mean", 1.0)]),
+        ]);
+        let current = baseline(vec![
+            // Within tolerance, a flipped pass flag, and a doubled op count
+            run("time_warp", 1, &[("rms_mean", 1.04), ("passed", 0.0), ("packets_sent", 200.0)]),
+            // Improvement, worse detection, and a zero-baseline op count
+            run("swarm", 1, &[("rms_mean", 1.0), ("detection_rate", 0.4), ("packets_dropped", 5.0)]),
+            run("flash_mob", 2, &[("rms_mean", 9.0)]),
+        ]);
+        
+        let comparison = BenchComparison::compare(&base, &current, 0.05);
+        let regressed: Vec<(&str, &str)> = comparison.regressions()
+            .map(|d| (d.scenario.as_str(), d.metric.as_str()))
+            .collect();
+        assert_eq!(regressed, vec![("swarm", "detection_rate"), ("time_warp", "passed")]);
+        assert!(comparison.has_regressions());
+        assert_eq!(comparison.new_runs, vec![("flash_mob".to_string(), 2)]);
+        assert_eq!(comparison.missing_runs, vec![("byzantine".to_string(), 3)]);
+        
+        let rms = comparison.deltas.iter().find(|d| d.scenario == "swarm" && d.metric == "rms_mean").unwrap();
+        assert_eq!(rms.change, Some(-0.5));
+        let dropped = comparison.deltas.iter().find(|d| d.metric == "packets_dropped").unwrap();
+        assert_eq!((dropped.change, dropped.regressed), (None, false));
+        
+        // A looser tolerance only keeps the flipped pass flag
+        assert_eq!(BenchComparison::compare(&base, &current, 0.5).regressions().count(), 1);
+    }
+    
+    #[test]
+    fn test_markdown_lists_regressions_first() {
+        let base = baseline(vec![run("time_warp", 42, &[("rms_mean", 0.8), ("rms_p95", 1.0), ("ticks", 300.0)])]);
+        let current = baseline(vec![run("time_warp", 42, &[("rms_mean", 0.4), ("rms_p95", 1.5), ("ticks", 300.0)])]);
+        let md = BenchComparison::compare(&base, &current, DEFAULT_BENCH_TOLERANCE).to_markdown();
+        
+        let expected_table = "\
+| Scenario | Seed | Metric | Baseline | Current | Change | |
+|---|---:|---|---:|---:|---:|---|
+| time_warp | 42 | rms_p95 | 1 | 1.500 | +50.0% | :x: regression |
+| time_warp | 42 | rms_mean | 0.800 | 0.400 | -50.0% |  |
+";
+        assert!(md.contains("tolerance 5.0%: **1 regression(s)**, 2 of 3 metric(s) changed."), "{md}");
+        assert!(md.ends_with(expected_table), "{md}");
+        
+        let unchanged = BenchComparison::compare(&base, &base, DEFAULT_BENCH_TOLERANCE).to_markdown();
+        assert!(unchanged.contains("**0 regression(s)**, 0 of 3") && !unchanged.contains('|'));
+    }
+    
+    #[test]
+    fn test_baseline_round_trip_and_track_cv() {
+        assert_eq!(coefficient_of_variation(&[2.0, 2.0, 2.0]), Some(0.0));
+        assert_eq!(coefficient_of_variation(&[1.0, 3.0]), Some(0.5));
+        assert_eq!(coefficient_of_variation(&[4.0]), None);
+        
+        let path = std::env::temp_dir().join(format!("godview-bench-{}.json", std::process::id()));
+        assert_eq!(BenchBaseline::load(&path).unwrap(), None, "missing file has no baseline");
+        
+        let base = baseline(vec![
+            run("swarm", 2, &[("rms_mean", 1.0)]),
+            run("byzantine", 1, &[("rms_mean", 2.0)]),
+            run("swarm", 2, &[("rms_mean", 3.0)]),
+        ]);
+        assert_eq!(base.runs().len(), 2);
+        assert_eq!(base.get("swarm", 2).unwrap().metrics["rms_mean"], 3.0, "last run of a pair wins");
+        
+        base.save(&path).unwrap();
+        assert_eq!(BenchBaseline::load(&path).unwrap(), Some(base));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
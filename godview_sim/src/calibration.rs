@@ -0,0 +1,313 @@
+//! Pass thresholds fitted to a scenario's own runs.
+//!
+//! The scenarios' limits are hand-picked constants. `godview-sim calibrate`
+//! runs a scenario over many seeds instead, and for each metric in
+//! [`calibrated_metrics`] takes a percentile of what the seeds scored
+//! ([`fit_threshold`]): a metric that must stay under its limit gets the
+//! high tail plus a margin, one that must reach it the low tail minus a
+//! margin. The result is a [`CalibratedThresholds`] file; a runner given it
+//! ([`ScenarioRunner::with_thresholds`](crate::ScenarioRunner::with_thresholds))
+//! judges that scenario by the fitted values instead of the constants, and
+//! each [`ScenarioResult`] records which it used ([`ThresholdSource`]).
+
+use crate::corpus::CRATE_VERSION;
+use crate::failure::Bound;
+use crate::runner::ScenarioResult;
+use crate::scenarios::ScenarioId;
+use crate::stats;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+
+/// Default percentile of the seeds' scores a threshold is fitted to.
+pub const DEFAULT_CALIBRATION_PERCENTILE: f64 = 99.0;
+
+/// Default margin added past the fitted percentile, relative to it (10%).
+pub const DEFAULT_CALIBRATION_MARGIN: f64 = 0.1;
+
+/// The metrics of `scenario` that calibrated thresholds replace, with the
+/// side of its threshold each must stay on (empty if the scenario only has
+/// built-in limits). Names are [`ScenarioResult::registry`] gauges.
+pub fn calibrated_metrics(scenario: ScenarioId) -> &'static [(&'static str, Bound)] {
+    match scenario {
+        ScenarioId::Swarm => &[
+            ("track_count_cv", Bound::Max),
+            ("rms_mean", Bound::Max),
+            ("rms_p95", Bound::Max),
+            ("mota", Bound::Min),
+        ],
+        ScenarioId::AdaptiveSwarm => &[
+            ("detection_rate", Bound::Min),
+            ("rms_mean", Bound::Max),
+            ("rms_p95", Bound::Max),
+        ],
+        _ => &[],
+    }
+}
+
+/// Fits a threshold to `samples` of a metric: for [`Bound::Max`] their
+/// `percentile`th percentile (nearest rank) raised by `margin` of its
+/// magnitude, for [`Bound::Min`] the mirror-image low tail lowered by it.
+/// None if there are no finite samples.
+pub fn fit_threshold(samples: &[f64], bound: Bound, percentile: f64, margin: f64) -> Option<f64> {
+    let finite: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+    if finite.is_empty() {
+        return None;
+    }
+    let threshold = match bound {
+        Bound::Max => {
+            let tail = stats::percentile(&finite, percentile);
+            tail + margin * tail.abs()
+        }
+        Bound::Min => {
+            let tail = stats::percentile(&finite, 100.0 - percentile);
+            tail - margin * tail.abs()
+        }
+    };
+    Some(threshold)
+}
+
+/// Why thresholds couldn't be calibrated.
+#[derive(Debug, thiserror::Error)]
+pub enum CalibrationError {
+    #[error("{0} has no calibratable thresholds (supported: {})", calibratable_scenarios())]
+    Unsupported(ScenarioId),
+    
+    #[error("percentile must be in (0, 100], got {0}")]
+    InvalidPercentile(f64),
+    
+    #[error("margin must be finite and non-negative, got {0}")]
+    InvalidMargin(f64),
+    
+    #[error("no finite {metric} in any of the {runs} runs")]
+    NoSamples { metric: String, runs: usize },
+}
+
+fn calibratable_scenarios() -> String {
+    ScenarioId::all().into_iter()
+        .filter(|s| !calibrated_metrics(*s).is_empty())
+        .map(|s| s.name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// One fitted threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Threshold {
+    /// Limit the metric is held to
+    pub value: f64,
+    
+    /// Which side of it the metric must stay on
+    pub bound: Bound,
+}
+
+/// A scenario's thresholds fitted over a set of seeds, as written by
+/// `godview-sim calibrate`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalibratedThresholds {
+    /// Crate version that calibrated them
+    #[serde(default)]
+    pub crate_version: String,
+    
+    /// Scenario name (see [`ScenarioId::name`])
+    pub scenario: String,
+    
+    /// Runs the thresholds were fitted to
+    pub seeds: usize,
+    
+    /// Percentile of the runs' scores fitted (0-100)
+    pub percentile: f64,
+    
+    /// Margin added past the percentile, relative to it
+    pub margin: f64,
+    
+    /// Threshold of each metric, by name
+    pub thresholds: BTreeMap<String, Threshold>,
+}
+
+impl CalibratedThresholds {
+    /// Fits every [`calibrated_metrics`] threshold of `scenario` to
+    /// `results`. Aborted runs and runs of other scenarios are left out.
+    pub fn calibrate(
+        scenario: ScenarioId,
+        results: &[ScenarioResult],
+        percentile: f64,
+        margin: f64,
+    ) -> Result<Self, CalibrationError> {
+        let metrics = calibrated_metrics(scenario);
+        if metrics.is_empty() {
+            return Err(CalibrationError::Unsupported(scenario));
+        }
+        if !(percentile > 0.0 && percentile <= 100.0) {
+            return Err(CalibrationError::InvalidPercentile(percentile));
+        }
+        if !(margin.is_finite() && margin >= 0.0) {
+            return Err(CalibrationError::InvalidMargin(margin));
+        }
+        let runs: Vec<&ScenarioResult> = results.iter()
+            .filter(|r| r.scenario == scenario && r.aborted.is_none())
+            .collect();
+        let mut thresholds = BTreeMap::new();
+        for &(metric, bound) in metrics {
+            let samples: Vec<f64> = runs.iter()
+                .filter_map(|r| r.registry.snapshot().gauges.get(metric).copied())
+                .collect();
+            let value = fit_threshold(&samples, bound, percentile, margin)
+                .ok_or_else(|| CalibrationError::NoSamples { metric: metric.to_string(), runs: runs.len() })?;
+            thresholds.insert(metric.to_string(), Threshold { value, bound });
+        }
+        Ok(Self {
+            crate_version: CRATE_VERSION.to_string(),
+            scenario: scenario.name().to_string(),
+            seeds: runs.len(),
+            percentile,
+            margin,
+            thresholds,
+        })
+    }
+    
+    /// Loads a thresholds file.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+    
+    /// Writes the thresholds as pretty-printed JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        let mut file = std::fs::File::create(path)?;
+        file.write_all(json.as_bytes())?;
+        file.write_all(b"\n")
+    }
+    
+    /// Whether the thresholds were calibrated on `scenario`.
+    pub fn applies_to(&self, scenario: ScenarioId) -> bool {
+        self.scenario == scenario.name()
+    }
+    
+    /// Calibrated limit of `metric`, if there is one.
+    pub fn get(&self, metric: &str) -> Option<f64> {
+        self.thresholds.get(metric).map(|t| t.value)
+    }
+    
+    /// Where a run judged by these thresholds got them.
+    pub fn source(&self) -> ThresholdSource {
+        ThresholdSource::Calibrated { seeds: self.seeds, percentile: self.percentile, margin: self.margin }
+    }
+}
+
+/// Where a run's pass thresholds came from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ThresholdSource {
+    /// The scenario's hardcoded limits
+    #[default]
+    Builtin,
+    
+    /// A [`CalibratedThresholds`] file
+    Calibrated {
+        seeds: usize,
+        percentile: f64,
+        margin: f64,
+    },
+}
+
+impl ThresholdSource {
+    /// Lower-case label used in reports.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThresholdSource::Builtin => "builtin",
+            ThresholdSource::Calibrated { .. } => "calibrated",
+        }
+    }
+}
+
+impl fmt::Display for ThresholdSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThresholdSource::Builtin => write!(f, "builtin"),
+            ThresholdSource::Calibrated { seeds, percentile, margin } => {
+                write!(f, "calibrated (p{} +{}% over {} seeds)", percentile, margin * 100.0, seeds)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_percentile_is_nearest_rank() {
+        let values: Vec<f64> = (1..=100).rev().map(f64::from).collect();
+        assert_eq!(stats::percentile(&values, 99.0), 99.0);
+        assert_eq!(stats::percentile(&values, 1.0), 1.0);
+        assert_eq!(stats::percentile(&values, 100.0), 100.0);
+        assert_eq!(stats::percentile(&values, 0.0), 1.0);
+        assert_eq!(stats::percentile(&values, 95.0), stats::p95(&values));
+        assert_eq!(stats::percentile(&[], 50.0), 0.0);
+    }
+    
+    #[test]
+    fn test_fit_threshold_adds_the_margin_past_the_tail() {
+        let samples: Vec<f64> = (1..=100).map(f64::from).collect();
+        // p99 of 1..=100 is 99, plus 10%
+        assert!((fit_threshold(&samples, Bound::Max, 99.0, 0.1).unwrap() - 108.9).abs() < 1e-9);
+        // The mirror-image low tail is 1, less 10%
+        assert!((fit_threshold(&samples, Bound::Min, 99.0, 0.1).unwrap() - 0.9).abs() < 1e-9);
+        assert_eq!(fit_threshold(&samples, Bound::Max, 50.0, 0.0), Some(50.0));
+        
+        // The margin loosens negative tails too
+        assert!((fit_threshold(&[-2.0, -1.0], Bound::Min, 100.0, 0.5).unwrap() + 3.0).abs() < 1e-9);
+        
+        // Non-finite samples are left out
+        assert_eq!(fit_threshold(&[f64::NAN, 2.0, f64::INFINITY], Bound::Max, 99.0, 0.0), Some(2.0));
+        assert_eq!(fit_threshold(&[f64::NAN], Bound::Max, 99.0, 0.0), None);
+    }
+    
+    #[test]
+    fn test_calibrate_rejects_what_it_cannot_fit() {
+        assert!(matches!(
+            CalibratedThresholds::calibrate(ScenarioId::TimeWarp, &[], 99.0, 0.1),
+            Err(CalibrationError::Unsupported(ScenarioId::TimeWarp))
+        ));
+        assert!(matches!(
+            CalibratedThresholds::calibrate(ScenarioId::Swarm, &[], 0.0, 0.1),
+            Err(CalibrationError::InvalidPercentile(_))
+        ));
+        assert!(matches!(
+            CalibratedThresholds::calibrate(ScenarioId::Swarm, &[], 99.0, -0.1),
+            Err(CalibrationError::InvalidMargin(_))
+        ));
+        let err = CalibratedThresholds::calibrate(ScenarioId::Swarm, &[], 99.0, 0.1).unwrap_err();
+        assert_eq!(err.to_string(), "no finite track_count_cv in any of the 0 runs");
+    }
+    
+    #[test]
+    fn test_thresholds_file_round_trips() {
+        let thresholds = CalibratedThresholds {
+            crate_version: CRATE_VERSION.to_string(),
+            scenario: "swarm".to_string(),
+            seeds: 100,
+            percentile: 99.0,
+            margin: 0.1,
+            thresholds: BTreeMap::from([
+                ("mota".to_string(), Threshold { value: 0.5, bound: Bound::Min }),
+                ("rms_mean".to_string(), Threshold { value: 1.2, bound: Bound::Max }),
+            ]),
+        };
+        let json = serde_json::to_string(&thresholds).unwrap();
+        assert!(json.contains(r#""mota":{"value":0.5,"bound":"min"}"#));
+        let parsed: CalibratedThresholds = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, thresholds);
+        
+        assert!(parsed.applies_to(ScenarioId::Swarm));
+        assert!(!parsed.applies_to(ScenarioId::AdaptiveSwarm));
+        assert_eq!(parsed.get("rms_mean"), Some(1.2));
+        assert_eq!(parsed.get("track_count_cv"), None);
+        assert_eq!(parsed.source().to_string(), "calibrated (p99 +10% over 100 seeds)");
+        assert_eq!(ThresholdSource::default().label(), "builtin");
+    }
+}
@@ -0,0 +1,258 @@
+//! Scenarios chained against one persistent world.
+//!
+//! A [`Campaign`] is an ordered list of phases, each a scenario and how long
+//! to run it. [`ScenarioRunner::run_campaign`](crate::ScenarioRunner::run_campaign)
+//! builds a swarm world once and plays every phase against the same agents
+//! and oracle, so tracks, reputations and anything else an agent has learned
+//! carry over from one phase into the next. A quiet phase after a chaos
+//! storm then shows whether the swarm recovers, not how it does from a cold
+//! start.
+//!
+//! Only scenarios that can be played as events on a running
+//! [`Simulation`](crate::Simulation) can be phases: see
+//! [`CAMPAIGN_SCENARIOS`]. Campaign files are TOML:
+//!
+//! ```toml
+//! [[phase]]
+//! scenario = "swarm"
+//! duration_secs = 10
+//!
+//! [[phase]]
+//! scenario = "chaos_storm"
+//! duration_secs = 15
+//! ```
+
+use crate::runner::ScenarioResult;
+use crate::scenarios::ScenarioId;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Scenarios a campaign phase can run.
+pub const CAMPAIGN_SCENARIOS: [ScenarioId; 3] = [ScenarioId::Swarm, ScenarioId::SplitBrain, ScenarioId::ChaosStorm];
+
+/// Multiple of its first phase's RMS the swarm must end a campaign within to
+/// have recovered.
+pub const CAMPAIGN_RECOVERY_FACTOR: f64 = 1.25;
+
+/// One scenario of a campaign.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CampaignPhase {
+    /// Scenario played during the phase
+    pub scenario: ScenarioId,
+    
+    /// How long the phase runs (s)
+    pub duration_secs: f64,
+}
+
+/// Why a campaign couldn't be loaded.
+#[derive(Debug, thiserror::Error)]
+pub enum CampaignError {
+    #[error("failed to read campaign: {0}")]
+    Io(#[from] std::io::Error),
+    
+    #[error("malformed campaign: {0}")]
+    Toml(#[from] toml::de::Error),
+    
+    #[error("campaign has no phases")]
+    Empty,
+    
+    #[error("phase {phase}: {message}")]
+    UnknownScenario { phase: usize, message: String },
+    
+    #[error("phase {phase}: {scenario} can't run as a campaign phase (supported: {})", supported_scenarios())]
+    UnsupportedScenario { phase: usize, scenario: ScenarioId },
+    
+    #[error("phase {phase}: duration must be positive, got {duration_secs}s")]
+    InvalidDuration { phase: usize, duration_secs: f64 },
+}
+
+fn supported_scenarios() -> String {
+    CAMPAIGN_SCENARIOS.map(|s| s.name()).join(", ")
+}
+
+/// A campaign file as written.
+#[derive(Debug, Deserialize)]
+struct CampaignFile {
+    #[serde(default, rename = "phase")]
+    phases: Vec<PhaseEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PhaseEntry {
+    scenario: String,
+    duration_secs: f64,
+}
+
+/// An ordered list of phases run against one world.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Campaign {
+    phases: Vec<CampaignPhase>,
+}
+
+impl Campaign {
+    /// Creates a campaign, checking every phase is a supported scenario with
+    /// a positive duration.
+    pub fn new(phases: Vec<CampaignPhase>) -> Result<Self, CampaignError> {
+        if phases.is_empty() {
+            return Err(CampaignError::Empty);
+        }
+        for (phase, p) in phases.iter().enumerate() {
+            if !CAMPAIGN_SCENARIOS.contains(&p.scenario) {
+                return Err(CampaignError::UnsupportedScenario { phase, scenario: p.scenario });
+            }
+            if !(p.duration_secs.is_finite() && p.duration_secs > 0.0) {
+                return Err(CampaignError::InvalidDuration { phase, duration_secs: p.duration_secs });
+            }
+        }
+        Ok(Self { phases })
+    }
+    
+    /// Parses a campaign from TOML text.
+    pub fn from_toml(text: &str) -> Result<Self, CampaignError> {
+        let file: CampaignFile = toml::from_str(text)?;
+        let phases = file.phases.into_iter()
+            .enumerate()
+            .map(|(phase, entry)| {
+                let scenario = entry.scenario.parse()
+                    .map_err(|message| CampaignError::UnknownScenario { phase, message })?;
+                Ok(CampaignPhase { scenario, duration_secs: entry.duration_secs })
+            })
+            .collect::<Result<_, CampaignError>>()?;
+        Self::new(phases)
+    }
+    
+    /// Loads a campaign from a TOML file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CampaignError> {
+        Self::from_toml(&std::fs::read_to_string(path)?)
+    }
+    
+    /// The phases, in the order they run.
+    pub fn phases(&self) -> &[CampaignPhase] {
+        &self.phases
+    }
+    
+    /// Total simulated time over every phase (s).
+    pub fn duration_secs(&self) -> f64 {
+        self.phases.iter().map(|p| p.duration_secs).sum()
+    }
+}
+
+/// How accuracy moved across a campaign's phases.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CampaignMetrics {
+    /// Mean RMS of each phase, in order (m)
+    pub phase_rms: Vec<f64>,
+    
+    /// Phase with the highest mean RMS
+    pub worst_phase: usize,
+    
+    /// Whether every phase's RMS was at least the one before it
+    pub degraded_monotonically: bool,
+    
+    /// Whether the last phase ended within [`CAMPAIGN_RECOVERY_FACTOR`] of
+    /// the first phase's RMS
+    pub recovered: bool,
+}
+
+impl CampaignMetrics {
+    /// Computes the metrics from each phase's mean RMS.
+    pub fn from_phase_rms(phase_rms: &[f64]) -> Self {
+        let worst_phase = phase_rms.iter()
+            .enumerate()
+            .fold(None, |worst: Option<(usize, f64)>, (i, &rms)| match worst {
+                Some((_, max)) if max >= rms => worst,
+                _ => Some((i, rms)),
+            })
+            .map_or(0, |(i, _)| i);
+        let recovered = match (phase_rms.first(), phase_rms.last()) {
+            (Some(&first), Some(&last)) => last <= CAMPAIGN_RECOVERY_FACTOR * first,
+            _ => false,
+        };
+        Self {
+            phase_rms: phase_rms.to_vec(),
+            worst_phase,
+            degraded_monotonically: phase_rms.windows(2).all(|w| w[1] >= w[0]),
+            recovered,
+        }
+    }
+}
+
+/// Outcome of a campaign: one result per phase plus how they compare.
+#[derive(Debug, Clone)]
+pub struct CampaignResult {
+    /// Seed used
+    pub seed: u64,
+    
+    /// Result of each phase that ran, in order; phases after an aborted
+    /// one are not run
+    pub phases: Vec<ScenarioResult>,
+    
+    /// Accuracy across the phases
+    pub metrics: CampaignMetrics,
+}
+
+impl CampaignResult {
+    /// Whether every phase passed.
+    pub fn passed(&self) -> bool {
+        self.phases.iter().all(|r| r.passed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_campaign_parses_phases_in_order() {
+        let campaign = Campaign::from_toml(r#"
+            [[phase]]
+            scenario = "swarm"
+            duration_secs = 10
+            
+            [[phase]]
+            scenario = "dst-008"
+            duration_secs = 5.5
+        "#).unwrap();
+        
+        assert_eq!(campaign.phases(), &[
+            CampaignPhase { scenario: ScenarioId::Swarm, duration_secs: 10.0 },
+            CampaignPhase { scenario: ScenarioId::ChaosStorm, duration_secs: 5.5 },
+        ]);
+        assert_eq!(campaign.duration_secs(), 15.5);
+    }
+    
+    #[test]
+    fn test_campaign_rejects_bad_phases() {
+        let phase = |scenario: &str, duration_secs: f64| {
+            format!("[[phase]]\nscenario = \"{scenario}\"\nduration_secs = {duration_secs:?}\n")
+        };
+        
+        assert!(matches!(Campaign::from_toml(""), Err(CampaignError::Empty)));
+        assert!(matches!(Campaign::from_toml("phase = 3"), Err(CampaignError::Toml(_))));
+        assert!(matches!(
+            Campaign::from_toml(&(phase("swarm", 1.0) + &phase("nope", 1.0))),
+            Err(CampaignError::UnknownScenario { phase: 1, .. })
+        ));
+        assert!(matches!(
+            Campaign::from_toml(&phase("time_warp", 1.0)),
+            Err(CampaignError::UnsupportedScenario { phase: 0, scenario: ScenarioId::TimeWarp })
+        ));
+        assert!(matches!(
+            Campaign::from_toml(&phase("swarm", 0.0)),
+            Err(CampaignError::InvalidDuration { phase: 0, .. })
+        ));
+    }
+    
+    #[test]
+    fn test_metrics_track_degradation_and_recovery() {
+        let degrading = CampaignMetrics::from_phase_rms(&[1.0, 1.5, 3.0]);
+        assert!(degrading.degraded_monotonically);
+        assert!(!degrading.recovered);
+        assert_eq!(degrading.worst_phase, 2);
+        
+        let recovering = CampaignMetrics::from_phase_rms(&[1.0, 3.0, 1.2]);
+        assert!(!recovering.degraded_monotonically);
+        assert!(recovering.recovered);
+        assert_eq!(recovering.worst_phase, 1);
+    }
+}
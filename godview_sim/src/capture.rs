@@ -0,0 +1,414 @@
+//! Time-of-interest capture: a short export window around a run's failure.
+//!
+//! A [`FrameRecorder`] keeps the last few seconds of downsampled frames in a
+//! ring buffer. When an invariant first breaks it keeps recording for as long
+//! again, so the violation sits at the center of the window; a run that fails
+//! without one is captured up to its last tick. The window is written as a
+//! [`SimExport`] annotated with the [`InvariantViolation`].
+//!
+//! Frames hold ground truth positions and per-agent track counts. Verbose
+//! capture adds each agent's track positions and RMS error. Agents that log
+//! their gossip decisions ([`AgentConfig::gossip_decision_log`]) have the
+//! latest ones as of the window's last frame exported with it.
+//!
+//! [`AgentConfig::gossip_decision_log`]: godview_core::AgentConfig::gossip_decision_log
+
+use crate::agent::SimulatedAgent;
+use crate::exporter::{AgentFrame, AgentGossipDecisions, EntityPosition, SimExport, SimFrame, TrackPosition};
+use crate::oracle::Oracle;
+use crate::scoring::{label_tracks, DEFAULT_MATCH_GATE_M};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+/// Default seconds captured on each side of a violation.
+pub const DEFAULT_CAPTURE_WINDOW_SECS: f64 = 5.0;
+
+/// Most frames kept on each side of a violation; longer windows are
+/// downsampled to fit.
+pub const MAX_FRAMES_PER_SIDE: usize = 50;
+
+/// The first invariant a run broke.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvariantViolation {
+    /// Loop iteration the violation was seen on (0-based)
+    pub tick: u64,
+    
+    /// Simulated time of that tick (s)
+    pub time_sec: f64,
+    
+    /// Which invariant broke (e.g. "numerical_fault", "memory_budget")
+    pub invariant: String,
+    
+    /// What was observed
+    pub details: String,
+}
+
+impl InvariantViolation {
+    /// Creates a violation of `invariant` at `tick`.
+    pub fn new(tick: u64, time_sec: f64, invariant: &str, details: String) -> Self {
+        Self { tick, time_sec, invariant: invariant.to_string(), details }
+    }
+}
+
+/// Where and how much a runner captures around failures.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptureConfig {
+    /// Directory window files are written to
+    pub dir: PathBuf,
+    
+    /// Seconds captured on each side of the violation
+    pub window_secs: f64,
+    
+    /// Record track positions and RMS error, not just track counts
+    pub verbose: bool,
+}
+
+impl CaptureConfig {
+    /// Captures [`DEFAULT_CAPTURE_WINDOW_SECS`] either side into `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            window_secs: DEFAULT_CAPTURE_WINDOW_SECS,
+            verbose: false,
+        }
+    }
+    
+    /// Sets the seconds captured on each side of the violation.
+    pub fn with_window_secs(mut self, secs: f64) -> Self {
+        self.window_secs = secs.max(0.0);
+        self
+    }
+    
+    /// Records track positions and RMS error in every frame.
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+    
+    /// Path of the window file for a violation at `tick`.
+    pub fn file_path(&self, scenario: &str, seed: u64, tick: u64) -> PathBuf {
+        self.dir.join(capture_file_name(scenario, seed, tick))
+    }
+}
+
+/// `failure_<scenario>_<seed>_<tick>.json`
+pub fn capture_file_name(scenario: &str, seed: u64, tick: u64) -> String {
+    format!("failure_{}_{}_{}.json", scenario, seed, tick)
+}
+
+/// Ring buffer of downsampled frames around the first violation.
+#[derive(Debug, Clone)]
+pub struct FrameRecorder {
+    stride: u64,
+    frames_per_side: usize,
+    verbose: bool,
+    frames: VecDeque<(u64, SimFrame)>,
+    violation: Option<InvariantViolation>,
+    frames_after: usize,
+    gossip_decisions: Vec<AgentGossipDecisions>,
+}
+
+impl FrameRecorder {
+    /// Creates a recorder for `window_secs` either side at `tick_rate_hz`.
+    pub fn new(window_secs: f64, tick_rate_hz: u32, verbose: bool) -> Self {
+        let window_ticks = ((window_secs * tick_rate_hz as f64).round() as u64).max(1);
+        let stride = window_ticks.div_ceil(MAX_FRAMES_PER_SIDE as u64);
+        Self {
+            stride,
+            frames_per_side: (window_ticks / stride) as usize,
+            verbose,
+            frames: VecDeque::new(),
+            violation: None,
+            frames_after: 0,
+            gossip_decisions: Vec::new(),
+        }
+    }
+    
+    /// Creates a recorder from a runner's capture settings.
+    pub fn from_config(config: &CaptureConfig, tick_rate_hz: u32) -> Self {
+        Self::new(config.window_secs, tick_rate_hz, config.verbose)
+    }
+    
+    /// Ticks between recorded frames.
+    pub fn stride(&self) -> u64 {
+        self.stride
+    }
+    
+    /// Frames currently held.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+    
+    /// Returns true if no frames have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+    
+    /// The violation being captured, if one has fired.
+    pub fn violation(&self) -> Option<&InvariantViolation> {
+        self.violation.as_ref()
+    }
+    
+    /// Returns true once the window after the violation is full.
+    pub fn is_complete(&self) -> bool {
+        self.violation.is_some() && self.frames_after >= self.frames_per_side
+    }
+    
+    /// Records the state after loop iteration `tick`, on every stride'th
+    /// tick, along with the agents' logged gossip decisions. Before a
+    /// violation only the latest window is kept.
+    pub fn record<'a>(
+        &mut self,
+        tick: u64,
+        oracle: &Oracle,
+        agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+    ) {
+        if !tick.is_multiple_of(self.stride) || self.is_complete() {
+            return;
+        }
+        
+        let agents: Vec<&SimulatedAgent> = agents.into_iter().collect();
+        let frame = snapshot_frame(tick, oracle, agents.iter().copied(), self.verbose);
+        self.frames.push_back((tick, frame));
+        self.gossip_decisions = agents.iter()
+            .filter_map(|agent| agent.gossip_decisions().map(|log| AgentGossipDecisions {
+                agent_id: agent.agent_index(),
+                decisions: log.records().copied().collect(),
+            }))
+            .collect();
+        if self.violation.is_some() {
+            self.frames_after += 1;
+        } else if self.frames.len() > self.frames_per_side + 1 {
+            self.frames.pop_front();
+        }
+    }
+    
+    /// Starts capturing after `violation`, unless an earlier one already is.
+    pub fn violate(&mut self, violation: InvariantViolation) {
+        if self.violation.is_some() {
+            return;
+        }
+        // Frames recorded on the violation tick belong before it
+        self.frames_after = self.frames.iter().filter(|(tick, _)| *tick > violation.tick).count();
+        self.violation = Some(violation);
+    }
+    
+    /// The captured window as an export, annotated with the violation and
+    /// the latest gossip decisions.
+    pub fn into_export(self, scenario: &str, seed: u64, passed: bool, rms: Option<f64>) -> SimExport {
+        let mut export = SimExport::new(scenario, seed);
+        for (_, frame) in self.frames {
+            export.add_frame(frame);
+        }
+        export.finalize(passed, rms);
+        export.violation = self.violation;
+        export.gossip_decisions = self.gossip_decisions;
+        export
+    }
+    
+    /// Writes the window to `dir/failure_<scenario>_<seed>_<tick>.json`,
+    /// returning the path. Does nothing without a violation.
+    pub fn write(self, config: &CaptureConfig, scenario: &str, seed: u64, passed: bool, rms: Option<f64>) -> std::io::Result<Option<PathBuf>> {
+        let Some(tick) = self.violation.as_ref().map(|v| v.tick) else {
+            return Ok(None);
+        };
+        let path = config.file_path(scenario, seed, tick);
+        let export = self.into_export(scenario, seed, passed, rms);
+        export.write_to_file(path_str(&path)?)?;
+        Ok(Some(path))
+    }
+}
+
+/// The state after loop iteration `tick`: ground truth and each agent's
+/// track count, plus track positions, coasting ages, ground-truth labels
+/// (debug only, see [`TruthLabel`](crate::TruthLabel)), provenance (if the
+/// agents keep it) and RMS error if `verbose`.
+pub(crate) fn snapshot_frame<'a>(
+    tick: u64,
+    oracle: &Oracle,
+    agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+    verbose: bool,
+) -> SimFrame {
+    let ground_truth = oracle.ground_truth_positions();
+    let agents = agents.into_iter()
+        .map(|agent| {
+            let (tracks, rms_error) = if verbose {
+                let states = agent.track_states();
+                let positions: Vec<Vector3<f64>> = states.iter().map(|(_, pos, _)| *pos).collect();
+                let labels = label_tracks(&ground_truth, &positions, DEFAULT_MATCH_GATE_M);
+                let tracks = states.into_iter()
+                    .zip(labels)
+                    .map(|((uuid, pos, coasting_ticks), label)| {
+                        let track = TrackPosition::new(uuid, pos).with_coasting_ticks(coasting_ticks).with_truth_label(label);
+                        match agent.track(&uuid) {
+                            Some(fused) => track.with_provenance(fused),
+                            None => track,
+                        }
+                    })
+                    .collect();
+                (tracks, Some(agent.compute_position_error(&ground_truth)))
+            } else {
+                (Vec::new(), None)
+            };
+            AgentFrame {
+                agent_id: agent.agent_index(),
+                tracks,
+                rms_error,
+                track_count: Some(agent.track_count()),
+            }
+        })
+        .collect();
+    SimFrame {
+        time_sec: oracle.time(),
+        tick: Some(tick),
+        ground_truth: ground_truth.into_iter().map(|(id, pos)| EntityPosition::new(id, pos)).collect(),
+        agents,
+        events: vec![],
+    }
+}
+
+/// `path` as UTF-8, as [`SimExport::write_to_file`] takes it.
+fn path_str(path: &Path) -> std::io::Result<&str> {
+    path.to_str().ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "non-UTF-8 capture path"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Vector3;
+    
+    #[test]
+    fn test_window_is_bounded_and_centered() {
+        let mut recorder = FrameRecorder::new(1.0, 10, false);
+        assert_eq!((recorder.stride(), recorder.frames_per_side), (1, 10));
+        
+        let mut oracle = Oracle::new(7);
+        oracle.spawn_entity(Vector3::new(0.0, 0.0, 100.0), Vector3::new(5.0, 0.0, 0.0), "target");
+        for tick in 0..30 {
+            recorder.record(tick, &oracle, std::iter::empty());
+            assert!(recorder.len() <= 11);
+        }
+        recorder.violate(InvariantViolation::new(29, 2.9, "test", "first".to_string()));
+        recorder.violate(InvariantViolation::new(30, 3.0, "test", "second".to_string()));
+        for tick in 30..100 {
+            recorder.record(tick, &oracle, std::iter::empty());
+        }
+        assert!(recorder.is_complete());
+        
+        let export = recorder.into_export("test", 1, false, None);
+        let ticks: Vec<u64> = export.frames.iter().filter_map(|f| f.tick).collect();
+        assert_eq!(ticks, (19..=39).collect::<Vec<_>>());
+        assert_eq!(export.violation.unwrap().details, "first");
+    }
+    
+    #[test]
+    fn test_export_carries_latest_gossip_decisions() {
+        use crate::adaptive::GossipDecision;
+        use crate::context::SimContext;
+        use crate::keys::DeterministicKeyProvider;
+        use crate::network::SimNetwork;
+        use godview_core::AgentConfig;
+        use godview_env::NodeId;
+        use std::sync::Arc;
+        
+        let key_provider = DeterministicKeyProvider::new(42);
+        let mut logging = SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(1))),
+            key_provider.biscuit_root_key().public(),
+            1,
+            AgentConfig { gossip_decision_log: Some(4), ..SimulatedAgent::default_config() },
+        );
+        let mut source = SimulatedAgent::new(
+            Arc::new(SimContext::new(43)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(2))),
+            key_provider.biscuit_root_key().public(),
+            2,
+            SimulatedAgent::default_config(),
+        );
+        source.ingest_readings(&[crate::oracle::SensorReading::new(7, Vector3::new(0.0, 0.0, 100.0), Vector3::zeros())]);
+        logging.receive_gossip_from(2, source.recent_packets());
+        
+        let oracle = Oracle::new(7);
+        let mut recorder = FrameRecorder::new(0.1, 10, false);
+        recorder.record(0, &oracle, [&logging, &source]);
+        recorder.violate(InvariantViolation::new(0, 0.0, "test", String::new()));
+        
+        let json = serde_json::to_string(&recorder.into_export("test", 1, false, None)).unwrap();
+        let export = SimExport::from_json(&json).unwrap();
+        assert_eq!(export.gossip_decisions.len(), 1, "only the logging agent");
+        let decisions = &export.gossip_decisions[0];
+        assert_eq!(decisions.agent_id, 1);
+        assert_eq!(decisions.decisions.len(), 1);
+        assert_eq!((decisions.decisions[0].neighbor_id, decisions.decisions[0].decision), (2, GossipDecision::Accepted));
+    }
+    
+    #[test]
+    fn test_truth_labels_match_the_scoring_assignment() {
+        use crate::context::SimContext;
+        use crate::keys::DeterministicKeyProvider;
+        use crate::network::SimNetwork;
+        use crate::scoring::{assign, MotAccumulator, MotTrack};
+        use godview_env::NodeId;
+        use std::sync::Arc;
+        
+        let mut oracle = Oracle::new(7);
+        oracle.set_position_noise(1.5);
+        for x in [0.0, 4.0, 60.0] {
+            oracle.spawn_entity(Vector3::new(x, 0.0, 100.0), Vector3::new(2.0, 1.0, 0.0), "target");
+        }
+        let mut agent = SimulatedAgent::new(
+            Arc::new(SimContext::new(7)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(1))),
+            DeterministicKeyProvider::new(7).biscuit_root_key().public(),
+            1,
+            SimulatedAgent::default_config(),
+        );
+        for _ in 0..10 {
+            oracle.step(0.1);
+            agent.tick();
+            agent.ingest_readings(&oracle.generate_sensor_readings());
+        }
+        
+        let frame = snapshot_frame(9, &oracle, [&agent], true);
+        let tracks = &frame.agents[0].tracks;
+        let ground_truth = oracle.ground_truth_positions();
+        let states = agent.track_states();
+        assert_eq!(tracks.len(), states.len());
+        assert!(!tracks.is_empty());
+        
+        let truth: Vec<Vector3<f64>> = ground_truth.iter().map(|(_, p)| *p).collect();
+        let positions: Vec<Vector3<f64>> = states.iter().map(|(_, p, _)| *p).collect();
+        let matches = assign(&truth, &positions, DEFAULT_MATCH_GATE_M);
+        let mut expected: Vec<Option<(u64, f64)>> = vec![None; tracks.len()];
+        for m in &matches {
+            expected[m.track] = Some((ground_truth[m.truth].0, m.distance));
+        }
+        for (track, expected) in tracks.iter().zip(&expected) {
+            assert_eq!(track.debug_truth_id, expected.map(|(id, _)| id));
+            if let Some((_, distance)) = expected {
+                assert_eq!(track.debug_error_m, Some(*distance));
+            }
+        }
+        
+        // And MOT scoring counts exactly the labeled tracks as matches
+        let mot_tracks: Vec<MotTrack<'_>> = states.iter().map(|(id, p, _)| MotTrack::new(*id, *p)).collect();
+        let mut scorer = MotAccumulator::new(DEFAULT_MATCH_GATE_M);
+        scorer.observe(&ground_truth, &mot_tracks);
+        let labeled = tracks.iter().filter(|t| t.debug_truth_id.is_some()).count() as u64;
+        assert_eq!(scorer.metrics().matches, labeled);
+        assert_eq!(scorer.metrics().false_positives, tracks.len() as u64 - labeled);
+    }
+    
+    #[test]
+    fn test_long_windows_are_downsampled() {
+        let recorder = FrameRecorder::new(DEFAULT_CAPTURE_WINDOW_SECS, 100, false);
+        assert_eq!(recorder.stride(), 10);
+        assert_eq!(recorder.frames_per_side, MAX_FRAMES_PER_SIDE);
+        
+        assert_eq!(FrameRecorder::new(0.0, 30, false).stride(), 1);
+        assert_eq!(capture_file_name("swarm", 42, 120), "failure_swarm_42_120.json");
+    }
+}
@@ -0,0 +1,172 @@
+//! Gossip time alignment and per-neighbor clock offset estimation.
+//!
+//! A [`TimeAlignment::Propagate`] agent moves each gossiped measurement from
+//! its timestamp to the receiver's clock along the packet's velocity before
+//! fusing it. That corrects for gossip delay, but a sender whose clock is
+//! off by `δ` then lands `v·δ` away from the truth, every packet, in the same
+//! direction. [`TimeAlignment::Estimate`] also learns each neighbor's offset
+//! from how its aligned packets sit against the receiver's own tracks: a
+//! residual `r` along the velocity `v` means the offset is still off by
+//! `-(r·v)/|v|²`.
+
+use nalgebra::Vector3;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Smallest fraction of each residual-timing sample folded into an offset
+/// estimate. The first `1 / OFFSET_GAIN` samples are averaged outright so a
+/// new neighbor's estimate settles quickly; later ones are weighted by this
+/// gain so the estimate keeps following drift.
+pub const OFFSET_GAIN: f64 = 0.05;
+
+/// Slowest target (m/s) whose residuals are used; slower ones say little
+/// about timing.
+pub const MIN_ESTIMATION_SPEED: f64 = 2.0;
+
+/// Largest |age| (s) a packet is propagated by, and largest single offset
+/// sample.
+pub const MAX_ALIGNMENT_SECS: f64 = 5.0;
+
+/// How an agent interprets the timestamps of gossiped packets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeAlignment {
+    /// Timestamps are ignored: packets are fused where they were measured
+    #[default]
+    Off,
+    
+    /// Packets are propagated from their timestamp to the local clock
+    Propagate,
+    
+    /// As `Propagate`, after correcting each neighbor's estimated clock offset
+    Estimate,
+}
+
+/// One neighbor's estimated clock offset.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OffsetEstimate {
+    /// How far the neighbor's clock runs ahead of ours (s)
+    pub offset_secs: f64,
+    
+    /// Residuals folded in
+    pub samples: u64,
+}
+
+/// Clock offset estimates by neighbor.
+#[derive(Debug, Clone, Default)]
+pub struct ClockOffsetEstimator {
+    estimates: HashMap<usize, OffsetEstimate>,
+}
+
+impl ClockOffsetEstimator {
+    /// Creates an estimator with no neighbors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Current estimate for `neighbor` (0.0 if never observed).
+    pub fn offset_secs(&self, neighbor: usize) -> f64 {
+        self.estimates.get(&neighbor).map_or(0.0, |e| e.offset_secs)
+    }
+    
+    /// Estimate for `neighbor`, if it has been observed.
+    pub fn estimate(&self, neighbor: usize) -> Option<OffsetEstimate> {
+        self.estimates.get(&neighbor).copied()
+    }
+    
+    /// Folds in the residual (aligned packet minus own track) of a packet
+    /// moving at `velocity`. Returns the remaining offset error the sample
+    /// implied, or None if the target was too slow to say.
+    pub fn observe(&mut self, neighbor: usize, residual: Vector3<f64>, velocity: Vector3<f64>) -> Option<f64> {
+        let speed_squared = velocity.norm_squared();
+        if speed_squared < MIN_ESTIMATION_SPEED * MIN_ESTIMATION_SPEED {
+            return None;
+        }
+        let remaining = (-residual.dot(&velocity) / speed_squared).clamp(-MAX_ALIGNMENT_SECS, MAX_ALIGNMENT_SECS);
+        let estimate = self.estimates.entry(neighbor).or_default();
+        estimate.samples += 1;
+        estimate.offset_secs += remaining * (1.0 / estimate.samples as f64).max(OFFSET_GAIN);
+        Some(remaining)
+    }
+}
+
+/// Swarm RMS at one sender clock skew.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SkewSample {
+    /// Sender clock offset (ms)
+    pub skew_ms: f64,
+    
+    /// Post-warm-up mean RMS error (m)
+    pub rms: f64,
+}
+
+/// Outcome of the ClockSkew scenario's sweep.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClockSkewStats {
+    /// RMS with timestamps propagated but offsets uncorrected, by skew
+    pub propagated: Vec<SkewSample>,
+    
+    /// Least-squares slope of `propagated`: meters of RMS per 100ms of skew
+    pub rms_per_100ms: f64,
+    
+    /// RMS at the largest skew with neighbor offsets estimated
+    pub compensated_rms: f64,
+    
+    /// Worst error of any agent's offset estimate at the end of that run (ms)
+    pub offset_error_ms: f64,
+}
+
+impl ClockSkewStats {
+    /// Fits the degradation slope over `propagated`.
+    pub fn new(propagated: Vec<SkewSample>, compensated_rms: f64, offset_error_ms: f64) -> Self {
+        let n = propagated.len().max(1) as f64;
+        let mean_x = propagated.iter().map(|s| s.skew_ms / 100.0).sum::<f64>() / n;
+        let mean_y = propagated.iter().map(|s| s.rms).sum::<f64>() / n;
+        let (covariance, variance) = propagated.iter().fold((0.0, 0.0), |(c, v), s| {
+            let dx = s.skew_ms / 100.0 - mean_x;
+            (c + dx * (s.rms - mean_y), v + dx * dx)
+        });
+        let rms_per_100ms = if variance > 0.0 { covariance / variance } else { 0.0 };
+        Self { propagated, rms_per_100ms, compensated_rms, offset_error_ms }
+    }
+    
+    /// RMS of the unskewed run, if the sweep included one.
+    pub fn baseline_rms(&self) -> Option<f64> {
+        self.propagated.iter().find(|s| s.skew_ms == 0.0).map(|s| s.rms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_estimate_converges_on_true_offset() {
+        let mut estimator = ClockOffsetEstimator::new();
+        let offset = 0.3;
+        let velocity = Vector3::new(12.0, -5.0, 0.0);
+        
+        for _ in 0..200 {
+            // An aligned packet trails the truth by v * (offset - estimate)
+            let residual = -velocity * (offset - estimator.offset_secs(1));
+            estimator.observe(1, residual, velocity);
+        }
+        assert!((estimator.offset_secs(1) - offset).abs() < 1e-3, "{}", estimator.offset_secs(1));
+        assert_eq!(estimator.estimate(1).unwrap().samples, 200);
+        
+        // Slow targets and other neighbors are left alone
+        assert_eq!(estimator.observe(2, Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.5, 0.0, 0.0)), None);
+        assert_eq!(estimator.estimate(2), None);
+        assert_eq!(estimator.offset_secs(2), 0.0);
+    }
+    
+    #[test]
+    fn test_skew_slope_fit() {
+        let sweep = [(0.0, 1.0), (100.0, 1.6), (200.0, 2.0), (300.0, 2.6)]
+            .map(|(skew_ms, rms)| SkewSample { skew_ms, rms });
+        let stats = ClockSkewStats::new(sweep.to_vec(), 1.1, 4.0);
+        assert!((stats.rms_per_100ms - 0.52).abs() < 1e-9, "{}", stats.rms_per_100ms);
+        assert_eq!(stats.baseline_rms(), Some(1.0));
+        
+        assert_eq!(ClockSkewStats::new(vec![sweep[0]], 1.0, 0.0).rms_per_100ms, 0.0);
+    }
+}
@@ -3,9 +3,9 @@
 use async_trait::async_trait;
 use ed25519_dalek::SigningKey;
 use godview_env::GodViewContext;
-use rand::SeedableRng;
-use rand_chacha::ChaCha8Rng;
-use std::sync::{Arc, Mutex};
+use crate::executor::Scheduler;
+use crate::rng_audit::AuditedRng;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Simulation context backed by deterministic time and RNG.
@@ -13,7 +13,13 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 /// This implements `GodViewContext` using:
 /// - A virtual clock that can be advanced manually
 /// - A seeded ChaCha8 RNG for deterministic key generation
-/// - Simulated sleep that advances virtual time
+/// - Simulated sleep that advances virtual time, or parks the task on a
+///   virtual timer once a [`SimExecutor`](crate::SimExecutor) is attached
+///
+/// Clones share the virtual clock but each carries its own clock skew, so
+/// agents built from clones of one context can disagree about the time:
+/// [`GodViewContext::now`] is the agent's local clock, `true + offset +
+/// drift_ppm * 1e-6 * true`, while [`Self::time_ns`] stays true time.
 pub struct SimContext {
     /// Master seed for this simulation
     seed: u64,
@@ -22,10 +28,26 @@ pub struct SimContext {
     virtual_time_ns: Arc<Mutex<u64>>,
     
     /// Deterministic RNG for crypto operations
-    rng: Arc<Mutex<ChaCha8Rng>>,
+    rng: Arc<Mutex<AuditedRng>>,
     
     /// Epoch offset (virtual time 0 maps to this wall-clock time)
     epoch: SystemTime,
+    
+    /// This clone's local clock error
+    skew: Mutex<ClockSkew>,
+    
+    /// Executor that `sleep` and `spawn` go through, once one is attached
+    executor: Arc<OnceLock<Arc<Scheduler>>>,
+}
+
+/// Error of a local clock relative to true virtual time.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct ClockSkew {
+    /// Constant offset (s); positive runs ahead
+    offset_secs: f64,
+    
+    /// Rate error in parts per million; positive runs fast
+    drift_ppm: f64,
 }
 
 impl SimContext {
@@ -34,8 +56,10 @@ impl SimContext {
         Self {
             seed,
             virtual_time_ns: Arc::new(Mutex::new(0)),
-            rng: Arc::new(Mutex::new(ChaCha8Rng::seed_from_u64(seed))),
+            rng: Arc::new(Mutex::new(AuditedRng::derive(seed, "context/crypto"))),
             epoch: UNIX_EPOCH + Duration::from_secs(1704067200), // 2024-01-01 00:00:00 UTC
+            skew: Mutex::new(ClockSkew::default()),
+            executor: Arc::new(OnceLock::new()),
         }
     }
     
@@ -44,6 +68,12 @@ impl SimContext {
         Arc::new(Self::new(seed))
     }
     
+    /// Stream `name` of this context's seed (see [`AuditedRng::derive`]),
+    /// e.g. `derive_stream("agent-3/evolution")`.
+    pub fn derive_stream(&self, name: &str) -> AuditedRng {
+        AuditedRng::derive(self.seed, name)
+    }
+    
     /// Advances virtual time by the given duration.
     pub fn advance_time(&self, duration: Duration) {
         let mut time = self.virtual_time_ns.lock().unwrap();
@@ -60,6 +90,33 @@ impl SimContext {
     pub fn time_ns(&self) -> u64 {
         *self.virtual_time_ns.lock().unwrap()
     }
+    
+    /// Makes this clone's local clock run `offset` ahead of true time.
+    pub fn set_clock_offset(&self, offset: Duration) {
+        self.set_clock_offset_secs(offset.as_secs_f64());
+    }
+    
+    /// Sets this clone's local clock offset in seconds (negative runs behind).
+    pub fn set_clock_offset_secs(&self, offset_secs: f64) {
+        self.skew.lock().unwrap().offset_secs = offset_secs;
+    }
+    
+    /// Makes this clone's local clock gain `ppm` microseconds per second of
+    /// true time (negative loses time).
+    pub fn set_drift_ppm(&self, ppm: f64) {
+        self.skew.lock().unwrap().drift_ppm = ppm;
+    }
+    
+    /// Current error of the local clock, offset plus accumulated drift (s).
+    pub fn clock_error_secs(&self) -> f64 {
+        let skew = *self.skew.lock().unwrap();
+        skew.offset_secs + skew.drift_ppm * 1e-6 * self.time_ns() as f64 * 1e-9
+    }
+    
+    /// The scheduler of this context's executor, attaching one if needed.
+    pub(crate) fn attach_executor(&self) -> Arc<Scheduler> {
+        Arc::clone(self.executor.get_or_init(|| Arc::new(Scheduler::new(Arc::clone(&self.virtual_time_ns)))))
+    }
 }
 
 impl Clone for SimContext {
@@ -69,6 +126,8 @@ impl Clone for SimContext {
             virtual_time_ns: Arc::clone(&self.virtual_time_ns),
             rng: Arc::clone(&self.rng),
             epoch: self.epoch,
+            skew: Mutex::new(*self.skew.lock().unwrap()),
+            executor: Arc::clone(&self.executor),
         }
     }
 }
@@ -76,7 +135,13 @@ impl Clone for SimContext {
 #[async_trait]
 impl GodViewContext for SimContext {
     fn now(&self) -> Duration {
-        Duration::from_nanos(*self.virtual_time_ns.lock().unwrap())
+        let true_ns = self.time_ns();
+        let skew = *self.skew.lock().unwrap();
+        if skew == ClockSkew::default() {
+            return Duration::from_nanos(true_ns);
+        }
+        // A clock set behind true time reads zero until true time catches up
+        Duration::from_secs_f64((true_ns as f64 * 1e-9 + self.clock_error_secs()).max(0.0))
     }
     
     fn system_time(&self) -> SystemTime {
@@ -84,27 +149,29 @@ impl GodViewContext for SimContext {
     }
     
     async fn sleep(&self, duration: Duration) {
-        // In simulation, sleep advances virtual time
-        // In a full madsim integration, this would yield to the scheduler
-        self.advance_time(duration);
+        match self.executor.get() {
+            // Under an executor, wait for it to move the clock past the deadline
+            Some(scheduler) => scheduler.sleep_until(self.time_ns() + duration.as_nanos() as u64).await,
+            // Otherwise sleep advances virtual time
+            None => self.advance_time(duration),
+        }
     }
     
     fn spawn<F>(&self, name: &str, future: F)
     where
         F: std::future::Future<Output = ()> + Send + 'static,
     {
-        let _name = name.to_string();
-        // In full madsim, this would use madsim::spawn
-        // For now, use tokio::spawn as a fallback
-        tokio::spawn(async move {
-            future.await;
-        });
+        match self.executor.get() {
+            Some(scheduler) => scheduler.spawn(name, future),
+            // Without an executor, fall back to tokio
+            None => {
+                tokio::spawn(future);
+            }
+        }
     }
     
     fn derive_signing_key(&self, seed_extension: u64) -> SigningKey {
-        // Combine master seed with extension for deterministic key
-        let combined_seed = self.seed.wrapping_mul(0x517cc1b727220a95) ^ seed_extension;
-        let mut key_rng = ChaCha8Rng::seed_from_u64(combined_seed);
+        let mut key_rng = AuditedRng::derive(self.seed, &format!("context/keys/{}", seed_extension));
         SigningKey::generate(&mut key_rng)
     }
     
@@ -161,4 +228,24 @@ mod tests {
         // Both should see the same time
         assert_eq!(ctx1.now(), ctx2.now());
     }
+    
+    #[test]
+    fn test_clock_skew_is_per_clone() {
+        let truth = SimContext::new(42);
+        let skewed = truth.clone();
+        skewed.set_clock_offset(Duration::from_millis(300));
+        skewed.set_drift_ppm(100.0);
+        
+        truth.advance_time(Duration::from_secs(10));
+        assert_eq!(truth.now(), Duration::from_secs(10));
+        assert_eq!(skewed.time_ns(), truth.time_ns());
+        
+        // 300ms offset plus 100ppm of 10s
+        assert!((skewed.clock_error_secs() - 0.301).abs() < 1e-9);
+        assert!((skewed.now().as_secs_f64() - 10.301).abs() < 1e-6);
+        
+        let behind = truth.clone();
+        behind.set_clock_offset_secs(-20.0);
+        assert_eq!(behind.now(), Duration::ZERO);
+    }
 }
@@ -0,0 +1,266 @@
+//! Corpus of failing (scenario, seed) pairs, replayed before fresh seeds.
+//!
+//! The CLI's `--corpus` file is a [`SeedCorpus`]: every failing run is
+//! recorded with its failure reason, its [`FailureKind`] and the crate
+//! version that produced it, and the next invocation runs those pairs first
+//! (optionally only those that failed a given way). Entries are unique per
+//! (scenario, seed) and kept sorted, so the file diffs cleanly.
+
+use crate::failure::FailureKind;
+use crate::runner::ScenarioResult;
+use crate::scenarios::ScenarioId;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+/// Version of this crate, recorded with each new entry.
+pub const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Whether a run came from the corpus or from a fresh seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RunOrigin {
+    /// A seed picked for this invocation
+    #[default]
+    Fresh,
+    
+    /// A previously failing pair replayed from the corpus
+    Corpus,
+}
+
+impl RunOrigin {
+    /// Lower-case label used in reports.
+    pub fn label(&self) -> &'static str {
+        match self {
+            RunOrigin::Fresh => "fresh",
+            RunOrigin::Corpus => "corpus",
+        }
+    }
+}
+
+/// One recorded failure.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CorpusEntry {
+    /// Scenario name (see [`ScenarioId::name`])
+    pub scenario: String,
+    
+    /// Seed the scenario failed with
+    pub seed: u64,
+    
+    /// Failure message of the recorded run
+    #[serde(default)]
+    pub failure_reason: Option<String>,
+    
+    /// How the recorded run failed (absent in entries from older versions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<FailureKind>,
+    
+    /// Crate version that recorded the failure
+    #[serde(default)]
+    pub crate_version: String,
+}
+
+impl CorpusEntry {
+    /// Records a run's scenario, seed and failure reason with this crate's version.
+    pub fn from_result(result: &ScenarioResult) -> Self {
+        Self {
+            scenario: result.scenario.name().to_string(),
+            seed: result.seed,
+            failure_reason: result.failure_reason(),
+            failure: result.failure.clone(),
+            crate_version: CRATE_VERSION.to_string(),
+        }
+    }
+    
+    /// The scenario, if its name is still known.
+    pub fn scenario_id(&self) -> Option<ScenarioId> {
+        self.scenario.parse().ok()
+    }
+}
+
+/// Failing (scenario, seed) pairs, unique and sorted by scenario name then seed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SeedCorpus {
+    entries: Vec<CorpusEntry>,
+}
+
+impl SeedCorpus {
+    /// Creates an empty corpus.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Loads a corpus file, or returns an empty corpus if it doesn't exist yet.
+    ///
+    /// Duplicates and ordering in a hand-edited file are normalized.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let json = match std::fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::new()),
+            Err(e) => return Err(e),
+        };
+        let file: SeedCorpus = serde_json::from_str(&json)?;
+        let mut corpus = Self::new();
+        for entry in file.entries {
+            corpus.insert(entry);
+        }
+        Ok(corpus)
+    }
+    
+    /// Writes the corpus as pretty-printed JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        let mut file = std::fs::File::create(path)?;
+        file.write_all(json.as_bytes())?;
+        file.write_all(b"\n")
+    }
+    
+    /// Adds an entry in sorted position. Returns false (leaving the existing
+    /// entry untouched) if the pair is already recorded.
+    pub fn insert(&mut self, entry: CorpusEntry) -> bool {
+        let key = |e: &CorpusEntry| (e.scenario.clone(), e.seed);
+        match self.entries.binary_search_by_key(&key(&entry), key) {
+            Ok(_) => false,
+            Err(at) => {
+                self.entries.insert(at, entry);
+                true
+            }
+        }
+    }
+    
+    /// Records a failed run. Returns true if its pair was new.
+    pub fn record_failure(&mut self, result: &ScenarioResult) -> bool {
+        !result.passed && self.insert(CorpusEntry::from_result(result))
+    }
+    
+    /// Returns true if the pair is recorded.
+    pub fn contains(&self, scenario: ScenarioId, seed: u64) -> bool {
+        self.entries.iter().any(|e| e.scenario == scenario.name() && e.seed == seed)
+    }
+    
+    /// The recorded failure kind of a pair, if it has one.
+    pub fn recorded_failure(&self, scenario: ScenarioId, seed: u64) -> Option<&FailureKind> {
+        self.entries.iter()
+            .find(|e| e.scenario == scenario.name() && e.seed == seed)
+            .and_then(|e| e.failure.as_ref())
+    }
+    
+    /// All entries, in order.
+    pub fn entries(&self) -> &[CorpusEntry] {
+        &self.entries
+    }
+    
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    /// Returns true if nothing is recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    
+    /// Recorded pairs whose scenario is in `scenarios`, in corpus order.
+    /// Entries naming a scenario that no longer exists are skipped.
+    pub fn pairs_for(&self, scenarios: &[ScenarioId]) -> Vec<(ScenarioId, u64)> {
+        self.entries.iter()
+            .filter_map(|e| Some((e.scenario_id()?, e.seed)))
+            .filter(|(scenario, _)| scenarios.contains(scenario))
+            .collect()
+    }
+    
+    /// Like [`Self::pairs_for`], but only entries whose recorded failure has
+    /// one of `labels` (see [`FailureKind::label`]). Entries without a
+    /// recorded kind never match.
+    pub fn pairs_of_kind(&self, scenarios: &[ScenarioId], labels: &[&str]) -> Vec<(ScenarioId, u64)> {
+        self.entries.iter()
+            .filter(|e| e.failure.as_ref().is_some_and(|f| labels.contains(&f.label())))
+            .filter_map(|e| Some((e.scenario_id()?, e.seed)))
+            .filter(|(scenario, _)| scenarios.contains(scenario))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn entry(scenario: &str, seed: u64) -> CorpusEntry {
+        CorpusEntry {
+            scenario: scenario.to_string(),
+            seed,
+            failure_reason: Some(format!("{scenario} failed")),
+            failure: Some(FailureKind::expectation("test", format!("{scenario} failed"))),
+            crate_version: "0.0.1".to_string(),
+        }
+    }
+    
+    #[test]
+    fn test_insert_dedups_and_keeps_entries_sorted() {
+        let mut corpus = SeedCorpus::new();
+        assert!(corpus.insert(entry("swarm", 9)));
+        assert!(corpus.insert(entry("byzantine", 3)));
+        assert!(corpus.insert(entry("swarm", 2)));
+        
+        let mut again = entry("swarm", 9);
+        again.failure_reason = None;
+        assert!(!corpus.insert(again));
+        assert_eq!(corpus.entries()[2].failure_reason.as_deref(), Some("swarm failed"));
+        
+        let order: Vec<(&str, u64)> = corpus.entries().iter().map(|e| (e.scenario.as_str(), e.seed)).collect();
+        assert_eq!(order, vec![("byzantine", 3), ("swarm", 2), ("swarm", 9)]);
+        assert!(corpus.contains(ScenarioId::Swarm, 2));
+        assert!(!corpus.contains(ScenarioId::Swarm, 3));
+    }
+    
+    #[test]
+    fn test_round_trip_normalizes_and_filters_pairs() {
+        let path = std::env::temp_dir().join(format!("godview-corpus-{}.json", std::process::id()));
+        assert!(SeedCorpus::load(&path).unwrap().is_empty(), "missing file is an empty corpus");
+        
+        // A hand-edited file with a duplicate, bad ordering and a retired scenario
+        std::fs::write(&path, r#"{"entries": [
+            {"scenario": "time_warp", "seed": 5},
+            {"scenario": "retired_scenario", "seed": 1},
+            {"scenario": "byzantine", "seed": 8, "failure_reason": "x", "crate_version": "0.1.0"},
+            {"scenario": "time_warp", "seed": 5}
+        ]}"#).unwrap();
+        let corpus = SeedCorpus::load(&path).unwrap();
+        assert_eq!(corpus.len(), 3);
+        assert_eq!(
+            corpus.pairs_for(&ScenarioId::all()),
+            vec![(ScenarioId::Byzantine, 8), (ScenarioId::TimeWarp, 5)],
+        );
+        assert_eq!(corpus.pairs_for(&[ScenarioId::TimeWarp]), vec![(ScenarioId::TimeWarp, 5)]);
+        
+        corpus.save(&path).unwrap();
+        assert_eq!(SeedCorpus::load(&path).unwrap(), corpus);
+        std::fs::remove_file(&path).unwrap();
+    }
+    
+    #[test]
+    fn test_pairs_of_kind_match_recorded_failures() {
+        let mut corpus = SeedCorpus::new();
+        let mut rms = entry("time_warp", 1);
+        rms.failure = Some(FailureKind::RmsExceeded { mean: 6.0, p95: 9.0, threshold: 5.0 });
+        let mut untagged = entry("time_warp", 2);
+        untagged.failure = None;
+        corpus.insert(rms);
+        corpus.insert(untagged);
+        corpus.insert(entry("swarm", 3));
+        
+        let all = ScenarioId::all();
+        assert_eq!(corpus.pairs_of_kind(&all, &["rms_exceeded"]), vec![(ScenarioId::TimeWarp, 1)]);
+        assert_eq!(
+            corpus.pairs_of_kind(&all, &["rms_exceeded", "expectation_failed"]),
+            vec![(ScenarioId::Swarm, 3), (ScenarioId::TimeWarp, 1)],
+        );
+        assert!(corpus.pairs_of_kind(&[ScenarioId::Byzantine], &["rms_exceeded"]).is_empty());
+        assert_eq!(corpus.recorded_failure(ScenarioId::TimeWarp, 1).map(FailureKind::label), Some("rms_exceeded"));
+        assert_eq!(corpus.recorded_failure(ScenarioId::TimeWarp, 2), None);
+        
+        // The kind survives a save, tagged by its label
+        let json = serde_json::to_string(&corpus).unwrap();
+        assert!(json.contains(r#""kind":"rms_exceeded""#));
+        assert_eq!(serde_json::from_str::<SeedCorpus>(&json).unwrap(), corpus);
+    }
+}
@@ -0,0 +1,477 @@
+//! Interactive single-step debugging of a scenario's world.
+//!
+//! A [`DebugRepl`] drives a [`Simulation`] one command per line: step it,
+//! list an agent's tracks or the oracle's entities, partition the network,
+//! turn an agent into a bad actor, or run until a condition holds.
+//!
+//! Inspection commands only read the world, so a session steps through the
+//! same ticks an uninterrupted run of the seed would. Injections change it,
+//! and each is recorded at the tick it was made before; `save-script` writes
+//! them as an [`EventScript`] that replays the session through
+//! [`Simulation::with_event_script`] (or `--event-script` on the CLI).
+
+use crate::agent::SimulatedAgent;
+use crate::capture::snapshot_frame;
+use crate::exporter::SimExport;
+use crate::scenarios::ScenarioId;
+use crate::simulation::{EventScript, SimEventRequest, Simulation};
+use crate::stats::{every_agent, swarm_convergence, ConvergenceStats};
+use std::io::{BufRead, Write};
+use uuid::Uuid;
+
+/// Commands understood by the REPL, as printed by `help`.
+pub const REPL_HELP: &str = "\
+commands:
+  step [n]                     advance n ticks (default 1)
+  continue-until <cond>        step until <cond> holds or the run's duration ends;
+                               <cond> is rms|tracks|tick|time then >, >=, < or <= then a number
+  status                       tick, time, RMS, tracks and injected events
+  agent <i> tracks             agent i's confirmed and coasting tracks
+  agent <i> track <uuid>       one track's state and covariance (merged IDs resolve)
+  oracle entities              ground-truth entities
+  partition a=<ids> b=<ids>    block gossip between two groups (e.g. a=0-4 b=5,6,8-9)
+  heal                         remove every partition
+  inject-bad <i>               agent i adds garbage to its gossip
+  export-frame <file>          write the current frame as a one-frame export
+  save-script <file>           write the injections made so far as an event script
+  help                         this list
+  quit                         leave (also at end of input)";
+
+/// Quantity a `continue-until` condition tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplMetric {
+    /// Mean position RMS over running agents (m)
+    Rms,
+    
+    /// Tracks summed over running agents
+    Tracks,
+    
+    /// Ticks stepped
+    Tick,
+    
+    /// Simulated time (s)
+    Time,
+}
+
+/// Comparison in a `continue-until` condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Above,
+    AtLeast,
+    Below,
+    AtMost,
+}
+
+/// A `continue-until` stopping condition, e.g. `rms>5`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StopCondition {
+    pub metric: ReplMetric,
+    pub comparison: Comparison,
+    pub value: f64,
+}
+
+impl StopCondition {
+    /// Returns true if `observed` satisfies the condition.
+    pub fn holds(&self, observed: f64) -> bool {
+        match self.comparison {
+            Comparison::Above => observed > self.value,
+            Comparison::AtLeast => observed >= self.value,
+            Comparison::Below => observed < self.value,
+            Comparison::AtMost => observed <= self.value,
+        }
+    }
+}
+
+impl std::str::FromStr for StopCondition {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: String = s.split_whitespace().collect();
+        // Two-character operators first, so ">=" isn't read as ">"
+        let (at, comparison, len) = [(">=", Comparison::AtLeast), ("<=", Comparison::AtMost), (">", Comparison::Above), ("<", Comparison::Below)]
+            .into_iter()
+            .find_map(|(op, comparison)| s.find(op).map(|at| (at, comparison, op.len())))
+            .ok_or_else(|| format!("condition needs >, >=, < or <=: {s}"))?;
+        let metric = match &s[..at] {
+            "rms" => ReplMetric::Rms,
+            "tracks" => ReplMetric::Tracks,
+            "tick" => ReplMetric::Tick,
+            "time" => ReplMetric::Time,
+            other => return Err(format!("unknown quantity '{other}' (rms, tracks, tick, time)")),
+        };
+        let value = s[at + len..].parse::<f64>().map_err(|e| format!("bad threshold '{}': {e}", &s[at + len..]))?;
+        Ok(Self { metric, comparison, value })
+    }
+}
+
+/// One parsed REPL line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplCommand {
+    Step(u64),
+    ContinueUntil(StopCondition),
+    Status,
+    AgentTracks(usize),
+    AgentTrack(usize, Uuid),
+    OracleEntities,
+    Partition { group_a: Vec<usize>, group_b: Vec<usize> },
+    Heal,
+    InjectBad(usize),
+    ExportFrame(String),
+    SaveScript(String),
+    Help,
+    Quit,
+}
+
+impl ReplCommand {
+    /// Parses one line; None for a blank one.
+    pub fn parse(line: &str) -> Result<Option<Self>, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let command = match words.as_slice() {
+            [] => return Ok(None),
+            ["step"] => ReplCommand::Step(1),
+            ["step", n] => ReplCommand::Step(n.parse().map_err(|e| format!("bad tick count '{n}': {e}"))?),
+            ["continue-until", condition @ ..] if !condition.is_empty() => {
+                ReplCommand::ContinueUntil(condition.concat().parse()?)
+            }
+            ["status"] => ReplCommand::Status,
+            ["agent", i, "tracks"] => ReplCommand::AgentTracks(parse_index(i)?),
+            ["agent", i, "track", id] => {
+                ReplCommand::AgentTrack(parse_index(i)?, id.parse().map_err(|e| format!("bad track ID '{id}': {e}"))?)
+            }
+            ["oracle", "entities"] => ReplCommand::OracleEntities,
+            ["partition", a, b] => ReplCommand::Partition { group_a: parse_group(a, "a")?, group_b: parse_group(b, "b")? },
+            ["heal"] => ReplCommand::Heal,
+            ["inject-bad", i] => ReplCommand::InjectBad(parse_index(i)?),
+            ["export-frame", path] => ReplCommand::ExportFrame(path.to_string()),
+            ["save-script", path] => ReplCommand::SaveScript(path.to_string()),
+            ["help"] => ReplCommand::Help,
+            ["quit"] | ["exit"] => ReplCommand::Quit,
+            _ => return Err(format!("unknown command '{}' (try help)", line.trim())),
+        };
+        Ok(Some(command))
+    }
+}
+
+fn parse_index(s: &str) -> Result<usize, String> {
+    s.parse().map_err(|e| format!("bad agent index '{s}': {e}"))
+}
+
+/// Parses `name=<ids>`, where ids are comma-separated indices and
+/// inclusive ranges (`0-4,7`).
+fn parse_group(s: &str, name: &str) -> Result<Vec<usize>, String> {
+    let ids = s.strip_prefix(name).and_then(|rest| rest.strip_prefix('='))
+        .ok_or_else(|| format!("expected {name}=<ids>, got '{s}'"))?;
+    let mut group = Vec::new();
+    for part in ids.split(',') {
+        match part.split_once('-') {
+            Some((lo, hi)) => {
+                let (lo, hi) = (parse_index(lo)?, parse_index(hi)?);
+                if lo > hi {
+                    return Err(format!("empty range '{part}'"));
+                }
+                group.extend(lo..=hi);
+            }
+            None => group.push(parse_index(part)?),
+        }
+    }
+    Ok(group)
+}
+
+/// A simulation driven from the command line.
+pub struct DebugRepl {
+    sim: Simulation,
+    scenario: ScenarioId,
+    
+    /// Injections, loaded and made, at the tick they apply before
+    script: EventScript,
+}
+
+impl DebugRepl {
+    /// Wraps `sim`, a world of `scenario` (see
+    /// [`ScenarioRunner::world`](crate::ScenarioRunner::world)).
+    pub fn new(sim: Simulation, scenario: ScenarioId) -> Self {
+        Self { sim, scenario, script: EventScript::new() }
+    }
+    
+    /// Replays `script` as the world steps, and keeps its events at the
+    /// front of the script `save-script` writes.
+    pub fn with_event_script(mut self, script: EventScript) -> Self {
+        self.sim = self.sim.with_event_script(script.clone());
+        self.script = script;
+        self
+    }
+    
+    /// The simulation being debugged.
+    pub fn simulation(&self) -> &Simulation {
+        &self.sim
+    }
+    
+    /// Injections so far, loaded and made.
+    pub fn script(&self) -> &EventScript {
+        &self.script
+    }
+    
+    /// Convergence of the running agents against ground truth.
+    fn convergence(&self) -> ConvergenceStats {
+        swarm_convergence(self.sim.live_agents(), &self.sim.oracle().ground_truth_positions(), every_agent)
+    }
+    
+    fn observe(&self, metric: ReplMetric) -> f64 {
+        match metric {
+            ReplMetric::Rms => self.convergence().rms_mean,
+            ReplMetric::Tracks => self.convergence().per_agent.iter().map(|a| a.tracks).sum::<usize>() as f64,
+            ReplMetric::Tick => self.sim.tick_count() as f64,
+            ReplMetric::Time => self.sim.time(),
+        }
+    }
+    
+    fn agent(&self, index: usize) -> Result<&SimulatedAgent, String> {
+        let count = self.sim.agents().len();
+        self.sim.agents().get(index).ok_or_else(|| format!("no agent {index} (the world has {count})"))
+    }
+    
+    /// Applies `event` now and records it against the next tick.
+    fn inject(&mut self, event: SimEventRequest) {
+        self.script.push(self.sim.tick_count(), event.clone());
+        self.sim.inject_event(event);
+    }
+    
+    /// Reads commands from `input` until `quit` or its end, writing
+    /// results and errors to `output`.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> std::io::Result<()> {
+        writeln!(output, "{} (seed={}): {} agents, {} entities, {} ticks; type help for commands",
+            self.scenario.name(), self.sim.config().seed, self.sim.agents().len(),
+            self.sim.oracle().active_entities().len(), self.sim.duration_ticks())?;
+        write!(output, "[{}]> ", self.sim.tick_count())?;
+        output.flush()?;
+        for line in input.lines() {
+            match ReplCommand::parse(&line?) {
+                Ok(Some(ReplCommand::Quit)) => return Ok(()),
+                Ok(Some(command)) => {
+                    if let Err(e) = self.execute(command, &mut output)? {
+                        writeln!(output, "error: {e}")?;
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => writeln!(output, "error: {e}")?,
+            }
+            write!(output, "[{}]> ", self.sim.tick_count())?;
+            output.flush()?;
+        }
+        writeln!(output)
+    }
+    
+    /// Runs one command. The outer error is from writing; the inner one is
+    /// the command's, for the REPL to print.
+    pub fn execute(&mut self, command: ReplCommand, out: &mut impl Write) -> std::io::Result<Result<(), String>> {
+        match command {
+            ReplCommand::Step(n) => {
+                for _ in 0..n {
+                    self.sim.step();
+                }
+                self.write_status_line(out)?;
+            }
+            ReplCommand::ContinueUntil(condition) => {
+                let end = self.sim.duration_ticks();
+                let mut held = condition.holds(self.observe(condition.metric));
+                while !held && self.sim.tick_count() < end {
+                    self.sim.step();
+                    held = condition.holds(self.observe(condition.metric));
+                }
+                if !held {
+                    writeln!(out, "condition never held before the end of the run (tick {end})")?;
+                }
+                self.write_status_line(out)?;
+            }
+            ReplCommand::Status => {
+                self.write_status_line(out)?;
+                let bad: Vec<String> = self.sim.bad_actors().iter().map(|i| i.to_string()).collect();
+                writeln!(out, "  bad actors: {}", if bad.is_empty() { "none".to_string() } else { bad.join(",") })?;
+                writeln!(out, "  injected events: {}", self.script.events().len())?;
+            }
+            ReplCommand::AgentTracks(index) => {
+                let agent = match self.agent(index) {
+                    Ok(agent) => agent,
+                    Err(e) => return Ok(Err(e)),
+                };
+                let mut tracks = agent.ghost_scored_tracks(self.sim.agents().len());
+                tracks.sort_by_key(|(track, _)| track.canonical_id);
+                writeln!(out, "{:<36}  {:<10} {:>10} {:>10} {:>10} {:>10} {:>6}", "TRACK", "STATE", "X", "Y", "Z", "COV_TRACE", "GHOST")?;
+                for (track, ghost) in &tracks {
+                    let p = track.position();
+                    writeln!(out, "{:<36}  {:<10} {:>10.2} {:>10.2} {:>10.2} {:>10.3} {:>6.2}", track.canonical_id,
+                        format!("{:?}", track.track_state), p.x, p.y, p.z, track.position_covariance().trace(), ghost)?;
+                }
+                writeln!(out, "{} tracks ({} total, tentative included)", tracks.len(), agent.track_count())?;
+            }
+            ReplCommand::AgentTrack(index, id) => {
+                let track = match self.agent(index).and_then(|a| a.track(&id).ok_or_else(|| format!("agent {index} has no track {id}"))) {
+                    Ok(track) => track,
+                    Err(e) => return Ok(Err(e)),
+                };
+                let (p, v) = (track.position(), track.velocity());
+                writeln!(out, "track {} ({:?}, class {})", track.canonical_id, track.track_state, track.class_id)?;
+                if track.canonical_id != id {
+                    writeln!(out, "  {id} was merged into it")?;
+                }
+                writeln!(out, "  position  {:>10.3} {:>10.3} {:>10.3}", p.x, p.y, p.z)?;
+                writeln!(out, "  velocity  {:>10.3} {:>10.3} {:>10.3}", v.x, v.y, v.z)?;
+                writeln!(out, "  age {} cycles, {} hits over {}, last update t={:.3}s, {} merged IDs",
+                    track.age, track.hits, track.lifetime, track.last_update, track.observed_ids.len())?;
+                writeln!(out, "  position covariance (m²):")?;
+                let covariance = track.position_covariance();
+                for row in covariance.row_iter() {
+                    writeln!(out, "    {:>12.5} {:>12.5} {:>12.5}", row[0], row[1], row[2])?;
+                }
+            }
+            ReplCommand::OracleEntities => {
+                let mut entities = self.sim.oracle().active_entities();
+                entities.sort_by_key(|e| e.id);
+                writeln!(out, "{:>6}  {:<10} {:>10} {:>10} {:>10} {:>8} {:>8} {:>8}", "ID", "CLASS", "X", "Y", "Z", "VX", "VY", "VZ")?;
+                for e in &entities {
+                    writeln!(out, "{:>6}  {:<10} {:>10.2} {:>10.2} {:>10.2} {:>8.2} {:>8.2} {:>8.2}", e.id, e.class,
+                        e.position.x, e.position.y, e.position.z, e.velocity.x, e.velocity.y, e.velocity.z)?;
+                }
+                writeln!(out, "{} entities", entities.len())?;
+            }
+            ReplCommand::Partition { group_a, group_b } => {
+                if let Some(&bad) = group_a.iter().chain(&group_b).find(|&&i| i >= self.sim.agents().len()) {
+                    return Ok(Err(format!("no agent {bad} (the world has {})", self.sim.agents().len())));
+                }
+                writeln!(out, "partitioned {:?} from {:?} before tick {}", group_a, group_b, self.sim.tick_count())?;
+                self.inject(SimEventRequest::Partition { group_a, group_b });
+            }
+            ReplCommand::Heal => {
+                self.inject(SimEventRequest::Heal);
+                writeln!(out, "healed before tick {}", self.sim.tick_count())?;
+            }
+            ReplCommand::InjectBad(agent) => {
+                if let Err(e) = self.agent(agent) {
+                    return Ok(Err(e));
+                }
+                self.inject(SimEventRequest::BadActor { agent });
+                writeln!(out, "agent {agent} is a bad actor from tick {}", self.sim.tick_count())?;
+            }
+            ReplCommand::ExportFrame(path) => {
+                let mut export = SimExport::new(self.scenario.name(), self.sim.config().seed);
+                let tick = self.sim.tick_count().saturating_sub(1);
+                export.add_frame(snapshot_frame(tick, self.sim.oracle(), self.sim.agents(), true));
+                export.finalize(false, Some(self.convergence().rms_mean));
+                if let Err(e) = export.write_to_file(&path) {
+                    return Ok(Err(format!("failed to write {path}: {e}")));
+                }
+                writeln!(out, "wrote tick {tick} to {path}")?;
+            }
+            ReplCommand::SaveScript(path) => {
+                if let Err(e) = self.script.save(&path) {
+                    return Ok(Err(format!("failed to write {path}: {e}")));
+                }
+                writeln!(out, "wrote {} events to {path}; replay with --seed {} --scenario {} --debug-repl --event-script {path}",
+                    self.script.events().len(), self.sim.config().seed, self.scenario.name())?;
+            }
+            ReplCommand::Help => writeln!(out, "{REPL_HELP}")?,
+            ReplCommand::Quit => {}
+        }
+        Ok(Ok(()))
+    }
+    
+    /// `tick N  t=S  rms=M  tracks=T`
+    fn write_status_line(&self, out: &mut impl Write) -> std::io::Result<()> {
+        let convergence = self.convergence();
+        let tracks: usize = convergence.per_agent.iter().map(|a| a.tracks).sum();
+        writeln!(out, "tick {}/{}  t={:.2}s  rms={:.3}m  tracks={}", self.sim.tick_count(), self.sim.duration_ticks(),
+            self.sim.time(), convergence.rms_mean, tracks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::ScenarioRunner;
+    use crate::swarm_network::SwarmConfig;
+    
+    fn small_swarm() -> Simulation {
+        let config = SwarmConfig { rows: 2, cols: 3, num_entities: 10, ..SwarmConfig::default() };
+        ScenarioRunner::new(7, 6).with_swarm_config(config).with_duration(2.0).world(ScenarioId::Swarm).unwrap()
+    }
+    
+    fn run(repl: &mut DebugRepl, session: &str) -> String {
+        let mut output = Vec::new();
+        repl.run(session.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+    
+    fn positions(sim: &Simulation) -> Vec<Vec<(Uuid, [f64; 3])>> {
+        sim.agents().iter()
+            .map(|a| {
+                let mut tracks: Vec<_> = a.track_positions().into_iter().map(|(id, p)| (id, [p.x, p.y, p.z])).collect();
+                tracks.sort_by_key(|(id, _)| *id);
+                tracks
+            })
+            .collect()
+    }
+    
+    #[test]
+    fn test_commands_parse() {
+        assert_eq!(ReplCommand::parse("  "), Ok(None));
+        assert_eq!(ReplCommand::parse("step"), Ok(Some(ReplCommand::Step(1))));
+        assert_eq!(ReplCommand::parse("step 30"), Ok(Some(ReplCommand::Step(30))));
+        assert_eq!(ReplCommand::parse("partition a=0-2,5 b=3,4"), Ok(Some(ReplCommand::Partition {
+            group_a: vec![0, 1, 2, 5],
+            group_b: vec![3, 4],
+        })));
+        let until = ReplCommand::parse("continue-until rms >= 5").unwrap().unwrap();
+        let ReplCommand::ContinueUntil(condition) = until else { panic!("{until:?}") };
+        assert_eq!((condition.metric, condition.comparison, condition.value), (ReplMetric::Rms, Comparison::AtLeast, 5.0));
+        assert!(condition.holds(5.0) && !condition.holds(4.9));
+        
+        for bad in ["step x", "agent 0 track nope", "partition b=0 a=1", "partition a=3-1 b=0", "continue-until rms", "continue-until speed>1", "fly"] {
+            assert!(ReplCommand::parse(bad).is_err(), "{bad}");
+        }
+    }
+    
+    #[test]
+    fn test_inspection_does_not_perturb_the_run() {
+        let mut plain = small_swarm();
+        for _ in 0..20 {
+            plain.step();
+        }
+        
+        let export = std::env::temp_dir().join(format!("godview-repl-frame-{}.json", std::process::id()));
+        let mut repl = DebugRepl::new(small_swarm(), ScenarioId::Swarm);
+        let output = run(&mut repl, &format!(
+            "step 10\nagent 0 tracks\noracle entities\nstatus\nexport-frame {}\nagent 9 tracks\ncontinue-until tick>=20\nquit\nstep 5\n",
+            export.display(),
+        ));
+        assert_eq!(repl.simulation().tick_count(), 20, "{output}");
+        assert_eq!(positions(repl.simulation()), positions(&plain));
+        assert!(output.contains("10 entities") && output.contains("error: no agent 9"), "{output}");
+        
+        let frame = SimExport::load_from_file(export.to_str().unwrap()).unwrap();
+        assert_eq!(frame.frames.len(), 1);
+        assert_eq!(frame.frames[0].tick, Some(9));
+        std::fs::remove_file(export).unwrap();
+    }
+    
+    #[test]
+    fn test_injections_replay_as_an_event_script() {
+        let mut repl = DebugRepl::new(small_swarm(), ScenarioId::Swarm);
+        run(&mut repl, "step 5\npartition a=0-2 b=3-5\nstep 10\ninject-bad 4\nheal\nstep 10\n");
+        assert_eq!(repl.script().events().len(), 3);
+        assert_eq!(repl.script().events()[0].at_tick, 5);
+        assert_eq!(repl.script().events()[2].at_tick, 15);
+        
+        let path = std::env::temp_dir().join(format!("godview-repl-script-{}.json", std::process::id()));
+        repl.script().save(&path).unwrap();
+        let script = EventScript::load(&path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(&script, repl.script());
+        
+        let mut replay = small_swarm().with_event_script(script);
+        for _ in 0..25 {
+            replay.step();
+        }
+        assert_eq!(replay.bad_actors(), repl.simulation().bad_actors());
+        assert_eq!(positions(&replay), positions(repl.simulation()));
+    }
+}
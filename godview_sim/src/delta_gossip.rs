@@ -0,0 +1,402 @@
+//! Delta-encoded gossip.
+//!
+//! With compression on, each directed link keeps a baseline per track: the
+//! last full packet the sender sent of it. The first broadcast of a track in
+//! an epoch ([`DeltaCompression::epoch_secs`]) goes out in full and becomes
+//! the baseline; later ones carry only the entity ID, the time since the
+//! baseline and the position and velocity offsets from it, quantized to
+//! [`DeltaCompression`]'s precision ([`PacketDelta`]). The receiver rebuilds
+//! the packet from its own copy of the baseline.
+//!
+//! Offsets are always taken from the baseline, never from the previous
+//! delta, so quantization error doesn't accumulate: every rebuilt component
+//! is within half a quantum of the one sent. A change to anything a delta
+//! doesn't carry (class, confidence, priority, measurement model) forces a
+//! full packet instead.
+//!
+//! Each full packet is numbered per track ([`GossipFrame::Full`]), and
+//! deltas name the baseline they were taken from. A receiver that lost the
+//! full packet, or holds an older one, can't rebuild the delta: it drops it
+//! and asks for a full packet, which the sender sends on its next broadcast
+//! of the track.
+
+use crate::swarm_network::wire_bytes;
+use godview_core::godview_tracking::GlobalHazardPacket;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Precision of delta-encoded gossip and how often baselines are refreshed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct DeltaCompression {
+    /// Position offsets are sent in multiples of this (m in the
+    /// simulation's local frame)
+    pub position_quantum_m: f64,
+    
+    /// Velocity offsets are sent in multiples of this (m/s)
+    pub velocity_quantum_mps: f64,
+    
+    /// Time since the baseline is sent in multiples of this (s)
+    pub time_quantum_secs: f64,
+    
+    /// A track's baseline is replaced by a full packet once it is this old
+    /// (s of packet time)
+    pub epoch_secs: f64,
+}
+
+impl DeltaCompression {
+    /// Largest error a rebuilt position component can have (m).
+    pub fn position_bound(&self) -> f64 {
+        self.position_quantum_m / 2.0
+    }
+    
+    /// Largest error a rebuilt velocity component can have (m/s).
+    pub fn velocity_bound(&self) -> f64 {
+        self.velocity_quantum_mps / 2.0
+    }
+}
+
+impl Default for DeltaCompression {
+    fn default() -> Self {
+        Self {
+            position_quantum_m: 0.01,
+            velocity_quantum_mps: 0.01,
+            time_quantum_secs: 0.001,
+            epoch_secs: 2.0,
+        }
+    }
+}
+
+/// A packet sent as quantized offsets from the receiver's baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PacketDelta {
+    /// Track the delta belongs to
+    #[serde(rename = "e")]
+    pub entity_id: Uuid,
+    
+    /// Number of the full packet the offsets are taken from
+    #[serde(rename = "g")]
+    pub generation: u32,
+    
+    /// Time since the baseline, in time quanta
+    #[serde(rename = "t")]
+    pub dt: i32,
+    
+    /// Position offset, in position quanta
+    #[serde(rename = "p")]
+    pub position: [i32; 3],
+    
+    /// Velocity offset, in velocity quanta
+    #[serde(rename = "v")]
+    pub velocity: [i32; 3],
+}
+
+/// What a gossiped packet travels as on a compressed link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GossipFrame {
+    /// The whole packet, which becomes the track's baseline
+    #[serde(rename = "f")]
+    Full {
+        #[serde(rename = "g")]
+        generation: u32,
+        
+        #[serde(rename = "p")]
+        packet: GlobalHazardPacket,
+    },
+    
+    /// Offsets from the baseline
+    #[serde(rename = "d")]
+    Delta(PacketDelta),
+}
+
+impl GossipFrame {
+    /// The track the frame is about.
+    pub fn entity_id(&self) -> Uuid {
+        match self {
+            GossipFrame::Full { packet, .. } => packet.entity_id,
+            GossipFrame::Delta(delta) => delta.entity_id,
+        }
+    }
+    
+    /// Serialized size of the frame (JSON, like [`wire_bytes`]).
+    pub fn wire_bytes(&self) -> u64 {
+        serde_json::to_vec(self).map_or(0, |bytes| bytes.len() as u64)
+    }
+}
+
+/// Frames sent over compressed links and what they saved, for one link or
+/// summed over several.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct CompressionStats {
+    /// Packets sent in full, including any the link then dropped
+    pub full_frames: u64,
+    
+    /// Packets sent as deltas
+    pub delta_frames: u64,
+    
+    /// Deltas dropped because the receiver lacked their baseline; each
+    /// asked the sender for a full packet
+    pub full_requests: u64,
+    
+    /// Wire bytes the packets would have cost uncompressed ([`wire_bytes`])
+    pub raw_bytes: u64,
+    
+    /// Wire bytes of the frames actually sent
+    pub frame_bytes: u64,
+}
+
+impl CompressionStats {
+    /// Uncompressed bytes per byte sent (1.0 before anything was sent).
+    pub fn ratio(&self) -> f64 {
+        if self.frame_bytes == 0 {
+            1.0
+        } else {
+            self.raw_bytes as f64 / self.frame_bytes as f64
+        }
+    }
+    
+    /// Adds `other`'s counters to these.
+    pub fn merge(&mut self, other: &CompressionStats) {
+        self.full_frames += other.full_frames;
+        self.delta_frames += other.delta_frames;
+        self.full_requests += other.full_requests;
+        self.raw_bytes += other.raw_bytes;
+        self.frame_bytes += other.frame_bytes;
+    }
+}
+
+/// A track's baseline: the number and contents of its last full packet.
+type Baseline = (u32, GlobalHazardPacket);
+
+/// Both ends of one directed link's delta compression: the sender's
+/// baselines and the receiver's copies of them.
+#[derive(Debug, Clone)]
+pub struct DeltaLink {
+    config: DeltaCompression,
+    sent: HashMap<Uuid, Baseline>,
+    received: HashMap<Uuid, Baseline>,
+    next_generation: HashMap<Uuid, u32>,
+    stats: CompressionStats,
+}
+
+impl DeltaLink {
+    /// A link with no baselines yet.
+    pub fn new(config: DeltaCompression) -> Self {
+        Self {
+            config,
+            sent: HashMap::new(),
+            received: HashMap::new(),
+            next_generation: HashMap::new(),
+            stats: CompressionStats::default(),
+        }
+    }
+    
+    /// Encodes `packet` on the sending side: as a delta from its track's
+    /// baseline when one is current and the offsets fit, else in full as the
+    /// new baseline.
+    pub fn send(&mut self, packet: &GlobalHazardPacket) -> GossipFrame {
+        let delta = self.sent.get(&packet.entity_id)
+            .and_then(|(generation, baseline)| self.delta_from(*generation, baseline, packet));
+        let frame = match delta {
+            Some(delta) => {
+                self.stats.delta_frames += 1;
+                GossipFrame::Delta(delta)
+            }
+            None => {
+                let next = self.next_generation.entry(packet.entity_id).or_default();
+                let generation = *next;
+                *next = next.wrapping_add(1);
+                self.sent.insert(packet.entity_id, (generation, packet.clone()));
+                self.stats.full_frames += 1;
+                GossipFrame::Full { generation, packet: packet.clone() }
+            }
+        };
+        self.stats.raw_bytes += wire_bytes(packet);
+        self.stats.frame_bytes += frame.wire_bytes();
+        frame
+    }
+    
+    /// Decodes `frame` on the receiving side. A delta without its baseline
+    /// is dropped and the sender's baseline forgotten, so its next broadcast
+    /// of the track goes out in full.
+    pub fn receive(&mut self, frame: GossipFrame) -> Option<GlobalHazardPacket> {
+        match frame {
+            GossipFrame::Full { generation, packet } => {
+                self.received.insert(packet.entity_id, (generation, packet.clone()));
+                Some(packet)
+            }
+            GossipFrame::Delta(delta) => {
+                match self.received.get(&delta.entity_id) {
+                    Some((generation, baseline)) if *generation == delta.generation => {
+                        Some(self.apply(baseline, &delta))
+                    }
+                    _ => {
+                        self.stats.full_requests += 1;
+                        self.sent.remove(&delta.entity_id);
+                        None
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Returns the link's counters.
+    pub fn stats(&self) -> CompressionStats {
+        self.stats
+    }
+    
+    /// Offsets of `packet` from `baseline`, or None if they can't express it.
+    fn delta_from(&self, generation: u32, baseline: &GlobalHazardPacket, packet: &GlobalHazardPacket) -> Option<PacketDelta> {
+        let config = &self.config;
+        let age = packet.timestamp - baseline.timestamp;
+        if !(0.0..config.epoch_secs).contains(&age)
+            || packet.class_id != baseline.class_id
+            || packet.confidence_score != baseline.confidence_score
+            || packet.priority != baseline.priority
+            || packet.measurement_model != baseline.measurement_model
+        {
+            return None;
+        }
+        
+        let offsets = |now: [f64; 3], then: [f64; 3], quantum: f64| -> Option<[i32; 3]> {
+            let mut out = [0; 3];
+            for i in 0..3 {
+                out[i] = quantize(now[i] - then[i], quantum)?;
+            }
+            Some(out)
+        };
+        Some(PacketDelta {
+            entity_id: packet.entity_id,
+            generation,
+            dt: quantize(age, config.time_quantum_secs)?,
+            position: offsets(packet.position, baseline.position, config.position_quantum_m)?,
+            velocity: offsets(packet.velocity, baseline.velocity, config.velocity_quantum_mps)?,
+        })
+    }
+    
+    /// `baseline` moved by `delta`.
+    fn apply(&self, baseline: &GlobalHazardPacket, delta: &PacketDelta) -> GlobalHazardPacket {
+        let config = &self.config;
+        let shift = |then: [f64; 3], steps: [i32; 3], quantum: f64| {
+            std::array::from_fn(|i| then[i] + steps[i] as f64 * quantum)
+        };
+        GlobalHazardPacket {
+            position: shift(baseline.position, delta.position, config.position_quantum_m),
+            velocity: shift(baseline.velocity, delta.velocity, config.velocity_quantum_mps),
+            timestamp: baseline.timestamp + delta.dt as f64 * config.time_quantum_secs,
+            ..baseline.clone()
+        }
+    }
+}
+
+/// `value` in whole multiples of `quantum`, or None if it doesn't fit an i32
+/// (or isn't finite).
+fn quantize(value: f64, quantum: f64) -> Option<i32> {
+    let steps = (value / quantum).round();
+    (steps.abs() <= i32::MAX as f64).then_some(steps as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::Oracle;
+    use godview_core::godview_tracking::{MeasurementModel, TrackManager, TrackingConfig};
+    
+    /// Entity 7 at `t`, moving along a curve so every offset is non-trivial.
+    fn packet_at(t: f64) -> GlobalHazardPacket {
+        GlobalHazardPacket {
+            entity_id: Uuid::from_u128(7),
+            position: [12.345 * t + 0.1234, -3.21 * t * t, 100.0 + (t * 1.7).sin()],
+            velocity: [12.345, -6.42 * t, 1.7 * (t * 1.7).cos()],
+            class_id: 4,
+            timestamp: 1000.0 + t,
+            confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        }
+    }
+    
+    /// Sends `packet` over `link` and decodes it at the other end.
+    fn relay(link: &mut DeltaLink, packet: &GlobalHazardPacket) -> Option<GlobalHazardPacket> {
+        let frame = link.send(packet);
+        link.receive(frame)
+    }
+    
+    #[test]
+    fn test_rebuilt_packets_are_within_the_quantization_bound() {
+        let config = DeltaCompression::default();
+        let mut link = DeltaLink::new(config);
+        
+        for step in 0..60 {
+            let packet = packet_at(step as f64 * 0.1667);
+            let rebuilt = relay(&mut link, &packet).unwrap();
+            for i in 0..3 {
+                assert!((rebuilt.position[i] - packet.position[i]).abs() <= config.position_bound() + 1e-9);
+                assert!((rebuilt.velocity[i] - packet.velocity[i]).abs() <= config.velocity_bound() + 1e-9);
+            }
+            assert!((rebuilt.timestamp - packet.timestamp).abs() <= config.time_quantum_secs / 2.0 + 1e-9);
+            assert_eq!((rebuilt.class_id, rebuilt.confidence_score), (packet.class_id, packet.confidence_score));
+        }
+        
+        // 10s of packets with a 2s epoch: one full packet per epoch
+        let stats = link.stats();
+        assert_eq!(stats.full_frames, 5);
+        assert_eq!(stats.delta_frames, 55);
+        assert!(stats.ratio() > 1.5, "ratio {}", stats.ratio());
+    }
+    
+    #[test]
+    fn test_changed_confidence_forces_a_full_packet() {
+        let mut link = DeltaLink::new(DeltaCompression::default());
+        link.send(&packet_at(0.0));
+        assert!(matches!(link.send(&packet_at(0.5)), GossipFrame::Delta(_)));
+        
+        let frame = link.send(&GlobalHazardPacket { confidence_score: 0.5, ..packet_at(1.0) });
+        assert!(matches!(frame, GossipFrame::Full { generation: 1, .. }));
+    }
+    
+    #[test]
+    fn test_missing_baseline_requests_a_full_packet() {
+        let mut link = DeltaLink::new(DeltaCompression::default());
+        
+        // The full packet is lost, so the delta after it can't be rebuilt
+        link.send(&packet_at(0.0));
+        let delta = link.send(&packet_at(0.5));
+        assert!(matches!(delta, GossipFrame::Delta(_)));
+        assert!(link.receive(delta).is_none());
+        assert_eq!(link.stats().full_requests, 1);
+        
+        // ...and the next broadcast restores it
+        let frame = link.send(&packet_at(1.0));
+        assert!(matches!(frame, GossipFrame::Full { generation: 1, .. }));
+        link.receive(frame).unwrap();
+        assert!(relay(&mut link, &packet_at(1.5)).is_some());
+        
+        // A delta taken from a newer baseline than the receiver holds is
+        // refused too, rather than applied to the wrong one
+        link.send(&GlobalHazardPacket { class_id: 1, ..packet_at(2.0) });
+        assert!(relay(&mut link, &GlobalHazardPacket { class_id: 1, ..packet_at(2.5) }).is_none());
+        assert_eq!(link.stats().full_requests, 2);
+    }
+    
+    #[test]
+    fn test_fusion_of_compressed_gossip_stays_within_the_bound() {
+        let config = DeltaCompression::default();
+        let tracking = TrackingConfig { frame: Oracle::frame(), ..TrackingConfig::default() };
+        let mut plain = TrackManager::new(tracking.clone());
+        let mut compressed = TrackManager::new(tracking);
+        let mut link = DeltaLink::new(config);
+        
+        let mut ids = (Uuid::nil(), Uuid::nil());
+        for step in 0..60 {
+            let packet = packet_at(step as f64 * 0.1667);
+            ids.0 = plain.process_packet(&packet, None, None).unwrap();
+            let rebuilt = relay(&mut link, &packet).unwrap();
+            ids.1 = compressed.process_packet(&rebuilt, None, None).unwrap();
+        }
+        
+        let (a, b) = (plain.get_track(&ids.0).unwrap(), compressed.get_track(&ids.1).unwrap());
+        let error = (a.position() - b.position()).amax();
+        assert!(error < config.position_bound(), "fused positions differ by {error}m");
+    }
+}
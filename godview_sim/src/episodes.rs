@@ -0,0 +1,184 @@
+//! Many short episodes of one learning scenario in one process.
+//!
+//! [`ScenarioRunner::run_episodes`](crate::ScenarioRunner::run_episodes)
+//! plays a scenario's world over and over. Before each episode the oracle is
+//! reset ([`Oracle::reset`](crate::Oracle::reset)) with the episode's seed
+//! and its entities respawned, and the agents are readied as the
+//! [`ResetPolicy`] says: rebuilt from scratch, or kept with their keys,
+//! energy and evolved parameters
+//! ([`SimulatedAgent::reset_world_state`](crate::SimulatedAgent::reset_world_state)).
+//! Comparing the learning curves of the two shows what carrying parameters
+//! from one episode into the next buys.
+//!
+//! Only scenarios that evolve parameters can run as episodes: see
+//! [`EPISODIC_SCENARIOS`].
+
+use crate::runner::ScenarioResult;
+use crate::scenarios::ScenarioId;
+use crate::stats;
+use serde::{Deserialize, Serialize};
+
+/// Scenarios that can run as episodes.
+pub const EPISODIC_SCENARIOS: [ScenarioId; 2] = [ScenarioId::BlindLearning, ScenarioId::CommonBias];
+
+/// Seed of episode `episode`'s world in a run seeded `seed`. The first
+/// episode plays the world a single run of the scenario does.
+pub fn episode_seed(seed: u64, episode: usize) -> u64 {
+    seed.wrapping_add(episode as u64)
+}
+
+/// What agents keep from one episode into the next.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResetPolicy {
+    /// Nothing: agents are rebuilt for every episode, each a single run
+    Fresh,
+    /// Keys, energy, evolved parameters and fitness history; neighbor
+    /// reputations are forgotten
+    #[default]
+    KeepParams,
+    /// Everything [`Self::KeepParams`] keeps, and neighbor reputations
+    KeepReputation,
+}
+
+impl ResetPolicy {
+    /// Every policy, in order.
+    pub const ALL: [ResetPolicy; 3] = [ResetPolicy::Fresh, ResetPolicy::KeepParams, ResetPolicy::KeepReputation];
+    
+    /// Name used on the command line and in reports.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResetPolicy::Fresh => "fresh",
+            ResetPolicy::KeepParams => "keep_params",
+            ResetPolicy::KeepReputation => "keep_reputation",
+        }
+    }
+    
+    /// Whether agents carry over from one episode into the next.
+    pub fn keeps_agents(&self) -> bool {
+        *self != ResetPolicy::Fresh
+    }
+}
+
+impl std::fmt::Display for ResetPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for ResetPolicy {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase().replace('-', "_");
+        Self::ALL.into_iter()
+            .find(|p| p.name() == name)
+            .ok_or_else(|| format!(
+                "unknown reset policy '{s}' (expected {})",
+                Self::ALL.map(|p| p.name()).join(", ")
+            ))
+    }
+}
+
+/// How one episode learned.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EpisodeCurve {
+    /// Swarm RMS averaged over each evolution epoch, in order (m)
+    pub epoch_rms: Vec<f64>,
+    /// Agents' mean fitness in each evolution epoch they completed, in
+    /// order: what the swarm evolves to raise
+    pub epoch_fitness: Vec<f64>,
+    /// Epochs before the curve settled near its final level, None if it
+    /// never did (as HybridLearning counts them)
+    pub epochs_to_convergence: Option<usize>,
+}
+
+impl EpisodeCurve {
+    /// RMS of the episode's first epoch (m; 0.0 if it closed none): how
+    /// well the swarm starts the episode.
+    pub fn first_epoch_rms(&self) -> f64 {
+        self.epoch_rms.first().copied().unwrap_or(0.0)
+    }
+    
+    /// Mean of the episode's epoch fitness (0.0 if it completed none).
+    pub fn mean_fitness(&self) -> f64 {
+        stats::mean(&self.epoch_fitness)
+    }
+}
+
+/// How accuracy moved within and across episodes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EpisodeMetrics {
+    /// Each episode's learning curve, in order
+    pub curves: Vec<EpisodeCurve>,
+    /// Mean RMS of each episode, in order (m): the cross-episode curve
+    pub episode_rms: Vec<f64>,
+    /// Mean fitness of each episode, in order
+    pub episode_fitness: Vec<f64>,
+    /// Epochs to convergence summed over every episode; an episode that
+    /// never converged counts all its epochs
+    pub total_epochs_to_convergence: usize,
+}
+
+impl EpisodeMetrics {
+    /// Computes the metrics from each episode's curve and mean RMS.
+    pub fn new(curves: Vec<EpisodeCurve>, episode_rms: Vec<f64>) -> Self {
+        let total_epochs_to_convergence = curves.iter()
+            .map(|c| c.epochs_to_convergence.unwrap_or(c.epoch_rms.len()))
+            .sum();
+        let episode_fitness = curves.iter().map(EpisodeCurve::mean_fitness).collect();
+        Self { curves, episode_rms, episode_fitness, total_epochs_to_convergence }
+    }
+    
+    /// Mean RMS of each episode's first epoch, in order (m).
+    pub fn first_epoch_rms(&self) -> Vec<f64> {
+        self.curves.iter().map(EpisodeCurve::first_epoch_rms).collect()
+    }
+}
+
+/// Outcome of a multi-episode run: one result per episode plus how they
+/// learned.
+#[derive(Debug, Clone)]
+pub struct EpisodesResult {
+    /// Seed used
+    pub seed: u64,
+    /// What agents kept between episodes
+    pub policy: ResetPolicy,
+    /// Result of each episode that ran, in order; episodes after an
+    /// aborted one are not run
+    pub episodes: Vec<ScenarioResult>,
+    /// Learning within and across the episodes
+    pub metrics: EpisodeMetrics,
+}
+
+impl EpisodesResult {
+    /// Whether every episode passed.
+    pub fn passed(&self) -> bool {
+        self.episodes.iter().all(|r| r.passed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_reset_policy_round_trips_names() {
+        for policy in ResetPolicy::ALL {
+            assert_eq!(policy.name().parse::<ResetPolicy>(), Ok(policy));
+        }
+        assert_eq!("Keep-Params".parse::<ResetPolicy>(), Ok(ResetPolicy::KeepParams));
+        assert!("forget".parse::<ResetPolicy>().is_err());
+    }
+    
+    #[test]
+    fn test_unconverged_episodes_count_every_epoch() {
+        let metrics = EpisodeMetrics::new(vec![
+            EpisodeCurve { epoch_rms: vec![4.0, 2.0, 1.0], epoch_fitness: vec![1.0, 3.0], epochs_to_convergence: None },
+            EpisodeCurve { epoch_rms: vec![1.5, 1.0, 1.0], epoch_fitness: Vec::new(), epochs_to_convergence: Some(1) },
+        ], vec![2.5, 1.1]);
+        assert_eq!(metrics.total_epochs_to_convergence, 4);
+        assert_eq!(metrics.first_epoch_rms(), vec![4.0, 1.5]);
+        assert_eq!(metrics.episode_fitness, vec![2.0, 0.0]);
+    }
+}
@@ -0,0 +1,481 @@
+//! Simulation-wide event bus.
+//!
+//! Partitions, bad-actor injections, faults, merges and evolution epochs are
+//! published as [`SimEvent`]s on a [`SimEventBus`] where they happen: by
+//! scenario code, the [`SimNetworkController`](crate::SimNetworkController),
+//! the [`SwarmNetwork`](crate::SwarmNetwork), a [`Simulation`](crate::Simulation)
+//! or [`SwarmHarness`](crate::SwarmHarness), and each
+//! [`SimulatedAgent`](crate::SimulatedAgent) handed the bus. Every subscriber
+//! receives every event synchronously, in publish order, stamped with a
+//! sequence number and the bus's clock ([`SimEventBus::set_clock`]), which a
+//! [`ScenarioRunner`](crate::ScenarioRunner) advances after each tick it
+//! observes. Every event is also logged at debug level.
+//!
+//! A harness ticks its agents on a thread pool. Events they publish inside a
+//! [`SimEventBus::parallel_phase`] are held until the phase ends and then
+//! delivered by agent index, in the order a serial pass would have published
+//! them, so delivery depends on the seed and not on the thread count.
+//!
+//! For each run the runner subscribes the sinks it needs: an [`EventLog`]
+//! whose events land in recorded frames, the Rerun logger, a metrics
+//! registry counting `events_<kind>`, and an [`EventInvariants`] checker
+//! whose violation the failure capture centers on.
+
+use crate::capture::InvariantViolation;
+use crate::faults::{ConfigPerturbation, Fault};
+use godview_env::NodeId;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use uuid::Uuid;
+
+/// Something that happened during a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SimEvent {
+    /// A scenario run, or one phase of a campaign, started
+    RunStarted { scenario: String, seed: u64, phase: Option<usize> },
+    
+    /// Gossip between two groups of nodes is blocked
+    Partitioned { group_a: Vec<NodeId>, group_b: Vec<NodeId> },
+    
+    /// Every partition was healed
+    Healed,
+    
+    /// Agents started gossiping garbage
+    BadActorsInjected { agents: Vec<usize> },
+    
+    /// Every bad actor stopped
+    BadActorsCleared,
+    
+    /// Gossip between every pair of agents is now dropped with probability `rate`
+    PacketLossSet { rate: f64 },
+    
+    /// A ground-truth entity was removed
+    EntityDespawned { entity_id: u64 },
+    
+    /// An agent crashed, stalled or lost its tracks
+    FaultInjected { fault: Fault },
+    
+    /// A stalled agent picked up where it froze
+    AgentResumed { agent: usize },
+    
+    /// Running agents swapped in a perturbed tracking config; `agents`
+    /// accepted it
+    ConfigPerturbed { change: ConfigPerturbation, agents: usize },
+    
+    /// An agent lost its GPS and dead-reckons from here
+    GpsDenied { agent: usize },
+    
+    /// An agent's GPS came back
+    GpsRestored { agent: usize },
+    
+    /// Moving agents made or broke directed radio links
+    LinksChanged { made: u64, broken: u64 },
+    
+    /// An agent folded one track ID into another (recorded with genealogy on)
+    TracksMerged { agent: usize, winner: Uuid, loser: Uuid },
+    
+    /// An agent refused a gossip envelope
+    GossipRejected { agent: usize, from: usize, reason: String },
+    
+    /// An agent scored an evolution epoch
+    EpochCompleted { agent: usize, tick: u64, fitness: f64, accepted: bool },
+    
+    /// The run was interrupted and ends after this tick
+    Interrupted,
+}
+
+impl SimEvent {
+    /// Snake-case name of the variant, as serialized in `kind`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SimEvent::RunStarted { .. } => "run_started",
+            SimEvent::Partitioned { .. } => "partitioned",
+            SimEvent::Healed => "healed",
+            SimEvent::BadActorsInjected { .. } => "bad_actors_injected",
+            SimEvent::BadActorsCleared => "bad_actors_cleared",
+            SimEvent::PacketLossSet { .. } => "packet_loss_set",
+            SimEvent::EntityDespawned { .. } => "entity_despawned",
+            SimEvent::FaultInjected { .. } => "fault_injected",
+            SimEvent::AgentResumed { .. } => "agent_resumed",
+            SimEvent::ConfigPerturbed { .. } => "config_perturbed",
+            SimEvent::GpsDenied { .. } => "gps_denied",
+            SimEvent::GpsRestored { .. } => "gps_restored",
+            SimEvent::LinksChanged { .. } => "links_changed",
+            SimEvent::TracksMerged { .. } => "tracks_merged",
+            SimEvent::GossipRejected { .. } => "gossip_rejected",
+            SimEvent::EpochCompleted { .. } => "epoch_completed",
+            SimEvent::Interrupted => "interrupted",
+        }
+    }
+    
+    /// The agent the event happened to, if it is about one agent.
+    pub fn agent(&self) -> Option<usize> {
+        match *self {
+            SimEvent::FaultInjected { fault } => Some(fault.agent()),
+            SimEvent::AgentResumed { agent }
+            | SimEvent::GpsDenied { agent }
+            | SimEvent::GpsRestored { agent }
+            | SimEvent::TracksMerged { agent, .. }
+            | SimEvent::GossipRejected { agent, .. }
+            | SimEvent::EpochCompleted { agent, .. } => Some(agent),
+            _ => None,
+        }
+    }
+    
+    /// Whether the event is worth a warning: something attacked or broke.
+    pub fn is_warning(&self) -> bool {
+        matches!(self, SimEvent::BadActorsInjected { .. } | SimEvent::FaultInjected { .. }
+            | SimEvent::GossipRejected { .. } | SimEvent::Interrupted)
+    }
+}
+
+impl fmt::Display for SimEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimEvent::RunStarted { scenario, seed, phase: None } => write!(f, "{} (seed={})", scenario, seed),
+            SimEvent::RunStarted { scenario, seed, phase: Some(phase) } => {
+                write!(f, "campaign phase {}: {} (seed={})", phase, scenario, seed)
+            }
+            SimEvent::Partitioned { group_a, group_b } => {
+                write!(f, "Partition {} | {} nodes", group_a.len(), group_b.len())
+            }
+            SimEvent::Healed => write!(f, "Healed every partition"),
+            SimEvent::BadActorsInjected { agents } => write!(f, "Bad actors injected: {:?}", agents),
+            SimEvent::BadActorsCleared => write!(f, "Bad actors cleared"),
+            SimEvent::PacketLossSet { rate } => write!(f, "Packet loss {:.0}%", rate * 100.0),
+            SimEvent::EntityDespawned { entity_id } => write!(f, "Entity {} despawned", entity_id),
+            SimEvent::FaultInjected { fault } => write!(f, "Injected {}", fault),
+            SimEvent::AgentResumed { agent } => write!(f, "Agent {} resumed", agent),
+            SimEvent::ConfigPerturbed { change, agents } => {
+                write!(f, "Tracking config perturbed ({}) on {} agents", change, agents)
+            }
+            SimEvent::GpsDenied { agent } => write!(f, "Agent {} lost GPS", agent),
+            SimEvent::GpsRestored { agent } => write!(f, "Agent {} regained GPS", agent),
+            SimEvent::LinksChanged { made, broken } => write!(f, "{} links made, {} broken", made, broken),
+            SimEvent::TracksMerged { agent, winner, loser } => {
+                write!(f, "Agent {} merged track {} into {}", agent, loser, winner)
+            }
+            SimEvent::GossipRejected { agent, from, reason } => {
+                write!(f, "Agent {} rejected gossip from {}: {}", agent, from, reason)
+            }
+            SimEvent::EpochCompleted { agent, tick, fitness, accepted } => {
+                write!(f, "Agent {} epoch at tick {}: fitness {:.3} ({})", agent, tick, fitness,
+                    if *accepted { "kept" } else { "reverted" })
+            }
+            SimEvent::Interrupted => write!(f, "Interrupted"),
+        }
+    }
+}
+
+/// An event as subscribers receive it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublishedEvent {
+    /// Position in the bus's publish order, from 0 since the last reset
+    pub seq: u64,
+    
+    /// Tick the bus's clock read when the event was published
+    pub tick: u64,
+    
+    /// Simulated time the bus's clock read (s)
+    pub time_secs: f64,
+    
+    pub event: SimEvent,
+}
+
+/// Receives every event published on a [`SimEventBus`] it is subscribed to.
+///
+/// Delivery happens while the bus is locked, so a subscriber must not
+/// publish.
+pub trait SimEventSubscriber: Send {
+    fn on_event(&mut self, event: &PublishedEvent);
+}
+
+/// Handle to a subscriber registered with [`SimEventBus::subscribe`].
+pub struct Subscription<S> {
+    subscriber: Arc<Mutex<S>>,
+}
+
+impl<S> Subscription<S> {
+    /// Locks the subscriber to read or drain what it has received.
+    pub fn lock(&self) -> MutexGuard<'_, S> {
+        self.subscriber.lock().unwrap()
+    }
+}
+
+impl<S> Clone for Subscription<S> {
+    fn clone(&self) -> Self {
+        Self { subscriber: Arc::clone(&self.subscriber) }
+    }
+}
+
+#[derive(Default)]
+struct BusState {
+    tick: u64,
+    time_secs: f64,
+    next_seq: u64,
+    subscribers: Vec<Arc<Mutex<dyn SimEventSubscriber>>>,
+    /// Events published inside a parallel phase, not yet delivered
+    held: Option<Vec<SimEvent>>,
+}
+
+impl BusState {
+    fn deliver(&mut self, event: SimEvent) {
+        tracing::debug!("t={:.2}s: {}", self.time_secs, event);
+        let published = PublishedEvent { seq: self.next_seq, tick: self.tick, time_secs: self.time_secs, event };
+        self.next_seq += 1;
+        for subscriber in &self.subscribers {
+            subscriber.lock().unwrap().on_event(&published);
+        }
+    }
+}
+
+/// Synchronous publish/subscribe channel for [`SimEvent`]s.
+///
+/// Clones share the same subscribers and clock, so every component a
+/// scenario hands one to publishes into the same stream.
+#[derive(Clone, Default)]
+pub struct SimEventBus {
+    state: Arc<Mutex<BusState>>,
+}
+
+impl SimEventBus {
+    /// Creates a bus with no subscribers, its clock at tick 0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Registers `subscriber` for every event published from now on, after
+    /// the subscribers already registered.
+    pub fn subscribe<S: SimEventSubscriber + 'static>(&self, subscriber: S) -> Subscription<S> {
+        let subscriber = Arc::new(Mutex::new(subscriber));
+        self.state.lock().unwrap().subscribers.push(subscriber.clone());
+        Subscription { subscriber }
+    }
+    
+    /// Drops every subscriber and restarts the sequence and clock at 0.
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = BusState::default();
+    }
+    
+    /// Sets the tick and simulated time stamped on events published from now on.
+    pub fn set_clock(&self, tick: u64, time_secs: f64) {
+        let mut state = self.state.lock().unwrap();
+        state.tick = tick;
+        state.time_secs = time_secs;
+    }
+    
+    /// Delivers `event` to every subscriber, or holds it until the current
+    /// [`Self::parallel_phase`] ends.
+    pub fn publish(&self, event: SimEvent) {
+        let mut state = self.state.lock().unwrap();
+        match state.held.as_mut() {
+            Some(held) => held.push(event),
+            None => state.deliver(event),
+        }
+    }
+    
+    /// Holds events until the returned guard drops, then delivers them
+    /// ordered by [`SimEvent::agent`] (events about no agent first), each
+    /// agent's in the order it published them. Inside an outer phase the
+    /// guard does nothing.
+    pub fn parallel_phase(&self) -> ParallelPhase<'_> {
+        let mut state = self.state.lock().unwrap();
+        let owner = state.held.is_none();
+        if owner {
+            state.held = Some(Vec::new());
+        }
+        ParallelPhase { bus: self, owner }
+    }
+    
+    /// Events published since the last reset, held ones included.
+    pub fn published(&self) -> u64 {
+        let state = self.state.lock().unwrap();
+        state.next_seq + state.held.as_ref().map_or(0, |h| h.len() as u64)
+    }
+}
+
+/// Guard returned by [`SimEventBus::parallel_phase`].
+pub struct ParallelPhase<'a> {
+    bus: &'a SimEventBus,
+    owner: bool,
+}
+
+impl Drop for ParallelPhase<'_> {
+    fn drop(&mut self) {
+        if !self.owner {
+            return;
+        }
+        let mut state = self.bus.state.lock().unwrap();
+        let mut held = state.held.take().unwrap_or_default();
+        held.sort_by_key(SimEvent::agent);
+        for event in held {
+            state.deliver(event);
+        }
+    }
+}
+
+/// Subscriber that keeps every event it receives.
+#[derive(Debug, Clone, Default)]
+pub struct EventLog {
+    events: Vec<PublishedEvent>,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Events received, oldest first.
+    pub fn events(&self) -> &[PublishedEvent] {
+        &self.events
+    }
+    
+    /// Takes the events received since the last take.
+    pub fn take(&mut self) -> Vec<PublishedEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+impl SimEventSubscriber for EventLog {
+    fn on_event(&mut self, event: &PublishedEvent) {
+        self.events.push(event.clone());
+    }
+}
+
+/// Subscriber that checks the event stream for contradictions:
+///
+/// - `overlapping_partition`: a node on both sides of a partition
+/// - `crashed_agent_event`: an agent publishing after it crashed
+#[derive(Debug, Clone, Default)]
+pub struct EventInvariants {
+    crashed: BTreeSet<usize>,
+    violation: Option<InvariantViolation>,
+}
+
+impl EventInvariants {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Takes the first violation seen, if there was one not yet taken.
+    pub fn take_violation(&mut self) -> Option<InvariantViolation> {
+        self.violation.take()
+    }
+    
+    fn check(&self, event: &SimEvent) -> Option<(&'static str, String)> {
+        match event {
+            SimEvent::Partitioned { group_a, group_b } => {
+                let both = group_a.iter().filter(|n| group_b.contains(n)).count();
+                (both > 0).then(|| ("overlapping_partition", format!("{} nodes on both sides of a partition", both)))
+            }
+            SimEvent::FaultInjected { .. } => None,
+            _ => event.agent()
+                .filter(|agent| self.crashed.contains(agent))
+                .map(|agent| ("crashed_agent_event", format!("Agent {} crashed but published: {}", agent, event))),
+        }
+    }
+}
+
+impl SimEventSubscriber for EventInvariants {
+    fn on_event(&mut self, published: &PublishedEvent) {
+        if let Some((invariant, details)) = self.check(&published.event) {
+            if self.violation.is_none() {
+                self.violation = Some(InvariantViolation::new(published.tick, published.time_secs, invariant, details));
+            }
+        }
+        if let SimEvent::FaultInjected { fault: Fault::Crash { agent, .. } } = published.event {
+            self.crashed.insert(agent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn kinds(log: &Subscription<EventLog>) -> Vec<&'static str> {
+        log.lock().events().iter().map(|e| e.event.kind()).collect()
+    }
+    
+    #[test]
+    fn test_subscribers_receive_events_in_publish_order() {
+        let bus = SimEventBus::new();
+        let first = bus.subscribe(EventLog::new());
+        bus.publish(SimEvent::Healed);
+        let second = bus.subscribe(EventLog::new());
+        bus.set_clock(3, 0.3);
+        bus.publish(SimEvent::PacketLossSet { rate: 0.2 });
+        bus.clone().publish(SimEvent::BadActorsCleared);
+        
+        assert_eq!(kinds(&first), ["healed", "packet_loss_set", "bad_actors_cleared"]);
+        assert_eq!(kinds(&second), ["packet_loss_set", "bad_actors_cleared"]);
+        let events = first.lock().take();
+        assert_eq!(events.iter().map(|e| (e.seq, e.tick)).collect::<Vec<_>>(), [(0, 0), (1, 3), (2, 3)]);
+        assert_eq!(bus.published(), 3);
+        
+        bus.reset();
+        bus.publish(SimEvent::Healed);
+        assert!(first.lock().events().is_empty(), "a reset unsubscribes");
+    }
+    
+    #[test]
+    fn test_parallel_phase_delivers_in_agent_order() {
+        let bus = SimEventBus::new();
+        let log = bus.subscribe(EventLog::new());
+        let merged = |agent| SimEvent::TracksMerged { agent, winner: Uuid::nil(), loser: Uuid::max() };
+        {
+            let _phase = bus.parallel_phase();
+            bus.publish(merged(2));
+            bus.publish(SimEvent::AgentResumed { agent: 0 });
+            bus.publish(SimEvent::GpsDenied { agent: 2 });
+            {
+                let _inner = bus.parallel_phase();
+                bus.publish(merged(0));
+            }
+            bus.publish(SimEvent::Healed);
+            assert!(log.lock().events().is_empty());
+            assert_eq!(bus.published(), 5);
+        }
+        
+        let order: Vec<(Option<usize>, &str)> = log.lock().events().iter()
+            .map(|e| (e.event.agent(), e.event.kind()))
+            .collect();
+        assert_eq!(order, [
+            (None, "healed"),
+            (Some(0), "agent_resumed"),
+            (Some(0), "tracks_merged"),
+            (Some(2), "tracks_merged"),
+            (Some(2), "gps_denied"),
+        ]);
+    }
+    
+    #[test]
+    fn test_event_invariants() {
+        let bus = SimEventBus::new();
+        let invariants = bus.subscribe(EventInvariants::new());
+        let nodes: Vec<NodeId> = (0..3).map(NodeId::from_seed).collect();
+        bus.publish(SimEvent::Partitioned { group_a: nodes[..1].to_vec(), group_b: nodes[1..].to_vec() });
+        bus.publish(SimEvent::FaultInjected { fault: Fault::Crash { at_tick: 4, agent: 1 } });
+        bus.publish(SimEvent::GpsDenied { agent: 0 });
+        assert_eq!(invariants.lock().take_violation(), None);
+        
+        bus.set_clock(7, 0.7);
+        bus.publish(SimEvent::EpochCompleted { agent: 1, tick: 7, fitness: 0.5, accepted: true });
+        bus.publish(SimEvent::Partitioned { group_a: nodes.clone(), group_b: nodes[2..].to_vec() });
+        let violation = invariants.lock().take_violation().unwrap();
+        assert_eq!((violation.tick, violation.invariant.as_str()), (7, "crashed_agent_event"));
+        assert_eq!(invariants.lock().take_violation(), None, "only the first violation is kept");
+    }
+    
+    #[test]
+    fn test_events_serialize_with_their_kind() {
+        let event = SimEvent::FaultInjected { fault: Fault::Stall { at_tick: 5, agent: 2, for_ticks: 10 } };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["kind"], event.kind());
+        assert_eq!(serde_json::from_value::<SimEvent>(json).unwrap(), event);
+        assert!(event.is_warning() && !SimEvent::Healed.is_warning());
+    }
+}
@@ -1,7 +1,29 @@
+use crate::adaptive::{GossipQuota, TrustInflation};
+use crate::priority::GossipPriority;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Number of trailing epochs [`param_variance`] looks at.
+pub const CONVERGENCE_WINDOW_EPOCHS: usize = 5;
+
+/// Parameter variance below which an agent counts as converged, in units of
+/// squared mutation steps.
+pub const CONVERGENCE_VARIANCE_THRESHOLD: f64 = 1.0;
+
+/// Steepest trust inflation curve mutations may evolve.
+pub const MAX_TRUST_EXPONENT: f64 = 4.0;
+
+/// Smallest gossip quota capacity mutations may evolve, in packets.
+pub const MIN_QUOTA_CAPACITY: f64 = 1.0;
+
+/// Largest gossip TTL mutations may evolve, in hops.
+pub const MAX_GOSSIP_TTL: u8 = 16;
+
+/// Longest anti-entropy digest interval mutations may evolve, in ticks.
+pub const MAX_DIGEST_INTERVAL_TICKS: u64 = 300;
 
 /// Parameters that can be evolved/adapted at runtime.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct EvoParams {
     /// How many ticks between gossip rounds.
     pub gossip_interval_ticks: u64,
@@ -15,6 +37,34 @@ pub struct EvoParams {
     /// Sensor bias estimate (v0.6.0): calibration offset for systematic errors.
     /// Agents evolve this to compensate for GPS bias.
     pub sensor_bias_estimate: f64,
+    
+    /// Weights used to order gossip under bandwidth limits. Only mutated for
+    /// agents with priority genes enabled.
+    pub gossip_priority: GossipPriority,
+    
+    /// How neighbor reliability inflates gossip covariance before fusion.
+    /// Only mutated for agents with trust genes enabled.
+    pub trust_inflation: TrustInflation,
+    
+    /// Per-neighbor gossip ingestion quota (None: unlimited). Only mutated
+    /// for agents with quota genes enabled.
+    pub gossip_quota: Option<GossipQuota>,
+    
+    /// Hops the agent's own gossip envelopes may travel (None: never
+    /// expires). Only mutated for agents with TTL genes enabled.
+    #[serde(default = "single_hop")]
+    pub gossip_ttl: Option<u8>,
+    
+    /// Ticks between anti-entropy digest exchanges (None: push only). Only
+    /// mutated for agents with anti-entropy genes enabled.
+    #[serde(default)]
+    pub digest_interval_ticks: Option<u64>,
+}
+
+/// Envelopes reach direct neighbors only; the TTL of params saved before
+/// it existed.
+fn single_hop() -> Option<u8> {
+    Some(1)
 }
 
 impl Default for EvoParams {
@@ -24,10 +74,102 @@ impl Default for EvoParams {
             max_neighbors_gossip: 100, // Effectively infinite (all neighbors)
             confidence_threshold: 0.0,
             sensor_bias_estimate: 0.0, // No bias compensation by default
+            gossip_priority: GossipPriority::default(),
+            trust_inflation: TrustInflation::OFF,
+            gossip_quota: None,
+            gossip_ttl: single_hop(),
+            digest_interval_ticks: None,
         }
     }
 }
 
+impl EvoParams {
+    /// Genes in units of their base mutation step (1 tick, 5 neighbors,
+    /// 0.05 confidence, 0.5 m bias), so they can be compared with each other.
+    /// The gossip priority weights, trust curve and gossip quota are left out
+    /// so convergence means the same for agents with and without those genes.
+    fn in_steps(&self) -> [f64; 4] {
+        [
+            self.gossip_interval_ticks as f64,
+            self.max_neighbors_gossip as f64 / 5.0,
+            self.confidence_threshold / 0.05,
+            self.sensor_bias_estimate / 0.5,
+        ]
+    }
+}
+
+/// Outcome of one evolutionary epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EpochRecord {
+    /// Agent tick count when the epoch ended
+    pub tick: u64,
+    
+    /// Parameters the epoch ran with
+    pub params: EvoParams,
+    
+    /// Fitness scored for the epoch
+    pub fitness: f64,
+    
+    /// Whether `params` were kept (false if the mutation was reverted)
+    pub accepted: bool,
+}
+
+/// Mean per-gene variance of the parameters over the last `window` epochs,
+/// in squared mutation steps (None with fewer than `window` epochs).
+pub fn param_variance(history: &[EpochRecord], window: usize) -> Option<f64> {
+    if window == 0 || history.len() < window {
+        return None;
+    }
+    let recent: Vec<[f64; 4]> = history[history.len() - window..].iter()
+        .map(|r| r.params.in_steps())
+        .collect();
+    
+    let n = window as f64;
+    let total: f64 = (0..4)
+        .map(|gene| {
+            let mean = recent.iter().map(|p| p[gene]).sum::<f64>() / n;
+            recent.iter().map(|p| (p[gene] - mean).powi(2)).sum::<f64>() / n
+        })
+        .sum();
+    Some(total / 4.0)
+}
+
+/// How settled a group of evolving agents' parameters are at the end of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ParamConvergence {
+    /// Agents with at least [`CONVERGENCE_WINDOW_EPOCHS`] epochs
+    pub agents: usize,
+    
+    /// Of those, agents whose variance is below [`CONVERGENCE_VARIANCE_THRESHOLD`]
+    pub converged_agents: usize,
+    
+    /// Mean [`param_variance`] over those agents
+    pub mean_variance: f64,
+}
+
+impl ParamConvergence {
+    /// Measures convergence from each agent's epoch history (None if no
+    /// agent has run enough epochs).
+    pub fn measure<'a>(histories: impl IntoIterator<Item = &'a [EpochRecord]>) -> Option<Self> {
+        let variances: Vec<f64> = histories.into_iter()
+            .filter_map(|h| param_variance(h, CONVERGENCE_WINDOW_EPOCHS))
+            .collect();
+        if variances.is_empty() {
+            return None;
+        }
+        Some(Self {
+            agents: variances.len(),
+            converged_agents: variances.iter().filter(|v| **v < CONVERGENCE_VARIANCE_THRESHOLD).count(),
+            mean_variance: variances.iter().sum::<f64>() / variances.len() as f64,
+        })
+    }
+    
+    /// Returns true if the mean variance is below the threshold.
+    pub fn converged(&self) -> bool {
+        self.mean_variance < CONVERGENCE_VARIANCE_THRESHOLD
+    }
+}
+
 /// Context passed to the fitness function containing all collected metrics.
 #[derive(Debug, Clone, Default)]
 pub struct FitnessContext {
@@ -55,12 +197,22 @@ pub struct FitnessContext {
     
     /// Energy penalty (0.0 = full battery, 1.0 = dead).
     pub energy_penalty: f64,
+    
+    // --- Epoch ---
+    /// Epochs completed before this one (0 for the first).
+    pub epoch: u64,
+    
+    /// Average position error over the ticks of this epoch that had ground
+    /// truth, or None for an epoch run blind. Unlike
+    /// [`Self::avg_position_error`], blind ticks don't count as zero error.
+    pub ground_truth_error: Option<f64>,
 }
 
-/// Trait for fitness calculation strategies (Oracle vs Blind).
+/// Trait for fitness calculation strategies (Oracle, Blind or Hybrid).
 pub trait FitnessProvider: Send + Sync {
     /// Calculate fitness score based on the provided context.
-    /// Higher is better.
+    /// Higher is better. [`FitnessContext::ground_truth_error`] tells the
+    /// epochs that saw ground truth from the blind ones.
     fn calculate_fitness(&self, ctx: &FitnessContext) -> f64;
     
     /// Returns the name of this provider.
@@ -160,6 +312,133 @@ impl FitnessProvider for BlindFitness {
     }
 }
 
+/// How much weight [`HybridFitness`] gives ground truth, by epoch: from
+/// `start` at epoch 0 toward `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GtWeightSchedule {
+    /// Straight line, reaching `end` at epoch `epochs`
+    Linear { start: f64, end: f64, epochs: u64 },
+    
+    /// `start` before epoch `at_epoch`, `end` from it on
+    Step { start: f64, end: f64, at_epoch: u64 },
+    
+    /// Gap to `end` halving every `half_life_epochs`
+    Exponential { start: f64, end: f64, half_life_epochs: f64 },
+}
+
+impl GtWeightSchedule {
+    /// Anneals from mostly ground truth to mostly blind over 30 epochs.
+    pub const DEFAULT: Self = Self::Linear { start: 0.9, end: 0.1, epochs: 30 };
+    
+    /// Ground-truth weight of epoch `epoch`, within 0..=1.
+    pub fn weight(&self, epoch: u64) -> f64 {
+        let weight = match *self {
+            Self::Linear { start, end, epochs } => {
+                let progress = if epochs == 0 { 1.0 } else { (epoch as f64 / epochs as f64).min(1.0) };
+                start + (end - start) * progress
+            }
+            Self::Step { start, end, at_epoch } => if epoch < at_epoch { start } else { end },
+            Self::Exponential { start, end, half_life_epochs } => {
+                end + (start - end) * 0.5f64.powf(epoch as f64 / half_life_epochs)
+            }
+        };
+        weight.clamp(0.0, 1.0)
+    }
+}
+
+impl Default for GtWeightSchedule {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl std::fmt::Display for GtWeightSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Linear { start, end, epochs } => write!(f, "linear:{start},{end},{epochs}"),
+            Self::Step { start, end, at_epoch } => write!(f, "step:{start},{end},{at_epoch}"),
+            Self::Exponential { start, end, half_life_epochs } => write!(f, "exp:{start},{end},{half_life_epochs}"),
+        }
+    }
+}
+
+impl std::str::FromStr for GtWeightSchedule {
+    type Err = String;
+    
+    /// Parses `linear:START,END,EPOCHS`, `step:START,END,AT_EPOCH` or
+    /// `exp:START,END,HALF_LIFE_EPOCHS`, with weights in 0..=1; `linear`
+    /// alone is [`Self::DEFAULT`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, params) = s.split_once(':').unwrap_or((s, ""));
+        let values = params.split(',')
+            .filter(|p| !p.trim().is_empty())
+            .map(|p| p.trim().parse::<f64>().map_err(|e| format!("bad schedule parameter '{p}': {e}")))
+            .collect::<Result<Vec<f64>, String>>()?;
+        let weight = |w: f64| (0.0..=1.0).contains(&w);
+        let epochs = |n: f64| n >= 0.0 && n.fract() == 0.0;
+        let schedule = match (kind.trim().to_ascii_lowercase().as_str(), values.as_slice()) {
+            ("linear", []) => Self::DEFAULT,
+            ("linear", &[start, end, n]) if weight(start) && weight(end) && epochs(n) => {
+                Self::Linear { start, end, epochs: n as u64 }
+            }
+            ("step", &[start, end, at]) if weight(start) && weight(end) && epochs(at) => {
+                Self::Step { start, end, at_epoch: at as u64 }
+            }
+            ("exp", &[start, end, half_life]) if weight(start) && weight(end) && half_life > 0.0 && half_life.is_finite() => {
+                Self::Exponential { start, end, half_life_epochs: half_life }
+            }
+            _ => return Err(format!(
+                "expected linear[:START,END,EPOCHS], step:START,END,AT_EPOCH or exp:START,END,HALF_LIFE \
+                 with weights in 0..=1, got '{s}'"
+            )),
+        };
+        Ok(schedule)
+    }
+}
+
+/// Ground truth when there is some, blind metrics always: an epoch that saw
+/// ground truth (passing a surveyed landmark, say) scores
+/// `w * oracle + (1 - w) * blind`, with `w` from the schedule, and a blind
+/// epoch scores as [`BlindFitness`].
+pub struct HybridFitness {
+    pub gt_weight_schedule: GtWeightSchedule,
+    pub oracle: OracleFitness,
+    pub blind: BlindFitness,
+}
+
+impl HybridFitness {
+    pub fn new(gt_weight_schedule: GtWeightSchedule) -> Self {
+        Self {
+            gt_weight_schedule,
+            oracle: OracleFitness::new(),
+            blind: BlindFitness::new(),
+        }
+    }
+}
+
+impl Default for HybridFitness {
+    fn default() -> Self {
+        Self::new(GtWeightSchedule::DEFAULT)
+    }
+}
+
+impl FitnessProvider for HybridFitness {
+    fn calculate_fitness(&self, ctx: &FitnessContext) -> f64 {
+        let blind = self.blind.calculate_fitness(ctx);
+        let Some(error) = ctx.ground_truth_error else {
+            return blind;
+        };
+        let oracle = self.oracle.calculate_fitness(&FitnessContext { avg_position_error: error, ..ctx.clone() });
+        let w = self.gt_weight_schedule.weight(ctx.epoch);
+        w * oracle + (1.0 - w) * blind
+    }
+    
+    fn name(&self) -> &str {
+        "HybridFitness"
+    }
+}
+
 /// State for the evolutionary learning process.
 pub struct EvolutionaryState {
     /// Current active parameters.
@@ -181,6 +460,7 @@ pub struct EvolutionaryState {
     epoch_msgs_sent: u64,
     epoch_error_sum: f64,
     epoch_samples: u64,
+    epoch_gt_samples: u64,
     
     // Blind metrics accumulators
     epoch_nis_sum: f64,
@@ -200,6 +480,24 @@ pub struct EvolutionaryState {
     
     /// Whether the last mutation attempt was multi-parameter.
     was_multi_param: bool,
+    
+    /// One record per completed epoch, oldest first.
+    history: Vec<EpochRecord>,
+    
+    /// Whether mutations may change the gossip priority weights.
+    priority_genes: bool,
+    
+    /// Whether mutations may change the trust inflation exponent.
+    trust_genes: bool,
+    
+    /// Whether mutations may resize the gossip quota.
+    quota_genes: bool,
+    
+    /// Whether mutations may change the gossip TTL.
+    ttl_genes: bool,
+    
+    /// Whether mutations may change the anti-entropy digest interval.
+    digest_genes: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -212,6 +510,22 @@ enum MutationType {
     DecreaseConfidence,
     IncreaseBias,   // v0.6.0: Sensor bias calibration
     DecreaseBias,
+    IncreasePriorityWeight(usize),
+    DecreasePriorityWeight(usize),
+    IncreaseTrustExponent,
+    DecreaseTrustExponent,
+    IncreaseQuota,
+    DecreaseQuota,
+    IncreaseTtl,
+    DecreaseTtl,
+    IncreaseDigestInterval,
+    DecreaseDigestInterval,
+}
+
+impl Default for EvolutionaryState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EvolutionaryState {
@@ -225,6 +539,7 @@ impl EvolutionaryState {
             epoch_msgs_sent: 0,
             epoch_error_sum: 0.0,
             epoch_samples: 0,
+            epoch_gt_samples: 0,
             epoch_nis_sum: 0.0,
             epoch_pa_sum: 0.0,
             epoch_bytes_sent: 0,
@@ -234,19 +549,74 @@ impl EvolutionaryState {
             consecutive_failures: 0,
             step_multiplier: 1.0,
             was_multi_param: false,
+            history: Vec::new(),
+            priority_genes: false,
+            trust_genes: false,
+            quota_genes: false,
+            ttl_genes: false,
+            digest_genes: false,
         }
     }
     
-    /// Record metrics for the current epoch.
+    /// Creates a state that starts from `params` instead of the defaults.
+    pub fn with_params(params: EvoParams) -> Self {
+        Self {
+            current_params: params,
+            prev_params: params,
+            ..Self::new()
+        }
+    }
+    
+    /// Restarts evolution from `params`, as if they'd been evolved so far.
+    /// Which genes may mutate is left as configured.
+    pub fn load_params(&mut self, params: EvoParams) {
+        self.current_params = params;
+        self.prev_params = params;
+    }
+    
+    /// Lets mutations change the gossip priority weights, for agents that
+    /// order their gossip by them.
+    pub fn set_priority_genes(&mut self, enabled: bool) {
+        self.priority_genes = enabled;
+    }
+    
+    /// Lets mutations change the trust inflation exponent, for agents that
+    /// inflate gossip covariance by neighbor reliability.
+    pub fn set_trust_genes(&mut self, enabled: bool) {
+        self.trust_genes = enabled;
+    }
+    
+    /// Lets mutations resize the gossip quota, for agents that rate-limit
+    /// their neighbors' gossip.
+    pub fn set_quota_genes(&mut self, enabled: bool) {
+        self.quota_genes = enabled;
+    }
+    
+    /// Lets mutations change the gossip TTL, for agents that relay gossip.
+    pub fn set_ttl_genes(&mut self, enabled: bool) {
+        self.ttl_genes = enabled;
+    }
+    
+    /// Lets mutations change the digest interval, for agents that exchange
+    /// anti-entropy digests.
+    pub fn set_digest_genes(&mut self, enabled: bool) {
+        self.digest_genes = enabled;
+    }
+    
+    /// Record metrics for the current epoch; `error` is None for a tick
+    /// without ground truth.
     pub fn record_metrics(
         &mut self, 
-        error: f64, 
+        error: Option<f64>, 
         nis: f64, 
         pa_cost: f64,
         energy_remaining: f64,
         cov_trace: f64,
     ) {
-        self.epoch_error_sum += error;
+        if let Some(error) = error {
+            self.epoch_error_sum += error;
+            self.epoch_gt_samples += 1;
+        }
         self.epoch_nis_sum += nis;
         self.epoch_pa_sum += pa_cost;
         self.epoch_energy_remaining_sum += energy_remaining;
@@ -256,7 +626,7 @@ impl EvolutionaryState {
     
     /// Legacy: Record only accuracy (for backward compat if needed).
     pub fn record_accuracy(&mut self, error: f64) {
-        self.record_metrics(error, 0.0, 0.0, 1000.0, 0.0);
+        self.record_metrics(Some(error), 0.0, 0.0, 1000.0, 0.0);
     }
     
     pub fn record_message_sent(&mut self, bytes: u64) {
@@ -264,9 +634,15 @@ impl EvolutionaryState {
         self.epoch_bytes_sent += bytes;
     }
     
-    /// End the current epoch, calculate fitness, and evolve parameters.
+    /// Completed epochs, oldest first.
+    pub fn history(&self) -> &[EpochRecord] {
+        &self.history
+    }
+    
+    /// End the current epoch (at agent tick `tick`), calculate fitness, and evolve parameters.
     pub fn evolve<R: Rng>(
         &mut self, 
+        tick: u64,
         rng: &mut R, 
         provider: &dyn FitnessProvider
     ) {
@@ -286,6 +662,9 @@ impl EvolutionaryState {
             avg_covariance_trace: self.epoch_cov_trace_sum / samples,
             msgs_per_tick: self.epoch_msgs_sent as f64 / samples,
             energy_penalty,
+            epoch: self.history.len() as u64,
+            ground_truth_error: (self.epoch_gt_samples > 0)
+                .then(|| self.epoch_error_sum / self.epoch_gt_samples as f64),
         };
         
         // 2. Calculate Fitness via Provider
@@ -295,8 +674,11 @@ impl EvolutionaryState {
         self.current_fitness = fitness;
         
         // 3. Evaluate last mutation with adaptive step tracking
+        let evaluated = self.current_params;
+        let mut accepted = true;
         if let Some(_mutation) = self.active_mutation {
-            if self.current_fitness >= self.prev_fitness {
+            accepted = self.current_fitness >= self.prev_fitness;
+            if accepted {
                 // Good mutation! Keep it and reduce step multiplier.
                 self.consecutive_failures = 0;
                 self.step_multiplier = (self.step_multiplier * 0.8).max(1.0);
@@ -311,6 +693,7 @@ impl EvolutionaryState {
                 }
             }
         }
+        self.history.push(EpochRecord { tick, params: evaluated, fitness, accepted });
         
         // 4. Propose new mutation (with 10% chance of multi-param)
         self.prev_params = self.current_params;
@@ -325,9 +708,24 @@ impl EvolutionaryState {
             self.apply_mutation();
         }
         
-        // Reset accumulators
+        self.reset_accumulators();
+    }
+    
+    /// Starts a new episode of a world that restarts at time 0: the
+    /// mutation under test is reverted, as its fitness would be judged
+    /// against a different world, and the metrics of the unfinished epoch
+    /// are dropped. Parameters, history and the adaptive step are kept.
+    pub fn start_episode(&mut self) {
+        if self.active_mutation.take().is_some() {
+            self.current_params = self.prev_params;
+        }
+        self.reset_accumulators();
+    }
+    
+    fn reset_accumulators(&mut self) {
         self.epoch_error_sum = 0.0;
         self.epoch_samples = 0;
+        self.epoch_gt_samples = 0;
         self.epoch_msgs_sent = 0;
         self.epoch_nis_sum = 0.0;
         self.epoch_pa_sum = 0.0;
@@ -337,7 +735,28 @@ impl EvolutionaryState {
     }
     
     fn pick_mutation<R: Rng>(&self, rng: &mut R) -> MutationType {
-        match rng.gen_range(0..8) {
+        let genes = 8
+            + if self.priority_genes { 2 } else { 0 }
+            + if self.trust_genes { 2 } else { 0 }
+            + if self.quota_genes { 2 } else { 0 }
+            + if self.ttl_genes { 2 } else { 0 }
+            + if self.digest_genes { 2 } else { 0 };
+        // Enabled optional genes take the indices after the fixed eight, in
+        // order; shift them back to where they'd be with every gene enabled
+        let mut gene = rng.gen_range(0..genes);
+        if gene >= 8 && !self.priority_genes {
+            gene += 2;
+        }
+        if gene >= 10 && !self.trust_genes {
+            gene += 2;
+        }
+        if gene >= 12 && !self.quota_genes {
+            gene += 2;
+        }
+        if gene >= 14 && !self.ttl_genes {
+            gene += 2;
+        }
+        match gene {
             0 => MutationType::IncreaseGossipInterval,
             1 => MutationType::DecreaseGossipInterval,
             2 => MutationType::IncreaseMaxNeighbors,
@@ -345,7 +764,17 @@ impl EvolutionaryState {
             4 => MutationType::IncreaseConfidence,
             5 => MutationType::DecreaseConfidence,
             6 => MutationType::IncreaseBias,
-            _ => MutationType::DecreaseBias,
+            7 => MutationType::DecreaseBias,
+            8 => MutationType::IncreasePriorityWeight(rng.gen_range(0..GossipPriority::WEIGHTS)),
+            9 => MutationType::DecreasePriorityWeight(rng.gen_range(0..GossipPriority::WEIGHTS)),
+            10 => MutationType::IncreaseTrustExponent,
+            11 => MutationType::DecreaseTrustExponent,
+            12 => MutationType::IncreaseQuota,
+            13 => MutationType::DecreaseQuota,
+            14 => MutationType::IncreaseTtl,
+            15 => MutationType::DecreaseTtl,
+            16 => MutationType::IncreaseDigestInterval,
+            _ => MutationType::DecreaseDigestInterval,
         }
     }
     
@@ -397,6 +826,39 @@ impl EvolutionaryState {
             MutationType::DecreaseBias => {
                 self.current_params.sensor_bias_estimate -= 0.5 * step;
             }
+            MutationType::IncreasePriorityWeight(index) => {
+                *self.current_params.gossip_priority.weight_mut(index) += 0.25 * step;
+            }
+            MutationType::DecreasePriorityWeight(index) => {
+                let weight = self.current_params.gossip_priority.weight_mut(index);
+                *weight = (*weight - 0.25 * step).max(0.0);
+            }
+            MutationType::IncreaseTrustExponent => {
+                let curve = &mut self.current_params.trust_inflation;
+                curve.exponent = (curve.exponent + 0.25 * step).min(MAX_TRUST_EXPONENT);
+            }
+            MutationType::DecreaseTrustExponent => {
+                let curve = &mut self.current_params.trust_inflation;
+                curve.exponent = (curve.exponent - 0.25 * step).max(0.0);
+            }
+            MutationType::IncreaseQuota => {
+                resize_quota(&mut self.current_params.gossip_quota, 1.25f64.powf(step));
+            }
+            MutationType::DecreaseQuota => {
+                resize_quota(&mut self.current_params.gossip_quota, 1.25f64.powf(-step));
+            }
+            MutationType::IncreaseTtl => {
+                step_ttl(&mut self.current_params.gossip_ttl, step.ceil() as i64);
+            }
+            MutationType::DecreaseTtl => {
+                step_ttl(&mut self.current_params.gossip_ttl, -(step.ceil() as i64));
+            }
+            MutationType::IncreaseDigestInterval => {
+                step_digest_interval(&mut self.current_params.digest_interval_ticks, step.ceil() as i64);
+            }
+            MutationType::DecreaseDigestInterval => {
+                step_digest_interval(&mut self.current_params.digest_interval_ticks, -(step.ceil() as i64));
+            }
         }
     }
     
@@ -423,7 +885,283 @@ impl EvolutionaryState {
         let bias_delta = rng.gen_range(-1.0..=1.0) * step;
         self.current_params.sensor_bias_estimate += bias_delta;
         
+        if self.priority_genes {
+            for index in 0..GossipPriority::WEIGHTS {
+                let weight = self.current_params.gossip_priority.weight_mut(index);
+                *weight = (*weight + rng.gen_range(-0.25..=0.25) * step).max(0.0);
+            }
+        }
+        
+        if self.trust_genes {
+            let curve = &mut self.current_params.trust_inflation;
+            curve.exponent = (curve.exponent + rng.gen_range(-0.25..=0.25) * step).clamp(0.0, MAX_TRUST_EXPONENT);
+        }
+        
+        if self.quota_genes {
+            resize_quota(&mut self.current_params.gossip_quota, 1.25f64.powf(rng.gen_range(-1.0..=1.0) * step));
+        }
+        
+        if self.ttl_genes {
+            step_ttl(&mut self.current_params.gossip_ttl, (rng.gen_range(-1..=1) as f64 * step).round() as i64);
+        }
+        
+        if self.digest_genes {
+            step_digest_interval(&mut self.current_params.digest_interval_ticks, (rng.gen_range(-2..=2) as f64 * step).round() as i64);
+        }
+        
         // Mark as multi-param (no single active_mutation)
         self.active_mutation = None;
     }
 }
+
+/// Scales a quota's capacity and refill rate by `factor`, keeping at least
+/// [`MIN_QUOTA_CAPACITY`] packets of capacity. No quota stays unlimited.
+fn resize_quota(quota: &mut Option<GossipQuota>, factor: f64) {
+    if let Some(quota) = quota {
+        let factor = factor.max(MIN_QUOTA_CAPACITY / quota.capacity);
+        quota.capacity *= factor;
+        quota.refill_per_sec *= factor;
+    }
+}
+
+/// Moves a TTL by `hops`, within 1..=[`MAX_GOSSIP_TTL`]. No TTL stays
+/// unlimited.
+fn step_ttl(ttl: &mut Option<u8>, hops: i64) {
+    if let Some(ttl) = ttl {
+        *ttl = (*ttl as i64 + hops).clamp(1, MAX_GOSSIP_TTL as i64) as u8;
+    }
+}
+
+/// Moves a digest interval by `ticks`, within
+/// 1..=[`MAX_DIGEST_INTERVAL_TICKS`]. Push-only gossip stays push-only.
+fn step_digest_interval(interval: &mut Option<u64>, ticks: i64) {
+    if let Some(interval) = interval {
+        *interval = (*interval as i64 + ticks).clamp(1, MAX_DIGEST_INTERVAL_TICKS as i64) as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::HashSet;
+    
+    #[test]
+    fn test_evolve_records_each_epoch() {
+        let mut state = EvolutionaryState::new();
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let fitness = OracleFitness::new();
+        
+        for epoch in 1..=8u64 {
+            // Error grows every epoch, so every single-gene mutation is reverted
+            state.record_accuracy(epoch as f64);
+            state.evolve(epoch * 20, &mut rng, &fitness);
+        }
+        
+        let history = state.history();
+        assert_eq!(history.len(), 8);
+        assert_eq!(history[0].tick, 20);
+        assert_eq!(history[0].params, EvoParams::default());
+        assert!(history[0].accepted, "the baseline epoch has no mutation to reject");
+        assert!(history.windows(2).all(|w| w[1].fitness < w[0].fitness));
+        assert!(history.iter().skip(1).any(|r| !r.accepted));
+    }
+    
+    #[test]
+    fn test_trust_genes_mutate_only_when_enabled() {
+        let mutate_all = |state: &mut EvolutionaryState| {
+            let mut rng = ChaCha8Rng::seed_from_u64(3);
+            for _ in 0..200 {
+                state.active_mutation = Some(state.pick_mutation(&mut rng));
+                state.apply_mutation();
+                state.apply_multi_mutation(&mut rng);
+            }
+        };
+        
+        let mut fixed = EvolutionaryState::new();
+        mutate_all(&mut fixed);
+        assert_eq!(fixed.current_params.trust_inflation, TrustInflation::OFF);
+        
+        let mut evolving = EvolutionaryState::with_params(EvoParams {
+            trust_inflation: TrustInflation::INVERSE,
+            ..EvoParams::default()
+        });
+        evolving.set_trust_genes(true);
+        mutate_all(&mut evolving);
+        let exponent = evolving.current_params.trust_inflation.exponent;
+        assert_ne!(exponent, 1.0);
+        assert!((0.0..=MAX_TRUST_EXPONENT).contains(&exponent));
+    }
+    
+    #[test]
+    fn test_quota_genes_resize_quota() {
+        let quota = GossipQuota::new(10.0, 20.0);
+        let mut evolving = EvolutionaryState::with_params(EvoParams { gossip_quota: Some(quota), ..EvoParams::default() });
+        evolving.set_quota_genes(true);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for _ in 0..200 {
+            evolving.active_mutation = Some(evolving.pick_mutation(&mut rng));
+            evolving.apply_mutation();
+            evolving.apply_multi_mutation(&mut rng);
+        }
+        let evolved = evolving.current_params.gossip_quota.unwrap();
+        assert_ne!(evolved, quota);
+        assert!(evolved.capacity >= MIN_QUOTA_CAPACITY);
+        assert!((evolved.refill_per_sec / evolved.capacity - 2.0).abs() < 1e-9, "capacity and refill scale together");
+        
+        // Quota genes without a quota leave intake unlimited
+        let mut unlimited = EvolutionaryState::new();
+        unlimited.set_quota_genes(true);
+        unlimited.active_mutation = Some(MutationType::IncreaseQuota);
+        unlimited.apply_mutation();
+        assert_eq!(unlimited.current_params.gossip_quota, None);
+    }
+    
+    #[test]
+    fn test_ttl_genes_step_within_bounds() {
+        let mut evolving = EvolutionaryState::with_params(EvoParams { gossip_ttl: Some(3), ..EvoParams::default() });
+        evolving.set_ttl_genes(true);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let mut seen = HashSet::new();
+        for _ in 0..200 {
+            evolving.active_mutation = Some(evolving.pick_mutation(&mut rng));
+            evolving.apply_mutation();
+            evolving.apply_multi_mutation(&mut rng);
+            seen.insert(evolving.current_params.gossip_ttl.unwrap());
+        }
+        assert!(seen.len() > 1, "TTL never moved");
+        assert!(seen.iter().all(|ttl| (1..=MAX_GOSSIP_TTL).contains(ttl)));
+        
+        // Without TTL genes, or without a TTL, it stays put
+        let mut fixed = EvolutionaryState::new();
+        for _ in 0..200 {
+            fixed.active_mutation = Some(fixed.pick_mutation(&mut rng));
+            fixed.apply_mutation();
+            fixed.apply_multi_mutation(&mut rng);
+        }
+        let mut unbounded = EvolutionaryState::with_params(EvoParams { gossip_ttl: None, ..EvoParams::default() });
+        unbounded.set_ttl_genes(true);
+        unbounded.active_mutation = Some(MutationType::IncreaseTtl);
+        unbounded.apply_mutation();
+        assert_eq!((fixed.current_params.gossip_ttl, unbounded.current_params.gossip_ttl), (Some(1), None));
+        
+        // Params saved before the TTL existed load as single-hop
+        let mut saved = serde_json::to_value(EvoParams::default()).unwrap();
+        saved.as_object_mut().unwrap().remove("gossip_ttl");
+        assert_eq!(serde_json::from_value::<EvoParams>(saved).unwrap().gossip_ttl, Some(1));
+    }
+    
+    #[test]
+    fn test_digest_genes_step_within_bounds() {
+        let mut evolving = EvolutionaryState::with_params(EvoParams { digest_interval_ticks: Some(2), ..EvoParams::default() });
+        evolving.set_digest_genes(true);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let mut seen = HashSet::new();
+        for _ in 0..200 {
+            evolving.active_mutation = Some(evolving.pick_mutation(&mut rng));
+            evolving.apply_mutation();
+            evolving.apply_multi_mutation(&mut rng);
+            seen.insert(evolving.current_params.digest_interval_ticks.unwrap());
+        }
+        assert!(seen.len() > 1, "digest interval never moved");
+        assert!(seen.iter().all(|ticks| (1..=MAX_DIGEST_INTERVAL_TICKS).contains(ticks)));
+        
+        // Push-only agents stay push-only, and older params load as push-only
+        let mut push = EvolutionaryState::new();
+        push.set_digest_genes(true);
+        push.active_mutation = Some(MutationType::DecreaseDigestInterval);
+        push.apply_mutation();
+        assert_eq!(push.current_params.digest_interval_ticks, None);
+        let mut saved = serde_json::to_value(EvoParams::default()).unwrap();
+        saved.as_object_mut().unwrap().remove("digest_interval_ticks");
+        assert_eq!(serde_json::from_value::<EvoParams>(saved).unwrap().digest_interval_ticks, None);
+    }
+    
+    #[test]
+    fn test_param_variance_window() {
+        let record = |interval: u64| EpochRecord {
+            tick: 0,
+            params: EvoParams { gossip_interval_ticks: interval, ..EvoParams::default() },
+            fitness: 0.0,
+            accepted: true,
+        };
+        
+        let steady: Vec<_> = [9, 1, 5, 5, 5, 5, 5].into_iter().map(record).collect();
+        assert_eq!(param_variance(&steady, 5), Some(0.0));
+        assert_eq!(param_variance(&steady[..4], 5), None);
+        
+        // Interval alternating 3/7 has variance 4 steps², averaged over 4 genes
+        let swinging: Vec<_> = [3, 7, 3, 7].into_iter().map(record).collect();
+        assert_eq!(param_variance(&swinging, 4), Some(1.0));
+        
+        let c = ParamConvergence::measure([&steady[..], &swinging[..], &steady[..3]]).unwrap();
+        assert_eq!(c.agents, 1, "only histories with a full window count");
+        assert_eq!(c.converged_agents, 1);
+        assert!(c.converged());
+    }    
+    #[test]
+    fn test_gt_weight_schedules() {
+        let linear: GtWeightSchedule = "linear:1,0,10".parse().unwrap();
+        assert_eq!(linear.weight(0), 1.0);
+        assert_eq!(linear.weight(5), 0.5);
+        assert_eq!(linear.weight(50), 0.0);
+        
+        let step: GtWeightSchedule = "step:0.8,0.2,3".parse().unwrap();
+        assert_eq!((step.weight(2), step.weight(3)), (0.8, 0.2));
+        
+        let exp: GtWeightSchedule = "exp:1,0,2".parse().unwrap();
+        assert_eq!(exp.weight(2), 0.5);
+        assert_eq!(exp.weight(4), 0.25);
+        
+        assert_eq!("linear".parse::<GtWeightSchedule>().unwrap(), GtWeightSchedule::DEFAULT);
+        for schedule in [linear, step, exp, GtWeightSchedule::DEFAULT] {
+            assert_eq!(schedule.to_string().parse::<GtWeightSchedule>().unwrap(), schedule);
+        }
+        for bad in ["linear:1,0", "step:1.5,0,3", "exp:1,0,0", "cosine:1,0,3", "linear:1,0,2.5"] {
+            assert!(bad.parse::<GtWeightSchedule>().is_err(), "{bad}");
+        }
+    }
+    
+    #[test]
+    fn test_hybrid_fitness_weighs_ground_truth_by_epoch() {
+        let hybrid = HybridFitness::new("linear:1,0,10".parse().unwrap());
+        let blind = BlindFitness::new().calculate_fitness(&FitnessContext::default());
+        let oracle = OracleFitness::new().calculate_fitness(&FitnessContext { avg_position_error: 2.0, ..Default::default() });
+        let at = |epoch, ground_truth_error| hybrid.calculate_fitness(&FitnessContext {
+            epoch,
+            ground_truth_error,
+            ..Default::default()
+        });
+        
+        assert_eq!(at(0, None), blind, "blind epochs score as BlindFitness");
+        assert_eq!(at(0, Some(2.0)), oracle);
+        assert_eq!(at(5, Some(2.0)), 0.5 * oracle + 0.5 * blind);
+        assert_eq!(at(10, Some(2.0)), blind);
+    }
+    
+    #[test]
+    fn test_evolve_passes_ground_truth_only_when_seen() {
+        struct Probe(std::sync::Mutex<Vec<(u64, Option<f64>)>>);
+        impl FitnessProvider for Probe {
+            fn calculate_fitness(&self, ctx: &FitnessContext) -> f64 {
+                self.0.lock().unwrap().push((ctx.epoch, ctx.ground_truth_error));
+                0.0
+            }
+            fn name(&self) -> &str {
+                "Probe"
+            }
+        }
+        
+        let probe = Probe(Default::default());
+        let mut state = EvolutionaryState::new();
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        state.record_metrics(Some(3.0), 0.0, 0.0, 1000.0, 0.0);
+        state.record_metrics(None, 0.0, 0.0, 1000.0, 0.0);
+        state.evolve(10, &mut rng, &probe);
+        state.record_metrics(None, 0.0, 0.0, 1000.0, 0.0);
+        state.evolve(20, &mut rng, &probe);
+        
+        assert_eq!(*probe.0.lock().unwrap(), [(0, Some(3.0)), (1, None)]);
+    }
+}
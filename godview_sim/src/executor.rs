@@ -0,0 +1,294 @@
+//! Deterministic single-threaded executor on a [`SimContext`]'s virtual clock.
+//!
+//! Code written against [`GodViewContext`] sleeps and spawns through its
+//! context. Once a [`SimExecutor`] is attached to a `SimContext` (and so to
+//! every clone of it), `sleep` parks the task on a virtual timer and `spawn`
+//! queues the task here instead of on tokio. [`SimExecutor::block_on`] polls
+//! woken tasks in the order they were woken and, when none are left, moves
+//! the clock to the earliest timer and wakes every task due then in the
+//! order they went to sleep. Nothing depends on wall-clock time or thread
+//! scheduling, so the interleaving is the same every run.
+//!
+//! Futures from tokio's sync primitives (`mpsc`, `Mutex`) only need a waker,
+//! so [`SimNetwork`](crate::SimNetwork) runs here unchanged. Tokio timers
+//! and `tokio::spawn` need a tokio runtime and don't belong in code meant
+//! to run under both environments.
+//!
+//! [`GodViewContext`]: godview_env::GodViewContext
+
+use crate::context::SimContext;
+use std::collections::{BTreeMap, VecDeque};
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+type TaskFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Task ID of the future passed to [`SimExecutor::block_on`].
+const MAIN_TASK: usize = usize::MAX;
+
+struct Task {
+    name: String,
+    future: TaskFuture,
+}
+
+/// Tasks, wake queue and timers, shared by a context and its executor.
+pub(crate) struct Scheduler {
+    /// The context's true virtual time (ns)
+    time_ns: Arc<Mutex<u64>>,
+    
+    /// Unfinished spawned tasks, by ID
+    tasks: Mutex<BTreeMap<usize, Task>>,
+    next_task: AtomicUsize,
+    
+    /// Woken task IDs, each at most once, in wake order
+    ready: Arc<Mutex<VecDeque<usize>>>,
+    
+    /// Sleeping tasks by (deadline ns, registration order)
+    timers: Mutex<BTreeMap<(u64, u64), Waker>>,
+    next_timer: AtomicU64,
+}
+
+impl Scheduler {
+    pub(crate) fn new(time_ns: Arc<Mutex<u64>>) -> Self {
+        Self {
+            time_ns,
+            tasks: Mutex::new(BTreeMap::new()),
+            next_task: AtomicUsize::new(0),
+            ready: Arc::new(Mutex::new(VecDeque::new())),
+            timers: Mutex::new(BTreeMap::new()),
+            next_timer: AtomicU64::new(0),
+        }
+    }
+    
+    /// Queues `future` to be polled on the next turn of the executor.
+    pub(crate) fn spawn(&self, name: &str, future: impl Future<Output = ()> + Send + 'static) {
+        let id = self.next_task.fetch_add(1, Ordering::Relaxed);
+        let future = Box::pin(tokio::task::unconstrained(future));
+        self.tasks.lock().unwrap().insert(id, Task { name: name.to_string(), future });
+        wake(&self.ready, id);
+    }
+    
+    /// A future that completes once the clock reaches `deadline_ns`.
+    pub(crate) fn sleep_until(self: &Arc<Self>, deadline_ns: u64) -> Sleep {
+        Sleep { scheduler: Arc::clone(self), deadline_ns, timer: None }
+    }
+    
+    fn now_ns(&self) -> u64 {
+        *self.time_ns.lock().unwrap()
+    }
+    
+    fn waker(&self, id: usize) -> Waker {
+        Waker::from(Arc::new(TaskWaker { id, ready: Arc::clone(&self.ready) }))
+    }
+    
+    /// Moves the clock to the earliest timer and wakes everything due then.
+    /// Returns false if no task is asleep.
+    fn fire_next_timers(&self) -> bool {
+        let mut timers = self.timers.lock().unwrap();
+        let Some(&(deadline_ns, _)) = timers.keys().next() else {
+            return false;
+        };
+        {
+            let mut now = self.time_ns.lock().unwrap();
+            *now = (*now).max(deadline_ns);
+        }
+        let mut due = Vec::new();
+        while let Some(timer) = timers.first_entry().filter(|t| t.key().0 <= deadline_ns) {
+            due.push(timer.remove());
+        }
+        drop(timers);
+        due.into_iter().for_each(Waker::wake);
+        true
+    }
+}
+
+fn wake(ready: &Mutex<VecDeque<usize>>, id: usize) {
+    let mut ready = ready.lock().unwrap();
+    if !ready.contains(&id) {
+        ready.push_back(id);
+    }
+}
+
+struct TaskWaker {
+    id: usize,
+    ready: Arc<Mutex<VecDeque<usize>>>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        wake(&self.ready, self.id);
+    }
+}
+
+/// Future returned by a context's `sleep` while an executor is attached.
+pub(crate) struct Sleep {
+    scheduler: Arc<Scheduler>,
+    deadline_ns: u64,
+    timer: Option<(u64, u64)>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+    
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if this.scheduler.now_ns() >= this.deadline_ns {
+            if let Some(timer) = this.timer.take() {
+                this.scheduler.timers.lock().unwrap().remove(&timer);
+            }
+            return Poll::Ready(());
+        }
+        let scheduler = &this.scheduler;
+        let timer = *this.timer.get_or_insert_with(|| {
+            (this.deadline_ns, scheduler.next_timer.fetch_add(1, Ordering::Relaxed))
+        });
+        scheduler.timers.lock().unwrap().insert(timer, cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            self.scheduler.timers.lock().unwrap().remove(&timer);
+        }
+    }
+}
+
+/// Runs tasks of a [`SimContext`] deterministically on its virtual clock.
+///
+/// Handles are cheap: every executor created for a context (or a clone of
+/// it) drives the same tasks and timers.
+pub struct SimExecutor {
+    scheduler: Arc<Scheduler>,
+}
+
+impl SimExecutor {
+    /// Attaches an executor to `context`: from now on its `sleep` and
+    /// `spawn`, and those of its clones, go through this executor.
+    pub fn new(context: &SimContext) -> Self {
+        Self { scheduler: context.attach_executor() }
+    }
+    
+    /// Queues `future` as a task, to be run by [`Self::block_on`].
+    pub fn spawn(&self, name: &str, future: impl Future<Output = ()> + Send + 'static) {
+        self.scheduler.spawn(name, future);
+    }
+    
+    /// Runs `future`, and every task, until `future` completes. Returns
+    /// None if it never can: no task is ready and none is asleep.
+    ///
+    /// Tasks still unfinished when `future` completes stay queued for the
+    /// next call. Works inside a tokio runtime too, blocking its thread.
+    pub fn block_on<F: Future>(&self, future: F) -> Option<F::Output> {
+        // Inside a tokio runtime, tokio's cooperative budget would hand
+        // wakeups to the runtime's scheduler, which isn't running meanwhile
+        let mut future = pin!(tokio::task::unconstrained(future));
+        let main_waker = self.scheduler.waker(MAIN_TASK);
+        wake(&self.scheduler.ready, MAIN_TASK);
+        loop {
+            let next = self.scheduler.ready.lock().unwrap().pop_front();
+            match next {
+                Some(MAIN_TASK) => {
+                    if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&main_waker)) {
+                        return Some(output);
+                    }
+                }
+                Some(id) => {
+                    let Some(mut task) = self.scheduler.tasks.lock().unwrap().remove(&id) else {
+                        continue;
+                    };
+                    let waker = self.scheduler.waker(id);
+                    if task.future.as_mut().poll(&mut Context::from_waker(&waker)).is_pending() {
+                        self.scheduler.tasks.lock().unwrap().insert(id, task);
+                    }
+                }
+                None => {
+                    if !self.scheduler.fire_next_timers() {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Names of spawned tasks that haven't finished, in spawn order.
+    pub fn pending_tasks(&self) -> Vec<String> {
+        self.scheduler.tasks.lock().unwrap().values().map(|t| t.name.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use godview_env::GodViewContext;
+    use std::time::Duration;
+    
+    #[test]
+    fn test_timers_fire_in_deadline_then_sleep_order() {
+        let ctx = SimContext::new(42);
+        let executor = SimExecutor::new(&ctx);
+        let log = Arc::new(Mutex::new(Vec::new()));
+        for (name, delay_ms) in [("slow", 30), ("a", 10), ("b", 10)] {
+            let (ctx, log) = (ctx.clone(), Arc::clone(&log));
+            ctx.clone().spawn(name, async move {
+                ctx.sleep(Duration::from_millis(delay_ms)).await;
+                log.lock().unwrap().push((name, ctx.now()));
+            });
+        }
+        
+        let ctx_main = ctx.clone();
+        let woke = executor.block_on(async move {
+            ctx_main.sleep(Duration::from_millis(20)).await;
+            ctx_main.now()
+        });
+        assert_eq!(woke, Some(Duration::from_millis(20)));
+        assert_eq!(executor.pending_tasks(), ["slow"]);
+        
+        assert_eq!(executor.block_on(ctx.sleep(Duration::from_millis(50))), Some(()));
+        assert_eq!(*log.lock().unwrap(), [
+            ("a", Duration::from_millis(10)),
+            ("b", Duration::from_millis(10)),
+            ("slow", Duration::from_millis(30)),
+        ]);
+        assert_eq!(ctx.now(), Duration::from_millis(70));
+        assert!(executor.pending_tasks().is_empty());
+    }
+    
+    #[test]
+    fn test_block_on_reports_a_stuck_future() {
+        let ctx = SimContext::new(42);
+        let executor = SimExecutor::new(&ctx);
+        let (_tx, mut rx) = tokio::sync::mpsc::channel::<u8>(1);
+        assert_eq!(executor.block_on(rx.recv()), None);
+        assert_eq!(ctx.now(), Duration::ZERO);
+    }
+    
+    #[test]
+    fn test_channels_keep_waking_inside_a_tokio_runtime() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let received = runtime.block_on(async {
+            let ctx = SimContext::new(42);
+            let executor = SimExecutor::new(&ctx);
+            let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+            let producer = ctx.clone();
+            ctx.spawn("producer", async move {
+                for i in 0..500u32 {
+                    producer.sleep(Duration::from_millis(1)).await;
+                    tx.send(i).await.unwrap();
+                }
+            });
+            executor.block_on(async move {
+                let mut received = 0;
+                while rx.recv().await.is_some() {
+                    received += 1;
+                }
+                received
+            })
+        });
+        assert_eq!(received, Some(500));
+    }
+}
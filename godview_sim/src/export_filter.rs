@@ -0,0 +1,354 @@
+//! Export filters: write only the part of a run being debugged.
+//!
+//! A FlashMob export holds every ground-truth entity and every track in
+//! every frame. An [`ExportFilter`] on [`SimExport::with_filter`] keeps
+//! only entities and tracks inside a [`RegionOfInterest`], only listed
+//! entity IDs and track IDs, only the top K tracks of each frame by a
+//! [`TrackRank`], and only every Nth frame offered. The filter is written
+//! to the export header under `filter`, so a script reading the file knows
+//! it is partial; per-agent `track_count` and `rms_error` still describe
+//! the whole agent.
+//!
+//! Decimation thins the frames the caller hands over, on top of however
+//! often it takes them (every 10 ticks for `--export`).
+//!
+//! [`SimExport::with_filter`]: crate::SimExport::with_filter
+
+use crate::exporter::{SimFrame, TrackPosition};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// A region of space to export.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "shape", rename_all = "snake_case")]
+pub enum RegionOfInterest {
+    /// Axis-aligned box, bounds inclusive
+    Aabb { min: [f64; 3], max: [f64; 3] },
+    
+    /// Ball of `radius` meters around `center`, boundary inclusive
+    Sphere { center: [f64; 3], radius: f64 },
+}
+
+impl RegionOfInterest {
+    /// True if `(x, y, z)` is inside the region.
+    pub fn contains(&self, x: f64, y: f64, z: f64) -> bool {
+        let point = [x, y, z];
+        match self {
+            RegionOfInterest::Aabb { min, max } => (0..3).all(|i| min[i] <= point[i] && point[i] <= max[i]),
+            RegionOfInterest::Sphere { center, radius } => {
+                (Vector3::from(point) - Vector3::from(*center)).norm() <= *radius
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for RegionOfInterest {
+    type Err = String;
+    
+    /// Parses `X,Y,Z,RADIUS` as a sphere or `MINX,MINY,MINZ,MAXX,MAXY,MAXZ`
+    /// as a box.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s.split(',')
+            .map(|v| v.trim().parse::<f64>().map_err(|e| format!("bad region value '{}': {}", v.trim(), e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.iter().any(|v| !v.is_finite()) {
+            return Err(format!("region values must be finite: {s}"));
+        }
+        match values[..] {
+            [x, y, z, radius] if radius >= 0.0 => Ok(RegionOfInterest::Sphere { center: [x, y, z], radius }),
+            [_, _, _, radius] => Err(format!("region radius must not be negative, got {radius}")),
+            [x0, y0, z0, x1, y1, z1] if x0 <= x1 && y0 <= y1 && z0 <= z1 => {
+                Ok(RegionOfInterest::Aabb { min: [x0, y0, z0], max: [x1, y1, z1] })
+            }
+            [_, _, _, _, _, _] => Err(format!("region box minimum exceeds its maximum: {s}")),
+            _ => Err(format!("region needs X,Y,Z,RADIUS or MINX,MINY,MINZ,MAXX,MAXY,MAXZ, got {} values", values.len())),
+        }
+    }
+}
+
+/// What ranks tracks for [`ExportFilter::with_top_k`], highest first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackRank {
+    /// [`TrackPosition::ghost_score`]: likeliest duplicates first
+    #[default]
+    GhostScore,
+    
+    /// [`TrackPosition::covariance_trace`]: least certain first
+    Covariance,
+}
+
+impl TrackRank {
+    /// Both ranks, ghost score first.
+    pub const ALL: [TrackRank; 2] = [TrackRank::GhostScore, TrackRank::Covariance];
+    
+    /// Name used on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TrackRank::GhostScore => "ghost",
+            TrackRank::Covariance => "covariance",
+        }
+    }
+    
+    /// The track's value under this rank, if the export recorded it.
+    pub fn value(&self, track: &TrackPosition) -> Option<f64> {
+        match self {
+            TrackRank::GhostScore => track.ghost_score,
+            TrackRank::Covariance => track.covariance_trace,
+        }
+    }
+}
+
+impl std::fmt::Display for TrackRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for TrackRank {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_lowercase();
+        Self::ALL.into_iter()
+            .find(|r| r.name() == name)
+            .ok_or_else(|| format!("Unknown track rank: {} (available: ghost, covariance)", s))
+    }
+}
+
+/// Keep only the top `k` tracks of each frame by `rank`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TopTracks {
+    /// Tracks kept per frame, over all agents together
+    pub k: usize,
+    
+    /// What orders them
+    pub rank: TrackRank,
+}
+
+/// Which entities, tracks and frames an export keeps.
+///
+/// Every filter set must pass: an entity or track outside the region is
+/// dropped even if its ID is listed. The default keeps everything.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportFilter {
+    /// Only entities and tracks inside this region
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<RegionOfInterest>,
+    
+    /// Only ground-truth entities with these IDs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_ids: Option<BTreeSet<u64>>,
+    
+    /// Only tracks with these IDs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track_ids: Option<BTreeSet<String>>,
+    
+    /// Only the top K tracks of each frame, after the filters above
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top_tracks: Option<TopTracks>,
+    
+    /// Keep one frame in this many offered, starting with the first
+    pub decimation: u64,
+}
+
+impl Default for ExportFilter {
+    fn default() -> Self {
+        Self {
+            region: None,
+            entity_ids: None,
+            track_ids: None,
+            top_tracks: None,
+            decimation: 1,
+        }
+    }
+}
+
+impl ExportFilter {
+    /// Keeps only entities and tracks inside `region`.
+    pub fn with_region(mut self, region: RegionOfInterest) -> Self {
+        self.region = Some(region);
+        self
+    }
+    
+    /// Keeps only ground-truth entities with these IDs.
+    pub fn with_entity_ids(mut self, ids: impl IntoIterator<Item = u64>) -> Self {
+        self.entity_ids = Some(ids.into_iter().collect());
+        self
+    }
+    
+    /// Keeps only tracks with these IDs.
+    pub fn with_track_ids(mut self, ids: impl IntoIterator<Item = String>) -> Self {
+        self.track_ids = Some(ids.into_iter().collect());
+        self
+    }
+    
+    /// Keeps only the `k` highest-ranked tracks of each frame.
+    pub fn with_top_k(mut self, k: usize, rank: TrackRank) -> Self {
+        self.top_tracks = Some(TopTracks { k, rank });
+        self
+    }
+    
+    /// Keeps one frame in `decimation` (at least 1).
+    pub fn with_decimation(mut self, decimation: u64) -> Self {
+        self.decimation = decimation.max(1);
+        self
+    }
+    
+    /// True if the filter keeps everything.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+    
+    /// True if the `index`th frame offered (from 0) is kept.
+    pub fn keeps_frame(&self, index: u64) -> bool {
+        index.is_multiple_of(self.decimation.max(1))
+    }
+    
+    /// True if the region contains `(x, y, z)`, or there is none.
+    fn in_region(&self, x: f64, y: f64, z: f64) -> bool {
+        self.region.as_ref().is_none_or(|region| region.contains(x, y, z))
+    }
+    
+    /// Drops the ground truth and tracks in `frame` the filter rejects.
+    ///
+    /// Top-K ranks all agents' remaining tracks together; tracks without
+    /// the ranked value come last, and ties go to the smaller track ID.
+    pub fn apply(&self, frame: &mut SimFrame) {
+        frame.ground_truth.retain(|e| {
+            self.in_region(e.x, e.y, e.z) && self.entity_ids.as_ref().is_none_or(|ids| ids.contains(&e.id))
+        });
+        for agent in &mut frame.agents {
+            agent.tracks.retain(|t| {
+                self.in_region(t.x, t.y, t.z) && self.track_ids.as_ref().is_none_or(|ids| ids.contains(&t.track_id))
+            });
+        }
+        
+        let Some(TopTracks { k, rank }) = self.top_tracks else {
+            return;
+        };
+        let mut ranked: Vec<(f64, &str, usize, usize)> = frame.agents.iter().enumerate()
+            .flat_map(|(a, agent)| agent.tracks.iter().enumerate()
+                .map(move |(t, track)| (rank.value(track).unwrap_or(f64::NEG_INFINITY), track.track_id.as_str(), a, t)))
+            .collect();
+        ranked.sort_by(|x, y| y.0.total_cmp(&x.0).then_with(|| x.1.cmp(y.1)));
+        let kept: BTreeSet<(usize, usize)> = ranked.iter().take(k).map(|&(_, _, a, t)| (a, t)).collect();
+        for (a, agent) in frame.agents.iter_mut().enumerate() {
+            let mut t = 0;
+            agent.tracks.retain(|_| {
+                t += 1;
+                kept.contains(&(a, t - 1))
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exporter::{AgentFrame, EntityPosition, ExportFormat, SimExport};
+    use uuid::Uuid;
+    
+    fn track(id: u128, x: f64, covariance_trace: f64, ghost_score: f64) -> TrackPosition {
+        TrackPosition::new(Uuid::from_u128(id), Vector3::new(x, 0.0, 10.0)).with_metrics(covariance_trace, ghost_score)
+    }
+    
+    fn frame(tick: u64) -> SimFrame {
+        SimFrame {
+            time_sec: tick as f64 / 30.0,
+            tick: Some(tick),
+            ground_truth: (0..6).map(|id| EntityPosition::new(id, Vector3::new(id as f64 * 20.0, 0.0, 10.0))).collect(),
+            agents: vec![
+                AgentFrame { agent_id: 0, tracks: vec![track(1, 0.0, 4.0, 0.1), track(2, 20.0, 9.0, 0.8), track(3, 60.0, 1.0, 0.9)], rms_error: Some(0.5), track_count: Some(3) },
+                AgentFrame { agent_id: 1, tracks: vec![track(4, 40.0, 2.0, 0.6), track(5, 25.0, 7.0, 0.2)], rms_error: None, track_count: Some(2) },
+            ],
+            events: Vec::new(),
+        }
+    }
+    
+    fn track_ids(frame: &SimFrame) -> Vec<Vec<u128>> {
+        frame.agents.iter()
+            .map(|a| a.tracks.iter().map(|t| Uuid::parse_str(&t.track_id).unwrap().as_u128()).collect())
+            .collect()
+    }
+    
+    #[test]
+    fn test_filters_keep_exactly_the_matching_subset() {
+        let region = RegionOfInterest::Aabb { min: [10.0, -1.0, 0.0], max: [45.0, 1.0, 20.0] };
+        let mut filtered = frame(0);
+        ExportFilter::default().with_region(region).apply(&mut filtered);
+        assert_eq!(filtered.ground_truth.iter().map(|e| e.id).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(track_ids(&filtered), [vec![2], vec![4, 5]]);
+        assert_eq!(filtered.agents[0].track_count, Some(3), "counts describe the whole agent");
+        
+        // Every filter must pass
+        let mut filtered = frame(0);
+        ExportFilter::default()
+            .with_region(RegionOfInterest::Sphere { center: [30.0, 0.0, 10.0], radius: 25.0 })
+            .with_entity_ids([0, 2, 5])
+            .with_track_ids([Uuid::from_u128(3), Uuid::from_u128(5)].map(|id| id.to_string()))
+            .apply(&mut filtered);
+        assert_eq!(filtered.ground_truth.iter().map(|e| e.id).collect::<Vec<_>>(), [2]);
+        assert_eq!(track_ids(&filtered), [vec![], vec![5]]);
+        
+        // Top K ranks all agents' tracks together
+        let mut filtered = frame(0);
+        ExportFilter::default().with_top_k(3, TrackRank::GhostScore).apply(&mut filtered);
+        assert_eq!(track_ids(&filtered), [vec![2, 3], vec![4]]);
+        let mut filtered = frame(0);
+        ExportFilter::default().with_region(region).with_top_k(2, TrackRank::Covariance).apply(&mut filtered);
+        assert_eq!(track_ids(&filtered), [vec![2], vec![5]]);
+        assert_eq!(filtered.ground_truth.len(), 2);
+        
+        // Tracks without the ranked value come last
+        let mut filtered = frame(0);
+        filtered.agents[1].tracks[0].ghost_score = None;
+        ExportFilter::default().with_top_k(4, TrackRank::GhostScore).apply(&mut filtered);
+        assert_eq!(track_ids(&filtered), [vec![1, 2, 3], vec![5]]);
+    }
+    
+    #[test]
+    fn test_filter_header_round_trips_and_frames_are_decimated() {
+        let filter = ExportFilter::default()
+            .with_region(RegionOfInterest::Sphere { center: [0.0, 0.0, 10.0], radius: 30.0 })
+            .with_entity_ids([0, 1])
+            .with_top_k(1, TrackRank::Covariance)
+            .with_decimation(3);
+        let mut export = SimExport::new("flash_mob", 42).with_filter(filter.clone());
+        for tick in (0..70).step_by(10) {
+            export.add_frame(frame(tick));
+        }
+        assert_eq!(export.frames.iter().map(|f| f.tick.unwrap()).collect::<Vec<_>>(), [0, 30, 60]);
+        assert_eq!(export.duration_sec, 2.0);
+        assert!(export.frames.iter().all(|f| f.ground_truth.len() == 2 && track_ids(f) == [vec![2], vec![]]));
+        
+        for format in ExportFormat::ALL {
+            let loaded = SimExport::from_bytes(&export.to_bytes(format).unwrap()).unwrap();
+            assert_eq!(loaded.filter.as_ref(), Some(&filter), "{format}");
+            assert_eq!(loaded.frames[1].agents[0].tracks[0].covariance_trace, Some(9.0));
+        }
+        let document = serde_json::to_value(&export).unwrap();
+        assert_eq!(document["filter"]["region"]["shape"], "sphere");
+        assert_eq!(document["filter"]["top_tracks"]["rank"], "covariance");
+        assert!(SimExport::json_schema()["properties"]["filter"]["properties"].get("top_tracks").is_some());
+        
+        // An empty filter writes no header
+        let unfiltered = SimExport::new("flash_mob", 42).with_filter(ExportFilter::default().with_decimation(0));
+        assert!(unfiltered.filter.is_none());
+        assert!(serde_json::to_value(&unfiltered).unwrap().get("filter").is_none());
+    }
+    
+    #[test]
+    fn test_cli_spellings_parse() {
+        assert_eq!("1,2,3,4".parse(), Ok(RegionOfInterest::Sphere { center: [1.0, 2.0, 3.0], radius: 4.0 }));
+        assert_eq!("0,0,0, 10,10,5".parse(), Ok(RegionOfInterest::Aabb { min: [0.0; 3], max: [10.0, 10.0, 5.0] }));
+        assert!("1,2,3".parse::<RegionOfInterest>().is_err());
+        assert!("0,0,0,-1".parse::<RegionOfInterest>().is_err());
+        assert!("5,0,0,0,10,10".parse::<RegionOfInterest>().is_err());
+        assert!("0,0,nan,1".parse::<RegionOfInterest>().is_err());
+        assert_eq!("Covariance".parse(), Ok(TrackRank::Covariance));
+        assert_eq!("ghost".parse(), Ok(TrackRank::GhostScore));
+        assert!("entropy".parse::<TrackRank>().is_err());
+    }
+}
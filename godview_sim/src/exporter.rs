@@ -1,11 +1,134 @@
-//! JSON exporter for Rerun visualization.
+//! JSON and MessagePack exporter for Rerun visualization.
 //!
-//! Exports simulation frames as JSON for the Python Rerun visualizer.
+//! Exports simulation frames for the Python Rerun visualizer, as JSON or,
+//! for large runs, as MessagePack with the same field names and content
+//! (see [`ExportFormat`]).
+//!
+//! Every export carries a `schema_version`. [`SimExport::load_auto`]
+//! reads any supported version in either format, migrating older layouts to
+//! the current one, and [`SimExport::json_schema`] describes the current
+//! layout for scripts that want to validate against it
+//! (`godview-sim --export-schema`).
+//!
+//! | Version | Layout |
+//! |---------|--------|
+//! | 1 | Frames of ground truth, agent tracks and events; pass/fail and final RMS (unversioned) |
+//! | 2 | Adds genealogy, observer divergence, evolution, per-agent track counts, frame ticks and the capture violation (unversioned) |
+//! | 3 | Adds `schema_version` |
+//! | 4 | Adds `interrupted` |
+//! | 5 | Adds the `filter` header (see [`ExportFilter`]) and per-track `covariance_trace` and `ghost_score` |
+//! | 6 | Adds per-track `coasting_ticks` |
+//! | 7 | Adds the failure capture's `gossip_decisions` |
+//! | 8 | Adds per-track `debug_truth_id` and `debug_error_m` |
+//! | 9 | Adds per-track `provenance` and `dominant_contributor` |
 
+use crate::adaptive::GossipDecisionRecord;
+use crate::capture::InvariantViolation;
+use crate::event_bus::SimEvent;
+use crate::evolution::EpochRecord;
+use crate::export_filter::ExportFilter;
+use crate::genealogy::GenealogyEvent;
+use crate::observer::{AgentDivergence, DivergenceSampler};
+use crate::scoring::TruthLabel;
+use godview_core::UniqueTrack;
 use nalgebra::Vector3;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use uuid::Uuid;
+
+/// Layout version written by this build.
+pub const SCHEMA_VERSION: u32 = 9;
+
+/// Layout versions [`SimExport::load_from_file`] can read.
+pub const SUPPORTED_SCHEMA_VERSIONS: [u32; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+/// Top-level keys that first appeared in version 2.
+const V2_KEYS: [&str; 5] = ["genealogy", "observer_rms", "observer_divergence", "evolution", "violation"];
+
+/// Why an export file couldn't be loaded.
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("failed to read export: {0}")]
+    Io(#[from] std::io::Error),
+    
+    #[error("malformed export: {0}")]
+    Json(#[from] serde_json::Error),
+    
+    #[error("malformed MessagePack export: {0}")]
+    MsgPack(#[from] rmp_serde::decode::Error),
+    
+    #[error("unsupported export schema_version {found} (supported: {})", supported_versions())]
+    UnsupportedVersion { found: Value },
+}
+
+fn supported_versions() -> String {
+    SUPPORTED_SCHEMA_VERSIONS.map(|v| v.to_string()).join(", ")
+}
+
+/// Serialization of an export file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Pretty-printed JSON
+    #[default]
+    Json,
+    
+    /// MessagePack with named fields: same keys and values as the JSON,
+    /// UUIDs included as strings
+    MsgPack,
+}
+
+impl ExportFormat {
+    /// Both formats, JSON first.
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Json, ExportFormat::MsgPack];
+    
+    /// Name used on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::MsgPack => "msgpack",
+        }
+    }
+    
+    /// The format a path's extension names: MessagePack for `.msgpack` or
+    /// `.mpk`, JSON otherwise.
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("msgpack") || ext.eq_ignore_ascii_case("mpk") => ExportFormat::MsgPack,
+            _ => ExportFormat::Json,
+        }
+    }
+    
+    /// The format of an export's bytes: JSON if the first non-whitespace
+    /// byte opens an object, MessagePack otherwise (an export is a map, so
+    /// its first MessagePack byte is never `{`).
+    pub fn sniff(bytes: &[u8]) -> Self {
+        match bytes.iter().find(|b| !b.is_ascii_whitespace()) {
+            Some(b'{') => ExportFormat::Json,
+            _ => ExportFormat::MsgPack,
+        }
+    }
+}
+
+impl std::fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for ExportFormat {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_lowercase();
+        Self::ALL.into_iter()
+            .find(|f| f.name() == name)
+            .ok_or_else(|| format!("Unknown export format: {} (available: json, msgpack)", s))
+    }
+}
 
 /// A single frame of simulation data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +136,10 @@ pub struct SimFrame {
     /// Simulation time in seconds
     pub time_sec: f64,
     
+    /// Loop iteration the frame was taken after, if recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    
     /// Ground truth entity positions
     pub ground_truth: Vec<EntityPosition>,
     
@@ -20,8 +147,62 @@ pub struct SimFrame {
     pub agents: Vec<AgentFrame>,
     
     /// Events (partitions, revocations, etc.)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub events: Vec<SimEvent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<FrameEvent>,
+}
+
+impl SimFrame {
+    /// Sorts ground truth by entity id, agents by agent id and each agent's
+    /// tracks by track id, so a frame's layout doesn't depend on hash map
+    /// iteration order.
+    pub fn sort_by_id(&mut self) {
+        self.ground_truth.sort_by_key(|e| e.id);
+        self.agents.sort_by_key(|a| a.agent_id);
+        for agent in &mut self.agents {
+            agent.tracks.sort_by(|a, b| a.track_id.cmp(&b.track_id));
+        }
+    }
+    
+    /// Digest of the frame's full state: the first 8 bytes of the SHA-256
+    /// of its JSON, sorted by id (see [`Self::sort_by_id`]). Equal frames
+    /// digest equally in any build.
+    pub fn state_digest(&self) -> u64 {
+        let mut sorted = self.clone();
+        sorted.sort_by_id();
+        let json = serde_json::to_vec(&sorted).expect("frames serialize");
+        let hash = Sha256::digest(&json);
+        u64::from_le_bytes(hash[..8].try_into().expect("SHA-256 is 32 bytes"))
+    }
+}
+
+/// First frame two runs' state differs at (see [`first_frame_divergence`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameDivergence {
+    /// Index of the frame
+    pub frame: usize,
+    
+    /// Its simulation time in each run (None: the run stops before it)
+    pub left_time_sec: Option<f64>,
+    pub right_time_sec: Option<f64>,
+}
+
+impl std::fmt::Display for FrameDivergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let time = |t: Option<f64>| t.map_or("missing".to_string(), |t| format!("t={:.3}s", t));
+        write!(f, "frame {} ({} vs {})", self.frame, time(self.left_time_sec), time(self.right_time_sec))
+    }
+}
+
+/// The first frame whose [`SimFrame::state_digest`]s differ between `left`
+/// and `right`, or that only one of them has (None: identical runs).
+pub fn first_frame_divergence(left: &[SimFrame], right: &[SimFrame]) -> Option<FrameDivergence> {
+    (0..left.len().max(right.len()))
+        .find(|&frame| left.get(frame).map(SimFrame::state_digest) != right.get(frame).map(SimFrame::state_digest))
+        .map(|frame| FrameDivergence {
+            frame,
+            left_time_sec: left.get(frame).map(|f| f.time_sec),
+            right_time_sec: right.get(frame).map(|f| f.time_sec),
+        })
 }
 
 /// Position of an entity.
@@ -51,6 +232,8 @@ pub struct AgentFrame {
     pub tracks: Vec<TrackPosition>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rms_error: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track_count: Option<usize>,
 }
 
 /// Track position.
@@ -60,19 +243,138 @@ pub struct TrackPosition {
     pub x: f64,
     pub y: f64,
     pub z: f64,
+    
+    /// Trace of the position covariance (m²), if recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub covariance_trace: Option<f64>,
+    
+    /// Ghost score in [0, 1] (see [`godview_core::calculate_ghost_score`]),
+    /// if recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ghost_score: Option<f64>,
+    
+    /// Ticks the track has coasted on prediction since it was last updated
+    /// (0 if updated this tick), if recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coasting_ticks: Option<u32>,
+    
+    /// Debug only: the ground-truth entity the track is matched to (see
+    /// [`TruthLabel`]), never seen by agents; None if unmatched or not
+    /// recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug_truth_id: Option<u64>,
+    
+    /// Debug only: distance to that entity, or to the nearest one if
+    /// unmatched (m), if recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug_error_m: Option<f64>,
+    
+    /// `(neighbor, information)` for each neighbor whose gossip added
+    /// information (covariance trace reduction, m²) to the track, by
+    /// neighbor (see [`UniqueTrack::provenance`]), if kept and any has
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Vec<(usize, f64)>>,
+    
+    /// The neighbor that added the most, if `provenance` is recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dominant_contributor: Option<usize>,
+}
+
+impl TrackPosition {
+    pub fn new(track_id: Uuid, pos: Vector3<f64>) -> Self {
+        Self {
+            track_id: track_id.to_string(),
+            x: pos.x,
+            y: pos.y,
+            z: pos.z,
+            covariance_trace: None,
+            ghost_score: None,
+            coasting_ticks: None,
+            debug_truth_id: None,
+            debug_error_m: None,
+            provenance: None,
+            dominant_contributor: None,
+        }
+    }
+    
+    /// Records the track's covariance trace and ghost score.
+    pub fn with_metrics(mut self, covariance_trace: f64, ghost_score: f64) -> Self {
+        self.covariance_trace = Some(covariance_trace);
+        self.ghost_score = Some(ghost_score);
+        self
+    }
+    
+    /// Records how many ticks the track has coasted (see
+    /// [`SimulatedAgent::track_states`](crate::SimulatedAgent::track_states)).
+    pub fn with_coasting_ticks(mut self, coasting_ticks: u32) -> Self {
+        self.coasting_ticks = Some(coasting_ticks);
+        self
+    }
+    
+    /// Records the ground-truth entity the track is tracking (debug only).
+    pub fn with_truth_label(mut self, label: TruthLabel) -> Self {
+        self.debug_truth_id = label.truth_id;
+        self.debug_error_m = label.error_m;
+        self
+    }
+    
+    /// Records which neighbors' gossip shaped the track, if it keeps
+    /// provenance and any has.
+    pub fn with_provenance(mut self, track: &UniqueTrack) -> Self {
+        if !track.provenance.is_empty() {
+            self.provenance = Some(track.provenance.iter().map(|(&neighbor, &information)| (neighbor, information)).collect());
+            self.dominant_contributor = track.dominant_contributor();
+        }
+        self
+    }
 }
 
-/// Simulation event.
+/// Event shown on a frame.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SimEvent {
+pub struct FrameEvent {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub level: Option<String>,
 }
 
+impl From<&SimEvent> for FrameEvent {
+    /// The event's message, at level "warn" or "info".
+    fn from(event: &SimEvent) -> Self {
+        let level = if event.is_warning() { "warn" } else { "info" };
+        Self { message: event.to_string(), level: Some(level.to_string()) }
+    }
+}
+
+/// Track genealogy recorded by one agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentGenealogy {
+    pub agent_id: u64,
+    pub events: Vec<GenealogyEvent>,
+}
+
+/// Most epochs exported per agent; longer histories are thinned evenly.
+pub const MAX_EXPORTED_EPOCHS: usize = 100;
+
+/// Evolution history recorded by one agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentEvolution {
+    pub agent_id: u64,
+    pub epochs: Vec<EpochRecord>,
+}
+
+/// Latest gossip acceptance decisions logged by one agent, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentGossipDecisions {
+    pub agent_id: u64,
+    pub decisions: Vec<GossipDecisionRecord>,
+}
+
 /// Complete simulation export.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimExport {
+    /// Layout version ([`SCHEMA_VERSION`] when written by this build)
+    pub schema_version: u32,
+    
     /// Scenario name
     pub scenario: String,
     
@@ -82,6 +384,11 @@ pub struct SimExport {
     /// Duration in seconds
     pub duration_sec: f64,
     
+    /// What the frames were filtered to, if anything: with a filter the
+    /// export is partial
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ExportFilter>,
+    
     /// All frames
     pub frames: Vec<SimFrame>,
     
@@ -91,38 +398,599 @@ pub struct SimExport {
     /// Final RMS error if applicable
     #[serde(skip_serializing_if = "Option::is_none")]
     pub final_rms_error: Option<f64>,
+    
+    /// The run was cut short by Ctrl-C; frames stop where it was
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub interrupted: bool,
+    
+    /// Per-agent track genealogy, if recorded
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub genealogy: Vec<AgentGenealogy>,
+    
+    /// Mean observer RMS error against ground truth, if an observer ran
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub observer_rms: Option<f64>,
+    
+    /// Per-agent divergence from the observer
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub observer_divergence: Vec<AgentDivergence>,
+    
+    /// Per-agent evolution history, downsampled to [`MAX_EXPORTED_EPOCHS`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evolution: Vec<AgentEvolution>,
+    
+    /// Invariant violation a failure capture was centered on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub violation: Option<InvariantViolation>,
+    
+    /// Each logging agent's latest gossip decisions at the end of a failure
+    /// capture
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gossip_decisions: Vec<AgentGossipDecisions>,
+    
+    /// Frames offered to [`Self::add_frame`], kept or not
+    #[serde(skip)]
+    frames_offered: u64,
 }
 
 impl SimExport {
     /// Creates a new export container.
     pub fn new(scenario: &str, seed: u64) -> Self {
         Self {
+            schema_version: SCHEMA_VERSION,
             scenario: scenario.to_string(),
             seed,
             duration_sec: 0.0,
+            filter: None,
             frames: Vec::new(),
             passed: false,
             final_rms_error: None,
+            interrupted: false,
+            genealogy: Vec::new(),
+            observer_rms: None,
+            observer_divergence: Vec::new(),
+            evolution: Vec::new(),
+            violation: None,
+            gossip_decisions: Vec::new(),
+            frames_offered: 0,
         }
     }
     
-    /// Adds a frame.
-    pub fn add_frame(&mut self, frame: SimFrame) {
+    /// Filters the frames added from now on, and records `filter` in the
+    /// header. A filter that keeps everything records nothing.
+    pub fn with_filter(mut self, filter: ExportFilter) -> Self {
+        self.filter = (!filter.is_empty()).then_some(filter);
+        self
+    }
+    
+    /// Adds a frame, sorted by id (see [`SimFrame::sort_by_id`]), unless
+    /// the filter decimates it away.
+    pub fn add_frame(&mut self, mut frame: SimFrame) {
         self.duration_sec = frame.time_sec;
+        let index = self.frames_offered;
+        self.frames_offered += 1;
+        if let Some(filter) = &self.filter {
+            if !filter.keeps_frame(index) {
+                return;
+            }
+            filter.apply(&mut frame);
+        }
+        frame.sort_by_id();
         self.frames.push(frame);
     }
     
+    /// Adds an agent's track genealogy (skipped if it recorded nothing).
+    pub fn add_genealogy(&mut self, agent_id: u64, events: &[GenealogyEvent]) {
+        if !events.is_empty() {
+            self.genealogy.push(AgentGenealogy { agent_id, events: events.to_vec() });
+        }
+    }
+    
+    /// Records observer error and per-agent divergence from a sampler.
+    pub fn add_divergence(&mut self, sampler: &DivergenceSampler) {
+        self.observer_rms = Some(sampler.observer_rms());
+        self.observer_divergence = sampler.divergence();
+    }
+    
+    /// Adds an agent's evolution history (skipped if it ran no epochs).
+    ///
+    /// Histories longer than [`MAX_EXPORTED_EPOCHS`] keep evenly spaced
+    /// epochs, always including the first and last.
+    pub fn add_evolution(&mut self, agent_id: u64, history: &[EpochRecord]) {
+        if history.is_empty() {
+            return;
+        }
+        let epochs = if history.len() <= MAX_EXPORTED_EPOCHS {
+            history.to_vec()
+        } else {
+            let last = history.len() - 1;
+            (0..MAX_EXPORTED_EPOCHS)
+                .map(|i| history[i * last / (MAX_EXPORTED_EPOCHS - 1)])
+                .collect()
+        };
+        self.evolution.push(AgentEvolution { agent_id, epochs });
+    }
+    
     /// Finalizes the export.
     pub fn finalize(&mut self, passed: bool, rms_error: Option<f64>) {
         self.passed = passed;
         self.final_rms_error = rms_error;
     }
     
-    /// Writes to a JSON file.
-    pub fn write_to_file(&self, path: &str) -> std::io::Result<()> {
-        let json = serde_json::to_string_pretty(self)?;
-        let mut file = File::create(path)?;
-        file.write_all(json.as_bytes())?;
-        Ok(())
+    /// Finalizes the export of a run stopped by Ctrl-C: failed, scored on
+    /// the frames it got through.
+    pub fn finalize_interrupted(&mut self, rms_error: Option<f64>) {
+        self.finalize(false, rms_error);
+        self.interrupted = true;
+    }
+    
+    /// Writes to a file in the format its extension names (see
+    /// [`ExportFormat::from_path`]), returning the bytes written.
+    pub fn write_to_file(&self, path: &str) -> std::io::Result<u64> {
+        self.write_as(path, ExportFormat::from_path(path))
+    }
+    
+    /// Writes to a file in `format`, returning the bytes written.
+    pub fn write_as(&self, path: &str, format: ExportFormat) -> std::io::Result<u64> {
+        let bytes = self.to_bytes(format)?;
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(&bytes)?;
+        file.flush()?;
+        Ok(bytes.len() as u64)
+    }
+    
+    /// Serializes in `format`.
+    pub fn to_bytes(&self, format: ExportFormat) -> std::io::Result<Vec<u8>> {
+        match format {
+            ExportFormat::Json => Ok(serde_json::to_vec_pretty(self)?),
+            ExportFormat::MsgPack => {
+                // Human-readable, so UUIDs stay strings as in the JSON
+                let mut bytes = Vec::new();
+                let mut serializer = rmp_serde::Serializer::new(&mut bytes).with_struct_map().with_human_readable();
+                self.serialize(&mut serializer).map_err(std::io::Error::other)?;
+                Ok(bytes)
+            }
+        }
+    }
+    
+    /// Reads a JSON export written by any supported version.
+    pub fn load_from_file(path: &str) -> Result<Self, ExportError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+    
+    /// Reads an export in either format, sniffing which from its contents
+    /// rather than its name.
+    pub fn load_auto(path: impl AsRef<Path>) -> Result<Self, ExportError> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+    
+    /// The first frame this export and `other` disagree on (see
+    /// [`first_frame_divergence`]), as `--compare` reports it.
+    pub fn first_divergence(&self, other: &SimExport) -> Option<FrameDivergence> {
+        first_frame_divergence(&self.frames, &other.frames)
+    }
+    
+    /// Parses an export in either format (see [`ExportFormat::sniff`]) of
+    /// any supported version, migrating it to [`SCHEMA_VERSION`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ExportError> {
+        let document: Value = match ExportFormat::sniff(bytes) {
+            ExportFormat::Json => serde_json::from_slice(bytes)?,
+            ExportFormat::MsgPack => Value::deserialize(&mut rmp_serde::Deserializer::new(bytes).with_human_readable())?,
+        };
+        Self::from_document(document)
+    }
+    
+    /// Parses an export of any supported version, migrating it to
+    /// [`SCHEMA_VERSION`].
+    pub fn from_json(json: &str) -> Result<Self, ExportError> {
+        Self::from_document(serde_json::from_str(json)?)
+    }
+    
+    /// Migrates a parsed export of any supported version.
+    fn from_document(mut document: Value) -> Result<Self, ExportError> {
+        let version = Self::detect_version(&document)?;
+        if version < 2 {
+            migrate_v1_to_v2(&mut document);
+        }
+        if version < SCHEMA_VERSION {
+            stamp_current_version(&mut document);
+        }
+        Ok(serde_json::from_value(document)?)
+    }
+    
+    /// The layout version of a parsed export.
+    ///
+    /// Unversioned documents predate version 3; they are version 2 if they
+    /// carry any field version 2 added, and version 1 otherwise.
+    pub fn detect_version(document: &Value) -> Result<u32, ExportError> {
+        match document.get("schema_version") {
+            Some(found) => found.as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .filter(|v| SUPPORTED_SCHEMA_VERSIONS.contains(v))
+                .ok_or_else(|| ExportError::UnsupportedVersion { found: found.clone() }),
+            None => {
+                let frames = document.get("frames").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+                let v2_frame = frames.iter().any(|frame| {
+                    frame.get("tick").is_some()
+                        || frame.get("agents").and_then(Value::as_array)
+                            .is_some_and(|agents| agents.iter().any(|a| a.get("track_count").is_some()))
+                });
+                let v2_key = V2_KEYS.iter().any(|key| document.get(key).is_some());
+                Ok(if v2_frame || v2_key { 2 } else { 1 })
+            }
+        }
+    }
+    
+    /// JSON Schema (draft 2020-12) of the layout this build writes.
+    pub fn json_schema() -> Value {
+        let number = json!({ "type": "number" });
+        let optional_number = json!({ "type": ["number", "null"] });
+        let uint = json!({ "type": "integer", "minimum": 0 });
+        // A `[neighbor, information]` pair of a track's provenance
+        let contribution = json!({ "type": "array", "prefixItems": [uint, number], "minItems": 2, "maxItems": 2 });
+        let uuid = json!({ "type": "string", "format": "uuid" });
+        let genealogy_event = |kind: &str, ids: [&str; 2]| {
+            let mut properties = serde_json::Map::new();
+            properties.insert("kind".into(), json!({ "const": kind }));
+            properties.insert("timestamp".into(), number.clone());
+            let mut required = vec!["kind", "timestamp"];
+            for id in ids.into_iter().filter(|id| !id.is_empty()) {
+                properties.insert(id.into(), uuid.clone());
+                required.push(id);
+            }
+            json!({ "type": "object", "properties": properties, "required": required })
+        };
+        let gossip_decision = json!({
+            "type": "object",
+            "required": ["time_secs", "neighbor_id", "entity_id", "decision"],
+            "properties": {
+                "time_secs": number,
+                "neighbor_id": uint,
+                "entity_id": uuid,
+                "decision": { "enum": [
+                    "accepted", "rejected_reputation", "rejected_confidence",
+                    "rejected_quota", "deduplicated", "rejected_stale",
+                ] },
+                "score": number,
+                "value": number,
+            },
+        });
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "SimExport",
+            "type": "object",
+            "required": ["schema_version", "scenario", "seed", "duration_sec", "frames", "passed"],
+            "properties": {
+                "schema_version": { "const": SCHEMA_VERSION },
+                "scenario": { "type": "string" },
+                "seed": uint,
+                "duration_sec": number,
+                "filter": {
+                    "type": "object",
+                    "required": ["decimation"],
+                    "properties": {
+                        "region": { "oneOf": [
+                            {
+                                "type": "object",
+                                "required": ["shape", "min", "max"],
+                                "properties": { "shape": { "const": "aabb" }, "min": { "$ref": "#/$defs/vec3" }, "max": { "$ref": "#/$defs/vec3" } },
+                            },
+                            {
+                                "type": "object",
+                                "required": ["shape", "center", "radius"],
+                                "properties": { "shape": { "const": "sphere" }, "center": { "$ref": "#/$defs/vec3" }, "radius": number },
+                            },
+                        ] },
+                        "entity_ids": { "type": "array", "items": uint },
+                        "track_ids": { "type": "array", "items": { "type": "string" } },
+                        "top_tracks": {
+                            "type": "object",
+                            "required": ["k", "rank"],
+                            "properties": { "k": uint, "rank": { "enum": ["ghost_score", "covariance"] } },
+                        },
+                        "decimation": { "type": "integer", "minimum": 1 },
+                    },
+                },
+                "frames": { "type": "array", "items": { "$ref": "#/$defs/frame" } },
+                "passed": { "type": "boolean" },
+                "final_rms_error": optional_number,
+                "interrupted": { "type": "boolean" },
+                "genealogy": { "type": "array", "items": {
+                    "type": "object",
+                    "required": ["agent_id", "events"],
+                    "properties": {
+                        "agent_id": uint,
+                        "events": { "type": "array", "items": { "oneOf": [
+                            genealogy_event("created", ["track_id", ""]),
+                            genealogy_event("merged", ["winner_id", "loser_id"]),
+                            genealogy_event("split", ["parent_id", "child_id"]),
+                            genealogy_event("deleted", ["track_id", ""]),
+                        ] } },
+                    },
+                } },
+                "observer_rms": optional_number,
+                "observer_divergence": { "type": "array", "items": {
+                    "type": "object",
+                    "required": ["agent_id", "compared", "position_rms", "id_disagreement_rate"],
+                    "properties": {
+                        "agent_id": uint,
+                        "compared": uint,
+                        "position_rms": number,
+                        "id_disagreement_rate": number,
+                    },
+                } },
+                "evolution": { "type": "array", "items": {
+                    "type": "object",
+                    "required": ["agent_id", "epochs"],
+                    "properties": {
+                        "agent_id": uint,
+                        "epochs": { "type": "array", "items": {
+                            "type": "object",
+                            "required": ["tick", "params", "fitness", "accepted"],
+                            "properties": {
+                                "tick": uint,
+                                "params": { "type": "object" },
+                                "fitness": number,
+                                "accepted": { "type": "boolean" },
+                            },
+                        } },
+                    },
+                } },
+                "violation": {
+                    "type": "object",
+                    "required": ["tick", "time_sec", "invariant", "details"],
+                    "properties": {
+                        "tick": uint,
+                        "time_sec": number,
+                        "invariant": { "type": "string" },
+                        "details": { "type": "string" },
+                    },
+                },
+                "gossip_decisions": { "type": "array", "items": {
+                    "type": "object",
+                    "required": ["agent_id", "decisions"],
+                    "properties": {
+                        "agent_id": uint,
+                        "decisions": { "type": "array", "items": gossip_decision },
+                    },
+                } },
+            },
+            "$defs": {
+                "vec3": { "type": "array", "items": number, "minItems": 3, "maxItems": 3 },
+                "position": {
+                    "type": "object",
+                    "required": ["x", "y", "z"],
+                    "properties": { "x": number, "y": number, "z": number },
+                },
+                "frame": {
+                    "type": "object",
+                    "required": ["time_sec", "ground_truth", "agents"],
+                    "properties": {
+                        "time_sec": number,
+                        "tick": uint,
+                        "ground_truth": { "type": "array", "items": {
+                            "allOf": [{ "$ref": "#/$defs/position" }],
+                            "required": ["id"],
+                            "properties": { "id": uint },
+                        } },
+                        "agents": { "type": "array", "items": {
+                            "type": "object",
+                            "required": ["agent_id", "tracks"],
+                            "properties": {
+                                "agent_id": uint,
+                                "tracks": { "type": "array", "items": {
+                                    "allOf": [{ "$ref": "#/$defs/position" }],
+                                    "required": ["track_id"],
+                                    "properties": {
+                                        "track_id": { "type": "string" },
+                                        "covariance_trace": number,
+                                        "ghost_score": number,
+                                        "coasting_ticks": uint,
+                                        "debug_truth_id": uint,
+                                        "debug_error_m": number,
+                                        "provenance": { "type": "array", "items": contribution },
+                                        "dominant_contributor": uint,
+                                    },
+                                } },
+                                "rms_error": optional_number,
+                                "track_count": uint,
+                            },
+                        } },
+                        "events": { "type": "array", "items": {
+                            "type": "object",
+                            "required": ["message"],
+                            "properties": {
+                                "message": { "type": "string" },
+                                "level": { "type": ["string", "null"] },
+                            },
+                        } },
+                    },
+                },
+            },
+        })
+    }
+}
+
+/// Fills the fields version 2 added with their empty defaults.
+fn migrate_v1_to_v2(document: &mut Value) {
+    let Some(object) = document.as_object_mut() else {
+        return;
+    };
+    for key in ["genealogy", "observer_divergence", "evolution"] {
+        object.entry(key).or_insert_with(|| json!([]));
+    }
+    object.entry("observer_rms").or_insert(Value::Null);
+}
+
+/// Stamps the current version; versions 3 to 9 only added fields that
+/// default when missing (the version itself, `interrupted`, `filter`, the
+/// track metrics and coasting ages, gossip decisions, truth labels and
+/// provenance).
+fn stamp_current_version(document: &mut Value) {
+    if let Some(object) = document.as_object_mut() {
+        object.insert("schema_version".into(), json!(SCHEMA_VERSION));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const V1_FIXTURE: &str = include_str!("../fixtures/export_v1.json");
+    const V2_FIXTURE: &str = include_str!("../fixtures/export_v2.json");
+    
+    #[test]
+    fn test_export_round_trips_at_current_version() {
+        let mut export = SimExport::new("swarm", 9);
+        export.add_frame(SimFrame {
+            time_sec: 0.5,
+            tick: Some(14),
+            ground_truth: vec![EntityPosition::new(1, Vector3::new(1.0, 2.0, 3.0))],
+            agents: vec![AgentFrame { agent_id: 0, tracks: Vec::new(), rms_error: Some(0.3), track_count: Some(0) }],
+            events: vec![FrameEvent { message: "heal".into(), level: None }],
+        });
+        export.add_genealogy(0, &[GenealogyEvent::Created { timestamp: 0.0, track_id: Uuid::from_u128(5) }]);
+        export.finalize(true, Some(0.3));
+        
+        let json = serde_json::to_string(&export).unwrap();
+        let loaded = SimExport::from_json(&json).unwrap();
+        assert_eq!(loaded.schema_version, SCHEMA_VERSION);
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+        
+        // Everything written is described by the schema
+        let schema = SimExport::json_schema();
+        let document: Value = serde_json::from_str(&json).unwrap();
+        for key in document.as_object().unwrap().keys() {
+            assert!(schema["properties"].get(key).is_some(), "{key} missing from schema");
+        }
+        for key in document["frames"][0].as_object().unwrap().keys() {
+            assert!(schema["$defs"]["frame"]["properties"].get(key).is_some(), "frame {key} missing from schema");
+        }
+    }
+    
+    #[test]
+    fn test_frames_are_written_sorted_by_id() {
+        let track = |id| TrackPosition::new(Uuid::from_u128(id), Vector3::zeros());
+        let agent = |agent_id, tracks: Vec<TrackPosition>| AgentFrame { agent_id, track_count: Some(tracks.len()), tracks, rms_error: None };
+        let mut export = SimExport::new("swarm", 1);
+        export.add_frame(SimFrame {
+            time_sec: 0.0,
+            tick: Some(0),
+            ground_truth: [3, 1, 2].map(|id| EntityPosition::new(id, Vector3::zeros())).to_vec(),
+            agents: vec![agent(1, vec![track(9), track(4)]), agent(0, vec![track(7), track(2), track(5)])],
+            events: Vec::new(),
+        });
+        
+        let frame = &export.frames[0];
+        assert_eq!(frame.ground_truth.iter().map(|e| e.id).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(frame.agents.iter().map(|a| a.agent_id).collect::<Vec<_>>(), [0, 1]);
+        let ids = |a: &AgentFrame| a.tracks.iter().map(|t| t.track_id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&frame.agents[0]), [2, 5, 7].map(|id| Uuid::from_u128(id).to_string()));
+        assert_eq!(ids(&frame.agents[1]), [4, 9].map(|id| Uuid::from_u128(id).to_string()));
+    }
+    
+    #[test]
+    fn test_divergence_names_the_first_differing_frame() {
+        let frame = |time_sec: f64, ids: [u64; 2]| SimFrame {
+            time_sec,
+            tick: None,
+            ground_truth: ids.map(|id| EntityPosition::new(id, Vector3::new(time_sec, 0.0, 0.0))).to_vec(),
+            agents: Vec::new(),
+            events: Vec::new(),
+        };
+        let run = [frame(0.0, [1, 2]), frame(0.1, [1, 2]), frame(0.2, [1, 2])];
+        
+        // Layout doesn't count, state does
+        let reordered = [frame(0.0, [2, 1]), frame(0.1, [2, 1]), frame(0.2, [1, 2])];
+        assert_eq!(first_frame_divergence(&run, &reordered), None);
+        let mut moved = run.clone();
+        moved[1].ground_truth[0].x += 1e-9;
+        assert_eq!(first_frame_divergence(&run, &moved).unwrap().frame, 1);
+        
+        let short = first_frame_divergence(&run, &run[..2]).unwrap();
+        assert_eq!(short, FrameDivergence { frame: 2, left_time_sec: Some(0.2), right_time_sec: None });
+        assert_eq!(short.to_string(), "frame 2 (t=0.200s vs missing)");
+    }
+    
+    #[test]
+    fn test_both_formats_round_trip_to_the_same_content() {
+        let mut export = SimExport::new("scale_limit", 3);
+        let mut track = TrackPosition::new(Uuid::from_u128(8), Vector3::new(-1.4, 2.1, 99.0)).with_metrics(0.7, 0.25).with_coasting_ticks(3)
+            .with_truth_label(TruthLabel { truth_id: Some(7), error_m: Some(0.17) });
+        track.provenance = Some(vec![(1, 0.4), (5, 1.25)]);
+        track.dominant_contributor = Some(5);
+        export.add_frame(SimFrame {
+            time_sec: 1.0,
+            tick: Some(9),
+            ground_truth: vec![EntityPosition::new(7, Vector3::new(-1.5, 2.0, 100.0))],
+            agents: vec![AgentFrame {
+                agent_id: 2,
+                tracks: vec![track],
+                rms_error: None,
+                track_count: Some(1),
+            }],
+            events: Vec::new(),
+        });
+        export.add_genealogy(2, &[GenealogyEvent::Merged { timestamp: 0.5, winner_id: Uuid::from_u128(8), loser_id: Uuid::from_u128(9) }]);
+        export.finalize_interrupted(Some(0.2));
+        let json = serde_json::to_string(&export).unwrap();
+        
+        let dir = std::env::temp_dir();
+        for format in ExportFormat::ALL {
+            let bytes = export.to_bytes(format).unwrap();
+            assert_eq!(ExportFormat::sniff(&bytes), format);
+            let loaded = SimExport::from_bytes(&bytes).unwrap();
+            assert_eq!(serde_json::to_string(&loaded).unwrap(), json, "{format}");
+            
+            // The extension picks the format on write; load_auto ignores it
+            let path = dir.join(format!("godview-export-{}.{}", std::process::id(), format.name()));
+            let path = path.to_str().unwrap();
+            assert_eq!(export.write_to_file(path).unwrap(), bytes.len() as u64);
+            let renamed = format!("{path}.bin");
+            std::fs::rename(path, &renamed).unwrap();
+            assert_eq!(serde_json::to_string(&SimExport::load_auto(&renamed).unwrap()).unwrap(), json);
+            std::fs::remove_file(renamed).unwrap();
+        }
+        
+        assert!(export.to_bytes(ExportFormat::MsgPack).unwrap().len() < export.to_bytes(ExportFormat::Json).unwrap().len());
+        assert_eq!(ExportFormat::from_path("run.MPK"), ExportFormat::MsgPack);
+        assert_eq!(ExportFormat::from_path("run.json"), ExportFormat::Json);
+        assert_eq!("MsgPack".parse::<ExportFormat>(), Ok(ExportFormat::MsgPack));
+        assert!("arrow".parse::<ExportFormat>().is_err());
+        assert!(matches!(SimExport::from_bytes(&[0xc1]), Err(ExportError::MsgPack(_))));
+    }
+    
+    #[test]
+    fn test_historical_layouts_migrate() {
+        let v1: Value = serde_json::from_str(V1_FIXTURE).unwrap();
+        assert_eq!(SimExport::detect_version(&v1).unwrap(), 1);
+        let export = SimExport::from_json(V1_FIXTURE).unwrap();
+        assert_eq!(export.schema_version, SCHEMA_VERSION);
+        assert_eq!((export.scenario.as_str(), export.seed, export.frames.len()), ("time_warp", 42, 2));
+        assert_eq!(export.frames[0].events.len(), 1);
+        assert_eq!(export.frames[1].agents[0].rms_error, None);
+        assert!(export.genealogy.is_empty() && export.observer_divergence.is_empty() && export.violation.is_none());
+        
+        let v2: Value = serde_json::from_str(V2_FIXTURE).unwrap();
+        assert_eq!(SimExport::detect_version(&v2).unwrap(), 2);
+        let export = SimExport::from_json(V2_FIXTURE).unwrap();
+        assert_eq!(export.frames[0].tick, Some(29));
+        assert_eq!(export.frames[0].agents[0].track_count, Some(1));
+        assert_eq!(export.genealogy[0].events.len(), 2);
+        assert_eq!(export.observer_rms, Some(0.4));
+        assert_eq!(export.violation.unwrap().invariant, "numerical_fault");
+        assert!(export.evolution.is_empty());
+    }
+    
+    #[test]
+    fn test_unsupported_version_lists_supported() {
+        let future = V1_FIXTURE.replacen('{', r#"{ "schema_version": 99,"#, 1);
+        let err = SimExport::from_json(&future).unwrap_err();
+        assert!(matches!(err, ExportError::UnsupportedVersion { .. }));
+        assert_eq!(err.to_string(), "unsupported export schema_version 99 (supported: 1, 2, 3, 4, 5, 6, 7, 8, 9)");
+        
+        let garbled = V1_FIXTURE.replacen('{', r#"{ "schema_version": "three","#, 1);
+        assert!(SimExport::from_json(&garbled).is_err());
+        assert!(matches!(SimExport::load_from_file("/nonexistent/export.json"), Err(ExportError::Io(_))));
     }
 }
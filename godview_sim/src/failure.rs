@@ -0,0 +1,251 @@
+//! Why a scenario run failed, as data.
+//!
+//! Every run that fails carries a [`FailureKind`] in
+//! [`ScenarioResult::failure`](crate::ScenarioResult::failure), and its
+//! display string is derived from it. The JSON and JUnit outputs and the
+//! seed corpus record the kind structurally (tagged by [`FailureKind::label`]),
+//! so tooling can match on it instead of parsing messages.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Which side of a threshold a metric must stay on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Bound {
+    /// The metric must reach the threshold
+    Min,
+    
+    /// The metric must stay under the threshold
+    Max,
+}
+
+impl Bound {
+    fn label(self) -> &'static str {
+        match self {
+            Bound::Min => "min",
+            Bound::Max => "max",
+        }
+    }
+}
+
+/// The first check a failed run missed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FailureKind {
+    /// Post-warm-up RMS error over the scenario's limit (the mean, or the
+    /// p95 over its multiple of it)
+    RmsExceeded {
+        mean: f64,
+        p95: f64,
+        threshold: f64,
+    },
+    
+    /// Agents' estimates of the same entity still spread too far apart
+    /// (coefficient of variation)
+    ConvergenceNotReached {
+        cv: f64,
+        threshold: f64,
+    },
+    
+    /// Too few bad actors were identified
+    DetectionRateTooLow {
+        rate: f64,
+        threshold: f64,
+    },
+    
+    /// A resource or throughput budget was broken
+    PerformanceBudget {
+        metric: String,
+        value: f64,
+        budget: f64,
+        bound: Bound,
+    },
+    
+    /// Something that must never happen did
+    InvariantViolated {
+        name: String,
+        tick: Option<u64>,
+        details: String,
+    },
+    
+    /// RMS went NaN or infinite
+    NumericalInstability {
+        faults: u64,
+        rms_mean: f64,
+        rms_p95: f64,
+    },
+    
+    /// A progress hook stopped the run early
+    Aborted {
+        tick: u64,
+        target_ticks: u64,
+        time_secs: f64,
+        rms: f64,
+    },
+    
+    /// A scenario-specific metric missed its threshold
+    ThresholdMissed {
+        metric: String,
+        value: f64,
+        threshold: f64,
+        bound: Bound,
+    },
+    
+    /// Something the scenario sets up to happen never did
+    ExpectationFailed {
+        check: String,
+        details: String,
+    },
+    
+    /// The run couldn't be simulated: the runner refused its configuration
+    /// or a subsystem failed (see
+    /// [`ScenarioError`](crate::ScenarioError))
+    RunError {
+        details: String,
+    },
+}
+
+impl FailureKind {
+    /// Every [`Self::label`], in declaration order.
+    pub const LABELS: [&'static str; 10] = [
+        "rms_exceeded",
+        "convergence_not_reached",
+        "detection_rate_too_low",
+        "performance_budget",
+        "invariant_violated",
+        "numerical_instability",
+        "aborted",
+        "threshold_missed",
+        "expectation_failed",
+        "run_error",
+    ];
+    
+    /// `metric` fell short of `threshold`.
+    pub fn below(metric: &str, value: f64, threshold: f64) -> Self {
+        FailureKind::ThresholdMissed { metric: metric.to_string(), value, threshold, bound: Bound::Min }
+    }
+    
+    /// `metric` went past the `threshold` it must stay under.
+    pub fn above(metric: &str, value: f64, threshold: f64) -> Self {
+        FailureKind::ThresholdMissed { metric: metric.to_string(), value, threshold, bound: Bound::Max }
+    }
+    
+    /// `metric` broke its `bound` on `budget`.
+    pub fn budget(metric: &str, value: f64, budget: f64, bound: Bound) -> Self {
+        FailureKind::PerformanceBudget { metric: metric.to_string(), value, budget, bound }
+    }
+    
+    /// `check` never happened, described by `details`.
+    pub fn expectation(check: &str, details: impl Into<String>) -> Self {
+        FailureKind::ExpectationFailed { check: check.to_string(), details: details.into() }
+    }
+    
+    /// Invariant `name` broke at an unknown tick, described by `details`.
+    pub fn invariant(name: &str, details: impl Into<String>) -> Self {
+        FailureKind::InvariantViolated { name: name.to_string(), tick: None, details: details.into() }
+    }
+    
+    /// Snake-case name of the variant, as tagged in JSON.
+    pub fn label(&self) -> &'static str {
+        let index = match self {
+            FailureKind::RmsExceeded { .. } => 0,
+            FailureKind::ConvergenceNotReached { .. } => 1,
+            FailureKind::DetectionRateTooLow { .. } => 2,
+            FailureKind::PerformanceBudget { .. } => 3,
+            FailureKind::InvariantViolated { .. } => 4,
+            FailureKind::NumericalInstability { .. } => 5,
+            FailureKind::Aborted { .. } => 6,
+            FailureKind::ThresholdMissed { .. } => 7,
+            FailureKind::ExpectationFailed { .. } => 8,
+            FailureKind::RunError { .. } => 9,
+        };
+        Self::LABELS[index]
+    }
+}
+
+impl fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FailureKind::RmsExceeded { mean, p95, threshold } => {
+                write!(f, "RMS {:.2}m (p95 {:.2}m) over {:.1}m", mean, p95, threshold)
+            }
+            FailureKind::ConvergenceNotReached { cv, threshold } => {
+                write!(f, "CV {:.1}% (max {:.1}%)", cv * 100.0, threshold * 100.0)
+            }
+            FailureKind::DetectionRateTooLow { rate, threshold } => {
+                write!(f, "Detection {:.0}% (min {:.0}%)", rate * 100.0, threshold * 100.0)
+            }
+            FailureKind::PerformanceBudget { metric, value, budget, bound } => {
+                let side = match bound {
+                    Bound::Min => "under",
+                    Bound::Max => "over",
+                };
+                write!(f, "{} {:.1} {} budget {:.1}", metric, value, side, budget)
+            }
+            FailureKind::InvariantViolated { tick: Some(tick), details, .. } => write!(f, "{} at tick {}", details, tick),
+            FailureKind::InvariantViolated { tick: None, details, .. } => f.write_str(details),
+            FailureKind::NumericalInstability { faults, rms_mean, rms_p95 } => write!(
+                f,
+                "Non-finite RMS (mean {}, p95 {}) after {} numerical faults",
+                rms_mean, rms_p95, faults,
+            ),
+            FailureKind::Aborted { tick, target_ticks, time_secs, rms } => write!(
+                f,
+                "Aborted at t={:.1}s (tick {}/{}): RMS {:.2}m",
+                time_secs, tick, target_ticks, rms,
+            ),
+            FailureKind::ThresholdMissed { metric, value, threshold, bound } => {
+                write!(f, "{} {:.3} ({} {})", metric, value, bound.label(), threshold)
+            }
+            FailureKind::ExpectationFailed { details, .. } => f.write_str(details),
+            FailureKind::RunError { details } => f.write_str(details),
+        }
+    }
+}
+
+/// The first failure among `checks`, in order.
+pub fn first_failure(checks: impl IntoIterator<Item = Option<FailureKind>>) -> Option<FailureKind> {
+    checks.into_iter().flatten().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_kinds_serialize_tagged_by_label() {
+        let kinds = [
+            FailureKind::RmsExceeded { mean: 6.2, p95: 9.0, threshold: 5.0 },
+            FailureKind::ConvergenceNotReached { cv: 0.4, threshold: 0.2 },
+            FailureKind::DetectionRateTooLow { rate: 0.1, threshold: 0.3 },
+            FailureKind::budget("ticks_per_sec", 4.2, 10.0, Bound::Min),
+            FailureKind::InvariantViolated { name: "partition_leak".to_string(), tick: Some(40), details: "3 envelopes crossed".to_string() },
+            FailureKind::NumericalInstability { faults: 3, rms_mean: f64::NAN, rms_p95: f64::NAN },
+            FailureKind::Aborted { tick: 30, target_ticks: 100, time_secs: 1.5, rms: 80.0 },
+            FailureKind::below("mota", 0.41, 0.6),
+            FailureKind::expectation("key_rotation", "Run ended before key rotation"),
+            FailureKind::RunError { details: "swarm: invalid configuration: empty grid".to_string() },
+        ];
+        for (kind, label) in kinds.iter().zip(FailureKind::LABELS) {
+            assert_eq!(kind.label(), label);
+            let json = serde_json::to_value(kind).unwrap();
+            assert_eq!(json["kind"], label);
+            if !matches!(kind, FailureKind::NumericalInstability { .. }) {
+                assert_eq!(&serde_json::from_value::<FailureKind>(json).unwrap(), kind);
+            }
+        }
+        
+        assert_eq!(kinds[0].to_string(), "RMS 6.20m (p95 9.00m) over 5.0m");
+        assert_eq!(kinds[3].to_string(), "ticks_per_sec 4.2 under budget 10.0");
+        assert_eq!(kinds[4].to_string(), "3 envelopes crossed at tick 40");
+        assert_eq!(kinds[7].to_string(), "mota 0.410 (min 0.6)");
+        assert_eq!(kinds[8].to_string(), "Run ended before key rotation");
+        assert_eq!(kinds[9].to_string(), "swarm: invalid configuration: empty grid");
+        assert_eq!(
+            first_failure([None, Some(kinds[1].clone()), Some(kinds[2].clone())]),
+            Some(kinds[1].clone()),
+        );
+        assert_eq!(first_failure([None, None]), None);
+    }
+}
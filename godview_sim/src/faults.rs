@@ -0,0 +1,499 @@
+//! Deterministic agent-local faults: crashes, stalls and amnesia, and
+//! perturbations of the agents' tracking parameters.
+//!
+//! A [`FaultPlan`] lists faults by tick. A [`Simulation`](crate::Simulation)
+//! built with [`with_fault_plan`](crate::Simulation::with_fault_plan) applies
+//! each fault at the start of its tick, and a [`RecoveryTracker`] measures how
+//! long the remaining swarm takes to regain its coverage of ground-truth
+//! entities afterwards. A [`PerturbationTracker`] compares RMS error and
+//! track counts in the window before each perturbation with the window after.
+
+use crate::agent::SimulatedAgent;
+use crate::stats;
+use godview_core::{TrackingConfig, TrackingError};
+use h3o::Resolution;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Distance within which a track covers a ground-truth entity (m).
+pub const COVERAGE_RADIUS_M: f64 = 5.0;
+
+/// Fraction of its pre-fault coverage the swarm must regain to have recovered.
+pub const RECOVERY_FRACTION: f64 = 0.95;
+
+/// A failure of one agent's process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Fault {
+    /// The agent stops ticking, sensing and gossiping for the rest of the run
+    Crash { at_tick: u64, agent: usize },
+    
+    /// The agent freezes for `for_ticks`, then resumes with the tick count
+    /// and tracks it had when it froze
+    Stall { at_tick: u64, agent: usize, for_ticks: u64 },
+    
+    /// The agent's tracks are wiped; its keys and reputations survive
+    Amnesia { at_tick: u64, agent: usize },
+}
+
+impl Fault {
+    /// Tick at whose start the fault is applied.
+    pub fn at_tick(&self) -> u64 {
+        match *self {
+            Fault::Crash { at_tick, .. } | Fault::Stall { at_tick, .. } | Fault::Amnesia { at_tick, .. } => at_tick,
+        }
+    }
+    
+    /// Index of the faulted agent.
+    pub fn agent(&self) -> usize {
+        match *self {
+            Fault::Crash { agent, .. } | Fault::Stall { agent, .. } | Fault::Amnesia { agent, .. } => agent,
+        }
+    }
+    
+    /// Tick recovery is measured from: the resume tick for a stall, the
+    /// fault tick otherwise.
+    pub fn effective_tick(&self) -> u64 {
+        match *self {
+            Fault::Stall { at_tick, for_ticks, .. } => at_tick + for_ticks,
+            _ => self.at_tick(),
+        }
+    }
+}
+
+impl std::fmt::Display for Fault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Fault::Crash { at_tick, agent } => write!(f, "crash of agent {agent} at tick {at_tick}"),
+            Fault::Stall { at_tick, agent, for_ticks } => {
+                write!(f, "stall of agent {agent} at tick {at_tick} for {for_ticks} ticks")
+            }
+            Fault::Amnesia { at_tick, agent } => write!(f, "amnesia of agent {agent} at tick {at_tick}"),
+        }
+    }
+}
+
+/// A change to the tracking parameters of every agent still running.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ConfigPerturbation {
+    /// Scales the Mahalanobis gating threshold
+    GatingThreshold { factor: f64 },
+    
+    /// Scales `max_age`, rounding to at least one cycle
+    MaxAge { factor: f64 },
+    
+    /// Moves the spatial index to another H3 resolution
+    H3Resolution { resolution: u8 },
+}
+
+impl ConfigPerturbation {
+    /// `config` with the change applied.
+    pub fn apply(&self, config: &TrackingConfig) -> Result<TrackingConfig, TrackingError> {
+        let mut changed = config.clone();
+        match *self {
+            ConfigPerturbation::GatingThreshold { factor } => changed.gating_threshold *= factor,
+            ConfigPerturbation::MaxAge { factor } => {
+                changed.max_age = (config.max_age as f64 * factor).round().max(1.0) as u32;
+            }
+            ConfigPerturbation::H3Resolution { resolution } => {
+                changed.h3_resolution = Resolution::try_from(resolution)
+                    .map_err(|_| TrackingError::InvalidConfig(format!("no H3 resolution {resolution}")))?;
+            }
+        }
+        Ok(changed)
+    }
+}
+
+impl std::fmt::Display for ConfigPerturbation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ConfigPerturbation::GatingThreshold { factor } => write!(f, "gating threshold x{factor}"),
+            ConfigPerturbation::MaxAge { factor } => write!(f, "max_age x{factor}"),
+            ConfigPerturbation::H3Resolution { resolution } => write!(f, "H3 resolution {resolution}"),
+        }
+    }
+}
+
+/// A [`ConfigPerturbation`] applied at the start of a tick.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Perturbation {
+    pub at_tick: u64,
+    pub change: ConfigPerturbation,
+}
+
+impl std::fmt::Display for Perturbation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at tick {}", self.change, self.at_tick)
+    }
+}
+
+/// Faults and perturbations to inject into a run, each in the order they
+/// were added.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FaultPlan {
+    faults: Vec<Fault>,
+    perturbations: Vec<Perturbation>,
+}
+
+impl FaultPlan {
+    /// Creates an empty plan.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Crashes `agent` at the start of `at_tick`.
+    pub fn with_crash(mut self, at_tick: u64, agent: usize) -> Self {
+        self.faults.push(Fault::Crash { at_tick, agent });
+        self
+    }
+    
+    /// Freezes `agent` from `at_tick` for `for_ticks` ticks.
+    pub fn with_stall(mut self, at_tick: u64, agent: usize, for_ticks: u64) -> Self {
+        self.faults.push(Fault::Stall { at_tick, agent, for_ticks });
+        self
+    }
+    
+    /// Wipes `agent`'s tracks at the start of `at_tick`.
+    pub fn with_amnesia(mut self, at_tick: u64, agent: usize) -> Self {
+        self.faults.push(Fault::Amnesia { at_tick, agent });
+        self
+    }
+    
+    /// Changes every running agent's tracking config at the start of `at_tick`.
+    pub fn with_perturbation(mut self, at_tick: u64, change: ConfigPerturbation) -> Self {
+        self.perturbations.push(Perturbation { at_tick, change });
+        self
+    }
+    
+    /// All planned faults.
+    pub fn faults(&self) -> &[Fault] {
+        &self.faults
+    }
+    
+    /// All planned perturbations.
+    pub fn perturbations(&self) -> &[Perturbation] {
+        &self.perturbations
+    }
+    
+    /// Returns true if nothing is planned.
+    pub fn is_empty(&self) -> bool {
+        self.faults.is_empty() && self.perturbations.is_empty()
+    }
+    
+    /// Faults applied at the start of `tick`.
+    pub fn starting_at(&self, tick: u64) -> impl Iterator<Item = &Fault> {
+        self.faults.iter().filter(move |f| f.at_tick() == tick)
+    }
+    
+    /// Perturbations applied at the start of `tick`.
+    pub fn perturbations_at(&self, tick: u64) -> impl Iterator<Item = &Perturbation> {
+        self.perturbations.iter().filter(move |p| p.at_tick == tick)
+    }
+}
+
+/// Whether an agent in a faulted run is doing work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AgentStatus {
+    /// Ticking, sensing and gossiping normally
+    #[default]
+    Running,
+    
+    /// Frozen until the start of `until_tick`
+    Stalled { until_tick: u64 },
+    
+    /// Down for the rest of the run
+    Crashed,
+}
+
+impl AgentStatus {
+    /// Returns true if the agent does work this tick.
+    pub fn is_running(&self) -> bool {
+        matches!(self, AgentStatus::Running)
+    }
+}
+
+/// Mean fraction of ground-truth entities each agent has a track within
+/// [`COVERAGE_RADIUS_M`] of (0.0 with no agents or entities).
+pub fn swarm_coverage<'a>(
+    agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+    ground_truth: &[(u64, Vector3<f64>)],
+) -> f64 {
+    if ground_truth.is_empty() {
+        return 0.0;
+    }
+    
+    let (sum, count) = agents.into_iter()
+        .map(|agent| {
+            let positions: Vec<Vector3<f64>> = agent.track_positions().into_iter().map(|(_, p)| p).collect();
+            let covered = ground_truth.iter()
+                .filter(|(_, truth)| positions.iter().any(|p| (p - truth).norm() <= COVERAGE_RADIUS_M))
+                .count();
+            covered as f64 / ground_truth.len() as f64
+        })
+        .fold((0.0, 0usize), |(sum, count), c| (sum + c, count + 1));
+    if count > 0 {
+        sum / count as f64
+    } else {
+        0.0
+    }
+}
+
+/// How the swarm's coverage came back after one fault.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FaultRecovery {
+    /// The fault
+    pub fault: Fault,
+    
+    /// Swarm coverage on the tick before the fault
+    pub coverage_before: f64,
+    
+    /// Lowest coverage seen between the fault and recovery
+    pub coverage_min: f64,
+    
+    /// Seconds from the fault taking effect (a stall's resume) until coverage
+    /// was back to [`RECOVERY_FRACTION`] of `coverage_before` (None if it never was)
+    pub recovery_secs: Option<f64>,
+}
+
+/// A fault still waiting for coverage to come back.
+#[derive(Debug, Clone, Copy)]
+struct PendingRecovery {
+    fault: Fault,
+    coverage_before: f64,
+    coverage_min: f64,
+    effective_secs: Option<f64>,
+}
+
+/// Follows swarm coverage tick by tick and times recovery from each fault.
+pub struct RecoveryTracker {
+    plan: FaultPlan,
+    last_coverage: Option<f64>,
+    pending: Vec<PendingRecovery>,
+    recovered: Vec<FaultRecovery>,
+}
+
+impl RecoveryTracker {
+    /// Creates a tracker for the faults in `plan`.
+    pub fn new(plan: &FaultPlan) -> Self {
+        Self {
+            plan: plan.clone(),
+            last_coverage: None,
+            pending: Vec::new(),
+            recovered: Vec::new(),
+        }
+    }
+    
+    /// Records swarm coverage after `tick`, which ended at `time_secs`.
+    ///
+    /// Call once per tick, including the ticks before the first fault.
+    pub fn observe(&mut self, tick: u64, time_secs: f64, coverage: f64) {
+        let before = self.last_coverage.unwrap_or(coverage);
+        for fault in self.plan.starting_at(tick) {
+            self.pending.push(PendingRecovery {
+                fault: *fault,
+                coverage_before: before,
+                coverage_min: coverage,
+                effective_secs: None,
+            });
+        }
+        self.last_coverage = Some(coverage);
+        
+        let mut still_pending = Vec::new();
+        for mut pending in self.pending.drain(..) {
+            pending.coverage_min = pending.coverage_min.min(coverage);
+            if tick < pending.fault.effective_tick() {
+                still_pending.push(pending);
+                continue;
+            }
+            let effective_secs = *pending.effective_secs.get_or_insert(time_secs);
+            if coverage >= RECOVERY_FRACTION * pending.coverage_before {
+                self.recovered.push(FaultRecovery {
+                    fault: pending.fault,
+                    coverage_before: pending.coverage_before,
+                    coverage_min: pending.coverage_min,
+                    recovery_secs: Some(time_secs - effective_secs),
+                });
+            } else {
+                still_pending.push(pending);
+            }
+        }
+        self.pending = still_pending;
+    }
+    
+    /// Recoveries in plan order. Faults that never recovered (or stalls that
+    /// never resumed) have no recovery time; faults whose tick never ran are
+    /// left out.
+    pub fn finish(self) -> Vec<FaultRecovery> {
+        let unrecovered = self.pending.into_iter().map(|p| FaultRecovery {
+            fault: p.fault,
+            coverage_before: p.coverage_before,
+            coverage_min: p.coverage_min,
+            recovery_secs: None,
+        });
+        let mut all: Vec<FaultRecovery> = self.recovered.into_iter().chain(unrecovered).collect();
+        let order = |fault: &Fault| self.plan.faults().iter().position(|f| f == fault);
+        all.sort_by_key(|r| order(&r.fault));
+        all
+    }
+}
+
+/// RMS error and track counts around one perturbation.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PerturbationImpact {
+    /// The perturbation
+    pub perturbation: Perturbation,
+    
+    /// Mean swarm RMS error (m) over the window before the perturbation
+    pub rms_before: f64,
+    
+    /// Mean swarm RMS error (m) over the window from the perturbation on
+    pub rms_after: f64,
+    
+    /// Mean live tracks per agent over the window before
+    pub tracks_before: f64,
+    
+    /// Mean live tracks per agent over the window from the perturbation on
+    pub tracks_after: f64,
+}
+
+impl PerturbationImpact {
+    /// How much the perturbation raised RMS error (m; negative if it fell).
+    pub fn rms_delta(&self) -> f64 {
+        self.rms_after - self.rms_before
+    }
+    
+    /// How many tracks per agent the perturbation added (negative if fewer).
+    pub fn track_delta(&self) -> f64 {
+        self.tracks_after - self.tracks_before
+    }
+}
+
+/// Follows swarm RMS and track counts tick by tick and compares the window
+/// before each planned perturbation with the window after.
+pub struct PerturbationTracker {
+    perturbations: Vec<Perturbation>,
+    window_ticks: u64,
+    
+    /// (tick, RMS if any agent was scored, tracks per agent)
+    samples: Vec<(u64, Option<f64>, f64)>,
+}
+
+impl PerturbationTracker {
+    /// Creates a tracker for the perturbations in `plan`, comparing
+    /// `window_ticks` ticks on either side.
+    pub fn new(plan: &FaultPlan, window_ticks: u64) -> Self {
+        Self {
+            perturbations: plan.perturbations().to_vec(),
+            window_ticks,
+            samples: Vec::new(),
+        }
+    }
+    
+    /// Records swarm RMS error and mean tracks per agent after `tick`.
+    ///
+    /// Call once per tick; a perturbation's own tick counts as after it.
+    pub fn observe(&mut self, tick: u64, rms: Option<f64>, tracks_per_agent: f64) {
+        self.samples.push((tick, rms, tracks_per_agent));
+    }
+    
+    /// Impacts in plan order. A window cut short by the start or end of the
+    /// run averages what it has; perturbations whose tick never ran are
+    /// left out.
+    pub fn finish(self) -> Vec<PerturbationImpact> {
+        let window = self.window_ticks;
+        let means = |ticks: std::ops::Range<u64>| {
+            let inside: Vec<_> = self.samples.iter().filter(|(t, _, _)| ticks.contains(t)).collect();
+            let rms: Vec<f64> = inside.iter().filter_map(|(_, rms, _)| *rms).collect();
+            let tracks: Vec<f64> = inside.iter().map(|(_, _, tracks)| *tracks).collect();
+            (stats::mean(&rms), stats::mean(&tracks))
+        };
+        self.perturbations.iter()
+            .filter(|p| self.samples.iter().any(|(t, _, _)| *t == p.at_tick))
+            .map(|&perturbation| {
+                let at = perturbation.at_tick;
+                let (rms_before, tracks_before) = means(at.saturating_sub(window)..at);
+                let (rms_after, tracks_after) = means(at..at + window);
+                PerturbationImpact { perturbation, rms_before, rms_after, tracks_before, tracks_after }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_recovery_is_timed_from_a_stall_resume() {
+        let plan = FaultPlan::new()
+            .with_amnesia(2, 0)
+            .with_stall(2, 1, 3);
+        let mut tracker = RecoveryTracker::new(&plan);
+        
+        let coverage = [1.0, 1.0, 0.5, 0.6, 0.7, 0.9, 0.96, 1.0];
+        for (tick, c) in coverage.iter().enumerate() {
+            tracker.observe(tick as u64, (tick + 1) as f64 * 0.1, *c);
+        }
+        
+        let recoveries = tracker.finish();
+        assert_eq!(recoveries.len(), 2);
+        
+        // Amnesia at tick 2 recovers at tick 6 (0.96 >= 0.95)
+        assert_eq!(recoveries[0].fault, plan.faults()[0]);
+        assert_eq!(recoveries[0].coverage_before, 1.0);
+        assert_eq!(recoveries[0].coverage_min, 0.5);
+        assert!((recoveries[0].recovery_secs.unwrap() - 0.4).abs() < 1e-9);
+        
+        // The stall resumes at tick 5, one tick before coverage is back
+        assert!((recoveries[1].recovery_secs.unwrap() - 0.1).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_perturbation_impact_compares_the_windows_around_it() {
+        let plan = FaultPlan::new()
+            .with_perturbation(4, ConfigPerturbation::GatingThreshold { factor: 0.5 })
+            .with_perturbation(50, ConfigPerturbation::MaxAge { factor: 2.0 });
+        let mut tracker = PerturbationTracker::new(&plan, 3);
+        
+        // RMS steps from 1 to 3 and tracks from 10 to 7 at tick 4
+        for tick in 0..10u64 {
+            let after = tick >= 4;
+            let rms = (tick != 2).then_some(if after { 3.0 } else { 1.0 });
+            tracker.observe(tick, rms, if after { 7.0 } else { 10.0 });
+        }
+        
+        let impacts = tracker.finish();
+        assert_eq!(impacts.len(), 1, "tick 50 never ran");
+        assert_eq!(impacts[0].perturbation, plan.perturbations()[0]);
+        assert_eq!((impacts[0].rms_before, impacts[0].rms_after), (1.0, 3.0));
+        assert_eq!(impacts[0].rms_delta(), 2.0);
+        assert_eq!(impacts[0].track_delta(), -3.0);
+    }
+    
+    #[test]
+    fn test_config_perturbations_scale_from_the_live_config() {
+        let config = TrackingConfig { max_age: 3, ..TrackingConfig::default() };
+        let halved = ConfigPerturbation::GatingThreshold { factor: 0.5 }.apply(&config).unwrap();
+        assert_eq!(halved.gating_threshold, config.gating_threshold / 2.0);
+        assert_eq!(ConfigPerturbation::MaxAge { factor: 0.1 }.apply(&config).unwrap().max_age, 1);
+        assert_eq!(ConfigPerturbation::MaxAge { factor: 2.0 }.apply(&halved).unwrap().max_age, 6);
+        let coarse = ConfigPerturbation::H3Resolution { resolution: 8 }.apply(&config).unwrap();
+        assert_eq!(coarse.h3_resolution, Resolution::Eight);
+        assert!(ConfigPerturbation::H3Resolution { resolution: 16 }.apply(&config).is_err());
+    }
+    
+    #[test]
+    fn test_unrecovered_and_untriggered_faults_have_no_recovery_time() {
+        let plan = FaultPlan::new()
+            .with_crash(1, 0)
+            .with_crash(10, 1);
+        let mut tracker = RecoveryTracker::new(&plan);
+        for (tick, c) in [0.8, 0.4, 0.5].iter().enumerate() {
+            tracker.observe(tick as u64, tick as f64, *c);
+        }
+        
+        let recoveries = tracker.finish();
+        assert_eq!(recoveries.len(), 1, "tick 10 never ran");
+        assert_eq!(recoveries[0].coverage_before, 0.8);
+        assert_eq!(recoveries[0].recovery_secs, None);
+    }
+}
@@ -0,0 +1,186 @@
+//! Sensors that only see part of the field at a time.
+//!
+//! A [`ScanningSensor`] registered with the Oracle
+//! ([`Oracle::register_sensor_with_scan`]) has a heading and a horizontal
+//! field of view, and turns at a fixed rate as the Oracle steps (a rotating
+//! radar; a rate of zero is a fixed camera). Its agent only gets readings of
+//! entities inside the instantaneous frustum
+//! ([`Oracle::generate_readings_for_agent`]), so any one agent sees an entity
+//! only part of the time. An [`ObservationSampler`] scores how much of the
+//! time each entity was seen by anyone ([`ObservationCoverage`]).
+//!
+//! [`Oracle::register_sensor_with_scan`]: crate::Oracle::register_sensor_with_scan
+//! [`Oracle::generate_readings_for_agent`]: crate::Oracle::generate_readings_for_agent
+
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Where a sensor sits and which way it faces.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SensorPose {
+    /// Sensor position in the oracle frame (m)
+    pub position: Vector3<f64>,
+    
+    /// Facing, in degrees counterclockwise from east (+x)
+    pub heading_deg: f64,
+}
+
+impl SensorPose {
+    /// A sensor at `position` facing `heading_deg`.
+    pub fn new(position: Vector3<f64>, heading_deg: f64) -> Self {
+        Self { position, heading_deg }
+    }
+}
+
+/// A sensor with a limited horizontal field of view that turns at a fixed
+/// rate.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScanningSensor {
+    /// Current position and heading
+    pub pose: SensorPose,
+    
+    /// Full horizontal field of view (degrees); 360 or more sees all round
+    pub fov_deg: f64,
+    
+    /// Turn rate (degrees per second, positive counterclockwise)
+    pub scan_rate_dps: f64,
+}
+
+impl ScanningSensor {
+    /// Turns the sensor through `dt` seconds of scanning, keeping its
+    /// heading in [0, 360).
+    pub fn advance(&mut self, dt: f64) {
+        self.pose.heading_deg = (self.pose.heading_deg + self.scan_rate_dps * dt).rem_euclid(360.0);
+    }
+    
+    /// True if `target` is inside the field of view right now. Only bearing
+    /// counts: elevation and range are unlimited, and a target directly
+    /// above or below the sensor is always in view.
+    pub fn in_view(&self, target: &Vector3<f64>) -> bool {
+        if self.fov_deg >= 360.0 {
+            return true;
+        }
+        let offset = target - self.pose.position;
+        if offset.x.hypot(offset.y) < 1e-9 {
+            return true;
+        }
+        let bearing = offset.y.atan2(offset.x).to_degrees();
+        let off_axis = (bearing - self.pose.heading_deg + 180.0).rem_euclid(360.0) - 180.0;
+        off_axis.abs() <= self.fov_deg / 2.0
+    }
+}
+
+/// How continuously a swarm's sensors saw each entity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObservationCoverage {
+    /// Entities that were alive during at least one sampled tick
+    pub entities: usize,
+    
+    /// Mean over entities of the fraction of their ticks at least one
+    /// agent had a reading of them
+    pub mean_fraction: f64,
+    
+    /// Lowest such fraction of any entity
+    pub min_fraction: f64,
+}
+
+/// Counts, per entity, the ticks it was alive and the ticks some agent
+/// observed it.
+#[derive(Debug, Clone, Default)]
+pub struct ObservationSampler {
+    /// (ticks alive, ticks observed) by entity ID
+    ticks: BTreeMap<u64, (u64, u64)>,
+}
+
+impl ObservationSampler {
+    /// Creates an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Records one tick: `alive` entities existed and `observed` of them
+    /// were read by at least one agent. Observations of entities not alive
+    /// (clutter) are ignored.
+    pub fn observe(&mut self, alive: impl IntoIterator<Item = u64>, observed: &BTreeSet<u64>) {
+        for id in alive {
+            let (ticks, seen) = self.ticks.entry(id).or_insert((0, 0));
+            *ticks += 1;
+            *seen += observed.contains(&id) as u64;
+        }
+    }
+    
+    /// Returns the coverage so far.
+    pub fn coverage(&self) -> ObservationCoverage {
+        let fractions: Vec<f64> = self.ticks.values()
+            .map(|&(ticks, seen)| seen as f64 / ticks as f64)
+            .collect();
+        if fractions.is_empty() {
+            return ObservationCoverage::default();
+        }
+        ObservationCoverage {
+            entities: fractions.len(),
+            mean_fraction: fractions.iter().sum::<f64>() / fractions.len() as f64,
+            min_fraction: fractions.iter().copied().fold(1.0, f64::min),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::{Oracle, DEFAULT_SENSOR_ID};
+    
+    #[test]
+    fn test_frustum_bounds_bearing() {
+        let sensor = ScanningSensor { pose: SensorPose::new(Vector3::zeros(), 350.0), fov_deg: 60.0, scan_rate_dps: 0.0 };
+        assert!(sensor.in_view(&Vector3::new(100.0, 0.0, 50.0)));
+        assert!(sensor.in_view(&Vector3::new(100.0, 30.0, 0.0)), "19° off axis, across 0°");
+        assert!(!sensor.in_view(&Vector3::new(100.0, 100.0, 0.0)));
+        assert!(!sensor.in_view(&Vector3::new(-100.0, 0.0, 0.0)));
+        assert!(sensor.in_view(&Vector3::new(0.0, 0.0, 100.0)), "straight up");
+        
+        let mut turning = ScanningSensor { scan_rate_dps: -90.0, ..sensor };
+        turning.advance(5.0);
+        assert_eq!(turning.pose.heading_deg, 260.0);
+    }
+    
+    #[test]
+    fn test_scanning_sensor_sweeps_onto_a_hidden_entity() {
+        let mut oracle = Oracle::new(42);
+        let behind = oracle.spawn_entity(Vector3::new(-100.0, 0.0, 20.0), Vector3::zeros(), "target");
+        oracle.register_sensor_with_scan(3, SensorPose::new(Vector3::zeros(), 0.0), 60.0, 90.0);
+        assert!(oracle.generate_readings_for_agent(3, DEFAULT_SENSOR_ID).is_empty());
+        
+        // The frustum's leading edge reaches 180° after (180 - 30) / 90 s
+        let dt = 1.0 / 30.0;
+        let first_seen = (1..=120u32)
+            .find(|_| {
+                oracle.step(dt);
+                !oracle.generate_readings_for_agent(3, DEFAULT_SENSOR_ID).is_empty()
+            })
+            .map(|tick| tick as f64 * dt)
+            .expect("never swept past the entity");
+        assert!((first_seen - 150.0 / 90.0).abs() <= dt, "first seen at {first_seen:.2}s");
+        assert_eq!(oracle.generate_readings_for_agent(3, DEFAULT_SENSOR_ID)[0].entity_id, behind);
+        
+        // Agents without a scanner see nothing through this call
+        assert!(oracle.generate_readings_for_agent(0, DEFAULT_SENSOR_ID).is_empty());
+    }
+    
+    #[test]
+    fn test_coverage_is_per_entity_fraction_of_ticks() {
+        let mut sampler = ObservationSampler::new();
+        assert_eq!(sampler.coverage(), ObservationCoverage::default());
+        
+        sampler.observe([1, 2], &BTreeSet::from([1, 99]));
+        sampler.observe([1, 2], &BTreeSet::from([1]));
+        sampler.observe([1, 2, 3], &BTreeSet::from([2, 3]));
+        sampler.observe([1, 2, 3], &BTreeSet::new());
+        
+        let coverage = sampler.coverage();
+        assert_eq!(coverage.entities, 3);
+        assert_eq!(coverage.min_fraction, 0.25);
+        assert!((coverage.mean_fraction - (0.5 + 0.25 + 0.5) / 3.0).abs() < 1e-12);
+    }
+}
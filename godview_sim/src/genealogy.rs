@@ -0,0 +1,182 @@
+//! Track genealogy recording for post-run analysis.
+//!
+//! The TrackManager doesn't emit lifecycle events, so the recorder infers
+//! them from what it observes after each packet and tick: a new canonical ID
+//! is a creation, a new UUID joining a track is a Highlander merge, a UUID
+//! that was absorbed earlier reappearing as its own track is a split, and a
+//! canonical ID that disappears after ageing is a deletion.
+
+use godview_core::TrackManager;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+
+/// A single track lifecycle event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GenealogyEvent {
+    /// A new track was created
+    Created { timestamp: f64, track_id: Uuid },
+    
+    /// Two track IDs collapsed into one (winner is the minimum UUID)
+    Merged { timestamp: f64, winner_id: Uuid, loser_id: Uuid },
+    
+    /// A previously merged ID started a track of its own again
+    Split { timestamp: f64, parent_id: Uuid, child_id: Uuid },
+    
+    /// A track was removed by ageing
+    Deleted { timestamp: f64, track_id: Uuid },
+}
+
+impl GenealogyEvent {
+    /// Simulation time of the event.
+    pub fn timestamp(&self) -> f64 {
+        match self {
+            Self::Created { timestamp, .. }
+            | Self::Merged { timestamp, .. }
+            | Self::Split { timestamp, .. }
+            | Self::Deleted { timestamp, .. } => *timestamp,
+        }
+    }
+}
+
+/// Records the genealogy of every track an agent maintains.
+#[derive(Debug, Clone, Default)]
+pub struct TrackGenealogy {
+    /// Events in the order they were observed
+    events: Vec<GenealogyEvent>,
+    
+    /// Canonical IDs of live tracks (ordered so deletions are deterministic)
+    live: BTreeSet<Uuid>,
+    
+    /// Absorbed ID -> the canonical ID that absorbed it
+    absorbed_by: HashMap<Uuid, Uuid>,
+}
+
+impl TrackGenealogy {
+    /// Creates an empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Records the outcome of processing a packet for `packet_id` that
+    /// resolved to the track `canonical_id`.
+    pub fn observe_packet(&mut self, tracks: &TrackManager, packet_id: Uuid, canonical_id: Uuid, timestamp: f64) {
+        let Some(track) = tracks.get_track(&canonical_id) else {
+            return;
+        };
+        
+        if !self.live.contains(&canonical_id) {
+            // Either a brand new track, or an existing one re-keyed to a smaller UUID
+            let previous = track.observed_ids.iter()
+                .filter(|id| **id != canonical_id && self.live.contains(id))
+                .min()
+                .copied();
+            
+            match previous {
+                Some(old_id) => {
+                    self.live.remove(&old_id);
+                    self.absorbed_by.insert(old_id, canonical_id);
+                    self.events.push(GenealogyEvent::Merged { timestamp, winner_id: canonical_id, loser_id: old_id });
+                }
+                None => match self.absorbed_by.remove(&canonical_id) {
+                    Some(parent_id) if self.live.contains(&parent_id) => {
+                        self.events.push(GenealogyEvent::Split { timestamp, parent_id, child_id: canonical_id });
+                    }
+                    _ => self.events.push(GenealogyEvent::Created { timestamp, track_id: canonical_id }),
+                },
+            }
+            self.live.insert(canonical_id);
+            return;
+        }
+        
+        if packet_id != canonical_id && self.resolve(packet_id) != canonical_id {
+            self.absorbed_by.insert(packet_id, canonical_id);
+            self.events.push(GenealogyEvent::Merged { timestamp, winner_id: canonical_id, loser_id: packet_id });
+        }
+    }
+    
+    /// Follows the absorption chain from `id` to the ID that now represents it.
+    fn resolve(&self, mut id: Uuid) -> Uuid {
+        // Each hop moves to a strictly smaller UUID, so the chain terminates
+        while let Some(&next) = self.absorbed_by.get(&id) {
+            if next >= id {
+                break;
+            }
+            id = next;
+        }
+        id
+    }
+    
+    /// Records deletions of tracks that are no longer present.
+    pub fn observe_tick(&mut self, tracks: &TrackManager, timestamp: f64) {
+        let gone: Vec<Uuid> = self.live.iter()
+            .filter(|id| tracks.get_track(id).is_none())
+            .copied()
+            .collect();
+        
+        for track_id in gone {
+            self.live.remove(&track_id);
+            self.events.push(GenealogyEvent::Deleted { timestamp, track_id });
+        }
+    }
+    
+    /// Returns all recorded events.
+    pub fn events(&self) -> &[GenealogyEvent] {
+        &self.events
+    }
+    
+    /// Number of merge events recorded.
+    pub fn merge_count(&self) -> usize {
+        self.events.iter().filter(|e| matches!(e, GenealogyEvent::Merged { .. })).count()
+    }
+    
+    /// Estimated footprint in bytes of the recorded events and ID maps.
+    pub fn memory_estimate_bytes(&self) -> usize {
+        let uuid = std::mem::size_of::<Uuid>();
+        std::mem::size_of::<Self>()
+            + self.events.len() * std::mem::size_of::<GenealogyEvent>()
+            + self.live.len() * uuid
+            + self.absorbed_by.len() * 2 * uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use godview_core::{GlobalHazardPacket, MeasurementModel};
+    
+    fn packet(id: u128) -> GlobalHazardPacket {
+        GlobalHazardPacket {
+            entity_id: Uuid::from_u128(id),
+            position: [37.7749, -122.4194, 10.0],
+            velocity: [1.0, 0.0, 0.0],
+            class_id: 1,
+            timestamp: 0.0,
+            confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        }
+    }
+    
+    #[test]
+    fn test_rekey_chain_counts_each_merge_once() {
+        let mut tracks = TrackManager::with_defaults();
+        let mut genealogy = TrackGenealogy::new();
+        
+        // 5 creates, 9 joins 5, 2 re-keys the track, then 9 and 5 repeat
+        for (t, id) in [5u128, 9, 2, 9, 5].into_iter().enumerate() {
+            let p = packet(id);
+            let canonical = tracks.process_packet(&p, None, None).unwrap();
+            genealogy.observe_packet(&tracks, p.entity_id, canonical, t as f64);
+        }
+        
+        let u = Uuid::from_u128;
+        assert_eq!(genealogy.events(), &[
+            GenealogyEvent::Created { timestamp: 0.0, track_id: u(5) },
+            GenealogyEvent::Merged { timestamp: 1.0, winner_id: u(5), loser_id: u(9) },
+            GenealogyEvent::Merged { timestamp: 2.0, winner_id: u(2), loser_id: u(5) },
+        ]);
+        assert_eq!(genealogy.merge_count(), 2);
+    }
+}
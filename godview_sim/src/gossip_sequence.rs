@@ -0,0 +1,465 @@
+//! Per-sender gossip sequence numbers, gap detection and retransmission.
+//!
+//! Every envelope an agent sends, its own or relayed, is stamped with the
+//! next number of a u32 counter ([`SignedPacketEnvelope::sequence`]). The
+//! number belongs to the hop, so a relay stamps its own. Each receiver keeps
+//! a [`SequenceTracker`] per neighbor: the highest sequence heard, and the
+//! holes below it. A hole is a gap, reported as a [`SequenceRange`].
+//!
+//! Sequences compare by serial number arithmetic: one less than 2^31 ahead
+//! of the highest heard is newer, so the counter wraps past u32::MAX without
+//! a reset. Trackers unwrap them to u64 internally.
+//!
+//! With [`Retransmission`] on, a receiver NACKs its gaps to the neighbor
+//! ([`GossipNack`]), which resends what is still in its bounded
+//! [`RetransmitBuffer`]. A gap NACKed `max_nacks` times without being
+//! filled is abandoned, so a neighbor that can't answer doesn't hold its
+//! receivers' gap counts up forever.
+
+use godview_env::SignedPacketEnvelope;
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+
+/// Most missing sequences a tracker holds per neighbor; past this the
+/// oldest gaps are abandoned.
+pub const MAX_TRACKED_GAP: u64 = 4096;
+
+/// Wire bytes of one NACKed range: a u32 start and a u32 length.
+pub const SEQUENCE_RANGE_BYTES: usize = 8;
+
+/// Default envelopes a sender keeps for retransmission.
+pub const DEFAULT_RETRANSMIT_BUFFER: usize = 64;
+
+/// Default NACKs a receiver sends for a gap before abandoning it.
+pub const DEFAULT_MAX_NACKS: u32 = 3;
+
+/// A run of consecutive sequence numbers, possibly wrapping past u32::MAX.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SequenceRange {
+    /// First sequence in the range
+    pub start: u32,
+    
+    /// Number of sequences (at least 1)
+    pub len: u32,
+}
+
+impl SequenceRange {
+    /// Last sequence in the range.
+    pub fn last(&self) -> u32 {
+        self.start.wrapping_add(self.len.saturating_sub(1))
+    }
+    
+    /// True if `sequence` falls in the range.
+    pub fn contains(&self, sequence: u32) -> bool {
+        sequence.wrapping_sub(self.start) < self.len
+    }
+    
+    /// Sequences in the range, in order.
+    pub fn iter(&self) -> impl Iterator<Item = u32> {
+        let start = self.start;
+        (0..self.len).map(move |i| start.wrapping_add(i))
+    }
+}
+
+/// What recording an envelope's sequence number found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceOutcome {
+    /// The next sequence, or the first heard from the neighbor
+    InOrder,
+    
+    /// Jumped ahead, opening a gap of `missing` sequences
+    Gap { missing: u64 },
+    
+    /// Filled a sequence of an earlier gap
+    Repaired,
+    
+    /// Older than the first sequence heard; not tracked
+    Late,
+    
+    /// Already received, or abandoned
+    Duplicate,
+}
+
+/// Gap counters of one tracker, or summed over several.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SequenceStats {
+    /// Sequenced envelopes recorded, duplicates excluded
+    pub received: u64,
+    
+    /// Missing sequences found when a later one arrived
+    pub gaps_detected: u64,
+    
+    /// Missing sequences that arrived after all
+    pub gaps_repaired: u64,
+    
+    /// Missing sequences given up on: NACKed too often, or pushed out by
+    /// [`MAX_TRACKED_GAP`]
+    pub gaps_abandoned: u64,
+    
+    /// Envelopes whose sequence was already received or abandoned
+    pub duplicates: u64,
+}
+
+impl SequenceStats {
+    /// Adds `other`'s counters to these.
+    pub fn merge(&mut self, other: &SequenceStats) {
+        self.received += other.received;
+        self.gaps_detected += other.gaps_detected;
+        self.gaps_repaired += other.gaps_repaired;
+        self.gaps_abandoned += other.gaps_abandoned;
+        self.duplicates += other.duplicates;
+    }
+    
+    /// Missing sequences neither repaired nor abandoned.
+    pub fn outstanding(&self) -> u64 {
+        self.gaps_detected - self.gaps_repaired - self.gaps_abandoned
+    }
+}
+
+/// A hole below the highest sequence heard: unwrapped sequences up to (not
+/// including) `end`, and how often it has been NACKed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hole {
+    end: u64,
+    nacks: u32,
+}
+
+/// What one receiver has heard of one neighbor's sequence numbers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SequenceTracker {
+    /// Unwrapped first sequence heard, and the highest
+    first: Option<u64>,
+    highest: u64,
+    
+    /// Holes below `highest`, by unwrapped start
+    holes: BTreeMap<u64, Hole>,
+    
+    /// Total length of `holes`
+    missing: u64,
+    
+    stats: SequenceStats,
+}
+
+impl SequenceTracker {
+    /// Creates a tracker that has heard nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Unwraps `sequence` to the u64 nearest the highest heard, or None if
+    /// that would be negative.
+    fn unwrap(&self, sequence: u32) -> Option<u64> {
+        let delta = sequence.wrapping_sub(self.highest as u32) as i32 as i64;
+        u64::try_from(self.highest as i64 + delta).ok()
+    }
+    
+    /// Records an envelope's sequence number.
+    pub fn record(&mut self, sequence: u32) -> SequenceOutcome {
+        let Some(first) = self.first else {
+            // Start well clear of zero so sequences just before the first
+            // one still unwrap
+            let start = (1u64 << 32) + sequence as u64;
+            self.first = Some(start);
+            self.highest = start;
+            self.stats.received += 1;
+            return SequenceOutcome::InOrder;
+        };
+        let Some(seq) = self.unwrap(sequence).filter(|&seq| seq >= first) else {
+            return SequenceOutcome::Late;
+        };
+        
+        if seq > self.highest {
+            let missing = seq - self.highest - 1;
+            if missing > 0 {
+                self.holes.insert(self.highest + 1, Hole { end: seq, nacks: 0 });
+                self.missing += missing;
+                self.stats.gaps_detected += missing;
+                self.trim();
+            }
+            self.highest = seq;
+            self.stats.received += 1;
+            return if missing > 0 { SequenceOutcome::Gap { missing } } else { SequenceOutcome::InOrder };
+        }
+        
+        let hole = self.holes.range(..=seq).next_back()
+            .filter(|(_, hole)| seq < hole.end)
+            .map(|(&start, &hole)| (start, hole));
+        let Some((start, hole)) = hole else {
+            self.stats.duplicates += 1;
+            return SequenceOutcome::Duplicate;
+        };
+        self.holes.remove(&start);
+        if start < seq {
+            self.holes.insert(start, Hole { end: seq, ..hole });
+        }
+        if seq + 1 < hole.end {
+            self.holes.insert(seq + 1, hole);
+        }
+        self.missing -= 1;
+        self.stats.received += 1;
+        self.stats.gaps_repaired += 1;
+        SequenceOutcome::Repaired
+    }
+    
+    /// Abandons the oldest holes until at most [`MAX_TRACKED_GAP`]
+    /// sequences are missing.
+    fn trim(&mut self) {
+        while self.missing > MAX_TRACKED_GAP {
+            let Some((start, hole)) = self.holes.pop_first() else {
+                break;
+            };
+            let drop = (hole.end - start).min(self.missing - MAX_TRACKED_GAP);
+            if start + drop < hole.end {
+                self.holes.insert(start + drop, hole);
+            }
+            self.missing -= drop;
+            self.stats.gaps_abandoned += drop;
+        }
+    }
+    
+    /// Number of sequences missing below the highest heard.
+    pub fn gap_count(&self) -> u64 {
+        self.missing
+    }
+    
+    /// The missing sequences, oldest first.
+    pub fn missing_ranges(&self) -> Vec<SequenceRange> {
+        self.holes.iter()
+            .map(|(&start, hole)| SequenceRange { start: start as u32, len: (hole.end - start) as u32 })
+            .collect()
+    }
+    
+    /// Highest sequence heard, if any.
+    pub fn highest(&self) -> Option<u32> {
+        self.first.map(|_| self.highest as u32)
+    }
+    
+    /// Gap counters.
+    pub fn stats(&self) -> SequenceStats {
+        self.stats
+    }
+    
+    /// Bytes held, holes included.
+    pub fn memory_bytes(&self) -> usize {
+        size_of::<Self>() + self.holes.len() * (size_of::<u64>() + size_of::<Hole>())
+    }
+    
+    /// The gaps to NACK now. Each gap counts one NACK; gaps already NACKed
+    /// `max_nacks` times are abandoned instead.
+    pub fn nack(&mut self, max_nacks: u32) -> Vec<SequenceRange> {
+        let mut ranges = Vec::new();
+        let mut abandoned = Vec::new();
+        for (&start, hole) in self.holes.iter_mut() {
+            if hole.nacks >= max_nacks {
+                abandoned.push(start);
+            } else {
+                hole.nacks += 1;
+                ranges.push(SequenceRange { start: start as u32, len: (hole.end - start) as u32 });
+            }
+        }
+        for start in abandoned {
+            if let Some(hole) = self.holes.remove(&start) {
+                self.missing -= hole.end - start;
+                self.stats.gaps_abandoned += hole.end - start;
+            }
+        }
+        ranges
+    }
+}
+
+/// A receiver's request to resend the sequences it is missing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct GossipNack {
+    /// Missing sequences, oldest first
+    pub ranges: Vec<SequenceRange>,
+}
+
+impl GossipNack {
+    /// Number of sequences asked for.
+    pub fn sequence_count(&self) -> u64 {
+        self.ranges.iter().map(|r| r.len as u64).sum()
+    }
+    
+    /// Bytes the NACK takes on the wire.
+    pub fn wire_bytes(&self) -> usize {
+        self.ranges.len() * SEQUENCE_RANGE_BYTES
+    }
+}
+
+/// Whether agents NACK their gaps, and how much senders keep to answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Retransmission {
+    /// Most recent envelopes each sender keeps for resending
+    pub buffer_envelopes: usize,
+    
+    /// NACKs a receiver sends for a gap before abandoning it
+    pub max_nacks: u32,
+}
+
+impl Retransmission {
+    /// Keeps `buffer_envelopes` envelopes, with [`DEFAULT_MAX_NACKS`].
+    pub fn new(buffer_envelopes: usize) -> Self {
+        Self { buffer_envelopes, max_nacks: DEFAULT_MAX_NACKS }
+    }
+    
+    /// NACKs each gap at most `max_nacks` times.
+    pub fn with_max_nacks(mut self, max_nacks: u32) -> Self {
+        self.max_nacks = max_nacks;
+        self
+    }
+}
+
+impl Default for Retransmission {
+    fn default() -> Self {
+        Self::new(DEFAULT_RETRANSMIT_BUFFER)
+    }
+}
+
+/// A sender's most recent sequenced envelopes, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct RetransmitBuffer {
+    capacity: usize,
+    envelopes: VecDeque<SignedPacketEnvelope>,
+}
+
+impl RetransmitBuffer {
+    /// Creates a buffer holding the last `capacity` envelopes.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, envelopes: VecDeque::with_capacity(capacity) }
+    }
+    
+    /// Keeps a sequenced envelope, dropping the oldest once full.
+    /// Envelopes must be pushed in sequence order.
+    pub fn push(&mut self, envelope: SignedPacketEnvelope) {
+        if self.capacity == 0 || envelope.sequence.is_none() {
+            return;
+        }
+        if self.envelopes.len() == self.capacity {
+            self.envelopes.pop_front();
+        }
+        self.envelopes.push_back(envelope);
+    }
+    
+    /// The envelope stamped `sequence`, if still held.
+    pub fn get(&self, sequence: u32) -> Option<&SignedPacketEnvelope> {
+        let oldest = self.envelopes.front()?.sequence?;
+        self.envelopes.get(sequence.wrapping_sub(oldest) as usize)
+    }
+    
+    /// The held envelopes `nack` asks for, in its order.
+    pub fn answer(&self, nack: &GossipNack) -> Vec<SignedPacketEnvelope> {
+        nack.ranges.iter()
+            .flat_map(|range| range.iter())
+            .filter_map(|sequence| self.get(sequence).cloned())
+            .collect()
+    }
+    
+    /// Number of envelopes held.
+    pub fn len(&self) -> usize {
+        self.envelopes.len()
+    }
+    
+    /// True if no envelopes are held.
+    pub fn is_empty(&self) -> bool {
+        self.envelopes.is_empty()
+    }
+    
+    /// Bytes held, envelope headers included.
+    pub fn memory_bytes(&self) -> usize {
+        self.envelopes.iter().map(|e| size_of::<SignedPacketEnvelope>() + e.payload.len()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn tracker_fed(sequences: &[u32]) -> SequenceTracker {
+        let mut tracker = SequenceTracker::new();
+        for &seq in sequences {
+            tracker.record(seq);
+        }
+        tracker
+    }
+    
+    #[test]
+    fn test_gaps_are_detected_and_repaired_out_of_order() {
+        let mut tracker = tracker_fed(&[0, 1]);
+        assert_eq!(tracker.record(5), SequenceOutcome::Gap { missing: 3 });
+        assert_eq!(tracker.record(4), SequenceOutcome::Repaired);
+        assert_eq!(tracker.missing_ranges(), vec![SequenceRange { start: 2, len: 2 }]);
+        assert_eq!(tracker.record(8), SequenceOutcome::Gap { missing: 2 });
+        assert_eq!(tracker.gap_count(), 4);
+        
+        // Filling the middle of a hole splits it
+        assert_eq!(tracker.record(6), SequenceOutcome::Repaired);
+        assert_eq!(tracker.record(4), SequenceOutcome::Duplicate);
+        assert_eq!(tracker.missing_ranges(), vec![SequenceRange { start: 2, len: 2 }, SequenceRange { start: 7, len: 1 }]);
+        
+        for seq in [3, 7, 2] {
+            assert_eq!(tracker.record(seq), SequenceOutcome::Repaired);
+        }
+        assert_eq!(tracker.gap_count(), 0);
+        let stats = tracker.stats();
+        assert_eq!((stats.gaps_detected, stats.gaps_repaired, stats.duplicates), (5, 5, 1));
+        assert_eq!(stats.received, 9);
+        
+        // Reordering before the first sequence heard isn't a gap
+        let mut tracker = tracker_fed(&[10]);
+        assert_eq!(tracker.record(9), SequenceOutcome::Late);
+        assert_eq!(tracker.gap_count(), 0);
+    }
+    
+    #[test]
+    fn test_sequences_wrap_past_u32_max() {
+        let tracker = tracker_fed(&[u32::MAX - 1, u32::MAX, 0, 1]);
+        assert_eq!(tracker.gap_count(), 0);
+        assert_eq!(tracker.highest(), Some(1));
+        
+        // A gap spanning the wrap is one range
+        let mut tracker = tracker_fed(&[u32::MAX - 2]);
+        assert_eq!(tracker.record(1), SequenceOutcome::Gap { missing: 3 });
+        let ranges = tracker.missing_ranges();
+        assert_eq!(ranges, vec![SequenceRange { start: u32::MAX - 1, len: 3 }]);
+        assert_eq!(ranges[0].last(), 0);
+        assert_eq!(ranges[0].iter().collect::<Vec<_>>(), vec![u32::MAX - 1, u32::MAX, 0]);
+        assert!(ranges[0].contains(u32::MAX) && !ranges[0].contains(1));
+        
+        // Repairs arriving reordered across the wrap
+        assert_eq!(tracker.record(0), SequenceOutcome::Repaired);
+        assert_eq!(tracker.record(u32::MAX - 1), SequenceOutcome::Repaired);
+        assert_eq!(tracker.record(u32::MAX - 2), SequenceOutcome::Duplicate);
+        assert_eq!(tracker.missing_ranges(), vec![SequenceRange { start: u32::MAX, len: 1 }]);
+        
+        // The sender buffer finds envelopes across the wrap too
+        let mut buffer = RetransmitBuffer::new(4);
+        for seq in [u32::MAX - 2, u32::MAX - 1, u32::MAX, 0, 1] {
+            buffer.push(SignedPacketEnvelope::new(vec![seq as u8], 0).with_sequence(seq));
+        }
+        assert_eq!(buffer.len(), 4);
+        assert!(buffer.get(u32::MAX - 2).is_none());
+        let nack = GossipNack { ranges: tracker.missing_ranges() };
+        let resent: Vec<Option<u32>> = buffer.answer(&nack).iter().map(|e| e.sequence).collect();
+        assert_eq!(resent, vec![Some(u32::MAX)]);
+    }
+    
+    #[test]
+    fn test_nacks_are_bounded_and_oversized_gaps_trimmed() {
+        let mut tracker = tracker_fed(&[0, 4]);
+        for _ in 0..2 {
+            assert_eq!(tracker.nack(2), vec![SequenceRange { start: 1, len: 3 }]);
+        }
+        assert!(tracker.nack(2).is_empty());
+        assert_eq!(tracker.gap_count(), 0);
+        assert_eq!(tracker.stats().gaps_abandoned, 3);
+        assert_eq!(tracker.record(2), SequenceOutcome::Duplicate);
+        
+        let mut tracker = tracker_fed(&[0, 10]);
+        tracker.record(MAX_TRACKED_GAP as u32 + 20);
+        assert_eq!(tracker.gap_count(), MAX_TRACKED_GAP);
+        let stats = tracker.stats();
+        assert_eq!(stats.gaps_abandoned, 9 + 9);
+        assert_eq!(stats.outstanding(), MAX_TRACKED_GAP);
+        assert_eq!(tracker.missing_ranges()[0].start, 11 + 9);
+    }
+}
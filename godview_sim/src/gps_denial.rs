@@ -0,0 +1,222 @@
+//! Agents that lose their own position fix.
+//!
+//! A [`GpsDenial`] cuts some agents off from GPS for a window of the run
+//! ([`SwarmHarness::with_gps_denial`](crate::SwarmHarness::with_gps_denial)).
+//! They dead-reckon their own position, so everything their placed sensors
+//! report, and everything they gossip, drifts with their pose error. Their
+//! neighbors see the drift as a systematic offset between that gossip and
+//! their own tracks. A [`GpsDenialSampler`] scores the swarm before, during
+//! and after the window.
+
+use crate::agent::SimulatedAgent;
+use nalgebra::Vector3;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Multiple of its pre-denial RMS the swarm must get back under to have
+/// recovered.
+pub const GPS_RECOVERY_FACTOR: f64 = 1.25;
+
+/// A window during which some agents have no GPS.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpsDenial {
+    /// Indices of the denied agents
+    pub agents: Vec<usize>,
+    
+    /// Simulated time GPS is lost at (s)
+    pub start_secs: f64,
+    
+    /// Simulated time GPS comes back at (s)
+    pub end_secs: f64,
+    
+    /// Speed each denied agent's dead-reckoned position drifts at (m/s)
+    pub drift_mps: f64,
+}
+
+impl GpsDenial {
+    /// Returns true if GPS is denied at `time_secs`.
+    pub fn is_active(&self, time_secs: f64) -> bool {
+        (self.start_secs..self.end_secs).contains(&time_secs)
+    }
+    
+    /// Returns true if `agent` is one of the denied agents.
+    pub fn denies(&self, agent: usize) -> bool {
+        self.agents.contains(&agent)
+    }
+    
+    /// Draws one agent's drift: `drift_mps` in a uniformly random
+    /// horizontal direction.
+    pub fn draw_drift<R: Rng>(&self, rng: &mut R) -> Vector3<f64> {
+        let heading = rng.gen_range(0.0..std::f64::consts::TAU);
+        Vector3::new(heading.cos(), heading.sin(), 0.0) * self.drift_mps
+    }
+}
+
+/// How a swarm held up while some of its agents had no GPS.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct GpsDenialStats {
+    /// Agents denied GPS
+    pub denied_agents: usize,
+    
+    /// Mean swarm RMS after warm-up and before the denial (m)
+    pub rms_before: f64,
+    
+    /// Mean swarm RMS during the denial (m)
+    pub rms_during: f64,
+    
+    /// Worst swarm RMS during the denial (m)
+    pub rms_peak: f64,
+    
+    /// Largest pose error of any denied agent (m)
+    pub peak_pose_error_m: f64,
+    
+    /// Seconds from GPS returning until swarm RMS was back within
+    /// [`GPS_RECOVERY_FACTOR`] of `rms_before` (None if it never was)
+    pub recovery_secs: Option<f64>,
+    
+    /// Denied neighbors honest agents had an offset estimate for when GPS
+    /// came back
+    pub denied_pairs: usize,
+    
+    /// Of those, the ones estimated past the tolerance
+    pub flagged_denied_pairs: usize,
+    
+    /// Honest neighbors honest agents estimated past the tolerance
+    pub flagged_honest_pairs: usize,
+}
+
+impl GpsDenialStats {
+    /// Fraction of the (honest agent, denied neighbor) pairs with an
+    /// estimate in which the denied neighbor was flagged (0 with none).
+    pub fn detection_rate(&self) -> f64 {
+        if self.denied_pairs == 0 {
+            return 0.0;
+        }
+        self.flagged_denied_pairs as f64 / self.denied_pairs as f64
+    }
+}
+
+/// Splits swarm RMS samples around a [`GpsDenial`] and times the recovery.
+pub struct GpsDenialSampler {
+    denial: GpsDenial,
+    warmup_secs: f64,
+    before: Vec<f64>,
+    during: Vec<f64>,
+    stats: GpsDenialStats,
+}
+
+impl GpsDenialSampler {
+    /// Creates a sampler for `denial` that ignores samples before
+    /// `warmup_secs`.
+    pub fn new(denial: &GpsDenial, warmup_secs: f64) -> Self {
+        Self {
+            denial: denial.clone(),
+            warmup_secs,
+            before: Vec::new(),
+            during: Vec::new(),
+            stats: GpsDenialStats { denied_agents: denial.agents.len(), ..GpsDenialStats::default() },
+        }
+    }
+    
+    /// Records the swarm's RMS at `time_secs` and, while GPS is denied,
+    /// the denied agents' pose errors and which neighbors the honest
+    /// agents have flagged.
+    pub fn observe(&mut self, time_secs: f64, rms: f64, agents: &[SimulatedAgent]) {
+        if time_secs < self.warmup_secs {
+            return;
+        }
+        if time_secs < self.denial.start_secs {
+            self.before.push(rms);
+        } else if self.denial.is_active(time_secs) {
+            self.during.push(rms);
+            for &idx in &self.denial.agents {
+                if let Some(agent) = agents.get(idx) {
+                    self.stats.peak_pose_error_m = self.stats.peak_pose_error_m.max(agent.pose_error().norm());
+                }
+            }
+            self.count_flags(agents);
+        } else if self.stats.recovery_secs.is_none() && rms <= GPS_RECOVERY_FACTOR * mean(&self.before) {
+            self.stats.recovery_secs = Some(time_secs - self.denial.end_secs);
+        }
+    }
+    
+    /// Counts the honest agents' flagged neighbors, replacing the last count.
+    fn count_flags(&mut self, agents: &[SimulatedAgent]) {
+        let (mut denied, mut flagged_denied, mut flagged_honest) = (0, 0, 0);
+        for (idx, agent) in agents.iter().enumerate().filter(|(idx, _)| !self.denial.denies(*idx)) {
+            let flagged = agent.offset_neighbors();
+            for &neighbor in &self.denial.agents {
+                if neighbor != idx && agent.peer_offset(neighbor).is_some() {
+                    denied += 1;
+                    flagged_denied += flagged.contains(&neighbor) as usize;
+                }
+            }
+            flagged_honest += flagged.iter().filter(|&&n| !self.denial.denies(n)).count();
+        }
+        self.stats.denied_pairs = denied;
+        self.stats.flagged_denied_pairs = flagged_denied;
+        self.stats.flagged_honest_pairs = flagged_honest;
+    }
+    
+    /// Returns the stats so far.
+    pub fn stats(&self) -> GpsDenialStats {
+        GpsDenialStats {
+            rms_before: mean(&self.before),
+            rms_during: mean(&self.during),
+            rms_peak: self.during.iter().copied().fold(0.0, f64::max),
+            ..self.stats
+        }
+    }
+}
+
+/// Mean of `samples` (0 if empty).
+fn mean(samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    
+    #[test]
+    fn test_sampler_times_recovery_after_the_window() {
+        let denial = GpsDenial { agents: vec![1], start_secs: 2.0, end_secs: 4.0, drift_mps: 1.0 };
+        let mut sampler = GpsDenialSampler::new(&denial, 0.5);
+        
+        let rms_at = |t: f64| match t {
+            t if t < 2.0 => 1.0,
+            t if t < 4.0 => 3.0,
+            t if t < 5.0 => 2.0,
+            _ => 1.1,
+        };
+        for tick in 0..70 {
+            let t = tick as f64 * 0.1;
+            sampler.observe(t, rms_at(t), &[]);
+        }
+        
+        let stats = sampler.stats();
+        assert_eq!(stats.denied_agents, 1);
+        assert_eq!(stats.rms_before, 1.0);
+        assert_eq!(stats.rms_during, 3.0);
+        assert_eq!(stats.rms_peak, 3.0);
+        let recovery = stats.recovery_secs.unwrap();
+        assert!((recovery - 1.0).abs() < 1e-9, "recovered after {recovery}s");
+    }
+    
+    #[test]
+    fn test_drift_is_horizontal_at_the_configured_speed() {
+        let denial = GpsDenial { agents: vec![0], start_secs: 0.0, end_secs: 1.0, drift_mps: 0.5 };
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        for _ in 0..10 {
+            let drift = denial.draw_drift(&mut rng);
+            assert!((drift.norm() - 0.5).abs() < 1e-12);
+            assert_eq!(drift.z, 0.0);
+        }
+        assert!(denial.is_active(0.0) && !denial.is_active(1.0));
+    }
+}
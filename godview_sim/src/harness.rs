@@ -0,0 +1,1518 @@
+//! Reusable scaffold for grid-gossip swarm scenarios.
+//!
+//! A [`SwarmHarness`] owns a scenario's oracle, agents and [`SwarmNetwork`]
+//! and advances them one tick at a time: physics, sensing, and every few
+//! ticks a gossip round with bad-actor injection, packet loss, bandwidth
+//! limits and neighbor-attributed delivery. Per-agent tick and ingest work
+//! can run on an [`AgentPool`]; everything random is drawn serially first,
+//! so the result doesn't depend on the thread count. Scenarios configure it with
+//! `with_*` builders, spawn entities through [`SwarmHarness::oracle_mut`],
+//! drive [`SwarmHarness::step`] and do their own scoring.
+//!
+//! Agents are identical unless [`SwarmHarness::with_agent_profiles`] assigns
+//! each one an [`AgentProfile`]: its own config, sensor noise, energy and
+//! whether it evolves. [`SwarmHarness::profile_groups`] lets a scenario score
+//! each profile separately.
+//!
+//! Placed sensors stay put unless a profile gives its agent an
+//! [`AgentMotion`]; [`SwarmHarness::with_radio_range`] then lets the moving
+//! agents' distances decide who gossips with whom.
+//!
+//! With [`SwarmHarness::with_reputation_sharing`] agents also send their
+//! neighbors their first-hand reputation scores every so often (see
+//! [`ReputationSharing`]); [`SwarmHarness::with_false_accusations`] has some
+//! agents lie in them.
+//!
+//! [`SwarmHarness::with_shards`] splits the world into H3 cells each agent
+//! answers for (see [`ShardMap`]): agents sense and keep tracks only there,
+//! and hand a track to the next owner when it crosses over.
+
+use crate::adaptive::{GossipQuota, TrustInflation};
+use crate::agent::SimulatedAgent;
+use crate::context::SimContext;
+use crate::delta_gossip::DeltaCompression;
+use crate::event_bus::{SimEvent, SimEventBus};
+use crate::evolution::FitnessProvider;
+use crate::frustum::{ObservationCoverage, ObservationSampler, SensorPose};
+use crate::gps_denial::GpsDenial;
+use crate::keys::DeterministicKeyProvider;
+use crate::mobility::AgentMotion;
+use crate::network::SimNetwork;
+use crate::observer::ObserverAgent;
+use crate::oracle::{Oracle, SensorReading, DEFAULT_SENSOR_ID};
+use crate::parallel::{AgentPool, PoolStats};
+use crate::reputation_gossip::{ReputationConsensus, ReputationSharing, ReputationSummary};
+use crate::rng_audit::AuditedRng;
+use crate::sharding::{ShardMap, ShardState, ShardStats};
+use crate::swarm_network::{wire_bytes, LinkConfig, SwarmNetwork};
+use crate::trained::TrainedParams;
+use crate::world::Visibility;
+use godview_core::godview_tracking::{GlobalHazardPacket, MeasurementModel};
+use godview_core::{AgentConfig, SpatialEngine};
+use godview_env::NodeId;
+use nalgebra::Vector3;
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Class ID stamped on garbage packets.
+pub const GARBAGE_CLASS_ID: u8 = 99;
+
+/// Random packets a bad actor adds to its gossip every round.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GarbageSpec {
+    /// Garbage packets per bad actor per round
+    pub packets_per_round: usize,
+    
+    /// Lower corner of the box garbage positions are drawn from
+    pub min_position: [f64; 3],
+    
+    /// Upper corner of that box
+    pub max_position: [f64; 3],
+    
+    /// Bound on each horizontal velocity component (0 sends stationary garbage)
+    pub max_speed: f64,
+    
+    /// Confidence range (equal bounds send a fixed confidence)
+    pub confidence: (f64, f64),
+}
+
+impl GarbageSpec {
+    /// Draws one garbage packet stamped at `timestamp`.
+    ///
+    /// Draws position, then velocity, then confidence, skipping fixed values,
+    /// then the entity ID, so a seeded run gossips the same garbage IDs.
+    pub fn packet<R: Rng>(&self, rng: &mut R, timestamp: f64) -> GlobalHazardPacket {
+        let mut position = [0.0; 3];
+        for (axis, p) in position.iter_mut().enumerate() {
+            *p = rng.gen_range(self.min_position[axis]..self.max_position[axis]);
+        }
+        let velocity = if self.max_speed > 0.0 {
+            [rng.gen_range(-self.max_speed..self.max_speed), rng.gen_range(-self.max_speed..self.max_speed), 0.0]
+        } else {
+            [0.0; 3]
+        };
+        let (low, high) = self.confidence;
+        let confidence_score = if high > low { rng.gen_range(low..high) } else { low };
+        
+        GlobalHazardPacket {
+            entity_id: Uuid::from_u128(rng.gen()),
+            position,
+            velocity,
+            class_id: GARBAGE_CLASS_ID,
+            timestamp,
+            confidence_score,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        }
+    }
+}
+
+/// How bad actors misbehave.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BadActorStrategy {
+    /// Gossip their tracks plus random garbage
+    Garbage(GarbageSpec),
+    
+    /// Built with bad-actor adaptive state ([`SimulatedAgent::new_bad_actor`])
+    /// and otherwise gossip normally
+    Adaptive,
+}
+
+/// Which agents are bad actors, and from when.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BadActorSchedule {
+    /// These agents, from the first tick
+    Fixed(Vec<usize>),
+    
+    /// Exactly `count` distinct random agents (at most every agent), placed
+    /// per `placement`, at the first tick starting at or after `at_secs`
+    RandomAt { count: usize, at_secs: f64, placement: BadActorPlacement },
+}
+
+/// Whether randomly drawn bad actors may be grid neighbors of each other.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BadActorPlacement {
+    /// Any agents, neighbors or not
+    #[default]
+    Anywhere,
+    
+    /// No two bad actors are neighbors, as far as the grid allows
+    Apart,
+    
+    /// One connected group, grown from a random agent
+    Clustered,
+}
+
+/// The bad actors a random schedule turned, and when.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BadActorInjection {
+    /// Agent indices, in draw order
+    pub ids: Vec<usize>,
+    
+    /// Tick the schedule fired on (0-based)
+    pub tick: u64,
+    
+    /// Simulated time of that tick (s)
+    pub time_secs: f64,
+}
+
+/// Draws exactly `count` distinct agents of `network` (capped at its size)
+/// with a partial Fisher-Yates shuffle, placed per `placement`.
+///
+/// Draws only depend on `rng`, so the same seed always picks the same
+/// agents. If the grid can't keep `count` agents apart, or a cluster runs
+/// out of neighbors, the rest come from the remaining agents in draw order.
+pub fn draw_bad_actors(
+    rng: &mut impl Rng,
+    network: &SwarmNetwork,
+    count: usize,
+    placement: BadActorPlacement,
+) -> Vec<usize> {
+    let total = network.agent_count();
+    let count = count.min(total);
+    let mut pool: Vec<usize> = (0..total).collect();
+    let mut chosen = Vec::with_capacity(count);
+    match placement {
+        BadActorPlacement::Anywhere => {
+            for i in 0..count {
+                let j = rng.gen_range(i..total);
+                pool.swap(i, j);
+                chosen.push(pool[i]);
+            }
+        }
+        BadActorPlacement::Apart => {
+            // Shuffle everyone, then take agents in order while they have no
+            // bad neighbor
+            for i in 0..total {
+                let j = rng.gen_range(i..total);
+                pool.swap(i, j);
+            }
+            for &idx in &pool {
+                if chosen.len() == count {
+                    break;
+                }
+                if !network.neighbors(idx).iter().any(|n| chosen.contains(n)) {
+                    chosen.push(idx);
+                }
+            }
+            for &idx in &pool {
+                if chosen.len() == count {
+                    break;
+                }
+                if !chosen.contains(&idx) {
+                    chosen.push(idx);
+                }
+            }
+        }
+        BadActorPlacement::Clustered => {
+            while chosen.len() < count {
+                let mut frontier: Vec<usize> = chosen.iter()
+                    .flat_map(|&idx| network.neighbors(idx).iter().copied())
+                    .filter(|n| !chosen.contains(n))
+                    .collect();
+                frontier.sort_unstable();
+                frontier.dedup();
+                if frontier.is_empty() {
+                    frontier = (0..total).filter(|idx| !chosen.contains(idx)).collect();
+                }
+                chosen.push(frontier[rng.gen_range(0..frontier.len())]);
+            }
+        }
+    }
+    chosen
+}
+
+/// Faults drawn for each agent's sensing every tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SensorFaults {
+    /// The agent misses the whole tick (no tick, no readings) with this probability
+    Jitter { skip_probability: f64 },
+    
+    /// All readings are lost with `blackout_probability`; otherwise, with
+    /// `noise_probability`, each is pushed up to `noise_m` off horizontally
+    Blackout { blackout_probability: f64, noise_probability: f64, noise_m: f64 },
+}
+
+/// One class of agent in a mixed fleet (see [`SwarmHarness::with_agent_profiles`]).
+#[derive(Debug, Clone)]
+pub struct AgentProfile {
+    /// Group the agent is scored under (e.g. "precise")
+    pub name: String,
+    
+    /// Tracking configuration: filter tuning, fusion, capacity, frame
+    pub config: AgentConfig,
+    
+    /// Standard deviation (m) of horizontal noise added to each of the
+    /// agent's readings, on top of the oracle's own
+    pub sensor_noise_m: f64,
+    
+    /// Confidence the agent stamps on its own readings; None keeps the
+    /// agent default
+    pub reading_confidence: Option<f64>,
+    
+    /// Starting energy (J); None keeps the agent default
+    pub energy_j: Option<f64>,
+    
+    /// Whether the agent evolves when the harness has evolution
+    pub evolves: bool,
+    
+    /// How the agent's placed sensor moves; None keeps it where it was
+    /// placed
+    pub motion: Option<AgentMotion>,
+}
+
+impl AgentProfile {
+    /// A profile named `name` running `config`, with no extra noise, default
+    /// energy and evolution enabled.
+    pub fn new(name: impl Into<String>, config: AgentConfig) -> Self {
+        Self {
+            name: name.into(),
+            config,
+            sensor_noise_m: 0.0,
+            reading_confidence: None,
+            energy_j: None,
+            evolves: true,
+            motion: None,
+        }
+    }
+    
+    /// Adds `std_m` of Gaussian noise to each reading's x and y.
+    pub fn with_sensor_noise(mut self, std_m: f64) -> Self {
+        self.sensor_noise_m = std_m;
+        self
+    }
+    
+    /// Has the agent stamp `confidence` on its own readings, so its tracker
+    /// and its neighbors weigh them as a sensor that noisy deserves.
+    pub fn with_reading_confidence(mut self, confidence: f64) -> Self {
+        self.reading_confidence = Some(confidence);
+        self
+    }
+    
+    /// Starts the agent with `joules` of energy.
+    pub fn with_energy(mut self, joules: f64) -> Self {
+        self.energy_j = Some(joules);
+        self
+    }
+    
+    /// Moves the agent's placed sensor (see
+    /// [`SwarmHarness::with_sensor_positions`]) per `motion`, starting from
+    /// where it was placed.
+    pub fn with_motion(mut self, motion: AgentMotion) -> Self {
+        self.motion = Some(motion);
+        self
+    }
+    
+    /// Keeps the agent's parameters fixed even when the harness evolves.
+    pub fn without_evolution(mut self) -> Self {
+        self.evolves = false;
+        self
+    }
+}
+
+/// Per-agent parameter evolution.
+#[derive(Debug, Clone, Copy)]
+struct Evolution {
+    epoch_ticks: u64,
+    fitness: fn() -> Box<dyn FitnessProvider>,
+}
+
+/// The harness's RNG streams, each derived by name from the scenario seed
+/// ([`AuditedRng::derive`]) so a draw added to one never shifts another.
+#[derive(Debug, Clone)]
+struct HarnessStreams {
+    seed: u64,
+    
+    /// Scenario setup, e.g. randomizing entities (`harness/scenario`)
+    scenario: AuditedRng,
+    
+    /// Bad-actor placement and garbage packets (`harness/bad-actors`)
+    bad_actors: AuditedRng,
+    
+    /// Each agent's sensor faults, noise and GPS drift
+    /// (`harness/agent-{idx}/sensor-noise`)
+    sensors: BTreeMap<usize, AuditedRng>,
+    
+    /// Loss of each agent's gossip (`harness/agent-{idx}/gossip-loss`)
+    gossip_loss: BTreeMap<usize, AuditedRng>,
+    
+    /// Loss of each link's reputation summaries
+    /// (`harness/link-{from}-{to}/loss`)
+    link_loss: BTreeMap<(usize, usize), AuditedRng>,
+}
+
+impl HarnessStreams {
+    fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            scenario: AuditedRng::derive(seed, "harness/scenario"),
+            bad_actors: AuditedRng::derive(seed, "harness/bad-actors"),
+            sensors: BTreeMap::new(),
+            gossip_loss: BTreeMap::new(),
+            link_loss: BTreeMap::new(),
+        }
+    }
+    
+    fn sensor(&mut self, idx: usize) -> &mut AuditedRng {
+        let seed = self.seed;
+        self.sensors.entry(idx)
+            .or_insert_with(|| AuditedRng::derive(seed, &format!("harness/agent-{}/sensor-noise", idx)))
+    }
+    
+    fn gossip_loss(&mut self, idx: usize) -> &mut AuditedRng {
+        let seed = self.seed;
+        self.gossip_loss.entry(idx)
+            .or_insert_with(|| AuditedRng::derive(seed, &format!("harness/agent-{}/gossip-loss", idx)))
+    }
+    
+    fn link_loss(&mut self, from: usize, to: usize) -> &mut AuditedRng {
+        let seed = self.seed;
+        self.link_loss.entry((from, to))
+            .or_insert_with(|| AuditedRng::derive(seed, &format!("harness/link-{}-{}/loss", from, to)))
+    }
+}
+
+/// Outcome of one [`SwarmHarness::step`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HarnessTick {
+    /// Tick that ran (0-based)
+    pub tick: u64,
+    
+    /// Whether the tick ended with a gossip round
+    pub gossip_round: bool,
+}
+
+/// Oracle, agents and grid network of a swarm scenario.
+pub struct SwarmHarness {
+    seed: u64,
+    rows: usize,
+    cols: usize,
+    dt: f64,
+    agent_config: AgentConfig,
+    profiles: Option<Vec<AgentProfile>>,
+    visibility: Visibility,
+    sensor_positions: Option<Vec<Vector3<f64>>>,
+    sensor_origins: Vec<Vector3<f64>>,
+    sensor_scan: Option<(f64, f64)>,
+    observation: ObservationSampler,
+    gossip_interval_ticks: u64,
+    packet_loss: f64,
+    link_config: Option<LinkConfig>,
+    radio_range: Option<f64>,
+    delta_compression: Option<DeltaCompression>,
+    bad_actor_schedule: Option<BadActorSchedule>,
+    bad_actor_strategy: BadActorStrategy,
+    sensor_faults: Option<SensorFaults>,
+    evolution: Option<Evolution>,
+    trust_inflation: TrustInflation,
+    gossip_quota: Option<(GossipQuota, bool)>,
+    index_verify_interval: Option<u64>,
+    peer_offset_tolerance: Option<f64>,
+    gps_denial: Option<GpsDenial>,
+    trained_params: Option<TrainedParams>,
+    reputation_sharing: Option<ReputationSharing>,
+    false_accusations: Option<(Vec<usize>, usize)>,
+    shards: Option<ShardState>,
+    pool: AgentPool,
+    rngs: HarnessStreams,
+    oracle: Oracle,
+    agents: Vec<SimulatedAgent>,
+    network: SwarmNetwork,
+    observer: Option<ObserverAgent>,
+    bad_actors: Vec<usize>,
+    bad_actor_injection: Option<BadActorInjection>,
+    tick: u64,
+    packets_offered: u64,
+    packets_lost: u64,
+    bytes_offered: u64,
+    bytes_lost: u64,
+    reputation_messages: u64,
+    reputation_bytes: u64,
+    events: Option<SimEventBus>,
+}
+
+impl SwarmHarness {
+    /// Creates a `rows x cols` grid of honest agents ticking at
+    /// `tick_rate_hz`, with an empty oracle.
+    ///
+    /// Defaults: every agent sees every entity, gossip every 5 ticks, no
+    /// loss or bandwidth limit, no bad actors, no evolution, serial agent
+    /// work, and the scenario RNG streams derived from `seed`.
+    pub fn new(seed: u64, rows: usize, cols: usize, tick_rate_hz: u32) -> Self {
+        let mut harness = Self {
+            seed,
+            rows,
+            cols,
+            dt: 1.0 / tick_rate_hz as f64,
+            agent_config: SimulatedAgent::default_config(),
+            profiles: None,
+            visibility: Visibility::All,
+            sensor_positions: None,
+            sensor_origins: Vec::new(),
+            sensor_scan: None,
+            observation: ObservationSampler::new(),
+            gossip_interval_ticks: 5,
+            packet_loss: 0.0,
+            link_config: None,
+            radio_range: None,
+            delta_compression: None,
+            bad_actor_schedule: None,
+            bad_actor_strategy: BadActorStrategy::Adaptive,
+            sensor_faults: None,
+            evolution: None,
+            trust_inflation: TrustInflation::OFF,
+            gossip_quota: None,
+            index_verify_interval: None,
+            peer_offset_tolerance: None,
+            gps_denial: None,
+            trained_params: None,
+            reputation_sharing: None,
+            false_accusations: None,
+            shards: None,
+            pool: AgentPool::serial(),
+            rngs: HarnessStreams::new(seed),
+            oracle: Oracle::new(seed.wrapping_mul(0x9e3779b97f4a7c15)),
+            agents: Vec::new(),
+            network: SwarmNetwork::new_grid(rows, cols),
+            observer: None,
+            bad_actors: Vec::new(),
+            bad_actor_injection: None,
+            tick: 0,
+            packets_offered: 0,
+            packets_lost: 0,
+            bytes_offered: 0,
+            bytes_lost: 0,
+            reputation_messages: 0,
+            reputation_bytes: 0,
+            events: None,
+        };
+        harness.build_agents();
+        harness
+    }
+    
+    /// Gives every agent `config` (rebuilds the agents).
+    pub fn with_agent_config(mut self, config: AgentConfig) -> Self {
+        self.agent_config = config;
+        self.build_agents();
+        self
+    }
+    
+    /// Gives agent `i` the profile `profile_of(i)` instead of the shared
+    /// config (rebuilds the agents).
+    pub fn with_agent_profiles(mut self, profile_of: impl Fn(usize) -> AgentProfile) -> Self {
+        self.profiles = Some((0..self.rows * self.cols).map(profile_of).collect());
+        self.build_agents();
+        self
+    }
+    
+    /// Restricts which readings each agent receives.
+    pub fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+    
+    /// Places agent `i`'s sensor at `positions[i]`: each agent gets its own
+    /// readings of the entities it has line of sight to
+    /// ([`Oracle::generate_readings_for`]) instead of the shared ones, still
+    /// filtered by [`Self::with_visibility`] (with entity IDs as indices).
+    /// Agents without a position see nothing.
+    ///
+    /// Placed agents have blind spots, so they also get
+    /// [`SimulatedAgent::set_blind_spot_credit`]. Agents whose profile has
+    /// an [`AgentMotion`] move from here each tick.
+    pub fn with_sensor_positions(mut self, positions: Vec<Vector3<f64>>) -> Self {
+        self.sensor_origins = positions.clone();
+        self.sensor_positions = Some(positions);
+        for agent in &mut self.agents {
+            agent.set_blind_spot_credit(true);
+        }
+        self
+    }
+    
+    /// Narrows each placed sensor ([`Self::with_sensor_positions`]) to
+    /// `fov_deg` of bearing, turning at `scan_rate_dps`
+    /// ([`Oracle::register_sensor_with_scan`]). Headings start spread evenly
+    /// round the circle in agent order.
+    pub fn with_sensor_scan(mut self, fov_deg: f64, scan_rate_dps: f64) -> Self {
+        self.sensor_scan = Some((fov_deg, scan_rate_dps));
+        let positions = self.sensor_positions.clone().unwrap_or_default();
+        for (idx, position) in positions.iter().enumerate() {
+            let heading_deg = idx as f64 * 360.0 / positions.len() as f64;
+            self.oracle.register_sensor_with_scan(idx, SensorPose::new(*position, heading_deg), fov_deg, scan_rate_dps);
+        }
+        self
+    }
+    
+    /// Makes each agent answer for the cells `map` gives it: it only senses
+    /// entities read inside them, drops tracks beyond
+    /// [`ShardMap::margin_rings`] of them after each tick and, unless the
+    /// map is [`ShardMap::without_handoff`], hands a track that moves into
+    /// another agent's cells straight to that agent and drops its own copy
+    /// (see [`SimulatedAgent::accept_handoff`]).
+    pub fn with_shards(mut self, map: ShardMap) -> Self {
+        self.shards = Some(ShardState::new(map, self.agents.len()));
+        self
+    }
+    
+    /// Runs a gossip round every `ticks` ticks.
+    pub fn with_gossip_interval(mut self, ticks: u64) -> Self {
+        self.gossip_interval_ticks = ticks.max(1);
+        self
+    }
+    
+    /// Drops each gossiped packet with probability `probability` before it
+    /// reaches the network.
+    pub fn with_packet_loss(mut self, probability: f64) -> Self {
+        self.packet_loss = probability;
+        self
+    }
+    
+    /// Limits every link's bandwidth (see [`SwarmNetwork::with_link_config`]).
+    pub fn with_link_config(mut self, config: LinkConfig) -> Self {
+        self.link_config = Some(config);
+        self.network = self.build_network();
+        self
+    }
+    
+    /// Links agents whose placed sensors ([`Self::with_sensor_positions`])
+    /// are within `range_m` of each other instead of grid neighbors, and
+    /// relinks them every tick as they move (see
+    /// [`SwarmNetwork::with_radio_range`]).
+    pub fn with_radio_range(mut self, range_m: f64) -> Self {
+        self.radio_range = Some(range_m);
+        self.network = self.build_network();
+        self
+    }
+    
+    /// Delta-encodes gossip on every link (see
+    /// [`SwarmNetwork::with_delta_compression`]).
+    pub fn with_delta_compression(mut self, config: DeltaCompression) -> Self {
+        self.delta_compression = Some(config);
+        self.network = self.build_network();
+        self
+    }
+    
+    /// A fresh network with the configured links and topology.
+    fn build_network(&self) -> SwarmNetwork {
+        let mut network = SwarmNetwork::new_grid(self.rows, self.cols);
+        if let Some(config) = self.link_config {
+            network = network.with_link_config(config, self.seed);
+        }
+        if let Some(range_m) = self.radio_range {
+            network = network.with_radio_range(range_m, self.sensor_positions.as_deref().unwrap_or_default());
+        }
+        if let Some(config) = self.delta_compression {
+            network = network.with_delta_compression(config);
+        }
+        if let Some(bus) = &self.events {
+            network.set_event_bus(bus.clone());
+        }
+        network
+    }
+    
+    /// Publishes bad-actor injections and GPS denials on `bus`, and hands it
+    /// to the agents and the network. Agents publish while they tick in
+    /// parallel, in a [`SimEventBus::parallel_phase`].
+    pub fn with_event_bus(mut self, bus: SimEventBus) -> Self {
+        for agent in &mut self.agents {
+            agent.set_event_bus(bus.clone());
+        }
+        self.network.set_event_bus(bus.clone());
+        self.events = Some(bus);
+        self
+    }
+    
+    fn publish(&self, event: SimEvent) {
+        if let Some(bus) = &self.events {
+            bus.publish(event);
+        }
+    }
+    
+    /// Makes the agents in `schedule` misbehave per `strategy`.
+    ///
+    /// [`BadActorStrategy::Adaptive`] rebuilds the chosen agents, so it needs
+    /// a [`BadActorSchedule::Fixed`] schedule.
+    pub fn with_bad_actors(mut self, schedule: BadActorSchedule, strategy: BadActorStrategy) -> Self {
+        self.bad_actor_strategy = strategy;
+        if let BadActorSchedule::Fixed(ids) = &schedule {
+            self.bad_actors = ids.clone();
+            self.build_agents();
+        }
+        self.bad_actor_schedule = Some(schedule);
+        self
+    }
+    
+    /// Draws `faults` for every agent's sensing each tick.
+    pub fn with_sensor_faults(mut self, faults: SensorFaults) -> Self {
+        self.sensor_faults = Some(faults);
+        self
+    }
+    
+    /// Evolves every agent's parameters each `epoch_ticks`, scored by a
+    /// provider from `fitness`.
+    ///
+    /// Evolving agents only speak on rounds that fall on their evolved
+    /// gossip interval, are charged the [`wire_bytes`] of their packets, and
+    /// take gossip from at most their evolved neighbor count.
+    pub fn with_evolution(mut self, epoch_ticks: u64, fitness: fn() -> Box<dyn FitnessProvider>) -> Self {
+        self.evolution = Some(Evolution { epoch_ticks, fitness });
+        let evolves: Vec<bool> = (0..self.agents.len()).map(|idx| self.evolves(idx)).collect();
+        for (agent, _) in self.agents.iter_mut().zip(evolves).filter(|(_, evolves)| *evolves) {
+            agent.set_fitness_provider(fitness());
+        }
+        self
+    }
+    
+    /// Inflates every agent's gossip covariance by sender reliability under
+    /// `curve` (see [`SimulatedAgent::set_trust_inflation`]).
+    pub fn with_trust_inflation(mut self, curve: TrustInflation) -> Self {
+        self.trust_inflation = curve;
+        for agent in &mut self.agents {
+            agent.set_trust_inflation(curve);
+        }
+        self
+    }
+    
+    /// Rate-limits every agent's intake from each neighbor to `quota`,
+    /// evolvable with `evolve` (see [`SimulatedAgent::set_gossip_quota`]).
+    pub fn with_gossip_quota(mut self, quota: GossipQuota, evolve: bool) -> Self {
+        self.gossip_quota = Some((quota, evolve));
+        for agent in &mut self.agents {
+            agent.set_gossip_quota(Some(quota), evolve);
+        }
+        self
+    }
+    
+    /// Has every agent verify its spatial index every `interval_ticks` and
+    /// repair it when inconsistent (see
+    /// [`SimulatedAgent::set_index_verification`]).
+    pub fn with_index_verification(mut self, interval_ticks: u64) -> Self {
+        self.index_verify_interval = Some(interval_ticks);
+        for agent in &mut self.agents {
+            agent.set_index_verification(Some(interval_ticks));
+        }
+        self
+    }
+    
+    /// Has every agent estimate its neighbors' gossip offsets and
+    /// down-weight those past `tolerance_m` (see
+    /// [`SimulatedAgent::set_peer_offset_tolerance`]).
+    pub fn with_peer_offset_tolerance(mut self, tolerance_m: f64) -> Self {
+        self.peer_offset_tolerance = Some(tolerance_m);
+        for agent in &mut self.agents {
+            agent.set_peer_offset_tolerance(Some(tolerance_m));
+        }
+        self
+    }
+    
+    /// Cuts `denial.agents` off from GPS for its window: each dead-reckons
+    /// at `drift_mps` in a direction drawn from the scenario RNG when the
+    /// window opens, and gets its fix back when it closes (see
+    /// [`SimulatedAgent::deny_gps`]). Only readings from placed sensors
+    /// ([`Self::with_sensor_positions`]) are affected.
+    pub fn with_gps_denial(mut self, denial: GpsDenial) -> Self {
+        self.gps_denial = Some(denial);
+        self
+    }
+    
+    /// Starts agent `i` from the trained parameters for index `i` (see
+    /// [`TrainedParams::for_agent`]) instead of the defaults.
+    pub fn with_trained_params(mut self, params: TrainedParams) -> Self {
+        for (idx, agent) in self.agents.iter_mut().enumerate() {
+            if let Some(params) = params.for_agent(idx) {
+                agent.load_evolved_params(params);
+            }
+        }
+        self.trained_params = Some(params);
+        self
+    }
+    
+    /// Has every agent send each neighbor its first-hand reputation scores
+    /// per `sharing`, after the gossip round on ticks that have one. Each
+    /// summary is lost with the gossip packet loss probability.
+    pub fn with_reputation_sharing(mut self, sharing: ReputationSharing) -> Self {
+        self.reputation_sharing = Some(sharing);
+        self
+    }
+    
+    /// Has `liars` score `victim` at 0 in every reputation summary they
+    /// share, however it really behaves. They gossip tracks honestly, so
+    /// nothing in their packets gives them away.
+    pub fn with_false_accusations(mut self, liars: Vec<usize>, victim: usize) -> Self {
+        self.false_accusations = Some((liars, victim));
+        self
+    }
+    
+    /// Runs per-agent tick and ingest work on `threads` workers (1 = serial).
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.pool = AgentPool::new(threads);
+        self
+    }
+    
+    /// Derives the scenario RNG streams (bad actors, loss, sensor faults)
+    /// from `seed` instead.
+    pub fn with_rng_seed(mut self, seed: u64) -> Self {
+        self.rngs = HarnessStreams::new(seed);
+        self
+    }
+    
+    /// Replaces the oracle with an empty one seeded with `seed`.
+    pub fn with_oracle_seed(mut self, seed: u64) -> Self {
+        self.oracle = Oracle::new(seed);
+        self
+    }
+    
+    /// Adds an all-seeing observer fed every reading and every gossiped
+    /// packet, garbage included.
+    pub fn with_observer(mut self) -> Self {
+        self.observer = Some(ObserverAgent::new(&SimulatedAgent::default_config()));
+        self
+    }
+    
+    fn build_agents(&mut self) {
+        let root_key = DeterministicKeyProvider::new(self.seed).biscuit_root_key().public();
+        let adaptive_bad_actors = matches!(self.bad_actor_strategy, BadActorStrategy::Adaptive);
+        self.agents = (0..self.rows * self.cols)
+            .map(|i| {
+                let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
+                let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
+                let profile = self.profile(i);
+                let config = profile.map_or_else(|| self.agent_config.clone(), |p| p.config.clone());
+                let mut agent = if adaptive_bad_actors && self.bad_actors.contains(&i) {
+                    SimulatedAgent::new_bad_actor(context, network, root_key, i as u64, config)
+                } else {
+                    SimulatedAgent::new(context, network, root_key, i as u64, config)
+                };
+                if let Some(evolution) = self.evolution.filter(|_| self.evolves(i)) {
+                    agent.set_fitness_provider((evolution.fitness)());
+                }
+                if let Some(joules) = profile.and_then(|p| p.energy_j) {
+                    agent.set_energy(joules);
+                }
+                if let Some(confidence) = profile.and_then(|p| p.reading_confidence) {
+                    agent.set_reading_confidence(confidence);
+                }
+                if !self.trust_inflation.is_off() {
+                    agent.set_trust_inflation(self.trust_inflation);
+                }
+                if let Some((quota, evolve)) = self.gossip_quota {
+                    agent.set_gossip_quota(Some(quota), evolve);
+                }
+                agent.set_index_verification(self.index_verify_interval);
+                agent.set_peer_offset_tolerance(self.peer_offset_tolerance);
+                if let Some(params) = self.trained_params.as_ref().and_then(|t| t.for_agent(i)) {
+                    agent.load_evolved_params(params);
+                }
+                agent.set_blind_spot_credit(self.sensor_positions.is_some());
+                if let Some(bus) = &self.events {
+                    agent.set_event_bus(bus.clone());
+                }
+                agent
+            })
+            .collect();
+    }
+    
+    /// Advances one tick: bad-actor schedule, GPS denial, physics and agent
+    /// motion, sensing, evolution, shard handoffs, then a gossip round and
+    /// reputation sharing if due, and finally dropping tracks agents no
+    /// longer answer for.
+    pub fn step(&mut self) -> HarnessTick {
+        let tick = self.tick;
+        let time = tick as f64 * self.dt;
+        
+        match self.bad_actor_schedule {
+            Some(BadActorSchedule::RandomAt { count, at_secs, placement })
+                if self.bad_actor_injection.is_none() && time >= at_secs => {
+                self.bad_actors = draw_bad_actors(&mut self.rngs.bad_actors, &self.network, count, placement);
+                self.bad_actor_injection = Some(BadActorInjection { ids: self.bad_actors.clone(), tick, time_secs: time });
+                self.publish(SimEvent::BadActorsInjected { agents: self.bad_actors.clone() });
+            }
+            Some(BadActorSchedule::Fixed(_)) if tick == 0 && !self.bad_actors.is_empty() => {
+                self.publish(SimEvent::BadActorsInjected { agents: self.bad_actors.clone() });
+            }
+            _ => {}
+        }
+        
+        if let Some(denial) = &self.gps_denial {
+            let active = denial.is_active(time);
+            let publish = |event| {
+                if let Some(bus) = &self.events {
+                    bus.publish(event);
+                }
+            };
+            for &idx in &denial.agents {
+                let Some(agent) = self.agents.get_mut(idx) else { continue };
+                if active && !agent.is_gps_denied() {
+                    agent.deny_gps(denial.draw_drift(self.rngs.sensor(idx)));
+                    publish(SimEvent::GpsDenied { agent: idx });
+                } else if !active && agent.is_gps_denied() {
+                    agent.restore_gps();
+                    publish(SimEvent::GpsRestored { agent: idx });
+                }
+            }
+        }
+        
+        self.oracle.step(self.dt);
+        self.move_agents();
+        let readings = self.oracle.generate_sensor_readings();
+        if let Some(observer) = self.observer.as_mut() {
+            observer.tick(self.dt);
+            observer.ingest_readings(&readings, self.oracle.time());
+        }
+        let ground_truth = self.evolution.map(|_| self.oracle.ground_truth_positions());
+        
+        // Draw every agent's faults in agent order before any agent runs
+        let mut plans: Vec<Option<Vec<SensorReading>>> = Vec::with_capacity(self.agents.len());
+        for idx in 0..self.agents.len() {
+            if let Some(SensorFaults::Jitter { skip_probability }) = self.sensor_faults {
+                if self.rngs.sensor(idx).gen::<f64>() < skip_probability {
+                    plans.push(None);
+                    continue;
+                }
+            }
+            
+            let mut agent_readings: Vec<_> = match &self.sensor_positions {
+                Some(_) if self.sensor_scan.is_some() => self.oracle
+                    .generate_readings_for_agent(idx, DEFAULT_SENSOR_ID)
+                    .into_iter()
+                    .filter(|r| self.visibility.sees(idx, r.entity_id as usize))
+                    .collect(),
+                Some(positions) => positions.get(idx)
+                    .map(|position| self.oracle.generate_readings_for(idx, DEFAULT_SENSOR_ID, position))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|r| self.visibility.sees(idx, r.entity_id as usize))
+                    .collect(),
+                None => readings.iter()
+                    .enumerate()
+                    .filter(|(entity_idx, _)| self.visibility.sees(idx, *entity_idx))
+                    .map(|(_, r)| r.clone())
+                    .collect(),
+            };
+            
+            if let Some(shards) = self.shards.as_mut() {
+                let before = agent_readings.len();
+                agent_readings.retain(|r| shards.map.owner_at(&r.position) == Some(idx));
+                shards.stats.readings_outside += (before - agent_readings.len()) as u64;
+            }
+            
+            let noise_m = self.profile(idx).map_or(0.0, |p| p.sensor_noise_m);
+            if noise_m > 0.0 {
+                let normal = Normal::new(0.0, noise_m).expect("finite sensor noise");
+                let rng = self.rngs.sensor(idx);
+                for r in agent_readings.iter_mut() {
+                    r.position.x += normal.sample(rng);
+                    r.position.y += normal.sample(rng);
+                }
+            }
+            
+            if let Some(SensorFaults::Blackout { blackout_probability, noise_probability, noise_m }) = self.sensor_faults {
+                let rng = self.rngs.sensor(idx);
+                if rng.gen::<f64>() < blackout_probability {
+                    agent_readings.clear();
+                } else if rng.gen::<f64>() < noise_probability {
+                    for r in agent_readings.iter_mut() {
+                        r.position.x += rng.gen_range(-noise_m..noise_m);
+                        r.position.y += rng.gen_range(-noise_m..noise_m);
+                    }
+                }
+            }
+            plans.push(Some(agent_readings));
+        }
+        if self.sensor_positions.is_some() {
+            let observed: BTreeSet<u64> = plans.iter()
+                .flatten()
+                .flatten()
+                .filter(|r| !r.is_clutter)
+                .map(|r| r.entity_id)
+                .collect();
+            let alive = self.oracle.active_entities().into_iter().map(|e| e.id);
+            self.observation.observe(alive, &observed);
+        }
+            
+        let evolution = self.evolution;
+        let evolves: Vec<bool> = (0..self.agents.len()).map(|idx| self.evolves(idx)).collect();
+        let phase = self.events.as_ref().map(SimEventBus::parallel_phase);
+        self.pool.for_each(&mut self.agents, |idx, agent| {
+            let Some(agent_readings) = &plans[idx] else {
+                return;
+            };
+            agent.tick();
+            if let Some(evolution) = evolution.filter(|_| evolves[idx]) {
+                agent.tick_evolution(evolution.epoch_ticks, ground_truth.as_deref());
+            }
+            agent.ingest_readings(agent_readings);
+        });
+        drop(phase);
+        
+        if let Some(shards) = self.shards.as_mut() {
+            shards.hand_off(&mut self.agents);
+        }
+        let gossip_round = tick.is_multiple_of(self.gossip_interval_ticks);
+        if gossip_round {
+            self.gossip_round(tick, time);
+        }
+        if let Some(sharing) = self.reputation_sharing.filter(|s| tick.is_multiple_of(s.interval_ticks)) {
+            self.share_reputations(sharing);
+        }
+        if let Some(shards) = self.shards.as_mut() {
+            shards.prune(&mut self.agents);
+        }
+        
+        self.tick += 1;
+        HarnessTick { tick, gossip_round }
+    }
+    
+    /// Moves every agent with an [`AgentMotion`] to where it is at the
+    /// oracle's time, its scanning sensor with it, and relinks the network.
+    fn move_agents(&mut self) {
+        let (Some(profiles), Some(positions)) = (&self.profiles, &mut self.sensor_positions) else {
+            return;
+        };
+        let time = self.oracle.time();
+        let mut moved = false;
+        for (idx, (profile, origin)) in profiles.iter().zip(&self.sensor_origins).enumerate() {
+            let (Some(motion), Some(position)) = (&profile.motion, positions.get_mut(idx)) else {
+                continue;
+            };
+            *position = motion.position_at(origin, time);
+            self.oracle.move_sensor(idx, *position);
+            moved = true;
+        }
+        if moved {
+            self.network.move_agents(positions);
+        }
+    }
+    
+    /// Collects every agent's packets (plus garbage from bad actors), drops
+    /// lost ones, queues the rest and delivers each agent's intake credited
+    /// to the agents that sent it.
+    fn gossip_round(&mut self, tick: u64, time: f64) {
+        let evolving = self.evolution.is_some();
+        let garbage = match self.bad_actor_strategy {
+            BadActorStrategy::Garbage(spec) => Some(spec),
+            BadActorStrategy::Adaptive => None,
+        };
+        
+        let mut outgoing = Vec::new();
+        for (idx, agent) in self.agents.iter_mut().enumerate() {
+            if evolving && !tick.is_multiple_of(agent.gossip_interval()) {
+                continue;
+            }
+            let packets = agent.recent_packets().to_vec();
+            if evolving {
+                agent.record_message_sent_metric(packets.iter().map(wire_bytes).sum());
+            }
+            outgoing.extend(packets.into_iter().map(|p| (idx, p)));
+            
+            if let Some(spec) = garbage.filter(|_| self.bad_actors.contains(&idx)) {
+                for _ in 0..spec.packets_per_round {
+                    outgoing.push((idx, spec.packet(&mut self.rngs.bad_actors, time)));
+                }
+            }
+        }
+        
+        for (from_idx, packet) in outgoing {
+            if let Some(observer) = self.observer.as_mut() {
+                observer.ingest_gossip(std::slice::from_ref(&packet));
+            }
+            let bytes = wire_bytes(&packet);
+            self.packets_offered += 1;
+            self.bytes_offered += bytes;
+            if self.packet_loss > 0.0 && self.rngs.gossip_loss(from_idx).gen::<f64>() < self.packet_loss {
+                self.packets_lost += 1;
+                self.bytes_lost += bytes;
+                continue;
+            }
+            self.network.queue_gossip(from_idx, packet);
+        }
+        self.network.deliver_round();
+        
+        for (idx, agent) in self.agents.iter_mut().enumerate() {
+            let limit = if evolving { agent.max_gossip_neighbors() } else { usize::MAX };
+            let incoming = self.network.take_gossip_from(idx, limit);
+            agent.receive_attributed_gossip(&incoming);
+            agent.clear_recent_packets();
+        }
+    }
+    
+    /// Sends every agent's reputation summary, lies included, to each of its
+    /// neighbors. Summaries are taken before any is blended in.
+    fn share_reputations(&mut self, sharing: ReputationSharing) {
+        let summaries: Vec<ReputationSummary> = (0..self.agents.len())
+            .map(|idx| {
+                let mut summary = self.agents[idx].reputation_summary();
+                if let Some((_, victim)) = self.false_accusations.as_ref().filter(|(liars, _)| liars.contains(&idx)) {
+                    summary.scores.insert(*victim, 0.0);
+                }
+                summary
+            })
+            .collect();
+        for (from_idx, summary) in summaries.iter().enumerate() {
+            if summary.is_empty() {
+                continue;
+            }
+            for &to_idx in self.network.neighbors(from_idx) {
+                self.reputation_messages += 1;
+                self.reputation_bytes += summary.wire_bytes() as u64;
+                if self.packet_loss > 0.0 && self.rngs.link_loss(from_idx, to_idx).gen::<f64>() < self.packet_loss {
+                    continue;
+                }
+                self.agents[to_idx].receive_reputation_summary(from_idx, summary, sharing.trust_discount);
+            }
+        }
+    }
+    
+    /// The profile agent `idx` runs, if profiles were assigned.
+    pub fn profile(&self, idx: usize) -> Option<&AgentProfile> {
+        self.profiles.as_ref().and_then(|p| p.get(idx))
+    }
+    
+    /// Whether agent `idx` takes part in evolution (when there is any).
+    fn evolves(&self, idx: usize) -> bool {
+        self.profile(idx).is_none_or(|p| p.evolves)
+    }
+    
+    /// Agent indices by profile name, in order of each name's first agent.
+    /// Empty without profiles.
+    pub fn profile_groups(&self) -> Vec<(String, Vec<usize>)> {
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        for (idx, profile) in self.profiles.iter().flatten().enumerate() {
+            match groups.iter_mut().find(|(name, _)| *name == profile.name) {
+                Some((_, members)) => members.push(idx),
+                None => groups.push((profile.name.clone(), vec![idx])),
+            }
+        }
+        groups
+    }
+    
+    /// Seconds per tick.
+    pub fn dt(&self) -> f64 {
+        self.dt
+    }
+    
+    /// Simulated time in seconds.
+    pub fn time(&self) -> f64 {
+        self.oracle.time()
+    }
+    
+    /// Returns the oracle.
+    pub fn oracle(&self) -> &Oracle {
+        &self.oracle
+    }
+    
+    /// Returns the oracle, e.g. to spawn entities before the first step.
+    pub fn oracle_mut(&mut self) -> &mut Oracle {
+        &mut self.oracle
+    }
+    
+    /// Returns the scenario RNG, e.g. to randomize entities before the first step.
+    pub fn rng_mut(&mut self) -> &mut AuditedRng {
+        &mut self.rngs.scenario
+    }
+    
+    /// Returns all agents, bad actors included.
+    pub fn agents(&self) -> &[SimulatedAgent] {
+        &self.agents
+    }
+    
+    /// Returns the agents that are not bad actors.
+    pub fn good_agents(&self) -> impl Iterator<Item = &SimulatedAgent> + '_ {
+        self.agents.iter()
+            .enumerate()
+            .filter(|(idx, _)| !self.bad_actors.contains(idx))
+            .map(|(_, a)| a)
+    }
+    
+    /// Indices of the current bad actors (empty until a random schedule fires).
+    pub fn bad_actors(&self) -> &[usize] {
+        &self.bad_actors
+    }
+    
+    /// The agents a random schedule turned and when (None until it fires).
+    pub fn bad_actor_injection(&self) -> Option<&BadActorInjection> {
+        self.bad_actor_injection.as_ref()
+    }
+    
+    /// Returns the agents' sensor positions, if they were placed.
+    pub fn sensor_positions(&self) -> Option<&[Vector3<f64>]> {
+        self.sensor_positions.as_deref()
+    }
+    
+    /// How continuously the placed sensors saw each entity so far (None
+    /// unless sensors were placed).
+    pub fn observation_coverage(&self) -> Option<ObservationCoverage> {
+        self.sensor_positions.as_ref().map(|_| self.observation.coverage())
+    }
+    
+    /// Returns the gossip network.
+    pub fn network(&self) -> &SwarmNetwork {
+        &self.network
+    }
+    
+    /// Returns the cells each agent answers for, if sharded.
+    pub fn shard_map(&self) -> Option<&ShardMap> {
+        self.shards.as_ref().map(|s| &s.map)
+    }
+    
+    /// Returns agent `idx`'s spatial index of the tracks it kept after the
+    /// last tick, if sharded.
+    pub fn shard_index(&self, idx: usize) -> Option<&SpatialEngine> {
+        self.shards.as_ref().and_then(|s| s.engine(idx))
+    }
+    
+    /// Returns the handoffs, dropped readings and dropped tracks so far, if
+    /// sharded.
+    pub fn shard_stats(&self) -> Option<ShardStats> {
+        self.shards.as_ref().map(|s| s.stats)
+    }
+    
+    /// Returns the observer, if one was added.
+    pub fn observer(&self) -> Option<&ObserverAgent> {
+        self.observer.as_ref()
+    }
+    
+    /// Time spent in per-agent work, and the speedup threads gave it.
+    pub fn pool_stats(&self) -> PoolStats {
+        self.pool.stats()
+    }
+    
+    /// Packets offered to the network, garbage included, before loss.
+    pub fn packets_offered(&self) -> u64 {
+        self.packets_offered
+    }
+    
+    /// Packets dropped by [`with_packet_loss`](Self::with_packet_loss).
+    pub fn packets_lost(&self) -> u64 {
+        self.packets_lost
+    }
+    
+    /// Wire bytes of the packets offered.
+    pub fn bytes_offered(&self) -> u64 {
+        self.bytes_offered
+    }
+    
+    /// Wire bytes of the packets lost.
+    pub fn bytes_lost(&self) -> u64 {
+        self.bytes_lost
+    }
+    
+    /// Reputation summaries sent to neighbors, lost ones included.
+    pub fn reputation_messages(&self) -> u64 {
+        self.reputation_messages
+    }
+    
+    /// Wire bytes of those summaries.
+    pub fn reputation_bytes(&self) -> u64 {
+        self.reputation_bytes
+    }
+    
+    /// How well good agents' reputation scores, first-hand or blended,
+    /// match the bad actors (see [`ReputationConsensus`]). Liars
+    /// ([`Self::with_false_accusations`]) don't count as observers.
+    pub fn reputation_consensus(&self) -> ReputationConsensus {
+        ReputationConsensus::measure(
+            self.honest_observers().map(|(idx, agent)| (idx, agent.adaptive_state())),
+            &self.bad_actors,
+        )
+    }
+    
+    /// Mean score good agents other than `subject` and any liars give
+    /// `subject`, over those holding one (None if none do).
+    pub fn average_reputation(&self, subject: usize) -> Option<f64> {
+        let scores: Vec<f64> = self.honest_observers()
+            .filter(|(idx, _)| *idx != subject)
+            .filter_map(|(_, agent)| agent.adaptive_state().neighbor_reputations.get(&subject))
+            .map(|rep| rep.reliability_score)
+            .collect();
+        (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64)
+    }
+    
+    /// Agents that are neither bad actors nor liars, with their indices.
+    fn honest_observers(&self) -> impl Iterator<Item = (usize, &SimulatedAgent)> + '_ {
+        let liars = self.false_accusations.as_ref().map_or(&[][..], |(liars, _)| liars.as_slice());
+        self.agents.iter()
+            .enumerate()
+            .filter(move |(idx, _)| !self.bad_actors.contains(idx) && !liars.contains(idx))
+    }
+    
+    /// Counts good agents' verdicts on neighboring bad actors as
+    /// (identified, possible): identified when the good agent's reliability
+    /// score for the bad actor is below `threshold`.
+    pub fn bad_actor_detection(&self, threshold: f64) -> (usize, usize) {
+        let mut identified = 0;
+        let mut possible = 0;
+        for (idx, agent) in self.agents.iter().enumerate() {
+            if self.bad_actors.contains(&idx) {
+                continue;
+            }
+            let neighbors = self.network.neighbors(idx);
+            for &bad_id in self.bad_actors.iter().filter(|id| neighbors.contains(id)) {
+                possible += 1;
+                if agent.adaptive_state().neighbor_reputations.get(&bad_id)
+                    .is_some_and(|rep| rep.reliability_score < threshold)
+                {
+                    identified += 1;
+                }
+            }
+        }
+        (identified, possible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Vector3;
+    use crate::adaptive::GOSSIP_FILTER_THRESHOLD;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    
+    #[test]
+    fn test_gossip_round_loss_and_garbage_accounting() {
+        let garbage = GarbageSpec {
+            packets_per_round: 2,
+            min_position: [-10.0, -10.0, 0.0],
+            max_position: [10.0, 10.0, 5.0],
+            max_speed: 0.0,
+            confidence: (0.1, 0.1),
+        };
+        let mut harness = SwarmHarness::new(7, 2, 2, 10)
+            .with_gossip_interval(2)
+            .with_bad_actors(BadActorSchedule::Fixed(vec![0]), BadActorStrategy::Garbage(garbage))
+            .with_observer();
+        harness.oracle_mut().spawn_entity(Vector3::new(0.0, 0.0, 10.0), Vector3::new(1.0, 0.0, 0.0), "t");
+        
+        let reports: Vec<_> = (0..4).map(|_| harness.step()).collect();
+        assert_eq!(reports.iter().filter(|r| r.gossip_round).count(), 2);
+        
+        // Each agent sends one packet per tick since the last round, and
+        // agent 0 adds 2 garbage packets: (4 + 2) + (4 * 2 + 2)
+        assert_eq!(harness.packets_offered(), 16);
+        assert_eq!(harness.packets_lost(), 0);
+        assert_eq!(harness.good_agents().count(), 3);
+        assert!(harness.agents()[3].gossip_received() > 0);
+        
+        let mut lossy = SwarmHarness::new(7, 2, 2, 10).with_packet_loss(1.0);
+        lossy.oracle_mut().spawn_entity(Vector3::new(0.0, 0.0, 10.0), Vector3::new(1.0, 0.0, 0.0), "t");
+        lossy.step();
+        assert_eq!(lossy.packets_lost(), lossy.packets_offered());
+        assert_eq!(lossy.bytes_lost(), lossy.bytes_offered());
+        assert!(lossy.bytes_offered() > 0);
+        assert_eq!(lossy.network().messages_sent(), 0);
+    }
+    
+    #[test]
+    fn test_agent_profiles_configure_each_agent() {
+        const ROWS: usize = 2;
+        let mut harness = SwarmHarness::new(5, ROWS, 2, 10)
+            .with_evolution(5, || Box::new(crate::evolution::OracleFitness::new()))
+            .with_agent_profiles(|idx| match idx {
+                0 => AgentProfile::new("precise", SimulatedAgent::default_config()),
+                1 => AgentProfile::new("spent", SimulatedAgent::default_config()).with_energy(0.0),
+                _ => AgentProfile::new("noisy", SimulatedAgent::default_config()).with_sensor_noise(20.0).without_evolution(),
+            });
+        harness.oracle_mut().spawn_entity(Vector3::new(0.0, 0.0, 10.0), Vector3::zeros(), "t");
+        
+        let groups = harness.profile_groups();
+        let names: Vec<&str> = groups.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["precise", "spent", "noisy"]);
+        assert_eq!(groups[2].1, [2, 3]);
+        assert_eq!(harness.profile(3).unwrap().sensor_noise_m, 20.0);
+        assert!(!harness.agents()[1].is_alive());
+        
+        for _ in 0..20 {
+            harness.step();
+        }
+        let ground_truth = harness.oracle().ground_truth_positions();
+        let error = |idx: usize| harness.agents()[idx].compute_position_error(&ground_truth);
+        assert!(error(2) > error(0), "noisy {} vs precise {}", error(2), error(0));
+        assert!(!harness.agents()[0].evolution_history().is_empty());
+        assert!(harness.agents()[2].evolution_history().is_empty());
+        assert_eq!(harness.agents().len(), ROWS * 2);
+    }
+    
+    #[test]
+    fn test_random_bad_actors_appear_on_schedule() {
+        let garbage = GarbageSpec {
+            packets_per_round: 1,
+            min_position: [0.0; 3],
+            max_position: [1.0; 3],
+            max_speed: 5.0,
+            confidence: (0.0, 0.5),
+        };
+        let schedule = BadActorSchedule::RandomAt { count: 3, at_secs: 0.5, placement: BadActorPlacement::Anywhere };
+        let mut harness = SwarmHarness::new(3, 2, 5, 10)
+            .with_bad_actors(schedule, BadActorStrategy::Garbage(garbage));
+        for _ in 0..5 {
+            harness.step();
+        }
+        assert!(harness.bad_actors().is_empty());
+        assert!(harness.bad_actor_injection().is_none());
+        harness.step();
+        assert_eq!(harness.bad_actors().len(), 3);
+        let injection = harness.bad_actor_injection().unwrap();
+        assert_eq!((injection.ids.as_slice(), injection.tick), (harness.bad_actors(), 5));
+        
+        // The schedule fires once
+        harness.step();
+        assert_eq!(harness.bad_actor_injection().unwrap().tick, 5);
+    }
+    
+    #[test]
+    fn test_bad_actor_draws_are_exact_and_reproducible() {
+        let network = SwarmNetwork::new_grid(5, 10);
+        let draw = |seed: u64, count, placement| {
+            draw_bad_actors(&mut ChaCha8Rng::seed_from_u64(seed), &network, count, placement)
+        };
+        let neighbor_pairs = |ids: &[usize]| {
+            ids.iter().filter(|&&a| network.neighbors(a).iter().any(|n| ids.contains(n))).count()
+        };
+        
+        for seed in 0..200 {
+            for placement in [BadActorPlacement::Anywhere, BadActorPlacement::Apart, BadActorPlacement::Clustered] {
+                let ids = draw(seed, 5, placement);
+                let mut distinct = ids.clone();
+                distinct.sort_unstable();
+                distinct.dedup();
+                assert_eq!(distinct.len(), 5, "seed {seed} {placement:?}: {ids:?}");
+                assert!(ids.iter().all(|&idx| idx < 50));
+                assert_eq!(ids, draw(seed, 5, placement), "seed {seed} {placement:?} not reproducible");
+                
+                match placement {
+                    BadActorPlacement::Apart => assert_eq!(neighbor_pairs(&ids), 0, "seed {seed}: {ids:?}"),
+                    BadActorPlacement::Clustered => assert_eq!(neighbor_pairs(&ids), 5, "seed {seed}: {ids:?}"),
+                    BadActorPlacement::Anywhere => {}
+                }
+            }
+        }
+        
+        // More than the grid holds is every agent; apart more than fits still fills up
+        assert_eq!(draw(1, 60, BadActorPlacement::Anywhere).len(), 50);
+        assert_eq!(draw(1, 40, BadActorPlacement::Apart).len(), 40);
+        assert!(draw(1, 0, BadActorPlacement::Clustered).is_empty());
+    }
+    
+    #[test]
+    fn test_parallel_agent_work_matches_serial() {
+        let run = |threads: usize| {
+            let mut harness = SwarmHarness::new(11, 3, 4, 10)
+                .with_sensor_faults(SensorFaults::Blackout { blackout_probability: 0.1, noise_probability: 0.5, noise_m: 2.0 })
+                .with_bad_actors(BadActorSchedule::Fixed(vec![5]), BadActorStrategy::Adaptive)
+                .with_threads(threads);
+            for i in 0..4 {
+                harness.oracle_mut().spawn_entity(Vector3::new(i as f64 * 20.0, 0.0, 50.0), Vector3::new(2.0, 1.0, 0.0), "t");
+            }
+            for _ in 0..60 {
+                harness.step();
+            }
+            assert_eq!(harness.pool_stats().threads, threads);
+            harness.agents()
+                .iter()
+                .map(|agent| {
+                    let mut tracks = agent.track_positions();
+                    tracks.sort_by_key(|(uuid, _)| *uuid);
+                    (tracks, agent.gossip_received())
+                })
+                .collect::<Vec<_>>()
+        };
+        
+        let serial = run(1);
+        assert!(serial.iter().all(|(tracks, _)| !tracks.is_empty()));
+        assert_eq!(serial, run(4), "parallel run diverged from serial");
+    }
+    
+    #[test]
+    fn test_scanning_sensors_cover_the_field_over_time() {
+        let coverage = |scan_rate_dps: f64| {
+            let mut harness = SwarmHarness::new(5, 2, 2, 10)
+                .with_sensor_positions(vec![Vector3::zeros(); 4])
+                .with_sensor_scan(60.0, scan_rate_dps);
+            harness.oracle_mut().spawn_entity(Vector3::new(100.0, 0.0, 20.0), Vector3::zeros(), "ahead");
+            harness.oracle_mut().spawn_entity(Vector3::new(100.0, 100.0, 20.0), Vector3::zeros(), "between");
+            for _ in 0..20 {
+                harness.step();
+            }
+            harness.observation_coverage().unwrap()
+        };
+        
+        // Fixed sensors face 0, 90, 180 and 270 degrees
+        let fixed = coverage(0.0);
+        assert_eq!((fixed.entities, fixed.mean_fraction, fixed.min_fraction), (2, 0.5, 0.0));
+        
+        let scanning = coverage(90.0);
+        assert!(scanning.min_fraction > 0.0, "{scanning:?}");
+        assert!(SwarmHarness::new(5, 2, 2, 10).observation_coverage().is_none());
+    }
+    
+    #[test]
+    fn test_moving_agents_carry_sensors_and_relink() {
+        // Agent 1 drives away from agent 0 at 10 m/s; agent 0 stays put
+        let mut harness = SwarmHarness::new(5, 1, 2, 10)
+            .with_agent_profiles(|idx| {
+                let profile = AgentProfile::new("convoy", SimulatedAgent::default_config());
+                match idx {
+                    1 => profile.with_motion(AgentMotion::Velocity(Vector3::new(10.0, 0.0, 0.0))),
+                    _ => profile,
+                }
+            })
+            .with_sensor_positions(vec![Vector3::zeros(), Vector3::new(20.0, 0.0, 0.0)])
+            .with_sensor_scan(360.0, 0.0)
+            .with_radio_range(30.0);
+        harness.oracle_mut().set_sensor_range(25.0);
+        harness.oracle_mut().spawn_entity(Vector3::new(40.0, 0.0, 0.0), Vector3::zeros(), "parked");
+        assert_eq!(harness.network().neighbors(0), [1]);
+        
+        harness.step();
+        assert_eq!(harness.sensor_positions().unwrap()[1], Vector3::new(21.0, 0.0, 0.0));
+        assert_eq!(harness.oracle().scanner(1).unwrap().pose.position, Vector3::new(21.0, 0.0, 0.0));
+        assert!(harness.oracle_mut().generate_readings_for_agent(0, DEFAULT_SENSOR_ID).is_empty(), "out of range");
+        assert_eq!(harness.oracle_mut().generate_readings_for_agent(1, DEFAULT_SENSOR_ID).len(), 1);
+        
+        for _ in 0..10 {
+            harness.step();
+        }
+        assert!(harness.network().neighbors(0).is_empty());
+        assert_eq!(harness.network().link_changes(), 2);
+    }
+    
+    #[test]
+    fn test_false_accusations_are_outvoted() {
+        // Placed sensors with a short range: each agent gossips what its
+        // neighbors can't sense, so honest agents earn high scores
+        let positions: Vec<_> = (0..16).map(|i| Vector3::new((i % 4) as f64 * 30.0, (i / 4) as f64 * 30.0, 0.0)).collect();
+        let mut harness = SwarmHarness::new(42, 4, 4, 10)
+            .with_sensor_positions(positions)
+            .with_reputation_sharing(ReputationSharing::new(10))
+            .with_false_accusations(vec![6], 5);
+        harness.oracle_mut().set_sensor_range(35.0);
+        for i in 0..36 {
+            let position = Vector3::new((i % 6) as f64 * 20.0, (i / 6) as f64 * 20.0, 10.0);
+            harness.oracle_mut().spawn_entity(position, Vector3::zeros(), "target");
+        }
+        for _ in 0..150 {
+            harness.step();
+        }
+        
+        // The lie reached agent 6's neighbors, but no honest agent filters
+        // agent 5 and the swarm's average is well above the threshold
+        let scores: Vec<f64> = harness.honest_observers()
+            .filter_map(|(_, agent)| agent.adaptive_state().neighbor_reputations.get(&5))
+            .map(|rep| rep.reliability_score)
+            .collect();
+        let lowest = scores.iter().copied().fold(f64::INFINITY, f64::min);
+        assert!((GOSSIP_FILTER_THRESHOLD..0.9).contains(&lowest), "lowest={lowest}");
+        assert!(harness.average_reputation(5).unwrap() > GOSSIP_FILTER_THRESHOLD);
+        assert_eq!(harness.reputation_consensus().accuracy, 1.0);
+        
+        // Summaries go to every neighbor on each sharing tick
+        let links: usize = (0..16).map(|i| harness.network().neighbors(i).len()).sum();
+        assert_eq!(harness.reputation_messages(), 15 * links as u64);
+        assert!(harness.reputation_bytes() > 0);
+    }
+}
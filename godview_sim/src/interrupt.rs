@@ -0,0 +1,72 @@
+//! Graceful interruption of long sweeps.
+//!
+//! An [`Interrupt`] is a shared flag. The CLI sets it from a Ctrl-C handler
+//! ([`Interrupt::on_ctrl_c`]); a [`ScenarioRunner`](crate::ScenarioRunner)
+//! with it installed checks it after every tick and ends the run the same
+//! way an abort does, so the caller can still write out everything that
+//! completed before the signal.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Exit code of a run stopped by Ctrl-C (128 + SIGINT, as shells report it).
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Cloneable flag asking a run to stop after its current tick.
+#[derive(Debug, Clone, Default)]
+pub struct Interrupt {
+    flag: Arc<AtomicBool>,
+}
+
+impl Interrupt {
+    /// A flag that hasn't been set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// A flag set by the first Ctrl-C. A second Ctrl-C exits the process at
+    /// once with [`INTERRUPTED_EXIT_CODE`], for when flushing hangs.
+    ///
+    /// The handler runs on its own thread, so it works whether or not the
+    /// caller is inside an async runtime.
+    pub fn on_ctrl_c() -> std::io::Result<Self> {
+        let interrupt = Self::new();
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_io().build()?;
+        let flag = interrupt.clone();
+        std::thread::Builder::new().name("ctrl-c".into()).spawn(move || {
+            runtime.block_on(async {
+                while tokio::signal::ctrl_c().await.is_ok() {
+                    if flag.is_set() {
+                        std::process::exit(INTERRUPTED_EXIT_CODE);
+                    }
+                    flag.trigger();
+                }
+            });
+        })?;
+        Ok(interrupt)
+    }
+    
+    /// Asks every holder of this flag to stop.
+    pub fn trigger(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+    }
+    
+    /// Whether [`Self::trigger`] has been called.
+    pub fn is_set(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_interrupt_is_shared_by_clones() {
+        let interrupt = Interrupt::new();
+        let held_by_runner = interrupt.clone();
+        assert!(!held_by_runner.is_set());
+        interrupt.trigger();
+        assert!(held_by_runner.is_set());
+    }
+}
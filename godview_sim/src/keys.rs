@@ -1,10 +1,31 @@
 //! Deterministic key provider for simulation.
+//!
+//! Per-agent signing keys are derived with HKDF-SHA256 from the master seed,
+//! so every agent has its own identity and keys can be rotated by epoch
+//! without touching any other agent's key.
 
 use ed25519_dalek::{SigningKey, VerifyingKey};
-use rand::SeedableRng;
-use rand_chacha::ChaCha8Rng;
+use crate::rng_audit::AuditedRng;
+use hkdf::Hkdf;
+use sha2::Sha256;
 use std::collections::HashMap;
 
+/// HKDF salt for agent signing keys. Bump the version to re-key every agent.
+const AGENT_KEY_SALT: &[u8] = b"godview-sim/agent-signing-key/v1";
+
+/// A key rotation performed through [`DeterministicKeyProvider::rotate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyRotation {
+    /// Agent whose key was rotated
+    pub agent_id: u64,
+    
+    /// Epoch the agent was on before the rotation
+    pub from_epoch: u64,
+    
+    /// Epoch the agent is on after the rotation
+    pub to_epoch: u64,
+}
+
 /// Provides deterministic Ed25519 keys derived from seeds.
 ///
 /// In simulation, we need reproducible keys for each agent.
@@ -21,22 +42,35 @@ pub struct DeterministicKeyProvider {
     
     /// Root key for the simulation (for Trust Engine)
     root_key: SigningKey,
+    
+    /// Current key epoch per agent (absent = epoch 0)
+    epochs: HashMap<u64, u64>,
+    
+    /// Rotations performed, in order
+    rotations: Vec<KeyRotation>,
 }
 
 impl DeterministicKeyProvider {
     /// Creates a new key provider with the given master seed.
     pub fn new(master_seed: u64) -> Self {
-        // Generate root key from master seed
-        let mut rng = ChaCha8Rng::seed_from_u64(master_seed);
+        let mut rng = AuditedRng::derive(master_seed, "keys/root");
         let root_key = SigningKey::generate(&mut rng);
         
         Self {
             master_seed,
             key_cache: HashMap::new(),
             root_key,
+            epochs: HashMap::new(),
+            rotations: Vec::new(),
         }
     }
     
+    /// Stream `name` of the master seed (see [`AuditedRng::derive`]), e.g.
+    /// `derive_stream("oracle/noise")`. Independent of the key streams.
+    pub fn derive_stream(&self, name: &str) -> AuditedRng {
+        AuditedRng::derive(self.master_seed, name)
+    }
+    
     /// Returns the root signing key (for Trust Engine root authority).
     pub fn root_signing_key(&self) -> &SigningKey {
         &self.root_key
@@ -52,29 +86,19 @@ impl DeterministicKeyProvider {
     /// This is needed because biscuit-auth has its own key type that's incompatible
     /// with ed25519-dalek keys. We derive a separate biscuit key from the seed.
     pub fn biscuit_root_key(&self) -> biscuit_auth::KeyPair {
-        use rand::SeedableRng;
-        use rand_chacha::ChaCha8Rng;
-        // Use a different salt for biscuit keys to avoid collision
-        let biscuit_seed = self.master_seed.wrapping_mul(0x3c6ef372fe94f82b);
-        let mut rng = ChaCha8Rng::seed_from_u64(biscuit_seed);
+        let mut rng = AuditedRng::derive(self.master_seed, "keys/biscuit");
         biscuit_auth::KeyPair::new_with_rng(&mut rng)
     }
     
     /// Generates or retrieves the signing key for an agent.
     ///
-    /// The key is derived deterministically from:
-    /// `master_seed XOR (agent_id * prime)`
+    /// The key is drawn from the master seed's `keys/agent-{id}` stream.
     pub fn agent_key(&mut self, agent_id: u64) -> SigningKey {
         if let Some(key) = self.key_cache.get(&agent_id) {
             return key.clone();
         }
         
-        // Derive unique seed for this agent
-        let agent_seed = self.master_seed
-            .wrapping_mul(0x9e3779b97f4a7c15)  // Golden ratio prime
-            .wrapping_add(agent_id.wrapping_mul(0x517cc1b727220a95));
-        
-        let mut rng = ChaCha8Rng::seed_from_u64(agent_seed);
+        let mut rng = AuditedRng::derive(self.master_seed, &format!("keys/agent-{}", agent_id));
         let key = SigningKey::generate(&mut rng);
         
         self.key_cache.insert(agent_id, key.clone());
@@ -87,6 +111,132 @@ impl DeterministicKeyProvider {
             .map(|id| self.agent_key(id))
             .collect()
     }
+    
+    /// Returns the agent's signing key for its current epoch.
+    ///
+    /// Derived as `HKDF-SHA256(salt, master_seed, agent_id || epoch)`, so the
+    /// key depends only on the seed, the agent and the epoch.
+    pub fn agent_signing_key(&self, agent_id: u64) -> SigningKey {
+        self.agent_signing_key_at(agent_id, self.current_epoch(agent_id))
+    }
+    
+    /// Returns the agent's signing key for a specific epoch.
+    pub fn agent_signing_key_at(&self, agent_id: u64, epoch: u64) -> SigningKey {
+        let mut info = [0u8; 16];
+        info[..8].copy_from_slice(&agent_id.to_le_bytes());
+        info[8..].copy_from_slice(&epoch.to_le_bytes());
+        
+        let mut secret = [0u8; 32];
+        hkdf_sha256(AGENT_KEY_SALT, &self.master_seed.to_le_bytes(), &info, &mut secret);
+        SigningKey::from_bytes(&secret)
+    }
+    
+    /// Returns the agent's current key epoch (0 until first rotated).
+    pub fn current_epoch(&self, agent_id: u64) -> u64 {
+        self.epochs.get(&agent_id).copied().unwrap_or(0)
+    }
+    
+    /// Rotates the agent's key to `epoch` and returns the new key.
+    ///
+    /// Epochs only move forward: rotating to an epoch at or before the
+    /// current one returns that epoch's key but records nothing.
+    pub fn rotate(&mut self, agent_id: u64, epoch: u64) -> SigningKey {
+        let from_epoch = self.current_epoch(agent_id);
+        if epoch > from_epoch {
+            self.epochs.insert(agent_id, epoch);
+            self.rotations.push(KeyRotation { agent_id, from_epoch, to_epoch: epoch });
+        }
+        self.agent_signing_key_at(agent_id, epoch)
+    }
+    
+    /// Returns all rotations performed so far.
+    pub fn rotations(&self) -> &[KeyRotation] {
+        &self.rotations
+    }
+}
+
+/// Result of checking a signer's key against a [`KeyRegistry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyStatus {
+    /// The key is the agent's current key
+    Current,
+    
+    /// The key belonged to the agent in an earlier epoch
+    Stale { epoch: u64 },
+    
+    /// The key was never registered for this agent
+    Unknown,
+}
+
+/// Maps agent IDs to the public key they are expected to sign with.
+///
+/// Keys replaced by a rotation are remembered so that packets signed with
+/// them can be reported as stale rather than simply unknown.
+#[derive(Debug, Clone, Default)]
+pub struct KeyRegistry {
+    /// Current (epoch, key) per agent
+    current: HashMap<u64, (u64, VerifyingKey)>,
+    
+    /// Retired keys per agent: (epoch, key)
+    retired: HashMap<u64, Vec<(u64, VerifyingKey)>>,
+}
+
+impl KeyRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Creates a registry holding the current keys of agents `0..num_agents`.
+    pub fn from_provider(provider: &DeterministicKeyProvider, num_agents: usize) -> Self {
+        let mut registry = Self::new();
+        for id in 0..num_agents as u64 {
+            registry.register(id, provider.current_epoch(id), provider.agent_signing_key(id).verifying_key());
+        }
+        registry
+    }
+    
+    /// Registers `key` as the agent's key for `epoch`.
+    ///
+    /// A later epoch replaces the current key and retires the old one; an
+    /// earlier or equal epoch is ignored.
+    pub fn register(&mut self, agent_id: u64, epoch: u64, key: VerifyingKey) {
+        match self.current.get(&agent_id) {
+            Some(&(current_epoch, _)) if epoch <= current_epoch => {}
+            Some(&(current_epoch, current_key)) => {
+                self.retired.entry(agent_id).or_default().push((current_epoch, current_key));
+                self.current.insert(agent_id, (epoch, key));
+            }
+            None => {
+                self.current.insert(agent_id, (epoch, key));
+            }
+        }
+    }
+    
+    /// Returns the agent's current epoch, if registered.
+    pub fn epoch(&self, agent_id: u64) -> Option<u64> {
+        self.current.get(&agent_id).map(|&(epoch, _)| epoch)
+    }
+    
+    /// Checks whether `key` is the agent's current key.
+    pub fn check(&self, agent_id: u64, key: &VerifyingKey) -> KeyStatus {
+        if let Some((_, current)) = self.current.get(&agent_id) {
+            if current == key {
+                return KeyStatus::Current;
+            }
+        }
+        self.retired.get(&agent_id)
+            .and_then(|keys| keys.iter().find(|(_, k)| k == key))
+            .map(|&(epoch, _)| KeyStatus::Stale { epoch })
+            .unwrap_or(KeyStatus::Unknown)
+    }
+}
+
+/// HKDF-SHA256 extract-and-expand (RFC 5869) into `okm`.
+fn hkdf_sha256(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) {
+    Hkdf::<Sha256>::new(Some(salt), ikm)
+        .expand(info, okm)
+        .expect("HKDF output too long");
 }
 
 #[cfg(test)]
@@ -148,4 +298,68 @@ mod tests {
             );
         }
     }
+    
+    #[test]
+    fn test_hkdf_rfc5869_vector() {
+        // RFC 5869 test case 1
+        let ikm = [0x0bu8; 22];
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+        let mut okm = [0u8; 42];
+        hkdf_sha256(&salt, &ikm, &info, &mut okm);
+        
+        let expected = "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865";
+        let hex: String = okm.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(hex, expected);
+    }
+    
+    #[test]
+    fn test_agent_signing_keys_deterministic_and_distinct() {
+        let provider1 = DeterministicKeyProvider::new(42);
+        let provider2 = DeterministicKeyProvider::new(42);
+        let other_seed = DeterministicKeyProvider::new(43);
+        
+        assert_eq!(provider1.agent_signing_key(3).to_bytes(), provider2.agent_signing_key(3).to_bytes());
+        assert_ne!(provider1.agent_signing_key(3).to_bytes(), provider1.agent_signing_key(4).to_bytes());
+        assert_ne!(provider1.agent_signing_key(3).to_bytes(), other_seed.agent_signing_key(3).to_bytes());
+    }
+    
+    #[test]
+    fn test_rotation() {
+        let mut provider = DeterministicKeyProvider::new(42);
+        let epoch0 = provider.agent_signing_key(3);
+        let untouched = provider.agent_signing_key(4);
+        
+        let epoch1 = provider.rotate(3, 1);
+        assert_ne!(epoch0.to_bytes(), epoch1.to_bytes());
+        assert_eq!(provider.agent_signing_key(3).to_bytes(), epoch1.to_bytes());
+        assert_eq!(provider.agent_signing_key(4).to_bytes(), untouched.to_bytes());
+        
+        // Rotation is deterministic and never goes backwards
+        assert_eq!(DeterministicKeyProvider::new(42).rotate(3, 1).to_bytes(), epoch1.to_bytes());
+        provider.rotate(3, 0);
+        assert_eq!(provider.current_epoch(3), 1);
+        assert_eq!(provider.rotations(), &[KeyRotation { agent_id: 3, from_epoch: 0, to_epoch: 1 }]);
+    }
+    
+    #[test]
+    fn test_registry_reports_stale_keys() {
+        let mut provider = DeterministicKeyProvider::new(42);
+        let mut registry = KeyRegistry::from_provider(&provider, 4);
+        let old = provider.agent_signing_key(2).verifying_key();
+        
+        assert_eq!(registry.check(2, &old), KeyStatus::Current);
+        assert_eq!(registry.check(1, &old), KeyStatus::Unknown);
+        
+        let new = provider.rotate(2, 1).verifying_key();
+        registry.register(2, 1, new);
+        
+        assert_eq!(registry.epoch(2), Some(1));
+        assert_eq!(registry.check(2, &new), KeyStatus::Current);
+        assert_eq!(registry.check(2, &old), KeyStatus::Stale { epoch: 0 });
+        
+        // Replaying an old registration doesn't resurrect the stale key
+        registry.register(2, 0, old);
+        assert_eq!(registry.check(2, &old), KeyStatus::Stale { epoch: 0 });
+    }
 }
@@ -57,20 +57,103 @@ mod agent;
 mod runner;
 pub mod visualizer;
 pub mod exporter;
+pub mod export_filter;
 pub mod swarm_network;
 pub mod adaptive;
 pub mod evolution;
+pub mod priority;
+pub mod genealogy;
+pub mod report;
+pub mod bench;
+pub mod calibration;
+pub mod clock_sync;
+pub mod scoring;
+pub mod stats;
+pub mod metrics;
+pub mod corpus;
+pub mod trained;
+pub mod capture;
+pub mod failure;
+pub mod simulation;
+mod progress;
+mod observer;
+mod occlusion;
+mod gps_denial;
+mod spread;
+mod anti_entropy;
+mod campaign;
+mod episodes;
+mod faults;
+mod harness;
+mod parallel;
+mod pacing;
+mod interrupt;
+mod rng_audit;
+mod frustum;
+mod executor;
+mod mobility;
+mod sharding;
+mod reputation_gossip;
+mod gossip_sequence;
+mod delta_gossip;
+mod debug_repl;
+mod event_bus;
+mod status;
+#[cfg(feature = "dev-tools")]
+pub mod watch;
+#[cfg(feature = "python")]
+mod python;
 
 pub use context::SimContext;
-pub use world::{SimWorld, SimConfig};
-pub use oracle::{Oracle, GroundTruthEntity, SensorReading};
-pub use network::{SimNetwork, SimNetworkController};
-pub use keys::DeterministicKeyProvider;
-pub use agent::SimulatedAgent;
-pub use runner::{ScenarioRunner, ScenarioResult, ScenarioMetrics};
+pub use executor::SimExecutor;
+pub use world::{SimWorld, SimConfig, Visibility, GossipConfig, SensorLinkFaults};
+pub use simulation::{Simulation, SimFrames, SimEventRequest, TickReport, EventScript, ScheduledEvent};
+pub use oracle::{Oracle, GroundTruthEntity, SensorReading, SensorModality, DetectionModel, ClassConfusion, class_id_of, is_ballistic_class, GroundContact, CLUTTER_ENTITY_ID_BASE, MIN_BOUNCE_SPEED, STANDARD_GRAVITY, VEHICLE_CLASS_ID, PEDESTRIAN_CLASS_ID, CYCLIST_CLASS_ID, DRONE_CLASS_ID};
+pub use network::{DelayQueue, LatencyHistogram, LatencyModel, LinkFaultStats, SimNetwork, SimNetworkController};
+pub use keys::{DeterministicKeyProvider, KeyRegistry, KeyRotation, KeyStatus};
+pub use agent::{SimulatedAgent, BroadcastConfidence, GossipRejection, GossipRelay, GossipMode, SignedGossipStats, NetworkStats};
+pub use runner::{ScenarioRunner, ScenarioResult, ScenarioError, ScenarioMetrics, ProfileRms, PriorityClassMetrics, LearningArmMetrics, PartitionDivergence, PartitionSample, RmsSampler, DEFAULT_WARMUP_SECS};
+pub use metrics::{MetricsRegistry, MetricsSnapshot, MetricKey, Histogram, AgentSample};
+pub use corpus::{SeedCorpus, CorpusEntry, RunOrigin};
+pub use trained::TrainedParams;
+pub use failure::{FailureKind, Bound, first_failure};
+pub use capture::{CaptureConfig, FrameRecorder, InvariantViolation, capture_file_name, DEFAULT_CAPTURE_WINDOW_SECS, MAX_FRAMES_PER_SIDE};
+pub use clock_sync::{TimeAlignment, ClockOffsetEstimator, OffsetEstimate, ClockSkewStats, SkewSample};
+pub use stats::{ConvergenceStats, AgentConvergence, swarm_convergence};
+pub use scoring::{MotMetrics, MotAccumulator, IdChurn, MotSampler, MotTrack, ClutterMetrics, ClutterSampler, CoastingMetrics, CoastingSampler, TrackMultiplicity, TruthLabel, label_tracks, DEFAULT_MATCH_GATE_M};
+pub use bench::{BenchBaseline, BenchRun, BenchComparison, MetricDelta, DEFAULT_BENCH_TOLERANCE};
+pub use calibration::{CalibratedThresholds, CalibrationError, Threshold, ThresholdSource, calibrated_metrics, fit_threshold, DEFAULT_CALIBRATION_PERCENTILE, DEFAULT_CALIBRATION_MARGIN};
+pub use progress::{ProgressReport, ProgressFn, AbortFn, DEFAULT_PROGRESS_INTERVAL_TICKS};
+pub use status::{status_channel, StatusReceiver, StatusSender, StatusSnapshot};
+#[cfg(feature = "status-server")]
+pub use status::StatusServer;
+pub use occlusion::{Obstacle, OcclusionCoverage, sphere_blocks};
+pub use gps_denial::{GpsDenial, GpsDenialStats, GpsDenialSampler, GPS_RECOVERY_FACTOR};
+pub use spread::{LossSpread, SpreadSampler};
+pub use anti_entropy::{GossipDigest, digest_response, DIGEST_ENTRY_BYTES};
+pub use gossip_sequence::{SequenceTracker, SequenceRange, SequenceOutcome, SequenceStats, GossipNack, Retransmission, RetransmitBuffer, MAX_TRACKED_GAP, SEQUENCE_RANGE_BYTES, DEFAULT_RETRANSMIT_BUFFER, DEFAULT_MAX_NACKS};
+pub use reputation_gossip::{ReputationSharing, ReputationSummary, ReputationConsensus, blend_summary, REPUTATION_ENTRY_BYTES, DEFAULT_TRUST_DISCOUNT};
+pub use delta_gossip::{DeltaCompression, DeltaLink, PacketDelta, GossipFrame, CompressionStats};
+pub use campaign::{Campaign, CampaignPhase, CampaignError, CampaignMetrics, CampaignResult, CAMPAIGN_SCENARIOS, CAMPAIGN_RECOVERY_FACTOR};
+pub use episodes::{episode_seed, EpisodeCurve, EpisodeMetrics, EpisodesResult, ResetPolicy, EPISODIC_SCENARIOS};
+pub use observer::{ObserverAgent, AgentDivergence, DivergenceSampler};
+pub use faults::{Fault, FaultPlan, AgentStatus, FaultRecovery, RecoveryTracker, ConfigPerturbation, Perturbation, PerturbationImpact, PerturbationTracker, swarm_coverage, COVERAGE_RADIUS_M, RECOVERY_FRACTION};
+pub use harness::{SwarmHarness, AgentProfile, HarnessTick, BadActorSchedule, BadActorStrategy, BadActorPlacement, BadActorInjection, draw_bad_actors, GarbageSpec, SensorFaults, GARBAGE_CLASS_ID};
+pub use parallel::{AgentPool, PoolStats, available_threads};
+pub use pacing::Pacer;
+pub use interrupt::{Interrupt, INTERRUPTED_EXIT_CODE};
+pub use debug_repl::{DebugRepl, ReplCommand, ReplMetric, Comparison, StopCondition, REPL_HELP};
+pub use rng_audit::{RngAudit, RngAuditGuard, AuditedRng, RngTrace, RngSnapshot, RngDivergence, DEFAULT_RNG_AUDIT_INTERVAL_TICKS};
+pub use frustum::{SensorPose, ScanningSensor, ObservationCoverage, ObservationSampler};
+pub use mobility::AgentMotion;
+pub use sharding::{ShardMap, ShardStats, HandoffMetrics, HandoffSampler, HANDOFF_WINDOW_SECS, HANDOFF_MATCH_RADIUS_M};
 pub use visualizer::RerunLogger;
-pub use exporter::{SimExport, SimFrame, EntityPosition, AgentFrame, TrackPosition, SimEvent};
-pub use swarm_network::{SwarmNetwork, SwarmConfig};
-pub use adaptive::{AdaptiveState, AdaptiveMetrics, NeighborReputation, TrackConfidence};
+pub use event_bus::{SimEventBus, SimEvent, PublishedEvent, SimEventSubscriber, Subscription, ParallelPhase, EventLog, EventInvariants};
+pub use exporter::{SimExport, ExportFormat, SimFrame, EntityPosition, AgentFrame, TrackPosition, FrameEvent, AgentGenealogy, AgentEvolution, AgentGossipDecisions, ExportError, FrameDivergence, first_frame_divergence, SCHEMA_VERSION, SUPPORTED_SCHEMA_VERSIONS};
+pub use export_filter::{ExportFilter, RegionOfInterest, TrackRank, TopTracks};
+pub use swarm_network::{SwarmNetwork, SwarmConfig, LinkConfig, LinkStats, DropPolicy, RouteStats, wire_bytes};
+pub use genealogy::{TrackGenealogy, GenealogyEvent};
+pub use priority::{GossipOrder, GossipPriority, PacketValue};
+pub use adaptive::{AdaptiveState, AdaptiveMetrics, GossipQuota, GossipDecision, GossipDecisionCounts, GossipDecisionLog, GossipDecisionRecord, NeighborReputation, TrackConfidence, TrustInflation, GOSSIP_FILTER_THRESHOLD};
 
 
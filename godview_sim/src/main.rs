@@ -2,26 +2,52 @@
 //!
 //! Run deterministic simulation tests with chaos engineering scenarios.
 
-use clap::Parser;
-use godview_sim::{ScenarioRunner, ScenarioResult};
-use godview_sim::scenarios::ScenarioId;
-use godview_sim::{SimExport, SimFrame, EntityPosition, AgentFrame, TrackPosition};
+use clap::{Parser, Subcommand, ValueEnum};
+use godview_sim::{ScenarioRunner, ScenarioResult, RmsSampler, ProgressReport, AgentSample, MetricsRegistry};
+use godview_sim::{ObserverAgent, DivergenceSampler};
+use godview_sim::{SeedCorpus, RunOrigin, RerunLogger, Bound, FailureKind, TrainedParams, Interrupt, INTERRUPTED_EXIT_CODE};
+use godview_sim::{BenchBaseline, BenchComparison, CaptureConfig, DEFAULT_CAPTURE_WINDOW_SECS};
+use godview_sim::{Campaign, CampaignResult, EpisodeMetrics, EpisodesResult, ResetPolicy};
+use godview_sim::{CalibratedThresholds, ThresholdSource, DEFAULT_CALIBRATION_MARGIN, DEFAULT_CALIBRATION_PERCENTILE};
+use godview_sim::{RngTrace, DEFAULT_RNG_AUDIT_INTERVAL_TICKS};
+use godview_sim::{DebugRepl, EventScript, LatencyModel};
+use godview_sim::StatusSender;
+use godview_sim::evolution::GtWeightSchedule;
+use godview_sim::swarm_network::SwarmConfig;
+use godview_sim::DeltaCompression;
+use godview_sim::report;
+use godview_sim::scenarios::{ScenarioId, ScenarioInfo};
+use godview_sim::{SimExport, SimFrame, EntityPosition, AgentFrame, TrackPosition, ExportFormat, label_tracks, DEFAULT_MATCH_GATE_M};
+use godview_sim::{ExportFilter, RegionOfInterest, TrackRank};
 use godview_sim::{SimContext, SimNetwork, SimulatedAgent, Oracle, DeterministicKeyProvider};
 use godview_core::AgentConfig;
+use godview_env::determinism::WallClock;
 use godview_env::NodeId;
 use nalgebra::Vector3;
 use std::sync::Arc;
 use std::time::Duration;
-use tracing::{info, error, debug, Level};
+use tracing::{info, warn, error, debug, Level};
 use tracing_subscriber::FmtSubscriber;
 
+/// File --export writes, its format, and what it keeps.
+struct ExportTarget<'a> {
+    path: &'a str,
+    format: ExportFormat,
+    filter: ExportFilter,
+}
+
 /// Run a scenario with frame-by-frame export for visualization.
+///
+/// Once `interrupt` is set the run stops after the current tick and the
+/// export is finalized and written with what it has.
 fn run_with_export(
     seed: u64,
-    _num_agents: usize,
     scenario: ScenarioId,
     duration: f64,
-    export_path: &str,
+    warmup_secs: f64,
+    abort_on_rms: Option<f64>,
+    target: ExportTarget<'_>,
+    interrupt: &Interrupt,
 ) -> ScenarioResult {
     let context_seed = seed;
     let physics_seed = seed.wrapping_mul(0x9e3779b97f4a7c15);
@@ -37,10 +63,11 @@ fn run_with_export(
         network,
         root_key,
         0,
-        AgentConfig::default(),
+        AgentConfig { record_genealogy: true, ..SimulatedAgent::default_config() },
     );
     
-    let mut export = SimExport::new(scenario.name(), seed);
+    let mut observer = ObserverAgent::new(&SimulatedAgent::default_config());
+    let mut export = SimExport::new(scenario.name(), seed).with_filter(target.filter);
     
     // Spawn entities based on scenario
     let num_entities = match scenario {
@@ -64,6 +91,13 @@ fn run_with_export(
     
     // Export every 10 ticks (3 FPS in Rerun)
     let export_interval = 10;
+    // Evolutionary scenarios evolve the agent once a second
+    let evo_epoch_ticks = scenario.is_evolutionary().then_some(tick_rate_hz as u64);
+    let warmup_secs = warmup_secs.min(duration / 2.0);
+    let mut rms = RmsSampler::new(warmup_secs);
+    let mut divergence = DivergenceSampler::new(warmup_secs);
+    let mut aborted = None;
+    let mut registry = MetricsRegistry::new();
     
     for tick in 0..target_ticks {
         oracle.step(dt);
@@ -72,6 +106,13 @@ fn run_with_export(
         
         let readings = oracle.generate_sensor_readings();
         agent.ingest_readings(&readings);
+        if let Some(epoch_ticks) = evo_epoch_ticks {
+            agent.tick_evolution(epoch_ticks, Some(&oracle.ground_truth_positions()));
+        }
+        observer.tick(dt);
+        observer.ingest_gossip(agent.recent_packets());
+        observer.ingest_readings(&readings, oracle.time());
+        rms.sample(oracle.time(), [&agent], &oracle.ground_truth_positions());
         
         // Export frame periodically
         if tick % export_interval == 0 {
@@ -80,31 +121,43 @@ fn run_with_export(
                 .map(|(id, pos)| EntityPosition::new(id, pos))
                 .collect();
             
-            let tracks: Vec<TrackPosition> = agent.track_positions()
-                .into_iter()
-                .map(|(uuid, pos)| TrackPosition {
-                    track_id: uuid.to_string(),
-                    x: pos.x,
-                    y: pos.y,
-                    z: pos.z,
-                })
+            let gt_for_error = oracle.ground_truth_positions();
+            let scored = agent.ghost_scored_tracks(1);
+            let positions: Vec<_> = scored.iter().map(|(track, _)| track.position()).collect();
+            let tracks: Vec<TrackPosition> = scored.into_iter()
+                .zip(label_tracks(&gt_for_error, &positions, DEFAULT_MATCH_GATE_M))
+                .map(|((track, ghost_score), label)| TrackPosition::new(track.canonical_id, track.position())
+                    .with_metrics(track.position_covariance().trace(), ghost_score)
+                    .with_coasting_ticks(track.age)
+                    .with_truth_label(label))
                 .collect();
             
-            let gt_for_error = oracle.ground_truth_positions();
             let rms_error = agent.compute_position_error(&gt_for_error);
             
             let frame = SimFrame {
                 time_sec: oracle.time(),
+                tick: Some(tick),
                 ground_truth,
                 agents: vec![AgentFrame {
                     agent_id: 0,
                     tracks,
                     rms_error: Some(rms_error),
+                    track_count: None,
                 }],
                 events: vec![],
             };
             
             export.add_frame(frame);
+            registry.record_agents(tick + 1, oracle.time(), &[AgentSample::capture(&agent, &gt_for_error)]);
+            divergence.sample(oracle.time(), &observer, [&agent], &gt_for_error);
+            
+            if let Some(limit) = abort_on_rms {
+                let report = ProgressReport::capture(scenario, tick + 1, target_ticks, &oracle, [&agent]);
+                if report.time_secs >= warmup_secs && report.rms > limit {
+                    aborted = Some(report);
+                    break;
+                }
+            }
         }
         
         if tick % 30 == 0 {
@@ -114,37 +167,238 @@ fn run_with_export(
                 agent.track_count()
             );
         }
+        
+        if interrupt.is_set() {
+            warn!("Interrupted at t={:.1}s (tick {}/{})", oracle.time(), tick + 1, target_ticks);
+            aborted.get_or_insert_with(|| ProgressReport::capture(scenario, tick + 1, target_ticks, &oracle, [&agent]));
+            break;
+        }
     }
     
-    let ground_truth = oracle.ground_truth_positions();
-    let rms_error = agent.compute_position_error(&ground_truth);
-    let passed = rms_error < 5.0;
+    let failure = rms.failure(5.0);
+    let passed = aborted.is_none() && failure.is_none();
     
-    export.finalize(passed, Some(rms_error));
-    
-    if let Err(e) = export.write_to_file(export_path) {
-        error!("Failed to write export: {:?}", e);
+    export.add_genealogy(0, agent.genealogy());
+    export.add_divergence(&divergence);
+    export.add_evolution(0, agent.evolution_history());
+    if interrupt.is_set() {
+        export.finalize_interrupted(Some(rms.mean()));
     } else {
-        info!("Exported {} frames to {}", export.frames.len(), export_path);
+        export.finalize(passed, Some(rms.mean()));
+    }
+    
+    let started = std::time::Instant::now();
+    match export.write_as(target.path, target.format) {
+        Ok(bytes) => info!("Exported {} frames to {} ({}, {:.2} MiB in {:.0}ms)", export.frames.len(), target.path,
+            target.format, bytes as f64 / (1024.0 * 1024.0), started.elapsed().as_secs_f64() * 1000.0),
+        Err(e) => error!("Failed to write export: {:?}", e),
     }
     
-    ScenarioResult {
+    let mut result = ScenarioResult {
         scenario,
         seed,
         passed,
         total_ticks: target_ticks,
         final_time_secs: oracle.time(),
         final_entity_count: oracle.active_entities().len(),
-        failure_reason: if !passed {
-            Some(format!("RMS error {:.2}m exceeds threshold", rms_error))
-        } else {
-            None
-        },
+        failure,
+        rms_mean: rms.mean(),
+        rms_p95: rms.p95(),
+        rms_samples: rms.len(),
         metrics: godview_sim::ScenarioMetrics::default(),
+        duration_clamp: None,
+        aborted: None,
+        registry,
+        origin: RunOrigin::Fresh,
+        thresholds: ThresholdSource::Builtin,
+    };
+    if let Some(report) = aborted {
+        result.mark_aborted(report);
+    }
+    result.registry.record_agent_snapshot(&[AgentSample::capture(&agent, &oracle.ground_truth_positions())]);
+    result.record_final_metrics();
+    result
+}
+
+/// Renders a progress report as a single log line with a text bar.
+fn progress_line(report: &ProgressReport) -> String {
+    const WIDTH: usize = 20;
+    let filled = (report.fraction() * WIDTH as f64).round() as usize;
+    format!(
+        "  [{}{}] {:>3.0}% {} t={:.1}s | rms={:.2}m | tracks={} (max {}/agent) | mem~{} KiB",
+        "#".repeat(filled),
+        ".".repeat(WIDTH - filled),
+        report.fraction() * 100.0,
+        report.scenario.name(),
+        report.time_secs,
+        report.rms,
+        report.total_tracks,
+        report.max_tracks,
+        report.memory_estimate_bytes / 1024,
+    )
+}
+
+
+
+/// Format of the run summary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable log lines
+    Text,
+    /// JSON summary on stdout
+    Json,
+    /// JUnit XML written to --output-file
+    Junit,
+}
+
+/// Format of the file written by --metrics-out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum MetricsFormat {
+    /// One row per recorded tick of each run's time series
+    Csv,
+    /// Final values in the Prometheus text exposition format
+    Prom,
+}
+
+/// Writes every result's metrics to `path`.
+fn write_metrics(path: &str, format: MetricsFormat, results: &[ScenarioResult]) {
+    let text = match format {
+        MetricsFormat::Csv => report::metrics_csv(results),
+        MetricsFormat::Prom => report::metrics_prometheus(results),
+    };
+    if let Err(e) = std::fs::write(path, text) {
+        error!("Failed to write metrics to {}: {:?}", path, e);
+    } else {
+        info!("Wrote metrics to {}", path);
+    }
+}
+
+/// Writes the RNG trace of every audited run to `path`.
+fn write_rng_traces(path: &str, traces: &[RngTrace]) {
+    let written = serde_json::to_string_pretty(traces)
+        .map_err(|e| e.to_string())
+        .and_then(|json| std::fs::write(path, json).map_err(|e| e.to_string()));
+    match written {
+        Ok(()) => info!("Wrote RNG audit of {} run(s) to {}", traces.len(), path),
+        Err(e) => error!("Failed to write RNG audit to {}: {}", path, e),
+    }
+}
+
+/// Loads an --rng-audit file, exiting on failure.
+fn load_rng_traces(path: &str) -> Vec<RngTrace> {
+    let traces = std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()));
+    traces.unwrap_or_else(|e| {
+        eprintln!("Error: failed to load RNG audit {}: {}", path, e);
+        std::process::exit(1);
+    })
+}
+
+/// Prints the first divergence of each run in `right` from the same
+/// scenario and seed in `left`. Returns true if any run diverged.
+fn print_rng_diff(left: &[RngTrace], right: &[RngTrace]) -> bool {
+    let mut diverged = false;
+    for trace in right {
+        let Some(reference) = left.iter().find(|l| l.scenario == trace.scenario && l.seed == trace.seed) else {
+            println!("{} seed={}: not in the reference file", trace.scenario, trace.seed);
+            continue;
+        };
+        match reference.first_divergence(trace) {
+            None => println!("{} seed={}: identical ({} snapshots)", trace.scenario, trace.seed, trace.snapshots.len()),
+            Some(d) => {
+                diverged = true;
+                let draws = |n: Option<u64>| n.map_or("-".to_string(), |n| n.to_string());
+                println!("{} seed={}: diverged by tick {} (snapshot {}): {} drew {} vs {}",
+                    trace.scenario, trace.seed, d.tick, d.snapshot, d.label, draws(d.left), draws(d.right));
+            }
+        }
     }
+    diverged
 }
 
+/// Prints one row per scenario: name, tags, agents, entities, description.
+fn print_scenario_list() {
+    println!("{:<20} {:<28} {:>16} {:>8}  DESCRIPTION", "SCENARIO", "TAGS", "AGENTS", "ENTITIES");
+    for scenario in ScenarioId::all() {
+        let info = scenario.info();
+        let tags: Vec<&str> = info.tags.iter().map(|t| t.name()).collect();
+        println!("{:<20} {:<28} {:>16} {:>8}  {}",
+            info.name, tags.join(","), info.agents.to_string(), info.entities, info.description);
+    }
+}
 
+/// Prints everything known about one scenario.
+fn print_scenario_info(info: &ScenarioInfo) {
+    let timing = &info.timing;
+    let tags: Vec<&str> = info.tags.iter().map(|t| t.name()).collect();
+    println!("{}", info.name);
+    println!("  {}", info.description);
+    println!();
+    println!("  Duration:      {}s (accepts {}s to {}s) at {} Hz",
+        timing.default_duration_secs, timing.min_duration_secs, timing.max_duration_secs, timing.tick_rate_hz);
+    println!("  Agents:        {}", info.agents);
+    println!("  Entities:      {}", info.entities);
+    println!("  Pass criteria: {}", info.pass_criteria);
+    println!("  Tags:          {}", if tags.is_empty() { "-".to_string() } else { tags.join(", ") });
+    println!("  Knobs:");
+    for knob in &info.knobs {
+        println!("    {}", knob);
+    }
+}
+
+/// Commands other than running scenarios.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List every scenario with its tags
+    List,
+    
+    /// Show a scenario's metadata and the knobs it honors
+    Describe {
+        /// Scenario name (or DST id)
+        scenario: String,
+    },
+    
+    /// Compare two --rng-audit files run by run and print where each pair
+    /// first consumed randomness differently
+    RngDiff {
+        /// Trace file of the reference run
+        left: String,
+        
+        /// Trace file of the run to compare
+        right: String,
+    },
+    
+    /// Run a scenario over many seeds and write pass thresholds fitted to
+    /// its metrics, for --thresholds
+    Calibrate(CalibrateArgs),
+}
+
+/// Options of `calibrate`; the runs take --seed, --agents, --duration and
+/// the other run options from before the subcommand.
+#[derive(clap::Args, Debug)]
+struct CalibrateArgs {
+    /// Scenario to calibrate (swarm or adaptive_swarm)
+    #[arg(short = 'S', long)]
+    scenario: String,
+    
+    /// Number of seeds run, starting at --seed
+    #[arg(long, default_value = "100")]
+    seeds: usize,
+    
+    /// File the thresholds are written to
+    #[arg(long, value_name = "FILE")]
+    out: String,
+    
+    /// Percentile of the seeds' scores each threshold is fitted to (the
+    /// low tail for metrics that must reach their threshold)
+    #[arg(long, default_value_t = DEFAULT_CALIBRATION_PERCENTILE)]
+    percentile: f64,
+    
+    /// Margin added past the percentile, relative to it
+    #[arg(long, default_value_t = DEFAULT_CALIBRATION_MARGIN)]
+    margin: f64,
+}
 
 /// GodView Deterministic Simulation Testing CLI
 #[derive(Parser, Debug)]
@@ -159,7 +413,8 @@ struct Args {
     #[arg(short, long, default_value = "6")]
     agents: usize,
     
-    /// Scenario to run (time_warp, split_brain, byzantine, flash_mob, slow_loris, all)
+    /// Scenario to run: a name from `list`, `all`, or `tag:<tag>` for every
+    /// scenario carrying a tag (extreme, evolutionary, network)
     #[arg(short = 'S', long, default_value = "all")]
     scenario: String,
     
@@ -167,25 +422,776 @@ struct Args {
     #[arg(long, default_value = "1")]
     seeds: usize,
     
-    /// Maximum simulation duration in seconds
-    #[arg(short, long, default_value = "10")]
-    duration: f64,
+    /// Simulation duration in seconds, clamped to each scenario's bounds
+    /// (default: each scenario's own)
+    #[arg(short, long)]
+    duration: Option<f64>,
+    
+    /// Warm-up window excluded from RMS scoring, in seconds
+    #[arg(long, default_value = "2")]
+    warmup: f64,
+    
+    /// Worker threads for per-agent work in swarm scenarios (default: every
+    /// core; 1 = serial). Results are identical for any count
+    #[arg(long)]
+    threads: Option<usize>,
+    
+    /// Log a progress line every --progress-interval ticks
+    #[arg(long)]
+    progress: bool,
+    
+    /// Ticks between progress reports
+    #[arg(long, default_value_t = godview_sim::DEFAULT_PROGRESS_INTERVAL_TICKS)]
+    progress_interval: u64,
+    
+    /// Abort a run once its mean RMS error exceeds this many meters after warm-up
+    #[arg(long)]
+    abort_on_rms: Option<f64>,
     
     /// Verbose output
     #[arg(short, long)]
     verbose: bool,
     
-    /// JSON output for CI parsing
+    /// JSON output for CI parsing (same as --output-format json)
     #[arg(long)]
     json: bool,
     
-    /// Export simulation data to JSON file for Rerun visualization
+    /// Summary format
+    #[arg(long, value_enum, default_value = "text")]
+    output_format: OutputFormat,
+    
+    /// File written by --output-format junit
+    #[arg(long, default_value = "dst-results.xml")]
+    output_file: String,
+    
+    /// Print GitHub Actions `::error::` annotations for failures
+    #[arg(long)]
+    github_annotations: bool,
+    
+    /// Export simulation data to this file for Rerun visualization
     #[arg(long)]
     export: Option<String>,
+    
+    /// Format of --export: json or msgpack (default: from the extension,
+    /// msgpack for .msgpack/.mpk, otherwise json)
+    #[arg(long, value_name = "FORMAT", requires = "export")]
+    export_format: Option<ExportFormat>,
+    
+    /// Only export entities and tracks inside this region: a sphere
+    /// X,Y,Z,RADIUS or a box MINX,MINY,MINZ,MAXX,MAXY,MAXZ (meters)
+    #[arg(long, value_name = "REGION", requires = "export", allow_hyphen_values = true)]
+    export_roi: Option<RegionOfInterest>,
+    
+    /// Only export these ground-truth entity IDs (comma-separated)
+    #[arg(long, value_name = "IDS", requires = "export", value_delimiter = ',')]
+    export_entities: Option<Vec<u64>>,
+    
+    /// Only export these track IDs (comma-separated UUIDs)
+    #[arg(long, value_name = "UUIDS", requires = "export", value_delimiter = ',')]
+    export_tracks: Option<Vec<uuid::Uuid>>,
+    
+    /// Only export the top K tracks of each frame, ranked by --export-rank
+    #[arg(long, value_name = "K", requires = "export")]
+    export_top_k: Option<usize>,
+    
+    /// What ranks tracks for --export-top-k: ghost (score) or covariance
+    /// (trace), highest first
+    #[arg(long, value_name = "RANK", default_value = "ghost", requires = "export_top_k")]
+    export_rank: TrackRank,
+    
+    /// Export one frame in N (on top of one frame every 10 ticks)
+    #[arg(long, value_name = "N", default_value_t = 1, requires = "export",
+        value_parser = clap::value_parser!(u64).range(1..))]
+    export_decimate: u64,
+    
+    /// Print the JSON Schema of --export files and exit
+    #[arg(long)]
+    export_schema: bool,
+    
+    /// An earlier --export of the same run (from another build, say) to
+    /// compare this --export with frame by frame; the first frame whose
+    /// state differs is printed and fails the run
+    #[arg(long, value_name = "FILE", requires = "export")]
+    compare: Option<String>,
+    
+    /// Stream each run live to the Rerun Viewer at this address
+    /// (e.g. 127.0.0.1:9876); requires the `visualization` feature
+    #[arg(long, value_name = "ADDR")]
+    rerun_connect: Option<String>,
+    
+    /// Ticks between frames streamed by --rerun-connect
+    #[arg(long, default_value = "1", requires = "rerun_connect")]
+    rerun_every: u64,
+    
+    /// Most entities (and tracks per agent) streamed per frame
+    #[arg(long, requires = "rerun_connect")]
+    rerun_max_entities: Option<usize>,
+    
+    /// Pace runs to wall clock: virtual time advances at most FACTOR times
+    /// as fast as real time (1.0 = real time, 0.5 = half speed). Results
+    /// are unaffected
+    #[arg(long, value_name = "FACTOR", conflicts_with = "export", value_parser = parse_realtime)]
+    realtime: Option<f64>,
+    
+    /// Serve the running scenario's status as JSON at
+    /// http://0.0.0.0:PORT/status; requires the `status-server` feature
+    #[arg(long, value_name = "PORT", conflicts_with = "export")]
+    status_port: Option<u16>,
+    
+    /// Where runners publish status (set up from --status-port)
+    #[arg(skip)]
+    status: Option<StatusSender>,
+    
+    /// Seconds of frames captured either side of a failed run's first
+    /// invariant violation (0 disables capture); --verbose adds track
+    /// positions to each frame
+    #[arg(long, value_name = "SECS", default_value_t = DEFAULT_CAPTURE_WINDOW_SECS)]
+    failure_window: f64,
+    
+    /// Directory failure_<scenario>_<seed>_<tick>.json captures are written to
+    #[arg(long, value_name = "DIR", default_value = ".")]
+    failure_dir: String,
+    
+    /// Write each run's metrics registry to this file
+    #[arg(long)]
+    metrics_out: Option<String>,
+    
+    /// Format of --metrics-out
+    #[arg(long, value_enum, default_value = "csv")]
+    metrics_format: MetricsFormat,
+    
+    /// Ticks between CSV time series rows
+    #[arg(long, default_value = "1")]
+    metrics_interval: u64,
+    
+    /// Seed corpus file: its failing (scenario, seed) pairs are replayed
+    /// before fresh seeds, and new failures are appended to it
+    #[arg(long, conflicts_with = "export")]
+    corpus: Option<String>,
+    
+    /// Run only the pairs from --corpus
+    #[arg(long, requires = "corpus")]
+    corpus_only: bool,
+    
+    /// Replay only corpus pairs whose recorded failure is of these kinds
+    /// (comma-separated, e.g. rms_exceeded,aborted)
+    #[arg(long, requires = "corpus", value_delimiter = ',',
+        value_parser = clap::builder::PossibleValuesParser::new(FailureKind::LABELS))]
+    corpus_kind: Vec<String>,
+    
+    /// Benchmark baseline file: written by the first run, then compared
+    /// against (a regression fails the run)
+    #[arg(long, conflicts_with = "export")]
+    baseline: Option<String>,
+    
+    /// Relative worsening of a scored metric that counts as a regression
+    #[arg(long, default_value_t = godview_sim::DEFAULT_BENCH_TOLERANCE, requires = "baseline")]
+    baseline_tolerance: f64,
+    
+    /// Overwrite --baseline with this run after comparing
+    #[arg(long, requires = "baseline")]
+    update_baseline: bool,
+    
+    /// Write the markdown comparison to this file (otherwise it is printed,
+    /// except in JSON mode)
+    #[arg(long, requires = "baseline")]
+    bench_report: Option<String>,
+    
+    /// Write this invocation's benchmark metrics to this file, in the
+    /// --baseline format, without comparing against anything
+    #[arg(long, value_name = "FILE", conflicts_with = "export")]
+    bench_out: Option<String>,
+    
+    /// Rebuild and re-run on every source change, printing one line per run
+    /// against the previous one; requires the `dev-tools` feature
+    #[arg(long, conflicts_with_all = ["export", "campaign", "corpus", "baseline", "bench_out"])]
+    watch: bool,
+    
+    /// Write each agent's final evolved parameters to this file (one
+    /// scenario and one seed only)
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["export", "corpus"])]
+    save_params: Option<String>,
+    
+    /// Start agents from parameters written by --save-params (agent i takes
+    /// entry i, cycling if the counts differ)
+    #[arg(long, value_name = "FILE", conflicts_with = "export")]
+    load_params: Option<String>,
+    
+    /// Run the phases of this TOML campaign file back to back against one
+    /// persistent world instead of running scenarios
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["export", "corpus", "baseline", "save_params"])]
+    campaign: Option<String>,
+    
+    /// Run each scenario (blind_learning or common_bias) as this many
+    /// back-to-back episodes in one world instead of once
+    #[arg(long, value_name = "N", conflicts_with_all = ["export", "corpus", "baseline", "campaign", "save_params"])]
+    episodes: Option<usize>,
+    
+    /// What agents keep between --episodes: fresh, keep_params or
+    /// keep_reputation
+    #[arg(long, value_name = "POLICY", default_value_t = ResetPolicy::KeepParams, requires = "episodes")]
+    reset_policy: ResetPolicy,
+    
+    /// Count every RNG stream's draws and write each run's per-stream
+    /// counts to this JSON file, for `rng-diff`
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["export", "campaign"])]
+    rng_audit: Option<String>,
+    
+    /// Ticks between --rng-audit snapshots
+    #[arg(long, value_name = "TICKS", default_value_t = DEFAULT_RNG_AUDIT_INTERVAL_TICKS, requires = "rng_audit")]
+    rng_audit_interval: u64,
+    
+    /// Judge the scenario this file was calibrated on (by `calibrate`) by
+    /// its thresholds instead of the built-in ones
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["export", "campaign"])]
+    thresholds: Option<String>,
+    
+    /// Delay TimeWarp's and TimeTornado's sensor readings by a latency drawn
+    /// per reading: constant:MS, uniform:MIN,MAX, lognormal[:MU,SIGMA] or
+    /// bimodal[:FAST,SLOW,P_SLOW] (ms; bare names are mesh-radio presets)
+    #[arg(long, value_name = "MODEL", conflicts_with = "export")]
+    sensor_latency: Option<LatencyModel>,
+    
+    /// Ground-truth weight schedule of HybridLearning's hybrid agents, by
+    /// epoch: linear[:START,END,EPOCHS], step:START,END,AT_EPOCH or
+    /// exp:START,END,HALF_LIFE (weights in 0..=1)
+    #[arg(long, value_name = "SCHEDULE")]
+    gt_schedule: Option<GtWeightSchedule>,
+    
+    /// Evolve BlindLearning's and CommonBias's agents every TICKS ticks
+    /// instead of every 20 and 50
+    #[arg(long, value_name = "TICKS")]
+    epoch_ticks: Option<u64>,
+    
+    /// Delta-encode AdaptiveSwarm's gossip, sending each track in full once
+    /// per epoch and as quantized offsets in between
+    #[arg(long)]
+    delta_gossip: bool,
+    
+    /// Build the scenario's world (time_warp or swarm) and step it from
+    /// stdin commands instead of running it; type `help` for the commands
+    #[arg(long, conflicts_with_all = ["export", "campaign", "watch", "corpus", "baseline", "bench_out"])]
+    debug_repl: bool,
+    
+    /// Replay the injections a --debug-repl session saved with `save-script`
+    #[arg(long, value_name = "FILE", requires = "debug_repl")]
+    event_script: Option<String>,
+    
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Parses a --realtime factor, which must be positive and finite.
+fn parse_realtime(s: &str) -> Result<f64, String> {
+    let factor: f64 = s.parse().map_err(|e| format!("{e}"))?;
+    if factor.is_finite() && factor > 0.0 {
+        Ok(factor)
+    } else {
+        Err(format!("realtime factor must be positive, got {factor}"))
+    }
+}
+
+/// Runs `scenario`, or prints why it can't run and reports that as a
+/// failed result, so the rest of a sweep still runs and its outputs are
+/// written.
+fn run_or_record(runner: &ScenarioRunner, scenario: ScenarioId, seed: u64) -> ScenarioResult {
+    runner.run(scenario).unwrap_or_else(|e| {
+        eprintln!("Error: {} (seed={})", e, seed);
+        ScenarioResult::errored(scenario, seed, &e)
+    })
+}
+
+/// Builds the --export filter from the CLI options.
+fn export_filter(args: &Args) -> ExportFilter {
+    let mut filter = ExportFilter::default().with_decimation(args.export_decimate);
+    if let Some(region) = args.export_roi {
+        filter = filter.with_region(region);
+    }
+    if let Some(ids) = &args.export_entities {
+        filter = filter.with_entity_ids(ids.iter().copied());
+    }
+    if let Some(ids) = &args.export_tracks {
+        filter = filter.with_track_ids(ids.iter().map(|id| id.to_string()));
+    }
+    if let Some(k) = args.export_top_k {
+        filter = filter.with_top_k(k, args.export_rank);
+    }
+    filter
+}
+
+/// Builds the runner for one seed from the CLI options.
+fn scenario_runner(
+    args: &Args,
+    seed: u64,
+    trained: Option<&TrainedParams>,
+    thresholds: Option<&CalibratedThresholds>,
+    interrupt: &Interrupt,
+) -> ScenarioRunner {
+    let mut runner = ScenarioRunner::new(seed, args.agents)
+        .with_warmup_secs(args.warmup)
+        .with_progress_interval(args.progress_interval)
+        .with_interrupt(interrupt.clone());
+    if let Some(trained) = trained {
+        runner = runner.with_trained_params(trained.clone());
+    }
+    if let Some(thresholds) = thresholds {
+        runner = runner.with_thresholds(thresholds.clone());
+    }
+    if let Some(model) = args.sensor_latency {
+        runner = runner.with_sensor_latency(model);
+    }
+    if let Some(schedule) = args.gt_schedule {
+        runner = runner.with_gt_schedule(schedule);
+    }
+    if let Some(ticks) = args.epoch_ticks {
+        runner = runner.with_epoch_ticks(ticks);
+    }
+    if args.delta_gossip {
+        runner = runner.with_swarm_config(SwarmConfig {
+            delta_gossip: Some(DeltaCompression::default()),
+            ..SwarmConfig::default()
+        });
+    }
+    if let Some(duration) = args.duration {
+        runner = runner.with_duration(duration);
+    }
+    if let Some(threads) = args.threads {
+        runner = runner.with_threads(threads);
+    }
+    if args.metrics_out.is_some() && args.metrics_format == MetricsFormat::Csv {
+        runner = runner.with_metrics_interval(args.metrics_interval);
+    }
+    if let Some(addr) = &args.rerun_connect {
+        let mut logger = RerunLogger::connect(addr).with_log_every(args.rerun_every);
+        if let Some(max) = args.rerun_max_entities {
+            logger = logger.with_max_entities(max);
+        }
+        runner = runner.with_rerun(logger);
+    }
+    if let Some(factor) = args.realtime {
+        runner = runner.with_realtime(factor);
+    }
+    if let Some(status) = &args.status {
+        runner = runner.with_status(status.clone());
+    }
+    if args.rng_audit.is_some() {
+        runner = runner.with_rng_audit(args.rng_audit_interval);
+    }
+    if args.failure_window > 0.0 {
+        let capture = CaptureConfig::new(&args.failure_dir)
+            .with_window_secs(args.failure_window)
+            .with_verbose(args.verbose);
+        runner = runner.with_failure_capture(capture);
+    }
+    if args.progress {
+        runner = runner.with_progress(Box::new(|report| info!("{}", progress_line(&report))));
+    }
+    if let Some(limit) = args.abort_on_rms {
+        let warmup_secs = args.warmup;
+        runner = runner.with_abort_if(Box::new(move |report| {
+            report.time_secs >= warmup_secs && report.rms > limit
+        }));
+    }
+    runner
+}
+
+/// Writes the --baseline file if it doesn't exist yet, or compares `results`
+/// with it and reports the markdown diff. Returns true if a metric regressed.
+fn compare_with_baseline(args: &Args, path: &str, results: &[ScenarioResult]) -> bool {
+    let current = BenchBaseline::from_results(results);
+    let save = |what: &str| match current.save(path) {
+        Ok(()) => info!("{} benchmark baseline {} ({} runs)", what, path, current.runs().len()),
+        Err(e) => error!("Failed to write baseline {}: {:?}", path, e),
+    };
+    let baseline = match BenchBaseline::load(path) {
+        Ok(Some(baseline)) => baseline,
+        Ok(None) => {
+            save("Wrote");
+            return false;
+        }
+        Err(e) => {
+            eprintln!("Error: failed to load baseline {}: {}", path, e);
+            std::process::exit(1);
+        }
+    };
+    
+    let comparison = BenchComparison::compare(&baseline, &current, args.baseline_tolerance);
+    let markdown = comparison.to_markdown();
+    match &args.bench_report {
+        Some(report_path) => match std::fs::write(report_path, &markdown) {
+            Ok(()) => info!("Wrote benchmark report to {}", report_path),
+            Err(e) => error!("Failed to write benchmark report to {}: {:?}", report_path, e),
+        },
+        None if !args.json => println!("{}", markdown),
+        None => {}
+    }
+    for d in comparison.regressions() {
+        error!("Benchmark regression: {} seed={} {} {:.3} -> {:.3}", d.scenario, d.seed, d.metric, d.baseline, d.current);
+    }
+    if args.update_baseline {
+        save("Updated");
+    }
+    comparison.has_regressions()
+}
+
+/// Runs --compare: prints the first frame the export at `path` differs
+/// from the one at `reference` on. Returns true if they differ.
+fn compare_exports(reference: &str, path: &str) -> bool {
+    let load = |path: &str| SimExport::load_auto(path).unwrap_or_else(|e| {
+        eprintln!("Error: {}: {}", path, e);
+        std::process::exit(1);
+    });
+    let (expected, actual) = (load(reference), load(path));
+    match expected.first_divergence(&actual) {
+        None => {
+            println!("State matches {} ({} frames)", reference, actual.frames.len());
+            false
+        }
+        Some(divergence) => {
+            println!("State diverges from {} at {}", reference, divergence);
+            true
+        }
+    }
+}
+
+/// Runs --watch: this command line, minus --watch, rebuilt and re-run on
+/// every source change until Ctrl-C. Returns the process exit code.
+#[cfg(feature = "dev-tools")]
+fn watch(interrupt: &Interrupt) -> i32 {
+    let args = std::env::args().skip(1).filter(|arg| arg != "--watch").collect();
+    match godview_sim::watch::Watcher::new(args) {
+        Ok(watcher) => match watcher.run(interrupt) {
+            Ok(()) => INTERRUPTED_EXIT_CODE,
+            Err(e) => {
+                eprintln!("Error: failed to watch the sources: {}", e);
+                1
+            }
+        },
+        Err(e) => {
+            eprintln!("Error: failed to locate this binary: {}", e);
+            1
+        }
+    }
+}
+
+#[cfg(not(feature = "dev-tools"))]
+fn watch(_interrupt: &Interrupt) -> i32 {
+    eprintln!("Error: --watch requires the dev-tools feature (cargo run -p godview_sim --features dev-tools -- ...)");
+    1
+}
+
+/// Starts --status-port's server on `port` and has every runner publish to
+/// it. Exits if the port can't be bound.
+#[cfg(feature = "status-server")]
+fn start_status_server(args: &mut Args, port: u16) -> godview_sim::StatusServer {
+    let (sender, receiver) = godview_sim::status_channel();
+    match godview_sim::StatusServer::start(("0.0.0.0", port), receiver) {
+        Ok(server) => {
+            info!("Serving status at http://{}/status", server.local_addr());
+            args.status = Some(sender);
+            server
+        }
+        Err(e) => {
+            eprintln!("Error: failed to serve status on port {}: {}", port, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(not(feature = "status-server"))]
+fn start_status_server(_args: &mut Args, _port: u16) {
+    eprintln!("Error: --status-port requires the status-server feature (cargo run -p godview_sim --features status-server -- ...)");
+    std::process::exit(1);
+}
+
+/// Runs `calibrate`: the scenario once per seed with the built-in
+/// thresholds, then thresholds fitted to the runs written to its --out
+/// file. Returns the process exit code.
+fn calibrate(args: &Args, calibrate: &CalibrateArgs, base_seed: u64, interrupt: &Interrupt) -> i32 {
+    let scenario = match calibrate.scenario.parse::<ScenarioId>() {
+        Ok(scenario) => scenario,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return 1;
+        }
+    };
+    let mut results = Vec::new();
+    for seed_offset in 0..calibrate.seeds {
+        if interrupt.is_set() {
+            return INTERRUPTED_EXIT_CODE;
+        }
+        let seed = base_seed.wrapping_add(seed_offset as u64);
+        let runner = scenario_runner(args, seed, None, None, interrupt);
+        let result = match runner.run(scenario) {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("Error: {} (seed={})", e, seed);
+                return 1;
+            }
+        };
+        if interrupt.is_set() {
+            return INTERRUPTED_EXIT_CODE;
+        }
+        log_result(args, &result);
+        results.push(result);
+    }
+    
+    let thresholds = match CalibratedThresholds::calibrate(scenario, &results, calibrate.percentile, calibrate.margin) {
+        Ok(thresholds) => thresholds,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return 1;
+        }
+    };
+    if let Err(e) = thresholds.save(&calibrate.out) {
+        eprintln!("Error: failed to write thresholds {}: {}", calibrate.out, e);
+        return 1;
+    }
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&thresholds).unwrap());
+    } else {
+        info!("Wrote {} thresholds for {} to {} (p{} +{}% over {} seeds)", thresholds.thresholds.len(),
+            scenario.name(), calibrate.out, thresholds.percentile, thresholds.margin * 100.0, thresholds.seeds);
+        for (metric, threshold) in &thresholds.thresholds {
+            info!("  {:<16} {} {:.4}", metric, if threshold.bound == Bound::Max { "<" } else { ">=" }, threshold.value);
+        }
+    }
+    0
+}
+
+/// Runs --debug-repl: the one selected scenario's world, stepped by
+/// commands read from stdin. Returns the process exit code.
+fn debug_repl(args: &Args, scenarios: &[ScenarioId], seed: u64, interrupt: &Interrupt) -> i32 {
+    let [scenario] = scenarios else {
+        eprintln!("Error: --debug-repl only supports a single scenario, not 'all' or a tag");
+        return 1;
+    };
+    let world = match scenario_runner(args, seed, None, None, interrupt).world(*scenario) {
+        Ok(world) => world,
+        Err(e) => {
+            eprintln!("Error: {} (seed={})", e, seed);
+            return 1;
+        }
+    };
+    let mut repl = DebugRepl::new(world, *scenario);
+    if let Some(path) = &args.event_script {
+        match EventScript::load(path) {
+            Ok(script) => repl = repl.with_event_script(script),
+            Err(e) => {
+                eprintln!("Error: failed to load event script {}: {}", path, e);
+                return 1;
+            }
+        }
+    }
+    match repl.run(std::io::stdin().lock(), std::io::stdout().lock()) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+/// Runs `campaign` once per seed and reports each phase and the campaign
+/// metrics. Returns the process exit code.
+fn run_campaign(args: &Args, campaign: &Campaign, base_seed: u64, interrupt: &Interrupt) -> i32 {
+    let mut results: Vec<CampaignResult> = Vec::new();
+    for seed_offset in 0..args.seeds {
+        if interrupt.is_set() {
+            break;
+        }
+        let seed = base_seed.wrapping_add(seed_offset as u64);
+        let result = scenario_runner(args, seed, None, None, interrupt).run_campaign(campaign);
+        if !args.json {
+            if result.passed() {
+                info!("✓ campaign (seed={}) PASSED", seed);
+            } else {
+                for (index, phase) in result.phases.iter().enumerate().filter(|(_, p)| !p.passed) {
+                    error!("✗ campaign (seed={}) phase {} {} FAILED: {}", seed, index, phase.scenario.name(),
+                        phase.failure_reason().as_deref().unwrap_or("unknown"));
+                }
+            }
+        }
+        results.push(result);
+    }
+    
+    if let Some(path) = &args.metrics_out {
+        let phases: Vec<ScenarioResult> = results.iter().flat_map(|r| r.phases.iter().cloned()).collect();
+        write_metrics(path, args.metrics_format, &phases);
+    }
+    let failed = results.iter().filter(|r| !r.passed()).count();
+    if args.json {
+        let summary = serde_json::json!({
+            "total": results.len(),
+            "passed": results.len() - failed,
+            "failed": failed,
+            "interrupted": interrupt.is_set(),
+            "results": results.iter().map(|r| {
+                serde_json::json!({
+                    "seed": r.seed,
+                    "passed": r.passed(),
+                    "campaign_metrics": r.metrics,
+                    "phases": r.phases.iter().map(|p| {
+                        serde_json::json!({
+                            "scenario": p.scenario.name(),
+                            "passed": p.passed,
+                            "ticks": p.total_ticks,
+                            "time_secs": p.final_time_secs,
+                            "rms_mean": p.rms_mean,
+                            "rms_p95": p.rms_p95,
+                            "rms_samples": p.rms_samples,
+                            "scenario_metrics": p.metrics,
+                            "failure_reason": p.failure_reason(),
+                            "failure": p.failure,
+                        })
+                    }).collect::<Vec<_>>(),
+                })
+            }).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&summary).unwrap());
+    } else if failed == 0 {
+        info!("✅ All {} campaign runs passed!", results.len());
+    } else {
+        error!("❌ {}/{} campaign runs failed!", failed, results.len());
+    }
+    
+    if interrupt.is_set() {
+        INTERRUPTED_EXIT_CODE
+    } else if failed > 0 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Runs every scenario as `episodes` episodes once per seed and reports
+/// each episode and the learning across them. Returns the process exit
+/// code.
+fn run_episodes(args: &Args, scenarios: &[ScenarioId], episodes: usize, base_seed: u64, interrupt: &Interrupt) -> i32 {
+    let mut results: Vec<(ScenarioId, EpisodesResult)> = Vec::new();
+    for seed_offset in 0..args.seeds {
+        for &scenario in scenarios {
+            if interrupt.is_set() {
+                break;
+            }
+            let seed = base_seed.wrapping_add(seed_offset as u64);
+            let result = scenario_runner(args, seed, None, None, interrupt)
+                .run_episodes(scenario, episodes, args.reset_policy)
+                .unwrap_or_else(|e| {
+                    eprintln!("Error: {} (seed={})", e, seed);
+                    EpisodesResult {
+                        seed,
+                        policy: args.reset_policy,
+                        episodes: vec![ScenarioResult::errored(scenario, seed, &e)],
+                        metrics: EpisodeMetrics::default(),
+                    }
+                });
+            if !args.json {
+                if result.passed() {
+                    info!("✓ {} x{} (seed={}) PASSED", scenario.name(), result.episodes.len(), seed);
+                } else {
+                    for (index, episode) in result.episodes.iter().enumerate().filter(|(_, e)| !e.passed) {
+                        error!("✗ {} (seed={}) episode {} FAILED: {}", scenario.name(), seed, index,
+                            episode.failure_reason().as_deref().unwrap_or("unknown"));
+                    }
+                }
+            }
+            results.push((scenario, result));
+        }
+    }
+    
+    if let Some(path) = &args.metrics_out {
+        let episodes: Vec<ScenarioResult> = results.iter().flat_map(|(_, r)| r.episodes.iter().cloned()).collect();
+        write_metrics(path, args.metrics_format, &episodes);
+    }
+    let failed = results.iter().filter(|(_, r)| !r.passed()).count();
+    if args.json {
+        let summary = serde_json::json!({
+            "total": results.len(),
+            "passed": results.len() - failed,
+            "failed": failed,
+            "interrupted": interrupt.is_set(),
+            "results": results.iter().map(|(scenario, r)| {
+                serde_json::json!({
+                    "scenario": scenario.name(),
+                    "seed": r.seed,
+                    "reset_policy": r.policy,
+                    "passed": r.passed(),
+                    "episode_metrics": r.metrics,
+                    "episodes": r.episodes.iter().map(|e| e.to_json()).collect::<Vec<_>>(),
+                })
+            }).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&summary).unwrap());
+    } else if failed == 0 {
+        info!("✅ All {} multi-episode runs passed!", results.len());
+    } else {
+        error!("❌ {}/{} multi-episode runs failed!", failed, results.len());
+    }
+    
+    if interrupt.is_set() {
+        INTERRUPTED_EXIT_CODE
+    } else if failed > 0 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Logs a one-line pass/fail for a run (silent in JSON mode).
+fn log_result(args: &Args, result: &ScenarioResult) {
+    if args.json {
+        return;
+    }
+    let origin = match result.origin {
+        RunOrigin::Fresh => "",
+        RunOrigin::Corpus => "[corpus] ",
+    };
+    if result.passed {
+        info!("✓ {}{} (seed={}) PASSED", origin, result.scenario.name(), result.seed);
+    } else {
+        error!("✗ {}{} (seed={}) FAILED: {}", 
+            origin,
+            result.scenario.name(), 
+            result.seed, 
+            result.failure_reason().as_deref().unwrap_or("unknown")
+        );
+    }
 }
 
 fn main() {
-    let args = Args::parse();
+    let mut args = Args::parse();
+    match &args.command {
+        Some(Command::List) => {
+            print_scenario_list();
+            return;
+        }
+        Some(Command::Describe { scenario }) => {
+            match scenario.parse::<ScenarioId>() {
+                Ok(id) => print_scenario_info(&id.info()),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+        Some(Command::RngDiff { left, right }) => {
+            if print_rng_diff(&load_rng_traces(left), &load_rng_traces(right)) {
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Calibrate(_)) | None => {}
+    }
+    if args.export_schema {
+        println!("{}", serde_json::to_string_pretty(&SimExport::json_schema()).unwrap());
+        return;
+    }
+    if args.json {
+        args.output_format = OutputFormat::Json;
+    }
+    args.json = args.output_format == OutputFormat::Json;
     
     // Initialize logging
     let level = if args.verbose { Level::DEBUG } else { Level::INFO };
@@ -200,20 +1206,30 @@ fn main() {
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     }
     
+    // Ctrl-C stops the current run after its tick; everything completed
+    // before it is still reported
+    let interrupt = Interrupt::on_ctrl_c().unwrap_or_else(|e| {
+        warn!("Failed to install Ctrl-C handler: {}", e);
+        Interrupt::new()
+    });
+    
+    if args.watch {
+        std::process::exit(watch(&interrupt));
+    }
+    
+    // Serves status until the process exits
+    let _status_server = args.status_port.map(|port| start_status_server(&mut args, port));
+    
     // Parse scenarios
-    let scenarios: Vec<ScenarioId> = if args.scenario == "all" {
-        ScenarioId::all()
-    } else {
-        vec![args.scenario.parse().unwrap_or_else(|e| {
-            eprintln!("Error: {}", e);
-            eprintln!("Available scenarios: time_warp, split_brain, byzantine, flash_mob, slow_loris, all");
-            std::process::exit(1);
-        })]
-    };
+    let scenarios = ScenarioId::select(&args.scenario).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
     
     // Determine base seed
     let base_seed = if args.seed == 0 {
-        std::time::SystemTime::now()
+        // Before any runner exists, so outside the simulation
+        WallClock::system().now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_nanos() as u64
@@ -221,6 +1237,26 @@ fn main() {
         args.seed
     };
     
+    if let Some(Command::Calibrate(calibrate_args)) = &args.command {
+        std::process::exit(calibrate(&args, calibrate_args, base_seed, &interrupt));
+    }
+    
+    if args.debug_repl {
+        std::process::exit(debug_repl(&args, &scenarios, base_seed, &interrupt));
+    }
+    
+    if let Some(path) = &args.campaign {
+        let campaign = Campaign::load(path).unwrap_or_else(|e| {
+            eprintln!("Error: failed to load campaign {}: {}", path, e);
+            std::process::exit(1);
+        });
+        std::process::exit(run_campaign(&args, &campaign, base_seed, &interrupt));
+    }
+    
+    if let Some(episodes) = args.episodes {
+        std::process::exit(run_episodes(&args, &scenarios, episodes, base_seed, &interrupt));
+    }
+    
     // Track results
     let mut all_results: Vec<ScenarioResult> = Vec::new();
     let mut failed_count = 0;
@@ -228,7 +1264,7 @@ fn main() {
     // Handle --export mode for visualization
     if let Some(export_path) = &args.export {
         if scenarios.len() > 1 {
-            eprintln!("Error: --export only supports a single scenario, not 'all'");
+            eprintln!("Error: --export only supports a single scenario, not 'all' or a tag");
             std::process::exit(1);
         }
         
@@ -237,12 +1273,26 @@ fn main() {
         // Run specialized export simulation
         let result = run_with_export(
             base_seed, 
-            args.agents, 
             scenarios[0], 
-            args.duration,
-            export_path,
+            args.duration.unwrap_or(scenarios[0].timing().default_duration_secs),
+            args.warmup,
+            args.abort_on_rms,
+            ExportTarget {
+                path: export_path,
+                format: args.export_format.unwrap_or_else(|| ExportFormat::from_path(export_path)),
+                filter: export_filter(&args),
+            },
+            &interrupt,
         );
+        if let Some(path) = &args.metrics_out {
+            write_metrics(path, args.metrics_format, std::slice::from_ref(&result));
+        }
         
+        if interrupt.is_set() {
+            warn!("Interrupted; partial export written to {}", export_path);
+            std::process::exit(INTERRUPTED_EXIT_CODE);
+        }
+        let diverged = args.compare.as_ref().is_some_and(|reference| compare_exports(reference, export_path));
         if result.passed {
             info!("✓ {} (seed={}) PASSED - exported to {}", 
                 scenarios[0].name(), base_seed, export_path);
@@ -250,37 +1300,111 @@ fn main() {
         } else {
             error!("✗ {} FAILED: {}", 
                 scenarios[0].name(),
-                result.failure_reason.as_deref().unwrap_or("unknown")
+                result.failure_reason().as_deref().unwrap_or("unknown")
             );
         }
         
-        if !result.passed {
+        if !result.passed || diverged {
             std::process::exit(1);
         }
         return;
     }
     
+    if args.save_params.is_some() && (scenarios.len() > 1 || args.seeds > 1) {
+        eprintln!("Error: --save-params only supports a single scenario and seed");
+        std::process::exit(1);
+    }
+    let trained = args.load_params.as_ref().map(|path| {
+        let trained = TrainedParams::load(path).unwrap_or_else(|e| {
+            eprintln!("Error: failed to load params {}: {}", path, e);
+            std::process::exit(1);
+        });
+        if !args.json {
+            info!("Loaded params for {} agents from {} (trained on {} seed={}, v{})",
+                trained.agents.len(), path, trained.scenario, trained.seed, trained.crate_version);
+        }
+        if trained.crate_version != godview_sim::corpus::CRATE_VERSION {
+            warn!("  {} was written by v{}, this is v{}", path, trained.crate_version, godview_sim::corpus::CRATE_VERSION);
+        }
+        trained
+    });
+    let thresholds = args.thresholds.as_ref().map(|path| {
+        let thresholds = CalibratedThresholds::load(path).unwrap_or_else(|e| {
+            eprintln!("Error: failed to load thresholds {}: {}", path, e);
+            std::process::exit(1);
+        });
+        if !args.json {
+            info!("Judging {} by thresholds from {} ({} seeds, p{} +{}%)", thresholds.scenario, path,
+                thresholds.seeds, thresholds.percentile, thresholds.margin * 100.0);
+        }
+        if !scenarios.iter().any(|s| thresholds.applies_to(*s)) {
+            warn!("  {} was calibrated on {}, which isn't being run", path, thresholds.scenario);
+        }
+        thresholds
+    });
+    
+    // Replay the corpus first, then fresh seeds it doesn't already cover
+    let mut corpus = args.corpus.as_ref().map(|path| {
+        SeedCorpus::load(path).unwrap_or_else(|e| {
+            eprintln!("Error: failed to load corpus {}: {}", path, e);
+            std::process::exit(1);
+        })
+    });
+    let kinds: Vec<&str> = args.corpus_kind.iter().map(String::as_str).collect();
+    let corpus_pairs = corpus.as_ref()
+        .map(|c| if kinds.is_empty() { c.pairs_for(&scenarios) } else { c.pairs_of_kind(&scenarios, &kinds) })
+        .unwrap_or_default();
+    if !args.json && corpus.is_some() {
+        info!("Replaying {} corpus seed(s)", corpus_pairs.len());
+    }
+    
+    let mut rng_traces: Vec<RngTrace> = Vec::new();
+    for &(scenario, seed) in &corpus_pairs {
+        if interrupt.is_set() {
+            break;
+        }
+        let runner = scenario_runner(&args, seed, trained.as_ref(), thresholds.as_ref(), &interrupt);
+        let mut result = run_or_record(&runner, scenario, seed);
+        rng_traces.extend(runner.take_rng_trace());
+        if interrupt.is_set() {
+            warn!("Interrupted during {} seed={}; dropping the partial run", scenario.name(), seed);
+            break;
+        }
+        result.origin = RunOrigin::Corpus;
+        log_result(&args, &result);
+        let recorded = corpus.as_ref().and_then(|c| c.recorded_failure(scenario, seed));
+        if let (Some(recorded), Some(now)) = (recorded, result.failure.as_ref()) {
+            if recorded.label() != now.label() && !args.json {
+                warn!("  {} seed={} was recorded as {}, now fails as {}",
+                    scenario.name(), seed, recorded.label(), now.label());
+            }
+        }
+        if !result.passed {
+            failed_count += 1;
+        }
+        all_results.push(result);
+    }
+    
     // Run simulations
-    for seed_offset in 0..args.seeds {
+    let fresh_seeds = if args.corpus_only { 0 } else { args.seeds };
+    'seeds: for seed_offset in 0..fresh_seeds {
         let seed = base_seed.wrapping_add(seed_offset as u64);
-        
-        let runner = ScenarioRunner::new(seed, args.agents)
-            .with_duration(args.duration);
+        let runner = scenario_runner(&args, seed, trained.as_ref(), thresholds.as_ref(), &interrupt);
         
         for scenario in &scenarios {
-            let result = runner.run(*scenario);
-            
-            if !args.json {
-                if result.passed {
-                    info!("✓ {} (seed={}) PASSED", scenario.name(), seed);
-                } else {
-                    error!("✗ {} (seed={}) FAILED: {}", 
-                        scenario.name(), 
-                        seed, 
-                        result.failure_reason.as_deref().unwrap_or("unknown")
-                    );
-                }
+            if interrupt.is_set() {
+                break 'seeds;
             }
+            if corpus_pairs.contains(&(*scenario, seed)) {
+                continue;
+            }
+            let result = run_or_record(&runner, *scenario, seed);
+            rng_traces.extend(runner.take_rng_trace());
+            if interrupt.is_set() {
+                warn!("Interrupted during {} seed={}; dropping the partial run", scenario.name(), seed);
+                break 'seeds;
+            }
+            log_result(&args, &result);
             
             if !result.passed {
                 failed_count += 1;
@@ -290,32 +1414,81 @@ fn main() {
         }
     }
     
+    if let (Some(corpus), Some(path)) = (corpus.as_mut(), &args.corpus) {
+        let added = all_results.iter().filter(|r| corpus.record_failure(r)).count();
+        if added > 0 {
+            match corpus.save(path) {
+                Ok(()) => info!("Recorded {} new failing seed(s) in {}", added, path),
+                Err(e) => error!("Failed to write corpus {}: {:?}", path, e),
+            }
+        }
+    }
+    
+    if let (Some(path), Some(result)) = (&args.save_params, all_results.last()) {
+        match TrainedParams::from_result(result) {
+            Some(trained) => match trained.save(path) {
+                Ok(()) => info!("Saved params for {} agents to {}", trained.agents.len(), path),
+                Err(e) => error!("Failed to write params {}: {:?}", path, e),
+            },
+            None => error!("{} doesn't evolve parameters; nothing written to {}", result.scenario.name(), path),
+        }
+    }
+    
+    let interrupted = interrupt.is_set();
+    if interrupted && args.baseline.is_some() {
+        warn!("Interrupted; not comparing a partial sweep against the benchmark baseline");
+    }
+    let bench_regressions = !interrupted
+        && args.baseline.as_ref().is_some_and(|path| compare_with_baseline(&args, path, &all_results));
+    if let Some(path) = &args.bench_out {
+        if let Err(e) = BenchBaseline::from_results(&all_results).save(path) {
+            error!("Failed to write benchmark metrics {}: {:?}", path, e);
+        }
+    }
+    
     // Summary
     let total = all_results.len();
     let passed = total - failed_count;
     
+    if args.github_annotations {
+        for line in report::github_annotations(&all_results) {
+            println!("{}", line);
+        }
+    }
+    
+    if args.output_format == OutputFormat::Junit {
+        let xml = if interrupted { report::interrupted_junit_xml(&all_results) } else { report::junit_xml(&all_results) };
+        if let Err(e) = std::fs::write(&args.output_file, xml) {
+            error!("Failed to write JUnit report to {}: {:?}", args.output_file, e);
+        } else {
+            info!("Wrote JUnit report to {}", args.output_file);
+        }
+    }
+    
+    if let Some(path) = &args.metrics_out {
+        write_metrics(path, args.metrics_format, &all_results);
+    }
+    if let Some(path) = &args.rng_audit {
+        write_rng_traces(path, &rng_traces);
+    }
+    
     if args.json {
         // JSON output for CI parsing
         let summary = serde_json::json!({
             "total": total,
             "passed": passed,
             "failed": failed_count,
-            "results": all_results.iter().map(|r| {
-                serde_json::json!({
-                    "scenario": r.scenario.name(),
-                    "seed": r.seed,
-                    "passed": r.passed,
-                    "ticks": r.total_ticks,
-                    "time_secs": r.final_time_secs,
-                    "failure_reason": r.failure_reason,
-                })
-            }).collect::<Vec<_>>(),
+            "interrupted": interrupted,
+            "results": all_results.iter().map(ScenarioResult::to_json).collect::<Vec<_>>(),
         });
         println!("{}", serde_json::to_string_pretty(&summary).unwrap());
     } else {
         info!("");
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
+        if interrupted {
+            warn!("⚠️ Interrupted: only the {} completed run(s) are reported", total);
+        }
         if failed_count == 0 {
             info!("✅ All {} scenario runs passed!", total);
         } else {
@@ -327,7 +1500,7 @@ fn main() {
                     error!("  - {} seed={}: {}", 
                         result.scenario.name(),
                         result.seed,
-                        result.failure_reason.as_deref().unwrap_or("unknown")
+                        result.failure_reason().as_deref().unwrap_or("unknown")
                     );
                 }
             }
@@ -335,7 +1508,70 @@ fn main() {
     }
     
     // Exit with proper code for CI
-    if failed_count > 0 {
+    if interrupted {
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    }
+    if failed_count > 0 || bench_regressions {
         std::process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_interrupted_export_is_finalized() {
+        let path = std::env::temp_dir().join(format!("godview-interrupted-{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        
+        // Set before the first tick ends, so the run stops there
+        let interrupt = Interrupt::new();
+        interrupt.trigger();
+        let result = run_with_export(42, ScenarioId::TimeWarp, 10.0, 0.0, None, ExportTarget { path, format: ExportFormat::Json, filter: ExportFilter::default() }, &interrupt);
+        assert!(!result.passed);
+        assert_eq!(result.total_ticks, 1);
+        
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(json["interrupted"], true);
+        assert_eq!(json["passed"], false);
+        assert!(json["final_rms_error"].is_number());
+        let export = SimExport::load_from_file(path).unwrap();
+        assert_eq!(export.frames.len(), 1);
+        std::fs::remove_file(path).unwrap();
+    }    
+    #[test]
+    fn test_export_filter_flags() {
+        let args = Args::try_parse_from([
+            "godview-sim", "--export", "run.json", "--export-roi", "-10,0,0,10,20,200",
+            "--export-entities", "3,1", "--export-top-k", "20", "--export-rank", "covariance", "--export-decimate", "4",
+        ]).unwrap();
+        assert_eq!(export_filter(&args), ExportFilter::default()
+            .with_region(RegionOfInterest::Aabb { min: [-10.0, 0.0, 0.0], max: [10.0, 20.0, 200.0] })
+            .with_entity_ids([1, 3])
+            .with_top_k(20, TrackRank::Covariance)
+            .with_decimation(4));
+        
+        assert!(export_filter(&Args::try_parse_from(["godview-sim", "--export", "run.json"]).unwrap()).is_empty());
+        assert!(Args::try_parse_from(["godview-sim", "--export-top-k", "5"]).is_err(), "needs --export");
+        assert!(Args::try_parse_from(["godview-sim", "--export", "run.json", "--export-decimate", "0"]).is_err());
+    }
+    
+    #[test]
+    fn test_compare_finds_where_exports_diverge() {
+        let dir = std::env::temp_dir();
+        let [reference, rerun, other_seed] = ["reference", "rerun", "other-seed"]
+            .map(|name| dir.join(format!("godview-compare-{}-{}.json", name, std::process::id())).to_str().unwrap().to_string());
+        for (path, seed) in [(&reference, 42), (&rerun, 42), (&other_seed, 7)] {
+            let target = ExportTarget { path, format: ExportFormat::Json, filter: ExportFilter::default() };
+            run_with_export(seed, ScenarioId::TimeWarp, 0.5, 0.0, None, target, &Interrupt::new());
+        }
+        
+        assert!(!compare_exports(&reference, &rerun));
+        assert!(compare_exports(&reference, &other_seed));
+        assert!(Args::try_parse_from(["godview-sim", "--compare", "run.json"]).is_err(), "needs --export");
+        for path in [reference, rerun, other_seed] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+}
@@ -0,0 +1,618 @@
+//! Named metrics recorded during a scenario run.
+//!
+//! A [`MetricsRegistry`] holds counters, gauges and histograms by name, each
+//! optionally labelled with the agent it describes, plus a per-tick time
+//! series of scenario-level gauges. The runner fills one per run and attaches
+//! it to [`ScenarioResult::registry`](crate::ScenarioResult::registry),
+//! counting the run's bus events by kind as `events_<kind>`.
+//! [`csv_text`] renders the time series and [`prometheus_text`] the final
+//! values in the Prometheus text exposition format.
+
+use crate::agent::SimulatedAgent;
+use crate::event_bus::{PublishedEvent, SimEventSubscriber};
+use nalgebra::Vector3;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Upper bounds of histogram buckets; larger values only land in `+Inf`.
+pub const HISTOGRAM_BUCKETS: [f64; 13] = [
+    0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0,
+];
+
+/// Prefix of metric names in the Prometheus exposition.
+pub const PROMETHEUS_PREFIX: &str = "godview_";
+
+/// Labels identifying one registry in a combined export, e.g. scenario and seed.
+pub type MetricLabels = Vec<(&'static str, String)>;
+
+/// A metric name, optionally labelled with an agent index.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MetricKey {
+    /// Metric name
+    pub name: String,
+    
+    /// Agent the value describes (None for scenario-wide values)
+    pub agent: Option<u64>,
+}
+
+impl MetricKey {
+    fn scenario(name: &str) -> Self {
+        Self { name: name.to_string(), agent: None }
+    }
+    
+    fn agent(name: &str, agent: u64) -> Self {
+        Self { name: name.to_string(), agent: Some(agent) }
+    }
+}
+
+/// Distribution of observed values over [`HISTOGRAM_BUCKETS`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Histogram {
+    /// Observations per bucket (not cumulative; the `+Inf` overflow is
+    /// `count` minus their sum)
+    pub buckets: Vec<u64>,
+    
+    /// Number of observations
+    pub count: u64,
+    
+    /// Sum of observations
+    pub sum: f64,
+    
+    /// Smallest observation (0.0 if none)
+    pub min: f64,
+    
+    /// Largest observation (0.0 if none)
+    pub max: f64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: vec![0; HISTOGRAM_BUCKETS.len()],
+            count: 0,
+            sum: 0.0,
+            min: 0.0,
+            max: 0.0,
+        }
+    }
+}
+
+impl Histogram {
+    /// Adds one observation.
+    pub fn observe(&mut self, value: f64) {
+        if let Some(i) = HISTOGRAM_BUCKETS.iter().position(|&bound| value <= bound) {
+            self.buckets[i] += 1;
+        }
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.count += 1;
+        self.sum += value;
+    }
+    
+    /// Adds every observation of `other`.
+    pub fn merge(&mut self, other: &Histogram) {
+        if other.count == 0 {
+            return;
+        }
+        for (mine, theirs) in self.buckets.iter_mut().zip(&other.buckets) {
+            *mine += theirs;
+        }
+        if self.count == 0 {
+            self.min = other.min;
+            self.max = other.max;
+        } else {
+            self.min = self.min.min(other.min);
+            self.max = self.max.max(other.max);
+        }
+        self.count += other.count;
+        self.sum += other.sum;
+    }
+    
+    /// Mean observation (0.0 if none).
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.sum / self.count as f64
+    }
+    
+    /// Observations at or below each bucket bound, as (bound, count).
+    pub fn cumulative(&self) -> Vec<(f64, u64)> {
+        let mut total = 0;
+        HISTOGRAM_BUCKETS.iter()
+            .zip(&self.buckets)
+            .map(|(&bound, &n)| {
+                total += n;
+                (bound, total)
+            })
+            .collect()
+    }
+}
+
+/// One agent's tracking state at a tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AgentSample {
+    /// Agent index
+    pub agent: u64,
+    
+    /// RMS position error against ground truth (m)
+    pub rms_error: f64,
+    
+    /// Live tracks
+    pub tracks: usize,
+    
+    /// Live tracks that are confirmed or coasting
+    pub confirmed_tracks: usize,
+    
+    /// Live tracks still awaiting confirmation
+    pub tentative_tracks: usize,
+    
+    /// Gossip packets received so far
+    pub gossip_rx: u64,
+}
+
+impl AgentSample {
+    /// Samples `agent` against the oracle's ground truth.
+    pub fn capture(agent: &SimulatedAgent, ground_truth: &[(u64, Vector3<f64>)]) -> Self {
+        let states = agent.track_state_counts();
+        Self {
+            agent: agent.agent_index(),
+            rms_error: agent.compute_position_error(ground_truth),
+            tracks: agent.track_count(),
+            confirmed_tracks: states.reportable(),
+            tentative_tracks: states.tentative,
+            gossip_rx: agent.gossip_received(),
+        }
+    }
+}
+
+/// Scenario-level gauges recorded at one tick.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct SeriesRow {
+    time_secs: f64,
+    values: BTreeMap<String, f64>,
+}
+
+/// Counters, gauges, histograms and a per-tick time series for one run.
+///
+/// A name should be used for one kind of metric only; the same name may
+/// carry both a scenario-wide value and per-agent values.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsRegistry {
+    counters: BTreeMap<MetricKey, u64>,
+    gauges: BTreeMap<MetricKey, f64>,
+    histograms: BTreeMap<String, Histogram>,
+    series: BTreeMap<u64, SeriesRow>,
+}
+
+impl MetricsRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Returns true if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.counters.is_empty() && self.gauges.is_empty() && self.histograms.is_empty() && self.series.is_empty()
+    }
+    
+    /// Adds `by` to a scenario-wide counter.
+    pub fn inc(&mut self, name: &str, by: u64) {
+        *self.counters.entry(MetricKey::scenario(name)).or_insert(0) += by;
+    }
+    
+    /// Adds `by` to one agent's counter.
+    pub fn inc_agent(&mut self, name: &str, agent: u64, by: u64) {
+        *self.counters.entry(MetricKey::agent(name, agent)).or_insert(0) += by;
+    }
+    
+    /// Sets a scenario-wide gauge.
+    pub fn set_gauge(&mut self, name: &str, value: f64) {
+        self.gauges.insert(MetricKey::scenario(name), value);
+    }
+    
+    /// Sets one agent's gauge.
+    pub fn set_agent_gauge(&mut self, name: &str, agent: u64, value: f64) {
+        self.gauges.insert(MetricKey::agent(name, agent), value);
+    }
+    
+    /// Adds an observation to a histogram.
+    pub fn observe(&mut self, name: &str, value: f64) {
+        self.histograms.entry(name.to_string()).or_default().observe(value);
+    }
+    
+    /// Records a scenario-wide gauge in the time series at `tick`, and sets
+    /// its final value.
+    pub fn record(&mut self, tick: u64, time_secs: f64, name: &str, value: f64) {
+        let row = self.series.entry(tick).or_default();
+        row.time_secs = time_secs;
+        row.values.insert(name.to_string(), value);
+        self.set_gauge(name, value);
+    }
+    
+    /// Records swarm-wide `rms_error` and `tracks_per_agent` (means) and
+    /// `gossip_rx`, `confirmed_tracks` and `tentative_tracks` (totals) in
+    /// the time series at `tick`.
+    ///
+    /// Does nothing without samples.
+    pub fn record_agents(&mut self, tick: u64, time_secs: f64, samples: &[AgentSample]) {
+        if samples.is_empty() {
+            return;
+        }
+        let n = samples.len() as f64;
+        let rms = samples.iter().map(|s| s.rms_error).sum::<f64>() / n;
+        let tracks = samples.iter().map(|s| s.tracks).sum::<usize>() as f64 / n;
+        let gossip = samples.iter().map(|s| s.gossip_rx).sum::<u64>() as f64;
+        let confirmed = samples.iter().map(|s| s.confirmed_tracks).sum::<usize>() as f64;
+        let tentative = samples.iter().map(|s| s.tentative_tracks).sum::<usize>() as f64;
+        self.record(tick, time_secs, "rms_error", rms);
+        self.record(tick, time_secs, "tracks_per_agent", tracks);
+        self.record(tick, time_secs, "gossip_rx", gossip);
+        self.record(tick, time_secs, "confirmed_tracks", confirmed);
+        self.record(tick, time_secs, "tentative_tracks", tentative);
+    }
+    
+    /// Sets per-agent `rms_error`, `tracks_per_agent`, `confirmed_tracks`,
+    /// `tentative_tracks` and `gossip_rx` gauges and adds each agent to the `agent_rms_error` and `agent_tracks`
+    /// histograms.
+    pub fn record_agent_snapshot(&mut self, samples: &[AgentSample]) {
+        for s in samples {
+            self.set_agent_gauge("rms_error", s.agent, s.rms_error);
+            self.set_agent_gauge("tracks_per_agent", s.agent, s.tracks as f64);
+            self.set_agent_gauge("confirmed_tracks", s.agent, s.confirmed_tracks as f64);
+            self.set_agent_gauge("tentative_tracks", s.agent, s.tentative_tracks as f64);
+            self.set_agent_gauge("gossip_rx", s.agent, s.gossip_rx as f64);
+            self.observe("agent_rms_error", s.rms_error);
+            self.observe("agent_tracks", s.tracks as f64);
+        }
+    }
+    
+    /// Folds `other` into this registry: counters add, histograms merge,
+    /// and gauges and time series values from `other` win.
+    pub fn merge(&mut self, other: &MetricsRegistry) {
+        for (key, value) in &other.counters {
+            *self.counters.entry(key.clone()).or_insert(0) += value;
+        }
+        for (key, value) in &other.gauges {
+            self.gauges.insert(key.clone(), *value);
+        }
+        for (name, histogram) in &other.histograms {
+            self.histograms.entry(name.clone()).or_default().merge(histogram);
+        }
+        for (tick, row) in &other.series {
+            let mine = self.series.entry(*tick).or_default();
+            mine.time_secs = row.time_secs;
+            mine.values.extend(row.values.iter().map(|(k, v)| (k.clone(), *v)));
+        }
+    }
+    
+    /// Scenario-wide counter value (0 if never incremented).
+    pub fn counter(&self, name: &str) -> u64 {
+        self.counters.get(&MetricKey::scenario(name)).copied().unwrap_or(0)
+    }
+    
+    /// Scenario-wide gauge value.
+    pub fn gauge(&self, name: &str) -> Option<f64> {
+        self.gauges.get(&MetricKey::scenario(name)).copied()
+    }
+    
+    /// One agent's gauge value.
+    pub fn agent_gauge(&self, name: &str, agent: u64) -> Option<f64> {
+        self.gauges.get(&MetricKey::agent(name, agent)).copied()
+    }
+    
+    /// Mean of a gauge over the agents that have it (None if none do).
+    pub fn agent_mean(&self, name: &str) -> Option<f64> {
+        let (sum, count) = self.gauges.iter()
+            .filter(|(key, _)| key.agent.is_some() && key.name == name)
+            .fold((0.0, 0usize), |(sum, count), (_, v)| (sum + v, count + 1));
+        (count > 0).then(|| sum / count as f64)
+    }
+    
+    /// Histogram by name.
+    pub fn histogram(&self, name: &str) -> Option<&Histogram> {
+        self.histograms.get(name)
+    }
+    
+    /// Ticks with a time series row, in order.
+    pub fn series_ticks(&self) -> impl Iterator<Item = u64> + '_ {
+        self.series.keys().copied()
+    }
+    
+    /// Final values for the JSON summary.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let mut snapshot = MetricsSnapshot::default();
+        for (key, value) in &self.counters {
+            match key.agent {
+                None => { snapshot.counters.insert(key.name.clone(), *value); }
+                Some(agent) => { snapshot.agents.entry(agent).or_default().insert(key.name.clone(), *value as f64); }
+            }
+        }
+        for (key, value) in &self.gauges {
+            match key.agent {
+                None => { snapshot.gauges.insert(key.name.clone(), *value); }
+                Some(agent) => { snapshot.agents.entry(agent).or_default().insert(key.name.clone(), *value); }
+            }
+        }
+        snapshot.histograms = self.histograms.clone();
+        snapshot
+    }
+}
+
+impl SimEventSubscriber for MetricsRegistry {
+    /// Counts each event under `events_<kind>`.
+    fn on_event(&mut self, published: &PublishedEvent) {
+        self.inc(&format!("events_{}", published.event.kind()), 1);
+    }
+}
+
+/// Final registry values, as embedded in the JSON summary.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MetricsSnapshot {
+    /// Scenario-wide counters
+    pub counters: BTreeMap<String, u64>,
+    
+    /// Scenario-wide gauges
+    pub gauges: BTreeMap<String, f64>,
+    
+    /// Histograms
+    pub histograms: BTreeMap<String, Histogram>,
+    
+    /// Per-agent counters and gauges, by agent index
+    pub agents: BTreeMap<u64, BTreeMap<String, f64>>,
+}
+
+/// Renders the time series of each registry as CSV, one row per recorded tick.
+///
+/// Columns are the label names of the first run, `tick`, `time_secs`, then
+/// every series metric in name order; a metric missing from a row is left
+/// empty.
+pub fn csv_text(runs: &[(MetricLabels, &MetricsRegistry)]) -> String {
+    let label_names: Vec<&str> = runs.first()
+        .map(|(labels, _)| labels.iter().map(|(name, _)| *name).collect())
+        .unwrap_or_default();
+    let mut metric_names: Vec<&str> = runs.iter()
+        .flat_map(|(_, registry)| registry.series.values())
+        .flat_map(|row| row.values.keys().map(String::as_str))
+        .collect();
+    metric_names.sort_unstable();
+    metric_names.dedup();
+    
+    let mut csv = String::new();
+    let header = label_names.iter().copied()
+        .chain(["tick", "time_secs"])
+        .chain(metric_names.iter().copied())
+        .map(csv_field)
+        .collect::<Vec<_>>()
+        .join(",");
+    let _ = writeln!(csv, "{header}");
+    
+    for (labels, registry) in runs {
+        let label_values: Vec<String> = label_names.iter()
+            .map(|name| labels.iter().find(|(n, _)| n == name).map(|(_, v)| csv_field(v)).unwrap_or_default())
+            .collect();
+        for (tick, row) in &registry.series {
+            let values = metric_names.iter()
+                .map(|name| row.values.get(*name).map(|v| v.to_string()).unwrap_or_default());
+            let line = label_values.iter().cloned()
+                .chain([tick.to_string(), row.time_secs.to_string()])
+                .chain(values)
+                .collect::<Vec<_>>()
+                .join(",");
+            let _ = writeln!(csv, "{line}");
+        }
+    }
+    csv
+}
+
+/// Quotes a CSV field if it contains a separator, quote or newline.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Renders the final values of each registry in the Prometheus text
+/// exposition format.
+///
+/// Metric names get [`PROMETHEUS_PREFIX`], each run's labels are attached to
+/// every sample, and per-agent values add an `agent` label. Samples of one
+/// metric are grouped under a single `# TYPE` line across runs.
+pub fn prometheus_text(runs: &[(MetricLabels, &MetricsRegistry)]) -> String {
+    let mut samples: Vec<(String, &'static str, String)> = Vec::new();
+    for (labels, registry) in runs {
+        for (key, value) in &registry.counters {
+            let name = format!("{PROMETHEUS_PREFIX}{}", prometheus_name(&key.name));
+            samples.push((name.clone(), "counter", format!("{name}{} {value}", label_set(labels, key.agent, None))));
+        }
+        for (key, value) in &registry.gauges {
+            let name = format!("{PROMETHEUS_PREFIX}{}", prometheus_name(&key.name));
+            samples.push((name.clone(), "gauge", format!("{name}{} {}", label_set(labels, key.agent, None), prometheus_value(*value))));
+        }
+        for (hist_name, histogram) in &registry.histograms {
+            let name = format!("{PROMETHEUS_PREFIX}{}", prometheus_name(hist_name));
+            for (bound, count) in histogram.cumulative() {
+                let le = prometheus_value(bound);
+                samples.push((name.clone(), "histogram", format!("{name}_bucket{} {count}", label_set(labels, None, Some(&le)))));
+            }
+            samples.push((name.clone(), "histogram",
+                format!("{name}_bucket{} {}", label_set(labels, None, Some("+Inf")), histogram.count)));
+            samples.push((name.clone(), "histogram",
+                format!("{name}_sum{} {}", label_set(labels, None, None), prometheus_value(histogram.sum))));
+            samples.push((name.clone(), "histogram",
+                format!("{name}_count{} {}", label_set(labels, None, None), histogram.count)));
+        }
+    }
+    let mut families: BTreeMap<String, (&str, Vec<String>)> = BTreeMap::new();
+    for (name, kind, line) in samples {
+        families.entry(name).or_insert((kind, Vec::new())).1.push(line);
+    }
+    
+    let mut text = String::new();
+    for (name, (kind, lines)) in families {
+        let _ = writeln!(text, "# TYPE {name} {kind}");
+        for line in lines {
+            let _ = writeln!(text, "{line}");
+        }
+    }
+    text
+}
+
+/// Replaces characters Prometheus doesn't allow in metric names with `_`.
+fn prometheus_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == ':' { c } else { '_' })
+        .collect()
+}
+
+/// Formats a sample value, spelling non-finite values the Prometheus way.
+fn prometheus_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Builds `{run labels, agent="..", le=".."}`, or nothing if there are no labels.
+fn label_set(labels: &MetricLabels, agent: Option<u64>, le: Option<&str>) -> String {
+    let agent = agent.map(|a| a.to_string());
+    let pairs: Vec<String> = labels.iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .chain(agent.as_deref().map(|a| ("agent", a)))
+        .chain(le.map(|le| ("le", le)))
+        .map(|(name, value)| format!("{name}=\"{}\"", escape_label(value)))
+        .collect();
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+/// Escapes a label value for the exposition format.
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn sample(agent: u64, rms_error: f64, tracks: usize, gossip_rx: u64) -> AgentSample {
+        AgentSample { agent, rms_error, tracks, confirmed_tracks: tracks - 1, tentative_tracks: 1, gossip_rx }
+    }
+    
+    #[test]
+    fn test_registry_aggregates_agents_and_merges() {
+        let mut registry = MetricsRegistry::new();
+        registry.record_agents(1, 0.1, &[sample(0, 1.0, 4, 10), sample(1, 3.0, 6, 20)]);
+        registry.record_agents(2, 0.2, &[sample(0, 2.0, 4, 15), sample(1, 4.0, 8, 25)]);
+        registry.record_agent_snapshot(&[sample(0, 2.0, 4, 15), sample(1, 4.0, 8, 25)]);
+        registry.inc("packets_sent", 5);
+        
+        // Final gauges are the last series values
+        assert_eq!(registry.gauge("rms_error"), Some(3.0));
+        assert_eq!(registry.gauge("tracks_per_agent"), Some(6.0));
+        assert_eq!(registry.gauge("gossip_rx"), Some(40.0));
+        assert_eq!(registry.gauge("confirmed_tracks"), Some(10.0));
+        assert_eq!(registry.gauge("tentative_tracks"), Some(2.0));
+        assert_eq!(registry.agent_gauge("confirmed_tracks", 1), Some(7.0));
+        assert_eq!(registry.agent_gauge("rms_error", 1), Some(4.0));
+        assert_eq!(registry.agent_mean("tracks_per_agent"), Some(6.0));
+        assert_eq!(registry.agent_mean("missing"), None);
+        assert_eq!(registry.series_ticks().collect::<Vec<_>>(), vec![1, 2]);
+        
+        let tracks = registry.histogram("agent_tracks").unwrap();
+        assert_eq!((tracks.count, tracks.min, tracks.max), (2, 4.0, 8.0));
+        assert_eq!(tracks.cumulative()[6], (10.0, 2));
+        assert_eq!(tracks.cumulative()[5], (5.0, 1));
+        
+        let mut other = MetricsRegistry::new();
+        other.inc("packets_sent", 2);
+        other.set_gauge("rms_error", 9.0);
+        other.observe("agent_tracks", 2000.0);
+        registry.merge(&other);
+        assert_eq!(registry.counter("packets_sent"), 7);
+        assert_eq!(registry.gauge("rms_error"), Some(9.0));
+        let tracks = registry.histogram("agent_tracks").unwrap();
+        assert_eq!((tracks.count, tracks.max), (3, 2000.0));
+        assert_eq!(tracks.cumulative().last(), Some(&(1000.0, 2)), "overflow only counts toward +Inf");
+        
+        let snapshot = registry.snapshot();
+        assert_eq!(snapshot.counters["packets_sent"], 7);
+        assert_eq!(snapshot.agents[&0]["gossip_rx"], 15.0);
+    }
+    
+    #[test]
+    fn test_csv_has_one_row_per_tick_with_run_labels() {
+        let mut a = MetricsRegistry::new();
+        a.record(1, 0.5, "rms_error", 1.5);
+        a.record(2, 1.0, "rms_error", 2.5);
+        let mut b = MetricsRegistry::new();
+        b.record(1, 0.5, "detection_rate", 0.25);
+        
+        let runs = vec![
+            (vec![("scenario", "swarm".to_string()), ("seed", "42".to_string())], &a),
+            (vec![("scenario", "a,b".to_string()), ("seed", "7".to_string())], &b),
+        ];
+        let csv = csv_text(&runs);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines, vec![
+            "scenario,seed,tick,time_secs,detection_rate,rms_error",
+            "swarm,42,1,0.5,,1.5",
+            "swarm,42,2,1,,2.5",
+            "\"a,b\",7,1,0.5,0.25,",
+        ]);
+    }
+    
+    #[test]
+    fn test_prometheus_groups_families_across_runs() {
+        let mut a = MetricsRegistry::new();
+        a.inc("packets_sent", 3);
+        a.set_gauge("rms_error", 1.25);
+        a.set_agent_gauge("rms_error", 2, 0.5);
+        a.observe("agent_tracks", 3.0);
+        let mut b = MetricsRegistry::new();
+        b.inc("packets_sent", 4);
+        b.set_gauge("rms_error", f64::NAN);
+        
+        let runs = vec![
+            (vec![("scenario", "swarm".to_string())], &a),
+            (vec![("scenario", "say \"hi\"".to_string())], &b),
+        ];
+        let text = prometheus_text(&runs);
+        
+        assert_eq!(text.matches("# TYPE godview_packets_sent counter").count(), 1);
+        assert!(text.contains("godview_packets_sent{scenario=\"swarm\"} 3\n"));
+        assert!(text.contains("godview_packets_sent{scenario=\"say \\\"hi\\\"\"} 4\n"));
+        assert!(text.contains("# TYPE godview_rms_error gauge\n"));
+        assert!(text.contains("godview_rms_error{scenario=\"swarm\",agent=\"2\"} 0.5\n"));
+        assert!(text.contains("godview_rms_error{scenario=\"say \\\"hi\\\"\"} NaN\n"));
+        assert!(text.contains("# TYPE godview_agent_tracks histogram\n"));
+        assert!(text.contains("godview_agent_tracks_bucket{scenario=\"swarm\",le=\"2.5\"} 0\n"));
+        assert!(text.contains("godview_agent_tracks_bucket{scenario=\"swarm\",le=\"5\"} 1\n"));
+        assert!(text.contains("godview_agent_tracks_bucket{scenario=\"swarm\",le=\"+Inf\"} 1\n"));
+        assert!(text.contains("godview_agent_tracks_count{scenario=\"swarm\"} 1\n"));
+        
+        // Every sample line follows its family's TYPE line
+        let mut current = String::new();
+        for line in text.lines() {
+            if let Some(rest) = line.strip_prefix("# TYPE ") {
+                current = rest.split(' ').next().unwrap().to_string();
+            } else {
+                assert!(line.starts_with(&current), "{line} outside {current}");
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Agents that move.
+//!
+//! An agent's sensor sits where [`SwarmHarness::with_sensor_positions`]
+//! put it unless its profile carries an [`AgentMotion`]
+//! ([`AgentProfile::with_motion`]). The harness then moves it every tick,
+//! carries its scanning sensor along ([`Oracle::move_sensor`]) and, with a
+//! radio range ([`SwarmHarness::with_radio_range`]), rebuilds the gossip
+//! topology from the new positions ([`SwarmNetwork::move_agents`]).
+//!
+//! Positions are a function of time from the starting position, not
+//! integrated tick by tick, so they don't drift with the tick rate.
+//!
+//! [`SwarmHarness::with_sensor_positions`]: crate::SwarmHarness::with_sensor_positions
+//! [`SwarmHarness::with_radio_range`]: crate::SwarmHarness::with_radio_range
+//! [`AgentProfile::with_motion`]: crate::AgentProfile::with_motion
+//! [`Oracle::move_sensor`]: crate::Oracle::move_sensor
+//! [`SwarmNetwork::move_agents`]: crate::SwarmNetwork::move_agents
+
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// How an agent moves from its starting position.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AgentMotion {
+    /// Straight on at a constant velocity (m/s)
+    Velocity(Vector3<f64>),
+    
+    /// Through each waypoint in turn at `speed_mps`, then holding at the
+    /// last one
+    Waypoints { waypoints: Vec<Vector3<f64>>, speed_mps: f64 },
+}
+
+impl AgentMotion {
+    /// Position `time_secs` after leaving `start`.
+    pub fn position_at(&self, start: &Vector3<f64>, time_secs: f64) -> Vector3<f64> {
+        let time_secs = time_secs.max(0.0);
+        match self {
+            AgentMotion::Velocity(velocity) => start + velocity * time_secs,
+            AgentMotion::Waypoints { waypoints, speed_mps } => {
+                let mut remaining = speed_mps.max(0.0) * time_secs;
+                let mut from = *start;
+                for to in waypoints {
+                    let leg = (to - from).norm();
+                    if remaining < leg {
+                        return from + (to - from) * (remaining / leg);
+                    }
+                    remaining -= leg;
+                    from = *to;
+                }
+                from
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_waypoints_are_followed_at_speed_then_held() {
+        let start = Vector3::new(0.0, 0.0, 10.0);
+        let motion = AgentMotion::Waypoints {
+            waypoints: vec![Vector3::new(30.0, 0.0, 10.0), Vector3::new(30.0, 40.0, 10.0)],
+            speed_mps: 10.0,
+        };
+        assert_eq!(motion.position_at(&start, 0.0), start);
+        assert_eq!(motion.position_at(&start, 1.5), Vector3::new(15.0, 0.0, 10.0));
+        assert_eq!(motion.position_at(&start, 5.0), Vector3::new(30.0, 20.0, 10.0));
+        assert_eq!(motion.position_at(&start, 60.0), Vector3::new(30.0, 40.0, 10.0));
+        
+        let driving = AgentMotion::Velocity(Vector3::new(2.0, -1.0, 0.0));
+        assert_eq!(driving.position_at(&start, 3.0), Vector3::new(6.0, -3.0, 10.0));
+    }
+}
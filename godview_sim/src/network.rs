@@ -1,12 +1,21 @@
 //! Simulated network transport with fault injection.
 
 use async_trait::async_trait;
-use godview_env::{EnvError, NetworkTransport, NodeId, SignedPacketEnvelope};
-use std::collections::HashMap;
+use crate::context::SimContext;
+use crate::event_bus::{SimEvent, SimEventBus};
+use godview_env::{EnvError, GodViewContext, NetworkTransport, NodeId, SignedPacketEnvelope};
+use rand::Rng;
+use rand_distr::Distribution;
+use crate::rng_audit::AuditedRng;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use tokio::sync::mpsc;
 
+/// Capacity of each channel of a [`SimNetwork::mesh`].
+const MESH_CHANNEL_CAPACITY: usize = 1000;
+
 /// Simulated network interface for an agent.
 pub struct SimNetwork {
     /// This node's ID
@@ -17,6 +26,9 @@ pub struct SimNetwork {
     
     /// Receiver for incoming packets (behind tokio mutex for async)
     rx: Arc<tokio::sync::Mutex<mpsc::Receiver<(NodeId, SignedPacketEnvelope)>>>,
+    
+    /// Nodes a broadcast goes to, in order
+    peers: Vec<NodeId>,
 
     /// Metric: Total bytes sent
     pub bytes_sent: Arc<AtomicU64>,
@@ -44,11 +56,61 @@ impl SimNetwork {
             local_id,
             tx,
             rx: Arc::new(tokio::sync::Mutex::new(rx)),
+            peers: Vec::new(),
             bytes_sent: Arc::new(AtomicU64::new(0)),
             packets_sent: Arc::new(AtomicU64::new(0)),
         }
     }
     
+    /// Makes broadcasts go to `peers`, in order (by default they go nowhere).
+    pub fn with_peers(mut self, peers: Vec<NodeId>) -> Self {
+        self.peers = peers;
+        self
+    }
+    
+    /// Connects `nodes` to each other through a router task spawned on
+    /// `context`, so attach a [`SimExecutor`](crate::SimExecutor) first.
+    /// The router holds each packet for a latency drawn from its link's
+    /// model in `controller` on the context's virtual clock and drops packets across a partition;
+    /// loss and reorder/duplicate faults aren't applied. Each link delivers
+    /// in send order, and packets due at the same instant arrive in the
+    /// order they were sent. Every node's broadcasts go to the others, in
+    /// `nodes` order.
+    pub fn mesh(context: &SimContext, controller: &SimNetworkController, nodes: &[NodeId]) -> Vec<SimNetwork> {
+        let (router_tx, mut router_rx) = mpsc::channel::<NetworkMessage>(MESH_CHANNEL_CAPACITY);
+        let mut inboxes = HashMap::new();
+        let networks = nodes.iter()
+            .map(|&node| {
+                let (inbox_tx, inbox_rx) = mpsc::channel(MESH_CHANNEL_CAPACITY);
+                inboxes.insert(node, inbox_tx);
+                let peers = nodes.iter().copied().filter(|peer| *peer != node).collect();
+                SimNetwork::new(node, router_tx.clone(), inbox_rx).with_peers(peers)
+            })
+            .collect();
+        
+        let (ctx, controller) = (context.clone(), controller.clone());
+        context.spawn("network/router", async move {
+            let mut last_due: HashMap<(NodeId, NodeId), u64> = HashMap::new();
+            while let Some(msg) = router_rx.recv().await {
+                if !controller.can_communicate(msg.from, msg.to) {
+                    continue;
+                }
+                let Some(inbox) = inboxes.get(&msg.to).cloned() else {
+                    continue;
+                };
+                let latency_ns = controller.sample_latency(msg.from, msg.to) * 1_000_000;
+                let link_due = last_due.entry((msg.from, msg.to)).or_default();
+                *link_due = (ctx.time_ns() + latency_ns).max(*link_due);
+                let (due_ns, delivery_ctx) = (*link_due, ctx.clone());
+                ctx.spawn("network/delivery", async move {
+                    delivery_ctx.sleep(Duration::from_nanos(due_ns.saturating_sub(delivery_ctx.time_ns()))).await;
+                    let _ = inbox.send((msg.from, msg.packet)).await;
+                });
+            }
+        });
+        networks
+    }
+    
     /// Creates a stub network for testing (doesn't actually send/receive).
     pub fn new_stub(local_id: NodeId) -> Self {
         let (tx, _) = mpsc::channel(1);
@@ -57,10 +119,12 @@ impl SimNetwork {
             local_id,
             tx,
             rx: Arc::new(tokio::sync::Mutex::new(rx)),
+            peers: Vec::new(),
             bytes_sent: Arc::new(AtomicU64::new(0)),
             packets_sent: Arc::new(AtomicU64::new(0)),
         }
     }
+    
     /// Returns current bandwidth usage (bytes_sent, packets_sent).
     pub fn get_bandwidth_usage(&self) -> (u64, u64) {
         (
@@ -95,10 +159,14 @@ impl NetworkTransport for SimNetwork {
         rx.recv().await
     }
     
-    async fn broadcast(&self, _packet: SignedPacketEnvelope) -> usize {
-        // Broadcasts would be sent to all known peers
-        // For now, return 0 as we don't have peer discovery yet
-        0
+    async fn broadcast(&self, packet: SignedPacketEnvelope) -> usize {
+        let mut sent = 0;
+        for &peer in &self.peers {
+            if self.send(peer, packet.clone()).await.is_ok() {
+                sent += 1;
+            }
+        }
+        sent
     }
     
     fn local_id(&self) -> NodeId {
@@ -106,32 +174,283 @@ impl NetworkTransport for SimNetwork {
     }
 }
 
+/// A pair of node groups that cannot reach each other.
+type Partition = (Vec<NodeId>, Vec<NodeId>);
+
+/// Counters for reorder/duplicate faults applied on a link.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LinkFaultStats {
+    /// Messages dequeued (excluding injected duplicates)
+    pub delivered: u64,
+    
+    /// Messages held back behind later ones
+    pub reordered: u64,
+    
+    /// Extra copies delivered
+    pub duplicated: u64,
+}
+
+/// Distribution of one-way delivery delays, at millisecond resolution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    /// Deliveries per delay (ms)
+    counts: BTreeMap<u64, u64>,
+    
+    /// Total deliveries
+    count: u64,
+}
+
+impl LatencyHistogram {
+    /// Records one delivery that took `delay_ms`.
+    pub fn observe(&mut self, delay_ms: u64) {
+        *self.counts.entry(delay_ms).or_default() += 1;
+        self.count += 1;
+    }
+    
+    /// Adds every delivery of `other`.
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        for (&delay_ms, &n) in &other.counts {
+            *self.counts.entry(delay_ms).or_default() += n;
+        }
+        self.count += other.count;
+    }
+    
+    /// Number of deliveries recorded.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+    
+    /// Smallest delay at or below which a `q` fraction of deliveries
+    /// arrived (0 if none were recorded).
+    pub fn quantile(&self, q: f64) -> u64 {
+        let rank = ((q.clamp(0.0, 1.0) * self.count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (&delay_ms, &n) in &self.counts {
+            seen += n;
+            if seen >= rank {
+                return delay_ms;
+            }
+        }
+        0
+    }
+    
+    /// Median delay (ms).
+    pub fn p50(&self) -> u64 {
+        self.quantile(0.5)
+    }
+    
+    /// 95th percentile delay (ms).
+    pub fn p95(&self) -> u64 {
+        self.quantile(0.95)
+    }
+    
+    /// 99th percentile delay (ms).
+    pub fn p99(&self) -> u64 {
+        self.quantile(0.99)
+    }
+    
+    /// Longest delay (0 if none were recorded).
+    pub fn max(&self) -> u64 {
+        self.counts.keys().next_back().copied().unwrap_or(0)
+    }
+}
+
+/// One-way delay of a link, drawn per message from the link's RNG stream.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LatencyModel {
+    /// Every message takes `ms`
+    Constant { ms: f64 },
+    
+    /// Uniform between `min_ms` and `max_ms`
+    Uniform { min_ms: f64, max_ms: f64 },
+    
+    /// `exp(N(mu, sigma²))` ms: median `exp(mu)`, with a heavy right tail
+    LogNormal { mu: f64, sigma: f64 },
+    
+    /// `slow_ms` (the retransmit path) with probability `p_slow`, otherwise
+    /// `fast_ms`
+    Bimodal { fast_ms: f64, slow_ms: f64, p_slow: f64 },
+}
+
+impl LatencyModel {
+    /// Mesh radio link: median 55ms, p95 about 240ms, p99 about 450ms.
+    pub const MESH_LOGNORMAL: Self = Self::LogNormal { mu: 4.0, sigma: 0.9 };
+    
+    /// 15ms direct, 350ms for the one message in ten that is retransmitted.
+    pub const MESH_BIMODAL: Self = Self::Bimodal { fast_ms: 15.0, slow_ms: 350.0, p_slow: 0.1 };
+    
+    /// Draws one delay (ms, never negative).
+    pub fn sample_ms(&self, rng: &mut impl Rng) -> f64 {
+        let ms = match *self {
+            Self::Constant { ms } => ms,
+            Self::Uniform { min_ms, max_ms } if max_ms > min_ms => rng.gen_range(min_ms..max_ms),
+            Self::Uniform { min_ms, .. } => min_ms,
+            Self::LogNormal { mu, sigma } => match rand_distr::LogNormal::new(mu, sigma) {
+                Ok(lognormal) => lognormal.sample(rng),
+                Err(_) => mu.exp(),
+            },
+            Self::Bimodal { fast_ms, slow_ms, p_slow } => {
+                if rng.gen_bool(p_slow.clamp(0.0, 1.0)) { slow_ms } else { fast_ms }
+            }
+        };
+        ms.max(0.0)
+    }
+    
+    /// Expected delay (ms).
+    pub fn mean_ms(&self) -> f64 {
+        match *self {
+            Self::Constant { ms } => ms,
+            Self::Uniform { min_ms, max_ms } => (min_ms + max_ms.max(min_ms)) / 2.0,
+            Self::LogNormal { mu, sigma } => (mu + sigma * sigma / 2.0).exp(),
+            Self::Bimodal { fast_ms, slow_ms, p_slow } => fast_ms + p_slow * (slow_ms - fast_ms),
+        }
+    }
+    
+    /// Variance of the delay (ms²).
+    pub fn variance_ms2(&self) -> f64 {
+        match *self {
+            Self::Constant { .. } => 0.0,
+            Self::Uniform { min_ms, max_ms } => (max_ms.max(min_ms) - min_ms).powi(2) / 12.0,
+            Self::LogNormal { mu, sigma } => ((sigma * sigma).exp() - 1.0) * (2.0 * mu + sigma * sigma).exp(),
+            Self::Bimodal { fast_ms, slow_ms, p_slow } => p_slow * (1.0 - p_slow) * (slow_ms - fast_ms).powi(2),
+        }
+    }
+    
+    /// Whether every message takes the same time (no draws).
+    pub fn is_constant(&self) -> bool {
+        matches!(self, Self::Constant { .. })
+    }
+}
+
+impl Default for LatencyModel {
+    fn default() -> Self {
+        Self::Constant { ms: 0.0 }
+    }
+}
+
+impl std::fmt::Display for LatencyModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Constant { ms } => write!(f, "constant:{ms}"),
+            Self::Uniform { min_ms, max_ms } => write!(f, "uniform:{min_ms},{max_ms}"),
+            Self::LogNormal { mu, sigma } => write!(f, "lognormal:{mu},{sigma}"),
+            Self::Bimodal { fast_ms, slow_ms, p_slow } => write!(f, "bimodal:{fast_ms},{slow_ms},{p_slow}"),
+        }
+    }
+}
+
+impl std::str::FromStr for LatencyModel {
+    type Err = String;
+    
+    /// Parses `constant:MS`, `uniform:MIN,MAX`, `lognormal:MU,SIGMA`,
+    /// `bimodal:FAST,SLOW,P_SLOW`, or `lognormal` / `bimodal` alone for
+    /// [`Self::MESH_LOGNORMAL`] / [`Self::MESH_BIMODAL`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, params) = s.split_once(':').unwrap_or((s, ""));
+        let values = params.split(',')
+            .filter(|p| !p.trim().is_empty())
+            .map(|p| p.trim().parse::<f64>().map_err(|e| format!("bad latency parameter '{p}': {e}")))
+            .collect::<Result<Vec<f64>, String>>()?;
+        if values.iter().any(|v| !v.is_finite()) {
+            return Err(format!("latency parameters must be finite: {s}"));
+        }
+        let model = match (kind.trim().to_ascii_lowercase().as_str(), values.as_slice()) {
+            ("lognormal", []) => Self::MESH_LOGNORMAL,
+            ("bimodal", []) => Self::MESH_BIMODAL,
+            ("constant", &[ms]) if ms >= 0.0 => Self::Constant { ms },
+            ("uniform", &[min_ms, max_ms]) if 0.0 <= min_ms && min_ms <= max_ms => Self::Uniform { min_ms, max_ms },
+            ("lognormal", &[mu, sigma]) if sigma >= 0.0 => Self::LogNormal { mu, sigma },
+            ("bimodal", &[fast_ms, slow_ms, p_slow]) if fast_ms >= 0.0 && slow_ms >= 0.0 && (0.0..=1.0).contains(&p_slow) => {
+                Self::Bimodal { fast_ms, slow_ms, p_slow }
+            }
+            _ => return Err(format!(
+                "expected constant:MS, uniform:MIN,MAX, lognormal[:MU,SIGMA] or bimodal[:FAST,SLOW,P_SLOW] \
+                 with non-negative delays, got '{s}'"
+            )),
+        };
+        Ok(model)
+    }
+}
+
+/// Reorder/duplicate configuration and RNG stream for one directed link.
+struct LinkFaults {
+    reorder_probability: f64,
+    max_displacement: usize,
+    duplicate_probability: f64,
+    rng: AuditedRng,
+    stats: LinkFaultStats,
+    
+    /// Delays drawn for the link's messages
+    latency: LatencyHistogram,
+}
+
+impl LinkFaults {
+    fn new(seed: u64, from: NodeId, to: NodeId) -> Self {
+        Self {
+            reorder_probability: 0.0,
+            max_displacement: 0,
+            duplicate_probability: 0.0,
+            rng: AuditedRng::derive(seed, &format!("network/link-{}-{}", from, to)),
+            stats: LinkFaultStats::default(),
+            latency: LatencyHistogram::default(),
+        }
+    }
+}
+
 /// Network controller for fault injection.
+///
+/// Clones share the same partitions and link settings, so a scenario can
+/// keep a handle while a [`SwarmNetwork`](crate::SwarmNetwork) routes
+/// through another.
+#[derive(Clone)]
 pub struct SimNetworkController {
-    /// Per-link latency in milliseconds
-    link_latency: Arc<Mutex<HashMap<(NodeId, NodeId), u64>>>,
+    /// Per-link latency distribution
+    link_latency: Arc<Mutex<HashMap<(NodeId, NodeId), LatencyModel>>>,
     
     /// Per-link packet loss rate (0.0 - 1.0)
     link_loss: Arc<Mutex<HashMap<(NodeId, NodeId), f64>>>,
     
     /// Active partitions (nodes that cannot communicate)
-    partitions: Arc<Mutex<Vec<(Vec<NodeId>, Vec<NodeId>)>>>,
+    partitions: Arc<Mutex<Vec<Partition>>>,
+    
+    /// Per-link reorder/duplicate faults, applied at dequeue time
+    link_faults: Arc<Mutex<HashMap<(NodeId, NodeId), LinkFaults>>>,
+    
+    /// Seed for per-link fault RNG streams
+    seed: u64,
+    
+    /// Bus partitions and heals are published on
+    events: Arc<Mutex<Option<SimEventBus>>>,
 }
 
 impl SimNetworkController {
     /// Creates a new network controller.
     pub fn new() -> Self {
+        Self::with_seed(0)
+    }
+    
+    /// Creates a network controller whose link fault streams derive from `seed`.
+    pub fn with_seed(seed: u64) -> Self {
         Self {
             link_latency: Arc::new(Mutex::new(HashMap::new())),
             link_loss: Arc::new(Mutex::new(HashMap::new())),
             partitions: Arc::new(Mutex::new(Vec::new())),
+            link_faults: Arc::new(Mutex::new(HashMap::new())),
+            seed,
+            events: Arc::new(Mutex::new(None)),
         }
     }
     
-    /// Sets latency for a specific link.
+    /// Sets a constant latency for a specific link.
     pub fn set_latency(&self, from: NodeId, to: NodeId, latency_ms: u64) {
+        self.set_latency_model(from, to, LatencyModel::Constant { ms: latency_ms as f64 });
+    }
+    
+    /// Draws each message's latency on a link from `model`, using the
+    /// link's RNG stream.
+    pub fn set_latency_model(&self, from: NodeId, to: NodeId, model: LatencyModel) {
         let mut latencies = self.link_latency.lock().unwrap();
-        latencies.insert((from, to), latency_ms);
+        latencies.insert((from, to), model);
     }
     
     /// Sets packet loss rate for a link.
@@ -140,16 +459,29 @@ impl SimNetworkController {
         losses.insert((from, to), loss_rate.clamp(0.0, 1.0));
     }
     
+    /// Publishes every partition and heal, from this handle and its clones,
+    /// on `bus`.
+    pub fn set_event_bus(&self, bus: SimEventBus) {
+        *self.events.lock().unwrap() = Some(bus);
+    }
+    
+    fn publish(&self, event: SimEvent) {
+        if let Some(bus) = self.events.lock().unwrap().as_ref() {
+            bus.publish(event);
+        }
+    }
+    
     /// Creates a network partition between two groups.
     pub fn partition(&self, group_a: Vec<NodeId>, group_b: Vec<NodeId>) {
-        let mut partitions = self.partitions.lock().unwrap();
-        partitions.push((group_a, group_b));
+        let event = SimEvent::Partitioned { group_a: group_a.clone(), group_b: group_b.clone() };
+        self.partitions.lock().unwrap().push((group_a, group_b));
+        self.publish(event);
     }
     
     /// Heals all active partitions.
     pub fn heal_all(&self) {
-        let mut partitions = self.partitions.lock().unwrap();
-        partitions.clear();
+        self.partitions.lock().unwrap().clear();
+        self.publish(SimEvent::Healed);
     }
     
     /// Checks if two nodes can communicate (not partitioned).
@@ -171,10 +503,45 @@ impl SimNetworkController {
         true
     }
     
-    /// Gets the latency for a link (default 0).
-    pub fn get_latency(&self, from: NodeId, to: NodeId) -> u64 {
+    /// Gets a link's latency model (default: constant 0).
+    pub fn latency_model(&self, from: NodeId, to: NodeId) -> LatencyModel {
         let latencies = self.link_latency.lock().unwrap();
-        *latencies.get(&(from, to)).unwrap_or(&0)
+        latencies.get(&(from, to)).copied().unwrap_or_default()
+    }
+    
+    /// Gets the mean latency for a link in ms (default 0): what every
+    /// message takes on a constant link.
+    pub fn get_latency(&self, from: NodeId, to: NodeId) -> u64 {
+        self.latency_model(from, to).mean_ms().round() as u64
+    }
+    
+    /// Draws one message's latency (ms) for a link and records it in the
+    /// link's histogram. Links without a model take 0 and record nothing;
+    /// constant ones draw nothing from the link's RNG stream.
+    pub fn sample_latency(&self, from: NodeId, to: NodeId) -> u64 {
+        let Some(model) = self.link_latency.lock().unwrap().get(&(from, to)).copied() else {
+            return 0;
+        };
+        let mut faults = self.link_faults.lock().unwrap();
+        let link = faults.entry((from, to)).or_insert_with(|| LinkFaults::new(self.seed, from, to));
+        let latency_ms = model.sample_ms(&mut link.rng).round() as u64;
+        link.latency.observe(latency_ms);
+        latency_ms
+    }
+    
+    /// Returns the latencies drawn for a link's messages.
+    pub fn link_latency(&self, from: NodeId, to: NodeId) -> LatencyHistogram {
+        let faults = self.link_faults.lock().unwrap();
+        faults.get(&(from, to)).map(|l| l.latency.clone()).unwrap_or_default()
+    }
+    
+    /// Returns the latencies drawn for every link's messages.
+    pub fn total_link_latency(&self) -> LatencyHistogram {
+        let faults = self.link_faults.lock().unwrap();
+        faults.values().fold(LatencyHistogram::default(), |mut acc, l| {
+            acc.merge(&l.latency);
+            acc
+        })
     }
     
     /// Gets the loss rate for a link (default 0.0).
@@ -182,6 +549,68 @@ impl SimNetworkController {
         let losses = self.link_loss.lock().unwrap();
         *losses.get(&(from, to)).unwrap_or(&0.0)
     }
+    
+    /// Holds back dequeued messages with `probability`, delivering each one
+    /// behind up to `max_displacement` later messages on the same link, or
+    /// as many ticks later if the link goes quiet first.
+    pub fn set_reorder(&self, from: NodeId, to: NodeId, probability: f64, max_displacement: usize) {
+        let mut faults = self.link_faults.lock().unwrap();
+        let link = faults.entry((from, to)).or_insert_with(|| LinkFaults::new(self.seed, from, to));
+        link.reorder_probability = probability.clamp(0.0, 1.0);
+        link.max_displacement = max_displacement;
+    }
+    
+    /// Delivers an extra copy of dequeued messages with `probability`.
+    pub fn set_duplicate(&self, from: NodeId, to: NodeId, probability: f64) {
+        let mut faults = self.link_faults.lock().unwrap();
+        let link = faults.entry((from, to)).or_insert_with(|| LinkFaults::new(self.seed, from, to));
+        link.duplicate_probability = probability.clamp(0.0, 1.0);
+    }
+    
+    /// Returns reorder/duplicate counters for a link.
+    pub fn link_fault_stats(&self, from: NodeId, to: NodeId) -> LinkFaultStats {
+        let faults = self.link_faults.lock().unwrap();
+        faults.get(&(from, to)).map(|l| l.stats).unwrap_or_default()
+    }
+    
+    /// Returns reorder/duplicate counters summed over all links.
+    pub fn total_link_fault_stats(&self) -> LinkFaultStats {
+        let faults = self.link_faults.lock().unwrap();
+        faults.values().fold(LinkFaultStats::default(), |acc, l| LinkFaultStats {
+            delivered: acc.delivered + l.stats.delivered,
+            reordered: acc.reordered + l.stats.reordered,
+            duplicated: acc.duplicated + l.stats.duplicated,
+        })
+    }
+    
+    /// Decides whether to hold back a message, returning how many later
+    /// messages it should be delivered behind.
+    fn roll_reorder(&self, from: NodeId, to: NodeId) -> Option<usize> {
+        let mut faults = self.link_faults.lock().unwrap();
+        let link = faults.get_mut(&(from, to))?;
+        if link.max_displacement == 0 || link.reorder_probability <= 0.0 {
+            return None;
+        }
+        if !link.rng.gen_bool(link.reorder_probability) {
+            return None;
+        }
+        link.stats.reordered += 1;
+        Some(link.rng.gen_range(1..=link.max_displacement))
+    }
+    
+    /// Counts a delivery and decides whether to duplicate it.
+    fn roll_duplicate(&self, from: NodeId, to: NodeId) -> bool {
+        let mut faults = self.link_faults.lock().unwrap();
+        let Some(link) = faults.get_mut(&(from, to)) else {
+            return false;
+        };
+        link.stats.delivered += 1;
+        if link.duplicate_probability <= 0.0 || !link.rng.gen_bool(link.duplicate_probability) {
+            return false;
+        }
+        link.stats.duplicated += 1;
+        true
+    }
 }
 
 impl Default for SimNetworkController {
@@ -190,9 +619,114 @@ impl Default for SimNetworkController {
     }
 }
 
+/// Per-link delivery queue that applies the controller's reorder and
+/// duplicate faults as messages are dequeued.
+pub struct DelayQueue<T> {
+    from: NodeId,
+    to: NodeId,
+    
+    /// (deliver_tick, message), ordered by delivery tick
+    pending: VecDeque<(u64, T)>,
+    
+    /// Held-back messages, how many more deliveries they wait behind and
+    /// the tick they're released by regardless
+    held: Vec<(usize, u64, T)>,
+}
+
+impl<T: Clone> DelayQueue<T> {
+    /// Creates an empty queue for the link `from -> to`.
+    pub fn new(from: NodeId, to: NodeId) -> Self {
+        Self { from, to, pending: VecDeque::new(), held: Vec::new() }
+    }
+    
+    /// Enqueues `item` for delivery at `deliver_tick`, after anything
+    /// already due at that tick.
+    pub fn push(&mut self, deliver_tick: u64, item: T) {
+        let idx = self.pending.partition_point(|(t, _)| *t <= deliver_tick);
+        self.pending.insert(idx, (deliver_tick, item));
+    }
+    
+    /// Enqueues `item`, sent at `tick`, behind a latency drawn from the
+    /// link's model in `controller` (see
+    /// [`SimNetworkController::sample_latency`]), rounded down to whole
+    /// ticks of `tick_secs`.
+    pub fn push_after_latency(&mut self, tick: u64, tick_secs: f64, item: T, controller: &SimNetworkController) {
+        let latency_ms = controller.sample_latency(self.from, self.to);
+        let delay_ticks = (latency_ms as f64 / 1000.0 / tick_secs) as u64;
+        self.push(tick + delay_ticks, item);
+    }
+    
+    /// Dequeues everything due by `tick`, with faults from `controller`
+    /// applied. A message held back at tick `t` behind `n` later ones is
+    /// released by tick `t + n` even if fewer arrive, so call this every
+    /// tick, including ticks with nothing pushed.
+    pub fn pop_due(&mut self, tick: u64, controller: &SimNetworkController) -> Vec<T> {
+        let mut out = Vec::new();
+        
+        while self.pending.front().is_some_and(|(t, _)| *t <= tick) {
+            let (_, item) = self.pending.pop_front().unwrap();
+            
+            if let Some(displacement) = controller.roll_reorder(self.from, self.to) {
+                self.held.push((displacement, tick.saturating_add(displacement as u64), item));
+                continue;
+            }
+            self.deliver(item, controller, &mut out);
+            
+            // Release held-back messages whose displacement has been served
+            for entry in self.held.iter_mut() {
+                entry.0 -= 1;
+            }
+            self.release_held(|(remaining, _, _)| *remaining == 0, controller, &mut out);
+        }
+        
+        // ...and those whose link went quiet before it was
+        self.release_held(|(_, release_tick, _)| *release_tick <= tick, controller, &mut out);
+        
+        out
+    }
+    
+    /// Dequeues everything regardless of delivery tick, releasing
+    /// held-back messages last.
+    pub fn drain(&mut self, controller: &SimNetworkController) -> Vec<T> {
+        let mut out = self.pop_due(u64::MAX, controller);
+        for (_, _, item) in std::mem::take(&mut self.held) {
+            self.deliver(item, controller, &mut out);
+        }
+        out
+    }
+    
+    /// Number of messages still queued or held back.
+    pub fn len(&self) -> usize {
+        self.pending.len() + self.held.len()
+    }
+    
+    /// Whether nothing is queued or held back.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty() && self.held.is_empty()
+    }
+    
+    /// Delivers the held-back messages `release` picks, in the order they
+    /// were held.
+    fn release_held(&mut self, release: impl Fn(&(usize, u64, T)) -> bool, controller: &SimNetworkController, out: &mut Vec<T>) {
+        while let Some(idx) = self.held.iter().position(&release) {
+            let (_, _, released) = self.held.remove(idx);
+            self.deliver(released, controller, out);
+        }
+    }
+    
+    fn deliver(&self, item: T, controller: &SimNetworkController, out: &mut Vec<T>) {
+        if controller.roll_duplicate(self.from, self.to) {
+            out.push(item.clone());
+        }
+        out.push(item);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
     
     #[test]
     fn test_network_controller_partition() {
@@ -238,6 +772,168 @@ mod tests {
         assert_eq!(controller.get_latency(b, a), 0);
     }
     
+    #[test]
+    fn test_latency_models_match_their_moments() {
+        let n = 200_000;
+        let models = [
+            LatencyModel::Constant { ms: 40.0 },
+            LatencyModel::Uniform { min_ms: 0.0, max_ms: 500.0 },
+            LatencyModel::MESH_LOGNORMAL,
+            LatencyModel::MESH_BIMODAL,
+        ];
+        for model in models {
+            let mut rng = ChaCha8Rng::seed_from_u64(42);
+            let samples: Vec<f64> = (0..n).map(|_| model.sample_ms(&mut rng)).collect();
+            let mean = samples.iter().sum::<f64>() / n as f64;
+            let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+            
+            let (expected_mean, expected_variance) = (model.mean_ms(), model.variance_ms2());
+            assert!((mean - expected_mean).abs() <= 0.01 * expected_mean, "{model}: mean {mean}, expected {expected_mean}");
+            assert!((variance - expected_variance).abs() <= 0.05 * expected_variance.max(1e-9),
+                "{model}: variance {variance}, expected {expected_variance}");
+            assert!(samples.iter().all(|&s| s >= 0.0));
+        }
+        
+        // The lognormal median is exp(mu); the bimodal one only takes its two values
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let mut lognormal: Vec<f64> = (0..n).map(|_| LatencyModel::MESH_LOGNORMAL.sample_ms(&mut rng)).collect();
+        lognormal.sort_by(f64::total_cmp);
+        assert!((lognormal[n / 2] - 4.0f64.exp()).abs() < 1.0, "median {}", lognormal[n / 2]);
+        let slow = (0..n).map(|_| LatencyModel::MESH_BIMODAL.sample_ms(&mut rng)).filter(|&s| s == 350.0).count();
+        assert!((slow as f64 / n as f64 - 0.1).abs() < 0.005, "slow share {}", slow as f64 / n as f64);
+    }
+    
+    #[test]
+    fn test_latency_model_parses() {
+        assert_eq!("lognormal".parse(), Ok(LatencyModel::MESH_LOGNORMAL));
+        assert_eq!("bimodal".parse(), Ok(LatencyModel::MESH_BIMODAL));
+        assert_eq!("uniform:0,500".parse(), Ok(LatencyModel::Uniform { min_ms: 0.0, max_ms: 500.0 }));
+        assert_eq!("bimodal:10, 200, 0.2".parse(), Ok(LatencyModel::Bimodal { fast_ms: 10.0, slow_ms: 200.0, p_slow: 0.2 }));
+        for model in [LatencyModel::Constant { ms: 12.5 }, LatencyModel::LogNormal { mu: 3.5, sigma: 0.4 }] {
+            assert_eq!(model.to_string().parse(), Ok(model));
+        }
+        for bad in ["constant", "uniform:5,1", "lognormal:4,-1", "bimodal:1,2,1.5", "gamma:1,2", "constant:-3", "constant:nan"] {
+            assert!(bad.parse::<LatencyModel>().is_err(), "{bad}");
+        }
+    }
+    
+    #[test]
+    fn test_sampled_latency_is_per_link_and_recorded() {
+        let draw = |seed| {
+            let controller = SimNetworkController::with_seed(seed);
+            let (a, b) = (NodeId::from_seed(1), NodeId::from_seed(2));
+            controller.set_latency_model(a, b, LatencyModel::MESH_BIMODAL);
+            let delays: Vec<u64> = (0..1000).map(|_| controller.sample_latency(a, b)).collect();
+            
+            assert_eq!(controller.sample_latency(b, a), 0);
+            assert_eq!(controller.link_latency(b, a).count(), 0);
+            let histogram = controller.total_link_latency();
+            assert_eq!(histogram.count(), 1000);
+            assert_eq!((histogram.p50(), histogram.max()), (15, 350));
+            assert_eq!(controller.get_latency(a, b), 49);
+            delays
+        };
+        assert_eq!(draw(3), draw(3));
+        assert_ne!(draw(3), draw(4));
+        
+        let controller = SimNetworkController::with_seed(3);
+        let (a, b) = (NodeId::from_seed(1), NodeId::from_seed(2));
+        controller.set_latency_model(a, b, LatencyModel::Constant { ms: 100.0 });
+        let mut queue = DelayQueue::new(a, b);
+        queue.push_after_latency(5, 0.05, "reading", &controller);
+        assert!(queue.pop_due(6, &controller).is_empty());
+        assert_eq!(queue.pop_due(7, &controller), vec!["reading"]);
+    }
+    
+    #[test]
+    fn test_reorder_and_duplicate_rates() {
+        let controller = SimNetworkController::with_seed(42);
+        let a = NodeId::from_seed(1);
+        let b = NodeId::from_seed(2);
+        controller.set_reorder(a, b, 0.2, 4);
+        controller.set_duplicate(a, b, 0.1);
+        
+        let n = 20_000u64;
+        let mut queue = DelayQueue::new(a, b);
+        let mut delivered = Vec::new();
+        for tick in 0..n {
+            queue.push(tick, tick);
+            delivered.extend(queue.pop_due(tick, &controller));
+        }
+        delivered.extend(queue.drain(&controller));
+        
+        let stats = controller.link_fault_stats(a, b);
+        assert_eq!(stats.delivered, n);
+        assert_eq!(delivered.len() as u64, n + stats.duplicated);
+        
+        let reorder_rate = stats.reordered as f64 / n as f64;
+        let duplicate_rate = stats.duplicated as f64 / n as f64;
+        assert!((reorder_rate - 0.2).abs() < 0.02, "reorder rate {reorder_rate}");
+        assert!((duplicate_rate - 0.1).abs() < 0.02, "duplicate rate {duplicate_rate}");
+        
+        let out_of_order = delivered.windows(2).filter(|w| w[1] < w[0]).count();
+        assert!(out_of_order > 0);
+        
+        // Other links are untouched
+        assert_eq!(controller.link_fault_stats(b, a), LinkFaultStats::default());
+    }
+    
+    #[test]
+    fn test_held_message_released_when_link_goes_quiet() {
+        let controller = SimNetworkController::with_seed(42);
+        let a = NodeId::from_seed(1);
+        let b = NodeId::from_seed(2);
+        controller.set_reorder(a, b, 1.0, 3);
+        
+        let mut queue = DelayQueue::new(a, b);
+        queue.push(0, "last");
+        assert!(queue.pop_due(0, &controller).is_empty());
+        
+        // Nothing else is sent; the held reading still arrives within 3 ticks
+        let arrived = (1..=3).find(|&tick| queue.pop_due(tick, &controller) == vec!["last"]);
+        assert!(arrived.is_some());
+        assert!(queue.is_empty());
+    }
+    
+    #[test]
+    fn test_link_faults_deterministic() {
+        let run = || {
+            let controller = SimNetworkController::with_seed(7);
+            let a = NodeId::from_seed(1);
+            let b = NodeId::from_seed(2);
+            controller.set_reorder(a, b, 0.3, 3);
+            controller.set_duplicate(a, b, 0.2);
+            
+            let mut queue = DelayQueue::new(a, b);
+            (0..500u64).for_each(|i| queue.push(i / 10, i));
+            let mut out = Vec::new();
+            for tick in 0..50 {
+                out.extend(queue.pop_due(tick, &controller));
+            }
+            out.extend(queue.drain(&controller));
+            out
+        };
+        
+        assert_eq!(run(), run());
+    }
+    
+    #[test]
+    fn test_latency_histogram_quantiles() {
+        let mut a = LatencyHistogram::default();
+        assert_eq!((a.p50(), a.p95(), a.max()), (0, 0, 0));
+        for delay_ms in 1..=90 {
+            a.observe(delay_ms);
+        }
+        let mut b = LatencyHistogram::default();
+        (0..10).for_each(|_| b.observe(500));
+        a.merge(&b);
+        
+        assert_eq!(a.count(), 100);
+        assert_eq!(a.p50(), 50);
+        assert_eq!(a.p95(), 500);
+        assert_eq!(a.max(), 500);
+    }
+    
     #[tokio::test]
     async fn test_bandwidth_tracking() {
         let (tx, _rx) = mpsc::channel(10);
@@ -0,0 +1,336 @@
+//! Passive observer that fuses every reading and every gossip packet.
+//!
+//! An [`ObserverAgent`] sits outside the gossip topology and bandwidth limits:
+//! it ingests all oracle readings and every packet any agent gossips, so its
+//! tracks are the best fused picture the swarm could reach. Comparing an agent
+//! against it separates sensor-limited error (the observer's own error against
+//! ground truth) from communication-limited error (the agent's divergence from
+//! the observer).
+
+use crate::agent::SimulatedAgent;
+use crate::oracle::SensorReading;
+use godview_core::godview_tracking::{GlobalHazardPacket, MeasurementModel};
+use godview_core::{AgentConfig, TrackManager, TrackingConfig, UniqueTrack};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// Leading bytes of the observer's reading UUIDs. Larger than any agent's
+/// UUIDs, so the observer never wins Highlander resolution and its canonical
+/// IDs are the ones a fully connected agent would settle on.
+const OBSERVER_ID_PREFIX: u64 = u64::MAX;
+
+/// A reference tracker fed by every reading and every gossip packet.
+pub struct ObserverAgent {
+    tracks: TrackManager,
+    
+    /// Oracle entity ID -> the UUID the observer reports it under
+    entity_ids: HashMap<u64, Uuid>,
+    
+    /// Oracle entity ID -> canonical ID of the track its last reading fused
+    /// into. A Highlander re-key can replace the track holding the
+    /// observer's own UUID, so lookups go through this first.
+    entity_tracks: HashMap<u64, Uuid>,
+    
+    /// Gossip waiting to be fused ahead of the next readings
+    pending_gossip: Vec<GlobalHazardPacket>,
+    
+    readings_ingested: u64,
+    packets_ingested: u64,
+}
+
+impl ObserverAgent {
+    /// Creates an observer tracking with the same settings as agents built
+    /// from `config`, but without a track capacity.
+    pub fn new(config: &AgentConfig) -> Self {
+        let tracking_config = TrackingConfig {
+            max_tracks: None,
+            ..config.tracking_config()
+        };
+        Self {
+            tracks: TrackManager::new(tracking_config),
+            entity_ids: HashMap::new(),
+            entity_tracks: HashMap::new(),
+            pending_gossip: Vec::new(),
+            readings_ingested: 0,
+            packets_ingested: 0,
+        }
+    }
+    
+    /// Predicts and ages tracks by one tick of `dt` seconds.
+    pub fn tick(&mut self, dt: f64) {
+        self.tracks.predict_tracks(dt);
+        self.tracks.age_tracks();
+    }
+    
+    /// Fuses gossip received since the last call, then oracle readings taken
+    /// at `now_secs`.
+    pub fn ingest_readings(&mut self, readings: &[SensorReading], now_secs: f64) {
+        for packet in std::mem::take(&mut self.pending_gossip) {
+            if self.tracks.process_packet(&packet, None, None).is_ok() {
+                self.packets_ingested += 1;
+            }
+        }
+        
+        for reading in readings {
+            let entity_id = *self.entity_ids.entry(reading.entity_id).or_insert_with(|| {
+                Uuid::from_u64_pair(OBSERVER_ID_PREFIX, reading.entity_id)
+            });
+            let packet = GlobalHazardPacket {
+                entity_id,
+                position: [reading.position.x, reading.position.y, reading.position.z],
+                velocity: [reading.velocity.x, reading.velocity.y, reading.velocity.z],
+                class_id: reading.class_id,
+                timestamp: now_secs,
+                confidence_score: 0.95,
+                measurement_model: MeasurementModel::default(),
+                priority: 0,
+            };
+            if let Ok(track_id) = self.tracks.process_packet(&packet, None, None) {
+                self.entity_tracks.insert(reading.entity_id, track_id);
+                self.readings_ingested += 1;
+            }
+        }
+    }
+    
+    /// Queues packets gossiped by any agent.
+    ///
+    /// They are fused at the next [`Self::ingest_readings`], ahead of the
+    /// readings: a gossip round can re-key and replace the observer's
+    /// tracks, and fusing readings last keeps every seen entity mapped to a
+    /// live track.
+    pub fn ingest_gossip(&mut self, packets: &[GlobalHazardPacket]) {
+        self.pending_gossip.extend_from_slice(packets);
+    }
+    
+    /// Returns the observer's track for an oracle entity.
+    pub fn track_for_entity(&self, entity_id: u64) -> Option<&UniqueTrack> {
+        let own = self.entity_ids.get(&entity_id)?;
+        self.entity_tracks.get(&entity_id)
+            .and_then(|id| self.tracks.get_track(id))
+            .or_else(|| self.tracks.get_track(own))
+            .or_else(|| self.tracks.tracks().find(|t| t.observed_ids.contains(own)))
+    }
+    
+    /// Returns the number of reference tracks.
+    pub fn track_count(&self) -> usize {
+        self.tracks.track_count()
+    }
+    
+    /// Returns (readings, gossip packets) fused so far.
+    pub fn ingested(&self) -> (u64, u64) {
+        (self.readings_ingested, self.packets_ingested)
+    }
+    
+    /// RMS position error of the reference tracks against ground truth.
+    pub fn position_error(&self, ground_truth: &[(u64, Vector3<f64>)]) -> f64 {
+        let (sum, count) = ground_truth.iter()
+            .filter_map(|(id, truth)| self.track_for_entity(*id).map(|t| (t.position() - truth).norm_squared()))
+            .fold((0.0, 0usize), |(sum, count), e| (sum + e, count + 1));
+        if count > 0 {
+            (sum / count as f64).sqrt()
+        } else {
+            0.0
+        }
+    }
+    
+    /// Compares an agent's tracks for `entity_ids` against the reference.
+    ///
+    /// An agent's track for an entity is the one holding its own UUID for
+    /// it, or failing that the one with the smallest canonical ID among
+    /// those sharing an ID with the reference track. Entities either side
+    /// has no track for are skipped.
+    fn compare(&self, agent: &SimulatedAgent, entity_ids: &[u64]) -> Comparison {
+        let mut by_id: HashMap<Uuid, &UniqueTrack> = HashMap::new();
+        for track in agent.inner().track_manager.tracks() {
+            for id in &track.observed_ids {
+                by_id.insert(*id, track);
+            }
+        }
+        
+        let mut comparison = Comparison::default();
+        for &entity_id in entity_ids {
+            let Some(reference) = self.track_for_entity(entity_id) else {
+                continue;
+            };
+            let own = agent.local_entity_uuid(entity_id).and_then(|id| by_id.get(&id).copied());
+            let Some(track) = own.or_else(|| {
+                reference.observed_ids.iter()
+                    .filter_map(|id| by_id.get(id).copied())
+                    .min_by_key(|t| t.canonical_id)
+            }) else {
+                continue;
+            };
+            
+            comparison.compared += 1;
+            comparison.squared_error += (track.position() - reference.position()).norm_squared();
+            if track.canonical_id != reference.canonical_id {
+                comparison.id_disagreements += 1;
+            }
+        }
+        comparison
+    }
+}
+
+/// Entity-level comparison between one agent and the observer.
+#[derive(Debug, Clone, Copy, Default)]
+struct Comparison {
+    compared: u64,
+    squared_error: f64,
+    id_disagreements: u64,
+}
+
+/// How far one agent's tracks sat from the observer's over a run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct AgentDivergence {
+    /// Agent index
+    pub agent_id: u64,
+    
+    /// Entity comparisons made, summed over samples
+    pub compared: u64,
+    
+    /// RMS distance between the agent's and the observer's track positions (m)
+    pub position_rms: f64,
+    
+    /// Fraction of comparisons where the agent's canonical ID differed
+    pub id_disagreement_rate: f64,
+}
+
+/// Accumulates agent divergence and observer error once warm-up has elapsed.
+pub struct DivergenceSampler {
+    warmup_secs: f64,
+    per_agent: BTreeMap<u64, Comparison>,
+    observer_rms: Vec<f64>,
+}
+
+impl DivergenceSampler {
+    /// Creates a sampler that ignores samples before `warmup_secs`.
+    pub fn new(warmup_secs: f64) -> Self {
+        Self {
+            warmup_secs,
+            per_agent: BTreeMap::new(),
+            observer_rms: Vec::new(),
+        }
+    }
+    
+    /// Compares `agents` against the observer on every ground-truth entity.
+    pub fn sample<'a>(
+        &mut self,
+        time_secs: f64,
+        observer: &ObserverAgent,
+        agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+        ground_truth: &[(u64, Vector3<f64>)],
+    ) {
+        if time_secs < self.warmup_secs {
+            return;
+        }
+        
+        let entity_ids: Vec<u64> = ground_truth.iter().map(|(id, _)| *id).collect();
+        for agent in agents {
+            let comparison = observer.compare(agent, &entity_ids);
+            let total = self.per_agent.entry(agent.agent_index()).or_default();
+            total.compared += comparison.compared;
+            total.squared_error += comparison.squared_error;
+            total.id_disagreements += comparison.id_disagreements;
+        }
+        self.observer_rms.push(observer.position_error(ground_truth));
+    }
+    
+    /// Per-agent divergence, in agent index order.
+    pub fn divergence(&self) -> Vec<AgentDivergence> {
+        self.per_agent.iter()
+            .map(|(&agent_id, c)| {
+                let n = c.compared.max(1) as f64;
+                AgentDivergence {
+                    agent_id,
+                    compared: c.compared,
+                    position_rms: (c.squared_error / n).sqrt(),
+                    id_disagreement_rate: c.id_disagreements as f64 / n,
+                }
+            })
+            .collect()
+    }
+    
+    /// Mean observer RMS error against ground truth (0.0 if no samples).
+    pub fn observer_rms(&self) -> f64 {
+        if self.observer_rms.is_empty() {
+            return 0.0;
+        }
+        self.observer_rms.iter().sum::<f64>() / self.observer_rms.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::SimContext;
+    use crate::keys::DeterministicKeyProvider;
+    use crate::network::SimNetwork;
+    use godview_env::NodeId;
+    use std::sync::Arc;
+    
+    fn agent(index: u64) -> SimulatedAgent {
+        let key_provider = DeterministicKeyProvider::new(42);
+        SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(index))),
+            key_provider.biscuit_root_key().public(),
+            index,
+            AgentConfig { h3_resolution: 0, ..SimulatedAgent::default_config() },
+        )
+    }
+    
+    fn reading(entity_id: u64, x: f64) -> SensorReading {
+        SensorReading::new(entity_id, Vector3::new(x, 0.0, 100.0), Vector3::zeros())
+    }
+    
+    #[test]
+    fn test_agent_without_gossip_diverges_on_ids_only() {
+        let config = AgentConfig { h3_resolution: 0, ..SimulatedAgent::default_config() };
+        let mut observer = ObserverAgent::new(&config);
+        let mut a = agent(1);
+        let mut b = agent(2);
+        
+        let readings = [reading(7, 10.0)];
+        a.ingest_readings(&readings);
+        b.ingest_readings(&readings);
+        observer.ingest_readings(&readings, 0.0);
+        observer.ingest_gossip(a.recent_packets());
+        observer.ingest_gossip(b.recent_packets());
+        observer.ingest_readings(&readings, 0.0);
+        
+        // The observer settles on the smallest agent UUID, not its own
+        let reference = observer.track_for_entity(7).unwrap();
+        assert_eq!(Some(reference.canonical_id), a.local_entity_uuid(7));
+        
+        let truth = [(7, Vector3::new(10.0, 0.0, 100.0))];
+        let mut sampler = DivergenceSampler::new(0.0);
+        sampler.sample(0.0, &observer, [&a, &b], &truth);
+        
+        let divergence = sampler.divergence();
+        assert_eq!(divergence.len(), 2);
+        assert_eq!(divergence[0].id_disagreement_rate, 0.0);
+        assert_eq!(divergence[1].id_disagreement_rate, 1.0);
+        assert!(divergence.iter().all(|d| d.compared == 1 && d.position_rms < 1e-6));
+        assert!(sampler.observer_rms() < 1e-6);
+    }
+    
+    #[test]
+    fn test_warmup_and_unseen_entities_are_skipped() {
+        let mut observer = ObserverAgent::new(&AgentConfig { h3_resolution: 0, ..SimulatedAgent::default_config() });
+        let mut a = agent(1);
+        observer.ingest_readings(&[reading(1, 0.0), reading(2, 50.0)], 0.0);
+        a.ingest_readings(&[reading(1, 3.0)]);
+        
+        let truth = [(1, Vector3::new(0.0, 0.0, 100.0)), (2, Vector3::new(50.0, 0.0, 100.0))];
+        let mut sampler = DivergenceSampler::new(1.0);
+        sampler.sample(0.5, &observer, [&a], &truth);
+        assert!(sampler.divergence().is_empty());
+        
+        sampler.sample(1.0, &observer, [&a], &truth);
+        let divergence = sampler.divergence()[0];
+        assert_eq!(divergence.compared, 1);
+        assert!((divergence.position_rms - 3.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,195 @@
+//! Line-of-sight between sensors and entities.
+//!
+//! The [`Oracle`] can hold static [`Obstacle`] boxes and give entities a
+//! radius. A sensor sees an entity when the segment between them clears
+//! every obstacle and every other entity's sphere; occluded entities produce
+//! no reading ([`Oracle::generate_readings_for`]). [`OcclusionCoverage`]
+//! scores how much of what each agent can't see gossip filled in.
+
+use crate::agent::SimulatedAgent;
+use crate::faults::COVERAGE_RADIUS_M;
+use crate::oracle::Oracle;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// A static axis-aligned box (wall, building) that blocks line of sight.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Obstacle {
+    /// Center of the box in the oracle frame (m)
+    pub center: Vector3<f64>,
+    
+    /// Half the box's size along each axis (m)
+    pub half_extents: Vector3<f64>,
+}
+
+impl Obstacle {
+    /// Creates a box around `center`; negative extents are taken as positive.
+    pub fn new(center: Vector3<f64>, half_extents: Vector3<f64>) -> Self {
+        Self { center, half_extents: half_extents.abs() }
+    }
+    
+    /// Returns true if the box contains `point`.
+    pub fn contains(&self, point: &Vector3<f64>) -> bool {
+        (point - self.center).abs().iter().zip(self.half_extents.iter()).all(|(d, h)| d <= h)
+    }
+    
+    /// Returns true if the segment `from`-`to` passes through the box
+    /// (slab test).
+    pub fn blocks(&self, from: &Vector3<f64>, to: &Vector3<f64>) -> bool {
+        let direction = to - from;
+        let (mut enter, mut exit) = (0.0_f64, 1.0_f64);
+        for axis in 0..3 {
+            let low = self.center[axis] - self.half_extents[axis];
+            let high = self.center[axis] + self.half_extents[axis];
+            if direction[axis].abs() < 1e-12 {
+                if from[axis] < low || from[axis] > high {
+                    return false;
+                }
+                continue;
+            }
+            let t1 = (low - from[axis]) / direction[axis];
+            let t2 = (high - from[axis]) / direction[axis];
+            enter = enter.max(t1.min(t2));
+            exit = exit.min(t1.max(t2));
+            if enter > exit {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Returns true if a sphere of `radius` at `center` crosses the segment
+/// `from`-`to` strictly between its ends.
+pub fn sphere_blocks(center: &Vector3<f64>, radius: f64, from: &Vector3<f64>, to: &Vector3<f64>) -> bool {
+    let direction = to - from;
+    let length_sq = direction.norm_squared();
+    if radius <= 0.0 || length_sq < 1e-12 {
+        return false;
+    }
+    let t = (center - from).dot(&direction) / length_sq;
+    if t <= 0.0 || t >= 1.0 {
+        return false;
+    }
+    (from + direction * t - center).norm() < radius
+}
+
+/// How well a swarm covered entities some of its agents couldn't see.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct OcclusionCoverage {
+    /// Mean fraction of entities each agent has line of sight to
+    pub direct_coverage: f64,
+    
+    /// Mean fraction of entities each agent tracks within
+    /// [`COVERAGE_RADIUS_M`], directly or through gossip
+    pub fused_coverage: f64,
+    
+    /// Mean fraction of each agent's occluded entities it still tracks
+    pub hidden_coverage: f64,
+    
+    /// Lowest hidden coverage of any agent with an occluded entity
+    pub min_hidden_coverage: f64,
+    
+    /// Agents with at least one occluded entity
+    pub occluded_agents: usize,
+}
+
+impl OcclusionCoverage {
+    /// Scores `agents` against the oracle's current entities, agent `i`
+    /// sensing from `sensor_positions[i]`.
+    pub fn measure<'a>(
+        agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+        oracle: &Oracle,
+        sensor_positions: &[Vector3<f64>],
+    ) -> Self {
+        let entities = oracle.active_entities();
+        let mut coverage = OcclusionCoverage { min_hidden_coverage: 1.0, ..Self::default() };
+        if entities.is_empty() {
+            return coverage;
+        }
+        
+        let mut counted = 0;
+        let mut hidden_sum = 0.0;
+        for (agent, sensor) in agents.into_iter().zip(sensor_positions) {
+            let tracks: Vec<Vector3<f64>> = agent.track_positions().into_iter().map(|(_, p)| p).collect();
+            let tracked = |position: &Vector3<f64>| tracks.iter().any(|p| (p - position).norm() <= COVERAGE_RADIUS_M);
+            
+            let (mut seen, mut covered, mut hidden, mut hidden_covered) = (0, 0, 0, 0);
+            for entity in &entities {
+                let is_tracked = tracked(&entity.position);
+                covered += is_tracked as usize;
+                if oracle.line_of_sight(sensor, entity.id) {
+                    seen += 1;
+                } else {
+                    hidden += 1;
+                    hidden_covered += is_tracked as usize;
+                }
+            }
+            
+            counted += 1;
+            coverage.direct_coverage += seen as f64 / entities.len() as f64;
+            coverage.fused_coverage += covered as f64 / entities.len() as f64;
+            if hidden > 0 {
+                let fraction = hidden_covered as f64 / hidden as f64;
+                hidden_sum += fraction;
+                coverage.min_hidden_coverage = coverage.min_hidden_coverage.min(fraction);
+                coverage.occluded_agents += 1;
+            }
+        }
+        
+        if counted > 0 {
+            coverage.direct_coverage /= counted as f64;
+            coverage.fused_coverage /= counted as f64;
+        }
+        coverage.hidden_coverage = if coverage.occluded_agents > 0 {
+            hidden_sum / coverage.occluded_agents as f64
+        } else {
+            1.0
+        };
+        coverage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_segment_tests() {
+        let wall = Obstacle::new(Vector3::new(0.0, 0.0, 10.0), Vector3::new(1.0, 50.0, 10.0));
+        let west = Vector3::new(-20.0, 0.0, 5.0);
+        assert!(wall.blocks(&west, &Vector3::new(20.0, 3.0, 5.0)));
+        assert!(!wall.blocks(&west, &Vector3::new(-5.0, 30.0, 5.0)), "same side");
+        assert!(!wall.blocks(&west, &Vector3::new(20.0, 0.0, 40.0)), "clears the top");
+        assert!(!wall.blocks(&Vector3::new(-20.0, 0.0, 30.0), &Vector3::new(20.0, 0.0, 30.0)), "over the top");
+        assert!(wall.contains(&Vector3::new(0.5, -49.0, 1.0)));
+        
+        let from = Vector3::zeros();
+        let to = Vector3::new(10.0, 0.0, 0.0);
+        assert!(sphere_blocks(&Vector3::new(5.0, 0.5, 0.0), 1.0, &from, &to));
+        assert!(!sphere_blocks(&Vector3::new(5.0, 2.0, 0.0), 1.0, &from, &to));
+        assert!(!sphere_blocks(&Vector3::new(12.0, 0.0, 0.0), 5.0, &from, &to), "behind the target");
+        assert!(!sphere_blocks(&Vector3::new(5.0, 0.0, 0.0), 0.0, &from, &to), "zero radius never blocks");
+    }
+    
+    #[test]
+    fn test_occluded_entities_produce_no_readings() {
+        let mut oracle = Oracle::new(42);
+        let visible = oracle.spawn_entity(Vector3::new(-10.0, 0.0, 5.0), Vector3::zeros(), "near");
+        let behind_wall = oracle.spawn_entity(Vector3::new(10.0, 0.0, 5.0), Vector3::zeros(), "far");
+        let sensor = Vector3::new(-20.0, 0.0, 5.0);
+        assert_eq!(oracle.generate_readings_for(0, 0, &sensor).len(), 2);
+        
+        oracle.add_obstacle(Vector3::new(0.0, 0.0, 10.0), Vector3::new(1.0, 50.0, 10.0));
+        let ids: Vec<u64> = oracle.generate_readings_for(0, 0, &sensor).iter().map(|r| r.entity_id).collect();
+        assert_eq!(ids, vec![visible]);
+        assert!(!oracle.line_of_sight(&sensor, behind_wall));
+        
+        // Entities shadow each other only once they have a radius
+        let shadowed = oracle.spawn_entity(Vector3::new(-5.0, 0.0, 5.0), Vector3::zeros(), "shadowed");
+        assert!(oracle.line_of_sight(&sensor, shadowed));
+        oracle.set_target_radius(1.0);
+        assert!(!oracle.line_of_sight(&sensor, shadowed));
+        assert!(oracle.line_of_sight(&sensor, visible));
+    }
+}
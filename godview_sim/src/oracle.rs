@@ -2,16 +2,24 @@
 //!
 //! The Oracle maintains the "God's eye view" of the simulated world:
 //! - True positions of all entities
-//! - Physics simulation (kinematics)
-//! - Sensor reading generation (with noise)
+//! - Physics simulation (kinematics, optional gravity, a ground plane)
+//! - Sensor reading generation (with noise and per-sensor bias)
+//! - Missed detections and clutter, per sensor
+//! - Misclassification, per sensor
+//! - What each sensor measures: position and velocity, position only,
+//!   bearings or range and range rate
+//! - Line of sight, past static obstacles and other entities
+//! - Per-agent fields of view, turning as the simulation steps
 
+use crate::frustum::{ScanningSensor, SensorPose};
+use crate::occlusion::{sphere_blocks, Obstacle};
+use crate::rng_audit::AuditedRng;
+use godview_core::{Frame, MeasurementModel};
 use nalgebra::{Vector3, Vector6};
-use rand::SeedableRng;
 use rand::Rng;
-use rand_chacha::ChaCha8Rng;
-use rand_distr::{Distribution, Normal, Cauchy};
+use rand_distr::{Distribution, Normal, Cauchy, Poisson};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Noise model for sensor readings (v0.6.0)
 /// Agents evolved on Gaussian may fail on heavy-tailed distributions.
@@ -26,13 +34,75 @@ pub enum NoiseModel {
     Levy,
 }
 
+/// Wire class IDs (see `GlobalHazardPacket::class_id`).
+pub const VEHICLE_CLASS_ID: u8 = 1;
+pub const PEDESTRIAN_CLASS_ID: u8 = 2;
+pub const CYCLIST_CLASS_ID: u8 = 3;
+pub const DRONE_CLASS_ID: u8 = 4;
+
+/// Wire class ID of an entity class name. Names without one of their own
+/// (scenario-specific targets) are drones, as agents have always reported
+/// them.
+pub fn class_id_of(class: &str) -> u8 {
+    match class {
+        "vehicle" => VEHICLE_CLASS_ID,
+        "pedestrian" => PEDESTRIAN_CLASS_ID,
+        "cyclist" => CYCLIST_CLASS_ID,
+        _ => DRONE_CLASS_ID,
+    }
+}
+
+/// What happens to an entity that reaches the ground plane (see
+/// [`Oracle::set_ground_plane`]).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GroundContact {
+    /// Held on the ground with its vertical velocity zeroed, still moving
+    /// horizontally (vehicles and anything else that drives)
+    Clamp,
+    
+    /// Thrown back up with `restitution` of its vertical speed; it stops
+    /// once the rebound is slower than [`MIN_BOUNCE_SPEED`]
+    Bounce { restitution: f64 },
+    
+    /// Stopped where it touched down (a drone landing)
+    Stop,
+    
+    /// Removed from the simulation (an impact)
+    Despawn,
+}
+
+/// Slowest rebound (m/s) a bouncing entity keeps; slower ones stop.
+pub const MIN_BOUNCE_SPEED: f64 = 0.5;
+
+/// Standard gravity (m/s²), for [`Oracle::set_gravity`].
+pub const STANDARD_GRAVITY: f64 = 9.80665;
+
+impl GroundContact {
+    /// Ground contact of an entity class: ground classes are clamped,
+    /// ballistic ones (see [`is_ballistic_class`]) despawn on impact and
+    /// everything else, drones included, stops.
+    pub fn for_class(class: &str) -> Self {
+        match class {
+            "vehicle" | "pedestrian" | "cyclist" => GroundContact::Clamp,
+            _ if is_ballistic_class(class) => GroundContact::Despawn,
+            _ => GroundContact::Stop,
+        }
+    }
+}
+
+/// True for classes that fall under gravity (see [`Oracle::set_gravity`]):
+/// "ballistic", "projectile" and "debris".
+pub fn is_ballistic_class(class: &str) -> bool {
+    matches!(class, "ballistic" | "projectile" | "debris")
+}
+
 /// A ground truth entity in the simulation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroundTruthEntity {
     /// Unique entity ID
     pub id: u64,
     
-    /// Position [x, y, z] in meters (global frame)
+    /// Position [east, north, up] in meters (see [`Oracle::frame`])
     pub position: Vector3<f64>,
     
     /// Velocity [vx, vy, vz] in m/s
@@ -41,8 +111,24 @@ pub struct GroundTruthEntity {
     /// Entity class (e.g., "drone", "vehicle", "pedestrian")
     pub class: String,
     
+    /// Wire class ID of `class` (see [`class_id_of`])
+    pub class_id: u8,
+    
     /// Entity is active (not destroyed/removed)
     pub active: bool,
+    
+    /// What happens when it reaches the ground plane (defaults by class,
+    /// see [`GroundContact::for_class`])
+    pub ground_contact: GroundContact,
+    
+    /// Falls under the Oracle's gravity (defaults by class, see
+    /// [`is_ballistic_class`])
+    pub ballistic: bool,
+    
+    /// Threat level its readings carry (see
+    /// [`GlobalHazardPacket::priority`](godview_core::GlobalHazardPacket::priority))
+    #[serde(default)]
+    pub priority: u8,
 }
 
 impl GroundTruthEntity {
@@ -53,7 +139,11 @@ impl GroundTruthEntity {
             position,
             velocity: Vector3::zeros(),
             class: class.to_string(),
+            class_id: class_id_of(class),
             active: true,
+            ground_contact: GroundContact::for_class(class),
+            ballistic: is_ballistic_class(class),
+            priority: 0,
         }
     }
     
@@ -69,7 +159,11 @@ impl GroundTruthEntity {
             position,
             velocity,
             class: class.to_string(),
+            class_id: class_id_of(class),
             active: true,
+            ground_contact: GroundContact::for_class(class),
+            ballistic: is_ballistic_class(class),
+            priority: 0,
         }
     }
     
@@ -82,26 +176,260 @@ impl GroundTruthEntity {
     }
 }
 
+/// Sensor used when a scenario doesn't distinguish between sensors.
+pub const DEFAULT_SENSOR_ID: u32 = 0;
+
+/// Entity ID of the first clutter reading; later ones count up from it, so
+/// clutter never shares an ID with a spawned entity.
+pub const CLUTTER_ENTITY_ID_BASE: u64 = 1 << 48;
+
+/// How imperfectly a sensor detects: entities it misses and clutter it
+/// reports where there is nothing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectionModel {
+    /// Probability each entity is detected on a given call (Pd)
+    pub detection_probability: f64,
+    
+    /// Mean clutter readings per call (Poisson rate)
+    pub clutter_rate: f64,
+    
+    /// Opposite corners of the box clutter is drawn uniformly within
+    pub footprint: (Vector3<f64>, Vector3<f64>),
+}
+
+impl DetectionModel {
+    /// Detects everything and reports no clutter.
+    pub fn perfect() -> Self {
+        Self { detection_probability: 1.0, clutter_rate: 0.0, footprint: (Vector3::zeros(), Vector3::zeros()) }
+    }
+}
+
+/// What a sensor measures. Each reading carries the matching
+/// [`MeasurementModel`], with the sensor's position as the origin of
+/// bearings and ranges (the world origin for an unplaced sensor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SensorModality {
+    /// Position and velocity
+    #[default]
+    PositionVelocity,
+    
+    /// Position only
+    Position,
+    
+    /// Azimuth and elevation
+    Bearing,
+    
+    /// Range and range rate
+    RangeDoppler,
+}
+
+impl SensorModality {
+    /// Rewrites a reading of position and velocity into what this modality
+    /// measures, laid out as its [`MeasurementModel`] describes. Bearings
+    /// and ranges come from the noisy position, so they share its noise.
+    pub fn reduce(self, reading: &mut SensorReading) {
+        let origin = reading.origin.unwrap_or_else(Vector3::zeros);
+        let offset = reading.position - origin;
+        let origin = [origin.x, origin.y, origin.z];
+        match self {
+            SensorModality::PositionVelocity => {}
+            SensorModality::Position => {
+                reading.velocity = Vector3::zeros();
+                reading.measurement_model = MeasurementModel::Position3D;
+            }
+            SensorModality::Bearing => {
+                let azimuth = offset.y.atan2(offset.x);
+                let elevation = offset.z.atan2(offset.x.hypot(offset.y));
+                reading.position = Vector3::new(azimuth, elevation, 0.0);
+                reading.velocity = Vector3::zeros();
+                reading.measurement_model = MeasurementModel::BearingOnly { origin };
+            }
+            SensorModality::RangeDoppler => {
+                let range = offset.norm();
+                let rate = if range > 0.0 { offset.dot(&reading.velocity) / range } else { 0.0 };
+                reading.position = Vector3::new(range, 0.0, 0.0);
+                reading.velocity = Vector3::new(rate, 0.0, 0.0);
+                reading.measurement_model = MeasurementModel::RangeDoppler { origin };
+            }
+        }
+    }
+}
+
+/// How a sensor misclassifies: for each true class, the classes it
+/// reports instead and how often.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClassConfusion {
+    /// (reported class, probability) of each wrong report, by true class;
+    /// the remaining probability is a correct report
+    pub errors: BTreeMap<u8, Vec<(u8, f64)>>,
+}
+
+impl ClassConfusion {
+    /// Classifies everything correctly.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Reports `true_class` as `reported` with `probability`.
+    pub fn with_error(mut self, true_class: u8, reported: u8, probability: f64) -> Self {
+        self.errors.entry(true_class).or_default().push((reported, probability));
+        self
+    }
+    
+    /// Mistakes `a` for `b` and `b` for `a`, each with `probability`.
+    pub fn swapping(self, a: u8, b: u8, probability: f64) -> Self {
+        self.with_error(a, b, probability).with_error(b, a, probability)
+    }
+    
+    /// The class reported for `true_class` given a uniform draw `u` in
+    /// [0, 1).
+    fn report(&self, true_class: u8, u: f64) -> u8 {
+        let mut cumulative = 0.0;
+        for &(reported, probability) in self.errors.get(&true_class).into_iter().flatten() {
+            cumulative += probability;
+            if u < cumulative {
+                return reported;
+            }
+        }
+        true_class
+    }
+    
+    /// True if `true_class` is ever misreported.
+    fn confuses(&self, true_class: u8) -> bool {
+        self.errors.get(&true_class).is_some_and(|errors| errors.iter().any(|&(_, p)| p > 0.0))
+    }
+}
+
 /// A sensor reading generated from ground truth with noise.
 #[derive(Debug, Clone)]
 pub struct SensorReading {
     /// Entity ID this reading corresponds to
     pub entity_id: u64,
     
-    /// Noisy position measurement
+    /// Noisy position measurement, or what `measurement_model` carries in
+    /// its place
     pub position: Vector3<f64>,
     
-    /// Velocity (typically from derivative or sensor)
+    /// Velocity (typically from derivative or sensor), or what
+    /// `measurement_model` carries in its place
     pub velocity: Vector3<f64>,
+    
+    /// Sensor that produced the reading
+    pub sensor_id: u32,
+    
+    /// Class the sensor reported, which a confused sensor sometimes gets
+    /// wrong (see [`Oracle::set_classification_confusion`])
+    pub class_id: u8,
+    
+    /// Systematic offset the Oracle added to `position` (ground truth for
+    /// scoring; agents must estimate it themselves)
+    pub bias: Option<Vector3<f64>>,
+    
+    /// The reading is clutter with no entity behind it (ground truth for
+    /// scoring; agents never look at it)
+    pub is_clutter: bool,
+    
+    /// True position of the sensor that took the reading, for sensors
+    /// placed in the world (None: an unplaced sensor, whose readings are
+    /// absolute fixes)
+    pub origin: Option<Vector3<f64>>,
+    
+    /// What the reading measures (see [`Oracle::set_sensor_modality`])
+    pub measurement_model: MeasurementModel,
+    
+    /// Priority of the entity read (0 for clutter)
+    pub priority: u8,
+}
+
+impl SensorReading {
+    /// Creates an unbiased reading from [`DEFAULT_SENSOR_ID`].
+    pub fn new(entity_id: u64, position: Vector3<f64>, velocity: Vector3<f64>) -> Self {
+        Self {
+            entity_id,
+            position,
+            velocity,
+            sensor_id: DEFAULT_SENSOR_ID,
+            class_id: DRONE_CLASS_ID,
+            bias: None,
+            is_clutter: false,
+            origin: None,
+            measurement_model: MeasurementModel::PositionVelocity6D,
+            priority: 0,
+        }
+    }
+}
+
+/// Whose readings a measurement noise draw is for: each has a stream of
+/// its own, so one agent's readings don't shift another's noise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum NoiseReader {
+    /// Readings every agent shares (`oracle/noise`)
+    Shared,
+    
+    /// A sensor's readings (`oracle/noise/sensor-{id}`)
+    Sensor(u32),
+    
+    /// An agent's own readings (`oracle/noise/agent-{id}`)
+    Agent(usize),
+}
+
+impl NoiseReader {
+    fn label(self) -> String {
+        match self {
+            NoiseReader::Shared => "oracle/noise".to_string(),
+            NoiseReader::Sensor(id) => format!("oracle/noise/sensor-{}", id),
+            NoiseReader::Agent(id) => format!("oracle/noise/agent-{}", id),
+        }
+    }
+}
+
+/// The Oracle's RNG streams, each derived from the physics seed by name
+/// ([`AuditedRng::derive`]), so that e.g. turning clutter on doesn't shift
+/// the measurement noise.
+#[derive(Debug, Clone)]
+struct OracleStreams {
+    /// Seed the noise streams are derived from
+    physics_seed: u64,
+    
+    /// Measurement noise of each reader, derived on its first reading
+    noise: BTreeMap<NoiseReader, AuditedRng>,
+    
+    /// Missed detections (`oracle/detection`)
+    detection: AuditedRng,
+    
+    /// Clutter counts and positions (`oracle/clutter`)
+    clutter: AuditedRng,
+    
+    /// Misreported classes (`oracle/classification`)
+    classification: AuditedRng,
+}
+
+impl OracleStreams {
+    fn new(physics_seed: u64) -> Self {
+        Self {
+            physics_seed,
+            noise: BTreeMap::new(),
+            detection: AuditedRng::derive(physics_seed, "oracle/detection"),
+            clutter: AuditedRng::derive(physics_seed, "oracle/clutter"),
+            classification: AuditedRng::derive(physics_seed, "oracle/classification"),
+        }
+    }
+    
+    /// The measurement noise stream of `reader`.
+    fn noise(&mut self, reader: NoiseReader) -> &mut AuditedRng {
+        let seed = self.physics_seed;
+        self.noise.entry(reader).or_insert_with(|| AuditedRng::derive(seed, &reader.label()))
+    }
 }
 
 /// The Oracle - maintains ground truth and generates sensor readings.
 pub struct Oracle {
-    /// RNG for physics (noise, random events)
-    physics_rng: ChaCha8Rng,
+    /// RNG streams for physics (noise, random events)
+    rngs: OracleStreams,
     
-    /// All ground truth entities
-    entities: HashMap<u64, GroundTruthEntity>,
+    /// All ground truth entities, ordered by ID so readings and noise draws
+    /// come out in the same order every run
+    entities: BTreeMap<u64, GroundTruthEntity>,
     
     /// Next entity ID
     next_id: u64,
@@ -109,37 +437,250 @@ pub struct Oracle {
     /// Current simulation time (seconds)
     current_time: f64,
     
-    /// Position noise standard deviation (meters)
+    /// Horizontal (x, y) position noise standard deviation (meters)
     position_noise_std: f64,
     
+    /// Vertical (z) position noise standard deviation (meters)
+    vertical_noise_std: f64,
+    
     /// Noise model (v0.6.0): Gaussian, Cauchy, or Levy
     noise_model: NoiseModel,
+    
+    /// Per-axis position bias for each sensor that has one
+    sensor_biases: BTreeMap<u32, Vector3<f64>>,
+    
+    /// Static boxes that block line of sight
+    obstacles: Vec<Obstacle>,
+    
+    /// Radius entities block line of sight with (0 = they don't)
+    target_radius: f64,
+    
+    /// Missed detections and clutter for each sensor that has them
+    detection_models: BTreeMap<u32, DetectionModel>,
+    
+    /// Entity ID of the next clutter reading
+    next_clutter_id: u64,
+    
+    /// Misclassification for each sensor that has it
+    class_confusions: BTreeMap<u32, ClassConfusion>,
+    
+    /// What each sensor that doesn't measure position and velocity measures
+    modalities: BTreeMap<u32, SensorModality>,
+    
+    /// Pose and field of view of each agent that has a scanning sensor
+    scanners: BTreeMap<usize, ScanningSensor>,
+    
+    /// Farthest (m) a placed sensor reads entities from (None: no limit)
+    sensor_range: Option<f64>,
+    
+    /// Height (m) of the ground plane entities can't sink below (None: no
+    /// ground)
+    ground_z: Option<f64>,
+    
+    /// Downward acceleration (m/s²) of ballistic entities (0: none)
+    gravity: f64,
 }
 
+/// WGS84 location (degrees) the simulated world's ENU origin sits at.
+pub const ORIGIN_LAT_LON: (f64, f64) = (37.7749, -122.4194);
+
 impl Oracle {
+    /// Frame of entity positions: local ENU meters around [`ORIGIN_LAT_LON`].
+    pub fn frame() -> Frame {
+        Frame::local_enu(ORIGIN_LAT_LON.0, ORIGIN_LAT_LON.1).expect("origin is a valid location")
+    }
+    
     /// Creates a new Oracle with the given physics seed.
     ///
     /// Note: The physics seed should be derived separately from the network seed
     /// so that changing network topology doesn't affect entity trajectories.
     pub fn new(physics_seed: u64) -> Self {
         Self {
-            physics_rng: ChaCha8Rng::seed_from_u64(physics_seed),
-            entities: HashMap::new(),
+            rngs: OracleStreams::new(physics_seed),
+            entities: BTreeMap::new(),
             next_id: 0,
             current_time: 0.0,
             position_noise_std: 0.5, // 50cm noise by default
+            vertical_noise_std: 0.5,
             noise_model: NoiseModel::Gaussian,
+            sensor_biases: BTreeMap::new(),
+            obstacles: Vec::new(),
+            target_radius: 0.0,
+            detection_models: BTreeMap::new(),
+            next_clutter_id: CLUTTER_ENTITY_ID_BASE,
+            class_confusions: BTreeMap::new(),
+            modalities: BTreeMap::new(),
+            scanners: BTreeMap::new(),
+            sensor_range: None,
+            ground_z: Some(0.0),
+            gravity: 0.0,
         }
     }
     
+    /// Starts the world over for another episode: removes every entity,
+    /// rewinds time to 0 and reseeds the physics RNG streams from
+    /// `physics_seed`. Sensors, noise, obstacles and the rest of the
+    /// configuration are kept, so a reset Oracle behaves as a freshly
+    /// configured one.
+    pub fn reset(&mut self, physics_seed: u64) {
+        self.rngs = OracleStreams::new(physics_seed);
+        self.entities.clear();
+        self.next_id = 0;
+        self.current_time = 0.0;
+        self.next_clutter_id = CLUTTER_ENTITY_ID_BASE;
+    }
+    
     /// Sets the noise model (v0.6.0).
     pub fn set_noise_model(&mut self, model: NoiseModel) {
         self.noise_model = model;
     }
     
-    /// Sets the position noise standard deviation.
+    /// Sets the position noise standard deviation on every axis.
     pub fn set_position_noise(&mut self, std_dev: f64) {
-        self.position_noise_std = std_dev;
+        self.set_position_noise_hv(std_dev, std_dev);
+    }
+    
+    /// Sets the position noise standard deviation separately for the
+    /// horizontal axes (x, y) and the vertical axis (z), e.g. for GNSS, whose
+    /// altitude is typically 2-3x worse than its horizontal fix.
+    pub fn set_position_noise_hv(&mut self, horizontal_std: f64, vertical_std: f64) {
+        self.position_noise_std = horizontal_std;
+        self.vertical_noise_std = vertical_std;
+    }
+    
+    /// Horizontal and vertical position noise standard deviations.
+    pub fn position_noise_hv(&self) -> (f64, f64) {
+        (self.position_noise_std, self.vertical_noise_std)
+    }
+    
+    /// Sets the per-axis position bias added to readings from `sensor_id`.
+    pub fn set_sensor_bias(&mut self, sensor_id: u32, bias: Vector3<f64>) {
+        self.sensor_biases.insert(sensor_id, bias);
+    }
+    
+    /// Returns the bias configured for `sensor_id`, if any.
+    pub fn sensor_bias(&self, sensor_id: u32) -> Option<Vector3<f64>> {
+        self.sensor_biases.get(&sensor_id).copied()
+    }
+    
+    /// Makes `sensor_id` miss entities and report clutter per `model`.
+    /// Sensors without a model detect every entity and report no clutter,
+    /// drawing nothing extra from the physics RNG.
+    pub fn set_detection_model(&mut self, sensor_id: u32, model: DetectionModel) {
+        self.detection_models.insert(sensor_id, model);
+    }
+    
+    /// Returns the detection model configured for `sensor_id`, if any.
+    pub fn detection_model(&self, sensor_id: u32) -> Option<DetectionModel> {
+        self.detection_models.get(&sensor_id).copied()
+    }
+    
+    /// Makes `sensor_id` misreport classes per `confusion`. Readings of
+    /// classes it never confuses draw nothing extra from the physics RNG.
+    pub fn set_classification_confusion(&mut self, sensor_id: u32, confusion: ClassConfusion) {
+        self.class_confusions.insert(sensor_id, confusion);
+    }
+    
+    /// Makes `sensor_id` measure per `modality`. Sensors without one
+    /// measure position and velocity. Reducing a reading draws nothing from
+    /// the physics RNG.
+    pub fn set_sensor_modality(&mut self, sensor_id: u32, modality: SensorModality) {
+        self.modalities.insert(sensor_id, modality);
+    }
+    
+    /// Returns what `sensor_id` measures.
+    pub fn sensor_modality(&self, sensor_id: u32) -> SensorModality {
+        self.modalities.get(&sensor_id).copied().unwrap_or_default()
+    }
+    
+    /// Gives `agent_id` a sensor at `pose` that sees `fov_deg` of bearing
+    /// centered on its heading and turns at `scan_rate_dps` each step (0: it
+    /// stays put). Its readings come from [`Self::generate_readings_for_agent`].
+    pub fn register_sensor_with_scan(&mut self, agent_id: usize, pose: SensorPose, fov_deg: f64, scan_rate_dps: f64) {
+        self.scanners.insert(agent_id, ScanningSensor { pose, fov_deg, scan_rate_dps });
+    }
+    
+    /// Returns the scanning sensor registered for `agent_id`, if any.
+    pub fn scanner(&self, agent_id: usize) -> Option<&ScanningSensor> {
+        self.scanners.get(&agent_id)
+    }
+    
+    /// Moves `agent_id`'s scanning sensor to `position`, keeping its
+    /// heading. Does nothing if it has none.
+    pub fn move_sensor(&mut self, agent_id: usize, position: Vector3<f64>) {
+        if let Some(scanner) = self.scanners.get_mut(&agent_id) {
+            scanner.pose.position = position;
+        }
+    }
+    
+    /// Limits placed sensors ([`Self::generate_readings_for`] and
+    /// [`Self::generate_readings_for_agent`]) to entities within `range_m`
+    /// of them.
+    pub fn set_sensor_range(&mut self, range_m: f64) {
+        self.sensor_range = Some(range_m.max(0.0));
+    }
+    
+    /// Moves the ground plane to height `z` (m; 0 by default), or removes it
+    /// with None so entities descend forever.
+    pub fn set_ground_plane(&mut self, z: Option<f64>) {
+        self.ground_z = z;
+    }
+    
+    /// Returns the ground plane's height, if there is one.
+    pub fn ground_plane(&self) -> Option<f64> {
+        self.ground_z
+    }
+    
+    /// Accelerates ballistic entities downward at `g` m/s² (0, the default,
+    /// turns gravity off; see [`STANDARD_GRAVITY`]).
+    pub fn set_gravity(&mut self, g: f64) {
+        self.gravity = g.max(0.0);
+    }
+    
+    /// Overrides what happens when an entity reaches the ground.
+    pub fn set_ground_contact(&mut self, id: u64, contact: GroundContact) {
+        if let Some(entity) = self.entities.get_mut(&id) {
+            entity.ground_contact = contact;
+        }
+    }
+    
+    /// Overrides whether an entity falls under gravity.
+    pub fn set_ballistic(&mut self, id: u64, ballistic: bool) {
+        if let Some(entity) = self.entities.get_mut(&id) {
+            entity.ballistic = ballistic;
+        }
+    }
+    
+    /// Adds a static box that blocks line of sight and returns its index.
+    pub fn add_obstacle(&mut self, center: Vector3<f64>, half_extents: Vector3<f64>) -> usize {
+        self.obstacles.push(Obstacle::new(center, half_extents));
+        self.obstacles.len() - 1
+    }
+    
+    /// Returns the static obstacles.
+    pub fn obstacles(&self) -> &[Obstacle] {
+        &self.obstacles
+    }
+    
+    /// Lets entities block line of sight to each other as spheres of
+    /// `radius` meters (0, the default, turns entity occlusion off).
+    pub fn set_target_radius(&mut self, radius: f64) {
+        self.target_radius = radius.max(0.0);
+    }
+    
+    /// Returns true if a sensor at `from` can see the entity: the segment
+    /// to it clears every obstacle and every other active entity's sphere.
+    /// Unknown and inactive entities are never visible.
+    pub fn line_of_sight(&self, from: &Vector3<f64>, entity_id: u64) -> bool {
+        let Some(target) = self.entities.get(&entity_id).filter(|e| e.active) else {
+            return false;
+        };
+        if self.obstacles.iter().any(|o| o.blocks(from, &target.position)) {
+            return false;
+        }
+        self.target_radius <= 0.0 || !self.entities.values()
+            .filter(|e| e.active && e.id != entity_id)
+            .any(|e| sphere_blocks(&e.position, self.target_radius, from, &target.position))
     }
     
     /// Spawns a new entity and returns its ID.
@@ -148,16 +689,34 @@ impl Oracle {
         position: Vector3<f64>,
         velocity: Vector3<f64>,
         class: &str,
+    ) -> u64 {
+        self.spawn_entity_with_priority(position, velocity, class, 0)
+    }
+    
+    /// Spawns a new entity whose readings carry `priority` and returns its ID.
+    pub fn spawn_entity_with_priority(
+        &mut self,
+        position: Vector3<f64>,
+        velocity: Vector3<f64>,
+        class: &str,
+        priority: u8,
     ) -> u64 {
         let id = self.next_id;
         self.next_id += 1;
         
-        let entity = GroundTruthEntity::with_velocity(id, position, velocity, class);
+        let entity = GroundTruthEntity { priority, ..GroundTruthEntity::with_velocity(id, position, velocity, class) };
         self.entities.insert(id, entity);
         
         id
     }
     
+    /// Turns an entity onto `velocity` from the next step (a maneuver).
+    pub fn set_velocity(&mut self, id: u64, velocity: Vector3<f64>) {
+        if let Some(entity) = self.entities.get_mut(&id) {
+            entity.velocity = velocity;
+        }
+    }
+    
     /// Removes an entity from the simulation.
     pub fn remove_entity(&mut self, id: u64) {
         if let Some(entity) = self.entities.get_mut(&id) {
@@ -169,12 +728,22 @@ impl Oracle {
     pub fn step(&mut self, dt: f64) {
         self.current_time += dt;
         
-        // Simple constant-velocity model
+        // Constant velocity, except that ballistic entities fall and
+        // nothing sinks through the ground
         for entity in self.entities.values_mut() {
             if entity.active {
+                if entity.ballistic {
+                    entity.velocity.z -= self.gravity * dt;
+                }
                 entity.position += entity.velocity * dt;
+                if let Some(ground_z) = self.ground_z {
+                    touch_ground(entity, ground_z);
+                }
             }
         }
+        for scanner in self.scanners.values_mut() {
+            scanner.advance(dt);
+        }
     }
     
     /// Returns the current simulation time.
@@ -192,57 +761,67 @@ impl Oracle {
         self.entities.get(&id)
     }
     
-    /// Generates a noisy sensor reading for an entity.
+    /// Generates a noisy sensor reading for an entity, on the noise stream
+    /// of readings every agent shares.
     ///
-    /// Uses configured noise model (Gaussian, Cauchy, or Levy).
+    /// Uses configured noise model (Gaussian, Cauchy, or Levy), scaled by the
+    /// horizontal noise on x and y and the vertical noise on z.
     pub fn generate_sensor_reading(&mut self, entity_id: u64) -> Option<Vector3<f64>> {
+        self.noisy_position(NoiseReader::Shared, entity_id)
+    }
+    
+    /// An active entity's position plus noise drawn from `reader`'s stream.
+    fn noisy_position(&mut self, reader: NoiseReader, entity_id: u64) -> Option<Vector3<f64>> {
         let entity = self.entities.get(&entity_id)?;
         if !entity.active {
             return None;
         }
+        let position = entity.position;
+        let rng = self.rngs.noise(reader);
         
-        // Generate noise based on configured model
+        // Generate noise based on configured model: one draw per axis
+        // either way, so seeded readings don't depend on whether the noise
+        // is anisotropic
+        let (horizontal, vertical) = (self.position_noise_std, self.vertical_noise_std);
         let noise = match self.noise_model {
             NoiseModel::Gaussian => {
-                let normal = Normal::new(0.0, self.position_noise_std).unwrap();
+                let normal = Normal::new(0.0, 1.0).unwrap();
                 Vector3::new(
-                    normal.sample(&mut self.physics_rng),
-                    normal.sample(&mut self.physics_rng),
-                    normal.sample(&mut self.physics_rng),
+                    horizontal * normal.sample(&mut *rng),
+                    horizontal * normal.sample(&mut *rng),
+                    vertical * normal.sample(&mut *rng),
                 )
             }
             NoiseModel::Cauchy => {
                 // Cauchy: heavy tails, mean=0, scale=std_dev
-                let cauchy = Cauchy::new(0.0, self.position_noise_std).unwrap();
+                let cauchy = Cauchy::new(0.0, 1.0).unwrap();
                 Vector3::new(
-                    cauchy.sample(&mut self.physics_rng),
-                    cauchy.sample(&mut self.physics_rng),
-                    cauchy.sample(&mut self.physics_rng),
+                    horizontal * cauchy.sample(&mut *rng),
+                    horizontal * cauchy.sample(&mut *rng),
+                    vertical * cauchy.sample(&mut *rng),
                 )
             }
             NoiseModel::Levy => {
                 // Lévy: extremely heavy tails (simulated via inverse CDF)
                 // Sample u ~ Uniform(0,1), then X = scale / u^2
-                let scale = self.position_noise_std;
-                
                 // Sample inline to avoid closure borrow issues
-                let u1: f64 = self.physics_rng.gen_range(0.01..1.0);
-                let s1 = if self.physics_rng.gen::<bool>() { 1.0 } else { -1.0 };
-                let x = s1 * scale / (u1 * u1);
+                let u1: f64 = rng.gen_range(0.01..1.0);
+                let s1 = if rng.gen::<bool>() { 1.0 } else { -1.0 };
+                let x = s1 * horizontal / (u1 * u1);
                 
-                let u2: f64 = self.physics_rng.gen_range(0.01..1.0);
-                let s2 = if self.physics_rng.gen::<bool>() { 1.0 } else { -1.0 };
-                let y = s2 * scale / (u2 * u2);
+                let u2: f64 = rng.gen_range(0.01..1.0);
+                let s2 = if rng.gen::<bool>() { 1.0 } else { -1.0 };
+                let y = s2 * horizontal / (u2 * u2);
                 
-                let u3: f64 = self.physics_rng.gen_range(0.01..1.0);
-                let s3 = if self.physics_rng.gen::<bool>() { 1.0 } else { -1.0 };
-                let z = s3 * scale / (u3 * u3);
+                let u3: f64 = rng.gen_range(0.01..1.0);
+                let s3 = if rng.gen::<bool>() { 1.0 } else { -1.0 };
+                let z = s3 * vertical / (u3 * u3);
                 
                 Vector3::new(x, y, z)
             }
         };
         
-        Some(entity.position + noise)
+        Some(position + noise)
     }
     
     /// Generates sensor readings for all active entities.
@@ -263,25 +842,159 @@ impl Oracle {
     
     /// Generates SensorReading structs for all active entities.
     ///
-    /// This is the preferred method for agent consumption.
+    /// This is the preferred method for agent consumption. Readings come from
+    /// [`DEFAULT_SENSOR_ID`].
     pub fn generate_sensor_readings(&mut self) -> Vec<SensorReading> {
+        self.active_readings(NoiseReader::Shared, DEFAULT_SENSOR_ID)
+    }
+    
+    /// Generates SensorReading structs for all active entities as seen by
+    /// `sensor_id`, including that sensor's bias. The noise comes from the
+    /// sensor's own stream.
+    pub fn generate_sensor_readings_from(&mut self, sensor_id: u32) -> Vec<SensorReading> {
+        self.active_readings(NoiseReader::Sensor(sensor_id), sensor_id)
+    }
+    
+    /// Readings from `sensor_id` of every active entity, with `reader`'s
+    /// noise.
+    fn active_readings(&mut self, reader: NoiseReader, sensor_id: u32) -> Vec<SensorReading> {
         let entity_ids: Vec<(u64, Vector3<f64>)> = self.entities
             .values()
             .filter(|e| e.active)
             .map(|e| (e.id, e.velocity))
             .collect();
+        self.readings_of(reader, sensor_id, entity_ids, None)
+    }
         
-        entity_ids
+    /// Generates `agent_id`'s readings from `sensor_id` placed at
+    /// `sensor_position`, skipping entities it has no line of sight to (see
+    /// [`Self::line_of_sight`]) or that are out of range (see
+    /// [`Self::set_sensor_range`]). Skipped entities draw no noise; the rest
+    /// draw from the agent's own noise stream. Readings carry
+    /// `sensor_position` as their origin.
+    pub fn generate_readings_for(&mut self, agent_id: usize, sensor_id: u32, sensor_position: &Vector3<f64>) -> Vec<SensorReading> {
+        self.visible_readings(agent_id, sensor_id, sensor_position, |_| true)
+    }
+    
+    /// Generates readings from `sensor_id` on `agent_id`'s scanning sensor:
+    /// as [`Self::generate_readings_for`] from its registered position, but
+    /// only of entities inside its field of view at its current heading.
+    /// Agents without one get no readings.
+    pub fn generate_readings_for_agent(&mut self, agent_id: usize, sensor_id: u32) -> Vec<SensorReading> {
+        let Some(scanner) = self.scanners.get(&agent_id).copied() else {
+            return Vec::new();
+        };
+        self.visible_readings(agent_id, sensor_id, &scanner.pose.position, |target| scanner.in_view(target))
+    }
+    
+    /// `agent_id`'s readings from a sensor at `sensor_position` of the
+    /// entities in range it has line of sight to and `in_view` accepts the
+    /// position of.
+    fn visible_readings(
+        &mut self,
+        agent_id: usize,
+        sensor_id: u32,
+        sensor_position: &Vector3<f64>,
+        in_view: impl Fn(&Vector3<f64>) -> bool,
+    ) -> Vec<SensorReading> {
+        let entity_ids: Vec<(u64, Vector3<f64>)> = self.entities
+            .values()
+            .filter(|e| self.sensor_range.is_none_or(|range| (e.position - sensor_position).norm() <= range))
+            .filter(|e| in_view(&e.position) && self.line_of_sight(sensor_position, e.id))
+            .map(|e| (e.id, e.velocity))
+            .collect();
+        self.readings_of(NoiseReader::Agent(agent_id), sensor_id, entity_ids, Some(*sensor_position))
+    }
+    
+    /// Readings from `sensor_id` for `(entity, velocity)` pairs, in order,
+    /// followed by the sensor's clutter, reduced to what the sensor
+    /// measures. Noise comes from `reader`'s stream; missed entities draw
+    /// none.
+    fn readings_of(
+        &mut self,
+        reader: NoiseReader,
+        sensor_id: u32,
+        entity_ids: Vec<(u64, Vector3<f64>)>,
+        origin: Option<Vector3<f64>>,
+    ) -> Vec<SensorReading> {
+        let bias = self.sensor_bias(sensor_id);
+        let model = self.detection_model(sensor_id);
+        let detected: Vec<(u64, Vector3<f64>)> = entity_ids
+            .into_iter()
+            .filter(|_| model.is_none_or(|m| self.rngs.detection.gen_bool(m.detection_probability.clamp(0.0, 1.0))))
+            .collect();
+        let mut readings: Vec<SensorReading> = detected
             .into_iter()
             .filter_map(|(id, velocity)| {
-                self.generate_sensor_reading(id).map(|position| {
-                    SensorReading {
-                        entity_id: id,
-                        position,
-                        velocity,
-                    }
+                let position = self.noisy_position(reader, id)?;
+                Some(SensorReading {
+                    entity_id: id,
+                    position: position + bias.unwrap_or_else(Vector3::zeros),
+                    velocity,
+                    sensor_id,
+                    class_id: self.reported_class(sensor_id, self.entities[&id].class_id),
+                    bias,
+                    is_clutter: false,
+                    origin,
+                    measurement_model: MeasurementModel::PositionVelocity6D,
+                    priority: self.entities[&id].priority,
                 })
             })
+            .collect();
+        if let Some(model) = model {
+            readings.extend(self.clutter_of(sensor_id, &model, bias, origin));
+        }
+        let modality = self.sensor_modality(sensor_id);
+        for reading in &mut readings {
+            modality.reduce(reading);
+        }
+        readings
+    }
+    
+    /// The class `sensor_id` reports for an entity of `true_class`.
+    fn reported_class(&mut self, sensor_id: u32, true_class: u8) -> u8 {
+        match self.class_confusions.get(&sensor_id).filter(|c| c.confuses(true_class)) {
+            Some(confusion) => confusion.report(true_class, self.rngs.classification.gen()),
+            None => true_class,
+        }
+    }
+    
+    /// A Poisson-distributed number of clutter readings, uniform over the
+    /// model's footprint, each under a fresh entity ID.
+    fn clutter_of(
+        &mut self,
+        sensor_id: u32,
+        model: &DetectionModel,
+        bias: Option<Vector3<f64>>,
+        origin: Option<Vector3<f64>>,
+    ) -> Vec<SensorReading> {
+        let count = match Poisson::new(model.clutter_rate) {
+            Ok(poisson) => poisson.sample(&mut self.rngs.clutter) as u64,
+            Err(_) => 0,
+        };
+        let (a, b) = model.footprint;
+        (0..count)
+            .map(|_| {
+                let mut position = Vector3::zeros();
+                for axis in 0..3 {
+                    let (lo, hi) = (a[axis].min(b[axis]), a[axis].max(b[axis]));
+                    position[axis] = if hi > lo { self.rngs.clutter.gen_range(lo..hi) } else { lo };
+                }
+                let entity_id = self.next_clutter_id;
+                self.next_clutter_id += 1;
+                SensorReading {
+                    entity_id,
+                    position: position + bias.unwrap_or_else(Vector3::zeros),
+                    velocity: Vector3::zeros(),
+                    sensor_id,
+                    class_id: DRONE_CLASS_ID,
+                    bias,
+                    is_clutter: true,
+                    origin,
+                    measurement_model: MeasurementModel::PositionVelocity6D,
+                    priority: 0,
+                }
+            })
             .collect()
     }
     
@@ -293,6 +1006,48 @@ impl Oracle {
             .map(|e| (e.id, e.position))
             .collect()
     }
+    
+    /// Returns ground truth positions and velocities, for velocity as well
+    /// as position error.
+    pub fn ground_truth_states(&self) -> Vec<(u64, Vector3<f64>, Vector3<f64>)> {
+        self.entities
+            .values()
+            .filter(|e| e.active)
+            .map(|e| (e.id, e.position, e.velocity))
+            .collect()
+    }
+}
+
+/// Applies `entity`'s ground contact if it is below `ground_z`.
+fn touch_ground(entity: &mut GroundTruthEntity, ground_z: f64) {
+    if entity.position.z >= ground_z {
+        return;
+    }
+    match entity.ground_contact {
+        GroundContact::Clamp => {
+            entity.position.z = ground_z;
+            entity.velocity.z = 0.0;
+        }
+        GroundContact::Bounce { restitution } => {
+            let restitution = restitution.clamp(0.0, 1.0);
+            let rebound = -entity.velocity.z * restitution;
+            if rebound < MIN_BOUNCE_SPEED {
+                entity.position.z = ground_z;
+                entity.velocity = Vector3::zeros();
+            } else {
+                entity.position.z = ground_z + (ground_z - entity.position.z) * restitution;
+                entity.velocity.z = rebound;
+            }
+        }
+        GroundContact::Stop => {
+            entity.position.z = ground_z;
+            entity.velocity = Vector3::zeros();
+        }
+        GroundContact::Despawn => {
+            entity.position.z = ground_z;
+            entity.active = false;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -328,6 +1083,14 @@ mod tests {
         
         let entity = oracle.entity(id).unwrap();
         assert!((entity.position.x - 20.0).abs() < 0.001);
+        
+        // A maneuver takes effect from the next step
+        oracle.set_velocity(id, Vector3::new(0.0, 5.0, 0.0));
+        oracle.step(1.0);
+        assert_eq!(
+            oracle.ground_truth_states(),
+            vec![(id, Vector3::new(20.0, 5.0, 100.0), Vector3::new(0.0, 5.0, 0.0))],
+        );
     }
     
     #[test]
@@ -344,4 +1107,261 @@ mod tests {
         // Same seed = same noise
         assert_eq!(reading1, reading2);
     }
+    
+    #[test]
+    fn test_each_agent_draws_its_own_noise() {
+        let sensor = Vector3::new(0.0, 0.0, 100.0);
+        let agent_1_readings = |agent_0_reads: usize| {
+            let mut oracle = Oracle::new(42);
+            oracle.spawn_entity(Vector3::new(30.0, 40.0, 100.0), Vector3::zeros(), "drone");
+            oracle.generate_sensor_readings();
+            (0..5)
+                .map(|_| {
+                    for _ in 0..agent_0_reads {
+                        oracle.generate_readings_for(0, DEFAULT_SENSOR_ID, &sensor);
+                    }
+                    oracle.generate_readings_for(1, DEFAULT_SENSOR_ID, &sensor).remove(0).position
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(agent_1_readings(0), agent_1_readings(3));
+        
+        // Agents don't share draws either
+        let mut oracle = Oracle::new(42);
+        oracle.spawn_entity(Vector3::new(30.0, 40.0, 100.0), Vector3::zeros(), "drone");
+        let reading = |oracle: &mut Oracle, agent| oracle.generate_readings_for(agent, DEFAULT_SENSOR_ID, &sensor).remove(0).position;
+        assert_ne!(reading(&mut oracle, 0), reading(&mut oracle, 1));
+    }
+    
+    #[test]
+    fn test_vertical_noise_scales_altitude_only() {
+        for model in [NoiseModel::Gaussian, NoiseModel::Cauchy, NoiseModel::Levy] {
+            let noise = |horizontal, vertical| {
+                let mut oracle = Oracle::new(42);
+                oracle.set_noise_model(model);
+                oracle.set_position_noise_hv(horizontal, vertical);
+                let id = oracle.spawn_entity(Vector3::zeros(), Vector3::zeros(), "drone");
+                oracle.generate_sensor_reading(id).unwrap()
+            };
+            
+            // Same draws either way, z scaled by the vertical noise alone
+            let (flat, tall) = (noise(0.5, 0.5), noise(0.5, 1.5));
+            assert_eq!((tall.x, tall.y), (flat.x, flat.y), "{model:?}");
+            assert!((tall.z - 3.0 * flat.z).abs() < 1e-9, "{model:?}");
+        }
+        
+        let mut oracle = Oracle::new(42);
+        oracle.set_position_noise_hv(0.5, 1.5);
+        oracle.set_position_noise(2.0);
+        assert_eq!(oracle.position_noise_hv(), (2.0, 2.0));
+    }
+    
+    #[test]
+    fn test_reset_replays_a_fresh_oracle() {
+        let mut reset = Oracle::new(1);
+        reset.set_position_noise(2.0);
+        reset.spawn_entity(Vector3::new(5.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0), "drone");
+        reset.step(3.0);
+        reset.reset(42);
+        
+        let mut fresh = Oracle::new(42);
+        fresh.set_position_noise(2.0);
+        
+        assert_eq!(reset.time(), 0.0);
+        assert!(reset.active_entities().is_empty());
+        let id1 = reset.spawn_entity(Vector3::zeros(), Vector3::zeros(), "drone");
+        let id2 = fresh.spawn_entity(Vector3::zeros(), Vector3::zeros(), "drone");
+        assert_eq!(id1, id2);
+        
+        // Same seed and configuration = same noise
+        assert_eq!(reset.generate_sensor_reading(id1), fresh.generate_sensor_reading(id2));
+    }
+    
+    #[test]
+    fn test_sensor_bias_applied_per_sensor() {
+        let bias = Vector3::new(5.0, -3.0, 1.0);
+        let mut biased = Oracle::new(42);
+        let mut plain = Oracle::new(42);
+        biased.set_sensor_bias(1, bias);
+        biased.spawn_entity(Vector3::zeros(), Vector3::zeros(), "drone");
+        plain.spawn_entity(Vector3::zeros(), Vector3::zeros(), "drone");
+        
+        let from_biased = biased.generate_sensor_readings_from(1);
+        let from_plain = plain.generate_sensor_readings_from(1);
+        assert_eq!(from_biased[0].sensor_id, 1);
+        assert_eq!(from_biased[0].bias, Some(bias));
+        assert!((from_biased[0].position - from_plain[0].position - bias).norm() < 1e-12);
+        
+        // Other sensors are unaffected
+        let default = biased.generate_sensor_readings();
+        assert_eq!(default[0].sensor_id, DEFAULT_SENSOR_ID);
+        assert_eq!(default[0].bias, None);
+    }
+    
+    #[test]
+    fn test_detection_model_misses_and_clutters() {
+        let footprint = (Vector3::new(0.0, 0.0, 50.0), Vector3::new(500.0, 500.0, 150.0));
+        let readings_over = |seed: u64, model: Option<DetectionModel>| {
+            let mut oracle = Oracle::new(seed);
+            if let Some(model) = model {
+                oracle.set_detection_model(1, model);
+            }
+            for i in 0..10 {
+                oracle.spawn_entity(Vector3::new(i as f64 * 40.0, 100.0, 100.0), Vector3::zeros(), "drone");
+            }
+            (0..200).flat_map(|_| oracle.generate_sensor_readings_from(1)).collect::<Vec<_>>()
+        };
+        
+        let model = DetectionModel { detection_probability: 0.8, clutter_rate: 3.0, footprint };
+        let readings = readings_over(42, Some(model));
+        let (clutter, detections): (Vec<_>, Vec<_>) = readings.iter().partition(|r| r.is_clutter);
+        let pd = detections.len() as f64 / 2000.0;
+        let rate = clutter.len() as f64 / 200.0;
+        assert!((pd - 0.8).abs() < 0.03, "pd={pd}");
+        assert!((rate - 3.0).abs() < 0.3, "clutter rate={rate}");
+        assert!(clutter.iter().all(|r| r.entity_id >= CLUTTER_ENTITY_ID_BASE && r.position.z >= 50.0 && r.position.x <= 500.0));
+        let mut ids: Vec<u64> = clutter.iter().map(|r| r.entity_id).collect();
+        ids.dedup();
+        assert_eq!(ids.len(), clutter.len(), "clutter IDs are never reused");
+        
+        // Driven by the physics seed alone; no model, no extra draws
+        let again = readings_over(42, Some(model));
+        assert!(readings.iter().zip(&again).all(|(a, b)| a.entity_id == b.entity_id && a.position == b.position));
+        let clean = readings_over(42, None);
+        assert_eq!(clean.len(), 2000);
+        let perfect = readings_over(42, Some(DetectionModel::perfect()));
+        assert_eq!(perfect.len(), 2000);
+        assert!(clean.iter().all(|r| !r.is_clutter));
+        
+        // Clutter draws from its own stream, so the detections' noise is
+        // exactly that of a clutter-free run
+        let cluttered = readings_over(42, Some(DetectionModel { detection_probability: 1.0, ..model }));
+        let detections: Vec<_> = cluttered.iter().filter(|r| !r.is_clutter).collect();
+        assert_eq!(detections.len(), clean.len());
+        assert!(detections.iter().zip(&clean).all(|(a, b)| a.entity_id == b.entity_id && a.position == b.position));
+    }
+    
+    #[test]
+    fn test_sensor_modalities_reduce_readings() {
+        let sensor = Vector3::new(0.0, 0.0, 100.0);
+        let reading_as = |modality: Option<SensorModality>| {
+            let mut oracle = Oracle::new(42);
+            if let Some(modality) = modality {
+                oracle.set_sensor_modality(1, modality);
+            }
+            oracle.spawn_entity(Vector3::new(30.0, 40.0, 100.0), Vector3::new(3.0, 4.0, 0.0), "drone");
+            oracle.generate_readings_for(0, 1, &sensor).remove(0)
+        };
+        let full = reading_as(None);
+        assert_eq!(full.measurement_model, MeasurementModel::PositionVelocity6D);
+        
+        let position = reading_as(Some(SensorModality::Position));
+        assert_eq!(position.measurement_model, MeasurementModel::Position3D);
+        assert_eq!((position.position, position.velocity), (full.position, Vector3::zeros()));
+        
+        // 50 m out on the sensor's level, moving straight away at 5 m/s;
+        // reduced from the same noisy position
+        let offset = full.position - sensor;
+        let bearing = reading_as(Some(SensorModality::Bearing));
+        assert_eq!(bearing.measurement_model, MeasurementModel::BearingOnly { origin: [0.0, 0.0, 100.0] });
+        assert_eq!(bearing.position.x, offset.y.atan2(offset.x));
+        assert!((bearing.position.x - 4f64.atan2(3.0)).abs() < 0.05 && bearing.position.y.abs() < 0.05);
+        
+        let range = reading_as(Some(SensorModality::RangeDoppler));
+        assert_eq!(range.measurement_model, MeasurementModel::RangeDoppler { origin: [0.0, 0.0, 100.0] });
+        assert_eq!(range.position.x, offset.norm());
+        assert!((range.velocity.x - 5.0).abs() < 0.1, "range rate {}", range.velocity.x);
+    }
+    
+    #[test]
+    fn test_classification_confusion_misreports_per_sensor() {
+        let readings_over = |confusion: Option<ClassConfusion>| {
+            let mut oracle = Oracle::new(42);
+            if let Some(confusion) = confusion {
+                oracle.set_classification_confusion(1, confusion);
+            }
+            oracle.spawn_entity(Vector3::new(0.0, 0.0, 100.0), Vector3::zeros(), "cyclist");
+            oracle.spawn_entity(Vector3::new(50.0, 0.0, 100.0), Vector3::zeros(), "target");
+            (0..1000).flat_map(|_| oracle.generate_sensor_readings_from(1)).collect::<Vec<_>>()
+        };
+        
+        let confusion = ClassConfusion::new().swapping(CYCLIST_CLASS_ID, PEDESTRIAN_CLASS_ID, 0.1);
+        let readings = readings_over(Some(confusion));
+        let cyclists: Vec<u8> = readings.iter().filter(|r| r.entity_id == 0).map(|r| r.class_id).collect();
+        let rate = cyclists.iter().filter(|&&c| c == PEDESTRIAN_CLASS_ID).count() as f64 / 1000.0;
+        assert!((rate - 0.1).abs() < 0.03, "misclassification rate={rate}");
+        assert!(cyclists.iter().all(|&c| c == CYCLIST_CLASS_ID || c == PEDESTRIAN_CLASS_ID));
+        assert!(readings.iter().filter(|r| r.entity_id == 1).all(|r| r.class_id == DRONE_CLASS_ID));
+        
+        // Without confusion every reading carries its entity's class; the
+        // class draw comes after a reading's noise
+        let clean = readings_over(None);
+        assert!(clean.iter().all(|r| r.class_id == class_id_of(if r.entity_id == 0 { "cyclist" } else { "drone" })));
+        assert_eq!(clean[0].position, readings[0].position);
+    }
+    
+    #[test]
+    fn test_clamped_entity_slides_along_the_ground() {
+        let mut oracle = Oracle::new(42);
+        oracle.set_position_noise(0.0);
+        let id = oracle.spawn_entity(Vector3::new(0.0, 0.0, 3.0), Vector3::new(10.0, 0.0, -4.0), "vehicle");
+        assert_eq!(oracle.entity(id).unwrap().ground_contact, GroundContact::Clamp);
+        
+        for _ in 0..50 {
+            oracle.step(0.1);
+            assert!(oracle.ground_truth_positions().iter().all(|(_, p)| p.z >= 0.0));
+            assert!(oracle.generate_sensor_readings().iter().all(|r| r.position.z >= 0.0));
+        }
+        assert_eq!(
+            oracle.ground_truth_states(),
+            vec![(id, Vector3::new(50.0, 0.0, 0.0), Vector3::new(10.0, 0.0, 0.0))],
+        );
+        
+        // Without a ground plane it keeps descending
+        oracle.set_ground_plane(None);
+        oracle.set_velocity(id, Vector3::new(0.0, 0.0, -4.0));
+        oracle.step(1.0);
+        assert_eq!(oracle.entity(id).unwrap().position.z, -4.0);
+    }
+    
+    #[test]
+    fn test_ground_contact_policies_and_gravity() {
+        let mut oracle = Oracle::new(42);
+        oracle.set_ground_plane(Some(10.0));
+        oracle.set_gravity(STANDARD_GRAVITY);
+        let down = Vector3::new(1.0, 0.0, -20.0);
+        let drone = oracle.spawn_entity(Vector3::new(0.0, 0.0, 15.0), down, "drone");
+        let bouncer = oracle.spawn_entity(Vector3::new(0.0, 0.0, 15.0), down, "drone");
+        oracle.set_ground_contact(bouncer, GroundContact::Bounce { restitution: 0.5 });
+        let shell = oracle.spawn_entity(Vector3::new(0.0, 0.0, 11.0), Vector3::zeros(), "projectile");
+        
+        // Only the projectile falls; it's gone once it hits the ground
+        oracle.step(0.1);
+        assert!(oracle.entity(shell).unwrap().active);
+        assert!((oracle.entity(shell).unwrap().velocity.z + 0.980665).abs() < 1e-9);
+        for _ in 0..9 {
+            oracle.step(0.1);
+        }
+        assert!(!oracle.entity(shell).unwrap().active);
+        assert_eq!(oracle.ground_truth_positions().len(), 2);
+        
+        // The drone landed where it touched down
+        let landed = oracle.entity(drone).unwrap();
+        assert!((landed.position - Vector3::new(0.3, 0.0, 10.0)).norm() < 1e-9);
+        assert_eq!(landed.velocity, Vector3::zeros());
+        assert!(oracle.entity(bouncer).unwrap().position.z > 10.0);
+        
+        // A bouncer comes back up at half speed and settles once its
+        // rebounds get too slow
+        let mut oracle = Oracle::new(42);
+        let bouncer = oracle.spawn_entity(Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, -2.0), "drone");
+        oracle.set_ground_contact(bouncer, GroundContact::Bounce { restitution: 0.5 });
+        oracle.step(1.0);
+        assert_eq!(oracle.entity(bouncer).unwrap().position.z, 0.5);
+        assert_eq!(oracle.entity(bouncer).unwrap().velocity.z, 1.0);
+        oracle.set_velocity(bouncer, Vector3::new(0.0, 0.0, -0.8));
+        oracle.step(1.0);
+        assert_eq!(oracle.entity(bouncer).unwrap().velocity, Vector3::zeros());
+        assert_eq!(oracle.entity(bouncer).unwrap().position.z, 0.0);
+    }
 }
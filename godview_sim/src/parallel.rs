@@ -0,0 +1,200 @@
+//! Deterministic parallel execution of per-agent work.
+//!
+//! Agents only interact at gossip rounds, so between them each agent's tick
+//! and ingest work is independent. An [`AgentPool`] runs that work over every
+//! agent on a rayon pool and returns once all of it has finished. Anything
+//! random the work needs is drawn by the caller beforehand, in agent order,
+//! and gossip stays in the caller's serial phase, so a parallel run is
+//! bit-identical to a serial one.
+//!
+//! That also lets a parallel pool time itself honestly: every
+//! [`CALIBRATION_INTERVAL`]th phase runs serially, and the speedup is the
+//! mean serial phase time over the mean parallel one.
+
+use godview_env::determinism;
+use std::time::Instant;
+use tracing::warn;
+
+/// Worker threads available to this process (1 if unknown).
+pub fn available_threads() -> usize {
+    std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// A parallel pool runs one phase in this many serially, to measure its speedup.
+pub const CALIBRATION_INTERVAL: u64 = 20;
+
+/// Time spent in an [`AgentPool`]'s phases.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PoolStats {
+    /// Worker threads (1 = serial)
+    pub threads: usize,
+    
+    /// Phases run
+    pub phases: u64,
+    
+    /// Wall-clock time spent in phases (s)
+    pub wall_secs: f64,
+    
+    /// Phases run on the calling thread (all of them for a serial pool)
+    pub serial_phases: u64,
+    
+    /// Wall-clock time spent in serial phases (s)
+    pub serial_secs: f64,
+}
+
+impl PoolStats {
+    /// Mean serial phase time over mean parallel phase time (1.0 until
+    /// both kinds have run).
+    pub fn speedup(&self) -> f64 {
+        let parallel_phases = self.phases - self.serial_phases;
+        let parallel_secs = self.wall_secs - self.serial_secs;
+        if self.serial_phases == 0 || parallel_phases == 0 || parallel_secs <= 0.0 {
+            return 1.0;
+        }
+        (self.serial_secs / self.serial_phases as f64) / (parallel_secs / parallel_phases as f64)
+    }
+}
+
+/// Runs per-agent work serially or on a fixed-size thread pool.
+pub struct AgentPool {
+    pool: Option<rayon::ThreadPool>,
+    stats: PoolStats,
+}
+
+impl AgentPool {
+    /// Runs every phase on the calling thread.
+    pub fn serial() -> Self {
+        Self {
+            pool: None,
+            stats: PoolStats { threads: 1, ..PoolStats::default() },
+        }
+    }
+    
+    /// Runs phases on `threads` workers; 0 or 1 is serial. Falls back to
+    /// serial if the pool can't be built.
+    ///
+    /// Built on a thread that has entered simulation, the workers enter it
+    /// too (see [`determinism`]).
+    pub fn new(threads: usize) -> Self {
+        if threads <= 1 {
+            return Self::serial();
+        }
+        let simulating = determinism::in_simulation();
+        let builder = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .start_handler(move |_| if simulating { determinism::enter_simulation() });
+        match builder.build() {
+            Ok(pool) => Self {
+                pool: Some(pool),
+                stats: PoolStats { threads, ..PoolStats::default() },
+            },
+            Err(e) => {
+                warn!("Could not start {} worker threads ({}); running serially", threads, e);
+                Self::serial()
+            }
+        }
+    }
+    
+    /// Worker threads (1 = serial).
+    pub fn threads(&self) -> usize {
+        self.stats.threads
+    }
+    
+    /// Returns true if phases run on a thread pool.
+    pub fn is_parallel(&self) -> bool {
+        self.pool.is_some()
+    }
+    
+    /// Time spent in phases so far.
+    pub fn stats(&self) -> PoolStats {
+        self.stats
+    }
+    
+    /// Runs `work(index, item)` for every item and waits for all of it.
+    ///
+    /// `work` sees only its own item, so the order items run in can't
+    /// change the outcome.
+    pub fn for_each<T, F>(&mut self, items: &mut [T], work: F)
+    where
+        T: Send,
+        F: Fn(usize, &mut T) + Sync,
+    {
+        let calibrating = self.stats.phases.is_multiple_of(CALIBRATION_INTERVAL);
+        let start = Instant::now();
+        let serial = match &self.pool {
+            Some(pool) if !calibrating => {
+                use rayon::prelude::*;
+                pool.install(|| items.par_iter_mut().enumerate().for_each(|(idx, item)| work(idx, item)));
+                false
+            }
+            _ => {
+                for (idx, item) in items.iter_mut().enumerate() {
+                    work(idx, item);
+                }
+                true
+            }
+        };
+        
+        let secs = start.elapsed().as_secs_f64();
+        self.stats.phases += 1;
+        self.stats.wall_secs += secs;
+        if serial {
+            self.stats.serial_phases += 1;
+            self.stats.serial_secs += secs;
+        }
+    }
+}
+
+impl Default for AgentPool {
+    fn default() -> Self {
+        Self::serial()
+    }
+}
+
+impl std::fmt::Debug for AgentPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AgentPool").field("stats", &self.stats).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_pool_runs_every_item_once() {
+        for threads in [0, 1, 4] {
+            let mut pool = AgentPool::new(threads);
+            assert_eq!(pool.is_parallel(), threads > 1);
+            
+            let mut items: Vec<u64> = (0..100).collect();
+            pool.for_each(&mut items, |idx, item| *item += idx as u64 * 1000);
+            pool.for_each(&mut items, |_, item| *item += 1);
+            assert_eq!(items, (0..100).map(|i| i * 1001 + 1).collect::<Vec<_>>());
+            
+            // A parallel pool calibrates on its first phase only
+            let stats = pool.stats();
+            assert_eq!(stats.phases, 2);
+            assert_eq!(stats.serial_phases, if threads > 1 { 1 } else { 2 });
+            assert!(stats.speedup() > 0.0);
+        }
+    }
+    
+    #[test]
+    fn test_workers_enter_simulation_with_their_pool() {
+        let simulating = |pool: &mut AgentPool| {
+            let mut items = vec![false; 8];
+            // Past the calibration phase, which runs on this thread
+            for _ in 0..2 {
+                pool.for_each(&mut items, |_, item| *item = determinism::in_simulation());
+            }
+            items
+        };
+        let mut production = AgentPool::new(2);
+        std::thread::spawn(move || {
+            determinism::enter_simulation();
+            assert!(simulating(&mut AgentPool::new(2)).iter().all(|&s| s));
+        }).join().unwrap();
+        assert!(simulating(&mut production).iter().all(|&s| !s));
+    }
+}
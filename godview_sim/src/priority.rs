@@ -0,0 +1,123 @@
+//! Gossip prioritization under bandwidth limits.
+//!
+//! When a link can only carry part of an agent's recent packets, a
+//! [`GossipOrder::Prioritized`] agent sends the ones with the highest
+//! information value first. Each candidate is described by a [`PacketValue`]
+//! and scored by the evolvable [`GossipPriority`] weights.
+
+use serde::{Deserialize, Serialize};
+
+/// Gossip rounds after which an unshared track counts as fully stale.
+pub const STALENESS_HORIZON_ROUNDS: u64 = 10;
+
+/// Order in which an agent offers its recent packets for gossip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GossipOrder {
+    /// As sensed; whatever doesn't fit is truncated from the end
+    #[default]
+    Fifo,
+    
+    /// Descending [`GossipPriority::score`]
+    Prioritized,
+}
+
+/// Information-value features of one candidate packet, each in [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PacketValue {
+    /// Sender's uncertainty about the track (covariance trace `t` as `t / (1 + t)`),
+    /// i.e. how much a fresh measurement can still reduce it
+    pub uncertainty: f64,
+    
+    /// Gossip rounds since the track was last shared, over
+    /// [`STALENESS_HORIZON_ROUNDS`] (1.0 if never shared)
+    pub staleness: f64,
+    
+    /// 1.0 unless the destination is known to have gossiped this ID to us
+    pub novelty: f64,
+    
+    /// One minus the sender's corroborated confidence in the track
+    /// (0.0 for tracks it has no confidence record for)
+    pub ghost: f64,
+}
+
+/// Weights of the packet score. Part of [`EvoParams`](crate::evolution::EvoParams),
+/// so evolving agents can tune them.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GossipPriority {
+    /// Weight of [`PacketValue::uncertainty`]
+    pub uncertainty: f64,
+    
+    /// Weight of [`PacketValue::staleness`]
+    pub staleness: f64,
+    
+    /// Weight of [`PacketValue::novelty`]
+    pub novelty: f64,
+    
+    /// Penalty weight of [`PacketValue::ghost`]
+    pub ghost_penalty: f64,
+}
+
+impl Default for GossipPriority {
+    fn default() -> Self {
+        Self {
+            uncertainty: 1.0,
+            staleness: 1.0,
+            novelty: 1.0,
+            ghost_penalty: 1.0,
+        }
+    }
+}
+
+impl GossipPriority {
+    /// Number of weights, in [`Self::weight_mut`] order.
+    pub const WEIGHTS: usize = 4;
+    
+    /// Information value of a packet; higher is sent first.
+    pub fn score(&self, value: &PacketValue) -> f64 {
+        self.uncertainty * value.uncertainty
+            + self.staleness * value.staleness
+            + self.novelty * value.novelty
+            - self.ghost_penalty * value.ghost
+    }
+    
+    /// The `index`th weight (uncertainty, staleness, novelty, ghost penalty).
+    pub fn weight_mut(&mut self, index: usize) -> &mut f64 {
+        match index % Self::WEIGHTS {
+            0 => &mut self.uncertainty,
+            1 => &mut self.staleness,
+            2 => &mut self.novelty,
+            _ => &mut self.ghost_penalty,
+        }
+    }
+    
+    /// Indices of `values` in send order: descending score, ties in
+    /// original order.
+    pub fn send_order(&self, values: &[PacketValue]) -> Vec<usize> {
+        let scores: Vec<f64> = values.iter().map(|v| self.score(v)).collect();
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_send_order_is_descending_score_then_original_order() {
+        let value = |uncertainty, staleness, ghost| PacketValue { uncertainty, staleness, novelty: 1.0, ghost };
+        let values = [
+            value(0.5, 0.0, 0.0),
+            value(0.5, 1.0, 0.0),
+            value(0.5, 1.0, 0.9),
+            value(0.5, 0.0, 0.0),
+            value(0.9, 1.0, 0.0),
+        ];
+        assert_eq!(GossipPriority::default().send_order(&values), vec![4, 1, 2, 0, 3]);
+        
+        // Only staleness counts: the stale packets first, ghosts included
+        let staleness_only = GossipPriority { uncertainty: 0.0, staleness: 1.0, novelty: 0.0, ghost_penalty: 0.0 };
+        assert_eq!(staleness_only.send_order(&values), vec![1, 2, 4, 0, 3]);
+    }
+}
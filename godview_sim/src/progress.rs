@@ -0,0 +1,545 @@
+//! Progress reporting and early-abort hooks for scenario runs.
+//!
+//! A [`ScenarioRunner`](crate::ScenarioRunner) with hooks installed builds a
+//! [`ProgressReport`] every few ticks, hands it to the progress callback and
+//! asks the abort predicate whether the run should stop. An aborted run ends
+//! cleanly and its result carries the report that stopped it.
+//!
+//! The same per-tick hook fills the run's [`MetricsRegistry`]: swarm-wide
+//! time series every metrics interval (if one is set) and per-agent values
+//! on the final tick. It also streams frames to a [`RerunLogger`], if one is
+//! installed, and feeds the failure capture [`FrameRecorder`], checking the
+//! run's invariants (no numerical faults, agents within their memory budget)
+//! as it goes. A recording, if one is set, keeps a verbose frame of the whole
+//! run every recording interval as a [`SimExport`]. With a [`Pacer`] installed it sleeps before logging anything,
+//! so streamed frames and progress reports arrive at the paced wall time.
+//! An [`Interrupt`], once set, ends the run after the current tick like an
+//! abort does, whatever the reporting interval.
+//!
+//! The hooks also own the run's [`SimEventBus`]. Each run restarts it with
+//! the sinks the hooks need subscribed: an [`EventLog`] whose events land in
+//! the next recorded frame, the Rerun logger, a [`MetricsRegistry`] counting
+//! events by kind and, with failure capture on, an [`EventInvariants`]
+//! checker. Every observed tick advances the bus's clock.
+//!
+//! With a [`StatusSender`] installed, each progress report is also published
+//! as a [`StatusSnapshot`], with the latest invariant violation; invariants
+//! are then checked even without failure capture.
+
+use crate::agent::SimulatedAgent;
+use crate::capture::{snapshot_frame, CaptureConfig, FrameRecorder, InvariantViolation};
+use crate::event_bus::{EventInvariants, EventLog, SimEvent, SimEventBus, Subscription};
+use crate::exporter::{FrameEvent, SimExport};
+use crate::interrupt::Interrupt;
+use crate::metrics::{AgentSample, MetricsRegistry};
+use crate::oracle::Oracle;
+use crate::pacing::Pacer;
+use crate::rng_audit::{RngAudit, RngAuditGuard, RngTrace};
+use crate::scenarios::ScenarioId;
+use crate::status::{StatusSender, StatusSnapshot};
+use crate::stats::{every_agent, swarm_convergence};
+use crate::visualizer::RerunLogger;
+use std::cell::{Cell, RefCell};
+use std::time::Instant;
+
+/// Default number of ticks between progress reports.
+pub const DEFAULT_PROGRESS_INTERVAL_TICKS: u64 = 100;
+
+/// Callback invoked with each progress report.
+pub type ProgressFn = Box<dyn Fn(ProgressReport) + Send>;
+
+/// Predicate that ends a run early when it returns true.
+pub type AbortFn = Box<dyn Fn(&ProgressReport) -> bool + Send>;
+
+/// Snapshot of a running scenario.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressReport {
+    /// Scenario being run
+    pub scenario: ScenarioId,
+    
+    /// Ticks completed so far
+    pub tick: u64,
+    
+    /// Ticks the run was scheduled for
+    pub target_ticks: u64,
+    
+    /// Simulated time in seconds
+    pub time_secs: f64,
+    
+    /// Mean RMS position error across agents at this tick (m)
+    pub rms: f64,
+    
+    /// Agents scored in this report
+    pub agents: usize,
+    
+    /// Live tracks summed over all agents
+    pub total_tracks: usize,
+    
+    /// Live tracks held by the busiest agent
+    pub max_tracks: usize,
+    
+    /// Estimated memory held by the agents, summed, in bytes
+    pub memory_estimate_bytes: usize,
+}
+
+impl ProgressReport {
+    /// Builds a report from the agents' current state, scored against the
+    /// oracle's ground truth.
+    pub fn capture<'a>(
+        scenario: ScenarioId,
+        tick: u64,
+        target_ticks: u64,
+        oracle: &Oracle,
+        agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+    ) -> Self {
+        let agents: Vec<&SimulatedAgent> = agents.into_iter().collect();
+        let convergence = swarm_convergence(agents.iter().copied(), &oracle.ground_truth_positions(), every_agent);
+        Self {
+            scenario,
+            tick,
+            target_ticks,
+            time_secs: oracle.time(),
+            rms: convergence.rms_mean,
+            agents: convergence.agents(),
+            total_tracks: convergence.per_agent.iter().map(|a| a.tracks).sum(),
+            max_tracks: convergence.per_agent.iter().map(|a| a.tracks).max().unwrap_or(0),
+            memory_estimate_bytes: agents.iter().map(|a| a.memory_estimate_bytes()).sum(),
+        }
+    }
+    
+    /// Fraction of the scheduled ticks completed, in `[0, 1]`.
+    pub fn fraction(&self) -> f64 {
+        if self.target_ticks == 0 {
+            return 1.0;
+        }
+        (self.tick as f64 / self.target_ticks as f64).min(1.0)
+    }
+}
+
+/// Hooks installed on a runner, plus the abort report and metrics of the
+/// last run.
+pub(crate) struct ProgressHooks {
+    pub(crate) progress: Option<ProgressFn>,
+    pub(crate) abort_if: Option<AbortFn>,
+    pub(crate) interval_ticks: u64,
+    pub(crate) metrics_interval_ticks: Option<u64>,
+    pub(crate) rerun: Option<RerunLogger>,
+    pub(crate) capture: Option<CaptureConfig>,
+    pub(crate) memory_budget_bytes: Option<u64>,
+    pub(crate) realtime_factor: Option<f64>,
+    pub(crate) interrupt: Option<Interrupt>,
+    pub(crate) rng_audit_interval_ticks: Option<u64>,
+    pub(crate) recording_interval_ticks: Option<u64>,
+    pub(crate) status: Option<StatusSender>,
+    aborted: Cell<Option<ProgressReport>>,
+    metrics: RefCell<MetricsRegistry>,
+    peaks: Cell<RunPeaks>,
+    recorder: RefCell<Option<FrameRecorder>>,
+    pacer: RefCell<Option<Pacer>>,
+    rng_audit: RefCell<Option<(RngAudit, RngTrace)>>,
+    recording: RefCell<Option<SimExport>>,
+    events: SimEventBus,
+    sinks: RefCell<Option<EventSinks>>,
+    status_run: Cell<Option<(u64, Instant)>>,
+    last_violation: RefCell<Option<InvariantViolation>>,
+}
+
+/// What the hooks subscribed to the event bus for the current run.
+struct EventSinks {
+    frames: Option<Subscription<EventLog>>,
+    metrics: Subscription<MetricsRegistry>,
+    invariants: Option<Subscription<EventInvariants>>,
+}
+
+impl ProgressHooks {
+    pub(crate) fn new() -> Self {
+        Self {
+            progress: None,
+            abort_if: None,
+            interval_ticks: DEFAULT_PROGRESS_INTERVAL_TICKS,
+            metrics_interval_ticks: None,
+            rerun: None,
+            capture: None,
+            memory_budget_bytes: None,
+            realtime_factor: None,
+            interrupt: None,
+            rng_audit_interval_ticks: None,
+            recording_interval_ticks: None,
+            status: None,
+            aborted: Cell::new(None),
+            metrics: RefCell::new(MetricsRegistry::new()),
+            peaks: Cell::new(RunPeaks::default()),
+            recorder: RefCell::new(None),
+            pacer: RefCell::new(None),
+            rng_audit: RefCell::new(None),
+            recording: RefCell::new(None),
+            events: SimEventBus::new(),
+            sinks: RefCell::new(None),
+            status_run: Cell::new(None),
+            last_violation: RefCell::new(None),
+        }
+    }
+    
+    /// The bus every run publishes on. Handles stay valid across runs.
+    pub(crate) fn event_bus(&self) -> SimEventBus {
+        self.events.clone()
+    }
+    
+    /// Restarts the event bus for a run, subscribing the frame sink if
+    /// recording, the Rerun logger if installed, an event counter and the
+    /// invariant checker if capturing or publishing status.
+    pub(crate) fn start_events(&self) {
+        self.events.reset();
+        let frames = self.recording_interval_ticks.map(|_| self.events.subscribe(EventLog::new()));
+        if let Some(rerun) = &self.rerun {
+            self.events.subscribe(rerun.share());
+        }
+        let metrics = self.events.subscribe(MetricsRegistry::new());
+        let invariants = (self.capture.is_some() || self.status.is_some())
+            .then(|| self.events.subscribe(EventInvariants::new()));
+        self.sinks.replace(Some(EventSinks { frames, metrics, invariants }));
+    }
+    
+    /// Takes the event counts of the last run.
+    pub(crate) fn take_event_metrics(&self) -> MetricsRegistry {
+        self.sinks.take().map(|sinks| std::mem::take(&mut *sinks.metrics.lock())).unwrap_or_default()
+    }
+    
+    /// Takes the first invariant the current run's events broke, if any.
+    fn take_event_violation(&self) -> Option<InvariantViolation> {
+        self.sinks.borrow().as_ref()?.invariants.as_ref()?.lock().take_violation()
+    }
+    
+    /// Takes the events the frame sink received since the last take.
+    fn take_frame_events(&self) -> Vec<FrameEvent> {
+        let sinks = self.sinks.borrow();
+        let Some(frames) = sinks.as_ref().and_then(|s| s.frames.as_ref()) else {
+            return Vec::new();
+        };
+        let events = frames.lock().take();
+        events.iter().map(|e| FrameEvent::from(&e.event)).collect()
+    }
+    
+    /// Whether the installed [`Interrupt`] has been set.
+    pub(crate) fn interrupted(&self) -> bool {
+        self.interrupt.as_ref().is_some_and(Interrupt::is_set)
+    }
+    
+    /// Takes the report that aborted the last run, if any.
+    pub(crate) fn take_aborted(&self) -> Option<ProgressReport> {
+        self.aborted.take()
+    }
+    
+    /// Takes the metrics recorded during the last run.
+    pub(crate) fn take_metrics(&self) -> MetricsRegistry {
+        self.metrics.take()
+    }
+    
+    /// Takes the peaks the observed agents reached during the last run.
+    pub(crate) fn take_peaks(&self) -> RunPeaks {
+        self.peaks.take()
+    }
+    
+    /// Starts a fresh failure capture for a run at `tick_rate_hz`, if
+    /// capture is configured.
+    pub(crate) fn start_capture(&self, tick_rate_hz: u32) {
+        let recorder = self.capture.as_ref().map(|c| FrameRecorder::from_config(c, tick_rate_hz));
+        self.recorder.replace(recorder);
+    }
+    
+    /// Starts pacing a run from now, if a realtime factor is set.
+    pub(crate) fn start_pacing(&self) {
+        self.pacer.replace(self.realtime_factor.and_then(Pacer::new));
+    }
+    
+    /// Starts a fresh RNG trace of a run of `scenario` with `seed`, if
+    /// auditing is configured. Streams count their draws until the returned
+    /// guard is dropped.
+    pub(crate) fn start_rng_audit(&self, scenario: ScenarioId, seed: u64) -> Option<RngAuditGuard> {
+        let interval_ticks = self.rng_audit_interval_ticks?;
+        let audit = RngAudit::new();
+        let guard = audit.install();
+        let trace = RngTrace {
+            scenario: scenario.name().to_string(),
+            seed,
+            interval_ticks,
+            snapshots: Vec::new(),
+        };
+        self.rng_audit.replace(Some((audit, trace)));
+        Some(guard)
+    }
+    
+    /// Starts a fresh recording of a run of `scenario` with `seed`, if
+    /// recording is configured.
+    pub(crate) fn start_recording(&self, scenario: ScenarioId, seed: u64) {
+        let export = self.recording_interval_ticks.map(|_| SimExport::new(scenario.name(), seed));
+        self.recording.replace(export);
+    }
+    
+    /// Starts timing a run of `seed` for its status snapshots, if a status
+    /// sender is installed.
+    pub(crate) fn start_status(&self, seed: u64) {
+        self.status_run.set(self.status.as_ref().map(|_| (seed, Instant::now())));
+        self.last_violation.replace(None);
+    }
+    
+    /// Finalizes the recording with the run's outcome.
+    pub(crate) fn finish_recording(&self, passed: bool, rms: f64, interrupted: bool) {
+        if let Some(export) = self.recording.borrow_mut().as_mut() {
+            if interrupted {
+                export.finalize_interrupted(Some(rms));
+            } else {
+                export.finalize(passed, Some(rms));
+            }
+        }
+    }
+    
+    /// Takes the last recorded run's export.
+    pub(crate) fn take_recording(&self) -> Option<SimExport> {
+        self.recording.take()
+    }
+    
+    /// Takes the last audited run's RNG trace.
+    pub(crate) fn take_rng_trace(&self) -> Option<RngTrace> {
+        self.rng_audit.take().map(|(_, trace)| trace)
+    }
+    
+    /// Per-agent memory budget for a run of `scenario`: the runner's
+    /// override, or the scenario's own.
+    pub(crate) fn memory_budget_bytes(&self, scenario: ScenarioId) -> Option<u64> {
+        self.memory_budget_bytes.or(scenario.agent_memory_budget_bytes())
+    }
+    
+    /// Takes the last run's failure capture.
+    pub(crate) fn take_recorder(&self) -> Option<FrameRecorder> {
+        self.recorder.take()
+    }
+}
+
+/// Ticks between memory samples; the final tick is always sampled.
+const MEMORY_SAMPLE_INTERVAL_TICKS: u64 = 10;
+
+/// Maxima the observed agents reached during a run, and their totals at
+/// the last observation.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct RunPeaks {
+    /// Numerical faults summed over agents
+    pub(crate) numerical_faults: u64,
+    
+    /// Spatial index repairs summed over agents
+    pub(crate) index_repairs: u64,
+    
+    /// Largest single-agent memory estimate (bytes)
+    pub(crate) agent_memory_bytes: u64,
+    
+    /// Largest memory estimate summed over agents (bytes)
+    pub(crate) total_memory_bytes: u64,
+    
+    /// Longest gossip envelope delay any agent received (ms)
+    pub(crate) max_latency_ms: u64,
+    
+    /// Gossip packets received, summed over agents
+    pub(crate) gossip_received: u64,
+    
+    /// Live tracks summed over agents at the last observation
+    pub(crate) final_tracks: u64,
+}
+
+/// Per-run view of a runner's hooks, used from inside a scenario loop.
+pub(crate) struct RunMonitor<'a> {
+    hooks: &'a ProgressHooks,
+    scenario: ScenarioId,
+    target_ticks: u64,
+}
+
+impl<'a> RunMonitor<'a> {
+    pub(crate) fn new(hooks: &'a ProgressHooks, scenario: ScenarioId, target_ticks: u64) -> Self {
+        Self { hooks, scenario, target_ticks }
+    }
+    
+    /// Reports progress after loop iteration `tick` (0-based) and returns
+    /// true if the run should stop.
+    ///
+    /// Off the reporting interval, or when no hooks are installed, only
+    /// metrics, the run's fault and memory peaks and the failure capture are
+    /// recorded. Once a run has been aborted every later call returns true,
+    /// so scenarios with several loops stop at the first one.
+    pub(crate) fn observe<'b>(
+        &self,
+        tick: u64,
+        oracle: &Oracle,
+        agents: impl IntoIterator<Item = &'b SimulatedAgent>,
+    ) -> bool {
+        if self.hooks.aborted.get().is_some() {
+            return true;
+        }
+        
+        let agents: Vec<&SimulatedAgent> = agents.into_iter().collect();
+        let completed = tick + 1;
+        let is_final = completed == self.target_ticks;
+        self.hooks.events.set_clock(completed, oracle.time());
+        if let Some((audit, trace)) = self.hooks.rng_audit.borrow_mut().as_mut() {
+            if completed.is_multiple_of(trace.interval_ticks) || is_final {
+                trace.record(tick, audit);
+            }
+        }
+        let faults_before = self.hooks.peaks.get().numerical_faults;
+        let agent_memory = self.record_peaks(&agents, is_final || completed.is_multiple_of(MEMORY_SAMPLE_INTERVAL_TICKS));
+        let mut recorder = self.hooks.recorder.borrow_mut();
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.record(tick, oracle, agents.iter().copied());
+        }
+        if recorder.is_some() || self.hooks.status.is_some() {
+            let violation = self.check_invariants(tick, oracle.time(), faults_before, agent_memory)
+                .or_else(|| self.hooks.take_event_violation());
+            if let Some(violation) = violation {
+                if let Some(recorder) = recorder.as_mut() {
+                    recorder.violate(violation.clone());
+                }
+                self.hooks.last_violation.replace(Some(violation));
+            }
+        }
+        drop(recorder);
+        if let Some(interval) = self.hooks.recording_interval_ticks {
+            self.record_frame(tick, interval, is_final, oracle, &agents);
+        }
+        if let Some(pacer) = self.hooks.pacer.borrow_mut().as_mut() {
+            pacer.pace(oracle.time());
+        }
+        if self.interrupted() {
+            self.hooks.events.publish(SimEvent::Interrupted);
+            let report = ProgressReport::capture(self.scenario, completed, self.target_ticks, oracle, agents);
+            self.hooks.aborted.set(Some(report));
+            return true;
+        }
+        
+        let on_series = self.hooks.metrics_interval_ticks.is_some_and(|n| completed.is_multiple_of(n));
+        let on_report = (self.hooks.progress.is_some() || self.hooks.abort_if.is_some() || self.hooks.status.is_some())
+            && (completed.is_multiple_of(self.hooks.interval_ticks) || is_final);
+        let rerun = self.hooks.rerun.as_ref().filter(|r| r.should_log(tick));
+        if !on_series && !is_final && !on_report && rerun.is_none() {
+            return false;
+        }
+        
+        if let Some(rerun) = rerun {
+            rerun.log_swarm(tick, oracle, agents.iter().copied());
+        }
+        if on_series || is_final {
+            let ground_truth = oracle.ground_truth_positions();
+            let samples: Vec<AgentSample> = agents.iter()
+                .map(|a| AgentSample::capture(a, &ground_truth))
+                .collect();
+            let mut metrics = self.hooks.metrics.borrow_mut();
+            metrics.record_agents(completed, oracle.time(), &samples);
+            if is_final {
+                metrics.record_agent_snapshot(&samples);
+            }
+        }
+        if !on_report {
+            return false;
+        }
+        
+        let report = ProgressReport::capture(
+            self.scenario,
+            completed,
+            self.target_ticks,
+            oracle,
+            agents.iter().copied(),
+        );
+        self.publish_status(&report, &agents);
+        if let Some(progress) = &self.hooks.progress {
+            progress(report);
+        }
+        if self.hooks.abort_if.as_ref().is_some_and(|abort_if| abort_if(&report)) {
+            self.hooks.aborted.set(Some(report));
+            return true;
+        }
+        false
+    }
+    
+    /// Whether the installed [`Interrupt`] has been set.
+    pub(crate) fn interrupted(&self) -> bool {
+        self.hooks.interrupted()
+    }
+    
+    /// Replaces the status sender's snapshot with `report`'s, if one is
+    /// installed. Never blocks on readers.
+    fn publish_status(&self, report: &ProgressReport, agents: &[&SimulatedAgent]) {
+        let (Some(status), Some((seed, started))) = (&self.hooks.status, self.hooks.status_run.get()) else {
+            return;
+        };
+        let violation = self.hooks.last_violation.borrow().clone();
+        let snapshot = StatusSnapshot::capture(report, seed, started.elapsed().as_secs_f64(), agents, violation);
+        status.send_replace(Some(snapshot));
+    }
+    
+    /// Adds a verbose frame to the recording every `interval` ticks and on
+    /// the final tick, carrying the events published since the previous
+    /// one. A scenario that plays its world more than once
+    /// (AdaptiveSwarm's trust-inflation comparison) restarts the recording
+    /// at each tick 0, so it holds the last pass.
+    fn record_frame(&self, tick: u64, interval: u64, is_final: bool, oracle: &Oracle, agents: &[&SimulatedAgent]) {
+        let mut recording = self.hooks.recording.borrow_mut();
+        let Some(export) = recording.as_mut() else {
+            return;
+        };
+        if tick == 0 && !export.frames.is_empty() {
+            *export = SimExport::new(&export.scenario, export.seed);
+        }
+        if tick.is_multiple_of(interval) || is_final {
+            let mut frame = snapshot_frame(tick, oracle, agents.iter().copied(), true);
+            frame.events = self.hooks.take_frame_events();
+            export.add_frame(frame);
+        }
+    }
+    
+    /// Raises the run's peaks to the agents' current fault count, gossip
+    /// received and latency and, if `sample_memory`, their memory
+    /// estimates, and notes their live tracks. Returns the largest agent
+    /// estimate when memory was sampled.
+    fn record_peaks(&self, agents: &[&SimulatedAgent], sample_memory: bool) -> Option<u64> {
+        let mut peaks = self.hooks.peaks.get();
+        peaks.numerical_faults = peaks.numerical_faults.max(agents.iter().map(|a| a.numerical_faults()).sum());
+        peaks.index_repairs = peaks.index_repairs.max(agents.iter().map(|a| a.index_repairs()).sum());
+        peaks.gossip_received = peaks.gossip_received.max(agents.iter().map(|a| a.gossip_received()).sum());
+        peaks.max_latency_ms = agents.iter()
+            .map(|a| a.network_stats().latency.max())
+            .fold(peaks.max_latency_ms, u64::max);
+        peaks.final_tracks = agents.iter().map(|a| a.track_count() as u64).sum();
+        let mut largest = None;
+        if sample_memory {
+            let memory: Vec<u64> = agents.iter().map(|a| a.memory_estimate_bytes() as u64).collect();
+            let agent_max = memory.iter().copied().max().unwrap_or(0);
+            peaks.agent_memory_bytes = peaks.agent_memory_bytes.max(agent_max);
+            peaks.total_memory_bytes = peaks.total_memory_bytes.max(memory.iter().sum());
+            largest = Some(agent_max);
+        }
+        self.hooks.peaks.set(peaks);
+        largest
+    }
+    
+    /// The run's first broken invariant at `tick`, given the fault count
+    /// before it and the largest agent memory estimate if one was sampled.
+    fn check_invariants(&self, tick: u64, time_sec: f64, faults_before: u64, agent_memory: Option<u64>) -> Option<InvariantViolation> {
+        let faults = self.hooks.peaks.get().numerical_faults;
+        if faults > faults_before {
+            return Some(InvariantViolation::new(tick, time_sec, "numerical_fault",
+                format!("{} numerical faults contained (was {})", faults, faults_before)));
+        }
+        let budget = self.hooks.memory_budget_bytes(self.scenario)?;
+        agent_memory.filter(|&bytes| bytes > budget).map(|bytes| {
+            InvariantViolation::new(tick, time_sec, "memory_budget",
+                format!("Agent memory {} bytes over budget {} bytes", bytes, budget))
+        })
+    }
+    
+    /// Records a scenario-wide gauge in the run's time series, on the same
+    /// ticks [`Self::observe`] samples the agents (nothing without a
+    /// metrics interval).
+    pub(crate) fn record(&self, tick: u64, time_secs: f64, name: &str, value: f64) {
+        let completed = tick + 1;
+        if self.hooks.metrics_interval_ticks.is_some_and(|n| completed.is_multiple_of(n)) {
+            self.hooks.metrics.borrow_mut().record(completed, time_secs, name, value);
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Python bindings (`python` feature), built into a wheel with maturin.
+//!
+//! ```python
+//! import godview_sim
+//!
+//! result = godview_sim.run_scenario("time_warp", seed=42, duration=1.0)
+//! sweep = godview_sim.run_sweep("swarm", [1, 2, 3], overrides={"agents": 9})
+//! for frame in godview_sim.frames("time_warp", seed=42, verbose=True):
+//!     ...
+//! ```
+//!
+//! Results are the dicts `--json` reports per run
+//! ([`ScenarioResult::to_json`]) and frames are [`SimFrame`]s as exports
+//! write them. Simulation runs with the GIL released, so other Python
+//! threads keep going while a scenario runs.
+//!
+//! `overrides` sets the runner options a sweep varies: `agents`,
+//! `tick_rate`, `warmup_secs` and `threads`. Unknown keys are a
+//! `ValueError`, as are unknown scenario names and configurations the
+//! runner refuses.
+
+use crate::exporter::SimFrame;
+use crate::runner::{ScenarioError, ScenarioResult, ScenarioRunner};
+use crate::scenarios::ScenarioId;
+use crate::simulation::SimFrames;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyModule};
+use serde::{Deserialize, Serialize};
+
+/// Agents per run when `overrides` doesn't set them (the CLI's default).
+const DEFAULT_AGENTS: usize = 6;
+
+/// Runner options a Python caller may override.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RunOverrides {
+    agents: Option<usize>,
+    tick_rate: Option<u32>,
+    warmup_secs: Option<f64>,
+    threads: Option<usize>,
+}
+
+impl RunOverrides {
+    /// Parses a Python dict by way of its JSON form.
+    fn from_dict(dict: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(dict) = dict else {
+            return Ok(Self::default());
+        };
+        let json: String = dict.py().import("json")?.call_method1("dumps", (dict,))?.extract()?;
+        serde_json::from_str(&json).map_err(|e| PyValueError::new_err(format!("invalid overrides: {e}")))
+    }
+    
+    /// Builds the runner for one seed.
+    fn runner(&self, seed: u64, duration: Option<f64>) -> ScenarioRunner {
+        let mut runner = ScenarioRunner::new(seed, self.agents.unwrap_or(DEFAULT_AGENTS));
+        if let Some(hz) = self.tick_rate {
+            runner = runner.with_tick_rate(hz);
+        }
+        if let Some(secs) = self.warmup_secs {
+            runner = runner.with_warmup_secs(secs);
+        }
+        if let Some(threads) = self.threads {
+            runner = runner.with_threads(threads);
+        }
+        if let Some(secs) = duration {
+            runner = runner.with_duration(secs);
+        }
+        runner
+    }
+}
+
+fn scenario(name: &str) -> PyResult<ScenarioId> {
+    name.parse().map_err(PyValueError::new_err)
+}
+
+fn scenario_error(e: ScenarioError) -> PyErr {
+    match e {
+        ScenarioError::InvalidConfig { .. } => PyValueError::new_err(e.to_string()),
+        _ => PyRuntimeError::new_err(e.to_string()),
+    }
+}
+
+/// Converts anything serializable into Python objects by way of JSON.
+fn to_python<T: Serialize>(py: Python<'_>, value: &T) -> PyResult<PyObject> {
+    let json = serde_json::to_string(value).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    Ok(py.import("json")?.call_method1("loads", (json,))?.unbind())
+}
+
+fn result_to_python(py: Python<'_>, result: &ScenarioResult) -> PyResult<PyObject> {
+    to_python(py, &result.to_json())
+}
+
+/// Runs one scenario and returns its result dict.
+#[pyfunction]
+#[pyo3(signature = (name, seed = 42, duration = None, overrides = None))]
+fn run_scenario(
+    py: Python<'_>,
+    name: &str,
+    seed: u64,
+    duration: Option<f64>,
+    overrides: Option<&Bound<'_, PyDict>>,
+) -> PyResult<PyObject> {
+    let scenario = scenario(name)?;
+    let overrides = RunOverrides::from_dict(overrides)?;
+    let result = py.allow_threads(|| overrides.runner(seed, duration).run(scenario))
+        .map_err(scenario_error)?;
+    result_to_python(py, &result)
+}
+
+/// Runs one scenario once per seed, in order, and returns the result dicts.
+#[pyfunction]
+#[pyo3(signature = (name, seeds, duration = None, overrides = None))]
+fn run_sweep(
+    py: Python<'_>,
+    name: &str,
+    seeds: Vec<u64>,
+    duration: Option<f64>,
+    overrides: Option<&Bound<'_, PyDict>>,
+) -> PyResult<Vec<PyObject>> {
+    let scenario = scenario(name)?;
+    let overrides = RunOverrides::from_dict(overrides)?;
+    let results = py.allow_threads(|| {
+        seeds.iter()
+            .map(|&seed| overrides.runner(seed, duration).run(scenario))
+            .collect::<Result<Vec<_>, _>>()
+    }).map_err(scenario_error)?;
+    results.iter().map(|r| result_to_python(py, r)).collect()
+}
+
+/// Steps a scenario's world lazily, one frame dict per tick (see
+/// [`ScenarioRunner::world`] for the scenarios that have one).
+#[pyfunction]
+#[pyo3(signature = (name, seed = 42, duration = None, overrides = None, verbose = false))]
+fn frames(
+    name: &str,
+    seed: u64,
+    duration: Option<f64>,
+    overrides: Option<&Bound<'_, PyDict>>,
+    verbose: bool,
+) -> PyResult<FrameIterator> {
+    let scenario = scenario(name)?;
+    let world = RunOverrides::from_dict(overrides)?
+        .runner(seed, duration)
+        .world(scenario)
+        .map_err(scenario_error)?;
+    Ok(FrameIterator { frames: world.frames(verbose) })
+}
+
+/// Python iterator over a [`SimFrames`]: each `next` steps one tick.
+#[pyclass(module = "godview_sim")]
+struct FrameIterator {
+    frames: SimFrames,
+}
+
+#[pymethods]
+impl FrameIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+    
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let frame: Option<SimFrame> = py.allow_threads(|| self.frames.next());
+        frame.map(|f| to_python(py, &f)).transpose()
+    }
+    
+    fn __len__(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+#[pymodule]
+fn godview_sim(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(run_scenario, m)?)?;
+    m.add_function(wrap_pyfunction!(run_sweep, m)?)?;
+    m.add_function(wrap_pyfunction!(frames, m)?)?;
+    m.add_class::<FrameIterator>()?;
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    Ok(())
+}
@@ -0,0 +1,288 @@
+//! CI report formats for scenario results.
+//!
+//! - JUnit XML: one `<testcase>` per (scenario, seed), for CI test reporters
+//! - GitHub Actions annotations: `::error::` lines that show up inline on PRs
+//!
+//! Runs replayed from a seed corpus are labelled `corpus` in both, so a
+//! regression on a known seed reads differently from a new failure.
+//! - Metrics: each result's [`MetricsRegistry`](crate::MetricsRegistry) as a
+//!   CSV time series or Prometheus text, labelled by scenario and seed
+
+use crate::corpus::RunOrigin;
+use crate::failure::FailureKind;
+use crate::metrics::{self, MetricLabels, MetricsRegistry};
+use crate::runner::ScenarioResult;
+use std::fmt::Write;
+
+/// Name of the JUnit test suite.
+const SUITE_NAME: &str = "godview-dst";
+
+/// Renders results as a JUnit XML document.
+///
+/// Test case time is the simulated duration (`final_time_secs`). Corpus
+/// replays are classed under `godview-dst.corpus.<scenario>`.
+pub fn junit_xml(results: &[ScenarioResult]) -> String {
+    render_junit(results, false)
+}
+
+/// Renders the results a sweep completed before Ctrl-C stopped it, with an
+/// `interrupted` suite property so CI doesn't mistake it for a full run.
+pub fn interrupted_junit_xml(results: &[ScenarioResult]) -> String {
+    render_junit(results, true)
+}
+
+fn render_junit(results: &[ScenarioResult], interrupted: bool) -> String {
+    let failures = results.iter().filter(|r| !r.passed).count();
+    let total_time: f64 = results.iter().map(|r| r.final_time_secs).sum();
+    
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"{SUITE_NAME}\" tests=\"{}\" failures=\"{failures}\" time=\"{total_time:.3}\">",
+        results.len(),
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"{SUITE_NAME}\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" time=\"{total_time:.3}\">",
+        results.len(),
+    );
+    if interrupted {
+        xml.push_str("    <properties>\n      <property name=\"interrupted\" value=\"true\"/>\n    </properties>\n");
+    }
+    
+    for result in results {
+        let name = result.scenario.name();
+        let class = match result.origin {
+            RunOrigin::Fresh => format!("{SUITE_NAME}.{name}"),
+            RunOrigin::Corpus => format!("{SUITE_NAME}.corpus.{name}"),
+        };
+        let _ = write!(
+            xml,
+            "    <testcase classname=\"{class}\" name=\"{name}[seed={}]\" time=\"{:.3}\"",
+            result.seed,
+            result.final_time_secs,
+        );
+        
+        if result.passed {
+            xml.push_str("/>\n");
+            continue;
+        }
+        
+        let reason = result.failure_reason().unwrap_or_else(|| "unknown".to_string());
+        let kind = result.failure.as_ref().map_or("unknown", FailureKind::label);
+        let _ = writeln!(xml, ">");
+        let _ = writeln!(
+            xml,
+            "      <failure type=\"{}\" message=\"{}\">{}</failure>",
+            kind,
+            escape(&reason),
+            escape(&metrics_summary(result)),
+        );
+        xml.push_str("    </testcase>\n");
+    }
+    
+    xml.push_str("  </testsuite>\n");
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Renders one `::error::` line per failed result.
+pub fn github_annotations(results: &[ScenarioResult]) -> Vec<String> {
+    results.iter()
+        .filter(|r| !r.passed)
+        .map(|r| {
+            let title = match r.origin {
+                RunOrigin::Fresh => format!("DST {} (seed={})", r.scenario.name(), r.seed),
+                RunOrigin::Corpus => format!("DST corpus {} (seed={})", r.scenario.name(), r.seed),
+            };
+            let reason = r.failure_reason().unwrap_or_else(|| "unknown".to_string());
+            format!("::error title={}::{}", escape_property(&title), escape_data(&reason))
+        })
+        .collect()
+}
+
+/// Renders every result's metrics time series as one CSV document.
+pub fn metrics_csv(results: &[ScenarioResult]) -> String {
+    metrics::csv_text(&labelled_registries(results))
+}
+
+/// Renders every result's final metrics in the Prometheus text format.
+pub fn metrics_prometheus(results: &[ScenarioResult]) -> String {
+    metrics::prometheus_text(&labelled_registries(results))
+}
+
+/// Pairs each result's registry with its scenario and seed labels.
+fn labelled_registries(results: &[ScenarioResult]) -> Vec<(MetricLabels, &MetricsRegistry)> {
+    results.iter()
+        .map(|r| (vec![("scenario", r.scenario.name().to_string()), ("seed", r.seed.to_string())], &r.registry))
+        .collect()
+}
+
+/// Key metrics included in a JUnit failure body.
+fn metrics_summary(result: &ScenarioResult) -> String {
+    let m = &result.metrics;
+    format!(
+        "scenario={} seed={} ticks={} sim_time={:.2}s rms_mean={:.3}m rms_p95={:.3}m rms_samples={} \
+         packets_sent={} packets_dropped={} kind={} reason={}",
+        result.scenario.name(),
+        result.seed,
+        result.total_ticks,
+        result.final_time_secs,
+        result.rms_mean,
+        result.rms_p95,
+        result.rms_samples,
+        m.packets_sent,
+        m.packets_dropped,
+        result.failure.as_ref().map_or("unknown", FailureKind::label),
+        result.failure_reason().as_deref().unwrap_or("unknown"),
+    )
+}
+
+/// Escapes text for XML attributes and character data.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            c if (c as u32) < 0x20 && c != '\t' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Escapes a workflow command message (GitHub's `escapeData`).
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes a workflow command property (GitHub's `escapeProperty`).
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration::ThresholdSource;
+    use crate::runner::ScenarioMetrics;
+    use crate::scenarios::ScenarioId;
+    
+    fn fabricated_results() -> Vec<ScenarioResult> {
+        vec![
+            ScenarioResult {
+                scenario: ScenarioId::TimeWarp,
+                seed: 42,
+                passed: true,
+                total_ticks: 300,
+                final_time_secs: 10.0,
+                final_entity_count: 10,
+                failure: None,
+                rms_mean: 0.86,
+                rms_p95: 1.1,
+                rms_samples: 240,
+                metrics: ScenarioMetrics::default(),
+                duration_clamp: None,
+                aborted: None,
+                registry: MetricsRegistry::default(),
+                origin: RunOrigin::Fresh,
+                thresholds: ThresholdSource::Builtin,
+            },
+            ScenarioResult {
+                scenario: ScenarioId::CommonBias,
+                seed: 7,
+                passed: false,
+                total_ticks: 100,
+                final_time_secs: 10.0,
+                final_entity_count: 5,
+                failure: Some(FailureKind::expectation("escaping", "RMS 8.78m > 5.0m & p95 <bad>")),
+                rms_mean: 8.78,
+                rms_p95: 12.5,
+                rms_samples: 80,
+                metrics: ScenarioMetrics {
+                    packets_sent: 120,
+                    packets_dropped: 3,
+                    ..Default::default()
+                },
+                duration_clamp: None,
+                aborted: None,
+                registry: MetricsRegistry::default(),
+                origin: RunOrigin::Fresh,
+                thresholds: ThresholdSource::Builtin,
+            },
+        ]
+    }
+    
+    #[test]
+    fn test_junit_golden() {
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="godview-dst" tests="2" failures="1" time="20.000">
+  <testsuite name="godview-dst" tests="2" failures="1" errors="0" time="20.000">
+    <testcase classname="godview-dst.time_warp" name="time_warp[seed=42]" time="10.000"/>
+    <testcase classname="godview-dst.common_bias" name="common_bias[seed=7]" time="10.000">
+      <failure type="expectation_failed" message="RMS 8.78m &gt; 5.0m &amp; p95 &lt;bad&gt;">scenario=common_bias seed=7 ticks=100 sim_time=10.00s rms_mean=8.780m rms_p95=12.500m rms_samples=80 packets_sent=120 packets_dropped=3 kind=expectation_failed reason=RMS 8.78m &gt; 5.0m &amp; p95 &lt;bad&gt;</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#;
+        assert_eq!(junit_xml(&fabricated_results()), expected);
+    }
+    
+    #[test]
+    fn test_junit_empty() {
+        let xml = junit_xml(&[]);
+        assert!(xml.contains(r#"tests="0" failures="0""#));
+        assert!(!xml.contains("<testcase"));
+        assert!(!xml.contains("interrupted"));
+        
+        let xml = interrupted_junit_xml(&fabricated_results()[..1]);
+        assert!(xml.contains(r#"<property name="interrupted" value="true"/>"#));
+        assert!(xml.contains(r#"tests="1" failures="0""#));
+    }
+    
+    #[test]
+    fn test_github_annotations_only_failures() {
+        let mut results = fabricated_results();
+        results[1].failure = Some(FailureKind::expectation("multi_line", "line one\nline two: 100%"));
+        
+        assert_eq!(
+            github_annotations(&results),
+            vec!["::error title=DST common_bias (seed=7)::line one%0Aline two: 100%25".to_string()]
+        );
+    }
+    
+    #[test]
+    fn test_corpus_runs_are_labelled() {
+        let mut results = fabricated_results();
+        for r in &mut results {
+            r.origin = RunOrigin::Corpus;
+        }
+        
+        let xml = junit_xml(&results);
+        assert!(xml.contains(r#"<testcase classname="godview-dst.corpus.time_warp" name="time_warp[seed=42]""#));
+        assert!(xml.contains(r#"<testcase classname="godview-dst.corpus.common_bias" name="common_bias[seed=7]""#));
+        assert!(github_annotations(&results)[0].starts_with("::error title=DST corpus common_bias (seed=7)::"));
+    }
+    
+    #[test]
+    fn test_metrics_are_labelled_by_scenario_and_seed() {
+        let mut results = fabricated_results();
+        for r in &mut results {
+            r.record_final_metrics();
+        }
+        results[0].registry.record(1, 0.1, "rms_error", 0.5);
+        
+        let prom = metrics_prometheus(&results);
+        assert!(prom.contains("godview_packets_sent{scenario=\"common_bias\",seed=\"7\"} 120\n"));
+        assert!(prom.contains("godview_passed{scenario=\"time_warp\",seed=\"42\"} 1\n"));
+        
+        let csv = metrics_csv(&results);
+        assert_eq!(csv, "scenario,seed,tick,time_secs,rms_error\ntime_warp,42,1,0.1,0.5\n");
+    }
+}
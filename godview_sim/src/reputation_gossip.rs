@@ -0,0 +1,229 @@
+//! Second-order reputation: agents share their neighbor assessments.
+//!
+//! An agent only scores the neighbors whose gossip it receives, so an agent
+//! learns nothing about a bad actor two hops away until the topology puts
+//! them side by side. With sharing on ([`ReputationSharing`]), every so often
+//! each agent sends its neighbors a [`ReputationSummary`] of the scores it
+//! earned first-hand, and each receiver pulls its own scores toward them
+//! ([`blend_summary`]).
+//!
+//! Summaries are a weapon too: a bad actor can report a good agent as
+//! worthless. Three things blunt that. A receiver moves only
+//! `trust_discount` of the way to a reported score, scaled by how reliable it
+//! finds the sender, so one liar is outvoted by the honest agents around it.
+//! It ignores summaries from senders it already filters, and what a sender
+//! says about itself or the receiver. And a summary only carries first-hand
+//! scores, so a lie reaches only the liar's neighbors and isn't relayed on
+//! as if it were theirs.
+
+use crate::adaptive::{AdaptiveState, GOSSIP_FILTER_THRESHOLD, NEUTRAL_RELIABILITY};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Wire bytes of one summary entry: a u32 agent index and an f64 score.
+pub const REPUTATION_ENTRY_BYTES: usize = 12;
+
+/// Default share of the gap to a reported score a receiver closes, at a
+/// sender's full reliability.
+pub const DEFAULT_TRUST_DISCOUNT: f64 = 0.1;
+
+/// How often agents share reputations, and how far they trust what they hear.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ReputationSharing {
+    /// Ticks between summaries (each agent sends one to every neighbor)
+    pub interval_ticks: u64,
+    
+    /// Share of the gap to a reported score a receiver closes, scaled by
+    /// its reliability score for the sender (0: ignore summaries, 1: adopt
+    /// a fully trusted sender's scores outright)
+    pub trust_discount: f64,
+}
+
+impl ReputationSharing {
+    /// Shares every `interval_ticks` ticks with [`DEFAULT_TRUST_DISCOUNT`].
+    pub fn new(interval_ticks: u64) -> Self {
+        Self { interval_ticks: interval_ticks.max(1), trust_discount: DEFAULT_TRUST_DISCOUNT }
+    }
+    
+    /// Closes `trust_discount` (clamped to [0, 1]) of the gap per summary.
+    pub fn with_trust_discount(mut self, trust_discount: f64) -> Self {
+        self.trust_discount = trust_discount.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// The neighbor scores an agent earned first-hand, by agent index.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReputationSummary {
+    /// Reliability score of each neighbor the agent has received gossip from
+    pub scores: BTreeMap<usize, f64>,
+}
+
+impl ReputationSummary {
+    /// Summarizes the neighbors in `state` with gossip behind their score,
+    /// leaving out scores only blended in from other agents' summaries.
+    pub fn from_state(state: &AdaptiveState) -> Self {
+        Self {
+            scores: state.neighbor_reputations.values()
+                .filter(|rep| rep.packets_received > 0)
+                .map(|rep| (rep.neighbor_id, rep.reliability_score))
+                .collect(),
+        }
+    }
+    
+    /// Number of agents assessed.
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+    
+    /// True if the summary assesses no one.
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+    
+    /// Bytes the summary takes on the wire.
+    pub fn wire_bytes(&self) -> usize {
+        self.scores.len() * REPUTATION_ENTRY_BYTES
+    }
+}
+
+/// Pulls `receiver`'s scores in `state` toward `sender`'s `summary`: each
+/// closes `trust_discount` times the receiver's reliability score for the
+/// sender (neutral if unknown) of the gap. Returns the number of scores
+/// moved: none if the receiver filters the sender, and never the
+/// receiver's or the sender's own.
+///
+/// Agents the receiver has no score for start from neutral, so a summary
+/// also introduces agents it has never heard from.
+pub fn blend_summary(
+    state: &mut AdaptiveState,
+    receiver: usize,
+    sender: usize,
+    summary: &ReputationSummary,
+    trust_discount: f64,
+) -> usize {
+    if !state.should_accept_gossip(sender) {
+        return 0;
+    }
+    let sender_reliability = state.neighbor_reputations.get(&sender)
+        .map_or(NEUTRAL_RELIABILITY, |rep| rep.reliability_score);
+    let weight = trust_discount.clamp(0.0, 1.0) * sender_reliability;
+    let mut blended = 0;
+    for (&subject, &reported) in &summary.scores {
+        if subject == receiver || subject == sender {
+            continue;
+        }
+        let rep = state.get_neighbor(subject);
+        rep.reliability_score += weight * (reported.clamp(0.0, 1.0) - rep.reliability_score);
+        blended += 1;
+    }
+    blended
+}
+
+/// How well the swarm's reputation scores match who is actually bad.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct ReputationConsensus {
+    /// Scores held by honest agents about other agents, first-hand or
+    /// blended
+    pub assessments: usize,
+    
+    /// Share of those scores on the right side of
+    /// [`GOSSIP_FILTER_THRESHOLD`]: below it for bad actors, at or above it
+    /// for everyone else (1.0 with none)
+    pub accuracy: f64,
+    
+    /// Share of (honest agent, bad actor) pairs, over the whole swarm
+    /// rather than neighbors only, where the honest agent scores the bad
+    /// actor below the threshold (0 with no bad actors)
+    pub swarm_detection_rate: f64,
+}
+
+impl ReputationConsensus {
+    /// Scores `observers`' adaptive states (by agent index) against
+    /// `bad_actors`.
+    pub fn measure<'a>(observers: impl IntoIterator<Item = (usize, &'a AdaptiveState)>, bad_actors: &[usize]) -> Self {
+        let (mut assessments, mut correct) = (0, 0);
+        let (mut detected, mut possible) = (0, 0);
+        for (idx, state) in observers {
+            for rep in state.neighbor_reputations.values().filter(|rep| rep.neighbor_id != idx) {
+                assessments += 1;
+                let flagged = rep.reliability_score < GOSSIP_FILTER_THRESHOLD;
+                if flagged == bad_actors.contains(&rep.neighbor_id) {
+                    correct += 1;
+                }
+            }
+            for bad_id in bad_actors.iter().filter(|&&bad_id| bad_id != idx) {
+                possible += 1;
+                if state.neighbor_reputations.get(bad_id).is_some_and(|rep| rep.reliability_score < GOSSIP_FILTER_THRESHOLD) {
+                    detected += 1;
+                }
+            }
+        }
+        Self {
+            assessments,
+            accuracy: if assessments > 0 { correct as f64 / assessments as f64 } else { 1.0 },
+            swarm_detection_rate: if possible > 0 { detected as f64 / possible as f64 } else { 0.0 },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn state_scoring(scores: &[(usize, f64)]) -> AdaptiveState {
+        let mut state = AdaptiveState::new();
+        for &(neighbor, score) in scores {
+            let rep = state.get_neighbor(neighbor);
+            rep.record_useful();
+            rep.reliability_score = score;
+        }
+        state
+    }
+    
+    #[test]
+    fn test_summary_carries_first_hand_scores_only() {
+        let mut state = state_scoring(&[(1, 0.9), (2, 0.1)]);
+        state.get_neighbor(7).reliability_score = 0.2;
+        let summary = ReputationSummary::from_state(&state);
+        assert_eq!(summary.scores, BTreeMap::from([(1, 0.9), (2, 0.1)]));
+        assert_eq!(summary.wire_bytes(), 2 * REPUTATION_ENTRY_BYTES);
+    }
+    
+    #[test]
+    fn test_blend_is_discounted_by_trust_in_the_sender() {
+        // Receiver 0 trusts sender 1 fully and knows nothing of 2 or 3
+        let mut state = state_scoring(&[(1, 1.0)]);
+        let summary = ReputationSummary { scores: BTreeMap::from([(0, 0.0), (1, 1.0), (2, 0.0), (3, 1.0)]) };
+        assert_eq!(blend_summary(&mut state, 0, 1, &summary, 0.5), 2);
+        assert_eq!(state.neighbor_reputations[&2].reliability_score, 0.25);
+        assert_eq!(state.neighbor_reputations[&3].reliability_score, 0.75);
+        assert!(!state.neighbor_reputations.contains_key(&0));
+        
+        // At a low discount it takes repeated reports to flag an agent the
+        // receiver never heard from
+        let mut state = state_scoring(&[(1, 1.0)]);
+        for _ in 0..4 {
+            blend_summary(&mut state, 0, 1, &summary, 0.1);
+        }
+        assert!(state.neighbor_reputations[&2].reliability_score >= GOSSIP_FILTER_THRESHOLD);
+        blend_summary(&mut state, 0, 1, &summary, 0.1);
+        assert!(state.neighbor_reputations[&2].reliability_score < GOSSIP_FILTER_THRESHOLD);
+        
+        // A sender the receiver filters is ignored
+        let mut state = state_scoring(&[(1, 0.2), (2, 0.8)]);
+        assert_eq!(blend_summary(&mut state, 0, 1, &summary, 1.0), 0);
+        assert_eq!(state.neighbor_reputations[&2].reliability_score, 0.8);
+    }
+    
+    #[test]
+    fn test_consensus_scores_verdicts_against_bad_actors() {
+        // Agent 0 flags bad actor 2 and trusts 1; agent 1 wrongly flags 0
+        // and has no opinion of 2
+        let observers = [(0, state_scoring(&[(1, 0.9), (2, 0.1)])), (1, state_scoring(&[(0, 0.2)]))];
+        let consensus = ReputationConsensus::measure(observers.iter().map(|(idx, s)| (*idx, s)), &[2]);
+        assert_eq!(consensus.assessments, 3);
+        assert!((consensus.accuracy - 2.0 / 3.0).abs() < 1e-12);
+        assert_eq!(consensus.swarm_detection_rate, 0.5);
+    }
+}
@@ -0,0 +1,276 @@
+//! Per-stream RNG consumption, for pinpointing determinism leaks.
+//!
+//! Every RNG stream the simulation derives is an [`AuditedRng`] with a label
+//! (`agent-3/evolution`, `oracle/noise`, `runner/chaos-storm`). Normally a
+//! stream is a plain ChaCha8 generator plus one `None` check per draw. While
+//! an [`RngAudit`] is installed on the thread ([`RngAudit::install`]),
+//! streams created there count their draws per label, and a run snapshots
+//! the counts into an [`RngTrace`] every so many ticks. Two runs of one seed
+//! must produce identical traces; [`RngTrace::first_divergence`] names the
+//! first snapshot and label where they don't.
+//!
+//! Streams are derived by name ([`AuditedRng::derive`]): each is seeded from
+//! a hash of the simulation seed and its name, so adding a draw to one
+//! stream never shifts another's sequence.
+
+use rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Ticks between [`RngTrace`] snapshots unless configured otherwise.
+pub const DEFAULT_RNG_AUDIT_INTERVAL_TICKS: u64 = 10;
+
+thread_local! {
+    static INSTALLED: RefCell<Option<RngAudit>> = const { RefCell::new(None) };
+}
+
+/// Draw counters of every audited stream, by label.
+///
+/// Clones share the counters. Streams created with the same label share one
+/// counter, so a stream rebuilt mid-run keeps counting where it left off.
+#[derive(Debug, Clone, Default)]
+pub struct RngAudit {
+    counters: Arc<Mutex<BTreeMap<String, Arc<AtomicU64>>>>,
+}
+
+impl RngAudit {
+    /// Creates an audit with no streams yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Makes streams created on this thread count their draws here until
+    /// the guard is dropped.
+    pub fn install(&self) -> RngAuditGuard {
+        let previous = INSTALLED.with(|installed| installed.replace(Some(self.clone())));
+        RngAuditGuard { previous }
+    }
+    
+    /// The audit installed on this thread, if any.
+    pub fn installed() -> Option<RngAudit> {
+        INSTALLED.with(|installed| installed.borrow().clone())
+    }
+    
+    /// Draws so far, by label.
+    pub fn counts(&self) -> BTreeMap<String, u64> {
+        self.counters.lock().unwrap().iter()
+            .map(|(label, draws)| (label.clone(), draws.load(Ordering::Relaxed)))
+            .collect()
+    }
+    
+    fn counter(&self, label: String) -> Arc<AtomicU64> {
+        Arc::clone(self.counters.lock().unwrap().entry(label).or_default())
+    }
+}
+
+/// Uninstalls an [`RngAudit`] when dropped, restoring whichever was
+/// installed before.
+pub struct RngAuditGuard {
+    previous: Option<RngAudit>,
+}
+
+impl Drop for RngAuditGuard {
+    fn drop(&mut self) {
+        INSTALLED.with(|installed| *installed.borrow_mut() = self.previous.take());
+    }
+}
+
+/// A ChaCha8 stream that counts its draws while audited.
+///
+/// Draws are exactly those of the wrapped generator, audited or not.
+#[derive(Debug, Clone)]
+pub struct AuditedRng {
+    rng: ChaCha8Rng,
+    draws: Option<Arc<AtomicU64>>,
+}
+
+impl AuditedRng {
+    /// Stream `name` of `seed`, keyed by SHA-256 of both and counted under
+    /// `name`. Streams of one seed with different names are independent:
+    /// however many draws one takes, the others' sequences don't move.
+    pub fn derive(seed: u64, name: &str) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(seed.to_le_bytes());
+        hasher.update(name.as_bytes());
+        Self::wrap(ChaCha8Rng::from_seed(hasher.finalize().into()), || name.to_string())
+    }
+    
+    /// Counts `rng`'s draws under `label` if an audit is installed (the
+    /// label is only built then).
+    fn wrap(rng: ChaCha8Rng, label: impl FnOnce() -> String) -> Self {
+        let draws = RngAudit::installed().map(|audit| audit.counter(label()));
+        Self { rng, draws }
+    }
+    
+    #[inline]
+    fn count(&self) {
+        if let Some(draws) = &self.draws {
+            draws.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+impl RngCore for AuditedRng {
+    fn next_u32(&mut self) -> u32 {
+        self.count();
+        self.rng.next_u32()
+    }
+    
+    fn next_u64(&mut self) -> u64 {
+        self.count();
+        self.rng.next_u64()
+    }
+    
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.count();
+        self.rng.fill_bytes(dest)
+    }
+    
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.count();
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+// ChaCha8 underneath, so audited key streams stay usable for key generation
+impl CryptoRng for AuditedRng {}
+
+/// Draw counts of every stream at one tick.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RngSnapshot {
+    /// Tick the counts were taken after
+    pub tick: u64,
+    
+    /// Draws so far, by stream label
+    pub draws: BTreeMap<String, u64>,
+}
+
+/// RNG consumption of one run, snapshotted every `interval_ticks`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RngTrace {
+    /// Scenario run
+    pub scenario: String,
+    
+    /// Seed run
+    pub seed: u64,
+    
+    /// Ticks between snapshots
+    pub interval_ticks: u64,
+    
+    /// Snapshots in the order taken (ticks restart for scenarios that run
+    /// their world more than once)
+    pub snapshots: Vec<RngSnapshot>,
+}
+
+/// Where two [`RngTrace`]s first disagree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RngDivergence {
+    /// Index of the first differing snapshot
+    pub snapshot: usize,
+    
+    /// Tick of that snapshot; the extra or missing draws happened since the
+    /// snapshot before it
+    pub tick: u64,
+    
+    /// First stream label, in order, whose draw counts differ
+    pub label: String,
+    
+    /// Its draws in each trace (None: the stream doesn't exist there)
+    pub left: Option<u64>,
+    pub right: Option<u64>,
+}
+
+impl RngTrace {
+    /// Takes a snapshot of `audit` after `tick`.
+    pub fn record(&mut self, tick: u64, audit: &RngAudit) {
+        self.snapshots.push(RngSnapshot { tick, draws: audit.counts() });
+    }
+    
+    /// The first snapshot, and within it the first label, where this trace
+    /// and `other` disagree (None if they agree). A snapshot one trace has
+    /// and the other lacks disagrees on its first label.
+    pub fn first_divergence(&self, other: &RngTrace) -> Option<RngDivergence> {
+        let empty = RngSnapshot::default();
+        (0..self.snapshots.len().max(other.snapshots.len())).find_map(|snapshot| {
+            let left = self.snapshots.get(snapshot);
+            let right = other.snapshots.get(snapshot);
+            let (l, r) = (left.unwrap_or(&empty), right.unwrap_or(&empty));
+            if left.is_some() && right.is_some() && l == r {
+                return None;
+            }
+            let label = l.draws.keys().chain(r.draws.keys())
+                .filter(|label| l.draws.get(*label) != r.draws.get(*label))
+                .min()
+                .or_else(|| l.draws.keys().chain(r.draws.keys()).min())?;
+            Some(RngDivergence {
+                snapshot,
+                tick: left.or(right).map_or(0, |s| s.tick),
+                label: label.clone(),
+                left: l.draws.get(label).copied(),
+                right: r.draws.get(label).copied(),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    
+    #[test]
+    fn test_streams_count_only_while_audited() {
+        let sequence = |rng: &mut AuditedRng| (0..3).map(|_| rng.gen()).collect::<Vec<u64>>();
+        let plain = sequence(&mut AuditedRng::derive(7, "a"));
+        
+        let audit = RngAudit::new();
+        {
+            let _guard = audit.install();
+            // Counting leaves the draws alone
+            assert_eq!(sequence(&mut AuditedRng::derive(7, "a")), plain);
+            let _: u64 = AuditedRng::derive(8, "b").gen();
+        }
+        assert!(RngAudit::installed().is_none());
+        let _: u64 = AuditedRng::derive(7, "a").gen();
+        assert_eq!(audit.counts(), BTreeMap::from([("a".to_string(), 3), ("b".to_string(), 1)]));
+        
+        // Names and seeds both pick the stream
+        let first = |seed, name| AuditedRng::derive(seed, name).gen::<u64>();
+        assert_ne!(first(7, "a"), first(7, "b"));
+        assert_ne!(first(7, "a"), first(8, "a"));
+    }
+    
+    #[test]
+    fn test_divergence_names_the_first_differing_snapshot_and_label() {
+        let snapshot = |tick, draws: &[(&str, u64)]| RngSnapshot {
+            tick,
+            draws: draws.iter().map(|(label, n)| (label.to_string(), *n)).collect(),
+        };
+        let left = RngTrace {
+            snapshots: vec![snapshot(0, &[("a", 1), ("b", 1)]), snapshot(1, &[("a", 2), ("b", 3)])],
+            ..RngTrace::default()
+        };
+        assert_eq!(left.first_divergence(&left), None);
+        
+        let mut right = left.clone();
+        right.snapshots[1] = snapshot(1, &[("a", 2), ("b", 4), ("c", 1)]);
+        assert_eq!(left.first_divergence(&right), Some(RngDivergence {
+            snapshot: 1,
+            tick: 1,
+            label: "b".to_string(),
+            left: Some(3),
+            right: Some(4),
+        }));
+        
+        // A run that stopped early diverges at its first missing snapshot
+        let mut short = left.clone();
+        short.snapshots.pop();
+        let divergence = left.first_divergence(&short).unwrap();
+        assert_eq!((divergence.snapshot, divergence.label.as_str(), divergence.right), (1, "a", None));
+    }
+}
@@ -1,18 +1,55 @@
 //! Scenario runner - executes chaos engineering test scenarios.
 
+use crate::calibration::{CalibratedThresholds, ThresholdSource};
+use crate::campaign::{Campaign, CampaignMetrics, CampaignPhase, CampaignResult};
+use crate::capture::{CaptureConfig, FrameRecorder, InvariantViolation};
+use crate::clock_sync::{ClockSkewStats, SkewSample, TimeAlignment};
 use crate::context::SimContext;
-use crate::keys::DeterministicKeyProvider;
-use crate::network::{SimNetwork, SimNetworkController};
-use crate::oracle::Oracle;
-use crate::scenarios::ScenarioId;
-use crate::agent::SimulatedAgent;
-
-use godview_core::AgentConfig;
-use godview_env::{GodViewContext, NodeId};
+use crate::corpus::RunOrigin;
+use crate::delta_gossip::CompressionStats;
+use crate::episodes::{episode_seed, EpisodeCurve, EpisodeMetrics, EpisodesResult, ResetPolicy, EPISODIC_SCENARIOS};
+use crate::failure::{first_failure, Bound, FailureKind};
+use crate::event_bus::SimEvent;
+use crate::exporter::SimExport;
+use crate::interrupt::Interrupt;
+use crate::faults::{swarm_coverage, AgentStatus, Fault, FaultRecovery, PerturbationImpact, PerturbationTracker, RecoveryTracker};
+use crate::evolution::{EpochRecord, EvoParams, FitnessProvider, GtWeightSchedule, ParamConvergence, CONVERGENCE_WINDOW_EPOCHS};
+use crate::trained::TrainedParams;
+use crate::keys::{DeterministicKeyProvider, KeyRegistry};
+use crate::metrics::MetricsRegistry;
+use crate::network::{DelayQueue, LatencyHistogram, LatencyModel, LinkFaultStats, SimNetwork, SimNetworkController};
+use crate::oracle::{DetectionModel, GroundTruthEntity, Oracle, SensorModality, SensorReading};
+use crate::observer::{AgentDivergence, DivergenceSampler};
+use crate::frustum::ObservationCoverage;
+use crate::occlusion::OcclusionCoverage;
+use crate::gossip_sequence::SequenceStats;
+use crate::gps_denial::{GpsDenial, GpsDenialSampler, GpsDenialStats, GPS_RECOVERY_FACTOR};
+use crate::spread::{LossSpread, SpreadSampler};
+use crate::progress::{AbortFn, ProgressFn, ProgressHooks, ProgressReport, RunMonitor};
+use crate::status::StatusSender;
+use crate::rng_audit::{AuditedRng, RngTrace};
+use crate::sharding::{HandoffMetrics, HandoffSampler, ShardMap, ShardStats};
+use crate::scenarios::{AgentCount, DurationClamp, ScenarioId};
+use crate::scoring::{ClutterMetrics, ClutterSampler, CoastingMetrics, CoastingSampler, IdChurn, MotMetrics, MotSampler, TrackMultiplicity};
+use crate::stats::{self, every_agent, swarm_convergence};
+use crate::simulation::{SimEventRequest, Simulation};
+use crate::world::{GossipConfig, SensorLinkFaults, SimConfig, Visibility};
+use crate::mobility::AgentMotion;
+use crate::harness::{AgentProfile, BadActorInjection, BadActorSchedule, BadActorStrategy, GarbageSpec, SensorFaults, SwarmHarness};
+use crate::adaptive::{GossipQuota, TrustInflation};
+use crate::agent::{GossipMode, GossipRejection, GossipRelay, SignedGossipStats, SimulatedAgent};
+use crate::swarm_network::{wire_bytes, DropPolicy, LinkConfig, LinkStats, SwarmConfig};
+use crate::parallel::{available_threads, AgentPool};
+use crate::priority::GossipOrder;
+use crate::visualizer::RerunLogger;
+
+use godview_core::{AgentConfig, FilterTuning, GlobalHazardPacket, LifecycleConfig, PacketVersion, RobustFusion, TrackLifecycle};
+use godview_env::{determinism, NodeId};
+use h3o::Resolution;
 use nalgebra::Vector3;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
-use rand::SeedableRng;
 use tracing::{info, warn, debug};
 use uuid::Uuid;
 
@@ -37,15 +74,349 @@ pub struct ScenarioResult {
     /// Number of active entities at end
     pub final_entity_count: usize,
     
-    /// Failure message if any
-    pub failure_reason: Option<String>,
+    /// What failed, if the run did
+    pub failure: Option<FailureKind>,
+    
+    /// Mean of the per-tick RMS position error samples taken after warm-up (m)
+    pub rms_mean: f64,
+    
+    /// 95th percentile of the post-warm-up RMS samples (m)
+    pub rms_p95: f64,
+    
+    /// Number of RMS samples scored (0 for scenarios that don't score RMS)
+    pub rms_samples: usize,
     
     /// Metrics collected during run
     pub metrics: ScenarioMetrics,
+    
+    /// Set when the requested duration was outside the scenario's bounds
+    pub duration_clamp: Option<DurationClamp>,
+    
+    /// Progress report that triggered an early abort (metrics are partial)
+    pub aborted: Option<ProgressReport>,
+    
+    /// Named counters, gauges, histograms and per-tick series from the run
+    pub registry: MetricsRegistry,
+    
+    /// Whether the (scenario, seed) pair was replayed from a seed corpus
+    pub origin: RunOrigin,
+    
+    /// Whether the run was judged by built-in or calibrated thresholds
+    pub thresholds: ThresholdSource,
+}
+
+impl ScenarioResult {
+    /// The result as reported by `--json` (and the Python bindings): the
+    /// headline numbers, the structured metrics and the registry snapshot.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "scenario": self.scenario.name(),
+            "seed": self.seed,
+            "origin": self.origin.label(),
+            "thresholds": self.thresholds,
+            "passed": self.passed,
+            "ticks": self.total_ticks,
+            "time_secs": self.final_time_secs,
+            "rms_mean": self.rms_mean,
+            "rms_p95": self.rms_p95,
+            "rms_samples": self.rms_samples,
+            "velocity_rms_mean": self.metrics.velocity_rms_mean,
+            "velocity_rms_p95": self.metrics.velocity_rms_p95,
+            "duration_clamp": self.duration_clamp.map(|c| c.to_string()),
+            "aborted_at_secs": self.aborted.map(|a| a.time_secs),
+            "fault_recoveries": self.metrics.fault_recoveries,
+            "perturbations": self.metrics.perturbations,
+            "param_convergence": self.metrics.param_convergence,
+            "clock_skew": self.metrics.clock_skew,
+            "occlusion": self.metrics.occlusion,
+            "clutter": self.metrics.clutter,
+            "baseline_clutter": self.metrics.baseline_clutter,
+            "partition_divergence": self.metrics.partition_divergence,
+            "profile_rms": self.metrics.profile_rms,
+            "priority_classes": self.metrics.priority_classes,
+            "learning_arms": self.metrics.learning_arms,
+            "bad_actor_injection": self.metrics.bad_actor_injection,
+            "handoff": self.metrics.handoff,
+            "shard_stats": self.metrics.shard_stats,
+            "mot": self.metrics.mot,
+            "id_churn": self.metrics.id_churn,
+            "track_multiplicity": self.metrics.track_multiplicity,
+            "coasting": self.metrics.coasting,
+            "scenario_metrics": self.metrics,
+            "metrics": self.registry.snapshot(),
+            "failure_reason": self.failure_reason(),
+            "failure": self.failure,
+        })
+    }
+    
+    /// Failure message derived from [`Self::failure`].
+    pub fn failure_reason(&self) -> Option<String> {
+        self.failure.as_ref().map(FailureKind::to_string)
+    }
+    
+    /// Fails the result with `kind`, replacing any earlier failure.
+    pub fn fail(&mut self, kind: FailureKind) {
+        self.passed = false;
+        self.failure = Some(kind);
+    }
+    
+    /// A failed result for a run that returned `error` instead of one,
+    /// so a sweep can report it alongside the runs that did complete.
+    pub fn errored(scenario: ScenarioId, seed: u64, error: &ScenarioError) -> Self {
+        ScenarioResult {
+            scenario,
+            seed,
+            passed: false,
+            total_ticks: 0,
+            final_time_secs: 0.0,
+            final_entity_count: 0,
+            failure: Some(FailureKind::RunError { details: error.to_string() }),
+            rms_mean: 0.0,
+            rms_p95: 0.0,
+            rms_samples: 0,
+            metrics: ScenarioMetrics::default(),
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// Marks the result as failed by an abort at `report`, keeping the
+    /// metrics gathered up to that point.
+    pub fn mark_aborted(&mut self, report: ProgressReport) {
+        self.total_ticks = report.tick;
+        self.final_time_secs = report.time_secs;
+        self.fail(FailureKind::Aborted {
+            tick: report.tick,
+            target_ticks: report.target_ticks,
+            time_secs: report.time_secs,
+            rms: report.rms,
+        });
+        self.aborted = Some(report);
+    }
+    
+    /// Fails the result if its RMS is NaN or infinite, whatever the scenario
+    /// concluded: a NaN compares false against any threshold, so it would
+    /// otherwise pass or fail depending on which way the check was written.
+    ///
+    /// Returns true if the result was failed.
+    pub fn fail_on_non_finite_rms(&mut self) -> bool {
+        if self.rms_mean.is_finite() && self.rms_p95.is_finite() {
+            return false;
+        }
+        self.fail(FailureKind::NumericalInstability {
+            faults: self.metrics.numerical_faults,
+            rms_mean: self.rms_mean,
+            rms_p95: self.rms_p95,
+        });
+        true
+    }
+    
+    /// Fails the result if any agent's peak memory estimate exceeded
+    /// `budget_bytes`. Returns true if the result was failed.
+    pub fn fail_over_memory_budget(&mut self, budget_bytes: u64) -> bool {
+        let peak = self.metrics.peak_agent_memory_bytes;
+        if peak <= budget_bytes {
+            return false;
+        }
+        self.fail(FailureKind::budget("agent_memory_mib", peak as f64 / MIB, budget_bytes as f64 / MIB, Bound::Max));
+        true
+    }
+    
+    /// Copies the headline numbers and [`ScenarioMetrics`] counters into
+    /// [`Self::registry`].
+    pub fn record_final_metrics(&mut self) {
+        let m = &self.metrics;
+        let counters = [
+            ("packets_sent", m.packets_sent),
+            ("packets_dropped", m.packets_dropped),
+            ("bytes_sent", m.bytes_sent),
+            ("bytes_dropped", m.bytes_dropped),
+            ("oosm_updates", m.oosm_updates),
+            ("ghost_detections", m.ghost_detections),
+            ("key_rotations", m.key_rotations),
+            ("stale_key_rejections", m.stale_key_rejections),
+            ("link_enqueued", m.link_enqueued),
+            ("link_delivered", m.link_delivered),
+            ("link_dropped_overflow", m.link_dropped_overflow),
+            ("link_preempted", m.link_preempted),
+            ("reordered_deliveries", m.reordered_deliveries),
+            ("duplicated_deliveries", m.duplicated_deliveries),
+            ("digest_packets", m.digest_packets),
+            ("gossip_gaps_detected", m.gossip_gaps_detected),
+            ("gossip_gaps_repaired", m.gossip_gaps_repaired),
+            ("track_evictions", m.track_evictions),
+            ("wide_associations", m.wide_associations),
+            ("numerical_faults", m.numerical_faults),
+            ("index_repairs", m.index_repairs),
+            ("downgraded_packets", m.downgraded_packets),
+            ("peak_agent_memory_bytes", m.peak_agent_memory_bytes),
+            ("peak_total_memory_bytes", m.peak_total_memory_bytes),
+            ("gossip_received_total", m.gossip_received_total),
+            ("tracks_final_total", m.tracks_final_total),
+            ("bad_actors_detected", m.bad_actors_detected),
+            ("link_changes", m.link_changes),
+            ("ticks", self.total_ticks),
+        ];
+        for (name, value) in counters {
+            self.registry.inc(name, value);
+        }
+        
+        let registry = &mut self.registry;
+        registry.set_gauge("passed", if self.passed { 1.0 } else { 0.0 });
+        registry.set_gauge("rms_mean", self.rms_mean);
+        registry.set_gauge("rms_p95", self.rms_p95);
+        registry.set_gauge("final_entity_count", self.final_entity_count as f64);
+        registry.set_gauge("max_latency_ms", m.max_latency_ms as f64);
+        registry.set_gauge("latency_p50_ms", m.latency_p50_ms as f64);
+        registry.set_gauge("latency_p95_ms", m.latency_p95_ms as f64);
+        registry.set_gauge("link_latency_p50_ms", m.link_latency_p50_ms as f64);
+        registry.set_gauge("link_latency_p95_ms", m.link_latency_p95_ms as f64);
+        registry.set_gauge("link_latency_p99_ms", m.link_latency_p99_ms as f64);
+        registry.set_gauge("link_latency_max_ms", m.link_latency_max_ms as f64);
+        if let Some(rms) = m.observer_rms {
+            registry.set_gauge("observer_rms", rms);
+        }
+        if let Some(rms) = m.velocity_rms_mean {
+            registry.set_gauge("velocity_rms_mean", rms);
+        }
+        if let Some(rms) = m.velocity_rms_p95 {
+            registry.set_gauge("velocity_rms_p95", rms);
+        }
+        if let Some(secs) = m.convergence_time_secs {
+            registry.set_gauge("convergence_time_secs", secs);
+        }
+        if let Some(secs) = m.push_convergence_time_secs {
+            registry.set_gauge("push_convergence_time_secs", secs);
+        }
+        if let Some(d) = &m.partition_divergence {
+            registry.set_gauge("peak_partition_divergence_m", d.peak_divergence_m);
+            if let Some(at_heal) = d.divergence_at_heal_m {
+                registry.set_gauge("divergence_at_heal_m", at_heal);
+            }
+            if let Some(secs) = d.reconverge_secs {
+                registry.set_gauge("reconverge_secs", secs);
+            }
+            registry.set_gauge("peak_canonical_ids", d.peak_canonical_ids as f64);
+        }
+        if let Some(c) = &m.param_convergence {
+            registry.set_gauge("param_variance", c.mean_variance);
+        }
+        if let Some(mot) = &m.mot {
+            registry.set_gauge("mota", mot.mota());
+            registry.set_gauge("motp", mot.motp());
+            registry.inc("mot_misses", mot.misses);
+            registry.inc("mot_false_positives", mot.false_positives);
+            registry.inc("mot_id_switches", mot.id_switches);
+        }
+        if let Some(churn) = &m.id_churn {
+            registry.set_gauge("id_churn_mean", churn.mean);
+            registry.set_gauge("id_churn_max", churn.max as f64);
+        }
+        if let Some(multiplicity) = &m.track_multiplicity {
+            registry.set_gauge("duplicated_entity_rate", multiplicity.duplicated_rate());
+            registry.inc("duplicate_tracks", multiplicity.extra_tracks);
+        }
+        if let Some(coasting) = &m.coasting {
+            registry.set_gauge("rms_updated", coasting.updated_rms);
+            registry.set_gauge("rms_coasting", coasting.coasting_rms);
+            registry.set_gauge("coasting_error_growth", coasting.error_growth_m2_per_s);
+        }
+        if let Some(rms) = m.v1_rms {
+            registry.set_gauge("rms_v1", rms);
+        }
+        if let Some(rms) = m.v2_rms {
+            registry.set_gauge("rms_v2", rms);
+        }
+        if let Some(skew) = &m.clock_skew {
+            registry.set_gauge("rms_per_100ms_skew", skew.rms_per_100ms);
+            registry.set_gauge("clock_offset_error_ms", skew.offset_error_ms);
+        }
+        if let Some(coverage) = &m.occlusion {
+            registry.set_gauge("direct_coverage", coverage.direct_coverage);
+            registry.set_gauge("fused_coverage", coverage.fused_coverage);
+            registry.set_gauge("hidden_coverage", coverage.hidden_coverage);
+            registry.set_gauge("min_hidden_coverage", coverage.min_hidden_coverage);
+        }
+        if let Some(coverage) = &m.observation_coverage {
+            registry.set_gauge("observation_coverage_mean", coverage.mean_fraction);
+            registry.set_gauge("observation_coverage_min", coverage.min_fraction);
+        }
+        if let Some(clutter) = &m.clutter {
+            registry.set_gauge("clutter_suppression", clutter.suppression());
+            registry.set_gauge("clutter_false_track_rate", clutter.false_track_rate());
+            registry.inc("clutter_readings", clutter.clutter_readings);
+        }
+        if let Some(baseline) = &m.baseline_clutter {
+            registry.set_gauge("baseline_false_track_rate", baseline.false_track_rate());
+        }
+        if let Some(denial) = &m.gps_denial {
+            registry.set_gauge("rms_gps_denied", denial.rms_during);
+            registry.set_gauge("peak_pose_error_m", denial.peak_pose_error_m);
+            registry.set_gauge("offset_detection_rate", denial.detection_rate());
+            if let Some(secs) = denial.recovery_secs {
+                registry.set_gauge("gps_recovery_secs", secs);
+            }
+        }
+        for spread in &m.loss_spread {
+            let pct = (spread.loss_rate * 100.0).round();
+            if let Some(secs) = spread.mean_spread_secs {
+                registry.set_gauge(&format!("spread_secs_loss_{pct}"), secs);
+            }
+            if let Some(secs) = spread.max_spread_secs {
+                registry.set_gauge(&format!("max_spread_secs_loss_{pct}"), secs);
+            }
+        }
+        for group in &m.profile_rms {
+            registry.set_gauge(&format!("rms_{}", group.profile), group.rms_mean);
+        }
+        for (i, impact) in m.perturbations.iter().enumerate() {
+            registry.set_gauge(&format!("rms_delta_perturbation_{i}"), impact.rms_delta());
+            registry.set_gauge(&format!("track_delta_perturbation_{i}"), impact.track_delta());
+        }
+        for class in &m.priority_classes {
+            registry.set_gauge(&format!("rms_priority_{}", class.class), class.rms_mean);
+            registry.set_gauge(&format!("coverage_priority_{}", class.class), class.coverage);
+        }
+        for arm in &m.learning_arms {
+            registry.set_gauge(&format!("rms_learning_{}", arm.fitness), arm.rms_mean);
+            if let Some(epochs) = arm.epochs_to_convergence {
+                registry.set_gauge(&format!("epochs_to_convergence_{}", arm.fitness), epochs as f64);
+            }
+        }
+        if let Some(compression) = &m.gossip_compression {
+            registry.set_gauge("gossip_compression_ratio", compression.ratio());
+            registry.inc("delta_frames", compression.delta_frames);
+            registry.inc("full_frame_requests", compression.full_requests);
+        }
+        if let Some(handoff) = &m.handoff {
+            registry.set_gauge("handoff_success_rate", handoff.success_rate());
+            registry.set_gauge("handoff_duplicate_rate", handoff.duplicate_rate());
+            registry.set_gauge("handoff_gap_mean_secs", handoff.gap_mean_secs);
+            registry.set_gauge("handoff_gap_max_secs", handoff.gap_max_secs);
+            registry.inc("handoff_crossings", handoff.crossings);
+        }
+        if let Some(shards) = &m.shard_stats {
+            registry.inc("handoffs_sent", shards.handoffs_sent);
+            registry.inc("shard_tracks_dropped", shards.tracks_dropped);
+        }
+        if let Some(injection) = &m.bad_actor_injection {
+            registry.set_gauge("bad_actors", injection.ids.len() as f64);
+            registry.set_gauge("bad_actor_inject_secs", injection.time_secs);
+        }
+        for d in m.observer_divergence.iter().filter(|d| d.compared > 0) {
+            registry.set_agent_gauge("divergence_rms", d.agent_id, d.position_rms);
+        }
+        for (name, value) in &m.extra {
+            registry.set_gauge(name, *value);
+        }
+    }
 }
 
 /// Metrics collected during scenario execution.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct ScenarioMetrics {
     /// Total packets sent
     pub packets_sent: u64,
@@ -53,760 +424,4086 @@ pub struct ScenarioMetrics {
     /// Packets dropped due to partition
     pub packets_dropped: u64,
     
-    /// Maximum observed latency (ms)
+    /// Wire bytes of the packets sent (see [`wire_bytes`])
+    pub bytes_sent: u64,
+    
+    /// Wire bytes of the packets dropped
+    pub bytes_dropped: u64,
+    
+    /// Maximum observed gossip envelope latency (ms)
     pub max_latency_ms: u64,
     
+    /// Median gossip envelope latency over all agents (ms)
+    pub latency_p50_ms: u64,
+    
+    /// 95th percentile gossip envelope latency over all agents (ms)
+    pub latency_p95_ms: u64,
+    
     /// OOSM updates processed
     pub oosm_updates: u64,
     
     /// Ghost tracks detected
     pub ghost_detections: u64,
-}
-
-/// Runs chaos scenarios.
-pub struct ScenarioRunner {
-    /// Configuration seed
-    seed: u64,
     
-    /// Number of agents
-    num_agents: usize,
+    /// Agent key rotations performed
+    pub key_rotations: u64,
     
-    /// Tick rate in Hz
-    tick_rate_hz: u32,
+    /// Packets rejected because they were signed with a rotated-out key
+    pub stale_key_rejections: u64,
+    
+    /// Packets accepted into bandwidth-limited link queues
+    pub link_enqueued: u64,
+    
+    /// Packets delivered over bandwidth-limited links
+    pub link_delivered: u64,
+    
+    /// Packets dropped by congested link queues
+    pub link_dropped_overflow: u64,
+    
+    /// Queued packets evicted from full link queues by high-priority ones
+    pub link_preempted: u64,
+    
+    /// Highlander merges per simulated minute, averaged over recording agents
+    pub merges_per_minute: f64,
+    
+    /// Live tracks per ground-truth entity, averaged over recording agents
+    pub avg_tracks_per_entity: f64,
+    
+    /// Messages delivered behind later ones by injected reordering
+    pub reordered_deliveries: u64,
+    
+    /// Extra copies delivered by injected duplication
+    pub duplicated_deliveries: u64,
+    
+    /// Median latency drawn for messages on links with a latency model (ms)
+    pub link_latency_p50_ms: u64,
+    
+    /// 95th percentile link latency drawn (ms)
+    pub link_latency_p95_ms: u64,
+    
+    /// 99th percentile link latency drawn (ms)
+    pub link_latency_p99_ms: u64,
+    
+    /// Longest link latency drawn (ms)
+    pub link_latency_max_ms: u64,
+    
+    /// Seconds from partition heal until all agents agreed (None if they never did)
+    pub convergence_time_secs: Option<f64>,
+    
+    /// Mean of the per-tick RMS velocity error samples taken after warm-up
+    /// (m/s; None if not scored)
+    pub velocity_rms_mean: Option<f64>,
+    
+    /// 95th percentile of the post-warm-up velocity RMS samples (m/s; None
+    /// if not scored)
+    pub velocity_rms_p95: Option<f64>,
+    
+    /// Divergence between the partitioned groups (SplitBrain only)
+    pub partition_divergence: Option<PartitionDivergence>,
+    
+    /// Seconds from partition heal until all agents agreed with push gossip
+    /// alone (SplitBrain's baseline; None if they never did)
+    pub push_convergence_time_secs: Option<f64>,
+    
+    /// Packets sent in answer to anti-entropy digests
+    pub digest_packets: u64,
+    
+    /// Gossip envelope sequences receivers found missing, summed over agents
+    pub gossip_gaps_detected: u64,
+    
+    /// Of those, sequences that arrived after all, reordered or resent
+    pub gossip_gaps_repaired: u64,
+    
+    /// Tracks evicted by capacity-bounded agents, summed over agents
+    pub track_evictions: u64,
+    
+    /// Associations found beyond the k=1 H3 ring, summed over agents
+    pub wide_associations: u64,
+    
+    /// Mean RMS error of the all-seeing observer against ground truth
+    /// (sensor-limited error; None without an observer)
+    pub observer_rms: Option<f64>,
+    
+    /// Per-agent divergence from the observer (communication-limited error)
+    pub observer_divergence: Vec<AgentDivergence>,
+    
+    /// Coverage recovery after each injected agent fault, in plan order
+    pub fault_recoveries: Vec<FaultRecovery>,
+    
+    /// RMS and track counts around each tracking-config perturbation, in
+    /// plan order
+    pub perturbations: Vec<PerturbationImpact>,
+    
+    /// How settled evolving agents' parameters were at the end of the run
+    /// (None if no agent ran enough epochs)
+    pub param_convergence: Option<ParamConvergence>,
+    
+    /// RMS degradation under clock skew and how well offsets were estimated
+    /// (ClockSkew only)
+    pub clock_skew: Option<ClockSkewStats>,
+    
+    /// How much of what each agent couldn't see gossip filled in
+    /// (Occlusion only)
+    pub occlusion: Option<OcclusionCoverage>,
+    
+    /// Fraction of ticks each entity was read by at least one placed
+    /// sensor (Occlusion only)
+    pub observation_coverage: Option<ObservationCoverage>,
+    
+    /// Clutter tracks of the agents confirming M-of-N (Clutter only)
+    pub clutter: Option<ClutterMetrics>,
+    
+    /// Clutter tracks of the agents confirming every track (Clutter only)
+    pub baseline_clutter: Option<ClutterMetrics>,
+    
+    /// RMS around the GPS denial window and how well neighbors spotted the
+    /// denied agents (GpsDenied only)
+    pub gps_denial: Option<GpsDenialStats>,
+    
+    /// How fast entities reached every agent at each gossip loss rate, in
+    /// the order run (SlowLoris only)
+    pub loss_spread: Vec<LossSpread>,
+    
+    /// How tracks followed entities across shard edges (BoundaryCrossing
+    /// only)
+    pub handoff: Option<HandoffMetrics>,
+    
+    /// Handoffs sent and readings and tracks dropped by a sharded swarm
+    /// (BoundaryCrossing only)
+    pub shard_stats: Option<ShardStats>,
+    
+    /// The bad actors drawn mid-run and the tick they turned on
+    /// (AdaptiveSwarm only)
+    pub bad_actor_injection: Option<BadActorInjection>,
+    
+    /// CLEAR MOT counts over all agents after warm-up (None if not scored)
+    pub mot: Option<MotMetrics>,
+    
+    /// Canonical ID changes of each entity's track after warm-up, as each
+    /// agent saw it (None if not MOT-scored)
+    pub id_churn: Option<IdChurn>,
+    
+    /// Tracks per entity over all agents after warm-up, from ground truth
+    /// (debug only; None if not MOT-scored)
+    pub track_multiplicity: Option<TrackMultiplicity>,
+    
+    /// Error of updated and coasting track states after warm-up, scored
+    /// apart (None if not scored)
+    pub coasting: Option<CoastingMetrics>,
     
-    /// Maximum duration in seconds
-    max_duration_secs: f64,
+    /// Tracks dropped and packets refused for NaN or indefinite covariances,
+    /// summed over observed agents
+    pub numerical_faults: u64,
+    
+    /// Periodic verifications that found an agent's spatial index out of
+    /// sync with its tracks and rebuilt it, summed over observed agents
+    pub index_repairs: u64,
+    
+    /// Largest memory estimate of any one agent over the run (bytes)
+    pub peak_agent_memory_bytes: u64,
+    
+    /// Largest memory estimate summed over agents over the run (bytes)
+    pub peak_total_memory_bytes: u64,
+    
+    /// Packets decoded from a newer wire version than the receiver speaks,
+    /// summed over agents
+    pub downgraded_packets: u64,
+    
+    /// Mean RMS error of the agents speaking wire V1 (ProtocolDrift only)
+    pub v1_rms: Option<f64>,
+    
+    /// Mean RMS error of the agents speaking wire V2 (ProtocolDrift only)
+    pub v2_rms: Option<f64>,
+    
+    /// RMS error of each agent profile group (empty unless the scenario
+    /// mixes profiles)
+    pub profile_rms: Vec<ProfileRms>,
+    
+    /// RMS error and coverage of high-priority and routine entities (empty
+    /// unless the scenario spawns prioritized entities)
+    pub priority_classes: Vec<PriorityClassMetrics>,
+    
+    /// Convergence of each fitness regime HybridLearning compares (empty
+    /// for other scenarios)
+    pub learning_arms: Vec<LearningArmMetrics>,
+    
+    /// Final evolved parameters of agent `i` at index `i` (empty unless the
+    /// scenario evolves its agents)
+    pub evolved_params: Vec<EvoParams>,
+    
+    /// Gossip packets received, summed over agents at the end of the run
+    pub gossip_received_total: u64,
+    
+    /// Live tracks summed over agents at the end of the run
+    pub tracks_final_total: u64,
+    
+    /// Bad actors good agents' reputation scores identified, counted per
+    /// neighboring pair (AdaptiveSwarm and ZombieApocalypse)
+    pub bad_actors_detected: u64,
+    
+    /// Radio links made or broken as agents moved, counted per direction
+    /// (Convoy only)
+    pub link_changes: u64,
+    
+    /// Frames sent and bytes saved by delta-encoded gossip (None unless
+    /// the run compressed its gossip)
+    pub gossip_compression: Option<CompressionStats>,
+    
+    /// Post-warm-up mean RMS error (m), as in [`ScenarioResult::rms_mean`]
+    pub rms_mean: f64,
+    
+    /// Scenario-specific values without a field of their own, flattened
+    /// into the serialized metrics
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, f64>,
 }
 
-impl ScenarioRunner {
-    /// Creates a new scenario runner.
-    pub fn new(seed: u64, num_agents: usize) -> Self {
-        Self {
-            seed,
-            num_agents,
-            tick_rate_hz: 30,
-            max_duration_secs: 60.0,
-        }
+impl ScenarioMetrics {
+    /// Copies aggregate link counters from a bandwidth-limited network.
+    fn record_link_stats(&mut self, stats: LinkStats) {
+        self.link_enqueued = stats.enqueued;
+        self.link_delivered = stats.delivered;
+        self.link_dropped_overflow = stats.dropped_overflow;
+        self.link_preempted = stats.preempted;
     }
     
-    /// Sets the tick rate.
-    pub fn with_tick_rate(mut self, hz: u32) -> Self {
-        self.tick_rate_hz = hz;
-        self
+    /// Sets a scenario-specific value in [`Self::extra`].
+    fn set_extra(&mut self, name: &str, value: f64) {
+        self.extra.insert(name.to_string(), value);
     }
     
-    /// Sets the maximum duration.
-    pub fn with_duration(mut self, secs: f64) -> Self {
-        self.max_duration_secs = secs;
-        self
+    /// Copies reorder/duplicate counters from a network controller.
+    fn record_link_faults(&mut self, stats: LinkFaultStats) {
+        self.reordered_deliveries = stats.reordered;
+        self.duplicated_deliveries = stats.duplicated;
     }
     
-    /// Runs a scenario and returns the result.
-    pub fn run(&self, scenario: ScenarioId) -> ScenarioResult {
-        info!("Starting scenario: {} (seed={})", scenario.name(), self.seed);
-        
-        if scenario.is_extreme() {
-            warn!("🔥 EXTREME SCENARIO - Pushing to the limit!");
-        }
-        
-        match scenario {
-            ScenarioId::TimeWarp => self.run_time_warp(),
-            ScenarioId::SplitBrain => self.run_split_brain(),
-            ScenarioId::Byzantine => self.run_byzantine(),
-            ScenarioId::FlashMob => self.run_flash_mob(),
-            ScenarioId::SlowLoris => self.run_slow_loris(),
-            ScenarioId::Swarm => self.run_swarm(),
-            ScenarioId::AdaptiveSwarm => self.run_adaptive_swarm(),
-            // Extreme scenarios
-            ScenarioId::ChaosStorm => self.run_chaos_storm(),
-            ScenarioId::ScaleLimit => self.run_scale_limit(),
-            ScenarioId::NetworkHell => self.run_network_hell(),
-            ScenarioId::TimeTornado => self.run_time_tornado(),
-            ScenarioId::ZombieApocalypse => self.run_zombie_apocalypse(),
-            ScenarioId::RapidFire => self.run_rapid_fire(),
-            // Evolutionary
-            ScenarioId::EvoWar => self.run_evo_war(),
-            ScenarioId::ResourceStarvation => self.run_resource_starvation(),
-            ScenarioId::ProtocolDrift => self.run_protocol_drift(),
-            ScenarioId::BlindLearning => self.run_blind_learning(),
-            ScenarioId::BlackoutSurvival => self.run_blackout_survival(),
-            ScenarioId::LongHaul => self.run_long_haul(),
-            ScenarioId::CommonBias => self.run_common_bias(),
-            ScenarioId::HeavyTail => self.run_heavy_tail(),
-            ScenarioId::SensorDrift => self.run_sensor_drift(),
+    /// Copies the percentiles of the link latencies a controller drew.
+    fn record_link_latency(&mut self, latency: &LatencyHistogram) {
+        self.link_latency_p50_ms = latency.p50();
+        self.link_latency_p95_ms = latency.p95();
+        self.link_latency_p99_ms = latency.p99();
+        self.link_latency_max_ms = latency.max();
+    }
+    
+    /// Merges agents' gossip envelope latency histograms.
+    fn record_latency<'a>(&mut self, agents: impl IntoIterator<Item = &'a SimulatedAgent>) {
+        let mut latency = LatencyHistogram::default();
+        for agent in agents {
+            latency.merge(&agent.network_stats().latency);
         }
+        self.max_latency_ms = latency.max();
+        self.latency_p50_ms = latency.p50();
+        self.latency_p95_ms = latency.p95();
     }
     
-    /// DST-001: TimeWarp - OOSM stress test with extreme jitter.
-    ///
-    /// Tests the Time Engine's ability to handle out-of-sequence measurements
-    /// with 0-500ms jitter and 20% packet reordering.
-    ///
-    /// **Enhanced**: Now processes through full SimulatedAgent → TrackManager pipeline.
-    /// **Assertion**: Track position error < 5m RMS vs ground truth.
-    fn run_time_warp(&self) -> ScenarioResult {
-        info!("DST-001: TimeWarp - OOSM stress test");
-        
-        // Setup
-        let context_seed = self.seed;
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
-        
-        let context = Arc::new(SimContext::new(context_seed));
-        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
-        let key_provider = DeterministicKeyProvider::new(self.seed);
-        let root_key = key_provider.biscuit_root_key().public();
-        
-        let mut oracle = Oracle::new(physics_seed);
-        
-        // Create a SimulatedAgent
-        let mut agent = SimulatedAgent::new(
-            context.clone(),
-            network,
-            root_key,
-            0,
-            AgentConfig::default(),
-        );
-        
-        // Spawn 10 fast-moving entities
-        for i in 0..10 {
-            let pos = Vector3::new(
-                (i as f64) * 100.0,
-                0.0,
-                100.0 + (i as f64) * 10.0,
-            );
-            let vel = Vector3::new(50.0, 10.0 * (i as f64 - 5.0), 0.0);
-            oracle.spawn_entity(pos, vel, "drone");
+    /// Copies gossip sequence gap counters.
+    fn record_sequence_gaps(&mut self, gaps: SequenceStats) {
+        self.gossip_gaps_detected = gaps.gaps_detected;
+        self.gossip_gaps_repaired = gaps.gaps_repaired;
+    }
+    
+    /// Sums capacity evictions over agents.
+    fn record_evictions(&mut self, agents: &[SimulatedAgent]) {
+        self.track_evictions = agents.iter().map(|a| a.track_evictions()).sum();
+    }
+    
+    /// Copies MOT counts from a sampler and logs them.
+    fn record_mot(&mut self, sampler: &MotSampler) {
+        let mot = sampler.metrics();
+        self.ghost_detections = mot.false_positives;
+        info!("  MOT: MOTA {:.3}, MOTP {:.2}m ({} misses, {} false positives, {} ID switches over {} frames)",
+            mot.mota(), mot.motp(), mot.misses, mot.false_positives, mot.id_switches, mot.frames);
+        let churn = sampler.id_churn();
+        info!("  ID churn: {:.2} canonical ID changes per tracked entity (max {})", churn.mean, churn.max);
+        let multiplicity = sampler.multiplicity();
+        info!("  Track multiplicity: {:.1}% of entity frames had duplicate tracks ({} extra, max {} per entity)",
+            multiplicity.duplicated_rate() * 100.0, multiplicity.extra_tracks, multiplicity.max);
+        self.mot = Some(mot);
+        self.id_churn = Some(churn);
+        self.track_multiplicity = Some(multiplicity);
+    }
+    
+    /// Copies observer error and per-agent divergence from a sampler.
+    fn record_divergence(&mut self, sampler: &DivergenceSampler) {
+        self.observer_rms = Some(sampler.observer_rms());
+        self.observer_divergence = sampler.divergence();
+    }
+    
+    /// Measures parameter convergence over evolving agents and logs it.
+    fn record_convergence<'a>(&mut self, agents: impl IntoIterator<Item = &'a SimulatedAgent>) {
+        self.param_convergence = ParamConvergence::measure(agents.into_iter().map(|a| a.evolution_history()));
+        match &self.param_convergence {
+            Some(c) => info!("    Convergence:   {}/{} agents, variance {:.2} (last {} epochs)  {}",
+                c.converged_agents, c.agents, c.mean_variance, CONVERGENCE_WINDOW_EPOCHS,
+                if c.converged() { "✓" } else { "✗" }),
+            None => info!("    Convergence:   n/a (fewer than {} epochs)", CONVERGENCE_WINDOW_EPOCHS),
         }
-        
-        let mut metrics = ScenarioMetrics::default();
-        let dt = 1.0 / self.tick_rate_hz as f64;
-        let target_ticks = (self.max_duration_secs * self.tick_rate_hz as f64) as u64;
-        
-        // Run simulation
-        for tick in 0..target_ticks {
-            // Advance physics
-            oracle.step(dt);
-            context.advance_time(Duration::from_secs_f64(dt));
-            
-            // Agent tick (prediction step)
-            agent.tick();
-            
-            // Generate sensor readings and ingest through agent
-            let readings = oracle.generate_sensor_readings();
-            metrics.oosm_updates += readings.len() as u64;
-            
-            // Process readings through full pipeline
-            agent.ingest_readings(&readings);
-            
-            // Progress log every 30 ticks (1 second)
-            if tick % 30 == 0 {
-                debug!("  t={:.1}s | entities={} | tracks={}", 
-                    oracle.time(), 
-                    oracle.active_entities().len(),
-                    agent.track_count()
-                );
-            }
+    }
+    
+    /// Records every agent's final evolved parameters, in agent order.
+    fn record_evolved_params<'a>(&mut self, agents: impl IntoIterator<Item = &'a SimulatedAgent>) {
+        self.evolved_params = agents.into_iter().map(|a| a.evolutionary_state().current_params).collect();
+    }
+    
+    /// Mean divergence over agents as (position RMS, ID disagreement rate).
+    pub fn mean_divergence(&self) -> (f64, f64) {
+        let n = self.observer_divergence.len().max(1) as f64;
+        let (position, ids) = self.observer_divergence.iter()
+            .fold((0.0, 0.0), |(p, i), d| (p + d.position_rms, i + d.id_disagreement_rate));
+        (position / n, ids / n)
+    }
+    
+    /// Aggregates genealogy from agents that recorded it.
+    fn record_genealogy(&mut self, agents: &[SimulatedAgent], elapsed_secs: f64, num_entities: usize) {
+        let recorders: Vec<_> = agents.iter()
+            .filter_map(|a| a.genealogy_recorder().map(|g| (a, g)))
+            .collect();
+        if recorders.is_empty() || elapsed_secs <= 0.0 {
+            return;
         }
         
-        // Compute position error against ground truth
-        let ground_truth = oracle.ground_truth_positions();
-        let rms_error = agent.compute_position_error(&ground_truth);
-        
-        // Assertion: RMS error should be < 5m (generous for OOSM stress)
-        let max_acceptable_error = 5.0;
-        let passed = rms_error < max_acceptable_error;
-        
-        info!("✓ TimeWarp complete: {} OOSM updates, {} tracks, RMS error: {:.2}m", 
-            metrics.oosm_updates, agent.track_count(), rms_error);
+        let n = recorders.len() as f64;
+        let merges: usize = recorders.iter().map(|(_, g)| g.merge_count()).sum();
+        self.merges_per_minute = merges as f64 / n / (elapsed_secs / 60.0);
         
-        ScenarioResult {
-            scenario: ScenarioId::TimeWarp,
-            seed: self.seed,
-            passed,
-            total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed {
-                Some(format!("RMS error {:.2}m exceeds threshold {:.1}m", rms_error, max_acceptable_error))
-            } else {
-                None
-            },
-            metrics,
+        if num_entities > 0 {
+            let tracks: usize = recorders.iter().map(|(a, _)| a.track_count()).sum();
+            self.avg_tracks_per_entity = tracks as f64 / n / num_entities as f64;
         }
     }
-    
-    /// DST-002: SplitBrain - Network partition and CRDT convergence.
-    ///
-    /// Tests that agents converge to consistent state after a network partition heals.
-    fn run_split_brain(&self) -> ScenarioResult {
-        info!("DST-002: SplitBrain - Network partition test");
-        
-        let context_seed = self.seed;
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
-        
-        let context = SimContext::shared(context_seed);
-        let mut oracle = Oracle::new(physics_seed);
-        let network_controller = SimNetworkController::new();
-        
-        // Spawn entity that will be observed by both partitions
-        let _entity_id = oracle.spawn_entity(
+}
+
+/// Bytes per mebibyte, for memory reporting.
+const MIB: f64 = 1024.0 * 1024.0;
+
+/// Default warm-up window excluded from RMS scoring, in simulated seconds.
+pub const DEFAULT_WARMUP_SECS: f64 = 2.0;
+
+/// The p95 RMS may exceed a scenario's mean threshold by at most this factor.
+const RMS_P95_FACTOR: f64 = 2.0;
+
+/// Share of possible bad-actor detections AdaptiveSwarm and ZombieApocalypse
+/// must make. With gossip credited to its real sender both identify every
+/// bad actor across seeds 1-3 and 42.
+const MIN_DETECTION_RATE: f64 = 0.8;
+
+/// TimeWarp's mean velocity RMS threshold (m/s).
+const TIME_WARP_MAX_VELOCITY_RMS: f64 = 1.0;
+
+/// Largest distance SplitBrain's groups may drift apart on the shared
+/// entity while partitioned (m). Both see the same slow entity, so more
+/// than a few sensor sigmas means a filter ran away; seeds 1-3 and 42 peak
+/// near 1.2m.
+const MAX_PARTITION_DIVERGENCE_M: f64 = 5.0;
+
+/// Ticks between each SplitBrain agent's anti-entropy digests: every
+/// gossip round at 30Hz.
+const SPLIT_BRAIN_DIGEST_INTERVAL_TICKS: u64 = 5;
+
+/// How many times faster than push gossip alone SplitBrain's agents must
+/// converge after the heal with anti-entropy digests.
+const SPLIT_BRAIN_MIN_SPEEDUP: f64 = 2.0;
+
+/// How long after the heal SplitBrain's winning group stays blind (s).
+/// Push gossip has to wait for its readings to carry the minimum UUID
+/// across; anti-entropy digests don't.
+const SPLIT_BRAIN_BLIND_SECS: f64 = 1.5;
+
+/// Per-neighbor gossip quota ZombieApocalypse's survivors defend with: one
+/// round of a neighbor's packets (each agent senses ~100 entities a tick and
+/// gossips every 5 ticks at 10Hz), refilled every round.
+const ZOMBIE_GOSSIP_QUOTA: GossipQuota = GossipQuota { capacity: 500.0, refill_per_sec: 1000.0 };
+
+/// Ticks between spatial index verifications in the scenarios whose
+/// quarantines rekey tracks (AdaptiveSwarm, ZombieApocalypse): every 5s at
+/// 10Hz, O(tracks) per agent each time.
+const INDEX_VERIFY_INTERVAL_TICKS: u64 = 50;
+
+/// HybridLearning's hybrid agents see ground truth on one epoch in this
+/// many, starting with the first.
+const HYBRID_LANDMARK_EVERY_EPOCHS: u64 = 3;
+
+/// TimeTornado matches tracks to its target this far out (m): readings up
+/// to 5s late put updated tracks tens of meters behind.
+const TIME_TORNADO_MATCH_GATE_M: f64 = 250.0;
+
+/// HybridLearning counts a regime converged, and multi-episode runs an
+/// episode, once every later epoch's RMS stays within this fraction of the
+/// mean over its last [`CONVERGENCE_WINDOW_EPOCHS`] epochs.
+const LEARNING_SETTLED_BAND: f64 = 0.2;
+
+/// Offset CommonBias adds to every axis of every reading (m).
+const COMMON_BIAS_GPS_BIAS: f64 = 5.0;
+
+/// ChaosStorm's relaxed RMS threshold, also applied to campaign ChaosStorm
+/// phases (m).
+const CHAOS_STORM_MAX_RMS: f64 = 10.0;
+
+/// Gossip loss a campaign's ChaosStorm phase puts on every link, as in
+/// ChaosStorm itself.
+const CAMPAIGN_CHAOS_LOSS: f64 = 0.3;
+
+/// Every this many agents, one is a bad actor during a campaign's
+/// ChaosStorm phase (5 of the 50).
+const CAMPAIGN_CHAOS_BAD_ACTOR_STRIDE: usize = 10;
+
+/// Gossip loss rates SlowLoris runs its swarm at; the last is scored, the
+/// others give the degradation curve.
+const SLOW_LORIS_LOSS_RATES: [f64; 3] = [0.0, 0.25, 0.5];
+
+/// SlowLoris's RMS threshold at its highest loss rate (m).
+const SLOW_LORIS_MAX_RMS: f64 = 3.0;
+
+/// Longest SlowLoris allows from an entity's first track to every agent
+/// tracking it at its highest loss rate (s).
+const SLOW_LORIS_SPREAD_DEADLINE_SECS: f64 = 1.0;
+
+/// Seconds of RMS and track counts ParameterShock compares on either side
+/// of each perturbation (capped at a quarter of the run).
+pub const PERTURBATION_WINDOW_SECS: f64 = 5.0;
+
+/// Most a ParameterShock perturbation may raise swarm RMS (m).
+const PARAMETER_SHOCK_MAX_RMS_RISE_M: f64 = 1.0;
+
+/// Largest fraction of its tracks per agent a ParameterShock perturbation
+/// may cost the swarm.
+const PARAMETER_SHOCK_MAX_TRACK_LOSS: f64 = 0.2;
+
+/// Smallest fraction of BoundaryCrossing's shard crossings that must be
+/// handed off in time.
+const BOUNDARY_MIN_HANDOFF_RATE: f64 = 0.95;
+
+/// Largest fraction of BoundaryCrossing's shard crossings that may leave the
+/// new owner with duplicate tracks.
+const BOUNDARY_MAX_DUPLICATE_RATE: f64 = 0.05;
+
+/// Longest BoundaryCrossing's new owners may go without a track of a
+/// crossing entity on average (s).
+const BOUNDARY_MAX_GAP_SECS: f64 = 0.2;
+
+/// RMS error of one group of agents sharing an [`AgentProfile`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ProfileRms {
+    /// Profile name
+    pub profile: String,
+    
+    /// Agents in the group
+    pub agents: usize,
+    
+    /// Mean of the group's RMS samples
+    pub rms_mean: f64,
+    
+    /// 95th percentile of the group's RMS samples
+    pub rms_p95: f64,
+}
+
+impl ProfileRms {
+    fn new(profile: &str, agents: usize, rms: &RmsSampler) -> Self {
+        Self { profile: profile.to_string(), agents, rms_mean: rms.mean(), rms_p95: rms.p95() }
+    }
+}
+
+/// RMS error and coverage of the entities in one priority class.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct PriorityClassMetrics {
+    /// `high` (at or above [`GlobalHazardPacket::HIGH_PRIORITY`]) or `routine`
+    pub class: String,
+    
+    /// Active entities in the class at the end of the run
+    pub entities: usize,
+    
+    /// Mean of the class's RMS samples
+    pub rms_mean: f64,
+    
+    /// 95th percentile of the class's RMS samples
+    pub rms_p95: f64,
+    
+    /// Post-warm-up mean of the swarm coverage of the class (see
+    /// [`swarm_coverage`])
+    pub coverage: f64,
+}
+
+/// How one fitness regime of HybridLearning converged.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct LearningArmMetrics {
+    /// `ground_truth`, `blind` or `hybrid`
+    pub fitness: String,
+    
+    /// Epochs each agent completed
+    pub epochs: usize,
+    
+    /// Of those, epochs scored with ground truth (agent 0's)
+    pub ground_truth_epochs: usize,
+    
+    /// Epochs until the swarm's per-epoch RMS settled within
+    /// [`LEARNING_SETTLED_BAND`] of its final level, None if it never did
+    /// (or ran fewer than [`CONVERGENCE_WINDOW_EPOCHS`] epochs)
+    pub epochs_to_convergence: Option<usize>,
+    
+    /// Mean of the regime's RMS samples
+    pub rms_mean: f64,
+    
+    /// 95th percentile of the regime's RMS samples
+    pub rms_p95: f64,
+}
+
+/// Fitness regimes HybridLearning compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LearningRegime {
+    GroundTruth,
+    Blind,
+    Hybrid,
+}
+
+impl LearningRegime {
+    const ALL: [Self; 3] = [Self::GroundTruth, Self::Blind, Self::Hybrid];
+    
+    fn name(self) -> &'static str {
+        match self {
+            Self::GroundTruth => "ground_truth",
+            Self::Blind => "blind",
+            Self::Hybrid => "hybrid",
+        }
+    }
+    
+    fn provider(self, schedule: GtWeightSchedule) -> Box<dyn FitnessProvider> {
+        use crate::evolution::{BlindFitness, HybridFitness, OracleFitness};
+        
+        match self {
+            Self::GroundTruth => Box::new(OracleFitness::new()),
+            Self::Blind => Box::new(BlindFitness::new()),
+            Self::Hybrid => Box::new(HybridFitness::new(schedule)),
+        }
+    }
+    
+    /// Whether the regime's agents get ground truth during epoch `epoch`.
+    fn sees_ground_truth(self, epoch: u64) -> bool {
+        match self {
+            Self::GroundTruth => true,
+            Self::Blind => false,
+            Self::Hybrid => epoch.is_multiple_of(HYBRID_LANDMARK_EVERY_EPOCHS),
+        }
+    }
+}
+
+/// Samples RMS error and swarm coverage separately for high-priority and
+/// routine entities.
+struct PriorityClassSampler {
+    high: RmsSampler,
+    routine: RmsSampler,
+    /// (coverage sum, samples) for high, then routine
+    coverage: [(f64, usize); 2],
+    /// Active entities per class at the last sample
+    entities: [usize; 2],
+}
+
+impl PriorityClassSampler {
+    fn new(warmup_secs: f64) -> Self {
+        Self {
+            high: RmsSampler::new(warmup_secs),
+            routine: RmsSampler::new(warmup_secs),
+            coverage: [(0.0, 0); 2],
+            entities: [0; 2],
+        }
+    }
+    
+    fn sample(&mut self, time_secs: f64, agents: &[SimulatedAgent], oracle: &Oracle) {
+        let (high, routine): (Vec<_>, Vec<_>) = oracle.active_entities().into_iter()
+            .partition(|e| e.priority >= GlobalHazardPacket::HIGH_PRIORITY);
+        let truth = |entities: Vec<&GroundTruthEntity>| entities.into_iter().map(|e| (e.id, e.position)).collect::<Vec<_>>();
+        let classes = [truth(high), truth(routine)];
+        self.entities = [classes[0].len(), classes[1].len()];
+        self.high.sample(time_secs, agents, &classes[0]);
+        self.routine.sample(time_secs, agents, &classes[1]);
+        if time_secs < self.high.warmup_secs {
+            return;
+        }
+        for (coverage, truth) in self.coverage.iter_mut().zip(&classes) {
+            if !truth.is_empty() {
+                coverage.0 += swarm_coverage(agents, truth);
+                coverage.1 += 1;
+            }
+        }
+    }
+    
+    fn class(&self, class: usize) -> PriorityClassMetrics {
+        let (name, rms) = if class == 0 { ("high", &self.high) } else { ("routine", &self.routine) };
+        let (sum, samples) = self.coverage[class];
+        PriorityClassMetrics {
+            class: name.to_string(),
+            entities: self.entities[class],
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            coverage: if samples > 0 { sum / samples as f64 } else { 0.0 },
+        }
+    }
+    
+    fn metrics(&self) -> Vec<PriorityClassMetrics> {
+        vec![self.class(0), self.class(1)]
+    }
+}
+
+/// How far SplitBrain's two groups drift apart on the shared entity while
+/// partitioned, and how quickly they agree again after the heal.
+///
+/// Divergence is the distance between the groups' mean fused positions.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct PartitionDivergence {
+    /// Largest divergence while partitioned (m)
+    pub peak_divergence_m: f64,
+    
+    /// Divergence on the tick of the heal (None if the run ended first)
+    pub divergence_at_heal_m: Option<f64>,
+    
+    /// Seconds from the heal until divergence fell, and stayed, below the
+    /// tolerance (None if it never did)
+    pub reconverge_secs: Option<f64>,
+    
+    /// Most distinct canonical IDs for the entity across all agents at once
+    pub peak_canonical_ids: usize,
+    
+    /// One sample per gossip round
+    pub series: Vec<PartitionSample>,
+}
+
+/// One [`PartitionDivergence`] sample.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct PartitionSample {
+    pub time_secs: f64,
+    pub divergence_m: f64,
+    pub canonical_ids: usize,
+    pub partitioned: bool,
+}
+
+/// Per-tick RMS error samples, collected once the warm-up window has elapsed.
+///
+/// Scoring on the distribution of samples rather than the final tick keeps
+/// short CI runs from passing or failing on a single unlucky instant.
+pub struct RmsSampler {
+    warmup_secs: f64,
+    samples: Vec<f64>,
+}
+
+impl RmsSampler {
+    /// Creates a sampler that ignores samples before `warmup_secs`.
+    pub fn new(warmup_secs: f64) -> Self {
+        Self {
+            warmup_secs,
+            samples: Vec::new(),
+        }
+    }
+    
+    /// Records the mean RMS error of `agents` at `time_secs` (see
+    /// [`swarm_convergence`]).
+    ///
+    /// Ticks inside the warm-up window are skipped, as are ticks with no
+    /// agents to score (e.g. every agent has run out of energy, or every
+    /// RMS is NaN).
+    pub fn sample<'a>(
+        &mut self,
+        time_secs: f64,
+        agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+        ground_truth: &[(u64, Vector3<f64>)],
+    ) {
+        if time_secs < self.warmup_secs {
+            return;
+        }
+        
+        let convergence = swarm_convergence(agents, ground_truth, every_agent);
+        if convergence.scored() > 0 {
+            self.samples.push(convergence.rms_mean);
+        }
+    }
+    
+    /// Records the mean RMS velocity error of `agents` at `time_secs`, as
+    /// [`Self::sample`] does position error.
+    pub fn sample_velocity<'a>(
+        &mut self,
+        time_secs: f64,
+        agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+        ground_truth: &[(u64, Vector3<f64>, Vector3<f64>)],
+    ) {
+        if time_secs < self.warmup_secs {
+            return;
+        }
+        
+        let (sum, count) = agents.into_iter()
+            .fold((0.0, 0usize), |(sum, count), a| (sum + a.compute_velocity_error(ground_truth), count + 1));
+        if count > 0 {
+            self.samples.push(sum / count as f64);
+        }
+    }
+    
+    /// Records the mean RMS altitude error of `agents` at `time_secs`, as
+    /// [`Self::sample`] does 3D position error.
+    pub fn sample_altitude<'a>(
+        &mut self,
+        time_secs: f64,
+        agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+        ground_truth: &[(u64, Vector3<f64>)],
+    ) {
+        if time_secs < self.warmup_secs {
+            return;
+        }
+        
+        let (sum, count) = agents.into_iter()
+            .fold((0.0, 0usize), |(sum, count), a| (sum + a.compute_altitude_error(ground_truth), count + 1));
+        if count > 0 {
+            self.samples.push(sum / count as f64);
+        }
+    }
+    
+    /// Number of samples recorded after warm-up.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+    
+    /// Returns true if no samples have been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+    
+    /// Mean of the recorded samples (0.0 if none).
+    pub fn mean(&self) -> f64 {
+        stats::mean(&self.samples)
+    }
+    
+    /// 95th percentile of the recorded samples (nearest-rank, 0.0 if none).
+    pub fn p95(&self) -> f64 {
+        stats::p95(&self.samples)
+    }
+    
+    /// Whether the samples meet a scenario's mean RMS threshold.
+    pub fn within(&self, max_mean: f64) -> bool {
+        self.within_limits((max_mean, max_mean * RMS_P95_FACTOR))
+    }
+    
+    /// Whether the mean and p95 stay under separate limits.
+    pub fn within_limits(&self, (max_mean, max_p95): (f64, f64)) -> bool {
+        self.mean() < max_mean && self.p95() < max_p95
+    }
+    
+    /// [`FailureKind::RmsExceeded`] unless the samples are [`Self::within`]
+    /// `max_mean`.
+    pub fn failure(&self, max_mean: f64) -> Option<FailureKind> {
+        self.failure_limits((max_mean, max_mean * RMS_P95_FACTOR))
+    }
+    
+    /// [`FailureKind::RmsExceeded`] unless the samples are
+    /// [`Self::within_limits`] `limits`.
+    pub fn failure_limits(&self, limits: (f64, f64)) -> Option<FailureKind> {
+        (!self.within_limits(limits)).then(|| FailureKind::RmsExceeded { mean: self.mean(), p95: self.p95(), threshold: limits.0 })
+    }
+}
+
+/// Epochs before a per-epoch RMS series settled: the first epoch from which
+/// every epoch stays within `band` (a fraction) of the mean of the last
+/// [`CONVERGENCE_WINDOW_EPOCHS`]. None with fewer epochs than that, or if
+/// even the last epoch is outside the band.
+fn epochs_to_settle(epoch_rms: &[f64], band: f64) -> Option<usize> {
+    let window = epoch_rms.len().checked_sub(CONVERGENCE_WINDOW_EPOCHS)?;
+    let settled = stats::mean(&epoch_rms[window..]);
+    match epoch_rms.iter().rposition(|rms| (rms - settled).abs() > band * settled) {
+        None => Some(0),
+        Some(last) if last + 1 < epoch_rms.len() => Some(last + 1),
+        Some(_) => None,
+    }
+}
+
+/// Swarm RMS averaged over each evolution epoch of a run: its learning
+/// curve.
+struct EpochRmsCurve {
+    epoch_ticks: u64,
+    sum: f64,
+    rms: Vec<f64>,
+}
+
+impl EpochRmsCurve {
+    fn new(epoch_ticks: u64) -> Self {
+        Self { epoch_ticks, sum: 0.0, rms: Vec::new() }
+    }
+    
+    /// Adds the swarm's RMS at loop iteration `tick` (0-based), closing an
+    /// epoch every `epoch_ticks` iterations.
+    fn observe<'a>(&mut self, tick: u64, agents: impl IntoIterator<Item = &'a SimulatedAgent>, ground_truth: &[(u64, Vector3<f64>)]) {
+        self.sum += swarm_convergence(agents, ground_truth, every_agent).rms_mean;
+        if (tick + 1).is_multiple_of(self.epoch_ticks) {
+            self.rms.push(self.sum / self.epoch_ticks as f64);
+            self.sum = 0.0;
+        }
+    }
+    
+    /// Each closed epoch's mean RMS, in order (m).
+    fn into_rms(self) -> Vec<f64> {
+        self.rms
+    }
+}
+
+/// Logs sensor-limited (observer) against communication-limited (divergence) error.
+fn log_divergence(metrics: &ScenarioMetrics) {
+    let (position, ids) = metrics.mean_divergence();
+    let worst = metrics.observer_divergence.iter()
+        .max_by(|a, b| a.position_rms.total_cmp(&b.position_rms));
+    info!("  Observer: sensor-limited RMS {:.2}m | divergence {:.2}m, ID disagreement {:.1}%",
+        metrics.observer_rms.unwrap_or(0.0), position, ids * 100.0);
+    if let Some(worst) = worst {
+        info!("    Most divergent: agent {} ({:.2}m, {:.1}% IDs)",
+            worst.agent_id, worst.position_rms, worst.id_disagreement_rate * 100.0);
+    }
+}
+
+/// Why a scenario couldn't run.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ScenarioError {
+    #[error("{scenario}: invalid configuration: {reason}")]
+    InvalidConfig { scenario: ScenarioId, reason: String },
+    
+    #[error("{scenario}: oracle: {reason}")]
+    OracleError { scenario: ScenarioId, reason: String },
+    
+    #[error("{scenario}: agent {agent}: {reason}")]
+    AgentError { scenario: ScenarioId, agent: usize, reason: String },
+    
+    #[error("{scenario}: internal error: {reason}")]
+    Internal { scenario: ScenarioId, reason: String },
+}
+
+impl ScenarioError {
+    fn invalid_config(scenario: ScenarioId, reason: impl Into<String>) -> Self {
+        ScenarioError::InvalidConfig { scenario, reason: reason.into() }
+    }
+    
+    fn oracle(scenario: ScenarioId, reason: impl Into<String>) -> Self {
+        ScenarioError::OracleError { scenario, reason: reason.into() }
+    }
+    
+    fn agent(scenario: ScenarioId, agent: usize, reason: impl Into<String>) -> Self {
+        ScenarioError::AgentError { scenario, agent, reason: reason.into() }
+    }
+    
+    fn internal(scenario: ScenarioId, reason: impl Into<String>) -> Self {
+        ScenarioError::Internal { scenario, reason: reason.into() }
+    }
+}
+
+/// Duration and tick rate a scenario run resolved to.
+#[derive(Debug, Clone, Copy)]
+struct RunTiming {
+    duration_secs: f64,
+    tick_rate_hz: u32,
+}
+
+impl RunTiming {
+    /// Seconds per tick.
+    fn dt(&self) -> f64 {
+        1.0 / self.tick_rate_hz as f64
+    }
+    
+    /// Ticks needed to cover the duration.
+    fn target_ticks(&self) -> u64 {
+        (self.duration_secs * self.tick_rate_hz as f64).round() as u64
+    }
+}
+
+/// Runs chaos scenarios.
+pub struct ScenarioRunner {
+    /// Configuration seed
+    seed: u64,
+    
+    /// Number of agents
+    num_agents: usize,
+    
+    /// Tick rate override in Hz (None = each scenario's own)
+    tick_rate_hz: Option<u32>,
+    
+    /// Requested duration in seconds (None = each scenario's default)
+    duration_secs: Option<f64>,
+    
+    /// Warm-up window excluded from RMS scoring, in seconds
+    warmup_secs: f64,
+    
+    /// Worker threads for per-agent work within a scenario (1 = serial)
+    threads: usize,
+    
+    /// Grid, entities, limits and bad actors of Swarm and AdaptiveSwarm
+    swarm_config: SwarmConfig,
+    
+    /// Progress callback and abort predicate
+    hooks: ProgressHooks,
+    
+    /// Parameters evolving scenarios start their agents from (None = defaults)
+    trained_params: Option<TrainedParams>,
+    
+    /// Calibrated limits replacing one scenario's built-in ones
+    thresholds: Option<CalibratedThresholds>,
+    
+    /// Sensor link latency of TimeWarp and TimeTornado (None = each
+    /// scenario's own)
+    sensor_latency: Option<LatencyModel>,
+    
+    /// Ground-truth weight schedule of HybridLearning's hybrid agents
+    gt_schedule: GtWeightSchedule,
+    
+    /// Evolution epoch length of BlindLearning and CommonBias in ticks
+    /// (None = each scenario's own)
+    epoch_ticks: Option<u64>,
+}
+
+impl ScenarioRunner {
+    /// Creates a new scenario runner.
+    ///
+    /// In debug builds this enters simulation on the calling thread: OS
+    /// entropy and the system clock panic there from then on (see
+    /// [`godview_env::determinism`]).
+    pub fn new(seed: u64, num_agents: usize) -> Self {
+        if cfg!(debug_assertions) {
+            determinism::enter_simulation();
+        }
+        Self {
+            seed,
+            num_agents,
+            tick_rate_hz: None,
+            duration_secs: None,
+            warmup_secs: DEFAULT_WARMUP_SECS,
+            threads: available_threads(),
+            swarm_config: SwarmConfig::default(),
+            hooks: ProgressHooks::new(),
+            trained_params: None,
+            thresholds: None,
+            sensor_latency: None,
+            gt_schedule: GtWeightSchedule::DEFAULT,
+            epoch_ticks: None,
+        }
+    }
+    
+    /// Overrides every scenario's tick rate.
+    pub fn with_tick_rate(mut self, hz: u32) -> Self {
+        self.tick_rate_hz = Some(hz);
+        self
+    }
+    
+    /// Sets the simulated duration.
+    ///
+    /// Each scenario clamps it to its own [`ScenarioTiming`] bounds and
+    /// records the clamp in [`ScenarioResult::duration_clamp`]; a duration
+    /// that isn't positive and finite fails [`Self::run`] instead.
+    ///
+    /// [`ScenarioTiming`]: crate::scenarios::ScenarioTiming
+    pub fn with_duration(mut self, secs: f64) -> Self {
+        self.duration_secs = Some(secs);
+        self
+    }
+    
+    /// Sets the warm-up window excluded from RMS scoring.
+    ///
+    /// The effective warm-up is capped at half of a scenario's simulated
+    /// duration, so very short runs still have samples to score.
+    pub fn with_warmup_secs(mut self, secs: f64) -> Self {
+        self.warmup_secs = secs.max(0.0);
+        self
+    }
+    
+    /// Runs each large swarm's per-agent work on `threads` workers (default:
+    /// every available core; 1 = serial). Results don't depend on it.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+    
+    /// Runs Swarm and AdaptiveSwarm with `config` instead of
+    /// [`SwarmConfig::default`] (AdaptiveSwarm's bad-actor count, injection
+    /// time and placement included). ChaosStorm, NetworkHell and
+    /// ZombieApocalypse take their grid and entity count from it, MixedFleet
+    /// its grid.
+    pub fn with_swarm_config(mut self, config: SwarmConfig) -> Self {
+        self.swarm_config = config;
+        self
+    }
+    
+    /// Holds each sensor reading of TimeWarp and TimeTornado for a latency
+    /// drawn from `model` on the sensor link's RNG stream. TimeWarp adds it
+    /// to its reordering (by default readings aren't delayed); TimeTornado
+    /// uses it instead of its uniform 0-5s delay.
+    pub fn with_sensor_latency(mut self, model: LatencyModel) -> Self {
+        self.sensor_latency = Some(model);
+        self
+    }
+    
+    /// Anneals HybridLearning's hybrid agents from ground truth to blind
+    /// fitness by `schedule` instead of [`GtWeightSchedule::DEFAULT`].
+    pub fn with_gt_schedule(mut self, schedule: GtWeightSchedule) -> Self {
+        self.gt_schedule = schedule;
+        self
+    }
+    
+    /// Evolves BlindLearning's and CommonBias's agents every `ticks` ticks
+    /// instead of every 20 and 50, e.g. to fit enough epochs into short
+    /// episodes.
+    pub fn with_epoch_ticks(mut self, ticks: u64) -> Self {
+        self.epoch_ticks = Some(ticks);
+        self
+    }
+    
+    /// Judges the scenario `thresholds` were calibrated on by them instead
+    /// of its built-in limits (see
+    /// [`calibrated_metrics`](crate::calibration::calibrated_metrics));
+    /// other scenarios keep theirs.
+    pub fn with_thresholds(mut self, thresholds: CalibratedThresholds) -> Self {
+        self.thresholds = Some(thresholds);
+        self
+    }
+    
+    /// Starts the agents of evolving scenarios from `params`, matched by
+    /// agent index (see [`TrainedParams::for_agent`]), instead of the
+    /// default parameters.
+    pub fn with_trained_params(mut self, params: TrainedParams) -> Self {
+        self.trained_params = Some(params);
+        self
+    }
+    
+    /// Calls `progress` with a [`ProgressReport`] every progress interval.
+    pub fn with_progress(mut self, progress: ProgressFn) -> Self {
+        self.hooks.progress = Some(progress);
+        self
+    }
+    
+    /// Publishes a [`StatusSnapshot`](crate::StatusSnapshot) on `status`
+    /// every progress interval and on each run's final tick. The runs
+    /// themselves are unaffected: the sender's latest value is replaced,
+    /// never waited on.
+    pub fn with_status(mut self, status: StatusSender) -> Self {
+        self.hooks.status = Some(status);
+        self
+    }
+    
+    /// Ends a run early once `abort_if` returns true for a progress report.
+    ///
+    /// The predicate is checked every progress interval. The run stops
+    /// cleanly and its result is failed with [`ScenarioResult::aborted`] set.
+    pub fn with_abort_if(mut self, abort_if: AbortFn) -> Self {
+        self.hooks.abort_if = Some(abort_if);
+        self
+    }
+    
+    /// Ends the run after the current tick once `interrupt` is set, and
+    /// runs that start after it stop after their first.
+    ///
+    /// The result is aborted as by [`Self::with_abort_if`], but no failure
+    /// capture is written for it.
+    pub fn with_interrupt(mut self, interrupt: Interrupt) -> Self {
+        self.hooks.interrupt = Some(interrupt);
+        self
+    }
+    
+    /// Sets the number of ticks between progress reports
+    /// (default [`DEFAULT_PROGRESS_INTERVAL_TICKS`]).
+    ///
+    /// [`DEFAULT_PROGRESS_INTERVAL_TICKS`]: crate::DEFAULT_PROGRESS_INTERVAL_TICKS
+    pub fn with_progress_interval(mut self, ticks: u64) -> Self {
+        self.hooks.interval_ticks = ticks.max(1);
+        self
+    }
+    
+    /// Records swarm-wide time series in [`ScenarioResult::registry`] every
+    /// `ticks` ticks (by default only the final tick is recorded).
+    pub fn with_metrics_interval(mut self, ticks: u64) -> Self {
+        self.hooks.metrics_interval_ticks = Some(ticks.max(1));
+        self
+    }
+    
+    /// Streams every run to `logger` (see [`RerunLogger::connect`]).
+    ///
+    pub fn with_rerun(mut self, logger: RerunLogger) -> Self {
+        self.hooks.rerun = Some(logger);
+        self
+    }
+    
+    /// Captures a window of frames around each failed run's first invariant
+    /// violation (or its end, if none fired) into `config.dir`.
+    pub fn with_failure_capture(mut self, config: CaptureConfig) -> Self {
+        self.hooks.capture = Some(config);
+        self
+    }
+    
+    /// Paces every run so virtual time advances no faster than `factor`
+    /// times wall time (1.0 = real time), for watching it in a viewer.
+    ///
+    /// Pacing only adds sleeps between ticks: results are the same as an
+    /// unpaced run's. A factor that isn't positive and finite is ignored.
+    pub fn with_realtime(mut self, factor: f64) -> Self {
+        self.hooks.realtime_factor = Some(factor);
+        self
+    }
+    
+    /// Overrides every scenario's per-agent memory budget.
+    pub fn with_agent_memory_budget(mut self, bytes: u64) -> Self {
+        self.hooks.memory_budget_bytes = Some(bytes);
+        self
+    }
+    
+    /// Counts every RNG stream's draws during each run and snapshots them
+    /// every `ticks` ticks, for [`Self::take_rng_trace`].
+    pub fn with_rng_audit(mut self, ticks: u64) -> Self {
+        self.hooks.rng_audit_interval_ticks = Some(ticks.max(1));
+        self
+    }
+    
+    /// Takes the RNG trace of the last run, if auditing is on
+    /// ([`Self::with_rng_audit`]).
+    pub fn take_rng_trace(&self) -> Option<RngTrace> {
+        self.hooks.take_rng_trace()
+    }
+    
+    /// Records a verbose frame (ground truth, every agent's tracks and RMS
+    /// error) every `ticks` ticks of each run, and on its final tick, for
+    /// [`Self::take_recording`].
+    pub fn with_recording(mut self, ticks: u64) -> Self {
+        self.hooks.recording_interval_ticks = Some(ticks.max(1));
+        self
+    }
+    
+    /// Takes the export recorded during the last run, if recording is on
+    /// ([`Self::with_recording`]).
+    pub fn take_recording(&self) -> Option<SimExport> {
+        self.hooks.take_recording()
+    }
+    
+    /// Creates the progress monitor for one run of `scenario`.
+    fn monitor(&self, scenario: ScenarioId, target_ticks: u64) -> RunMonitor<'_> {
+        RunMonitor::new(&self.hooks, scenario, target_ticks)
+    }
+    
+    /// Starts each of `agents` from its trained parameters, if any were given.
+    fn load_trained_params(&self, agents: &mut [SimulatedAgent]) {
+        let Some(trained) = &self.trained_params else {
+            return;
+        };
+        if trained.agents.len() != agents.len() {
+            info!("  Trained params for {} agents ({} seed={}) reused round-robin over {}",
+                trained.agents.len(), trained.scenario, trained.seed, agents.len());
+        }
+        for (idx, agent) in agents.iter_mut().enumerate() {
+            if let Some(params) = trained.for_agent(idx) {
+                agent.load_evolved_params(params);
+            }
+        }
+    }
+    
+    /// Creates an RMS sampler for a scenario simulating `run_secs` seconds.
+    fn rms_sampler(&self, run_secs: f64) -> RmsSampler {
+        RmsSampler::new(self.warmup_secs.min(run_secs / 2.0))
+    }
+    
+    /// Creates an observer divergence sampler with the same warm-up as
+    /// [`Self::rms_sampler`].
+    fn divergence_sampler(&self, run_secs: f64) -> DivergenceSampler {
+        DivergenceSampler::new(self.warmup_secs.min(run_secs / 2.0))
+    }
+    
+    /// A MOT sampler with the runner's warm-up, capped at half the run.
+    fn mot_sampler(&self, run_secs: f64) -> MotSampler {
+        MotSampler::new(self.warmup_secs.min(run_secs / 2.0))
+    }
+    
+    /// A [`CoastingSampler`] for agents ticking every `dt`, with the same
+    /// warm-up as [`Self::rms_sampler`].
+    fn coasting_sampler(&self, run_secs: f64, dt: f64) -> CoastingSampler {
+        CoastingSampler::new(self.warmup_secs.min(run_secs / 2.0), dt)
+    }
+    
+    /// Resolves the duration and tick rate `scenario` will run with.
+    fn timing(&self, scenario: ScenarioId) -> (RunTiming, Option<DurationClamp>) {
+        let declared = scenario.timing();
+        let (duration_secs, clamp) = declared.resolve(self.duration_secs);
+        let timing = RunTiming {
+            duration_secs,
+            tick_rate_hz: self.tick_rate_hz.unwrap_or(declared.tick_rate_hz),
+        };
+        (timing, clamp)
+    }
+    
+    /// Checks the runner's configuration can run `scenario` for `timing`:
+    /// at least one agent and the scenario's minimum if it takes the
+    /// runner's count, a positive duration and tick rate, a non-empty grid
+    /// for the scenarios [`Self::with_swarm_config`] sizes, an honest agent
+    /// in ChaosStorm, and for Swarm and AdaptiveSwarm a gossip interval and
+    /// at least one honest agent.
+    fn validate(&self, scenario: ScenarioId, timing: RunTiming) -> Result<(), ScenarioError> {
+        let invalid = |reason: String| Err(ScenarioError::invalid_config(scenario, reason));
+        if self.num_agents == 0 {
+            return invalid("needs at least 1 agent, got 0".to_string());
+        }
+        if let AgentCount::FromRunner { min } = scenario.agents() {
+            if self.num_agents < min {
+                return invalid(format!("needs at least {} agents, got {}", min, self.num_agents));
+            }
+        }
+        if let Some(secs) = self.duration_secs.filter(|secs| !(secs.is_finite() && *secs > 0.0)) {
+            return invalid(format!("duration must be positive, got {}s", secs));
+        }
+        if timing.tick_rate_hz == 0 {
+            return invalid("tick rate must be positive, got 0 Hz".to_string());
+        }
+        if timing.target_ticks() == 0 {
+            return invalid(format!("{}s at {} Hz runs no ticks", timing.duration_secs, timing.tick_rate_hz));
+        }
+        if self.epoch_ticks == Some(0) {
+            return invalid("epochs must last at least 1 tick".to_string());
+        }
+        let config = &self.swarm_config;
+        let swarm_agents = config.rows * config.cols;
+        let sized_by_swarm_config = matches!(scenario, ScenarioId::Swarm | ScenarioId::AdaptiveSwarm
+            | ScenarioId::ChaosStorm | ScenarioId::NetworkHell | ScenarioId::ZombieApocalypse
+            | ScenarioId::MixedFleet);
+        if sized_by_swarm_config && swarm_agents == 0 {
+            return invalid(format!("swarm grid is {}x{}, with no agents", config.rows, config.cols));
+        }
+        if scenario == ScenarioId::ChaosStorm && swarm_agents < 2 {
+            return invalid(format!("a {}-agent swarm is all bad actors", swarm_agents));
+        }
+        if matches!(scenario, ScenarioId::Swarm | ScenarioId::AdaptiveSwarm) {
+            if config.gossip_interval == 0 {
+                return invalid("swarm gossip interval must be at least 1 tick".to_string());
+            }
+            if scenario == ScenarioId::AdaptiveSwarm && config.num_bad_actors >= swarm_agents {
+                return invalid(format!("{} bad actors leave no honest agent in a {}-agent swarm",
+                    config.num_bad_actors, swarm_agents));
+            }
+        }
+        Ok(())
+    }
+    
+    /// Thresholds calibrated on `scenario`, if the runner has them.
+    fn thresholds_for(&self, scenario: ScenarioId) -> Option<&CalibratedThresholds> {
+        self.thresholds.as_ref().filter(|t| t.applies_to(scenario))
+    }
+    
+    /// The limit a run of `scenario` holds `metric` to: its calibrated
+    /// threshold if there is one (see [`Self::with_thresholds`]), else
+    /// `builtin`.
+    fn limit(&self, scenario: ScenarioId, metric: &str, builtin: f64) -> f64 {
+        self.thresholds_for(scenario).and_then(|t| t.get(metric)).unwrap_or(builtin)
+    }
+    
+    /// The (mean, p95) RMS limits of a scenario whose built-in mean limit
+    /// is `max_mean`, either replaced by a calibrated threshold.
+    fn rms_limits(&self, scenario: ScenarioId, max_mean: f64) -> (f64, f64) {
+        (self.limit(scenario, "rms_mean", max_mean), self.limit(scenario, "rms_p95", max_mean * RMS_P95_FACTOR))
+    }
+    
+    /// Builds `scenario`'s world as a [`Simulation`] without stepping it,
+    /// for driving tick by tick (see [`DebugRepl`](crate::DebugRepl)).
+    ///
+    /// The world is the scenario's agents, network and entities; events the
+    /// scenario scripts during a run (Swarm's partition) are left to the
+    /// caller. Only scenarios built on [`Simulation`] have one: TimeWarp and
+    /// Swarm. Others, and configurations [`Self::run`] would refuse, return
+    /// [`ScenarioError::InvalidConfig`].
+    pub fn world(&self, scenario: ScenarioId) -> Result<Simulation, ScenarioError> {
+        let (timing, _) = self.timing(scenario);
+        self.validate(scenario, timing)?;
+        match scenario {
+            ScenarioId::TimeWarp => Ok(self.time_warp_world(timing)),
+            ScenarioId::Swarm => self.swarm_world(timing),
+            _ => Err(ScenarioError::invalid_config(scenario, "has no step-wise world (supported: time_warp, swarm)")),
+        }
+    }
+    
+    /// Runs a scenario and returns the result.
+    ///
+    /// Returns [`ScenarioError::InvalidConfig`] before simulating anything
+    /// if the runner's configuration can't run `scenario` (see
+    /// [`Self::with_duration`] and [`Self::with_swarm_config`]).
+    pub fn run(&self, scenario: ScenarioId) -> Result<ScenarioResult, ScenarioError> {
+        info!("Starting scenario: {} (seed={})", scenario.name(), self.seed);
+        
+        let (timing, duration_clamp) = self.timing(scenario);
+        self.validate(scenario, timing)?;
+        
+        if scenario.is_extreme() {
+            warn!("🔥 EXTREME SCENARIO - Pushing to the limit!");
+        }
+        
+        if let Some(clamp) = &duration_clamp {
+            warn!("  Duration {}", clamp);
+        }
+        
+        self.hooks.start_events();
+        self.hooks.event_bus().publish(SimEvent::RunStarted { scenario: scenario.name().to_string(), seed: self.seed, phase: None });
+        self.hooks.start_capture(timing.tick_rate_hz);
+        self.hooks.start_recording(scenario, self.seed);
+        self.hooks.start_status(self.seed);
+        self.hooks.start_pacing();
+        let _rng_audit = self.hooks.start_rng_audit(scenario, self.seed);
+        
+        let mut result = match scenario {
+            ScenarioId::TimeWarp => self.run_time_warp(timing)?,
+            ScenarioId::SplitBrain => self.run_split_brain(timing),
+            ScenarioId::Byzantine => self.run_byzantine(timing),
+            ScenarioId::FlashMob => self.run_flash_mob(timing),
+            ScenarioId::SlowLoris => self.run_slow_loris(timing)?,
+            ScenarioId::Swarm => self.run_swarm(timing)?,
+            ScenarioId::AdaptiveSwarm => self.run_adaptive_swarm(timing)?,
+            // Extreme scenarios
+            ScenarioId::ChaosStorm => self.run_chaos_storm(timing),
+            ScenarioId::ScaleLimit => self.run_scale_limit(timing),
+            ScenarioId::NetworkHell => self.run_network_hell(timing),
+            ScenarioId::TimeTornado => self.run_time_tornado(timing),
+            ScenarioId::ZombieApocalypse => self.run_zombie_apocalypse(timing),
+            ScenarioId::ZombieRestart => self.run_zombie_restart(timing),
+            ScenarioId::RapidFire => self.run_rapid_fire(timing),
+            // Evolutionary
+            ScenarioId::EvoWar => self.run_evo_war(timing)?,
+            ScenarioId::ResourceStarvation => self.run_resource_starvation(timing),
+            ScenarioId::ProtocolDrift => self.run_protocol_drift(timing),
+            ScenarioId::BlindLearning => self.run_blind_learning(timing)?,
+            ScenarioId::HybridLearning => self.run_hybrid_learning(timing),
+            ScenarioId::DegradedAltitude => self.run_degraded_altitude(timing),
+            ScenarioId::ParameterShock => self.run_parameter_shock(timing),
+            ScenarioId::BoundaryCrossing => self.run_boundary_crossing(timing),
+            ScenarioId::BlackoutSurvival => self.run_blackout_survival(timing),
+            ScenarioId::LongHaul => self.run_long_haul(timing),
+            ScenarioId::CommonBias => self.run_common_bias(timing),
+            ScenarioId::HeavyTail => self.run_heavy_tail(timing),
+            ScenarioId::SensorDrift => self.run_sensor_drift(timing),
+            ScenarioId::MultiSensorBias => self.run_multi_sensor_bias(timing),
+            ScenarioId::ClockSkew => self.run_clock_skew(timing),
+            ScenarioId::Occlusion => self.run_occlusion(timing),
+            ScenarioId::MixedFleet => self.run_mixed_fleet(timing),
+            ScenarioId::Clutter => self.run_clutter(timing),
+            ScenarioId::GpsDenied => self.run_gps_denied(timing),
+            ScenarioId::Convoy => self.run_convoy(timing),
+            ScenarioId::Triage => self.run_triage(timing),
+        };
+        result.duration_clamp = duration_clamp;
+        Ok(self.finish_run(scenario, result))
+    }
+    
+    /// Applies what the hooks saw during a run of `scenario` to its
+    /// `result`: an abort, the fault and memory peaks, the memory budget,
+    /// the failure capture, the recording and the metrics registry.
+    fn finish_run(&self, scenario: ScenarioId, mut result: ScenarioResult) -> ScenarioResult {
+        let interrupted = self.hooks.interrupted();
+        if let Some(report) = self.hooks.take_aborted() {
+            warn!("  {} at t={:.1}s (tick {}/{})", if interrupted { "Interrupted" } else { "Aborted" },
+                report.time_secs, report.tick, report.target_ticks);
+            result.mark_aborted(report);
+        }
+        let peaks = self.hooks.take_peaks();
+        result.metrics.numerical_faults = peaks.numerical_faults;
+        result.metrics.index_repairs = peaks.index_repairs;
+        result.metrics.peak_agent_memory_bytes = peaks.agent_memory_bytes;
+        result.metrics.peak_total_memory_bytes = peaks.total_memory_bytes;
+        result.metrics.max_latency_ms = result.metrics.max_latency_ms.max(peaks.max_latency_ms);
+        result.metrics.gossip_received_total = peaks.gossip_received;
+        result.metrics.tracks_final_total = peaks.final_tracks;
+        if result.metrics.numerical_faults > 0 {
+            warn!("  {} numerical faults contained", result.metrics.numerical_faults);
+        }
+        if result.metrics.index_repairs > 0 {
+            warn!("  {} spatial index repairs", result.metrics.index_repairs);
+        }
+        info!("  Memory: peak {:.2} MiB/agent, {:.2} MiB total",
+            peaks.agent_memory_bytes as f64 / MIB, peaks.total_memory_bytes as f64 / MIB);
+        let over_budget = self.hooks.memory_budget_bytes(scenario)
+            .is_some_and(|budget| result.fail_over_memory_budget(budget));
+        if result.fail_on_non_finite_rms() || over_budget {
+            warn!("  {}", result.failure_reason().unwrap_or_default());
+        }
+        if let Some(recorder) = self.hooks.take_recorder().filter(|_| !interrupted) {
+            self.write_failure_capture(recorder, &result);
+        }
+        self.hooks.finish_recording(result.passed, result.rms_mean, interrupted);
+        result.metrics.rms_mean = result.rms_mean;
+        if let Some(thresholds) = self.thresholds_for(scenario) {
+            result.thresholds = thresholds.source();
+        }
+        result.registry.merge(&self.hooks.take_metrics());
+        result.registry.merge(&self.hooks.take_event_metrics());
+        result.record_final_metrics();
+        result
+    }
+    
+    /// Runs every phase of `campaign`, in order, against one swarm world
+    /// built like Swarm's.
+    ///
+    /// Each phase is scored from its own start with the runner's warm-up,
+    /// and starts from whatever the phase before it left: tracks,
+    /// reputations, quarantines. Phases after an aborted one don't run.
+    pub fn run_campaign(&self, campaign: &Campaign) -> CampaignResult {
+        info!("Starting campaign: {} phases, {:.0}s (seed={})",
+            campaign.phases().len(), campaign.duration_secs(), self.seed);
+        
+        let config = &self.swarm_config;
+        let mut sim = Simulation::from_config(SimConfig {
+            seed: self.seed,
+            num_agents: config.rows * config.cols,
+            tick_rate_hz: self.tick_rate_hz.unwrap_or(ScenarioId::Swarm.timing().tick_rate_hz),
+            max_duration_secs: campaign.duration_secs(),
+            visibility: Visibility::Striped { period: 10, visible: 5 },
+            gossip: Some(GossipConfig {
+                rows: config.rows,
+                cols: config.cols,
+                interval_ticks: config.gossip_interval as u64,
+                relay: GossipRelay::Off,
+                max_packet_age_ms: None,
+            }),
+            ..SimConfig::default()
+        }).with_event_bus(self.hooks.event_bus());
+        spawn_swarm_targets(sim.oracle_mut(), config.num_entities);
+        self.hooks.start_pacing();
+        
+        let mut phases = Vec::with_capacity(campaign.phases().len());
+        for (index, phase) in campaign.phases().iter().enumerate() {
+            let result = self.run_campaign_phase(&mut sim, index, phase);
+            let aborted = result.aborted.is_some();
+            phases.push(result);
+            if aborted {
+                break;
+            }
+        }
+        
+        let phase_rms: Vec<f64> = phases.iter().map(|r| r.rms_mean).collect();
+        let metrics = CampaignMetrics::from_phase_rms(&phase_rms);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  CAMPAIGN RESULTS:");
+        for (index, result) in phases.iter().enumerate() {
+            info!("    Phase {} {:<14} RMS {:.2}m  {}", index, result.scenario.name(), result.rms_mean,
+                if result.passed { "✓" } else { "✗" });
+        }
+        info!("    Worst phase:            {}", metrics.worst_phase);
+        info!("    Degraded monotonically: {}", metrics.degraded_monotonically);
+        info!("    Recovered:              {}", metrics.recovered);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        CampaignResult { seed: self.seed, phases, metrics }
+    }
+    
+    /// Plays one campaign phase on `sim`:
+    /// - Swarm: steady state
+    /// - SplitBrain: the grid's left and right halves partitioned for the
+    ///   first half of the phase, then healed
+    /// - ChaosStorm: lossy links and garbage-gossiping bad actors for the
+    ///   whole phase, lifted at its end
+    fn run_campaign_phase(&self, sim: &mut Simulation, index: usize, phase: &CampaignPhase) -> ScenarioResult {
+        info!("Campaign phase {}: {} for {:.0}s", index, phase.scenario.name(), phase.duration_secs);
+        
+        let config = &self.swarm_config;
+        let num_agents = sim.agents().len();
+        let timing = RunTiming { duration_secs: phase.duration_secs, tick_rate_hz: sim.config().tick_rate_hz };
+        let target_ticks = timing.target_ticks();
+        let start_secs = sim.time();
+        let mut rms = RmsSampler::new(start_secs + self.warmup_secs.min(phase.duration_secs / 2.0));
+        let bad_actors: Vec<usize> = match phase.scenario {
+            ScenarioId::ChaosStorm => (0..num_agents).step_by(CAMPAIGN_CHAOS_BAD_ACTOR_STRIDE).collect(),
+            _ => Vec::new(),
+        };
+        let heal_tick = (phase.scenario == ScenarioId::SplitBrain).then_some(target_ticks / 2);
+        
+        self.hooks.start_events();
+        self.hooks.event_bus().publish(SimEvent::RunStarted {
+            scenario: phase.scenario.name().to_string(),
+            seed: self.seed,
+            phase: Some(index),
+        });
+        self.hooks.start_capture(timing.tick_rate_hz);
+        self.hooks.start_recording(phase.scenario, self.seed);
+        self.hooks.start_status(self.seed);
+        if heal_tick.is_some() {
+            let (left, right): (Vec<usize>, Vec<usize>) = (0..num_agents)
+                .partition(|&i| i % config.cols < config.cols / 2);
+            sim.inject_event(SimEventRequest::Partition { group_a: left, group_b: right });
+        }
+        if !bad_actors.is_empty() {
+            sim.inject_event(SimEventRequest::PacketLoss { rate: CAMPAIGN_CHAOS_LOSS });
+            for &agent in &bad_actors {
+                sim.inject_event(SimEventRequest::BadActor { agent });
+            }
+        }
+        
+        let routes_before = sim.swarm_network().map(|n| n.route_stats()).unwrap_or_default();
+        let gaps_before = sequence_totals(sim.agents());
+        let mut partition_blocked = 0;
+        let monitor = self.monitor(phase.scenario, target_ticks);
+        let mut ticks = 0;
+        for tick in 0..target_ticks {
+            if heal_tick == Some(tick) {
+                sim.inject_event(SimEventRequest::Heal);
+            }
+            let report = sim.step();
+            ticks += 1;
+            partition_blocked += report.gossip_blocked;
+            let good_agents = sim.agents().iter().enumerate()
+                .filter(|(i, _)| !bad_actors.contains(i))
+                .map(|(_, a)| a);
+            rms.sample(sim.time(), good_agents, &sim.oracle().ground_truth_positions());
+            if monitor.observe(tick, sim.oracle(), sim.agents()) {
+                break;
+            }
+        }
+        
+        // Leave the world clean for the next phase
+        if heal_tick.is_some() {
+            sim.inject_event(SimEventRequest::Heal);
+        }
+        if !bad_actors.is_empty() {
+            sim.inject_event(SimEventRequest::PacketLoss { rate: 0.0 });
+            sim.inject_event(SimEventRequest::ClearBadActors);
+        }
+        let routes = sim.swarm_network().map(|n| n.route_stats()).unwrap_or_default();
+        let gaps = sequence_totals(sim.agents());
+        
+        let failure = match phase.scenario {
+            ScenarioId::ChaosStorm => rms.failure(CHAOS_STORM_MAX_RMS),
+            ScenarioId::SplitBrain => first_failure([
+                rms.failure(config.max_position_error),
+                (partition_blocked == 0).then(|| FailureKind::expectation("partition_blocked", "The partition blocked no envelopes")),
+            ]),
+            _ => rms.failure(config.max_position_error),
+        };
+        info!("  Phase {}: RMS {:.2}m (p95 {:.2}m), {} envelopes blocked, {} lost  {}",
+            index, rms.mean(), rms.p95(), partition_blocked, routes.dropped_loss - routes_before.dropped_loss,
+            if failure.is_none() { "✓" } else { "✗" });
+        
+        let result = ScenarioResult {
+            scenario: phase.scenario,
+            seed: self.seed,
+            passed: failure.is_none(),
+            total_ticks: ticks,
+            final_time_secs: sim.time() - start_secs,
+            final_entity_count: sim.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics: ScenarioMetrics {
+                packets_sent: routes.sent - routes_before.sent,
+                packets_dropped: (routes.dropped_loss - routes_before.dropped_loss) + partition_blocked,
+                gossip_gaps_detected: gaps.gaps_detected - gaps_before.gaps_detected,
+                gossip_gaps_repaired: gaps.gaps_repaired - gaps_before.gaps_repaired,
+                ..Default::default()
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        };
+        self.finish_run(phase.scenario, result)
+    }
+    
+    /// Runs `episodes` back-to-back episodes of BlindLearning or CommonBias
+    /// (see [`EPISODIC_SCENARIOS`]) in one world, for training.
+    ///
+    /// Every episode runs the scenario's duration against a fresh world
+    /// (the oracle reset with [`episode_seed`] and its entities respawned)
+    /// and is scored from its own start with the runner's warm-up. Between
+    /// episodes agents are rebuilt or readied as `policy` says. Episodes
+    /// after an aborted one don't run.
+    ///
+    /// Returns [`ScenarioError::InvalidConfig`] for other scenarios, zero
+    /// episodes or a configuration [`Self::run`] would refuse.
+    pub fn run_episodes(&self, scenario: ScenarioId, episodes: usize, policy: ResetPolicy) -> Result<EpisodesResult, ScenarioError> {
+        if !EPISODIC_SCENARIOS.contains(&scenario) {
+            let supported = EPISODIC_SCENARIOS.map(|s| s.name()).join(", ");
+            return Err(ScenarioError::invalid_config(scenario, format!("can't run as episodes (supported: {supported})")));
+        }
+        if episodes == 0 {
+            return Err(ScenarioError::invalid_config(scenario, "needs at least 1 episode, got 0"));
+        }
+        let (timing, _) = self.timing(scenario);
+        self.validate(scenario, timing)?;
+        info!("Starting {} episodes of {} ({}, seed={})", episodes, scenario.name(), policy, self.seed);
+        
+        let learning_agents = || match scenario {
+            ScenarioId::BlindLearning => self.blind_learning_agents(),
+            _ => self.common_bias_agents(),
+        };
+        let mut oracle = Oracle::new(self.seed);
+        let mut agents = learning_agents();
+        self.hooks.start_pacing();
+        
+        let mut results = Vec::with_capacity(episodes);
+        let mut curves = Vec::with_capacity(episodes);
+        for episode in 0..episodes {
+            let seed = episode_seed(self.seed, episode);
+            if episode > 0 {
+                oracle.reset(seed);
+                if policy.keeps_agents() {
+                    let keep_reputation = policy == ResetPolicy::KeepReputation;
+                    agents.iter_mut().for_each(|a| a.reset_world_state(keep_reputation));
+                } else {
+                    agents = learning_agents();
+                }
+            }
+            
+            self.hooks.start_events();
+            self.hooks.event_bus().publish(SimEvent::RunStarted { scenario: scenario.name().to_string(), seed, phase: None });
+            self.hooks.start_capture(timing.tick_rate_hz);
+            self.hooks.start_recording(scenario, seed);
+            let monitor = self.monitor(scenario, timing.target_ticks());
+            let epochs_before: Vec<usize> = agents.iter().map(|a| a.evolution_history().len()).collect();
+            let (result, epoch_rms) = match scenario {
+                ScenarioId::BlindLearning => {
+                    spawn_blind_learning_targets(&mut oracle);
+                    let (run, initial_rms) = self.blind_learning_episode(timing, &mut oracle, &mut agents, seed, &monitor);
+                    let epoch_rms = run.epoch_rms.clone();
+                    (self.blind_learning_result(timing, &oracle, &agents, run, initial_rms)?, epoch_rms)
+                }
+                _ => {
+                    spawn_common_bias_targets(&mut oracle);
+                    let run = self.common_bias_episode(timing, &mut oracle, &mut agents, &monitor);
+                    let epoch_rms = run.epoch_rms.clone();
+                    (self.common_bias_result(timing, &oracle, &agents, run), epoch_rms)
+                }
+            };
+            let result = self.finish_run(scenario, result);
+            let epochs_to_convergence = epochs_to_settle(&epoch_rms, LEARNING_SETTLED_BAND);
+            let epoch_fitness = swarm_epoch_fitness(&agents, &epochs_before);
+            let curve = EpisodeCurve { epoch_rms, epoch_fitness, epochs_to_convergence };
+            info!("  Episode {}: RMS {:.2}m, fitness {:.2}, converged after {} epochs  {}", episode, result.rms_mean,
+                curve.mean_fitness(), epochs_to_convergence.map_or("no".to_string(), |e| e.to_string()),
+                if result.passed { "✓" } else { "✗" });
+            curves.push(curve);
+            let aborted = result.aborted.is_some();
+            results.push(result);
+            if aborted {
+                break;
+            }
+        }
+        
+        let episode_rms = results.iter().map(|r| r.rms_mean).collect();
+        let metrics = EpisodeMetrics::new(curves, episode_rms);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  EPISODE RESULTS ({}):", policy);
+        info!("    Episode RMS:          {:?}", metrics.episode_rms);
+        info!("    First-epoch RMS:      {:?}", metrics.first_epoch_rms());
+        info!("    Episode fitness:      {:?}", metrics.episode_fitness);
+        info!("    Epochs to converge:   {} in total", metrics.total_epochs_to_convergence);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        Ok(EpisodesResult { seed: self.seed, policy, episodes: results, metrics })
+    }
+    
+    /// Writes `recorder`'s window if the run broke an invariant or failed.
+    fn write_failure_capture(&self, mut recorder: FrameRecorder, result: &ScenarioResult) {
+        let Some(config) = self.hooks.capture.as_ref() else {
+            return;
+        };
+        if recorder.violation().is_none() {
+            if result.passed {
+                return;
+            }
+            recorder.violate(InvariantViolation::new(
+                result.total_ticks.saturating_sub(1),
+                result.final_time_secs,
+                "run_failed",
+                result.failure_reason().unwrap_or_default(),
+            ));
+        }
+        if let Some(v) = recorder.violation() {
+            warn!("  Invariant {} broken at tick {} (t={:.1}s): {}", v.invariant, v.tick, v.time_sec, v.details);
+        }
+        
+        let frames = recorder.len();
+        match recorder.write(config, result.scenario.name(), self.seed, result.passed, Some(result.rms_mean)) {
+            Ok(Some(path)) => info!("  Captured {} frames to {}", frames, path.display()),
+            Ok(None) => {}
+            Err(e) => warn!("  Failed to write failure capture: {}", e),
+        }
+    }
+    
+    /// TimeWarp's world: one agent behind a reordering, duplicating sensor
+    /// link (with the runner's sensor latency, if any), and ten fast-moving
+    /// entities.
+    fn time_warp_world(&self, timing: RunTiming) -> Simulation {
+        // Single agent; 20% of readings arrive behind up to 15 later ones,
+        // 5% arrive twice
+        let mut sim = Simulation::from_config(SimConfig {
+            seed: self.seed,
+            num_agents: 1,
+            tick_rate_hz: timing.tick_rate_hz,
+            max_duration_secs: timing.duration_secs,
+            sensor_faults: Some(SensorLinkFaults {
+                reorder_probability: 0.2,
+                max_displacement: 15,
+                duplicate_probability: 0.05,
+                latency: self.sensor_latency,
+            }),
+            // Tracks are predicted forward every tick; without it a 50 m/s
+            // track lags each reading it fuses by most of a tick's travel
+            agent_config: AgentConfig { filter: Some(FilterTuning::default()), ..SimulatedAgent::default_config() },
+            ..SimConfig::default()
+        });
+        
+        // Spawn 10 fast-moving entities
+        for i in 0..10 {
+            let pos = Vector3::new(
+                (i as f64) * 100.0,
+                0.0,
+                100.0 + (i as f64) * 10.0,
+            );
+            let vel = Vector3::new(50.0, 10.0 * (i as f64 - 5.0), 0.0);
+            sim.oracle_mut().spawn_entity(pos, vel, "drone");
+        }
+        sim
+    }
+    
+    /// DST-001: TimeWarp - OOSM stress test with extreme jitter.
+    ///
+    /// Tests the Time Engine's ability to handle out-of-sequence measurements
+    /// with 0-500ms jitter and 20% packet reordering.
+    ///
+    /// **Enhanced**: Now processes through full SimulatedAgent → TrackManager pipeline.
+    /// **Assertion**: Post-warm-up mean track position error < 5m RMS vs ground truth.
+    fn run_time_warp(&self, timing: RunTiming) -> Result<ScenarioResult, ScenarioError> {
+        info!("DST-001: TimeWarp - OOSM stress test");
+        
+        let mut sim = self.time_warp_world(timing).with_event_bus(self.hooks.event_bus());
+        let mut metrics = ScenarioMetrics::default();
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut velocity_rms = self.rms_sampler(target_ticks as f64 * dt);
+        
+        let monitor = self.monitor(ScenarioId::TimeWarp, target_ticks);
+        
+        // Run simulation
+        for tick in 0..target_ticks {
+            let report = sim.step();
+            metrics.oosm_updates += report.readings_delivered;
+            rms.sample(sim.time(), sim.agents(), &sim.oracle().ground_truth_positions());
+            velocity_rms.sample_velocity(sim.time(), sim.agents(), &sim.oracle().ground_truth_states());
+            
+            // Progress log every 30 ticks (1 second)
+            if tick % 30 == 0 {
+                debug!("  t={:.1}s | entities={} | tracks={}", 
+                    sim.time(), 
+                    sim.oracle().active_entities().len(),
+                    sim.agents().iter().map(|a| a.track_count()).sum::<usize>()
+                );
+            }
+            
+            if monitor.observe(tick, sim.oracle(), sim.agents()) {
+                break;
+            }
+        }
+        
+        metrics.record_link_faults(sim.network_controller().total_link_fault_stats());
+        metrics.record_link_latency(&sim.network_controller().total_link_latency());
+        let tracks = sim.agents().first()
+            .ok_or_else(|| ScenarioError::agent(ScenarioId::TimeWarp, 0, "the simulation built no agent"))?
+            .track_count();
+        
+        // Assertion: RMS error should be < 5m (generous for OOSM stress),
+        // and the fast movers' velocity within TIME_WARP_MAX_VELOCITY_RMS
+        let max_acceptable_error = 5.0;
+        metrics.velocity_rms_mean = Some(velocity_rms.mean());
+        metrics.velocity_rms_p95 = Some(velocity_rms.p95());
+        let failure = rms.failure(max_acceptable_error)
+            .or_else(|| velocity_rms_failure(&velocity_rms, TIME_WARP_MAX_VELOCITY_RMS));
+        let passed = failure.is_none();
+        
+        info!("✓ TimeWarp complete: {} OOSM updates ({} reordered, {} duplicated), {} tracks, RMS error: {:.2}m (p95 {:.2}m), velocity {:.2}m/s (p95 {:.2}m/s)", 
+            metrics.oosm_updates, metrics.reordered_deliveries, metrics.duplicated_deliveries,
+            tracks, rms.mean(), rms.p95(), velocity_rms.mean(), velocity_rms.p95());
+        if let Some(model) = self.sensor_latency {
+            info!("  Sensor latency {}: p50 {}ms, p95 {}ms, p99 {}ms", model,
+                metrics.link_latency_p50_ms, metrics.link_latency_p95_ms, metrics.link_latency_p99_ms);
+        }
+        
+        Ok(ScenarioResult {
+            scenario: ScenarioId::TimeWarp,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: sim.time(),
+            final_entity_count: sim.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        })
+    }
+    
+    /// DST-002: SplitBrain - Network partition and CRDT convergence.
+    ///
+    /// Two groups of agents observe a shared entity under different local
+    /// UUIDs while partitioned. At the heal, the group holding the minimum
+    /// UUID loses sight of the entity for [`SPLIT_BRAIN_BLIND_SECS`], so push
+    /// gossip can't carry that UUID across until it sees it again, while
+    /// anti-entropy digests ([`GossipMode::PushPull`]) can right away. After
+    /// the heal, every agent must settle on the same (minimum) canonical ID
+    /// and agree on the fused position, in both modes. The groups' fused
+    /// positions must stay within [`MAX_PARTITION_DIVERGENCE_M`] of each
+    /// other throughout, and come back within the position tolerance soon
+    /// after the heal. The same run with push gossip only is the baseline
+    /// anti-entropy must beat by [`SPLIT_BRAIN_MIN_SPEEDUP`].
+    fn run_split_brain(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-002: SplitBrain - Network partition test");
+        
+        let mut push = self.split_brain_run(timing, GossipMode::Push);
+        let mut run = self.split_brain_run(timing, GossipMode::PushPull {
+            digest_interval_ticks: SPLIT_BRAIN_DIGEST_INTERVAL_TICKS,
+        });
+        let failure = run.failure.take().or_else(|| push.failure.take()).or_else(|| anti_entropy_failure(
+            run.metrics.convergence_time_secs,
+            push.metrics.convergence_time_secs,
+        ));
+        let passed = failure.is_none();
+        
+        info!("  Push only: converged {} after heal | push-pull: {} ({} digest packets)",
+            fmt_secs(push.metrics.convergence_time_secs),
+            fmt_secs(run.metrics.convergence_time_secs),
+            run.metrics.digest_packets);
+        
+        ScenarioResult {
+            scenario: ScenarioId::SplitBrain,
+            seed: self.seed,
+            passed,
+            total_ticks: timing.target_ticks(),
+            final_time_secs: run.final_time_secs,
+            final_entity_count: run.final_entity_count,
+            failure,
+            rms_mean: 0.0,
+            rms_p95: 0.0,
+            rms_samples: 0,
+            metrics: ScenarioMetrics {
+                push_convergence_time_secs: push.metrics.convergence_time_secs,
+                ..run.metrics
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// Runs the SplitBrain partition once, with every agent gossiping in
+    /// `mode`.
+    fn split_brain_run(&self, timing: RunTiming, mode: GossipMode) -> SplitBrainRun {
+        use rand::Rng;
+        
+        let context_seed = self.seed;
+        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
+        
+        let context = SimContext::shared(context_seed);
+        let mut oracle = Oracle::new(physics_seed);
+        let network_controller = SimNetworkController::with_seed(self.seed);
+        network_controller.set_event_bus(self.hooks.event_bus());
+        let key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        // Picks each digest's recipient
+        let mut rng = key_provider.derive_stream("runner/digests");
+        
+        // Slow-moving shared entity observed by both partitions
+        let entity_id = oracle.spawn_entity(
             Vector3::new(0.0, 0.0, 100.0),
-            Vector3::new(10.0, 0.0, 0.0),
+            Vector3::new(0.5, 0.2, 0.0),
             "shared_target",
         );
         
-        let mut metrics = ScenarioMetrics::default();
-        let dt = 1.0 / self.tick_rate_hz as f64;
-        let target_ticks = (self.max_duration_secs * self.tick_rate_hz as f64) as u64;
+        // Coarse H3 cells keep the entity inside one k-ring between
+        // updates, so readings and gossip gate against the existing track
+        let num_agents = self.num_agents;
+        let mut agents: Vec<SimulatedAgent> = (0..num_agents)
+            .map(|i| SimulatedAgent::new(
+                context.clone(),
+                Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64))),
+                root_key,
+                i as u64,
+                AgentConfig { h3_resolution: 2, ..SimulatedAgent::default_config() },
+            ))
+            .collect();
+        for agent in agents.iter_mut() {
+            agent.set_gossip_mode(mode, false);
+        }
+        let nodes: Vec<NodeId> = (0..num_agents).map(|i| NodeId::from_seed(i as u64)).collect();
+        
+        let mut metrics = ScenarioMetrics::default();
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let gossip_interval = 5;
+        
+        // Partitioned from the start, healed at t=10s (or half the run);
+        // agents must converge within 5s of the heal
+        let half = num_agents / 2;
+        let group_a = nodes[..half].to_vec();
+        let group_b = nodes[half..].to_vec();
+        let partition_end = ((10.0 * timing.tick_rate_hz as f64) as u64).min(target_ticks / 2);
+        let convergence_deadline_secs = 5.0;
+        let position_tolerance = 3.0;
+        
+        network_controller.partition(group_a.clone(), group_b.clone());
+        info!("  {:?}: partition {} | {} agents until t={:.1}s",
+            mode, group_a.len(), group_b.len(), partition_end as f64 * dt);
+        
+        let mut diverged_during_partition = false;
+        let mut heal_time = None;
+        let mut converged_at = None;
+        let mut divergence = PartitionDivergence::default();
+        let mut reconverged_at = None;
+        // The group whose UUID wins loses sight of the entity right after
+        // its last gossip round inside the partition, leaving that UUID to
+        // spread from tracks alone until it sees the entity again
+        let blind_from = partition_end.saturating_sub(1) / gossip_interval * gossip_interval + 1;
+        let blind_until = partition_end + (SPLIT_BRAIN_BLIND_SECS * timing.tick_rate_hz as f64) as u64;
+        let mut blind = 0..0;
+        
+        let monitor = self.monitor(ScenarioId::SplitBrain, target_ticks);
+        
+        for tick in 0..target_ticks {
+            if tick == partition_end {
+                info!("  ✓ Healing network partition at t={:.1}s", oracle.time());
+                network_controller.heal_all();
+                heal_time = Some(oracle.time());
+            }
+            if tick == blind_from {
+                let min_holder = (0..num_agents)
+                    .filter_map(|i| Some((agents[i].local_entity_uuid(entity_id)?, i)))
+                    .min()
+                    .map(|(_, i)| i);
+                blind = match min_holder {
+                    Some(i) if i < half => 0..half,
+                    Some(_) => half..num_agents,
+                    None => 0..0,
+                };
+            }
+            if tick == blind_until {
+                blind = 0..0;
+            }
+            
+            oracle.step(dt);
+            context.advance_time(Duration::from_secs_f64(dt));
+            
+            // Each agent gets its own noisy reading of the entity
+            for (i, agent) in agents.iter_mut().enumerate() {
+                let readings = oracle.generate_sensor_readings();
+                agent.tick();
+                if !blind.contains(&i) {
+                    agent.ingest_readings(&readings);
+                }
+            }
+            
+            // Full-mesh gossip, filtered by the partition
+            if tick % gossip_interval == 0 {
+                let outgoing: Vec<Vec<_>> = agents.iter().map(|a| a.recent_packets().to_vec()).collect();
+                for (to, agent) in agents.iter_mut().enumerate() {
+                    for (from, packets) in outgoing.iter().enumerate() {
+                        if from == to || packets.is_empty() {
+                            continue;
+                        }
+                        metrics.packets_sent += packets.len() as u64;
+                        if !network_controller.can_communicate(nodes[from], nodes[to]) {
+                            metrics.packets_dropped += packets.len() as u64;
+                            continue;
+                        }
+                        agent.receive_gossip_from(from, packets);
+                    }
+                }
+                agents.iter_mut().for_each(|a| a.clear_recent_packets());
+            }
+            
+            // Anti-entropy: agents due send a random neighbor their digest
+            // and take its answer
+            for from in 0..num_agents {
+                if !agents[from].digest_due() {
+                    continue;
+                }
+                let to = (from + rng.gen_range(1..num_agents)) % num_agents;
+                if !network_controller.can_communicate(nodes[from], nodes[to]) {
+                    continue;
+                }
+                let digest = agents[from].gossip_digest();
+                let packets = agents[to].answer_digest(&digest);
+                metrics.digest_packets += packets.len() as u64;
+                agents[from].receive_gossip_from(to, &packets);
+            }
+            
+            let view = split_brain_view(&agents, entity_id);
+            let agreed = view.as_ref().is_some_and(|v| v.single_id && v.spread <= position_tolerance);
+            
+            let partitioned = tick < partition_end;
+            if let Some(sample) = partition_sample(&agents, half, entity_id, oracle.time(), partitioned) {
+                if partitioned {
+                    divergence.peak_divergence_m = divergence.peak_divergence_m.max(sample.divergence_m);
+                } else if tick == partition_end {
+                    divergence.divergence_at_heal_m = Some(sample.divergence_m);
+                }
+                if !partitioned {
+                    let within = sample.divergence_m <= position_tolerance;
+                    reconverged_at = if within { reconverged_at.or(Some(oracle.time())) } else { None };
+                }
+                divergence.peak_canonical_ids = divergence.peak_canonical_ids.max(sample.canonical_ids);
+                if tick % gossip_interval == 0 {
+                    divergence.series.push(sample);
+                }
+                monitor.record(tick, oracle.time(), "partition_divergence_m", sample.divergence_m);
+                monitor.record(tick, oracle.time(), "canonical_ids", sample.canonical_ids as f64);
+            }
+            
+            if partitioned {
+                if let Some(v) = &view {
+                    diverged_during_partition |= !v.single_id;
+                }
+            } else if agreed && converged_at.is_none() {
+                converged_at = Some(oracle.time());
+            } else if !agreed {
+                converged_at = None;
+            }
+            
+            if tick % 30 == 0 {
+                debug!("  t={:.1}s | partitioned={} | agreed={}",
+                    oracle.time(), tick < partition_end, agreed);
+            }
+            
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
+            }
+        }
+        
+        let final_view = split_brain_view(&agents, entity_id);
+        let global_min = (0..num_agents)
+            .filter_map(|i| agents[i].local_entity_uuid(entity_id))
+            .min();
+        let converged_on_min = final_view.as_ref()
+            .is_some_and(|v| v.single_id && Some(v.canonical_id) == global_min);
+        
+        metrics.convergence_time_secs = match (heal_time, converged_at) {
+            (Some(heal), Some(at)) => Some((at - heal).max(0.0)),
+            _ => None,
+        };
+        divergence.reconverge_secs = match (heal_time, reconverged_at) {
+            (Some(heal), Some(at)) => Some((at - heal).max(0.0)),
+            _ => None,
+        };
+        let failure = split_brain_failure(
+            diverged_during_partition,
+            converged_on_min,
+            final_view.as_ref(),
+            metrics.convergence_time_secs,
+            convergence_deadline_secs,
+        ).or_else(|| partition_divergence_failure(&divergence, convergence_deadline_secs));
+        
+        info!("  {:?}: {} packets dropped during partition, converged {} after heal, spread {:.2}",
+            mode,
+            metrics.packets_dropped,
+            fmt_secs(metrics.convergence_time_secs),
+            final_view.as_ref().map(|v| v.spread).unwrap_or(f64::NAN));
+        info!("  Divergence: peak {:.2}m partitioned, {:.2}m at heal, back within {}m {:.2}s after | {} canonical IDs at most",
+            divergence.peak_divergence_m,
+            divergence.divergence_at_heal_m.unwrap_or(f64::NAN),
+            position_tolerance,
+            divergence.reconverge_secs.unwrap_or(f64::NAN),
+            divergence.peak_canonical_ids);
+        metrics.partition_divergence = Some(divergence);
+        
+        SplitBrainRun {
+            metrics,
+            failure,
+            final_time_secs: oracle.time(),
+            final_entity_count: oracle.active_entities().len(),
+        }
+    }
+    
+    /// DST-003: Byzantine - Malicious agent with delayed revocation.
+    ///
+    /// Tests Trust Engine's ability to revoke a malicious agent's credentials.
+    /// Every agent signs its gossip with its own key. Agent 0 is compromised and
+    /// gossips conflicting positions; mid-run its key is rotated in the registry
+    /// without the new key reaching it, while honest agent 1 is rotated normally.
+    ///
+    /// **Assertion**: No stale-key rejections before the rotation, every envelope
+    /// agent 0 sends afterwards is rejected as stale, and agent 1's envelopes
+    /// signed with its new key are still accepted.
+    fn run_byzantine(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-003: Byzantine - Malicious agent test");
+        
+        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
+        let mut oracle = Oracle::new(physics_seed);
+        for i in 0..5 {
+            oracle.spawn_entity(
+                Vector3::new(i as f64 * 40.0, 0.0, 100.0),
+                Vector3::new(10.0, 2.0 * (i as f64 - 2.0), 0.0),
+                "target",
+            );
+        }
+        
+        let mut key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        let num_agents = self.num_agents;
+        let mut registry = KeyRegistry::from_provider(&key_provider, num_agents);
+        
+        // Agent 0 is the malicious agent, agent 1 an honest agent rotated alongside it
+        let malicious_agent = 0usize;
+        let rotated_honest = 1usize;
+        
+        let mut agents: Vec<SimulatedAgent> = (0..num_agents)
+            .map(|i| {
+                let mut agent = SimulatedAgent::new(
+                    Arc::new(SimContext::new(self.seed.wrapping_add(i as u64))),
+                    Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64))),
+                    root_key,
+                    i as u64,
+                    SimulatedAgent::default_config(),
+                );
+                agent.set_signing_key(key_provider.agent_signing_key(i as u64));
+                agent
+            })
+            .collect();
+        
+        let mut metrics = ScenarioMetrics::default();
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let gossip_interval = 5;
+        
+        // Revocation happens at 15 seconds, or halfway through shorter runs
+        let revocation_secs = 15.0_f64.min(timing.duration_secs / 2.0);
+        let revocation_tick = (revocation_secs * timing.tick_rate_hz as f64) as u64;
+        let mut revoked = false;
+        
+        let mut stale_before_revocation = 0u64;
+        let mut malicious_accepted_after = 0u64;
+        let mut rotated_accepted_after = 0u64;
+        
+        let monitor = self.monitor(ScenarioId::Byzantine, target_ticks);
+        
+        for tick in 0..target_ticks {
+            if tick == revocation_tick {
+                info!("  🔒 Rotating keys of agents {} and {} at t={:.1}s", malicious_agent, rotated_honest, revocation_secs);
+                
+                // The malicious agent never receives its new key
+                let new_key = key_provider.rotate(malicious_agent as u64, 1);
+                registry.register(malicious_agent as u64, 1, new_key.verifying_key());
+                
+                let new_key = key_provider.rotate(rotated_honest as u64, 1);
+                registry.register(rotated_honest as u64, 1, new_key.verifying_key());
+                agents[rotated_honest].set_signing_key(new_key);
+                
+                metrics.key_rotations = key_provider.rotations().len() as u64;
+                revoked = true;
+            }
+            
+            oracle.step(dt);
+            let readings = oracle.generate_sensor_readings();
+            
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                agent.tick();
+                if idx == malicious_agent {
+                    // Conflicting track data: every target reported 50m off
+                    let lies: Vec<_> = readings.iter()
+                        .map(|r| SensorReading { position: r.position + Vector3::new(50.0, 0.0, 0.0), ..r.clone() })
+                        .collect();
+                    agent.ingest_readings(&lies);
+                } else {
+                    agent.ingest_readings(&readings);
+                }
+            }
+            
+            if tick % gossip_interval == 0 {
+                let envelopes: Vec<_> = agents.iter()
+                    .enumerate()
+                    .filter_map(|(idx, a)| a.sign_recent_packets().map(|e| (idx, e)))
+                    .collect();
+                
+                for (from, envelope) in &envelopes {
+                    for (to, agent) in agents.iter_mut().enumerate() {
+                        if to == *from {
+                            continue;
+                        }
+                        metrics.packets_sent += 1;
+                        
+                        match agent.receive_signed_gossip(*from, envelope, &registry) {
+                            Ok(_) if revoked && *from == malicious_agent => malicious_accepted_after += 1,
+                            Ok(_) if revoked && *from == rotated_honest => rotated_accepted_after += 1,
+                            Ok(_) => {}
+                            Err(rejection) => {
+                                metrics.packets_dropped += 1;
+                                if matches!(rejection, GossipRejection::StaleKey { .. }) {
+                                    metrics.stale_key_rejections += 1;
+                                    if !revoked {
+                                        stale_before_revocation += 1;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                
+                for agent in agents.iter_mut() {
+                    agent.clear_recent_packets();
+                }
+            }
+            
+            if tick % 30 == 0 {
+                debug!("  t={:.1}s | revoked={} | stale rejections={}", oracle.time(), revoked, metrics.stale_key_rejections);
+            }
+            
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
+            }
+        }
+        
+        let failure = byzantine_failure(
+            revoked,
+            stale_before_revocation,
+            metrics.stale_key_rejections,
+            malicious_accepted_after,
+            rotated_accepted_after,
+        );
+        let passed = failure.is_none();
+        
+        match &failure {
+            None => info!("✓ Byzantine complete: {} stale-key envelopes rejected after {} rotations", 
+                metrics.stale_key_rejections, metrics.key_rotations),
+            Some(failure) => warn!("✗ Byzantine failed: {}", failure),
+        }
+        
+        ScenarioResult {
+            scenario: ScenarioId::Byzantine,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: oracle.time(),
+            final_entity_count: oracle.active_entities().len(),
+            failure,
+            rms_mean: 0.0,
+            rms_p95: 0.0,
+            rms_samples: 0,
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// DST-004: FlashMob - H3 boundary crossing stress test.
+    ///
+    /// Tests Space Engine with 1000 drones crossing H3 cell boundaries rapidly.
+    /// A single agent tracks all of them.
+    ///
+    /// **Success Criteria**: MOTA >= 0.9 after warm-up.
+    fn run_flash_mob(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-004: FlashMob - H3 boundary crossing stress test");
+        
+        let context_seed = self.seed;
+        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
+        
+        let context = SimContext::shared(context_seed);
+        let mut oracle = Oracle::new(physics_seed);
+        
+        // Spawn 1000 fast-moving drones in a grid
+        let num_drones = 1000;
+        for i in 0..num_drones {
+            let x = (i % 100) as f64 * 10.0;
+            let y = (i / 100) as f64 * 10.0;
+            let vel_x = 100.0 * ((i % 2) as f64 * 2.0 - 1.0); // Alternating directions
+            let vel_y = 50.0 * ((i / 100 % 2) as f64 * 2.0 - 1.0);
+            
+            oracle.spawn_entity(
+                Vector3::new(x, y, 50.0),
+                Vector3::new(vel_x, vel_y, 0.0),
+                "drone",
+            );
+        }
+        
+        // One agent tracks every drone, predicting between readings
+        let key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        let config = AgentConfig {
+            tick_rate_hz: timing.tick_rate_hz,
+            filter: Some(FilterTuning::default()),
+            ..SimulatedAgent::default_config()
+        };
+        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+        let mut tracker = SimulatedAgent::new(Arc::clone(&context), network, root_key, 0, config);
+        
+        let mut metrics = ScenarioMetrics::default();
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut mot = self.mot_sampler(target_ticks as f64 * dt);
+        
+        let monitor = self.monitor(ScenarioId::FlashMob, target_ticks);
+        
+        for tick in 0..target_ticks {
+            oracle.step(dt);
+            context.advance_time(Duration::from_secs_f64(dt));
+            
+            // Generate readings for all drones (stress test)
+            let readings = oracle.generate_sensor_readings();
+            metrics.oosm_updates += readings.len() as u64;
+            tracker.tick();
+            tracker.ingest_readings(&readings);
+            // Nobody gossips here; don't let the send buffer grow all run
+            tracker.clear_recent_packets();
+            mot.sample(oracle.time(), [&tracker], &oracle.ground_truth_positions());
+            
+            if tick % 30 == 0 {
+                debug!("  t={:.1}s | drones={} | readings/tick={}", 
+                    oracle.time(), 
+                    oracle.active_entities().len(),
+                    readings.len()
+                );
+            }
+            
+            if monitor.observe(tick, &oracle, [&tracker]) {
+                break;
+            }
+        }
+        
+        info!("✓ FlashMob complete: processed {} sensor readings for {} drones", 
+            metrics.oosm_updates, num_drones);
+        metrics.wide_associations = tracker.wide_associations();
+        info!("  Associations beyond the k=1 ring: {}", metrics.wide_associations);
+        metrics.record_mot(&mot);
+        
+        // Crossing drones cost a few ID switches; misses or ghosts would
+        // cost far more
+        let min_mota = 0.9;
+        let mota = mot.metrics().mota();
+        let failure = flash_mob_failure(mota, min_mota);
+        let passed = failure.is_none();
+        
+        ScenarioResult {
+            scenario: ScenarioId::FlashMob,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: oracle.time(),
+            final_entity_count: oracle.active_entities().len(),
+            failure,
+            rms_mean: 0.0,
+            rms_p95: 0.0,
+            rms_samples: 0,
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// DST-005: SlowLoris - High packet loss recovery.
+    ///
+    /// A small swarm tracks a handful of entities, each sensed by one agent,
+    /// over gossip links that drop half their packets. Every gossip round
+    /// re-broadcasts each agent's current observations, relayed a few hops,
+    /// so a lost packet leaves a gap only until the next round: tracks must
+    /// still converge, and every entity must be tracked swarm-wide within
+    /// [`SLOW_LORIS_SPREAD_DEADLINE_SECS`] of its first observation. The
+    /// same swarm at the lower [`SLOW_LORIS_LOSS_RATES`] gives the
+    /// degradation curve.
+    fn run_slow_loris(&self, timing: RunTiming) -> Result<ScenarioResult, ScenarioError> {
+        info!("DST-005: SlowLoris - 50% packet loss test");
+        
+        let runs: Vec<SlowLorisRun> = SLOW_LORIS_LOSS_RATES.iter()
+            .map(|&loss_rate| self.slow_loris_run(timing, loss_rate))
+            .collect();
+        let scored = runs.last()
+            .ok_or_else(|| ScenarioError::internal(ScenarioId::SlowLoris, "no loss rates to run"))?;
+        let spread = &scored.spread;
+        let failure = slow_loris_failure(scored.rms.failure(SLOW_LORIS_MAX_RMS), spread);
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  SLOW LORIS RESULTS:");
+        for run in &runs {
+            info!("    {:>3.0}% loss: RMS {:.2}m, {}/{} entities swarm-wide, spread mean {} max {}",
+                run.spread.loss_rate * 100.0, run.rms.mean(), run.spread.spread, run.spread.observed,
+                fmt_secs(run.spread.mean_spread_secs), fmt_secs(run.spread.max_spread_secs));
+        }
+        info!("    Packets:   {} sent, {} lost", scored.packets_sent, scored.packets_lost);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        Ok(ScenarioResult {
+            scenario: ScenarioId::SlowLoris,
+            seed: self.seed,
+            passed,
+            total_ticks: scored.ticks,
+            final_time_secs: scored.final_time_secs,
+            final_entity_count: scored.final_entity_count,
+            failure,
+            rms_mean: scored.rms.mean(),
+            rms_p95: scored.rms.p95(),
+            rms_samples: scored.rms.len(),
+            metrics: ScenarioMetrics {
+                packets_sent: scored.packets_sent,
+                packets_dropped: scored.packets_lost,
+                loss_spread: runs.iter().map(|r| r.spread).collect(),
+                ..ScenarioMetrics::default()
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        })
+    }
+    
+    /// Runs the SlowLoris swarm once with `loss_rate` on every gossip link.
+    fn slow_loris_run(&self, timing: RunTiming, loss_rate: f64) -> SlowLorisRun {
+        // Two rows with diagonal links keep every agent within a few hops
+        let cols = self.num_agents.div_ceil(2);
+        let num_agents = 2 * cols;
+        let num_entities = ScenarioId::SlowLoris.entities();
+        let mut sim = Simulation::from_config(SimConfig {
+            seed: self.seed,
+            num_agents,
+            tick_rate_hz: timing.tick_rate_hz,
+            max_duration_secs: timing.duration_secs,
+            agent_config: AgentConfig { filter: Some(FilterTuning::default()), ..SimulatedAgent::default_config() },
+            // Each entity is sensed by one agent; the rest only hear of it
+            visibility: Visibility::Striped { period: num_agents, visible: 1 },
+            gossip: Some(GossipConfig {
+                rows: 2,
+                cols,
+                interval_ticks: 3,
+                relay: GossipRelay::Hops(cols as u8),
+                max_packet_age_ms: None,
+            }),
+            ..SimConfig::default()
+        }).with_event_bus(self.hooks.event_bus());
+        sim.inject_event(SimEventRequest::PacketLoss { rate: loss_rate });
+        // Every agent is blind to most entities, so a neighbor relaying one
+        // is useful however well it is already tracked
+        for agent in sim.agents_mut() {
+            agent.set_blind_spot_credit(true);
+        }
+        
+        // Entities appear one by one over the first half of the run, so each
+        // has its own first observation to spread from
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let spawn_every = (target_ticks / (2 * num_entities as u64)).max(1);
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut spread = SpreadSampler::new(loss_rate);
+        
+        info!("  {:.0}% loss: {} agents, {} entities, {} ticks", loss_rate * 100.0, num_agents, num_entities, target_ticks);
+        
+        let monitor = self.monitor(ScenarioId::SlowLoris, target_ticks);
+        let mut ticks = 0;
+        for tick in 0..target_ticks {
+            if tick % spawn_every == 0 && sim.oracle().active_entities().len() < num_entities {
+                let i = sim.oracle().active_entities().len() as f64;
+                sim.oracle_mut().spawn_entity(Vector3::new(i * 200.0, 0.0, 100.0), Vector3::new(20.0, 0.0, 0.0), "vehicle");
+            }
+            sim.step();
+            ticks += 1;
+            let ground_truth = sim.oracle().ground_truth_positions();
+            rms.sample(sim.time(), sim.agents(), &ground_truth);
+            spread.observe(sim.time(), sim.agents(), &ground_truth);
+            
+            if monitor.observe(tick, sim.oracle(), sim.agents()) {
+                break;
+            }
+        }
+        
+        let routes = sim.swarm_network().map(|n| n.route_stats()).unwrap_or_default();
+        SlowLorisRun {
+            spread: spread.stats(),
+            rms,
+            packets_sent: routes.sent,
+            packets_lost: routes.dropped_loss,
+            ticks,
+            final_time_secs: sim.time(),
+            final_entity_count: sim.oracle().active_entities().len(),
+        }
+    }
+    
+    /// Swarm's world: the configured grid of gossiping agents, each seeing
+    /// half of the entities, plus an observer.
+    fn swarm_world(&self, timing: RunTiming) -> Result<Simulation, ScenarioError> {
+        let config = &self.swarm_config;
+        let num_agents = config.rows * config.cols;
+        let mut sim = Simulation::from_config(SimConfig {
+            seed: self.seed,
+            num_agents,
+            tick_rate_hz: timing.tick_rate_hz,
+            max_duration_secs: timing.duration_secs,
+            agent_config: AgentConfig { record_genealogy: true, ..SimulatedAgent::default_config() },
+            // Each agent sees half of the entities (simulating limited sensor range)
+            visibility: Visibility::Striped { period: 10, visible: 5 },
+            gossip: Some(GossipConfig {
+                rows: config.rows,
+                cols: config.cols,
+                interval_ticks: config.gossip_interval as u64,
+                relay: GossipRelay::Off,
+                max_packet_age_ms: None,
+            }),
+            ..SimConfig::default()
+        })
+        .with_observer();
+        
+        // 200 entities moving through space
+        spawn_swarm_targets(sim.oracle_mut(), config.num_entities);
+        if sim.oracle().active_entities().is_empty() {
+            return Err(ScenarioError::oracle(ScenarioId::Swarm, "no entities to track (swarm config has num_entities = 0)"));
+        }
+        Ok(sim)
+    }
+    
+    /// DST-006: Swarm - 50-agent multi-agent scale test.
+    ///
+    /// Tests multi-agent coordination with P2P gossip:
+    /// - 50 agents in 5x10 grid
+    /// - 200 entities moving through space
+    /// - P2P gossip between neighbors every 3 ticks
+    /// - Measures convergence: entity count variance, position error
+    fn run_swarm(&self, timing: RunTiming) -> Result<ScenarioResult, ScenarioError> {
+        info!("DST-006: Swarm - 50-Agent Scale Test");
+        
+        let config = &self.swarm_config;
+        let num_agents = config.rows * config.cols; // 50
+        let mut sim = self.swarm_world(timing)?.with_event_bus(self.hooks.event_bus());
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut mot = self.mot_sampler(target_ticks as f64 * dt);
+        let mut divergence = self.divergence_sampler(target_ticks as f64 * dt);
+        
+        info!("  Agents: {} | Entities: {} | Ticks: {}", num_agents, config.num_entities, target_ticks);
+        
+        // SplitBrain-style partition between the grid's left and right halves
+        // for the middle third of the run
+        let (partition_start, partition_end) = (target_ticks / 3, 2 * target_ticks / 3);
+        let (left, right): (Vec<usize>, Vec<usize>) = (0..num_agents)
+            .partition(|&i| i % config.cols < config.cols / 2);
+        let cross_deliveries = |sim: &Simulation| {
+            let network = sim.swarm_network()
+                .ok_or_else(|| ScenarioError::internal(ScenarioId::Swarm, "simulation has no gossip network"))?;
+            Ok(left.iter()
+                .flat_map(|&a| right.iter().map(move |&b| (a, b)))
+                .map(|(a, b)| network.envelopes_delivered(a, b) + network.envelopes_delivered(b, a))
+                .sum::<u64>())
+        };
+        let mut partition_blocked = 0;
+        let mut partition_leaks = 0;
+        let mut deliveries_at_split = None;
+        
+        let monitor = self.monitor(ScenarioId::Swarm, target_ticks);
+        
+        // Main simulation loop
+        for tick in 0..target_ticks {
+            if tick == partition_start {
+                deliveries_at_split = Some(cross_deliveries(&sim)?);
+                sim.inject_event(SimEventRequest::Partition { group_a: left.clone(), group_b: right.clone() });
+            }
+            if tick == partition_end {
+                if let Some(at_split) = deliveries_at_split.take() {
+                    partition_leaks = cross_deliveries(&sim)? - at_split;
+                }
+                sim.inject_event(SimEventRequest::Heal);
+            }
+            
+            let report = sim.step();
+            if (partition_start..partition_end).contains(&tick) {
+                partition_blocked += report.gossip_blocked;
+            }
+            let ground_truth = sim.oracle().ground_truth_positions();
+            rms.sample(sim.time(), sim.agents(), &ground_truth);
+            mot.sample(sim.time(), sim.agents(), &ground_truth);
+            if report.gossip_round {
+                if let Some(observer) = sim.observer() {
+                    divergence.sample(sim.time(), observer, sim.agents(), &ground_truth);
+                }
+            }
+            
+            // Progress log every second
+            if tick % timing.tick_rate_hz as u64 == 0 && tick > 0 {
+                let avg_tracks = swarm_convergence(sim.agents(), &[], every_agent).track_mean;
+                debug!("  t={:.0}s | avg_tracks={:.1} | gossip_msgs={}", 
+                    tick as f64 / timing.tick_rate_hz as f64,
+                    avg_tracks,
+                    sim.swarm_network().map_or(0, |n| n.messages_sent())
+                );
+            }
+            
+            if monitor.observe(tick, sim.oracle(), sim.agents()) {
+                break;
+            }
+        }
+        
+        // A run aborted mid-partition is checked up to where it stopped
+        if let Some(at_split) = deliveries_at_split {
+            partition_leaks = cross_deliveries(&sim)? - at_split;
+        }
+        
+        let agents = sim.agents();
+        let messages_sent = sim.swarm_network().map_or(0, |n| n.messages_sent());
+        let bytes_sent = sim.swarm_network().map_or(0, |n| n.bytes_sent());
+        
+        // Compute convergence metrics
+        let convergence = swarm_convergence(agents, &sim.oracle().ground_truth_positions(), every_agent);
+        let (mean_count, coefficient_of_variation) = (convergence.track_mean, convergence.track_cv);
+        
+        // Total gossip stats
+        let total_gossip: u64 = agents.iter().map(|a| a.gossip_received()).sum();
+        
+        // Check pass criteria
+        let max_variance = self.limit(ScenarioId::Swarm, "track_count_cv", config.max_variance);
+        let rms_limits = self.rms_limits(ScenarioId::Swarm, config.max_position_error);
+        let min_mota = self.limit(ScenarioId::Swarm, "mota", config.min_mota);
+        let variance_ok = coefficient_of_variation < max_variance;
+        let error_ok = rms.within_limits(rms_limits);
+        let partition_ok = partition_blocked > 0 && partition_leaks == 0;
+        let gaps = sequence_totals(agents);
+        let gaps_ok = gaps.gaps_detected == partition_blocked;
+        let mota = mot.metrics().mota();
+        let mota_ok = mota >= min_mota;
+        let failure = swarm_failure(
+            coefficient_of_variation,
+            max_variance,
+            rms.failure_limits(rms_limits),
+            (mota, min_mota),
+            partition_blocked,
+            partition_leaks,
+            gaps.gaps_detected,
+        );
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  Agents: {} | Entities: {} | P2P Messages: {}", num_agents, config.num_entities, messages_sent);
+        info!("  Partition t={:.1}s-{:.1}s: {} envelopes blocked, {} crossed  {}",
+            partition_start as f64 * dt, partition_end as f64 * dt,
+            partition_blocked, partition_leaks, if partition_ok { "✓" } else { "✗" });
+        info!("  Sequence gaps after heal: {} for {} blocked envelopes  {}",
+            gaps.gaps_detected, partition_blocked, if gaps_ok { "✓" } else { "✗" });
+        info!("  CONVERGENCE METRICS:");
+        info!("    Track count (mean):     {:.1}", mean_count);
+        info!("    Track count (CV):       {:.1}%  {}", coefficient_of_variation * 100.0, if variance_ok { "✓" } else { "✗" });
+        info!("    Avg RMS error:          {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if error_ok { "✓" } else { "✗" });
+        info!("    MOTA:                   {:.3} (min {})  {}", mota, min_mota, if mota_ok { "✓" } else { "✗" });
+        info!("    Total gossip received:  {}", total_gossip);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        let mut metrics = ScenarioMetrics {
+            packets_sent: messages_sent,
+            packets_dropped: partition_blocked,
+            bytes_sent,
+            ..Default::default()
+        };
+        metrics.set_extra("track_count_cv", coefficient_of_variation);
+        metrics.record_sequence_gaps(gaps);
+        metrics.record_genealogy(agents, sim.time(), config.num_entities);
+        info!("  Genealogy: {:.1} merges/min per agent, {:.2} tracks per entity",
+            metrics.merges_per_minute, metrics.avg_tracks_per_entity);
+        metrics.record_divergence(&divergence);
+        log_divergence(&metrics);
+        metrics.record_latency(agents);
+        info!("  Gossip latency: p50 {}ms, p95 {}ms, max {}ms",
+            metrics.latency_p50_ms, metrics.latency_p95_ms, metrics.max_latency_ms);
+        metrics.record_mot(&mot);
+        
+        Ok(ScenarioResult {
+            scenario: ScenarioId::Swarm,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: sim.time(),
+            final_entity_count: sim.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        })
+    }
+    
+    /// DST-007: AdaptiveSwarm - Learning agents with bad actor detection.
+    ///
+    /// Tests adaptive intelligence:
+    /// - 50 agents (45 good, 5 bad actors injected at t=10s)
+    /// - Agents learn to identify and ignore bad actors
+    /// - Measures: bad actors detected, accuracy maintained
+    ///
+    /// The swarm runs twice on the same seed: with the binary reputation
+    /// filter alone, which the pass criteria score, and with gossip
+    /// covariance also inflated by sender reliability
+    /// ([`TrustInflation::INVERSE`]), whose RMS is reported alongside.
+    fn run_adaptive_swarm(&self, timing: RunTiming) -> Result<ScenarioResult, ScenarioError> {
+        info!("DST-007: AdaptiveSwarm - Learning Agents");
+        
+        let inflated = self.adaptive_swarm_run(timing, TrustInflation::INVERSE)?;
+        let AdaptiveSwarmRun { harness, rms, divergence } = self.adaptive_swarm_run(timing, TrustInflation::OFF)?;
+        let num_agents = harness.agents().len();
+        let target_ticks = timing.target_ticks();
+        
+        // Count how many good agents identified bad actors (only among neighbors)
+        let (bad_actors_identified, possible_detections) = harness.bad_actor_detection(0.3);
+        
+        // Aggregate adaptive metrics
+        let agents = harness.agents();
+        let total_gossip_filtered: u64 = agents.iter()
+            .map(|a| a.adaptive_metrics().gossip_filtered)
+            .sum();
+        let total_tracks_dropped: u64 = agents.iter()
+            .map(|a| a.adaptive_metrics().tracks_dropped)
+            .sum();
+        let avg_efficiency: f64 = agents.iter()
+            .map(|a| a.adaptive_metrics().gossip_efficiency)
+            .sum::<f64>() / num_agents as f64;
+        let consensus = harness.reputation_consensus();
+        
+        // Check pass criteria
+        let detection_rate = if possible_detections > 0 {
+            bad_actors_identified as f64 / possible_detections as f64
+        } else {
+            0.0
+        };
+        
+        let min_detection_rate = self.limit(ScenarioId::AdaptiveSwarm, "detection_rate", MIN_DETECTION_RATE);
+        let detection_ok = detection_rate >= min_detection_rate || possible_detections == 0;
+        // RMS is scored over good agents only
+        let rms_limits = self.rms_limits(ScenarioId::AdaptiveSwarm, 5.0);
+        let error_ok = rms.within_limits(rms_limits);
+        let failure = adaptive_swarm_failure(
+            (detection_rate, min_detection_rate),
+            possible_detections,
+            rms.failure_limits(rms_limits),
+        );
+        let passed = failure.is_none();
+        let messages_sent = harness.network().messages_sent();
+        let bytes_sent = harness.network().bytes_sent();
+        let compression = self.swarm_config.delta_gossip.map(|_| harness.network().compression_stats());
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  Agents: {} ({} bad actors)", num_agents, harness.bad_actors().len());
+        info!("  P2P Messages: {}", messages_sent);
+        if let Some(stats) = &compression {
+            info!("  Delta gossip: {:.2}x ({} full, {} deltas, {} full packets requested)",
+                stats.ratio(), stats.full_frames, stats.delta_frames, stats.full_requests);
+        }
+        info!("  ADAPTIVE METRICS:");
+        info!("    Detection rate:      {:.0}%  {}", detection_rate * 100.0, if detection_ok { "✓" } else { "✗" });
+        info!("    Good agent RMS:      {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if error_ok { "✓" } else { "✗" });
+        info!("    + trust inflation:   {:.3}m (p95 {:.2}m) vs {:.3}m filter only",
+            inflated.rms.mean(), inflated.rms.p95(), rms.mean());
+        info!("    Gossip filtered:     {}", total_gossip_filtered);
+        info!("    Tracks auto-dropped: {}", total_tracks_dropped);
+        info!("    Gossip efficiency:   {:.0}%", avg_efficiency * 100.0);
+        info!("    Reputation consensus: {:.0}% of {} scores, {:.0}% swarm-wide detection, {} summaries ({} B)",
+            consensus.accuracy * 100.0, consensus.assessments, consensus.swarm_detection_rate * 100.0,
+            harness.reputation_messages(), harness.reputation_bytes());
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        let mut metrics = ScenarioMetrics {
+            packets_sent: messages_sent,
+            bytes_sent,
+            bad_actors_detected: bad_actors_identified as u64,
+            bad_actor_injection: harness.bad_actor_injection().cloned(),
+            gossip_compression: compression,
+            ..Default::default()
+        };
+        metrics.set_extra("detection_rate", detection_rate);
+        metrics.set_extra("gossip_filtered", total_gossip_filtered as f64);
+        metrics.set_extra("gossip_efficiency", avg_efficiency);
+        metrics.set_extra("inflated_rms_mean", inflated.rms.mean());
+        metrics.set_extra("consensus_accuracy", consensus.accuracy);
+        metrics.set_extra("swarm_detection_rate", consensus.swarm_detection_rate);
+        metrics.set_extra("reputation_messages", harness.reputation_messages() as f64);
+        metrics.set_extra("reputation_bytes", harness.reputation_bytes() as f64);
+        metrics.record_divergence(&divergence);
+        log_divergence(&metrics);
+        
+        Ok(ScenarioResult {
+            scenario: ScenarioId::AdaptiveSwarm,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        })
+    }
+    
+    /// Runs the AdaptiveSwarm simulation once with agents inflating gossip
+    /// covariance per `trust_inflation`.
+    fn adaptive_swarm_run(&self, timing: RunTiming, trust_inflation: TrustInflation) -> Result<AdaptiveSwarmRun, ScenarioError> {
+        let config = &self.swarm_config;
+        let num_agents = config.rows * config.cols; // 50
+        let num_bad_actors = config.num_bad_actors;
+        let bad_actor_inject_time = config.bad_actor_inject_secs;
+        
+        // Bad actors add 3 garbage packets per round, scattered over 2km
+        let garbage = GarbageSpec {
+            packets_per_round: 3,
+            min_position: [-1000.0; 3],
+            max_position: [1000.0; 3],
+            max_speed: 0.0,
+            confidence: (0.1, 0.1),
+        };
+        
+        // All agents start good; by default 5 distinct random ones turn at
+        // t=10s. The observer builds a reference picture from every reading
+        // and gossiped packet.
+        let mut harness = SwarmHarness::new(self.seed, config.rows, config.cols, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_threads(self.threads)
+            .with_visibility(Visibility::Striped { period: 10, visible: 5 })
+            .with_gossip_interval(config.gossip_interval as u64)
+            // Quarantining bad actors' IDs rekeys tracks; check the index survives
+            .with_index_verification(INDEX_VERIFY_INTERVAL_TICKS)
+            .with_bad_actors(
+                BadActorSchedule::RandomAt {
+                    count: num_bad_actors,
+                    at_secs: bad_actor_inject_time,
+                    placement: config.bad_actor_placement,
+                },
+                BadActorStrategy::Garbage(garbage),
+            )
+            .with_trust_inflation(trust_inflation)
+            .with_rng_seed(self.seed.wrapping_mul(0xdeadbeef))
+            .with_observer();
+        if let Some(sharing) = config.reputation_sharing {
+            harness = harness.with_reputation_sharing(sharing);
+        }
+        if let Some(compression) = config.delta_gossip {
+            harness = harness.with_delta_compression(compression);
+        }
+        
+        // 200 entities
+        for i in 0..config.num_entities {
+            let x = (i % 50) as f64 * 20.0;
+            let y = (i / 50) as f64 * 20.0;
+            let z = 100.0 + (i % 10) as f64 * 10.0;
+            let vx = 10.0 + (i % 5) as f64 * 2.0;
+            let vy = 5.0 * ((i % 3) as f64 - 1.0);
+            harness.oracle_mut().spawn_entity(Vector3::new(x, y, z), Vector3::new(vx, vy, 0.0), "target");
+        }
+        if harness.oracle().active_entities().is_empty() {
+            return Err(ScenarioError::oracle(ScenarioId::AdaptiveSwarm, "no entities to track (swarm config has num_entities = 0)"));
+        }
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut divergence = self.divergence_sampler(target_ticks as f64 * dt);
+        
+        info!("  Agents: {} ({} will become bad actors at t={}s), trust inflation exponent {}", 
+            num_agents, num_bad_actors, bad_actor_inject_time, trust_inflation.exponent);
+        
+        let monitor = self.monitor(ScenarioId::AdaptiveSwarm, target_ticks);
+        let mut bad_actors_announced = false;
+        
+        // Main simulation loop
+        for tick in 0..target_ticks {
+            let report = harness.step();
+            let current_time = tick as f64 * dt;
+            
+            if !bad_actors_announced && !harness.bad_actors().is_empty() {
+                info!("  ⚠️  Injecting {} bad actors at t={:.1}s: {:?}", 
+                    harness.bad_actors().len(), current_time, harness.bad_actors());
+                bad_actors_announced = true;
+            }
+            
+            let ground_truth = harness.oracle().ground_truth_positions();
+            rms.sample(harness.time(), harness.good_agents(), &ground_truth);
+            if report.gossip_round {
+                if let Some(observer) = harness.observer() {
+                    divergence.sample(harness.time(), observer, harness.good_agents(), &ground_truth);
+                }
+            }
+            
+            // Progress log every 5 seconds
+            if tick % (timing.tick_rate_hz * 5) as u64 == 0 && tick > 0 {
+                let (sum, count): (f64, i32) = harness.good_agents()
+                    .flat_map(|a| {
+                        harness.bad_actors().iter().filter_map(|bad_id| {
+                            a.adaptive_state().neighbor_reputations.get(bad_id)
+                                .map(|r| r.reliability_score)
+                        })
+                    })
+                    .fold((0.0, 0), |(sum, count), r| (sum + r, count + 1));
+                let avg_bad = if count > 0 { sum / count as f64 } else { 0.5 };
+                
+                debug!("  t={:.0}s | bad_actor_reliability={:.2}", 
+                    current_time, avg_bad);
+            }
+            
+            if monitor.observe(tick, harness.oracle(), harness.good_agents()) {
+                break;
+            }
+        }
+        
+        Ok(AdaptiveSwarmRun { harness, rms, divergence })
+    }
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // EXTREME CHAOS SCENARIOS - Push GodView to its absolute limits!
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// DST-008: ChaosStorm - Everything bad at once.
+    ///
+    /// Combines: jitter + 30% packet loss + bad actors + moving entities
+    fn run_chaos_storm(&self, timing: RunTiming) -> ScenarioResult {
+        use rand::Rng;
+        
+        info!("DST-008: ChaosStorm - EVERYTHING AT ONCE 🔥");
+        
+        let config = &self.swarm_config;
+        let num_agents = config.rows * config.cols;
+        let num_entities = config.num_entities;
+        // Every 10th agent: 5 of the default 50
+        let bad_actors: Vec<usize> = (0..num_agents).step_by(10).collect();
+        let num_bad_actors = bad_actors.len();
+        let packet_loss_rate = 0.30; // 30% loss
+        let max_jitter_ms = 500.0;
+        
+        let garbage = GarbageSpec {
+            packets_per_round: 3,
+            min_position: [-500.0, -500.0, 0.0],
+            max_position: [500.0, 500.0, 500.0],
+            max_speed: 0.0,
+            confidence: (0.1, 0.1),
+        };
+        
+        // Jitter: 20% of agent ticks are delayed (simulated by not processing)
+        let mut harness = SwarmHarness::new(self.seed, config.rows, config.cols, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_threads(self.threads)
+            .with_visibility(Visibility::Striped { period: 4, visible: 2 })
+            .with_packet_loss(packet_loss_rate)
+            .with_sensor_faults(SensorFaults::Jitter { skip_probability: 0.2 })
+            .with_bad_actors(
+                BadActorSchedule::Fixed(bad_actors),
+                BadActorStrategy::Garbage(garbage),
+            )
+            .with_rng_seed(self.seed.wrapping_mul(0xcafe8080));
+        
+        // MOVING entities
+        for i in 0..num_entities {
+            let x = (i % 50) as f64 * 20.0;
+            let y = (i / 50) as f64 * 20.0;
+            let z = 100.0 + (i % 10) as f64 * 10.0;
+            // Fast moving entities in random directions
+            let rng = harness.rng_mut();
+            let vx = (rng.gen::<f64>() - 0.5) * 40.0;
+            let vy = (rng.gen::<f64>() - 0.5) * 40.0;
+            let vz = (rng.gen::<f64>() - 0.5) * 10.0;
+            harness.oracle_mut().spawn_entity(Vector3::new(x, y, z), Vector3::new(vx, vy, vz), "chaos_target");
+        }
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        
+        info!("  Config: {} agents, {} entities, {}% loss, {}ms jitter, {} bad actors",
+            num_agents, num_entities, (packet_loss_rate * 100.0) as u32, 
+            max_jitter_ms as u32, num_bad_actors);
+        
+        let monitor = self.monitor(ScenarioId::ChaosStorm, target_ticks);
+        
+        for tick in 0..target_ticks {
+            harness.step();
+            
+            rms.sample(harness.time(), harness.good_agents(), &harness.oracle().ground_truth_positions());
+            
+            if monitor.observe(tick, harness.oracle(), harness.good_agents()) {
+                break;
+            }
+        }
+        
+        // Measure: Did we survive? What's the error?
+        let packets_sent = harness.packets_offered();
+        let packets_dropped = harness.packets_lost();
+        let (bytes_sent, bytes_dropped) = (harness.bytes_offered(), harness.bytes_lost());
+        let loss_rate = if packets_sent > 0 { packets_dropped as f64 / packets_sent as f64 } else { 0.0 };
+        let failure = rms.failure(CHAOS_STORM_MAX_RMS); // Relaxed threshold for chaos
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  CHAOS STORM RESULTS:");
+        info!("    RMS error:     {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if passed { "✓" } else { "✗" });
+        info!("    Packet loss:   {:.0}%", loss_rate * 100.0);
+        info!("    Messages:      {} sent, {} dropped", packets_sent, packets_dropped);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        let metrics = ScenarioMetrics {
+            packets_sent,
+            packets_dropped,
+            bytes_sent,
+            bytes_dropped,
+            ..Default::default()
+        };
+        
+        ScenarioResult {
+            scenario: ScenarioId::ChaosStorm,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// DST-009: ScaleLimit - 200 agents, 1000 entities.
+    fn run_scale_limit(&self, timing: RunTiming) -> ScenarioResult {
+        use crate::swarm_network::SwarmNetwork;
+        
+        info!("DST-009: ScaleLimit - 200 AGENTS, 1000 ENTITIES 🔥");
+        
+        let num_agents = 200;
+        let num_entities = 1000;
+        
+        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
+        let key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        
+        let mut oracle = Oracle::new(physics_seed);
+        for i in 0..num_entities {
+            let x = (i % 100) as f64 * 10.0;
+            let y = (i / 100) as f64 * 10.0;
+            let z = 50.0 + (i % 20) as f64 * 5.0;
+            oracle.spawn_entity(Vector3::new(x, y, z), Vector3::new(5.0, 2.0, 0.0), "scale_target");
+        }
+        
+        // Create 200 agents in 10x20 grid
+        let mut agents: Vec<SimulatedAgent> = Vec::with_capacity(num_agents);
+        for i in 0..num_agents {
+            let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
+            let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
+            agents.push(SimulatedAgent::new(
+                context,
+                network,
+                root_key,
+                i as u64,
+                AgentConfig { max_tracks: Some(2 * num_entities), ..SimulatedAgent::default_config() },
+            ));
+        }
+        
+        let mut swarm_network = SwarmNetwork::new_grid(10, 20);
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        
+        let start_time = std::time::Instant::now();
+        
+        info!("  Config: {} agents, {} entities, {}s", num_agents, num_entities, target_ticks as f64 * dt);
+        
+        let monitor = self.monitor(ScenarioId::ScaleLimit, target_ticks);
+        let mut pool = AgentPool::new(self.threads);
+        
+        for tick in 0..target_ticks {
+            oracle.step(dt);
+            let readings = oracle.generate_sensor_readings();
+            
+            // Agents only interact at gossip rounds, so tick and ingest in parallel
+            pool.for_each(&mut agents, |agent_idx, agent| {
+                // Each agent sees ~20% of entities
+                let agent_readings: Vec<_> = readings.iter()
+                    .enumerate()
+                    .filter(|(entity_idx, _)| (entity_idx + agent_idx * 7) % 5 == 0)
+                    .map(|(_, r)| r.clone())
+                    .collect();
+                
+                agent.tick();
+                agent.ingest_readings(&agent_readings);
+            });
+            
+            // Gossip every 10 ticks
+            if tick % 10 == 0 {
+                let all_packets: Vec<_> = agents.iter()
+                    .enumerate()
+                    .flat_map(|(idx, a)| a.recent_packets().iter().map(|p| (idx, p.clone())).collect::<Vec<_>>())
+                    .collect();
+                
+                for (from_idx, packet) in all_packets {
+                    swarm_network.queue_gossip(from_idx, packet);
+                }
+                
+                for (agent_idx, agent) in agents.iter_mut().enumerate() {
+                    let incoming = swarm_network.take_gossip_packets(agent_idx);
+                    agent.receive_gossip(&incoming);
+                    agent.clear_recent_packets();
+                }
+            }
+            
+            rms.sample(oracle.time(), &agents, &oracle.ground_truth_positions());
+            
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
+            }
+        }
+        
+        let elapsed = start_time.elapsed();
+        let ticks_per_sec = target_ticks as f64 / elapsed.as_secs_f64();
+        
+        let rms_ok = rms.within(5.0);
+        // Must run at >10 ticks/sec real-time
+        let failure = rms_then_budget(rms.failure(5.0), "ticks_per_sec", ticks_per_sec, 10.0);
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  SCALE LIMIT RESULTS:");
+        info!("    RMS error:      {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms_ok { "✓" } else { "✗" });
+        info!("    Performance:    {:.1} ticks/sec  {}", ticks_per_sec, if ticks_per_sec > 10.0 { "✓" } else { "✗" });
+        info!("    Wall time:      {:.2}s", elapsed.as_secs_f64());
+        let pool_stats = pool.stats();
+        info!("    Agent phase:    {:.2}s on {} thread(s), {:.1}x speedup", pool_stats.wall_secs, pool_stats.threads, pool_stats.speedup());
+        info!("    Messages:       {}", swarm_network.messages_sent());
+        
+        let mut metrics = ScenarioMetrics {
+            packets_sent: swarm_network.messages_sent(),
+            bytes_sent: swarm_network.bytes_sent(),
+            ..Default::default()
+        };
+        metrics.record_evictions(&agents);
+        info!("    Evictions:      {}", metrics.track_evictions);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        ScenarioResult {
+            scenario: ScenarioId::ScaleLimit,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: oracle.time(),
+            final_entity_count: oracle.active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// DST-010: NetworkHell - 90% packet loss.
+    fn run_network_hell(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-010: NetworkHell - 90% PACKET LOSS 🔥");
+        
+        let config = &self.swarm_config;
+        let num_agents = config.rows * config.cols;
+        let packet_loss_rate = 0.90;
         
-        // Create node IDs for partitioning
-        let group_a: Vec<NodeId> = (0..self.num_agents/2)
-            .map(|i| NodeId::from_seed(i as u64))
-            .collect();
-        let group_b: Vec<NodeId> = (self.num_agents/2..self.num_agents)
-            .map(|i| NodeId::from_seed(i as u64))
-            .collect();
+        let mut harness = SwarmHarness::new(self.seed, config.rows, config.cols, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_threads(self.threads)
+            .with_visibility(Visibility::Striped { period: 4, visible: 1 })
+            .with_packet_loss(packet_loss_rate)
+            .with_rng_seed(self.seed.wrapping_mul(0xbe11be11));
+        for i in 0..config.num_entities {
+            harness.oracle_mut().spawn_entity(
+                Vector3::new((i % 20) as f64 * 50.0, (i / 20) as f64 * 50.0, 100.0),
+                Vector3::new(10.0, 5.0, 0.0),
+                "hell_target",
+            );
+        }
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
         
-        // Phase 1: Normal operation (first 20 seconds)
-        let partition_start = (20.0 * self.tick_rate_hz as f64) as u64;
-        let partition_end = (30.0 * self.tick_rate_hz as f64) as u64;
+        info!("  Config: {} agents, {}% packet loss", num_agents, (packet_loss_rate * 100.0) as u32);
+        
+        let monitor = self.monitor(ScenarioId::NetworkHell, target_ticks);
         
         for tick in 0..target_ticks {
-            // Create partition at 20 seconds
-            if tick == partition_start {
-                info!("  ⚡ Creating network partition at t=20s");
-                network_controller.partition(group_a.clone(), group_b.clone());
-                metrics.packets_dropped += 1; // Mark partition event
-            }
-            
-            // Heal partition at 30 seconds
-            if tick == partition_end {
-                info!("  ✓ Healing network partition at t=30s");
-                network_controller.heal_all();
-            }
-            
-            // Advance physics
-            oracle.step(dt);
-            context.advance_time(Duration::from_secs_f64(dt));
-            
-            // Count packets dropped due to partition
-            if tick >= partition_start && tick < partition_end {
-                // During partition, cross-group packets would be dropped
-                if !group_a.is_empty() && !group_b.is_empty() {
-                    let can_talk = network_controller.can_communicate(group_a[0], group_b[0]);
-                    if !can_talk {
-                        metrics.packets_dropped += 1;
-                    }
-                }
-            }
+            harness.step();
             
-            if tick % 30 == 0 {
-                debug!("  t={:.1}s | partitioned={}", 
-                    oracle.time(), 
-                    tick >= partition_start && tick < partition_end
-                );
+            rms.sample(harness.time(), harness.agents(), &harness.oracle().ground_truth_positions());
+                
+            if monitor.observe(tick, harness.oracle(), harness.agents()) {
+                break;
             }
         }
         
-        // SplitBrain passes if we survived the partition/heal cycle
-        info!("✓ SplitBrain complete: {} packets dropped during partition", metrics.packets_dropped);
+        let packets_sent = harness.packets_offered();
+        let packets_dropped = harness.packets_lost();
+        let (bytes_sent, bytes_dropped) = (harness.bytes_offered(), harness.bytes_lost());
+        let actual_loss = packets_dropped as f64 / packets_sent.max(1) as f64;
+        
+        // With 90% loss, we're just testing survival and some coherence
+        let failure = rms.failure(50.0); // Very relaxed - just don't go crazy
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  NETWORK HELL RESULTS:");
+        info!("    RMS error:     {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if passed { "✓ (survived!)" } else { "✗" });
+        info!("    Packet loss:   {:.0}% ({} / {})", actual_loss * 100.0, packets_dropped, packets_sent);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        let metrics = ScenarioMetrics {
+            packets_sent,
+            packets_dropped,
+            bytes_sent,
+            bytes_dropped,
+            ..Default::default()
+        };
         
         ScenarioResult {
-            scenario: ScenarioId::SplitBrain,
+            scenario: ScenarioId::NetworkHell,
             seed: self.seed,
-            passed: true,
+            passed,
             total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: None,
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
             metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-003: Byzantine - Malicious agent with delayed revocation.
+    /// DST-011: TimeTornado - 5-second OOSM delays.
     ///
-    /// Tests Trust Engine's ability to revoke a malicious agent's credentials.
-    fn run_byzantine(&self) -> ScenarioResult {
-        info!("DST-003: Byzantine - Malicious agent test");
+    /// The agent ticks once a tick and fuses whatever readings are due, so
+    /// its track coasts through ticks with none. Updated and coasting states
+    /// are scored apart: updated RMS < 200m, and coasting error growth within
+    /// what the 22m/s target allows (see [`coasting_failure`]).
+    fn run_time_tornado(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-011: TimeTornado - 5-SECOND OOSM DELAYS 🔥");
         
-        let context_seed = self.seed;
         let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
+        let key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
         
-        let context = SimContext::shared(context_seed);
-        let mut oracle = Oracle::new(physics_seed);
+        let context = Arc::new(SimContext::new(self.seed));
+        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+        let mut agent = SimulatedAgent::new(context, network, root_key, 0, SimulatedAgent::default_config());
         
-        // The "malicious" agent ID
-        let malicious_agent = NodeId::from_seed(0);
+        let mut oracle = Oracle::new(physics_seed);
+        let target_velocity = Vector3::new(20.0, 10.0, 0.0);
+        oracle.spawn_entity(Vector3::new(0.0, 0.0, 100.0), target_velocity, "tornado_target");
+        
+        let dt = timing.dt();
+        let latency = self.sensor_latency.unwrap_or(LatencyModel::Uniform { min_ms: 0.0, max_ms: 5000.0 });
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut coasting = self.coasting_sampler(target_ticks as f64 * dt, dt).with_gate(TIME_TORNADO_MATCH_GATE_M);
+        
+        // Sensor -> agent link; the random delay does the reordering, the
+        // link adds duplicates on top
+        let sensor_node = NodeId::from_seed(1);
+        let agent_node = NodeId::from_seed(0);
+        let network_controller = SimNetworkController::with_seed(self.seed);
+        network_controller.set_latency_model(sensor_node, agent_node, latency);
+        network_controller.set_duplicate(sensor_node, agent_node, 0.1);
+        let mut sensor_link = DelayQueue::new(sensor_node, agent_node);
+        let mut oosm_count = 0u64;
         
-        let mut metrics = ScenarioMetrics::default();
-        let dt = 1.0 / self.tick_rate_hz as f64;
-        let target_ticks = (self.max_duration_secs * self.tick_rate_hz as f64) as u64;
+        info!("  Config: sensor latency {} (mean {:.0}ms), duration {}s", latency, latency.mean_ms(), target_ticks as f64 * dt);
         
-        // Revocation happens at 15 seconds
-        let revocation_tick = (15.0 * self.tick_rate_hz as f64) as u64;
-        let mut revoked = false;
+        let monitor = self.monitor(ScenarioId::TimeTornado, target_ticks);
         
         for tick in 0..target_ticks {
-            if tick == revocation_tick {
-                info!("  🔒 Revoking malicious agent {} at t=15s", malicious_agent);
-                revoked = true;
-            }
-            
             oracle.step(dt);
-            context.advance_time(Duration::from_secs_f64(dt));
+            let readings = oracle.generate_sensor_readings();
             
-            // After revocation, packets from malicious agent would be rejected
-            if revoked {
-                metrics.packets_dropped += 1;
+            // Add current readings to queue with random delay
+            for reading in readings {
+                sensor_link.push_after_latency(tick, dt, reading, &network_controller);
             }
             
-            if tick % 30 == 0 {
-                debug!("  t={:.1}s | revoked={}", oracle.time(), revoked);
+            // Deliver readings whose time has come (simulating OOSM); a
+            // tick with none due leaves the track coasting
+            agent.tick();
+            for reading in sensor_link.pop_due(tick, &network_controller) {
+                agent.ingest_readings(&[reading]);
+                oosm_count += 1;
             }
+            
+            let ground_truth = oracle.ground_truth_positions();
+            rms.sample(oracle.time(), [&agent], &ground_truth);
+            coasting.sample(oracle.time(), [&agent], &ground_truth);
+            
+            if monitor.observe(tick, &oracle, [&agent]) {
+                break;
+            }
+        }
+        
+        // Drain remaining queue
+        agent.tick();
+        for reading in sensor_link.drain(&network_controller) {
+            agent.ingest_readings(&[reading]);
+            oosm_count += 1;
         }
         
-        info!("✓ Byzantine complete: malicious packets blocked after revocation");
+        // With 5s delays on a moving target, some error is expected
+        let coasting = coasting.metrics();
+        let failure = time_tornado_failure(coasting_failure(&coasting, 200.0, target_velocity.norm()), oosm_count);
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  TIME TORNADO RESULTS:");
+        info!("    RMS error:      {:.2}m (p95 {:.2}m), {:.2}m updated, {:.2}m coasting  {}",
+            rms.mean(), rms.p95(), coasting.updated_rms, coasting.coasting_rms, if passed { "✓" } else { "✗" });
+        info!("    Error growth:   {:.0}m²/s per coasted second (up to {:.1}s coasted)",
+            coasting.error_growth_m2_per_s, coasting.max_coast_secs);
+        info!("    OOSM updates:   {} ({} duplicated)", oosm_count, network_controller.total_link_fault_stats().duplicated);
+        
+        let mut metrics = ScenarioMetrics {
+            oosm_updates: oosm_count,
+            coasting: Some(coasting),
+            ..Default::default()
+        };
+        metrics.record_link_faults(network_controller.total_link_fault_stats());
+        metrics.record_link_latency(&network_controller.total_link_latency());
+        info!("    Latency:        p50 {}ms, p95 {}ms, p99 {}ms", metrics.link_latency_p50_ms, metrics.link_latency_p95_ms, metrics.link_latency_p99_ms);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
         ScenarioResult {
-            scenario: ScenarioId::Byzantine,
+            scenario: ScenarioId::TimeTornado,
             seed: self.seed,
-            passed: true,
+            passed,
             total_ticks: target_ticks,
             final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: None,
+            final_entity_count: 1,
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
             metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-004: FlashMob - H3 boundary crossing stress test.
+    /// DST-012: ZombieApocalypse - 50% of agents are bad actors.
     ///
-    /// Tests Space Engine with 1000 drones crossing H3 cell boundaries rapidly.
-    fn run_flash_mob(&self) -> ScenarioResult {
-        info!("DST-004: FlashMob - H3 boundary crossing stress test");
+    /// The apocalypse runs twice on the same seed: with unlimited gossip
+    /// intake, which the RMS and detection criteria score, and with every
+    /// survivor capping each neighbor at [`ZOMBIE_GOSSIP_QUOTA`], whose RMS
+    /// must come out lower.
+    fn run_zombie_apocalypse(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-012: ZombieApocalypse - 50% BAD ACTORS 🔥");
         
-        let context_seed = self.seed;
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
+        let quota_run = self.zombie_apocalypse_run(timing, Some(ZOMBIE_GOSSIP_QUOTA));
+        let ZombieApocalypseRun { harness, rms } = self.zombie_apocalypse_run(timing, None);
+        let target_ticks = timing.target_ticks();
         
-        let context = SimContext::shared(context_seed);
-        let mut oracle = Oracle::new(physics_seed);
+        // Count zombies identified by survivors
+        let (zombies_identified, possible_detections) = harness.bad_actor_detection(0.3);
         
-        // Spawn 1000 fast-moving drones in a grid
-        let num_drones = 1000;
-        for i in 0..num_drones {
-            let x = (i % 100) as f64 * 10.0;
-            let y = (i / 100) as f64 * 10.0;
-            let vel_x = 100.0 * ((i % 2) as f64 * 2.0 - 1.0); // Alternating directions
-            let vel_y = 50.0 * ((i / 100 % 2) as f64 * 2.0 - 1.0);
-            
-            oracle.spawn_entity(
-                Vector3::new(x, y, 50.0),
-                Vector3::new(vel_x, vel_y, 0.0),
-                "drone",
+        let detection_rate = if possible_detections > 0 { zombies_identified as f64 / possible_detections as f64 } else { 0.0 };
+        let rate_limited: u64 = quota_run.harness.good_agents()
+            .map(|a| a.adaptive_metrics().gossip_rate_limited)
+            .sum();
+        let rms_ok = rms.within(10.0);
+        let failure = zombie_apocalypse_failure(rms.failure(10.0), detection_rate, quota_run.rms.mean(), rms.mean());
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  ZOMBIE APOCALYPSE RESULTS:");
+        info!("    Survivor RMS:    {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms_ok { "✓" } else { "✗" });
+        info!("    Zombie detection: {:.0}%  {}", detection_rate * 100.0, if detection_rate >= MIN_DETECTION_RATE { "✓" } else { "✗" });
+        info!("    Zombies spotted: {} / {}", zombies_identified, possible_detections);
+        info!("    + gossip quota:   {:.3}m (p95 {:.2}m) vs {:.3}m unlimited, {} packets rate limited  {}",
+            quota_run.rms.mean(), quota_run.rms.p95(), rms.mean(), rate_limited,
+            if quota_run.rms.mean() < rms.mean() { "✓" } else { "✗" });
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        let mut metrics = ScenarioMetrics {
+            packets_sent: harness.network().messages_sent(),
+            bytes_sent: harness.network().bytes_sent(),
+            bad_actors_detected: zombies_identified as u64,
+            ..Default::default()
+        };
+        metrics.set_extra("detection_rate", detection_rate);
+        metrics.set_extra("quota_rms_mean", quota_run.rms.mean());
+        metrics.set_extra("gossip_rate_limited", rate_limited as f64);
+        
+        ScenarioResult {
+            scenario: ScenarioId::ZombieApocalypse,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// Runs the ZombieApocalypse simulation once, with survivors limiting
+    /// each neighbor's gossip to `quota` (None: unlimited).
+    fn zombie_apocalypse_run(&self, timing: RunTiming, quota: Option<GossipQuota>) -> ZombieApocalypseRun {
+        let config = &self.swarm_config;
+        let num_agents = config.rows * config.cols;
+        let num_bad_actors = num_agents / 2; // Half!
+        
+        // Zombies inject lots of fast-moving garbage: 10 packets each per round
+        let garbage = GarbageSpec {
+            packets_per_round: 10,
+            min_position: [-1000.0, -1000.0, 0.0],
+            max_position: [1000.0, 1000.0, 500.0],
+            max_speed: 100.0,
+            confidence: (0.0, 0.5),
+        };
+        
+        // First half are zombies (bad actors)
+        let mut harness = SwarmHarness::new(self.seed, config.rows, config.cols, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_threads(self.threads)
+            .with_visibility(Visibility::Striped { period: 4, visible: 2 })
+            .with_bad_actors(
+                BadActorSchedule::Fixed((0..num_bad_actors).collect()),
+                BadActorStrategy::Garbage(garbage),
+            )
+            .with_index_verification(INDEX_VERIFY_INTERVAL_TICKS)
+            .with_rng_seed(self.seed.wrapping_mul(0xdead0dead));
+        if let Some(quota) = quota {
+            harness = harness.with_gossip_quota(quota, false);
+        }
+        for i in 0..config.num_entities {
+            harness.oracle_mut().spawn_entity(
+                Vector3::new((i % 20) as f64 * 50.0, (i / 20) as f64 * 50.0, 100.0),
+                Vector3::new(10.0, 5.0, 0.0),
+                "survivor_target",
             );
         }
         
-        let mut metrics = ScenarioMetrics::default();
-        let dt = 1.0 / self.tick_rate_hz as f64;
-        let target_ticks = (self.max_duration_secs * self.tick_rate_hz as f64) as u64;
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        
+        info!("  Config: {} agents, {} zombies ({}%), gossip quota {:?}",
+            num_agents, num_bad_actors, num_bad_actors * 100 / num_agents, quota);
+        
+        let monitor = self.monitor(ScenarioId::ZombieApocalypse, target_ticks);
         
         for tick in 0..target_ticks {
-            oracle.step(dt);
-            context.advance_time(Duration::from_secs_f64(dt));
+            harness.step();
             
-            // Generate readings for all drones (stress test)
-            let readings = oracle.generate_all_readings();
-            metrics.oosm_updates += readings.len() as u64;
+            // Only measure GOOD agents (survivors)
+            rms.sample(harness.time(), harness.good_agents(), &harness.oracle().ground_truth_positions());
             
-            if tick % 30 == 0 {
-                debug!("  t={:.1}s | drones={} | readings/tick={}", 
-                    oracle.time(), 
-                    oracle.active_entities().len(),
-                    readings.len()
-                );
+            if monitor.observe(tick, harness.oracle(), harness.good_agents()) {
+                break;
             }
         }
         
-        info!("✓ FlashMob complete: processed {} sensor readings for {} drones", 
-            metrics.oosm_updates, num_drones);
+        ZombieApocalypseRun { harness, rms }
+    }
+    
+    /// DST-024: ZombieRestart - agent crashes, stalls and amnesia.
+    ///
+    /// 16 agents in a 4x4 grid each sense half of 40 entities and gossip the
+    /// rest. The scenario's [`FaultPlan`] crashes one agent, stalls another
+    /// for 2s and wipes the tracks of four more.
+    ///
+    /// **Success Criteria**: coverage is back within 3s of every fault taking
+    /// effect, the crashed agent never ticks again, the stalled one resumes
+    /// behind by its stall, no gossip is rejected (keys survive amnesia),
+    /// every envelope an agent missed while stalled shows up as a sequence
+    /// gap, and live-agent RMS < 5.0m.
+    fn run_zombie_restart(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-024: ZombieRestart - CRASH, STALL, AMNESIA 🧟");
+        
+        let (rows, cols) = (4, 4);
+        let num_entities = 40;
+        let max_recovery_secs = 3.0;
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let plan = ScenarioId::ZombieRestart.fault_plan(target_ticks);
+        
+        let mut sim = Simulation::from_config(SimConfig {
+            seed: self.seed,
+            num_agents: rows * cols,
+            tick_rate_hz: timing.tick_rate_hz,
+            max_duration_secs: timing.duration_secs,
+            visibility: Visibility::Striped { period: 4, visible: 2 },
+            gossip: Some(GossipConfig { rows, cols, interval_ticks: 3, relay: GossipRelay::Off, max_packet_age_ms: None }),
+            ..SimConfig::default()
+        })
+        .with_fault_plan(plan.clone())
+        .with_event_bus(self.hooks.event_bus());
+        
+        for i in 0..num_entities {
+            let position = Vector3::new((i % 10) as f64 * 40.0, (i / 10) as f64 * 40.0, 100.0);
+            let velocity = Vector3::new(8.0, 2.0 * ((i % 3) as f64 - 1.0), 0.0);
+            sim.oracle_mut().spawn_entity(position, velocity, "restart_target");
+        }
+        
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut recovery = RecoveryTracker::new(&plan);
+        
+        info!("  Config: {} agents, {} entities, {} ticks, {} faults", rows * cols, num_entities, target_ticks, plan.faults().len());
+        
+        let monitor = self.monitor(ScenarioId::ZombieRestart, target_ticks);
+        
+        for tick in 0..target_ticks {
+            sim.step();
+            let ground_truth = sim.oracle().ground_truth_positions();
+            rms.sample(sim.time(), sim.live_agents(), &ground_truth);
+            recovery.observe(tick, sim.time(), swarm_coverage(sim.live_agents(), &ground_truth));
+            
+            if monitor.observe(tick, sim.oracle(), sim.live_agents()) {
+                break;
+            }
+        }
+        
+        let fault_recoveries = recovery.finish();
+        
+        // Tick counts show the crash and stall were honoured
+        let clocks_ok = plan.faults().iter().all(|fault| {
+            let ticks = sim.agents()[fault.agent()].tick_count();
+            match *fault {
+                Fault::Crash { at_tick, .. } => ticks == at_tick,
+                Fault::Stall { for_ticks, .. } => ticks == target_ticks - for_ticks,
+                Fault::Amnesia { .. } => true,
+            }
+        });
+        let rejected: u64 = sim.agents().iter().map(|a| a.signed_gossip_stats().rejected()).sum();
+        // Without retransmission, what a stalled agent missed stays
+        // reported as gaps once its neighbors' next envelopes arrive
+        let live: Vec<usize> = (0..sim.agents().len()).filter(|&i| sim.agent_status(i) != AgentStatus::Crashed).collect();
+        let gap_mismatches = live.iter()
+            .filter(|&&i| sim.agents()[i].gap_count() != sim.missed_envelopes(i))
+            .count();
+        let gaps = sequence_totals(sim.live_agents());
+        let rms_ok = rms.within(5.0);
+        let failure = zombie_restart_failure(
+            &fault_recoveries,
+            plan.faults().len(),
+            max_recovery_secs,
+            clocks_ok,
+            rejected,
+            gap_mismatches,
+            rms.failure(5.0),
+        );
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  ZOMBIE RESTART RESULTS:");
+        for r in &fault_recoveries {
+            match r.recovery_secs {
+                Some(secs) => info!("    {}: coverage {:.0}% -> {:.0}%, recovered in {:.1}s",
+                    r.fault, r.coverage_before * 100.0, r.coverage_min * 100.0, secs),
+                None => info!("    {}: coverage {:.0}% -> {:.0}%, never recovered",
+                    r.fault, r.coverage_before * 100.0, r.coverage_min * 100.0),
+            }
+        }
+        info!("    Agent clocks: {}", if clocks_ok { "✓" } else { "✗" });
+        info!("    Gossip rejected: {}  {}", rejected, if rejected == 0 { "✓" } else { "✗" });
+        info!("    Sequence gaps: {} for {} envelopes missed while down  {}",
+            gaps.outstanding(), live.iter().map(|&i| sim.missed_envelopes(i)).sum::<u64>(),
+            if gap_mismatches == 0 { "✓" } else { "✗" });
+        info!("    Live RMS: {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms_ok { "✓" } else { "✗" });
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        let mut metrics = ScenarioMetrics {
+            packets_sent: sim.swarm_network().map(|n| n.route_stats().sent).unwrap_or(0),
+            fault_recoveries,
+            ..Default::default()
+        };
+        metrics.record_sequence_gaps(gaps);
+        metrics.record_latency(sim.agents());
         
         ScenarioResult {
-            scenario: ScenarioId::FlashMob,
+            scenario: ScenarioId::ZombieRestart,
             seed: self.seed,
-            passed: true,
+            passed,
             total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: None,
+            final_time_secs: sim.time(),
+            final_entity_count: sim.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
             metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-005: SlowLoris - High packet loss recovery.
+    /// DST-034: ParameterShock - tracking parameters perturbed mid-run.
     ///
-    /// Tests protocol resilience with 50% packet loss.
-    fn run_slow_loris(&self) -> ScenarioResult {
-        info!("DST-005: SlowLoris - 50% packet loss test");
-        
-        let context_seed = self.seed;
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
+    /// 12 agents in a 3x4 grid sense and gossip 24 entities. The scenario's
+    /// [`FaultPlan`](crate::FaultPlan) halves every agent's gating threshold
+    /// at 25% of the run, doubles its `max_age` at 50% and coarsens its H3
+    /// cells from resolution 11 to 9 at 75%. RMS and tracks per agent are
+    /// compared over [`PERTURBATION_WINDOW_SECS`] either side of each
+    /// perturbation; the default 40s run starts the first window after
+    /// neighbor reputations have settled (about 3s in).
+    ///
+    /// **Success Criteria**: every agent ends on the perturbed config with a
+    /// spatial index consistent after each swap, no perturbation raises RMS
+    /// by more than 1m or costs more than 20% of the tracks per agent, and
+    /// RMS < 3.0m.
+    fn run_parameter_shock(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-034: ParameterShock - TRACKING PARAMETERS PERTURBED MID-RUN 🎚️");
+        
+        let (rows, cols) = (3, 4);
+        let num_entities = 24;
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let plan = ScenarioId::ParameterShock.fault_plan(target_ticks);
+        let window_ticks = ((PERTURBATION_WINDOW_SECS / dt).round() as u64).min(target_ticks / 4).max(1);
+        
+        let mut sim = Simulation::from_config(SimConfig {
+            seed: self.seed,
+            num_agents: rows * cols,
+            tick_rate_hz: timing.tick_rate_hz,
+            max_duration_secs: timing.duration_secs,
+            gossip: Some(GossipConfig { rows, cols, interval_ticks: 3, relay: GossipRelay::Off, max_packet_age_ms: None }),
+            ..SimConfig::default()
+        })
+        .with_fault_plan(plan.clone())
+        .with_event_bus(self.hooks.event_bus());
         
-        let context = SimContext::shared(context_seed);
-        let mut oracle = Oracle::new(physics_seed);
-        let network_controller = SimNetworkController::new();
-        
-        // Set 50% loss between all agents
-        for i in 0..self.num_agents {
-            for j in 0..self.num_agents {
-                if i != j {
-                    let from = NodeId::from_seed(i as u64);
-                    let to = NodeId::from_seed(j as u64);
-                    network_controller.set_loss(from, to, 0.5);
-                }
-            }
+        for i in 0..num_entities {
+            let position = Vector3::new((i % 6) as f64 * 40.0, (i / 6) as f64 * 40.0, 100.0);
+            let velocity = Vector3::new(8.0, 2.0 * ((i % 3) as f64 - 1.0), 0.0);
+            sim.oracle_mut().spawn_entity(position, velocity, "shock_target");
         }
         
-        // Spawn a few entities
-        for i in 0..5 {
-            oracle.spawn_entity(
-                Vector3::new(i as f64 * 200.0, 0.0, 100.0),
-                Vector3::new(20.0, 0.0, 0.0),
-                "vehicle",
-            );
-        }
+        // Each change lands on top of the last
+        let expected = plan.perturbations().iter()
+            .try_fold(sim.agents()[0].tracking_config().clone(), |config, p| p.change.apply(&config))
+            .expect("ParameterShock's perturbations are valid");
         
-        let mut metrics = ScenarioMetrics::default();
-        let dt = 1.0 / self.tick_rate_hz as f64;
-        let target_ticks = (self.max_duration_secs * self.tick_rate_hz as f64) as u64;
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut impacts = PerturbationTracker::new(&plan, window_ticks);
+        let mut index_errors = 0;
         
-        // Use seeded RNG for packet loss decisions
-        use rand::SeedableRng;
-        use rand_chacha::ChaCha8Rng;
-        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        info!("  Config: {} agents, {} entities, {} ticks, {} perturbations, {}-tick windows",
+            rows * cols, num_entities, target_ticks, plan.perturbations().len(), window_ticks);
+        
+        let monitor = self.monitor(ScenarioId::ParameterShock, target_ticks);
         
         for tick in 0..target_ticks {
-            oracle.step(dt);
-            context.advance_time(Duration::from_secs_f64(dt));
+            sim.step();
+            let ground_truth = sim.oracle().ground_truth_positions();
+            rms.sample(sim.time(), sim.live_agents(), &ground_truth);
+            let convergence = swarm_convergence(sim.live_agents(), &ground_truth, every_agent);
+            let tracks: Vec<f64> = sim.live_agents().map(|a| a.track_count() as f64).collect();
+            impacts.observe(tick, (convergence.scored() > 0).then_some(convergence.rms_mean), stats::mean(&tracks));
             
-            // Simulate packet sending with 50% loss
-            let simulated_packets = 10; // Packets per tick
-            for _ in 0..simulated_packets {
-                use rand::Rng;
-                metrics.packets_sent += 1;
-                if rng.gen_bool(0.5) {
-                    metrics.packets_dropped += 1;
-                }
+            if plan.perturbations_at(tick).next().is_some() {
+                index_errors += sim.live_agents()
+                    .filter(|a| !a.inner().track_manager.verify_spatial_index().is_empty())
+                    .count();
             }
             
-            if tick % 30 == 0 {
-                let loss_rate = metrics.packets_dropped as f64 / metrics.packets_sent.max(1) as f64;
-                debug!("  t={:.1}s | loss_rate={:.1}%", oracle.time(), loss_rate * 100.0);
+            if monitor.observe(tick, sim.oracle(), sim.live_agents()) {
+                break;
             }
         }
         
-        let actual_loss_rate = metrics.packets_dropped as f64 / metrics.packets_sent.max(1) as f64;
-        info!("✓ SlowLoris complete: {:.1}% packet loss ({}/{} dropped)", 
-            actual_loss_rate * 100.0, 
-            metrics.packets_dropped, 
-            metrics.packets_sent
+        let perturbations = impacts.finish();
+        let refusals = sim.live_agents()
+            .filter(|a| {
+                let config = a.tracking_config();
+                config.gating_threshold != expected.gating_threshold
+                    || config.max_age != expected.max_age
+                    || config.h3_resolution != expected.h3_resolution
+            })
+            .count();
+        let rms_ok = rms.within(3.0);
+        let failure = parameter_shock_failure(
+            &perturbations,
+            plan.perturbations().len(),
+            refusals,
+            index_errors,
+            rms.failure(3.0),
         );
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  PARAMETER SHOCK RESULTS:");
+        for p in &perturbations {
+            info!("    {}: RMS {:.2}m -> {:.2}m ({:+.2}m), tracks/agent {:.1} -> {:.1}",
+                p.perturbation, p.rms_before, p.rms_after, p.rms_delta(), p.tracks_before, p.tracks_after);
+        }
+        info!("    Agents on the perturbed config: {}/{}  {}", sim.live_agents().count() - refusals,
+            sim.live_agents().count(), if refusals == 0 { "✓" } else { "✗" });
+        info!("    Spatial index errors after swaps: {}  {}", index_errors, if index_errors == 0 { "✓" } else { "✗" });
+        info!("    RMS: {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms_ok { "✓" } else { "✗" });
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        // Pass if loss rate is within expected range (40-60%)
-        let passed = actual_loss_rate >= 0.4 && actual_loss_rate <= 0.6;
+        let mut metrics = ScenarioMetrics {
+            packets_sent: sim.swarm_network().map(|n| n.route_stats().sent).unwrap_or(0),
+            perturbations,
+            ..Default::default()
+        };
+        metrics.record_latency(sim.agents());
         
         ScenarioResult {
-            scenario: ScenarioId::SlowLoris,
+            scenario: ScenarioId::ParameterShock,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed {
-                Some(format!("Unexpected loss rate: {:.1}%", actual_loss_rate * 100.0))
-            } else {
-                None
-            },
+            final_time_secs: sim.time(),
+            final_entity_count: sim.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
             metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-006: Swarm - 50-agent multi-agent scale test.
-    ///
-    /// Tests multi-agent coordination with P2P gossip:
-    /// - 50 agents in 5x10 grid
-    /// - 200 entities moving through space
-    /// - P2P gossip between neighbors every 3 ticks
-    /// - Measures convergence: entity count variance, position error
-    fn run_swarm(&self) -> ScenarioResult {
-        use crate::swarm_network::SwarmNetwork;
+    /// DST-035: BoundaryCrossing - Tracks Handed Across H3 Shard Edges
+    /// 
+    /// Four agents each answer for a band of resolution-10 H3 cells, west
+    /// to east (see [`ShardMap::bands`]), and only sense entities read
+    /// inside their own band. Twelve drones shuttle east and west across
+    /// all four bands, turning back 280m either side of the origin. Each
+    /// time a coasting track moves into the next band its holder hands it
+    /// straight to that band's owner and drops it, and tracks more than a
+    /// ring of cells past an agent's band are dropped. Gossip runs only
+    /// every 30 ticks, so a handoff rather than gossip has to carry the
+    /// track over.
+    /// 
+    /// **Success Criteria**: at least 95% of crossings are handed off
+    /// within [`HANDOFF_WINDOW_SECS`](crate::HANDOFF_WINDOW_SECS), at most
+    /// 5% leave the new owner with duplicate tracks, the new owner goes
+    /// without a track of the entity for at most 0.2s on average, and
+    /// RMS < 2.0m.
+    fn run_boundary_crossing(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-035: BoundaryCrossing - Tracks Handed Across H3 Shard Edges 🗺️");
+        
+        let num_agents = 4;
+        let num_entities = 12;
+        let turn_x = 280.0;
+        let gossip_interval_ticks = 30;
+        let map = ShardMap::bands(Resolution::Ten, 3, num_agents);
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let run_secs = target_ticks as f64 * dt;
+        
+        let mut harness = SwarmHarness::new(self.seed, 1, num_agents, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_threads(self.threads)
+            .with_agent_config(AgentConfig {
+                tick_rate_hz: timing.tick_rate_hz,
+                filter: Some(FilterTuning::default()),
+                ..SimulatedAgent::default_config()
+            })
+            .with_gossip_interval(gossip_interval_ticks)
+            .with_shards(map.clone());
+        
+        for i in 0..num_entities {
+            let x = -turn_x + i as f64 * (2.0 * turn_x / num_entities as f64);
+            let y = (i % 5) as f64 * 40.0 - 80.0;
+            let speed = 6.0 + (i % 4) as f64 * 2.0;
+            let heading = if i % 2 == 0 { 1.0 } else { -1.0 };
+            harness.oracle_mut().spawn_entity(Vector3::new(x, y, 30.0), Vector3::new(heading * speed, 0.0, 0.0), "drone");
+        }
+        
+        let mut rms = self.rms_sampler(run_secs);
+        let mut handoffs = HandoffSampler::new();
+        
+        info!("  Config: {} agents over {} cells, {} entities turning at ±{}m, gossip every {} ticks, {} ticks",
+            num_agents, (0..num_agents).map(|i| map.cells_of(i).len()).sum::<usize>(), num_entities, turn_x,
+            gossip_interval_ticks, target_ticks);
+        
+        let monitor = self.monitor(ScenarioId::BoundaryCrossing, target_ticks);
+        
+        for tick in 0..target_ticks {
+            // Turn back any drone past the turning points
+            let turning: Vec<(u64, Vector3<f64>)> = harness.oracle().active_entities().iter()
+                .filter(|e| e.position.x.abs() > turn_x && e.position.x * e.velocity.x > 0.0)
+                .map(|e| (e.id, Vector3::new(-e.velocity.x, e.velocity.y, e.velocity.z)))
+                .collect();
+            for (id, velocity) in turning {
+                harness.oracle_mut().set_velocity(id, velocity);
+            }
+            
+            harness.step();
+            
+            let ground_truth = harness.oracle().ground_truth_positions();
+            rms.sample(harness.time(), harness.agents(), &ground_truth);
+            handoffs.sample(harness.time(), &map, harness.agents(), |i| harness.shard_index(i), &ground_truth);
+            
+            if monitor.observe(tick, harness.oracle(), harness.agents()) {
+                break;
+            }
+        }
+        
+        let handoff = handoffs.metrics();
+        let shard_stats = harness.shard_stats().unwrap_or_default();
+        let failure = boundary_crossing_failure(&handoff, rms.failure(2.0));
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  BOUNDARY CROSSING RESULTS:");
+        info!("    Crossings:      {} ({} handoffs sent, {} tracks dropped past margins)",
+            handoff.crossings, shard_stats.handoffs_sent, shard_stats.tracks_dropped);
+        info!("    Handed off:     {:.1}% (min {:.0}%)  {}", handoff.success_rate() * 100.0, BOUNDARY_MIN_HANDOFF_RATE * 100.0,
+            if handoff.success_rate() >= BOUNDARY_MIN_HANDOFF_RATE { "✓" } else { "✗" });
+        info!("    Duplicated:     {}  {}", handoff.duplicated,
+            if handoff.duplicate_rate() <= BOUNDARY_MAX_DUPLICATE_RATE { "✓" } else { "✗" });
+        info!("    Gap:            {:.2}s mean, {:.2}s max (max mean {:.1}s)  {}", handoff.gap_mean_secs, handoff.gap_max_secs,
+            BOUNDARY_MAX_GAP_SECS, if handoff.gap_mean_secs <= BOUNDARY_MAX_GAP_SECS { "✓" } else { "✗" });
+        info!("    RMS error:      {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms.within(2.0) { "✓" } else { "✗" });
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        info!("DST-006: Swarm - 50-Agent Scale Test");
+        let metrics = ScenarioMetrics {
+            packets_sent: harness.packets_offered(),
+            bytes_sent: harness.bytes_offered(),
+            handoff: Some(handoff),
+            shard_stats: Some(shard_stats),
+            ..ScenarioMetrics::default()
+        };
         
-        let config = crate::swarm_network::SwarmConfig::default();
-        let num_agents = config.rows * config.cols; // 50
+        ScenarioResult {
+            scenario: ScenarioId::BoundaryCrossing,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// DST-013: RapidFire - 100Hz tick rate.
+    fn run_rapid_fire(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-013: RapidFire - 100Hz TICK RATE 🔥");
         
-        // Setup shared components
         let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
         let key_provider = DeterministicKeyProvider::new(self.seed);
         let root_key = key_provider.biscuit_root_key().public();
         
-        // Create Oracle with 200 entities
-        let mut oracle = crate::oracle::Oracle::new(physics_seed);
-        for i in 0..config.num_entities {
-            let x = (i % 50) as f64 * 20.0;
-            let y = (i / 50) as f64 * 20.0;
-            let z = 100.0 + (i % 10) as f64 * 10.0;
-            let vx = 10.0 + (i % 5) as f64 * 2.0;
-            let vy = 5.0 * ((i % 3) as f64 - 1.0);
-            oracle.spawn_entity(Vector3::new(x, y, z), Vector3::new(vx, vy, 0.0), "target");
-        }
+        let context = Arc::new(SimContext::new(self.seed));
+        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
+        let mut agent = SimulatedAgent::new(context, network, root_key, 0, SimulatedAgent::default_config());
         
-        // Create 50 agents
-        let mut agents: Vec<SimulatedAgent> = Vec::with_capacity(num_agents);
-        for i in 0..num_agents {
-            let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
-            let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-            
-            let agent = SimulatedAgent::new(
-                context,
-                network,
-                root_key.clone(),
-                i as u64,
-                AgentConfig::default(),
+        let mut oracle = Oracle::new(physics_seed);
+        for i in 0..10 {
+            oracle.spawn_entity(
+                Vector3::new(i as f64 * 100.0, 0.0, 100.0),
+                Vector3::new(50.0, 25.0 * ((i % 2) as f64 * 2.0 - 1.0), 0.0),
+                "rapid_target",
             );
-            agents.push(agent);
         }
         
-        // Create gossip network
-        let mut swarm_network = SwarmNetwork::new_grid(config.rows, config.cols);
+        let tick_rate = timing.tick_rate_hz as f64;
+        let dt = timing.dt();
+        let sim_duration = timing.duration_secs;
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
         
-        let dt = 1.0 / config.tick_rate_hz as f64;
-        let target_ticks = (self.max_duration_secs.min(config.duration_secs) * config.tick_rate_hz as f64) as u64;
+        let start_time = std::time::Instant::now();
         
-        info!("  Agents: {} | Entities: {} | Ticks: {}", num_agents, config.num_entities, target_ticks);
+        info!("  Config: {}Hz tick rate, {} ticks, {}s sim time", tick_rate, target_ticks, sim_duration);
+        
+        let monitor = self.monitor(ScenarioId::RapidFire, target_ticks);
         
-        // Main simulation loop
         for tick in 0..target_ticks {
-            // Physics step
             oracle.step(dt);
-            
-            // Each agent observes entities (simplified: all agents see all entities)
-            // In a real sim, you'd filter by H3 cell proximity
             let readings = oracle.generate_sensor_readings();
+            agent.tick();
+            agent.ingest_readings(&readings);
+            rms.sample(oracle.time(), [&agent], &oracle.ground_truth_positions());
             
-            // Distribute readings to agents (each gets a random subset based on position)
-            for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                // Each agent sees ~10% of entities (simulating limited sensor range)
-                let agent_readings: Vec<_> = readings.iter()
-                    .enumerate()
-                    .filter(|(entity_idx, _)| {
-                        // Simple visibility: agent i sees entities where (entity_id + agent_id) % 10 < 5
-                        (entity_idx + agent_idx) % 10 < 5
-                    })
-                    .map(|(_, r)| r.clone())
-                    .collect();
-                
-                agent.tick();
-                agent.ingest_readings(&agent_readings);
-            }
-            
-            // Gossip round every N ticks
-            if tick % config.gossip_interval as u64 == 0 {
-                // Collect packets from all agents
-                let all_packets: Vec<_> = agents.iter()
-                    .enumerate()
-                    .flat_map(|(idx, a)| {
-                        a.recent_packets().iter().map(move |p| (idx, p.clone()))
-                    })
-                    .collect();
-                
-                // Queue gossip
-                for (from_idx, packet) in all_packets {
-                    swarm_network.queue_gossip(from_idx, packet);
-                }
-                
-                // Deliver gossip
-                for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
-                    agent.receive_gossip(&incoming);
-                    agent.clear_recent_packets();
-                }
-            }
-            
-            // Progress log every second
-            if tick % config.tick_rate_hz as u64 == 0 && tick > 0 {
-                let avg_tracks: f64 = agents.iter().map(|a| a.track_count() as f64).sum::<f64>() / num_agents as f64;
-                debug!("  t={:.0}s | avg_tracks={:.1} | gossip_msgs={}", 
-                    tick as f64 / config.tick_rate_hz as f64,
-                    avg_tracks,
-                    swarm_network.messages_sent()
-                );
+            if monitor.observe(tick, &oracle, [&agent]) {
+                break;
             }
         }
         
-        // Compute convergence metrics
-        let track_counts: Vec<usize> = agents.iter().map(|a| a.track_count()).collect();
-        let mean_count = track_counts.iter().sum::<usize>() as f64 / num_agents as f64;
-        let variance = track_counts.iter()
-            .map(|&c| (c as f64 - mean_count).powi(2))
-            .sum::<f64>() / num_agents as f64;
-        let std_dev = variance.sqrt();
-        let coefficient_of_variation = if mean_count > 0.0 { std_dev / mean_count } else { 1.0 };
-        
-        // Compute average RMS error across agents
-        let ground_truth = oracle.ground_truth_positions();
-        let total_rms: f64 = agents.iter()
-            .map(|a| a.compute_position_error(&ground_truth))
-            .sum();
-        let avg_rms_error = total_rms / num_agents as f64;
-        
-        // Total gossip stats
-        let total_gossip: u64 = agents.iter().map(|a| a.gossip_received()).sum();
+        let elapsed = start_time.elapsed();
+        let actual_rate = target_ticks as f64 / elapsed.as_secs_f64();
         
-        // Check pass criteria
-        let variance_ok = coefficient_of_variation < config.max_variance;
-        let error_ok = avg_rms_error < config.max_position_error;
-        let passed = variance_ok && error_ok;
+        // Must run at least 50% of target rate and maintain accuracy
+        let rms_ok = rms.within(3.0);
+        let failure = rms_then_budget(rms.failure(3.0), "tick_rate_hz", actual_rate, tick_rate * 0.5);
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  Agents: {} | Entities: {} | P2P Messages: {}", num_agents, config.num_entities, swarm_network.messages_sent());
-        info!("  CONVERGENCE METRICS:");
-        info!("    Track count (mean):     {:.1}", mean_count);
-        info!("    Track count (CV):       {:.1}%  {}", coefficient_of_variation * 100.0, if variance_ok { "✓" } else { "✗" });
-        info!("    Avg RMS error:          {:.2}m  {}", avg_rms_error, if error_ok { "✓" } else { "✗" });
-        info!("    Total gossip received:  {}", total_gossip);
+        info!("  RAPID FIRE RESULTS:");
+        info!("    RMS error:    {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms_ok { "✓" } else { "✗" });
+        info!("    Target rate:  {}Hz", tick_rate);
+        info!("    Actual rate:  {:.0}Hz  {}", actual_rate, if actual_rate > tick_rate * 0.5 { "✓" } else { "✗" });
+        info!("    Wall time:    {:.3}s", elapsed.as_secs_f64());
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        let mut metrics = ScenarioMetrics::default();
-        metrics.packets_sent = swarm_network.messages_sent();
-        
         ScenarioResult {
-            scenario: ScenarioId::Swarm,
+            scenario: ScenarioId::RapidFire,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
             final_time_secs: oracle.time(),
             final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed {
-                Some(format!("CV={:.1}% (max {}%), RMS={:.2}m (max {})", 
-                    coefficient_of_variation * 100.0, config.max_variance * 100.0,
-                    avg_rms_error, config.max_position_error))
-            } else {
-                None
-            },
-            metrics,
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics: ScenarioMetrics::default(),
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-007: AdaptiveSwarm - Learning agents with bad actor detection.
+    // ═══════════════════════════════════════════════════════════════════════════
+    // EVOLUTIONARY INTELLIGENCE - Adapting to the Unknown
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// DST-014: EvoWar - Evolution vs Chaos.
     ///
-    /// Tests adaptive intelligence:
-    /// - 50 agents (45 good, 5 bad actors injected at t=10s)
-    /// - Agents learn to identify and ignore bad actors
-    /// - Measures: bad actors detected, accuracy maintained
-    fn run_adaptive_swarm(&self) -> ScenarioResult {
+    /// Red Team (static bad actors) vs Blue Team (evolutionary).
+    /// Can Blue evolve to survive high noise + bad actors?
+    fn run_evo_war(&self, timing: RunTiming) -> Result<ScenarioResult, ScenarioError> {
         use crate::swarm_network::SwarmNetwork;
-        
-        use rand::SeedableRng;
         use rand::Rng;
-        use rand_chacha::ChaCha8Rng;
         
-        info!("DST-007: AdaptiveSwarm - Learning Agents");
+        info!("DST-014: EvoWar - EVOLUTION VS CHAOS 🧬");
         
-        let config = crate::swarm_network::SwarmConfig::default();
-        let num_agents = config.rows * config.cols; // 50
-        let num_bad_actors = 5;
-        let bad_actor_inject_time = 10.0; // Inject at t=10s
+        let num_agents = 50;
+        let num_red_team = 25; // Half are static/bad
+        let packet_loss_rate = 0.30;
         
-        // Setup shared components
         let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
         let key_provider = DeterministicKeyProvider::new(self.seed);
         let root_key = key_provider.biscuit_root_key().public();
+        let mut rng = key_provider.derive_stream("runner/evo-war");
         
-        // Random number generator for bad actor behavior
-        let mut rng = ChaCha8Rng::seed_from_u64(self.seed.wrapping_mul(0xdeadbeef));
-        
-        // Create Oracle with 200 entities
-        let mut oracle = crate::oracle::Oracle::new(physics_seed);
-        for i in 0..config.num_entities {
-            let x = (i % 50) as f64 * 20.0;
-            let y = (i / 50) as f64 * 20.0;
-            let z = 100.0 + (i % 10) as f64 * 10.0;
-            let vx = 10.0 + (i % 5) as f64 * 2.0;
-            let vy = 5.0 * ((i % 3) as f64 - 1.0);
-            oracle.spawn_entity(Vector3::new(x, y, z), Vector3::new(vx, vy, 0.0), "target");
+        // Oracle setup
+        let mut oracle = Oracle::new(physics_seed);
+        for i in 0..100 {
+            oracle.spawn_entity(
+                Vector3::new((i % 10) as f64 * 50.0, (i / 10) as f64 * 50.0, 100.0),
+                Vector3::new(5.0, 2.0, 0.0),
+                "evo_target",
+            );
         }
         
-        // Create 50 agents (all start as good)
+        // Agents
         let mut agents: Vec<SimulatedAgent> = Vec::with_capacity(num_agents);
         for i in 0..num_agents {
             let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
             let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-            
-            let agent = SimulatedAgent::new(
+            agents.push(SimulatedAgent::new(
                 context,
                 network,
-                root_key.clone(),
+                root_key,
                 i as u64,
-                AgentConfig::default(),
-            );
-            agents.push(agent);
+                SimulatedAgent::default_config(),
+            ));
+        }
+        
+        // Red team indices (static bad actors)
+        let red_team_ids: Vec<usize> = (0..num_red_team).collect();
+        // Blue team indices (evolving)
+        let blue_team_ids: Vec<usize> = (num_red_team..num_agents).collect();
+        
+        // Configure Red Team as bad actors
+        for &id in &red_team_ids {
+            // Re-create as bad actor
+            let context = Arc::new(SimContext::new(self.seed.wrapping_add(id as u64)));
+            let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(id as u64)));
+            agents[id] = SimulatedAgent::new_bad_actor(context, network, root_key, id as u64, SimulatedAgent::default_config());
         }
+        self.load_trained_params(&mut agents);
         
-        // Track which agents become bad actors
-        let mut bad_actor_ids: Vec<usize> = Vec::new();
-        let mut bad_actors_converted = false;
+        let mut swarm_network = SwarmNetwork::new_grid(5, 10);
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let evo_epoch_ticks = 20; // Evolve every 2s
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
         
-        // Create gossip network
-        let mut swarm_network = SwarmNetwork::new_grid(config.rows, config.cols);
+        info!("  Config: {} Blue (learning), {} Red (static/bad), 30% loss", blue_team_ids.len(), red_team_ids.len());
         
-        let dt = 1.0 / config.tick_rate_hz as f64;
-        let target_ticks = (self.max_duration_secs.min(config.duration_secs) * config.tick_rate_hz as f64) as u64;
+        let _ground_truth_buffer: Vec<_> = oracle.ground_truth_positions(); // Initial
         
-        info!("  Agents: {} ({} will become bad actors at t={}s)", 
-            num_agents, num_bad_actors, bad_actor_inject_time);
+        let monitor = self.monitor(ScenarioId::EvoWar, target_ticks);
         
-        // Main simulation loop
         for tick in 0..target_ticks {
-            let current_time = tick as f64 * dt;
-            
-            // INJECT BAD ACTORS at t=10s
-            if current_time >= bad_actor_inject_time && !bad_actors_converted {
-                // Pick 5 random agents to become bad actors
-                for _ in 0..num_bad_actors {
-                    let bad_idx = rng.gen_range(0..num_agents);
-                    if !bad_actor_ids.contains(&bad_idx) {
-                        bad_actor_ids.push(bad_idx);
-                    }
-                }
-                info!("  ⚠️  Injecting {} bad actors at t={:.1}s: {:?}", 
-                    bad_actor_ids.len(), current_time, bad_actor_ids);
-                bad_actors_converted = true;
-            }
-            
-            // Physics step
             oracle.step(dt);
-            
-            // Each agent observes entities
             let readings = oracle.generate_sensor_readings();
+            let ground_truth = oracle.ground_truth_positions();
             
             for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                // Each agent sees ~50% of entities
+                // Blue team evolves
+                if blue_team_ids.contains(&agent_idx) {
+                    agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
+                } else {
+                    agent.tick(); // Red team just ticks
+                }
+                
+                // Sensor input
                 let agent_readings: Vec<_> = readings.iter()
                     .enumerate()
-                    .filter(|(entity_idx, _)| {
-                        (entity_idx + agent_idx) % 10 < 5
-                    })
+                    .filter(|(entity_idx, _)| (entity_idx + agent_idx) % 4 == 0)
                     .map(|(_, r)| r.clone())
                     .collect();
-                
-                agent.tick();
                 agent.ingest_readings(&agent_readings);
             }
             
-            // Gossip round every N ticks
-            if tick % config.gossip_interval as u64 == 0 {
-                // Collect packets from all agents
+            // Gossip
+            if tick % 5 == 0 {
+                // Collect packets
                 let all_packets: Vec<_> = agents.iter()
                     .enumerate()
                     .flat_map(|(idx, a)| {
@@ -814,1433 +4511,2140 @@ impl ScenarioRunner {
                             .map(|p| (idx, p.clone()))
                             .collect();
                         
-                        // BAD ACTORS: inject garbage packets
-                        if bad_actor_ids.contains(&idx) {
-                            for _ in 0..3 {
+                        // Red team injects garbage
+                        if red_team_ids.contains(&idx) {
+                            for _ in 0..5 {
                                 let garbage = godview_core::godview_tracking::GlobalHazardPacket {
-                                    entity_id: Uuid::new_v4(), // Random fake entity
-                                    position: [
-                                        rng.gen_range(-1000.0..1000.0),
-                                        rng.gen_range(-1000.0..1000.0),
-                                        rng.gen_range(-1000.0..1000.0),
-                                    ],
+                                    entity_id: Uuid::new_v4(), // Confusing ID
+                                    position: [rng.gen_range(-500.0..500.0), rng.gen_range(-500.0..500.0), 0.0],
                                     velocity: [0.0, 0.0, 0.0],
-                                    class_id: 99, // Fake class
-                                    timestamp: current_time,
-                                    confidence_score: 0.1,
+                                    class_id: 99,
+                                    timestamp: tick as f64 * dt,
+                                    confidence_score: 0.9, // High confidence lie
+                                    measurement_model: godview_core::MeasurementModel::default(),
+                                    priority: 0,
                                 };
                                 packets.push((idx, garbage));
                             }
                         }
-                        
                         packets
                     })
                     .collect();
-                
-                // Queue gossip with source tracking
+
+                // Distribute packets (Blue respects evo params)
                 for (from_idx, packet) in all_packets {
+                    // Packet loss
+                    if rng.gen::<f64>() < packet_loss_rate { continue; }
+                    
+                    let size = wire_bytes(&packet);
                     swarm_network.queue_gossip(from_idx, packet);
+                    
+                    // Record measurement for BLUE team sender
+                    if blue_team_ids.contains(&from_idx) {
+                        agents[from_idx].record_message_sent_metric(size);
+                    }
                 }
                 
-                // Deliver gossip WITH neighbor tracking
                 for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    // Get gossip from network
-                    let incoming = swarm_network.take_gossip(agent_idx);
-                    
-                    // In a real implementation, we'd track which neighbor sent each packet
-                    // For now, simulate by using the agent's position in grid
-                    let neighbors = swarm_network.neighbors(agent_idx);
-                    if !neighbors.is_empty() {
-                        // Distribute incoming packets among neighbors
-                        let packets_per_neighbor = incoming.len() / neighbors.len().max(1);
-                        for (i, neighbor_id) in neighbors.iter().enumerate() {
-                            let start = i * packets_per_neighbor;
-                            let end = ((i + 1) * packets_per_neighbor).min(incoming.len());
-                            if start < end {
-                                agent.receive_gossip_from(*neighbor_id, &incoming[start..end]);
-                            }
-                        }
-                    }
+                    // Apply EVO constraints for Blue
+                    let max_neighbors = if blue_team_ids.contains(&agent_idx) {
+                        agent.max_gossip_neighbors()
+                    } else {
+                        100 // Red talks to everyone
+                    };
                     
+                    // Only hear from that many neighbors (simulate bandwidth constraint)
+                    let incoming = gossip_packets(swarm_network.take_gossip_from(agent_idx, max_neighbors));
+                    agent.receive_gossip(&incoming);
                     agent.clear_recent_packets();
                 }
             }
             
-            // Progress log every 5 seconds
-            if tick % (config.tick_rate_hz * 5) as u64 == 0 && tick > 0 {
-                let good_agents: Vec<_> = agents.iter().enumerate()
-                    .filter(|(idx, _)| !bad_actor_ids.contains(idx))
-                    .collect();
-                
-                let (sum, count): (f64, i32) = good_agents.iter()
-                    .flat_map(|(_, a)| {
-                        bad_actor_ids.iter().filter_map(|&bad_id| {
-                            a.adaptive_state().neighbor_reputations.get(&bad_id)
-                                .map(|r| r.reliability_score)
-                        })
-                    })
-                    .fold((0.0, 0), |(sum, count), r| (sum + r, count + 1));
-                let avg_bad = if count > 0 { sum / count as f64 } else { 0.5 };
-                
-                debug!("  t={:.0}s | bad_actor_reliability={:.2}", 
-                    current_time, avg_bad);
+            // Blue Team Score
+            rms.sample(
+                oracle.time(),
+                agents.iter().enumerate()
+                    .filter(|(idx, _)| blue_team_ids.contains(idx))
+                    .map(|(_, a)| a),
+                &ground_truth,
+            );
+            
+            if monitor.observe(
+                tick,
+                &oracle,
+                agents.iter().enumerate()
+                    .filter(|(idx, _)| blue_team_ids.contains(idx))
+                    .map(|(_, a)| a),
+            ) {
+                break;
             }
         }
         
-        // Compute convergence metrics
-        let track_counts: Vec<usize> = agents.iter().map(|a| a.track_count()).collect();
-        let mean_count = track_counts.iter().sum::<usize>() as f64 / num_agents as f64;
-        let variance = track_counts.iter()
-            .map(|&c| (c as f64 - mean_count).powi(2))
-            .sum::<f64>() / num_agents as f64;
-        let std_dev = variance.sqrt();
-        let _coefficient_of_variation = if mean_count > 0.0 { std_dev / mean_count } else { 1.0 };
-        
-        // Compute RMS error for GOOD agents only
-        let ground_truth = oracle.ground_truth_positions();
-        let good_agent_rms: Vec<f64> = agents.iter().enumerate()
-            .filter(|(idx, _)| !bad_actor_ids.contains(idx))
-            .map(|(_, a)| a.compute_position_error(&ground_truth))
-            .collect();
-        let avg_rms_error = if good_agent_rms.is_empty() {
-            0.0
-        } else {
-            good_agent_rms.iter().sum::<f64>() / good_agent_rms.len() as f64
-        };
+        // Did params diverge from default?
+        let blue_lead = blue_team_ids.first().copied()
+            .ok_or_else(|| ScenarioError::internal(ScenarioId::EvoWar, "the blue team is empty"))?;
+        let blue_params = &agents.get(blue_lead)
+            .ok_or_else(|| ScenarioError::agent(ScenarioId::EvoWar, blue_lead, "blue team agent doesn't exist"))?
+            .evolutionary_state().current_params;
+        let param_drift = (blue_params.confidence_threshold - 0.0).abs() > 0.01 || 
+                          blue_params.max_neighbors_gossip != 100 ||
+                          blue_params.gossip_interval_ticks != 5;
         
-        // Count how many good agents identified bad actors (only among neighbors)
-        let mut bad_actors_identified = 0;
-        let mut possible_detections = 0;
-        
-        for (agent_idx, agent) in agents.iter().enumerate() {
-            if bad_actor_ids.contains(&agent_idx) {
-                continue; // Skip bad actors
-            }
-            
-            // Only check bad actors that are neighbors of this agent
-            let neighbors = swarm_network.neighbors(agent_idx);
-            for &bad_id in &bad_actor_ids {
-                if neighbors.contains(&bad_id) {
-                    possible_detections += 1;
-                    if let Some(rep) = agent.adaptive_state().neighbor_reputations.get(&bad_id) {
-                        if rep.reliability_score < 0.3 {
-                            bad_actors_identified += 1;
-                        }
-                    }
-                }
-            }
-        }
+        let failure = rms.failure(10.0);
+        let passed = failure.is_none();
         
-        // Aggregate adaptive metrics
-        let total_gossip_filtered: u64 = agents.iter()
-            .map(|a| a.adaptive_metrics().gossip_filtered)
-            .sum();
-        let total_tracks_dropped: u64 = agents.iter()
-            .map(|a| a.adaptive_metrics().tracks_dropped)
-            .sum();
-        let avg_efficiency: f64 = agents.iter()
-            .map(|a| a.adaptive_metrics().gossip_efficiency)
-            .sum::<f64>() / num_agents as f64;
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  EVO WAR RESULTS:");
+        info!("    Blue RMS:      {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if passed { "✓" } else { "✗" });
+        info!("    Param Drift:   {} (Agents adapted!)", if param_drift { "YES" } else { "NO" });
+        info!("    Final Params:  interval={}, neighbors={}, conf={:.2}", 
+            blue_params.gossip_interval_ticks, blue_params.max_neighbors_gossip, blue_params.confidence_threshold);
+        let mut metrics = ScenarioMetrics {
+            packets_sent: swarm_network.messages_sent(),
+            bytes_sent: swarm_network.bytes_sent(),
+            ..Default::default()
+        };
+        metrics.record_convergence(blue_team_ids.iter().map(|&idx| &agents[idx]));
+        metrics.record_evolved_params(&agents);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        // Check pass criteria
-        let detection_rate = if possible_detections > 0 {
-            bad_actors_identified as f64 / possible_detections as f64
-        } else {
-            0.0
+        Ok(ScenarioResult {
+            scenario: ScenarioId::EvoWar,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: oracle.time(),
+            final_entity_count: oracle.active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        })
+    }
+    
+    /// DST-015: ResourceStarvation.
+    ///
+    /// Every gossip link carries at most 3 packets per tick with an 8-deep
+    /// drop-tail queue, so agents that talk too much lose packets to overflow.
+    /// Agents only offer what their links have room for; the scenario runs
+    /// once with FIFO truncation and once (on the same seed) with gossip
+    /// prioritized by information value, which must track better.
+    fn run_resource_starvation(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-015: ResourceStarvation - BANDWIDTH LIMIT 🧬");
+        
+        let fifo = self.starvation_run(timing, GossipOrder::Fifo);
+        let run = self.starvation_run(timing, GossipOrder::Prioritized);
+        let rms = &run.rms;
+        let avg_interval = run.avg_interval;
+        
+        let mut metrics = ScenarioMetrics {
+            packets_sent: run.messages_sent,
+            packets_dropped: run.link_stats.dropped_overflow,
+            bytes_sent: run.bytes_sent,
+            bytes_dropped: run.link_stats.bytes_dropped,
+            evolved_params: run.evolved_params,
+            ..Default::default()
         };
+        metrics.record_link_stats(run.link_stats);
         
-        let detection_ok = detection_rate >= 0.3 || possible_detections == 0;
-        let error_ok = avg_rms_error < 5.0;
-        let passed = detection_ok && error_ok;
+        let rms_ok = rms.within(5.0);
+        let interval_ok = avg_interval > 5.0; // Interval should increase > 5 (default)
+        let priority_ok = rms.mean() < fifo.rms.mean();
+        let failure = resource_starvation_failure(rms.failure(5.0), avg_interval, rms.mean(), fifo.rms.mean());
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  Agents: {} ({} bad actors)", num_agents, bad_actor_ids.len());
-        info!("  P2P Messages: {}", swarm_network.messages_sent());
-        info!("  ADAPTIVE METRICS:");
-        info!("    Detection rate:      {:.0}%  {}", detection_rate * 100.0, if detection_ok { "✓" } else { "✗" });
-        info!("    Good agent RMS:      {:.2}m  {}", avg_rms_error, if error_ok { "✓" } else { "✗" });
-        info!("    Gossip filtered:     {}", total_gossip_filtered);
-        info!("    Tracks auto-dropped: {}", total_tracks_dropped);
-        info!("    Gossip efficiency:   {:.0}%", avg_efficiency * 100.0);
+        info!("  RESOURCE STARVATION RESULTS:");
+        info!("    RMS error:      {:.3}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms_ok { "✓" } else { "✗" });
+        info!("    FIFO RMS:       {:.3}m (p95 {:.2}m), prioritized better  {}",
+            fifo.rms.mean(), fifo.rms.p95(), if priority_ok { "✓" } else { "✗" });
+        info!("    Avg Interval:   {:.1} ticks (started at 5) {}", avg_interval, if interval_ok { "✓ (Adapted)" } else { "✗" });
+        metrics.set_extra("avg_gossip_interval", avg_interval);
+        metrics.set_extra("fifo_rms_mean", fifo.rms.mean());
+        info!("    Link Overflow:  {:.1}% ({} of {} packets)",
+            run.link_stats.dropped_overflow as f64 * 100.0 / metrics.packets_sent.max(1) as f64,
+            run.link_stats.dropped_overflow, metrics.packets_sent);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        let mut metrics = ScenarioMetrics::default();
-        metrics.packets_sent = swarm_network.messages_sent();
         
         ScenarioResult {
-            scenario: ScenarioId::AdaptiveSwarm,
+            scenario: ScenarioId::ResourceStarvation,
             seed: self.seed,
             passed,
-            total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed {
-                Some(format!("Detection={:.0}% (min 30%), RMS={:.2}m (max 5)", 
-                    detection_rate * 100.0, avg_rms_error))
-            } else {
-                None
-            },
+            total_ticks: run.ticks,
+            final_time_secs: run.final_time_secs,
+            final_entity_count: run.final_entity_count,
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
             metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    // ═══════════════════════════════════════════════════════════════════════════
-    // EXTREME CHAOS SCENARIOS - Push GodView to its absolute limits!
-    // ═══════════════════════════════════════════════════════════════════════════
-    
-    /// DST-008: ChaosStorm - Everything bad at once.
-    ///
-    /// Combines: jitter + 30% packet loss + bad actors + moving entities
-    fn run_chaos_storm(&self) -> ScenarioResult {
+    /// Runs the ResourceStarvation simulation once with the given gossip order.
+    fn starvation_run(&self, timing: RunTiming, order: GossipOrder) -> StarvationRun {
         use crate::swarm_network::SwarmNetwork;
-        use rand::SeedableRng;
-        use rand::Rng;
-        use rand_chacha::ChaCha8Rng;
-        
-        info!("DST-008: ChaosStorm - EVERYTHING AT ONCE 🔥");
         
         let num_agents = 50;
-        let num_entities = 200;
-        let num_bad_actors = 5;
-        let packet_loss_rate = 0.30; // 30% loss
-        let max_jitter_ms = 500.0;
+        let link_config = LinkConfig::drop_tail(1, 6); // 1 packet per link per tick: well under 10 sensed per agent
         
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
+        let mut agents: Vec<SimulatedAgent> = Vec::with_capacity(num_agents);
         let key_provider = DeterministicKeyProvider::new(self.seed);
         let root_key = key_provider.biscuit_root_key().public();
-        let mut rng = ChaCha8Rng::seed_from_u64(self.seed.wrapping_mul(0xcafe8080));
-        
-        // Create Oracle with MOVING entities
-        let mut oracle = Oracle::new(physics_seed);
-        for i in 0..num_entities {
-            let x = (i % 50) as f64 * 20.0;
-            let y = (i / 50) as f64 * 20.0;
-            let z = 100.0 + (i % 10) as f64 * 10.0;
-            // Fast moving entities in random directions
-            let vx = (rng.gen::<f64>() - 0.5) * 40.0;
-            let vy = (rng.gen::<f64>() - 0.5) * 40.0;
-            let vz = (rng.gen::<f64>() - 0.5) * 10.0;
-            oracle.spawn_entity(Vector3::new(x, y, z), Vector3::new(vx, vy, vz), "chaos_target");
-        }
         
-        // Create agents
-        let mut agents: Vec<SimulatedAgent> = Vec::with_capacity(num_agents);
         for i in 0..num_agents {
             let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
             let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-            agents.push(SimulatedAgent::new(
-                context,
-                network,
-                root_key.clone(),
-                i as u64,
-                AgentConfig::default(),
-            ));
+            let mut agent = SimulatedAgent::new(
+                context, 
+                network, 
+                root_key, 
+                i as u64, 
+                SimulatedAgent::default_config()
+            );
+            agent.set_gossip_order(order);
+            agents.push(agent);
         }
+        self.load_trained_params(&mut agents);
         
-        // Designate bad actors
-        let bad_actor_ids: Vec<usize> = (0..num_bad_actors).map(|i| i * 10).collect();
+        let mut oracle = Oracle::new(self.seed);
+        for i in 0..50 {
+             oracle.spawn_entity(
+                Vector3::new(i as f64 * 10.0, 0.0, 100.0),
+                Vector3::new(1.0, 0.0, 0.0),
+                "starve_target",
+            );
+        }
         
-        let mut swarm_network = SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1; // 10 Hz
-        let target_ticks = (self.max_duration_secs.min(30.0) * 10.0) as u64;
+        let mut swarm_network = SwarmNetwork::new_grid(5, 10)
+            .with_link_config(link_config, self.seed);
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let evo_epoch_ticks = 10;
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
         
-        let mut packets_sent = 0u64;
-        let mut packets_dropped = 0u64;
+        info!("  Config: {} agents, 50 entities, {} ticks, {:?} gossip", num_agents, target_ticks, order);
         
-        info!("  Config: {} agents, {} entities, {}% loss, {}ms jitter, {} bad actors",
-            num_agents, num_entities, (packet_loss_rate * 100.0) as u32, 
-            max_jitter_ms as u32, num_bad_actors);
+        let monitor = self.monitor(ScenarioId::ResourceStarvation, target_ticks);
         
         for tick in 0..target_ticks {
-            // Physics - entities are MOVING
             oracle.step(dt);
-            
             let readings = oracle.generate_sensor_readings();
+            let ground_truth = oracle.ground_truth_positions();
             
-            for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                // Apply jitter: some readings arrive with delay (simulated by not processing)
-                let jitter_skip = rng.gen::<f64>() < 0.2; // 20% delayed
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
                 
-                if !jitter_skip {
-                    let agent_readings: Vec<_> = readings.iter()
-                        .enumerate()
-                        .filter(|(entity_idx, _)| (entity_idx + agent_idx) % 4 < 2)
-                        .map(|(_, r)| r.clone())
-                        .collect();
-                    
-                    agent.tick();
-                    agent.ingest_readings(&agent_readings);
-                }
-            }
-            
-            // Gossip with packet loss
-            if tick % 5 == 0 {
-                let all_packets: Vec<_> = agents.iter()
+                 let agent_readings: Vec<_> = readings.iter()
                     .enumerate()
-                    .flat_map(|(idx, a)| {
-                        let mut packets: Vec<_> = a.recent_packets().iter()
-                            .map(|p| (idx, p.clone()))
-                            .collect();
-                        
-                        // Bad actors inject garbage
-                        if bad_actor_ids.contains(&idx) {
-                            for _ in 0..3 {
-                                let garbage = godview_core::godview_tracking::GlobalHazardPacket {
-                                    entity_id: Uuid::new_v4(),
-                                    position: [rng.gen_range(-500.0..500.0), rng.gen_range(-500.0..500.0), rng.gen_range(0.0..500.0)],
-                                    velocity: [0.0, 0.0, 0.0],
-                                    class_id: 99,
-                                    timestamp: tick as f64 * dt,
-                                    confidence_score: 0.1,
-                                };
-                                packets.push((idx, garbage));
-                            }
-                        }
-                        packets
-                    })
+                    .filter(|(entity_idx, _)| (entity_idx + idx) % 5 == 0)
+                    .map(|(_, r)| r.clone())
                     .collect();
-                
-                for (from_idx, packet) in all_packets {
-                    packets_sent += 1;
-                    // Apply packet loss
-                    if rng.gen::<f64>() < packet_loss_rate {
-                        packets_dropped += 1;
-                        continue;
-                    }
-                    swarm_network.queue_gossip(from_idx, packet);
-                }
-                
-                for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
-                    let neighbors = swarm_network.neighbors(agent_idx);
-                    if !neighbors.is_empty() && !incoming.is_empty() {
-                        let per = incoming.len() / neighbors.len().max(1);
-                        for (i, neighbor_id) in neighbors.iter().enumerate() {
-                            let start = i * per;
-                            let end = ((i + 1) * per).min(incoming.len());
-                            if start < end {
-                                agent.receive_gossip_from(*neighbor_id, &incoming[start..end]);
-                            }
-                        }
+                agent.ingest_readings(&agent_readings);
+            }
+            
+            // Queue as many packets as the fullest outbound link has room for
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                // Respect agent's evolved gossip interval
+                if tick.is_multiple_of(agent.gossip_interval()) {
+                    let room = swarm_network.neighbors(idx).iter()
+                        .map(|&n| link_config.max_queue_depth.saturating_sub(swarm_network.queue_depth(idx, n)))
+                        .min()
+                        .unwrap_or(0);
+                    for p in agent.gossip_batch(room, None) {
+                        agent.record_message_sent_metric(wire_bytes(&p));
+                        swarm_network.queue_gossip(idx, p);
                     }
-                    agent.clear_recent_packets();
                 }
             }
+            
+            swarm_network.deliver_round();
+            
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                let incoming = swarm_network.take_gossip_packets(idx);
+                agent.receive_gossip(&incoming);
+                agent.clear_recent_packets();
+            }
+            
+            rms.sample(oracle.time(), &agents, &ground_truth);
+            
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
+            }
         }
         
-        // Measure: Did we survive? What's the error?
-        let ground_truth = oracle.ground_truth_positions();
-        let good_agent_rms: Vec<f64> = agents.iter().enumerate()
-            .filter(|(idx, _)| !bad_actor_ids.contains(idx))
-            .map(|(_, a)| a.compute_position_error(&ground_truth))
-            .collect();
-        let avg_rms_error = good_agent_rms.iter().sum::<f64>() / good_agent_rms.len().max(1) as f64;
+        // Check if agents increased gossip interval to reduce cost
+        let avg_interval: f64 = agents.iter().map(|a| a.gossip_interval() as f64).sum::<f64>() / num_agents as f64;
         
-        let loss_rate = if packets_sent > 0 { packets_dropped as f64 / packets_sent as f64 } else { 0.0 };
-        let passed = avg_rms_error < 10.0; // Relaxed threshold for chaos
+        StarvationRun {
+            rms,
+            avg_interval,
+            evolved_params: agents.iter().map(|a| a.evolutionary_state().current_params).collect(),
+            link_stats: swarm_network.total_link_stats(),
+            messages_sent: swarm_network.messages_sent(),
+            bytes_sent: swarm_network.bytes_sent(),
+            ticks: target_ticks,
+            final_time_secs: oracle.time(),
+            final_entity_count: oracle.active_entities().len(),
+        }
+    }
+    
+    /// DST-016: ProtocolDrift - Mixed wire versions.
+    ///
+    /// A 4x4 gossip grid in a checkerboard of V2 and V1-only agents: every
+    /// V2 envelope reaches V1 neighbors, which must decode it by the fields
+    /// they know (ignoring the covariance) instead of rejecting it.
+    ///
+    /// **Success Criteria**: no envelope rejected, some packets downgraded,
+    /// V1 RMS within 2x of V2 RMS.
+    fn run_protocol_drift(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-016: ProtocolDrift - MIXED WIRE VERSIONS 🧬");
+        
+        let (rows, cols) = (4, 4);
+        let num_entities = 30;
+        let max_rms_ratio = 2.0;
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        
+        let mut sim = Simulation::from_config(SimConfig {
+            seed: self.seed,
+            num_agents: rows * cols,
+            tick_rate_hz: timing.tick_rate_hz,
+            max_duration_secs: timing.duration_secs,
+            visibility: Visibility::Striped { period: 4, visible: 2 },
+            gossip: Some(GossipConfig { rows, cols, interval_ticks: 3, relay: GossipRelay::Off, max_packet_age_ms: None }),
+            ..SimConfig::default()
+        }).with_event_bus(self.hooks.event_bus());
+        
+        let speaks_v2 = |idx: usize| (idx / cols + idx % cols).is_multiple_of(2);
+        for (idx, agent) in sim.agents_mut().iter_mut().enumerate() {
+            agent.set_packet_version(if speaks_v2(idx) { PacketVersion::V2 } else { PacketVersion::V1 });
+        }
+        
+        for i in 0..num_entities {
+            let position = Vector3::new((i % 10) as f64 * 40.0, (i / 10) as f64 * 40.0, 100.0);
+            let velocity = Vector3::new(6.0, 2.0 * ((i % 3) as f64 - 1.0), 0.0);
+            sim.oracle_mut().spawn_entity(position, velocity, "drift_target");
+        }
+        
+        let run_secs = target_ticks as f64 * dt;
+        let mut rms = self.rms_sampler(run_secs);
+        let mut v1_rms = self.rms_sampler(run_secs);
+        let mut v2_rms = self.rms_sampler(run_secs);
+        
+        info!("  Config: {} agents ({} V2, {} V1), {} entities, {} ticks",
+            rows * cols, (0..rows * cols).filter(|&i| speaks_v2(i)).count(),
+            (0..rows * cols).filter(|&i| !speaks_v2(i)).count(), num_entities, target_ticks);
+        
+        let monitor = self.monitor(ScenarioId::ProtocolDrift, target_ticks);
+        
+        for tick in 0..target_ticks {
+            sim.step();
+            let ground_truth = sim.oracle().ground_truth_positions();
+            rms.sample(sim.time(), sim.agents(), &ground_truth);
+            v1_rms.sample(sim.time(), sim.agents().iter().filter(|a| a.packet_version() == PacketVersion::V1), &ground_truth);
+            v2_rms.sample(sim.time(), sim.agents().iter().filter(|a| a.packet_version() == PacketVersion::V2), &ground_truth);
+            
+            if monitor.observe(tick, sim.oracle(), sim.agents()) {
+                break;
+            }
+        }
+        
+        let stats: Vec<SignedGossipStats> = sim.agents().iter().map(|a| a.signed_gossip_stats()).collect();
+        let rejected: u64 = stats.iter().map(|s| s.rejected()).sum();
+        let downgraded: u64 = stats.iter().map(|s| s.downgraded_packets).sum();
+        let (v1, v2) = (v1_rms.mean(), v2_rms.mean());
+        let ratio_ok = v1 <= max_rms_ratio * v2.max(f64::EPSILON);
+        let failure = protocol_drift_failure(rejected, downgraded, v1, v2, max_rms_ratio);
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  CHAOS STORM RESULTS:");
-        info!("    RMS error:     {:.2}m  {}", avg_rms_error, if passed { "✓" } else { "✗" });
-        info!("    Packet loss:   {:.0}%", loss_rate * 100.0);
-        info!("    Messages:      {} sent, {} dropped", packets_sent, packets_dropped);
+        info!("  PROTOCOL DRIFT RESULTS:");
+        info!("    Gossip rejected: {}  {}", rejected, if rejected == 0 { "✓" } else { "✗" });
+        info!("    Packets downgraded V2→V1: {}  {}", downgraded, if downgraded > 0 { "✓" } else { "✗" });
+        info!("    RMS: V1 {:.2}m, V2 {:.2}m (max ratio {})  {}", v1, v2, max_rms_ratio, if ratio_ok { "✓" } else { "✗" });
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        let mut metrics = ScenarioMetrics::default();
-        metrics.packets_sent = packets_sent;
-        metrics.packets_dropped = packets_dropped;
+        
+        let mut metrics = ScenarioMetrics {
+            packets_sent: sim.swarm_network().map(|n| n.route_stats().sent).unwrap_or(0),
+            downgraded_packets: downgraded,
+            v1_rms: Some(v1),
+            v2_rms: Some(v2),
+            ..Default::default()
+        };
+        metrics.record_latency(sim.agents());
         
         ScenarioResult {
-            scenario: ScenarioId::ChaosStorm,
+            scenario: ScenarioId::ProtocolDrift,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed { Some(format!("RMS {:.2}m > 10m limit", avg_rms_error)) } else { None },
+            final_time_secs: sim.time(),
+            final_entity_count: sim.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
             metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
-    
-    /// DST-009: ScaleLimit - 200 agents, 1000 entities.
-    fn run_scale_limit(&self) -> ScenarioResult {
-        use crate::swarm_network::SwarmNetwork;
+
+    /// DST-017: BlindLearning - Evolve without Ground Truth.
+    ///
+    /// Agents must optimize NIS (Internal Consistency) and Peer Agreement (Consensus)
+    /// to find good parameters, without ever knowing their true error.
+    fn run_blind_learning(&self, timing: RunTiming) -> Result<ScenarioResult, ScenarioError> {
+        info!("DST-017: BlindLearning - ADAPTING BLINDLY 🙈");
         
-        info!("DST-009: ScaleLimit - 200 AGENTS, 1000 ENTITIES 🔥");
+        let mut oracle = Oracle::new(self.seed);
+        spawn_blind_learning_targets(&mut oracle);
+        let mut agents = self.blind_learning_agents();
+        info!("  Config: {} agents using BlindFitness (NIS+PA+BW)", agents.len());
         
-        let num_agents = 200;
-        let num_entities = 1000;
+        let monitor = self.monitor(ScenarioId::BlindLearning, timing.target_ticks());
+        let (episode, initial_rms) = self.blind_learning_episode(timing, &mut oracle, &mut agents, self.seed, &monitor);
+        self.blind_learning_result(timing, &oracle, &agents, episode, initial_rms)
+    }
+    
+    /// BlindLearning's 50 agents, initialized with BlindFitness.
+    fn blind_learning_agents(&self) -> Vec<SimulatedAgent> {
+        use crate::evolution::BlindFitness;
         
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
         let key_provider = DeterministicKeyProvider::new(self.seed);
         let root_key = key_provider.biscuit_root_key().public();
+        let mut agents: Vec<SimulatedAgent> = (0..50)
+            .map(|i| {
+                let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
+                let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
+                let mut agent = SimulatedAgent::new(context, network, root_key, i as u64, SimulatedAgent::default_config());
+                
+                // CRITICAL: Switch to Blind Fitness!
+                agent.set_fitness_provider(Box::new(BlindFitness::new()));
+                agent
+            })
+            .collect();
+        self.load_trained_params(&mut agents);
+        agents
+    }
+    
+    /// Plays one BlindLearning episode against `oracle`'s targets, dropping
+    /// packets with losses drawn from `seed`. Returns what it measured and
+    /// the swarm's RMS 20 ticks in.
+    fn blind_learning_episode(
+        &self,
+        timing: RunTiming,
+        oracle: &mut Oracle,
+        agents: &mut [SimulatedAgent],
+        seed: u64,
+        monitor: &RunMonitor<'_>,
+    ) -> (LearningEpisode, f64) {
+        use rand::Rng;
         
-        let mut oracle = Oracle::new(physics_seed);
-        for i in 0..num_entities {
-            let x = (i % 100) as f64 * 10.0;
-            let y = (i / 100) as f64 * 10.0;
-            let z = 50.0 + (i % 20) as f64 * 5.0;
-            oracle.spawn_entity(Vector3::new(x, y, z), Vector3::new(5.0, 2.0, 0.0), "scale_target");
-        }
-        
-        // Create 200 agents in 10x20 grid
-        let mut agents: Vec<SimulatedAgent> = Vec::with_capacity(num_agents);
-        for i in 0..num_agents {
-            let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
-            let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-            agents.push(SimulatedAgent::new(
-                context,
-                network,
-                root_key.clone(),
-                i as u64,
-                AgentConfig::default(),
-            ));
-        }
-        
-        let mut swarm_network = SwarmNetwork::new_grid(10, 20);
-        let dt = 0.1;
-        let target_ticks = (self.max_duration_secs.min(20.0) * 10.0) as u64;
-        
-        let start_time = std::time::Instant::now();
+        let packet_loss_rate = 0.20; // Moderate noise
+        let mut swarm_network = crate::swarm_network::SwarmNetwork::new_grid(5, 10);
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let evo_epoch_ticks = self.epoch_ticks.unwrap_or(20);
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut epoch_rms = EpochRmsCurve::new(evo_epoch_ticks);
+        let mut rng = AuditedRng::derive(seed, "runner/blind-learning/loss");
         
-        info!("  Config: {} agents, {} entities, {}s", num_agents, num_entities, target_ticks as f64 * dt);
+        // Tracking convergence
+        let mut initial_rms = 0.0;
         
         for tick in 0..target_ticks {
             oracle.step(dt);
             let readings = oracle.generate_sensor_readings();
+            let ground_truth = oracle.ground_truth_positions();
             
-            for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                // Each agent sees ~20% of entities
+            // Measure initial performance after a few ticks
+            if tick == 20 {
+                initial_rms = swarm_convergence(&*agents, &ground_truth, every_agent).rms_mean;
+                info!("  Initial RMS: {:.2}m", initial_rms);
+            }
+            
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                 // Evolution Step
+                 // We pass ground_truth ONLY for recording the "true" accuracy for our report/metrics.
+                 // The agent's BlindFitness provider will IGNORE it.
+                agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
+                
                 let agent_readings: Vec<_> = readings.iter()
                     .enumerate()
-                    .filter(|(entity_idx, _)| (entity_idx + agent_idx * 7) % 5 == 0)
+                    .filter(|(entity_idx, _)| (entity_idx + idx) % 5 == 0)
                     .map(|(_, r)| r.clone())
                     .collect();
-                
-                agent.tick();
                 agent.ingest_readings(&agent_readings);
             }
             
-            // Gossip every 10 ticks
-            if tick % 10 == 0 {
-                let all_packets: Vec<_> = agents.iter()
+            // Gossip Logic (with packet drop)
+            if tick % 5 == 0 {
+                // Collect packets
+                let all_packets: Vec<_> = agents.iter_mut()
                     .enumerate()
-                    .flat_map(|(idx, a)| a.recent_packets().iter().map(|p| (idx, p.clone())).collect::<Vec<_>>())
+                    .flat_map(|(idx, a)| {
+                        // Respect evolved interval? 
+                        // Simplified: check simple modulus against agent's interval
+                        if tick.is_multiple_of(a.gossip_interval()) {
+                            let packets: Vec<_> = a.recent_packets().iter().map(|p| (idx, p.clone())).collect();
+                            // Charge bandwidth
+                            a.record_message_sent_metric(packets.iter().map(|(_, p)| wire_bytes(p)).sum());
+                            packets
+                        } else {
+                            Vec::new()
+                        }
+                    })
                     .collect();
-                
+
+                // Distribute
                 for (from_idx, packet) in all_packets {
+                    // 20% Packet loss
+                    if rng.gen::<f64>() < packet_loss_rate { continue; }
                     swarm_network.queue_gossip(from_idx, packet);
                 }
                 
                 for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
+                    // Limit neighbors by evolved parameter
+                    let max_neighbors = agent.max_gossip_neighbors();
+                    let incoming = gossip_packets(swarm_network.take_gossip_from(agent_idx, max_neighbors));
                     agent.receive_gossip(&incoming);
                     agent.clear_recent_packets();
                 }
             }
+            
+            rms.sample(oracle.time(), &*agents, &ground_truth);
+            epoch_rms.observe(tick, &*agents, &ground_truth);
+            
+            if monitor.observe(tick, oracle, &*agents) {
+                break;
+            }
         }
         
-        let elapsed = start_time.elapsed();
-        let ticks_per_sec = target_ticks as f64 / elapsed.as_secs_f64();
-        
+        let episode = LearningEpisode {
+            rms,
+            epoch_rms: epoch_rms.into_rms(),
+            packets_sent: swarm_network.messages_sent(),
+            bytes_sent: swarm_network.bytes_sent(),
+        };
+        (episode, initial_rms)
+    }
+    
+    /// Scores a BlindLearning episode that started at `initial_rms`.
+    fn blind_learning_result(
+        &self,
+        timing: RunTiming,
+        oracle: &Oracle,
+        agents: &[SimulatedAgent],
+        episode: LearningEpisode,
+        initial_rms: f64,
+    ) -> Result<ScenarioResult, ScenarioError> {
+        let LearningEpisode { rms, packets_sent, bytes_sent, .. } = episode;
         let ground_truth = oracle.ground_truth_positions();
-        let avg_rms: f64 = agents.iter().map(|a| a.compute_position_error(&ground_truth)).sum::<f64>() / num_agents as f64;
+        let final_rms = swarm_convergence(agents, &ground_truth, every_agent).rms_mean;
         
-        let passed = avg_rms < 5.0 && ticks_per_sec > 10.0; // Must run at >10 ticks/sec real-time
+        // Did we improve?
+        let improved = final_rms < initial_rms;
+        // Did we survive reasonably well?
+        let failure = rms.failure(10.0);
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  SCALE LIMIT RESULTS:");
-        info!("    RMS error:      {:.2}m  {}", avg_rms, if avg_rms < 5.0 { "✓" } else { "✗" });
-        info!("    Performance:    {:.1} ticks/sec  {}", ticks_per_sec, if ticks_per_sec > 10.0 { "✓" } else { "✗" });
-        info!("    Wall time:      {:.2}s", elapsed.as_secs_f64());
-        info!("    Messages:       {}", swarm_network.messages_sent());
+        info!("  BLIND LEARNING RESULTS:");
+        info!("    Initial RMS:   {:.2}m", initial_rms);
+        info!("    Final RMS:     {:.2}m", final_rms);
+        info!("    Scored RMS:    {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if passed { "✓" } else { "✗" });
+        let improvement_pct = if initial_rms > 0.0 { (initial_rms - final_rms) / initial_rms * 100.0 } else { 0.0 };
+        info!("    Improvement:   {} ({:+.1}%)", if improved { "YES (Optimized!)" } else { "NO" }, improvement_pct);
+        
+        // Check params
+        let agent0_params = &agents.first()
+            .ok_or_else(|| ScenarioError::agent(ScenarioId::BlindLearning, 0, "the scenario built no agents"))?
+            .evolutionary_state().current_params;
+        info!("    Final Params:  interval={}, neighbors={}, conf={:.2}", 
+            agent0_params.gossip_interval_ticks, agent0_params.max_neighbors_gossip, agent0_params.confidence_threshold);
+        let mut metrics = ScenarioMetrics {
+            packets_sent,
+            bytes_sent,
+            ..Default::default()
+        };
+        metrics.record_convergence(agents);
+        metrics.record_evolved_params(agents);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        let mut metrics = ScenarioMetrics::default();
-        metrics.packets_sent = swarm_network.messages_sent();
+        Ok(ScenarioResult {
+            scenario: ScenarioId::BlindLearning,
+            seed: self.seed,
+            passed,
+            total_ticks: timing.target_ticks(),
+            final_time_secs: oracle.time(),
+            final_entity_count: oracle.active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        })
+    }
+
+    /// DST-032: HybridLearning - Ground Truth at Landmarks, Blind in Between.
+    ///
+    /// Evolves the same 16 agents over the same 40 entities, seed and lossy
+    /// grid three times: under OracleFitness with ground truth every epoch,
+    /// under BlindFitness with none, and under HybridFitness, which sees
+    /// ground truth on one epoch in [`HYBRID_LANDMARK_EVERY_EPOCHS`] (passing
+    /// a surveyed landmark) and weighs it by the runner's
+    /// [`GtWeightSchedule`] (`--gt-schedule`).
+    ///
+    /// Every sensor reads 2m off, less the agent's evolved bias estimate: a
+    /// common GPS bias, which peer agreement can't see.
+    ///
+    /// **Success Criteria**: every regime's RMS < 10m, as BlindLearning.
+    /// Epochs to convergence are reported per regime in
+    /// [`ScenarioMetrics::learning_arms`], not judged.
+    fn run_hybrid_learning(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-032: HybridLearning - Ground Truth at Landmarks, Blind in Between 🧭");
+        info!("  Config: 16 agents x 3 fitness regimes, 40 entities, schedule {}", self.gt_schedule);
+        
+        let target_ticks = timing.target_ticks();
+        let monitor = self.monitor(ScenarioId::HybridLearning, target_ticks * LearningRegime::ALL.len() as u64);
+        let mut runs = Vec::new();
+        for (i, regime) in LearningRegime::ALL.into_iter().enumerate() {
+            runs.push(self.learning_arm(timing, regime, &monitor, i as u64 * target_ticks));
+        }
+        
+        let max_rms = 10.0;
+        let failure = first_failure(runs.iter().map(|(arm, ..)| {
+            (arm.rms_mean >= max_rms).then(|| FailureKind::above(&format!("rms_learning_{}", arm.fitness), arm.rms_mean, max_rms))
+        }));
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  HYBRID LEARNING RESULTS:");
+        for (arm, ..) in &runs {
+            info!("    {:<13} RMS {:.2}m (p95 {:.2}m), {}/{} ground-truth epochs, converged {}  {}",
+                arm.fitness, arm.rms_mean, arm.rms_p95, arm.ground_truth_epochs, arm.epochs,
+                arm.epochs_to_convergence.map_or("never".to_string(), |e| format!("after {e} epochs")),
+                if arm.rms_mean < max_rms { "✓" } else { "✗" });
+        }
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        let (hybrid, oracle, agents, rms, packets_sent, bytes_sent) = runs.pop().expect("the hybrid regime runs last");
+        let mut metrics = ScenarioMetrics {
+            packets_sent: runs.iter().map(|r| r.4).sum::<u64>() + packets_sent,
+            bytes_sent: runs.iter().map(|r| r.5).sum::<u64>() + bytes_sent,
+            learning_arms: runs.into_iter().map(|r| r.0).chain([hybrid]).collect(),
+            ..Default::default()
+        };
+        metrics.record_convergence(&agents);
+        metrics.record_evolved_params(&agents);
         
         ScenarioResult {
-            scenario: ScenarioId::ScaleLimit,
+            scenario: ScenarioId::HybridLearning,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
             final_time_secs: oracle.time(),
             final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed { Some(format!("RMS={:.2}m, perf={:.1}tps", avg_rms, ticks_per_sec)) } else { None },
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
             metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-010: NetworkHell - 90% packet loss.
-    fn run_network_hell(&self) -> ScenarioResult {
+    /// Runs one HybridLearning regime; its ticks are reported to `monitor`
+    /// from `first_tick` on. Returns the regime's metrics, the final world,
+    /// its RMS samples and the packets and bytes gossiped.
+    fn learning_arm(
+        &self,
+        timing: RunTiming,
+        regime: LearningRegime,
+        monitor: &RunMonitor<'_>,
+        first_tick: u64,
+    ) -> (LearningArmMetrics, Oracle, Vec<SimulatedAgent>, RmsSampler, u64, u64) {
         use crate::swarm_network::SwarmNetwork;
-        use rand::SeedableRng;
         use rand::Rng;
-        use rand_chacha::ChaCha8Rng;
         
-        info!("DST-010: NetworkHell - 90% PACKET LOSS 🔥");
-        
-        let num_agents = 50;
-        let packet_loss_rate = 0.90;
-        
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
-        let key_provider = DeterministicKeyProvider::new(self.seed);
-        let root_key = key_provider.biscuit_root_key().public();
-        let mut rng = ChaCha8Rng::seed_from_u64(self.seed.wrapping_mul(0xbe11be11));
+        let (rows, cols) = (4, 4);
+        let num_entities = 40;
+        let packet_loss_rate = 0.20;
+        let evo_epoch_ticks = 10;
+        let gps_bias = 2.0;
         
-        let mut oracle = Oracle::new(physics_seed);
-        for i in 0..200 {
+        let mut oracle = Oracle::new(self.seed);
+        for i in 0..num_entities {
             oracle.spawn_entity(
-                Vector3::new((i % 20) as f64 * 50.0, (i / 20) as f64 * 50.0, 100.0),
-                Vector3::new(10.0, 5.0, 0.0),
-                "hell_target",
+                Vector3::new((i % 8) as f64 * 50.0, (i / 8) as f64 * 50.0, 100.0),
+                Vector3::new(5.0, 2.0, 0.0),
+                "hybrid_target",
             );
         }
         
-        let mut agents: Vec<SimulatedAgent> = (0..num_agents)
+        let key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        let mut agents: Vec<SimulatedAgent> = (0..rows * cols)
             .map(|i| {
                 let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
                 let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-                SimulatedAgent::new(context, network, root_key.clone(), i as u64, AgentConfig::default())
+                let mut agent = SimulatedAgent::new(context, network, root_key, i as u64, SimulatedAgent::default_config());
+                agent.set_fitness_provider(regime.provider(self.gt_schedule));
+                agent
             })
             .collect();
+        self.load_trained_params(&mut agents);
         
-        let mut swarm_network = SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = (self.max_duration_secs.min(30.0) * 10.0) as u64;
-        
-        let mut packets_sent = 0u64;
-        let mut packets_dropped = 0u64;
-        
-        info!("  Config: {} agents, {}% packet loss", num_agents, (packet_loss_rate * 100.0) as u32);
+        let mut swarm_network = SwarmNetwork::new_grid(rows, cols);
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        // Every regime draws the same losses
+        let mut rng = AuditedRng::derive(self.seed, "runner/hybrid-learning/loss");
+        let mut epoch_rms = EpochRmsCurve::new(evo_epoch_ticks);
         
         for tick in 0..target_ticks {
             oracle.step(dt);
             let readings = oracle.generate_sensor_readings();
+            let ground_truth = oracle.ground_truth_positions();
             
-            for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                let agent_readings: Vec<_> = readings.iter()
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                agent.tick();
+                let epoch = agent.evolutionary_state().history().len() as u64;
+                let sees_ground_truth = regime.sees_ground_truth(epoch);
+                agent.tick_evolution(evo_epoch_ticks, sees_ground_truth.then_some(&ground_truth[..]));
+                
+                // Every sensor reads gps_bias off, less the agent's evolved estimate
+                let offset = gps_bias - agent.sensor_bias_estimate();
+                let agent_readings: Vec<SensorReading> = readings.iter()
                     .enumerate()
-                    .filter(|(entity_idx, _)| (entity_idx + agent_idx) % 4 == 0)
-                    .map(|(_, r)| r.clone())
+                    .filter(|(entity_idx, _)| (entity_idx + idx) % 4 == 0)
+                    .map(|(_, r)| {
+                        let mut r = r.clone();
+                        r.position.add_scalar_mut(offset);
+                        r
+                    })
                     .collect();
-                
-                agent.tick();
                 agent.ingest_readings(&agent_readings);
             }
             
             if tick % 5 == 0 {
-                let all_packets: Vec<_> = agents.iter()
-                    .enumerate()
-                    .flat_map(|(idx, a)| a.recent_packets().iter().map(|p| (idx, p.clone())).collect::<Vec<_>>())
-                    .collect();
-                
-                for (from_idx, packet) in all_packets {
-                    packets_sent += 1;
-                    if rng.gen::<f64>() < packet_loss_rate {
-                        packets_dropped += 1;
+                for (idx, agent) in agents.iter_mut().enumerate() {
+                    if !tick.is_multiple_of(agent.gossip_interval()) {
                         continue;
                     }
-                    swarm_network.queue_gossip(from_idx, packet);
+                    let packets = agent.recent_packets().to_vec();
+                    agent.record_message_sent_metric(packets.iter().map(wire_bytes).sum());
+                    for packet in packets {
+                        if rng.gen::<f64>() >= packet_loss_rate {
+                            swarm_network.queue_gossip(idx, packet);
+                        }
+                    }
                 }
-                
-                for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
+                for (idx, agent) in agents.iter_mut().enumerate() {
+                    let incoming = gossip_packets(swarm_network.take_gossip_from(idx, agent.max_gossip_neighbors()));
                     agent.receive_gossip(&incoming);
                     agent.clear_recent_packets();
                 }
             }
+            
+            rms.sample(oracle.time(), &agents, &ground_truth);
+            epoch_rms.observe(tick, &agents, &ground_truth);
+            
+            if monitor.observe(first_tick + tick, &oracle, &agents) {
+                break;
+            }
         }
         
-        let ground_truth = oracle.ground_truth_positions();
-        let avg_rms: f64 = agents.iter().map(|a| a.compute_position_error(&ground_truth)).sum::<f64>() / num_agents as f64;
-        
-        let actual_loss = packets_dropped as f64 / packets_sent.max(1) as f64;
-        
-        // With 90% loss, we're just testing survival and some coherence
-        let passed = avg_rms < 50.0; // Very relaxed - just don't go crazy
-        
-        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  NETWORK HELL RESULTS:");
-        info!("    RMS error:     {:.2}m  {}", avg_rms, if passed { "✓ (survived!)" } else { "✗" });
-        info!("    Packet loss:   {:.0}% ({} / {})", actual_loss * 100.0, packets_dropped, packets_sent);
-        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        
-        let mut metrics = ScenarioMetrics::default();
-        metrics.packets_sent = packets_sent;
-        metrics.packets_dropped = packets_dropped;
-        
-        ScenarioResult {
-            scenario: ScenarioId::NetworkHell,
-            seed: self.seed,
-            passed,
-            total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed { Some(format!("RMS {:.2}m too high", avg_rms)) } else { None },
-            metrics,
-        }
+        let epochs = agents.first().map_or(0, |a| a.evolutionary_state().history().len());
+        let metrics = LearningArmMetrics {
+            fitness: regime.name().to_string(),
+            epochs,
+            ground_truth_epochs: (0..epochs as u64).filter(|&e| regime.sees_ground_truth(e)).count(),
+            epochs_to_convergence: epochs_to_settle(&epoch_rms.into_rms(), LEARNING_SETTLED_BAND),
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+        };
+        let (packets_sent, bytes_sent) = (swarm_network.messages_sent(), swarm_network.bytes_sent());
+        (metrics, oracle, agents, rms, packets_sent, bytes_sent)
     }
     
-    /// DST-011: TimeTornado - 5-second OOSM delays.
-    fn run_time_tornado(&self) -> ScenarioResult {
-        use rand::SeedableRng;
-        use rand::Rng;
-        use rand_chacha::ChaCha8Rng;
-        
-        info!("DST-011: TimeTornado - 5-SECOND OOSM DELAYS 🔥");
-        
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
-        let key_provider = DeterministicKeyProvider::new(self.seed);
-        let root_key = key_provider.biscuit_root_key().public();
-        let mut rng = ChaCha8Rng::seed_from_u64(self.seed.wrapping_mul(0xabed0abed));
+    /// DST-018: BlackoutSurvival - Total System Failure.
+    ///
+    /// The ultimate test: 50% Packet Loss + Sensor Faults + Bad Actors + Bandwidth Limit.
+    /// Agents must usage BlindFitness to filter noise, reject bad actors, and survive.
+    fn run_blackout_survival(&self, timing: RunTiming) -> ScenarioResult {
+        use crate::evolution::BlindFitness;
+
+        info!("DST-018: BlackoutSurvival - TOTAL SYSTEM FAILURE 💀");
         
-        let context = Arc::new(SimContext::new(self.seed));
-        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
-        let mut agent = SimulatedAgent::new(context, network, root_key, 0, AgentConfig::default());
+        // 1. Extreme Environment
+        let num_agents = 50;
+        let num_bad_actors = 10; // 20% Traitors
+        let packet_loss_rate = 0.50; // High loss
+        let sensor_fault_rate = 0.10; // 10% Blackouts
+        // Per-link bandwidth limit, randomly shedding load as queues fill
+        let link_config = LinkConfig {
+            capacity_per_round: 8,
+            max_queue_depth: 16,
+            drop_policy: DropPolicy::RandomEarly { min_depth: 8, max_drop_prob: 0.5 },
+            max_bytes_per_round: None,
+        };
         
-        let mut oracle = Oracle::new(physics_seed);
-        oracle.spawn_entity(Vector3::new(0.0, 0.0, 100.0), Vector3::new(20.0, 10.0, 0.0), "tornado_target");
+        // Agents: Blind Fitness + Bad Actors; 5% of the ticks that aren't
+        // blacked out carry severe (±50m) noise
+        let mut harness = SwarmHarness::new(self.seed, 5, 10, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_threads(self.threads)
+            .with_oracle_seed(self.seed)
+            .with_visibility(Visibility::Striped { period: 5, visible: 1 })
+            .with_packet_loss(packet_loss_rate)
+            .with_link_config(link_config)
+            .with_sensor_faults(SensorFaults::Blackout {
+                blackout_probability: sensor_fault_rate,
+                noise_probability: 0.05,
+                noise_m: 50.0,
+            })
+            .with_bad_actors(BadActorSchedule::Fixed((0..num_bad_actors).collect()), BadActorStrategy::Adaptive)
+            .with_evolution(20, || Box::new(BlindFitness::new()));
+        if let Some(trained) = &self.trained_params {
+            harness = harness.with_trained_params(trained.clone());
+        }
+        for i in 0..50 {
+            harness.oracle_mut().spawn_entity(
+                Vector3::new((i % 10) as f64 * 50.0, (i / 10) as f64 * 50.0, 100.0),
+                Vector3::new(5.0, 2.0, 0.0),
+                "blackout_target",
+            );
+        }
         
-        let dt = 0.1;
-        let max_delay_secs = 5.0;
-        let target_ticks = (self.max_duration_secs.min(60.0) * 10.0) as u64;
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
         
-        // Buffer for delayed readings
-        let mut delayed_queue: Vec<(u64, crate::oracle::SensorReading)> = Vec::new();
-        let mut oosm_count = 0u64;
+        info!("  Config: {} agents ({} bad), 50% loss, 10% sensor faults, BW limit", num_agents, num_bad_actors);
         
-        info!("  Config: max delay {}s, duration {}s", max_delay_secs, target_ticks as f64 * dt);
+        let monitor = self.monitor(ScenarioId::BlackoutSurvival, target_ticks);
         
         for tick in 0..target_ticks {
-            oracle.step(dt);
-            let readings = oracle.generate_sensor_readings();
+            harness.step();
             
-            // Add current readings to queue with random delay
-            for reading in readings {
-                let delay_ticks = (rng.gen::<f64>() * max_delay_secs / dt) as u64;
-                let delivery_tick = tick + delay_ticks;
-                delayed_queue.push((delivery_tick, reading));
-            }
-            
-            // Deliver readings whose time has come (simulating OOSM)
-            delayed_queue.sort_by_key(|(t, _)| std::cmp::Reverse(*t));
-            while let Some((delivery_tick, _)) = delayed_queue.last() {
-                if *delivery_tick <= tick {
-                    let (_, reading) = delayed_queue.pop().unwrap();
-                    agent.tick();
-                    agent.ingest_readings(&[reading]);
-                    oosm_count += 1;
-                } else {
-                    break;
-                }
+            // Filter out bad actors for scoring
+            rms.sample(harness.time(), harness.good_agents(), &harness.oracle().ground_truth_positions());
+            
+            if monitor.observe(tick, harness.oracle(), harness.good_agents()) {
+                break;
             }
         }
         
-        // Drain remaining queue
-        for (_, reading) in delayed_queue.drain(..) {
-            agent.tick();
-            agent.ingest_readings(&[reading]);
-            oosm_count += 1;
-        }
-        
-        let ground_truth = oracle.ground_truth_positions();
-        let rms_error = agent.compute_position_error(&ground_truth);
-        
-        // With 5s delays on a moving target, some error is expected
-        let passed = rms_error < 200.0 && oosm_count > 0;
+        // Did we survive?
+        let failure = rms.failure(15.0); // Relaxed threshold due to 50% loss + faults
+        let passed = failure.is_none();
+        
+        let network = harness.network();
+        let link_stats = network.total_link_stats();
+        let mut metrics = ScenarioMetrics {
+            packets_sent: network.messages_sent(),
+            packets_dropped: link_stats.dropped_overflow,
+            bytes_sent: network.bytes_sent(),
+            bytes_dropped: link_stats.bytes_dropped,
+            ..Default::default()
+        };
+        metrics.record_link_stats(link_stats);
+        metrics.record_evolved_params(harness.agents());
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  TIME TORNADO RESULTS:");
-        info!("    RMS error:      {:.2}m  {}", rms_error, if passed { "✓" } else { "✗" });
-        info!("    OOSM updates:   {}", oosm_count);
+        info!("  BLACKOUT RESULTS:");
+        info!("    Survivor RMS:  {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if passed { "✓" } else { "✗" });
+        info!("    Link Overflow: {} of {} packets", link_stats.dropped_overflow, metrics.packets_sent);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        let mut metrics = ScenarioMetrics::default();
-        metrics.oosm_updates = oosm_count;
-        
         ScenarioResult {
-            scenario: ScenarioId::TimeTornado,
+            scenario: ScenarioId::BlackoutSurvival,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: 1,
-            failure_reason: if !passed { Some(format!("RMS {:.2}m", rms_error)) } else { None },
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
             metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
-    
-    /// DST-012: ZombieApocalypse - 50% of agents are bad actors.
-    fn run_zombie_apocalypse(&self) -> ScenarioResult {
-        use crate::swarm_network::SwarmNetwork;
-        use rand::SeedableRng;
-        use rand::Rng;
-        use rand_chacha::ChaCha8Rng;
-        
-        info!("DST-012: ZombieApocalypse - 50% BAD ACTORS 🔥");
-        
-        let num_agents = 50;
-        let num_bad_actors = 25; // Half!
+
+    /// DST-019: LongHaul - Energy Crisis.
+    ///
+    /// Survive 2000 ticks with limited battery.
+    /// Agents must evolve to speak less (higher gossip interval) to survive.
+    fn run_long_haul(&self, timing: RunTiming) -> ScenarioResult {
+        use crate::evolution::BlindFitness;
+
+        info!("DST-019: LongHaul - THE ENERGY CRISIS 🔋");
         
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
-        let key_provider = DeterministicKeyProvider::new(self.seed);
-        let root_key = key_provider.biscuit_root_key().public();
-        let mut rng = ChaCha8Rng::seed_from_u64(self.seed.wrapping_mul(0xdead0dead));
+        // Config
+        let num_agents = 10;
+        let start_energy = 1000.0;
+        let mut oracle = Oracle::new(self.seed);
         
-        let mut oracle = Oracle::new(physics_seed);
-        for i in 0..200 {
+        // Spawn some entities to track
+        for i in 0..10 {
             oracle.spawn_entity(
-                Vector3::new((i % 20) as f64 * 50.0, (i / 20) as f64 * 50.0, 100.0),
-                Vector3::new(10.0, 5.0, 0.0),
-                "survivor_target",
+                Vector3::new((i as f64) * 20.0, 0.0, 100.0),
+                Vector3::new(1.0, 1.0, 0.0),
+                "long_haul_target",
             );
         }
         
+        let key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        
+        // Init Agents
         let mut agents: Vec<SimulatedAgent> = (0..num_agents)
             .map(|i| {
-                let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
-                let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-                SimulatedAgent::new(context, network, root_key.clone(), i as u64, AgentConfig::default())
+                 let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
+                 let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
+                 let mut agent = SimulatedAgent::new(context, network, root_key, i as u64, SimulatedAgent::default_config());
+                 agent.set_fitness_provider(Box::new(BlindFitness::new()));
+                 agent.consume_energy(850.0); // Start with 150J for fast test
+                 agent
             })
             .collect();
+        self.load_trained_params(&mut agents);
+            
+        let mut swarm_network = crate::swarm_network::SwarmNetwork::new_grid(5, 10);
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let evo_epoch_ticks = 20; // Faster evolution for test
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
         
-        // First half are zombies (bad actors)
-        let bad_actor_ids: Vec<usize> = (0..num_bad_actors).collect();
-        
-        let mut swarm_network = SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = (self.max_duration_secs.min(30.0) * 10.0) as u64;
+        info!("  Config: {} agents, 10 entities, {} ticks. Starting Energy: {}J", num_agents, target_ticks, start_energy);
         
-        info!("  Config: {} agents, {} zombies ({}%)", num_agents, num_bad_actors, num_bad_actors * 100 / num_agents);
+        let monitor = self.monitor(ScenarioId::LongHaul, target_ticks);
         
         for tick in 0..target_ticks {
             oracle.step(dt);
+            // Limit visible readings to reduce sensor cost pressure (allow some chance)
+            // Or just let them deal with it.
+            // Cost: 0.05 * 10 = 0.5 per tick. 2000 * 0.5 = 1000 J.
+            // They will all die if they process all.
+            // But they can't choose to ignore yet.
+            // I will artificially limit readings in this scenario to 2 entities per agent per tick (randomly).
+            // This represents "Scanning a sector" instead of 360 view.
+            
             let readings = oracle.generate_sensor_readings();
+            let ground_truth = oracle.ground_truth_positions();
             
-            for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                let agent_readings: Vec<_> = readings.iter()
-                    .enumerate()
-                    .filter(|(entity_idx, _)| (entity_idx + agent_idx) % 4 < 2)
-                    .map(|(_, r)| r.clone())
-                    .collect();
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                // Check if alive handled in tick() internal (returns false if dead)
+                if !agent.tick() {
+                    continue; // Dead
+                }
                 
-                agent.tick();
-                agent.ingest_readings(&agent_readings);
+                // Evolution
+                agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
+                
+                // Limited Ingestion (2 readings max)
+                let my_readings: Vec<_> = readings.iter()
+                    .skip(idx % 5) // Simple stagger
+                    .take(2)
+                    .cloned()
+                    .collect();
+                    
+                agent.ingest_readings(&my_readings);
             }
             
+             // Gossip Logic
             if tick % 5 == 0 {
-                let all_packets: Vec<_> = agents.iter()
+                // Collect packets
+                let all_packets: Vec<_> = agents.iter_mut()
                     .enumerate()
                     .flat_map(|(idx, a)| {
-                        let mut packets: Vec<_> = a.recent_packets().iter()
-                            .map(|p| (idx, p.clone()))
-                            .collect();
+                        // Check dead status before asking logic
+                        if !a.is_alive() { return Vec::new(); }
                         
-                        // Zombies inject lots of garbage
-                        if bad_actor_ids.contains(&idx) {
-                            for _ in 0..10 { // 10 garbage packets each!
-                                let garbage = godview_core::godview_tracking::GlobalHazardPacket {
-                                    entity_id: Uuid::new_v4(),
-                                    position: [rng.gen_range(-1000.0..1000.0), rng.gen_range(-1000.0..1000.0), rng.gen_range(0.0..500.0)],
-                                    velocity: [rng.gen_range(-100.0..100.0), rng.gen_range(-100.0..100.0), 0.0],
-                                    class_id: 99,
-                                    timestamp: tick as f64 * dt,
-                                    confidence_score: rng.gen_range(0.0..0.5),
-                                };
-                                packets.push((idx, garbage));
-                            }
+                        // Use new should_broadcast() with Emergency Protocol
+                        if a.should_broadcast(tick) {
+                            let packets: Vec<_> = a.recent_packets().iter().map(|p| (idx, p.clone())).collect();
+                            
+                            // Charge Message Cost!
+                            let cost = packets.len() as f64 * 1.0; 
+                            a.consume_energy(cost);
+                            
+                            // Metrics
+                            a.record_message_sent_metric(packets.iter().map(|(_, p)| wire_bytes(p)).sum());
+                            packets
+                        } else {
+                            Vec::new()
                         }
-                        packets
                     })
                     .collect();
                 
+                // Distribute
                 for (from_idx, packet) in all_packets {
                     swarm_network.queue_gossip(from_idx, packet);
                 }
                 
                 for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
-                    let neighbors = swarm_network.neighbors(agent_idx);
-                    if !neighbors.is_empty() && !incoming.is_empty() {
-                        let per = incoming.len() / neighbors.len().max(1);
-                        for (i, neighbor_id) in neighbors.iter().enumerate() {
-                            let start = i * per;
-                            let end = ((i + 1) * per).min(incoming.len());
-                            if start < end {
-                                agent.receive_gossip_from(*neighbor_id, &incoming[start..end]);
-                            }
-                        }
-                    }
-                    agent.clear_recent_packets();
+                     let incoming = swarm_network.take_gossip_packets(agent_idx);
+                     // Receive & Process
+                     agent.receive_gossip(&incoming);
+                     agent.clear_recent_packets();
                 }
             }
-        }
-        
-        // Only measure GOOD agents (survivors)
-        let ground_truth = oracle.ground_truth_positions();
-        let good_agent_rms: Vec<f64> = agents.iter().enumerate()
-            .filter(|(idx, _)| !bad_actor_ids.contains(idx))
-            .map(|(_, a)| a.compute_position_error(&ground_truth))
-            .collect();
-        let avg_rms = good_agent_rms.iter().sum::<f64>() / good_agent_rms.len().max(1) as f64;
-        
-        // Count zombies identified by survivors
-        let mut zombies_identified = 0;
-        let mut possible_detections = 0;
-        for (agent_idx, agent) in agents.iter().enumerate() {
-            if bad_actor_ids.contains(&agent_idx) { continue; }
-            let neighbors = swarm_network.neighbors(agent_idx);
-            for &zombie_id in &bad_actor_ids {
-                if neighbors.contains(&zombie_id) {
-                    possible_detections += 1;
-                    if let Some(rep) = agent.adaptive_state().neighbor_reputations.get(&zombie_id) {
-                        if rep.reliability_score < 0.3 {
-                            zombies_identified += 1;
-                        }
-                    }
-                }
+            
+            // Only survivors are scored; ticks with no survivors add no sample
+            rms.sample(oracle.time(), agents.iter().filter(|a| a.is_alive()), &ground_truth);
+            
+            if monitor.observe(tick, &oracle, agents.iter().filter(|a| a.is_alive())) {
+                break;
             }
         }
         
-        let detection_rate = if possible_detections > 0 { zombies_identified as f64 / possible_detections as f64 } else { 0.0 };
-        let passed = avg_rms < 10.0 && detection_rate > 0.2;
+        // Analysis
+        let final_survivors = agents.iter().filter(|a| a.is_alive()).count();
+        let survival_rate = final_survivors as f64 / num_agents as f64;
+        
+        // Success Criteria: > 80% Survivors AND < 5.0m RMS
+        let failure = long_haul_failure(survival_rate, rms.is_empty(), rms.failure(5.0));
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  ZOMBIE APOCALYPSE RESULTS:");
-        info!("    Survivor RMS:    {:.2}m  {}", avg_rms, if avg_rms < 10.0 { "✓" } else { "✗" });
-        info!("    Zombie detection: {:.0}%  {}", detection_rate * 100.0, if detection_rate > 0.2 { "✓" } else { "✗" });
-        info!("    Zombies spotted: {} / {}", zombies_identified, possible_detections);
+        info!("  LONG HAUL RESULTS:");
+        info!("    Survivors:    {}/{} ({:.1}%)", final_survivors, num_agents, survival_rate * 100.0);
+        info!("    Survivor RMS: {:.2}m (p95 {:.2}m)", rms.mean(), rms.p95());
+        
+        // Print average evolved parameters of survivors
+        if final_survivors > 0 {
+            let avg_interval: f64 = agents.iter()
+                .filter(|a| a.is_alive())
+                .map(|a| a.gossip_interval() as f64)
+                .sum::<f64>() / final_survivors as f64;
+            info!("    Avg Interval: {:.1} ticks (Started at 5.0)", avg_interval);
+        }
+        
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        let mut metrics = ScenarioMetrics::default();
-        metrics.packets_sent = swarm_network.messages_sent();
+        let mut metrics = ScenarioMetrics {
+            packets_sent: swarm_network.messages_sent(),
+            bytes_sent: swarm_network.bytes_sent(),
+            ..Default::default()
+        };
+        metrics.record_evolved_params(&agents);
+        metrics.set_extra("survivors", final_survivors as f64);
+        metrics.set_extra("survival_rate", survival_rate);
         
         ScenarioResult {
-            scenario: ScenarioId::ZombieApocalypse,
+            scenario: ScenarioId::LongHaul,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
             final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed { Some(format!("RMS={:.2}m, detection={:.0}%", avg_rms, detection_rate * 100.0)) } else { None },
+            final_entity_count: 5,
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
             metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-013: RapidFire - 100Hz tick rate.
-    fn run_rapid_fire(&self) -> ScenarioResult {
-        info!("DST-013: RapidFire - 100Hz TICK RATE 🔥");
-        
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
-        let key_provider = DeterministicKeyProvider::new(self.seed);
-        let root_key = key_provider.biscuit_root_key().public();
-        
-        let context = Arc::new(SimContext::new(self.seed));
-        let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(0)));
-        let mut agent = SimulatedAgent::new(context, network, root_key, 0, AgentConfig::default());
-        
-        let mut oracle = Oracle::new(physics_seed);
-        for i in 0..10 {
-            oracle.spawn_entity(
-                Vector3::new(i as f64 * 100.0, 0.0, 100.0),
-                Vector3::new(50.0, 25.0 * ((i % 2) as f64 * 2.0 - 1.0), 0.0),
-                "rapid_target",
-            );
-        }
-        
-        let tick_rate = 100.0; // 100 Hz
-        let dt = 1.0 / tick_rate;
-        let sim_duration = self.max_duration_secs.min(10.0); // Max 10s for speed
-        let target_ticks = (sim_duration * tick_rate) as u64;
-        
-        let start_time = std::time::Instant::now();
-        
-        info!("  Config: {}Hz tick rate, {} ticks, {}s sim time", tick_rate, target_ticks, sim_duration);
-        
-        for _tick in 0..target_ticks {
-            oracle.step(dt);
-            let readings = oracle.generate_sensor_readings();
-            agent.tick();
-            agent.ingest_readings(&readings);
-        }
-        
-        let elapsed = start_time.elapsed();
-        let actual_rate = target_ticks as f64 / elapsed.as_secs_f64();
-        
-        let ground_truth = oracle.ground_truth_positions();
-        let rms_error = agent.compute_position_error(&ground_truth);
-        
-        // Must run at least 50% of target rate and maintain accuracy
-        let passed = rms_error < 3.0 && actual_rate > tick_rate * 0.5;
+    /// DST-020: CommonBias - GPS bias detection via evolution (v0.6.0)
+    /// 
+    /// All agents receive a +5m GPS offset on sensor readings.
+    /// Tests if agents can evolve `sensor_bias_estimate` to compensate.
+    /// 
+    /// **Success Criteria**: Swarm RMS < 5.0m after evolution.
+    fn run_common_bias(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-020: CommonBias - GPS Bias Detection 🎯");
         
-        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  RAPID FIRE RESULTS:");
-        info!("    RMS error:    {:.2}m  {}", rms_error, if rms_error < 3.0 { "✓" } else { "✗" });
-        info!("    Target rate:  {}Hz", tick_rate);
-        info!("    Actual rate:  {:.0}Hz  {}", actual_rate, if actual_rate > tick_rate * 0.5 { "✓" } else { "✗" });
-        info!("    Wall time:    {:.3}s", elapsed.as_secs_f64());
-        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        let mut oracle = Oracle::new(self.seed);
+        spawn_common_bias_targets(&mut oracle);
+        let mut agents = self.common_bias_agents();
+        info!("  Config: {} agents, 5 entities, {} ticks. GPS Bias: +{}m", agents.len(), timing.target_ticks(), COMMON_BIAS_GPS_BIAS);
         
-        ScenarioResult {
-            scenario: ScenarioId::RapidFire,
-            seed: self.seed,
-            passed,
-            total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed { Some(format!("RMS={:.2}m, rate={:.0}Hz", rms_error, actual_rate)) } else { None },
-            metrics: ScenarioMetrics::default(),
-        }
+        let monitor = self.monitor(ScenarioId::CommonBias, timing.target_ticks());
+        let episode = self.common_bias_episode(timing, &mut oracle, &mut agents, &monitor);
+        self.common_bias_result(timing, &oracle, &agents, episode)
     }
     
-    // ═══════════════════════════════════════════════════════════════════════════
-    // EVOLUTIONARY INTELLIGENCE - Adapting to the Unknown
-    // ═══════════════════════════════════════════════════════════════════════════
-    
-    /// DST-014: EvoWar - Evolution vs Chaos.
-    ///
-    /// Red Team (static bad actors) vs Blue Team (evolutionary).
-    /// Can Blue evolve to survive high noise + bad actors?
-    fn run_evo_war(&self) -> ScenarioResult {
-        use crate::swarm_network::SwarmNetwork;
-        use rand::SeedableRng;
-        use rand::Rng;
-        use rand_chacha::ChaCha8Rng;
-        
-        info!("DST-014: EvoWar - EVOLUTION VS CHAOS 🧬");
-        
-        let num_agents = 50;
-        let num_red_team = 25; // Half are static/bad
-        let packet_loss_rate = 0.30;
+    /// CommonBias's 10 agents, initialized with BlindFitness.
+    fn common_bias_agents(&self) -> Vec<SimulatedAgent> {
+        use crate::evolution::BlindFitness;
         
-        let physics_seed = self.seed.wrapping_mul(0x9e3779b97f4a7c15);
         let key_provider = DeterministicKeyProvider::new(self.seed);
         let root_key = key_provider.biscuit_root_key().public();
-        let mut rng = ChaCha8Rng::seed_from_u64(self.seed.wrapping_mul(0xeb014));
-        
-        // Oracle setup
-        let mut oracle = Oracle::new(physics_seed);
-        for i in 0..100 {
-            oracle.spawn_entity(
-                Vector3::new((i % 10) as f64 * 50.0, (i / 10) as f64 * 50.0, 100.0),
-                Vector3::new(5.0, 2.0, 0.0),
-                "evo_target",
-            );
-        }
-        
-        // Agents
-        let mut agents: Vec<SimulatedAgent> = Vec::with_capacity(num_agents);
-        for i in 0..num_agents {
-            let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
-            let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-            agents.push(SimulatedAgent::new(
-                context,
-                network,
-                root_key.clone(),
-                i as u64,
-                AgentConfig::default(),
-            ));
-        }
-        
-        // Red team indices (static bad actors)
-        let red_team_ids: Vec<usize> = (0..num_red_team).collect();
-        // Blue team indices (evolving)
-        let blue_team_ids: Vec<usize> = (num_red_team..num_agents).collect();
-        
-        // Configure Red Team as bad actors
-        for &id in &red_team_ids {
-            // Re-create as bad actor
-            let context = Arc::new(SimContext::new(self.seed.wrapping_add(id as u64)));
-            let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(id as u64)));
-            agents[id] = SimulatedAgent::new_bad_actor(context, network, root_key.clone(), id as u64, AgentConfig::default());
-        }
-        
-        let mut swarm_network = SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = (self.max_duration_secs.min(30.0) * 10.0) as u64;
-        let evo_epoch_ticks = 20; // Evolve every 2s
-        
-        info!("  Config: {} Blue (learning), {} Red (static/bad), 30% loss", blue_team_ids.len(), red_team_ids.len());
-        
-        let _ground_truth_buffer: Vec<_> = oracle.ground_truth_positions(); // Initial
+        let mut agents: Vec<SimulatedAgent> = (0..10)
+            .map(|i| {
+                 let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
+                 let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
+                 let mut agent = SimulatedAgent::new(context, network, root_key, i as u64, SimulatedAgent::default_config());
+                 agent.set_fitness_provider(Box::new(BlindFitness::new()));
+                 agent
+            })
+            .collect();
+        self.load_trained_params(&mut agents);
+        agents
+    }
+    
+    /// Plays one CommonBias episode against `oracle`'s targets.
+    fn common_bias_episode(
+        &self,
+        timing: RunTiming,
+        oracle: &mut Oracle,
+        agents: &mut [SimulatedAgent],
+        monitor: &RunMonitor<'_>,
+    ) -> LearningEpisode {
+        let gps_bias = COMMON_BIAS_GPS_BIAS;
+        let mut swarm_network = crate::swarm_network::SwarmNetwork::new_grid(5, 10);
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let evo_epoch_ticks = self.epoch_ticks.unwrap_or(50);
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut epoch_rms = EpochRmsCurve::new(evo_epoch_ticks);
         
         for tick in 0..target_ticks {
             oracle.step(dt);
-            let readings = oracle.generate_sensor_readings();
+            
+            // Get base readings from Oracle
+            let base_readings = oracle.generate_sensor_readings();
             let ground_truth = oracle.ground_truth_positions();
             
-            for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                // Blue team evolves
-                if blue_team_ids.contains(&agent_idx) {
-                    agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
-                } else {
-                    agent.tick(); // Red team just ticks
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                if !agent.tick() { continue; } // Dead
+                
+                // Get a subset of readings for this agent
+                let my_readings: Vec<_> = base_readings.iter()
+                    .skip(idx % 5)
+                    .take(2)
+                    .cloned()
+                    .collect();
+                
+                // Apply GPS bias to readings
+                let mut biased_readings: Vec<_> = my_readings.into_iter()
+                    .map(|mut r| {
+                        r.position.x += gps_bias;
+                        r.position.y += gps_bias;
+                        r.position.z += gps_bias;
+                        r
+                    })
+                    .collect();
+                
+                // Apply agent's evolved bias compensation
+                let bias_compensation = agent.sensor_bias_estimate();
+                for reading in &mut biased_readings {
+                    reading.position.x -= bias_compensation;
+                    reading.position.y -= bias_compensation;
+                    reading.position.z -= bias_compensation;
                 }
                 
-                // Sensor input
-                let agent_readings: Vec<_> = readings.iter()
-                    .enumerate()
-                    .filter(|(entity_idx, _)| (entity_idx + agent_idx) % 4 == 0)
-                    .map(|(_, r)| r.clone())
-                    .collect();
-                agent.ingest_readings(&agent_readings);
+                agent.ingest_readings(&biased_readings);
+                
+                // Evolution tick
+                agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
             }
             
             // Gossip
             if tick % 5 == 0 {
-                // Collect packets
-                let all_packets: Vec<_> = agents.iter()
+                let all_packets: Vec<_> = agents.iter_mut()
                     .enumerate()
                     .flat_map(|(idx, a)| {
-                        let mut packets: Vec<_> = a.recent_packets().iter()
-                            .map(|p| (idx, p.clone()))
-                            .collect();
-                        
-                        // Red team injects garbage
-                        if red_team_ids.contains(&idx) {
-                            for _ in 0..5 {
-                                let garbage = godview_core::godview_tracking::GlobalHazardPacket {
-                                    entity_id: Uuid::new_v4(), // Confusing ID
-                                    position: [rng.gen_range(-500.0..500.0), rng.gen_range(-500.0..500.0), 0.0],
-                                    velocity: [0.0, 0.0, 0.0],
-                                    class_id: 99,
-                                    timestamp: tick as f64 * dt,
-                                    confidence_score: 0.9, // High confidence lie
-                                };
-                                packets.push((idx, garbage));
-                            }
+                        if !a.is_alive() { return Vec::new(); }
+                        if a.should_broadcast(tick) {
+                            a.recent_packets().iter().map(|p| (idx, p.clone())).collect()
+                        } else {
+                            Vec::new()
                         }
-                        packets
                     })
                     .collect();
-
-                // Distribute packets (Blue respects evo params)
+                
                 for (from_idx, packet) in all_packets {
-                    // Packet loss
-                    if rng.gen::<f64>() < packet_loss_rate { continue; }
-                    
                     swarm_network.queue_gossip(from_idx, packet);
-                    
-                    // Record measurement for BLUE team sender
-                    if blue_team_ids.contains(&from_idx) {
-                        // Estimate wire size (struct ~100 bytes + overhead ~25)
-                        let size = 125;
-                        agents[from_idx].record_message_sent_metric(size);
-                    }
                 }
                 
                 for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
-                    
-                    // Apply EVO constraints for Blue
-                    let max_neighbors = if blue_team_ids.contains(&agent_idx) {
-                        agent.max_gossip_neighbors()
-                    } else {
-                        100 // Red talks to everyone
-                    };
-                    
-                    // Filter incoming by max neighbors (simulate bandwidth constraint)
-                    let limited_incoming = if incoming.len() > max_neighbors {
-                        &incoming[0..max_neighbors]
-                    } else {
-                        &incoming[..]
-                    };
-                    
-                    agent.receive_gossip(limited_incoming);
-                    agent.clear_recent_packets();
+                     let incoming = swarm_network.take_gossip_packets(agent_idx);
+                     agent.receive_gossip(&incoming);
+                     agent.clear_recent_packets();
                 }
             }
+            
+            rms.sample(oracle.time(), &*agents, &ground_truth);
+            epoch_rms.observe(tick, &*agents, &ground_truth);
+            
+            if monitor.observe(tick, oracle, &*agents) {
+                break;
+            }
         }
         
-        // Metrics
-        let ground_truth = oracle.ground_truth_positions();
-        
-        // Blue Team Score
-        let blue_rms: Vec<f64> = agents.iter().enumerate()
-            .filter(|(idx, _)| blue_team_ids.contains(idx))
-            .map(|(_, a)| a.compute_position_error(&ground_truth))
-            .collect();
-        let avg_blue_rms = blue_rms.iter().sum::<f64>() / blue_rms.len().max(1) as f64;
+        LearningEpisode {
+            rms,
+            epoch_rms: epoch_rms.into_rms(),
+            packets_sent: swarm_network.messages_sent(),
+            bytes_sent: swarm_network.bytes_sent(),
+        }
+    }
+    
+    /// Scores a CommonBias episode.
+    fn common_bias_result(&self, timing: RunTiming, oracle: &Oracle, agents: &[SimulatedAgent], episode: LearningEpisode) -> ScenarioResult {
+        let LearningEpisode { rms, packets_sent, bytes_sent, .. } = episode;
         
-        // Did params diverge from default?
-        let blue_params = &agents[blue_team_ids[0]].evolutionary_state().current_params;
-        let param_drift = (blue_params.confidence_threshold - 0.0).abs() > 0.01 || 
-                          blue_params.max_neighbors_gossip != 100 ||
-                          blue_params.gossip_interval_ticks != 5;
+        // Check evolved bias estimates
+        let avg_bias_estimate: f64 = agents.iter()
+            .map(|a| a.sensor_bias_estimate())
+            .sum::<f64>() / agents.len() as f64;
         
-        let passed = avg_blue_rms < 10.0;
+        let failure = rms.failure(5.0);
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  EVO WAR RESULTS:");
-        info!("    Blue RMS:      {:.2}m  {}", avg_blue_rms, if passed { "✓" } else { "✗" });
-        info!("    Param Drift:   {} (Agents adapted!)", if param_drift { "YES" } else { "NO" });
-        info!("    Final Params:  interval={}, neighbors={}, conf={:.2}", 
-            blue_params.gossip_interval_ticks, blue_params.max_neighbors_gossip, blue_params.confidence_threshold);
+        info!("  COMMON BIAS RESULTS:");
+        info!("    RMS: {:.2}m, p95 {:.2}m (target < 5.0m)", rms.mean(), rms.p95());
+        info!("    Avg Bias Estimate: {:.2}m (true bias: {}m)", avg_bias_estimate, COMMON_BIAS_GPS_BIAS);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
+        let mut metrics = ScenarioMetrics {
+            packets_sent,
+            bytes_sent,
+            ..Default::default()
+        };
+        metrics.record_evolved_params(agents);
+        
         ScenarioResult {
-            scenario: ScenarioId::EvoWar,
+            scenario: ScenarioId::CommonBias,
             seed: self.seed,
             passed,
-            total_ticks: target_ticks,
+            total_ticks: timing.target_ticks(),
             final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed { Some(format!("Blue RMS {:.2}m", avg_blue_rms)) } else { None },
-            metrics: ScenarioMetrics::default(),
+            final_entity_count: 5,
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-015: ResourceStarvation.
-    fn run_resource_starvation(&self) -> ScenarioResult {
-        use crate::swarm_network::SwarmNetwork;
-        use rand::SeedableRng;
-        
-        
-        info!("DST-015: ResourceStarvation - BANDWIDTH LIMIT 🧬");
+    /// DST-025: ClockSkew - skewed agent clocks and timestamp reconciliation
+    /// 
+    /// Four agents share one virtual clock, but agent 0's local clock runs
+    /// ahead by a fixed offset plus a small drift. Every agent tracks the
+    /// same moving targets and gossips its latest packets with 300ms of
+    /// latency; receivers propagate them to their own clock. Sweeping agent 0's offset
+    /// measures how much each 100ms of skew costs; a final run at the largest
+    /// skew lets receivers estimate each neighbor's offset from residuals
+    /// against their own tracks.
+    /// 
+    /// **Success Criteria**: RMS grows with skew, every offset estimate ends
+    /// within 50ms of the truth, and the compensated RMS is within 25% of
+    /// the unskewed run.
+    fn run_clock_skew(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-025: ClockSkew - Clock Offset Estimation ⏱️");
+        
+        let skews_ms = [0.0, 100.0, 200.0, 300.0];
+        let max_offset_error_ms = 50.0;
+        let max_compensated_ratio = 1.25;
+        
+        let propagated: Vec<SkewSample> = skews_ms.iter()
+            .map(|&skew_ms| {
+                let run = self.clock_skew_run(timing, skew_ms / 1000.0, TimeAlignment::Propagate);
+                SkewSample { skew_ms, rms: run.rms.mean() }
+            })
+            .collect();
+        let ClockSkewRun { rms, offset_error_ms, packets_sent, ticks: target_ticks, final_time_secs: final_time } =
+            self.clock_skew_run(timing, skews_ms[skews_ms.len() - 1] / 1000.0, TimeAlignment::Estimate);
+        let stats = ClockSkewStats::new(propagated, rms.mean(), offset_error_ms);
+        
+        let baseline = stats.baseline_rms().unwrap_or(0.0);
+        let degrades = stats.rms_per_100ms > 0.0;
+        let estimated = offset_error_ms < max_offset_error_ms;
+        let compensated = rms.mean() <= baseline * max_compensated_ratio;
+        let failure = clock_skew_failure(
+            stats.rms_per_100ms,
+            (offset_error_ms, max_offset_error_ms),
+            (rms.mean(), baseline * max_compensated_ratio),
+        );
+        let passed = failure.is_none();
         
-        let num_agents = 50;
-        let total_bandwidth_limit = 1000; // packets per tick global
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  CLOCK SKEW RESULTS:");
+        for sample in &stats.propagated {
+            info!("    Skew {:>5.0}ms: RMS {:.2}m", sample.skew_ms, sample.rms);
+        }
+        info!("    Degradation: {:.2}m per 100ms of skew  {}", stats.rms_per_100ms, if degrades { "✓" } else { "✗" });
+        info!("    Worst offset estimate error: {:.1}ms (target < {}ms)  {}",
+            offset_error_ms, max_offset_error_ms, if estimated { "✓" } else { "✗" });
+        info!("    Compensated RMS: {:.2}m (target <= {:.2}m)  {}",
+            rms.mean(), baseline * max_compensated_ratio, if compensated { "✓" } else { "✗" });
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        let mut agents: Vec<SimulatedAgent> = Vec::with_capacity(num_agents);
-        let key_provider = DeterministicKeyProvider::new(self.seed);
-        let root_key = key_provider.biscuit_root_key().public();
         
-        for i in 0..num_agents {
-            let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
-            let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-            agents.push(SimulatedAgent::new(
-                context, 
-                network, 
-                root_key.clone(), 
-                i as u64, 
-                AgentConfig::default()
-            ));
+        ScenarioResult {
+            scenario: ScenarioId::ClockSkew,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: final_time,
+            final_entity_count: 6,
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics: ScenarioMetrics {
+                packets_sent,
+                clock_skew: Some(stats),
+                ..ScenarioMetrics::default()
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
+    }
+    
+    /// Runs the ClockSkew simulation once with agent 0's clock `skew_secs`
+    /// ahead (plus 100ppm drift).
+    fn clock_skew_run(&self, timing: RunTiming, skew_secs: f64, alignment: TimeAlignment) -> ClockSkewRun {
+        let num_agents = 4;
+        let gossip_interval = timing.tick_rate_hz as u64;
+        let latency_ticks = (timing.tick_rate_hz as u64 * 3 / 10).max(1);
+        let drift_ppm = 100.0;
         
         let mut oracle = Oracle::new(self.seed);
-        for i in 0..50 {
-             oracle.spawn_entity(
-                Vector3::new(i as f64 * 10.0, 0.0, 100.0),
-                Vector3::new(1.0, 0.0, 0.0),
-                "starve_target",
+        for i in 0..6 {
+            let heading = i as f64 * std::f64::consts::TAU / 6.0;
+            oracle.spawn_entity(
+                Vector3::new((i as f64) * 40.0, 0.0, 100.0),
+                Vector3::new(heading.cos(), heading.sin(), 0.0) * 10.0,
+                "skew_target",
             );
         }
         
-        let mut swarm_network = SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = (self.max_duration_secs.min(20.0) * 10.0) as u64;
-        let evo_epoch_ticks = 10;
+        let key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        
+        // Clones share the virtual clock; only agent 0's runs ahead
+        let context = SimContext::new(self.seed);
+        let contexts: Vec<Arc<SimContext>> = (0..num_agents)
+            .map(|i| {
+                let local = context.clone();
+                if i == 0 {
+                    local.set_clock_offset_secs(skew_secs);
+                    local.set_drift_ppm(drift_ppm);
+                }
+                Arc::new(local)
+            })
+            .collect();
+        let mut agents: Vec<SimulatedAgent> = contexts.iter()
+            .enumerate()
+            .map(|(i, local)| {
+                let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
+                // Predicted tracks keep up with the targets, so residuals
+                // against them measure timing rather than filter lag
+                let config = AgentConfig {
+                    tick_rate_hz: timing.tick_rate_hz,
+                    filter: Some(FilterTuning::default()),
+                    ..SimulatedAgent::default_config()
+                };
+                let mut agent = SimulatedAgent::new(Arc::clone(local), network, root_key, i as u64, config);
+                agent.set_time_alignment(alignment);
+                agent
+            })
+            .collect();
         
-        let mut total_sent = 0;
-        let mut total_dropped_bandwidth = 0;
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        
+        info!("  Config: {} agents, 6 entities, {} ticks. Agent 0 clock +{:.0}ms, {}ppm; alignment {:?}",
+            num_agents, target_ticks, skew_secs * 1000.0, drift_ppm, alignment);
+        
+        let monitor = self.monitor(ScenarioId::ClockSkew, target_ticks);
+        let mut in_flight = std::collections::VecDeque::new();
+        let mut packets_sent = 0;
         
         for tick in 0..target_ticks {
             oracle.step(dt);
-            let readings = oracle.generate_sensor_readings();
+            context.advance_time(Duration::from_secs_f64(dt));
             let ground_truth = oracle.ground_truth_positions();
             
-            for (idx, agent) in agents.iter_mut().enumerate() {
-                agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
-                
-                 let agent_readings: Vec<_> = readings.iter()
-                    .enumerate()
-                    .filter(|(entity_idx, _)| (entity_idx + idx) % 5 == 0)
-                    .map(|(_, r)| r.clone())
-                    .collect();
-                agent.ingest_readings(&agent_readings);
+            for agent in agents.iter_mut() {
+                let readings = oracle.generate_sensor_readings();
+                agent.tick();
+                agent.ingest_readings(&readings);
             }
             
-            // Collect all proposed packets
-            let mut pending_packets = Vec::new();
-            for (idx, agent) in agents.iter_mut().enumerate() {
-                // Respect agent's evolved gossip interval
-                if tick % agent.gossip_interval() == 0 {
-                    let recent_count = agent.recent_packets().len();
-                    for p in agent.recent_packets() {
-                        pending_packets.push((idx, p.clone()));
-                    }
-                    for _ in 0..recent_count {
-                        // Estimate average packet size (e.g., 100 bytes + overhead)
-                        let size = 125;
-                        agent.record_message_sent_metric(size); // Charged for attempting
+            // Each round gossips the latest packet per entity, delivered
+            // full-mesh after a fixed latency
+            if tick % gossip_interval == 0 {
+                let outgoing: Vec<Vec<GlobalHazardPacket>> = agents.iter_mut()
+                    .map(|a| {
+                        let mut latest: Vec<GlobalHazardPacket> = Vec::new();
+                        for packet in a.recent_packets().iter().rev() {
+                            if !latest.iter().any(|p| p.entity_id == packet.entity_id) {
+                                latest.push(packet.clone());
+                            }
+                        }
+                        a.clear_recent_packets();
+                        latest
+                    })
+                    .collect();
+                packets_sent += outgoing.iter().map(|p| p.len() as u64).sum::<u64>();
+                in_flight.push_back((tick + latency_ticks, outgoing));
+            }
+            while in_flight.front().is_some_and(|(due, _)| *due <= tick) {
+                let Some((_, outgoing)) = in_flight.pop_front() else {
+                    break;
+                };
+                for (to, agent) in agents.iter_mut().enumerate() {
+                    for (from, packets) in outgoing.iter().enumerate() {
+                        if from != to {
+                            agent.receive_gossip_from(from, packets);
+                        }
                     }
                 }
             }
             
-            // Global bandwidth limiter
-            total_sent += pending_packets.len();
-            if pending_packets.len() > total_bandwidth_limit {
-                total_dropped_bandwidth += pending_packets.len() - total_bandwidth_limit;
-                pending_packets.truncate(total_bandwidth_limit);
-            }
+            rms.sample(oracle.time(), &agents, &ground_truth);
             
-            // Deliver
-            for (from, packet) in pending_packets {
-                swarm_network.queue_gossip(from, packet);
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
             }
-            
-            for (idx, agent) in agents.iter_mut().enumerate() {
-                let incoming = swarm_network.take_gossip(idx);
-                agent.receive_gossip(&incoming);
-                agent.clear_recent_packets();
+        }
+        
+        // Neighbor j's clock runs ahead of agent i's by error_j - error_i
+        let offset_error_ms = if alignment == TimeAlignment::Estimate {
+            let errors: Vec<f64> = contexts.iter().map(|c| c.clock_error_secs()).collect();
+            agents.iter()
+                .enumerate()
+                .flat_map(|(i, agent)| {
+                    let errors = &errors;
+                    (0..num_agents).filter(move |&j| j != i).map(move |j| {
+                        let estimate = agent.clock_offset_estimate(j).map_or(0.0, |e| e.offset_secs);
+                        (estimate - (errors[j] - errors[i])).abs() * 1000.0
+                    })
+                })
+                .fold(0.0, f64::max)
+        } else {
+            0.0
+        };
+        
+        ClockSkewRun { rms, offset_error_ms, packets_sent, ticks: target_ticks, final_time_secs: oracle.time() }
+    }
+    
+    /// DST-026: Occlusion - a wall splits the field
+    /// 
+    /// Eight agents in a 2x4 gossip grid, checkerboarded either side of a
+    /// tall wall along x = 0 so every agent's neighbors include some on the
+    /// far side. Five targets move along each side of the wall, and targets
+    /// block each other as 1m spheres. Each agent only gets readings of what
+    /// it has line of sight to, so everything beyond the wall has to reach
+    /// it through gossip.
+    /// 
+    /// **Success Criteria**: agents see at most 60% of the targets directly,
+    /// every agent tracks at least 80% of the targets it can't see, updated
+    /// track states' RMS < 3.0m, and coasting error grows no faster than the
+    /// 3m/s targets allow (see [`coasting_failure`]).
+    fn run_occlusion(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-026: Occlusion - Wall Splits the Field 🧱");
+        
+        let (rows, cols) = (2, 4);
+        let targets_per_side = 5;
+        let max_direct_coverage = 0.6;
+        let min_hidden_coverage = 0.8;
+        let max_updated_rms = 3.0;
+        
+        let sensor_positions: Vec<Vector3<f64>> = (0..rows * cols)
+            .map(|idx| {
+                let (row, col) = (idx / cols, idx % cols);
+                let side = if (row + col) % 2 == 0 { -1.0 } else { 1.0 };
+                Vector3::new(side * 60.0, row as f64 * 60.0 - 30.0, 10.0)
+            })
+            .collect();
+        let mut harness = SwarmHarness::new(self.seed, rows, cols, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_threads(self.threads)
+            .with_sensor_positions(sensor_positions.clone());
+        
+        let target_speed = |i: usize| 1.0 + i as f64 * 0.5;
+        let max_target_speed = target_speed(targets_per_side - 1);
+        let oracle = harness.oracle_mut();
+        oracle.add_obstacle(Vector3::new(0.0, 0.0, 50.0), Vector3::new(1.0, 1000.0, 50.0));
+        oracle.set_target_radius(1.0);
+        for side in [-1.0, 1.0] {
+            for i in 0..targets_per_side {
+                oracle.spawn_entity(
+                    Vector3::new(side * (20.0 + i as f64 * 6.0), i as f64 * 15.0 - 30.0, 20.0),
+                    Vector3::new(0.0, target_speed(i), 0.0),
+                    "occluded_target",
+                );
             }
         }
         
-        let ground_truth = oracle.ground_truth_positions();
-        let avg_rms: f64 = agents.iter().map(|a| a.compute_position_error(&ground_truth)).sum::<f64>() / num_agents as f64;
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut coasting = self.coasting_sampler(target_ticks as f64 * dt, dt);
         
-        // Check if agents increased gossip interval to reduce cost
-        let avg_interval: f64 = agents.iter().map(|a| a.gossip_interval() as f64).sum::<f64>() / num_agents as f64;
+        info!("  Config: {} agents, {} targets, wall along x = 0", rows * cols, 2 * targets_per_side);
+        
+        let monitor = self.monitor(ScenarioId::Occlusion, target_ticks);
+        
+        for tick in 0..target_ticks {
+            harness.step();
+            
+            let ground_truth = harness.oracle().ground_truth_positions();
+            rms.sample(harness.time(), harness.agents(), &ground_truth);
+            coasting.sample(harness.time(), harness.agents(), &ground_truth);
+            
+            if monitor.observe(tick, harness.oracle(), harness.agents()) {
+                break;
+            }
+        }
         
-        let passed = avg_rms < 5.0 && avg_interval > 5.0; // Interval should increase > 5 (default)
+        let coverage = OcclusionCoverage::measure(harness.agents(), harness.oracle(), &sensor_positions);
+        let occluded = coverage.direct_coverage <= max_direct_coverage;
+        let filled = coverage.min_hidden_coverage >= min_hidden_coverage;
+        let coasting = coasting.metrics();
+        let coasting_check = coasting_failure(&coasting, max_updated_rms, max_target_speed);
+        let coasting_ok = coasting_check.is_none();
+        let failure = occlusion_failure(&coverage, max_direct_coverage, min_hidden_coverage, coasting_check);
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  RESOURCE STARVATION RESULTS:");
-        info!("    RMS error:      {:.2}m  {}", avg_rms, if avg_rms < 5.0 { "✓" } else { "✗" });
-        info!("    Avg Interval:   {:.1} ticks (started at 5) {}", avg_interval, if avg_interval > 5.0 { "✓ (Adapted)" } else { "✗" });
-        info!("    Bandwidth Drop: {:.1}%", total_dropped_bandwidth as f64 * 100.0 / total_sent as f64);
+        info!("  OCCLUSION RESULTS:");
+        info!("    Direct coverage:  {:.0}% (target <= {:.0}%)  {}",
+            coverage.direct_coverage * 100.0, max_direct_coverage * 100.0, if occluded { "✓" } else { "✗" });
+        info!("    Fused coverage:   {:.0}%", coverage.fused_coverage * 100.0);
+        info!("    Hidden coverage:  {:.0}% mean, {:.0}% worst agent (target >= {:.0}%)  {}",
+            coverage.hidden_coverage * 100.0, coverage.min_hidden_coverage * 100.0, min_hidden_coverage * 100.0,
+            if filled { "✓" } else { "✗" });
+        info!("    RMS error:        {:.2}m (p95 {:.2}m), {:.2}m updated, {:.2}m coasting  {}",
+            rms.mean(), rms.p95(), coasting.updated_rms, coasting.coasting_rms, if coasting_ok { "✓" } else { "✗" });
+        info!("    Error growth:     {:.2}m²/s per coasted second (up to {:.1}s coasted)",
+            coasting.error_growth_m2_per_s, coasting.max_coast_secs);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        let metrics = ScenarioMetrics {
+            packets_sent: harness.packets_offered(),
+            bytes_sent: harness.bytes_offered(),
+            occlusion: Some(coverage),
+            observation_coverage: harness.observation_coverage(),
+            coasting: Some(coasting),
+            ..ScenarioMetrics::default()
+        };
         
         ScenarioResult {
-            scenario: ScenarioId::ResourceStarvation,
+            scenario: ScenarioId::Occlusion,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed { Some(format!("RMS={:.2}m (want <5), Interval={:.1} (want >5)", avg_rms, avg_interval)) } else { None },
-            metrics: ScenarioMetrics::default(),
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-016: ProtocolDrift - Stub for now.
-    fn run_protocol_drift(&self) -> ScenarioResult {
-        info!("DST-016: ProtocolDrift - Placeholder 🧬");
-        ScenarioResult {
-            scenario: ScenarioId::ProtocolDrift,
-            seed: self.seed,
-            passed: true,
-            total_ticks: 0,
-            final_time_secs: 0.0,
-            final_entity_count: 0,
-            failure_reason: None,
-            metrics: ScenarioMetrics::default(),
+    /// DST-027: MixedFleet - precise and noisy agents in one swarm
+    /// 
+    /// Swarm's 5x10 gossip grid (the swarm config's) and striped visibility,
+    /// but only every fifth agent carries a precise sensor; the other 40 add
+    /// 5m of horizontal noise to every reading and stamp them with zero
+    /// confidence. Noisy agents fuse their neighbors' gossip with their own
+    /// readings, so their tracks end up far closer to the precise agents'
+    /// than their sensors alone allow (about 6x worse without the confidence
+    /// hint).
+    /// 
+    /// **Success Criteria**: swarm RMS < 5.0m and noisy agents' RMS within
+    /// 4.5x of the precise agents'.
+    fn run_mixed_fleet(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-027: MixedFleet - Precise and Noisy Agents 🎛️");
+        
+        let (rows, cols) = (self.swarm_config.rows, self.swarm_config.cols);
+        let num_entities = 100;
+        let noisy_sensor_m = 5.0;
+        let max_rms_ratio = 4.5;
+        
+        let mut harness = SwarmHarness::new(self.seed, rows, cols, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_threads(self.threads)
+            .with_visibility(Visibility::Striped { period: 10, visible: 5 })
+            .with_agent_profiles(|idx| {
+                if idx.is_multiple_of(5) {
+                    AgentProfile::new("precise", SimulatedAgent::default_config())
+                } else {
+                    AgentProfile::new("noisy", SimulatedAgent::default_config())
+                        .with_sensor_noise(noisy_sensor_m)
+                        .with_reading_confidence(0.0)
+                }
+            });
+        
+        for i in 0..num_entities {
+            let position = Vector3::new((i % 25) as f64 * 40.0, (i / 25) as f64 * 40.0, 100.0);
+            let velocity = Vector3::new(8.0 + (i % 5) as f64, 3.0 * ((i % 3) as f64 - 1.0), 0.0);
+            harness.oracle_mut().spawn_entity(position, velocity, "fleet_target");
         }
-    }
-
-    /// DST-017: BlindLearning - Evolve without Ground Truth.
-    ///
-    /// Agents must optimize NIS (Internal Consistency) and Peer Agreement (Consensus)
-    /// to find good parameters, without ever knowing their true error.
-    fn run_blind_learning(&self) -> ScenarioResult {
-        use crate::evolution::BlindFitness;
-
-        info!("DST-017: BlindLearning - ADAPTING BLINDLY 🙈");
         
-        let num_agents = 50;
-        let packet_loss_rate = 0.20; // Moderate noise
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let run_secs = target_ticks as f64 * dt;
+        let mut rms = self.rms_sampler(run_secs);
+        let groups = harness.profile_groups();
+        let mut group_rms: Vec<RmsSampler> = groups.iter().map(|_| self.rms_sampler(run_secs)).collect();
+        
+        info!("  Config: {} agents ({}), {} entities, {} ticks", rows * cols,
+            groups.iter().map(|(name, members)| format!("{} {}", members.len(), name)).collect::<Vec<_>>().join(", "),
+            num_entities, target_ticks);
+        
+        let monitor = self.monitor(ScenarioId::MixedFleet, target_ticks);
+        
+        for tick in 0..target_ticks {
+            harness.step();
+            
+            let ground_truth = harness.oracle().ground_truth_positions();
+            rms.sample(harness.time(), harness.agents(), &ground_truth);
+            for ((_, members), sampler) in groups.iter().zip(&mut group_rms) {
+                sampler.sample(harness.time(), members.iter().map(|&i| &harness.agents()[i]), &ground_truth);
+            }
+            
+            if monitor.observe(tick, harness.oracle(), harness.agents()) {
+                break;
+            }
+        }
+        
+        let profile_rms: Vec<ProfileRms> = groups.iter().zip(&group_rms)
+            .map(|((name, members), sampler)| ProfileRms::new(name, members.len(), sampler))
+            .collect();
+        let group_mean = |name: &str| profile_rms.iter().find(|g| g.profile == name).map_or(0.0, |g| g.rms_mean);
+        let (precise, noisy) = (group_mean("precise"), group_mean("noisy"));
+        let ratio_ok = noisy <= max_rms_ratio * precise.max(f64::EPSILON);
+        let failure = mixed_fleet_failure(precise, noisy, max_rms_ratio, rms.failure(5.0));
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  MIXED FLEET RESULTS:");
+        for group in &profile_rms {
+            info!("    {:>8} ({:>2} agents): {:.2}m RMS (p95 {:.2}m)", group.profile, group.agents, group.rms_mean, group.rms_p95);
+        }
+        info!("    Noisy/precise ratio: {:.2} (max {})  {}", noisy / precise.max(f64::EPSILON), max_rms_ratio,
+            if ratio_ok { "✓" } else { "✗" });
+        info!("    RMS error:           {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms.within(5.0) { "✓" } else { "✗" });
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        
+        ScenarioResult {
+            scenario: ScenarioId::MixedFleet,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics: ScenarioMetrics {
+                packets_sent: harness.packets_offered(),
+                bytes_sent: harness.bytes_offered(),
+                profile_rms,
+                ..ScenarioMetrics::default()
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// DST-028: Clutter - missed detections and phantom readings
+    /// 
+    /// Eight agents each watch ten moving entities through their own sensor,
+    /// which misses 10% of them every tick and reports a Poisson-distributed
+    /// handful of phantoms scattered over its footprint. Half the agents
+    /// confirm tracks 3-of-5 before trusting them; the other half confirm
+    /// every track on sight and serve as the baseline. There is no gossip,
+    /// so each agent's clutter tracks are its own.
+    /// 
+    /// **Success Criteria**: confirming agents' RMS < 2.0m, at least 90% of
+    /// their clutter tracks die tentative, and they hold fewer live false
+    /// tracks per frame than the baseline agents.
+    fn run_clutter(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-028: Clutter - Missed Detections and Phantoms 👻");
+        
+        let num_agents = 8;
+        let num_entities = 10;
+        let min_suppression = 0.9;
+        let detection = DetectionModel {
+            detection_probability: 0.9,
+            clutter_rate: 2.0,
+            footprint: (Vector3::new(-100.0, -100.0, 50.0), Vector3::new(700.0, 300.0, 150.0)),
+        };
         
-        // Oracle setup
         let mut oracle = Oracle::new(self.seed);
-        for i in 0..50 {
-            oracle.spawn_entity(
-                Vector3::new((i % 10) as f64 * 50.0, (i / 10) as f64 * 50.0, 100.0),
-                Vector3::new(5.0, 2.0, 0.0),
-                "blind_target",
-            );
+        for sensor_id in 0..num_agents {
+            oracle.set_detection_model(sensor_id as u32, detection);
+        }
+        for i in 0..num_entities {
+            let position = Vector3::new((i % 5) as f64 * 100.0, (i / 5) as f64 * 100.0, 100.0);
+            let velocity = Vector3::new(5.0 + (i % 3) as f64, 2.0 * ((i % 3) as f64 - 1.0), 0.0);
+            oracle.spawn_entity(position, velocity, "clutter_target");
         }
         
         let key_provider = DeterministicKeyProvider::new(self.seed);
         let root_key = key_provider.biscuit_root_key().public();
+        let confirming = |i: usize| i < num_agents / 2;
         
-        // Agents initialized with BLIND FITNESS
         let mut agents: Vec<SimulatedAgent> = (0..num_agents)
             .map(|i| {
                 let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
                 let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-                let mut agent = SimulatedAgent::new(context, network, root_key.clone(), i as u64, AgentConfig::default());
-                
-                // CRITICAL: Switch to Blind Fitness!
-                agent.set_fitness_provider(Box::new(BlindFitness::new()));
-                agent
+                let config = AgentConfig {
+                    lifecycle: confirming(i).then(|| LifecycleConfig::uniform(TrackLifecycle::default())),
+                    ..SimulatedAgent::default_config()
+                };
+                SimulatedAgent::new(context, network, root_key, i as u64, config)
             })
             .collect();
-            
-        let mut swarm_network = crate::swarm_network::SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = (self.max_duration_secs.min(45.0) * 10.0) as u64; // Runs a bit longer
-        let evo_epoch_ticks = 20;
         
-        let mut rng = rand::rngs::StdRng::seed_from_u64(self.seed);
-        use rand::{Rng, SeedableRng};
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        let mut confirming_clutter = ClutterSampler::new();
+        let mut baseline_clutter = ClutterSampler::new();
         
-        info!("  Config: {} agents using BlindFitness (NIS+PA+BW)", num_agents);
+        info!("  Config: {} agents ({} confirming 3-of-5), {} entities, Pd {}, {} clutter/scan, {} ticks",
+            num_agents, num_agents / 2, num_entities, detection.detection_probability, detection.clutter_rate, target_ticks);
         
-        // Tracking convergence
-        let mut initial_rms = 0.0;
+        let monitor = self.monitor(ScenarioId::Clutter, target_ticks);
         
         for tick in 0..target_ticks {
             oracle.step(dt);
-            let readings = oracle.generate_sensor_readings();
-            let ground_truth = oracle.ground_truth_positions();
-            
-            // Measure initial performance after a few ticks
-            if tick == 20 {
-                initial_rms = agents.iter().map(|a| a.compute_position_error(&ground_truth)).sum::<f64>() / num_agents as f64;
-                info!("  Initial RMS: {:.2}m", initial_rms);
-            }
             
             for (idx, agent) in agents.iter_mut().enumerate() {
-                 // Evolution Step
-                 // We pass ground_truth ONLY for recording the "true" accuracy for our report/metrics.
-                 // The agent's BlindFitness provider will IGNORE it.
-                agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
+                if !agent.tick() { continue; }
                 
-                let agent_readings: Vec<_> = readings.iter()
-                    .enumerate()
-                    .filter(|(entity_idx, _)| (entity_idx + idx) % 5 == 0)
-                    .map(|(_, r)| r.clone())
-                    .collect();
-                agent.ingest_readings(&agent_readings);
+                let readings = oracle.generate_sensor_readings_from(idx as u32);
+                agent.ingest_readings(&readings);
+                let sampler = if confirming(idx) { &mut confirming_clutter } else { &mut baseline_clutter };
+                sampler.observe(agent, &readings);
             }
             
-            // Gossip Logic (with packet drop)
-            if tick % 5 == 0 {
-                // Collect packets
-                let all_packets: Vec<_> = agents.iter_mut()
-                    .enumerate()
-                    .flat_map(|(idx, a)| {
-                        // Respect evolved interval? 
-                        // Simplified: check simple modulus against agent's interval
-                        if tick % a.gossip_interval() == 0 {
-                            let packets: Vec<_> = a.recent_packets().iter().map(|p| (idx, p.clone())).collect();
-                            // Charge bandwidth
-                            let count = packets.len();
-                             // Estimate wire size (struct ~100 bytes + overhead ~25)
-                            a.record_message_sent_metric(count as u64 * 125);
-                            packets
-                        } else {
-                            Vec::new()
-                        }
-                    })
-                    .collect();
-
-                // Distribute
-                for (from_idx, packet) in all_packets {
-                    // 20% Packet loss
-                    if rng.gen::<f64>() < packet_loss_rate { continue; }
-                    swarm_network.queue_gossip(from_idx, packet);
-                }
-                
-                for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
-                    
-                    // Limit neighbors by evolved parameter
-                    let max_neighbors = agent.max_gossip_neighbors();
-                     let limited_incoming = if incoming.len() > max_neighbors {
-                        &incoming[0..max_neighbors]
-                    } else {
-                        &incoming[..]
-                    };
-                    
-                    agent.receive_gossip(limited_incoming);
-                    agent.clear_recent_packets();
-                }
+            let (confirmers, baseline) = agents.split_at(num_agents / 2);
+            confirming_clutter.sample(confirmers);
+            baseline_clutter.sample(baseline);
+            rms.sample(oracle.time(), confirmers, &oracle.ground_truth_positions());
+            
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
             }
         }
         
-        let ground_truth = oracle.ground_truth_positions();
-        let final_rms: f64 = agents.iter().map(|a| a.compute_position_error(&ground_truth)).sum::<f64>() / num_agents as f64;
-        
-        // Did we improve?
-        let improved = final_rms < initial_rms;
-        // Did we survive reasonably well?
-        let passed = final_rms < 10.0; 
+        let (clutter, baseline) = (confirming_clutter.metrics(), baseline_clutter.metrics());
+        let failure = clutter_failure(&clutter, &baseline, min_suppression, rms.failure(2.0));
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  BLIND LEARNING RESULTS:");
-        info!("    Initial RMS:   {:.2}m", initial_rms);
-        info!("    Final RMS:     {:.2}m  {}", final_rms, if passed { "✓" } else { "✗" });
-        info!("    Improvement:   {}", if improved { "YES (Optimized!)" } else { "NO" });
-        
-        // Check params
-        let agent0_params = &agents[0].evolutionary_state().current_params;
-        info!("    Final Params:  interval={}, neighbors={}, conf={:.2}", 
-            agent0_params.gossip_interval_ticks, agent0_params.max_neighbors_gossip, agent0_params.confidence_threshold);
+        info!("  CLUTTER RESULTS:");
+        info!("    Clutter readings:    {} ({} started tracks)",
+            clutter.clutter_readings + baseline.clutter_readings, clutter.clutter_tracks + baseline.clutter_tracks);
+        info!("    Suppressed:          {:.1}% of clutter tracks (min {:.0}%)  {}", clutter.suppression() * 100.0,
+            min_suppression * 100.0, if clutter.suppression() >= min_suppression { "✓" } else { "✗" });
+        info!("    False tracks/frame:  {:.2} confirming vs {:.2} baseline  {}", clutter.false_track_rate(),
+            baseline.false_track_rate(), if clutter.false_track_rate() < baseline.false_track_rate() { "✓" } else { "✗" });
+        info!("    RMS error:           {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms.within(2.0) { "✓" } else { "✗" });
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
+        
         ScenarioResult {
-            scenario: ScenarioId::BlindLearning,
+            scenario: ScenarioId::Clutter,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
             final_time_secs: oracle.time(),
             final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed { Some(format!("RMS {:.2}m", final_rms)) } else { None },
-            metrics: ScenarioMetrics::default(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics: ScenarioMetrics {
+                ghost_detections: clutter.confirmed_clutter_tracks,
+                clutter: Some(clutter),
+                baseline_clutter: Some(baseline),
+                ..ScenarioMetrics::default()
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
-
-    /// DST-018: BlackoutSurvival - Total System Failure.
-    ///
-    /// The ultimate test: 50% Packet Loss + Sensor Faults + Bad Actors + Bandwidth Limit.
-    /// Agents must usage BlindFitness to filter noise, reject bad actors, and survive.
-    fn run_blackout_survival(&self) -> ScenarioResult {
-        use crate::evolution::BlindFitness;
-
-        info!("DST-018: BlackoutSurvival - TOTAL SYSTEM FAILURE 💀");
-        
-        // 1. Extreme Environment
-        let num_agents = 50;
-        let num_bad_actors = 10; // 20% Traitors
-        let packet_loss_rate = 0.50; // High loss
-        let sensor_fault_rate = 0.10; // 10% Blackouts
-        let bandwidth_limit = 1500; // Global limit
+    
+    /// DST-029: GpsDenied - agents lose their own position fix
+    /// 
+    /// Twelve agents in a 3x4 gossip grid, each with its own sensor placed
+    /// under the field and a striped view of fourteen moving targets. From
+    /// 25% to 50% of the run the agents of the second column lose GPS and
+    /// dead-reckon in random directions, 5m off by the end of the window,
+    /// so their readings, and their gossip, drift with them. Every agent estimates each neighbor's
+    /// systematic offset against its own readings and down-weights
+    /// neighbors more than 2m off.
+    /// 
+    /// **Success Criteria**: RMS during the denial < 3.0m, honest agents
+    /// flag at least 80% of their denied neighbors and no honest ones, RMS
+    /// back within 1.25x of its pre-denial mean within 3s of GPS returning,
+    /// and overall RMS < 3.0m.
+    fn run_gps_denied(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-029: GpsDenied - Agents Dead-Reckon Without GPS 🛰️");
+        
+        let (rows, cols) = (3, 4);
+        let num_entities = 14;
+        let offset_tolerance_m = 2.0;
+        let peak_pose_error_m = 5.0;
+        let max_rms_during = 3.0;
+        let min_detection_rate = 0.8;
+        let max_recovery_secs = 3.0;
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let run_secs = target_ticks as f64 * dt;
+        let (start_secs, end_secs) = (run_secs * 0.25, run_secs * 0.5);
+        let denial = GpsDenial {
+            agents: (0..rows * cols).filter(|idx| idx % cols == 1).collect(),
+            start_secs,
+            end_secs,
+            drift_mps: peak_pose_error_m / (end_secs - start_secs),
+        };
         
-        // Oracle setup
-        let mut oracle = Oracle::new(self.seed);
-        for i in 0..50 {
-            oracle.spawn_entity(
-                Vector3::new((i % 10) as f64 * 50.0, (i / 10) as f64 * 50.0, 100.0),
-                Vector3::new(5.0, 2.0, 0.0),
-                "blackout_target",
-            );
+        let sensor_positions: Vec<Vector3<f64>> = (0..rows * cols)
+            .map(|idx| Vector3::new((idx % cols) as f64 * 80.0, (idx / cols) as f64 * 80.0, 0.0))
+            .collect();
+        let mut harness = SwarmHarness::new(self.seed, rows, cols, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_agent_config(AgentConfig { tick_rate_hz: timing.tick_rate_hz, ..SimulatedAgent::default_config() })
+            .with_sensor_positions(sensor_positions)
+            .with_visibility(Visibility::Striped { period: 7, visible: 4 })
+            .with_peer_offset_tolerance(offset_tolerance_m)
+            .with_gps_denial(denial.clone());
+        
+        // Striped visibility: grid neighbors share some targets (to compare
+        // gossip against) and each sees some the other can't (so gossip
+        // between them stays useful)
+        for i in 0..num_entities {
+            let position = Vector3::new((i % 5) as f64 * 60.0, (i / 5) as f64 * 80.0 + 40.0, 50.0);
+            let velocity = Vector3::new(4.0 + (i % 3) as f64, 2.0 * ((i % 3) as f64 - 1.0), 0.0);
+            harness.oracle_mut().spawn_entity(position, velocity, "gps_denied_target");
         }
         
-        let key_provider = DeterministicKeyProvider::new(self.seed);
-        let root_key = key_provider.biscuit_root_key().public();
+        let mut rms = self.rms_sampler(run_secs);
+        let mut sampler = GpsDenialSampler::new(&denial, self.warmup_secs.min(denial.start_secs / 2.0));
         
-        // Agents: Blind Fitness + Bad Actors
-        let mut agents: Vec<SimulatedAgent> = Vec::with_capacity(num_agents);
-        for i in 0..num_agents {
-             let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
-            let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-            let mut agent = SimulatedAgent::new(context, network, root_key.clone(), i as u64, AgentConfig::default());
+        info!("  Config: {} agents ({} denied GPS from {:.1}s to {:.1}s, drifting {:.2} m/s), {} entities, {} ticks",
+            rows * cols, denial.agents.len(), denial.start_secs, denial.end_secs, denial.drift_mps, num_entities, target_ticks);
+        
+        let monitor = self.monitor(ScenarioId::GpsDenied, target_ticks);
+        
+        for tick in 0..target_ticks {
+            harness.step();
             
-            // Usage Blind Fitness
-            agent.set_fitness_provider(Box::new(BlindFitness::new()));
+            let ground_truth = harness.oracle().ground_truth_positions();
+            let agents = harness.agents();
+            rms.sample(harness.time(), agents, &ground_truth);
+            let swarm_rms = swarm_convergence(agents, &ground_truth, every_agent).rms_mean;
+            sampler.observe(harness.time(), swarm_rms, agents);
             
-             // Bad Actors?
-            if i < num_bad_actors as usize {
-                 let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
-                 let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-                 let mut bad_agent = SimulatedAgent::new_bad_actor(context, network, root_key.clone(), i as u64, AgentConfig::default());
-                 bad_agent.set_fitness_provider(Box::new(BlindFitness::new())); 
-                 agent = bad_agent;
+            if monitor.observe(tick, harness.oracle(), harness.agents()) {
+                break;
             }
-            agents.push(agent);
         }
+        
+        let stats = sampler.stats();
+        let failure = gps_denied_failure(&stats, max_rms_during, min_detection_rate, max_recovery_secs, rms.failure(3.0));
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  GPS DENIED RESULTS:");
+        info!("    RMS before denial:  {:.2}m", stats.rms_before);
+        info!("    RMS during denial:  {:.2}m (peak {:.2}m, max {})  {}", stats.rms_during, stats.rms_peak, max_rms_during,
+            if stats.rms_during < max_rms_during { "✓" } else { "✗" });
+        info!("    Peak pose error:    {:.2}m", stats.peak_pose_error_m);
+        info!("    Offsets flagged:    {}/{} denied neighbors, {} honest  {}", stats.flagged_denied_pairs,
+            stats.denied_pairs, stats.flagged_honest_pairs,
+            if stats.detection_rate() >= min_detection_rate && stats.flagged_honest_pairs == 0 { "✓" } else { "✗" });
+        match stats.recovery_secs {
+            Some(secs) => info!("    Recovered:          {:.1}s after GPS returned (max {}s)  {}", secs, max_recovery_secs,
+                if secs <= max_recovery_secs { "✓" } else { "✗" }),
+            None => info!("    Recovered:          never  ✗"),
+        }
+        info!("    RMS error:          {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms.within(3.0) { "✓" } else { "✗" });
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        ScenarioResult {
+            scenario: ScenarioId::GpsDenied,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics: ScenarioMetrics {
+                packets_sent: harness.packets_offered(),
+                bytes_sent: harness.bytes_offered(),
+                gps_denial: Some(stats),
+                ..ScenarioMetrics::default()
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// DST-030: Convoy - moving agents hand tracks down the line
+    /// 
+    /// Ten agents drive in a line along the x axis past eight parked
+    /// targets either side of the road. Each sensor reads 30m round its
+    /// agent, so a target is in view of one stretch of the convoy at a time
+    /// and every agent that reaches it has heard of it from the agents ahead
+    /// first. Each agent drives 2% slower than the one ahead, so the line
+    /// stretches and, with radio links only within 40m, the two-hop links
+    /// break partway through. The convoy's speed is set for the last agent
+    /// to clear the last target by the end of the run.
+    /// 
+    /// **Success Criteria**: radio links change during the run, no MOT ID
+    /// switches, at most one canonical ID change per (agent, target) pair,
+    /// and RMS < 2.0m.
+    fn run_convoy(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-030: Convoy - Tracks Handed Down a Moving Line 🚚");
+        
+        let num_agents = 10;
+        let num_entities = 8;
+        let spacing_m = 15.0;
+        let sensor_range_m = 30.0;
+        let radio_range_m = 40.0;
+        let slowdown_per_agent = 0.02;
+        let max_id_churn = 1;
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let run_secs = target_ticks as f64 * dt;
+        let targets_x: Vec<f64> = (0..num_entities).map(|i| 40.0 + i as f64 * 30.0).collect();
+        let last_target_x = targets_x.last().copied().unwrap_or_default();
+        let rear_start_x = -spacing_m * (num_agents - 1) as f64;
+        let rear_speed = (last_target_x + sensor_range_m - rear_start_x) / (run_secs * 0.9);
+        let lead_speed = rear_speed / (1.0 - slowdown_per_agent * (num_agents - 1) as f64);
+        
+        let starts: Vec<Vector3<f64>> = (0..num_agents)
+            .map(|idx| Vector3::new(-spacing_m * idx as f64, 0.0, 2.0))
+            .collect();
+        let mut harness = SwarmHarness::new(self.seed, 1, num_agents, timing.tick_rate_hz)
+            .with_event_bus(self.hooks.event_bus())
+            .with_threads(self.threads)
+            .with_agent_profiles(|idx| {
+                let speed = lead_speed * (1.0 - slowdown_per_agent * idx as f64);
+                AgentProfile::new("convoy", SimulatedAgent::default_config())
+                    .with_motion(AgentMotion::Velocity(Vector3::new(speed, 0.0, 0.0)))
+            })
+            .with_sensor_positions(starts)
+            .with_radio_range(radio_range_m);
+        
+        let oracle = harness.oracle_mut();
+        oracle.set_sensor_range(sensor_range_m);
+        for (i, x) in targets_x.iter().enumerate() {
+            let side = if i % 2 == 0 { 1.0 } else { -1.0 };
+            oracle.spawn_entity(Vector3::new(*x, side * 10.0, 0.0), Vector3::zeros(), "parked_target");
+        }
+        
+        let mut rms = self.rms_sampler(run_secs);
+        let mut mot = self.mot_sampler(run_secs);
+        
+        info!("  Config: {} agents {}m apart at {:.1}-{:.1} m/s, {}m sensors, {}m radios, {} parked targets, {} ticks",
+            num_agents, spacing_m, rear_speed, lead_speed, sensor_range_m, radio_range_m, num_entities, target_ticks);
+        
+        let monitor = self.monitor(ScenarioId::Convoy, target_ticks);
+        
+        for tick in 0..target_ticks {
+            harness.step();
             
-        let mut swarm_network = crate::swarm_network::SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = (self.max_duration_secs.min(60.0) * 10.0) as u64;
-        let evo_epoch_ticks = 20;
+            let ground_truth = harness.oracle().ground_truth_positions();
+            rms.sample(harness.time(), harness.agents(), &ground_truth);
+            mot.sample(harness.time(), harness.agents(), &ground_truth);
+            
+            if monitor.observe(tick, harness.oracle(), harness.agents()) {
+                break;
+            }
+        }
         
-        let mut rng = rand::rngs::StdRng::seed_from_u64(self.seed);
-        use rand::{Rng, SeedableRng};
+        let mut metrics = ScenarioMetrics {
+            packets_sent: harness.packets_offered(),
+            bytes_sent: harness.bytes_offered(),
+            observation_coverage: harness.observation_coverage(),
+            link_changes: harness.network().link_changes(),
+            ..ScenarioMetrics::default()
+        };
+        metrics.record_mot(&mot);
+        let (mot, churn) = (mot.metrics(), mot.id_churn());
+        let failure = convoy_failure(metrics.link_changes, mot.id_switches, churn.max, max_id_churn, rms.failure(2.0));
+        let passed = failure.is_none();
         
-        info!("  Config: {} agents ({} bad), 50% loss, 10% sensor faults, BW limit", num_agents, num_bad_actors);
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  CONVOY RESULTS:");
+        info!("    Link changes:   {}  {}", metrics.link_changes, if metrics.link_changes > 0 { "✓" } else { "✗" });
+        info!("    ID switches:    {}  {}", mot.id_switches, if mot.id_switches == 0 { "✓" } else { "✗" });
+        info!("    ID churn:       {:.2} mean, {} max (max {})  {}", churn.mean, churn.max, max_id_churn,
+            if churn.max <= max_id_churn { "✓" } else { "✗" });
+        info!("    RMS error:      {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms.within(2.0) { "✓" } else { "✗" });
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        ScenarioResult {
+            scenario: ScenarioId::Convoy,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: harness.time(),
+            final_entity_count: harness.oracle().active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// DST-031: Triage - high-priority entities keep their gossip under a
+    /// bandwidth limit
+    /// 
+    /// A 4x4 grid where each column of agents watches every fourth of 40
+    /// moving entities, each agent sensing on every other tick, staggered by
+    /// row, so an agent learns of its entities between its own readings
+    /// only through its neighbors above and below. Agents offer all they
+    /// sensed every fourth tick, but links carry one packet per tick with a
+    /// 4-deep queue and drop the rest. Two entities per column are high
+    /// priority: their packets go out the tick they are sensed, ahead of
+    /// the routine ones and in place of them at a full queue, and their
+    /// tracks age out sooner.
+    /// 
+    /// **Success Criteria**: high-priority packets preempted routine ones,
+    /// and high-priority RMS < 1.0m. Routine entities may degrade; both
+    /// classes are reported in [`ScenarioMetrics::priority_classes`].
+    fn run_triage(&self, timing: RunTiming) -> ScenarioResult {
+        use crate::swarm_network::SwarmNetwork;
+        
+        info!("DST-031: Triage - High-Priority Gossip Under a Bandwidth Limit 🚨");
+        
+        let (rows, cols) = (4, 4);
+        let num_entities = 40;
+        let high_priority = 200;
+        let sense_every_ticks = 2;
+        let max_high_priority_rms = 1.0;
+        let link_config = LinkConfig::drop_tail(1, 4);
+        let config = AgentConfig {
+            gossip_interval_ticks: 4,
+            high_priority_max_age: Some(15),
+            ..SimulatedAgent::default_config()
+        };
+        
+        let key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        let mut agents: Vec<SimulatedAgent> = (0..rows * cols)
+            .map(|i| {
+                let mut agent = SimulatedAgent::new(
+                    Arc::new(SimContext::new(self.seed.wrapping_add(i as u64))),
+                    Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64))),
+                    root_key,
+                    i as u64,
+                    config.clone(),
+                );
+                agent.set_gossip_order(GossipOrder::Prioritized);
+                agent
+            })
+            .collect();
+        
+        // Entity i is watched by column i % cols; the first two entities of
+        // each column are threats
+        let mut oracle = Oracle::new(self.seed);
+        for i in 0..num_entities {
+            let position = Vector3::new((i % 8) as f64 * 10.0, (i / 8) as f64 * 10.0 - 20.0, 100.0);
+            let velocity = Vector3::new(0.0, 3.0, 0.0);
+            let priority = if i < 2 * cols { high_priority } else { 0 };
+            oracle.spawn_entity_with_priority(position, velocity, "triage_target", priority);
+        }
+        
+        let mut swarm_network = SwarmNetwork::new_grid(rows, cols)
+            .with_link_config(link_config, self.seed);
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let run_secs = target_ticks as f64 * dt;
+        let mut rms = self.rms_sampler(run_secs);
+        let mut classes = PriorityClassSampler::new(self.warmup_secs.min(run_secs / 2.0));
+        
+        info!("  Config: {}x{} agents, {} entities ({} high priority), 1 packet/link/tick, 4-deep queues, {} ticks",
+            rows, cols, num_entities, 2 * cols, target_ticks);
+        
+        let monitor = self.monitor(ScenarioId::Triage, target_ticks);
         
         for tick in 0..target_ticks {
             oracle.step(dt);
@@ -2248,368 +6652,509 @@ impl ScenarioRunner {
             let ground_truth = oracle.ground_truth_positions();
             
             for (idx, agent) in agents.iter_mut().enumerate() {
-                // Evolution
-                agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
-                
-                // Sensor Faults injection
-                let mut agent_readings: Vec<_> = readings.iter()
-                    .enumerate()
-                    .filter(|(entity_idx, _)| (entity_idx + idx) % 5 == 0)
-                    .map(|(_, r)| r.clone())
-                    .collect();
-                
-                // 10% chance to lose all readings (Blackout)
-                if rng.gen::<f64>() < sensor_fault_rate {
-                    agent_readings.clear();
-                } else if rng.gen::<f64>() < 0.05 {
-                     // 5% chance of severe noise
-                     for r in agent_readings.iter_mut() {
-                         r.position.x += rng.gen_range(-50.0..50.0);
-                         r.position.y += rng.gen_range(-50.0..50.0);
-                     }
+                agent.tick();
+                if (tick + (idx / cols) as u64).is_multiple_of(sense_every_ticks) {
+                    let agent_readings: Vec<SensorReading> = readings.iter()
+                        .filter(|r| r.entity_id as usize % cols == idx % cols)
+                        .cloned()
+                        .collect();
+                    agent.ingest_readings(&agent_readings);
                 }
-                
-                agent.ingest_readings(&agent_readings);
             }
             
-             // Gossip Logic
-            if tick % 5 == 0 {
-                // Collect packets
-                let mut all_packets: Vec<_> = agents.iter_mut()
-                    .enumerate()
-                    .flat_map(|(idx, a)| {
-                        if tick % a.gossip_interval() == 0 {
-                            let packets: Vec<_> = a.recent_packets().iter().map(|p| (idx, p.clone())).collect();
-                            // Charge bandwidth
-                            let count = packets.len();
-                            a.record_message_sent_metric(count as u64 * 125);
-                            packets
-                        } else {
-                            Vec::new()
-                        }
-                    })
-                    .collect();
-                
-                // Global Bandwidth Limit (Starvation)
-                 if all_packets.len() > bandwidth_limit {
-                    // Random drop to limit
-                    use rand::seq::SliceRandom;
-                    all_packets.shuffle(&mut rng);
-                    all_packets.truncate(bandwidth_limit);
-                }
-
-                // Distribute
-                for (from_idx, packet) in all_packets {
-                    // 50% Packet loss
-                    if rng.gen::<f64>() < packet_loss_rate { continue; }
-                    swarm_network.queue_gossip(from_idx, packet);
+            // Everything sensed since the last gossip tick goes out on the
+            // next one, high-priority packets as soon as they are sensed
+            let gossip_ticks: Vec<bool> = agents.iter().map(|a| tick.is_multiple_of(a.gossip_interval())).collect();
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                let batch = if gossip_ticks[idx] {
+                    agent.gossip_batch(agent.recent_packets().len(), None)
+                } else {
+                    agent.urgent_gossip_batch()
+                };
+                for p in batch {
+                    swarm_network.queue_gossip(idx, p);
                 }
-                
-                for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
-                    
-                    // Limit neighbors
-                    let max_neighbors = agent.max_gossip_neighbors();
-                     let limited_incoming = if incoming.len() > max_neighbors {
-                        &incoming[0..max_neighbors]
-                    } else {
-                        &incoming[..]
-                    };
-                    
-                     // Receive gossip with neighbor attribution hacks for trust
-                     let neighbors = swarm_network.neighbors(agent_idx);
-                     if !neighbors.is_empty() && !limited_incoming.is_empty() {
-                        let per = limited_incoming.len() / neighbors.len().max(1);
-                        for (i, neighbor_id) in neighbors.iter().enumerate() {
-                            let start = i * per;
-                            let end = if i == neighbors.len() - 1 { limited_incoming.len() } else { (i + 1) * per };
-                            if start < end {
-                                agent.receive_gossip_from(*neighbor_id, &limited_incoming[start..end]);
-                            }
-                        }
-                    }
+            }
+            
+            swarm_network.deliver_round();
+            
+            for (idx, agent) in agents.iter_mut().enumerate() {
+                let incoming = swarm_network.take_gossip_packets(idx);
+                agent.receive_gossip(&incoming);
+                if gossip_ticks[idx] {
                     agent.clear_recent_packets();
                 }
             }
+            
+            rms.sample(oracle.time(), &agents, &ground_truth);
+            classes.sample(oracle.time(), &agents, &oracle);
+            
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
+            }
+        }
+        
+        let link_stats = swarm_network.total_link_stats();
+        let mut metrics = ScenarioMetrics {
+            packets_sent: swarm_network.messages_sent(),
+            packets_dropped: link_stats.dropped_overflow,
+            bytes_sent: swarm_network.bytes_sent(),
+            bytes_dropped: link_stats.bytes_dropped,
+            priority_classes: classes.metrics(),
+            ..ScenarioMetrics::default()
+        };
+        metrics.record_link_stats(link_stats);
+        let (high, routine) = (&metrics.priority_classes[0], &metrics.priority_classes[1]);
+        let failure = triage_failure(link_stats.preempted, high.rms_mean, max_high_priority_rms);
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  TRIAGE RESULTS:");
+        info!("    Preempted:      {} routine packets ({} dropped)  {}",
+            link_stats.preempted, link_stats.dropped_overflow, if link_stats.preempted > 0 { "✓" } else { "✗" });
+        info!("    High priority:  RMS {:.2}m (p95 {:.2}m, max {:.1}m), coverage {:.0}%  {}",
+            high.rms_mean, high.rms_p95, max_high_priority_rms, high.coverage * 100.0,
+            if high.rms_mean < max_high_priority_rms { "✓" } else { "✗" });
+        info!("    Routine:        RMS {:.2}m (p95 {:.2}m), coverage {:.0}%",
+            routine.rms_mean, routine.rms_p95, routine.coverage * 100.0);
+        info!("    RMS error:      {:.2}m (p95 {:.2}m)", rms.mean(), rms.p95());
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        ScenarioResult {
+            scenario: ScenarioId::Triage,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: oracle.time(),
+            final_entity_count: oracle.active_entities().len(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
+    }
+    
+    /// DST-033: DegradedAltitude - GNSS-like readings, altitude 3x worse
+    /// than the horizontal fix
+    /// 
+    /// Eight drones climb and descend through the field while four sensors
+    /// read them with 0.5m horizontal and 1.5m vertical noise. Each sensor
+    /// feeds two agents the same readings: one whose filter models reading
+    /// variance as isotropic, one told altitude variance is 9x the
+    /// horizontal ([`AgentConfig::vertical_variance_ratio`]). Readings
+    /// claim 0.99 confidence, the variance of the horizontal noise, so the
+    /// anisotropic R matches the sensor on every axis. Both predict with
+    /// constant-velocity tuning, so the gap is down to R alone.
+    /// 
+    /// **Success Criteria**: the anisotropic agents' altitude RMS, once
+    /// settled, at least 20% below the isotropic agents', and their RMS <
+    /// 2.0m.
+    fn run_degraded_altitude(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-033: DegradedAltitude - Altitude Noise 3x Horizontal ✈️");
+        
+        let num_sensors = 4;
+        let num_entities = 8;
+        let (horizontal_noise, vertical_noise): (f64, f64) = (0.5, 1.5);
+        let min_altitude_gain = 0.2;
+        let max_rms = 2.0;
+        let settle_secs = 5.0;
+        // 25m² base variance x (1 - 0.99) = (0.5m)², the horizontal noise
+        let reading_confidence = 0.99;
+        let isotropic = AgentConfig { filter: Some(FilterTuning::default()), ..SimulatedAgent::default_config() };
+        let anisotropic = AgentConfig {
+            vertical_variance_ratio: Some((vertical_noise / horizontal_noise).powi(2)),
+            ..isotropic.clone()
+        };
         
-        let ground_truth = oracle.ground_truth_positions();
+        let mut oracle = Oracle::new(self.seed);
+        oracle.set_position_noise_hv(horizontal_noise, vertical_noise);
+        for sensor in 0..num_sensors {
+            oracle.set_sensor_modality(sensor as u32, SensorModality::Position);
+        }
+        for i in 0..num_entities {
+            // Descending drones stay clear of the ground for the longest run
+            let position = Vector3::new((i % 4) as f64 * 50.0, (i / 4) as f64 * 50.0, 100.0 + 10.0 * i as f64);
+            let climb = if i % 2 == 0 { 1.5 } else { -1.5 };
+            oracle.spawn_entity(position, Vector3::new(4.0, 1.0, climb), "drone");
+        }
         
-        // Filter out bad actors for scoring
-        let good_agent_rms: Vec<f64> = agents.iter().enumerate()
-            .filter(|(idx, _)| *idx >= num_bad_actors)
-            .map(|(_, a)| a.compute_position_error(&ground_truth))
+        // Agents 0..4 model isotropic noise, 4..8 anisotropic; agent i and
+        // i + 4 read from sensor i
+        let key_provider = DeterministicKeyProvider::new(self.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        let mut agents: Vec<SimulatedAgent> = (0..2 * num_sensors)
+            .map(|i| {
+                let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
+                let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
+                let config = if i < num_sensors { isotropic.clone() } else { anisotropic.clone() };
+                let mut agent = SimulatedAgent::new(context, network, root_key, i as u64, config);
+                agent.set_reading_confidence(reading_confidence);
+                agent
+            })
             .collect();
+        
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let run_secs = target_ticks as f64 * dt;
+        let mut rms = self.rms_sampler(run_secs);
+        // Tracks start at rest and learn each climb rate from positions
+        // alone; altitude is compared once both have settled
+        let settled = self.warmup_secs.max(settle_secs).min(run_secs / 2.0);
+        let mut isotropic_altitude = RmsSampler::new(settled);
+        let mut altitude = RmsSampler::new(settled);
+        
+        info!("  Config: {} sensors x 2 agents, {} entities, noise {:.1}m horizontal / {:.1}m vertical, {} ticks",
+            num_sensors, num_entities, horizontal_noise, vertical_noise, target_ticks);
+        
+        let monitor = self.monitor(ScenarioId::DegradedAltitude, target_ticks);
+        
+        for tick in 0..target_ticks {
+            oracle.step(dt);
+            
+            for sensor in 0..num_sensors {
+                let readings = oracle.generate_sensor_readings_from(sensor as u32);
+                for idx in [sensor, sensor + num_sensors] {
+                    if agents[idx].tick() {
+                        agents[idx].ingest_readings(&readings);
+                    }
+                }
+            }
+            
+            let ground_truth = oracle.ground_truth_positions();
+            let (plain, modeled) = agents.split_at(num_sensors);
+            rms.sample(oracle.time(), modeled, &ground_truth);
+            isotropic_altitude.sample_altitude(oracle.time(), plain, &ground_truth);
+            altitude.sample_altitude(oracle.time(), modeled, &ground_truth);
             
-        let avg_rms = good_agent_rms.iter().sum::<f64>() / good_agent_rms.len().max(1) as f64;
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
+            }
+        }
         
-        // Did we survive?
-        let passed = avg_rms < 15.0; // Relaxed threshold due to 50% loss + faults
+        let gain = if isotropic_altitude.mean() > 0.0 { 1.0 - altitude.mean() / isotropic_altitude.mean() } else { 0.0 };
+        let failure = degraded_altitude_failure(gain, min_altitude_gain, rms.failure(max_rms));
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  BLACKOUT RESULTS:");
-        info!("    Survivor RMS:  {:.2}m  {}", avg_rms, if passed { "✓" } else { "✗" });
+        info!("  DEGRADED ALTITUDE RESULTS:");
+        info!("    Isotropic altitude RMS:   {:.2}m (p95 {:.2}m)", isotropic_altitude.mean(), isotropic_altitude.p95());
+        info!("    Anisotropic altitude RMS: {:.2}m (p95 {:.2}m)", altitude.mean(), altitude.p95());
+        info!("    Altitude gain:            {:.0}% (min {:.0}%)  {}", gain * 100.0, min_altitude_gain * 100.0,
+            if gain >= min_altitude_gain { "✓" } else { "✗" });
+        info!("    RMS error:                {:.2}m (p95 {:.2}m)  {}", rms.mean(), rms.p95(), if rms.within(max_rms) { "✓" } else { "✗" });
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
         ScenarioResult {
-            scenario: ScenarioId::BlackoutSurvival,
+            scenario: ScenarioId::DegradedAltitude,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
             final_time_secs: oracle.time(),
             final_entity_count: oracle.active_entities().len(),
-            failure_reason: if !passed { Some(format!("RMS {:.2}m", avg_rms)) } else { None },
-            metrics: ScenarioMetrics::default(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics: ScenarioMetrics {
+                extra: BTreeMap::from([
+                    ("isotropic_altitude_rms_mean".to_string(), isotropic_altitude.mean()),
+                    ("altitude_rms_mean".to_string(), altitude.mean()),
+                    ("altitude_gain".to_string(), gain),
+                ]),
+                ..ScenarioMetrics::default()
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
-
-    /// DST-019: LongHaul - Energy Crisis.
-    ///
-    /// Survive 2000 ticks with limited battery.
-    /// Agents must evolve to speak less (higher gossip interval) to survive.
-    fn run_long_haul(&self) -> ScenarioResult {
-        use crate::evolution::BlindFitness;
-
-        info!("DST-019: LongHaul - THE ENERGY CRISIS 🔋");
+    
+    /// DST-023: MultiSensorBias - per-sensor 3-axis bias calibration
+    /// 
+    /// Every agent carries two sensors with different per-axis biases. Both
+    /// see a surveyed landmark whose position is known, and each agent
+    /// calibrates a separate bias estimate per sensor against it before
+    /// fusing the corrected readings of the unsurveyed targets.
+    /// 
+    /// **Success Criteria**: every agent's estimate for both sensors within
+    /// 0.5m of the true bias, and swarm RMS < 2.0m.
+    fn run_multi_sensor_bias(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-023: MultiSensorBias - Per-Sensor Bias Calibration 🎯");
         
-        // Config
         let num_agents = 10;
-        let start_energy = 1000.0;
-        let mut oracle = Oracle::new(self.seed);
+        let biases = [Vector3::new(5.0, 5.0, 5.0), Vector3::new(-3.0, 2.0, 0.5)];
+        let max_bias_error = 0.5;
         
-        // Spawn some entities to track
-        for i in 0..10 {
+        let mut oracle = Oracle::new(self.seed);
+        for (sensor_id, bias) in biases.iter().enumerate() {
+            oracle.set_sensor_bias(sensor_id as u32, *bias);
+        }
+        let landmark_position = Vector3::new(0.0, 50.0, 100.0);
+        let landmark = oracle.spawn_entity(landmark_position, Vector3::zeros(), "landmark");
+        for i in 0..5 {
             oracle.spawn_entity(
-                Vector3::new((i as f64) * 20.0, 0.0, 100.0),
-                Vector3::new(1.0, 1.0, 0.0),
-                "long_haul_target",
+                Vector3::new((i as f64) * 30.0, 0.0, 100.0),
+                Vector3::zeros(),
+                "bias_target",
             );
         }
         
         let key_provider = DeterministicKeyProvider::new(self.seed);
         let root_key = key_provider.biscuit_root_key().public();
         
-        // Init Agents
         let mut agents: Vec<SimulatedAgent> = (0..num_agents)
             .map(|i| {
                  let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
                  let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-                 let mut agent = SimulatedAgent::new(context, network, root_key.clone(), i as u64, AgentConfig::default());
-                 agent.set_fitness_provider(Box::new(BlindFitness::new()));
-                 agent.consume_energy(850.0); // Start with 150J for fast test
-                 agent
+                 SimulatedAgent::new(context, network, root_key, i as u64, SimulatedAgent::default_config())
             })
             .collect();
-            
+        
         let mut swarm_network = crate::swarm_network::SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = 200;
-        let evo_epoch_ticks = 20; // Faster evolution for test
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
         
-        let _rng = rand::rngs::StdRng::seed_from_u64(self.seed);
+        info!("  Config: {} agents, 5 entities + 1 landmark, {} ticks. Sensor biases: {:?} / {:?}",
+            num_agents, target_ticks, biases[0].as_slice(), biases[1].as_slice());
         
-        info!("  Config: {} agents, 10 entities, {} ticks. Starting Energy: {}J", num_agents, target_ticks, start_energy);
+        let monitor = self.monitor(ScenarioId::MultiSensorBias, target_ticks);
         
         for tick in 0..target_ticks {
             oracle.step(dt);
-            // Limit visible readings to reduce sensor cost pressure (allow some chance)
-            // Or just let them deal with it.
-            // Cost: 0.05 * 10 = 0.5 per tick. 2000 * 0.5 = 1000 J.
-            // They will all die if they process all.
-            // But they can't choose to ignore yet.
-            // I will artificially limit readings in this scenario to 2 entities per agent per tick (randomly).
-            // This represents "Scanning a sector" instead of 360 view.
             
-            let readings = oracle.generate_sensor_readings();
-            let ground_truth = oracle.ground_truth_positions();
+            let sensor_readings: Vec<Vec<SensorReading>> = (0..biases.len())
+                .map(|sensor_id| oracle.generate_sensor_readings_from(sensor_id as u32))
+                .collect();
+            let ground_truth: Vec<_> = oracle.ground_truth_positions()
+                .into_iter()
+                .filter(|(id, _)| *id != landmark)
+                .collect();
             
             for (idx, agent) in agents.iter_mut().enumerate() {
-                // Check if alive handled in tick() internal (returns false if dead)
-                if !agent.tick() {
-                    continue; // Dead
-                }
+                if !agent.tick() { continue; }
                 
-                // Evolution
-                agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
+                let mut my_readings = Vec::new();
+                for readings in &sensor_readings {
+                    let (surveyed, targets): (Vec<_>, Vec<_>) = readings.iter()
+                        .partition(|r| r.entity_id == landmark);
+                    for reading in surveyed {
+                        agent.calibrate_sensor(reading, landmark_position);
+                    }
+                    my_readings.extend(targets.into_iter().skip(idx % 5).take(2).cloned());
+                }
                 
-                // Limited Ingestion (2 readings max)
-                let my_readings: Vec<_> = readings.iter()
-                    .skip(idx % 5) // Simple stagger
-                    .take(2)
-                    .cloned()
-                    .collect();
-                    
                 agent.ingest_readings(&my_readings);
             }
             
-             // Gossip Logic
+            // Gossip
             if tick % 5 == 0 {
-                // Collect packets
                 let all_packets: Vec<_> = agents.iter_mut()
                     .enumerate()
                     .flat_map(|(idx, a)| {
-                        // Check dead status before asking logic
                         if !a.is_alive() { return Vec::new(); }
-                        
-                        // Use new should_broadcast() with Emergency Protocol
                         if a.should_broadcast(tick) {
-                            let packets: Vec<_> = a.recent_packets().iter().map(|p| (idx, p.clone())).collect();
-                            
-                            // Charge Message Cost!
-                            let cost = packets.len() as f64 * 1.0; 
-                            a.consume_energy(cost);
-                            
-                            // Metrics
-                            a.record_message_sent_metric(packets.len() as u64 * 125);
-                            packets
+                            a.recent_packets().iter().map(|p| (idx, p.clone())).collect()
                         } else {
                             Vec::new()
                         }
                     })
                     .collect();
                 
-                // Distribute
                 for (from_idx, packet) in all_packets {
                     swarm_network.queue_gossip(from_idx, packet);
                 }
                 
                 for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                     let incoming = swarm_network.take_gossip(agent_idx);
-                     // Receive & Process
+                     let incoming = swarm_network.take_gossip_packets(agent_idx);
                      agent.receive_gossip(&incoming);
                      agent.clear_recent_packets();
                 }
             }
+            
+            rms.sample(oracle.time(), &agents, &ground_truth);
+            
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
+            }
         }
         
-        // Analysis
-        let final_survivors = agents.iter().filter(|a| a.is_alive()).count();
-        let survival_rate = final_survivors as f64 / num_agents as f64;
-        
-        let survivor_rms: f64 = if final_survivors > 0 {
-             let gt = oracle.ground_truth_positions();
-             let sum_rms: f64 = agents.iter()
-                .filter(|a| a.is_alive())
-                .map(|a| a.compute_position_error(&gt))
-                .sum();
-             sum_rms / final_survivors as f64
-        } else {
-            999.0
-        };
+        // Worst per-sensor estimate error across the swarm
+        let bias_errors: Vec<f64> = biases.iter()
+            .enumerate()
+            .map(|(sensor_id, bias)| {
+                agents.iter()
+                    .map(|a| (a.sensor_bias(sensor_id as u32) - bias).norm())
+                    .fold(0.0, f64::max)
+            })
+            .collect();
         
-        // Success Criteria: > 80% Survivors AND < 5.0m RMS
-        let passed = survival_rate > 0.8 && survivor_rms < 5.0;
+        let worst_bias_error = bias_errors.iter().copied().fold(0.0, f64::max);
+        let failure = multi_sensor_bias_failure(worst_bias_error, max_bias_error, rms.failure(2.0));
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  LONG HAUL RESULTS:");
-        info!("    Survivors:    {}/{} ({:.1}%)", final_survivors, num_agents, survival_rate * 100.0);
-        info!("    Survivor RMS: {:.2}m", survivor_rms);
-        
-        // Print average evolved parameters of survivors
-        if final_survivors > 0 {
-            let avg_interval: f64 = agents.iter()
-                .filter(|a| a.is_alive())
-                .map(|a| a.gossip_interval() as f64)
-                .sum::<f64>() / final_survivors as f64;
-            info!("    Avg Interval: {:.1} ticks (Started at 5.0)", avg_interval);
-        }
-        
+        info!("  MULTI-SENSOR BIAS RESULTS:");
+        info!("    RMS: {:.2}m, p95 {:.2}m (target < 2.0m)", rms.mean(), rms.p95());
+        info!("    Worst bias estimate error: sensor 0 {:.3}m, sensor 1 {:.3}m (target < {}m)",
+            bias_errors[0], bias_errors[1], max_bias_error);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
+        
         ScenarioResult {
-            scenario: ScenarioId::LongHaul,
+            scenario: ScenarioId::MultiSensorBias,
             seed: self.seed,
             passed,
             total_ticks: target_ticks,
             final_time_secs: oracle.time(),
             final_entity_count: 5,
-            failure_reason: if !passed { Some(format!("Survivors: {:.0}%, RMS: {:.2}m", survival_rate*100.0, survivor_rms)) } else { None },
-            metrics: ScenarioMetrics::default(),
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics: ScenarioMetrics {
+                packets_sent: swarm_network.messages_sent(),
+                bytes_sent: swarm_network.bytes_sent(),
+                extra: BTreeMap::from([("worst_bias_error_m".to_string(), worst_bias_error)]),
+                ..ScenarioMetrics::default()
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
         }
     }
     
-    /// DST-020: CommonBias - GPS bias detection via evolution (v0.6.0)
+    /// DST-021: HeavyTail - Cauchy noise stress test (v0.6.0)
     /// 
-    /// All agents receive a +5m GPS offset on sensor readings.
-    /// Tests if agents can evolve `sensor_bias_estimate` to compensate.
+    /// Tests agents evolved on Gaussian noise against heavy-tailed Cauchy noise.
+    /// Cauchy has occasional extreme outliers that challenge tracking filters.
     /// 
-    /// **Success Criteria**: Swarm RMS < 5.0m after evolution.
-    fn run_common_bias(&self) -> ScenarioResult {
+    /// Runs the same seed with plain and robust (Huber-weighted) fusion.
+    /// 
+    /// **Success Criteria**: robust post-warm-up mean RMS < 10.0m and at least
+    /// 30% below the plain run
+    fn run_heavy_tail(&self, timing: RunTiming) -> ScenarioResult {
+        info!("DST-021: HeavyTail - Cauchy Noise Stress Test 📉");
+        
+        let (baseline, _, _, _) = self.heavy_tail_rms(timing, None);
+        let (rms, packets_sent, target_ticks, final_time) = self.heavy_tail_rms(timing, Some(RobustFusion::default()));
+        let improvement = if baseline.mean() > 0.0 { 1.0 - rms.mean() / baseline.mean() } else { 0.0 };
+        
+        // Without robust fusion, Cauchy outliers that miss the gate replace
+        // the track outright and dominate the mean (~12-23m across seeds).
+        let rms_ok = rms.within(10.0);
+        let robust_ok = improvement >= 0.30;
+        let failure = heavy_tail_failure(rms.failure(10.0), improvement);
+        let passed = failure.is_none();
+        
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        info!("  HEAVY TAIL RESULTS:");
+        info!("    Plain RMS:  {:.2}m, p95 {:.2}m", baseline.mean(), baseline.p95());
+        info!("    Robust RMS: {:.2}m, p95 {:.2}m (target < 10.0m)  {}", rms.mean(), rms.p95(), if rms_ok { "✓" } else { "✗" });
+        info!("    Robust gain: {:.0}% (target >= 30%)  {}", improvement * 100.0, if robust_ok { "✓" } else { "✗" });
+        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        
+        ScenarioResult {
+            scenario: ScenarioId::HeavyTail,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: final_time,
+            final_entity_count: 5,
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics: ScenarioMetrics {
+                packets_sent,
+                extra: BTreeMap::from([
+                    ("plain_rms_mean".to_string(), baseline.mean()),
+                    ("robust_gain".to_string(), improvement),
+                ]),
+                ..ScenarioMetrics::default()
+            },
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+    
+    /// Runs the HeavyTail simulation once with the given fusion mode.
+    ///
+    /// Returns the RMS samples, gossip packets sent, ticks run and the final
+    /// simulation time.
+    fn heavy_tail_rms(&self, timing: RunTiming, robust: Option<RobustFusion>) -> (RmsSampler, u64, u64, f64) {
         use crate::evolution::BlindFitness;
-        info!("DST-020: CommonBias - GPS Bias Detection 🎯");
+        use crate::oracle::NoiseModel;
         
         let num_agents = 10;
-        let gps_bias = 5.0; // +5m bias on all readings
-        
-        // Create Oracle with 5 stationary targets
         let mut oracle = Oracle::new(self.seed);
+        
+        // Use Cauchy (heavy-tailed) noise
+        oracle.set_noise_model(NoiseModel::Cauchy);
+        oracle.set_position_noise(1.0); // 1m scale parameter
+        
+        // Spawn 5 stationary targets
         for i in 0..5 {
             oracle.spawn_entity(
                 Vector3::new((i as f64) * 30.0, 0.0, 100.0),
                 Vector3::zeros(),
-                "bias_target",
+                "heavy_target",
             );
         }
         
         let key_provider = DeterministicKeyProvider::new(self.seed);
         let root_key = key_provider.biscuit_root_key().public();
         
-        // Init Agents with BlindFitness
         let mut agents: Vec<SimulatedAgent> = (0..num_agents)
             .map(|i| {
-                 let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
-                 let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-                 let mut agent = SimulatedAgent::new(context, network, root_key.clone(), i as u64, AgentConfig::default());
-                 agent.set_fitness_provider(Box::new(BlindFitness::new()));
-                 agent
+                let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
+                let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
+                let mut agent = SimulatedAgent::new(context, network, root_key, i as u64, AgentConfig { robust_fusion: robust, ..SimulatedAgent::default_config() });
+                agent.set_fitness_provider(Box::new(BlindFitness::new()));
+                agent
             })
             .collect();
-            
+        self.load_trained_params(&mut agents);
+        
         let mut swarm_network = crate::swarm_network::SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = 500; // Longer run for evolution to find bias
-        let evo_epoch_ticks = 50;
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let evo_epoch_ticks = 30;
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        
+        info!("  Config: {} agents, 5 entities, {} ticks. Noise: Cauchy (heavy-tailed), robust fusion: {}",
+            num_agents, target_ticks, if robust.is_some() { "on" } else { "off" });
         
-        info!("  Config: {} agents, 5 entities, {} ticks. GPS Bias: +{}m", num_agents, target_ticks, gps_bias);
+        let monitor = self.monitor(ScenarioId::HeavyTail, target_ticks);
         
         for tick in 0..target_ticks {
             oracle.step(dt);
-            
-            // Get base readings from Oracle
-            let base_readings = oracle.generate_sensor_readings();
+            let readings = oracle.generate_sensor_readings();
             let ground_truth = oracle.ground_truth_positions();
             
             for (idx, agent) in agents.iter_mut().enumerate() {
-                if !agent.tick() { continue; } // Dead
+                if !agent.tick() { continue; }
                 
-                // Get a subset of readings for this agent
-                let my_readings: Vec<_> = base_readings.iter()
+                let my_readings: Vec<_> = readings.iter()
                     .skip(idx % 5)
                     .take(2)
                     .cloned()
                     .collect();
                 
-                // Apply GPS bias to readings
-                let mut biased_readings: Vec<_> = my_readings.into_iter()
-                    .map(|mut r| {
-                        r.position.x += gps_bias;
-                        r.position.y += gps_bias;
-                        r.position.z += gps_bias;
-                        r
-                    })
-                    .collect();
-                
-                // Apply agent's evolved bias compensation
-                let bias_compensation = agent.sensor_bias_estimate();
-                for reading in &mut biased_readings {
-                    reading.position.x -= bias_compensation;
-                    reading.position.y -= bias_compensation;
-                    reading.position.z -= bias_compensation;
-                }
-                
-                agent.ingest_readings(&biased_readings);
-                
-                // Evolution tick
+                agent.ingest_readings(&my_readings);
                 agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
             }
             
@@ -2631,70 +7176,46 @@ impl ScenarioRunner {
                     swarm_network.queue_gossip(from_idx, packet);
                 }
                 
-                for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                     let incoming = swarm_network.take_gossip(agent_idx);
-                     agent.receive_gossip(&incoming);
-                     agent.clear_recent_packets();
-                }
-            }
-        }
-        
-        // Measure final accuracy
-        let gt: Vec<_> = oracle.ground_truth_positions();
-        let avg_rms: f64 = agents.iter()
-            .map(|a| a.compute_position_error(&gt))
-            .sum::<f64>() / num_agents as f64;
-        
-        // Check evolved bias estimates
-        let avg_bias_estimate: f64 = agents.iter()
-            .map(|a| a.sensor_bias_estimate())
-            .sum::<f64>() / num_agents as f64;
-        
-        let passed = avg_rms < 5.0;
-        
-        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  COMMON BIAS RESULTS:");
-        info!("    Final RMS: {:.2}m (target < 5.0m)", avg_rms);
-        info!("    Avg Bias Estimate: {:.2}m (true bias: {}m)", avg_bias_estimate, gps_bias);
-        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        
-        ScenarioResult {
-            scenario: ScenarioId::CommonBias,
-            seed: self.seed,
-            passed,
-            total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: 5,
-            failure_reason: if !passed { Some(format!("RMS: {:.2}m", avg_rms)) } else { None },
-            metrics: ScenarioMetrics::default(),
+                for (agent_idx, agent) in agents.iter_mut().enumerate() {
+                    let incoming = swarm_network.take_gossip_packets(agent_idx);
+                    agent.receive_gossip(&incoming);
+                    agent.clear_recent_packets();
+                }
+            }
+            
+            rms.sample(oracle.time(), &agents, &ground_truth);
+            
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
+            }
         }
+        
+        (rms, swarm_network.messages_sent(), target_ticks, oracle.time())
     }
-    
-    /// DST-021: HeavyTail - Cauchy noise stress test (v0.6.0)
+    /// DST-022: SensorDrift - Gradual degradation (v0.6.0)
     /// 
-    /// Tests agents evolved on Gaussian noise against heavy-tailed Cauchy noise.
-    /// Cauchy has occasional extreme outliers that challenge tracking filters.
+    /// Sensor noise increases over time, simulating degradation or environmental changes.
+    /// Tests whether agents can adapt to non-stationary noise.
     /// 
-    /// **Success Criteria**: RMS < 10.0m (more lenient due to outliers)
-    fn run_heavy_tail(&self) -> ScenarioResult {
+    /// **Success Criteria**: RMS < 8.0m despite 5x noise increase by end
+    fn run_sensor_drift(&self, timing: RunTiming) -> ScenarioResult {
         use crate::evolution::BlindFitness;
-        use crate::oracle::NoiseModel;
         
-        info!("DST-021: HeavyTail - Cauchy Noise Stress Test 📉");
+        info!("DST-022: SensorDrift - Sensor Degradation Over Time 📈");
         
         let num_agents = 10;
         let mut oracle = Oracle::new(self.seed);
         
-        // Use Cauchy (heavy-tailed) noise
-        oracle.set_noise_model(NoiseModel::Cauchy);
-        oracle.set_position_noise(1.0); // 1m scale parameter
+        let initial_noise = 0.5;
+        let final_noise = 2.5; // 5x degradation
+        oracle.set_position_noise(initial_noise);
         
         // Spawn 5 stationary targets
         for i in 0..5 {
             oracle.spawn_entity(
                 Vector3::new((i as f64) * 30.0, 0.0, 100.0),
                 Vector3::zeros(),
-                "heavy_target",
+                "drift_target",
             );
         }
         
@@ -2705,20 +7226,30 @@ impl ScenarioRunner {
             .map(|i| {
                 let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
                 let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-                let mut agent = SimulatedAgent::new(context, network, root_key.clone(), i as u64, AgentConfig::default());
+                let mut agent = SimulatedAgent::new(context, network, root_key, i as u64, SimulatedAgent::default_config());
                 agent.set_fitness_provider(Box::new(BlindFitness::new()));
                 agent
             })
             .collect();
+        self.load_trained_params(&mut agents);
         
         let mut swarm_network = crate::swarm_network::SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = 300;
-        let evo_epoch_ticks = 30;
+        let dt = timing.dt();
+        let target_ticks = timing.target_ticks();
+        let evo_epoch_ticks = 40;
+        let mut rms = self.rms_sampler(target_ticks as f64 * dt);
+        
+        info!("  Config: {} agents, 5 entities, {} ticks. Noise: {:.1}m → {:.1}m", 
+              num_agents, target_ticks, initial_noise, final_noise);
         
-        info!("  Config: {} agents, 5 entities, {} ticks. Noise: Cauchy (heavy-tailed)", num_agents, target_ticks);
+        let monitor = self.monitor(ScenarioId::SensorDrift, target_ticks);
         
         for tick in 0..target_ticks {
+            // Linearly increase noise over time
+            let progress = tick as f64 / target_ticks as f64;
+            let current_noise = initial_noise + (final_noise - initial_noise) * progress;
+            oracle.set_position_noise(current_noise);
+            
             oracle.step(dt);
             let readings = oracle.generate_sensor_readings();
             let ground_truth = oracle.ground_truth_positions();
@@ -2755,184 +7286,1998 @@ impl ScenarioRunner {
                 }
                 
                 for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
+                    let incoming = swarm_network.take_gossip_packets(agent_idx);
                     agent.receive_gossip(&incoming);
                     agent.clear_recent_packets();
                 }
             }
+            
+            rms.sample(oracle.time(), &agents, &ground_truth);
+            
+            if monitor.observe(tick, &oracle, &agents) {
+                break;
+            }
         }
         
-        let gt = oracle.ground_truth_positions();
-        let avg_rms: f64 = agents.iter()
-            .map(|a| a.compute_position_error(&gt))
-            .sum::<f64>() / num_agents as f64;
-        
-        // More lenient threshold due to occasional extreme Cauchy outliers
-        let passed = avg_rms < 10.0;
+        let failure = rms.failure(8.0);
+        let passed = failure.is_none();
         
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  HEAVY TAIL RESULTS:");
-        info!("    Final RMS: {:.2}m (target < 10.0m)", avg_rms);
+        info!("  SENSOR DRIFT RESULTS:");
+        info!("    RMS: {:.2}m, p95 {:.2}m (target < 8.0m)", rms.mean(), rms.p95());
+        info!("    Final Noise: {:.1}m (5x degradation)", final_noise);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
-        ScenarioResult {
-            scenario: ScenarioId::HeavyTail,
-            seed: self.seed,
-            passed,
-            total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: 5,
-            failure_reason: if !passed { Some(format!("RMS: {:.2}m", avg_rms)) } else { None },
-            metrics: ScenarioMetrics::default(),
-        }
+        let mut metrics = ScenarioMetrics {
+            packets_sent: swarm_network.messages_sent(),
+            bytes_sent: swarm_network.bytes_sent(),
+            ..Default::default()
+        };
+        metrics.record_evolved_params(&agents);
+        
+        ScenarioResult {
+            scenario: ScenarioId::SensorDrift,
+            seed: self.seed,
+            passed,
+            total_ticks: target_ticks,
+            final_time_secs: oracle.time(),
+            final_entity_count: 5,
+            failure,
+            rms_mean: rms.mean(),
+            rms_p95: rms.p95(),
+            rms_samples: rms.len(),
+            metrics,
+            duration_clamp: None,
+            aborted: None,
+            registry: MetricsRegistry::default(),
+            origin: RunOrigin::Fresh,
+            thresholds: ThresholdSource::Builtin,
+        }
+    }
+}
+
+/// One AdaptiveSwarm run, for comparing trust inflation curves.
+struct AdaptiveSwarmRun {
+    harness: SwarmHarness,
+    rms: RmsSampler,
+    divergence: DivergenceSampler,
+}
+
+/// One ZombieApocalypse run, for comparing gossip quotas.
+struct ZombieApocalypseRun {
+    harness: SwarmHarness,
+    rms: RmsSampler,
+}
+
+/// Outcome of one ClockSkew run.
+struct ClockSkewRun {
+    rms: RmsSampler,
+    
+    /// Worst offset estimate error (ms; 0.0 unless estimating)
+    offset_error_ms: f64,
+    packets_sent: u64,
+    ticks: u64,
+    final_time_secs: f64,
+}
+
+/// Outcome of one ResourceStarvation run.
+struct StarvationRun {
+    rms: RmsSampler,
+    avg_interval: f64,
+    evolved_params: Vec<EvoParams>,
+    link_stats: LinkStats,
+    messages_sent: u64,
+    bytes_sent: u64,
+    ticks: u64,
+    final_time_secs: f64,
+    final_entity_count: usize,
+}
+
+/// What one BlindLearning or CommonBias episode measured.
+struct LearningEpisode {
+    rms: RmsSampler,
+    
+    /// Swarm RMS averaged over each evolution epoch (m)
+    epoch_rms: Vec<f64>,
+    packets_sent: u64,
+    bytes_sent: u64,
+}
+
+/// Velocity RMS check: the mean and p95 of `samples` within `max_mean`
+/// m/s, with the same p95 slack as position RMS.
+fn velocity_rms_failure(samples: &RmsSampler, max_mean: f64) -> Option<FailureKind> {
+    first_failure([
+        (samples.mean() >= max_mean).then(|| FailureKind::above("velocity_rms_mean", samples.mean(), max_mean)),
+        (samples.p95() >= max_mean * RMS_P95_FACTOR)
+            .then(|| FailureKind::above("velocity_rms_p95", samples.p95(), max_mean * RMS_P95_FACTOR)),
+    ])
+}
+
+/// One SplitBrain partition run in a single gossip mode.
+struct SplitBrainRun {
+    metrics: ScenarioMetrics,
+    failure: Option<FailureKind>,
+    final_time_secs: f64,
+    final_entity_count: usize,
+}
+
+/// How the agents currently see the SplitBrain shared entity.
+#[derive(Debug)]
+struct SplitBrainView {
+    /// Whether every agent's track carries the same canonical ID
+    single_id: bool,
+    
+    /// Smallest canonical ID across agents
+    canonical_id: Uuid,
+    
+    /// Largest pairwise distance between fused positions
+    spread: f64,
+}
+
+/// FlashMob's failure: MOTA under `min_mota`.
+fn flash_mob_failure(mota: f64, min_mota: f64) -> Option<FailureKind> {
+    (mota < min_mota).then(|| FailureKind::below("mota", mota, min_mota))
+}
+
+/// One SlowLoris swarm run at a single loss rate.
+struct SlowLorisRun {
+    spread: LossSpread,
+    rms: RmsSampler,
+    packets_sent: u64,
+    packets_lost: u64,
+    ticks: u64,
+    final_time_secs: f64,
+    final_entity_count: usize,
+}
+
+/// Seconds for a log line, or "-" when there were none.
+fn fmt_secs(secs: Option<f64>) -> String {
+    secs.map_or_else(|| "-".to_string(), |s| format!("{s:.2}s"))
+}
+
+/// SlowLoris's first failed check at its highest loss rate: RMS, then that
+/// every observed entity reached every agent, within the deadline.
+fn slow_loris_failure(rms: Option<FailureKind>, spread: &LossSpread) -> Option<FailureKind> {
+    first_failure([
+        rms,
+        (spread.spread < spread.observed).then(|| FailureKind::expectation(
+            "swarm_wide_tracks",
+            format!("{} of {} observed entities never reached every agent", spread.observed - spread.spread, spread.observed),
+        )),
+        spread.max_spread_secs
+            .filter(|&secs| secs > SLOW_LORIS_SPREAD_DEADLINE_SECS)
+            .map(|secs| FailureKind::above("spread_secs", secs, SLOW_LORIS_SPREAD_DEADLINE_SECS)),
+    ])
+}
+
+/// The first failed check on updated and coasting error: the updated
+/// states' RMS stays under `max_updated_rms`, then coasting error grows no
+/// faster than a track with that error can fall behind an entity moving at
+/// `max_speed` (`d(e²)/dt <= 2 e v`).
+fn coasting_failure(coasting: &CoastingMetrics, max_updated_rms: f64, max_speed: f64) -> Option<FailureKind> {
+    let max_growth = 2.0 * max_updated_rms * max_speed;
+    first_failure([
+        (coasting.updated_rms >= max_updated_rms)
+            .then(|| FailureKind::above("rms_updated", coasting.updated_rms, max_updated_rms)),
+        (coasting.error_growth_m2_per_s >= max_growth)
+            .then(|| FailureKind::above("coasting_error_growth", coasting.error_growth_m2_per_s, max_growth)),
+    ])
+}
+
+/// TimeTornado's first failed check: updated and coasting error (see
+/// [`coasting_failure`]), then that delayed readings were actually
+/// delivered out of sequence.
+fn time_tornado_failure(coasting: Option<FailureKind>, oosm_count: u64) -> Option<FailureKind> {
+    coasting.or_else(|| (oosm_count == 0).then(|| FailureKind::expectation("oosm_updates", "No out-of-sequence readings were delivered")))
+}
+
+/// ZombieApocalypse's first failed check: survivors' RMS, that they
+/// identified [`MIN_DETECTION_RATE`] of possible zombies, then that gossip
+/// quotas brought their RMS (`quota_rms`) under the unlimited run's.
+fn zombie_apocalypse_failure(
+    rms: Option<FailureKind>,
+    detection_rate: f64,
+    quota_rms: f64,
+    unlimited_rms: f64,
+) -> Option<FailureKind> {
+    first_failure([
+        rms,
+        (detection_rate < MIN_DETECTION_RATE)
+            .then_some(FailureKind::DetectionRateTooLow { rate: detection_rate, threshold: MIN_DETECTION_RATE }),
+        (quota_rms.is_nan() || quota_rms >= unlimited_rms)
+            .then(|| FailureKind::above("quota_rms_mean", quota_rms, unlimited_rms)),
+    ])
+}
+
+/// ZombieRestart's first failed check: every planned fault ran and coverage
+/// recovered within `max_recovery_secs` of each, frozen agents stayed
+/// frozen, no gossip was rejected, each live agent's sequence gaps match the
+/// envelopes it missed while down (`gap_mismatches` agents don't), and live
+/// agents' RMS is in range.
+fn zombie_restart_failure(
+    fault_recoveries: &[FaultRecovery],
+    planned_faults: usize,
+    max_recovery_secs: f64,
+    clocks_ok: bool,
+    rejected: u64,
+    gap_mismatches: usize,
+    rms: Option<FailureKind>,
+) -> Option<FailureKind> {
+    let slowest = fault_recoveries.iter()
+        .find(|r| r.recovery_secs.is_none_or(|secs| secs > max_recovery_secs));
+    first_failure([
+        slowest.map(|r| FailureKind::expectation(
+            "fault_recovery",
+            format!("No coverage recovery within {}s after {}", max_recovery_secs, r.fault),
+        )),
+        (fault_recoveries.len() != planned_faults)
+            .then(|| FailureKind::expectation("faults_ran", "Not every planned fault ran")),
+        (!clocks_ok).then(|| FailureKind::invariant("frozen_clock", "A crashed or stalled agent kept ticking")),
+        (rejected > 0).then(|| FailureKind::invariant("gossip_rejected", format!("{} gossip envelopes rejected", rejected))),
+        (gap_mismatches > 0).then(|| FailureKind::invariant(
+            "gossip_gaps",
+            format!("{} agents' sequence gaps don't match the envelopes they missed", gap_mismatches),
+        )),
+        rms,
+    ])
+}
+
+/// BoundaryCrossing's first failed check: some crossing was scored, enough
+/// were handed off, few enough duplicated, the mean gap, then RMS.
+fn boundary_crossing_failure(handoff: &HandoffMetrics, rms: Option<FailureKind>) -> Option<FailureKind> {
+    first_failure([
+        (handoff.crossings == 0)
+            .then(|| FailureKind::expectation("shard_crossings", "No entity crossed from one agent's cells into another's")),
+        (handoff.success_rate() < BOUNDARY_MIN_HANDOFF_RATE)
+            .then(|| FailureKind::below("handoff_success_rate", handoff.success_rate(), BOUNDARY_MIN_HANDOFF_RATE)),
+        (handoff.duplicate_rate() > BOUNDARY_MAX_DUPLICATE_RATE)
+            .then(|| FailureKind::above("handoff_duplicate_rate", handoff.duplicate_rate(), BOUNDARY_MAX_DUPLICATE_RATE)),
+        (handoff.gap_mean_secs > BOUNDARY_MAX_GAP_SECS)
+            .then(|| FailureKind::above("handoff_gap_mean_secs", handoff.gap_mean_secs, BOUNDARY_MAX_GAP_SECS)),
+        rms,
+    ])
+}
+
+/// ParameterShock's first failed check: every planned perturbation ran,
+/// every live agent took each one with a consistent spatial index, none
+/// raised RMS or cost tracks past its limit, then RMS.
+fn parameter_shock_failure(
+    perturbations: &[PerturbationImpact],
+    planned: usize,
+    refusals: usize,
+    index_errors: usize,
+    rms: Option<FailureKind>,
+) -> Option<FailureKind> {
+    let min_track_ratio = 1.0 - PARAMETER_SHOCK_MAX_TRACK_LOSS;
+    let track_ratio = |p: &PerturbationImpact| if p.tracks_before > 0.0 { p.tracks_after / p.tracks_before } else { 1.0 };
+    let rise = perturbations.iter().find(|p| p.rms_delta() > PARAMETER_SHOCK_MAX_RMS_RISE_M);
+    let loss = perturbations.iter().find(|p| track_ratio(p) < min_track_ratio);
+    first_failure([
+        (perturbations.len() != planned)
+            .then(|| FailureKind::expectation("perturbations_ran", "Not every planned perturbation ran")),
+        (refusals > 0).then(|| FailureKind::invariant(
+            "perturbation_refused",
+            format!("{} agents didn't end on the perturbed tracking config", refusals),
+        )),
+        (index_errors > 0).then(|| FailureKind::invariant(
+            "spatial_index",
+            format!("{} spatial indexes disagreed with their tracks after a config swap", index_errors),
+        )),
+        rise.map(|p| FailureKind::above("perturbation_rms_rise", p.rms_delta(), PARAMETER_SHOCK_MAX_RMS_RISE_M)),
+        loss.map(|p| FailureKind::below("perturbation_track_ratio", track_ratio(p), min_track_ratio)),
+        rms,
+    ])
+}
+
+/// ResourceStarvation's first failed check: RMS, agents backing off past the
+/// default 5-tick gossip interval, and prioritized gossip beating FIFO.
+fn resource_starvation_failure(
+    rms: Option<FailureKind>,
+    avg_interval: f64,
+    prioritized_rms: f64,
+    fifo_rms: f64,
+) -> Option<FailureKind> {
+    first_failure([
+        rms,
+        (avg_interval <= 5.0).then(|| FailureKind::below("avg_gossip_interval", avg_interval, 5.0)),
+        (prioritized_rms >= fifo_rms).then(|| FailureKind::above("prioritized_rms_mean", prioritized_rms, fifo_rms)),
+    ])
+}
+
+/// ProtocolDrift's first failed check: no envelope is rejected, V2 packets
+/// reach V1 agents, and V1 agents' RMS stays within `max_rms_ratio` of V2's.
+fn protocol_drift_failure(rejected: u64, downgraded: u64, v1_rms: f64, v2_rms: f64, max_rms_ratio: f64) -> Option<FailureKind> {
+    let ratio = v1_rms / v2_rms.max(f64::EPSILON);
+    first_failure([
+        (rejected > 0).then(|| FailureKind::invariant("gossip_rejected", format!("{} gossip envelopes rejected", rejected))),
+        (downgraded == 0).then(|| FailureKind::expectation("downgraded_packets", "No V2 packets reached a V1 agent")),
+        (ratio > max_rms_ratio).then(|| FailureKind::above("v1_v2_rms_ratio", ratio, max_rms_ratio)),
+    ])
+}
+
+/// MixedFleet's first failed check: RMS is in range and noisy agents' RMS
+/// stays within `max_rms_ratio` of precise agents'.
+fn mixed_fleet_failure(precise_rms: f64, noisy_rms: f64, max_rms_ratio: f64, rms: Option<FailureKind>) -> Option<FailureKind> {
+    let ratio = noisy_rms / precise_rms.max(f64::EPSILON);
+    first_failure([
+        rms,
+        (ratio > max_rms_ratio).then(|| FailureKind::above("noisy_precise_rms_ratio", ratio, max_rms_ratio)),
+    ])
+}
+
+/// Clutter's first failed check: RMS is in range, confirmation suppresses
+/// enough clutter tracks, and leaves fewer false tracks than no confirmation.
+fn clutter_failure(confirming: &ClutterMetrics, baseline: &ClutterMetrics, min_suppression: f64, rms: Option<FailureKind>) -> Option<FailureKind> {
+    let suppression = confirming.suppression();
+    first_failure([
+        rms,
+        (suppression < min_suppression).then(|| FailureKind::below("clutter_suppression", suppression, min_suppression)),
+        (confirming.false_track_rate() >= baseline.false_track_rate()).then(|| FailureKind::above(
+            "clutter_false_track_rate", confirming.false_track_rate(), baseline.false_track_rate())),
+    ])
+}
+
+/// GpsDenied's first failed check: RMS stays in range while GPS is denied,
+/// honest agents flag enough denied neighbors and no honest ones, the swarm
+/// recovers in time once GPS returns, then overall RMS.
+fn gps_denied_failure(
+    stats: &GpsDenialStats,
+    max_rms_during: f64,
+    min_detection_rate: f64,
+    max_recovery_secs: f64,
+    rms: Option<FailureKind>,
+) -> Option<FailureKind> {
+    first_failure([
+        (stats.rms_during >= max_rms_during).then(|| FailureKind::above("rms_gps_denied", stats.rms_during, max_rms_during)),
+        (stats.detection_rate() < min_detection_rate)
+            .then(|| FailureKind::below("offset_detection_rate", stats.detection_rate(), min_detection_rate)),
+        (stats.flagged_honest_pairs > 0).then(|| FailureKind::expectation(
+            "offset_false_flags",
+            format!("Honest agents flagged {} honest neighbors as offset", stats.flagged_honest_pairs),
+        )),
+        match stats.recovery_secs {
+            Some(secs) => (secs > max_recovery_secs).then(|| FailureKind::above("gps_recovery_secs", secs, max_recovery_secs)),
+            None => Some(FailureKind::expectation(
+                "gps_recovery_secs",
+                format!("RMS never got back within {}x of its pre-denial mean", GPS_RECOVERY_FACTOR),
+            )),
+        },
+        rms,
+    ])
+}
+
+/// LongHaul's first failed check: over 80% of agents survive, some RMS was
+/// scored, and it is in range.
+fn long_haul_failure(survival_rate: f64, unscored: bool, rms: Option<FailureKind>) -> Option<FailureKind> {
+    first_failure([
+        (survival_rate <= 0.8).then(|| FailureKind::below("survival_rate", survival_rate, 0.8)),
+        unscored.then(|| FailureKind::expectation("rms_samples", "No live agent was ever scored")),
+        rms,
+    ])
+}
+
+/// ClockSkew's first failed check: RMS grows with uncorrected skew, offset
+/// estimates land within their limit, and compensated RMS stays within its
+/// multiple of the unskewed baseline.
+fn clock_skew_failure(
+    rms_per_100ms: f64,
+    (offset_error_ms, max_offset_error_ms): (f64, f64),
+    (compensated_rms, max_compensated_rms): (f64, f64),
+) -> Option<FailureKind> {
+    first_failure([
+        (rms_per_100ms <= 0.0).then(|| FailureKind::expectation(
+            "skew_degradation",
+            format!("RMS did not grow with skew ({:.2}m per 100ms)", rms_per_100ms),
+        )),
+        (offset_error_ms >= max_offset_error_ms)
+            .then(|| FailureKind::above("offset_error_ms", offset_error_ms, max_offset_error_ms)),
+        (compensated_rms > max_compensated_rms)
+            .then(|| FailureKind::above("compensated_rms", compensated_rms, max_compensated_rms)),
+    ])
+}
+
+/// Triage's first failed check: high-priority packets had to preempt
+/// routine ones, and high-priority RMS is in range.
+fn triage_failure(preempted: u64, high_priority_rms: f64, max_high_priority_rms: f64) -> Option<FailureKind> {
+    first_failure([
+        (preempted == 0).then(|| FailureKind::expectation(
+            "link_preempted",
+            "No high-priority packet preempted a routine one; the links were never full".to_string(),
+        )),
+        (high_priority_rms >= max_high_priority_rms)
+            .then(|| FailureKind::above("rms_priority_high", high_priority_rms, max_high_priority_rms)),
+    ])
+}
+
+/// DegradedAltitude's first failed check: modeling altitude variance cut
+/// altitude RMS by at least `min_gain`, then RMS.
+fn degraded_altitude_failure(gain: f64, min_gain: f64, rms: Option<FailureKind>) -> Option<FailureKind> {
+    (gain < min_gain)
+        .then(|| FailureKind::below("altitude_gain", gain, min_gain))
+        .or(rms)
+}
+
+/// Convoy's first failed check: moving agents changed the topology, no
+/// entity switched tracks, canonical IDs changed at most `max_id_churn`
+/// times per (agent, entity) pair, and RMS is in range.
+fn convoy_failure(
+    link_changes: u64,
+    id_switches: u64,
+    id_churn_max: u64,
+    max_id_churn: u64,
+    rms: Option<FailureKind>,
+) -> Option<FailureKind> {
+    first_failure([
+        (link_changes == 0).then(|| FailureKind::expectation(
+            "link_changes",
+            "No radio link was made or broken as the convoy moved".to_string(),
+        )),
+        (id_switches > 0).then(|| FailureKind::above("mot_id_switches", id_switches as f64, 0.0)),
+        (id_churn_max > max_id_churn).then(|| FailureKind::above("id_churn_max", id_churn_max as f64, max_id_churn as f64)),
+        rms,
+    ])
+}
+
+/// Occlusion's first failed check: the wall hides enough targets, gossip
+/// fills in enough of the hidden ones for every agent, and updated and
+/// coasting error are in range (see [`coasting_failure`]).
+fn occlusion_failure(
+    coverage: &OcclusionCoverage,
+    max_direct_coverage: f64,
+    min_hidden_coverage: f64,
+    coasting: Option<FailureKind>,
+) -> Option<FailureKind> {
+    first_failure([
+        (coverage.direct_coverage > max_direct_coverage)
+            .then(|| FailureKind::above("direct_coverage", coverage.direct_coverage, max_direct_coverage)),
+        (coverage.min_hidden_coverage < min_hidden_coverage)
+            .then(|| FailureKind::below("min_hidden_coverage", coverage.min_hidden_coverage, min_hidden_coverage)),
+        coasting,
+    ])
+}
+
+/// MultiSensorBias's first failed check: every sensor's bias estimate is
+/// within `max_bias_error` for every agent, then RMS.
+fn multi_sensor_bias_failure(worst_bias_error: f64, max_bias_error: f64, rms: Option<FailureKind>) -> Option<FailureKind> {
+    (worst_bias_error >= max_bias_error)
+        .then(|| FailureKind::above("bias_error_m", worst_bias_error, max_bias_error))
+        .or(rms)
+}
+
+/// HeavyTail's first failed check: robust fusion's RMS, then its gain of at
+/// least 30% over plain fusion.
+fn heavy_tail_failure(rms: Option<FailureKind>, improvement: f64) -> Option<FailureKind> {
+    rms.or_else(|| (improvement < 0.30).then(|| FailureKind::below("robust_gain", improvement, 0.30)))
+}
+
+/// `rms`, else a [`FailureKind::PerformanceBudget`] unless `value` is over
+/// `min`.
+fn rms_then_budget(rms: Option<FailureKind>, metric: &str, value: f64, min: f64) -> Option<FailureKind> {
+    rms.or_else(|| (value <= min).then(|| FailureKind::budget(metric, value, min, Bound::Min)))
+}
+
+/// AdaptiveSwarm's first failed check: at least `min_rate` (built in:
+/// [`MIN_DETECTION_RATE`]) of possible bad-actor detections are made (if
+/// there are any), and good agents' RMS is in range.
+fn adaptive_swarm_failure(
+    (detection_rate, min_rate): (f64, f64),
+    possible_detections: usize,
+    rms: Option<FailureKind>,
+) -> Option<FailureKind> {
+    first_failure([
+        (detection_rate < min_rate && possible_detections > 0)
+            .then_some(FailureKind::DetectionRateTooLow { rate: detection_rate, threshold: min_rate }),
+        rms,
+    ])
+}
+
+/// The packets of gossip taken with [`SwarmNetwork::take_gossip_from`],
+/// for agents that don't track provenance.
+fn gossip_packets(incoming: Vec<(usize, GlobalHazardPacket)>) -> Vec<GlobalHazardPacket> {
+    incoming.into_iter().map(|(_, packet)| packet).collect()
+}
+
+/// Spawns Swarm's `count` targets: rows of 50, 20m apart, drifting
+/// east at 10-18 m/s.
+fn spawn_swarm_targets(oracle: &mut Oracle, count: usize) {
+    for i in 0..count {
+        let x = (i % 50) as f64 * 20.0;
+        let y = (i / 50) as f64 * 20.0;
+        let z = 100.0 + (i % 10) as f64 * 10.0;
+        let vx = 10.0 + (i % 5) as f64 * 2.0;
+        let vy = 5.0 * ((i % 3) as f64 - 1.0);
+        oracle.spawn_entity(Vector3::new(x, y, z), Vector3::new(vx, vy, 0.0), "target");
+    }
+}
+
+/// Spawns BlindLearning's 50 targets: a 10 x 5 grid, 50m apart, drifting
+/// together.
+fn spawn_blind_learning_targets(oracle: &mut Oracle) {
+    for i in 0..50 {
+        oracle.spawn_entity(
+            Vector3::new((i % 10) as f64 * 50.0, (i / 10) as f64 * 50.0, 100.0),
+            Vector3::new(5.0, 2.0, 0.0),
+            "blind_target",
+        );
+    }
+}
+
+/// Spawns CommonBias's 5 stationary targets, 30m apart.
+fn spawn_common_bias_targets(oracle: &mut Oracle) {
+    for i in 0..5 {
+        oracle.spawn_entity(
+            Vector3::new((i as f64) * 30.0, 0.0, 100.0),
+            Vector3::zeros(),
+            "bias_target",
+        );
+    }
+}
+
+/// Mean fitness of `agents` over each evolution epoch they completed since
+/// each had `epochs_before` in its history, up to the fewest any completed.
+fn swarm_epoch_fitness(agents: &[SimulatedAgent], epochs_before: &[usize]) -> Vec<f64> {
+    let episode: Vec<&[EpochRecord]> = agents.iter()
+        .zip(epochs_before)
+        .map(|(a, &before)| &a.evolution_history()[before..])
+        .collect();
+    let epochs = episode.iter().map(|h| h.len()).min().unwrap_or(0);
+    (0..epochs)
+        .map(|e| episode.iter().map(|h| h[e].fitness).sum::<f64>() / episode.len() as f64)
+        .collect()
+}
+
+/// Sums agents' gossip sequence gap counters.
+fn sequence_totals<'a>(agents: impl IntoIterator<Item = &'a SimulatedAgent>) -> SequenceStats {
+    let mut totals = SequenceStats::default();
+    for agent in agents {
+        totals.merge(&agent.sequence_stats());
+    }
+    totals
+}
+
+/// Swarm's first failed check: track counts agree (CV under `max_cv`), RMS
+/// and MOTA meet their limits, the partition blocks gossip without leaking
+/// any, and once healed receivers report one sequence gap per envelope it
+/// blocked.
+fn swarm_failure(
+    cv: f64,
+    max_cv: f64,
+    rms: Option<FailureKind>,
+    (mota, min_mota): (f64, f64),
+    partition_blocked: u64,
+    partition_leaks: u64,
+    gaps_detected: u64,
+) -> Option<FailureKind> {
+    first_failure([
+        (cv >= max_cv).then_some(FailureKind::ConvergenceNotReached { cv, threshold: max_cv }),
+        rms,
+        (mota < min_mota).then(|| FailureKind::below("mota", mota, min_mota)),
+        (partition_leaks > 0).then(|| FailureKind::invariant(
+            "partition_leak",
+            format!("{} envelopes crossed the partition", partition_leaks),
+        )),
+        (partition_blocked == 0).then(|| FailureKind::expectation("partition_blocked", "The partition blocked no envelopes")),
+        (gaps_detected != partition_blocked).then(|| FailureKind::invariant(
+            "gossip_gaps",
+            format!("{} sequence gaps reported for {} blocked envelopes", gaps_detected, partition_blocked),
+        )),
+    ])
+}
+
+/// Byzantine's first failed check: keys rotate, only then are stale-key
+/// envelopes rejected, and the rotated honest agent (but not the revoked
+/// one) is heard afterwards.
+fn byzantine_failure(
+    revoked: bool,
+    stale_before_revocation: u64,
+    stale_key_rejections: u64,
+    malicious_accepted_after: u64,
+    rotated_accepted_after: u64,
+) -> Option<FailureKind> {
+    first_failure([
+        (!revoked).then(|| FailureKind::expectation("key_rotation", "Run ended before key rotation")),
+        (stale_before_revocation > 0).then(|| FailureKind::invariant(
+            "stale_key_before_rotation",
+            format!("{} stale-key rejections before rotation", stale_before_revocation),
+        )),
+        (stale_key_rejections == 0).then(|| FailureKind::expectation(
+            "stale_key_rejection",
+            "No stale-key packets rejected after rotation",
+        )),
+        (malicious_accepted_after > 0).then(|| FailureKind::invariant(
+            "revoked_key_accepted",
+            format!("{} envelopes from revoked agent accepted", malicious_accepted_after),
+        )),
+        (rotated_accepted_after == 0).then(|| FailureKind::expectation(
+            "rotated_key_accepted",
+            "Rotated honest agent's envelopes were not accepted",
+        )),
+    ])
+}
+
+/// SplitBrain's first failed check: the groups diverge while partitioned,
+/// then agree on the minimum UUID within `deadline_secs` of the heal.
+fn split_brain_failure(
+    diverged: bool,
+    converged_on_min: bool,
+    final_view: Option<&SplitBrainView>,
+    convergence_secs: Option<f64>,
+    deadline_secs: f64,
+) -> Option<FailureKind> {
+    first_failure([
+        (!diverged).then(|| FailureKind::expectation("partition_divergence", "groups never diverged during the partition")),
+        (!converged_on_min).then(|| FailureKind::expectation(
+            "min_uuid_convergence",
+            format!("agents did not converge on the minimum UUID (final view: {:?})", final_view),
+        )),
+        match convergence_secs {
+            Some(secs) if secs > deadline_secs => Some(FailureKind::above("convergence_time_secs", secs, deadline_secs)),
+            Some(_) => None,
+            None => Some(FailureKind::expectation("convergence", "agents never agreed after the heal")),
+        },
+    ])
+}
+
+/// SplitBrain's anti-entropy check: push-pull gossip converged at least
+/// [`SPLIT_BRAIN_MIN_SPEEDUP`] times faster after the heal than push alone.
+/// Push never converging leaves nothing to compare against, so it fails too.
+fn anti_entropy_failure(push_pull_secs: Option<f64>, push_secs: Option<f64>) -> Option<FailureKind> {
+    let (Some(secs), Some(push_secs)) = (push_pull_secs, push_secs) else {
+        return Some(FailureKind::expectation("push_convergence", "push gossip never converged after the heal"));
+    };
+    let bound = push_secs / SPLIT_BRAIN_MIN_SPEEDUP;
+    (secs > bound).then(|| FailureKind::above("push_pull_convergence_time_secs", secs, bound))
+}
+
+/// SplitBrain's divergence checks: the groups stayed within
+/// [`MAX_PARTITION_DIVERGENCE_M`] while partitioned, and were back within
+/// tolerance `deadline_secs` after the heal.
+fn partition_divergence_failure(divergence: &PartitionDivergence, deadline_secs: f64) -> Option<FailureKind> {
+    first_failure([
+        (divergence.peak_divergence_m > MAX_PARTITION_DIVERGENCE_M).then(|| {
+            FailureKind::above("peak_partition_divergence_m", divergence.peak_divergence_m, MAX_PARTITION_DIVERGENCE_M)
+        }),
+        match divergence.reconverge_secs {
+            Some(secs) if secs > deadline_secs => Some(FailureKind::above("reconverge_secs", secs, deadline_secs)),
+            Some(_) => None,
+            None => Some(FailureKind::expectation("reconvergence", "group positions never came back together after the heal")),
+        },
+    ])
+}
+
+/// Compares the mean fused position of agents `..half` (group A) against
+/// the rest (group B) for `entity_id`; None until both groups track it.
+fn partition_sample(
+    agents: &[SimulatedAgent],
+    half: usize,
+    entity_id: u64,
+    time_secs: f64,
+    partitioned: bool,
+) -> Option<PartitionSample> {
+    let mean_position = |group: &[SimulatedAgent]| {
+        let positions: Vec<Vector3<f64>> = group.iter()
+            .filter_map(|a| a.track_for_entity(entity_id))
+            .map(|t| t.position())
+            .collect();
+        (!positions.is_empty()).then(|| positions.iter().sum::<Vector3<f64>>() / positions.len() as f64)
+    };
+    let (group_a, group_b) = agents.split_at(half);
+    let divergence_m = (mean_position(group_a)? - mean_position(group_b)?).norm();
+    let canonical_ids = agents.iter()
+        .filter_map(|a| a.track_for_entity(entity_id))
+        .map(|t| t.canonical_id)
+        .collect::<HashSet<_>>()
+        .len();
+    Some(PartitionSample { time_secs, divergence_m, canonical_ids, partitioned })
+}
+
+/// Collects each agent's track for `entity_id`; None until all agents have one.
+fn split_brain_view(agents: &[SimulatedAgent], entity_id: u64) -> Option<SplitBrainView> {
+    let tracks: Vec<_> = agents.iter()
+        .map(|a| a.track_for_entity(entity_id))
+        .collect::<Option<_>>()?;
+    
+    let canonical_id = tracks.iter().map(|t| t.canonical_id).min()?;
+    let single_id = tracks.iter().all(|t| t.canonical_id == canonical_id);
+    let spread = tracks.iter()
+        .flat_map(|a| tracks.iter().map(move |b| (a.position() - b.position()).norm()))
+        .fold(0.0, f64::max);
+    
+    Some(SplitBrainView { single_id, canonical_id, spread })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration::Threshold;
+    use crate::delta_gossip::DeltaCompression;
+    use crate::faults::{ConfigPerturbation, Perturbation};
+    use crate::reputation_gossip::{ReputationSharing, REPUTATION_ENTRY_BYTES};
+    use crate::scenarios::ClampReason;
+    
+    /// Seed-42 post-warm-up mean RMS for TimeWarp over 3s, with reorder and
+    /// duplicate injection on the sensor link and constant-velocity
+    /// prediction (0.49 without the faults).
+    const GOLDEN_TIME_WARP_MEAN: f64 = 0.672;
+    
+    /// Seed-42 post-warm-up mean RMS for RapidFire over 2s.
+    const GOLDEN_RAPID_FIRE_MEAN: f64 = 0.735;
+    
+    /// Seed-42 post-warm-up mean RMS over 1s for the swarm scenarios on
+    /// `SwarmHarness`, shrunk to a 2x3 grid tracking 20 entities so they
+    /// fit a debug test run.
+    const GOLDEN_HARNESS_SWARMS: [(ScenarioId, f64); 4] = [
+        (ScenarioId::AdaptiveSwarm, 0.593),
+        (ScenarioId::ChaosStorm, 1.343),
+        (ScenarioId::NetworkHell, 1.140),
+        (ScenarioId::ZombieApocalypse, 0.417),
+    ];
+    
+    /// Seed-42 post-warm-up mean RMS for Swarm over 1s on a 2x3 grid
+    /// tracking 20 entities.
+    const GOLDEN_SWARM_MEAN: f64 = 0.598;
+    
+    /// Seed-42 post-warm-up mean RMS for BlackoutSurvival over 2s on the
+    /// harness.
+    const GOLDEN_BLACKOUT_SURVIVAL_MEAN: f64 = 2.298;
+    
+    #[test]
+    fn test_time_warp_scenario() {
+        let runner = ScenarioRunner::new(42, 6)
+            .with_duration(1.0); // 1 second for fast test
+        
+        let result = runner.run(ScenarioId::TimeWarp).unwrap();
+        
+        assert!(result.passed);
+        assert!(result.metrics.oosm_updates > 0);
+        assert!(result.metrics.tracks_final_total > 0);
+        assert_eq!(result.metrics.rms_mean, result.rms_mean);
+        assert!(result.metrics.velocity_rms_mean.is_some_and(|rms| rms < TIME_WARP_MAX_VELOCITY_RMS));
+        assert_eq!(result.registry.gauge("velocity_rms_mean"), result.metrics.velocity_rms_mean);
+        assert_eq!(result.registry.counter("tracks_final_total"), result.metrics.tracks_final_total);
+    }
+    
+    #[test]
+    fn test_every_scenario_respects_duration_within_bounds() {
+        for scenario in ScenarioId::all() {
+            let declared = scenario.timing();
+            assert!(declared.min_duration_secs <= declared.default_duration_secs, "{scenario}");
+            assert!(declared.default_duration_secs <= declared.max_duration_secs, "{scenario}");
+            
+            for requested in [declared.min_duration_secs, declared.default_duration_secs, declared.max_duration_secs] {
+                let (timing, clamp) = ScenarioRunner::new(42, 6).with_duration(requested).timing(scenario);
+                assert_eq!(clamp, None, "{scenario}");
+                assert_eq!(timing.duration_secs, requested, "{scenario}");
+                assert_eq!(timing.target_ticks(), (requested * declared.tick_rate_hz as f64).round() as u64, "{scenario}");
+            }
+        }
+    }
+    
+    #[test]
+    fn test_total_ticks_match_duration_times_tick_rate() {
+        // The heaviest swarms are too slow for debug builds; they take
+        // target_ticks from the same RunTiming as the rest
+        let heavy = [
+            ScenarioId::Swarm,
+            ScenarioId::AdaptiveSwarm,
+            ScenarioId::ChaosStorm,
+            ScenarioId::ScaleLimit,
+            ScenarioId::NetworkHell,
+            ScenarioId::ZombieApocalypse,
+        ];
+        
+        for scenario in ScenarioId::all().into_iter().filter(|s| !heavy.contains(s)) {
+            let timing = scenario.timing();
+            let result = ScenarioRunner::new(42, 6)
+                .with_duration(timing.min_duration_secs)
+                .run(scenario).unwrap();
+            
+            let expected = (timing.min_duration_secs * timing.tick_rate_hz as f64).round() as u64;
+            assert_eq!(result.total_ticks, expected, "{scenario}");
+            assert_eq!(result.duration_clamp, None, "{scenario}");
+        }
+    }
+    
+    #[test]
+    fn test_out_of_bounds_duration_is_clamped_and_recorded() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(0.2)
+            .run(ScenarioId::TimeWarp).unwrap();
+        assert_eq!(result.total_ticks, 15);
+        let clamp = result.duration_clamp.expect("short request should be clamped");
+        assert_eq!(clamp.reason, ClampReason::BelowMinimum);
+        assert_eq!(clamp.applied_secs, 0.5);
+        
+        let timing = ScenarioId::RapidFire.timing();
+        let (secs, clamp) = timing.resolve(Some(60.0));
+        assert_eq!(secs, timing.max_duration_secs);
+        assert_eq!(clamp.unwrap().reason, ClampReason::AboveMaximum);
+        
+        assert_eq!(timing.resolve(None), (timing.default_duration_secs, None));
+        assert_eq!(timing.resolve(Some(5.0)), (5.0, None));
+    }
+    
+    #[test]
+    fn test_degenerate_configs_are_errors_not_panics() {
+        let degenerate = || [
+            ScenarioRunner::new(42, 0),
+            ScenarioRunner::new(42, 6).with_duration(0.0),
+            ScenarioRunner::new(42, 6).with_duration(-1.0),
+            ScenarioRunner::new(42, 6).with_duration(f64::NAN),
+            ScenarioRunner::new(42, 6).with_tick_rate(0),
+            ScenarioRunner::new(42, 6).with_epoch_ticks(0),
+        ];
+        for scenario in ScenarioId::all() {
+            for runner in degenerate() {
+                let result = runner.run(scenario);
+                assert!(matches!(result, Err(ScenarioError::InvalidConfig { scenario: s, .. }) if s == scenario),
+                    "{}: {:?}", scenario, result.map(|r| r.total_ticks));
+            }
+        }
+        
+        // One agent is too few for the scenarios sized by the runner
+        for scenario in [ScenarioId::SplitBrain, ScenarioId::Byzantine, ScenarioId::SlowLoris] {
+            assert!(matches!(ScenarioRunner::new(42, 1).run(scenario), Err(ScenarioError::InvalidConfig { .. })));
+        }
+        let err = ScenarioRunner::new(42, 2).run(ScenarioId::Byzantine).unwrap_err();
+        assert_eq!(err.to_string(), "byzantine: invalid configuration: needs at least 3 agents, got 2");
+        // A sweep reports it as a failed run
+        let errored = ScenarioResult::errored(ScenarioId::Byzantine, 7, &err);
+        assert!(!errored.passed);
+        assert_eq!(errored.to_json()["failure"]["kind"], "run_error");
+        assert_eq!(errored.failure_reason().unwrap(), err.to_string());
+        
+        // Swarm grids with no agents, gossip or honest agents
+        let swarm = |config: SwarmConfig| ScenarioRunner::new(42, 6).with_duration(1.0).with_swarm_config(config);
+        for (scenario, config) in [
+            (ScenarioId::Swarm, SwarmConfig { rows: 0, ..SwarmConfig::default() }),
+            (ScenarioId::Swarm, SwarmConfig { gossip_interval: 0, ..SwarmConfig::default() }),
+            (ScenarioId::AdaptiveSwarm, SwarmConfig { rows: 1, cols: 1, num_bad_actors: 1, ..SwarmConfig::default() }),
+            (ScenarioId::NetworkHell, SwarmConfig { cols: 0, ..SwarmConfig::default() }),
+            (ScenarioId::ChaosStorm, SwarmConfig { rows: 1, cols: 1, ..SwarmConfig::default() }),
+        ] {
+            assert!(matches!(swarm(config).run(scenario), Err(ScenarioError::InvalidConfig { .. })), "{}", scenario);
+        }
+        
+        // A swarm with nothing to track is the oracle's to report
+        for scenario in [ScenarioId::Swarm, ScenarioId::AdaptiveSwarm] {
+            let empty = SwarmConfig { rows: 2, cols: 2, num_entities: 0, num_bad_actors: 1, ..SwarmConfig::default() };
+            assert!(matches!(swarm(empty).run(scenario), Err(ScenarioError::OracleError { .. })), "{}", scenario);
+        }
+    }
+    
+    #[test]
+    fn test_progress_reported_every_interval() {
+        let reports = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(1.0)
+            .with_progress_interval(10)
+            .with_progress(Box::new(move |report| sink.lock().unwrap().push(report)))
+            .run(ScenarioId::TimeWarp).unwrap();
+        
+        let reports = reports.lock().unwrap();
+        let ticks: Vec<u64> = reports.iter().map(|r| r.tick).collect();
+        assert_eq!(ticks, vec![10, 20, 30]);
+        assert!(reports.iter().all(|r| r.scenario == ScenarioId::TimeWarp && r.agents == 1));
+        assert_eq!(reports[2].fraction(), 1.0);
+        assert!(reports[2].total_tracks > 0);
+        assert!(reports[2].memory_estimate_bytes > 0);
+        assert!(result.aborted.is_none());
+        
+        // Hooks observe the run without changing it
+        let plain = ScenarioRunner::new(42, 6).with_duration(1.0).run(ScenarioId::TimeWarp).unwrap();
+        assert_eq!(result.rms_mean, plain.rms_mean);
+    }
+    
+    #[test]
+    fn test_time_warp_json_reports_the_seeded_rms() {
+        // python/tests/test_smoke.py asserts the same value through the bindings
+        let result = ScenarioRunner::new(42, 6).with_duration(1.0).run(ScenarioId::TimeWarp).unwrap();
+        assert_eq!(result.rms_mean, 0.7625483875814048);
+        
+        let json = result.to_json();
+        assert_eq!(json["scenario"], "time_warp");
+        assert_eq!(json["seed"], 42);
+        assert_eq!(json["rms_mean"].as_f64(), Some(result.rms_mean));
+        assert_eq!(json["ticks"], result.total_ticks);
+        assert!(json["metrics"]["gauges"].is_object());
+    }
+    
+    #[test]
+    fn test_rng_audit_localizes_an_extra_draw() {
+        use rand::Rng;
+        
+        let audited = |inject: bool| {
+            let runner = ScenarioRunner::new(42, 6)
+                .with_duration(1.0)
+                .with_rng_audit(1)
+                .with_progress_interval(10)
+                .with_progress(Box::new(move |report| {
+                    // Progress runs on the scenario's thread, so this stream
+                    // shares the oracle's counter
+                    if inject && report.tick == 20 {
+                        let _: u64 = AuditedRng::derive(0, "oracle/noise").gen();
+                    }
+                }));
+            let result = runner.run(ScenarioId::TimeWarp).unwrap();
+            (result, runner.take_rng_trace().unwrap())
+        };
+        let (result, trace) = audited(false);
+        let (_, again) = audited(false);
+        let (_, injected) = audited(true);
+        
+        assert_eq!((trace.scenario.as_str(), trace.seed, trace.snapshots.len()), ("time_warp", 42, 30));
+        assert!(trace.snapshots[29].draws["oracle/noise"] > 0);
+        assert_eq!(trace.first_divergence(&again), None);
+        
+        // Drawn after tick 19's snapshot, so first seen in tick 20's
+        let divergence = trace.first_divergence(&injected).unwrap();
+        assert_eq!((divergence.tick, divergence.label.as_str()), (20, "oracle/noise"));
+        assert_eq!(divergence.right, divergence.left.map(|n| n + 1));
+        
+        // Auditing doesn't change the run
+        let plain = ScenarioRunner::new(42, 6).with_duration(1.0).run(ScenarioId::TimeWarp).unwrap();
+        assert_eq!(result.rms_mean, plain.rms_mean);
+    }
+    
+    #[test]
+    fn test_realtime_pacing_only_adds_latency() {
+        let arrivals = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = arrivals.clone();
+        let start = std::time::Instant::now();
+        let paced = ScenarioRunner::new(42, 6)
+            .with_duration(1.0)
+            .with_realtime(20.0)
+            .with_progress_interval(10)
+            .with_progress(Box::new(move |report| sink.lock().unwrap().push((report.time_secs, start.elapsed()))))
+            .run(ScenarioId::TimeWarp).unwrap();
+        
+        // Each report waits until its virtual time is due at 20x
+        let arrivals = arrivals.lock().unwrap();
+        assert_eq!(arrivals.len(), 3);
+        for &(time_secs, wall) in arrivals.iter() {
+            assert!(wall.as_secs_f64() >= time_secs / 20.0, "{time_secs}s arrived after {wall:?}");
+        }
+        
+        let plain = ScenarioRunner::new(42, 6)
+            .with_duration(1.0)
+            .with_progress_interval(10)
+            .with_progress(Box::new(|_| {}))
+            .run(ScenarioId::TimeWarp).unwrap();
+        assert_eq!(paced.passed, plain.passed);
+        assert_eq!(paced.total_ticks, plain.total_ticks);
+        assert_eq!(paced.final_time_secs, plain.final_time_secs);
+        assert_eq!((paced.rms_mean, paced.rms_p95, paced.rms_samples), (plain.rms_mean, plain.rms_p95, plain.rms_samples));
+        assert_eq!(paced.metrics.packets_sent, plain.metrics.packets_sent);
+        assert_eq!(paced.metrics.oosm_updates, plain.metrics.oosm_updates);
+    }
+    
+    #[test]
+    fn test_abort_predicate_ends_run_with_partial_metrics() {
+        let runner = ScenarioRunner::new(42, 6)
+            .with_duration(3.0)
+            .with_progress_interval(15)
+            .with_abort_if(Box::new(|report| report.tick >= 45));
+        let result = runner.run(ScenarioId::TimeWarp).unwrap();
+        
+        assert!(!result.passed);
+        assert_eq!(result.total_ticks, 45);
+        assert!((result.final_time_secs - 1.5).abs() < 1e-9);
+        assert_eq!(result.aborted.map(|r| r.tick), Some(45));
+        assert!(matches!(result.failure, Some(FailureKind::Aborted { tick: 45, .. })));
+        assert!(result.failure_reason().unwrap().starts_with("Aborted at t=1.5s"));
+        
+        // Abort state resets between runs: the next scenario stops at its own
+        // tick 45, not on its first tick
+        let result = runner.run(ScenarioId::SlowLoris).unwrap();
+        assert_eq!(result.aborted.map(|r| r.tick), Some(45));
+        assert_eq!(result.total_ticks, 45);
+    }
+    
+    #[test]
+    fn test_interrupt_ends_run_after_current_tick() {
+        let dir = std::env::temp_dir().join(format!("godview-interrupt-{}", std::process::id()));
+        let interrupt = Interrupt::new();
+        let signal = interrupt.clone();
+        let runner = ScenarioRunner::new(42, 6)
+            .with_duration(3.0)
+            .with_progress_interval(15)
+            .with_progress(Box::new(move |report| if report.tick == 30 { signal.trigger() }))
+            .with_failure_capture(CaptureConfig::new(&dir))
+            .with_interrupt(interrupt);
+        let result = runner.run(ScenarioId::TimeWarp).unwrap();
+        
+        // Set during tick 30's report, seen at the end of tick 31
+        assert_eq!(result.aborted.map(|r| r.tick), Some(31));
+        assert!(matches!(result.failure, Some(FailureKind::Aborted { tick: 31, .. })));
+        assert!(!dir.exists(), "an interrupted run isn't a failure worth capturing");
+        
+        // Runs started after the interrupt stop on their first tick
+        assert_eq!(runner.run(ScenarioId::SlowLoris).unwrap().total_ticks, 1);
+    }
+    
+    #[test]
+    fn test_non_finite_rms_always_fails() {
+        let mut result = ScenarioRunner::new(42, 6).with_duration(1.0).run(ScenarioId::TimeWarp).unwrap();
+        assert!(result.passed);
+        assert_eq!(result.metrics.numerical_faults, 0);
+        assert!(!result.fail_on_non_finite_rms());
+        
+        // NaN would pass a `rms > max` check; it fails regardless
+        result.rms_mean = f64::NAN;
+        result.metrics.numerical_faults = 3;
+        assert!(result.fail_on_non_finite_rms());
+        assert!(!result.passed);
+        assert!(matches!(result.failure, Some(FailureKind::NumericalInstability { faults: 3, .. })));
+        assert!(result.failure_reason().unwrap().contains("after 3 numerical faults"));
+    }
+    
+    #[test]
+    fn test_memory_peaks_recorded_and_budgeted() {
+        let mut result = ScenarioRunner::new(42, 6).with_duration(1.0).run(ScenarioId::Byzantine).unwrap();
+        let m = &result.metrics;
+        assert!(m.peak_agent_memory_bytes > 0);
+        assert!(m.peak_total_memory_bytes > m.peak_agent_memory_bytes, "{m:?}");
+        assert!(m.peak_total_memory_bytes <= 6 * m.peak_agent_memory_bytes);
+        assert_eq!(result.registry.counter("peak_agent_memory_bytes"), m.peak_agent_memory_bytes);
+        
+        let (peak, passed) = (m.peak_agent_memory_bytes, result.passed);
+        assert!(!result.fail_over_memory_budget(peak));
+        assert_eq!(result.passed, passed);
+        assert!(result.fail_over_memory_budget(peak - 1));
+        assert!(!result.passed);
+        assert_eq!(result.failure.as_ref().map(FailureKind::label), Some("performance_budget"));
+        assert!(result.failure_reason().unwrap().contains("over budget"));
+    }
+    
+    #[test]
+    fn test_failure_paths_produce_kinds() {
+        fn label(failure: Option<FailureKind>) -> Option<&'static str> {
+            failure.as_ref().map(FailureKind::label)
+        }
+        let rms = || Some(FailureKind::RmsExceeded { mean: 9.0, p95: 12.0, threshold: 5.0 });
+        
+        // RMS-only scenarios
+        let sampler = RmsSampler { warmup_secs: 0.0, samples: vec![1.0, 2.0, 3.0] };
+        assert_eq!(sampler.failure(5.0), None);
+        assert_eq!(
+            sampler.failure(1.5),
+            Some(FailureKind::RmsExceeded { mean: 2.0, p95: sampler.p95(), threshold: 1.5 }),
+        );
+        
+        assert_eq!(label(flash_mob_failure(0.4, 0.6)), Some("threshold_missed"));
+        assert_eq!(flash_mob_failure(0.7, 0.6), None);
+        let spread = |spread, max_spread_secs| LossSpread { loss_rate: 0.5, observed: 5, spread, mean_spread_secs: max_spread_secs, max_spread_secs };
+        assert_eq!(label(slow_loris_failure(rms(), &spread(5, Some(0.2)))), Some("rms_exceeded"));
+        assert_eq!(label(slow_loris_failure(None, &spread(4, Some(0.2)))), Some("expectation_failed"));
+        assert_eq!(slow_loris_failure(None, &spread(5, Some(1.5))), Some(FailureKind::above("spread_secs", 1.5, SLOW_LORIS_SPREAD_DEADLINE_SECS)));
+        assert_eq!(slow_loris_failure(None, &spread(5, Some(0.2))), None);
+        assert_eq!(label(time_tornado_failure(rms(), 0)), Some("rms_exceeded"));
+        assert_eq!(label(time_tornado_failure(None, 0)), Some("expectation_failed"));
+        assert_eq!(time_tornado_failure(None, 3), None);
+        
+        let coasting = |updated_rms, error_growth_m2_per_s| CoastingMetrics { updated_rms, error_growth_m2_per_s, ..Default::default() };
+        assert_eq!(coasting_failure(&coasting(1.0, 11.0), 2.0, 3.0), None);
+        assert_eq!(coasting_failure(&coasting(2.5, 11.0), 2.0, 3.0), Some(FailureKind::above("rms_updated", 2.5, 2.0)));
+        assert_eq!(coasting_failure(&coasting(1.0, 13.0), 2.0, 3.0),
+            Some(FailureKind::above("coasting_error_growth", 13.0, 12.0)));
+        assert_eq!(label(zombie_apocalypse_failure(None, 0.1, 1.0, 2.0)), Some("detection_rate_too_low"));
+        assert_eq!(label(zombie_apocalypse_failure(None, 0.5, 1.0, 2.0)), Some("detection_rate_too_low"));
+        assert_eq!(zombie_apocalypse_failure(None, 1.0, 2.0, 2.0), Some(FailureKind::above("quota_rms_mean", 2.0, 2.0)));
+        assert_eq!(zombie_apocalypse_failure(None, 1.0, 1.0, 2.0), None);
+        
+        let crash = Fault::Crash { at_tick: 10, agent: 0 };
+        let recovered = |secs| FaultRecovery { fault: crash, coverage_before: 1.0, coverage_min: 0.5, recovery_secs: secs };
+        assert_eq!(label(zombie_restart_failure(&[recovered(None)], 1, 5.0, true, 0, 0, None)), Some("expectation_failed"));
+        assert_eq!(label(zombie_restart_failure(&[recovered(Some(9.0))], 1, 5.0, true, 0, 0, None)), Some("expectation_failed"));
+        assert_eq!(label(zombie_restart_failure(&[], 1, 5.0, true, 0, 0, None)), Some("expectation_failed"));
+        assert_eq!(label(zombie_restart_failure(&[recovered(Some(1.0))], 1, 5.0, false, 0, 0, None)), Some("invariant_violated"));
+        assert_eq!(label(zombie_restart_failure(&[recovered(Some(1.0))], 1, 5.0, true, 2, 0, None)), Some("invariant_violated"));
+        assert_eq!(label(zombie_restart_failure(&[recovered(Some(1.0))], 1, 5.0, true, 0, 0, rms())), Some("rms_exceeded"));
+        assert_eq!(label(zombie_restart_failure(&[recovered(Some(1.0))], 1, 5.0, true, 0, 1, None)), Some("invariant_violated"));
+        assert_eq!(zombie_restart_failure(&[recovered(Some(1.0))], 1, 5.0, true, 0, 0, None), None);
+        
+        assert_eq!(label(resource_starvation_failure(rms(), 8.0, 1.0, 2.0)), Some("rms_exceeded"));
+        assert_eq!(label(resource_starvation_failure(None, 5.0, 1.0, 2.0)), Some("threshold_missed"));
+        assert_eq!(label(resource_starvation_failure(None, 8.0, 2.0, 1.0)), Some("threshold_missed"));
+        assert_eq!(resource_starvation_failure(None, 8.0, 1.0, 2.0), None);
+        
+        assert_eq!(label(protocol_drift_failure(1, 5, 1.0, 1.0, 2.0)), Some("invariant_violated"));
+        assert_eq!(label(protocol_drift_failure(0, 0, 1.0, 1.0, 2.0)), Some("expectation_failed"));
+        assert_eq!(label(protocol_drift_failure(0, 5, 3.0, 1.0, 2.0)), Some("threshold_missed"));
+        assert_eq!(protocol_drift_failure(0, 5, 1.0, 1.0, 2.0), None);
+        
+        assert_eq!(long_haul_failure(0.5, false, None), Some(FailureKind::below("survival_rate", 0.5, 0.8)));
+        assert_eq!(label(long_haul_failure(0.9, true, None)), Some("expectation_failed"));
+        assert_eq!(label(long_haul_failure(0.9, false, rms())), Some("rms_exceeded"));
+        
+        assert_eq!(label(clock_skew_failure(0.0, (1.0, 5.0), (1.0, 2.0))), Some("expectation_failed"));
+        assert_eq!(label(clock_skew_failure(0.5, (6.0, 5.0), (1.0, 2.0))), Some("threshold_missed"));
+        assert_eq!(label(clock_skew_failure(0.5, (1.0, 5.0), (3.0, 2.0))), Some("threshold_missed"));
+        assert_eq!(clock_skew_failure(0.5, (1.0, 5.0), (1.0, 2.0)), None);
+        
+        let coverage = OcclusionCoverage { direct_coverage: 0.6, min_hidden_coverage: 0.8, ..Default::default() };
+        assert_eq!(occlusion_failure(&coverage, 0.9, 0.5, None), None);
+        assert_eq!(occlusion_failure(&coverage, 0.5, 0.5, None), Some(FailureKind::above("direct_coverage", 0.6, 0.5)));
+        assert_eq!(occlusion_failure(&coverage, 0.9, 0.9, None), Some(FailureKind::below("min_hidden_coverage", 0.8, 0.9)));
+        assert_eq!(label(occlusion_failure(&coverage, 0.9, 0.5, rms())), Some("rms_exceeded"));
+        
+        let clutter = |tracks, confirmed, false_tracks| ClutterMetrics {
+            clutter_tracks: tracks, confirmed_clutter_tracks: confirmed, frames: 10, false_tracks, ..Default::default()
+        };
+        assert_eq!(clutter_failure(&clutter(10, 0, 0), &clutter(10, 10, 50), 0.9, None), None);
+        assert_eq!(clutter_failure(&clutter(10, 2, 0), &clutter(10, 10, 50), 0.9, None),
+            Some(FailureKind::below("clutter_suppression", 0.8, 0.9)));
+        assert_eq!(label(clutter_failure(&clutter(10, 0, 50), &clutter(10, 10, 50), 0.9, None)), Some("threshold_missed"));
+        assert_eq!(label(clutter_failure(&clutter(10, 0, 0), &clutter(10, 10, 50), 0.9, rms())), Some("rms_exceeded"));
+        
+        let denial = |rms_during, flagged, flagged_honest, recovery_secs| GpsDenialStats {
+            rms_during, denied_pairs: 10, flagged_denied_pairs: flagged, flagged_honest_pairs: flagged_honest, recovery_secs,
+            ..Default::default()
+        };
+        assert_eq!(gps_denied_failure(&denial(1.0, 9, 0, Some(1.0)), 3.0, 0.8, 3.0, None), None);
+        assert_eq!(gps_denied_failure(&denial(4.0, 9, 0, Some(1.0)), 3.0, 0.8, 3.0, None),
+            Some(FailureKind::above("rms_gps_denied", 4.0, 3.0)));
+        assert_eq!(gps_denied_failure(&denial(1.0, 5, 0, Some(1.0)), 3.0, 0.8, 3.0, None),
+            Some(FailureKind::below("offset_detection_rate", 0.5, 0.8)));
+        assert_eq!(label(gps_denied_failure(&denial(1.0, 9, 1, Some(1.0)), 3.0, 0.8, 3.0, None)), Some("expectation_failed"));
+        assert_eq!(gps_denied_failure(&denial(1.0, 9, 0, Some(5.0)), 3.0, 0.8, 3.0, None),
+            Some(FailureKind::above("gps_recovery_secs", 5.0, 3.0)));
+        assert_eq!(label(gps_denied_failure(&denial(1.0, 9, 0, None), 3.0, 0.8, 3.0, None)), Some("expectation_failed"));
+        assert_eq!(label(gps_denied_failure(&denial(1.0, 9, 0, Some(1.0)), 3.0, 0.8, 3.0, rms())), Some("rms_exceeded"));
+        
+        assert_eq!(mixed_fleet_failure(1.0, 4.0, 4.5, None), None);
+        assert_eq!(mixed_fleet_failure(1.0, 5.0, 4.5, None), Some(FailureKind::above("noisy_precise_rms_ratio", 5.0, 4.5)));
+        assert_eq!(label(mixed_fleet_failure(1.0, 5.0, 4.5, rms())), Some("rms_exceeded"));
+        
+        assert_eq!(label(multi_sensor_bias_failure(2.0, 1.0, rms())), Some("threshold_missed"));
+        assert_eq!(label(multi_sensor_bias_failure(0.5, 1.0, rms())), Some("rms_exceeded"));
+        assert_eq!(label(heavy_tail_failure(None, 0.1)), Some("threshold_missed"));
+        assert_eq!(heavy_tail_failure(None, 0.5), None);
+        assert_eq!(degraded_altitude_failure(0.05, 0.15, None), Some(FailureKind::below("altitude_gain", 0.05, 0.15)));
+        assert_eq!(label(degraded_altitude_failure(0.3, 0.15, rms())), Some("rms_exceeded"));
+        assert_eq!(degraded_altitude_failure(0.3, 0.15, None), None);
+        let shock = |rms_after: f64, tracks_after: f64| PerturbationImpact {
+            perturbation: Perturbation { at_tick: 100, change: ConfigPerturbation::GatingThreshold { factor: 0.5 } },
+            rms_before: 1.0,
+            rms_after,
+            tracks_before: 20.0,
+            tracks_after,
+        };
+        assert_eq!(parameter_shock_failure(&[shock(1.2, 19.0)], 1, 0, 0, None), None);
+        assert_eq!(label(parameter_shock_failure(&[], 1, 0, 0, None)), Some("expectation_failed"));
+        assert_eq!(label(parameter_shock_failure(&[shock(1.2, 19.0)], 1, 2, 0, None)), Some("invariant_violated"));
+        assert_eq!(label(parameter_shock_failure(&[shock(1.2, 19.0)], 1, 0, 1, None)), Some("invariant_violated"));
+        assert_eq!(
+            parameter_shock_failure(&[shock(2.5, 19.0)], 1, 0, 0, None),
+            Some(FailureKind::above("perturbation_rms_rise", 1.5, PARAMETER_SHOCK_MAX_RMS_RISE_M))
+        );
+        assert_eq!(label(parameter_shock_failure(&[shock(1.2, 15.0)], 1, 0, 0, None)), Some("threshold_missed"));
+        assert_eq!(label(parameter_shock_failure(&[shock(1.2, 19.0)], 1, 0, 0, rms())), Some("rms_exceeded"));
+        let handoff = |handed_off: u64, duplicated: u64, gap_mean_secs: f64| HandoffMetrics {
+            crossings: 20,
+            handed_off,
+            duplicated,
+            gap_mean_secs,
+            gap_max_secs: gap_mean_secs,
+        };
+        assert_eq!(boundary_crossing_failure(&handoff(20, 1, 0.1), None), None);
+        assert_eq!(label(boundary_crossing_failure(&HandoffMetrics::default(), None)), Some("expectation_failed"));
+        assert_eq!(
+            boundary_crossing_failure(&handoff(18, 0, 0.1), None),
+            Some(FailureKind::below("handoff_success_rate", 0.9, BOUNDARY_MIN_HANDOFF_RATE))
+        );
+        assert_eq!(label(boundary_crossing_failure(&handoff(20, 2, 0.1), None)), Some("threshold_missed"));
+        assert_eq!(label(boundary_crossing_failure(&handoff(20, 0, 0.5), None)), Some("threshold_missed"));
+        assert_eq!(label(boundary_crossing_failure(&handoff(20, 0, 0.1), rms())), Some("rms_exceeded"));
+        
+        // ScaleLimit and RapidFire budget their throughput
+        assert_eq!(
+            rms_then_budget(None, "ticks_per_sec", 4.0, 10.0),
+            Some(FailureKind::budget("ticks_per_sec", 4.0, 10.0, Bound::Min)),
+        );
+        assert_eq!(label(rms_then_budget(rms(), "ticks_per_sec", 4.0, 10.0)), Some("rms_exceeded"));
+        assert_eq!(rms_then_budget(None, "ticks_per_sec", 40.0, 10.0), None);
+        
+        assert_eq!(label(adaptive_swarm_failure((0.1, MIN_DETECTION_RATE), 4, None)), Some("detection_rate_too_low"));
+        assert_eq!(adaptive_swarm_failure((0.1, MIN_DETECTION_RATE), 0, None), None);
+        assert_eq!(label(adaptive_swarm_failure((0.5, MIN_DETECTION_RATE), 4, None)), Some("detection_rate_too_low"));
+        assert_eq!(label(adaptive_swarm_failure((1.0, MIN_DETECTION_RATE), 4, rms())), Some("rms_exceeded"));
+        
+        assert_eq!(label(swarm_failure(0.5, 0.2, None, (0.9, 0.6), 3, 0, 3)), Some("convergence_not_reached"));
+        assert_eq!(label(swarm_failure(0.1, 0.2, rms(), (0.9, 0.6), 3, 0, 3)), Some("rms_exceeded"));
+        assert_eq!(label(swarm_failure(0.1, 0.2, None, (0.3, 0.6), 3, 0, 3)), Some("threshold_missed"));
+        assert_eq!(label(swarm_failure(0.1, 0.2, None, (0.9, 0.6), 3, 1, 3)), Some("invariant_violated"));
+        assert_eq!(label(swarm_failure(0.1, 0.2, None, (0.9, 0.6), 0, 0, 0)), Some("expectation_failed"));
+        assert_eq!(label(swarm_failure(0.1, 0.2, None, (0.9, 0.6), 3, 0, 2)), Some("invariant_violated"));
+        assert_eq!(swarm_failure(0.1, 0.2, None, (0.9, 0.6), 3, 0, 3), None);
+        
+        assert_eq!(label(byzantine_failure(false, 0, 1, 0, 1)), Some("expectation_failed"));
+        assert_eq!(label(byzantine_failure(true, 2, 1, 0, 1)), Some("invariant_violated"));
+        assert_eq!(label(byzantine_failure(true, 0, 0, 0, 1)), Some("expectation_failed"));
+        assert_eq!(label(byzantine_failure(true, 0, 1, 3, 1)), Some("invariant_violated"));
+        assert_eq!(label(byzantine_failure(true, 0, 1, 0, 0)), Some("expectation_failed"));
+        assert_eq!(byzantine_failure(true, 0, 1, 0, 1), None);
+        
+        assert_eq!(label(split_brain_failure(false, true, None, Some(1.0), 5.0)), Some("expectation_failed"));
+        assert_eq!(label(split_brain_failure(true, false, None, Some(1.0), 5.0)), Some("expectation_failed"));
+        assert_eq!(
+            split_brain_failure(true, true, None, Some(8.0), 5.0),
+            Some(FailureKind::above("convergence_time_secs", 8.0, 5.0)),
+        );
+        assert_eq!(label(split_brain_failure(true, true, None, None, 5.0)), Some("expectation_failed"));
+        assert_eq!(split_brain_failure(true, true, None, Some(1.0), 5.0), None);
+        
+        let divergence = |peak, reconverge_secs| PartitionDivergence { peak_divergence_m: peak, reconverge_secs, ..Default::default() };
+        assert_eq!(
+            partition_divergence_failure(&divergence(12.0, Some(1.0)), 5.0),
+            Some(FailureKind::above("peak_partition_divergence_m", 12.0, MAX_PARTITION_DIVERGENCE_M)),
+        );
+        assert_eq!(label(partition_divergence_failure(&divergence(1.0, Some(8.0)), 5.0)), Some("threshold_missed"));
+        assert_eq!(label(partition_divergence_failure(&divergence(1.0, None), 5.0)), Some("expectation_failed"));
+        assert_eq!(partition_divergence_failure(&divergence(1.0, Some(1.0)), 5.0), None);
+        
+        assert_eq!(
+            anti_entropy_failure(Some(1.5), Some(2.0)),
+            Some(FailureKind::above("push_pull_convergence_time_secs", 1.5, 2.0 / SPLIT_BRAIN_MIN_SPEEDUP)),
+        );
+        assert_eq!(anti_entropy_failure(Some(0.5), Some(2.0)), None);
+        assert_eq!(label(anti_entropy_failure(Some(0.5), None)), Some("expectation_failed"), "push never converged");
+        assert_eq!(label(anti_entropy_failure(None, Some(2.0))), Some("expectation_failed"));
+        
+        let velocity = RmsSampler { warmup_secs: 0.0, samples: vec![0.5, 0.5, 5.0] };
+        assert_eq!(velocity_rms_failure(&velocity, 5.0), None);
+        assert_eq!(velocity_rms_failure(&velocity, 2.0), Some(FailureKind::above("velocity_rms_mean", 2.0, 2.0)));
+        assert_eq!(label(velocity_rms_failure(&velocity, 2.1)), Some("threshold_missed"));
     }
     
-    /// DST-022: SensorDrift - Gradual degradation (v0.6.0)
-    /// 
-    /// Sensor noise increases over time, simulating degradation or environmental changes.
-    /// Tests whether agents can adapt to non-stationary noise.
-    /// 
-    /// **Success Criteria**: RMS < 8.0m despite 5x noise increase by end
-    fn run_sensor_drift(&self) -> ScenarioResult {
-        use crate::evolution::BlindFitness;
-        
-        info!("DST-022: SensorDrift - Sensor Degradation Over Time 📈");
-        
-        let num_agents = 10;
-        let mut oracle = Oracle::new(self.seed);
+    #[test]
+    fn test_failure_capture_centers_the_violation() {
+        let dir = std::env::temp_dir().join(format!("godview-capture-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        
+        // A 1-byte budget breaks on the first memory sample (tick 9) and fails the run
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(2.0)
+            .with_agent_memory_budget(1)
+            .with_failure_capture(CaptureConfig::new(&dir).with_window_secs(0.2))
+            .run(ScenarioId::Byzantine).unwrap();
+        assert!(!result.passed);
+        
+        let path = dir.join(crate::capture::capture_file_name("byzantine", 42, 9));
+        let json = std::fs::read_to_string(&path);
+        std::fs::remove_dir_all(&dir).ok();
+        let export: SimExport = serde_json::from_str(&json.unwrap()).unwrap();
+        
+        let violation = export.violation.unwrap();
+        assert_eq!((violation.tick, violation.invariant.as_str()), (9, "memory_budget"));
+        let ticks: Vec<u64> = export.frames.iter().filter_map(|f| f.tick).collect();
+        assert_eq!(ticks.len(), 13, "{ticks:?}");
+        assert_eq!(ticks[ticks.len() / 2], violation.tick);
+        assert!(export.frames.iter().flat_map(|f| &f.agents).all(|a| a.track_count.is_some() && a.tracks.is_empty()));
+    }
+    
+    #[test]
+    fn test_rms_sampler_excludes_warmup() {
+        let mut sampler = RmsSampler::new(1.0);
+        assert!(sampler.is_empty());
+        
+        // Samples are only taken once agents exist; an empty iterator adds nothing
+        sampler.sample(2.0, std::iter::empty(), &[]);
+        assert!(sampler.is_empty());
+        
+        sampler.samples = (1..=20).map(|i| i as f64).collect();
+        assert_eq!(sampler.len(), 20);
+        assert!((sampler.mean() - 10.5).abs() < 1e-9);
+        assert_eq!(sampler.p95(), 19.0);
+        assert!(sampler.within(11.0));
+        assert!(!sampler.within(10.0));
+    }
+    
+    #[test]
+    fn test_warmup_capped_for_short_runs() {
+        // Default 2s warm-up is capped at half of a 1s run: 15 of 30 ticks scored
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(1.0)
+            .run(ScenarioId::TimeWarp).unwrap();
+        assert_eq!(result.rms_samples, 15);
+        
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(1.0)
+            .with_warmup_secs(0.0)
+            .run(ScenarioId::TimeWarp).unwrap();
+        assert_eq!(result.rms_samples, 30);
+    }
+    
+    #[test]
+    fn test_time_warp_golden_seed() {
+        // Regression guard: seed 42 must keep scoring where it did once its
+        // tracks were predicted (p95 0.978)
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(3.0)
+            .run(ScenarioId::TimeWarp).unwrap();
         
-        let initial_noise = 0.5;
-        let final_noise = 2.5; // 5x degradation
-        oracle.set_position_noise(initial_noise);
+        assert!(result.passed);
+        assert!((result.rms_mean - GOLDEN_TIME_WARP_MEAN).abs() < 0.05, "rms_mean={}", result.rms_mean);
+        assert!(result.rms_p95 >= result.rms_mean);
+        assert!(result.rms_p95 < 1.5, "rms_p95={}", result.rms_p95);
+    }
+    
+    #[test]
+    fn test_rapid_fire_golden_seed() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(2.0)
+            .run(ScenarioId::RapidFire).unwrap();
         
-        // Spawn 5 stationary targets
-        for i in 0..5 {
-            oracle.spawn_entity(
-                Vector3::new((i as f64) * 30.0, 0.0, 100.0),
-                Vector3::zeros(),
-                "drift_target",
-            );
+        assert!((result.rms_mean - GOLDEN_RAPID_FIRE_MEAN).abs() < 0.05, "rms_mean={}", result.rms_mean);
+        assert!(result.rms_p95 >= result.rms_mean);
+    }
+    
+    #[test]
+    fn test_carried_params_keep_raising_episode_fitness() {
+        let runner = ScenarioRunner::new(42, 6).with_duration(2.0);
+        let fresh = runner.run_episodes(ScenarioId::BlindLearning, 3, ResetPolicy::Fresh).unwrap();
+        let carried = runner.run_episodes(ScenarioId::BlindLearning, 3, ResetPolicy::KeepParams).unwrap();
+        
+        // The first episode is the same single run under either policy
+        assert_eq!(fresh.episodes.len(), 3);
+        assert_eq!(carried.episodes.len(), 3);
+        assert_eq!(fresh.metrics.curves[0], carried.metrics.curves[0]);
+        assert_eq!(fresh.episodes[0].rms_mean, runner.run(ScenarioId::BlindLearning).unwrap().rms_mean);
+        
+        // Rebuilt agents start over every episode; carried ones climb on
+        let (fresh_fitness, carried_fitness) = (&fresh.metrics.episode_fitness, &carried.metrics.episode_fitness);
+        assert!(carried_fitness.windows(2).all(|w| w[1] > w[0]), "{carried_fitness:?}");
+        for episode in 1..3 {
+            assert!(carried_fitness[episode] > fresh_fitness[episode], "{carried_fitness:?} vs {fresh_fitness:?}");
+        }
+        assert!(carried_fitness[2] - carried_fitness[0] > 2.0 * (fresh_fitness[2] - fresh_fitness[0]).abs(),
+            "{carried_fitness:?} vs {fresh_fitness:?}");
+        
+        // With 2-tick epochs, carried episodes settle in fewer epochs than
+        // the single run and than rebuilt agents in the same worlds
+        for (scenario, secs) in [(ScenarioId::BlindLearning, 4.0), (ScenarioId::CommonBias, 8.0)] {
+            let runner = ScenarioRunner::new(42, 6).with_duration(secs).with_epoch_ticks(2);
+            let epochs = |policy| {
+                let result = runner.run_episodes(scenario, 3, policy).unwrap();
+                result.metrics.curves.iter().map(|c| c.epochs_to_convergence.unwrap()).collect::<Vec<_>>()
+            };
+            let (fresh, carried) = (epochs(ResetPolicy::Fresh), epochs(ResetPolicy::KeepParams));
+            assert_eq!(fresh[0], carried[0]);
+            for episode in 1..3 {
+                assert!(carried[episode] < carried[0] && carried[episode] < fresh[episode],
+                    "{scenario}: {carried:?} vs {fresh:?}");
+            }
         }
         
-        let key_provider = DeterministicKeyProvider::new(self.seed);
-        let root_key = key_provider.biscuit_root_key().public();
-        
-        let mut agents: Vec<SimulatedAgent> = (0..num_agents)
-            .map(|i| {
-                let context = Arc::new(SimContext::new(self.seed.wrapping_add(i as u64)));
-                let network = Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64)));
-                let mut agent = SimulatedAgent::new(context, network, root_key.clone(), i as u64, AgentConfig::default());
-                agent.set_fitness_provider(Box::new(BlindFitness::new()));
-                agent
-            })
-            .collect();
-        
-        let mut swarm_network = crate::swarm_network::SwarmNetwork::new_grid(5, 10);
-        let dt = 0.1;
-        let target_ticks = 400;
-        let evo_epoch_ticks = 40;
+        assert!(matches!(
+            runner.run_episodes(ScenarioId::Swarm, 2, ResetPolicy::KeepParams),
+            Err(ScenarioError::InvalidConfig { .. })
+        ));
+        assert!(runner.run_episodes(ScenarioId::CommonBias, 0, ResetPolicy::Fresh).is_err());
+    }
+    
+    #[test]
+    fn test_campaign_phases_share_one_world() {
+        let config = SwarmConfig { rows: 2, cols: 5, num_entities: 20, ..SwarmConfig::default() };
+        let phase = |scenario| CampaignPhase { scenario, duration_secs: 1.0 };
+        let campaign = Campaign::new(vec![
+            phase(ScenarioId::Swarm),
+            phase(ScenarioId::ChaosStorm),
+            phase(ScenarioId::SplitBrain),
+            phase(ScenarioId::Swarm),
+        ]).unwrap();
+        let result = ScenarioRunner::new(42, 6)
+            .with_warmup_secs(0.2)
+            .with_swarm_config(config)
+            .run_campaign(&campaign);
+        
+        assert!(result.passed(), "{:?}", result.phases.iter().map(|p| p.failure_reason()).collect::<Vec<_>>());
+        let scenarios: Vec<ScenarioId> = result.phases.iter().map(|p| p.scenario).collect();
+        assert_eq!(scenarios, campaign.phases().iter().map(|p| p.scenario).collect::<Vec<_>>());
+        for phase in &result.phases {
+            assert_eq!(phase.total_ticks, 30);
+            assert!((phase.final_time_secs - 1.0).abs() < 1e-6);
+        }
         
-        info!("  Config: {} agents, 5 entities, {} ticks. Noise: {:.1}m → {:.1}m", 
-              num_agents, target_ticks, initial_noise, final_noise);
+        // Chaos loses packets and the partition blocks them; both are lifted
+        // before the next phase
+        assert!(result.phases[1].metrics.packets_dropped > 0);
+        assert!(result.phases[2].metrics.packets_dropped > 0);
+        assert_eq!(result.phases[3].metrics.packets_dropped, 0);
+        assert_eq!(result.metrics.phase_rms, result.phases.iter().map(|p| p.rms_mean).collect::<Vec<_>>());
+    }
+    
+    #[test]
+    fn test_swarm_reports_id_churn() {
+        let config = SwarmConfig { rows: 2, cols: 3, num_entities: 20, ..SwarmConfig::default() };
+        let result = ScenarioRunner::new(42, 6)
+            .with_warmup_secs(0.5)
+            .with_swarm_config(config)
+            .with_duration(2.0)
+            .run(ScenarioId::Swarm).unwrap();
+        
+        // Every agent's view of an entity starts under its own UUID, so gossip
+        // renames some of them after warm-up
+        let churn = result.metrics.id_churn.expect("Swarm is MOT-scored");
+        assert!(churn.tracked > 0);
+        assert!(churn.max >= 1 && churn.mean > 0.0, "{churn:?}");
+        assert_eq!(result.registry.gauge("id_churn_max"), Some(churn.max as f64));
+    }
+    
+    #[test]
+    fn test_adaptive_swarm_injects_exactly_its_bad_actors() {
+        let config = SwarmConfig {
+            rows: 2,
+            cols: 5,
+            num_entities: 20,
+            num_bad_actors: 4,
+            bad_actor_inject_secs: 0.2,
+            ..SwarmConfig::default()
+        };
+        let run = |seed| {
+            ScenarioRunner::new(seed, 6)
+                .with_duration(0.5)
+                .with_swarm_config(config.clone())
+                .run(ScenarioId::AdaptiveSwarm).unwrap()
+        };
         
-        for tick in 0..target_ticks {
-            // Linearly increase noise over time
-            let progress = tick as f64 / target_ticks as f64;
-            let current_noise = initial_noise + (final_noise - initial_noise) * progress;
-            oracle.set_position_noise(current_noise);
-            
-            oracle.step(dt);
-            let readings = oracle.generate_sensor_readings();
-            let ground_truth = oracle.ground_truth_positions();
-            
-            for (idx, agent) in agents.iter_mut().enumerate() {
-                if !agent.tick() { continue; }
-                
-                let my_readings: Vec<_> = readings.iter()
-                    .skip(idx % 5)
-                    .take(2)
-                    .cloned()
-                    .collect();
-                
-                agent.ingest_readings(&my_readings);
-                agent.tick_evolution(evo_epoch_ticks, Some(&ground_truth));
-            }
+        // Many seeds are covered by the harness's own draw test; these check
+        // the scenario wiring
+        for seed in 0..2 {
+            let result = run(seed);
+            let injection = result.metrics.bad_actor_injection.clone().expect("bad actors injected");
+            let mut ids = injection.ids.clone();
+            ids.sort_unstable();
+            ids.dedup();
+            assert_eq!(ids.len(), 4, "seed {seed}: {:?}", injection.ids);
+            assert_eq!(injection.tick, 6, "seed {seed}");
             
-            // Gossip
-            if tick % 5 == 0 {
-                let all_packets: Vec<_> = agents.iter_mut()
-                    .enumerate()
-                    .flat_map(|(idx, a)| {
-                        if !a.is_alive() { return Vec::new(); }
-                        if a.should_broadcast(tick) {
-                            a.recent_packets().iter().map(|p| (idx, p.clone())).collect()
-                        } else {
-                            Vec::new()
-                        }
-                    })
-                    .collect();
-                
-                for (from_idx, packet) in all_packets {
-                    swarm_network.queue_gossip(from_idx, packet);
-                }
-                
-                for (agent_idx, agent) in agents.iter_mut().enumerate() {
-                    let incoming = swarm_network.take_gossip(agent_idx);
-                    agent.receive_gossip(&incoming);
-                    agent.clear_recent_packets();
-                }
+            if seed == 0 {
+                let again = run(seed);
+                assert_eq!(again.metrics.bad_actor_injection, Some(injection));
+                assert_eq!((again.rms_mean, again.rms_p95), (result.rms_mean, result.rms_p95));
             }
         }
-        
-        let gt = oracle.ground_truth_positions();
-        let avg_rms: f64 = agents.iter()
-            .map(|a| a.compute_position_error(&gt))
-            .sum::<f64>() / num_agents as f64;
-        
-        let passed = avg_rms < 8.0;
-        
-        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        info!("  SENSOR DRIFT RESULTS:");
-        info!("    Final RMS: {:.2}m (target < 8.0m)", avg_rms);
-        info!("    Final Noise: {:.1}m (5x degradation)", final_noise);
-        info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        
-        ScenarioResult {
-            scenario: ScenarioId::SensorDrift,
-            seed: self.seed,
-            passed,
-            total_ticks: target_ticks,
-            final_time_secs: oracle.time(),
-            final_entity_count: 5,
-            failure_reason: if !passed { Some(format!("RMS: {:.2}m", avg_rms)) } else { None },
-            metrics: ScenarioMetrics::default(),
+    }
+    
+    #[test]
+    fn test_adaptive_swarm_reports_reputation_sharing() {
+        let config = SwarmConfig {
+            rows: 2,
+            cols: 5,
+            num_entities: 20,
+            num_bad_actors: 2,
+            bad_actor_inject_secs: 0.2,
+            reputation_sharing: Some(ReputationSharing::new(3)),
+            ..SwarmConfig::default()
+        };
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(0.5)
+            .with_swarm_config(config.clone())
+            .run(ScenarioId::AdaptiveSwarm).unwrap();
+        let extra = |name: &str| result.metrics.extra[name];
+        assert!(extra("reputation_messages") > 0.0);
+        assert_eq!(extra("reputation_bytes") % REPUTATION_ENTRY_BYTES as f64, 0.0);
+        assert!((0.0..=1.0).contains(&extra("consensus_accuracy")));
+        
+        let silent = ScenarioRunner::new(42, 6)
+            .with_duration(0.5)
+            .with_swarm_config(SwarmConfig { reputation_sharing: None, ..config })
+            .run(ScenarioId::AdaptiveSwarm).unwrap();
+        assert_eq!(silent.metrics.extra["reputation_messages"], 0.0);
+    }
+    
+    #[test]
+    fn test_adaptive_swarm_reports_delta_gossip_compression() {
+        let config = SwarmConfig { rows: 2, cols: 3, num_entities: 10, ..SwarmConfig::default() };
+        let run = |config: SwarmConfig| ScenarioRunner::new(42, 6)
+            .with_duration(1.5)
+            .with_swarm_config(config)
+            .run(ScenarioId::AdaptiveSwarm).unwrap();
+        let plain = run(config.clone());
+        let compression = DeltaCompression::default();
+        let compressed = run(SwarmConfig { delta_gossip: Some(compression), ..config });
+        
+        assert!(plain.metrics.gossip_compression.is_none());
+        let stats = compressed.metrics.gossip_compression.unwrap();
+        assert!(stats.delta_frames > stats.full_frames);
+        assert_eq!(stats.frame_bytes, compressed.metrics.bytes_sent);
+        assert!(compressed.metrics.bytes_sent < plain.metrics.bytes_sent);
+        assert!(stats.ratio() > 1.5, "ratio {}", stats.ratio());
+        assert_eq!(compressed.registry.gauge("gossip_compression_ratio"), Some(stats.ratio()));
+        
+        // Rebuilt packets are off by at most half a quantum, and so is what
+        // agents fuse from them
+        let drift = (compressed.rms_mean - plain.rms_mean).abs();
+        assert!(drift < compression.position_bound(), "rms {} vs {}", compressed.rms_mean, plain.rms_mean);
+    }
+    
+    #[test]
+    fn test_parameter_shock_reports_every_perturbation() {
+        let result = ScenarioRunner::new(42, 12)
+            .with_duration(8.0)
+            .run(ScenarioId::ParameterShock).unwrap();
+        let ticks: Vec<u64> = result.metrics.perturbations.iter().map(|p| p.perturbation.at_tick).collect();
+        assert_eq!(ticks, vec![20, 40, 60]);
+        for (i, impact) in result.metrics.perturbations.iter().enumerate() {
+            assert_eq!(result.registry.gauge(&format!("rms_delta_perturbation_{i}")), Some(impact.rms_delta()));
+            assert_eq!(result.registry.gauge(&format!("track_delta_perturbation_{i}")), Some(impact.track_delta()));
         }
+        assert!(result.passed, "{:?}", result.failure);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
     
     #[test]
-    fn test_time_warp_scenario() {
-        let runner = ScenarioRunner::new(42, 6)
-            .with_duration(1.0); // 1 second for fast test
-        
-        let result = runner.run(ScenarioId::TimeWarp);
+    fn test_boundary_crossing_hands_tracks_off() {
+        let result = ScenarioRunner::new(42, 4)
+            .with_duration(10.0)
+            .run(ScenarioId::BoundaryCrossing).unwrap();
+        let handoff = result.metrics.handoff.unwrap();
+        assert!(handoff.crossings > 0);
+        assert!(result.metrics.shard_stats.unwrap().handoffs_sent > 0);
+        assert_eq!(result.registry.gauge("handoff_success_rate"), Some(handoff.success_rate()));
+        assert!(result.passed, "{:?}", result.failure);
+    }
+    
+    #[test]
+    fn test_harness_swarms_golden_seed() {
+        let config = SwarmConfig { rows: 2, cols: 3, num_entities: 20, ..SwarmConfig::default() };
+        for (scenario, golden) in GOLDEN_HARNESS_SWARMS {
+            let result = ScenarioRunner::new(42, 6)
+                .with_swarm_config(config.clone())
+                .with_duration(1.0)
+                .run(scenario).unwrap();
+            assert!((result.rms_mean - golden).abs() < 0.05, "{scenario}: rms_mean={}", result.rms_mean);
+        }
+    }
+    
+    #[test]
+    fn test_calibrated_thresholds_replace_swarm_limits() {
+        let config = SwarmConfig { rows: 2, cols: 3, num_entities: 20, ..SwarmConfig::default() };
+        let runner = || ScenarioRunner::new(42, 6)
+            .with_warmup_secs(0.5)
+            .with_swarm_config(config.clone())
+            .with_duration(2.0);
+        let builtin = runner().run(ScenarioId::Swarm).unwrap();
+        assert_eq!(builtin.thresholds, ThresholdSource::Builtin);
+        
+        // Fitted to the run itself with a margin, every calibrated metric
+        // passes the same run
+        let fitted = CalibratedThresholds::calibrate(ScenarioId::Swarm, std::slice::from_ref(&builtin), 100.0, 0.1).unwrap();
+        assert_eq!(fitted.thresholds.len(), 4);
+        let rerun = runner().with_thresholds(fitted.clone()).run(ScenarioId::Swarm).unwrap();
+        assert_eq!(rerun.thresholds, fitted.source());
+        assert_eq!(rerun.rms_mean, builtin.rms_mean);
+        assert!(!matches!(rerun.failure, Some(FailureKind::RmsExceeded { .. } | FailureKind::ConvergenceNotReached { .. })),
+            "{:?}", rerun.failure);
+        assert!(!matches!(&rerun.failure, Some(FailureKind::ThresholdMissed { metric, .. }) if metric == "mota"));
+        
+        // A limit the run can't meet fails it by that limit
+        let mut strict = fitted;
+        strict.thresholds.insert("rms_mean".to_string(), Threshold { value: 0.0, bound: Bound::Max });
+        let failed = runner().with_thresholds(strict.clone()).run(ScenarioId::Swarm).unwrap();
+        assert_eq!(failed.failure, Some(FailureKind::RmsExceeded { mean: builtin.rms_mean, p95: builtin.rms_p95, threshold: 0.0 }));
+        
+        // Other scenarios keep their built-in limits
+        let other = ScenarioRunner::new(42, 6).with_duration(1.0).with_thresholds(strict).run(ScenarioId::TimeWarp).unwrap();
+        assert_eq!(other.thresholds, ThresholdSource::Builtin);
+    }
+    
+    #[test]
+    fn test_swarm_golden_seed() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_swarm_config(SwarmConfig { rows: 2, cols: 3, num_entities: 20, ..SwarmConfig::default() })
+            .with_duration(1.0)
+            .run(ScenarioId::Swarm).unwrap();
+        assert!(result.passed);
+        assert!(result.rms_mean < GOLDEN_SWARM_MEAN + 0.05, "rms_mean={}", result.rms_mean);
+    }
+    
+    #[test]
+    fn test_blackout_survival_golden_seed() {
+        // The harness ticks evolving agents (so epochs last 20 ticks instead
+        // of one) and draws faults from a ChaCha8 stream of its own, which
+        // cost a little accuracy against the hand-written loop's 2.443. The
+        // golden is now the harness's own score with named RNG streams; the
+        // band still allows for epoch timing
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(2.0)
+            .run(ScenarioId::BlackoutSurvival).unwrap();
         
         assert!(result.passed);
-        assert!(result.metrics.oosm_updates > 0);
+        let drift = (result.rms_mean - GOLDEN_BLACKOUT_SURVIVAL_MEAN) / GOLDEN_BLACKOUT_SURVIVAL_MEAN;
+        assert!(drift.abs() < 0.15, "rms_mean={}", result.rms_mean);
     }
     
     #[test]
     fn test_split_brain_scenario() {
         let runner = ScenarioRunner::new(42, 6)
-            .with_duration(1.0);
+            .with_duration(5.0);
+        
+        let result = runner.run(ScenarioId::SplitBrain).unwrap();
+        
+        assert!(result.passed, "{:?}", result.failure);
+        assert!(result.metrics.packets_dropped > 0);
+        assert!(result.metrics.digest_packets > 0);
+        // Push gossip carries the blinded group's UUID across only once it
+        // sees the entity again; anti-entropy doesn't wait
+        let push_secs = result.metrics.push_convergence_time_secs.expect("push gossip converges");
+        let push_pull_secs = result.metrics.convergence_time_secs.expect("push-pull gossip converges");
+        assert!(push_secs >= SPLIT_BRAIN_BLIND_SECS, "{push_secs}");
+        assert!(push_pull_secs <= push_secs / SPLIT_BRAIN_MIN_SPEEDUP, "{push_pull_secs} vs {push_secs}");
+        
+        let divergence = result.metrics.partition_divergence.expect("SplitBrain reports divergence");
+        assert!(divergence.peak_divergence_m > 0.0 && divergence.peak_divergence_m < MAX_PARTITION_DIVERGENCE_M);
+        assert!(divergence.divergence_at_heal_m.is_some());
+        assert!(divergence.reconverge_secs.is_some_and(|secs| secs <= 5.0));
+        assert_eq!(divergence.peak_canonical_ids, 2);
+        assert!(divergence.series.iter().any(|s| s.partitioned) && divergence.series.iter().any(|s| !s.partitioned));
+    }
+    
+    #[test]
+    fn test_byzantine_rejects_stale_keys_after_rotation() {
+        let result = ScenarioRunner::new(42, 4)
+            .with_duration(2.0)
+            .run(ScenarioId::Byzantine).unwrap();
+        
+        assert!(result.passed, "{:?}", result.failure);
+        assert_eq!(result.metrics.key_rotations, 2);
+        assert!(result.metrics.stale_key_rejections > 0);
+        assert_eq!(result.metrics.packets_dropped, result.metrics.stale_key_rejections);
+    }
+    
+    #[test]
+    fn test_resource_starvation_reports_link_counters() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(2.0)
+            .run(ScenarioId::ResourceStarvation).unwrap();
+        
+        let m = &result.metrics;
+        assert!(m.link_delivered > 0);
+        assert!(m.link_delivered <= m.link_enqueued);
+        assert_eq!(m.packets_sent, m.link_enqueued + m.link_dropped_overflow);
+        assert_eq!(m.packets_dropped, m.link_dropped_overflow);
+        assert!(m.bytes_sent > m.packets_sent);
+        assert_eq!(m.bytes_dropped > 0, m.packets_dropped > 0);
+    }
+    
+    #[test]
+    fn test_trained_params_carry_into_another_scenario() {
+        let training = ScenarioRunner::new(42, 6)
+            .with_duration(4.0)
+            .run(ScenarioId::SensorDrift).unwrap();
+        let trained = TrainedParams::from_result(&training).unwrap();
+        assert_eq!((trained.scenario.as_str(), trained.seed), ("sensor_drift", 42));
+        assert_eq!(trained.crate_version, crate::corpus::CRATE_VERSION);
+        assert_eq!(trained.agents.len(), 10);
+        assert!(trained.agents.iter().any(|p| *p != EvoParams::default()));
+        
+        let path = std::env::temp_dir().join(format!("godview_trained_{}.json", std::process::id()));
+        trained.save(&path).unwrap();
+        let loaded = TrainedParams::load(&path).unwrap();
+        let biases = |t: &TrainedParams| t.agents.iter().map(|p| p.sensor_bias_estimate).collect::<Vec<_>>();
+        assert_eq!((loaded.scenario.as_str(), loaded.seed), ("sensor_drift", 42));
+        assert_eq!(biases(&loaded), biases(&trained));
+        std::fs::remove_file(&path).unwrap();
+        
+        // Same seed, cold start vs the trained swarm: the bias estimates
+        // learned against drifting sensors start BlindLearning's agents
+        // closer to its biased ones (0.253m -> 0.221m)
+        let blind = |trained: Option<&TrainedParams>| {
+            let mut runner = ScenarioRunner::new(42, 6).with_duration(4.0);
+            if let Some(trained) = trained {
+                runner = runner.with_trained_params(trained.clone());
+            }
+            runner.run(ScenarioId::BlindLearning).unwrap()
+        };
+        let cold = blind(None);
+        let warm = blind(Some(&trained));
+        assert!(cold.passed && warm.passed, "{:?} {:?}", cold.failure, warm.failure);
+        assert!(warm.rms_mean < cold.rms_mean, "{} vs {}", warm.rms_mean, cold.rms_mean);
+        assert_ne!(warm.metrics.evolved_params, cold.metrics.evolved_params);
+        
+        let split_brain = ScenarioRunner::new(42, 6).with_duration(5.0).run(ScenarioId::SplitBrain).unwrap();
+        assert!(TrainedParams::from_result(&split_brain).is_none());
+    }
+    
+    #[test]
+    fn test_time_warp_injects_reorder_and_duplicates() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(3.0)
+            .run(ScenarioId::TimeWarp).unwrap();
+        
+        let m = &result.metrics;
+        assert!(result.passed, "{:?}", result.failure);
+        assert!(m.reordered_deliveries > 0);
+        assert!(m.duplicated_deliveries > 0);
+        // 30 ticks/s x 10 entities, plus one extra update per duplicate
+        assert!(m.oosm_updates > 900);
+    }
+    
+    #[test]
+    fn test_sensor_latency_variants_record_their_percentiles() {
+        // TimeTornado has one entity at 10Hz: give it 300 readings
+        let run = |scenario, model| {
+            let secs = if scenario == ScenarioId::TimeTornado { 30.0 } else { 4.0 };
+            let runner = ScenarioRunner::new(42, 1).with_duration(secs);
+            let runner = match model {
+                Some(model) => runner.with_sensor_latency(model),
+                None => runner,
+            };
+            runner.run(scenario).unwrap()
+        };
         
-        let result = runner.run(ScenarioId::SplitBrain);
+        // TimeWarp's readings are only reordered unless a model delays them
+        assert_eq!(run(ScenarioId::TimeWarp, None).metrics.link_latency_max_ms, 0);
+        let tornado = run(ScenarioId::TimeTornado, None);
+        assert!(tornado.passed, "{:?}", tornado.failure);
+        let m = &tornado.metrics;
+        assert!((2000..=3000).contains(&m.link_latency_p50_ms) && m.link_latency_max_ms <= 5000, "{m:?}");
+        
+        // Judged against current ground truth, TimeWarp's fast movers may
+        // miss 5m RMS once readings lag; only the latencies are checked
+        for scenario in [ScenarioId::TimeWarp, ScenarioId::TimeTornado] {
+            let lognormal = run(scenario, Some(LatencyModel::MESH_LOGNORMAL));
+            let m = &lognormal.metrics;
+            assert!((45..=65).contains(&m.link_latency_p50_ms), "{scenario}: {m:?}");
+            assert!((180..=320).contains(&m.link_latency_p95_ms), "{scenario}: {m:?}");
+            assert!(m.link_latency_p99_ms > m.link_latency_p95_ms);
+            
+            let bimodal = run(scenario, Some(LatencyModel::MESH_BIMODAL));
+            let m = &bimodal.metrics;
+            assert_eq!((m.link_latency_p50_ms, m.link_latency_p95_ms, m.link_latency_max_ms), (15, 350, 350), "{scenario}");
+            assert!(m.oosm_updates > 0);
+        }
+        assert!(run(ScenarioId::TimeTornado, Some(LatencyModel::MESH_BIMODAL)).passed);
+    }
+    
+    #[test]
+    fn test_heavy_tail_robust_fusion_beats_plain() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(4.0)
+            .run(ScenarioId::HeavyTail).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+    }
+    
+    #[test]
+    fn test_multi_sensor_bias_calibrates_both_sensors() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(10.0)
+            .run(ScenarioId::MultiSensorBias).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+    }
+    
+    #[test]
+    fn test_clock_skew_offsets_are_estimated() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(10.0)
+            .run(ScenarioId::ClockSkew).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        
+        let stats = result.metrics.clock_skew.unwrap();
+        assert_eq!(stats.propagated.len(), 4);
+        assert!(stats.rms_per_100ms > 0.0);
+    }
+    
+    #[test]
+    fn test_gossip_fills_occluded_targets() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(4.0)
+            .run(ScenarioId::Occlusion).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        
+        let coverage = result.metrics.occlusion.unwrap();
+        assert_eq!(coverage.occluded_agents, 8);
+        assert!(coverage.direct_coverage < coverage.fused_coverage);
+        assert!(result.registry.snapshot().gauges.contains_key("min_hidden_coverage"));
+        assert_eq!(result.metrics.observation_coverage.unwrap().entities, 10);
+    }
+    
+    #[test]
+    fn test_noisy_agents_close_on_precise_ones() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_swarm_config(SwarmConfig { rows: 2, cols: 5, ..SwarmConfig::default() })
+            .with_duration(1.0)
+            .run(ScenarioId::MixedFleet).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        
+        let groups = &result.metrics.profile_rms;
+        let agents: Vec<(&str, usize)> = groups.iter().map(|g| (g.profile.as_str(), g.agents)).collect();
+        assert_eq!(agents, [("precise", 2), ("noisy", 8)]);
+        assert!(groups[0].rms_mean < groups[1].rms_mean);
+        assert_eq!(result.registry.snapshot().gauges.get("rms_noisy"), Some(&groups[1].rms_mean));
+    }
+    
+    #[test]
+    fn test_confirmation_suppresses_clutter_tracks() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(3.0)
+            .run(ScenarioId::Clutter).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        
+        let (clutter, baseline) = (result.metrics.clutter.unwrap(), result.metrics.baseline_clutter.unwrap());
+        assert!(clutter.clutter_tracks > 0 && baseline.clutter_tracks > 0);
+        assert_eq!(baseline.suppression(), 0.0);
+        assert!(clutter.false_track_rate() < baseline.false_track_rate());
+        assert!(result.registry.snapshot().gauges.contains_key("clutter_suppression"));
+    }
+    
+    #[test]
+    fn test_neighbors_flag_gps_denied_agents() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(8.0)
+            .run(ScenarioId::GpsDenied).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        
+        let denial = result.metrics.gps_denial.unwrap();
+        assert_eq!(denial.denied_agents, 3);
+        assert!(denial.peak_pose_error_m > 4.0);
+        assert!(denial.rms_during > denial.rms_before);
+        assert!(denial.denied_pairs > 0 && denial.flagged_honest_pairs == 0);
+        assert!(result.registry.snapshot().gauges.contains_key("offset_detection_rate"));
+    }
+    
+    #[test]
+    fn test_convoy_hands_tracks_down_the_line() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(10.0)
+            .run(ScenarioId::Convoy).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        
+        assert!(result.metrics.link_changes > 0);
+        let churn = result.metrics.id_churn.unwrap();
+        assert!(churn.tracked >= 8, "{churn:?}");
+        assert_eq!(result.metrics.mot.unwrap().id_switches, 0);
+        assert!(result.registry.snapshot().counters.contains_key("link_changes"));
+    }
+    
+    #[test]
+    fn test_degraded_altitude_favors_the_anisotropic_filter() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(10.0)
+            .run(ScenarioId::DegradedAltitude).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        
+        let extra = &result.metrics.extra;
+        assert!(extra["altitude_rms_mean"] < extra["isotropic_altitude_rms_mean"], "{extra:?}");
+        assert!(extra["altitude_gain"] >= 0.2, "{extra:?}");
+    }
+    
+    #[test]
+    fn test_event_bus_replays_the_same_events_for_a_seed() {
+        let run = || {
+            let runner = ScenarioRunner::new(42, 6)
+                .with_duration(3.0)
+                .with_recording(10);
+            let result = runner.run(ScenarioId::Convoy).unwrap();
+            let events: Vec<String> = runner.take_recording().unwrap().frames.iter()
+                .flat_map(|f| &f.events)
+                .map(|e| e.message.clone())
+                .collect();
+            (result, events)
+        };
+        let (result, events) = run();
+        assert_eq!(events[0], "convoy (seed=42)");
+        assert!(events.iter().any(|e| e.ends_with("broken")), "{events:?}");
+        assert_eq!(result.registry.counter("events_run_started"), 1);
+        assert!(result.registry.counter("events_links_changed") > 0);
         
-        assert!(result.passed);
+        assert_eq!(run().1, events);
+    }
+    
+    #[test]
+    fn test_triage_keeps_high_priority_rms_low() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(8.0)
+            .run(ScenarioId::Triage).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        
+        assert!(result.metrics.link_preempted > 0);
+        let classes = &result.metrics.priority_classes;
+        assert_eq!(classes.len(), 2);
+        assert!(classes[0].rms_mean < classes[1].rms_mean, "{classes:?}");
+        assert!(result.registry.snapshot().gauges.contains_key("rms_priority_high"));
+    }
+    
+    #[test]
+    fn test_occlusion_scores_coasting_apart() {
+        let result = ScenarioRunner::new(42, 8)
+            .with_duration(4.0)
+            .run(ScenarioId::Occlusion).unwrap();
+        
+        let coasting = result.metrics.coasting.expect("Occlusion scores coasting states");
+        assert!(coasting.updated_samples > 0 && coasting.coasting_samples > 0, "{coasting:?}");
+        assert!(coasting.coasting_rms > coasting.updated_rms, "{coasting:?}");
+        let gauges = result.registry.snapshot().gauges;
+        assert_eq!(gauges["rms_coasting"], coasting.coasting_rms);
+        assert_eq!(result.to_json()["coasting"]["coasting_samples"], coasting.coasting_samples);
+    }
+    
+    #[test]
+    fn test_epochs_to_settle() {
+        let settling = [5.0, 3.0, 1.5, 1.05, 0.95, 1.0, 1.0, 1.0];
+        assert_eq!(epochs_to_settle(&settling, 0.2), Some(3));
+        assert_eq!(epochs_to_settle(&[1.0; 6], 0.2), Some(0));
+        assert_eq!(epochs_to_settle(&[1.0, 1.0, 1.0, 1.0, 1.0, 3.0], 0.2), None, "still moving at the end");
+        assert_eq!(epochs_to_settle(&settling[..4], 0.2), None, "shorter than the window");
+    }
+    
+    #[test]
+    fn test_hybrid_learning_reports_each_regime() {
+        let result = ScenarioRunner::new(42, 16)
+            .with_duration(6.0)
+            .with_gt_schedule("step:1,0,2".parse().unwrap())
+            .run(ScenarioId::HybridLearning).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        
+        let arms = &result.metrics.learning_arms;
+        let fitness: Vec<&str> = arms.iter().map(|a| a.fitness.as_str()).collect();
+        assert_eq!(fitness, ["ground_truth", "blind", "hybrid"]);
+        let gt_epochs: Vec<usize> = arms.iter().map(|a| a.ground_truth_epochs).collect();
+        assert_eq!(gt_epochs, [6, 0, 2]);
+        assert_eq!(result.rms_mean, arms[2].rms_mean);
+        
+        let gauges = result.registry.snapshot().gauges;
+        assert!(gauges.contains_key("rms_learning_blind"));
+        assert_eq!(result.to_json()["learning_arms"].as_array().unwrap().len(), 3);
+    }
+    
+    #[test]
+    #[ignore] // Minutes in debug builds even at 0.5s (ScaleLimit's 200 agents, 5-10s minimums); CI runs `make test-slow`
+    fn test_every_scenario_populates_metrics() {
+        // Nothing is gossiped in these, or nothing arrives within the run
+        let silent = [
+            ScenarioId::TimeWarp,
+            ScenarioId::FlashMob,
+            ScenarioId::SlowLoris,
+            ScenarioId::TimeTornado,
+            ScenarioId::RapidFire,
+            ScenarioId::Clutter,
+            ScenarioId::DegradedAltitude,
+        ];
+        for scenario in ScenarioId::all() {
+            let result = ScenarioRunner::new(42, 6)
+                .with_swarm_config(SwarmConfig { rows: 2, cols: 3, num_entities: 20, ..SwarmConfig::default() })
+                .with_duration(0.5)
+                .run(scenario).unwrap();
+            let m = &result.metrics;
+            assert_eq!(m.rms_mean.to_bits(), result.rms_mean.to_bits(), "{}", scenario.name());
+            if !silent.contains(&scenario) {
+                assert!(m.packets_sent > 0, "{} sent no packets", scenario.name());
+                assert!(m.gossip_received_total > 0, "{} received no gossip", scenario.name());
+                assert!(m.tracks_final_total > 0, "{} ended with no tracks", scenario.name());
+            }
+            
+            let json = serde_json::to_value(m).unwrap();
+            assert_eq!(json["tracks_final_total"], m.tracks_final_total);
+            for (name, value) in &m.extra {
+                assert_eq!(json[name], *value, "{}", scenario.name());
+                assert_eq!(result.registry.snapshot().gauges.get(name), Some(value), "{}", scenario.name());
+            }
+        }
+    }
+    
+    #[test]
+    fn test_extra_metrics_serialize_flattened() {
+        let mut metrics = ScenarioMetrics { packets_sent: 3, ..ScenarioMetrics::default() };
+        metrics.set_extra("robust_gain", 0.25);
+        
+        let json = serde_json::to_value(&metrics).unwrap();
+        assert_eq!(json["robust_gain"], 0.25);
+        assert_eq!(json["packets_sent"], 3);
+        assert!(json.get("extra").is_none());
+    }
+    
+    #[test]
+    fn test_protocol_drift_mixes_wire_versions() {
+        let result = ScenarioRunner::new(42, 6)
+            .with_duration(2.0)
+            .run(ScenarioId::ProtocolDrift).unwrap();
+        assert!(result.passed, "{:?}", result.failure);
+        assert!(result.metrics.downgraded_packets > 0);
+        assert!(result.metrics.v1_rms.unwrap() <= 2.0 * result.metrics.v2_rms.unwrap());
+        assert_eq!(result.registry.counter("downgraded_packets"), result.metrics.downgraded_packets);
     }
     
     #[test]
@@ -2940,10 +9285,14 @@ mod tests {
         let runner = ScenarioRunner::new(42, 6)
             .with_duration(1.0);
         
-        let result = runner.run(ScenarioId::FlashMob);
+        let result = runner.run(ScenarioId::FlashMob).unwrap();
         
         assert!(result.passed);
         assert_eq!(result.final_entity_count, 1000);
+        
+        let mot = result.metrics.mot.expect("FlashMob is MOT-scored");
+        assert!(mot.frames > 0);
+        assert!(mot.mota() >= 0.9, "MOTA {}", mot.mota());
     }
     
     #[test]
@@ -2952,9 +9301,23 @@ mod tests {
         let runner1 = ScenarioRunner::new(42, 6).with_duration(1.0);
         let runner2 = ScenarioRunner::new(42, 6).with_duration(1.0);
         
-        let result1 = runner1.run(ScenarioId::SlowLoris);
-        let result2 = runner2.run(ScenarioId::SlowLoris);
+        let result1 = runner1.run(ScenarioId::SlowLoris).unwrap();
+        let result2 = runner2.run(ScenarioId::SlowLoris).unwrap();
         
         assert_eq!(result1.metrics.packets_dropped, result2.metrics.packets_dropped);
+        assert_eq!(result1.metrics.loss_spread, result2.metrics.loss_spread);
+    }
+    
+    #[test]
+    fn test_slow_loris_spreads_tracks_at_every_loss_rate() {
+        let result = ScenarioRunner::new(42, 4).with_duration(4.0).run(ScenarioId::SlowLoris).unwrap();
+        let m = &result.metrics;
+        
+        assert!(result.passed, "{:?}", result.failure);
+        assert_eq!(m.loss_spread.iter().map(|s| s.loss_rate).collect::<Vec<_>>(), SLOW_LORIS_LOSS_RATES);
+        assert!(m.loss_spread.iter().all(|s| s.observed == 5 && s.spread == 5), "{:?}", m.loss_spread);
+        let loss = m.packets_dropped as f64 / m.packets_sent as f64;
+        assert!((0.4..0.6).contains(&loss), "{loss}");
+        assert!(result.registry.gauge("spread_secs_loss_50").is_some());
     }
 }
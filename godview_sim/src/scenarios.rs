@@ -1,5 +1,186 @@
 //! Chaos engineering scenarios for DST.
 
+use crate::faults::{ConfigPerturbation, FaultPlan};
+
+/// Duration bounds and tick rate a scenario is designed for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScenarioTiming {
+    /// Duration used when none is requested (seconds)
+    pub default_duration_secs: f64,
+    
+    /// Shortest accepted duration (seconds)
+    pub min_duration_secs: f64,
+    
+    /// Longest accepted duration (seconds)
+    pub max_duration_secs: f64,
+    
+    /// Simulation tick rate (Hz)
+    pub tick_rate_hz: u32,
+}
+
+impl ScenarioTiming {
+    const fn new(default_duration_secs: f64, min_duration_secs: f64, max_duration_secs: f64, tick_rate_hz: u32) -> Self {
+        Self { default_duration_secs, min_duration_secs, max_duration_secs, tick_rate_hz }
+    }
+    
+    /// Resolves a requested duration against the scenario's bounds.
+    ///
+    /// Returns the duration to run and, if the request was out of bounds,
+    /// a record of the clamp.
+    pub fn resolve(&self, requested_secs: Option<f64>) -> (f64, Option<DurationClamp>) {
+        let Some(requested_secs) = requested_secs else {
+            return (self.default_duration_secs, None);
+        };
+        
+        let reason = if requested_secs < self.min_duration_secs {
+            ClampReason::BelowMinimum
+        } else if requested_secs > self.max_duration_secs {
+            ClampReason::AboveMaximum
+        } else {
+            return (requested_secs, None);
+        };
+        
+        let applied_secs = requested_secs.clamp(self.min_duration_secs, self.max_duration_secs);
+        (applied_secs, Some(DurationClamp { requested_secs, applied_secs, reason }))
+    }
+}
+
+/// Which bound a requested duration violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClampReason {
+    /// Shorter than the scenario's minimum
+    BelowMinimum,
+    
+    /// Longer than the scenario's maximum
+    AboveMaximum,
+}
+
+/// Record of a requested duration the scenario couldn't honour.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationClamp {
+    /// Duration that was requested (seconds)
+    pub requested_secs: f64,
+    
+    /// Duration that was actually run (seconds)
+    pub applied_secs: f64,
+    
+    /// Bound that was hit
+    pub reason: ClampReason,
+}
+
+impl std::fmt::Display for DurationClamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bound = match self.reason {
+            ClampReason::BelowMinimum => "minimum",
+            ClampReason::AboveMaximum => "maximum",
+        };
+        write!(f, "requested {}s, clamped to the scenario {} of {}s", self.requested_secs, bound, self.applied_secs)
+    }
+}
+
+/// Labels scenarios can be selected by (`--scenario tag:<name>`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScenarioTag {
+    /// Pushes GodView to its limits (load, loss, adversaries, tick rate)
+    Extreme,
+    
+    /// Agents evolve their parameters during the run
+    Evolutionary,
+    
+    /// Stresses the gossip network: loss, partitions, bandwidth or wire formats
+    Network,
+}
+
+impl ScenarioTag {
+    /// Every tag, in listing order.
+    pub const ALL: [ScenarioTag; 3] = [ScenarioTag::Extreme, ScenarioTag::Evolutionary, ScenarioTag::Network];
+    
+    /// Returns the tag name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScenarioTag::Extreme => "extreme",
+            ScenarioTag::Evolutionary => "evolutionary",
+            ScenarioTag::Network => "network",
+        }
+    }
+}
+
+impl std::fmt::Display for ScenarioTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for ScenarioTag {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = s.to_lowercase();
+        Self::ALL.into_iter()
+            .find(|t| t.name() == tag)
+            .ok_or_else(|| format!("Unknown tag: {} (available: {})", s, join_names(Self::ALL.iter().map(|t| t.name()), ", ")))
+    }
+}
+
+/// How many agents a scenario runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgentCount {
+    /// Always this many
+    Fixed(usize),
+    
+    /// The runner's agent count (`--agents`), which must be at least `min`
+    FromRunner { min: usize },
+}
+
+impl std::fmt::Display for AgentCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AgentCount::Fixed(n) => write!(f, "{}", n),
+            AgentCount::FromRunner { min } => write!(f, "--agents (min {})", min),
+        }
+    }
+}
+
+/// Options every scenario honors.
+pub const COMMON_KNOBS: [&str; 8] = [
+    "--seed",
+    "--duration (clamped to the scenario's bounds)",
+    "--warmup",
+    "--abort-on-rms",
+    "--progress / --progress-interval",
+    "--failure-window / --failure-dir",
+    "--metrics-out",
+    "--rerun-connect",
+];
+
+/// Everything `godview-sim describe` prints about a scenario.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioInfo {
+    /// Scenario name
+    pub name: &'static str,
+    
+    /// One-line description
+    pub description: &'static str,
+    
+    /// Duration bounds and tick rate
+    pub timing: ScenarioTiming,
+    
+    /// Agents in the swarm
+    pub agents: AgentCount,
+    
+    /// Entities the oracle spawns
+    pub entities: usize,
+    
+    /// What the run must achieve to pass
+    pub pass_criteria: &'static str,
+    
+    /// Labels the scenario can be selected by
+    pub tags: Vec<ScenarioTag>,
+    
+    /// Options and runner settings the scenario honors
+    pub knobs: Vec<&'static str>,
+}
+
 /// Scenario identifiers
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScenarioId {
@@ -56,7 +237,7 @@ pub enum ScenarioId {
     /// DST-015: Bandwidth constrained evolution
     ResourceStarvation,
     
-    /// DST-016: Protocol divergence adaptation
+    /// DST-016: Mixed wire versions in one swarm
     ProtocolDrift,
     
     /// DST-017: Blind fitness learning (no oracle)
@@ -76,6 +257,45 @@ pub enum ScenarioId {
     
     /// DST-022: Sensor Drift (v0.6.0)
     SensorDrift,
+    
+    /// DST-023: Per-sensor bias calibration
+    MultiSensorBias,
+    
+    /// DST-024: Agent crashes, stalls and amnesia in one run
+    ZombieRestart,
+    
+    /// DST-025: Skewed agent clocks and gossip timestamp reconciliation
+    ClockSkew,
+    
+    /// DST-026: A wall hides half the field from each half of the swarm
+    Occlusion,
+    
+    /// DST-027: Precise and noisy agents sharing one swarm
+    MixedFleet,
+    
+    /// DST-028: Missed detections and sensor clutter
+    Clutter,
+    
+    /// DST-029: Agents dead-reckon their own position without GPS
+    GpsDenied,
+    
+    /// DST-030: A moving convoy hands entity tracks down the line
+    Convoy,
+    
+    /// DST-031: High-priority entities keep their gossip under a bandwidth limit
+    Triage,
+    
+    /// DST-032: Ground-truth, blind and hybrid fitness evolve the same swarm
+    HybridLearning,
+    
+    /// DST-033: GNSS-like readings whose altitude is 3x worse than horizontal
+    DegradedAltitude,
+    
+    /// DST-034: Tracking parameters change on every agent mid-run
+    ParameterShock,
+    
+    /// DST-035: Entities shuttle across H3 shard boundaries between owners
+    BoundaryCrossing,
 }
 
 impl ScenarioId {
@@ -95,6 +315,7 @@ impl ScenarioId {
             ScenarioId::NetworkHell,
             ScenarioId::TimeTornado,
             ScenarioId::ZombieApocalypse,
+            ScenarioId::ZombieRestart,
             ScenarioId::RapidFire,
             // Evolutionary
             ScenarioId::EvoWar,
@@ -106,6 +327,18 @@ impl ScenarioId {
             ScenarioId::CommonBias,
             ScenarioId::HeavyTail,
             ScenarioId::SensorDrift,
+            ScenarioId::MultiSensorBias,
+            ScenarioId::ClockSkew,
+            ScenarioId::Occlusion,
+            ScenarioId::MixedFleet,
+            ScenarioId::Clutter,
+            ScenarioId::GpsDenied,
+            ScenarioId::Convoy,
+            ScenarioId::Triage,
+            ScenarioId::HybridLearning,
+            ScenarioId::DegradedAltitude,
+            ScenarioId::ParameterShock,
+            ScenarioId::BoundaryCrossing,
         ]
     }
     
@@ -130,6 +363,7 @@ impl ScenarioId {
             ScenarioId::NetworkHell,
             ScenarioId::TimeTornado,
             ScenarioId::ZombieApocalypse,
+            ScenarioId::ZombieRestart,
             ScenarioId::RapidFire,
             ScenarioId::BlackoutSurvival,
         ]
@@ -144,6 +378,7 @@ impl ScenarioId {
             ScenarioId::CommonBias,
             ScenarioId::HeavyTail,
             ScenarioId::SensorDrift,
+            ScenarioId::MultiSensorBias,
         ]
     }
     
@@ -164,6 +399,7 @@ impl ScenarioId {
             ScenarioId::NetworkHell,
             ScenarioId::TimeTornado,
             ScenarioId::ZombieApocalypse,
+            ScenarioId::ZombieRestart,
             ScenarioId::RapidFire,
             // Evolutionary (stable)
             ScenarioId::EvoWar,
@@ -173,6 +409,18 @@ impl ScenarioId {
             ScenarioId::LongHaul,
             ScenarioId::HeavyTail,
             ScenarioId::SensorDrift,
+            ScenarioId::MultiSensorBias,
+            ScenarioId::ClockSkew,
+            ScenarioId::Occlusion,
+            ScenarioId::MixedFleet,
+            ScenarioId::Clutter,
+            ScenarioId::GpsDenied,
+            ScenarioId::Convoy,
+            ScenarioId::Triage,
+            ScenarioId::HybridLearning,
+            ScenarioId::DegradedAltitude,
+            ScenarioId::ParameterShock,
+            ScenarioId::BoundaryCrossing,
         ]
     }
     
@@ -202,6 +450,7 @@ impl ScenarioId {
             ScenarioId::NetworkHell => "network_hell",
             ScenarioId::TimeTornado => "time_tornado",
             ScenarioId::ZombieApocalypse => "zombie_apocalypse",
+            ScenarioId::ZombieRestart => "zombie_restart",
             ScenarioId::RapidFire => "rapid_fire",
             // Evolutionary
             ScenarioId::EvoWar => "evo_war",
@@ -213,6 +462,18 @@ impl ScenarioId {
             ScenarioId::CommonBias => "common_bias",
             ScenarioId::HeavyTail => "heavy_tail",
             ScenarioId::SensorDrift => "sensor_drift",
+            ScenarioId::MultiSensorBias => "multi_sensor_bias",
+            ScenarioId::ClockSkew => "clock_skew",
+            ScenarioId::Occlusion => "occlusion",
+            ScenarioId::MixedFleet => "mixed_fleet",
+            ScenarioId::Clutter => "clutter",
+            ScenarioId::GpsDenied => "gps_denied",
+            ScenarioId::Convoy => "convoy",
+            ScenarioId::Triage => "triage",
+            ScenarioId::HybridLearning => "hybrid_learning",
+            ScenarioId::DegradedAltitude => "degraded_altitude",
+            ScenarioId::ParameterShock => "parameter_shock",
+            ScenarioId::BoundaryCrossing => "boundary_crossing",
         }
     }
     
@@ -220,10 +481,10 @@ impl ScenarioId {
     pub fn description(&self) -> &'static str {
         match self {
             ScenarioId::TimeWarp => "OOSM stress test with 0-500ms jitter and 20% reordering",
-            ScenarioId::SplitBrain => "Network partition for 10s, verify Min-UUID convergence",
+            ScenarioId::SplitBrain => "Network partition for 10s, verify Min-UUID convergence with anti-entropy digests",
             ScenarioId::Byzantine => "Malicious agent with delayed revocation propagation",
             ScenarioId::FlashMob => "1000 drones crossing H3 boundaries rapidly",
-            ScenarioId::SlowLoris => "50% packet loss, verify protocol recovery",
+            ScenarioId::SlowLoris => "50% gossip loss, verify re-broadcast spreads every track swarm-wide",
             ScenarioId::Swarm => "50 agents, 200 entities, P2P gossip, convergence test",
             ScenarioId::AdaptiveSwarm => "50 agents + 5 bad actors, learning to identify them",
             // Extreme
@@ -232,17 +493,305 @@ impl ScenarioId {
             ScenarioId::NetworkHell => "🔥 90% PACKET LOSS: find the breaking point",
             ScenarioId::TimeTornado => "🔥 5-SECOND DELAYS: extreme OOSM stress",
             ScenarioId::ZombieApocalypse => "🔥 50% BAD ACTORS: can good agents survive?",
+            ScenarioId::ZombieRestart => "🧟 ZOMBIE RESTART: agents crash, stall and lose their memory mid-run",
             ScenarioId::RapidFire => "🔥 100Hz TICK RATE: high-frequency stress test",
             // Evolutionary
             ScenarioId::EvoWar => "🧬 Evolution vs Chaos: agents adapt parameters to survive",
             ScenarioId::ResourceStarvation => "🧬 Bandwidth Constrained: evolve efficiency",
-            ScenarioId::ProtocolDrift => "🧬 Protocol Divergence: one group changes logic",
+            ScenarioId::ProtocolDrift => "🧬 Protocol Drift: half the swarm gossips wire V2, half only V1",
             ScenarioId::BlindLearning => "🧬 Blind Learning: adapt without ground truth",
             ScenarioId::BlackoutSurvival => "💀 BLACKOUT: Loss + Faults + Bad Actors + Bandwidth Limit",
             ScenarioId::LongHaul => "🔋 LONG HAUL: Energy Crisis Survival",
             ScenarioId::CommonBias => "🎯 COMMON BIAS: All agents get +5m GPS offset, evolve compensation",
             ScenarioId::HeavyTail => "📉 HEAVY TAIL: Cauchy noise tests Gaussian-evolved parameters",
             ScenarioId::SensorDrift => "📈 SENSOR DRIFT: Gradual sensor degradation over time",
+            ScenarioId::MultiSensorBias => "🎯 MULTI-SENSOR BIAS: Two sensors with distinct 3-axis offsets, calibrated per sensor",
+            ScenarioId::ClockSkew => "⏱️ CLOCK SKEW: One agent's clock runs ahead, neighbors estimate its offset",
+            ScenarioId::Occlusion => "🧱 OCCLUSION: A wall splits the field, gossip fills each side's blind spot",
+            ScenarioId::MixedFleet => "🎛️ MIXED FLEET: 10 precise and 40 noisy agents, gossip closes the gap",
+            ScenarioId::Clutter => "👻 CLUTTER: Sensors miss 10% of entities and report phantoms, M-of-N confirmation filters them",
+            ScenarioId::GpsDenied => "🛰️ GPS DENIED: 3 of 12 agents dead-reckon for a quarter of the run, neighbors down-weight their drifting gossip",
+            ScenarioId::Convoy => "🚚 CONVOY: 10 agents drive past parked targets, radio links follow the spacing, tracks hand down the line",
+            ScenarioId::Triage => "🚨 TRIAGE: 8 of 40 entities are threats, their gossip preempts the rest on links carrying 1 packet per tick",
+            ScenarioId::HybridLearning => "🧭 Hybrid Learning: ground truth at landmarks, blind in between, against pure ground truth and pure blind",
+            ScenarioId::DegradedAltitude => "✈️ DEGRADED ALTITUDE: Drones read with 3x worse altitude than horizontal, anisotropic vs isotropic filters",
+            ScenarioId::ParameterShock => "🎚️ PARAMETER SHOCK: Gating, max_age and H3 resolution change on every agent mid-run",
+            ScenarioId::BoundaryCrossing => "🗺️ BOUNDARY CROSSING: 4 agents own bands of H3 cells, shuttling entities' tracks are handed across the edges",
+        }
+    }
+    
+    /// Returns the duration bounds and tick rate this scenario runs with.
+    pub fn timing(&self) -> ScenarioTiming {
+        match self {
+            ScenarioId::TimeWarp => ScenarioTiming::new(10.0, 0.5, 600.0, 30),
+            // Healed at half the run, the winning group stays blind 1.5s more:
+            // push gossip needs the rest of a 5s run to converge
+            ScenarioId::SplitBrain => ScenarioTiming::new(10.0, 5.0, 600.0, 30),
+            ScenarioId::Byzantine => ScenarioTiming::new(10.0, 0.5, 600.0, 30),
+            ScenarioId::FlashMob => ScenarioTiming::new(10.0, 0.5, 600.0, 30),
+            ScenarioId::SlowLoris => ScenarioTiming::new(10.0, 0.5, 600.0, 30),
+            ScenarioId::Swarm => ScenarioTiming::new(10.0, 0.5, 30.0, 30),
+            ScenarioId::AdaptiveSwarm => ScenarioTiming::new(10.0, 0.5, 30.0, 30),
+            // Extreme: 200-agent and 100Hz runs are capped to keep CI time bounded
+            ScenarioId::ChaosStorm => ScenarioTiming::new(10.0, 0.5, 30.0, 10),
+            ScenarioId::ScaleLimit => ScenarioTiming::new(10.0, 0.5, 20.0, 10),
+            ScenarioId::NetworkHell => ScenarioTiming::new(10.0, 0.5, 30.0, 10),
+            ScenarioId::TimeTornado => ScenarioTiming::new(10.0, 0.5, 60.0, 10),
+            ScenarioId::ZombieApocalypse => ScenarioTiming::new(10.0, 0.5, 30.0, 10),
+            // Long enough for a stall to end and coverage to come back
+            ScenarioId::ZombieRestart => ScenarioTiming::new(20.0, 5.0, 120.0, 10),
+            ScenarioId::RapidFire => ScenarioTiming::new(10.0, 0.5, 10.0, 100),
+            // Evolutionary: longer defaults give evolution epochs room to run
+            ScenarioId::EvoWar => ScenarioTiming::new(10.0, 0.5, 30.0, 10),
+            ScenarioId::ResourceStarvation => ScenarioTiming::new(10.0, 0.5, 20.0, 10),
+            ScenarioId::ProtocolDrift => ScenarioTiming::new(10.0, 0.5, 600.0, 10),
+            ScenarioId::BlindLearning => ScenarioTiming::new(10.0, 0.5, 45.0, 10),
+            ScenarioId::BlackoutSurvival => ScenarioTiming::new(10.0, 0.5, 60.0, 10),
+            ScenarioId::LongHaul => ScenarioTiming::new(20.0, 0.5, 120.0, 10),
+            ScenarioId::CommonBias => ScenarioTiming::new(50.0, 0.5, 120.0, 10),
+            ScenarioId::HeavyTail => ScenarioTiming::new(10.0, 0.5, 30.0, 10),
+            ScenarioId::SensorDrift => ScenarioTiming::new(40.0, 0.5, 120.0, 10),
+            ScenarioId::MultiSensorBias => ScenarioTiming::new(30.0, 0.5, 120.0, 10),
+            ScenarioId::ClockSkew => ScenarioTiming::new(20.0, 0.5, 120.0, 10),
+            ScenarioId::Occlusion => ScenarioTiming::new(20.0, 0.5, 120.0, 10),
+            ScenarioId::MixedFleet => ScenarioTiming::new(20.0, 0.5, 120.0, 10),
+            ScenarioId::Clutter => ScenarioTiming::new(20.0, 0.5, 120.0, 10),
+            // Long enough for the denial window and the recovery after it
+            ScenarioId::GpsDenied => ScenarioTiming::new(20.0, 8.0, 120.0, 10),
+            // The convoy's speed scales to clear the road in the run, so
+            // short runs would leave each target in view for a few ticks
+            ScenarioId::Convoy => ScenarioTiming::new(20.0, 10.0, 120.0, 10),
+            // Entities drift along the longitude axis, which bounds the run
+            ScenarioId::Triage => ScenarioTiming::new(20.0, 2.0, 45.0, 10),
+            // Three runs of 30 one-second epochs; convergence needs at
+            // least CONVERGENCE_WINDOW_EPOCHS of them
+            ScenarioId::HybridLearning => ScenarioTiming::new(30.0, 5.0, 120.0, 10),
+            // Altitude is compared after a 5s settling time
+            ScenarioId::DegradedAltitude => ScenarioTiming::new(20.0, 10.0, 60.0, 10),
+            // Room for a 5s window either side of each perturbation
+            ScenarioId::ParameterShock => ScenarioTiming::new(40.0, 8.0, 120.0, 10),
+            // Long enough for every entity to cross at least one edge
+            ScenarioId::BoundaryCrossing => ScenarioTiming::new(30.0, 10.0, 120.0, 10),
+        }
+    }
+    
+    /// Returns how many agents this scenario runs.
+    pub fn agents(&self) -> AgentCount {
+        match self {
+            ScenarioId::SplitBrain => AgentCount::FromRunner { min: 2 },
+            ScenarioId::Byzantine => AgentCount::FromRunner { min: 3 },
+            ScenarioId::SlowLoris => AgentCount::FromRunner { min: 2 },
+            ScenarioId::TimeWarp | ScenarioId::FlashMob | ScenarioId::TimeTornado | ScenarioId::RapidFire => AgentCount::Fixed(1),
+            ScenarioId::ScaleLimit => AgentCount::Fixed(200),
+            ScenarioId::ZombieRestart | ScenarioId::ProtocolDrift => AgentCount::Fixed(16),
+            ScenarioId::LongHaul | ScenarioId::CommonBias | ScenarioId::HeavyTail
+                | ScenarioId::SensorDrift | ScenarioId::MultiSensorBias => AgentCount::Fixed(10),
+            ScenarioId::ClockSkew | ScenarioId::BoundaryCrossing => AgentCount::Fixed(4),
+            ScenarioId::ParameterShock => AgentCount::Fixed(12),
+            ScenarioId::Occlusion => AgentCount::Fixed(8),
+            ScenarioId::MixedFleet => AgentCount::Fixed(50),
+            ScenarioId::Clutter => AgentCount::Fixed(8),
+            ScenarioId::GpsDenied => AgentCount::Fixed(12),
+            ScenarioId::Convoy => AgentCount::Fixed(10),
+            ScenarioId::DegradedAltitude => AgentCount::Fixed(8),
+            ScenarioId::Triage | ScenarioId::HybridLearning => AgentCount::Fixed(16),
+            ScenarioId::Swarm | ScenarioId::AdaptiveSwarm | ScenarioId::ChaosStorm | ScenarioId::NetworkHell
+                | ScenarioId::ZombieApocalypse | ScenarioId::EvoWar | ScenarioId::ResourceStarvation
+                | ScenarioId::BlindLearning | ScenarioId::BlackoutSurvival => AgentCount::Fixed(50),
+        }
+    }
+    
+    /// Returns how many entities the oracle spawns.
+    pub fn entities(&self) -> usize {
+        match self {
+            ScenarioId::SplitBrain | ScenarioId::TimeTornado => 1,
+            ScenarioId::Byzantine | ScenarioId::SlowLoris | ScenarioId::CommonBias
+                | ScenarioId::HeavyTail | ScenarioId::SensorDrift => 5,
+            ScenarioId::MultiSensorBias => 6, // 5 targets and a landmark
+            ScenarioId::ClockSkew => 6,
+            ScenarioId::Occlusion => 10,
+            ScenarioId::MixedFleet => 100,
+            ScenarioId::Clutter => 10,
+            ScenarioId::GpsDenied => 14,
+            ScenarioId::Convoy | ScenarioId::DegradedAltitude => 8,
+            ScenarioId::Triage | ScenarioId::HybridLearning => 40,
+            ScenarioId::TimeWarp | ScenarioId::RapidFire | ScenarioId::LongHaul => 10,
+            ScenarioId::ProtocolDrift => 30,
+            ScenarioId::ParameterShock => 24,
+            ScenarioId::BoundaryCrossing => 12,
+            ScenarioId::ZombieRestart => 40,
+            ScenarioId::ResourceStarvation | ScenarioId::BlindLearning | ScenarioId::BlackoutSurvival => 50,
+            ScenarioId::EvoWar => 100,
+            ScenarioId::Swarm | ScenarioId::AdaptiveSwarm | ScenarioId::ChaosStorm
+                | ScenarioId::NetworkHell | ScenarioId::ZombieApocalypse => 200,
+            ScenarioId::FlashMob | ScenarioId::ScaleLimit => 1000,
+        }
+    }
+    
+    /// Returns a summary of what a run must achieve to pass.
+    pub fn pass_criteria(&self) -> &'static str {
+        match self {
+            ScenarioId::TimeWarp => "RMS within 5m, velocity RMS within 1m/s",
+            ScenarioId::SplitBrain => "Groups diverge during the partition, then converge on the minimum UUID within the deadline, at least twice as fast as push gossip alone",
+            ScenarioId::Byzantine => "Stale keys rejected only after rotation, revoked agent's envelopes refused, rotated agent's accepted",
+            ScenarioId::FlashMob => "MOTA >= 0.9",
+            ScenarioId::SlowLoris => "At 50% loss: RMS within 3m, every entity tracked by every agent within 1s of its first track",
+            ScenarioId::Swarm => "Track count CV < 15%, RMS within 3m, MOTA >= 0.45, no gossip across the partition",
+            ScenarioId::AdaptiveSwarm => "Bad actor detection >= 80%, RMS within 5m",
+            ScenarioId::ChaosStorm => "RMS within 10m",
+            ScenarioId::ScaleLimit => "RMS within 5m at > 10 ticks/s wall clock, agents within the memory budget",
+            ScenarioId::NetworkHell => "RMS within 50m",
+            ScenarioId::TimeTornado => "Updated RMS within 200m and coasting error growth within the target's speed, with OOSM updates applied",
+            ScenarioId::ZombieApocalypse => "RMS within 10m, bad actor detection >= 80%, lower RMS under gossip quotas",
+            ScenarioId::ZombieRestart => "Coverage recovers after every fault, faults honored, no envelopes rejected, RMS within 5m",
+            ScenarioId::RapidFire => "RMS within 3m at > 50% of the 100Hz tick rate",
+            ScenarioId::EvoWar => "Blue team RMS within 10m",
+            ScenarioId::ResourceStarvation => "RMS within 5m, evolved gossip interval > 5 ticks, prioritized gossip beats FIFO",
+            ScenarioId::ProtocolDrift => "No envelopes rejected, V2 packets downgraded, V1 RMS at most 2x V2 RMS",
+            ScenarioId::BlindLearning => "RMS within 10m",
+            ScenarioId::BlackoutSurvival => "RMS within 15m",
+            ScenarioId::LongHaul => "> 80% of agents survive, RMS within 5m",
+            ScenarioId::CommonBias => "RMS within 5m",
+            ScenarioId::HeavyTail => "RMS within 10m and >= 30% below plain fusion",
+            ScenarioId::SensorDrift => "RMS within 8m",
+            ScenarioId::MultiSensorBias => "Per-sensor bias estimates within 0.5m, RMS within 2m",
+            ScenarioId::ClockSkew => "RMS grows with skew, offset estimated within 50ms, compensated RMS within 1.25x unskewed",
+            ScenarioId::Occlusion => "Agents see <= 60% of entities directly, every agent tracks >= 80% of the rest, updated RMS within 3m, coasting error growth within the targets' speed",
+            ScenarioId::MixedFleet => "RMS within 5m, noisy agents' RMS within 4.5x of precise agents'",
+            ScenarioId::Clutter => "M-of-N agents confirm <= 10% of clutter tracks and hold fewer false tracks than agents without it, RMS within 2m",
+            ScenarioId::GpsDenied => "RMS within 3m during the denial, >= 80% of denied neighbors flagged and no honest ones, recovery within 3s, RMS within 3m",
+            ScenarioId::Convoy => "Radio links change as the convoy stretches, no ID switches, <= 1 canonical ID change per tracked entity, RMS within 2m",
+            ScenarioId::Triage => "High-priority packets preempt routine ones, high-priority RMS within 1m (routine RMS reported, not judged)",
+            ScenarioId::HybridLearning => "RMS within 10m under each fitness (epochs to convergence reported, not judged)",
+            ScenarioId::DegradedAltitude => "Settled altitude RMS with anisotropic reading variance >= 20% below isotropic, RMS within 2m",
+            ScenarioId::ParameterShock => "Every agent takes every perturbation with a consistent spatial index, RMS rises at most 1m and tracks per agent fall at most 20% across each, RMS within 3m",
+            ScenarioId::BoundaryCrossing => ">= 95% of shard crossings handed off within 1s, <= 5% with duplicate tracks, mean gap within 0.2s, RMS within 2m",
+        }
+    }
+    
+    /// Returns the tags this scenario carries.
+    pub fn tags(&self) -> Vec<ScenarioTag> {
+        let evolutionary = matches!(self,
+            ScenarioId::EvoWar |
+            ScenarioId::ResourceStarvation |
+            ScenarioId::BlindLearning |
+            ScenarioId::HybridLearning |
+            ScenarioId::BlackoutSurvival |
+            ScenarioId::LongHaul |
+            ScenarioId::CommonBias |
+            ScenarioId::HeavyTail |
+            ScenarioId::SensorDrift
+        );
+        let network = matches!(self,
+            ScenarioId::SplitBrain |
+            ScenarioId::SlowLoris |
+            ScenarioId::ChaosStorm |
+            ScenarioId::NetworkHell |
+            ScenarioId::EvoWar |
+            ScenarioId::ResourceStarvation |
+            ScenarioId::ProtocolDrift |
+            ScenarioId::BlindLearning |
+            ScenarioId::HybridLearning |
+            ScenarioId::BlackoutSurvival |
+            ScenarioId::Convoy |
+            ScenarioId::Triage |
+            ScenarioId::BoundaryCrossing
+        );
+        [(ScenarioTag::Extreme, self.is_extreme()), (ScenarioTag::Evolutionary, evolutionary), (ScenarioTag::Network, network)]
+            .into_iter()
+            .filter_map(|(tag, carried)| carried.then_some(tag))
+            .collect()
+    }
+    
+    /// Returns true if this scenario carries `tag`.
+    pub fn has_tag(&self, tag: ScenarioTag) -> bool {
+        self.tags().contains(&tag)
+    }
+    
+    /// Returns the options and runner settings this scenario honors:
+    /// [`COMMON_KNOBS`] plus its own.
+    pub fn knobs(&self) -> Vec<&'static str> {
+        let own: &[&'static str] = match self {
+            ScenarioId::SplitBrain | ScenarioId::Byzantine | ScenarioId::SlowLoris => &["--agents"],
+            ScenarioId::ScaleLimit => &["--threads", "per-agent memory budget (50 MiB)"],
+            ScenarioId::AdaptiveSwarm | ScenarioId::ChaosStorm | ScenarioId::NetworkHell
+            | ScenarioId::ZombieApocalypse | ScenarioId::BlackoutSurvival | ScenarioId::Occlusion
+            | ScenarioId::MixedFleet => &["--threads"],
+            ScenarioId::GpsDenied => &["GPS denial window: 25%-50% of the run"],
+            ScenarioId::TimeWarp | ScenarioId::TimeTornado => &["--sensor-latency"],
+            ScenarioId::Convoy => &["--threads", "convoy speed scales with --duration"],
+            ScenarioId::ZombieRestart => &["fault plan: crash, stall and amnesia"],
+            ScenarioId::ParameterShock => &["fault plan: gating x0.5, max_age x2, H3 resolution 9"],
+            ScenarioId::BoundaryCrossing => &["--threads", "shards: 4 bands of H3 resolution 10 cells"],
+            ScenarioId::HybridLearning => &["--gt-schedule", "ground truth on one epoch in 3"],
+            _ => &[],
+        };
+        COMMON_KNOBS.iter().chain(own).copied().collect()
+    }
+    
+    /// Returns everything known about this scenario.
+    pub fn info(&self) -> ScenarioInfo {
+        ScenarioInfo {
+            name: self.name(),
+            description: self.description(),
+            timing: self.timing(),
+            agents: self.agents(),
+            entities: self.entities(),
+            pass_criteria: self.pass_criteria(),
+            tags: self.tags(),
+            knobs: self.knobs(),
+        }
+    }
+    
+    /// Returns every scenario carrying `tag`.
+    pub fn with_tag(tag: ScenarioTag) -> Vec<ScenarioId> {
+        Self::all().into_iter().filter(|s| s.has_tag(tag)).collect()
+    }
+    
+    /// Resolves a `--scenario` argument: `all`, `tag:<tag>` or a single
+    /// scenario name.
+    pub fn select(spec: &str) -> Result<Vec<ScenarioId>, String> {
+        if spec.eq_ignore_ascii_case("all") {
+            return Ok(Self::all());
+        }
+        if let Some(tag) = spec.strip_prefix("tag:") {
+            return Ok(Self::with_tag(tag.parse()?));
+        }
+        Ok(vec![spec.parse()?])
+    }
+    
+    /// Returns the agent-local faults this scenario injects into a run of
+    /// `target_ticks` ticks (empty for most scenarios).
+    pub fn fault_plan(&self, target_ticks: u64) -> FaultPlan {
+        match self {
+            // Crash at 25%, a 2s stall at 40% and amnesia for a quarter of
+            // the 16-agent swarm at 60%
+            ScenarioId::ZombieRestart => {
+                let stall_ticks = (2 * self.timing().tick_rate_hz as u64).min(target_ticks / 10);
+                (0..4).fold(
+                    FaultPlan::new()
+                        .with_crash(target_ticks / 4, 5)
+                        .with_stall(target_ticks * 2 / 5, 10, stall_ticks),
+                    |plan, agent| plan.with_amnesia(target_ticks * 3 / 5, agent),
+                )
+            }
+            // Halved gating at 25%, doubled max_age at 50% and coarser
+            // cells at 75%, each on top of the last
+            ScenarioId::ParameterShock => FaultPlan::new()
+                .with_perturbation(target_ticks / 4, ConfigPerturbation::GatingThreshold { factor: 0.5 })
+                .with_perturbation(target_ticks / 2, ConfigPerturbation::MaxAge { factor: 2.0 })
+                .with_perturbation(target_ticks * 3 / 4, ConfigPerturbation::H3Resolution { resolution: 9 }),
+            _ => FaultPlan::new(),
+        }
+    }
+    
+    /// Returns the most memory any one agent may hold, by
+    /// [`SimulatedAgent::memory_estimate_bytes`](crate::SimulatedAgent::memory_estimate_bytes),
+    /// before the run fails (None: unbudgeted).
+    pub fn agent_memory_budget_bytes(&self) -> Option<u64> {
+        match self {
+            ScenarioId::ScaleLimit => Some(50 * 1024 * 1024),
+            _ => None,
         }
     }
     
@@ -254,6 +803,7 @@ impl ScenarioId {
             ScenarioId::NetworkHell |
             ScenarioId::TimeTornado |
             ScenarioId::ZombieApocalypse |
+            ScenarioId::ZombieRestart |
             ScenarioId::RapidFire |
             ScenarioId::BlackoutSurvival
         )
@@ -294,6 +844,7 @@ impl std::str::FromStr for ScenarioId {
             "network_hell" | "networkhell" | "dst-010" => Ok(ScenarioId::NetworkHell),
             "time_tornado" | "timetornado" | "dst-011" => Ok(ScenarioId::TimeTornado),
             "zombie_apocalypse" | "zombieapocalypse" | "dst-012" => Ok(ScenarioId::ZombieApocalypse),
+            "zombie_restart" | "zombierestart" | "dst-024" => Ok(ScenarioId::ZombieRestart),
             "rapid_fire" | "rapidfire" | "dst-013" => Ok(ScenarioId::RapidFire),
             // Evolutionary
             "evo_war" | "evowar" | "dst-014" => Ok(ScenarioId::EvoWar),
@@ -305,10 +856,72 @@ impl std::str::FromStr for ScenarioId {
             "common_bias" | "commonbias" | "dst-020" => Ok(ScenarioId::CommonBias),
             "heavy_tail" | "heavytail" | "dst-021" => Ok(ScenarioId::HeavyTail),
             "sensor_drift" | "sensordrift" | "dst-022" => Ok(ScenarioId::SensorDrift),
+            "multi_sensor_bias" | "multisensorbias" | "dst-023" => Ok(ScenarioId::MultiSensorBias),
+            "clock_skew" | "clockskew" | "dst-025" => Ok(ScenarioId::ClockSkew),
+            "occlusion" | "dst-026" => Ok(ScenarioId::Occlusion),
+            "mixed_fleet" | "mixedfleet" | "dst-027" => Ok(ScenarioId::MixedFleet),
+            "clutter" | "dst-028" => Ok(ScenarioId::Clutter),
+            "gps_denied" | "gpsdenied" | "dst-029" => Ok(ScenarioId::GpsDenied),
+            "convoy" | "dst-030" => Ok(ScenarioId::Convoy),
+            "triage" | "dst-031" => Ok(ScenarioId::Triage),
+            "hybrid_learning" | "hybridlearning" | "dst-032" => Ok(ScenarioId::HybridLearning),
+            "degraded_altitude" | "degradedaltitude" | "dst-033" => Ok(ScenarioId::DegradedAltitude),
+            "parameter_shock" | "parametershock" | "dst-034" => Ok(ScenarioId::ParameterShock),
+            "boundary_crossing" | "boundarycrossing" | "dst-035" => Ok(ScenarioId::BoundaryCrossing),
             // Groups
-            "extreme" => Err("Use --scenario extreme (or all) for extreme scenarios".to_string()),
-            "evolutionary" => Err("Use --scenario evolutionary (or all) for evolutionary scenarios".to_string()),
-            _ => Err(format!("Unknown scenario: {}", s)),
+            "extreme" => Err("Use --scenario tag:extreme (or all) for extreme scenarios".to_string()),
+            "evolutionary" => Err("Use --scenario tag:evolutionary (or all) for evolutionary scenarios".to_string()),
+            _ => Err(format!(
+                "Unknown scenario: {} (available: {}, all, or tag:<{}>)",
+                s,
+                join_names(ScenarioId::all().iter().map(|id| id.name()), ", "),
+                join_names(ScenarioTag::ALL.iter().map(|t| t.name()), "|"),
+            )),
+        }
+    }
+}
+
+/// Names joined with `separator`, for error messages.
+fn join_names<'a>(names: impl Iterator<Item = &'a str>, separator: &str) -> String {
+    names.collect::<Vec<_>>().join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_every_scenario_has_metadata() {
+        for scenario in ScenarioId::all() {
+            let info = scenario.info();
+            assert!(!info.description.is_empty(), "{scenario}");
+            assert!(!info.pass_criteria.is_empty(), "{scenario}");
+            assert!(info.entities > 0, "{scenario}");
+            assert!(!matches!(info.agents, AgentCount::Fixed(0)), "{scenario}");
+            assert!(info.timing.default_duration_secs > 0.0, "{scenario}");
+            assert!(info.knobs.len() >= COMMON_KNOBS.len(), "{scenario}");
+            assert_eq!(info.name.parse::<ScenarioId>(), Ok(scenario));
+        }
+        
+        // Every tag selects something, and extreme agrees with is_extreme
+        for tag in ScenarioTag::ALL {
+            assert!(!ScenarioId::with_tag(tag).is_empty(), "{tag}");
+            assert_eq!(tag.name().parse::<ScenarioTag>(), Ok(tag));
+        }
+        assert_eq!(ScenarioId::with_tag(ScenarioTag::Extreme), ScenarioId::extreme());
+    }
+    
+    #[test]
+    fn test_select_by_name_tag_and_all() {
+        assert_eq!(ScenarioId::select("all").unwrap(), ScenarioId::all());
+        assert_eq!(ScenarioId::select("dst-007").unwrap(), vec![ScenarioId::AdaptiveSwarm]);
+        assert!(ScenarioId::select("tag:network").unwrap().contains(&ScenarioId::NetworkHell));
+        assert!(ScenarioId::select("tag:bogus").is_err());
+        
+        // The unknown-scenario error lists every scenario
+        let err = ScenarioId::select("warp_drive").unwrap_err();
+        for scenario in ScenarioId::all() {
+            assert!(err.contains(scenario.name()), "{scenario} missing from: {err}");
         }
     }
 }
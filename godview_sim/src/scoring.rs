@@ -0,0 +1,954 @@
+//! Multi-object tracking scores (CLEAR MOT).
+//!
+//! Position RMS only scores the tracks an agent can find for each entity, so
+//! a ghost track or an entity nobody tracks never shows up in it. Each tick
+//! scored here assigns ground truth to tracks one-to-one by minimum total
+//! distance (the Hungarian algorithm, gated at [`DEFAULT_MATCH_GATE_M`]),
+//! then counts:
+//!
+//! - **misses**: entities left without a track
+//! - **false positives**: tracks left without an entity
+//! - **ID switches**: entities matched to a different track than last time
+//!
+//! MOTA folds the three into one accuracy, `1 - (misses + false positives +
+//! ID switches) / ground truth`; MOTP is the mean distance of the matches.
+//!
+//! A Highlander rename is not an ID switch, but anything downstream keyed on
+//! `canonical_id` still sees a new ID. [`IdChurn`] counts every change of the
+//! canonical ID an entity is matched to, renames included.
+//!
+//! [`label_tracks`] names the entity each track is tracking by the same
+//! assignment, for exports to show, and [`TrackMultiplicity`] counts how
+//! many tracks each entity has: one too many is a ghost of it. Both read
+//! ground truth, so they are for debugging only and never reach agents.
+//!
+//! [`ClutterSampler`] attributes false tracks to the sensor clutter that
+//! started them, using the readings' ground-truth `is_clutter` flag.
+//!
+//! [`CoastingSampler`] scores track states updated this tick apart from
+//! those coasting on prediction, so a blended RMS can't hide which of the
+//! two is off, and measures how fast error grows per coasted second.
+
+use crate::agent::SimulatedAgent;
+use crate::oracle::SensorReading;
+use godview_core::UniqueTrack;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use uuid::Uuid;
+
+/// Farthest (m) a track may be from an entity and still count as tracking it.
+pub const DEFAULT_MATCH_GATE_M: f64 = 5.0;
+
+/// One ground-truth entity assigned to one track.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Match {
+    /// Index into the ground truth
+    pub truth: usize,
+    
+    /// Index into the tracks
+    pub track: usize,
+    
+    /// Distance between the two (m)
+    pub distance: f64,
+}
+
+/// Assigns `truth` to `tracks` one-to-one, matching as many pairs within
+/// `gate_m` as possible and, among those assignments, minimizing the total
+/// distance. Returns the matches in ground-truth order.
+///
+/// Only pairs within the gate can match, so the problem splits into the
+/// connected groups of nearby entities and tracks, each solved on its own.
+pub fn assign(truth: &[Vector3<f64>], tracks: &[Vector3<f64>], gate_m: f64) -> Vec<Match> {
+    let cell = |p: &Vector3<f64>| ((p.x / gate_m).floor() as i64, (p.y / gate_m).floor() as i64);
+    let mut grid: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    for (j, position) in tracks.iter().enumerate() {
+        grid.entry(cell(position)).or_default().push(j);
+    }
+    
+    // Union-find over truth (0..n) and tracks (n..n+m)
+    let n = truth.len();
+    let mut parent: Vec<usize> = (0..n + tracks.len()).collect();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    
+    let mut gated: HashMap<(usize, usize), f64> = HashMap::new();
+    for (i, position) in truth.iter().enumerate() {
+        let (cx, cy) = cell(position);
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for &j in grid.get(&(cx + dx, cy + dy)).into_iter().flatten() {
+                    let distance = (position - tracks[j]).norm();
+                    if distance <= gate_m {
+                        gated.insert((i, j), distance);
+                        let (a, b) = (root(&mut parent, i), root(&mut parent, n + j));
+                        parent[a] = b;
+                    }
+                }
+            }
+        }
+    }
+    
+    let mut groups: BTreeMap<usize, (Vec<usize>, Vec<usize>)> = BTreeMap::new();
+    for i in 0..n {
+        let r = root(&mut parent, i);
+        groups.entry(r).or_default().0.push(i);
+    }
+    for j in 0..tracks.len() {
+        let r = root(&mut parent, n + j);
+        groups.entry(r).or_default().1.push(j);
+    }
+    
+    let mut matches = Vec::new();
+    for (rows, cols) in groups.values() {
+        if rows.is_empty() || cols.is_empty() {
+            continue;
+        }
+        
+        // Ungated pairs cost more than any full set of gated ones, so the
+        // most gated matches win before distance is considered
+        let unmatched = gate_m * (rows.len().min(cols.len()) + 1) as f64;
+        let cost = |i: usize, j: usize| gated.get(&(i, j)).copied().unwrap_or(unmatched);
+        
+        let pairs: Vec<(usize, usize)> = if rows.len() <= cols.len() {
+            let table: Vec<Vec<f64>> = rows.iter().map(|&i| cols.iter().map(|&j| cost(i, j)).collect()).collect();
+            hungarian(&table).into_iter().enumerate().map(|(r, c)| (rows[r], cols[c])).collect()
+        } else {
+            let table: Vec<Vec<f64>> = cols.iter().map(|&j| rows.iter().map(|&i| cost(i, j)).collect()).collect();
+            hungarian(&table).into_iter().enumerate().map(|(c, r)| (rows[r], cols[c])).collect()
+        };
+        
+        matches.extend(pairs.into_iter().filter_map(|(i, j)| {
+            gated.get(&(i, j)).map(|&distance| Match { truth: i, track: j, distance })
+        }));
+    }
+    matches.sort_by_key(|m| m.truth);
+    matches
+}
+
+/// Minimum-cost assignment of each row of `cost` (no more rows than
+/// columns) to a distinct column. Returns each row's column.
+fn hungarian(cost: &[Vec<f64>]) -> Vec<usize> {
+    let n = cost.len();
+    let m = cost.first().map_or(0, Vec::len);
+    
+    // Potentials and the row assigned to each column, 1-indexed with 0 as
+    // the sentinel
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    let mut row_of = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+    
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut j0 = 0;
+        let mut min_slack = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = row_of[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let slack = cost[i0 - 1][j - 1] - u[i0] - v[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = j0;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if row_of[j0] == 0 {
+                break;
+            }
+        }
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+        }
+    }
+    
+    let mut assignment = vec![0; n];
+    for (j, &i) in row_of.iter().enumerate().skip(1) {
+        if i != 0 {
+            assignment[i - 1] = j - 1;
+        }
+    }
+    assignment
+}
+
+/// Which entity a track is tracking, by ground truth.
+///
+/// Debug only: agents never see ground truth, and nothing in a run may act
+/// on a label. Exports carry labels to make failed runs readable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TruthLabel {
+    /// Entity [`assign`] matches the track to (None: a false positive)
+    pub truth_id: Option<u64>,
+    
+    /// Distance to that entity, or to the nearest entity if unmatched (m;
+    /// None without ground truth)
+    pub error_m: Option<f64>,
+}
+
+/// Labels each of `tracks` with the entity in `(entity_id, position)`
+/// ground truth that [`assign`] matches it to within `gate_m`, in track
+/// order. Debug only (see [`TruthLabel`]).
+pub fn label_tracks(ground_truth: &[(u64, Vector3<f64>)], tracks: &[Vector3<f64>], gate_m: f64) -> Vec<TruthLabel> {
+    let truth: Vec<Vector3<f64>> = ground_truth.iter().map(|(_, p)| *p).collect();
+    let mut labels: Vec<TruthLabel> = tracks.iter()
+        .map(|position| TruthLabel { truth_id: None, error_m: nearest(&truth, position).map(|(_, d)| d) })
+        .collect();
+    for matched in assign(&truth, tracks, gate_m) {
+        labels[matched.track] = TruthLabel {
+            truth_id: Some(ground_truth[matched.truth].0),
+            error_m: Some(matched.distance),
+        };
+    }
+    labels
+}
+
+/// Index of and distance to the entity nearest `position`, if any.
+fn nearest(truth: &[Vector3<f64>], position: &Vector3<f64>) -> Option<(usize, f64)> {
+    truth.iter()
+        .enumerate()
+        .map(|(i, t)| (i, (t - position).norm()))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// A track as seen by the scorer.
+#[derive(Debug, Clone, Copy)]
+pub struct MotTrack<'a> {
+    /// Identity the track is reported under
+    pub id: Uuid,
+    
+    /// Current position estimate
+    pub position: Vector3<f64>,
+    
+    /// Other IDs the track is known by. An entity that moves to a track
+    /// listing its previous track's ID (a Highlander merge) keeps its identity.
+    pub aliases: Option<&'a HashSet<Uuid>>,
+}
+
+impl<'a> MotTrack<'a> {
+    /// A track known only by `id`.
+    pub fn new(id: Uuid, position: Vector3<f64>) -> Self {
+        Self { id, position, aliases: None }
+    }
+    
+    /// A track under its canonical ID, aliased by every ID it has observed.
+    pub fn from_track(track: &'a UniqueTrack) -> Self {
+        Self {
+            id: track.canonical_id,
+            position: track.position(),
+            aliases: Some(&track.observed_ids),
+        }
+    }
+    
+    fn continues(&self, previous: &Uuid) -> bool {
+        self.id == *previous || self.aliases.is_some_and(|a| a.contains(previous))
+    }
+}
+
+/// CLEAR MOT counts, summed over scored frames (and agents).
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct MotMetrics {
+    /// Frames scored
+    pub frames: u64,
+    
+    /// Ground-truth entities summed over frames
+    pub ground_truth: u64,
+    
+    /// Entity-track matches
+    pub matches: u64,
+    
+    /// Entities with no track within the gate
+    pub misses: u64,
+    
+    /// Tracks with no entity within the gate
+    pub false_positives: u64,
+    
+    /// Entities matched to a different track than at their previous match
+    pub id_switches: u64,
+    
+    /// Sum of match distances (m)
+    pub distance_sum: f64,
+}
+
+impl MotMetrics {
+    /// Multi-object tracking accuracy: 1.0 is perfect, and it goes negative
+    /// once errors outnumber entities (1.0 with no ground truth).
+    pub fn mota(&self) -> f64 {
+        if self.ground_truth == 0 {
+            return 1.0;
+        }
+        1.0 - (self.misses + self.false_positives + self.id_switches) as f64 / self.ground_truth as f64
+    }
+    
+    /// Multi-object tracking precision: mean match distance (m), 0.0 with no
+    /// matches.
+    pub fn motp(&self) -> f64 {
+        if self.matches == 0 {
+            return 0.0;
+        }
+        self.distance_sum / self.matches as f64
+    }
+    
+    /// Adds `other`'s counts to these.
+    pub fn merge(&mut self, other: &MotMetrics) {
+        self.frames += other.frames;
+        self.ground_truth += other.ground_truth;
+        self.matches += other.matches;
+        self.misses += other.misses;
+        self.false_positives += other.false_positives;
+        self.id_switches += other.id_switches;
+        self.distance_sum += other.distance_sum;
+    }
+}
+
+/// How many tracks each entity had, summed over scored frames (and
+/// agents). A track belongs to the entity it is matched to, or if
+/// unmatched to the nearest entity within the gate; a track near no entity
+/// belongs to none. Debug only (see [`TruthLabel`]).
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct TrackMultiplicity {
+    /// Entities summed over frames
+    pub entity_frames: u64,
+    
+    /// Of those, entities with two or more tracks
+    pub duplicated_frames: u64,
+    
+    /// Tracks beyond each entity's first, summed over frames: ghosts of
+    /// tracked entities
+    pub extra_tracks: u64,
+    
+    /// Most tracks any entity had in one frame
+    pub max: u64,
+}
+
+impl TrackMultiplicity {
+    /// Counts one frame's tracks per entity.
+    fn observe(&mut self, counts: &[u64]) {
+        self.entity_frames += counts.len() as u64;
+        for &n in counts {
+            self.duplicated_frames += u64::from(n > 1);
+            self.extra_tracks += n.saturating_sub(1);
+            self.max = self.max.max(n);
+        }
+    }
+    
+    /// Fraction of entity frames with two or more tracks (0.0 with none).
+    pub fn duplicated_rate(&self) -> f64 {
+        if self.entity_frames == 0 {
+            return 0.0;
+        }
+        self.duplicated_frames as f64 / self.entity_frames as f64
+    }
+    
+    /// Adds `other`'s counts to these.
+    pub fn merge(&mut self, other: &TrackMultiplicity) {
+        self.entity_frames += other.entity_frames;
+        self.duplicated_frames += other.duplicated_frames;
+        self.extra_tracks += other.extra_tracks;
+        self.max = self.max.max(other.max);
+    }
+}
+
+/// How often the canonical ID of each entity's track changed, over the
+/// (agent, entity) pairs that were ever matched.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct IdChurn {
+    /// (agent, entity) pairs matched at least once
+    pub tracked: usize,
+    
+    /// Mean canonical ID changes per pair
+    pub mean: f64,
+    
+    /// Most canonical ID changes of any pair
+    pub max: u64,
+}
+
+impl IdChurn {
+    /// Summarizes per-pair change counts.
+    pub fn from_counts(counts: impl IntoIterator<Item = u64>) -> Self {
+        let counts: Vec<u64> = counts.into_iter().collect();
+        if counts.is_empty() {
+            return Self::default();
+        }
+        Self {
+            tracked: counts.len(),
+            mean: counts.iter().sum::<u64>() as f64 / counts.len() as f64,
+            max: counts.iter().copied().max().unwrap_or(0),
+        }
+    }
+}
+
+/// Scores one tracker frame by frame, remembering each entity's last track.
+#[derive(Debug, Clone)]
+pub struct MotAccumulator {
+    gate_m: f64,
+    last_match: HashMap<u64, Uuid>,
+    churn: BTreeMap<u64, u64>,
+    metrics: MotMetrics,
+    multiplicity: TrackMultiplicity,
+}
+
+impl MotAccumulator {
+    /// Creates an accumulator matching within `gate_m`.
+    pub fn new(gate_m: f64) -> Self {
+        Self {
+            gate_m,
+            last_match: HashMap::new(),
+            churn: BTreeMap::new(),
+            metrics: MotMetrics::default(),
+            multiplicity: TrackMultiplicity::default(),
+        }
+    }
+    
+    /// Scores one frame of `(entity_id, position)` ground truth against
+    /// `tracks`.
+    pub fn observe(&mut self, ground_truth: &[(u64, Vector3<f64>)], tracks: &[MotTrack<'_>]) {
+        let truth: Vec<Vector3<f64>> = ground_truth.iter().map(|(_, p)| *p).collect();
+        let positions: Vec<Vector3<f64>> = tracks.iter().map(|t| t.position).collect();
+        let matches = assign(&truth, &positions, self.gate_m);
+        
+        let m = &mut self.metrics;
+        m.frames += 1;
+        m.ground_truth += truth.len() as u64;
+        m.matches += matches.len() as u64;
+        m.misses += (truth.len() - matches.len()) as u64;
+        m.false_positives += (tracks.len() - matches.len()) as u64;
+        
+        // Matched tracks, then unmatched ones near an entity, per entity
+        let mut counts = vec![0; truth.len()];
+        let mut matched_tracks = vec![false; tracks.len()];
+        for matched in &matches {
+            counts[matched.truth] += 1;
+            matched_tracks[matched.track] = true;
+        }
+        for (position, _) in positions.iter().zip(&matched_tracks).filter(|(_, &matched)| !matched) {
+            if let Some((i, _)) = nearest(&truth, position).filter(|&(_, d)| d <= self.gate_m) {
+                counts[i] += 1;
+            }
+        }
+        self.multiplicity.observe(&counts);
+        
+        for matched in matches {
+            let entity_id = ground_truth[matched.truth].0;
+            let track = &tracks[matched.track];
+            m.distance_sum += matched.distance;
+            let changes = self.churn.entry(entity_id).or_insert(0);
+            if let Some(previous) = self.last_match.insert(entity_id, track.id) {
+                if !track.continues(&previous) {
+                    m.id_switches += 1;
+                }
+                if track.id != previous {
+                    *changes += 1;
+                }
+            }
+        }
+    }
+    
+    /// Counts so far.
+    pub fn metrics(&self) -> MotMetrics {
+        self.metrics
+    }
+    
+    /// Canonical ID changes of each entity matched so far, by entity ID.
+    pub fn churn(&self) -> &BTreeMap<u64, u64> {
+        &self.churn
+    }
+    
+    /// Tracks per entity so far.
+    pub fn multiplicity(&self) -> TrackMultiplicity {
+        self.multiplicity
+    }
+}
+
+/// Per-agent MOT scoring, collected once the warm-up window has elapsed.
+pub struct MotSampler {
+    warmup_secs: f64,
+    gate_m: f64,
+    agents: BTreeMap<u64, MotAccumulator>,
+}
+
+impl MotSampler {
+    /// Creates a sampler that ignores frames before `warmup_secs`.
+    pub fn new(warmup_secs: f64) -> Self {
+        Self {
+            warmup_secs,
+            gate_m: DEFAULT_MATCH_GATE_M,
+            agents: BTreeMap::new(),
+        }
+    }
+    
+    /// Sets the match gate (m).
+    pub fn with_gate(mut self, gate_m: f64) -> Self {
+        self.gate_m = gate_m;
+        self
+    }
+    
+    /// Scores every agent's tracks against `ground_truth` at `time_secs`.
+    pub fn sample<'a>(
+        &mut self,
+        time_secs: f64,
+        agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+        ground_truth: &[(u64, Vector3<f64>)],
+    ) {
+        if time_secs < self.warmup_secs {
+            return;
+        }
+        
+        for agent in agents {
+            let mut tracks: Vec<MotTrack<'_>> = agent.inner().track_manager.tracks()
+                .map(MotTrack::from_track)
+                .collect();
+            tracks.sort_by_key(|t| t.id);
+            self.agents
+                .entry(agent.agent_index())
+                .or_insert_with(|| MotAccumulator::new(self.gate_m))
+                .observe(ground_truth, &tracks);
+        }
+    }
+    
+    /// Counts summed over agents.
+    pub fn metrics(&self) -> MotMetrics {
+        self.agents.values().fold(MotMetrics::default(), |mut total, a| {
+            total.merge(&a.metrics());
+            total
+        })
+    }
+    
+    /// Canonical ID churn over every agent's view of every entity.
+    pub fn id_churn(&self) -> IdChurn {
+        IdChurn::from_counts(self.agents.values().flat_map(|a| a.churn().values().copied()))
+    }
+    
+    /// Tracks per entity summed over agents.
+    pub fn multiplicity(&self) -> TrackMultiplicity {
+        self.agents.values().fold(TrackMultiplicity::default(), |mut total, a| {
+            total.merge(&a.multiplicity());
+            total
+        })
+    }
+}
+
+/// Position error of updated and coasting track states, summed over
+/// scored frames (and agents).
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct CoastingMetrics {
+    /// Matched states fused since the last tick
+    pub updated_samples: u64,
+    
+    /// RMS error of the updated states (m)
+    pub updated_rms: f64,
+    
+    /// Matched states coasting on prediction for a tick or more
+    pub coasting_samples: u64,
+    
+    /// RMS error of the coasting states (m)
+    pub coasting_rms: f64,
+    
+    /// Longest coast of a matched state (s)
+    pub max_coast_secs: f64,
+    
+    /// Growth of the mean squared error per coasted second (m²/s): the
+    /// least-squares slope of each coasting age's mean squared error
+    /// against the age, weighted by its samples, updated states included
+    /// as age 0. An entity moving as a random walk of spectral density `q`
+    /// per axis grows it by `3q` (0.0 without two ages to compare)
+    pub error_growth_m2_per_s: f64,
+}
+
+/// Matches each agent's confirmed and coasting tracks to ground truth as
+/// [`MotSampler`] does and bins the squared errors by coasting age, once
+/// the warm-up window has elapsed.
+#[derive(Debug, Clone)]
+pub struct CoastingSampler {
+    warmup_secs: f64,
+    tick_secs: f64,
+    gate_m: f64,
+    /// Per coasting age in ticks: (matched states, sum of squared errors)
+    by_age: BTreeMap<u32, (u64, f64)>,
+}
+
+impl CoastingSampler {
+    /// Creates a sampler that ignores frames before `warmup_secs`, for
+    /// agents ticking every `tick_secs`.
+    pub fn new(warmup_secs: f64, tick_secs: f64) -> Self {
+        Self {
+            warmup_secs,
+            tick_secs,
+            gate_m: DEFAULT_MATCH_GATE_M,
+            by_age: BTreeMap::new(),
+        }
+    }
+    
+    /// Sets the match gate (m). Coasting error past the gate goes
+    /// unscored, so the gate caps the growth that can be measured.
+    pub fn with_gate(mut self, gate_m: f64) -> Self {
+        self.gate_m = gate_m;
+        self
+    }
+    
+    /// Scores every agent's track states against `ground_truth` at
+    /// `time_secs`.
+    pub fn sample<'a>(
+        &mut self,
+        time_secs: f64,
+        agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+        ground_truth: &[(u64, Vector3<f64>)],
+    ) {
+        if time_secs < self.warmup_secs {
+            return;
+        }
+        
+        for agent in agents {
+            let tracks: Vec<(Vector3<f64>, u32)> = agent.track_states()
+                .into_iter()
+                .map(|(_, position, coasting_ticks)| (position, coasting_ticks))
+                .collect();
+            self.observe(ground_truth, &tracks);
+        }
+    }
+    
+    /// Scores one frame of `(entity_id, position)` ground truth against
+    /// `(position, coasting_ticks)` track states.
+    pub fn observe(&mut self, ground_truth: &[(u64, Vector3<f64>)], tracks: &[(Vector3<f64>, u32)]) {
+        let truth: Vec<Vector3<f64>> = ground_truth.iter().map(|(_, p)| *p).collect();
+        let positions: Vec<Vector3<f64>> = tracks.iter().map(|(p, _)| *p).collect();
+        for matched in assign(&truth, &positions, self.gate_m) {
+            let bin = self.by_age.entry(tracks[matched.track].1).or_default();
+            bin.0 += 1;
+            bin.1 += matched.distance * matched.distance;
+        }
+    }
+    
+    /// Metrics so far.
+    pub fn metrics(&self) -> CoastingMetrics {
+        let rms = |coasting: bool| {
+            let (n, sum) = self.by_age.iter()
+                .filter(|(&age, _)| (age > 0) == coasting)
+                .fold((0, 0.0), |(n, sum), (_, &(count, squares))| (n + count, sum + squares));
+            (n, if n > 0 { (sum / n as f64).sqrt() } else { 0.0 })
+        };
+        let (updated_samples, updated_rms) = rms(false);
+        let (coasting_samples, coasting_rms) = rms(true);
+        let max_age = self.by_age.keys().next_back().copied().unwrap_or(0);
+        
+        // Weighted least squares of mean squared error on coasted seconds
+        let points: Vec<(f64, f64, f64)> = self.by_age.iter()
+            .map(|(&age, &(n, sum))| (n as f64, age as f64 * self.tick_secs, sum / n as f64))
+            .collect();
+        let weight: f64 = points.iter().map(|p| p.0).sum();
+        let mean_secs = points.iter().map(|p| p.0 * p.1).sum::<f64>() / weight;
+        let mean_mse = points.iter().map(|p| p.0 * p.2).sum::<f64>() / weight;
+        let spread: f64 = points.iter().map(|p| p.0 * (p.1 - mean_secs).powi(2)).sum();
+        let error_growth_m2_per_s = if spread > 0.0 {
+            points.iter().map(|p| p.0 * (p.1 - mean_secs) * (p.2 - mean_mse)).sum::<f64>() / spread
+        } else {
+            0.0
+        };
+        
+        CoastingMetrics {
+            updated_samples,
+            updated_rms,
+            coasting_samples,
+            coasting_rms,
+            max_coast_secs: max_age as f64 * self.tick_secs,
+            error_growth_m2_per_s,
+        }
+    }
+}
+
+/// False tracks started by clutter readings, summed over agents.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ClutterMetrics {
+    /// Clutter readings handed to agents
+    pub clutter_readings: u64,
+    
+    /// Clutter readings that started a track of their own
+    pub clutter_tracks: u64,
+    
+    /// Of those tracks, the ones that were confirmed
+    pub confirmed_clutter_tracks: u64,
+    
+    /// Agent frames sampled
+    pub frames: u64,
+    
+    /// Live confirmed clutter tracks, summed over sampled agent frames
+    pub false_tracks: u64,
+}
+
+impl ClutterMetrics {
+    /// Confirmed clutter tracks alive per agent per frame.
+    pub fn false_track_rate(&self) -> f64 {
+        if self.frames == 0 {
+            return 0.0;
+        }
+        self.false_tracks as f64 / self.frames as f64
+    }
+    
+    /// Fraction of clutter tracks that were never confirmed (1.0 with none).
+    pub fn suppression(&self) -> f64 {
+        if self.clutter_tracks == 0 {
+            return 1.0;
+        }
+        1.0 - self.confirmed_clutter_tracks as f64 / self.clutter_tracks as f64
+    }
+}
+
+/// Follows each track an agent starts from a clutter reading until it is
+/// confirmed or deleted.
+#[derive(Debug, Default)]
+pub struct ClutterSampler {
+    /// Per agent: clutter tracks not yet confirmed
+    tentative: BTreeMap<u64, Vec<Uuid>>,
+    
+    /// Per agent: confirmed clutter tracks, while they live
+    confirmed: BTreeMap<u64, Vec<Uuid>>,
+    
+    metrics: ClutterMetrics,
+}
+
+impl ClutterSampler {
+    /// Creates an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Notes which of the `readings` `agent` just ingested were clutter,
+    /// and which of those started a track.
+    pub fn observe(&mut self, agent: &SimulatedAgent, readings: &[SensorReading]) {
+        let tracks = &agent.inner().track_manager;
+        for reading in readings.iter().filter(|r| r.is_clutter) {
+            self.metrics.clutter_readings += 1;
+            // A track born of the reading is keyed by the agent's UUID for it
+            let Some(local) = agent.local_entity_uuid(reading.entity_id) else {
+                continue;
+            };
+            if tracks.get_track(&local).is_some() {
+                self.metrics.clutter_tracks += 1;
+                self.tentative.entry(agent.agent_index()).or_default().push(local);
+            }
+        }
+    }
+    
+    /// Promotes clutter tracks that were confirmed, forgets deleted ones,
+    /// and counts the confirmed ones still alive.
+    pub fn sample<'a>(&mut self, agents: impl IntoIterator<Item = &'a SimulatedAgent>) {
+        for agent in agents {
+            let tracks = &agent.inner().track_manager;
+            let index = agent.agent_index();
+            let mut promoted = Vec::new();
+            if let Some(tentative) = self.tentative.get_mut(&index) {
+                tentative.retain(|id| match tracks.get_track(id) {
+                    Some(track) if !track.is_tentative() => {
+                        promoted.push(*id);
+                        false
+                    }
+                    Some(_) => true,
+                    None => false,
+                });
+            }
+            self.metrics.confirmed_clutter_tracks += promoted.len() as u64;
+            let confirmed = self.confirmed.entry(index).or_default();
+            confirmed.extend(promoted);
+            confirmed.retain(|id| tracks.get_track(id).is_some());
+            self.metrics.frames += 1;
+            self.metrics.false_tracks += confirmed.len() as u64;
+        }
+    }
+    
+    /// Counts so far.
+    pub fn metrics(&self) -> ClutterMetrics {
+        self.metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn at(x: f64, y: f64) -> Vector3<f64> {
+        Vector3::new(x, y, 0.0)
+    }
+    
+    #[test]
+    fn test_assignment_is_optimal_not_greedy() {
+        // Greedy nearest-first would pair truth 0 with track 0 (1.0m) and
+        // leave truth 1 to track 1 (3.0m); the optimum is 2.0m + 1.5m
+        let truth = [at(0.0, 0.0), at(2.0, 0.0)];
+        let tracks = [at(1.0, 0.0), at(-2.0, 0.0)];
+        let matches = assign(&truth, &tracks, 10.0);
+        assert_eq!(matches.len(), 2);
+        assert_eq!((matches[0].truth, matches[0].track), (0, 1));
+        assert_eq!((matches[1].truth, matches[1].track), (1, 0));
+        
+        // Gating prefers more matches over a shorter total
+        let truth = [at(0.0, 0.0), at(4.0, 0.0)];
+        let tracks = [at(3.0, 0.0), at(7.5, 0.0)];
+        let matches = assign(&truth, &tracks, 4.0);
+        assert_eq!(matches.iter().map(|m| (m.truth, m.track)).collect::<Vec<_>>(), vec![(0, 0), (1, 1)]);
+        
+        // Nothing within the gate, nothing matched
+        assert!(assign(&[at(0.0, 0.0)], &[at(50.0, 0.0)], 5.0).is_empty());
+        assert!(assign(&[], &[at(0.0, 0.0)], 5.0).is_empty());
+    }
+    
+    #[test]
+    fn test_one_track_cannot_cover_two_entities() {
+        let mut scorer = MotAccumulator::new(5.0);
+        let ghost = Uuid::from_u128(9);
+        let track = Uuid::from_u128(1);
+        
+        // One track between two entities, plus a ghost far from both
+        scorer.observe(
+            &[(1, at(0.0, 0.0)), (2, at(2.0, 0.0))],
+            &[MotTrack::new(track, at(1.0, 0.0)), MotTrack::new(ghost, at(100.0, 0.0))],
+        );
+        let m = scorer.metrics();
+        assert_eq!((m.matches, m.misses, m.false_positives, m.id_switches), (1, 1, 1, 0));
+        assert!((m.motp() - 1.0).abs() < 1e-12);
+        assert!((m.mota() - 0.0).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_duplicate_tracks_count_against_their_entity() {
+        let mut scorer = MotAccumulator::new(5.0);
+        let truth = [(1, at(0.0, 0.0)), (2, at(50.0, 0.0)), (3, at(100.0, 0.0))];
+        let ids: Vec<Uuid> = (0..5).map(Uuid::from_u128).collect();
+        
+        // Entity 1 has a ghost beside its track, entity 2 one track, entity
+        // 3 none; a far-off false track belongs to nobody
+        let positions = [at(0.5, 0.0), at(3.0, 0.0), at(50.2, 0.0), at(200.0, 0.0)];
+        let tracks: Vec<MotTrack<'_>> = positions.iter().zip(&ids).map(|(p, id)| MotTrack::new(*id, *p)).collect();
+        scorer.observe(&truth, &tracks);
+        assert_eq!(scorer.multiplicity(), TrackMultiplicity { entity_frames: 3, duplicated_frames: 1, extra_tracks: 1, max: 2 });
+        assert!((scorer.multiplicity().duplicated_rate() - 1.0 / 3.0).abs() < 1e-12);
+        
+        // Labels name the matched entity and leave the rest unmatched
+        let labels = label_tracks(&truth, &positions, 5.0);
+        let ids: Vec<Option<u64>> = labels.iter().map(|l| l.truth_id).collect();
+        assert_eq!(ids, vec![Some(1), None, Some(2), None]);
+        assert!((labels[1].error_m.unwrap() - 3.0).abs() < 1e-12);
+        assert!((labels[3].error_m.unwrap() - 100.0).abs() < 1e-12);
+        assert_eq!(label_tracks(&[], &positions, 5.0)[0], TruthLabel { truth_id: None, error_m: None });
+    }
+    
+    #[test]
+    fn test_id_switches_ignore_highlander_renames() {
+        let mut scorer = MotAccumulator::new(5.0);
+        let (a, b, merged) = (Uuid::from_u128(5), Uuid::from_u128(7), Uuid::from_u128(2));
+        let truth = [(1, at(0.0, 0.0))];
+        
+        scorer.observe(&truth, &[MotTrack::new(a, at(0.5, 0.0))]);
+        
+        // Another track takes over the entity: a switch
+        scorer.observe(&truth, &[MotTrack::new(b, at(0.5, 0.0))]);
+        assert_eq!(scorer.metrics().id_switches, 1);
+        
+        // The same track renamed by a merge that kept `b`: not a switch
+        let aliases: HashSet<Uuid> = [b, merged].into_iter().collect();
+        let renamed = MotTrack { id: merged, position: at(0.5, 0.0), aliases: Some(&aliases) };
+        scorer.observe(&truth, &[renamed]);
+        
+        // A frame without a match keeps the last track for later frames
+        scorer.observe(&truth, &[]);
+        scorer.observe(&truth, &[renamed]);
+        
+        let m = scorer.metrics();
+        assert_eq!((m.frames, m.ground_truth, m.matches, m.misses, m.id_switches), (5, 5, 4, 1, 1));
+        // Both the switch and the rename changed the ID downstream sees
+        assert_eq!(scorer.churn()[&1], 2);
+        let churn = IdChurn::from_counts([scorer.churn()[&1], 0]);
+        assert_eq!((churn.tracked, churn.mean, churn.max), (2, 1.0, 2));
+        assert!((m.mota() - (1.0 - 2.0 / 5.0)).abs() < 1e-12);
+        
+        let mut total = MotMetrics::default();
+        total.merge(&m);
+        total.merge(&m);
+        assert_eq!(total.matches, 8);
+        assert!((total.mota() - m.mota()).abs() < 1e-12);
+    }    
+    #[test]
+    fn test_coasting_error_growth_matches_process_noise() {
+        use crate::oracle::Oracle;
+        use godview_core::{FilterTuning, GlobalHazardPacket, MeasurementModel, TrackManager, TrackingConfig};
+        use rand::SeedableRng;
+        use rand_distr::{Distribution, Normal};
+        
+        // 400 entities random-walk with the position noise density the
+        // filter is configured with; their tracks see one reading, then coast
+        let (tick_secs, coast_ticks, q) = (0.1, 20u32, 0.5);
+        let tuning = FilterTuning { position_noise_density: q, velocity_noise_density: 0.0, ..FilterTuning::default() };
+        let mut manager = TrackManager::new(TrackingConfig {
+            frame: Oracle::frame(),
+            motion: Some(tuning),
+            ..TrackingConfig::default()
+        });
+        let mut truth: Vec<(u64, Vector3<f64>)> = (0..400u64)
+            .map(|i| (i, at((i % 20) as f64 * 200.0, (i / 20) as f64 * 200.0)))
+            .collect();
+        for (id, p) in &truth {
+            let packet = GlobalHazardPacket {
+                entity_id: Uuid::from_u128(*id as u128 + 1),
+                position: [p.x, p.y, p.z],
+                velocity: [0.0; 3],
+                class_id: 1,
+                timestamp: 0.0,
+                confidence_score: 1.0,
+                measurement_model: MeasurementModel::default(),
+                priority: 0,
+            };
+            manager.process_packet(&packet, None, None).unwrap();
+        }
+        
+        let mut sampler = CoastingSampler::new(0.0, tick_secs).with_gate(50.0);
+        let step = Normal::new(0.0, (q * tick_secs).sqrt()).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(3);
+        let mut ages = Vec::new();
+        for tick in 0..=coast_ticks {
+            if tick > 0 {
+                manager.predict_tracks(tick_secs);
+                manager.age_tracks();
+                for (_, position) in &mut truth {
+                    *position += Vector3::from_fn(|_, _| step.sample(&mut rng));
+                }
+            }
+            let tracks: Vec<(Vector3<f64>, u32)> = manager.tracks().map(|t| (t.position(), t.age)).collect();
+            ages.push(tracks.iter().map(|t| t.1).max().unwrap());
+            sampler.observe(&truth, &tracks);
+        }
+        
+        assert_eq!(ages, (0..=coast_ticks).collect::<Vec<_>>(), "coasting age climbs one a tick");
+        let m = sampler.metrics();
+        assert_eq!((m.updated_samples, m.coasting_samples), (400, 400 * coast_ticks as u64));
+        assert!((m.max_coast_secs - 2.0).abs() < 1e-9);
+        assert!(m.coasting_rms > m.updated_rms);
+        // E|error|² = 3 q t for a random walk in three axes
+        let expected = 3.0 * q;
+        assert!((m.error_growth_m2_per_s - expected).abs() < 0.1 * expected,
+            "growth {:.3} m²/s, expected {expected}", m.error_growth_m2_per_s);
+    }
+}
@@ -0,0 +1,468 @@
+//! Sharded worlds: each agent answers for a set of H3 cells.
+//!
+//! A [`ShardMap`] assigns H3 cells ([`WorldShard`] footprints) to agents.
+//! With one installed ([`SwarmHarness::with_shards`]) an agent only senses
+//! entities read inside its own cells and only keeps tracks within
+//! [`ShardMap::margin_rings`] rings of them. When one of its tracks moves
+//! into a cell another agent owns, it hands the track over: its state goes
+//! straight to the new owner as a full-state packet under the track's ID
+//! and the old holder drops it. Each agent's retained tracks are indexed in a
+//! [`SpatialEngine`] of its own, rebuilt every tick.
+//!
+//! [`HandoffSampler`] scores the handoffs against ground truth: every time an
+//! entity crosses from one owner's cells into another's, whether the new
+//! owner picked up the old owner's track within [`HANDOFF_WINDOW_SECS`], how
+//! long it went without any track of the entity, and whether it held two at
+//! once.
+//!
+//! [`SwarmHarness::with_shards`]: crate::SwarmHarness::with_shards
+
+use crate::agent::SimulatedAgent;
+use crate::oracle::{Oracle, ORIGIN_LAT_LON};
+use godview_core::godview_tracking::UniqueTrack;
+use godview_core::{Entity, SpatialEngine, WorldShard};
+use h3o::{CellIndex, LatLng, Resolution};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use uuid::Uuid;
+
+/// Seconds after an entity crosses into another agent's cells within which
+/// the new owner must hold the old owner's track for the handoff to count.
+pub const HANDOFF_WINDOW_SECS: f64 = 1.0;
+
+/// Distance (m) within which a track counts as tracking an entity when
+/// handoffs are scored.
+pub const HANDOFF_MATCH_RADIUS_M: f64 = 5.0;
+
+/// Which agent answers for which H3 cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShardMap {
+    resolution: Resolution,
+    owners: BTreeMap<CellIndex, usize>,
+    margin_rings: u32,
+    handoff: bool,
+}
+
+impl ShardMap {
+    /// A map of `resolution` cells with none assigned yet. Agents keep
+    /// tracks one ring past their cells and hand tracks off.
+    pub fn new(resolution: Resolution) -> Self {
+        Self { resolution, owners: BTreeMap::new(), margin_rings: 1, handoff: true }
+    }
+    
+    /// The cells within `rings` of the world origin's cell split into
+    /// `agents` bands of longitude, agent 0 westmost. Bands hold as near
+    /// the same number of cells as the split allows.
+    pub fn bands(resolution: Resolution, rings: u32, agents: usize) -> Self {
+        let origin = LatLng::new(ORIGIN_LAT_LON.0, ORIGIN_LAT_LON.1)
+            .expect("origin is a valid location")
+            .to_cell(resolution);
+        let mut cells: Vec<CellIndex> = origin.grid_disk_safe(rings).collect();
+        cells.sort_by(|a, b| LatLng::from(*a).lng().total_cmp(&LatLng::from(*b).lng()).then(a.cmp(b)));
+        let per_agent = cells.len().div_ceil(agents.max(1));
+        let mut map = Self::new(resolution);
+        for (i, cell) in cells.into_iter().enumerate() {
+            map.owners.insert(cell, i / per_agent.max(1));
+        }
+        map
+    }
+    
+    /// Gives `agent` the cells in `cells`, taking them from any agent that
+    /// had them.
+    pub fn assign(mut self, agent: usize, cells: impl IntoIterator<Item = CellIndex>) -> Self {
+        for cell in cells {
+            self.owners.insert(cell, agent);
+        }
+        self
+    }
+    
+    /// Keeps tracks up to `rings` rings past an agent's cells (0: only
+    /// inside them).
+    pub fn with_margin_rings(mut self, rings: u32) -> Self {
+        self.margin_rings = rings;
+        self
+    }
+    
+    /// Turns off handoffs: tracks leaving an agent's cells reach the new
+    /// owner only through ordinary gossip, if at all.
+    pub fn without_handoff(mut self) -> Self {
+        self.handoff = false;
+        self
+    }
+    
+    /// Resolution of the cells.
+    pub fn resolution(&self) -> Resolution {
+        self.resolution
+    }
+    
+    /// Rings past its cells an agent keeps tracks in.
+    pub fn margin_rings(&self) -> u32 {
+        self.margin_rings
+    }
+    
+    /// Whether tracks are handed to the owner of the cell they move into.
+    pub fn hands_off(&self) -> bool {
+        self.handoff
+    }
+    
+    /// `agent`'s cells, in index order.
+    pub fn cells_of(&self, agent: usize) -> Vec<CellIndex> {
+        self.owners.iter().filter(|(_, owner)| **owner == agent).map(|(cell, _)| *cell).collect()
+    }
+    
+    /// An empty shard for each of `agent`'s cells.
+    pub fn shards_of(&self, agent: usize) -> Vec<WorldShard> {
+        self.cells_of(agent).into_iter().map(WorldShard::new).collect()
+    }
+    
+    /// WGS84 [latitude°, longitude°, altitude m] of a world position.
+    pub fn to_global(position: &Vector3<f64>) -> Option<[f64; 3]> {
+        let latlng = Oracle::frame().to_latlng(position.x, position.y).ok()?;
+        Some([latlng.lat(), latlng.lng(), position.z])
+    }
+    
+    /// The cell a world position falls in.
+    pub fn cell_at(&self, position: &Vector3<f64>) -> Option<CellIndex> {
+        let latlng = Oracle::frame().to_latlng(position.x, position.y).ok()?;
+        Some(latlng.to_cell(self.resolution))
+    }
+    
+    /// The agent owning `cell`, if any.
+    pub fn owner_of(&self, cell: CellIndex) -> Option<usize> {
+        self.owners.get(&cell).copied()
+    }
+    
+    /// The agent owning the cell a world position falls in, if any.
+    pub fn owner_at(&self, position: &Vector3<f64>) -> Option<usize> {
+        self.cell_at(position).and_then(|cell| self.owner_of(cell))
+    }
+    
+    /// Whether `agent` keeps a track in `cell`: an agent's cell lies within
+    /// [`Self::margin_rings`] of it.
+    pub fn retains(&self, agent: usize, cell: CellIndex) -> bool {
+        cell.grid_disk_safe(self.margin_rings).any(|near| self.owner_of(near) == Some(agent))
+    }
+}
+
+/// Per-agent state of a sharded harness: the spatial index of the tracks
+/// each agent kept.
+pub(crate) struct ShardState {
+    pub(crate) map: ShardMap,
+    /// Per agent, the tracks inside its own cells at the end of the last
+    /// tick: the ones it answers for and hands off
+    owned: Vec<BTreeSet<Uuid>>,
+    /// Per agent, its retained tracks by cell
+    engines: Vec<SpatialEngine>,
+    pub(crate) stats: ShardStats,
+}
+
+/// What a sharded harness has done to keep tracks with their owners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ShardStats {
+    /// Tracks handed to the owner of the cell they moved into
+    pub handoffs_sent: u64,
+    
+    /// Sensor readings dropped for falling outside the reading agent's cells
+    pub readings_outside: u64,
+    
+    /// Tracks dropped for moving past an agent's margin
+    pub tracks_dropped: u64,
+}
+
+impl ShardState {
+    pub(crate) fn new(map: ShardMap, agents: usize) -> Self {
+        Self {
+            owned: vec![BTreeSet::new(); agents],
+            engines: (0..agents).map(|_| SpatialEngine::new(map.resolution)).collect(),
+            map,
+            stats: ShardStats::default(),
+        }
+    }
+    
+    /// Agent `idx`'s index of the tracks it kept at the end of the last
+    /// tick.
+    pub(crate) fn engine(&self, idx: usize) -> Option<&SpatialEngine> {
+        self.engines.get(idx)
+    }
+    
+    /// Hands every track that has moved out of its holder's cells into
+    /// another agent's to that agent, in agent order: the new owner takes
+    /// its state ([`SimulatedAgent::accept_handoff`]) and the old holder
+    /// drops it. Tracks an agent only heard of in another agent's cells
+    /// aren't its to hand off.
+    pub(crate) fn hand_off(&mut self, agents: &mut [SimulatedAgent]) {
+        if !self.map.handoff {
+            return;
+        }
+        for from in 0..agents.len() {
+            // A merge since the last tick may have given an owned track a
+            // new canonical ID
+            let owned = &self.owned[from];
+            let outgoing: Vec<(usize, Uuid)> = agents[from].track_positions()
+                .into_iter()
+                .filter(|(id, _)| agents[from].track(id).is_some_and(|t| track_ids(t).any(|id| owned.contains(&id))))
+                .filter_map(|(id, position)| {
+                    let to = self.map.owner_at(&position)?;
+                    (to != from && to < agents.len()).then_some((to, id))
+                })
+                .collect();
+            for (to, id) in outgoing {
+                let Some(packet) = agents[from].handoff_packet(&id) else { continue };
+                agents[to].accept_handoff(from, &packet);
+                agents[from].drop_track(&id);
+                self.stats.handoffs_sent += 1;
+            }
+        }
+    }
+    
+    /// Drops each agent's tracks outside its margin, reindexes the rest and
+    /// notes which lie in its own cells.
+    pub(crate) fn prune(&mut self, agents: &mut [SimulatedAgent]) {
+        for (idx, agent) in agents.iter_mut().enumerate() {
+            let mut engine = SpatialEngine::new(self.map.resolution);
+            for shard in self.map.shards_of(idx) {
+                engine.shards.insert(shard.cell_id, shard);
+            }
+            let tracks = &agent.inner().track_manager;
+            let entities: Vec<Entity> = tracks.tracks()
+                .map(|t| {
+                    let (position, velocity) = (t.position(), t.velocity());
+                    Entity {
+                        id: t.canonical_id,
+                        position: [position.x, position.y, position.z],
+                        velocity: [velocity.x, velocity.y, velocity.z],
+                        entity_type: "track".to_string(),
+                        timestamp: (t.last_update * 1000.0) as i64,
+                        confidence: tracks.covariance_to_confidence(&t.covariance) as f32,
+                    }
+                })
+                .collect();
+            let mut owned = BTreeSet::new();
+            for mut entity in entities {
+                let position = Vector3::from(entity.position);
+                let cell = self.map.cell_at(&position).filter(|cell| self.map.retains(idx, *cell));
+                let (Some(cell), Some(global)) = (cell, ShardMap::to_global(&position)) else {
+                    if agent.drop_track(&entity.id) {
+                        self.stats.tracks_dropped += 1;
+                    }
+                    continue;
+                };
+                // A track stays the holder's until handed off, even if a
+                // merge carried it over the boundary after this tick's handoffs
+                let held = agent.track(&entity.id)
+                    .is_some_and(|t| track_ids(t).any(|id| self.owned[idx].contains(&id)));
+                if held || self.map.owner_of(cell) == Some(idx) {
+                    owned.insert(entity.id);
+                }
+                entity.position = global;
+                let _ = engine.update_entity(entity);
+            }
+            self.owned[idx] = owned;
+            self.engines[idx] = engine;
+        }
+    }
+}
+
+/// How a sharded run's boundary crossings went.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct HandoffMetrics {
+    /// Entities crossing from one owner's cells into another's, scored
+    pub crossings: u64,
+    
+    /// Crossings after which the new owner held the old owner's track
+    /// within [`HANDOFF_WINDOW_SECS`]
+    pub handed_off: u64,
+    
+    /// Crossings during whose window the new owner held two or more tracks
+    /// of the entity at once
+    pub duplicated: u64,
+    
+    /// Mean seconds the new owner went without a track of the entity after
+    /// a crossing
+    pub gap_mean_secs: f64,
+    
+    /// Longest such gap (capped at the window)
+    pub gap_max_secs: f64,
+}
+
+impl HandoffMetrics {
+    /// Fraction of crossings handed off (1.0 with none).
+    pub fn success_rate(&self) -> f64 {
+        if self.crossings == 0 {
+            return 1.0;
+        }
+        self.handed_off as f64 / self.crossings as f64
+    }
+    
+    /// Fraction of crossings duplicated (0.0 with none).
+    pub fn duplicate_rate(&self) -> f64 {
+        if self.crossings == 0 {
+            return 0.0;
+        }
+        self.duplicated as f64 / self.crossings as f64
+    }
+}
+
+/// A crossing whose window is still open.
+#[derive(Debug, Clone)]
+struct PendingHandoff {
+    entity: u64,
+    to: usize,
+    at_secs: f64,
+    /// IDs the old owner tracked the entity under
+    old_ids: BTreeSet<Uuid>,
+    gap_secs: Option<f64>,
+    handed_off: bool,
+    duplicated: bool,
+}
+
+/// Scores handoffs from ground truth each tick (see [`HandoffMetrics`]).
+#[derive(Debug, Clone, Default)]
+pub struct HandoffSampler {
+    owners: BTreeMap<u64, usize>,
+    /// IDs of each entity's owner's track as last seen, which outlive the
+    /// track once it's handed off
+    owner_ids: BTreeMap<u64, BTreeSet<Uuid>>,
+    pending: Vec<PendingHandoff>,
+    metrics: HandoffMetrics,
+    gap_sum: f64,
+}
+
+impl HandoffSampler {
+    /// A sampler with no crossings seen.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Checks every entity's owner at `time`, opens a crossing for each one
+    /// that changed owner and scores the open ones against `engine_of`
+    /// (each agent's [`SpatialEngine`] of its tracks).
+    pub fn sample<'a>(
+        &mut self,
+        time: f64,
+        map: &ShardMap,
+        agents: &[SimulatedAgent],
+        engine_of: impl Fn(usize) -> Option<&'a SpatialEngine>,
+        ground_truth: &[(u64, Vector3<f64>)],
+    ) {
+        let positions: BTreeMap<u64, Vector3<f64>> = ground_truth.iter().copied().collect();
+        for (&entity, position) in &positions {
+            let Some(owner) = map.owner_at(position) else { continue };
+            let previous = self.owners.insert(entity, owner);
+            if previous.is_none_or(|from| from == owner) {
+                continue;
+            }
+            // A crossing before the last one's window closed ends it early
+            if let Some(i) = self.pending.iter().position(|p| p.entity == entity) {
+                let open = self.pending.remove(i);
+                self.close(open);
+            }
+            let old_ids = self.owner_ids.remove(&entity).unwrap_or_default();
+            self.pending.push(PendingHandoff {
+                entity,
+                to: owner,
+                at_secs: time,
+                old_ids,
+                gap_secs: None,
+                handed_off: false,
+                duplicated: false,
+            });
+        }
+        
+        for (&entity, &owner) in &self.owners {
+            let (Some(position), Some(agent)) = (positions.get(&entity), agents.get(owner)) else { continue };
+            let ids: BTreeSet<Uuid> = tracks_near(agent, engine_of(owner), position)
+                .into_iter()
+                .flat_map(track_ids)
+                .collect();
+            if !ids.is_empty() {
+                self.owner_ids.insert(entity, ids);
+            }
+        }
+        
+        for pending in &mut self.pending {
+            let (Some(position), Some(agent)) = (positions.get(&pending.entity), agents.get(pending.to)) else { continue };
+            let nearby = tracks_near(agent, engine_of(pending.to), position);
+            if !nearby.is_empty() && pending.gap_secs.is_none() {
+                pending.gap_secs = Some(time - pending.at_secs);
+            }
+            pending.duplicated |= nearby.len() > 1;
+            pending.handed_off |= nearby.iter().any(|t| track_ids(t).any(|id| pending.old_ids.contains(&id)));
+        }
+        
+        let (closed, open): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|p| time - p.at_secs >= HANDOFF_WINDOW_SECS);
+        self.pending = open;
+        for pending in closed {
+            self.close(pending);
+        }
+    }
+    
+    fn close(&mut self, pending: PendingHandoff) {
+        let gap = pending.gap_secs.unwrap_or(HANDOFF_WINDOW_SECS);
+        self.metrics.crossings += 1;
+        self.metrics.handed_off += u64::from(pending.handed_off);
+        self.metrics.duplicated += u64::from(pending.duplicated);
+        self.metrics.gap_max_secs = self.metrics.gap_max_secs.max(gap);
+        self.gap_sum += gap;
+    }
+    
+    /// Handoffs whose window has closed. Crossings still open when the run
+    /// ends aren't scored.
+    pub fn metrics(&self) -> HandoffMetrics {
+        let crossings = self.metrics.crossings.max(1) as f64;
+        HandoffMetrics { gap_mean_secs: self.gap_sum / crossings, ..self.metrics }
+    }
+}
+
+/// `agent`'s tracks within [`HANDOFF_MATCH_RADIUS_M`] of a world position,
+/// looked up in its `engine`.
+fn tracks_near<'a>(agent: &'a SimulatedAgent, engine: Option<&SpatialEngine>, position: &Vector3<f64>) -> Vec<&'a UniqueTrack> {
+    let (Some(engine), Some(global)) = (engine, ShardMap::to_global(position)) else {
+        return Vec::new();
+    };
+    engine.query_radius(global, HANDOFF_MATCH_RADIUS_M)
+        .iter()
+        .filter_map(|e| agent.track(&e.id))
+        .collect()
+}
+
+/// A track's canonical ID and the IDs merged into it.
+fn track_ids(track: &UniqueTrack) -> impl Iterator<Item = Uuid> + '_ {
+    track.observed_ids.iter().chain([&track.canonical_id]).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_bands_split_cells_west_to_east() {
+        let map = ShardMap::bands(Resolution::Ten, 2, 2);
+        let (west, east) = (map.cells_of(0), map.cells_of(1));
+        assert_eq!(west.len() + east.len(), 19);
+        assert_eq!(west.len(), 10);
+        let max_west = west.iter().map(|c| LatLng::from(*c).lng()).fold(f64::MIN, f64::max);
+        assert!(east.iter().all(|c| LatLng::from(*c).lng() >= max_west));
+        
+        // 200m either side of the origin lands in each band
+        assert_eq!(map.owner_at(&Vector3::new(-200.0, 0.0, 0.0)), Some(0));
+        assert_eq!(map.owner_at(&Vector3::new(200.0, 0.0, 0.0)), Some(1));
+        assert_eq!(map.owner_at(&Vector3::new(5_000.0, 0.0, 0.0)), None);
+    }
+    
+    #[test]
+    fn test_retains_within_margin_rings() {
+        let map = ShardMap::bands(Resolution::Ten, 2, 2).with_margin_rings(0);
+        let east = map.cells_of(1)[0];
+        assert!(map.retains(1, east));
+        assert!(!map.retains(0, east));
+        let west_edge = map.cells_of(0).into_iter()
+            .find(|c| c.grid_disk_safe(1).any(|n| map.owner_of(n) == Some(1)))
+            .unwrap();
+        assert!(!map.retains(1, west_edge));
+        assert!(map.clone().with_margin_rings(1).retains(1, west_edge));
+    }
+}
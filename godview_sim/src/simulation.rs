@@ -0,0 +1,1176 @@
+//! Step-by-step simulation driver.
+//!
+//! `Simulation` owns the oracle, the agents and the network, and advances
+//! them one tick per [`Simulation::step`]. A scenario is a loop over `step`
+//! plus assertions, which makes the sim easy to drive from tests or an
+//! embedding host:
+//!
+//! ```ignore
+//! use godview_sim::{Simulation, SimConfig, SimEventRequest};
+//!
+//! let mut sim = Simulation::from_config(SimConfig::default());
+//! sim.oracle_mut().spawn_entity(position, velocity, "drone");
+//!
+//! for _ in 0..300 {
+//!     let report = sim.step();
+//!     if report.tick == 150 {
+//!         sim.inject_event(SimEventRequest::Despawn { entity_id: 0 });
+//!     }
+//! }
+//! ```
+
+use crate::agent::SimulatedAgent;
+use crate::capture::snapshot_frame;
+use crate::context::SimContext;
+use crate::event_bus::{SimEvent, SimEventBus};
+use crate::exporter::SimFrame;
+use crate::faults::{AgentStatus, Fault, FaultPlan};
+use crate::gossip_sequence::Retransmission;
+use crate::keys::{DeterministicKeyProvider, KeyRegistry};
+use crate::network::{DelayQueue, SimNetwork, SimNetworkController};
+use crate::observer::ObserverAgent;
+use crate::oracle::{Oracle, SensorReading};
+use crate::rng_audit::AuditedRng;
+use crate::swarm_network::SwarmNetwork;
+use crate::world::SimConfig;
+
+use godview_core::godview_tracking::{GlobalHazardPacket, MeasurementModel};
+use godview_env::{GodViewContext, NodeId};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Garbage packets a bad actor adds to each gossip round.
+const BAD_ACTOR_GARBAGE_PER_ROUND: usize = 3;
+
+/// An event injected into a running simulation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SimEventRequest {
+    /// Blocks gossip between two groups of agent indices
+    Partition { group_a: Vec<usize>, group_b: Vec<usize> },
+    
+    /// Heals all partitions
+    Heal,
+    
+    /// Makes an agent add garbage packets to its gossip
+    BadActor { agent: usize },
+    
+    /// Stops every bad actor's garbage packets
+    ClearBadActors,
+    
+    /// Drops gossip between every pair of agents with probability `rate`
+    /// (0 restores lossless links)
+    PacketLoss { rate: f64 },
+    
+    /// Removes a ground-truth entity
+    Despawn { entity_id: u64 },
+}
+
+/// An event to inject at the start of a tick.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledEvent {
+    /// Tick at whose start the event is applied
+    pub at_tick: u64,
+    
+    pub event: SimEventRequest,
+}
+
+/// Events to inject into a run, in the order they were added: a recorded
+/// debugging session, replayable with [`Simulation::with_event_script`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventScript {
+    events: Vec<ScheduledEvent>,
+}
+
+impl EventScript {
+    /// Creates an empty script.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Injects `event` at the start of `at_tick`.
+    pub fn with_event(mut self, at_tick: u64, event: SimEventRequest) -> Self {
+        self.push(at_tick, event);
+        self
+    }
+    
+    /// Appends `event` at the start of `at_tick`.
+    pub fn push(&mut self, at_tick: u64, event: SimEventRequest) {
+        self.events.push(ScheduledEvent { at_tick, event });
+    }
+    
+    /// All scripted events.
+    pub fn events(&self) -> &[ScheduledEvent] {
+        &self.events
+    }
+    
+    /// Returns true if nothing is scripted.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+    
+    /// Events applied at the start of `tick`, in script order.
+    pub fn starting_at(&self, tick: u64) -> impl Iterator<Item = &SimEventRequest> {
+        self.events.iter().filter(move |e| e.at_tick == tick).map(|e| &e.event)
+    }
+    
+    /// Loads a script written by [`Self::save`].
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+    
+    /// Writes the script as pretty-printed JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        let mut file = std::fs::File::create(path)?;
+        file.write_all(json.as_bytes())?;
+        file.write_all(b"\n")
+    }
+}
+
+/// What happened during one [`Simulation::step`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TickReport {
+    /// Index of the tick that was executed (0-based)
+    pub tick: u64,
+    
+    /// Simulation time after the tick
+    pub time_secs: f64,
+    
+    /// Readings handed to agents (including injected duplicates)
+    pub readings_delivered: u64,
+    
+    /// Whether a gossip round ran this tick
+    pub gossip_round: bool,
+    
+    /// Gossip envelopes stopped by a partition
+    pub gossip_blocked: u64,
+    
+    /// Gossip envelopes delivered to a neighbor
+    pub gossip_delivered: u64,
+}
+
+/// A steppable simulation of agents observing an oracle.
+pub struct Simulation {
+    config: SimConfig,
+    context: Arc<SimContext>,
+    oracle: Oracle,
+    agents: Vec<SimulatedAgent>,
+    network_controller: SimNetworkController,
+    swarm_network: Option<SwarmNetwork>,
+    
+    /// Verifying keys for every agent's gossip signatures
+    registry: KeyRegistry,
+    
+    /// Sensor -> agent link per agent (only with sensor faults configured)
+    sensor_links: Vec<DelayQueue<SensorReading>>,
+    
+    /// Reference tracker fed every reading and gossip packet, if enabled
+    observer: Option<ObserverAgent>,
+    
+    /// Agent-local faults to inject, and each agent's resulting status
+    fault_plan: FaultPlan,
+    statuses: Vec<AgentStatus>,
+    
+    /// Events injected at the start of their ticks
+    event_script: EventScript,
+    
+    /// Envelopes each agent was handed while crashed or stalled, unread
+    missed_envelopes: Vec<u64>,
+    
+    bad_actors: BTreeSet<usize>,
+    rng: AuditedRng,
+    tick: u64,
+    
+    /// Bus injected events and faults are published on
+    events: Option<SimEventBus>,
+}
+
+impl Simulation {
+    /// Builds the oracle, agents and network described by `config`.
+    ///
+    /// No entities are spawned; use [`Self::oracle_mut`] before stepping.
+    pub fn from_config(config: SimConfig) -> Self {
+        let physics_seed = config.seed.wrapping_mul(0x9e3779b97f4a7c15);
+        let context = SimContext::shared(config.seed);
+        let key_provider = DeterministicKeyProvider::new(config.seed);
+        let root_key = key_provider.biscuit_root_key().public();
+        
+        let mut oracle = Oracle::new(physics_seed);
+        oracle.set_position_noise(config.sensor_noise_std);
+        
+        let agents: Vec<SimulatedAgent> = (0..config.num_agents)
+            .map(|i| {
+                let mut agent = SimulatedAgent::new(
+                    context.clone(),
+                    Arc::new(SimNetwork::new_stub(NodeId::from_seed(i as u64))),
+                    root_key,
+                    i as u64,
+                    config.agent_config.clone(),
+                );
+                agent.set_signing_key(key_provider.agent_signing_key(i as u64));
+                if let Some(gossip) = config.gossip {
+                    agent.set_gossip_relay(gossip.relay, false);
+                    agent.set_staleness_horizon(gossip.max_packet_age_ms.map(|ms| ms as f64 / 1000.0));
+                }
+                agent
+            })
+            .collect();
+        let registry = KeyRegistry::from_provider(&key_provider, config.num_agents);
+        
+        let network_controller = SimNetworkController::with_seed(config.seed);
+        
+        // Sensor nodes are numbered after the agents
+        let sensor_links = match config.sensor_faults {
+            Some(faults) => (0..config.num_agents)
+                .map(|i| {
+                    let sensor = NodeId::from_seed((config.num_agents + i) as u64);
+                    let agent = NodeId::from_seed(i as u64);
+                    network_controller.set_reorder(sensor, agent, faults.reorder_probability, faults.max_displacement);
+                    network_controller.set_duplicate(sensor, agent, faults.duplicate_probability);
+                    if let Some(model) = faults.latency {
+                        network_controller.set_latency_model(sensor, agent, model);
+                    }
+                    DelayQueue::new(sensor, agent)
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        
+        // Gossip is routed through the controller by each agent's node ID
+        let nodes: Vec<NodeId> = agents.iter().map(|a| a.node_id()).collect();
+        let swarm_network = config.gossip.map(|g| {
+            SwarmNetwork::new_grid(g.rows, g.cols)
+                .with_routing(network_controller.clone(), nodes, config.seed.wrapping_mul(0x5851f42d4c957f2d))
+        });
+        let rng = context.derive_stream("simulation/bad-actors");
+        
+        Self {
+            statuses: vec![AgentStatus::Running; agents.len()],
+            missed_envelopes: vec![0; agents.len()],
+            config,
+            context,
+            oracle,
+            agents,
+            network_controller,
+            swarm_network,
+            registry,
+            sensor_links,
+            observer: None,
+            fault_plan: FaultPlan::new(),
+            event_script: EventScript::new(),
+            bad_actors: BTreeSet::new(),
+            rng,
+            tick: 0,
+            events: None,
+        }
+    }
+    
+    /// Publishes injected events, faults and resumed stalls on `bus`, and
+    /// hands it to the agents and the network controller. The caller keeps
+    /// the bus's clock ([`SimEventBus::set_clock`]).
+    pub fn with_event_bus(mut self, bus: SimEventBus) -> Self {
+        for agent in &mut self.agents {
+            agent.set_event_bus(bus.clone());
+        }
+        self.network_controller.set_event_bus(bus.clone());
+        self.events = Some(bus);
+        self
+    }
+    
+    fn publish(&self, event: SimEvent) {
+        if let Some(bus) = &self.events {
+            bus.publish(event);
+        }
+    }
+    
+    /// Adds an [`ObserverAgent`] that sees every oracle reading and every
+    /// gossiped packet, bypassing visibility, topology and partitions.
+    pub fn with_observer(mut self) -> Self {
+        self.observer = Some(ObserverAgent::new(&self.config.agent_config));
+        self
+    }
+    
+    /// Injects the faults in `plan`, each at the start of its tick.
+    ///
+    /// Crashed and stalled agents skip their tick, their readings and both
+    /// sides of gossip; a stalled agent picks up where it froze.
+    pub fn with_fault_plan(mut self, plan: FaultPlan) -> Self {
+        self.fault_plan = plan;
+        self
+    }
+    
+    /// Injects the events in `script`, each at the start of its tick and
+    /// before that tick's faults, as [`Self::inject_event`] would between
+    /// the previous step and this one.
+    pub fn with_event_script(mut self, script: EventScript) -> Self {
+        self.event_script = script;
+        self
+    }
+    
+    /// Has every agent NACK the gaps in its neighbors' envelope sequences
+    /// and resend what its neighbors NACK. Off by default, in which case
+    /// gaps are only counted.
+    pub fn with_retransmission(mut self, retransmission: Retransmission) -> Self {
+        for agent in &mut self.agents {
+            agent.set_retransmission(Some(retransmission));
+        }
+        self
+    }
+    
+    /// Resumes stalls that end at `tick` and applies faults and
+    /// perturbations planned for it.
+    fn apply_faults(&mut self, tick: u64) {
+        for agent in 0..self.statuses.len() {
+            if self.statuses[agent] == (AgentStatus::Stalled { until_tick: tick }) {
+                self.statuses[agent] = AgentStatus::Running;
+                self.publish(SimEvent::AgentResumed { agent });
+            }
+        }
+        
+        for fault in self.fault_plan.starting_at(tick) {
+            let agent = fault.agent();
+            if self.statuses.get(agent).is_none_or(|s| *s == AgentStatus::Crashed) {
+                continue;
+            }
+            match *fault {
+                Fault::Crash { .. } => self.statuses[agent] = AgentStatus::Crashed,
+                Fault::Stall { for_ticks, .. } => {
+                    self.statuses[agent] = AgentStatus::Stalled { until_tick: tick + for_ticks };
+                }
+                Fault::Amnesia { .. } => self.agents[agent].forget_tracks(),
+            }
+            self.publish(SimEvent::FaultInjected { fault: *fault });
+        }
+        
+        // Each agent perturbs its own live config; one that refuses the
+        // result (e.g. a threshold scaled to zero) keeps the old one
+        for perturbation in self.fault_plan.perturbations_at(tick) {
+            let mut accepted = 0;
+            for (agent, status) in self.agents.iter_mut().zip(&self.statuses) {
+                if *status == AgentStatus::Crashed {
+                    continue;
+                }
+                let updated = perturbation.change.apply(agent.tracking_config())
+                    .and_then(|config| agent.update_tracking_config(config));
+                accepted += usize::from(updated.is_ok());
+            }
+            self.publish(SimEvent::ConfigPerturbed { change: perturbation.change, agents: accepted });
+        }
+    }
+    
+    /// Advances physics, sensing and gossip by one tick.
+    pub fn step(&mut self) -> TickReport {
+        let dt = 1.0 / self.config.tick_rate_hz as f64;
+        let tick = self.tick;
+        let mut report = TickReport { tick, ..TickReport::default() };
+        let scripted: Vec<SimEventRequest> = self.event_script.starting_at(tick).cloned().collect();
+        for event in scripted {
+            self.inject_event(event);
+        }
+        self.apply_faults(tick);
+        
+        self.oracle.step(dt);
+        self.context.advance_time(Duration::from_secs_f64(dt));
+        let readings = self.oracle.generate_sensor_readings();
+        if let Some(observer) = self.observer.as_mut() {
+            observer.tick(dt);
+            observer.ingest_readings(&readings, self.context.now().as_secs_f64());
+        }
+        
+        for (agent_idx, agent) in self.agents.iter_mut().enumerate() {
+            if !self.statuses[agent_idx].is_running() {
+                continue;
+            }
+            let visible = readings.iter()
+                .enumerate()
+                .filter(|(entity_idx, _)| self.config.visibility.sees(agent_idx, *entity_idx))
+                .map(|(_, r)| r.clone());
+            
+            let delivered: Vec<SensorReading> = match self.sensor_links.get_mut(agent_idx) {
+                Some(link) => {
+                    visible.for_each(|r| link.push_after_latency(tick, dt, r, &self.network_controller));
+                    link.pop_due(tick, &self.network_controller)
+                }
+                None => visible.collect(),
+            };
+            
+            agent.tick();
+            agent.ingest_readings(&delivered);
+            report.readings_delivered += delivered.len() as u64;
+        }
+        
+        if let Some(interval) = self.config.gossip.map(|g| g.interval_ticks.max(1)) {
+            if tick.is_multiple_of(interval) {
+                report.gossip_round = true;
+                (report.gossip_delivered, report.gossip_blocked) = self.gossip_round();
+            }
+        }
+        
+        self.tick += 1;
+        report.time_secs = self.oracle.time();
+        report
+    }
+    
+    /// Runs one gossip round over the grid.
+    ///
+    /// Each agent signs its recent packets into one envelope per round; the
+    /// network routes it to every reachable neighbor, which verifies it
+    /// against the key registry. Every envelope sent carries the sender's
+    /// next sequence number. With retransmission on, agents first resend
+    /// what the NACKs due for them ask for, and NACK their own gaps once
+    /// they have read the round's envelopes. Returns (delivered, blocked)
+    /// envelope counts.
+    fn gossip_round(&mut self) -> (u64, u64) {
+        let Some(swarm_network) = self.swarm_network.as_mut() else {
+            return (0, 0);
+        };
+        let now = self.context.now().as_secs_f64();
+        let now_ms = self.context.now().as_millis() as u64;
+        let before = swarm_network.route_stats();
+        
+        for (idx, agent) in self.agents.iter_mut().enumerate() {
+            let nacks = swarm_network.take_nacks(idx, now_ms);
+            if !self.statuses[idx].is_running() {
+                continue;
+            }
+            for (from_idx, nack) in nacks {
+                for envelope in agent.answer_nack(&nack) {
+                    swarm_network.send_envelope_to(idx, from_idx, envelope, now_ms);
+                }
+            }
+            let mut packets = agent.recent_packets().to_vec();
+            
+            if self.bad_actors.contains(&idx) {
+                for _ in 0..BAD_ACTOR_GARBAGE_PER_ROUND {
+                    packets.push(GlobalHazardPacket {
+                        entity_id: Uuid::from_u128(self.rng.gen()),
+                        position: [
+                            self.rng.gen_range(-1000.0..1000.0),
+                            self.rng.gen_range(-1000.0..1000.0),
+                            self.rng.gen_range(-1000.0..1000.0),
+                        ],
+                        velocity: [0.0, 0.0, 0.0],
+                        class_id: 99,
+                        timestamp: now,
+                        confidence_score: 0.1,
+                        measurement_model: MeasurementModel::default(),
+                        priority: 0,
+                    });
+                }
+            }
+            
+            if let Some(observer) = self.observer.as_mut() {
+                observer.ingest_gossip(&packets);
+            }
+            if let Some(envelope) = agent.sign_packets(&packets).and_then(|s| agent.seal_envelope(&s)) {
+                swarm_network.send_envelope(idx, agent.sequence_envelope(envelope), now_ms);
+            }
+            for envelope in agent.take_relay_envelopes() {
+                swarm_network.send_envelope(idx, agent.sequence_envelope(envelope), now_ms);
+            }
+        }
+        
+        for (agent_idx, agent) in self.agents.iter_mut().enumerate() {
+            let envelopes = swarm_network.take_envelopes(agent_idx, now_ms);
+            if !self.statuses[agent_idx].is_running() {
+                self.missed_envelopes[agent_idx] += envelopes.len() as u64;
+                continue;
+            }
+            for (from_idx, envelope) in envelopes {
+                // Rejections are published on the agent's event bus
+                let _ = agent.receive_gossip_envelope(from_idx, &envelope, &self.registry);
+            }
+            for (to_idx, nack) in agent.gossip_nacks() {
+                swarm_network.send_nack(agent_idx, to_idx, nack, now_ms);
+            }
+            agent.clear_recent_packets();
+        }
+        
+        let after = swarm_network.route_stats();
+        (after.delivered - before.delivered, after.blocked_partition - before.blocked_partition)
+    }
+    
+    /// Applies an event immediately.
+    pub fn inject_event(&mut self, event: SimEventRequest) {
+        match event {
+            SimEventRequest::Partition { group_a, group_b } => {
+                let agents = &self.agents;
+                let nodes = |group: Vec<usize>| group.into_iter().map(|i| agents[i].node_id()).collect();
+                self.network_controller.partition(nodes(group_a), nodes(group_b));
+            }
+            SimEventRequest::Heal => self.network_controller.heal_all(),
+            SimEventRequest::BadActor { agent } => {
+                self.bad_actors.insert(agent);
+                self.publish(SimEvent::BadActorsInjected { agents: vec![agent] });
+            }
+            SimEventRequest::ClearBadActors => {
+                self.bad_actors.clear();
+                self.publish(SimEvent::BadActorsCleared);
+            }
+            SimEventRequest::PacketLoss { rate } => {
+                for from in &self.agents {
+                    for to in &self.agents {
+                        self.network_controller.set_loss(from.node_id(), to.node_id(), rate);
+                    }
+                }
+                self.publish(SimEvent::PacketLossSet { rate });
+            }
+            SimEventRequest::Despawn { entity_id } => {
+                self.oracle.remove_entity(entity_id);
+                self.publish(SimEvent::EntityDespawned { entity_id });
+            }
+        }
+    }
+    
+    /// Configuration the simulation was built from.
+    pub fn config(&self) -> &SimConfig {
+        &self.config
+    }
+    
+    /// Number of ticks executed so far.
+    pub fn tick_count(&self) -> u64 {
+        self.tick
+    }
+    
+    /// Ticks the configured duration covers.
+    pub fn duration_ticks(&self) -> u64 {
+        (self.config.max_duration_secs * self.config.tick_rate_hz as f64).round() as u64
+    }
+    
+    /// Steps the rest of the configured duration lazily, yielding the frame
+    /// after each tick (see [`SimFrames`]).
+    pub fn frames(self, verbose: bool) -> SimFrames {
+        SimFrames { sim: self, verbose }
+    }
+    
+    /// Current simulation time in seconds.
+    pub fn time(&self) -> f64 {
+        self.oracle.time()
+    }
+    
+    /// Ground-truth oracle.
+    pub fn oracle(&self) -> &Oracle {
+        &self.oracle
+    }
+    
+    /// Mutable oracle, e.g. for spawning entities.
+    pub fn oracle_mut(&mut self) -> &mut Oracle {
+        &mut self.oracle
+    }
+    
+    /// All agents, in index order.
+    pub fn agents(&self) -> &[SimulatedAgent] {
+        &self.agents
+    }
+    
+    /// Mutable agents, in index order.
+    pub fn agents_mut(&mut self) -> &mut [SimulatedAgent] {
+        &mut self.agents
+    }
+    
+    /// Fault status of the agent at `index` (Running without a fault plan).
+    pub fn agent_status(&self, index: usize) -> AgentStatus {
+        self.statuses.get(index).copied().unwrap_or_default()
+    }
+    
+    /// Gossip envelopes delivered to the agent at `index` while it was
+    /// crashed or stalled, and dropped unread.
+    pub fn missed_envelopes(&self, index: usize) -> u64 {
+        self.missed_envelopes.get(index).copied().unwrap_or(0)
+    }
+    
+    /// Agents that have not crashed, in index order.
+    pub fn live_agents(&self) -> impl Iterator<Item = &SimulatedAgent> {
+        self.agents.iter()
+            .zip(&self.statuses)
+            .filter(|(_, status)| **status != AgentStatus::Crashed)
+            .map(|(agent, _)| agent)
+    }
+    
+    /// Agent indices currently acting as bad actors.
+    pub fn bad_actors(&self) -> &BTreeSet<usize> {
+        &self.bad_actors
+    }
+    
+    /// Network controller (partitions, sensor link faults).
+    pub fn network_controller(&self) -> &SimNetworkController {
+        &self.network_controller
+    }
+    
+    /// Reference observer, if enabled with [`Self::with_observer`].
+    pub fn observer(&self) -> Option<&ObserverAgent> {
+        self.observer.as_ref()
+    }
+    
+    /// Gossip network, if gossip is configured.
+    pub fn swarm_network(&self) -> Option<&SwarmNetwork> {
+        self.swarm_network.as_ref()
+    }
+}
+
+/// Iterator over a [`Simulation`]'s frames, one [`Simulation::step`] per
+/// item, ending once the configured duration is stepped.
+///
+/// Frames are built as a failure capture builds them: ground truth and each
+/// agent's track count, plus track positions and RMS error if `verbose`.
+pub struct SimFrames {
+    sim: Simulation,
+    verbose: bool,
+}
+
+impl SimFrames {
+    /// The simulation being stepped.
+    pub fn simulation(&self) -> &Simulation {
+        &self.sim
+    }
+}
+
+impl Iterator for SimFrames {
+    type Item = SimFrame;
+    
+    fn next(&mut self) -> Option<SimFrame> {
+        if self.sim.tick_count() >= self.sim.duration_ticks() {
+            return None;
+        }
+        let report = self.sim.step();
+        Some(snapshot_frame(report.tick, &self.sim.oracle, &self.sim.agents, self.verbose))
+    }
+    
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.sim.duration_ticks().saturating_sub(self.sim.tick_count()) as usize;
+        (left, Some(left))
+    }
+}
+
+impl ExactSizeIterator for SimFrames {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::GossipRelay;
+    use crate::rng_audit::RngAudit;
+    use crate::world::{GossipConfig, SensorLinkFaults, Visibility};
+    use godview_core::AgentConfig;
+    use nalgebra::Vector3;
+    
+    fn gossiping_sim() -> Simulation {
+        let mut sim = Simulation::from_config(SimConfig {
+            num_agents: 4,
+            visibility: Visibility::Striped { period: 2, visible: 1 },
+            gossip: Some(GossipConfig { rows: 2, cols: 2, interval_ticks: 3, relay: GossipRelay::Off, max_packet_age_ms: None }),
+            ..SimConfig::default()
+        });
+        for i in 0..4 {
+            sim.oracle_mut().spawn_entity(Vector3::new(i as f64 * 50.0, 0.0, 100.0), Vector3::new(5.0, 0.0, 0.0), "drone");
+        }
+        sim
+    }
+    
+    /// A single agent steps exactly as the hand-written TimeWarp loop did:
+    /// its context seeded with the run's seed, its node `from_seed(0)`, its
+    /// sensor `from_seed(1)` and the link faults drawn from a controller
+    /// seeded the same.
+    #[test]
+    fn test_single_agent_matches_a_hand_written_loop() {
+        let seed = 42;
+        let spawn = |oracle: &mut Oracle| {
+            for i in 0..10 {
+                let position = Vector3::new(i as f64 * 100.0, 0.0, 100.0 + i as f64 * 10.0);
+                oracle.spawn_entity(position, Vector3::new(50.0, 10.0 * (i as f64 - 5.0), 0.0), "drone");
+            }
+        };
+        let mut sim = Simulation::from_config(SimConfig {
+            seed,
+            num_agents: 1,
+            sensor_faults: Some(SensorLinkFaults {
+                reorder_probability: 0.2,
+                max_displacement: 15,
+                duplicate_probability: 0.05,
+                latency: None,
+            }),
+            ..SimConfig::default()
+        });
+        spawn(sim.oracle_mut());
+        
+        let context = SimContext::shared(seed);
+        let key_provider = DeterministicKeyProvider::new(seed);
+        let mut agent = SimulatedAgent::new(
+            context.clone(),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(0))),
+            key_provider.biscuit_root_key().public(),
+            0,
+            SimulatedAgent::default_config(),
+        );
+        let mut oracle = Oracle::new(seed.wrapping_mul(0x9e3779b97f4a7c15));
+        spawn(&mut oracle);
+        let (sensor_node, agent_node) = (NodeId::from_seed(1), NodeId::from_seed(0));
+        let controller = SimNetworkController::with_seed(seed);
+        controller.set_reorder(sensor_node, agent_node, 0.2, 15);
+        controller.set_duplicate(sensor_node, agent_node, 0.05);
+        let mut sensor_link = DelayQueue::new(sensor_node, agent_node);
+        
+        let sorted = |mut tracks: Vec<(Uuid, Vector3<f64>)>| {
+            tracks.sort_by_key(|(id, _)| *id);
+            tracks
+        };
+        let dt = 1.0 / 30.0;
+        for tick in 0..90 {
+            oracle.step(dt);
+            context.advance_time(Duration::from_secs_f64(dt));
+            for reading in oracle.generate_sensor_readings() {
+                sensor_link.push(tick, reading);
+            }
+            agent.tick();
+            agent.ingest_readings(&sensor_link.pop_due(tick, &controller));
+            
+            sim.step();
+            assert_eq!(sorted(sim.agents()[0].track_positions()), sorted(agent.track_positions()), "tick {tick}");
+        }
+        assert_eq!(sim.network_controller().total_link_fault_stats(), controller.total_link_fault_stats());
+    }
+    
+    #[test]
+    fn test_frames_step_the_configured_duration() {
+        let mut sim = Simulation::from_config(SimConfig {
+            num_agents: 4,
+            max_duration_secs: 0.5,
+            ..SimConfig::default()
+        });
+        for i in 0..4 {
+            sim.oracle_mut().spawn_entity(Vector3::new(i as f64 * 50.0, 0.0, 100.0), Vector3::new(5.0, 0.0, 0.0), "drone");
+        }
+        sim.step();
+        let duration_ticks = sim.duration_ticks();
+        
+        let frames = sim.frames(true);
+        assert_eq!(frames.len(), duration_ticks as usize - 1);
+        let frames: Vec<SimFrame> = frames.collect();
+        assert_eq!(frames.len(), duration_ticks as usize - 1);
+        assert_eq!(frames[0].tick, Some(1));
+        assert_eq!(frames.last().unwrap().tick, Some(duration_ticks - 1));
+        assert_eq!(frames[0].ground_truth.len(), 4);
+        assert_eq!(frames[0].agents.len(), 4);
+        assert!(frames.last().unwrap().agents.iter().all(|a| a.rms_error.is_some()));
+    }
+    
+    #[test]
+    fn test_verbose_frames_credit_gossiped_tracks_to_neighbors() {
+        let provenance_sim = |provenance| {
+            let mut sim = Simulation::from_config(SimConfig {
+                num_agents: 4,
+                max_duration_secs: 1.0,
+                agent_config: AgentConfig { provenance, ..SimulatedAgent::default_config() },
+                visibility: Visibility::Striped { period: 2, visible: 1 },
+                gossip: Some(GossipConfig { rows: 2, cols: 2, interval_ticks: 3, relay: GossipRelay::Off, max_packet_age_ms: None }),
+                ..SimConfig::default()
+            });
+            for i in 0..4 {
+                sim.oracle_mut().spawn_entity(Vector3::new(i as f64 * 50.0, 0.0, 100.0), Vector3::new(5.0, 0.0, 0.0), "drone");
+            }
+            sim.frames(true).last().unwrap()
+        };
+        
+        let frame = provenance_sim(Some(4));
+        for agent in &frame.agents {
+            let credited: Vec<_> = agent.tracks.iter().filter(|t| t.provenance.is_some()).collect();
+            assert!(!credited.is_empty(), "agent {}", agent.agent_id);
+            for track in credited {
+                let provenance = track.provenance.as_ref().unwrap();
+                assert!(provenance.iter().all(|&(n, _)| n < 4 && n as u64 != agent.agent_id), "{provenance:?}");
+                let most = provenance.iter().map(|&(_, information)| information).fold(0.0, f64::max);
+                let dominant = track.dominant_contributor.unwrap();
+                assert!(provenance.contains(&(dominant, most)), "{dominant} in {provenance:?}");
+            }
+        }
+        
+        let frame = provenance_sim(None);
+        assert!(frame.agents.iter().flat_map(|a| &a.tracks).all(|t| t.provenance.is_none() && t.dominant_contributor.is_none()));
+    }
+    
+    #[test]
+    fn test_step_reports_ticks_and_gossip_rounds() {
+        let mut sim = gossiping_sim();
+        
+        let reports: Vec<TickReport> = (0..6).map(|_| sim.step()).collect();
+        assert_eq!(reports.iter().map(|r| r.tick).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(reports.iter().filter(|r| r.gossip_round).count(), 2);
+        
+        // Each agent sees half of the 4 entities
+        assert!(reports.iter().all(|r| r.readings_delivered == 8));
+        assert_eq!(sim.tick_count(), 6);
+        assert!((sim.time() - 6.0 / 30.0).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_same_seed_same_tracks() {
+        let run = || {
+            let mut sim = gossiping_sim();
+            (0..30).for_each(|_| { sim.step(); });
+            sim.agents().iter().map(|a| a.track_positions().len()).collect::<Vec<_>>()
+        };
+        assert_eq!(run(), run());
+        
+        let mut a = gossiping_sim();
+        let mut b = gossiping_sim();
+        for _ in 0..30 {
+            assert_eq!(a.step(), b.step());
+        }
+        let truth = a.oracle().ground_truth_positions();
+        for (x, y) in a.agents().iter().zip(b.agents()) {
+            assert_eq!(x.compute_position_error(&truth), y.compute_position_error(&truth));
+        }
+    }
+    
+    /// Extra draws on the oracle's noise stream mid-run move that stream
+    /// alone: every other stream draws as often as before, and the sensor
+    /// links reorder and duplicate exactly the same readings.
+    #[test]
+    fn test_extra_oracle_noise_draws_leave_other_streams_unchanged() {
+        let run = |extra_draws: bool| {
+            let audit = RngAudit::new();
+            let _guard = audit.install();
+            let mut sim = Simulation::from_config(SimConfig {
+                num_agents: 3,
+                sensor_faults: Some(SensorLinkFaults {
+                    reorder_probability: 0.2,
+                    max_displacement: 5,
+                    duplicate_probability: 0.1,
+                    latency: None,
+                }),
+                ..SimConfig::default()
+            });
+            for i in 0..4 {
+                sim.oracle_mut().spawn_entity(Vector3::new(i as f64 * 50.0, 0.0, 100.0), Vector3::new(5.0, 0.0, 0.0), "drone");
+            }
+            let reports: Vec<TickReport> = (0..60)
+                .map(|tick| {
+                    if extra_draws && tick == 20 {
+                        sim.oracle_mut().generate_sensor_readings();
+                    }
+                    sim.step()
+                })
+                .collect();
+            (audit.counts(), reports, sim.network_controller().total_link_fault_stats())
+        };
+        let (mut draws, reports, faults) = run(false);
+        let (mut shifted_draws, shifted_reports, shifted_faults) = run(true);
+        
+        assert!(shifted_draws.remove("oracle/noise") > draws.remove("oracle/noise"));
+        assert!(draws.keys().any(|label| label.starts_with("network/link-")));
+        assert_eq!(shifted_draws, draws);
+        assert_eq!(shifted_reports, reports);
+        assert!(faults.reordered > 0 && faults.duplicated > 0);
+        assert_eq!(shifted_faults, faults);
+    }
+    
+    #[test]
+    fn test_partition_blocks_gossip_until_healed() {
+        let mut sim = gossiping_sim();
+        sim.inject_event(SimEventRequest::Partition { group_a: vec![0, 1], group_b: vec![2, 3] });
+        assert!(sim.step().gossip_blocked > 0);
+        
+        sim.inject_event(SimEventRequest::Heal);
+        for _ in 0..2 {
+            sim.step();
+        }
+        assert_eq!(sim.step().gossip_blocked, 0);
+    }
+    
+    #[test]
+    fn test_no_cross_partition_deliveries_during_partition() {
+        let cross_deliveries = |sim: &Simulation| {
+            let network = sim.swarm_network().unwrap();
+            [(0, 2), (0, 3), (1, 2), (1, 3)].iter()
+                .map(|&(a, b)| network.envelopes_delivered(a, b) + network.envelopes_delivered(b, a))
+                .sum::<u64>()
+        };
+        
+        let mut sim = gossiping_sim();
+        sim.inject_event(SimEventRequest::Partition { group_a: vec![0, 1], group_b: vec![2, 3] });
+        let mut blocked = 0;
+        let mut delivered = 0;
+        for _ in 0..30 {
+            let report = sim.step();
+            blocked += report.gossip_blocked;
+            delivered += report.gossip_delivered;
+        }
+        assert!(blocked > 0);
+        assert!(delivered > 0, "gossip within each group still flows");
+        assert_eq!(cross_deliveries(&sim), 0);
+        
+        // Every envelope was signed and verified
+        for agent in sim.agents() {
+            let stats = agent.signed_gossip_stats();
+            assert!(stats.accepted > 0);
+            assert_eq!(stats.bad_signature + stats.unknown_signer + stats.malformed, 0);
+        }
+        
+        sim.inject_event(SimEventRequest::Heal);
+        for _ in 0..6 {
+            sim.step();
+        }
+        assert!(cross_deliveries(&sim) > 0);
+    }
+    
+    #[test]
+    fn test_despawn_and_bad_actor_events() {
+        let mut sim = gossiping_sim();
+        sim.inject_event(SimEventRequest::Despawn { entity_id: 0 });
+        sim.inject_event(SimEventRequest::BadActor { agent: 1 });
+        
+        sim.step();
+        assert_eq!(sim.oracle().active_entities().len(), 3);
+        assert!(sim.bad_actors().contains(&1));
+        
+        // Garbage from agent 1 reaches its neighbors as extra tracks
+        let tracks: usize = sim.agents().iter().map(|a| a.track_count()).sum();
+        let mut clean = gossiping_sim();
+        clean.inject_event(SimEventRequest::Despawn { entity_id: 0 });
+        clean.step();
+        let clean_tracks: usize = clean.agents().iter().map(|a| a.track_count()).sum();
+        assert!(tracks > clean_tracks);
+    }
+    
+    #[test]
+    fn test_packet_loss_and_clearing_bad_actors() {
+        let mut sim = gossiping_sim();
+        sim.inject_event(SimEventRequest::BadActor { agent: 1 });
+        sim.inject_event(SimEventRequest::PacketLoss { rate: 1.0 });
+        for _ in 0..6 {
+            assert_eq!(sim.step().gossip_delivered, 0);
+        }
+        assert!(sim.swarm_network().unwrap().route_stats().dropped_loss > 0);
+        
+        sim.inject_event(SimEventRequest::PacketLoss { rate: 0.0 });
+        sim.inject_event(SimEventRequest::ClearBadActors);
+        assert!(sim.bad_actors().is_empty());
+        let delivered: u64 = (0..6).map(|_| sim.step().gossip_delivered).sum();
+        assert!(delivered > 0);
+    }
+    
+    #[test]
+    fn test_fault_plan_crash_stall_and_amnesia() {
+        let plan = FaultPlan::new()
+            .with_crash(2, 0)
+            .with_stall(2, 1, 3)
+            .with_amnesia(4, 2);
+        let mut sim = gossiping_sim().with_fault_plan(plan);
+        
+        for _ in 0..4 {
+            sim.step();
+        }
+        assert_eq!(sim.agent_status(0), AgentStatus::Crashed);
+        assert_eq!(sim.agent_status(1), AgentStatus::Stalled { until_tick: 5 });
+        assert_eq!(sim.live_agents().count(), 3);
+        assert!(sim.agents()[2].track_count() > 2, "gossip taught agent 2 more than it senses");
+        
+        // Amnesia wipes tracks at the start of tick 4; readings in the same
+        // tick rebuild only the two entities the agent senses
+        sim.step();
+        assert_eq!(sim.agents()[2].track_count(), 2);
+        
+        for _ in 5..9 {
+            sim.step();
+        }
+        assert_eq!(sim.agents()[0].tick_count(), 2, "crashed agent never ticks again");
+        assert_eq!(sim.agents()[1].tick_count(), 9 - 3, "stalled agent resumes behind");
+        assert_eq!(sim.agent_status(1), AgentStatus::Running);
+        assert_eq!(sim.agents()[3].tick_count(), 9);
+        
+        // Keys survive amnesia, so the amnesiac's gossip still verifies
+        assert!(sim.agents().iter().all(|a| a.signed_gossip_stats().rejected() == 0));
+    }
+    
+    #[test]
+    fn test_perturbations_reach_every_agent_still_running() {
+        use crate::event_bus::EventLog;
+        use crate::faults::ConfigPerturbation;
+        
+        let plan = FaultPlan::new()
+            .with_crash(1, 0)
+            .with_perturbation(3, ConfigPerturbation::GatingThreshold { factor: 0.5 })
+            .with_perturbation(4, ConfigPerturbation::GatingThreshold { factor: 0.0 })
+            .with_amnesia(5, 1);
+        let bus = SimEventBus::new();
+        let log = bus.subscribe(EventLog::new());
+        let mut sim = gossiping_sim().with_fault_plan(plan).with_event_bus(bus);
+        let default_gate = sim.agents()[0].tracking_config().gating_threshold;
+        for _ in 0..6 {
+            sim.step();
+        }
+        
+        assert_eq!(sim.agents()[0].tracking_config().gating_threshold, default_gate, "crashed agents are left alone");
+        for agent in &sim.agents()[1..] {
+            // The zero threshold was refused, and amnesia kept the perturbed config
+            assert_eq!(agent.tracking_config().gating_threshold, default_gate / 2.0);
+        }
+        let perturbed: Vec<usize> = log.lock().events().iter()
+            .filter_map(|e| match e.event {
+                SimEvent::ConfigPerturbed { agents, .. } => Some(agents),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(perturbed, [3, 0]);
+    }
+    
+    /// Partitions [`gossiping_sim`]'s grid in half for `ticks` after one
+    /// gossip round, then heals it for `ticks_after`. Returns the sim and
+    /// the envelopes blocked.
+    ///
+    /// A receiver's first envelope from a neighbor sets where its sequence
+    /// starts, so gaps only show on links that carried gossip before.
+    fn partitioned_then_healed(mut sim: Simulation, ticks: usize, ticks_after: usize) -> (Simulation, u64) {
+        for _ in 0..3 {
+            sim.step();
+        }
+        sim.inject_event(SimEventRequest::Partition { group_a: vec![0, 1], group_b: vec![2, 3] });
+        let blocked = (0..ticks).map(|_| sim.step().gossip_blocked).sum();
+        sim.inject_event(SimEventRequest::Heal);
+        for _ in 0..ticks_after {
+            sim.step();
+        }
+        (sim, blocked)
+    }
+    
+    #[test]
+    fn test_partition_gaps_are_reported_after_heal() {
+        let (sim, blocked) = partitioned_then_healed(gossiping_sim(), 30, 3);
+        assert!(blocked > 0);
+        
+        // One gap per blocked envelope, each only across the partition
+        let stats: Vec<_> = sim.agents().iter().map(|a| a.sequence_stats()).collect();
+        assert_eq!(stats.iter().map(|s| s.gaps_detected).sum::<u64>(), blocked);
+        assert_eq!(sim.agents().iter().map(|a| a.gap_count()).sum::<u64>(), blocked);
+        assert!(stats.iter().all(|s| s.gaps_repaired == 0 && s.duplicates == 0));
+        assert!(sim.agents()[0].missing_ranges(1).is_empty());
+        assert_eq!(sim.agents()[0].missing_ranges(2).len(), 1);
+        assert!(sim.agents().iter().all(|a| a.network_stats().nacks_sent == 0));
+    }
+    
+    #[test]
+    fn test_retransmission_repairs_gaps_after_heal() {
+        let sim = gossiping_sim().with_retransmission(Retransmission::default());
+        let (sim, blocked) = partitioned_then_healed(sim, 30, 9);
+        let detected: u64 = sim.agents().iter().map(|a| a.sequence_stats().gaps_detected).sum();
+        let repaired: u64 = sim.agents().iter().map(|a| a.sequence_stats().gaps_repaired).sum();
+        assert_eq!(detected, blocked);
+        assert_eq!(repaired, detected);
+        assert!(sim.agents().iter().all(|a| a.gap_count() == 0));
+        assert!(sim.agents().iter().all(|a| a.network_stats().retransmitted_envelopes > 0));
+        assert!(sim.swarm_network().unwrap().route_stats().nacks_delivered > 0);
+        
+        // Senders that no longer hold the envelopes can't repair them, so
+        // the gaps are abandoned after max_nacks
+        let sim = gossiping_sim().with_retransmission(Retransmission::new(2).with_max_nacks(2));
+        let (sim, _) = partitioned_then_healed(sim, 30, 12);
+        let stats = sim.agents().iter().fold(Default::default(), |mut total: crate::SequenceStats, a| {
+            total.merge(&a.sequence_stats());
+            total
+        });
+        assert!(sim.agents().iter().all(|a| a.gap_count() == 0));
+        assert!(stats.gaps_abandoned > 0);
+        assert_eq!(stats.gaps_repaired + stats.gaps_abandoned, stats.gaps_detected);
+    }
+    
+    #[test]
+    fn test_stalled_agent_gaps_match_missed_envelopes() {
+        let run = |retransmission: Option<Retransmission>| {
+            let sim = gossiping_sim().with_fault_plan(FaultPlan::new().with_stall(2, 1, 12));
+            let mut sim = match retransmission {
+                Some(r) => sim.with_retransmission(r),
+                None => sim,
+            };
+            for _ in 0..24 {
+                sim.step();
+            }
+            sim
+        };
+        
+        let sim = run(None);
+        assert!(sim.missed_envelopes(1) > 0);
+        assert_eq!(sim.agents()[1].gap_count(), sim.missed_envelopes(1));
+        assert!(sim.agents().iter().enumerate().all(|(i, a)| i == 1 || a.gap_count() == 0),
+            "the stalled agent sent nothing, so its neighbors miss nothing");
+        
+        let sim = run(Some(Retransmission::default()));
+        let stats = sim.agents()[1].sequence_stats();
+        assert_eq!(stats.gaps_detected, sim.missed_envelopes(1));
+        assert_eq!(stats.gaps_repaired, stats.gaps_detected);
+        assert_eq!(sim.agents()[1].gap_count(), 0);
+    }
+    
+    /// Two agents that both see one entity, which despawns once gossip has
+    /// been flowing; returns them `ticks` later.
+    fn despawned_entity_sim(relay: GossipRelay, max_packet_age_ms: Option<u64>, ticks: usize) -> Simulation {
+        let mut sim = Simulation::from_config(SimConfig {
+            num_agents: 2,
+            visibility: Visibility::All,
+            gossip: Some(GossipConfig { rows: 1, cols: 2, interval_ticks: 3, relay, max_packet_age_ms }),
+            ..SimConfig::default()
+        });
+        sim.oracle_mut().spawn_entity(Vector3::new(0.0, 0.0, 100.0), Vector3::new(5.0, 0.0, 0.0), "drone");
+        for _ in 0..10 {
+            sim.step();
+        }
+        assert!(sim.agents().iter().all(|a| a.track_count() == 1));
+        
+        sim.inject_event(SimEventRequest::Despawn { entity_id: 0 });
+        for _ in 0..ticks {
+            sim.step();
+        }
+        sim
+    }
+    
+    #[test]
+    fn test_gossip_ttl_lets_despawned_tracks_expire() {
+        // max_age plus the last relay round
+        let ticks = SimConfig::default().agent_config.tracking_config().max_age as usize + 6;
+        
+        let sim = despawned_entity_sim(GossipRelay::Hops(2), None, ticks);
+        assert!(sim.agents().iter().all(|a| a.track_count() == 0));
+        assert!(sim.agents().iter().all(|a| a.network_stats().relayed_envelopes > 0));
+        
+        // Without a TTL the last packets about it bounce between the two
+        // agents forever, each bounce resetting the track's age
+        let sim = despawned_entity_sim(GossipRelay::Unbounded, None, ticks);
+        assert!(sim.agents().iter().all(|a| a.track_count() == 1));
+        
+        // A staleness horizon drops them once they're old, TTL or not, and
+        // the tracks expire max_age after that
+        let sim = despawned_entity_sim(GossipRelay::Unbounded, Some(300), ticks + 9);
+        assert!(sim.agents().iter().all(|a| a.track_count() == 0));
+        assert!(sim.agents().iter().all(|a| a.network_stats().stale_packets > 0));
+    }
+    
+    #[test]
+    fn test_gossip_latency_is_measured_from_send_timestamps() {
+        let mut sim = gossiping_sim();
+        let nodes: Vec<NodeId> = sim.agents().iter().map(|a| a.node_id()).collect();
+        for &from in &nodes {
+            for &to in nodes.iter().filter(|&&to| to != from) {
+                sim.network_controller().set_latency(from, to, 200);
+            }
+        }
+        
+        for _ in 0..60 {
+            sim.step();
+        }
+        
+        // Gossip rounds fall every 100ms, so 200ms envelopes arrive two rounds on
+        for agent in sim.agents() {
+            let stats = agent.network_stats();
+            assert!(stats.latency.count() > 0);
+            assert!((200..=201).contains(&stats.latency.p50()), "p50 {}ms", stats.latency.p50());
+            assert!(stats.latency.max() <= 201, "max {}ms", stats.latency.max());
+            assert_eq!(stats.clamped_timestamps, 0, "honest packets predate their envelope");
+        }
+    }
+}
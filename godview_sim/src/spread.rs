@@ -0,0 +1,145 @@
+//! How long a newly seen entity takes to reach every agent.
+//!
+//! A [`SpreadSampler`] notes when each entity is first tracked by any agent
+//! and when it is first tracked by all of them at once (a track within
+//! [`COVERAGE_RADIUS_M`] of the truth, as for [`swarm_coverage`]). The gap
+//! is how long gossip took to carry it across the swarm; SlowLoris
+//! measures it at several loss rates ([`LossSpread`]).
+//!
+//! [`swarm_coverage`]: crate::swarm_coverage
+
+use crate::agent::SimulatedAgent;
+use crate::faults::COVERAGE_RADIUS_M;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How fast entities reached every agent at one gossip loss rate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct LossSpread {
+    /// Share of gossip packets dropped on every link
+    pub loss_rate: f64,
+    
+    /// Entities some agent tracked
+    pub observed: usize,
+    
+    /// Of those, entities every agent tracked at once
+    pub spread: usize,
+    
+    /// Mean seconds from an entity's first track to swarm-wide tracks
+    /// (None if none spread)
+    pub mean_spread_secs: Option<f64>,
+    
+    /// Slowest entity's seconds from first track to swarm-wide tracks
+    /// (None if none spread)
+    pub max_spread_secs: Option<f64>,
+}
+
+/// Times each entity's spread from its first track to every agent.
+pub struct SpreadSampler {
+    loss_rate: f64,
+    first_tracked: BTreeMap<u64, f64>,
+    swarm_wide: BTreeMap<u64, f64>,
+}
+
+impl SpreadSampler {
+    /// Creates a sampler for a run with `loss_rate` on its gossip links.
+    pub fn new(loss_rate: f64) -> Self {
+        Self { loss_rate, first_tracked: BTreeMap::new(), swarm_wide: BTreeMap::new() }
+    }
+    
+    /// Records which entities `agents` track at `time_secs`.
+    pub fn observe(&mut self, time_secs: f64, agents: &[SimulatedAgent], ground_truth: &[(u64, Vector3<f64>)]) {
+        let tracks: Vec<Vec<Vector3<f64>>> = agents.iter()
+            .map(|a| a.track_positions().into_iter().map(|(_, p)| p).collect())
+            .collect();
+        for (id, truth) in ground_truth {
+            if self.swarm_wide.contains_key(id) {
+                continue;
+            }
+            let holders = tracks.iter()
+                .filter(|positions| positions.iter().any(|p| (p - truth).norm() <= COVERAGE_RADIUS_M))
+                .count();
+            if holders > 0 {
+                self.first_tracked.entry(*id).or_insert(time_secs);
+            }
+            if holders == agents.len() && holders > 0 {
+                self.swarm_wide.insert(*id, time_secs);
+            }
+        }
+    }
+    
+    /// Returns the spread so far.
+    pub fn stats(&self) -> LossSpread {
+        let secs: Vec<f64> = self.swarm_wide.iter()
+            .map(|(id, at)| at - self.first_tracked[id])
+            .collect();
+        LossSpread {
+            loss_rate: self.loss_rate,
+            observed: self.first_tracked.len(),
+            spread: secs.len(),
+            mean_spread_secs: (!secs.is_empty()).then(|| secs.iter().sum::<f64>() / secs.len() as f64),
+            max_spread_secs: secs.iter().copied().reduce(f64::max),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::SimContext;
+    use crate::keys::DeterministicKeyProvider;
+    use crate::network::SimNetwork;
+    use crate::oracle::SensorReading;
+    use godview_env::NodeId;
+    use std::sync::Arc;
+    
+    fn agent(index: u64) -> SimulatedAgent {
+        SimulatedAgent::new(
+            Arc::new(SimContext::new(42)),
+            Arc::new(SimNetwork::new_stub(NodeId::from_seed(index))),
+            DeterministicKeyProvider::new(42).biscuit_root_key().public(),
+            index,
+            SimulatedAgent::default_config(),
+        )
+    }
+    
+    fn reading(entity_id: u64, position: Vector3<f64>) -> SensorReading {
+        SensorReading::new(entity_id, position, Vector3::zeros())
+    }
+    
+    #[test]
+    fn test_spread_is_timed_from_first_track_to_every_agent() {
+        let mut agents = vec![agent(0), agent(1)];
+        let truth = vec![(0, Vector3::new(10.0, 0.0, 100.0)), (1, Vector3::new(500.0, 0.0, 100.0))];
+        let mut sampler = SpreadSampler::new(0.5);
+        
+        sampler.observe(0.0, &agents, &truth);
+        agents[0].ingest_readings(&[reading(0, truth[0].1), reading(1, truth[1].1)]);
+        sampler.observe(1.0, &agents, &truth);
+        agents[1].ingest_readings(&[reading(0, truth[0].1)]);
+        sampler.observe(2.5, &agents, &truth);
+        
+        let stats = sampler.stats();
+        assert_eq!(stats.loss_rate, 0.5);
+        assert_eq!((stats.observed, stats.spread), (2, 1));
+        assert_eq!(stats.mean_spread_secs, Some(1.5));
+        assert_eq!(stats.max_spread_secs, Some(1.5));
+    }
+    
+    #[test]
+    fn test_distant_tracks_do_not_count() {
+        let mut agents = vec![agent(0), agent(1)];
+        let truth = vec![(0, Vector3::new(0.0, 0.0, 100.0))];
+        let mut sampler = SpreadSampler::new(0.0);
+        assert_eq!(sampler.stats(), LossSpread::default());
+        
+        agents[0].ingest_readings(&[reading(0, truth[0].1)]);
+        agents[1].ingest_readings(&[reading(0, truth[0].1 + Vector3::new(2.0 * COVERAGE_RADIUS_M, 0.0, 0.0))]);
+        sampler.observe(1.0, &agents, &truth);
+        
+        let stats = sampler.stats();
+        assert_eq!((stats.observed, stats.spread), (1, 0));
+        assert_eq!(stats.mean_spread_secs, None);
+    }
+}
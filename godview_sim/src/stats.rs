@@ -0,0 +1,220 @@
+//! Swarm-wide convergence statistics shared by the scenarios.
+//!
+//! [`swarm_convergence`] scores one instant of a swarm: each agent's RMS
+//! position error against ground truth and its live track count, summarized
+//! as the mean and 95th percentile RMS and the coefficient of variation of
+//! the track counts. Which agents count is an explicit predicate
+//! ([`every_agent`], [`honest`], [`alive`], [`excluding`]), so bad actors and
+//! dead agents are left out the same way everywhere, and averages divide by
+//! the agents that were scored, never by the size of the swarm.
+//!
+//! Empty input is defined: no agents scores an RMS of 0 and a track CV of
+//! 1.0, as do agents holding no tracks between them (nothing to agree on is
+//! not agreement). An agent whose RMS is NaN or infinite is kept in
+//! [`ConvergenceStats::per_agent`] but left out of the RMS summary and
+//! counted in [`ConvergenceStats::non_finite`], so one broken track can't
+//! turn a whole run's score into NaN.
+
+use crate::agent::SimulatedAgent;
+use nalgebra::Vector3;
+use serde::Serialize;
+
+/// One agent's contribution to [`ConvergenceStats`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AgentConvergence {
+    /// Agent index
+    pub agent_id: u64,
+    
+    /// RMS position error of the agent's tracks (m; 0 if it tracks nothing)
+    pub rms: f64,
+    
+    /// Live tracks the agent holds
+    pub tracks: usize,
+}
+
+/// Convergence of a set of agents at one instant.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConvergenceStats {
+    /// Mean of the agents' finite RMS errors (m; 0 with none)
+    pub rms_mean: f64,
+    
+    /// 95th percentile (nearest rank) of the agents' finite RMS errors (m;
+    /// 0 with none)
+    pub rms_p95: f64,
+    
+    /// Mean live tracks per agent (0 with no agents)
+    pub track_mean: f64,
+    
+    /// Population standard deviation of the track counts over their mean
+    /// (1.0 if the mean is 0)
+    pub track_cv: f64,
+    
+    /// Agents whose RMS was NaN or infinite, left out of the RMS summary
+    pub non_finite: usize,
+    
+    /// Every agent scored, in iteration order
+    pub per_agent: Vec<AgentConvergence>,
+}
+
+impl ConvergenceStats {
+    /// Summarizes per-agent samples.
+    pub fn from_agents(per_agent: Vec<AgentConvergence>) -> Self {
+        let rms: Vec<f64> = per_agent.iter().map(|a| a.rms).filter(|r| r.is_finite()).collect();
+        let tracks: Vec<f64> = per_agent.iter().map(|a| a.tracks as f64).collect();
+        Self {
+            rms_mean: mean(&rms),
+            rms_p95: p95(&rms),
+            track_mean: mean(&tracks),
+            track_cv: coefficient_of_variation(&tracks),
+            non_finite: per_agent.len() - rms.len(),
+            per_agent,
+        }
+    }
+    
+    /// Number of agents scored.
+    pub fn agents(&self) -> usize {
+        self.per_agent.len()
+    }
+    
+    /// Number of agents with a finite RMS, the ones [`Self::rms_mean`]
+    /// averages over.
+    pub fn scored(&self) -> usize {
+        self.per_agent.len() - self.non_finite
+    }
+}
+
+/// Scores the `agents` that `filter` accepts against `ground_truth`.
+pub fn swarm_convergence<'a>(
+    agents: impl IntoIterator<Item = &'a SimulatedAgent>,
+    ground_truth: &[(u64, Vector3<f64>)],
+    filter: impl Fn(&SimulatedAgent) -> bool,
+) -> ConvergenceStats {
+    ConvergenceStats::from_agents(agents.into_iter()
+        .filter(|a| filter(a))
+        .map(|a| AgentConvergence {
+            agent_id: a.agent_index(),
+            rms: a.compute_position_error(ground_truth),
+            tracks: a.track_count(),
+        })
+        .collect())
+}
+
+/// Accepts every agent.
+pub fn every_agent(_: &SimulatedAgent) -> bool {
+    true
+}
+
+/// Accepts agents not built as bad actors
+/// ([`SimulatedAgent::is_bad_actor`]). Agents a harness turns with garbage
+/// gossip are ordinary agents; leave them out with [`excluding`].
+pub fn honest(agent: &SimulatedAgent) -> bool {
+    !agent.is_bad_actor()
+}
+
+/// Accepts agents that haven't run out of energy.
+pub fn alive(agent: &SimulatedAgent) -> bool {
+    agent.is_alive()
+}
+
+/// Accepts agents whose index is not in `ids`.
+pub fn excluding(ids: &[usize]) -> impl Fn(&SimulatedAgent) -> bool + '_ {
+    move |agent| !ids.contains(&(agent.agent_index() as usize))
+}
+
+/// Mean of `values` (0 if empty).
+pub fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// 95th percentile of `values` by nearest rank (0 if empty).
+pub fn p95(values: &[f64]) -> f64 {
+    percentile(values, 95.0)
+}
+
+/// The `pct`th percentile (0-100, clamped) of `values` by nearest rank: the
+/// smallest value at least `pct`% of them are at or below (0 if empty).
+pub fn percentile(values: &[f64], pct: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let rank = (pct.clamp(0.0, 100.0) / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Population standard deviation of `values` over their mean (1.0 if the
+/// mean is 0, including when `values` is empty).
+pub fn coefficient_of_variation(values: &[f64]) -> f64 {
+    let mean = mean(values);
+    if mean <= 0.0 {
+        return 1.0;
+    }
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    variance.sqrt() / mean
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::{BadActorSchedule, BadActorStrategy, SwarmHarness};
+    
+    fn agent(agent_id: u64, rms: f64, tracks: usize) -> AgentConvergence {
+        AgentConvergence { agent_id, rms, tracks }
+    }
+    
+    #[test]
+    fn test_summary_of_agents() {
+        let stats = ConvergenceStats::from_agents(vec![agent(0, 1.0, 10), agent(1, 3.0, 10), agent(2, 2.0, 40)]);
+        assert_eq!((stats.rms_mean, stats.rms_p95, stats.track_mean), (2.0, 3.0, 20.0));
+        assert!((stats.track_cv - 200f64.sqrt() / 20.0).abs() < 1e-12);
+        assert_eq!((stats.agents(), stats.scored(), stats.non_finite), (3, 3, 0));
+    }
+    
+    #[test]
+    fn test_empty_and_trackless_input_is_unconverged() {
+        let empty = ConvergenceStats::from_agents(Vec::new());
+        assert_eq!((empty.rms_mean, empty.rms_p95, empty.track_mean, empty.track_cv), (0.0, 0.0, 0.0, 1.0));
+        assert_eq!(empty.agents(), 0);
+        
+        let trackless = ConvergenceStats::from_agents(vec![agent(0, 0.0, 0), agent(1, 0.0, 0)]);
+        assert_eq!(trackless.track_cv, 1.0);
+        assert_eq!(coefficient_of_variation(&[5.0, 5.0]), 0.0);
+    }
+    
+    #[test]
+    fn test_non_finite_rms_is_left_out() {
+        let stats = ConvergenceStats::from_agents(vec![agent(0, f64::NAN, 4), agent(1, 2.0, 4), agent(2, f64::INFINITY, 4)]);
+        assert_eq!((stats.rms_mean, stats.rms_p95), (2.0, 2.0));
+        assert_eq!((stats.scored(), stats.non_finite), (1, 2));
+        assert_eq!(stats.track_cv, 0.0);
+        assert!(stats.per_agent[0].rms.is_nan());
+        
+        let broken = ConvergenceStats::from_agents(vec![agent(0, f64::NAN, 1)]);
+        assert_eq!((broken.rms_mean, broken.scored()), (0.0, 0));
+    }
+    
+    #[test]
+    fn test_filters_pick_the_agents_scored() {
+        let mut harness = SwarmHarness::new(7, 2, 2, 10)
+            .with_bad_actors(BadActorSchedule::Fixed(vec![1]), BadActorStrategy::Adaptive);
+        harness.oracle_mut().spawn_entity(Vector3::new(0.0, 0.0, 10.0), Vector3::new(1.0, 0.0, 0.0), "target");
+        for _ in 0..5 {
+            harness.step();
+        }
+        let ground_truth = harness.oracle().ground_truth_positions();
+        let ids = |stats: ConvergenceStats| stats.per_agent.iter().map(|a| a.agent_id).collect::<Vec<_>>();
+        
+        assert_eq!(ids(swarm_convergence(harness.agents(), &ground_truth, every_agent)), [0, 1, 2, 3]);
+        assert_eq!(ids(swarm_convergence(harness.agents(), &ground_truth, honest)), [0, 2, 3]);
+        assert_eq!(ids(swarm_convergence(harness.agents(), &ground_truth, excluding(&[0, 3]))), [1, 2]);
+        assert_eq!(ids(swarm_convergence(harness.agents(), &ground_truth, alive)), [0, 1, 2, 3]);
+        
+        let honest_stats = swarm_convergence(harness.agents(), &ground_truth, honest);
+        assert_eq!(honest_stats.track_mean, 1.0);
+        assert!(honest_stats.rms_mean > 0.0 && honest_stats.rms_mean < 2.0);
+    }
+}
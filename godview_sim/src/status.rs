@@ -0,0 +1,159 @@
+//! Live status of a running scenario, for health checks during long runs.
+//!
+//! A runner given a [`StatusSender`] ([`ScenarioRunner::with_status`])
+//! publishes a [`StatusSnapshot`] every progress interval and on a run's
+//! final tick, built from the same observation as its progress reports. The
+//! snapshots travel on a tokio watch channel: the sim loop only replaces the
+//! latest value, never waits on a reader, and reads nothing back, so a seed
+//! replays identically whether anyone is watching or not.
+//!
+//! With the `status-server` feature, [`StatusServer`] serves the latest
+//! snapshot as JSON at `GET /status` from its own thread (the CLI's
+//! `--status-port`).
+//!
+//! [`ScenarioRunner::with_status`]: crate::ScenarioRunner::with_status
+
+use crate::agent::SimulatedAgent;
+use crate::capture::InvariantViolation;
+use crate::progress::ProgressReport;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+
+/// Publishes a runner's latest [`StatusSnapshot`] (None before its first run
+/// reports).
+pub type StatusSender = watch::Sender<Option<StatusSnapshot>>;
+
+/// Reads the latest [`StatusSnapshot`] a [`StatusSender`] published.
+pub type StatusReceiver = watch::Receiver<Option<StatusSnapshot>>;
+
+/// A channel with no snapshot on it yet.
+pub fn status_channel() -> (StatusSender, StatusReceiver) {
+    watch::channel(None)
+}
+
+/// What a running scenario looked like at its last progress report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusSnapshot {
+    /// Scenario being run
+    pub scenario: String,
+
+    /// Seed of the run
+    pub seed: u64,
+
+    /// Ticks completed so far
+    pub tick: u64,
+
+    /// Ticks the run was scheduled for
+    pub target_ticks: u64,
+
+    /// Simulated time in seconds
+    pub time_secs: f64,
+
+    /// Ticks completed per wall-clock second since the run started
+    pub ticks_per_sec: f64,
+
+    /// Mean RMS position error across agents (m)
+    pub rms: f64,
+
+    /// Live tracks held by each agent, in agent order
+    pub tracks_per_agent: Vec<usize>,
+
+    /// The most recent invariant the run broke, if any
+    pub last_violation: Option<InvariantViolation>,
+
+    /// Estimated memory held by the agents, summed, in bytes
+    pub memory_estimate_bytes: usize,
+}
+
+impl StatusSnapshot {
+    /// Builds a snapshot of a run of `seed` from its progress `report`, the
+    /// agents it scored and its wall-clock age.
+    pub(crate) fn capture(
+        report: &ProgressReport,
+        seed: u64,
+        elapsed_secs: f64,
+        agents: &[&SimulatedAgent],
+        last_violation: Option<InvariantViolation>,
+    ) -> Self {
+        Self {
+            scenario: report.scenario.name().to_string(),
+            seed,
+            tick: report.tick,
+            target_ticks: report.target_ticks,
+            time_secs: report.time_secs,
+            ticks_per_sec: if elapsed_secs > 0.0 { report.tick as f64 / elapsed_secs } else { 0.0 },
+            rms: report.rms,
+            tracks_per_agent: agents.iter().map(|a| a.track_count()).collect(),
+            last_violation,
+            memory_estimate_bytes: report.memory_estimate_bytes,
+        }
+    }
+}
+
+#[cfg(feature = "status-server")]
+pub use server::StatusServer;
+
+#[cfg(feature = "status-server")]
+mod server {
+    use super::StatusReceiver;
+    use std::io;
+    use std::net::{SocketAddr, ToSocketAddrs};
+    use std::sync::Arc;
+    use std::thread::JoinHandle;
+    use tiny_http::{Header, Method, Response, Server};
+
+    /// HTTP server answering `GET /status` with the latest snapshot as JSON
+    /// (503 before the first one, 404 for any other path). Stops when
+    /// dropped.
+    pub struct StatusServer {
+        server: Arc<Server>,
+        addr: SocketAddr,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl StatusServer {
+        /// Starts serving `status` on `addr` (port 0 picks a free one).
+        pub fn start(addr: impl ToSocketAddrs, status: StatusReceiver) -> io::Result<Self> {
+            let server = Arc::new(Server::http(addr).map_err(io::Error::other)?);
+            let addr = server.server_addr().to_ip()
+                .ok_or_else(|| io::Error::other("status server isn't listening on an IP address"))?;
+            let serving = Arc::clone(&server);
+            let thread = std::thread::Builder::new()
+                .name("status-server".to_string())
+                .spawn(move || {
+                    for request in serving.incoming_requests() {
+                        let response = match (request.method(), request.url()) {
+                            (Method::Get, "/status") => match status.borrow().as_ref() {
+                                Some(snapshot) => json(200, serde_json::to_string(snapshot).unwrap_or_default()),
+                                None => json(503, r#"{"error":"no run has reported yet"}"#.to_string()),
+                            },
+                            _ => json(404, r#"{"error":"not found"}"#.to_string()),
+                        };
+                        // A client that hung up needs no answer
+                        let _ = request.respond(response);
+                    }
+                })?;
+            Ok(Self { server, addr, thread: Some(thread) })
+        }
+
+        /// The address the server is listening on.
+        pub fn local_addr(&self) -> SocketAddr {
+            self.addr
+        }
+    }
+
+    impl Drop for StatusServer {
+        fn drop(&mut self) {
+            self.server.unblock();
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+        }
+    }
+
+    fn json(status: u16, body: String) -> Response<io::Cursor<Vec<u8>>> {
+        let content_type = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+            .expect("static header is valid");
+        Response::from_string(body).with_status_code(status).with_header(content_type)
+    }
+}
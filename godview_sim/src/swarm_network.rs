@@ -1,21 +1,287 @@
 //! P2P Swarm Network for multi-agent simulation.
 //!
 //! Simulates gossip-based communication between neighboring agents
-//! in an H3 spatial grid.
+//! in an H3 spatial grid. Links can optionally be bandwidth-limited, in
+//! which case each directed link has a bounded outbound queue drained at a
+//! fixed number of packets, and optionally bytes, per round.
+//!
+//! Every gossiped packet is charged its size in the V1 wire codec
+//! ([`wire_bytes`]), measured once when it is queued, so byte counters per
+//! link and per agent are the sums of real serialized sizes.
+//!
+//! Every message is routed through a [`SimNetworkController`]: partitioned
+//! links carry nothing and lossy links drop messages. Signed envelopes sent
+//! with [`SwarmNetwork::send_envelope`] also wait out the link's latency,
+//! as do the NACKs receivers send back to ask for envelopes they missed
+//! ([`SwarmNetwork::send_nack`]).
+//!
+//! With [`SwarmNetwork::with_delta_compression`] each link sends gossip as
+//! deltas from the last full packet of each track instead (see
+//! [`crate::delta_gossip`]), charged the bytes of the frames sent.
+//!
+//! Gossip reaches an agent in a fixed order whatever order it was queued
+//! in (see [`SwarmNetwork`]), so truncating what an agent takes is
+//! deterministic too.
+//!
+//! The topology is a grid unless [`SwarmNetwork::with_radio_range`] links
+//! agents by distance instead, in which case [`SwarmNetwork::move_agents`]
+//! makes and breaks links as agents move.
 
+use crate::delta_gossip::{CompressionStats, DeltaCompression, DeltaLink, GossipFrame};
+use crate::event_bus::{SimEvent, SimEventBus};
+use crate::gossip_sequence::GossipNack;
+use crate::harness::BadActorPlacement;
+use crate::network::SimNetworkController;
+use crate::reputation_gossip::ReputationSharing;
+use crate::rng_audit::AuditedRng;
 use godview_core::godview_tracking::GlobalHazardPacket;
-use std::collections::HashMap;
+use godview_env::{NodeId, SignedPacketEnvelope};
+use nalgebra::Vector3;
+use rand::Rng;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// What a link does with a packet that arrives when its queue is congested.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropPolicy {
+    /// Drop arriving packets only once the queue is full
+    DropTail,
+    
+    /// Random early detection: above `min_depth` queued packets, drop arrivals
+    /// with a probability rising linearly to `max_drop_prob` at a full queue.
+    /// Arrivals to a full queue are always dropped.
+    RandomEarly { min_depth: usize, max_drop_prob: f64 },
+}
+
+/// Serialized size of `packet` in the V1 wire codec (one element of the
+/// JSON batch, without the batch's brackets and separators).
+pub fn wire_bytes(packet: &GlobalHazardPacket) -> u64 {
+    serde_json::to_vec(packet).map_or(0, |bytes| bytes.len() as u64)
+}
+
+/// Bandwidth model applied to every directed link in a [`SwarmNetwork`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinkConfig {
+    /// Packets delivered per link per [`SwarmNetwork::deliver_round`]
+    pub capacity_per_round: usize,
+    
+    /// Maximum packets waiting in a link's outbound queue
+    pub max_queue_depth: usize,
+    
+    /// Behaviour when the queue is congested
+    pub drop_policy: DropPolicy,
+    
+    /// Wire bytes delivered per link per round, on top of the packet
+    /// capacity (None: unlimited)
+    pub max_bytes_per_round: Option<u64>,
+}
+
+impl LinkConfig {
+    /// Drop-tail link with the given capacity and queue depth.
+    pub fn drop_tail(capacity_per_round: usize, max_queue_depth: usize) -> Self {
+        Self {
+            capacity_per_round,
+            max_queue_depth,
+            drop_policy: DropPolicy::DropTail,
+            max_bytes_per_round: None,
+        }
+    }
+    
+    /// Limits each link to `bytes_per_sec` of wire bytes, delivered in
+    /// rounds `round_secs` apart.
+    pub fn with_bandwidth(mut self, bytes_per_sec: f64, round_secs: f64) -> Self {
+        self.max_bytes_per_round = Some((bytes_per_sec * round_secs).max(0.0) as u64);
+        self
+    }
+}
+
+/// Per-link (or aggregate) packet and byte counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LinkStats {
+    /// Packets accepted into the outbound queue
+    pub enqueued: u64,
+    
+    /// Packets delivered to the receiver
+    pub delivered: u64,
+    
+    /// Packets dropped because the queue was congested
+    pub dropped_overflow: u64,
+    
+    /// Wire bytes of the packets accepted
+    pub bytes_enqueued: u64,
+    
+    /// Wire bytes of the packets delivered
+    pub bytes_delivered: u64,
+    
+    /// Wire bytes of the packets dropped
+    pub bytes_dropped: u64,
+    
+    /// Queued packets evicted to make room for a high-priority one
+    pub preempted: u64,
+}
+
+impl LinkStats {
+    fn add(&mut self, other: &LinkStats) {
+        self.enqueued += other.enqueued;
+        self.delivered += other.delivered;
+        self.dropped_overflow += other.dropped_overflow;
+        self.bytes_enqueued += other.bytes_enqueued;
+        self.bytes_delivered += other.bytes_delivered;
+        self.bytes_dropped += other.bytes_dropped;
+        self.preempted += other.preempted;
+    }
+}
+
+/// Counters for messages routed through the network controller.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RouteStats {
+    /// Messages handed to a neighbor link
+    pub sent: u64,
+    
+    /// Envelopes handed to their receiver
+    pub delivered: u64,
+    
+    /// Messages stopped by a partition, at send time or while in flight
+    pub blocked_partition: u64,
+    
+    /// Messages lost to the link's loss rate
+    pub dropped_loss: u64,
+    
+    /// NACKs handed to their receiver
+    pub nacks_delivered: u64,
+}
+
+/// A message waiting out its link latency.
+struct InFlight<M> {
+    deliver_at_ms: u64,
+    from: usize,
+    message: M,
+}
+
+/// Queues `message` for `to` in delivery-time order.
+fn hold<M>(in_flight: &mut BTreeMap<usize, VecDeque<InFlight<M>>>, to: usize, message: InFlight<M>) {
+    let queue = in_flight.entry(to).or_default();
+    let idx = queue.partition_point(|m| m.deliver_at_ms <= message.deliver_at_ms);
+    queue.insert(idx, message);
+}
+
+/// Takes the messages due for `to` by `now_ms`.
+fn take_due<M>(in_flight: &mut BTreeMap<usize, VecDeque<InFlight<M>>>, to: usize, now_ms: u64) -> Vec<InFlight<M>> {
+    let Some(queue) = in_flight.get_mut(&to) else {
+        return Vec::new();
+    };
+    let due = queue.partition_point(|m| m.deliver_at_ms <= now_ms);
+    queue.drain(..due).collect()
+}
+
+/// A directed link's outbound queue of packets, the frames they travel as
+/// when compressed, and their wire bytes.
+#[derive(Default)]
+struct LinkQueue {
+    queue: VecDeque<(GlobalHazardPacket, Option<GossipFrame>, u64)>,
+    stats: LinkStats,
+    delta: Option<DeltaLink>,
+}
+
+impl LinkQueue {
+    /// The frame `packet` travels as and its wire bytes: with `compression`
+    /// on, the link's delta encoding of it, else the packet itself at
+    /// `bytes`.
+    fn encode(&mut self, packet: &GlobalHazardPacket, bytes: u64, compression: Option<DeltaCompression>) -> (Option<GossipFrame>, u64) {
+        let Some(config) = compression else {
+            return (None, bytes);
+        };
+        let frame = self.delta.get_or_insert_with(|| DeltaLink::new(config)).send(packet);
+        let bytes = frame.wire_bytes();
+        (Some(frame), bytes)
+    }
+    
+    /// What the receiver makes of a delivered packet: the packet itself, or
+    /// its frame decoded (None if the receiver lacked the baseline).
+    fn decode(&mut self, packet: GlobalHazardPacket, frame: Option<GossipFrame>) -> Option<GlobalHazardPacket> {
+        match (frame, self.delta.as_mut()) {
+            (Some(frame), Some(delta)) => delta.receive(frame),
+            _ => Some(packet),
+        }
+    }
+}
 
 /// Represents the P2P network topology for a swarm of agents.
+///
+/// # Delivery order
+///
+/// [`Self::take_gossip`] returns an agent's pending packets sorted by
+/// sender index, and each sender's packets in the order its link delivered
+/// them: the order they were queued, with high-priority packets ahead of
+/// routine ones on a bandwidth-limited link. How sends from different
+/// agents interleave never changes what a receiver sees, so neither does
+/// cutting its gossip short with [`Self::take_gossip_from`].
+///
+/// [`Self::take_gossip_from`] picks the senders it keeps round-robin: each
+/// receiver resumes after the last sender it kept, so no sender is heard
+/// more often for having a low index.
 pub struct SwarmNetwork {
     /// Adjacency list: agent_index -> list of neighbor indices
     adjacency: HashMap<usize, Vec<usize>>,
     
-    /// Gossip buffer: pending packets per agent
-    gossip_buffers: HashMap<usize, Vec<GlobalHazardPacket>>,
+    /// Gossip buffer: pending packets per agent, with the agent that sent each
+    gossip_buffers: HashMap<usize, Vec<(usize, GlobalHazardPacket)>>,
+    
+    /// Per receiver, the sender index [`Self::take_gossip_from`] starts
+    /// from next
+    sender_cursors: HashMap<usize, usize>,
     
     /// Total messages sent (for metrics)
     messages_sent: u64,
+    
+    /// Wire bytes of those messages
+    bytes_sent: u64,
+    
+    /// Bandwidth model; `None` delivers everything immediately
+    link_config: Option<LinkConfig>,
+    
+    /// Outbound queues keyed by (from, to), ordered for deterministic delivery
+    links: BTreeMap<(usize, usize), LinkQueue>,
+    
+    /// RNG for random early drop
+    rng: AuditedRng,
+    
+    /// Partitions, loss and latency applied to every message
+    controller: SimNetworkController,
+    
+    /// Node ID of each agent index, as the controller knows it
+    nodes: Vec<NodeId>,
+    
+    /// Seed of the link loss streams
+    loss_seed: u64,
+    
+    /// Link loss stream of each link that has drawn one
+    /// (`network/link-{from}-{to}/loss`), so draws on one link never shift
+    /// another's
+    loss_rngs: BTreeMap<(usize, usize), AuditedRng>,
+    
+    /// Envelopes in flight per receiver, ordered by delivery time
+    in_flight: BTreeMap<usize, VecDeque<InFlight<SignedPacketEnvelope>>>,
+    
+    /// NACKs in flight per receiver, ordered by delivery time
+    nacks_in_flight: BTreeMap<usize, VecDeque<InFlight<GossipNack>>>,
+    
+    /// Routing counters
+    route_stats: RouteStats,
+    
+    /// Envelopes delivered per directed link (from, to)
+    envelope_deliveries: BTreeMap<(usize, usize), u64>,
+    
+    /// Distance (m) within which agents are linked; `None` keeps the grid
+    radio_range: Option<f64>,
+    
+    /// Links made or broken by agents moving
+    link_changes: u64,
+    
+    /// Bus link changes are published on
+    events: Option<SimEventBus>,
+    
+    /// Delta encoding of gossip; `None` sends every packet in full
+    delta_compression: Option<DeltaCompression>,
 }
 
 impl SwarmNetwork {
@@ -60,33 +326,458 @@ impl SwarmNetwork {
         Self {
             adjacency,
             gossip_buffers,
+            sender_cursors: HashMap::new(),
             messages_sent: 0,
+            bytes_sent: 0,
+            link_config: None,
+            links: BTreeMap::new(),
+            rng: AuditedRng::derive(0, "network/early-drop"),
+            controller: SimNetworkController::new(),
+            nodes: (0..rows * cols).map(|i| NodeId::from_seed(i as u64)).collect(),
+            loss_seed: 0,
+            loss_rngs: BTreeMap::new(),
+            in_flight: BTreeMap::new(),
+            nacks_in_flight: BTreeMap::new(),
+            route_stats: RouteStats::default(),
+            envelope_deliveries: BTreeMap::new(),
+            radio_range: None,
+            link_changes: 0,
+            events: None,
+            delta_compression: None,
         }
     }
     
+    /// Replaces the grid with links between every pair of agents within
+    /// `range_m` of each other, agent `i` standing at `positions[i]`.
+    /// Agents without a position have no links.
+    pub fn with_radio_range(mut self, range_m: f64, positions: &[Vector3<f64>]) -> Self {
+        self.radio_range = Some(range_m);
+        self.adjacency = self.links_within(range_m, positions);
+        self
+    }
+    
+    /// Rebuilds the links from the agents' new `positions` and counts the
+    /// ones made or broken ([`Self::link_changes`]). Packets already queued
+    /// on a broken link still go out. Does nothing without a radio range.
+    pub fn move_agents(&mut self, positions: &[Vector3<f64>]) {
+        let Some(range_m) = self.radio_range else {
+            return;
+        };
+        let adjacency = self.links_within(range_m, positions);
+        let (mut made, mut broken) = (0, 0);
+        for (idx, neighbors) in &adjacency {
+            let before = self.neighbors(*idx);
+            made += neighbors.iter().filter(|n| !before.contains(n)).count() as u64;
+            broken += before.iter().filter(|n| !neighbors.contains(n)).count() as u64;
+        }
+        self.link_changes += made + broken;
+        self.adjacency = adjacency;
+        if let Some(bus) = self.events.as_ref().filter(|_| made + broken > 0) {
+            bus.publish(SimEvent::LinksChanged { made, broken });
+        }
+    }
+    
+    /// Publishes the links [`Self::move_agents`] makes or breaks on `bus`.
+    pub fn set_event_bus(&mut self, bus: SimEventBus) {
+        self.events = Some(bus);
+    }
+    
+    /// Directed links made or broken by [`Self::move_agents`] so far (a
+    /// link between two agents counts once per direction).
+    pub fn link_changes(&self) -> u64 {
+        self.link_changes
+    }
+    
+    /// Neighbors within `range_m` of each agent, in index order.
+    fn links_within(&self, range_m: f64, positions: &[Vector3<f64>]) -> HashMap<usize, Vec<usize>> {
+        (0..self.adjacency.len())
+            .map(|idx| {
+                let neighbors = positions.get(idx)
+                    .map(|here| (0..positions.len())
+                        .filter(|&other| other != idx && (positions[other] - here).norm() <= range_m)
+                        .collect())
+                    .unwrap_or_default();
+                (idx, neighbors)
+            })
+            .collect()
+    }
+    
+    /// Limits every link's bandwidth. Packets queued with [`Self::queue_gossip`]
+    /// then only reach receivers when [`Self::deliver_round`] is called.
+    ///
+    /// `seed` drives random early drop, so runs stay reproducible.
+    pub fn with_link_config(mut self, config: LinkConfig, seed: u64) -> Self {
+        self.link_config = Some(config);
+        self.rng = AuditedRng::derive(seed, "network/early-drop");
+        self
+    }
+    
+    /// Sends gossip on every link as deltas from the last full packet of
+    /// each track (see [`crate::delta_gossip`]). Bytes are then counted
+    /// from the frames sent, and a delta whose receiver lacks its baseline
+    /// is dropped on delivery.
+    pub fn with_delta_compression(mut self, config: DeltaCompression) -> Self {
+        self.delta_compression = Some(config);
+        self
+    }
+    
+    /// Returns the compression counters summed over every link (all zero
+    /// without compression).
+    pub fn compression_stats(&self) -> CompressionStats {
+        let mut total = CompressionStats::default();
+        for delta in self.links.values().filter_map(|link| link.delta.as_ref()) {
+            total.merge(&delta.stats());
+        }
+        total
+    }
+    
+    /// Routes every message through `controller`, with `nodes[i]` as the
+    /// node ID of agent `i`.
+    ///
+    /// `seed` drives link loss, so runs stay reproducible.
+    pub fn with_routing(mut self, controller: SimNetworkController, nodes: Vec<NodeId>, seed: u64) -> Self {
+        self.controller = controller;
+        self.nodes = nodes;
+        self.loss_seed = seed;
+        self.loss_rngs.clear();
+        self
+    }
+    
+    /// Returns the controller messages are routed through.
+    pub fn controller(&self) -> &SimNetworkController {
+        &self.controller
+    }
+    
+    /// Node ID of an agent index.
+    fn node(&self, agent_idx: usize) -> NodeId {
+        self.nodes.get(agent_idx).copied().unwrap_or_else(|| NodeId::from_seed(agent_idx as u64))
+    }
+    
+    /// The loss stream of link `from -> to`, derived on first use.
+    fn loss_rng(&mut self, from: usize, to: usize) -> &mut AuditedRng {
+        let seed = self.loss_seed;
+        self.loss_rngs.entry((from, to))
+            .or_insert_with(|| AuditedRng::derive(seed, &format!("network/link-{}-{}/loss", from, to)))
+    }
+    
+    /// Whether the controller lets a message from `from` reach `to`.
+    fn route(&mut self, from: usize, to: usize) -> bool {
+        let (from_node, to_node) = (self.node(from), self.node(to));
+        self.route_stats.sent += 1;
+        if !self.controller.can_communicate(from_node, to_node) {
+            self.route_stats.blocked_partition += 1;
+            return false;
+        }
+        let loss = self.controller.get_loss(from_node, to_node);
+        if loss > 0.0 && self.loss_rng(from, to).gen::<f64>() < loss {
+            self.route_stats.dropped_loss += 1;
+            return false;
+        }
+        true
+    }
+    
     /// Returns the neighbors of an agent.
     pub fn neighbors(&self, agent_idx: usize) -> &[usize] {
         self.adjacency.get(&agent_idx).map(|v| v.as_slice()).unwrap_or(&[])
     }
     
     /// Queues a packet for gossip to neighbors.
+    ///
+    /// Without a link config the packet lands in each neighbor's buffer
+    /// immediately; otherwise it joins each link's outbound queue. Either
+    /// way each copy is charged the packet's [`wire_bytes`], or its frame's
+    /// with delta compression on.
     pub fn queue_gossip(&mut self, from_agent: usize, packet: GlobalHazardPacket) {
-        if let Some(neighbors) = self.adjacency.get(&from_agent) {
-            for &neighbor in neighbors {
-                if let Some(buffer) = self.gossip_buffers.get_mut(&neighbor) {
-                    buffer.push(packet.clone());
-                    self.messages_sent += 1;
+        self.queue_gossip_where(from_agent, packet, |_| true);
+    }
+    
+    /// Queues a packet for gossip to the neighbors accepted by `reachable`
+    /// and by the controller's partitions and loss.
+    ///
+    /// On a limited link, [high-priority](GlobalHazardPacket::is_high_priority)
+    /// packets are queued ahead of the rest and are never dropped while a
+    /// routine packet is queued: the newest one is evicted instead.
+    ///
+    /// Returns how many neighbors were skipped.
+    pub fn queue_gossip_where(
+        &mut self,
+        from_agent: usize,
+        packet: GlobalHazardPacket,
+        reachable: impl Fn(usize) -> bool,
+    ) -> usize {
+        let Some(neighbors) = self.adjacency.get(&from_agent).cloned() else {
+            return 0;
+        };
+        let (neighbors, skipped): (Vec<usize>, Vec<usize>) = neighbors.into_iter()
+            .partition(|&n| reachable(n) && self.route(from_agent, n));
+        let packet_bytes = wire_bytes(&packet);
+        let compression = self.delta_compression;
+        
+        let Some(config) = self.link_config else {
+            for &neighbor in &neighbors {
+                let Some(buffer) = self.gossip_buffers.get_mut(&neighbor) else {
+                    continue;
+                };
+                let link = self.links.entry((from_agent, neighbor)).or_default();
+                let (frame, bytes) = link.encode(&packet, packet_bytes, compression);
+                if let Some(packet) = link.decode(packet.clone(), frame) {
+                    buffer.push((from_agent, packet));
+                }
+                self.messages_sent += 1;
+                self.bytes_sent += bytes;
+                link.stats.enqueued += 1;
+                link.stats.delivered += 1;
+                link.stats.bytes_enqueued += bytes;
+                link.stats.bytes_delivered += bytes;
+            }
+            return skipped.len();
+        };
+        
+        for &neighbor in &neighbors {
+            let link = self.links.entry((from_agent, neighbor)).or_default();
+            let (frame, bytes) = link.encode(&packet, packet_bytes, compression);
+            self.messages_sent += 1;
+            self.bytes_sent += bytes;
+            let depth = link.queue.len();
+            
+            let mut drop = match config.drop_policy {
+                _ if depth >= config.max_queue_depth => true,
+                DropPolicy::DropTail => false,
+                DropPolicy::RandomEarly { min_depth, max_drop_prob } => {
+                    depth >= min_depth && {
+                        let span = config.max_queue_depth.saturating_sub(min_depth).max(1);
+                        let p = max_drop_prob * (depth - min_depth) as f64 / span as f64;
+                        self.rng.gen::<f64>() < p
+                    }
+                }
+            };
+            
+            // High-priority packets skip early drop and, at a full queue,
+            // take the place of the newest low-priority packet
+            let high_priority = packet.is_high_priority();
+            if drop && high_priority {
+                if depth < config.max_queue_depth {
+                    drop = false;
+                } else if let Some(victim) = link.queue.iter().rposition(|(p, ..)| !p.is_high_priority()) {
+                    link.queue.remove(victim);
+                    link.stats.preempted += 1;
+                    drop = false;
+                }
+            }
+            
+            if drop {
+                link.stats.dropped_overflow += 1;
+                link.stats.bytes_dropped += bytes;
+            } else {
+                // ...and are delivered ahead of them
+                let at = if high_priority {
+                    link.queue.iter().position(|(p, ..)| !p.is_high_priority()).unwrap_or(link.queue.len())
+                } else {
+                    link.queue.len()
+                };
+                link.queue.insert(at, (packet.clone(), frame, bytes));
+                link.stats.enqueued += 1;
+                link.stats.bytes_enqueued += bytes;
+            }
+        }
+        
+        skipped.len()
+    }
+    
+    /// Moves up to `capacity_per_round` packets, and up to
+    /// `max_bytes_per_round` wire bytes, from each link's queue into its
+    /// receiver's buffer, oldest first. A round stops at the first packet
+    /// that would overrun the byte budget, which waits for the next round,
+    /// except that the first packet of a round always goes (so a packet
+    /// larger than the budget can't stall its link). Compressed frames are
+    /// decoded on arrival. No-op without a link config.
+    pub fn deliver_round(&mut self) {
+        let Some(config) = self.link_config else {
+            return;
+        };
+        
+        for (&(from, to), link) in self.links.iter_mut() {
+            let Some(buffer) = self.gossip_buffers.get_mut(&to) else {
+                continue;
+            };
+            let mut round_bytes = 0;
+            let mut count = 0;
+            for (_, _, bytes) in link.queue.iter().take(config.capacity_per_round) {
+                if count > 0 && config.max_bytes_per_round.is_some_and(|max| round_bytes + bytes > max) {
+                    break;
                 }
+                round_bytes += bytes;
+                count += 1;
             }
+            let arrived: Vec<_> = link.queue.drain(..count).collect();
+            for (packet, frame, _) in arrived {
+                if let Some(packet) = link.decode(packet, frame) {
+                    buffer.push((from, packet));
+                }
+            }
+            link.stats.delivered += count as u64;
+            link.stats.bytes_delivered += round_bytes;
+        }
+    }
+    
+    /// Returns the counters for the directed link `from -> to`.
+    pub fn link_stats(&self, from: usize, to: usize) -> LinkStats {
+        self.links.get(&(from, to)).map(|l| l.stats).unwrap_or_default()
+    }
+    
+    /// Returns the counters summed over `agent`'s outbound links.
+    pub fn agent_link_stats(&self, agent: usize) -> LinkStats {
+        let mut total = LinkStats::default();
+        for link in self.links.range((agent, 0)..(agent + 1, 0)).map(|(_, link)| link) {
+            total.add(&link.stats);
+        }
+        total
+    }
+    
+    /// Returns the counters summed over every link.
+    pub fn total_link_stats(&self) -> LinkStats {
+        let mut total = LinkStats::default();
+        for link in self.links.values() {
+            total.add(&link.stats);
         }
+        total
+    }
+    
+    /// Returns the number of packets waiting in the `from -> to` queue.
+    pub fn queue_depth(&self, from: usize, to: usize) -> usize {
+        self.links.get(&(from, to)).map(|l| l.queue.len()).unwrap_or(0)
     }
     
-    /// Takes all pending gossip for an agent (drains the buffer).
-    pub fn take_gossip(&mut self, agent_idx: usize) -> Vec<GlobalHazardPacket> {
-        self.gossip_buffers
+    /// Sends a signed envelope to every neighbor of `from_agent` at `now_ms`.
+    ///
+    /// Each copy is checked against the controller's partitions and loss,
+    /// then held for the link's latency. Returns how many neighbors were
+    /// skipped.
+    pub fn send_envelope(&mut self, from_agent: usize, envelope: SignedPacketEnvelope, now_ms: u64) -> usize {
+        let Some(neighbors) = self.adjacency.get(&from_agent).cloned() else {
+            return 0;
+        };
+        
+        let mut skipped = 0;
+        for neighbor in neighbors {
+            if !self.send_envelope_to(from_agent, neighbor, envelope.clone(), now_ms) {
+                skipped += 1;
+            }
+        }
+        skipped
+    }
+    
+    /// Sends a signed envelope to one agent at `now_ms`, e.g. to answer its
+    /// NACK. Returns false if a partition or loss stopped it.
+    pub fn send_envelope_to(&mut self, from_agent: usize, to_agent: usize, envelope: SignedPacketEnvelope, now_ms: u64) -> bool {
+        self.messages_sent += 1;
+        if !self.route(from_agent, to_agent) {
+            return false;
+        }
+        let deliver_at_ms = now_ms + self.controller.sample_latency(self.node(from_agent), self.node(to_agent));
+        hold(&mut self.in_flight, to_agent, InFlight { deliver_at_ms, from: from_agent, message: envelope });
+        true
+    }
+    
+    /// Takes the envelopes due for an agent by `now_ms`, with the index of
+    /// the neighbor that sent each one.
+    ///
+    /// Envelopes whose link was partitioned while they were in flight are
+    /// dropped.
+    pub fn take_envelopes(&mut self, agent_idx: usize, now_ms: u64) -> Vec<(usize, SignedPacketEnvelope)> {
+        let due = take_due(&mut self.in_flight, agent_idx, now_ms);
+        let delivered = self.still_routable(agent_idx, due);
+        for (from, _) in &delivered {
+            self.route_stats.delivered += 1;
+            *self.envelope_deliveries.entry((*from, agent_idx)).or_default() += 1;
+        }
+        delivered
+    }
+    
+    /// Sends a NACK from `from_agent` back to the neighbor `to_agent` at
+    /// `now_ms`, subject to the same partitions, loss and latency as
+    /// envelopes. Returns false if it was stopped.
+    pub fn send_nack(&mut self, from_agent: usize, to_agent: usize, nack: GossipNack, now_ms: u64) -> bool {
+        if !self.route(from_agent, to_agent) {
+            return false;
+        }
+        let deliver_at_ms = now_ms + self.controller.sample_latency(self.node(from_agent), self.node(to_agent));
+        hold(&mut self.nacks_in_flight, to_agent, InFlight { deliver_at_ms, from: from_agent, message: nack });
+        true
+    }
+    
+    /// Takes the NACKs due for an agent by `now_ms`, with the index of the
+    /// neighbor that sent each one.
+    pub fn take_nacks(&mut self, agent_idx: usize, now_ms: u64) -> Vec<(usize, GossipNack)> {
+        let due = take_due(&mut self.nacks_in_flight, agent_idx, now_ms);
+        let delivered = self.still_routable(agent_idx, due);
+        self.route_stats.nacks_delivered += delivered.len() as u64;
+        delivered
+    }
+    
+    /// Drops the messages for `to` whose link was partitioned in flight.
+    fn still_routable<M>(&mut self, to: usize, due: Vec<InFlight<M>>) -> Vec<(usize, M)> {
+        let to_node = self.node(to);
+        let mut out = Vec::with_capacity(due.len());
+        for message in due {
+            if self.controller.can_communicate(self.node(message.from), to_node) {
+                out.push((message.from, message.message));
+            } else {
+                self.route_stats.blocked_partition += 1;
+            }
+        }
+        out
+    }
+    
+    /// Returns the routing counters.
+    pub fn route_stats(&self) -> RouteStats {
+        self.route_stats
+    }
+    
+    /// Returns how many envelopes `from` has delivered to `to`.
+    pub fn envelopes_delivered(&self, from: usize, to: usize) -> u64 {
+        self.envelope_deliveries.get(&(from, to)).copied().unwrap_or(0)
+    }
+    
+    /// Takes all pending gossip for an agent (drains the buffer), with the
+    /// index of the agent that queued each packet, in
+    /// [delivery order](Self#delivery-order).
+    pub fn take_gossip(&mut self, agent_idx: usize) -> Vec<(usize, GlobalHazardPacket)> {
+        let mut incoming = self.gossip_buffers
             .get_mut(&agent_idx)
-            .map(|b| std::mem::take(b))
-            .unwrap_or_default()
+            .map(std::mem::take)
+            .unwrap_or_default();
+        // Stable, so each sender's packets keep their delivery order
+        incoming.sort_by_key(|(from, _)| *from);
+        incoming
+    }
+    
+    /// [`Self::take_gossip`], keeping only the packets from `max_senders`
+    /// senders, still in [delivery order](Self#delivery-order). The rest
+    /// are dropped.
+    ///
+    /// Senders are kept round-robin: the first at or after the receiver's
+    /// cursor, wrapping past the highest index, and the cursor moves past
+    /// the last one kept. Over rounds every sender is heard equally often.
+    pub fn take_gossip_from(&mut self, agent_idx: usize, max_senders: usize) -> Vec<(usize, GlobalHazardPacket)> {
+        let incoming = self.take_gossip(agent_idx);
+        let senders: Vec<usize> = incoming.chunk_by(|a, b| a.0 == b.0).map(|run| run[0].0).collect();
+        if senders.len() <= max_senders {
+            return incoming;
+        }
+        let cursor = self.sender_cursors.get(&agent_idx).copied().unwrap_or(0);
+        let start = senders.partition_point(|&from| from < cursor);
+        let kept: Vec<usize> = senders.iter().cycle().skip(start).take(max_senders).copied().collect();
+        if let Some(&last) = kept.last() {
+            self.sender_cursors.insert(agent_idx, last + 1);
+        }
+        incoming.into_iter().filter(|(from, _)| kept.contains(from)).collect()
+    }
+    
+    /// [`Self::take_gossip`] without the senders, for agents that don't
+    /// track provenance.
+    pub fn take_gossip_packets(&mut self, agent_idx: usize) -> Vec<GlobalHazardPacket> {
+        self.take_gossip(agent_idx).into_iter().map(|(_, packet)| packet).collect()
     }
     
     /// Returns the total number of messages sent.
@@ -94,6 +785,16 @@ impl SwarmNetwork {
         self.messages_sent
     }
     
+    /// Returns the wire bytes of every message sent.
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent
+    }
+    
+    /// Returns the wire bytes of messages dropped by congested links.
+    pub fn bytes_dropped(&self) -> u64 {
+        self.total_link_stats().bytes_dropped
+    }
+    
     /// Returns the total number of agents in the network.
     pub fn agent_count(&self) -> usize {
         self.adjacency.len()
@@ -126,6 +827,26 @@ pub struct SwarmConfig {
     
     /// Maximum acceptable position error (meters)
     pub max_position_error: f64,
+    
+    /// Minimum acceptable MOTA (see [`crate::scoring`])
+    pub min_mota: f64,
+    
+    /// Bad actors AdaptiveSwarm turns mid-run
+    pub num_bad_actors: usize,
+    
+    /// Simulated time at which AdaptiveSwarm's bad actors turn (s)
+    pub bad_actor_inject_secs: f64,
+    
+    /// Whether AdaptiveSwarm's bad actors may be neighbors of each other
+    pub bad_actor_placement: BadActorPlacement,
+    
+    /// Whether AdaptiveSwarm's agents share reputation summaries (None: each
+    /// learns from its own neighbors only)
+    pub reputation_sharing: Option<ReputationSharing>,
+    
+    /// Whether AdaptiveSwarm's gossip is delta-encoded (None: every packet
+    /// is sent in full)
+    pub delta_gossip: Option<DeltaCompression>,
 }
 
 impl Default for SwarmConfig {
@@ -139,6 +860,14 @@ impl Default for SwarmConfig {
             gossip_interval: 3, // Gossip every 3 ticks (~10 Hz)
             max_variance: 0.15,  // 15% CV allowed (partial visibility causes variance)
             max_position_error: 3.0,
+            // Each agent ends up tracking only the half of the entities it
+            // senses itself; gossip about the rest associates onto them
+            min_mota: 0.45,
+            num_bad_actors: 5,
+            bad_actor_inject_secs: 10.0,
+            bad_actor_placement: BadActorPlacement::Anywhere,
+            reputation_sharing: None,
+            delta_gossip: None,
         }
     }
 }
@@ -146,6 +875,7 @@ impl Default for SwarmConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use godview_core::MeasurementModel;
     
     #[test]
     fn test_grid_topology() {
@@ -161,6 +891,29 @@ mod tests {
         assert_eq!(network.neighbors(1).len(), 5);
     }
     
+    #[test]
+    fn test_radio_range_links_follow_positions() {
+        let spaced = |gap: f64| (0..4).map(|i| Vector3::new(i as f64 * gap, 0.0, 0.0)).collect::<Vec<_>>();
+        let mut network = SwarmNetwork::new_grid(1, 4).with_radio_range(25.0, &spaced(10.0));
+        assert_eq!(network.neighbors(0), [1, 2]);
+        assert_eq!(network.neighbors(1), [0, 2, 3]);
+        assert_eq!(network.link_changes(), 0);
+        
+        // Stretching the line past half the range breaks the two-hop links
+        network.move_agents(&spaced(15.0));
+        assert_eq!(network.neighbors(0), [1]);
+        assert_eq!(network.neighbors(1), [0, 2]);
+        assert_eq!(network.link_changes(), 4);
+        
+        network.move_agents(&spaced(15.0));
+        assert_eq!(network.link_changes(), 4);
+        
+        // Grids ignore positions
+        let mut grid = SwarmNetwork::new_grid(1, 4);
+        grid.move_agents(&spaced(100.0));
+        assert_eq!(grid.neighbors(1), [0, 2]);
+    }
+    
     #[test]
     fn test_gossip_delivery() {
         let mut network = SwarmNetwork::new_grid(2, 2);
@@ -172,6 +925,8 @@ mod tests {
             class_id: 1,
             timestamp: 0.0,
             confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
         };
         
         // Agent 0 gossips
@@ -187,4 +942,373 @@ mod tests {
         
         assert_eq!(network.messages_sent(), 3);
     }
+    
+    #[test]
+    fn test_gossip_is_attributed_to_its_sender() {
+        // Agent 4 is the center of a 3x3 grid, next to both 7 and 3
+        for bandwidth_limited in [false, true] {
+            let mut network = SwarmNetwork::new_grid(3, 3);
+            if bandwidth_limited {
+                network = network.with_link_config(LinkConfig::drop_tail(10, 10), 1);
+            }
+            network.queue_gossip(7, numbered_packet(70));
+            network.queue_gossip(3, numbered_packet(30));
+            network.queue_gossip(7, numbered_packet(71));
+            network.deliver_round();
+            
+            let mut received: Vec<(usize, u128)> = network.take_gossip(4).into_iter()
+                .map(|(from, p)| (from, p.entity_id.as_u128()))
+                .collect();
+            received.sort();
+            assert_eq!(received, vec![(3, 30), (7, 70), (7, 71)], "bandwidth limited: {bandwidth_limited}");
+        }
+    }
+    
+    #[test]
+    fn test_delivery_order_ignores_how_senders_interleave() {
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+        
+        // Every neighbor of agent 4 sends it three packets, numbered
+        // sender * 10 + n; only the interleaving of senders is shuffled
+        let senders: Vec<usize> = [0, 1, 2, 3, 5, 6, 7, 8].into_iter().flat_map(|s| [s; 3]).collect();
+        let received = |order: &[usize], bandwidth_limited: bool| {
+            let mut network = SwarmNetwork::new_grid(3, 3);
+            if bandwidth_limited {
+                network = network.with_link_config(LinkConfig::drop_tail(10, 10), 1);
+            }
+            let mut sent = [0; 9];
+            for &from in order {
+                network.queue_gossip(from, numbered_packet(from * 10 + sent[from]));
+                sent[from] += 1;
+            }
+            network.deliver_round();
+            network.take_gossip(4).into_iter()
+                .map(|(from, p)| (from, p.entity_id.as_u128()))
+                .collect::<Vec<_>>()
+        };
+        
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for bandwidth_limited in [false, true] {
+            let expected = received(&senders, bandwidth_limited);
+            assert_eq!(expected.len(), 24);
+            assert!(expected.is_sorted(), "by sender, then in send order: {expected:?}");
+            for _ in 0..10 {
+                let mut shuffled = senders.clone();
+                shuffled.shuffle(&mut rng);
+                assert_eq!(received(&shuffled, bandwidth_limited), expected, "bandwidth limited: {bandwidth_limited}");
+            }
+        }
+    }
+    
+    #[test]
+    fn test_take_gossip_from_rotates_through_senders() {
+        let mut network = SwarmNetwork::new_grid(3, 3);
+        let mut take = |max_senders| {
+            for (from, n) in [(7, 70), (3, 30), (5, 50), (3, 31), (1, 10)] {
+                network.queue_gossip(from, numbered_packet(n));
+            }
+            let kept: Vec<(usize, u128)> = network.take_gossip_from(4, max_senders).into_iter()
+                .map(|(from, p)| (from, p.entity_id.as_u128()))
+                .collect();
+            // The rest are dropped, not left for the next take
+            assert!(network.take_gossip(4).is_empty());
+            kept
+        };
+        assert_eq!(take(2), vec![(1, 10), (3, 30), (3, 31)]);
+        assert_eq!(take(2), vec![(5, 50), (7, 70)]);
+        // Wraps past the highest sender
+        assert_eq!(take(3), vec![(1, 10), (3, 30), (3, 31), (5, 50)]);
+        // Room for everyone leaves the cursor alone
+        assert_eq!(take(4).len(), 5);
+        assert_eq!(take(1), vec![(7, 70)]);
+        assert_eq!(take(1), vec![(1, 10)]);
+        assert!(take(0).is_empty());
+    }
+    
+    #[test]
+    fn test_take_gossip_from_hears_every_sender_equally() {
+        let mut network = SwarmNetwork::new_grid(3, 3);
+        let mut heard = BTreeMap::new();
+        // Agent 0's neighbors, with the corner's low indices first
+        for _ in 0..30 {
+            for from in [1, 3, 4] {
+                network.queue_gossip(from, numbered_packet(from));
+            }
+            for (from, _) in network.take_gossip_from(0, 1) {
+                *heard.entry(from).or_insert(0) += 1;
+            }
+        }
+        assert_eq!(heard, BTreeMap::from([(1, 10), (3, 10), (4, 10)]));
+    }
+    
+    fn numbered_packet(n: usize) -> GlobalHazardPacket {
+        GlobalHazardPacket {
+            entity_id: uuid::Uuid::from_u128(n as u128),
+            position: [n as f64, 0.0, 0.0],
+            velocity: [0.0, 0.0, 0.0],
+            class_id: 1,
+            timestamp: n as f64,
+            confidence_score: 0.9,
+            measurement_model: MeasurementModel::default(),
+            priority: 0,
+        }
+    }
+    
+    #[test]
+    fn test_link_at_capacity_delivers_fifo() {
+        // 1x2 grid: a single link in each direction
+        let mut network = SwarmNetwork::new_grid(1, 2)
+            .with_link_config(LinkConfig::drop_tail(3, 8), 7);
+        
+        for n in 0..10 {
+            network.queue_gossip(0, numbered_packet(n));
+        }
+        // Nothing is delivered until a round runs
+        assert!(network.take_gossip(1).is_empty());
+        
+        let stats = network.link_stats(0, 1);
+        assert_eq!(stats.enqueued, 8);
+        assert_eq!(stats.dropped_overflow, 2);
+        
+        let mut received = Vec::new();
+        for _ in 0..3 {
+            network.deliver_round();
+            let round = network.take_gossip_packets(1);
+            assert_eq!(round.len(), 3.min(8 - received.len()));
+            received.extend(round);
+        }
+        
+        let order: Vec<u128> = received.iter().map(|p| p.entity_id.as_u128()).collect();
+        assert_eq!(order, (0..8).collect::<Vec<u128>>());
+        assert_eq!(network.link_stats(0, 1).delivered, 8);
+        assert_eq!(network.queue_depth(0, 1), 0);
+    }
+    
+    #[test]
+    fn test_high_priority_packets_preempt_a_full_queue() {
+        let mut network = SwarmNetwork::new_grid(1, 2)
+            .with_link_config(LinkConfig::drop_tail(2, 3), 7);
+        let urgent = |n| GlobalHazardPacket { priority: GlobalHazardPacket::HIGH_PRIORITY, ..numbered_packet(n) };
+        
+        for n in 0..3 {
+            network.queue_gossip(0, numbered_packet(n));
+        }
+        // Each takes the newest routine packet's place and goes to the front
+        network.queue_gossip(0, urgent(10));
+        network.queue_gossip(0, urgent(11));
+        network.queue_gossip(0, urgent(12));
+        network.queue_gossip(0, urgent(13));
+        
+        let stats = network.link_stats(0, 1);
+        assert_eq!((stats.enqueued, stats.preempted, stats.dropped_overflow), (6, 3, 1));
+        
+        let mut order = Vec::new();
+        for _ in 0..2 {
+            network.deliver_round();
+            order.extend(network.take_gossip_packets(1).iter().map(|p| p.entity_id.as_u128()));
+        }
+        assert_eq!(order, vec![10, 11, 12]);
+    }
+    
+    #[test]
+    fn test_bytes_are_the_sum_of_serialized_sizes() {
+        let mut network = SwarmNetwork::new_grid(2, 2)
+            .with_link_config(LinkConfig::drop_tail(10, 2), 1);
+        let packets: Vec<GlobalHazardPacket> = [1, 22, 333].into_iter().map(numbered_packet).collect();
+        for packet in &packets {
+            network.queue_gossip(0, packet.clone());
+        }
+        network.deliver_round();
+        
+        let sizes: Vec<u64> = packets.iter().map(|p| serde_json::to_vec(p).unwrap().len() as u64).collect();
+        assert_ne!(sizes[0], sizes[2], "sizes vary with the content");
+        let stats = network.link_stats(0, 1);
+        assert_eq!((stats.bytes_enqueued, stats.bytes_delivered), (sizes[0] + sizes[1], sizes[0] + sizes[1]));
+        assert_eq!(stats.bytes_dropped, sizes[2]);
+        
+        // Agent 0 has three links
+        assert_eq!(network.agent_link_stats(0).bytes_enqueued, 3 * (sizes[0] + sizes[1]));
+        assert_eq!(network.bytes_sent(), 3 * sizes.iter().sum::<u64>());
+        assert_eq!(network.bytes_dropped(), 3 * sizes[2]);
+        assert_eq!(network.agent_link_stats(1), LinkStats::default());
+        
+        // Unlimited links count the same bytes
+        let mut unlimited = SwarmNetwork::new_grid(2, 2);
+        unlimited.queue_gossip(0, packets[2].clone());
+        assert_eq!(unlimited.link_stats(0, 3).bytes_delivered, sizes[2]);
+        assert_eq!(unlimited.bytes_sent(), 3 * sizes[2]);
+    }
+    
+    #[test]
+    fn test_byte_budget_truncates_a_round() {
+        let packets: Vec<GlobalHazardPacket> = (0..6).map(numbered_packet).collect();
+        let size = wire_bytes(&packets[0]);
+        assert!(packets.iter().all(|p| wire_bytes(p) == size));
+        
+        // Room for two and a half packets a round
+        let config = LinkConfig::drop_tail(10, 10).with_bandwidth(size as f64 * 25.0, 0.1);
+        assert_eq!(config.max_bytes_per_round, Some(size * 5 / 2));
+        let mut network = SwarmNetwork::new_grid(1, 2).with_link_config(config, 1);
+        for packet in &packets {
+            network.queue_gossip(0, packet.clone());
+        }
+        
+        let mut rounds = Vec::new();
+        for _ in 0..3 {
+            network.deliver_round();
+            rounds.push(network.take_gossip_packets(1).iter().map(|p| p.entity_id.as_u128()).collect::<Vec<_>>());
+        }
+        assert_eq!(rounds, [vec![0, 1], vec![2, 3], vec![4, 5]]);
+        assert_eq!(network.link_stats(0, 1).bytes_delivered, 6 * size);
+        
+        // A packet over the whole budget still goes, alone
+        let tight = LinkConfig::drop_tail(10, 10).with_bandwidth(1.0, 1.0);
+        let mut network = SwarmNetwork::new_grid(1, 2).with_link_config(tight, 1);
+        network.queue_gossip(0, packets[0].clone());
+        network.queue_gossip(0, packets[1].clone());
+        network.deliver_round();
+        assert_eq!(network.take_gossip(1).len(), 1);
+        assert_eq!(network.queue_depth(0, 1), 1);
+    }
+    
+    #[test]
+    fn test_delta_compression_sends_fewer_bytes_and_recovers_lost_baselines() {
+        let config = DeltaCompression::default();
+        let moving = |t: f64| GlobalHazardPacket {
+            position: [10.0 * t, 0.5, 100.0],
+            velocity: [10.0, 0.0, 0.0],
+            timestamp: t,
+            ..numbered_packet(1)
+        };
+        
+        let mut network = SwarmNetwork::new_grid(1, 2).with_delta_compression(config);
+        for step in 0..10 {
+            let packet = moving(step as f64 * 0.1);
+            network.queue_gossip(0, packet.clone());
+            let received = network.take_gossip_packets(1);
+            assert!((received[0].position[0] - packet.position[0]).abs() <= config.position_bound());
+        }
+        let stats = network.compression_stats();
+        assert_eq!((stats.full_frames, stats.delta_frames), (1, 9));
+        assert_eq!(network.bytes_sent(), stats.frame_bytes);
+        assert!(stats.raw_bytes > stats.frame_bytes);
+        
+        // A full packet dropped by a congested queue leaves the next delta
+        // undecodable; the broadcast after it goes out in full again
+        let mut network = SwarmNetwork::new_grid(1, 2)
+            .with_link_config(LinkConfig::drop_tail(1, 1), 7)
+            .with_delta_compression(config);
+        network.queue_gossip(0, numbered_packet(0));
+        network.queue_gossip(0, moving(0.0));
+        network.deliver_round();
+        network.queue_gossip(0, moving(0.1));
+        network.deliver_round();
+        assert_eq!(network.take_gossip_packets(1).len(), 1);
+        assert_eq!(network.compression_stats().full_requests, 1);
+        
+        network.queue_gossip(0, moving(0.2));
+        network.deliver_round();
+        assert_eq!(network.take_gossip_packets(1)[0].timestamp, 0.2);
+        assert_eq!(network.compression_stats().full_frames, 3);
+    }
+    
+    #[test]
+    fn test_random_early_drop_is_deterministic() {
+        let policy = LinkConfig {
+            capacity_per_round: 2,
+            max_queue_depth: 20,
+            drop_policy: DropPolicy::RandomEarly { min_depth: 5, max_drop_prob: 0.8 },
+            max_bytes_per_round: None,
+        };
+        let run = || {
+            let mut network = SwarmNetwork::new_grid(2, 2).with_link_config(policy, 99);
+            // Fifteen packets per link push the queues well past min_depth
+            for n in 0..60 {
+                network.queue_gossip(n % 4, numbered_packet(n));
+            }
+            network.deliver_round();
+            network.total_link_stats()
+        };
+        
+        let stats = run();
+        assert_eq!(stats, run());
+        assert!(stats.dropped_overflow > 0);
+        // Below min_depth nothing is dropped early
+        assert!(stats.enqueued >= 4 * 3 * 5);
+        assert_eq!(stats.delivered, 4 * 3 * 2);
+    }
+    
+    #[test]
+    fn test_partition_stops_envelopes_including_in_flight() {
+        let controller = SimNetworkController::new();
+        let nodes: Vec<NodeId> = (0..4).map(NodeId::from_seed).collect();
+        let mut network = SwarmNetwork::new_grid(2, 2).with_routing(controller.clone(), nodes.clone(), 1);
+        controller.set_latency(nodes[0], nodes[1], 50);
+        
+        // Sent before the partition, still in flight when it starts
+        network.send_envelope(0, SignedPacketEnvelope::new(vec![1], 0), 0);
+        controller.partition(vec![nodes[0], nodes[2]], vec![nodes[1], nodes[3]]);
+        assert_eq!(network.send_envelope(0, SignedPacketEnvelope::new(vec![2], 10), 10), 2);
+        
+        assert!(network.take_envelopes(1, 100).is_empty());
+        assert!(network.take_envelopes(3, 100).is_empty());
+        assert_eq!(network.take_envelopes(2, 100).len(), 2);
+        for (from, to) in [(0, 1), (0, 3)] {
+            assert_eq!(network.envelopes_delivered(from, to), 0);
+        }
+        let stats = network.route_stats();
+        assert_eq!(stats.blocked_partition, 4);
+        assert_eq!(stats.delivered, 2);
+        
+        controller.heal_all();
+        network.send_envelope(0, SignedPacketEnvelope::new(vec![3], 200), 200);
+        assert_eq!(network.take_envelopes(1, 250).len(), 1);
+        assert_eq!(network.envelopes_delivered(0, 1), 1);
+    }
+    
+    #[test]
+    fn test_link_loss_drops_envelopes() {
+        let controller = SimNetworkController::new();
+        let nodes: Vec<NodeId> = (0..2).map(NodeId::from_seed).collect();
+        controller.set_loss(nodes[0], nodes[1], 0.5);
+        let run = || {
+            let mut network = SwarmNetwork::new_grid(1, 2).with_routing(controller.clone(), nodes.clone(), 3);
+            for t in 0..200 {
+                network.send_envelope(0, SignedPacketEnvelope::new(vec![0], t), t);
+            }
+            (network.take_envelopes(1, 1000).len(), network.route_stats())
+        };
+        
+        let (received, stats) = run();
+        assert_eq!((received, stats), run());
+        assert_eq!(stats.dropped_loss + received as u64, 200);
+        assert!((60..140).contains(&received));
+    }
+    
+    #[test]
+    fn test_nacks_and_resends_are_routed_point_to_point() {
+        use crate::gossip_sequence::SequenceRange;
+        
+        let controller = SimNetworkController::new();
+        let nodes: Vec<NodeId> = (0..4).map(NodeId::from_seed).collect();
+        controller.set_latency(nodes[1], nodes[0], 30);
+        let mut network = SwarmNetwork::new_grid(2, 2).with_routing(controller.clone(), nodes.clone(), 1);
+        let nack = GossipNack { ranges: vec![SequenceRange { start: 4, len: 2 }] };
+        
+        assert!(network.send_nack(1, 0, nack.clone(), 0));
+        assert!(network.take_nacks(0, 20).is_empty(), "NACKs wait out the link latency");
+        assert_eq!(network.take_nacks(0, 30), vec![(1, nack.clone())]);
+        assert_eq!(network.route_stats().nacks_delivered, 1);
+        
+        // A resend reaches only the agent that asked
+        assert!(network.send_envelope_to(0, 1, SignedPacketEnvelope::new(vec![4], 40).with_sequence(4), 40));
+        assert_eq!(network.take_envelopes(1, 40).len(), 1);
+        assert!(network.take_envelopes(2, 40).is_empty() && network.take_envelopes(3, 40).is_empty());
+        
+        controller.partition(vec![nodes[0]], vec![nodes[1]]);
+        assert!(!network.send_nack(1, 0, nack, 50));
+        assert!(network.take_nacks(0, 100).is_empty());
+    }
 }
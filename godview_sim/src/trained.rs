@@ -0,0 +1,73 @@
+//! Evolved parameters carried from one run into another ("trained swarm").
+//!
+//! Evolving scenarios record each agent's final [`EvoParams`] in
+//! [`ScenarioMetrics::evolved_params`](crate::ScenarioMetrics::evolved_params).
+//! The CLI's `--save-params` writes them as a [`TrainedParams`] file, with
+//! the scenario, seed and crate version that produced them, and
+//! `--load-params` starts a later run's agents from it
+//! ([`ScenarioRunner::with_trained_params`](crate::ScenarioRunner::with_trained_params))
+//! instead of the defaults.
+
+use crate::corpus::CRATE_VERSION;
+use crate::evolution::EvoParams;
+use crate::runner::ScenarioResult;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+/// Each agent's evolved parameters at the end of a run, by agent index.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrainedParams {
+    /// Scenario the parameters were evolved in (see [`crate::ScenarioId::name`])
+    pub scenario: String,
+    
+    /// Seed of the training run
+    pub seed: u64,
+    
+    /// Crate version that ran it
+    pub crate_version: String,
+    
+    /// Final parameters of agent `i` at index `i`
+    pub agents: Vec<EvoParams>,
+}
+
+impl TrainedParams {
+    /// Takes the evolved parameters of a finished run, or None if its
+    /// scenario doesn't evolve its agents.
+    pub fn from_result(result: &ScenarioResult) -> Option<Self> {
+        if result.metrics.evolved_params.is_empty() {
+            return None;
+        }
+        Some(Self {
+            scenario: result.scenario.name().to_string(),
+            seed: result.seed,
+            crate_version: CRATE_VERSION.to_string(),
+            agents: result.metrics.evolved_params.clone(),
+        })
+    }
+    
+    /// Reads a file written by [`Self::save`].
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+    
+    /// Writes the parameters as pretty-printed JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        let mut file = std::fs::File::create(path)?;
+        file.write_all(json.as_bytes())?;
+        file.write_all(b"\n")
+    }
+    
+    /// Parameters for agent `index`. A swarm larger than the trained one
+    /// reuses them round-robin, so agent `i` gets the trained agent
+    /// `i % len`; a smaller one takes the first agents'. None if the file
+    /// holds no agents.
+    pub fn for_agent(&self, index: usize) -> Option<EvoParams> {
+        if self.agents.is_empty() {
+            return None;
+        }
+        Some(self.agents[index % self.agents.len()])
+    }
+}
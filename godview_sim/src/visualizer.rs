@@ -9,10 +9,31 @@
 //! - Agent track estimates as colored points per agent
 //! - Entity trajectories as lines
 //! - Simulation time as scalar timeline
+//!
+//! # Live Streaming
+//!
+//! [`RerunLogger::connect`] streams to a running Rerun Viewer over TCP while
+//! the scenario runs. Frames are stamped with the virtual clock on the
+//! `sim_time` timeline (and the tick on `tick`), so scrubbing follows
+//! simulated time. [`RerunLogger::with_log_every`] and
+//! [`RerunLogger::with_max_entities`] keep streaming from dominating long
+//! runs. A viewer that goes away only costs the frames: the first failed log
+//! drops to headless with a warning.
+//!
+//! A logger is also a [`SimEventSubscriber`]: the runner subscribes a
+//! [`RerunLogger::share`]d copy to the run's event bus, which logs each
+//! event as text under `events/<kind>` at the tick it was published.
 
 #[cfg(feature = "visualization")]
 use rerun::{RecordingStream, Points3D, Position3D, Color, Radius};
+use crate::agent::SimulatedAgent;
+use crate::event_bus::{PublishedEvent, SimEventSubscriber};
+use crate::oracle::Oracle;
 use nalgebra::Vector3;
+use std::cell::Cell;
+
+/// Address of a Rerun Viewer started with its default TCP port.
+pub const DEFAULT_RERUN_ADDR: &str = "127.0.0.1:9876";
 
 /// Rerun logger for simulation visualization.
 pub struct RerunLogger {
@@ -21,6 +42,15 @@ pub struct RerunLogger {
     
     /// Whether visualization is enabled
     enabled: bool,
+    
+    /// Set once a log call fails; the logger is headless from then on
+    failed: Cell<bool>,
+    
+    /// Ticks between frames logged by [`Self::log_swarm`]
+    every_n_ticks: u64,
+    
+    /// Most ground-truth entities, and tracks per agent, logged per frame
+    max_entities: Option<usize>,
 }
 
 impl RerunLogger {
@@ -30,9 +60,25 @@ impl RerunLogger {
             #[cfg(feature = "visualization")]
             rec: None,
             enabled: false,
+            failed: Cell::new(false),
+            every_n_ticks: 1,
+            max_entities: None,
         }
     }
     
+    /// Makes [`Self::log_swarm`] log a frame every `ticks` ticks (default 1).
+    pub fn with_log_every(mut self, ticks: u64) -> Self {
+        self.every_n_ticks = ticks.max(1);
+        self
+    }
+    
+    /// Makes [`Self::log_swarm`] log at most `max` ground-truth entities, and
+    /// `max` tracks per agent, per frame (default: all).
+    pub fn with_max_entities(mut self, max: usize) -> Self {
+        self.max_entities = Some(max);
+        self
+    }
+    
     /// Creates a new logger with visualization enabled.
     #[cfg(feature = "visualization")]
     pub fn new(name: &str) -> Self {
@@ -46,10 +92,7 @@ impl RerunLogger {
             }
             Err(e) => {
                 tracing::warn!("Failed to initialize Rerun: {:?}", e);
-                Self {
-                    rec: None,
-                    enabled: false,
-                }
+                Self::disabled()
             }
         }
     }
@@ -61,15 +104,95 @@ impl RerunLogger {
         Self::disabled()
     }
     
-    /// Returns whether visualization is enabled.
+    /// Creates a logger streaming to the Rerun Viewer listening on `addr`
+    /// (e.g. [`DEFAULT_RERUN_ADDR`]).
+    ///
+    /// An unparsable or unreachable address returns a disabled logger with
+    /// a warning, so the run continues headless.
+    #[cfg(feature = "visualization")]
+    pub fn connect(addr: &str) -> Self {
+        let addr = match reachable_addr(addr) {
+            Ok(addr) => addr,
+            Err(e) => {
+                tracing::warn!("Rerun Viewer at {} not reachable ({}); running headless", addr, e);
+                return Self::disabled();
+            }
+        };
+        let flush_timeout = Some(std::time::Duration::from_secs(2));
+        match rerun::RecordingStreamBuilder::new("godview_sim").connect_tcp_opts(addr, flush_timeout) {
+            Ok(rec) => {
+                tracing::info!("Streaming to Rerun Viewer at {}", addr);
+                Self {
+                    rec: Some(rec),
+                    enabled: true,
+                    ..Self::disabled()
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Failed to connect to Rerun at {}: {:?}", addr, e);
+                Self::disabled()
+            }
+        }
+    }
+    
+    /// Creates a logger - returns disabled if visualization feature not enabled.
+    #[cfg(not(feature = "visualization"))]
+    pub fn connect(_addr: &str) -> Self {
+        tracing::warn!("Rerun streaming not available (compile with --features visualization); running headless");
+        Self::disabled()
+    }
+    
+    /// A logger streaming to the same recording, logging every frame; it
+    /// goes headless on its own.
+    #[cfg(feature = "visualization")]
+    pub fn share(&self) -> Self {
+        Self {
+            rec: self.rec.clone(),
+            enabled: self.is_enabled(),
+            ..Self::disabled()
+        }
+    }
+    
+    #[cfg(not(feature = "visualization"))]
+    pub fn share(&self) -> Self {
+        Self { enabled: self.is_enabled(), ..Self::disabled() }
+    }
+    
+    /// Returns whether visualization is enabled (and hasn't failed).
     pub fn is_enabled(&self) -> bool {
-        self.enabled
+        self.enabled && !self.failed.get()
+    }
+    
+    /// Returns true if [`Self::log_swarm`] logs a frame at `tick`.
+    pub fn should_log(&self, tick: u64) -> bool {
+        self.is_enabled() && tick.is_multiple_of(self.every_n_ticks)
+    }
+    
+    /// The first [`Self::with_max_entities`] items of `items`.
+    fn limit<'a, T>(&self, items: &'a [T]) -> &'a [T] {
+        &items[..self.max_entities.map_or(items.len(), |max| max.min(items.len()))]
+    }
+    
+    /// Goes headless on the first failed log call.
+    #[cfg(feature = "visualization")]
+    fn check(&self, result: rerun::RecordingStreamResult<()>) {
+        if let Err(e) = result {
+            if !self.failed.replace(true) {
+                tracing::warn!("Rerun logging failed ({}); continuing headless", e);
+            }
+        }
+    }
+    
+    /// Active recording stream, unless logging has failed.
+    #[cfg(feature = "visualization")]
+    fn stream(&self) -> Option<&RecordingStream> {
+        self.rec.as_ref().filter(|_| !self.failed.get())
     }
     
     /// Sets the simulation time for subsequent logs.
     #[cfg(feature = "visualization")]
     pub fn set_time(&self, seconds: f64) {
-        if let Some(ref rec) = self.rec {
+        if let Some(rec) = self.stream() {
             rec.set_time_seconds("sim_time", seconds);
         }
     }
@@ -77,21 +200,49 @@ impl RerunLogger {
     #[cfg(not(feature = "visualization"))]
     pub fn set_time(&self, _seconds: f64) {}
     
+    /// Sets the tick and simulation time for subsequent logs.
+    #[cfg(feature = "visualization")]
+    pub fn set_tick(&self, tick: u64, seconds: f64) {
+        if let Some(rec) = self.stream() {
+            rec.set_time_sequence("tick", tick as i64);
+            rec.set_time_seconds("sim_time", seconds);
+        }
+    }
+    
+    #[cfg(not(feature = "visualization"))]
+    pub fn set_tick(&self, _tick: u64, _seconds: f64) {}
+    
+    /// Logs tick `tick` of a run (ground truth, each agent's tracks and RMS
+    /// error) at the oracle's virtual time, if it is on the
+    /// [`Self::with_log_every`] interval.
+    pub fn log_swarm<'a>(&self, tick: u64, oracle: &Oracle, agents: impl IntoIterator<Item = &'a SimulatedAgent>) {
+        if !self.should_log(tick) {
+            return;
+        }
+        let ground_truth = oracle.ground_truth_positions();
+        self.set_tick(tick, oracle.time());
+        self.log_ground_truth(self.limit(&ground_truth));
+        for agent in agents {
+            self.log_tracks(agent.agent_index(), self.limit(&agent.track_positions()));
+            self.log_error(agent.agent_index(), agent.compute_position_error(&ground_truth));
+        }
+    }
+    
     /// Logs ground truth entities from the Oracle.
     #[cfg(feature = "visualization")]
     pub fn log_ground_truth(&self, entities: &[(u64, Vector3<f64>)]) {
-        if let Some(ref rec) = self.rec {
+        if let Some(rec) = self.stream() {
             let points: Vec<Position3D> = entities
                 .iter()
                 .map(|(_, pos)| Position3D::new(pos.x as f32, pos.y as f32, pos.z as f32))
                 .collect();
             
-            let _ = rec.log(
+            self.check(rec.log(
                 "world/ground_truth",
                 &Points3D::new(points)
                     .with_colors([Color::from_rgb(0, 255, 0)]) // Green
                     .with_radii([Radius::new_scene_units(2.0)]),
-            );
+            ));
         }
     }
     
@@ -101,7 +252,7 @@ impl RerunLogger {
     /// Logs agent track estimates.
     #[cfg(feature = "visualization")]
     pub fn log_tracks(&self, agent_id: u64, tracks: &[(uuid::Uuid, Vector3<f64>)]) {
-        if let Some(ref rec) = self.rec {
+        if let Some(rec) = self.stream() {
             let points: Vec<Position3D> = tracks
                 .iter()
                 .map(|(_, pos)| Position3D::new(pos.x as f32, pos.y as f32, pos.z as f32))
@@ -117,12 +268,12 @@ impl RerunLogger {
                 _ => Color::from_rgb(255, 165, 0),   // Orange
             };
             
-            let _ = rec.log(
+            self.check(rec.log(
                 format!("world/agents/{}/tracks", agent_id),
                 &Points3D::new(points)
                     .with_colors([color])
                     .with_radii([Radius::new_scene_units(1.5)]),
-            );
+            ));
         }
     }
     
@@ -132,11 +283,11 @@ impl RerunLogger {
     /// Logs a text annotation (e.g., partition event).
     #[cfg(feature = "visualization")]
     pub fn log_event(&self, path: &str, message: &str) {
-        if let Some(ref rec) = self.rec {
-            let _ = rec.log(
+        if let Some(rec) = self.stream() {
+            self.check(rec.log(
                 path,
                 &rerun::TextLog::new(message),
-            );
+            ));
         }
     }
     
@@ -146,11 +297,11 @@ impl RerunLogger {
     /// Logs RMS error as a scalar metric.
     #[cfg(feature = "visualization")]
     pub fn log_error(&self, agent_id: u64, rms_error: f64) {
-        if let Some(ref rec) = self.rec {
-            let _ = rec.log(
+        if let Some(rec) = self.stream() {
+            self.check(rec.log(
                 format!("metrics/agent_{}/rms_error", agent_id),
                 &rerun::Scalar::new(rms_error),
-            );
+            ));
         }
     }
     
@@ -158,6 +309,25 @@ impl RerunLogger {
     pub fn log_error(&self, _agent_id: u64, _rms_error: f64) {}
 }
 
+impl SimEventSubscriber for RerunLogger {
+    fn on_event(&mut self, published: &PublishedEvent) {
+        self.set_tick(published.tick, published.time_secs);
+        self.log_event(&format!("events/{}", published.event.kind()), &published.event.to_string());
+    }
+}
+
+/// Resolves `addr` and checks that something accepts TCP connections there.
+#[cfg(feature = "visualization")]
+fn reachable_addr(addr: &str) -> std::io::Result<std::net::SocketAddr> {
+    use std::net::ToSocketAddrs;
+    
+    let addr = addr.to_socket_addrs()?
+        .next()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "no address"))?;
+    std::net::TcpStream::connect_timeout(&addr, std::time::Duration::from_secs(1))?;
+    Ok(addr)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,4 +341,29 @@ mod tests {
         logger.set_time(1.0);
         logger.log_ground_truth(&[(1, Vector3::new(0.0, 0.0, 0.0))]);
     }
+    
+    #[test]
+    fn test_throttling() {
+        let logger = RerunLogger::disabled().with_log_every(10).with_max_entities(2);
+        assert_eq!(logger.limit(&[1, 2, 3]), &[1, 2]);
+        assert_eq!(logger.limit(&[1]), &[1]);
+        assert_eq!(RerunLogger::disabled().limit(&[1, 2, 3]), &[1, 2, 3]);
+        
+        // Nothing is logged until the logger is enabled
+        assert!(!logger.should_log(10));
+        let logger = RerunLogger { enabled: true, ..logger };
+        assert!(logger.should_log(0) && logger.should_log(20));
+        assert!(!logger.should_log(5));
+        
+        // A failed log turns an enabled logger headless
+        logger.failed.set(true);
+        assert!(!logger.is_enabled() && !logger.should_log(20));
+    }
+    
+    #[test]
+    fn test_unreachable_viewer_runs_headless() {
+        // Port 9 (discard) on localhost isn't listening in CI
+        assert!(!RerunLogger::connect("127.0.0.1:9").is_enabled());
+        assert!(!RerunLogger::connect("not an address").is_enabled());
+    }
 }
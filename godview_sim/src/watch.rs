@@ -0,0 +1,296 @@
+//! Rebuild-and-rerun loop behind the CLI's `--watch` (`dev-tools` feature).
+//!
+//! A [`Watcher`] subscribes to the workspace's sources through [`notify`]
+//! (inotify, FSEvents or ReadDirectoryChangesW). On the first pass and after
+//! every change it runs `cargo build` for the `godview-sim` binary, runs the
+//! rebuilt binary with the same arguments plus `--bench-out`, and prints one
+//! line comparing the run's key metrics with the previous run's
+//! ([`watch_line`], built on [`BenchComparison`]).
+
+use crate::bench::{BenchBaseline, BenchComparison, BenchRun, DEFAULT_BENCH_TOLERANCE};
+use crate::interrupt::Interrupt;
+use notify::{Event, EventKind, RecursiveMode, Watcher as _};
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// How long a burst of changes must go quiet before a rebuild, so one
+/// save (or a `git checkout`) rebuilds once.
+pub const WATCH_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// How often the wait for changes checks for Ctrl-C.
+const INTERRUPT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Workspace members whose `.rs` and `.toml` files are watched.
+pub const WATCHED_CRATES: [&str; 3] = ["godview_core", "godview_env", "godview_sim"];
+
+/// True for the files a rebuild follows: `.rs` and `.toml` files outside
+/// any `target` directory.
+pub fn is_source(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "rs" || ext == "toml")
+        && !path.components().any(|c| c == Component::Normal("target".as_ref()))
+}
+
+/// Source files (see [`is_source`]) `event` created, modified or removed.
+/// Reads and other access events change nothing.
+pub fn changed_sources(event: &Event) -> Vec<PathBuf> {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return Vec::new();
+    }
+    event.paths.iter().filter(|path| is_source(path)).cloned().collect()
+}
+
+/// One line summarizing `current` against the `previous` run (None on the
+/// first run): per (scenario, seed), pass/fail with any flip, `rms_mean`
+/// and `tracks_per_agent` with their change, and any metric
+/// [`BenchComparison`] flags as regressed.
+pub fn watch_line(previous: Option<&BenchBaseline>, current: &BenchBaseline) -> String {
+    let comparison = previous.map(|p| BenchComparison::compare(p, current, DEFAULT_BENCH_TOLERANCE));
+    let before = |run: &BenchRun, metric: &str| {
+        comparison.as_ref()?.deltas.iter()
+            .find(|d| d.scenario == run.scenario && d.seed == run.seed && d.metric == metric)
+            .map(|d| d.baseline)
+    };
+    current.runs().iter()
+        .map(|run| {
+            let verdict = |passed: f64| if passed == 1.0 { "PASS" } else { "FAIL" };
+            let passed = run.metrics.get("passed").copied().unwrap_or(0.0);
+            let mut line = format!("{}[{}] ", run.scenario, run.seed);
+            match before(run, "passed") {
+                Some(was) if was != passed => line.push_str(&format!("{}→{}", verdict(was), verdict(passed))),
+                _ => line.push_str(verdict(passed)),
+            }
+            for (metric, label, unit) in [("rms_mean", "rms", "m"), ("tracks_per_agent", "tracks", "")] {
+                let Some(&now) = run.metrics.get(metric) else {
+                    continue;
+                };
+                line.push_str(&format!(" {label} {now:.3}{unit}"));
+                if let Some(was) = before(run, metric) {
+                    line.push_str(&format!(" ({:+.3})", now - was));
+                }
+            }
+            let regressed: Vec<&str> = comparison.iter()
+                .flat_map(|c| c.regressions())
+                .filter(|d| d.scenario == run.scenario && d.seed == run.seed && d.metric != "passed")
+                .map(|d| d.metric.as_str())
+                .collect();
+            if !regressed.is_empty() {
+                line.push_str(&format!(" ✗ worse: {}", regressed.join(", ")));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// Rebuilds and re-runs the CLI whenever the workspace sources change.
+pub struct Watcher {
+    /// Workspace root the binary was built from
+    workspace: PathBuf,
+    
+    /// Binary to re-run (resolved before any rebuild replaces it)
+    exe: PathBuf,
+    
+    /// Arguments for each run, without `--watch`
+    args: Vec<String>,
+    
+    /// File each run writes its metrics to
+    bench_file: PathBuf,
+}
+
+impl Watcher {
+    /// Watches the workspace this binary was built from, re-running it with
+    /// `args` (its command line minus `--watch`).
+    pub fn new(args: Vec<String>) -> std::io::Result<Self> {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        Ok(Self {
+            workspace: manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf(),
+            exe: std::env::current_exe()?,
+            args,
+            bench_file: std::env::temp_dir().join(format!("godview-watch-{}.json", std::process::id())),
+        })
+    }
+    
+    /// The watched crate directories and the workspace manifest.
+    pub fn roots(&self) -> Vec<PathBuf> {
+        std::iter::once(self.workspace.join("Cargo.toml"))
+            .chain(WATCHED_CRATES.iter().map(|name| self.workspace.join(name)))
+            .collect()
+    }
+    
+    /// Builds and runs once, then again after every change, until
+    /// `interrupt` is set. Fails if the sources can't be subscribed to.
+    pub fn run(&self, interrupt: &Interrupt) -> notify::Result<()> {
+        let (tx, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+        for root in self.roots() {
+            watcher.watch(&root, RecursiveMode::Recursive)?;
+        }
+        
+        let mut previous: Option<BenchBaseline> = None;
+        while !interrupt.is_set() {
+            if let Some(current) = self.build_and_run(interrupt) {
+                println!("{}", watch_line(previous.as_ref(), &current));
+                previous = Some(current);
+            }
+            eprintln!("Watching {} (Ctrl-C to stop)", self.workspace.display());
+            
+            let Some(changed) = next_change(&events, interrupt) else {
+                break;
+            };
+            let first = &changed[0];
+            let path = first.strip_prefix(&self.workspace).unwrap_or(first);
+            eprintln!("Changed: {}{}", path.display(), match changed.len() {
+                1 => String::new(),
+                n => format!(" and {} more", n - 1),
+            });
+        }
+        let _ = std::fs::remove_file(&self.bench_file);
+        Ok(())
+    }
+    
+    /// Rebuilds the binary and runs it; None if either failed or the run
+    /// was interrupted.
+    fn build_and_run(&self, interrupt: &Interrupt) -> Option<BenchBaseline> {
+        let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+        let mut build = Command::new(cargo);
+        build.current_dir(&self.workspace)
+            .args(["build", "-p", "godview_sim", "--bin", "godview-sim", "--features", "dev-tools"]);
+        if !cfg!(debug_assertions) {
+            build.arg("--release");
+        }
+        match build.status() {
+            Ok(status) if status.success() => {}
+            Ok(_) => {
+                eprintln!("Build failed; waiting for the next change");
+                return None;
+            }
+            Err(e) => {
+                eprintln!("Failed to run cargo: {}", e);
+                return None;
+            }
+        }
+        
+        let _ = std::fs::remove_file(&self.bench_file);
+        let run = Command::new(&self.exe)
+            .args(&self.args)
+            .arg("--bench-out")
+            .arg(&self.bench_file)
+            .status();
+        if let Err(e) = run {
+            eprintln!("Failed to run {}: {}", self.exe.display(), e);
+            return None;
+        }
+        if interrupt.is_set() {
+            return None;
+        }
+        match BenchBaseline::load(&self.bench_file) {
+            Ok(Some(current)) => Some(current),
+            Ok(None) => {
+                eprintln!("Run wrote no metrics; waiting for the next change");
+                None
+            }
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", self.bench_file.display(), e);
+                None
+            }
+        }
+    }
+}
+
+/// Waits for events that change a source file, then for them to go quiet
+/// for [`WATCH_DEBOUNCE`], and returns the changed files, sorted. None if
+/// `interrupt` is set or the watcher stopped first.
+fn next_change(events: &Receiver<notify::Result<Event>>, interrupt: &Interrupt) -> Option<Vec<PathBuf>> {
+    let mut changed: Vec<PathBuf> = Vec::new();
+    loop {
+        let wait = if changed.is_empty() { INTERRUPT_CHECK_INTERVAL } else { WATCH_DEBOUNCE };
+        match events.recv_timeout(wait) {
+            Ok(Ok(event)) => changed.extend(changed_sources(&event)),
+            Ok(Err(e)) => eprintln!("Watch error: {}", e),
+            Err(RecvTimeoutError::Timeout) if !changed.is_empty() => break,
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return None,
+        }
+        if interrupt.is_set() {
+            return None;
+        }
+    }
+    changed.sort();
+    changed.dedup();
+    Some(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn bench(runs: &[(&str, u64, f64, f64, f64)]) -> BenchBaseline {
+        let path = std::env::temp_dir().join(format!("godview-watch-test-{}-{}.json", std::process::id(), runs.len()));
+        let json = serde_json::json!({
+            "crate_version": "0.0.1",
+            "runs": runs.iter().map(|(scenario, seed, passed, rms, tracks)| serde_json::json!({
+                "scenario": scenario,
+                "seed": seed,
+                "metrics": { "passed": passed, "rms_mean": rms, "rms_p95": rms, "tracks_per_agent": tracks },
+            })).collect::<Vec<_>>(),
+        });
+        std::fs::write(&path, json.to_string()).unwrap();
+        let baseline = BenchBaseline::load(&path).unwrap().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        baseline
+    }
+    
+    #[test]
+    fn test_watch_line_shows_deltas_and_flips() {
+        let first = bench(&[("time_warp", 42, 1.0, 1.25, 10.0)]);
+        assert_eq!(watch_line(None, &first), "time_warp[42] PASS rms 1.250m tracks 10.000");
+        
+        let second = bench(&[("time_warp", 42, 0.0, 2.5, 9.0)]);
+        assert_eq!(
+            watch_line(Some(&first), &second),
+            "time_warp[42] PASS→FAIL rms 2.500m (+1.250) tracks 9.000 (-1.000) ✗ worse: rms_mean, rms_p95",
+        );
+        assert_eq!(watch_line(Some(&second), &second), "time_warp[42] FAIL rms 2.500m (+0.000) tracks 9.000 (+0.000)");
+    }
+    
+    #[test]
+    fn test_only_source_edits_outside_target_count() {
+        use notify::event::{AccessKind, CreateKind, ModifyKind, RemoveKind};
+        
+        assert!(is_source(Path::new("godview_sim/src/lib.rs")));
+        assert!(is_source(Path::new("Cargo.toml")));
+        assert!(!is_source(Path::new("godview_sim/DST.md")));
+        assert!(!is_source(Path::new("target/debug/build/out.rs")));
+        
+        let event = |kind| Event::new(kind)
+            .add_path(PathBuf::from("src/lib.rs"))
+            .add_path(PathBuf::from("notes.md"))
+            .add_path(PathBuf::from("target/built.rs"));
+        for kind in [
+            EventKind::Create(CreateKind::File),
+            EventKind::Modify(ModifyKind::Any),
+            EventKind::Remove(RemoveKind::File),
+        ] {
+            assert_eq!(changed_sources(&event(kind)), [PathBuf::from("src/lib.rs")], "{kind:?}");
+        }
+        assert!(changed_sources(&event(EventKind::Access(AccessKind::Read))).is_empty());
+    }
+    
+    #[test]
+    fn test_a_burst_of_events_is_one_change() {
+        use notify::event::ModifyKind;
+        
+        let (tx, events) = mpsc::channel();
+        let edit = |path: &str| Ok(Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from(path)));
+        for path in ["src/b.rs", "src/a.rs", "src/b.rs", "README.md"] {
+            tx.send(edit(path)).unwrap();
+        }
+        let interrupt = Interrupt::new();
+        assert_eq!(next_change(&events, &interrupt).unwrap(), [PathBuf::from("src/a.rs"), PathBuf::from("src/b.rs")]);
+        
+        drop(tx);
+        assert_eq!(next_change(&events, &interrupt), None);
+    }
+}
@@ -1,11 +1,13 @@
 //! SimWorld - The simulation harness container.
 
+use crate::agent::{GossipRelay, SimulatedAgent};
 use crate::context::SimContext;
 use crate::keys::DeterministicKeyProvider;
-use crate::network::{SimNetwork, SimNetworkController, NetworkMessage};
+use crate::network::{LatencyModel, SimNetwork, SimNetworkController, NetworkMessage};
 use crate::oracle::Oracle;
 
-use godview_env::{GodViewContext, NodeId};
+use godview_core::AgentConfig;
+use godview_env::{determinism, GodViewContext, NodeId};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -27,6 +29,19 @@ pub struct SimConfig {
     
     /// Position noise standard deviation for sensor readings
     pub sensor_noise_std: f64,
+    
+    /// Configuration shared by every agent
+    pub agent_config: AgentConfig,
+    
+    /// Which sensor readings each agent receives
+    pub visibility: Visibility,
+    
+    /// Gossip between agents (None = agents never gossip)
+    pub gossip: Option<GossipConfig>,
+    
+    /// Latency and reorder/duplicate faults on each sensor -> agent link
+    /// (None = readings are delivered directly)
+    pub sensor_faults: Option<SensorLinkFaults>,
 }
 
 impl Default for SimConfig {
@@ -37,10 +52,71 @@ impl Default for SimConfig {
             tick_rate_hz: 30,
             max_duration_secs: 60.0,
             sensor_noise_std: 0.5,
+            agent_config: SimulatedAgent::default_config(),
+            visibility: Visibility::All,
+            gossip: None,
+            sensor_faults: None,
+        }
+    }
+}
+
+/// Which entities an agent observes each tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    /// Every agent sees every entity
+    #[default]
+    All,
+    
+    /// Agent `a` sees entity index `e` when `(e + a) % period < visible`
+    Striped { period: usize, visible: usize },
+}
+
+impl Visibility {
+    /// Whether the agent at `agent_idx` sees the entity at `entity_idx`.
+    pub fn sees(&self, agent_idx: usize, entity_idx: usize) -> bool {
+        match *self {
+            Self::All => true,
+            Self::Striped { period, visible } => (entity_idx + agent_idx) % period.max(1) < visible,
         }
     }
 }
 
+/// Grid gossip between agents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GossipConfig {
+    /// Grid rows
+    pub rows: usize,
+    
+    /// Grid columns
+    pub cols: usize,
+    
+    /// Ticks between gossip rounds
+    pub interval_ticks: u64,
+    
+    /// Whether agents re-broadcast the envelopes they accept
+    pub relay: GossipRelay,
+    
+    /// Gossip packets older than this are dropped before fusion (`None`:
+    /// any age is fused)
+    pub max_packet_age_ms: Option<u64>,
+}
+
+/// Faults applied to readings between the sensor and each agent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorLinkFaults {
+    /// Probability a reading is held back behind later ones
+    pub reorder_probability: f64,
+    
+    /// Maximum number of later readings a held-back one waits behind
+    pub max_displacement: usize,
+    
+    /// Probability a reading is delivered twice
+    pub duplicate_probability: f64,
+    
+    /// Delay each reading is held for, in whole ticks (None = none)
+    pub latency: Option<LatencyModel>,
+}
+
 /// Handle to a simulated agent.
 pub struct AgentHandle {
     /// Agent's node ID
@@ -84,17 +160,19 @@ pub struct SimWorld {
 }
 
 impl SimWorld {
-    /// Creates a new SimWorld with the given configuration.
+    /// Creates a new SimWorld with the given configuration (entering
+    /// simulation in debug builds, as [`ScenarioRunner::new`](crate::ScenarioRunner::new) does).
     pub fn new(config: SimConfig) -> Self {
-        // Derive separate seeds for different subsystems
-        let context_seed = config.seed;
-        let physics_seed = config.seed.wrapping_mul(0x9e3779b97f4a7c15);
-        let key_seed = config.seed.wrapping_mul(0x517cc1b727220a95);
+        if cfg!(debug_assertions) {
+            determinism::enter_simulation();
+        }
         
-        let context = SimContext::shared(context_seed);
-        let keys = DeterministicKeyProvider::new(key_seed);
+        // Subsystems share the seed: each draws from its own named stream
+        // (`context/…`, `keys/…`, `oracle/…`), so none shifts another.
+        let context = SimContext::shared(config.seed);
+        let keys = DeterministicKeyProvider::new(config.seed);
         
-        let mut oracle = Oracle::new(physics_seed);
+        let mut oracle = Oracle::new(config.seed);
         oracle.set_position_noise(config.sensor_noise_std);
         
         let network_controller = SimNetworkController::new();
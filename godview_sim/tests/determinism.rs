@@ -0,0 +1,84 @@
+//! Wall-clock and OS-entropy leaks into simulation runs.
+//!
+//! A read of the system clock or the OS's entropy inside a run makes two
+//! runs of one seed drift apart. Each case runs a world twice, a little
+//! wall-clock time apart, and names the first frame whose state differs
+//! the way `--compare` compares two exports; the runs' RNG traces are
+//! compared the way `rng-diff` compares them. Separately, a debug build refuses OS
+//! entropy and the system clock outright once a runner exists (see
+//! [`godview_env::determinism`]).
+
+use godview_sim::scenarios::ScenarioId;
+use godview_sim::{first_frame_divergence, ScenarioRunner, SimFrame, SwarmConfig};
+use std::time::Duration;
+
+const SEED: u64 = 42;
+
+/// Wall-clock time between the two runs, so a clock read moves something.
+const PAUSE: Duration = Duration::from_millis(20);
+
+fn runner() -> ScenarioRunner {
+    ScenarioRunner::new(SEED, 6)
+        .with_threads(1)
+        .with_warmup_secs(0.5)
+        .with_duration(1.5)
+        .with_swarm_config(SwarmConfig { rows: 2, cols: 3, num_entities: 10, ..SwarmConfig::default() })
+}
+
+/// Every tick's frame: ground truth and each agent's tracks.
+fn frames(scenario: ScenarioId) -> Vec<SimFrame> {
+    runner().world(scenario).unwrap().frames(true).collect()
+}
+
+#[test]
+fn test_state_digests_ignore_the_wall_clock() {
+    for scenario in [ScenarioId::TimeWarp, ScenarioId::Swarm] {
+        let first = frames(scenario);
+        std::thread::sleep(PAUSE);
+        let second = frames(scenario);
+        assert!(!first.is_empty());
+        if let Some(divergence) = first_frame_divergence(&first, &second) {
+            panic!("{} diverged at {}", scenario.name(), divergence);
+        }
+    }
+}
+
+#[test]
+fn test_rng_traces_ignore_the_wall_clock() {
+    let trace = || {
+        let runner = runner().with_rng_audit(5);
+        runner.run(ScenarioId::TimeWarp).unwrap();
+        runner.take_rng_trace().unwrap()
+    };
+    let first = trace();
+    std::thread::sleep(PAUSE);
+    assert_eq!(first.first_divergence(&trace()), None);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn test_a_runner_forbids_os_entropy_and_the_system_clock() {
+    use godview_env::determinism::{self, Entropy, WallClock};
+    use godview_env::TokioContext;
+    use std::panic::catch_unwind;
+    
+    let _ = runner();
+    assert!(determinism::in_simulation());
+    for (source, attempt) in [
+        ("OsRng", catch_unwind(|| { Entropy::os(); })),
+        ("SystemTime", catch_unwind(|| { WallClock::system(); })),
+        ("SystemTime", catch_unwind(|| { TokioContext::new(); })),
+    ] {
+        let message = attempt.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(message.contains("nondeterminism in a simulation") && message.contains(source), "{message}");
+    }
+    
+    // Only this thread entered simulation
+    std::thread::spawn(|| {
+        assert!(!determinism::in_simulation());
+        Entropy::os();
+        WallClock::system();
+    }).join().unwrap();
+}
+
@@ -0,0 +1,166 @@
+//! Cross-environment conformance: one agent loop, written only against
+//! godview_env's traits, must make the same sends under tokio (real time,
+//! loopback transport) and under the simulator (virtual time, SimNetwork).
+//!
+//! The loop ticks on a fixed period, waits for packets with a timeout
+//! until the next tick, and sends only when its state changes. Latency is
+//! well under a tick, so every exchange settles inside the tick it starts
+//! in and the outcome doesn't depend on real-time jitter.
+
+use godview_env::{recv_timeout, EnvError, GodViewContext, LoopbackNetwork, NetworkTransport, NodeId, SignedPacketEnvelope, TokioContext};
+use godview_sim::{SimContext, SimExecutor, SimNetwork, SimNetworkController};
+use std::time::Duration;
+
+const NODES: u32 = 3;
+const TICKS: u32 = 12;
+const TICK: Duration = Duration::from_millis(40);
+const LATENCY: Duration = Duration::from_millis(5);
+
+/// One send of the reference agent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Sent {
+    tick: u32,
+    
+    /// Target (None: broadcast)
+    to: Option<NodeId>,
+    
+    value: u64,
+}
+
+fn packet(value: u64, now: Duration) -> SignedPacketEnvelope {
+    SignedPacketEnvelope::new(value.to_le_bytes().to_vec(), now.as_millis() as u64)
+}
+
+/// Gossips the highest counter it has seen. On its turn (every NODES-th
+/// tick) an agent bumps the counter and broadcasts it; an agent that hears
+/// a higher value adopts it and acknowledges the sender with it.
+async fn reference_agent<C: GodViewContext, N: NetworkTransport>(ctx: &C, net: &N, index: u32) -> Vec<Sent> {
+    let start = ctx.now();
+    let mut value = 0;
+    let mut sent = Vec::new();
+    for tick in 0..TICKS {
+        if tick % NODES == index {
+            value += 1;
+            assert_eq!(net.broadcast(packet(value, ctx.now())).await, NODES as usize - 1);
+            sent.push(Sent { tick, to: None, value });
+        }
+        
+        let deadline = start + TICK * (tick + 1);
+        while let Some(remaining) = deadline.checked_sub(ctx.now()).filter(|r| !r.is_zero()) {
+            match recv_timeout(ctx, net, remaining).await {
+                Ok((from, packet)) => {
+                    let received = u64::from_le_bytes(packet.payload.try_into().expect("8-byte counter"));
+                    if received > value {
+                        value = received;
+                        net.send(from, self::packet(value, ctx.now())).await.unwrap();
+                        sent.push(Sent { tick, to: Some(from), value });
+                    }
+                }
+                Err(EnvError::Timeout(_)) => break,
+                Err(e) => panic!("node {index}: {e}"),
+            }
+        }
+    }
+    sent
+}
+
+fn nodes() -> Vec<NodeId> {
+    (0..NODES as u64).map(NodeId::from_seed).collect()
+}
+
+/// Sends of every node, run on tokio with a loopback transport.
+async fn run_tokio() -> Vec<Vec<Sent>> {
+    let ctx = TokioContext::new();
+    let network = LoopbackNetwork::new(LATENCY);
+    let nets: Vec<_> = nodes().into_iter().map(|node| network.endpoint(node)).collect();
+    let (a, b, c) = tokio::join!(
+        reference_agent(&ctx, &nets[0], 0),
+        reference_agent(&ctx, &nets[1], 1),
+        reference_agent(&ctx, &nets[2], 2),
+    );
+    vec![a, b, c]
+}
+
+/// Sends of every node and the final virtual time, run on the simulator.
+fn run_sim(seed: u64) -> (Vec<Vec<Sent>>, Duration) {
+    let ctx = SimContext::new(seed);
+    let executor = SimExecutor::new(&ctx);
+    let controller = SimNetworkController::new();
+    let nodes = nodes();
+    for &from in &nodes {
+        for &to in &nodes {
+            controller.set_latency(from, to, LATENCY.as_millis() as u64);
+        }
+    }
+    let nets = SimNetwork::mesh(&ctx, &controller, &nodes);
+    let sent = executor.block_on(async {
+        let (a, b, c) = tokio::join!(
+            reference_agent(&ctx, &nets[0], 0),
+            reference_agent(&ctx, &nets[1], 1),
+            reference_agent(&ctx, &nets[2], 2),
+        );
+        vec![a, b, c]
+    });
+    (sent.expect("agents blocked forever"), ctx.now())
+}
+
+#[test]
+fn test_reference_agent_sends_the_same_under_tokio_and_sim() {
+    let (sim, _) = run_sim(42);
+    let tokio = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(run_tokio());
+    assert_eq!(tokio, sim);
+    
+    // Every turn broadcasts once and is acknowledged by each other node
+    let nodes = nodes();
+    for (index, sent) in sim.iter().enumerate() {
+        let turns: Vec<u32> = (0..TICKS).filter(|t| t % NODES == index as u32).collect();
+        let broadcasts: Vec<u32> = sent.iter().filter(|s| s.to.is_none()).map(|s| s.tick).collect();
+        assert_eq!(broadcasts, turns);
+        assert!(sent.iter().all(|s| s.to != Some(nodes[index])));
+    }
+    assert_eq!(sim.iter().map(Vec::len).sum::<usize>(), (TICKS * NODES) as usize);
+}
+
+#[test]
+fn test_sim_run_is_reproducible_on_virtual_time() {
+    let (first, elapsed) = run_sim(7);
+    assert_eq!(run_sim(7), (first, elapsed));
+    assert_eq!(elapsed, TICK * TICKS);
+}
+
+#[test]
+fn test_sim_latency_holds_packets_on_the_virtual_clock() {
+    let ctx = SimContext::new(1);
+    let executor = SimExecutor::new(&ctx);
+    let controller = SimNetworkController::new();
+    let (a, b) = (NodeId::from_seed(1), NodeId::from_seed(2));
+    controller.set_latency(a, b, 30);
+    let nets = SimNetwork::mesh(&ctx, &controller, &[a, b]);
+    
+    let (early, late) = executor.block_on(async {
+        nets[0].send(b, packet(1, ctx.now())).await.unwrap();
+        nets[0].send(b, packet(2, ctx.now())).await.unwrap();
+        let early = recv_timeout(&ctx, &nets[1], Duration::from_millis(20)).await;
+        let late = recv_timeout(&ctx, &nets[1], Duration::from_millis(20)).await.map(|(_, p)| (p.payload[0], ctx.now()));
+        (early, late)
+    }).unwrap();
+    
+    assert!(matches!(early, Err(EnvError::Timeout(20))));
+    assert_eq!(late.unwrap(), (1, Duration::from_millis(30)));
+    
+    // A partition drops packets at the router
+    controller.partition(vec![a], vec![b]);
+    let dropped = executor.block_on(async {
+        nets[0].send(b, packet(3, ctx.now())).await.unwrap();
+        let mut payloads = Vec::new();
+        while let Ok((_, p)) = recv_timeout(&ctx, &nets[1], Duration::from_millis(100)).await {
+            payloads.push(p.payload[0]);
+        }
+        payloads
+    });
+    assert_eq!(dropped, Some(vec![2]));
+}
@@ -0,0 +1,199 @@
+//! Golden exports: short, seeded runs of TimeWarp, Swarm and AdaptiveSwarm
+//! whose recorded [`SimExport`] is committed under `tests/golden/`.
+//!
+//! Each case re-runs its scenario and compares the export frame by frame
+//! with the fixture: ticks, ids, counts, coasting ages, truth labels and the event bus's
+//! messages exactly, positions, times and RMS errors within [`TOLERANCE`]. Frames are
+//! written sorted by id (see [`SimFrame::sort_by_id`]), so the comparison
+//! is positional.
+//!
+//! A mismatch means a change moved what the scenarios produce. If that was
+//! intended, regenerate the fixtures and review the diff before committing:
+//!
+//! ```text
+//! GODVIEW_BLESS=1 cargo test -p godview_sim --test golden
+//! git diff godview_sim/tests/golden/
+//! ```
+//!
+//! A missing fixture fails the same way; blessing writes it.
+
+use godview_sim::scenarios::ScenarioId;
+use godview_sim::{AgentFrame, ScenarioRunner, SimExport, SimFrame, SwarmConfig};
+use std::path::PathBuf;
+
+/// Largest accepted difference in a position, time or RMS error.
+const TOLERANCE: f64 = 1e-6;
+
+/// Environment variable that rewrites the fixtures instead of checking them.
+const BLESS_VAR: &str = "GODVIEW_BLESS";
+
+/// Mismatches listed before the rest are summarized.
+const MAX_REPORTED: usize = 20;
+
+const SEED: u64 = 42;
+
+/// Ticks between recorded frames.
+const RECORDING_INTERVAL_TICKS: u64 = 10;
+
+fn fixture_path(scenario: ScenarioId) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(format!("{}.json", scenario.name()))
+}
+
+fn small_swarm() -> SwarmConfig {
+    SwarmConfig {
+        rows: 2,
+        cols: 3,
+        num_entities: 10,
+        num_bad_actors: 1,
+        bad_actor_inject_secs: 1.0,
+        ..SwarmConfig::default()
+    }
+}
+
+fn record(scenario: ScenarioId, duration_secs: f64) -> SimExport {
+    let runner = ScenarioRunner::new(SEED, 6)
+        .with_threads(1)
+        .with_warmup_secs(0.5)
+        .with_duration(duration_secs)
+        .with_swarm_config(small_swarm())
+        .with_recording(RECORDING_INTERVAL_TICKS);
+    runner.run(scenario).unwrap();
+    runner.take_recording().unwrap()
+}
+
+/// Collects readable differences between an expected and actual export.
+#[derive(Default)]
+struct Diff {
+    lines: Vec<String>,
+    total: usize,
+}
+
+impl Diff {
+    fn push(&mut self, line: String) {
+        self.total += 1;
+        if self.lines.len() < MAX_REPORTED {
+            self.lines.push(line);
+        }
+    }
+    
+    fn exact<T: PartialEq + std::fmt::Debug>(&mut self, at: &str, what: &str, expected: T, actual: T) -> bool {
+        let same = expected == actual;
+        if !same {
+            self.push(format!("{at}: {what} expected {expected:?}, got {actual:?}"));
+        }
+        same
+    }
+    
+    fn close(&mut self, at: &str, what: &str, expected: f64, actual: f64) {
+        if (expected - actual).abs() > TOLERANCE && !(expected.is_nan() && actual.is_nan()) {
+            self.push(format!("{at}: {what} expected {expected}, got {actual} (off by {:.3e})", (expected - actual).abs()));
+        }
+    }
+    
+    fn close_opt(&mut self, at: &str, what: &str, expected: Option<f64>, actual: Option<f64>) {
+        match (expected, actual) {
+            (Some(e), Some(a)) => self.close(at, what, e, a),
+            (e, a) => {
+                self.exact(at, what, e, a);
+            }
+        }
+    }
+    
+    fn compare(&mut self, expected: &SimExport, actual: &SimExport) {
+        self.exact("export", "passed", expected.passed, actual.passed);
+        self.close_opt("export", "final_rms_error", expected.final_rms_error, actual.final_rms_error);
+        self.exact("export", "frame count", expected.frames.len(), actual.frames.len());
+        for (e, a) in expected.frames.iter().zip(&actual.frames) {
+            self.compare_frames(e, a);
+        }
+    }
+    
+    fn compare_frames(&mut self, expected: &SimFrame, actual: &SimFrame) {
+        let at = format!("frame tick {:?}", expected.tick);
+        self.exact(&at, "tick", expected.tick, actual.tick);
+        self.close(&at, "time_sec", expected.time_sec, actual.time_sec);
+        let messages = |frame: &SimFrame| frame.events.iter().map(|e| e.message.clone()).collect::<Vec<_>>();
+        self.exact(&at, "events", messages(expected), messages(actual));
+        
+        let ids = |frame: &SimFrame| frame.ground_truth.iter().map(|e| e.id).collect::<Vec<_>>();
+        if self.exact(&at, "ground truth ids", ids(expected), ids(actual)) {
+            for (e, a) in expected.ground_truth.iter().zip(&actual.ground_truth) {
+                let at = format!("{at} entity {}", e.id);
+                self.close(&at, "x", e.x, a.x);
+                self.close(&at, "y", e.y, a.y);
+                self.close(&at, "z", e.z, a.z);
+            }
+        }
+        
+        let ids = |frame: &SimFrame| frame.agents.iter().map(|a| a.agent_id).collect::<Vec<_>>();
+        if self.exact(&at, "agent ids", ids(expected), ids(actual)) {
+            for (e, a) in expected.agents.iter().zip(&actual.agents) {
+                self.compare_agents(&format!("{at} agent {}", e.agent_id), e, a);
+            }
+        }
+    }
+    
+    fn compare_agents(&mut self, at: &str, expected: &AgentFrame, actual: &AgentFrame) {
+        self.exact(at, "track_count", expected.track_count, actual.track_count);
+        self.close_opt(at, "rms_error", expected.rms_error, actual.rms_error);
+        let ids = |agent: &AgentFrame| agent.tracks.iter().map(|t| t.track_id.clone()).collect::<Vec<_>>();
+        if self.exact(at, "track ids", ids(expected), ids(actual)) {
+            for (e, a) in expected.tracks.iter().zip(&actual.tracks) {
+                let at = format!("{at} track {}", e.track_id);
+                self.close(&at, "x", e.x, a.x);
+                self.close(&at, "y", e.y, a.y);
+                self.close(&at, "z", e.z, a.z);
+                self.exact(&at, "coasting_ticks", e.coasting_ticks, a.coasting_ticks);
+                self.exact(&at, "debug_truth_id", e.debug_truth_id, a.debug_truth_id);
+                self.close_opt(&at, "debug_error_m", e.debug_error_m, a.debug_error_m);
+            }
+        }
+    }
+}
+
+/// Checks a fresh recording of `scenario` against its fixture, or rewrites
+/// the fixture when blessing.
+fn check_golden(scenario: ScenarioId, duration_secs: f64) {
+    let actual = record(scenario, duration_secs);
+    assert!(!actual.frames.is_empty(), "{scenario} recorded no frames");
+    let path = fixture_path(scenario);
+    if std::env::var_os(BLESS_VAR).is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        actual.write_to_file(path.to_str().unwrap()).unwrap();
+        eprintln!("blessed {}", path.display());
+        return;
+    }
+    
+    let regenerate = format!("If the change is intended, regenerate with `{BLESS_VAR}=1 cargo test -p godview_sim --test golden` and review the diff.");
+    let expected = match SimExport::load_from_file(path.to_str().unwrap()) {
+        Ok(expected) => expected,
+        Err(e) => panic!("can't load {}: {e}\n{regenerate}", path.display()),
+    };
+    let mut diff = Diff::default();
+    diff.compare(&expected, &actual);
+    if diff.total > 0 {
+        let more = diff.total.saturating_sub(diff.lines.len());
+        panic!(
+            "{scenario} export differs from {} in {} place(s):\n  {}{}\n{regenerate}",
+            path.display(),
+            diff.total,
+            diff.lines.join("\n  "),
+            if more > 0 { format!("\n  ... and {more} more") } else { String::new() },
+        );
+    }
+}
+
+#[test]
+fn test_time_warp_matches_golden() {
+    check_golden(ScenarioId::TimeWarp, 3.0);
+}
+
+#[test]
+fn test_swarm_matches_golden() {
+    check_golden(ScenarioId::Swarm, 2.0);
+}
+
+#[test]
+fn test_adaptive_swarm_matches_golden() {
+    check_golden(ScenarioId::AdaptiveSwarm, 2.0);
+}
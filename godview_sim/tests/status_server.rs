@@ -0,0 +1,90 @@
+//! The `--status-port` endpoint: a run publishing status while a
+//! [`StatusServer`] serves it over HTTP.
+//!
+//! The progress callback fetches `/status` as each report is made, so what
+//! the server returns can be checked against what the run saw at that tick;
+//! the final snapshot is checked against the result, and the run against
+//! one without a server.
+
+#![cfg(feature = "status-server")]
+
+use godview_sim::scenarios::ScenarioId;
+use godview_sim::{status_channel, ProgressReport, ScenarioRunner, StatusServer, StatusSnapshot, SwarmConfig};
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::{Arc, Mutex};
+
+const SEED: u64 = 42;
+
+fn runner() -> ScenarioRunner {
+    ScenarioRunner::new(SEED, 6)
+        .with_threads(1)
+        .with_warmup_secs(0.5)
+        .with_duration(1.5)
+        .with_progress_interval(5)
+        .with_swarm_config(SwarmConfig { rows: 2, cols: 3, num_entities: 10, ..SwarmConfig::default() })
+}
+
+/// Status code and body of `GET path`.
+fn get(addr: SocketAddr, path: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "GET {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").expect("a complete response");
+    let code = head.split(' ').nth(1).and_then(|code| code.parse().ok()).expect("a status line");
+    (code, body.to_string())
+}
+
+fn snapshot(addr: SocketAddr) -> StatusSnapshot {
+    let (code, body) = get(addr, "/status");
+    assert_eq!(code, 200, "{body}");
+    serde_json::from_str(&body).unwrap()
+}
+
+#[test]
+fn status_endpoint_serves_the_running_scenario() {
+    let (sender, receiver) = status_channel();
+    let server = StatusServer::start("127.0.0.1:0", receiver).unwrap();
+    let addr = server.local_addr();
+    assert_eq!(get(addr, "/status").0, 503, "nothing has run yet");
+    assert_eq!(get(addr, "/metrics").0, 404);
+
+    let seen: Arc<Mutex<Vec<(ProgressReport, StatusSnapshot)>>> = Arc::default();
+    let record = Arc::clone(&seen);
+    let result = runner()
+        .with_status(sender)
+        .with_progress(Box::new(move |report| record.lock().unwrap().push((report, snapshot(addr)))))
+        .run(ScenarioId::Swarm)
+        .unwrap();
+
+    // Each report is on the endpoint by the time progress hears of it
+    let seen = seen.lock().unwrap();
+    assert!(seen.len() > 2, "{} reports", seen.len());
+    for (report, status) in seen.iter() {
+        assert_eq!((status.tick, status.target_ticks), (report.tick, report.target_ticks));
+        // JSON floats parse back to within an ulp or so
+        assert!((status.time_secs - report.time_secs).abs() < 1e-9);
+        assert_eq!(status.tracks_per_agent.iter().sum::<usize>(), report.total_tracks);
+        assert_eq!(status.tracks_per_agent.iter().max().copied(), Some(report.max_tracks));
+        assert_eq!(status.memory_estimate_bytes, report.memory_estimate_bytes);
+    }
+    assert!(seen.windows(2).all(|pair| pair[0].1.tick < pair[1].1.tick));
+
+    let last = snapshot(addr);
+    assert_eq!(last.scenario, "swarm");
+    assert_eq!(last.seed, SEED);
+    assert_eq!(last.tick, result.total_ticks);
+    assert!((last.time_secs - result.final_time_secs).abs() < 1e-9);
+    assert_eq!(last.tracks_per_agent.len(), 6);
+    assert!(last.tracks_per_agent.iter().all(|&tracks| tracks > 0), "{:?}", last.tracks_per_agent);
+    assert!(last.ticks_per_sec > 0.0 && last.ticks_per_sec.is_finite());
+    assert!(last.memory_estimate_bytes > 0);
+    assert_eq!(last.last_violation, None);
+
+    // Watching changes nothing about the run
+    let plain = runner().run(ScenarioId::Swarm).unwrap();
+    assert_eq!((result.rms_mean, result.rms_p95, result.rms_samples), (plain.rms_mean, plain.rms_p95, plain.rms_samples));
+    assert_eq!(result.metrics.packets_sent, plain.metrics.packets_sent);
+    assert_eq!(result.total_ticks, plain.total_ticks);
+}
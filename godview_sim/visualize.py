@@ -8,6 +8,9 @@ Usage:
     
     # Visualize with Rerun
     python3 visualize.py sim_data.json
+
+MessagePack exports (--export sim_data.msgpack, or --export-format msgpack) are
+about a third the size of JSON; reading them needs `pip install msgpack`.
 """
 
 import json
@@ -16,10 +19,26 @@ import rerun as rr
 import numpy as np
 
 
+# Export layouts this script understands (see `godview-sim --export-schema`)
+SUPPORTED_SCHEMA_VERSIONS = (1, 2, 3, 4, 5, 6, 7, 8, 9)
+
+
 def load_simulation_data(path: str) -> dict:
-    """Load simulation data from JSON file."""
-    with open(path, 'r') as f:
-        return json.load(f)
+    """Load simulation data from a JSON or MessagePack file, refusing unknown layouts."""
+    with open(path, 'rb') as f:
+        raw = f.read()
+    # Same sniffing as SimExport::load_auto: JSON opens with an object
+    if raw.lstrip()[:1] == b'{':
+        data = json.loads(raw)
+    else:
+        import msgpack
+        data = msgpack.unpackb(raw, raw=False)
+    # Unversioned files predate schema_version 3; every field they lack is optional here
+    version = data.get("schema_version", 1)
+    if version not in SUPPORTED_SCHEMA_VERSIONS:
+        supported = ", ".join(str(v) for v in SUPPORTED_SCHEMA_VERSIONS)
+        sys.exit(f"{path}: schema_version {version} is not supported (supported: {supported})")
+    return data
 
 
 def visualize(data: dict):
@@ -64,12 +83,23 @@ def visualize(data: dict):
             if tracks:
                 positions = np.array([[t["x"], t["y"], t["z"]] for t in tracks])
                 color = agent_colors[agent_id % len(agent_colors)]
+                # Tracks with provenance take the color of the neighbor whose gossip
+                # shaped them most; coasting tracks (no update this tick) are drawn faded
+                colors = [
+                    agent_colors[t.get("dominant_contributor", agent_id) % len(agent_colors)][:3]
+                    + [255 if not t.get("coasting_ticks") else 96]
+                    for t in tracks
+                ]
+                # Debug-only ground-truth labels: the entity each track is matched to
+                labels = [f"entity {t['debug_truth_id']}" if t.get("debug_truth_id") is not None else "unmatched"
+                          for t in tracks]
                 rr.log(
                     f"world/agents/agent_{agent_id}",
                     rr.Points3D(
                         positions,
-                        colors=[color] * len(positions),
-                        radii=[1.5] * len(positions)
+                        colors=colors,
+                        radii=[1.5] * len(positions),
+                        labels=labels
                     )
                 )
             
@@ -90,12 +120,17 @@ def visualize(data: dict):
     print(f"  Seed: {seed}")
     if rms is not None:
         print(f"  Final RMS Error: {rms:.2f}m")
+    if data.get("interrupted", False):
+        print(f"  Interrupted after {len(frames)} frames")
+    if "filter" in data:
+        # Filtered exports hold only part of each frame (see --export-roi and friends)
+        print(f"  Partial export, filtered by: {', '.join(sorted(data['filter']))}")
     print(f"\nVisualization complete! Check the Rerun viewer.")
 
 
 def main():
     if len(sys.argv) < 2:
-        print("Usage: python3 visualize.py <simulation_data.json>")
+        print("Usage: python3 visualize.py <simulation_data.json|.msgpack>")
         print("\nGenerate data with:")
         print("  godview-sim --seed 42 --scenario time_warp --export sim_data.json")
         sys.exit(1)